@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 use server::{create_router, state::AppState};
 use std::io::Write;
 use std::path::PathBuf;
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, Layer};
 
 mod opencode_manager;
 use opencode_manager::OpenCodeManager;
@@ -47,6 +47,10 @@ struct Cli {
 
     #[arg(long, default_value = "http://localhost:4096")]
     opencode_url: String,
+
+    /// Serve a demo project seeded with sample tasks, sessions and wiki
+    #[arg(long)]
+    demo: bool,
 }
 
 #[derive(Subcommand)]
@@ -71,6 +75,11 @@ enum Commands {
 
         #[arg(long)]
         no_browser: bool,
+
+        /// Serve a demo project seeded with sample tasks, sessions and wiki
+        /// instead of PATH
+        #[arg(long, conflicts_with = "path")]
+        demo: bool,
     },
     /// Show project status
     Status {
@@ -80,6 +89,29 @@ enum Commands {
     },
     /// Update the frontend app to the latest version
     Update,
+    /// Code wiki tools
+    Wiki {
+        #[command(subcommand)]
+        command: WikiCommands,
+    },
+}
+
+#[derive(Subcommand)]
+enum WikiCommands {
+    /// Estimate what indexing the project would cost, without indexing it
+    Estimate {
+        /// Path to the project directory (defaults to current directory)
+        #[arg(value_name = "PATH")]
+        path: Option<PathBuf>,
+
+        /// Sub-directory to estimate; repeat for several (defaults to the configured roots)
+        #[arg(long = "root", value_name = "DIR")]
+        roots: Vec<String>,
+
+        /// Print the estimate as JSON
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -285,14 +317,26 @@ async fn main() -> Result<()> {
 
     match cli.command {
         Some(Commands::Init { path }) => init_project(path).await,
+        Some(Commands::Serve {
+            port,
+            opencode_url,
+            no_browser,
+            demo: true,
+            ..
+        }) => serve_demo(port, &opencode_url, !no_browser).await,
         Some(Commands::Serve {
             path,
             port,
             opencode_url,
             no_browser,
+            demo: false,
         }) => serve(path, port, &opencode_url, !no_browser).await,
         Some(Commands::Status { path }) => status(path).await,
         Some(Commands::Update) => update_frontend().await,
+        Some(Commands::Wiki {
+            command: WikiCommands::Estimate { path, roots, json },
+        }) => wiki_estimate(path, roots, json).await,
+        None if cli.demo => serve_demo(cli.port, &cli.opencode_url, true).await,
         None => serve(None, cli.port, &cli.opencode_url, true).await,
     }
 }
@@ -621,6 +665,61 @@ async fn serve(
     Ok(())
 }
 
+/// Serve the demo project, seeding it afresh from the bundled fixtures
+async fn serve_demo(port: u16, opencode_url: &str, open_browser: bool) -> Result<()> {
+    let app_dir = ensure_frontend_app().await.ok();
+
+    init_tracing();
+
+    // The seeded project is browsable without OpenCode; only running tasks
+    // needs it
+    let mut opencode_manager = OpenCodeManager::new(opencode_url);
+    if let Err(e) = opencode_manager.ensure_running().await {
+        tracing::warn!("OpenCode is not available, tasks cannot run: {}", e);
+    }
+
+    let state = AppState::new(opencode_url);
+    let state = if let Some(ref app_dir) = app_dir {
+        state.with_app_dir(app_dir.clone())
+    } else {
+        state
+    };
+
+    let seed = server::demo::seed(&state.project_manager)
+        .await
+        .context("Failed to seed demo project")?;
+
+    let app = create_router(state);
+
+    let listener = tokio::net::TcpListener::bind(format!("0.0.0.0:{}", port)).await?;
+
+    print_banner("demo", port);
+    println!(
+        "  {} Demo project at {}",
+        "ℹ".blue(),
+        seed.project_path.display().to_string().dimmed()
+    );
+    println!(
+        "     Remove it with {}",
+        format!("DELETE http://localhost:{}/api/admin/seed", port).cyan()
+    );
+    println!();
+
+    if open_browser {
+        let url = format!("http://localhost:{}", port);
+        tokio::spawn(async move {
+            tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+            if let Err(e) = open::that(&url) {
+                tracing::warn!("Failed to open browser: {}", e);
+            }
+        });
+    }
+
+    axum::serve(listener, app).await?;
+
+    Ok(())
+}
+
 async fn status(path: Option<PathBuf>) -> Result<()> {
     let cwd = resolve_project_path(path).await?;
     let studio_dir = cwd.join(STUDIO_DIR);
@@ -715,11 +814,95 @@ async fn status(path: Option<PathBuf>) -> Result<()> {
     Ok(())
 }
 
+async fn wiki_estimate(path: Option<PathBuf>, roots: Vec<String>, json: bool) -> Result<()> {
+    let cwd = resolve_project_path(path).await?;
+    let config = server::config::ProjectConfig::read(&cwd).await;
+
+    let embedding_model = config
+        .wiki
+        .embedding_model
+        .clone()
+        .unwrap_or_else(|| "openai/text-embedding-3-small".to_string());
+    let roots = if roots.is_empty() {
+        config.wiki.roots.clone()
+    } else {
+        roots
+    };
+
+    let spinner = ProgressBar::new_spinner();
+    spinner.set_message("Counting chunks and tokens...");
+    spinner.enable_steady_tick(std::time::Duration::from_millis(100));
+
+    let project_path = cwd.clone();
+    let model = embedding_model.clone();
+    let estimate = tokio::task::spawn_blocking(move || {
+        wiki::IndexEstimator::new(350, 100)
+            .with_roots(&roots)
+            .estimate(&project_path, &model)
+    })
+    .await??
+    .priced(&config.wiki.embedding_provider_config())
+    .await;
+    spinner.finish_and_clear();
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&estimate)?);
+        return Ok(());
+    }
+
+    println!();
+    println!(
+        "  {} {}",
+        "◆".magenta(),
+        "Wiki indexing estimate".white().bold()
+    );
+    println!("    {}", cwd.display().to_string().dimmed());
+    println!();
+    println!("    {:<10} {}", "Files".dimmed(), estimate.files);
+    println!("    {:<10} {}", "Chunks".dimmed(), estimate.chunks);
+    println!("    {:<10} {}", "Tokens".dimmed(), estimate.tokens);
+    println!("    {:<10} {}", "Model".dimmed(), embedding_model.cyan());
+    match (
+        estimate.estimated_cost_usd,
+        estimate.price_per_million_tokens,
+    ) {
+        (Some(cost), Some(price)) => println!(
+            "    {:<10} {} {}",
+            "Cost".dimmed(),
+            format!("${:.4}", cost).green().bold(),
+            format!("(${:.4} per 1M tokens)", price).dimmed()
+        ),
+        _ => println!(
+            "    {:<10} {}",
+            "Cost".dimmed(),
+            "unknown (no price listed for this model)".yellow()
+        ),
+    }
+    println!(
+        "    {:<10} ~{}s {}",
+        "Time".dimmed(),
+        estimate.estimated_seconds,
+        format!("({} embedding requests)", estimate.batches).dimmed()
+    );
+    println!();
+
+    Ok(())
+}
+
 fn init_tracing() {
+    // Job logs keep info lines even though the terminal only shows warnings
     tracing_subscriber::registry()
-        .with(tracing_subscriber::fmt::layer().with_target(false))
         .with(
-            tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| "warn".into()),
+            tracing_subscriber::fmt::layer()
+                .with_target(false)
+                .with_filter(
+                    tracing_subscriber::EnvFilter::try_from_default_env()
+                        .unwrap_or_else(|_| "warn".into()),
+                ),
+        )
+        .with(
+            server::job_logs::JobLogLayer::shared()
+                .with_filter(tracing_subscriber::filter::LevelFilter::INFO),
         )
         .init();
 }
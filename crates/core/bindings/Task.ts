@@ -1,4 +1,8 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { TaskStatus } from "./TaskStatus";
 
-export type Task = { id: string, title: string, description: string, status: TaskStatus, roadmap_item_id: string | null, workspace_path: string | null, created_at: string, updated_at: string, };
+export type Task = { id: string, title: string, description: string, status: TaskStatus, roadmap_item_id: string | null, workspace_path: string | null, 
+/**
+ * Tasks that must be done before this one can run
+ */
+depends_on: Array<string>, created_at: string, updated_at: string, };
@@ -91,6 +91,9 @@ pub struct Session {
     /// For multi-phase implementation: current phase title
     #[serde(skip_serializing_if = "Option::is_none")]
     pub implementation_phase_title: Option<String>,
+    /// Failed session this one retries
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_of: Option<Uuid>,
 }
 
 impl Session {
@@ -106,6 +109,7 @@ impl Session {
             created_at: Utc::now(),
             implementation_phase_number: None,
             implementation_phase_title: None,
+            retry_of: None,
         }
     }
 
@@ -126,6 +130,7 @@ impl Session {
             created_at: Utc::now(),
             implementation_phase_number: Some(phase_number),
             implementation_phase_title: Some(phase_title.into()),
+            retry_of: None,
         }
     }
 
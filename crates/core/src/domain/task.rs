@@ -3,13 +3,15 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default, ToSchema)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default, ToSchema)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(export))]
 #[serde(rename_all = "snake_case")]
 pub enum TaskStatus {
     #[default]
     Todo,
+    /// Waiting for the tasks it depends on to be done
+    Blocked,
     Planning,
     PlanningReview,
     InProgress,
@@ -24,6 +26,7 @@ impl TaskStatus {
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::Todo => "todo",
+            Self::Blocked => "blocked",
             Self::Planning => "planning",
             Self::PlanningReview => "planning_review",
             Self::InProgress => "in_progress",
@@ -37,6 +40,7 @@ impl TaskStatus {
     pub fn parse(s: &str) -> Option<Self> {
         match s {
             "todo" => Some(Self::Todo),
+            "blocked" => Some(Self::Blocked),
             "planning" => Some(Self::Planning),
             "planning_review" => Some(Self::PlanningReview),
             "in_progress" => Some(Self::InProgress),
@@ -59,6 +63,12 @@ pub struct Task {
     pub status: TaskStatus,
     pub roadmap_item_id: Option<Uuid>,
     pub workspace_path: Option<String>,
+    /// Tasks that must be done before this one can run
+    #[serde(default)]
+    pub depends_on: Vec<Uuid>,
+    /// Number of the GitHub pull request opened from the task's workspace
+    #[serde(default)]
+    pub pr_number: Option<u64>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -73,6 +83,8 @@ impl Task {
             status: TaskStatus::default(),
             roadmap_item_id: None,
             workspace_path: None,
+            depends_on: Vec::new(),
+            pr_number: None,
             created_at: now,
             updated_at: now,
         }
@@ -116,6 +128,8 @@ mod tests {
         assert_eq!(task.status, TaskStatus::Todo);
         assert!(task.roadmap_item_id.is_none());
         assert!(task.workspace_path.is_none());
+        assert!(task.depends_on.is_empty());
+        assert!(task.pr_number.is_none());
     }
 
     #[test]
@@ -132,6 +146,7 @@ mod tests {
             TaskStatus::parse("in_progress"),
             Some(TaskStatus::InProgress)
         );
+        assert_eq!(TaskStatus::parse("blocked"), Some(TaskStatus::Blocked));
         assert_eq!(TaskStatus::parse("invalid"), None);
     }
 
@@ -1,5 +1,9 @@
 pub mod domain;
 pub mod error;
+pub mod mcp;
+pub mod mcp_calls;
 
 pub use domain::*;
 pub use error::*;
+pub use mcp::*;
+pub use mcp_calls::*;
@@ -0,0 +1,95 @@
+//! Tool allowlists for the MCP servers attached to sessions
+
+use std::collections::BTreeSet;
+
+/// Environment variable MCP servers read their allowlist from, as a
+/// comma-separated list of tool names
+pub const ALLOWED_TOOLS_ENV: &str = "OPENCODE_MCP_ALLOWED_TOOLS";
+
+/// Tools an MCP server may offer a session. Other tools are hidden from the
+/// model and calls to them are refused.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ToolAllowlist {
+    /// `None` allows every tool
+    tools: Option<BTreeSet<String>>,
+}
+
+impl ToolAllowlist {
+    /// Allow every tool
+    pub fn all() -> Self {
+        Self { tools: None }
+    }
+
+    /// Allow only the given tools
+    pub fn only<I, S>(tools: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            tools: Some(tools.into_iter().map(Into::into).collect()),
+        }
+    }
+
+    /// Parse a comma-separated list of tool names; `*` allows every tool
+    pub fn parse(value: &str) -> Self {
+        if value.trim() == "*" {
+            return Self::all();
+        }
+        Self::only(
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|tool| !tool.is_empty()),
+        )
+    }
+
+    /// Allowlist given as the value of `ALLOWED_TOOLS_ENV`, allowing every
+    /// tool if there is none
+    pub fn new(value: Option<&str>) -> Self {
+        value.map(Self::parse).unwrap_or_default()
+    }
+
+    pub fn allows(&self, tool: &str) -> bool {
+        match &self.tools {
+            Some(tools) => tools.contains(tool),
+            None => true,
+        }
+    }
+
+    pub fn is_restricted(&self) -> bool {
+        self.tools.is_some()
+    }
+
+    /// Value for `ALLOWED_TOOLS_ENV`
+    pub fn to_env_value(&self) -> String {
+        match &self.tools {
+            Some(tools) => tools.iter().cloned().collect::<Vec<_>>().join(","),
+            None => "*".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tool_allowlist() {
+        let allowlist = ToolAllowlist::parse("list_findings, mark_fixed,");
+        assert!(allowlist.is_restricted());
+        assert!(allowlist.allows("mark_fixed"));
+        assert!(!allowlist.allows("create_finding"));
+        assert_eq!(allowlist.to_env_value(), "list_findings,mark_fixed");
+        assert_eq!(ToolAllowlist::parse(&allowlist.to_env_value()), allowlist);
+
+        assert!(ToolAllowlist::parse("*").allows("create_finding"));
+        assert!(ToolAllowlist::all().allows("anything"));
+        assert!(!ToolAllowlist::parse("").allows("anything"));
+        assert!(ToolAllowlist::new(None).allows("anything"));
+        assert_eq!(
+            ToolAllowlist::new(Some("mark_fixed,list_findings")),
+            allowlist
+        );
+    }
+}
@@ -0,0 +1,104 @@
+//! Log of the tool calls the MCP servers attached to sessions handle
+//!
+//! The servers run as separate processes, so each appends its calls to a
+//! file of the project, one JSON object per line, which the server reads to
+//! report usage. Once the log reaches `MAX_TOOL_CALL_LOG_BYTES` it is moved
+//! to `rotated_tool_call_log_path`, replacing the calls rotated before, so
+//! the calls kept never take more than twice that.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Environment variable MCP servers read the path of the tool call log from
+pub const TOOL_CALL_LOG_ENV: &str = "OPENCODE_MCP_CALL_LOG";
+
+/// Environment variable MCP servers read the id of the calling session from
+pub const SESSION_ID_ENV: &str = "OPENCODE_SESSION_ID";
+
+/// Tool call log of the project at `project_path`
+pub fn tool_call_log_path(project_path: &Path) -> PathBuf {
+    project_path
+        .join(".opencode-studio")
+        .join("analytics")
+        .join("mcp-calls.jsonl")
+}
+
+/// Size at which the tool call log is rotated
+pub const MAX_TOOL_CALL_LOG_BYTES: u64 = 8 * 1024 * 1024;
+
+/// Where the tool call log at `log_path` is moved when it is rotated
+pub fn rotated_tool_call_log_path(log_path: &Path) -> PathBuf {
+    let mut path = log_path.as_os_str().to_owned();
+    path.push(".1");
+    PathBuf::from(path)
+}
+
+/// One tool call handled by an MCP server
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct McpToolCall {
+    /// Name of the MCP server, e.g. `opencode-findings`
+    pub server: String,
+    pub tool: String,
+    /// Session that made the call, if the server knows it
+    pub session_id: Option<String>,
+    pub called_at: DateTime<Utc>,
+    pub duration_ms: u64,
+    pub success: bool,
+    /// Why the call failed
+    pub error: Option<String>,
+}
+
+impl McpToolCall {
+    /// Call of `tool` started at `called_at` that took `duration` and
+    /// failed with `error`, if any
+    pub fn new(
+        server: impl Into<String>,
+        tool: impl Into<String>,
+        session_id: Option<String>,
+        called_at: DateTime<Utc>,
+        duration: Duration,
+        error: Option<String>,
+    ) -> Self {
+        Self {
+            server: server.into(),
+            tool: tool.into(),
+            session_id,
+            called_at,
+            duration_ms: duration.as_millis().min(u64::MAX as u128) as u64,
+            success: error.is_none(),
+            error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tool_call_paths() {
+        let path = tool_call_log_path(Path::new("/repo"));
+        assert_eq!(
+            path,
+            Path::new("/repo/.opencode-studio/analytics/mcp-calls.jsonl")
+        );
+        assert_eq!(
+            rotated_tool_call_log_path(&path),
+            Path::new("/repo/.opencode-studio/analytics/mcp-calls.jsonl.1")
+        );
+
+        let failed = McpToolCall::new(
+            "opencode-wiki",
+            "search_code",
+            None,
+            Utc::now(),
+            Duration::from_millis(340),
+            Some("index missing".to_string()),
+        );
+        assert!(!failed.success);
+        assert_eq!(failed.duration_ms, 340);
+    }
+}
@@ -0,0 +1,14 @@
+-- Wiki pages updated or flagged after a task was merged
+CREATE TABLE IF NOT EXISTS task_wiki_updates (
+    id TEXT PRIMARY KEY,
+    task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    branch TEXT NOT NULL,
+    page_slug TEXT NOT NULL,
+    page_title TEXT NOT NULL,
+    action TEXT NOT NULL,
+    changed_files TEXT NOT NULL DEFAULT '[]',
+    content TEXT NOT NULL,
+    created_at INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_task_wiki_updates_task_id ON task_wiki_updates(task_id);
@@ -0,0 +1,9 @@
+-- Per-user defaults and notification subscriptions
+CREATE TABLE IF NOT EXISTS user_preferences (
+    user_id TEXT PRIMARY KEY,
+    board_filters TEXT NOT NULL DEFAULT '{}',
+    notification_channels TEXT NOT NULL DEFAULT '["in_app"]',
+    event_subscriptions TEXT NOT NULL DEFAULT '[]',
+    ask_model TEXT,
+    updated_at INTEGER NOT NULL
+);
@@ -0,0 +1,2 @@
+-- Link a retried session to the failed session it replaces
+ALTER TABLE sessions ADD COLUMN retry_of TEXT DEFAULT NULL;
@@ -0,0 +1,6 @@
+-- Persist published event envelopes in the event history table
+ALTER TABLE events ADD COLUMN event_id TEXT DEFAULT NULL;
+ALTER TABLE events ADD COLUMN task_id TEXT DEFAULT NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_events_event_id ON events(event_id);
+CREATE INDEX IF NOT EXISTS idx_events_task_id ON events(task_id);
@@ -0,0 +1,4 @@
+-- Email digests of board activity, scheduled per user
+ALTER TABLE user_preferences ADD COLUMN digest_email TEXT DEFAULT NULL;
+ALTER TABLE user_preferences ADD COLUMN digest_schedule TEXT NOT NULL DEFAULT 'off';
+ALTER TABLE user_preferences ADD COLUMN digest_last_sent_at INTEGER DEFAULT NULL;
@@ -0,0 +1,16 @@
+-- Files and reports produced by sessions. The content is stored once per
+-- hash under .opencode-studio/artifacts; rows only describe it.
+CREATE TABLE IF NOT EXISTS session_artifacts (
+    id TEXT PRIMARY KEY,
+    session_id TEXT NOT NULL REFERENCES sessions(id) ON DELETE CASCADE,
+    task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    kind TEXT NOT NULL,
+    name TEXT NOT NULL,
+    content_hash TEXT NOT NULL,
+    size_bytes INTEGER NOT NULL,
+    media_type TEXT NOT NULL,
+    created_at INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_session_artifacts_session_id ON session_artifacts(session_id);
+CREATE INDEX IF NOT EXISTS idx_session_artifacts_content_hash ON session_artifacts(content_hash);
@@ -0,0 +1,10 @@
+-- Diffs of tasks merged into the main branch, kept for release reviews
+CREATE TABLE IF NOT EXISTS task_merges (
+    id TEXT PRIMARY KEY,
+    task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    branch TEXT NOT NULL,
+    diff TEXT NOT NULL,
+    merged_at INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_task_merges_merged_at ON task_merges(merged_at);
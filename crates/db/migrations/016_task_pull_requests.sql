@@ -0,0 +1,2 @@
+-- GitHub pull request opened from a task's workspace
+ALTER TABLE tasks ADD COLUMN pr_number INTEGER;
@@ -0,0 +1,20 @@
+-- Tokens and estimated cost of each OpenRouter call, for cost reports per
+-- task and per wiki run. Rows outlive the tasks they belong to.
+CREATE TABLE IF NOT EXISTS usage (
+    id TEXT PRIMARY KEY,
+    task_id TEXT,
+    session_id TEXT,
+    wiki_branch TEXT,
+    model TEXT NOT NULL,
+    -- 'chat' or 'embedding'
+    kind TEXT NOT NULL,
+    prompt_tokens INTEGER NOT NULL,
+    completion_tokens INTEGER NOT NULL,
+    -- NULL when the model's price is unknown
+    cost_usd REAL,
+    created_at INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_usage_task_id ON usage(task_id);
+CREATE INDEX IF NOT EXISTS idx_usage_wiki_branch ON usage(wiki_branch);
+CREATE INDEX IF NOT EXISTS idx_usage_created_at ON usage(created_at);
@@ -1,9 +1,11 @@
 mod error;
 pub mod models;
+mod pagination;
 mod pool;
 pub mod repositories;
 
 pub use error::*;
 pub use models::{CreateSessionActivity, SessionActivity, SessionActivityRow};
+pub use pagination::*;
 pub use pool::*;
 pub use repositories::*;
@@ -16,6 +16,8 @@ pub struct SessionRow {
     pub implementation_phase_number: Option<i32>,
     /// For multi-phase implementation: current phase title
     pub implementation_phase_title: Option<String>,
+    /// ID of the failed session this one retries
+    pub retry_of: Option<String>,
 }
 
 impl SessionRow {
@@ -31,6 +33,7 @@ impl SessionRow {
             created_at: timestamp_to_datetime(self.created_at),
             implementation_phase_number: self.implementation_phase_number.map(|n| n as u32),
             implementation_phase_title: self.implementation_phase_title,
+            retry_of: self.retry_of.and_then(|id| Uuid::parse_str(&id).ok()),
         }
     }
 }
@@ -48,6 +51,7 @@ impl From<&Session> for SessionRow {
             created_at: datetime_to_timestamp(session.created_at),
             implementation_phase_number: session.implementation_phase_number.map(|n| n as i32),
             implementation_phase_title: session.implementation_phase_title.clone(),
+            retry_of: session.retry_of.map(|id| id.to_string()),
         }
     }
 }
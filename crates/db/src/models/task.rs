@@ -10,6 +10,9 @@ pub struct TaskRow {
     pub status: String,
     pub roadmap_item_id: Option<String>,
     pub workspace_path: Option<String>,
+    /// Comma-separated IDs of the tasks this one depends on
+    pub depends_on: Option<String>,
+    pub pr_number: Option<i64>,
     pub created_at: i64,
     pub updated_at: i64,
 }
@@ -23,6 +26,14 @@ impl TaskRow {
             status: TaskStatus::parse(&self.status).unwrap_or_default(),
             roadmap_item_id: self.roadmap_item_id.and_then(|s| Uuid::parse_str(&s).ok()),
             workspace_path: self.workspace_path,
+            depends_on: self
+                .depends_on
+                .as_deref()
+                .unwrap_or_default()
+                .split(',')
+                .filter_map(|id| Uuid::parse_str(id).ok())
+                .collect(),
+            pr_number: self.pr_number.and_then(|n| u64::try_from(n).ok()),
             created_at: timestamp_to_datetime(self.created_at),
             updated_at: timestamp_to_datetime(self.updated_at),
         }
@@ -38,6 +49,14 @@ impl From<&Task> for TaskRow {
             status: task.status.as_str().to_string(),
             roadmap_item_id: task.roadmap_item_id.map(|id| id.to_string()),
             workspace_path: task.workspace_path.clone(),
+            depends_on: (!task.depends_on.is_empty()).then(|| {
+                task.depends_on
+                    .iter()
+                    .map(Uuid::to_string)
+                    .collect::<Vec<_>>()
+                    .join(",")
+            }),
+            pr_number: task.pr_number.and_then(|n| i64::try_from(n).ok()),
             created_at: datetime_to_timestamp(task.created_at),
             updated_at: datetime_to_timestamp(task.updated_at),
         }
@@ -0,0 +1,52 @@
+/// Which slice of a list to return, and in what order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PageRequest {
+    /// Maximum number of items; `None` returns every item from `offset`
+    pub limit: Option<u32>,
+    pub offset: u32,
+    /// Field to sort by; repositories fall back to their default order for
+    /// fields they can't sort by
+    pub sort: Option<String>,
+    pub descending: bool,
+}
+
+impl PageRequest {
+    /// Parse a sort parameter such as `title` or `-created_at`, where a
+    /// leading `-` sorts in descending order
+    pub fn with_sort(mut self, sort: &str) -> Self {
+        match sort.strip_prefix('-') {
+            Some(field) => {
+                self.sort = Some(field.to_string());
+                self.descending = true;
+            }
+            None => {
+                self.sort = Some(sort.to_string());
+                self.descending = false;
+            }
+        }
+        self
+    }
+
+    /// `ORDER BY ... LIMIT ... OFFSET ...` for a query, sorting by one of
+    /// `columns` or `default_order` when no valid sort field was requested
+    pub(crate) fn sql_suffix(&self, columns: &[&str], default_order: &str) -> String {
+        let order = match self.sort.as_deref().filter(|s| columns.contains(s)) {
+            Some(column) => format!(
+                "{} {}, id",
+                column,
+                if self.descending { "DESC" } else { "ASC" }
+            ),
+            None => default_order.to_string(),
+        };
+        // SQLite treats a negative limit as no limit
+        let limit = self.limit.map(i64::from).unwrap_or(-1);
+        format!("ORDER BY {} LIMIT {} OFFSET {}", order, limit, self.offset)
+    }
+}
+
+/// One page of a list, with the size of the whole list
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: u64,
+}
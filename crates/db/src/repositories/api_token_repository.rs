@@ -0,0 +1,164 @@
+use crate::error::DbError;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ApiToken {
+    pub id: String,
+    pub name: String,
+    /// Hex-encoded SHA-256 of the token; the token itself is never stored
+    pub token_hash: String,
+    /// JSON array of scopes, e.g. `read:tasks`
+    pub scopes: String,
+    pub created_at: i64,
+    pub last_used_at: Option<i64>,
+    pub revoked_at: Option<i64>,
+}
+
+impl ApiToken {
+    /// Parsed list of scopes
+    pub fn scopes(&self) -> Vec<String> {
+        serde_json::from_str(&self.scopes).unwrap_or_default()
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+}
+
+#[derive(Clone)]
+pub struct ApiTokenRepository {
+    pool: SqlitePool,
+}
+
+impl ApiTokenRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Get all tokens, including revoked ones, newest first
+    pub async fn find_all(&self) -> Result<Vec<ApiToken>, DbError> {
+        let tokens = sqlx::query_as::<_, ApiToken>(
+            r#"
+            SELECT id, name, token_hash, scopes, created_at, last_used_at, revoked_at
+            FROM api_tokens
+            ORDER BY created_at DESC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(tokens)
+    }
+
+    /// Find the token with the given hash
+    pub async fn find_by_hash(&self, token_hash: &str) -> Result<Option<ApiToken>, DbError> {
+        let token = sqlx::query_as::<_, ApiToken>(
+            r#"
+            SELECT id, name, token_hash, scopes, created_at, last_used_at, revoked_at
+            FROM api_tokens
+            WHERE token_hash = ?
+            "#,
+        )
+        .bind(token_hash)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(token)
+    }
+
+    /// Store a new token
+    pub async fn create(
+        &self,
+        name: &str,
+        token_hash: &str,
+        scopes: &[String],
+    ) -> Result<ApiToken, DbError> {
+        let token = ApiToken {
+            id: Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            token_hash: token_hash.to_string(),
+            scopes: serde_json::to_string(scopes).unwrap_or_else(|_| "[]".to_string()),
+            created_at: Utc::now().timestamp(),
+            last_used_at: None,
+            revoked_at: None,
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO api_tokens (id, name, token_hash, scopes, created_at)
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&token.id)
+        .bind(&token.name)
+        .bind(&token.token_hash)
+        .bind(&token.scopes)
+        .bind(token.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(token)
+    }
+
+    /// Record that a token was just used
+    pub async fn touch(&self, id: &str) -> Result<(), DbError> {
+        sqlx::query("UPDATE api_tokens SET last_used_at = ? WHERE id = ?")
+            .bind(Utc::now().timestamp())
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Revoke a token. Returns false if there is no such active token.
+    pub async fn revoke(&self, id: &str) -> Result<bool, DbError> {
+        let result =
+            sqlx::query("UPDATE api_tokens SET revoked_at = ? WHERE id = ? AND revoked_at IS NULL")
+                .bind(Utc::now().timestamp())
+                .bind(id)
+                .execute(&self.pool)
+                .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_pool, run_migrations};
+
+    async fn setup_test_db() -> SqlitePool {
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+        pool
+    }
+
+    #[tokio::test]
+    async fn test_create_touch_and_revoke() {
+        let pool = setup_test_db().await;
+        let repo = ApiTokenRepository::new(pool);
+
+        let token = repo
+            .create("dashboard", "abc123", &["read:tasks".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(token.scopes(), vec!["read:tasks"]);
+
+        repo.touch(&token.id).await.unwrap();
+        let found = repo.find_by_hash("abc123").await.unwrap().unwrap();
+        assert!(found.last_used_at.is_some());
+        assert!(!found.is_revoked());
+
+        assert!(repo.revoke(&token.id).await.unwrap());
+        assert!(!repo.revoke(&token.id).await.unwrap());
+        let found = repo.find_by_hash("abc123").await.unwrap().unwrap();
+        assert!(found.is_revoked());
+
+        assert!(repo.find_by_hash("other").await.unwrap().is_none());
+        assert_eq!(repo.find_all().await.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,343 @@
+use crate::error::DbError;
+use crate::pagination::{Page, PageRequest};
+use chrono::{DateTime, Utc};
+use sqlx::{QueryBuilder, Sqlite, SqlitePool};
+use uuid::Uuid;
+
+/// A published event as stored in the event history
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct StoredEvent {
+    /// Position in the history, increasing in publish order
+    pub id: i64,
+    /// ID of the event envelope
+    pub event_id: String,
+    /// Event type tag, e.g. `task.created`
+    pub event_type: String,
+    pub task_id: Option<String>,
+    /// The serialized event envelope
+    pub payload: String,
+    pub created_at: i64,
+}
+
+/// Which events to return from the history
+#[derive(Debug, Clone, Default)]
+pub struct EventFilter {
+    /// Only events at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Only events stored after this position in the history
+    pub after_seq: Option<i64>,
+    pub event_type: Option<String>,
+    pub task_id: Option<Uuid>,
+}
+
+impl EventFilter {
+    fn push_where(&self, query: &mut QueryBuilder<'_, Sqlite>) {
+        query.push(" WHERE 1 = 1");
+        if let Some(since) = self.since {
+            query
+                .push(" AND created_at >= ")
+                .push_bind(since.timestamp());
+        }
+        if let Some(after_seq) = self.after_seq {
+            query.push(" AND id > ").push_bind(after_seq);
+        }
+        if let Some(event_type) = &self.event_type {
+            query
+                .push(" AND event_type = ")
+                .push_bind(event_type.clone());
+        }
+        if let Some(task_id) = self.task_id {
+            query.push(" AND task_id = ").push_bind(task_id.to_string());
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct EventRepository {
+    pool: SqlitePool,
+}
+
+impl EventRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Store an event; storing the same envelope twice is a no-op
+    pub async fn create(
+        &self,
+        event_id: Uuid,
+        event_type: &str,
+        task_id: Option<Uuid>,
+        payload: &str,
+        created_at: DateTime<Utc>,
+    ) -> Result<(), DbError> {
+        sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO events (event_id, event_type, task_id, payload, created_at)
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(event_id.to_string())
+        .bind(event_type)
+        .bind(task_id.map(|id| id.to_string()))
+        .bind(payload)
+        .bind(created_at.timestamp())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Get one page of events matching `filter`, oldest first unless sorted
+    /// otherwise
+    pub async fn find_page(
+        &self,
+        filter: &EventFilter,
+        page: &PageRequest,
+    ) -> Result<Page<StoredEvent>, DbError> {
+        let mut query = QueryBuilder::new(
+            "SELECT id, event_id, event_type, task_id, payload, created_at FROM events",
+        );
+        filter.push_where(&mut query);
+        query.push(" AND event_id IS NOT NULL ");
+        query.push(page.sql_suffix(&["created_at", "event_type"], "id ASC"));
+        let items = query
+            .build_query_as::<StoredEvent>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut count = QueryBuilder::new("SELECT COUNT(*) FROM events");
+        filter.push_where(&mut count);
+        count.push(" AND event_id IS NOT NULL");
+        let total: i64 = count.build_query_scalar().fetch_one(&self.pool).await?;
+
+        Ok(Page {
+            items,
+            total: total as u64,
+        })
+    }
+
+    /// Events stored after the envelope with `event_id`, in the order they
+    /// were published. Returns `None` if that envelope is not in the history.
+    pub async fn find_after(&self, event_id: Uuid) -> Result<Option<Vec<StoredEvent>>, DbError> {
+        let id: Option<i64> = sqlx::query_scalar("SELECT id FROM events WHERE event_id = ?")
+            .bind(event_id.to_string())
+            .fetch_optional(&self.pool)
+            .await?;
+        let Some(id) = id else {
+            return Ok(None);
+        };
+
+        let events = sqlx::query_as::<_, StoredEvent>(
+            r#"
+            SELECT id, event_id, event_type, task_id, payload, created_at
+            FROM events
+            WHERE id > ? AND event_id IS NOT NULL
+            ORDER BY id ASC
+            "#,
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(Some(events))
+    }
+
+    /// Up to `limit` events stored after position `seq`, in publish order,
+    /// and the position to continue from if more events follow them
+    pub async fn find_since_seq(
+        &self,
+        seq: i64,
+        limit: u32,
+    ) -> Result<(Vec<StoredEvent>, Option<i64>), DbError> {
+        let mut events = sqlx::query_as::<_, StoredEvent>(
+            r#"
+            SELECT id, event_id, event_type, task_id, payload, created_at
+            FROM events
+            WHERE id > ? AND event_id IS NOT NULL
+            ORDER BY id ASC
+            LIMIT ?
+            "#,
+        )
+        .bind(seq)
+        .bind(i64::from(limit) + 1)
+        .fetch_all(&self.pool)
+        .await?;
+
+        if events.len() <= limit as usize {
+            return Ok((events, None));
+        }
+        events.truncate(limit as usize);
+        let next_seq = events.last().map_or(seq, |e| e.id);
+        Ok((events, Some(next_seq)))
+    }
+
+    /// Position of the newest stored event, 0 for an empty history
+    pub async fn latest_seq(&self) -> Result<i64, DbError> {
+        let seq: Option<i64> =
+            sqlx::query_scalar("SELECT MAX(id) FROM events WHERE event_id IS NOT NULL")
+                .fetch_one(&self.pool)
+                .await?;
+
+        Ok(seq.unwrap_or(0))
+    }
+
+    /// Delete events older than `cutoff` and return how many were deleted
+    pub async fn delete_before(&self, cutoff: DateTime<Utc>) -> Result<u64, DbError> {
+        let result = sqlx::query("DELETE FROM events WHERE created_at < ?")
+            .bind(cutoff.timestamp())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_pool, run_migrations};
+    use chrono::Duration;
+
+    async fn setup_test_db() -> SqlitePool {
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+        pool
+    }
+
+    async fn create(
+        repo: &EventRepository,
+        event_type: &str,
+        task_id: Option<Uuid>,
+        created_at: DateTime<Utc>,
+    ) -> Uuid {
+        let event_id = Uuid::new_v4();
+        repo.create(event_id, event_type, task_id, "{}", created_at)
+            .await
+            .unwrap();
+        event_id
+    }
+
+    #[tokio::test]
+    async fn test_find_page_filters() {
+        let repo = EventRepository::new(setup_test_db().await);
+        let task_id = Uuid::new_v4();
+        let now = Utc::now();
+
+        create(
+            &repo,
+            "task.created",
+            Some(task_id),
+            now - Duration::hours(2),
+        )
+        .await;
+        create(&repo, "task.updated", Some(task_id), now).await;
+        create(&repo, "task.updated", Some(Uuid::new_v4()), now).await;
+        create(&repo, "project.opened", None, now).await;
+
+        let all = repo
+            .find_page(&EventFilter::default(), &PageRequest::default())
+            .await
+            .unwrap();
+        assert_eq!(all.total, 4);
+        assert_eq!(all.items[0].event_type, "task.created");
+
+        let filter = EventFilter {
+            since: Some(now - Duration::hours(1)),
+            task_id: Some(task_id),
+            ..Default::default()
+        };
+        let page = repo
+            .find_page(&filter, &PageRequest::default())
+            .await
+            .unwrap();
+        assert_eq!(page.total, 1);
+        assert_eq!(page.items[0].event_type, "task.updated");
+
+        let filter = EventFilter {
+            event_type: Some("task.updated".to_string()),
+            ..Default::default()
+        };
+        let page = repo
+            .find_page(
+                &filter,
+                &PageRequest {
+                    limit: Some(1),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(page.total, 2);
+        assert_eq!(page.items.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_after_seq_and_latest_seq() {
+        let repo = EventRepository::new(setup_test_db().await);
+        assert_eq!(repo.latest_seq().await.unwrap(), 0);
+
+        let now = Utc::now();
+        create(&repo, "task.created", None, now).await;
+        let seq = repo.latest_seq().await.unwrap();
+        create(&repo, "task.updated", None, now).await;
+        create(&repo, "session.started", None, now).await;
+        assert_eq!(repo.latest_seq().await.unwrap(), seq + 2);
+
+        let filter = EventFilter {
+            after_seq: Some(seq),
+            ..Default::default()
+        };
+        let page = repo
+            .find_page(&filter, &PageRequest::default())
+            .await
+            .unwrap();
+        assert_eq!(page.total, 2);
+        assert_eq!(page.items[0].event_type, "task.updated");
+        assert_eq!(page.items[0].id, seq + 1);
+    }
+
+    #[tokio::test]
+    async fn test_find_since_seq_is_capped() {
+        let repo = EventRepository::new(setup_test_db().await);
+        let now = Utc::now();
+        for _ in 0..5 {
+            create(&repo, "task.updated", None, now).await;
+        }
+        let latest = repo.latest_seq().await.unwrap();
+
+        let (first, next_seq) = repo.find_since_seq(0, 3).await.unwrap();
+        assert_eq!(first.len(), 3);
+        assert_eq!(next_seq, Some(first[2].id));
+
+        let (rest, next_seq) = repo.find_since_seq(next_seq.unwrap(), 3).await.unwrap();
+        assert_eq!(rest.len(), 2);
+        assert_eq!(rest[1].id, latest);
+        assert_eq!(next_seq, None);
+
+        let (exact, next_seq) = repo.find_since_seq(0, 5).await.unwrap();
+        assert_eq!(exact.len(), 5);
+        assert_eq!(next_seq, None);
+    }
+
+    #[tokio::test]
+    async fn test_find_after_and_delete_before() {
+        let repo = EventRepository::new(setup_test_db().await);
+        let now = Utc::now();
+        let old = create(&repo, "task.created", None, now - Duration::days(40)).await;
+        let first = create(&repo, "task.updated", None, now).await;
+        let second = create(&repo, "task.updated", None, now).await;
+        repo.create(second, "task.updated", None, "{}", now)
+            .await
+            .unwrap();
+
+        let after = repo.find_after(first).await.unwrap().unwrap();
+        assert_eq!(after.len(), 1);
+        assert_eq!(after[0].event_id, second.to_string());
+        assert!(repo.find_after(Uuid::new_v4()).await.unwrap().is_none());
+
+        let deleted = repo.delete_before(now - Duration::days(30)).await.unwrap();
+        assert_eq!(deleted, 1);
+        assert!(repo.find_after(old).await.unwrap().is_none());
+    }
+}
@@ -1,11 +1,29 @@
+mod api_token_repository;
 mod diff_viewed_repository;
+mod event_repository;
 mod review_comment_repository;
 mod session_activity_repository;
+mod session_artifact_repository;
 mod session_repository;
+mod session_share_repository;
+mod task_merge_repository;
 mod task_repository;
+mod task_template_repository;
+mod task_wiki_update_repository;
+mod usage_repository;
+mod user_preferences_repository;
 
+pub use api_token_repository::*;
 pub use diff_viewed_repository::*;
+pub use event_repository::*;
 pub use review_comment_repository::*;
 pub use session_activity_repository::*;
+pub use session_artifact_repository::*;
 pub use session_repository::*;
+pub use session_share_repository::*;
+pub use task_merge_repository::*;
 pub use task_repository::*;
+pub use task_template_repository::*;
+pub use task_wiki_update_repository::*;
+pub use usage_repository::*;
+pub use user_preferences_repository::*;
@@ -0,0 +1,200 @@
+use crate::error::DbError;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct SessionArtifact {
+    pub id: String,
+    pub session_id: String,
+    pub task_id: String,
+    /// What the artifact is, e.g. `plan` or `findings`
+    pub kind: String,
+    /// File name offered for download
+    pub name: String,
+    /// SHA-256 of the content, naming the stored file
+    pub content_hash: String,
+    pub size_bytes: i64,
+    pub media_type: String,
+    pub created_at: i64,
+}
+
+/// An artifact to record
+#[derive(Debug, Clone)]
+pub struct CreateSessionArtifact<'a> {
+    pub session_id: &'a str,
+    pub task_id: &'a str,
+    pub kind: &'a str,
+    pub name: &'a str,
+    pub content_hash: &'a str,
+    pub size_bytes: i64,
+    pub media_type: &'a str,
+}
+
+#[derive(Clone)]
+pub struct SessionArtifactRepository {
+    pool: SqlitePool,
+}
+
+impl SessionArtifactRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Get the artifacts of a session, oldest first
+    pub async fn find_by_session_id(
+        &self,
+        session_id: &str,
+    ) -> Result<Vec<SessionArtifact>, DbError> {
+        let artifacts = sqlx::query_as::<_, SessionArtifact>(
+            r#"
+            SELECT id, session_id, task_id, kind, name, content_hash, size_bytes, media_type, created_at
+            FROM session_artifacts
+            WHERE session_id = ?
+            ORDER BY created_at, name
+            "#,
+        )
+        .bind(session_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(artifacts)
+    }
+
+    pub async fn find_by_id(&self, id: &str) -> Result<Option<SessionArtifact>, DbError> {
+        let artifact = sqlx::query_as::<_, SessionArtifact>(
+            r#"
+            SELECT id, session_id, task_id, kind, name, content_hash, size_bytes, media_type, created_at
+            FROM session_artifacts
+            WHERE id = ?
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(artifact)
+    }
+
+    /// Record an artifact of a session. A session registering the same name
+    /// again replaces the earlier record.
+    pub async fn create(
+        &self,
+        artifact: &CreateSessionArtifact<'_>,
+    ) -> Result<SessionArtifact, DbError> {
+        let id = Uuid::new_v4().to_string();
+        let now = Utc::now().timestamp();
+
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("DELETE FROM session_artifacts WHERE session_id = ? AND name = ?")
+            .bind(artifact.session_id)
+            .bind(artifact.name)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query(
+            r#"
+            INSERT INTO session_artifacts (id, session_id, task_id, kind, name, content_hash, size_bytes, media_type, created_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&id)
+        .bind(artifact.session_id)
+        .bind(artifact.task_id)
+        .bind(artifact.kind)
+        .bind(artifact.name)
+        .bind(artifact.content_hash)
+        .bind(artifact.size_bytes)
+        .bind(artifact.media_type)
+        .bind(now)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        Ok(SessionArtifact {
+            id,
+            session_id: artifact.session_id.to_string(),
+            task_id: artifact.task_id.to_string(),
+            kind: artifact.kind.to_string(),
+            name: artifact.name.to_string(),
+            content_hash: artifact.content_hash.to_string(),
+            size_bytes: artifact.size_bytes,
+            media_type: artifact.media_type.to_string(),
+            created_at: now,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_pool, run_migrations};
+
+    async fn setup_test_db() -> SqlitePool {
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+        pool
+    }
+
+    /// Create a task and one of its sessions for foreign key constraints
+    async fn create_test_session(pool: &SqlitePool, task_id: &str, session_id: &str) {
+        let now = Utc::now().timestamp();
+        sqlx::query(
+            r#"
+            INSERT INTO tasks (id, title, description, status, created_at, updated_at)
+            VALUES (?, 'Test Task', 'Test description', 'planning', ?, ?)
+            "#,
+        )
+        .bind(task_id)
+        .bind(now)
+        .bind(now)
+        .execute(pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            r#"
+            INSERT INTO sessions (id, task_id, phase, status, created_at)
+            VALUES (?, ?, 'planning', 'completed', ?)
+            "#,
+        )
+        .bind(session_id)
+        .bind(task_id)
+        .bind(now)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_create_and_find_artifacts() {
+        let pool = setup_test_db().await;
+        let repo = SessionArtifactRepository::new(pool.clone());
+        create_test_session(&pool, "task-artifacts", "session-artifacts").await;
+
+        let plan = |hash: &'static str| CreateSessionArtifact {
+            session_id: "session-artifacts",
+            task_id: "task-artifacts",
+            kind: "plan",
+            name: "plan.md",
+            content_hash: hash,
+            size_bytes: 42,
+            media_type: "text/markdown",
+        };
+        let first = repo.create(&plan("aaa")).await.unwrap();
+        assert_eq!(
+            repo.find_by_id(&first.id)
+                .await
+                .unwrap()
+                .unwrap()
+                .content_hash,
+            "aaa"
+        );
+
+        // Registering the same name again replaces the record
+        repo.create(&plan("bbb")).await.unwrap();
+        let artifacts = repo.find_by_session_id("session-artifacts").await.unwrap();
+        assert_eq!(artifacts.len(), 1);
+        assert_eq!(artifacts[0].content_hash, "bbb");
+        assert!(repo.find_by_id(&first.id).await.unwrap().is_none());
+        assert!(repo.find_by_session_id("other").await.unwrap().is_empty());
+    }
+}
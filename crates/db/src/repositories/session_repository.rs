@@ -1,5 +1,6 @@
 use crate::error::DbError;
 use crate::models::SessionRow;
+use crate::pagination::{Page, PageRequest};
 use opencode_core::{Session, SessionStatus};
 use sqlx::SqlitePool;
 use uuid::Uuid;
@@ -19,8 +20,8 @@ impl SessionRepository {
 
         sqlx::query(
             r#"
-            INSERT INTO sessions (id, task_id, opencode_session_id, phase, status, started_at, completed_at, created_at, implementation_phase_number, implementation_phase_title)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            INSERT INTO sessions (id, task_id, opencode_session_id, phase, status, started_at, completed_at, created_at, implementation_phase_number, implementation_phase_title, retry_of)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(&row.id)
@@ -33,6 +34,7 @@ impl SessionRepository {
         .bind(row.created_at)
         .bind(row.implementation_phase_number)
         .bind(&row.implementation_phase_title)
+        .bind(&row.retry_of)
         .execute(&self.pool)
         .await?;
 
@@ -42,7 +44,7 @@ impl SessionRepository {
     pub async fn find_by_id(&self, id: Uuid) -> Result<Option<Session>, DbError> {
         let row: Option<SessionRow> = sqlx::query_as(
             r#"
-            SELECT id, task_id, opencode_session_id, phase, status, started_at, completed_at, created_at, implementation_phase_number, implementation_phase_title
+            SELECT id, task_id, opencode_session_id, phase, status, started_at, completed_at, created_at, implementation_phase_number, implementation_phase_title, retry_of
             FROM sessions
             WHERE id = ?
             "#,
@@ -57,7 +59,7 @@ impl SessionRepository {
     pub async fn find_by_task_id(&self, task_id: Uuid) -> Result<Vec<Session>, DbError> {
         let rows: Vec<SessionRow> = sqlx::query_as(
             r#"
-            SELECT id, task_id, opencode_session_id, phase, status, started_at, completed_at, created_at, implementation_phase_number, implementation_phase_title
+            SELECT id, task_id, opencode_session_id, phase, status, started_at, completed_at, created_at, implementation_phase_number, implementation_phase_title, retry_of
             FROM sessions
             WHERE task_id = ?
             ORDER BY created_at DESC
@@ -76,7 +78,7 @@ impl SessionRepository {
     ) -> Result<Option<Session>, DbError> {
         let row: Option<SessionRow> = sqlx::query_as(
             r#"
-            SELECT id, task_id, opencode_session_id, phase, status, started_at, completed_at, created_at, implementation_phase_number, implementation_phase_title
+            SELECT id, task_id, opencode_session_id, phase, status, started_at, completed_at, created_at, implementation_phase_number, implementation_phase_title, retry_of
             FROM sessions
             WHERE opencode_session_id = ?
             "#,
@@ -91,7 +93,7 @@ impl SessionRepository {
     pub async fn find_all(&self) -> Result<Vec<Session>, DbError> {
         let rows: Vec<SessionRow> = sqlx::query_as(
             r#"
-            SELECT id, task_id, opencode_session_id, phase, status, started_at, completed_at, created_at, implementation_phase_number, implementation_phase_title
+            SELECT id, task_id, opencode_session_id, phase, status, started_at, completed_at, created_at, implementation_phase_number, implementation_phase_title, retry_of
             FROM sessions
             ORDER BY created_at DESC
             "#,
@@ -102,10 +104,36 @@ impl SessionRepository {
         Ok(rows.into_iter().map(|r| r.into_domain()).collect())
     }
 
+    /// Get one page of sessions, newest first unless sorted otherwise
+    pub async fn find_page(&self, page: &PageRequest) -> Result<Page<Session>, DbError> {
+        let rows: Vec<SessionRow> = sqlx::query_as(&format!(
+            r#"
+            SELECT id, task_id, opencode_session_id, phase, status, started_at, completed_at, created_at, implementation_phase_number, implementation_phase_title, retry_of
+            FROM sessions
+            {}
+            "#,
+            page.sql_suffix(
+                &["phase", "status", "started_at", "completed_at", "created_at"],
+                "created_at DESC",
+            )
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+
+        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM sessions")
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(Page {
+            items: rows.into_iter().map(|r| r.into_domain()).collect(),
+            total: total as u64,
+        })
+    }
+
     pub async fn find_active(&self) -> Result<Vec<Session>, DbError> {
         let rows: Vec<SessionRow> = sqlx::query_as(
             r#"
-            SELECT id, task_id, opencode_session_id, phase, status, started_at, completed_at, created_at, implementation_phase_number, implementation_phase_title
+            SELECT id, task_id, opencode_session_id, phase, status, started_at, completed_at, created_at, implementation_phase_number, implementation_phase_title, retry_of
             FROM sessions
             WHERE status IN ('pending', 'running')
             ORDER BY created_at DESC
@@ -152,6 +180,54 @@ impl SessionRepository {
         Ok(result.rows_affected() > 0)
     }
 
+    /// Record that `id` retries the failed session `retry_of`
+    pub async fn set_retry_of(&self, id: Uuid, retry_of: Uuid) -> Result<bool, DbError> {
+        let result = sqlx::query("UPDATE sessions SET retry_of = ? WHERE id = ?")
+            .bind(retry_of.to_string())
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Sessions retrying the session `id`
+    pub async fn find_retries(&self, id: Uuid) -> Result<Vec<Session>, DbError> {
+        let rows: Vec<SessionRow> = sqlx::query_as(
+            r#"
+            SELECT id, task_id, opencode_session_id, phase, status, started_at, completed_at, created_at, implementation_phase_number, implementation_phase_title, retry_of
+            FROM sessions
+            WHERE retry_of = ?
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(id.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.into_domain()).collect())
+    }
+
+    /// Number of retries that led to the session `id`, following `retry_of`
+    /// back to the original session
+    pub async fn count_retries(&self, id: Uuid) -> Result<u32, DbError> {
+        let count: i64 = sqlx::query_scalar(
+            r#"
+            WITH RECURSIVE attempts(id, retry_of) AS (
+                SELECT id, retry_of FROM sessions WHERE id = ?
+                UNION ALL
+                SELECT s.id, s.retry_of FROM sessions s JOIN attempts a ON s.id = a.retry_of
+            )
+            SELECT COUNT(*) FROM attempts
+            "#,
+        )
+        .bind(id.to_string())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count.saturating_sub(1) as u32)
+    }
+
     pub async fn delete(&self, id: Uuid) -> Result<bool, DbError> {
         let result = sqlx::query("DELETE FROM sessions WHERE id = ?")
             .bind(id.to_string())
@@ -267,4 +343,37 @@ mod tests {
         let found = repo.find_by_id(session.id).await.unwrap();
         assert!(found.is_none());
     }
+
+    #[tokio::test]
+    async fn test_retry_lineage() {
+        let pool = setup_test_db().await;
+        let task = create_test_task(&pool).await;
+        let repo = SessionRepository::new(pool);
+
+        let mut original = Session::new(task.id, SessionPhase::Implementation);
+        original.fail();
+        repo.create(&original).await.unwrap();
+        assert_eq!(repo.count_retries(original.id).await.unwrap(), 0);
+
+        let first = Session::new(task.id, SessionPhase::Implementation);
+        repo.create(&first).await.unwrap();
+        assert!(repo.set_retry_of(first.id, original.id).await.unwrap());
+
+        let second = Session::new(task.id, SessionPhase::Implementation);
+        repo.create(&second).await.unwrap();
+        repo.set_retry_of(second.id, first.id).await.unwrap();
+
+        // Updating a session doesn't drop its lineage
+        let mut second = repo.find_by_id(second.id).await.unwrap().unwrap();
+        assert_eq!(second.retry_of, Some(first.id));
+        second.fail();
+        repo.update(&second).await.unwrap();
+
+        assert_eq!(repo.count_retries(second.id).await.unwrap(), 2);
+        let retries = repo.find_retries(original.id).await.unwrap();
+        assert_eq!(retries.len(), 1);
+        assert_eq!(retries[0].id, first.id);
+        let found = repo.find_by_id(second.id).await.unwrap().unwrap();
+        assert_eq!(found.retry_of, Some(first.id));
+    }
 }
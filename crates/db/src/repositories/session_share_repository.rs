@@ -0,0 +1,194 @@
+use crate::error::DbError;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct SessionShare {
+    pub id: String,
+    pub session_id: String,
+    /// Hex-encoded SHA-256 of the link's token; the token itself is never
+    /// stored
+    pub token_hash: String,
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub revoked_at: Option<i64>,
+}
+
+impl SessionShare {
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+
+    /// Whether the link still opens the session at `now`
+    pub fn is_active(&self, now: i64) -> bool {
+        !self.is_revoked() && now < self.expires_at
+    }
+}
+
+#[derive(Clone)]
+pub struct SessionShareRepository {
+    pool: SqlitePool,
+}
+
+impl SessionShareRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Get the links of a session, including revoked and expired ones,
+    /// newest first
+    pub async fn find_by_session_id(&self, session_id: &str) -> Result<Vec<SessionShare>, DbError> {
+        let shares = sqlx::query_as::<_, SessionShare>(
+            r#"
+            SELECT id, session_id, token_hash, created_at, expires_at, revoked_at
+            FROM session_shares
+            WHERE session_id = ?
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(session_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(shares)
+    }
+
+    /// Find the link with the given token hash
+    pub async fn find_by_hash(&self, token_hash: &str) -> Result<Option<SessionShare>, DbError> {
+        let share = sqlx::query_as::<_, SessionShare>(
+            r#"
+            SELECT id, session_id, token_hash, created_at, expires_at, revoked_at
+            FROM session_shares
+            WHERE token_hash = ?
+            "#,
+        )
+        .bind(token_hash)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(share)
+    }
+
+    /// Store a new link to a session, open until `expires_at`
+    pub async fn create(
+        &self,
+        session_id: &str,
+        token_hash: &str,
+        expires_at: i64,
+    ) -> Result<SessionShare, DbError> {
+        let share = SessionShare {
+            id: Uuid::new_v4().to_string(),
+            session_id: session_id.to_string(),
+            token_hash: token_hash.to_string(),
+            created_at: Utc::now().timestamp(),
+            expires_at,
+            revoked_at: None,
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO session_shares (id, session_id, token_hash, created_at, expires_at)
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&share.id)
+        .bind(&share.session_id)
+        .bind(&share.token_hash)
+        .bind(share.created_at)
+        .bind(share.expires_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(share)
+    }
+
+    /// Revoke a link of a session. Returns false if the session has no such
+    /// unrevoked link.
+    pub async fn revoke(&self, session_id: &str, id: &str) -> Result<bool, DbError> {
+        let result = sqlx::query(
+            "UPDATE session_shares SET revoked_at = ? WHERE id = ? AND session_id = ? AND revoked_at IS NULL",
+        )
+        .bind(Utc::now().timestamp())
+        .bind(id)
+        .bind(session_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_pool, run_migrations};
+
+    async fn setup_test_db() -> SqlitePool {
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+        pool
+    }
+
+    /// Create a task and one of its sessions for foreign key constraints
+    async fn create_test_session(pool: &SqlitePool, task_id: &str, session_id: &str) {
+        let now = Utc::now().timestamp();
+        sqlx::query(
+            r#"
+            INSERT INTO tasks (id, title, description, status, created_at, updated_at)
+            VALUES (?, 'Test Task', 'Test description', 'planning', ?, ?)
+            "#,
+        )
+        .bind(task_id)
+        .bind(now)
+        .bind(now)
+        .execute(pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            r#"
+            INSERT INTO sessions (id, task_id, phase, status, created_at)
+            VALUES (?, ?, 'planning', 'completed', ?)
+            "#,
+        )
+        .bind(session_id)
+        .bind(task_id)
+        .bind(now)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_create_find_and_revoke() {
+        let pool = setup_test_db().await;
+        let repo = SessionShareRepository::new(pool.clone());
+        create_test_session(&pool, "task-shares", "session-shares").await;
+
+        let now = Utc::now().timestamp();
+        let share = repo
+            .create("session-shares", "abc123", now + 3600)
+            .await
+            .unwrap();
+        let found = repo.find_by_hash("abc123").await.unwrap().unwrap();
+        assert_eq!(found.session_id, "session-shares");
+        assert!(found.is_active(now));
+        assert!(!found.is_active(now + 3600));
+
+        assert!(!repo.revoke("other-session", &share.id).await.unwrap());
+        assert!(repo.revoke("session-shares", &share.id).await.unwrap());
+        assert!(!repo.revoke("session-shares", &share.id).await.unwrap());
+        let found = repo.find_by_hash("abc123").await.unwrap().unwrap();
+        assert!(found.is_revoked());
+        assert!(!found.is_active(now));
+
+        assert!(repo.find_by_hash("other").await.unwrap().is_none());
+        assert_eq!(
+            repo.find_by_session_id("session-shares")
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use crate::error::DbError;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct TaskMerge {
+    pub id: String,
+    pub task_id: String,
+    /// Branch the task was merged into
+    pub branch: String,
+    /// Diff of the task's workspace at merge time
+    pub diff: String,
+    pub merged_at: i64,
+}
+
+#[derive(Clone)]
+pub struct TaskMergeRepository {
+    pool: SqlitePool,
+}
+
+impl TaskMergeRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Get the merges at or after `since` (unix seconds), oldest first
+    pub async fn find_since(&self, since: i64) -> Result<Vec<TaskMerge>, DbError> {
+        let merges = sqlx::query_as::<_, TaskMerge>(
+            r#"
+            SELECT id, task_id, branch, diff, merged_at
+            FROM task_merges
+            WHERE merged_at >= ?
+            ORDER BY merged_at, id
+            "#,
+        )
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(merges)
+    }
+
+    /// Get all merges of a task, oldest first
+    pub async fn find_by_task_id(&self, task_id: &str) -> Result<Vec<TaskMerge>, DbError> {
+        let merges = sqlx::query_as::<_, TaskMerge>(
+            r#"
+            SELECT id, task_id, branch, diff, merged_at
+            FROM task_merges
+            WHERE task_id = ?
+            ORDER BY merged_at, id
+            "#,
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(merges)
+    }
+
+    /// Record the merge of a task
+    pub async fn create(
+        &self,
+        task_id: &str,
+        branch: &str,
+        diff: &str,
+    ) -> Result<TaskMerge, DbError> {
+        let id = Uuid::new_v4().to_string();
+        let now = Utc::now().timestamp();
+
+        sqlx::query(
+            r#"
+            INSERT INTO task_merges (id, task_id, branch, diff, merged_at)
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&id)
+        .bind(task_id)
+        .bind(branch)
+        .bind(diff)
+        .bind(now)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(TaskMerge {
+            id,
+            task_id: task_id.to_string(),
+            branch: branch.to_string(),
+            diff: diff.to_string(),
+            merged_at: now,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_pool, run_migrations};
+
+    async fn setup_test_db() -> SqlitePool {
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+        pool
+    }
+
+    /// Create a task for foreign key constraints
+    async fn create_test_task(pool: &SqlitePool, task_id: &str) {
+        let now = Utc::now().timestamp();
+        sqlx::query(
+            r#"
+            INSERT INTO tasks (id, title, description, status, created_at, updated_at)
+            VALUES (?, 'Test Task', 'Test description', 'done', ?, ?)
+            "#,
+        )
+        .bind(task_id)
+        .bind(now)
+        .bind(now)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_create_and_find_merges() {
+        let pool = setup_test_db().await;
+        let repo = TaskMergeRepository::new(pool.clone());
+        create_test_task(&pool, "task-merged").await;
+
+        let merge = repo
+            .create("task-merged", "main", "diff --git a/x b/x")
+            .await
+            .unwrap();
+        assert_eq!(merge.branch, "main");
+
+        let merges = repo.find_by_task_id("task-merged").await.unwrap();
+        assert_eq!(merges.len(), 1);
+        assert_eq!(merges[0].diff, "diff --git a/x b/x");
+
+        assert_eq!(repo.find_since(merge.merged_at).await.unwrap().len(), 1);
+        assert!(repo
+            .find_since(merge.merged_at + 1)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
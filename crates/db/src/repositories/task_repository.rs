@@ -1,10 +1,17 @@
 use crate::error::DbError;
 use crate::models::TaskRow;
+use crate::pagination::{Page, PageRequest};
 use chrono::Utc;
 use opencode_core::{Task, UpdateTaskRequest};
 use sqlx::SqlitePool;
 use uuid::Uuid;
 
+/// Columns of a [`TaskRow`], with the task's dependencies gathered from
+/// `task_dependencies`
+const TASK_COLUMNS: &str = "id, title, description, status, roadmap_item_id, workspace_path, \
+     (SELECT GROUP_CONCAT(depends_on_id) FROM task_dependencies d WHERE d.task_id = tasks.id) AS depends_on, \
+     pr_number, created_at, updated_at";
+
 #[derive(Clone)]
 pub struct TaskRepository {
     pool: SqlitePool,
@@ -20,8 +27,8 @@ impl TaskRepository {
 
         sqlx::query(
             r#"
-            INSERT INTO tasks (id, title, description, status, roadmap_item_id, workspace_path, created_at, updated_at)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            INSERT INTO tasks (id, title, description, status, roadmap_item_id, workspace_path, pr_number, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(&row.id)
@@ -30,22 +37,28 @@ impl TaskRepository {
         .bind(&row.status)
         .bind(&row.roadmap_item_id)
         .bind(&row.workspace_path)
+        .bind(row.pr_number)
         .bind(row.created_at)
         .bind(row.updated_at)
         .execute(&self.pool)
         .await?;
 
+        for depends_on in &task.depends_on {
+            self.add_dependency(task.id, *depends_on).await?;
+        }
+
         Ok(task.clone())
     }
 
     pub async fn find_by_id(&self, id: Uuid) -> Result<Option<Task>, DbError> {
-        let row: Option<TaskRow> = sqlx::query_as(
+        let row: Option<TaskRow> = sqlx::query_as(&format!(
             r#"
-            SELECT id, title, description, status, roadmap_item_id, workspace_path, created_at, updated_at
+            SELECT {}
             FROM tasks
             WHERE id = ?
             "#,
-        )
+            TASK_COLUMNS
+        ))
         .bind(id.to_string())
         .fetch_optional(&self.pool)
         .await?;
@@ -54,19 +67,47 @@ impl TaskRepository {
     }
 
     pub async fn find_all(&self) -> Result<Vec<Task>, DbError> {
-        let rows: Vec<TaskRow> = sqlx::query_as(
+        let rows: Vec<TaskRow> = sqlx::query_as(&format!(
             r#"
-            SELECT id, title, description, status, roadmap_item_id, workspace_path, created_at, updated_at
+            SELECT {}
             FROM tasks
             ORDER BY created_at DESC
             "#,
-        )
+            TASK_COLUMNS
+        ))
         .fetch_all(&self.pool)
         .await?;
 
         Ok(rows.into_iter().map(|r| r.into_domain()).collect())
     }
 
+    /// Get one page of tasks, newest first unless sorted otherwise
+    pub async fn find_page(&self, page: &PageRequest) -> Result<Page<Task>, DbError> {
+        let rows: Vec<TaskRow> = sqlx::query_as(&format!(
+            r#"
+            SELECT {}
+            FROM tasks
+            {}
+            "#,
+            TASK_COLUMNS,
+            page.sql_suffix(
+                &["title", "status", "created_at", "updated_at"],
+                "created_at DESC",
+            )
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+
+        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM tasks")
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(Page {
+            items: rows.into_iter().map(|r| r.into_domain()).collect(),
+            total: total as u64,
+        })
+    }
+
     pub async fn update(
         &self,
         id: Uuid,
@@ -112,6 +153,76 @@ impl TaskRepository {
         Ok(Some(task))
     }
 
+    /// Record the GitHub pull request opened from the task's workspace;
+    /// returns `false` if there is no task with `id`
+    pub async fn set_pr_number(&self, id: Uuid, pr_number: u64) -> Result<bool, DbError> {
+        let result = sqlx::query("UPDATE tasks SET pr_number = ?, updated_at = ? WHERE id = ?")
+            .bind(i64::try_from(pr_number).unwrap_or(i64::MAX))
+            .bind(Utc::now().timestamp())
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Make `task_id` depend on `depends_on_id`; returns `false` if it
+    /// already did
+    pub async fn add_dependency(
+        &self,
+        task_id: Uuid,
+        depends_on_id: Uuid,
+    ) -> Result<bool, DbError> {
+        let result = sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO task_dependencies (task_id, depends_on_id, created_at)
+            VALUES (?, ?, ?)
+            "#,
+        )
+        .bind(task_id.to_string())
+        .bind(depends_on_id.to_string())
+        .bind(Utc::now().timestamp())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Drop the dependency of `task_id` on `depends_on_id`; returns `false`
+    /// if there was none
+    pub async fn remove_dependency(
+        &self,
+        task_id: Uuid,
+        depends_on_id: Uuid,
+    ) -> Result<bool, DbError> {
+        let result =
+            sqlx::query("DELETE FROM task_dependencies WHERE task_id = ? AND depends_on_id = ?")
+                .bind(task_id.to_string())
+                .bind(depends_on_id.to_string())
+                .execute(&self.pool)
+                .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Tasks that depend on the task with `id`
+    pub async fn find_dependents(&self, id: Uuid) -> Result<Vec<Task>, DbError> {
+        let rows: Vec<TaskRow> = sqlx::query_as(&format!(
+            r#"
+            SELECT {}
+            FROM tasks
+            WHERE id IN (SELECT task_id FROM task_dependencies WHERE depends_on_id = ?)
+            ORDER BY created_at ASC
+            "#,
+            TASK_COLUMNS
+        ))
+        .bind(id.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.into_domain()).collect())
+    }
+
     pub async fn delete(&self, id: Uuid) -> Result<bool, DbError> {
         let result = sqlx::query("DELETE FROM tasks WHERE id = ?")
             .bind(id.to_string())
@@ -161,6 +272,44 @@ mod tests {
         assert_eq!(all.len(), 2);
     }
 
+    #[tokio::test]
+    async fn test_find_page_of_tasks() {
+        let pool = setup_test_db().await;
+        let repo = TaskRepository::new(pool);
+
+        for title in ["Charlie", "Alpha", "Bravo"] {
+            repo.create(&Task::new(title, "Desc")).await.unwrap();
+        }
+
+        let page = repo
+            .find_page(&PageRequest {
+                limit: Some(2),
+                ..PageRequest::default().with_sort("title")
+            })
+            .await
+            .unwrap();
+        assert_eq!(page.total, 3);
+        let titles: Vec<_> = page.items.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["Alpha", "Bravo"]);
+
+        let page = repo
+            .find_page(&PageRequest {
+                offset: 1,
+                ..PageRequest::default().with_sort("-title")
+            })
+            .await
+            .unwrap();
+        let titles: Vec<_> = page.items.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["Bravo", "Alpha"]);
+
+        // Unknown sort fields fall back to the default order
+        let page = repo
+            .find_page(&PageRequest::default().with_sort("description; DROP TABLE tasks"))
+            .await
+            .unwrap();
+        assert_eq!(page.items.len(), 3);
+    }
+
     #[tokio::test]
     async fn test_update_task() {
         let pool = setup_test_db().await;
@@ -182,6 +331,53 @@ mod tests {
         assert_eq!(updated.status, TaskStatus::InProgress);
     }
 
+    #[tokio::test]
+    async fn test_set_pr_number() {
+        let pool = setup_test_db().await;
+        let repo = TaskRepository::new(pool);
+
+        let task = Task::new("Task", "Description");
+        repo.create(&task).await.unwrap();
+
+        assert!(repo.set_pr_number(task.id, 42).await.unwrap());
+        let found = repo.find_by_id(task.id).await.unwrap().unwrap();
+        assert_eq!(found.pr_number, Some(42));
+
+        assert!(!repo.set_pr_number(Uuid::new_v4(), 1).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_task_dependencies() {
+        let pool = setup_test_db().await;
+        let repo = TaskRepository::new(pool);
+
+        let first = Task::new("First", "Desc");
+        let second = Task::new("Second", "Desc");
+        repo.create(&first).await.unwrap();
+        repo.create(&second).await.unwrap();
+        let mut third = Task::new("Third", "Desc");
+        third.depends_on = vec![first.id];
+        repo.create(&third).await.unwrap();
+
+        assert!(repo.add_dependency(third.id, second.id).await.unwrap());
+        assert!(!repo.add_dependency(third.id, second.id).await.unwrap());
+        let found = repo.find_by_id(third.id).await.unwrap().unwrap();
+        assert_eq!(found.depends_on.len(), 2);
+        assert!(found.depends_on.contains(&first.id));
+        assert!(found.depends_on.contains(&second.id));
+
+        let dependents = repo.find_dependents(first.id).await.unwrap();
+        assert_eq!(dependents.len(), 1);
+        assert_eq!(dependents[0].id, third.id);
+
+        assert!(repo.remove_dependency(third.id, first.id).await.unwrap());
+        assert!(!repo.remove_dependency(third.id, first.id).await.unwrap());
+        // Deleting a task drops the dependencies on it
+        repo.delete(second.id).await.unwrap();
+        let found = repo.find_by_id(third.id).await.unwrap().unwrap();
+        assert!(found.depends_on.is_empty());
+    }
+
     #[tokio::test]
     async fn test_delete_task() {
         let pool = setup_test_db().await;
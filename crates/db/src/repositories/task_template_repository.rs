@@ -0,0 +1,203 @@
+use crate::error::DbError;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct TaskTemplate {
+    pub id: String,
+    pub name: String,
+    /// Title of the tasks created from the template
+    pub title: String,
+    pub description: String,
+    pub acceptance_criteria: String,
+    /// JSON object of the model each phase of the created tasks uses
+    pub phase_models: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl TaskTemplate {
+    pub fn new(
+        name: impl Into<String>,
+        title: impl Into<String>,
+        description: impl Into<String>,
+        acceptance_criteria: impl Into<String>,
+        phase_models: impl Into<String>,
+    ) -> Self {
+        let now = Utc::now().timestamp();
+        Self {
+            id: Uuid::new_v4().to_string(),
+            name: name.into(),
+            title: title.into(),
+            description: description.into(),
+            acceptance_criteria: acceptance_criteria.into(),
+            phase_models: phase_models.into(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct TaskTemplateRepository {
+    pool: SqlitePool,
+}
+
+impl TaskTemplateRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Get all templates, by name
+    pub async fn find_all(&self) -> Result<Vec<TaskTemplate>, DbError> {
+        let templates = sqlx::query_as::<_, TaskTemplate>(
+            r#"
+            SELECT id, name, title, description, acceptance_criteria, phase_models,
+                   created_at, updated_at
+            FROM task_templates
+            ORDER BY name COLLATE NOCASE, created_at
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(templates)
+    }
+
+    pub async fn find_by_id(&self, id: &str) -> Result<Option<TaskTemplate>, DbError> {
+        let template = sqlx::query_as::<_, TaskTemplate>(
+            r#"
+            SELECT id, name, title, description, acceptance_criteria, phase_models,
+                   created_at, updated_at
+            FROM task_templates
+            WHERE id = ?
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(template)
+    }
+
+    pub async fn create(&self, template: &TaskTemplate) -> Result<TaskTemplate, DbError> {
+        sqlx::query(
+            r#"
+            INSERT INTO task_templates
+            (id, name, title, description, acceptance_criteria, phase_models,
+             created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&template.id)
+        .bind(&template.name)
+        .bind(&template.title)
+        .bind(&template.description)
+        .bind(&template.acceptance_criteria)
+        .bind(&template.phase_models)
+        .bind(template.created_at)
+        .bind(template.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(template.clone())
+    }
+
+    /// Save the template's fields. Returns `None` if there is no such
+    /// template.
+    pub async fn update(&self, template: &TaskTemplate) -> Result<Option<TaskTemplate>, DbError> {
+        let updated_at = Utc::now().timestamp();
+        let result = sqlx::query(
+            r#"
+            UPDATE task_templates
+            SET name = ?, title = ?, description = ?, acceptance_criteria = ?,
+                phase_models = ?, updated_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(&template.name)
+        .bind(&template.title)
+        .bind(&template.description)
+        .bind(&template.acceptance_criteria)
+        .bind(&template.phase_models)
+        .bind(updated_at)
+        .bind(&template.id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+        Ok(Some(TaskTemplate {
+            updated_at,
+            ..template.clone()
+        }))
+    }
+
+    /// Delete a template. Returns false if there is no such template.
+    pub async fn delete(&self, id: &str) -> Result<bool, DbError> {
+        let result = sqlx::query("DELETE FROM task_templates WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_pool, run_migrations};
+
+    async fn setup_test_db() -> SqlitePool {
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+        pool
+    }
+
+    #[tokio::test]
+    async fn test_template_crud() {
+        let pool = setup_test_db().await;
+        let repo = TaskTemplateRepository::new(pool);
+
+        let template = repo
+            .create(&TaskTemplate::new(
+                "Endpoint",
+                "Add {{method}} {{path}}",
+                "Add the endpoint with tests",
+                "",
+                "{}",
+            ))
+            .await
+            .unwrap();
+        repo.create(&TaskTemplate::new("bug fix", "Fix {{bug}}", "", "", "{}"))
+            .await
+            .unwrap();
+
+        let names: Vec<String> = repo
+            .find_all()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+        assert_eq!(names, vec!["bug fix", "Endpoint"]);
+
+        let mut changed = template.clone();
+        changed.acceptance_criteria = "Returns 404 for unknown ids".to_string();
+        repo.update(&changed).await.unwrap().unwrap();
+        let found = repo.find_by_id(&template.id).await.unwrap().unwrap();
+        assert_eq!(found.acceptance_criteria, "Returns 404 for unknown ids");
+
+        assert!(repo.delete(&template.id).await.unwrap());
+        assert!(!repo.delete(&template.id).await.unwrap());
+        assert!(repo.find_by_id(&template.id).await.unwrap().is_none());
+        assert!(repo
+            .update(&TaskTemplate::new("gone", "", "", "", "{}"))
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
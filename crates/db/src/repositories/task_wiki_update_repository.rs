@@ -0,0 +1,162 @@
+use crate::error::DbError;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct TaskWikiUpdate {
+    pub id: String,
+    pub task_id: String,
+    pub branch: String,
+    pub page_slug: String,
+    pub page_title: String,
+    /// `suggested` or `regenerated`
+    pub action: String,
+    /// JSON array of the changed files that made the page stale
+    pub changed_files: String,
+    /// Edit suggestions, or the rewritten page content
+    pub content: String,
+    pub created_at: i64,
+}
+
+impl TaskWikiUpdate {
+    /// Parsed list of changed files
+    pub fn changed_files(&self) -> Vec<String> {
+        serde_json::from_str(&self.changed_files).unwrap_or_default()
+    }
+}
+
+#[derive(Clone)]
+pub struct TaskWikiUpdateRepository {
+    pool: SqlitePool,
+}
+
+impl TaskWikiUpdateRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Get all wiki updates recorded for a task
+    pub async fn find_by_task_id(&self, task_id: &str) -> Result<Vec<TaskWikiUpdate>, DbError> {
+        let updates = sqlx::query_as::<_, TaskWikiUpdate>(
+            r#"
+            SELECT id, task_id, branch, page_slug, page_title, action, changed_files, content, created_at
+            FROM task_wiki_updates
+            WHERE task_id = ?
+            ORDER BY created_at, page_slug
+            "#,
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(updates)
+    }
+
+    /// Record a wiki update for a task
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create(
+        &self,
+        task_id: &str,
+        branch: &str,
+        page_slug: &str,
+        page_title: &str,
+        action: &str,
+        changed_files: &[String],
+        content: &str,
+    ) -> Result<TaskWikiUpdate, DbError> {
+        let id = Uuid::new_v4().to_string();
+        let changed_files_json =
+            serde_json::to_string(changed_files).unwrap_or_else(|_| "[]".to_string());
+        let now = Utc::now().timestamp();
+
+        sqlx::query(
+            r#"
+            INSERT INTO task_wiki_updates (id, task_id, branch, page_slug, page_title, action, changed_files, content, created_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&id)
+        .bind(task_id)
+        .bind(branch)
+        .bind(page_slug)
+        .bind(page_title)
+        .bind(action)
+        .bind(&changed_files_json)
+        .bind(content)
+        .bind(now)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(TaskWikiUpdate {
+            id,
+            task_id: task_id.to_string(),
+            branch: branch.to_string(),
+            page_slug: page_slug.to_string(),
+            page_title: page_title.to_string(),
+            action: action.to_string(),
+            changed_files: changed_files_json,
+            content: content.to_string(),
+            created_at: now,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_pool, run_migrations};
+
+    async fn setup_test_db() -> SqlitePool {
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+        pool
+    }
+
+    /// Create a task in the database for foreign key constraints
+    async fn create_test_task(pool: &SqlitePool, task_id: &str) {
+        let now = Utc::now().timestamp();
+        sqlx::query(
+            r#"
+            INSERT INTO tasks (id, title, description, status, created_at, updated_at)
+            VALUES (?, 'Test Task', 'Test description', 'done', ?, ?)
+            "#,
+        )
+        .bind(task_id)
+        .bind(now)
+        .bind(now)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_create_and_find_updates() {
+        let pool = setup_test_db().await;
+        let repo = TaskWikiUpdateRepository::new(pool.clone());
+
+        let task_id = "test-task-wiki";
+        create_test_task(&pool, task_id).await;
+
+        assert!(repo.find_by_task_id(task_id).await.unwrap().is_empty());
+
+        let changed = vec!["src/auth.rs".to_string(), "src/lib.rs".to_string()];
+        repo.create(
+            task_id,
+            "main",
+            "auth",
+            "Authentication",
+            "suggested",
+            &changed,
+            "- Mention the new remember-me flag",
+        )
+        .await
+        .unwrap();
+
+        let updates = repo.find_by_task_id(task_id).await.unwrap();
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].page_slug, "auth");
+        assert_eq!(updates[0].action, "suggested");
+        assert_eq!(updates[0].changed_files(), changed);
+    }
+}
@@ -0,0 +1,205 @@
+use crate::error::DbError;
+use chrono::{DateTime, Utc};
+use sqlx::{QueryBuilder, Sqlite, SqlitePool};
+use uuid::Uuid;
+
+/// Tokens and estimated cost of one OpenRouter call
+#[derive(Debug, Clone, Default)]
+pub struct CreateUsageRecord {
+    pub task_id: Option<String>,
+    pub session_id: Option<String>,
+    /// Wiki branch the call indexed, generated or answered questions about
+    pub wiki_branch: Option<String>,
+    pub model: String,
+    /// `chat` or `embedding`
+    pub kind: String,
+    pub prompt_tokens: i64,
+    pub completion_tokens: i64,
+    /// `None` when the model's price is unknown
+    pub cost_usd: Option<f64>,
+}
+
+/// Usage summed over calls sharing a task, session, wiki branch, model and
+/// kind
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow)]
+pub struct UsageGroup {
+    pub task_id: Option<String>,
+    pub session_id: Option<String>,
+    pub wiki_branch: Option<String>,
+    pub model: String,
+    pub kind: String,
+    pub requests: i64,
+    pub prompt_tokens: i64,
+    pub completion_tokens: i64,
+    /// `None` when no call in the group had a known price
+    pub cost_usd: Option<f64>,
+}
+
+/// Which usage to sum
+#[derive(Debug, Clone, Default)]
+pub struct UsageFilter {
+    pub task_id: Option<Uuid>,
+    pub wiki_branch: Option<String>,
+    /// Only calls at or after this time
+    pub since: Option<DateTime<Utc>>,
+}
+
+impl UsageFilter {
+    fn push_where(&self, query: &mut QueryBuilder<'_, Sqlite>) {
+        query.push(" WHERE 1 = 1");
+        if let Some(task_id) = self.task_id {
+            query.push(" AND task_id = ").push_bind(task_id.to_string());
+        }
+        if let Some(branch) = &self.wiki_branch {
+            query.push(" AND wiki_branch = ").push_bind(branch.clone());
+        }
+        if let Some(since) = self.since {
+            query
+                .push(" AND created_at >= ")
+                .push_bind(since.timestamp());
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct UsageRepository {
+    pool: SqlitePool,
+}
+
+impl UsageRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Record one call
+    pub async fn create(&self, record: &CreateUsageRecord) -> Result<(), DbError> {
+        sqlx::query(
+            r#"
+            INSERT INTO usage (id, task_id, session_id, wiki_branch, model, kind,
+                               prompt_tokens, completion_tokens, cost_usd, created_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(&record.task_id)
+        .bind(&record.session_id)
+        .bind(&record.wiki_branch)
+        .bind(&record.model)
+        .bind(&record.kind)
+        .bind(record.prompt_tokens)
+        .bind(record.completion_tokens)
+        .bind(record.cost_usd)
+        .bind(Utc::now().timestamp())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Sum the calls matching `filter` per task, session, wiki branch, model
+    /// and kind
+    pub async fn summarize(&self, filter: &UsageFilter) -> Result<Vec<UsageGroup>, DbError> {
+        let mut query = QueryBuilder::new(
+            r#"
+            SELECT task_id, session_id, wiki_branch, model, kind,
+                   COUNT(*) AS requests,
+                   SUM(prompt_tokens) AS prompt_tokens,
+                   SUM(completion_tokens) AS completion_tokens,
+                   SUM(cost_usd) AS cost_usd
+            FROM usage
+            "#,
+        );
+        filter.push_where(&mut query);
+        query.push(
+            " GROUP BY task_id, session_id, wiki_branch, model, kind \
+             ORDER BY task_id, session_id, wiki_branch, model, kind",
+        );
+
+        let groups = query
+            .build_query_as::<UsageGroup>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(groups)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_pool, run_migrations};
+
+    async fn setup_test_db() -> SqlitePool {
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+        pool
+    }
+
+    fn record(
+        task_id: Option<&str>,
+        wiki_branch: Option<&str>,
+        cost: Option<f64>,
+    ) -> CreateUsageRecord {
+        CreateUsageRecord {
+            task_id: task_id.map(String::from),
+            session_id: None,
+            wiki_branch: wiki_branch.map(String::from),
+            model: "openai/text-embedding-3-small".to_string(),
+            kind: "embedding".to_string(),
+            prompt_tokens: 100,
+            completion_tokens: 0,
+            cost_usd: cost,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_and_summarize() {
+        let pool = setup_test_db().await;
+        let repo = UsageRepository::new(pool);
+        let task_id = Uuid::new_v4();
+
+        repo.create(&record(Some(&task_id.to_string()), None, Some(0.5)))
+            .await
+            .unwrap();
+        repo.create(&record(Some(&task_id.to_string()), None, None))
+            .await
+            .unwrap();
+        repo.create(&record(None, Some("main"), None))
+            .await
+            .unwrap();
+
+        let all = repo.summarize(&UsageFilter::default()).await.unwrap();
+        assert_eq!(all.len(), 2);
+
+        let task = repo
+            .summarize(&UsageFilter {
+                task_id: Some(task_id),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(task.len(), 1);
+        assert_eq!(task[0].requests, 2);
+        assert_eq!(task[0].prompt_tokens, 200);
+        assert_eq!(task[0].cost_usd, Some(0.5));
+
+        let wiki = repo
+            .summarize(&UsageFilter {
+                wiki_branch: Some("main".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(wiki.len(), 1);
+        assert_eq!(wiki[0].cost_usd, None);
+
+        let later = repo
+            .summarize(&UsageFilter {
+                since: Some(Utc::now() + chrono::Duration::hours(1)),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert!(later.is_empty());
+    }
+}
@@ -0,0 +1,261 @@
+use crate::error::DbError;
+use chrono::Utc;
+use sqlx::SqlitePool;
+
+/// User that requests act as while the server does not authenticate users
+pub const LOCAL_USER_ID: &str = "local";
+
+/// Notification channel for toasts in the web UI
+pub const IN_APP_CHANNEL: &str = "in_app";
+
+/// How often a user gets an email digest of board activity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DigestSchedule {
+    Off,
+    Daily,
+    Weekly,
+}
+
+impl DigestSchedule {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Daily => "daily",
+            Self::Weekly => "weekly",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "off" => Some(Self::Off),
+            "daily" => Some(Self::Daily),
+            "weekly" => Some(Self::Weekly),
+            _ => None,
+        }
+    }
+
+    /// Time a digest covers, `None` when digests are off
+    pub fn period(&self) -> Option<chrono::Duration> {
+        match self {
+            Self::Off => None,
+            Self::Daily => Some(chrono::Duration::days(1)),
+            Self::Weekly => Some(chrono::Duration::days(7)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct UserPreferences {
+    pub user_id: String,
+    /// JSON object with the board filters the UI restores
+    pub board_filters: String,
+    /// JSON array of channels notifications are delivered to
+    pub notification_channels: String,
+    /// JSON array of event types (e.g. `session.ended`, `task.*`) the UI
+    /// shows notifications for; empty means every event
+    pub event_subscriptions: String,
+    /// Chat model for ad-hoc wiki questions, overriding the project's
+    pub ask_model: Option<String>,
+    /// Address email digests are sent to
+    pub digest_email: Option<String>,
+    /// `off`, `daily` or `weekly`
+    pub digest_schedule: String,
+    /// When the last scheduled digest was sent
+    pub digest_last_sent_at: Option<i64>,
+    pub updated_at: i64,
+}
+
+impl UserPreferences {
+    /// Preferences of a user who has not saved any
+    pub fn defaults(user_id: &str) -> Self {
+        Self {
+            user_id: user_id.to_string(),
+            board_filters: "{}".to_string(),
+            notification_channels: format!("[\"{}\"]", IN_APP_CHANNEL),
+            event_subscriptions: "[]".to_string(),
+            ask_model: None,
+            digest_email: None,
+            digest_schedule: DigestSchedule::Off.as_str().to_string(),
+            digest_last_sent_at: None,
+            updated_at: Utc::now().timestamp(),
+        }
+    }
+
+    /// Parsed board filters
+    pub fn board_filters(&self) -> serde_json::Value {
+        serde_json::from_str(&self.board_filters)
+            .unwrap_or_else(|_| serde_json::Value::Object(Default::default()))
+    }
+
+    /// Parsed notification channels
+    pub fn notification_channels(&self) -> Vec<String> {
+        serde_json::from_str(&self.notification_channels).unwrap_or_default()
+    }
+
+    /// Parsed event subscriptions
+    pub fn event_subscriptions(&self) -> Vec<String> {
+        serde_json::from_str(&self.event_subscriptions).unwrap_or_default()
+    }
+
+    /// Parsed digest schedule; unknown values count as off
+    pub fn digest_schedule(&self) -> DigestSchedule {
+        DigestSchedule::parse(&self.digest_schedule).unwrap_or(DigestSchedule::Off)
+    }
+
+    /// Start of the period the next scheduled digest covers, if one is due
+    /// at `now`: the last digest, or one period back for the first one
+    pub fn digest_due(&self, now: i64) -> Option<i64> {
+        self.digest_email.as_ref()?;
+        let period = self.digest_schedule().period()?.num_seconds();
+        match self.digest_last_sent_at {
+            Some(last) if now - last < period => None,
+            Some(last) => Some(last),
+            None => Some(now - period),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct UserPreferencesRepository {
+    pool: SqlitePool,
+}
+
+impl UserPreferencesRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Get a user's preferences, or the defaults if none were saved
+    pub async fn find_or_default(&self, user_id: &str) -> Result<UserPreferences, DbError> {
+        let preferences = sqlx::query_as::<_, UserPreferences>(
+            r#"
+            SELECT user_id, board_filters, notification_channels, event_subscriptions, ask_model,
+                   digest_email, digest_schedule, digest_last_sent_at, updated_at
+            FROM user_preferences
+            WHERE user_id = ?
+            "#,
+        )
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(preferences.unwrap_or_else(|| UserPreferences::defaults(user_id)))
+    }
+
+    /// Preferences of every user who saved some
+    pub async fn find_all(&self) -> Result<Vec<UserPreferences>, DbError> {
+        let preferences = sqlx::query_as::<_, UserPreferences>(
+            r#"
+            SELECT user_id, board_filters, notification_channels, event_subscriptions, ask_model,
+                   digest_email, digest_schedule, digest_last_sent_at, updated_at
+            FROM user_preferences
+            ORDER BY user_id
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(preferences)
+    }
+
+    /// Record that a scheduled digest was sent to a user at `sent_at`
+    pub async fn mark_digest_sent(&self, user_id: &str, sent_at: i64) -> Result<bool, DbError> {
+        let result =
+            sqlx::query("UPDATE user_preferences SET digest_last_sent_at = ? WHERE user_id = ?")
+                .bind(sent_at)
+                .bind(user_id)
+                .execute(&self.pool)
+                .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Save a user's preferences, replacing earlier ones
+    pub async fn upsert(&self, preferences: &UserPreferences) -> Result<UserPreferences, DbError> {
+        let now = Utc::now().timestamp();
+
+        sqlx::query(
+            r#"
+            INSERT INTO user_preferences (user_id, board_filters, notification_channels, event_subscriptions, ask_model,
+                                          digest_email, digest_schedule, digest_last_sent_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(user_id) DO UPDATE SET
+                board_filters = excluded.board_filters,
+                notification_channels = excluded.notification_channels,
+                event_subscriptions = excluded.event_subscriptions,
+                ask_model = excluded.ask_model,
+                digest_email = excluded.digest_email,
+                digest_schedule = excluded.digest_schedule,
+                digest_last_sent_at = excluded.digest_last_sent_at,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(&preferences.user_id)
+        .bind(&preferences.board_filters)
+        .bind(&preferences.notification_channels)
+        .bind(&preferences.event_subscriptions)
+        .bind(&preferences.ask_model)
+        .bind(&preferences.digest_email)
+        .bind(&preferences.digest_schedule)
+        .bind(preferences.digest_last_sent_at)
+        .bind(now)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(UserPreferences {
+            updated_at: now,
+            ..preferences.clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_pool, run_migrations};
+
+    async fn setup_test_db() -> SqlitePool {
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+        pool
+    }
+
+    #[tokio::test]
+    async fn test_preferences_default_and_upsert() {
+        let pool = setup_test_db().await;
+        let repo = UserPreferencesRepository::new(pool);
+
+        let defaults = repo.find_or_default(LOCAL_USER_ID).await.unwrap();
+        assert_eq!(defaults.notification_channels(), vec![IN_APP_CHANNEL]);
+        assert!(defaults.ask_model.is_none());
+
+        let mut preferences = defaults.clone();
+        preferences.ask_model = Some("openai/gpt-4o-mini".to_string());
+        repo.upsert(&preferences).await.unwrap();
+
+        let saved = repo.find_or_default(LOCAL_USER_ID).await.unwrap();
+        assert_eq!(saved.ask_model.as_deref(), Some("openai/gpt-4o-mini"));
+    }
+
+    #[tokio::test]
+    async fn test_digest_schedule() {
+        let pool = setup_test_db().await;
+        let repo = UserPreferencesRepository::new(pool);
+
+        let mut preferences = UserPreferences::defaults(LOCAL_USER_ID);
+        let now = Utc::now().timestamp();
+        preferences.digest_email = Some("lead@example.com".to_string());
+        assert_eq!(preferences.digest_due(now), None);
+
+        preferences.digest_schedule = "daily".to_string();
+        repo.upsert(&preferences).await.unwrap();
+        assert_eq!(repo.find_all().await.unwrap().len(), 1);
+        assert_eq!(preferences.digest_due(now), Some(now - 86_400));
+
+        assert!(repo.mark_digest_sent(LOCAL_USER_ID, now).await.unwrap());
+        let saved = repo.find_or_default(LOCAL_USER_ID).await.unwrap();
+        assert_eq!(saved.digest_schedule(), DigestSchedule::Daily);
+        assert_eq!(saved.digest_due(now + 3_600), None);
+        assert_eq!(saved.digest_due(now + 86_400), Some(now));
+    }
+}
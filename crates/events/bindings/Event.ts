@@ -3,7 +3,31 @@ import type { AgentMessageData } from "./AgentMessageData";
 import type { ToolExecutionData } from "./ToolExecutionData";
 import type { WikiGenerationPhase } from "./WikiGenerationPhase";
 
-export type Event = { "type": "task.created", task_id: string, title: string, } | { "type": "task.updated", task_id: string, } | { "type": "task.status_changed", task_id: string, from_status: string, to_status: string, } | { "type": "session.started", session_id: string, task_id: string, 
+export type Event = { "type": "task.created", task_id: string, title: string, } | { "type": "task.updated", task_id: string, } | { "type": "task.status_changed", task_id: string, from_status: string, to_status: string, } | { "type": "findings.updated", task_id: string, finding_ids: Array<string>, 
+/**
+ * New status (fixed, skipped, dismissed)
+ */
+status: string, } | { "type": "task.transition_rule_evaluated", task_id: string, 
+/**
+ * How the phase finished (e.g. review_approved, review_rejected)
+ */
+outcome: string, 
+/**
+ * Whether the workflow has a rule for the outcome
+ */
+matched: boolean, from_status: string, 
+/**
+ * Status the task moved to; `from_status` when it stayed
+ */
+to_status: string, 
+/**
+ * Phase started after the transition
+ */
+queued_phase: string | null, 
+/**
+ * Why the task stayed where it was
+ */
+reason: string | null, } | { "type": "session.started", session_id: string, task_id: string, 
 /**
  * Session phase (planning, implementation, review, etc.)
  */
@@ -39,7 +63,59 @@ next_phase_number: number,
 /**
  * Total number of phases
  */
-total_phases: number, } | { "type": "agent.message", session_id: string, task_id: string, message: AgentMessageData, } | { "type": "tool.execution", session_id: string, task_id: string, tool: ToolExecutionData, } | { "type": "workspace.created", task_id: string, path: string, } | { "type": "workspace.merged", task_id: string, success: boolean, } | { "type": "workspace.deleted", task_id: string, } | { "type": "project.opened", path: string, name: string, was_initialized: boolean, } | { "type": "project.closed", path: string, } | { "type": "wiki.generation_progress", branch: string, phase: WikiGenerationPhase, current: number, total: number, current_item: string | null, message: string | null, } | { "type": "roadmap.generation_started" } | { "type": "roadmap.generation_progress", 
+total_phases: number, } | { "type": "phase.progress", task_id: string, 
+/**
+ * Session doing the work, once it was created
+ */
+session_id: string | null, 
+/**
+ * Session phase (planning, review)
+ */
+phase: string, 
+/**
+ * Step within the phase (analysis, drafting, finalizing, reviewing_files)
+ */
+step: string, 
+/**
+ * Position of the step (1-based), or files reviewed
+ */
+current: number, 
+/**
+ * Number of steps, or files to review
+ */
+total: number, 
+/**
+ * Findings created so far (review only)
+ */
+findings_count: number | null, message: string | null, } | { "type": "agent.message", session_id: string, task_id: string, message: AgentMessageData, } | { "type": "tool.execution", session_id: string, task_id: string, tool: ToolExecutionData, } | { "type": "workspace.created", task_id: string, path: string, } | { "type": "workspace.merged", task_id: string, success: boolean, } | { "type": "workspace.deleted", task_id: string, } | { "type": "workspace.path_violation", task_id: string, session_id: string, 
+/**
+ * Tool that wrote, or asked to write, the file
+ */
+tool: string, path: string, 
+/**
+ * Whether the write was denied before it happened
+ */
+blocked: boolean, } | { "type": "ci.status_changed", task_id: string, 
+/**
+ * Branch or commit the checks ran on
+ */
+ref_name: string, 
+/**
+ * Gate state (pending, passed, failed, timed_out)
+ */
+state: string, passed: number, failed: number, pending: number, } | { "type": "project.opened", path: string, name: string, was_initialized: boolean, } | { "type": "project.closed", path: string, } | { "type": "wiki.generation_progress", branch: string, phase: WikiGenerationPhase, current: number, total: number, current_item: string | null, message: string | null, } | { "type": "wiki.index_refreshed", branch: string, 
+/**
+ * Commit the index was built from
+ */
+commit_sha: string | null, chunk_count: number, } | { "type": "wiki.docs_refreshed", task_id: string, branch: string, 
+/**
+ * Pages updated or suggested for update
+ */
+pages: number, 
+/**
+ * `suggested` or `regenerated`
+ */
+action: string, } | { "type": "roadmap.generation_started" } | { "type": "roadmap.generation_progress", 
 /**
  * Current phase (analyzing, discovering, generating, complete, error)
  */
@@ -2,6 +2,22 @@
 //!
 //! This crate provides the event bus and event types for real-time
 //! communication between components.
+//!
+//! # Schema versioning
+//!
+//! Every [`EventEnvelope`] carries the `schema_version` it follows, and
+//! clients say which version they understand when they subscribe. The
+//! compatibility policy is:
+//!
+//! - Adding an optional field to an event is compatible and does not bump
+//!   the version. Clients must ignore fields they do not know.
+//! - Adding an event type bumps [`EVENT_SCHEMA_VERSION`]; the type's
+//!   [`Event::since_version`] is the new version, and clients on older
+//!   versions do not receive it.
+//! - Removing or renaming a field or event type, or changing a field's type,
+//!   bumps the version. Older versions keep being served until
+//!   [`MIN_EVENT_SCHEMA_VERSION`] is raised past them, which happens no
+//!   earlier than one release after the bump.
 
 mod bus;
 mod types;
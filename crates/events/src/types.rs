@@ -9,21 +9,60 @@ use uuid::Uuid;
 pub struct EventEnvelope {
     /// Unique event ID
     pub id: Uuid,
+    /// Version of the event schema the envelope follows
+    #[serde(default = "default_schema_version")]
+    pub schema_version: u32,
     /// When the event occurred
     pub timestamp: DateTime<Utc>,
     /// The actual event
     pub event: Event,
 }
 
+/// Current version of the event schema
+pub const EVENT_SCHEMA_VERSION: u32 = 6;
+
+/// Oldest event schema version clients can still request
+pub const MIN_EVENT_SCHEMA_VERSION: u32 = 1;
+
+/// Envelopes stored before versioning was introduced follow version 1
+fn default_schema_version() -> u32 {
+    1
+}
+
+/// Pick the schema version to send a client that understands up to
+/// `requested`, or the current version if it did not say
+///
+/// Returns `None` if `requested` is older than [`MIN_EVENT_SCHEMA_VERSION`].
+pub fn negotiate_schema_version(requested: Option<u32>) -> Option<u32> {
+    match requested {
+        None => Some(EVENT_SCHEMA_VERSION),
+        Some(version) if version < MIN_EVENT_SCHEMA_VERSION => None,
+        Some(version) => Some(version.min(EVENT_SCHEMA_VERSION)),
+    }
+}
+
 impl EventEnvelope {
     /// Create a new event envelope with auto-generated ID and timestamp
     pub fn new(event: Event) -> Self {
         Self {
             id: Uuid::new_v4(),
+            schema_version: EVENT_SCHEMA_VERSION,
             timestamp: Utc::now(),
             event,
         }
     }
+
+    /// The envelope as a client on schema `version` should receive it, or
+    /// `None` if the event did not exist in that version yet
+    pub fn for_version(&self, version: u32) -> Option<EventEnvelope> {
+        if self.event.since_version() > version {
+            return None;
+        }
+        Some(EventEnvelope {
+            schema_version: version.min(self.schema_version),
+            ..self.clone()
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -48,6 +87,32 @@ pub enum Event {
         to_status: String,
     },
 
+    /// Findings of a task changed status
+    #[serde(rename = "findings.updated")]
+    FindingsUpdated {
+        task_id: Uuid,
+        finding_ids: Vec<String>,
+        /// New status (fixed, skipped, dismissed)
+        status: String,
+    },
+
+    /// The workflow's rule for a finished phase was evaluated
+    #[serde(rename = "task.transition_rule_evaluated")]
+    TransitionRuleEvaluated {
+        task_id: Uuid,
+        /// How the phase finished (e.g. review_approved, review_rejected)
+        outcome: String,
+        /// Whether the workflow has a rule for the outcome
+        matched: bool,
+        from_status: String,
+        /// Status the task moved to; `from_status` when it stayed
+        to_status: String,
+        /// Phase started after the transition
+        queued_phase: Option<String>,
+        /// Why the task stayed where it was
+        reason: Option<String>,
+    },
+
     // Session events
     /// OpenCode session started
     #[serde(rename = "session.started")]
@@ -96,6 +161,25 @@ pub enum Event {
         total_phases: u32,
     },
 
+    /// A step of a running planning or review session
+    #[serde(rename = "phase.progress")]
+    PhaseProgress {
+        task_id: Uuid,
+        /// Session doing the work, once it was created
+        session_id: Option<Uuid>,
+        /// Session phase (planning, review)
+        phase: String,
+        /// Step within the phase (analysis, drafting, finalizing, reviewing_files)
+        step: String,
+        /// Position of the step (1-based), or files reviewed
+        current: u32,
+        /// Number of steps, or files to review
+        total: u32,
+        /// Findings created so far (review only)
+        findings_count: Option<u32>,
+        message: Option<String>,
+    },
+
     /// Message from OpenCode agent
     #[serde(rename = "agent.message")]
     AgentMessage {
@@ -125,6 +209,31 @@ pub enum Event {
     #[serde(rename = "workspace.deleted")]
     WorkspaceDeleted { task_id: Uuid },
 
+    /// A session tried to write a file outside its workspace
+    #[serde(rename = "workspace.path_violation")]
+    PathViolation {
+        task_id: Uuid,
+        session_id: Uuid,
+        /// Tool that wrote, or asked to write, the file
+        tool: String,
+        path: String,
+        /// Whether the write was denied before it happened
+        blocked: bool,
+    },
+
+    /// GitHub checks gating a task's completion were polled
+    #[serde(rename = "ci.status_changed")]
+    CiStatusChanged {
+        task_id: Uuid,
+        /// Branch or commit the checks ran on
+        ref_name: String,
+        /// Gate state (pending, passed, failed, timed_out)
+        state: String,
+        passed: u32,
+        failed: u32,
+        pending: u32,
+    },
+
     // Project events
     /// A project was opened/switched
     #[serde(rename = "project.opened")]
@@ -150,6 +259,26 @@ pub enum Event {
         message: Option<String>,
     },
 
+    /// A branch's code index was refreshed after a merge changed it
+    #[serde(rename = "wiki.index_refreshed")]
+    WikiIndexRefreshed {
+        branch: String,
+        /// Commit the index was built from
+        commit_sha: Option<String>,
+        chunk_count: u32,
+    },
+
+    /// Wiki pages were updated for a merged task's changes
+    #[serde(rename = "wiki.docs_refreshed")]
+    WikiDocsRefreshed {
+        task_id: Uuid,
+        branch: String,
+        /// Pages updated or suggested for update
+        pages: u32,
+        /// `suggested` or `regenerated`
+        action: String,
+    },
+
     // Roadmap events
     /// Roadmap generation started
     #[serde(rename = "roadmap.generation_started")]
@@ -248,23 +377,99 @@ pub struct ToolExecutionData {
 }
 
 impl Event {
+    /// The `type` tag the event is serialized with, e.g. `task.created`
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            Event::TaskCreated { .. } => "task.created",
+            Event::TaskUpdated { .. } => "task.updated",
+            Event::TaskStatusChanged { .. } => "task.status_changed",
+            Event::FindingsUpdated { .. } => "findings.updated",
+            Event::TransitionRuleEvaluated { .. } => "task.transition_rule_evaluated",
+            Event::SessionStarted { .. } => "session.started",
+            Event::SessionEnded { .. } => "session.ended",
+            Event::PhaseCompleted { .. } => "phase.completed",
+            Event::PhaseContinuing { .. } => "phase.continuing",
+            Event::PhaseProgress { .. } => "phase.progress",
+            Event::AgentMessage { .. } => "agent.message",
+            Event::ToolExecution { .. } => "tool.execution",
+            Event::WorkspaceCreated { .. } => "workspace.created",
+            Event::WorkspaceMerged { .. } => "workspace.merged",
+            Event::WorkspaceDeleted { .. } => "workspace.deleted",
+            Event::PathViolation { .. } => "workspace.path_violation",
+            Event::CiStatusChanged { .. } => "ci.status_changed",
+            Event::ProjectOpened { .. } => "project.opened",
+            Event::ProjectClosed { .. } => "project.closed",
+            Event::WikiGenerationProgress { .. } => "wiki.generation_progress",
+            Event::WikiIndexRefreshed { .. } => "wiki.index_refreshed",
+            Event::WikiDocsRefreshed { .. } => "wiki.docs_refreshed",
+            Event::RoadmapGenerationStarted => "roadmap.generation_started",
+            Event::RoadmapGenerationProgress { .. } => "roadmap.generation_progress",
+            Event::RoadmapGenerationCompleted { .. } => "roadmap.generation_completed",
+            Event::RoadmapGenerationFailed { .. } => "roadmap.generation_failed",
+            Event::RoadmapFeatureUpdated { .. } => "roadmap.feature_updated",
+            Event::RoadmapFeatureConverted { .. } => "roadmap.feature_converted",
+            Event::Error { .. } => "error",
+        }
+    }
+
+    /// The schema version the event was introduced in
+    pub fn since_version(&self) -> u32 {
+        match self {
+            Event::TaskCreated { .. }
+            | Event::TaskUpdated { .. }
+            | Event::TaskStatusChanged { .. }
+            | Event::FindingsUpdated { .. }
+            | Event::SessionStarted { .. }
+            | Event::SessionEnded { .. }
+            | Event::PhaseCompleted { .. }
+            | Event::PhaseContinuing { .. }
+            | Event::AgentMessage { .. }
+            | Event::ToolExecution { .. }
+            | Event::WorkspaceCreated { .. }
+            | Event::WorkspaceMerged { .. }
+            | Event::WorkspaceDeleted { .. }
+            | Event::ProjectOpened { .. }
+            | Event::ProjectClosed { .. }
+            | Event::WikiGenerationProgress { .. }
+            | Event::RoadmapGenerationStarted
+            | Event::RoadmapGenerationProgress { .. }
+            | Event::RoadmapGenerationCompleted { .. }
+            | Event::RoadmapGenerationFailed { .. }
+            | Event::RoadmapFeatureUpdated { .. }
+            | Event::RoadmapFeatureConverted { .. }
+            | Event::Error { .. } => 1,
+            Event::TransitionRuleEvaluated { .. } => 2,
+            Event::PhaseProgress { .. } => 3,
+            Event::CiStatusChanged { .. } => 4,
+            Event::PathViolation { .. } => 5,
+            Event::WikiIndexRefreshed { .. } | Event::WikiDocsRefreshed { .. } => 6,
+        }
+    }
+
     pub fn task_id(&self) -> Option<Uuid> {
         match self {
             Event::TaskCreated { task_id, .. } => Some(*task_id),
             Event::TaskUpdated { task_id } => Some(*task_id),
             Event::TaskStatusChanged { task_id, .. } => Some(*task_id),
+            Event::FindingsUpdated { task_id, .. } => Some(*task_id),
+            Event::TransitionRuleEvaluated { task_id, .. } => Some(*task_id),
             Event::SessionStarted { task_id, .. } => Some(*task_id),
             Event::SessionEnded { task_id, .. } => Some(*task_id),
             Event::PhaseCompleted { task_id, .. } => Some(*task_id),
             Event::PhaseContinuing { task_id, .. } => Some(*task_id),
+            Event::PhaseProgress { task_id, .. } => Some(*task_id),
             Event::AgentMessage { task_id, .. } => Some(*task_id),
             Event::ToolExecution { task_id, .. } => Some(*task_id),
             Event::WorkspaceCreated { task_id, .. } => Some(*task_id),
             Event::WorkspaceMerged { task_id, .. } => Some(*task_id),
             Event::WorkspaceDeleted { task_id } => Some(*task_id),
+            Event::PathViolation { task_id, .. } => Some(*task_id),
+            Event::CiStatusChanged { task_id, .. } => Some(*task_id),
             Event::ProjectOpened { .. } => None,
             Event::ProjectClosed { .. } => None,
             Event::WikiGenerationProgress { .. } => None,
+            Event::WikiIndexRefreshed { .. } => None,
+            Event::WikiDocsRefreshed { task_id, .. } => Some(*task_id),
             Event::RoadmapGenerationStarted => None,
             Event::RoadmapGenerationProgress { .. } => None,
             Event::RoadmapGenerationCompleted { .. } => None,
@@ -306,6 +511,119 @@ mod tests {
         assert!(json.contains("to_status"));
     }
 
+    #[test]
+    fn test_event_type_matches_serialized_tag() {
+        let event = Event::WorkspaceMerged {
+            task_id: Uuid::new_v4(),
+            success: true,
+        };
+
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], event.event_type());
+    }
+
+    #[test]
+    fn test_negotiate_schema_version() {
+        assert_eq!(negotiate_schema_version(None), Some(EVENT_SCHEMA_VERSION));
+        assert_eq!(
+            negotiate_schema_version(Some(EVENT_SCHEMA_VERSION + 5)),
+            Some(EVENT_SCHEMA_VERSION)
+        );
+        assert_eq!(
+            negotiate_schema_version(Some(MIN_EVENT_SCHEMA_VERSION)),
+            Some(MIN_EVENT_SCHEMA_VERSION)
+        );
+        assert_eq!(negotiate_schema_version(Some(0)), None);
+    }
+
+    #[test]
+    fn test_envelope_without_schema_version_is_version_1() {
+        let json = r#"{"id":"550e8400-e29b-41d4-a716-446655440000","timestamp":"2026-01-01T00:00:00Z","event":{"type":"task.updated","task_id":"550e8400-e29b-41d4-a716-446655440000"}}"#;
+        let envelope: EventEnvelope = serde_json::from_str(json).unwrap();
+        assert_eq!(envelope.schema_version, 1);
+
+        let downgraded = envelope.for_version(MIN_EVENT_SCHEMA_VERSION).unwrap();
+        assert_eq!(downgraded.schema_version, MIN_EVENT_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_transition_rule_event_needs_version_2() {
+        let envelope = EventEnvelope::new(Event::TransitionRuleEvaluated {
+            task_id: Uuid::new_v4(),
+            outcome: "review_rejected".to_string(),
+            matched: true,
+            from_status: "ai_review".to_string(),
+            to_status: "in_progress".to_string(),
+            queued_phase: Some("fix".to_string()),
+            reason: None,
+        });
+
+        assert!(envelope.for_version(1).is_none());
+        assert_eq!(envelope.for_version(2).unwrap().schema_version, 2);
+    }
+
+    #[test]
+    fn test_phase_progress_event_needs_version_3() {
+        let envelope = EventEnvelope::new(Event::PhaseProgress {
+            task_id: Uuid::new_v4(),
+            session_id: None,
+            phase: "review".to_string(),
+            step: "reviewing_files".to_string(),
+            current: 2,
+            total: 5,
+            findings_count: Some(1),
+            message: None,
+        });
+
+        assert!(envelope.for_version(2).is_none());
+        assert_eq!(envelope.for_version(3).unwrap().schema_version, 3);
+        assert_eq!(envelope.event.event_type(), "phase.progress");
+    }
+
+    #[test]
+    fn test_ci_status_event_needs_version_4() {
+        let envelope = EventEnvelope::new(Event::CiStatusChanged {
+            task_id: Uuid::new_v4(),
+            ref_name: "task-1".to_string(),
+            state: "pending".to_string(),
+            passed: 2,
+            failed: 0,
+            pending: 1,
+        });
+
+        assert!(envelope.for_version(3).is_none());
+        assert_eq!(envelope.for_version(4).unwrap().schema_version, 4);
+        assert_eq!(envelope.event.event_type(), "ci.status_changed");
+    }
+
+    #[test]
+    fn test_path_violation_event_needs_version_5() {
+        let envelope = EventEnvelope::new(Event::PathViolation {
+            task_id: Uuid::new_v4(),
+            session_id: Uuid::new_v4(),
+            tool: "write".to_string(),
+            path: "/etc/hosts".to_string(),
+            blocked: true,
+        });
+
+        assert!(envelope.for_version(4).is_none());
+        assert_eq!(envelope.for_version(5).unwrap().schema_version, 5);
+        assert_eq!(envelope.event.event_type(), "workspace.path_violation");
+    }
+
+    #[test]
+    fn test_wiki_refresh_events_need_version_6() {
+        let envelope = EventEnvelope::new(Event::WikiIndexRefreshed {
+            branch: "main".to_string(),
+            commit_sha: Some("abc123".to_string()),
+            chunk_count: 42,
+        });
+
+        assert!(envelope.for_version(5).is_none());
+        assert_eq!(envelope.for_version(6).unwrap().schema_version, 6);
+        assert_eq!(envelope.event.event_type(), "wiki.index_refreshed");
+    }
+
     #[test]
     fn test_event_deserialization() {
         let json = r#"{"type":"task.created","task_id":"550e8400-e29b-41d4-a716-446655440000","title":"Test"}"#;
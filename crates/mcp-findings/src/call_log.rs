@@ -0,0 +1,121 @@
+//! Writer of the project's tool call log (see `opencode_core::McpToolCall`)
+
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use opencode_core::{rotated_tool_call_log_path, McpToolCall, MAX_TOOL_CALL_LOG_BYTES};
+
+/// Appends the calls the server handles to the tool call log
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ToolCallLog {
+    /// `None` records nothing
+    path: Option<PathBuf>,
+}
+
+impl ToolCallLog {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: Some(path.into()),
+        }
+    }
+
+    /// Log that records nothing
+    pub fn disabled() -> Self {
+        Self { path: None }
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Append `call` to the log, creating it on the first call and rotating
+    /// it once it reaches `MAX_TOOL_CALL_LOG_BYTES`
+    pub fn record(&self, call: &McpToolCall) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            if !dir.exists() {
+                std::fs::create_dir_all(dir)?;
+                // Calls are local usage data, not project content
+                std::fs::write(dir.join(".gitignore"), "*\n")?;
+            }
+        }
+
+        match std::fs::metadata(path) {
+            Ok(metadata) if metadata.len() >= MAX_TOOL_CALL_LOG_BYTES => {
+                std::fs::rename(path, rotated_tool_call_log_path(path))?;
+            }
+            Ok(_) => {}
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+
+        let mut line = serde_json::to_string(call).map_err(io::Error::other)?;
+        line.push('\n');
+        // A single write keeps lines whole when several servers append
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?
+            .write_all(line.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::Utc;
+    use opencode_core::tool_call_log_path;
+
+    use super::*;
+
+    fn call(tool: &str) -> McpToolCall {
+        McpToolCall::new(
+            "opencode-findings",
+            tool,
+            None,
+            Utc::now(),
+            Duration::from_millis(12),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_record_rotates_full_log() {
+        let dir = std::env::temp_dir().join(format!("mcp-calls-{}", uuid::Uuid::new_v4()));
+        let path = tool_call_log_path(&dir);
+        let log = ToolCallLog::new(&path);
+
+        log.record(&call("list_findings")).unwrap();
+        assert!(path.parent().unwrap().join(".gitignore").exists());
+        let line = std::fs::read_to_string(&path).unwrap();
+        let recorded: McpToolCall = serde_json::from_str(line.trim_end()).unwrap();
+        assert_eq!(recorded.tool, "list_findings");
+
+        std::fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_len(MAX_TOOL_CALL_LOG_BYTES)
+            .unwrap();
+        log.record(&call("mark_fixed")).unwrap();
+
+        let rotated = rotated_tool_call_log_path(&path);
+        assert_eq!(
+            std::fs::metadata(&rotated).unwrap().len(),
+            MAX_TOOL_CALL_LOG_BYTES
+        );
+        let current = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(current.lines().count(), 1);
+        assert!(current.contains("\"mark_fixed\""));
+
+        ToolCallLog::disabled()
+            .record(&call("list_findings"))
+            .unwrap();
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -6,29 +6,54 @@
 //! The server exposes tools like:
 //! - `create_finding` - Create a new code review finding
 //! - `list_findings` - List all findings for the current task
+//! - `get_seen_context` - Recap the code earlier sessions of the task were shown
+//! - `get_diff` - Read the changes in the task's workspace
+//! - `apply_suggestion` - Apply the fix a finding proposes to the workspace
+//! - `add_finding_comment` - Reply in the discussion of a finding
+//! - `list_finding_comments` - Read the discussion of a finding
 //! - `approve_review` - Mark the review as approved (no issues found)
 //! - `complete_review` - Complete the review with findings
 
-use orchestrator::{FileManager, FindingSeverity, FindingStatus, ReviewFinding, ReviewFindings};
+mod call_log;
+
+pub use call_log::ToolCallLog;
+use chrono::Utc;
+use opencode_core::{McpToolCall, ToolAllowlist};
+use orchestrator::{
+    FileManager, FindingComment, FindingCommentAuthor, FindingSeverity, FindingStatus,
+    OrchestratorError, ReviewFinding, ReviewFindings,
+};
 use rmcp::{
-    handler::server::{router::tool::ToolRouter, tool::Parameters},
+    handler::server::{
+        router::tool::ToolRouter,
+        tool::{Parameters, ToolCallContext},
+    },
     model::{ErrorData as McpError, *},
-    schemars, tool, tool_handler, tool_router, ServerHandler,
+    schemars,
+    service::RequestContext,
+    tool, tool_router, RoleServer, ServerHandler,
 };
 use serde::Deserialize;
 use std::borrow::Cow;
 use std::future::Future;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::sync::Mutex;
 use tracing::{info, warn};
 use uuid::Uuid;
+use vcs::{same_relative_path, Workspace, WorkspaceBackend, WorkspaceConfig, WorkspaceManager};
+
+/// Longest diff `get_diff` returns; longer ones are cut at a file boundary
+const MAX_DIFF_CHARS: usize = 100_000;
 
 /// Request to create a new finding
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct CreateFindingRequest {
     /// The file path where the issue was found (optional for general findings)
-    #[schemars(description = "The file path where the issue was found")]
+    #[schemars(
+        description = "The file path where the issue was found, relative to the workspace root. Must exist"
+    )]
     pub file_path: Option<String>,
 
     /// Starting line number (optional)
@@ -52,6 +77,25 @@ pub struct CreateFindingRequest {
         description = "Severity level: error (must fix), warning (should fix), info (suggestion)"
     )]
     pub severity: String,
+
+    /// Steps or input that show the problem (optional)
+    #[schemars(description = "Steps, input or a command that show the problem")]
+    #[serde(default)]
+    pub reproduction: Option<String>,
+
+    /// What must hold once the issue is fixed (required for errors)
+    #[schemars(
+        description = "What must be true once the issue is fixed, e.g. a test that passes. Required for error severity"
+    )]
+    #[serde(default)]
+    pub acceptance_criteria: Option<String>,
+
+    /// Proposed fix as a unified diff (optional)
+    #[schemars(
+        description = "The fix you propose, as a unified diff against the workspace like the output of git diff. Only for small, certain fixes"
+    )]
+    #[serde(default)]
+    pub suggested_fix: Option<String>,
 }
 
 /// Request to complete the review
@@ -74,6 +118,35 @@ pub struct GetFindingRequest {
     pub finding_id: String,
 }
 
+/// Request to recap the code already shown to the task's sessions
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GetSeenContextRequest {
+    /// Only list ranges of this file
+    #[schemars(description = "Only list the ranges shown from this file (optional)")]
+    pub file_path: Option<String>,
+}
+
+/// Request to read the changes in the workspace
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GetDiffRequest {
+    /// Only show the changes to this file
+    #[schemars(
+        description = "Only show the changes to this file, relative to the workspace root (optional)"
+    )]
+    #[serde(default)]
+    pub file_path: Option<String>,
+}
+
+/// Request to apply the fix proposed with a finding
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ApplySuggestionRequest {
+    /// The ID of the finding whose suggested fix to apply
+    #[schemars(
+        description = "The ID of the finding whose suggested fix to apply (e.g., 'finding-1')"
+    )]
+    pub finding_id: String,
+}
+
 /// Request to mark a finding as fixed
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct MarkFixedRequest {
@@ -82,17 +155,47 @@ pub struct MarkFixedRequest {
     pub finding_id: String,
 }
 
+/// Request to comment on a finding
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct AddFindingCommentRequest {
+    /// The ID of the finding to comment on
+    #[schemars(description = "The ID of the finding to comment on (e.g., 'finding-1')")]
+    pub finding_id: String,
+
+    /// The comment
+    #[schemars(
+        description = "Your comment, e.g. an answer to the reviewer or why you fixed the finding the way you did"
+    )]
+    pub body: String,
+}
+
+/// Request to read the discussion of a finding
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListFindingCommentsRequest {
+    /// The ID of the finding whose comments to list
+    #[schemars(description = "The ID of the finding whose comments to list (e.g., 'finding-1')")]
+    pub finding_id: String,
+}
+
 #[derive(Clone)]
 pub struct FindingsService {
     task_id: Uuid,
     session_id: Uuid,
-    #[allow(dead_code)]
+    /// The task's worktree
     workspace_path: PathBuf,
+    /// How the worktree was created
+    workspace_backend: WorkspaceBackend,
     findings: Arc<Mutex<Vec<ReviewFinding>>>,
     summary: Arc<Mutex<Option<String>>>,
     approved: Arc<Mutex<Option<bool>>>,
     file_manager: Arc<FileManager>,
+    /// Files of the task's worktree, which findings point into and fixes
+    /// are applied to
+    workspace_files: Arc<FileManager>,
     tool_router: ToolRouter<FindingsService>,
+    /// Tools the session's phase may call
+    allowed_tools: ToolAllowlist,
+    call_log: ToolCallLog,
 }
 
 impl FindingsService {
@@ -103,11 +206,62 @@ impl FindingsService {
             task_id,
             session_id,
             workspace_path,
+            workspace_backend: WorkspaceBackend::default(),
             findings: Arc::new(Mutex::new(Vec::new())),
             summary: Arc::new(Mutex::new(None)),
             approved: Arc::new(Mutex::new(None)),
+            workspace_files: file_manager.clone(),
             file_manager,
             tool_router: Self::tool_router(),
+            allowed_tools: ToolAllowlist::all(),
+            call_log: ToolCallLog::disabled(),
+        }
+    }
+
+    /// Check findings against, and apply suggested fixes to, the task's
+    /// worktree at `workspace_path` rather than the project the findings are
+    /// stored in
+    pub fn with_workspace_path(mut self, workspace_path: PathBuf) -> Self {
+        self.workspace_files = Arc::new(FileManager::new(workspace_path.clone()));
+        self.workspace_path = workspace_path;
+        self
+    }
+
+    /// Read the worktree as the project's `workspace_backend` created it
+    pub fn with_workspace_backend(mut self, workspace_backend: WorkspaceBackend) -> Self {
+        self.workspace_backend = workspace_backend;
+        self
+    }
+
+    /// Only offer the tools in `allowed_tools`
+    pub fn with_allowed_tools(mut self, allowed_tools: ToolAllowlist) -> Self {
+        self.allowed_tools = allowed_tools;
+        self
+    }
+
+    /// Record the tool calls this service handles in `call_log`
+    pub fn with_call_log(mut self, call_log: ToolCallLog) -> Self {
+        self.call_log = call_log;
+        self
+    }
+
+    fn record_call(
+        &self,
+        tool: &str,
+        called_at: chrono::DateTime<Utc>,
+        started: Instant,
+        result: &Result<CallToolResult, McpError>,
+    ) {
+        let call = McpToolCall::new(
+            "opencode-findings",
+            tool,
+            Some(self.session_id.to_string()),
+            called_at,
+            started.elapsed(),
+            call_error(result),
+        );
+        if let Err(e) = self.call_log.record(&call) {
+            warn!(error = %e, tool = %tool, "Failed to record tool call");
         }
     }
 
@@ -124,6 +278,28 @@ impl FindingsService {
         self.approved.lock().await.is_some()
     }
 
+    /// A finding of this session, or else of the findings file, by ID
+    async fn find_finding(&self, finding_id: &str) -> Option<ReviewFinding> {
+        let session_finding = self
+            .findings
+            .lock()
+            .await
+            .iter()
+            .find(|f| f.id == finding_id)
+            .cloned();
+        if session_finding.is_some() {
+            return session_finding;
+        }
+
+        let existing = self
+            .file_manager
+            .read_findings(self.task_id)
+            .await
+            .ok()
+            .flatten()?;
+        existing.findings.into_iter().find(|f| f.id == finding_id)
+    }
+
     /// Save findings to file
     pub async fn save_findings(&self) -> anyhow::Result<()> {
         let review_findings = self.get_findings().await;
@@ -148,17 +324,14 @@ impl FindingsService {
         &self,
         Parameters(request): Parameters<CreateFindingRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let mut findings = self.findings.lock().await;
-        let finding_id = format!("finding-{}", findings.len() + 1);
-
         let severity = match request.severity.to_lowercase().as_str() {
             "error" => FindingSeverity::Error,
             "info" => FindingSeverity::Info,
             _ => FindingSeverity::Warning,
         };
 
-        let finding = ReviewFinding {
-            id: finding_id.clone(),
+        let mut finding = ReviewFinding {
+            id: String::new(),
             file_path: request.file_path.clone(),
             line_start: request.line_start,
             line_end: request.line_end,
@@ -166,8 +339,59 @@ impl FindingsService {
             description: request.description.clone(),
             severity,
             status: FindingStatus::Pending,
+            original_severity: None,
+            severity_rationale: None,
+            reproduction: request.reproduction,
+            acceptance_criteria: request.acceptance_criteria,
+            suggested_fix: request.suggested_fix,
+            consensus_score: None,
+            comments: Vec::new(),
+            snippet: None,
         };
 
+        if let Err(reason) = finding.validate() {
+            warn!(task_id = %self.task_id, reason = %reason, "Rejected incomplete finding");
+            return Ok(CallToolResult::error(vec![Content::text(format!(
+                "{}. Add acceptance_criteria describing what must hold once it is fixed, then create the finding again.",
+                reason
+            ))]));
+        }
+
+        let mut range_note = String::new();
+        if let Some(path) = request.file_path.as_deref() {
+            let content = match self.workspace_files.read_workspace_file(path).await {
+                Ok(Some(content)) => content,
+                Ok(None) => {
+                    return Ok(CallToolResult::error(vec![Content::text(format!(
+                        "{} does not exist in the workspace. Give the path relative to the workspace root.",
+                        path
+                    ))]));
+                }
+                Err(e) => {
+                    return Ok(CallToolResult::error(vec![Content::text(e.to_string())]));
+                }
+            };
+            match finding.anchor_to_source(&content) {
+                Ok(true) => {
+                    range_note = format!(
+                        "; line range adjusted to {} to fit the file",
+                        line_range(finding.line_start, finding.line_end)
+                    );
+                }
+                Ok(false) => {}
+                Err(reason) => {
+                    warn!(task_id = %self.task_id, reason = %reason, "Rejected finding with invalid lines");
+                    return Ok(CallToolResult::error(vec![Content::text(format!(
+                        "{}. Check the line numbers and create the finding again.",
+                        reason
+                    ))]));
+                }
+            }
+        }
+
+        let mut findings = self.findings.lock().await;
+        let finding_id = format!("finding-{}", findings.len() + 1);
+        finding.id = finding_id.clone();
         findings.push(finding);
 
         info!(
@@ -179,8 +403,8 @@ impl FindingsService {
         );
 
         Ok(CallToolResult::success(vec![Content::text(format!(
-            "Finding created: {} ({})",
-            finding_id, request.title
+            "Finding created: {} ({}){}",
+            finding_id, request.title, range_note
         ))]))
     }
 
@@ -223,6 +447,7 @@ impl FindingsService {
                     FindingStatus::Pending => "",
                     FindingStatus::Fixed => " [FIXED]",
                     FindingStatus::Skipped => " [SKIPPED]",
+                    FindingStatus::Dismissed => " [DISMISSED]",
                 };
                 format!(
                     "- {} [{}]{}{}: {}",
@@ -248,62 +473,259 @@ impl FindingsService {
         &self,
         Parameters(request): Parameters<GetFindingRequest>,
     ) -> Result<CallToolResult, McpError> {
-        // First check session findings
-        let session_findings = self.findings.lock().await;
-        if let Some(f) = session_findings.iter().find(|f| f.id == request.finding_id) {
-            let location = match (&f.file_path, f.line_start, f.line_end) {
-                (Some(path), Some(start), Some(end)) if start != end => {
-                    format!("Location: {}:{}-{}", path, start, end)
+        if let Some(f) = self.find_finding(&request.finding_id).await {
+            return Ok(CallToolResult::success(vec![Content::text(
+                finding_details(&f),
+            )]));
+        }
+
+        Ok(CallToolResult::success(vec![Content::text(format!(
+            "Finding '{}' not found.",
+            request.finding_id
+        ))]))
+    }
+
+    #[tool(
+        description = "Recap the code ranges earlier sessions of this task were already shown, with a one-line summary of each. Use this for a refresher instead of re-reading whole files."
+    )]
+    async fn get_seen_context(
+        &self,
+        Parameters(request): Parameters<GetSeenContextRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let seen = self
+            .file_manager
+            .read_seen_context(self.task_id)
+            .await
+            .map_err(|e| McpError {
+                code: ErrorCode(-32603),
+                message: Cow::from(format!("Failed to read seen context: {}", e)),
+                data: None,
+            })?;
+
+        let text = match request.file_path.as_deref() {
+            Some(file_path) => {
+                let ranges = seen.for_file(file_path);
+                if ranges.is_empty() {
+                    format!("No code from {} was shown earlier in this task.", file_path)
+                } else {
+                    let list = ranges
+                        .iter()
+                        .map(|r| {
+                            format!(
+                                "- lines {}-{} ({}): {}",
+                                r.start_line, r.end_line, r.phase, r.summary
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    format!("Shown from {}:\n{}", file_path, list)
                 }
-                (Some(path), Some(line), _) => format!("Location: {}:{}", path, line),
-                (Some(path), None, _) => format!("File: {}", path),
-                _ => "Location: Not specified".to_string(),
-            };
-            return Ok(CallToolResult::success(vec![Content::text(format!(
-                "Finding: {}\n\nTitle: {}\nSeverity: {}\nStatus: {:?}\n{}\n\nDescription:\n{}",
-                f.id,
-                f.title,
-                f.severity.as_str(),
-                f.status,
-                location,
-                f.description
+            }
+            None if seen.is_empty() => "No code was shown earlier in this task.".to_string(),
+            None => format!(
+                "Code shown earlier in this task ({} ranges):\n\n{}",
+                seen.ranges.len(),
+                seen.to_markdown()
+            ),
+        };
+
+        Ok(CallToolResult::success(vec![Content::text(text)]))
+    }
+
+    #[tool(
+        description = "Show the changes in the task's workspace as a unified diff against the main branch, optionally only those to one file."
+    )]
+    async fn get_diff(
+        &self,
+        Parameters(request): Parameters<GetDiffRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        // Findings live in the project, whose VCS also owns the worktree.
+        // Copy-based worktrees keep their baseline next to them.
+        let workspace_base = self
+            .workspace_path
+            .parent()
+            .map(PathBuf::from)
+            .unwrap_or_default();
+        let manager = WorkspaceManager::detect(
+            self.file_manager.base_path(),
+            WorkspaceConfig::new(workspace_base).with_backend(self.workspace_backend),
+        );
+        let task_id = self.task_id.to_string();
+        let workspace = Workspace::new(
+            task_id.clone(),
+            self.workspace_path.clone(),
+            format!("task-{}", task_id),
+        );
+        let diff = match manager.get_diff(&workspace).await {
+            Ok(diff) => diff,
+            Err(e) => {
+                warn!(task_id = %self.task_id, error = %e, "Failed to read workspace diff");
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Failed to read the workspace diff: {}",
+                    e
+                ))]));
+            }
+        };
+
+        let text = match request.file_path.as_deref() {
+            Some(file_path) => {
+                let file_diff = file_diff(&diff, file_path);
+                if file_diff.is_empty() {
+                    format!("No changes to {} in the workspace.", file_path)
+                } else {
+                    truncate_diff(&file_diff)
+                }
+            }
+            None if diff.trim().is_empty() => "No changes in the workspace.".to_string(),
+            None => truncate_diff(&diff),
+        };
+        Ok(CallToolResult::success(vec![Content::text(text)]))
+    }
+
+    #[tool(
+        description = "Apply the fix the reviewer proposed for a finding to the workspace. Check the result, then call mark_fixed."
+    )]
+    async fn apply_suggestion(
+        &self,
+        Parameters(request): Parameters<ApplySuggestionRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let finding = match self.find_finding(&request.finding_id).await {
+            Some(finding) => finding,
+            None => {
+                return Ok(CallToolResult::success(vec![Content::text(format!(
+                    "Finding '{}' not found.",
+                    request.finding_id
+                ))]));
+            }
+        };
+        let Some(patch) = finding.suggested_fix.as_deref() else {
+            return Ok(CallToolResult::error(vec![Content::text(format!(
+                "Finding '{}' has no suggested fix. Fix it by hand.",
+                request.finding_id
             ))]));
+        };
+
+        match self.workspace_files.apply_patch(patch).await {
+            Ok(paths) => {
+                info!(
+                    task_id = %self.task_id,
+                    finding_id = %request.finding_id,
+                    files = paths.len(),
+                    "Applied suggested fix"
+                );
+                Ok(CallToolResult::success(vec![Content::text(format!(
+                    "Applied the suggested fix for '{}' to: {}. Review the change, then call mark_fixed.",
+                    request.finding_id,
+                    paths.join(", ")
+                ))]))
+            }
+            Err(e) => {
+                warn!(
+                    task_id = %self.task_id,
+                    finding_id = %request.finding_id,
+                    error = %e,
+                    "Suggested fix does not apply"
+                );
+                Ok(CallToolResult::error(vec![Content::text(format!(
+                    "The suggested fix for '{}' does not apply, nothing was changed: {}. Fix the issue by hand.",
+                    request.finding_id, e
+                ))]))
+            }
         }
-        drop(session_findings);
+    }
 
-        // Then check file findings
-        if let Ok(Some(existing)) = self.file_manager.read_findings(self.task_id).await {
-            if let Some(f) = existing
-                .findings
-                .iter()
-                .find(|f| f.id == request.finding_id)
-            {
-                let location = match (&f.file_path, f.line_start, f.line_end) {
-                    (Some(path), Some(start), Some(end)) if start != end => {
-                        format!("Location: {}:{}-{}", path, start, end)
+    #[tool(
+        description = "Comment on a finding, e.g. to answer the human reviewer or explain how you fixed it. The reviewer sees the comment with the finding."
+    )]
+    async fn add_finding_comment(
+        &self,
+        Parameters(request): Parameters<AddFindingCommentRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        if request.body.trim().is_empty() {
+            return Ok(CallToolResult::error(vec![Content::text(
+                "The comment is empty.",
+            )]));
+        }
+        let comment = FindingComment::new(FindingCommentAuthor::Agent, request.body.trim());
+
+        // Findings created in this session are only saved when the review completes
+        let mut session_findings = self.findings.lock().await;
+        let added = match session_findings
+            .iter_mut()
+            .find(|f| f.id == request.finding_id)
+        {
+            Some(finding) => {
+                finding.comments.push(comment);
+                true
+            }
+            None => {
+                drop(session_findings);
+                match self
+                    .file_manager
+                    .add_finding_comment(self.task_id, &request.finding_id, comment)
+                    .await
+                {
+                    Ok(added) => added.is_some(),
+                    Err(OrchestratorError::FindingsNotFound(_)) => false,
+                    Err(e) => {
+                        return Err(McpError {
+                            code: ErrorCode(-32603),
+                            message: Cow::from(format!("Failed to save comment: {}", e)),
+                            data: None,
+                        });
                     }
-                    (Some(path), Some(line), _) => format!("Location: {}:{}", path, line),
-                    (Some(path), None, _) => format!("File: {}", path),
-                    _ => "Location: Not specified".to_string(),
-                };
-                return Ok(CallToolResult::success(vec![Content::text(format!(
-                    "Finding: {}\n\nTitle: {}\nSeverity: {}\nStatus: {:?}\n{}\n\nDescription:\n{}",
-                    f.id,
-                    f.title,
-                    f.severity.as_str(),
-                    f.status,
-                    location,
-                    f.description
-                ))]));
+                }
             }
+        };
+
+        if !added {
+            return Ok(CallToolResult::success(vec![Content::text(format!(
+                "Finding '{}' not found.",
+                request.finding_id
+            ))]));
         }
 
+        info!(
+            task_id = %self.task_id,
+            finding_id = %request.finding_id,
+            "Commented on finding"
+        );
+
         Ok(CallToolResult::success(vec![Content::text(format!(
-            "Finding '{}' not found.",
+            "Comment added to '{}'.",
             request.finding_id
         ))]))
     }
 
+    #[tool(
+        description = "Read the discussion of a finding: questions and notes from the human reviewer and earlier sessions."
+    )]
+    async fn list_finding_comments(
+        &self,
+        Parameters(request): Parameters<ListFindingCommentsRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let Some(finding) = self.find_finding(&request.finding_id).await else {
+            return Ok(CallToolResult::success(vec![Content::text(format!(
+                "Finding '{}' not found.",
+                request.finding_id
+            ))]));
+        };
+
+        if finding.comments.is_empty() {
+            return Ok(CallToolResult::success(vec![Content::text(format!(
+                "No comments on '{}'.",
+                request.finding_id
+            ))]));
+        }
+
+        Ok(CallToolResult::success(vec![Content::text(format!(
+            "Comments on '{}' ({}):\n{}",
+            request.finding_id,
+            finding.comments.len(),
+            format_comments(&finding.comments)
+        ))]))
+    }
+
     #[tool(
         description = "Mark a finding as fixed after you've addressed the issue. This updates the findings file."
     )]
@@ -446,7 +868,129 @@ impl FindingsService {
     }
 }
 
-#[tool_handler]
+/// Sections of a unified diff that change `file_path`, under either its
+/// old or its new name
+fn file_diff(diff: &str, file_path: &str) -> String {
+    let mut kept = String::new();
+    let mut keep = false;
+    for line in diff.split_inclusive('\n') {
+        if let Some(paths) = line.trim_end().strip_prefix("diff --git a/") {
+            keep = match paths.rfind(" b/") {
+                Some(i) => {
+                    same_relative_path(&paths[..i], file_path)
+                        || same_relative_path(&paths[i + 3..], file_path)
+                }
+                None => same_relative_path(paths, file_path),
+            };
+        }
+        if keep {
+            kept.push_str(line);
+        }
+    }
+    kept
+}
+
+/// `diff` cut to [`MAX_DIFF_CHARS`] at the last file that fits, with a note
+/// naming the files left out
+fn truncate_diff(diff: &str) -> String {
+    if diff.len() <= MAX_DIFF_CHARS {
+        return diff.to_string();
+    }
+    let mut limit = MAX_DIFF_CHARS;
+    while !diff.is_char_boundary(limit) {
+        limit -= 1;
+    }
+    let cut = diff[..limit]
+        .rfind("\ndiff --git ")
+        .map_or(limit, |i| i + 1);
+    let omitted: Vec<&str> = diff[cut..]
+        .lines()
+        .filter_map(|line| line.strip_prefix("diff --git a/"))
+        .map(|paths| paths.rfind(" b/").map_or(paths, |i| &paths[i + 3..]))
+        .collect();
+    format!(
+        "{}\n[Diff truncated; left out: {}. Call get_diff with file_path to see one of them.]",
+        &diff[..cut],
+        if omitted.is_empty() {
+            "the rest of the last file".to_string()
+        } else {
+            omitted.join(", ")
+        }
+    )
+}
+
+/// Lines of a finding as `start` or `start-end`
+fn line_range(start: Option<i32>, end: Option<i32>) -> String {
+    match (start, end) {
+        (Some(start), Some(end)) if start != end => format!("{}-{}", start, end),
+        (Some(line), _) | (None, Some(line)) => line.to_string(),
+        (None, None) => String::new(),
+    }
+}
+
+/// Full text of a finding, as returned by `get_finding`
+fn finding_details(f: &ReviewFinding) -> String {
+    let location = match (&f.file_path, f.line_start, f.line_end) {
+        (Some(path), Some(start), Some(end)) if start != end => {
+            format!("Location: {}:{}-{}", path, start, end)
+        }
+        (Some(path), Some(line), _) => format!("Location: {}:{}", path, line),
+        (Some(path), None, _) => format!("File: {}", path),
+        _ => "Location: Not specified".to_string(),
+    };
+    let mut details = format!(
+        "Finding: {}\n\nTitle: {}\nSeverity: {}\nStatus: {:?}\n{}\n\nDescription:\n{}",
+        f.id,
+        f.title,
+        f.severity.as_str(),
+        f.status,
+        location,
+        f.description
+    );
+    if let Some(snippet) = &f.snippet {
+        details.push_str(&format!("\n\nSource:\n```\n{}```", snippet));
+    }
+    if let Some(reproduction) = &f.reproduction {
+        details.push_str(&format!("\n\nReproduction:\n{}", reproduction));
+    }
+    if let Some(criteria) = &f.acceptance_criteria {
+        details.push_str(&format!("\n\nAcceptance criteria:\n{}", criteria));
+    }
+    if let Some(fix) = &f.suggested_fix {
+        details.push_str(&format!(
+            "\n\nSuggested fix (apply with apply_suggestion):\n```diff\n{}\n```",
+            fix
+        ));
+    }
+    if !f.comments.is_empty() {
+        details.push_str(&format!(
+            "\n\nDiscussion (reply with add_finding_comment):\n{}",
+            format_comments(&f.comments)
+        ));
+    }
+    details
+}
+
+/// Comments of a finding, one per line with their author
+fn format_comments(comments: &[FindingComment]) -> String {
+    comments
+        .iter()
+        .map(|c| {
+            let author = match c.author {
+                FindingCommentAuthor::Human => "reviewer",
+                FindingCommentAuthor::Agent => "agent",
+            };
+            format!(
+                "- [{}] {}: {}",
+                c.created_at.format("%Y-%m-%d %H:%M"),
+                author,
+                c.body
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 impl ServerHandler for FindingsService {
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
@@ -464,6 +1008,55 @@ impl ServerHandler for FindingsService {
             ),
         }
     }
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, McpError> {
+        let tools = self
+            .tool_router
+            .list_all()
+            .into_iter()
+            .filter(|tool| self.allowed_tools.allows(&tool.name))
+            .collect();
+        Ok(ListToolsResult::with_all_items(tools))
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        let (called_at, started) = (Utc::now(), Instant::now());
+        let tool = request.name.to_string();
+        let result = if !self.allowed_tools.allows(&tool) {
+            warn!(tool = %tool, "Denied call to a tool this phase may not use");
+            Ok(CallToolResult::error(vec![Content::text(format!(
+                "Permission denied: tool '{}' is not allowed in this phase",
+                tool
+            ))]))
+        } else {
+            let context = ToolCallContext::new(self, request, context);
+            self.tool_router.call(context).await
+        };
+        self.record_call(&tool, called_at, started, &result);
+        result
+    }
+}
+
+/// Why a tool call failed, if it did
+fn call_error(result: &Result<CallToolResult, McpError>) -> Option<String> {
+    match result {
+        Ok(result) if result.is_error == Some(true) => Some(
+            result
+                .content
+                .iter()
+                .find_map(|content| content.as_text().map(|text| text.text.clone()))
+                .unwrap_or_else(|| "Tool returned an error".to_string()),
+        ),
+        Ok(_) => None,
+        Err(e) => Some(e.message.to_string()),
+    }
 }
 
 #[cfg(test)]
@@ -472,26 +1065,349 @@ mod tests {
 
     #[tokio::test]
     async fn test_create_finding() {
-        let service =
-            FindingsService::new(Uuid::new_v4(), Uuid::new_v4(), PathBuf::from("/tmp/test"));
+        let workspace = std::env::temp_dir().join(format!("findings-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(workspace.join("src")).unwrap();
+        let source: String = (1..=50).map(|i| format!("line {}\n", i)).collect();
+        std::fs::write(workspace.join("src/main.rs"), source).unwrap();
+        // Findings are stored in the project, but point into the worktree
+        let project = std::env::temp_dir().join(format!("findings-{}", Uuid::new_v4()));
+        let service = FindingsService::new(Uuid::new_v4(), Uuid::new_v4(), project)
+            .with_workspace_path(workspace.clone());
 
-        // Create a finding
-        let request = CreateFindingRequest {
-            file_path: Some("src/main.rs".to_string()),
-            line_start: Some(42),
-            line_end: Some(45),
+        let request = |file_path: &str, line_start: i32, line_end: i32| CreateFindingRequest {
+            file_path: Some(file_path.to_string()),
+            line_start: Some(line_start),
+            line_end: Some(line_end),
             title: "Missing error handling".to_string(),
             description: "Function should handle errors".to_string(),
             severity: "error".to_string(),
+            reproduction: None,
+            acceptance_criteria: Some("Invalid input returns an error".to_string()),
+            suggested_fix: None,
         };
 
-        let result = service.create_finding(Parameters(request)).await.unwrap();
+        let result = service
+            .create_finding(Parameters(request("src/main.rs", 42, 45)))
+            .await
+            .unwrap();
+        assert_ne!(result.is_error, Some(true));
 
-        assert!(matches!(result, CallToolResult { .. }));
+        // The range is clamped to the file, but may not start past its end
+        let clamped = service
+            .create_finding(Parameters(request("src/main.rs", 49, 80)))
+            .await
+            .unwrap();
+        assert!(format!("{:?}", clamped.content).contains("adjusted to 49-50"));
+        for (path, start) in [("src/main.rs", 51), ("src/missing.rs", 1), ("../x.rs", 1)] {
+            let rejected = service
+                .create_finding(Parameters(request(path, start, start)))
+                .await
+                .unwrap();
+            assert_eq!(rejected.is_error, Some(true), "{}", path);
+        }
 
         // Check findings
         let findings = service.get_findings().await;
-        assert_eq!(findings.findings.len(), 1);
+        assert_eq!(findings.findings.len(), 2);
         assert_eq!(findings.findings[0].title, "Missing error handling");
+        assert_eq!(
+            findings.findings[0].snippet.as_deref(),
+            Some("42 | line 42\n43 | line 43\n44 | line 44\n45 | line 45\n")
+        );
+        assert_eq!(findings.findings[1].line_end, Some(50));
+
+        std::fs::remove_dir_all(&workspace).ok();
+    }
+
+    #[tokio::test]
+    async fn test_create_error_finding_requires_acceptance_criteria() {
+        let service =
+            FindingsService::new(Uuid::new_v4(), Uuid::new_v4(), PathBuf::from("/tmp/test"));
+        let request = |severity: &str| CreateFindingRequest {
+            file_path: None,
+            line_start: None,
+            line_end: None,
+            title: "Race in cache".to_string(),
+            description: "Two writers can interleave".to_string(),
+            severity: severity.to_string(),
+            reproduction: Some("Run the stress test".to_string()),
+            acceptance_criteria: None,
+            suggested_fix: None,
+        };
+
+        let rejected = service
+            .create_finding(Parameters(request("error")))
+            .await
+            .unwrap();
+        assert_eq!(rejected.is_error, Some(true));
+
+        service
+            .create_finding(Parameters(request("warning")))
+            .await
+            .unwrap();
+        let findings = service.get_findings().await;
+        assert_eq!(findings.findings.len(), 1);
+        assert_eq!(findings.findings[0].id, "finding-1");
+        assert_eq!(
+            findings.findings[0].reproduction.as_deref(),
+            Some("Run the stress test")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_finding_comments() {
+        let workspace = std::env::temp_dir().join(format!("findings-{}", Uuid::new_v4()));
+        let task_id = Uuid::new_v4();
+        let service = FindingsService::new(task_id, Uuid::new_v4(), workspace.clone());
+        service
+            .create_finding(Parameters(CreateFindingRequest {
+                file_path: None,
+                line_start: None,
+                line_end: None,
+                title: "Unbounded retry".to_string(),
+                description: "Retries forever".to_string(),
+                severity: "warning".to_string(),
+                reproduction: None,
+                acceptance_criteria: None,
+                suggested_fix: None,
+            }))
+            .await
+            .unwrap();
+
+        let comment = |body: &str| {
+            Parameters(AddFindingCommentRequest {
+                finding_id: "finding-1".to_string(),
+                body: body.to_string(),
+            })
+        };
+        service
+            .add_finding_comment(comment("Capped at five attempts"))
+            .await
+            .unwrap();
+        service.save_findings().await.unwrap();
+
+        // A later session comments on the saved finding
+        let fix_session = FindingsService::new(task_id, Uuid::new_v4(), workspace.clone());
+        fix_session
+            .add_finding_comment(comment("Kept the cap, added a test"))
+            .await
+            .unwrap();
+        let missing = fix_session
+            .add_finding_comment(Parameters(AddFindingCommentRequest {
+                finding_id: "finding-9".to_string(),
+                body: "?".to_string(),
+            }))
+            .await
+            .unwrap();
+        assert!(format!("{:?}", missing.content).contains("not found"));
+
+        let finding = fix_session.find_finding("finding-1").await.unwrap();
+        assert_eq!(finding.comments.len(), 2);
+        assert_eq!(finding.comments[1].author, FindingCommentAuthor::Agent);
+        assert!(finding_details(&finding).contains("Kept the cap, added a test"));
+
+        std::fs::remove_dir_all(&workspace).ok();
+    }
+
+    #[tokio::test]
+    async fn test_apply_suggestion() {
+        let root = std::env::temp_dir().join(format!("findings-{}", Uuid::new_v4()));
+        let project = root.join("project");
+        let workspace = root.join("workspace");
+        for dir in [&project, &workspace] {
+            std::fs::create_dir_all(dir.join("src")).unwrap();
+            std::fs::write(dir.join("src/lib.rs"), "fn parse() {\n    todo!()\n}\n").unwrap();
+        }
+        let service = FindingsService::new(Uuid::new_v4(), Uuid::new_v4(), project.clone())
+            .with_workspace_path(workspace.clone());
+
+        let request = |suggested_fix: Option<&str>| CreateFindingRequest {
+            file_path: Some("src/lib.rs".to_string()),
+            line_start: Some(2),
+            line_end: None,
+            title: "Unimplemented parser".to_string(),
+            description: "parse panics".to_string(),
+            severity: "warning".to_string(),
+            reproduction: None,
+            acceptance_criteria: None,
+            suggested_fix: suggested_fix.map(str::to_string),
+        };
+        let patch = "--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -1,3 +1,3 @@\n fn parse() {\n-    todo!()\n+    Ok(())\n }\n";
+        service
+            .create_finding(Parameters(request(Some(patch))))
+            .await
+            .unwrap();
+        service
+            .create_finding(Parameters(request(None)))
+            .await
+            .unwrap();
+
+        let apply = |id: &str| ApplySuggestionRequest {
+            finding_id: id.to_string(),
+        };
+        let applied = service
+            .apply_suggestion(Parameters(apply("finding-1")))
+            .await
+            .unwrap();
+        assert_ne!(applied.is_error, Some(true));
+        assert_eq!(
+            std::fs::read_to_string(workspace.join("src/lib.rs")).unwrap(),
+            "fn parse() {\n    Ok(())\n}\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(project.join("src/lib.rs")).unwrap(),
+            "fn parse() {\n    todo!()\n}\n"
+        );
+
+        // Applying again no longer matches the file
+        let stale = service
+            .apply_suggestion(Parameters(apply("finding-1")))
+            .await
+            .unwrap();
+        assert_eq!(stale.is_error, Some(true));
+        let missing = service
+            .apply_suggestion(Parameters(apply("finding-2")))
+            .await
+            .unwrap();
+        assert_eq!(missing.is_error, Some(true));
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    fn git(dir: &std::path::Path, args: &[&str]) {
+        let status = std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    #[tokio::test]
+    async fn test_get_diff_of_worktree() {
+        let root = std::env::temp_dir().join(format!("findings-{}", Uuid::new_v4()));
+        let project = root.join("project");
+        std::fs::create_dir_all(project.join("src")).unwrap();
+        std::fs::write(project.join("src/lib.rs"), "pub fn a() {}\n").unwrap();
+        std::fs::write(project.join("README.md"), "# Project\n").unwrap();
+        git(&project, &["init", "-q", "-b", "main"]);
+        git(&project, &["config", "user.name", "Test"]);
+        git(&project, &["config", "user.email", "test@example.com"]);
+        git(&project, &["add", "-A"]);
+        git(&project, &["commit", "-q", "-m", "Initial"]);
+
+        let task_id = Uuid::new_v4();
+        let worktree = root.join("worktree");
+        let branch = format!("task-{}", task_id);
+        let worktree_arg = worktree.to_string_lossy().to_string();
+        git(
+            &project,
+            &["worktree", "add", "-q", "-b", &branch, &worktree_arg],
+        );
+        std::fs::write(worktree.join("src/lib.rs"), "pub fn b() {}\n").unwrap();
+        std::fs::write(worktree.join("README.md"), "# Renamed\n").unwrap();
+
+        let service =
+            FindingsService::new(task_id, Uuid::new_v4(), project).with_workspace_path(worktree);
+        let diff = |file_path: Option<&str>| {
+            let service = service.clone();
+            let file_path = file_path.map(str::to_string);
+            async move {
+                let result = service
+                    .get_diff(Parameters(GetDiffRequest { file_path }))
+                    .await
+                    .unwrap();
+                assert_ne!(result.is_error, Some(true));
+                format!("{:?}", result.content)
+            }
+        };
+
+        let all = diff(None).await;
+        assert!(all.contains("pub fn b()") && all.contains("# Renamed"));
+        let lib = diff(Some("./src/lib.rs")).await;
+        assert!(lib.contains("pub fn b()") && !lib.contains("# Renamed"));
+        assert!(diff(Some("src/main.rs"))
+            .await
+            .contains("No changes to src/main.rs"));
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    #[tokio::test]
+    async fn test_get_diff_of_copy_workspace() {
+        let root = std::env::temp_dir().join(format!("findings-{}", Uuid::new_v4()));
+        let project = root.join("project");
+        std::fs::create_dir_all(project.join("src")).unwrap();
+        std::fs::write(project.join("src/lib.rs"), "pub fn a() {}\n").unwrap();
+        git(&project, &["init", "-q", "-b", "main"]);
+        git(&project, &["config", "user.name", "Test"]);
+        git(&project, &["config", "user.email", "test@example.com"]);
+        git(&project, &["add", "-A"]);
+        git(&project, &["commit", "-q", "-m", "Initial"]);
+
+        let task_id = Uuid::new_v4();
+        let workspace = WorkspaceManager::detect(
+            &project,
+            WorkspaceConfig::new(root.join("workspaces")).with_backend(WorkspaceBackend::Copy),
+        )
+        .setup_workspace(&task_id.to_string())
+        .await
+        .unwrap();
+        assert!(!workspace.path.join(".git").exists());
+        std::fs::write(workspace.path.join("src/lib.rs"), "pub fn b() {}\n").unwrap();
+
+        let service = FindingsService::new(task_id, Uuid::new_v4(), project)
+            .with_workspace_path(workspace.path.clone())
+            .with_workspace_backend(WorkspaceBackend::Copy);
+        let result = service
+            .get_diff(Parameters(GetDiffRequest { file_path: None }))
+            .await
+            .unwrap();
+        assert_ne!(result.is_error, Some(true));
+        assert!(format!("{:?}", result.content).contains("pub fn b()"));
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    #[test]
+    fn test_truncate_diff_at_file_boundary() {
+        let file = |name: &str, size: usize| {
+            format!(
+                "diff --git a/{0} b/{0}\n--- a/{0}\n+++ b/{0}\n+{1}\n",
+                name,
+                "x".repeat(size)
+            )
+        };
+        let short = file("a.rs", 10);
+        assert_eq!(truncate_diff(&short), short);
+
+        let diff = format!(
+            "{}{}{}",
+            file("a.rs", 10),
+            file("b.rs", MAX_DIFF_CHARS),
+            file("c.rs", 10)
+        );
+        let truncated = truncate_diff(&diff);
+        assert!(truncated.starts_with(&file("a.rs", 10)));
+        assert!(truncated.contains("left out: b.rs, c.rs"));
+    }
+
+    #[test]
+    fn test_allowed_tools_hide_others() {
+        let service =
+            FindingsService::new(Uuid::new_v4(), Uuid::new_v4(), PathBuf::from("/tmp/test"))
+                .with_allowed_tools(ToolAllowlist::parse("list_findings,mark_fixed"));
+
+        let offered: Vec<_> = service
+            .tool_router
+            .list_all()
+            .into_iter()
+            .filter(|tool| service.allowed_tools.allows(&tool.name))
+            .map(|tool| tool.name.to_string())
+            .collect();
+        assert_eq!(offered.len(), 2);
+        assert!(offered.contains(&"mark_fixed".to_string()));
+        assert!(!offered.contains(&"create_finding".to_string()));
     }
 }
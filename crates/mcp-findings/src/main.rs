@@ -7,14 +7,19 @@
 //! - OPENCODE_TASK_ID: UUID of the task being reviewed
 //! - OPENCODE_SESSION_ID: UUID of the review session
 //! - OPENCODE_WORKSPACE_PATH: Path to the workspace directory (worktree)
+//! - OPENCODE_WORKSPACE_BACKEND: How the workspace was created, native or copy (default: native)
 //! - OPENCODE_PROJECT_PATH: Path to the main project directory (for storing findings)
+//! - OPENCODE_MCP_ALLOWED_TOOLS: Comma-separated tools the session may call (default: all)
+//! - OPENCODE_MCP_CALL_LOG: File to record tool calls in (default: none)
 
 use anyhow::{Context, Result};
-use mcp_findings::FindingsService;
+use mcp_findings::{FindingsService, ToolCallLog};
+use opencode_core::{ToolAllowlist, ALLOWED_TOOLS_ENV, TOOL_CALL_LOG_ENV};
 use rmcp::{transport::stdio, ServiceExt};
 use std::path::PathBuf;
 use tracing::info;
 use uuid::Uuid;
+use vcs::WorkspaceBackend;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -44,23 +49,45 @@ async fn main() -> Result<()> {
         .context("OPENCODE_WORKSPACE_PATH environment variable not set")?;
     let workspace_path = PathBuf::from(workspace_path);
 
+    let workspace_backend = match std::env::var("OPENCODE_WORKSPACE_BACKEND") {
+        Ok(backend) => WorkspaceBackend::parse(&backend).with_context(|| {
+            format!(
+                "OPENCODE_WORKSPACE_BACKEND '{}' is not native or copy",
+                backend
+            )
+        })?,
+        Err(_) => WorkspaceBackend::default(),
+    };
+
     // Project path is where findings are stored (main repo, not worktree)
     // Falls back to workspace_path if not set for backwards compatibility
     let project_path = std::env::var("OPENCODE_PROJECT_PATH")
         .map(PathBuf::from)
         .unwrap_or_else(|_| workspace_path.clone());
 
+    let allowed_tools = std::env::var(ALLOWED_TOOLS_ENV).ok();
+    let call_log = std::env::var(TOOL_CALL_LOG_ENV)
+        .ok()
+        .filter(|path| !path.trim().is_empty())
+        .map(ToolCallLog::new)
+        .unwrap_or_default();
+
     info!(
         task_id = %task_id,
         session_id = %session_id,
         workspace_path = %workspace_path.display(),
+        workspace_backend = workspace_backend.as_str(),
         project_path = %project_path.display(),
         "Starting MCP Findings Server"
     );
 
     // Create the service and start serving
     // Use project_path for storing findings (not workspace which is a worktree)
-    let service = FindingsService::new(task_id, session_id, project_path);
+    let service = FindingsService::new(task_id, session_id, project_path)
+        .with_workspace_path(workspace_path)
+        .with_workspace_backend(workspace_backend)
+        .with_allowed_tools(ToolAllowlist::new(allowed_tools.as_deref()))
+        .with_call_log(call_log);
     let server = service.serve(stdio()).await?;
 
     info!("MCP Findings Server running");
@@ -0,0 +1,213 @@
+//! Guidance for tool calls against a missing or empty index
+//!
+//! Searching a branch that was never indexed returns nothing, which reads
+//! like "no such code" to an agent. Tools check the index status first and
+//! answer with what is wrong and how to fix it instead.
+
+use serde::Serialize;
+use wiki::{IndexState, IndexStatus};
+
+/// Why a project's index cannot answer a tool call, and what to do about it
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct IndexGuidance {
+    pub project: String,
+    /// Branch the call asked for; `None` for calls across all branches
+    pub branch: Option<String>,
+    /// `not_indexed`, `indexing`, `failed` or `empty`
+    pub state: &'static str,
+    pub message: String,
+    /// Progress of a running indexing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress_percent: Option<u8>,
+    /// Branches that can be searched instead
+    pub indexed_branches: Vec<String>,
+    pub next_steps: Vec<String>,
+}
+
+/// A status whose chunks can be searched
+fn is_searchable(status: &IndexStatus) -> bool {
+    status.chunk_count > 0 && status.state != IndexState::NotIndexed
+}
+
+impl IndexGuidance {
+    /// Guidance when `branch`, or every branch when `None`, has nothing to
+    /// search in `statuses`; `None` when it does. `can_trigger` tells
+    /// whether the `trigger_indexing` tool is available.
+    pub fn check(
+        project: &str,
+        branch: Option<&str>,
+        statuses: &[IndexStatus],
+        can_trigger: bool,
+    ) -> Option<Self> {
+        let indexed_branches: Vec<String> = statuses
+            .iter()
+            .filter(|s| is_searchable(s))
+            .map(|s| s.branch.clone())
+            .collect();
+
+        let relevant: Vec<&IndexStatus> = match branch {
+            Some(branch) => statuses.iter().filter(|s| s.branch == branch).collect(),
+            None => statuses.iter().collect(),
+        };
+        if relevant.iter().any(|s| is_searchable(s)) {
+            return None;
+        }
+
+        let scope = match branch {
+            Some(branch) => format!("Branch '{}'", branch),
+            None => format!("Project '{}'", project),
+        };
+        let target = branch.unwrap_or("main");
+        let mut next_steps = Vec::new();
+
+        let (state, message, progress_percent) = if let Some(running) =
+            relevant.iter().find(|s| s.is_indexing())
+        {
+            next_steps
+                .push("Retry once indexing finishes; follow it with get_index_status".to_string());
+            (
+                "indexing",
+                format!(
+                    "{} is being indexed ({}% done) and has nothing to search yet.",
+                    scope, running.progress_percent
+                ),
+                Some(running.progress_percent),
+            )
+        } else {
+            let (state, message) = match relevant.iter().find(|s| s.state == IndexState::Failed) {
+                Some(failed) => (
+                    "failed",
+                    format!(
+                        "{} failed to index: {}",
+                        scope,
+                        failed.error_message.as_deref().unwrap_or("unknown error")
+                    ),
+                ),
+                None if relevant.is_empty() => {
+                    ("not_indexed", format!("{} has not been indexed.", scope))
+                }
+                None => ("empty", format!("{} was indexed without any code.", scope)),
+            };
+
+            if branch.is_some() && !indexed_branches.is_empty() {
+                next_steps.push(format!(
+                    "Pass one of the indexed branches instead: {}",
+                    indexed_branches.join(", ")
+                ));
+            }
+            if state == "failed" {
+                next_steps.push(
+                    "Fix the cause of the failure, e.g. the OpenRouter API key, then index again"
+                        .to_string(),
+                );
+            }
+            next_steps.push(format!(
+                "Ask the user to index branch '{}' from the Wiki page of OpenCode Studio",
+                target
+            ));
+            next_steps.push(format!(
+                "Or index it through the API: POST /api/wiki/index {{\"branch\": \"{}\"}}",
+                target
+            ));
+            if can_trigger {
+                next_steps.push(format!(
+                    "Call trigger_indexing with branch '{}' to index it from this server",
+                    target
+                ));
+            }
+            (state, message, None)
+        };
+
+        Some(Self {
+            project: project.to_string(),
+            branch: branch.map(str::to_string),
+            state,
+            message,
+            progress_percent,
+            indexed_branches,
+            next_steps,
+        })
+    }
+
+    /// The guidance as text for the agent
+    pub fn render(&self) -> String {
+        let mut output = format!(
+            "Wiki index unavailable ({})\n\n{}\n",
+            self.state, self.message
+        );
+        if !self.next_steps.is_empty() {
+            output.push_str("\nNext steps:\n");
+            for step in &self.next_steps {
+                output.push_str(&format!("- {}\n", step));
+            }
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(branch: &str, state: IndexState, chunk_count: u32) -> IndexStatus {
+        let mut status = IndexStatus::new(branch.to_string());
+        status.state = state;
+        status.chunk_count = chunk_count;
+        status
+    }
+
+    #[test]
+    fn test_no_guidance_for_searchable_index() {
+        let statuses = vec![status("main", IndexState::Indexed, 10)];
+        assert!(IndexGuidance::check("studio", Some("main"), &statuses, false).is_none());
+        assert!(IndexGuidance::check("studio", None, &statuses, false).is_none());
+        // A stale index can still be searched
+        let stale = vec![status("main", IndexState::Stale, 10)];
+        assert!(IndexGuidance::check("studio", None, &stale, false).is_none());
+    }
+
+    #[test]
+    fn test_guidance_for_missing_branch() {
+        let statuses = vec![status("main", IndexState::Indexed, 10)];
+        let guidance = IndexGuidance::check("studio", Some("develop"), &statuses, true).unwrap();
+
+        assert_eq!(guidance.state, "not_indexed");
+        assert_eq!(guidance.indexed_branches, vec!["main"]);
+        assert!(guidance.next_steps[0].contains("indexed branches instead: main"));
+        assert!(guidance
+            .next_steps
+            .iter()
+            .any(|s| s.contains("trigger_indexing with branch 'develop'")));
+
+        let text = guidance.render();
+        assert!(text.starts_with("Wiki index unavailable (not_indexed)"));
+        assert!(text.contains("Branch 'develop' has not been indexed."));
+    }
+
+    #[test]
+    fn test_guidance_states() {
+        let mut running = status("main", IndexState::Indexing, 0);
+        running.progress_percent = 40;
+        let guidance = IndexGuidance::check("studio", None, &[running], false).unwrap();
+        assert_eq!(guidance.state, "indexing");
+        assert_eq!(guidance.progress_percent, Some(40));
+
+        let mut failed = status("main", IndexState::Failed, 0);
+        failed.error_message = Some("API key not configured".to_string());
+        let guidance = IndexGuidance::check("studio", None, &[failed], false).unwrap();
+        assert_eq!(guidance.state, "failed");
+        assert!(guidance.message.ends_with("API key not configured"));
+        assert!(!guidance
+            .next_steps
+            .iter()
+            .any(|s| s.contains("trigger_indexing")));
+
+        let empty = status("main", IndexState::Indexed, 0);
+        let guidance = IndexGuidance::check("studio", None, &[empty], false).unwrap();
+        assert_eq!(guidance.state, "empty");
+
+        let guidance = IndexGuidance::check("studio", None, &[], false).unwrap();
+        assert_eq!(guidance.state, "not_indexed");
+        assert_eq!(guidance.message, "Project 'studio' has not been indexed.");
+    }
+}
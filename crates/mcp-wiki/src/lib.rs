@@ -5,28 +5,67 @@
 //! a codebase using semantic search and RAG (Retrieval-Augmented Generation).
 //!
 //! The server exposes tools like:
-//! - `search_code` - Semantic search for code chunks
+//! - `search_code` - Semantic, keyword or hybrid search for code chunks
 //! - `get_documentation` - Retrieve wiki pages by slug
-//! - `ask_codebase` - RAG Q&A over the codebase
+//! - `ask_codebase` - RAG Q&A over the codebase, streamed as progress
+//!   notifications when the call carries a progress token
 //! - `list_wiki_pages` - List all wiki pages and structure
-
+//! - `find_untested_modules` - Source modules without associated tests
+//! - `trace_flow` - Step-by-step documentation of a request path
+//! - `recall_related_work` - Earlier tasks, findings and sessions similar to a query
+//! - `list_indexed_files` - Indexed files with their chunk counts
+//! - `list_projects` - Projects this server serves, see [`projects`]
+//! - `trigger_indexing` - Index a branch, when `OPENCODE_WIKI_ALLOW_INDEXING` is set
+//! - `regenerate_page` - Regenerate one wiki page from the files it references
+//!
+//! Tools called against a missing or empty index answer with an
+//! [`IndexGuidance`] rather than an empty result.
+
+mod call_log;
+mod guidance;
+pub mod projects;
+
+pub use call_log::ToolCallLog;
+use chrono::Utc;
+pub use guidance::IndexGuidance;
+use opencode_core::{McpToolCall, ToolAllowlist};
+pub use projects::{ProjectRegistry, RegistryError, WikiProject};
 use rmcp::{
-    handler::server::{router::tool::ToolRouter, tool::Parameters},
+    handler::server::{
+        router::tool::ToolRouter,
+        tool::{Parameters, ToolCallContext},
+    },
     model::{ErrorData as McpError, *},
-    schemars, tool, tool_handler, tool_router, ServerHandler,
+    schemars,
+    service::RequestContext,
+    tool, tool_router, Peer, RoleServer, ServerHandler,
 };
 use serde::Deserialize;
 use std::borrow::Cow;
 use std::future::Future;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::sync::Mutex;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
+
 use wiki::{
-    ChatMessage, Conversation, OpenRouterClient, RagSource, SearchResult, VectorStore, WikiConfig,
-    WikiPage, WikiStructure,
+    AnswerMode, BranchLockAttempt, BranchLockGuard, CallGraphExpansion, ChatMessage, CodeIndexer,
+    Conversation, EmbeddingProvider, EmbeddingProviderKind, FlowDocument, FlowTracer, IndexedFile,
+    OpenRouterClient, RagSource, RecallIndex, RecallMatch, RecallScope, SearchFilter, SearchMode,
+    SearchResult, SearchScope, TestCoverageMap, VectorStore, WikiConfig, WikiGenerator, WikiPage,
+    WikiStructure,
 };
 
+/// Maximum number of sources listed under an answer
+const MAX_LISTED_SOURCES: usize = 8;
+
+/// Indexed files listed per call unless the caller asks for another limit
+const DEFAULT_INDEXED_FILES_LIMIT: usize = 100;
+
+/// Most indexed files listed per call
+const MAX_INDEXED_FILES_LIMIT: usize = 500;
+
 /// Request to search for code
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct SearchCodeRequest {
@@ -37,6 +76,54 @@ pub struct SearchCodeRequest {
     /// Maximum number of results to return (default: 10)
     #[schemars(description = "Maximum number of results to return (1-50, default: 10)")]
     pub limit: Option<usize>,
+
+    /// How results are matched (default: semantic)
+    #[schemars(
+        description = "Matching: 'semantic' embedding similarity (default), 'keyword' exact terms such as identifiers, or 'hybrid' both merged"
+    )]
+    pub mode: Option<String>,
+
+    /// Most results from one file (default: no cap)
+    #[schemars(description = "Most results to return from one file (default: no cap)")]
+    pub max_per_file: Option<usize>,
+
+    /// Overlap share above which a result of the same file is dropped
+    #[schemars(
+        description = "Drop results whose lines overlap a better result of the same file by at least this share of the shorter range, 0.0-1.0 (default: keep overlaps)"
+    )]
+    pub dedup_overlap: Option<f32>,
+
+    /// Scale scores relative to the best result (default: false)
+    #[schemars(description = "Scale scores so the best result scores 100% (default: false)")]
+    pub normalize_scores: Option<bool>,
+
+    /// Workspace root to search in (default: every root)
+    #[schemars(
+        description = "Only search code under this configured workspace root of a monorepo, e.g. 'backend' (default: every root)"
+    )]
+    pub root: Option<String>,
+
+    /// Only return public API code (default: false)
+    #[schemars(
+        description = "Only return code tagged as public API: HTTP handlers, exported items and CLI commands (default: false)"
+    )]
+    pub public_api_only: Option<bool>,
+
+    /// Glob the file path must match (default: every file)
+    #[schemars(
+        description = "Only search files whose path matches this glob, e.g. 'crates/server/**' or '**/*.rs'; '*' also matches '/' (default: every file)"
+    )]
+    pub path_glob: Option<String>,
+
+    /// Language of the code (default: every language)
+    #[schemars(
+        description = "Only search code in this language, e.g. 'rust' or 'typescript'; file extensions such as 'rs' work too (default: every language)"
+    )]
+    pub language: Option<String>,
+
+    /// Project to use (default: the server's default project)
+    #[schemars(description = "Project to use, see list_projects (default: the default project)")]
+    pub project: Option<String>,
 }
 
 /// Request to get documentation page
@@ -45,6 +132,10 @@ pub struct GetDocumentationRequest {
     /// The slug of the wiki page to retrieve
     #[schemars(description = "The slug/path of the wiki page (e.g., 'overview', 'modules/auth')")]
     pub slug: String,
+
+    /// Project to use (default: the server's default project)
+    #[schemars(description = "Project to use, see list_projects (default: the default project)")]
+    pub project: Option<String>,
 }
 
 /// Request to ask a question about the codebase
@@ -57,6 +148,22 @@ pub struct AskCodebaseRequest {
     /// Conversation ID for multi-turn Q&A (optional)
     #[schemars(description = "Conversation ID to continue a previous conversation")]
     pub conversation_id: Option<String>,
+
+    /// Shape of the answer (default: standard)
+    #[schemars(
+        description = "Answer shape: 'concise' summary, 'detailed' walkthrough, 'code_only' snippets, or 'standard' (default)"
+    )]
+    pub mode: Option<String>,
+
+    /// Workspace root to answer from (default: every root)
+    #[schemars(
+        description = "Only answer from code under this configured workspace root of a monorepo, e.g. 'backend' (default: every root)"
+    )]
+    pub root: Option<String>,
+
+    /// Project to use (default: the server's default project)
+    #[schemars(description = "Project to use, see list_projects (default: the default project)")]
+    pub project: Option<String>,
 }
 
 /// Request to list wiki pages
@@ -65,36 +172,302 @@ pub struct ListWikiPagesRequest {
     /// Branch to list pages for (default: main)
     #[schemars(description = "Git branch to list pages for (default: main)")]
     pub branch: Option<String>,
+
+    /// Project to use (default: the server's default project)
+    #[schemars(description = "Project to use, see list_projects (default: the default project)")]
+    pub project: Option<String>,
+}
+
+/// Request to find source modules without tests
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct FindUntestedModulesRequest {
+    /// Branch to analyze (default: main)
+    #[schemars(description = "Git branch to analyze (default: main)")]
+    pub branch: Option<String>,
+
+    /// Only report these files (e.g. the files changed in a diff)
+    #[schemars(
+        description = "Only report these file paths, e.g. the files changed in a diff (default: all modules)"
+    )]
+    pub file_paths: Option<Vec<String>>,
+
+    /// Project to use (default: the server's default project)
+    #[schemars(description = "Project to use, see list_projects (default: the default project)")]
+    pub project: Option<String>,
+}
+
+/// Request to trace a request path across modules
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct TraceFlowRequest {
+    /// HTTP method of the request
+    #[schemars(description = "HTTP method of the request, e.g. POST")]
+    pub method: String,
+
+    /// Request path
+    #[schemars(description = "Request path, e.g. /api/tasks/{id}/execute")]
+    pub path: String,
+
+    /// Branch to trace in (default: main)
+    #[schemars(description = "Git branch to trace in (default: main)")]
+    pub branch: Option<String>,
+
+    /// Save the flow document as a wiki page
+    #[schemars(description = "Save the flow document as a wiki page (default: false)")]
+    pub persist: Option<bool>,
+
+    /// Project to use (default: the server's default project)
+    #[schemars(description = "Project to use, see list_projects (default: the default project)")]
+    pub project: Option<String>,
+}
+
+/// Request to recall earlier work related to a query
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct RecallRelatedWorkRequest {
+    /// What to look for, e.g. a bug description or the task at hand
+    #[schemars(
+        description = "Description of the problem or change to find related earlier work for"
+    )]
+    pub query: String,
+
+    /// Only search one kind of work
+    #[schemars(description = "Only search 'tasks', 'findings' or 'sessions' (default: all)")]
+    pub scope: Option<String>,
+
+    /// Maximum number of results to return (default: 5)
+    #[schemars(description = "Maximum number of results to return (1-20, default: 5)")]
+    pub limit: Option<usize>,
+
+    /// Project to use (default: the server's default project)
+    #[schemars(description = "Project to use, see list_projects (default: the default project)")]
+    pub project: Option<String>,
 }
 
+/// Request to list indexed files
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListIndexedFilesRequest {
+    /// Branch to list files for (default: main)
+    #[schemars(description = "Git branch to list indexed files for (default: main)")]
+    pub branch: Option<String>,
+
+    /// Only list files under this path
+    #[schemars(
+        description = "Only list files whose path starts with this prefix, e.g. 'src/api/'"
+    )]
+    pub path_prefix: Option<String>,
+
+    /// Maximum number of files to return (default: 100)
+    #[schemars(description = "Maximum number of files to return (1-500, default: 100)")]
+    pub limit: Option<usize>,
+
+    /// Number of files to skip
+    #[schemars(description = "Number of files to skip, for paging through results (default: 0)")]
+    pub offset: Option<usize>,
+
+    /// Project to use (default: the server's default project)
+    #[schemars(description = "Project to use, see list_projects (default: the default project)")]
+    pub project: Option<String>,
+}
+
+/// Request to index a branch from this server
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct TriggerIndexingRequest {
+    /// Branch to index (default: main)
+    #[schemars(description = "Git branch name to index the checkout as (default: main)")]
+    pub branch: Option<String>,
+
+    /// Clear the branch before indexing it again
+    #[schemars(
+        description = "Clear the branch and index it from scratch; a running job is never restarted (default: false)"
+    )]
+    pub force: Option<bool>,
+
+    /// Project to use (default: the server's default project)
+    #[schemars(description = "Project to use, see list_projects (default: the default project)")]
+    pub project: Option<String>,
+}
+
+/// Request to regenerate one wiki page
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct RegeneratePageRequest {
+    /// Slug of the page to regenerate
+    #[schemars(description = "Slug of the stale page, see list_wiki_pages")]
+    pub slug: String,
+
+    /// Branch of the page (default: main)
+    #[schemars(description = "Git branch name the page belongs to (default: main)")]
+    pub branch: Option<String>,
+
+    /// Project to use (default: the server's default project)
+    #[schemars(description = "Project to use, see list_projects (default: the default project)")]
+    pub project: Option<String>,
+}
+
+/// Name of the tool only offered when indexing from this server is allowed
+const TRIGGER_INDEXING_TOOL: &str = "trigger_indexing";
+
 /// Wiki MCP Service
 #[derive(Clone)]
 pub struct WikiService {
     openrouter: Arc<OpenRouterClient>,
+    embeddings: Arc<dyn EmbeddingProvider>,
     conversations: Arc<Mutex<std::collections::HashMap<String, Conversation>>>,
-    config: WikiConfig,
+    projects: Arc<ProjectRegistry>,
     tool_router: ToolRouter<WikiService>,
+    /// Tools the session's phase may call
+    allowed_tools: ToolAllowlist,
+    /// Whether `trigger_indexing` is offered
+    indexing_enabled: bool,
+    call_log: ToolCallLog,
+    /// Session the server is attached to, for the call log
+    session_id: Option<String>,
 }
 
 impl WikiService {
     /// Create a new WikiService with the given configuration
     pub fn new(config: WikiConfig) -> Result<Self, wiki::WikiError> {
         // Verify database can be opened (creates if needed)
-        let _ = VectorStore::new(&config.db_path)?;
+        let _ = config.open_vector_store()?;
 
         let openrouter = OpenRouterClient::new(
             config.openrouter_api_key.clone(),
             config.api_base_url.clone(),
         );
+        let embeddings = config.embedding_provider_config().build()?;
 
         Ok(Self {
             openrouter: Arc::new(openrouter),
+            embeddings,
             conversations: Arc::new(Mutex::new(std::collections::HashMap::new())),
-            config,
+            projects: Arc::new(ProjectRegistry::single(&config)),
             tool_router: Self::tool_router(),
+            allowed_tools: ToolAllowlist::all(),
+            indexing_enabled: false,
+            call_log: ToolCallLog::disabled(),
+            session_id: None,
         })
     }
 
+    /// Only offer the tools in `allowed_tools`
+    pub fn with_allowed_tools(mut self, allowed_tools: ToolAllowlist) -> Self {
+        self.allowed_tools = allowed_tools;
+        self
+    }
+
+    /// Offer the `trigger_indexing` tool, which indexes a project's checkout
+    /// from this process
+    pub fn with_indexing(mut self, enabled: bool) -> Self {
+        self.indexing_enabled = enabled;
+        self
+    }
+
+    /// Record the tool calls this service handles in `call_log`, as made
+    /// by `session_id`
+    pub fn with_call_log(mut self, call_log: ToolCallLog, session_id: Option<String>) -> Self {
+        self.call_log = call_log;
+        self.session_id = session_id;
+        self
+    }
+
+    fn record_call(
+        &self,
+        tool: &str,
+        called_at: chrono::DateTime<Utc>,
+        started: Instant,
+        result: &Result<CallToolResult, McpError>,
+    ) {
+        let call = McpToolCall::new(
+            "opencode-wiki",
+            tool,
+            self.session_id.clone(),
+            called_at,
+            started.elapsed(),
+            call_error(result),
+        );
+        if let Err(e) = self.call_log.record(&call) {
+            warn!(error = %e, tool = %tool, "Failed to record tool call");
+        }
+    }
+
+    /// Whether a tool is offered to the session
+    fn tool_enabled(&self, name: &str) -> bool {
+        self.allowed_tools.allows(name) && (name != TRIGGER_INDEXING_TOOL || self.indexing_enabled)
+    }
+
+    /// Guidance to return instead of a result when `branch` of `project`,
+    /// or every branch when `None`, has no searchable index
+    async fn missing_index(
+        &self,
+        project: &WikiProject,
+        branch: Option<&str>,
+    ) -> Result<Option<CallToolResult>, McpError> {
+        let db_path = project.db_path.clone();
+        let statuses = tokio::task::spawn_blocking(move || {
+            VectorStore::open_read_only(&db_path)?.get_index_statuses()
+        })
+        .await
+        .map_err(|e| McpError {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("Task join error: {}", e)),
+            data: None,
+        })?
+        .map_err(|e| McpError {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("Failed to get index status: {}", e)),
+            data: None,
+        })?;
+
+        let can_trigger = self.tool_enabled(TRIGGER_INDEXING_TOOL);
+        let Some(guidance) = IndexGuidance::check(&project.name, branch, &statuses, can_trigger)
+        else {
+            return Ok(None);
+        };
+        debug!(project = %project.name, state = guidance.state, "Index unavailable");
+        Ok(Some(CallToolResult::success(vec![
+            Content::text(guidance.render()),
+            Content::json(&guidance)?,
+        ])))
+    }
+
+    /// Serve the projects of `projects` instead of the configured database
+    pub fn with_projects(mut self, projects: ProjectRegistry) -> Self {
+        self.projects = Arc::new(projects);
+        self
+    }
+
+    /// The project a tool call names, or the default one
+    fn project(&self, name: Option<&str>) -> Result<WikiProject, McpError> {
+        self.projects
+            .resolve(name)
+            .cloned()
+            .map_err(|message| McpError {
+                code: ErrorCode(-32602),
+                message: Cow::from(message),
+                data: None,
+            })
+    }
+
+    /// Format the served projects as text
+    fn format_projects(projects: &[WikiProject]) -> String {
+        let mut output = format!("{} projects available:\n\n", projects.len());
+        for (i, project) in projects.iter().enumerate() {
+            let default = if i == 0 { " (default)" } else { "" };
+            let indexed = if project.db_path.exists() {
+                ""
+            } else {
+                ", not indexed yet"
+            };
+            output.push_str(&format!(
+                "- {}{}: {} (embeddings: {}{})\n",
+                project.name,
+                default,
+                project.db_path.display(),
+                project.embedding_model,
+                indexed
+            ));
+        }
+        output
+    }
+
     /// Format search results as text
     fn format_search_results(results: &[SearchResult]) -> String {
         if results.is_empty() {
@@ -116,7 +489,14 @@ impl WikiService {
             if let Some(lang) = &result.language {
                 output.push_str(&format!("Language: {}\n", lang));
             }
-            output.push_str(&format!("Type: {:?}\n\n", result.chunk_type));
+            output.push_str(&format!("Type: {:?}\n", result.chunk_type));
+            if result.is_public_api {
+                output.push_str("Public API: yes\n");
+            }
+            if let Some(summary) = &result.summary {
+                output.push_str(&format!("Summary: {}\n", summary));
+            }
+            output.push('\n');
 
             // Add code with language hint
             if let Some(lang) = &result.language {
@@ -129,6 +509,28 @@ impl WikiService {
         output
     }
 
+    /// Format recalled work as text
+    fn format_recall_matches(matches: &[RecallMatch]) -> String {
+        if matches.is_empty() {
+            return "No related earlier work found.".to_string();
+        }
+
+        let mut output = format!("Found {} related items of earlier work:\n\n", matches.len());
+        for (i, m) in matches.iter().enumerate() {
+            output.push_str(&format!(
+                "--- {}. [{}] {} ({:.0}% relevance) ---\n",
+                i + 1,
+                m.item.scope.as_str(),
+                m.item.title,
+                m.score * 100.0
+            ));
+            output.push_str(&format!("Task: {}\n\n", m.item.task_id));
+            output.push_str(m.item.content.trim_end());
+            output.push_str("\n\n");
+        }
+        output
+    }
+
     /// Format RAG sources as text
     fn format_sources(sources: &[RagSource]) -> String {
         if sources.is_empty() {
@@ -136,15 +538,28 @@ impl WikiService {
         }
 
         let mut output = "\n\n**Sources:**\n".to_string();
-        for (i, source) in sources.iter().take(5).enumerate() {
-            output.push_str(&format!(
-                "{}. {}:{}-{} ({:.0}% relevance)\n",
-                i + 1,
-                source.file_path,
-                source.start_line,
-                source.end_line,
-                source.score * 100.0
-            ));
+        for (i, source) in sources.iter().take(MAX_LISTED_SOURCES).enumerate() {
+            match &source.expansion {
+                Some(expansion) => output.push_str(&format!(
+                    "{}. {}:{}-{} (added as {})\n",
+                    i + 1,
+                    source.file_path,
+                    source.start_line,
+                    source.end_line,
+                    expansion
+                )),
+                None => output.push_str(&format!(
+                    "{}. {}:{}-{} ({:.0}% relevance)\n",
+                    i + 1,
+                    source.file_path,
+                    source.start_line,
+                    source.end_line,
+                    source.score * 100.0
+                )),
+            }
+            if let Some(summary) = &source.summary {
+                output.push_str(&format!("   {}\n", summary));
+            }
         }
         output
     }
@@ -186,6 +601,94 @@ impl WikiService {
         output
     }
 
+    /// Format the untested modules of a coverage map as text, optionally
+    /// limited to `file_paths`
+    fn format_untested_modules(
+        coverage: &TestCoverageMap,
+        branch: &str,
+        file_paths: Option<&[String]>,
+    ) -> String {
+        let untested: Vec<_> = coverage
+            .untested()
+            .filter(|m| file_paths.map_or(true, |paths| paths.contains(&m.file_path)))
+            .collect();
+
+        let scope = match file_paths {
+            Some(paths) => format!("{} requested files", paths.len()),
+            None => format!("{} source modules", coverage.modules.len()),
+        };
+        if untested.is_empty() {
+            return format!(
+                "All {} on branch '{}' have associated tests.",
+                scope, branch
+            );
+        }
+
+        let mut output = format!(
+            "{} of {} on branch '{}' have no associated tests:\n\n",
+            untested.len(),
+            scope,
+            branch
+        );
+        for module in untested {
+            output.push_str(&format!("- {} ({})\n", module.file_path, module.language));
+        }
+        output
+    }
+
+    /// Format a flow document as text, noting the wiki page it was saved as
+    fn format_flow_document(document: &FlowDocument, saved_slug: Option<&str>) -> String {
+        let mut output = document.content.clone();
+        if let Some(slug) = saved_slug {
+            output.push_str(&format!("\n\n---\nSaved as wiki page '{}'", slug));
+        }
+        output
+    }
+
+    /// Format one page of indexed files as text
+    fn format_indexed_files(
+        files: &[IndexedFile],
+        total: u32,
+        offset: usize,
+        branch: &str,
+        path_prefix: Option<&str>,
+    ) -> String {
+        let scope = match path_prefix {
+            Some(prefix) => format!("under '{}' ", prefix),
+            None => String::new(),
+        };
+        if files.is_empty() {
+            return format!(
+                "No indexed files {}on branch '{}' (total: {}).",
+                scope, branch, total
+            );
+        }
+
+        let mut output = format!(
+            "Indexed files {}on branch '{}' ({}-{} of {}):\n\n",
+            scope,
+            branch,
+            offset + 1,
+            offset + files.len(),
+            total
+        );
+        for file in files {
+            let commit = file.commit_sha.get(..8).unwrap_or(&file.commit_sha);
+            output.push_str(&format!(
+                "- {} ({} chunks, commit {}, indexed {})\n",
+                file.file_path,
+                file.chunk_count,
+                commit,
+                file.indexed_at.format("%Y-%m-%d %H:%M:%S")
+            ));
+        }
+        let next = offset + files.len();
+        if (next as u64) < u64::from(total) {
+            output.push_str(&format!("\nMore files available, use offset {}.", next));
+        }
+        output
+    }
+
     /// Format index status as text
     fn format_index_status(status: &wiki::IndexStatus, branch: &str) -> String {
         let mut output = format!("Index Status for branch '{}'\n\n", branch);
@@ -212,34 +715,120 @@ impl WikiService {
 #[tool_router]
 impl WikiService {
     #[tool(
-        description = "Search for code in the indexed codebase using semantic search. Returns relevant code snippets with file locations."
+        description = "Search for code in the indexed codebase using semantic, keyword or hybrid search, optionally limited to a path glob or language. Chunks defining a function or type named in the query rank first. Returns relevant code snippets with file locations."
     )]
     async fn search_code(
         &self,
         Parameters(request): Parameters<SearchCodeRequest>,
     ) -> Result<CallToolResult, McpError> {
+        let project = self.project(request.project.as_deref())?;
         let limit = request.limit.unwrap_or(10).min(50);
         let query = request.query.clone();
+        let mode = match request.mode.as_deref() {
+            Some(m) => SearchMode::parse(m).ok_or_else(|| McpError {
+                code: ErrorCode(-32602),
+                message: Cow::from(format!(
+                    "Invalid mode '{}'. Use semantic, keyword or hybrid",
+                    m
+                )),
+                data: None,
+            })?,
+            None => SearchMode::default(),
+        };
+        if let Some(overlap) = request.dedup_overlap {
+            if !(0.0..=1.0).contains(&overlap) {
+                return Err(McpError {
+                    code: ErrorCode(-32602),
+                    message: Cow::from("dedup_overlap must be between 0.0 and 1.0"),
+                    data: None,
+                });
+            }
+        }
+        let filter = SearchFilter {
+            max_per_file: request.max_per_file,
+            dedup_overlap: request.dedup_overlap,
+            normalize_scores: request.normalize_scores.unwrap_or(false),
+            public_api_only: request.public_api_only.unwrap_or(false),
+        };
+        let candidates = filter.candidates(limit);
 
-        info!(query = %query, limit = limit, "Searching code");
+        info!(query = %query, limit = limit, mode = mode.as_str(), "Searching code");
 
-        // Get embedding from OpenRouter
-        let embedding = self
-            .openrouter
-            .create_embedding(&query, &self.config.embedding_model)
-            .await
-            .map_err(|e| McpError {
-                code: ErrorCode(-32603),
-                message: Cow::from(format!("Failed to create embedding: {}", e)),
-                data: None,
-            })?;
+        if let Some(guidance) = self.missing_index(&project, None).await? {
+            return Ok(guidance);
+        }
+
+        // Expand project jargon with its glossary synonyms
+        let db_path = project.db_path.clone();
+        let glossary = tokio::task::spawn_blocking(move || {
+            let store = VectorStore::open_read_only(&db_path)?;
+            wiki::Glossary::load(&db_path, &store)
+        })
+        .await
+        .map_err(|e| McpError {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("Task join error: {}", e)),
+            data: None,
+        })?
+        .map_err(|e| McpError {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("Failed to load glossary: {}", e)),
+            data: None,
+        })?;
+        let expansion = glossary.expand(&query);
+        if expansion.is_expanded() {
+            debug!(
+                original = %expansion.original,
+                expanded = %expansion.text(),
+                matched = ?expansion.matched,
+                "Expanded query with glossary"
+            );
+        }
+
+        let query_text = expansion.text();
+        let embedding = if mode.needs_embedding() {
+            self.embeddings
+                .create_embedding(&query_text, &project.embedding_model)
+                .await
+                .map_err(|e| McpError {
+                    code: ErrorCode(-32603),
+                    message: Cow::from(format!("Failed to create embedding: {}", e)),
+                    data: None,
+                })?
+        } else {
+            Vec::new()
+        };
 
         // Search vector store in blocking task
-        let db_path = self.config.db_path.clone();
+        let db_path = project.db_path.clone();
+        let model = project.embedding_model.clone();
+        let root = request.root.clone();
+        let scope = SearchScope {
+            path_glob: request.path_glob.clone(),
+            language: request.language.clone(),
+        };
         let results =
             tokio::task::spawn_blocking(move || -> Result<Vec<SearchResult>, wiki::WikiError> {
-                let store = VectorStore::new(&db_path)?;
-                store.search_similar(&embedding, limit)
+                let store = VectorStore::open_read_only(&db_path)?;
+                let root = root.as_deref();
+                let mut results = match mode {
+                    SearchMode::Semantic => store
+                        .search_similar_scoped(&embedding, &model, candidates, None, root, &scope),
+                    SearchMode::Keyword => {
+                        store.search_keyword_scoped(&query_text, candidates, None, root, &scope)
+                    }
+                    SearchMode::Hybrid => store.search_hybrid_scoped(
+                        &query_text,
+                        &embedding,
+                        &model,
+                        candidates,
+                        None,
+                        root,
+                        &scope,
+                    ),
+                }?;
+                wiki::boost_symbol_matches(&mut results, &query);
+                Ok(filter.apply(results, limit))
             })
             .await
             .map_err(|e| McpError {
@@ -254,7 +843,14 @@ impl WikiService {
             })?;
 
         debug!("Found {} results", results.len());
-        let output = Self::format_search_results(&results);
+        let mut output = Self::format_search_results(&results);
+        if expansion.is_expanded() {
+            output = format!(
+                "Query expanded with glossary terms: {}\n\n{}",
+                expansion.added.join(", "),
+                output
+            );
+        }
         Ok(CallToolResult::success(vec![Content::text(output)]))
     }
 
@@ -265,12 +861,13 @@ impl WikiService {
         &self,
         Parameters(request): Parameters<GetDocumentationRequest>,
     ) -> Result<CallToolResult, McpError> {
+        let project = self.project(request.project.as_deref())?;
         let slug = request.slug.clone();
         info!(slug = %slug, "Getting documentation");
 
-        let db_path = self.config.db_path.clone();
+        let db_path = project.db_path.clone();
         let page_result = tokio::task::spawn_blocking(move || {
-            let store = VectorStore::new(&db_path)?;
+            let store = VectorStore::open_read_only(&db_path)?;
             store.get_wiki_page(&slug)
         })
         .await
@@ -290,10 +887,15 @@ impl WikiService {
                 let output = Self::format_wiki_page(&page);
                 Ok(CallToolResult::success(vec![Content::text(output)]))
             }
-            None => Ok(CallToolResult::success(vec![Content::text(format!(
-                "Page '{}' not found. Use list_wiki_pages to see available pages.",
-                request.slug
-            ))])),
+            None => {
+                if let Some(guidance) = self.missing_index(&project, None).await? {
+                    return Ok(guidance);
+                }
+                Ok(CallToolResult::success(vec![Content::text(format!(
+                    "Page '{}' not found. Use list_wiki_pages to see available pages.",
+                    request.slug
+                ))]))
+            }
         }
     }
 
@@ -303,14 +905,32 @@ impl WikiService {
     async fn ask_codebase(
         &self,
         Parameters(request): Parameters<AskCodebaseRequest>,
+        meta: Meta,
+        peer: Peer<RoleServer>,
     ) -> Result<CallToolResult, McpError> {
+        let project = self.project(request.project.as_deref())?;
         let question = request.question.clone();
-        info!(question = %question, "Asking codebase");
+        let mode = match request.mode.as_deref() {
+            Some(m) => AnswerMode::parse(m).ok_or_else(|| McpError {
+                code: ErrorCode(-32602),
+                message: Cow::from(format!(
+                    "Invalid mode '{}'. Use concise, detailed, code_only or standard",
+                    m
+                )),
+                data: None,
+            })?,
+            None => AnswerMode::default(),
+        };
+        info!(question = %question, mode = mode.as_str(), "Asking codebase");
+
+        if let Some(guidance) = self.missing_index(&project, None).await? {
+            return Ok(guidance);
+        }
 
         // Get embedding for the question
         let query_embedding = self
-            .openrouter
-            .create_embedding(&question, &self.config.embedding_model)
+            .embeddings
+            .create_embedding(&question, &project.embedding_model)
             .await
             .map_err(|e| McpError {
                 code: ErrorCode(-32603),
@@ -319,10 +939,19 @@ impl WikiService {
             })?;
 
         // Search for similar chunks in blocking task
-        let db_path = self.config.db_path.clone();
+        let db_path = project.db_path.clone();
+        let model = project.embedding_model.clone();
+        let root = request.root.clone();
         let search_results = tokio::task::spawn_blocking(move || {
-            let store = VectorStore::new(&db_path)?;
-            store.search_similar(&query_embedding, 10)
+            let store = VectorStore::open_read_only(&db_path)?;
+            let results = store.search_similar_in_branch(
+                &query_embedding,
+                &model,
+                10,
+                None,
+                root.as_deref(),
+            )?;
+            CallGraphExpansion::default().expand(&store, results)
         })
         .await
         .map_err(|e| McpError {
@@ -348,12 +977,12 @@ impl WikiService {
         let sources: Vec<RagSource> = search_results.iter().map(RagSource::from).collect();
 
         // Build messages for chat completion
-        let mut messages = vec![ChatMessage::system(RAG_SYSTEM_PROMPT)];
+        let mut messages = vec![ChatMessage::system(mode.system_prompt())];
 
         // Add conversation history if provided
         if let Some(conv_id) = &request.conversation_id {
             let conversations = self.conversations.lock().await;
-            if let Some(conversation) = conversations.get(conv_id) {
+            if let Some(conversation) = conversations.get(&conversation_key(&project, conv_id)) {
                 for msg in &conversation.messages {
                     match msg.role {
                         wiki::MessageRole::User => messages.push(ChatMessage::user(&msg.content)),
@@ -368,22 +997,20 @@ impl WikiService {
         // Add current question with context
         messages.push(ChatMessage::user(format_user_prompt(&question, &context)));
 
-        // Get chat completion
         let answer = self
-            .openrouter
-            .chat_completion(messages, &self.config.chat_model, Some(0.3), Some(2048))
-            .await
-            .map_err(|e| McpError {
-                code: ErrorCode(-32603),
-                message: Cow::from(format!("Chat completion failed: {}", e)),
-                data: None,
-            })?;
+            .stream_answer(
+                messages,
+                &project.chat_model,
+                mode,
+                meta.get_progress_token().map(|token| (peer, token)),
+            )
+            .await?;
 
         // Update conversation history if provided
         if let Some(conv_id) = request.conversation_id {
             let mut conversations = self.conversations.lock().await;
             let conversation = conversations
-                .entry(conv_id.clone())
+                .entry(conversation_key(&project, &conv_id))
                 .or_insert_with(|| Conversation::with_id(conv_id));
             conversation.add_user_message(&question);
             conversation.add_assistant_message(&answer);
@@ -395,18 +1022,65 @@ impl WikiService {
         Ok(CallToolResult::success(vec![Content::text(output)]))
     }
 
+    /// Stream the chat completion of `messages`, sending each piece of the
+    /// answer as a progress notification when the caller asked for progress
+    async fn stream_answer(
+        &self,
+        messages: Vec<ChatMessage>,
+        model: &str,
+        mode: AnswerMode,
+        progress: Option<(Peer<RoleServer>, ProgressToken)>,
+    ) -> Result<String, McpError> {
+        use futures::StreamExt;
+
+        let completion_failed = |e: wiki::WikiError| McpError {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("Chat completion failed: {}", e)),
+            data: None,
+        };
+        let stream = self
+            .openrouter
+            .chat_completion_stream(messages, model, Some(0.3), Some(mode.max_tokens()))
+            .await
+            .map_err(completion_failed)?;
+        tokio::pin!(stream);
+
+        let mut answer = String::new();
+        let mut pieces = 0;
+        while let Some(piece) = stream.next().await {
+            let piece = piece.map_err(completion_failed)?;
+            answer.push_str(&piece);
+            pieces += 1;
+
+            if let Some((ref peer, ref token)) = progress {
+                let notification = ProgressNotificationParam {
+                    progress_token: token.clone(),
+                    progress: pieces,
+                    total: None,
+                    message: Some(piece),
+                };
+                if let Err(e) = peer.notify_progress(notification).await {
+                    // The answer still arrives as the tool result
+                    debug!(error = %e, "Failed to send answer progress");
+                }
+            }
+        }
+        Ok(answer)
+    }
+
     #[tool(description = "List all wiki pages and their structure for a given branch.")]
     async fn list_wiki_pages(
         &self,
         Parameters(request): Parameters<ListWikiPagesRequest>,
     ) -> Result<CallToolResult, McpError> {
+        let project = self.project(request.project.as_deref())?;
         let branch = request.branch.clone().unwrap_or_else(|| "main".to_string());
         info!(branch = %branch, "Listing wiki pages");
 
-        let db_path = self.config.db_path.clone();
+        let db_path = project.db_path.clone();
         let branch_clone = branch.clone();
         let structure_result = tokio::task::spawn_blocking(move || {
-            let store = VectorStore::new(&db_path)?;
+            let store = VectorStore::open_read_only(&db_path)?;
             store.get_wiki_structure(&branch_clone)
         })
         .await
@@ -426,11 +1100,234 @@ impl WikiService {
                 let output = Self::format_wiki_structure(&structure, &branch);
                 Ok(CallToolResult::success(vec![Content::text(output)]))
             }
-            None => Ok(CallToolResult::success(vec![Content::text(format!(
-                "No wiki structure found for branch '{}'. The wiki may not be indexed yet.",
+            None => {
+                if let Some(guidance) = self.missing_index(&project, Some(&branch)).await? {
+                    return Ok(guidance);
+                }
+                Ok(CallToolResult::success(vec![Content::text(format!(
+                    "No wiki structure found for branch '{}'. The code is indexed but no wiki pages were generated yet.",
+                    branch
+                ))]))
+            }
+        }
+    }
+
+    #[tool(
+        description = "Find source modules that have no associated tests (no inline tests, no test file named after them, no test calling their functions). Useful to flag untested areas when reviewing changes."
+    )]
+    async fn find_untested_modules(
+        &self,
+        Parameters(request): Parameters<FindUntestedModulesRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let project = self.project(request.project.as_deref())?;
+        let branch = request.branch.clone().unwrap_or_else(|| "main".to_string());
+        info!(branch = %branch, "Finding untested modules");
+
+        let db_path = project.db_path.clone();
+        let branch_clone = branch.clone();
+        let coverage = tokio::task::spawn_blocking(move || {
+            let store = VectorStore::open_read_only(&db_path)?;
+            let chunks = store.get_branch_chunks(&branch_clone)?;
+            Ok::<_, wiki::WikiError>(TestCoverageMap::from_chunks(&chunks))
+        })
+        .await
+        .map_err(|e| McpError {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("Task join error: {}", e)),
+            data: None,
+        })?
+        .map_err(|e| McpError {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("Failed to analyze test coverage: {}", e)),
+            data: None,
+        })?;
+
+        if coverage.is_empty() {
+            if let Some(guidance) = self.missing_index(&project, Some(&branch)).await? {
+                return Ok(guidance);
+            }
+            return Ok(CallToolResult::success(vec![Content::text(format!(
+                "No source modules found for branch '{}'.",
                 branch
-            ))])),
+            ))]));
+        }
+
+        let output =
+            Self::format_untested_modules(&coverage, &branch, request.file_paths.as_deref());
+        Ok(CallToolResult::success(vec![Content::text(output)]))
+    }
+
+    #[tool(
+        description = "Trace an HTTP request through the codebase: finds the route handler and follows its calls across modules, then writes a step-by-step flow document with file:line citations."
+    )]
+    async fn trace_flow(
+        &self,
+        Parameters(request): Parameters<TraceFlowRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let project = self.project(request.project.as_deref())?;
+        let branch = request.branch.clone().unwrap_or_else(|| "main".to_string());
+        info!(method = %request.method, path = %request.path, branch = %branch, "Tracing request flow");
+
+        let db_path = project.db_path.clone();
+        let branch_clone = branch.clone();
+        let chunks = tokio::task::spawn_blocking(move || {
+            VectorStore::open_read_only(&db_path)?.get_branch_chunks(&branch_clone)
+        })
+        .await
+        .map_err(|e| McpError {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("Task join error: {}", e)),
+            data: None,
+        })?
+        .map_err(|e| McpError {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("Failed to load indexed code: {}", e)),
+            data: None,
+        })?;
+        if chunks.is_empty() {
+            if let Some(guidance) = self.missing_index(&project, Some(&branch)).await? {
+                return Ok(guidance);
+            }
         }
+
+        let tracer = FlowTracer::new(&self.openrouter, project.chat_model.clone());
+        let document = match tracer.trace(&chunks, &request.method, &request.path).await {
+            Ok(document) => document,
+            Err(e @ wiki::WikiError::RouteNotFound { .. }) => {
+                return Ok(CallToolResult::success(vec![Content::text(format!(
+                    "{} on branch '{}'.",
+                    e, branch
+                ))]));
+            }
+            Err(e) => {
+                return Err(McpError {
+                    code: ErrorCode(-32603),
+                    message: Cow::from(format!("Flow tracing failed: {}", e)),
+                    data: None,
+                });
+            }
+        };
+
+        let saved_slug = if request.persist.unwrap_or(false) {
+            let db_path = project.db_path.clone();
+            let document = document.clone();
+            let page = tokio::task::spawn_blocking(move || {
+                let store = VectorStore::new(&db_path)?;
+                wiki::flow::save_flow_page(&store, &branch, &document)
+            })
+            .await
+            .map_err(|e| McpError {
+                code: ErrorCode(-32603),
+                message: Cow::from(format!("Task join error: {}", e)),
+                data: None,
+            })?
+            .map_err(|e| McpError {
+                code: ErrorCode(-32603),
+                message: Cow::from(format!("Failed to save flow page: {}", e)),
+                data: None,
+            })?;
+            Some(page.slug)
+        } else {
+            None
+        };
+
+        let output = Self::format_flow_document(&document, saved_slug.as_deref());
+        Ok(CallToolResult::success(vec![Content::text(output)]))
+    }
+
+    #[tool(
+        description = "Find earlier tasks, review findings and session summaries similar to a query. Use it to learn how related problems were solved or reviewed before."
+    )]
+    async fn recall_related_work(
+        &self,
+        Parameters(request): Parameters<RecallRelatedWorkRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let project = self.project(request.project.as_deref())?;
+        let limit = request.limit.unwrap_or(5).clamp(1, 20);
+        let scope = match request.scope.as_deref() {
+            Some(s) => Some(RecallScope::parse(s).ok_or_else(|| McpError {
+                code: ErrorCode(-32602),
+                message: Cow::from(format!(
+                    "Invalid scope '{}'. Use tasks, findings or sessions",
+                    s
+                )),
+                data: None,
+            })?),
+            None => None,
+        };
+
+        info!(query = %request.query, limit = limit, "Recalling related work");
+
+        let matches = RecallIndex::new(self.embeddings.as_ref(), &project.embedding_model)
+            .search(&project.db_path, &request.query, scope, limit)
+            .await
+            .map_err(|e| McpError {
+                code: ErrorCode(-32603),
+                message: Cow::from(format!("Recall failed: {}", e)),
+                data: None,
+            })?;
+
+        let output = Self::format_recall_matches(&matches);
+        Ok(CallToolResult::success(vec![Content::text(output)]))
+    }
+
+    #[tool(
+        description = "List indexed files with their chunk counts and the commit they were indexed at. Use it to check whether a file is indexed before trusting search results."
+    )]
+    async fn list_indexed_files(
+        &self,
+        Parameters(request): Parameters<ListIndexedFilesRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let project = self.project(request.project.as_deref())?;
+        let branch = request.branch.clone().unwrap_or_else(|| "main".to_string());
+        let path_prefix = request.path_prefix.filter(|p| !p.is_empty());
+        let limit = request
+            .limit
+            .unwrap_or(DEFAULT_INDEXED_FILES_LIMIT)
+            .clamp(1, MAX_INDEXED_FILES_LIMIT);
+        let offset = request.offset.unwrap_or(0);
+        info!(branch = %branch, path_prefix = ?path_prefix, "Listing indexed files");
+
+        let db_path = project.db_path.clone();
+        let branch_clone = branch.clone();
+        let prefix_clone = path_prefix.clone();
+        let (files, total) = tokio::task::spawn_blocking(move || {
+            let store = VectorStore::open_read_only(&db_path)?;
+            let prefix = prefix_clone.as_deref();
+            let files = store.list_indexed_files(&branch_clone, prefix, limit, offset)?;
+            let total = store.count_indexed_files(&branch_clone, prefix)?;
+            Ok::<_, wiki::WikiError>((files, total))
+        })
+        .await
+        .map_err(|e| McpError {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("Task join error: {}", e)),
+            data: None,
+        })?
+        .map_err(|e| McpError {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("Failed to list indexed files: {}", e)),
+            data: None,
+        })?;
+
+        if total == 0 && path_prefix.is_none() {
+            if let Some(guidance) = self.missing_index(&project, Some(&branch)).await? {
+                return Ok(guidance);
+            }
+        }
+
+        let output =
+            Self::format_indexed_files(&files, total, offset, &branch, path_prefix.as_deref());
+        Ok(CallToolResult::success(vec![Content::text(output)]))
+    }
+
+    #[tool(
+        description = "List the projects this server serves. Pass a project's name as the 'project' argument of the other tools to use its wiki."
+    )]
+    async fn list_projects(&self) -> Result<CallToolResult, McpError> {
+        info!("Listing projects");
+        let output = Self::format_projects(self.projects.projects());
+        Ok(CallToolResult::success(vec![Content::text(output)]))
     }
 
     #[tool(description = "Get the indexing status for the wiki.")]
@@ -438,13 +1335,14 @@ impl WikiService {
         &self,
         Parameters(request): Parameters<ListWikiPagesRequest>,
     ) -> Result<CallToolResult, McpError> {
+        let project = self.project(request.project.as_deref())?;
         let branch = request.branch.clone().unwrap_or_else(|| "main".to_string());
         info!(branch = %branch, "Getting index status");
 
-        let db_path = self.config.db_path.clone();
+        let db_path = project.db_path.clone();
         let branch_clone = branch.clone();
         let status_result = tokio::task::spawn_blocking(move || {
-            let store = VectorStore::new(&db_path)?;
+            let store = VectorStore::open_read_only(&db_path)?;
             store.get_index_status(&branch_clone)
         })
         .await
@@ -464,26 +1362,227 @@ impl WikiService {
                 let output = Self::format_index_status(&status, &branch);
                 Ok(CallToolResult::success(vec![Content::text(output)]))
             }
-            None => Ok(CallToolResult::success(vec![Content::text(format!(
-                "Branch '{}' has not been indexed yet.",
-                branch
-            ))])),
+            None => {
+                if let Some(guidance) = self.missing_index(&project, Some(&branch)).await? {
+                    return Ok(guidance);
+                }
+                Ok(CallToolResult::success(vec![Content::text(format!(
+                    "Branch '{}' has not been indexed yet.",
+                    branch
+                ))]))
+            }
         }
     }
-}
 
-/// System prompt for code Q&A
-const RAG_SYSTEM_PROMPT: &str = r#"You are a knowledgeable code assistant helping developers understand a codebase.
+    #[tool(
+        description = "Index a branch of the project's checkout so the other tools can search it. Runs in the background; follow it with get_index_status."
+    )]
+    async fn trigger_indexing(
+        &self,
+        Parameters(request): Parameters<TriggerIndexingRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let project = self.project(request.project.as_deref())?;
+        let branch = request.branch.clone().unwrap_or_else(|| "main".to_string());
+        let force = request.force.unwrap_or(false);
+        info!(project = %project.name, branch = %branch, force = force, "Triggering indexing");
 
-You have access to relevant code snippets from the codebase to answer questions.
-When answering:
-- Reference specific files and line numbers when relevant (format: `file_path:line_number`)
-- Provide concise but complete explanations
-- Include code examples when helpful
-- If the context doesn't contain enough information, say so clearly
-- Don't make up information that's not in the provided context
+        if !self.indexing_enabled {
+            return Ok(CallToolResult::error(vec![Content::text(
+                "Indexing from this server is disabled. Set OPENCODE_WIKI_ALLOW_INDEXING=1 to enable it.",
+            )]));
+        }
+        let Some(repo_path) = project.repo_path.clone() else {
+            return Ok(CallToolResult::error(vec![Content::text(format!(
+                "Project '{}' has no repository path. Set repo_path in the project registry.",
+                project.name
+            ))]));
+        };
 
-Always cite the relevant code locations to support your answers."#;
+        let db_path = project.db_path.clone();
+        let branch_clone = branch.clone();
+        let attempt = tokio::task::spawn_blocking(move || {
+            VectorStore::new(&db_path)?.acquire_branch_lock(&branch_clone, "indexing")
+        })
+        .await
+        .map_err(|e| McpError {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("Task join error: {}", e)),
+            data: None,
+        })?
+        .map_err(|e| McpError {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("Failed to lock branch: {}", e)),
+            data: None,
+        })?;
+        let lock = match attempt {
+            BranchLockAttempt::Acquired(lock) => lock,
+            BranchLockAttempt::Held(held) => {
+                return Ok(CallToolResult::success(vec![Content::text(format!(
+                    "Branch '{}' is already being processed by {} job {}. Follow it with get_index_status.",
+                    branch, held.operation, held.job_id
+                ))]));
+            }
+        };
+
+        let commit_sha = wiki::git::get_head_sha(&repo_path).map_err(|e| McpError {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!(
+                "Failed to read the commit of {}: {}",
+                repo_path.display(),
+                e
+            )),
+            data: None,
+        })?;
+
+        // The vector store is not Send, so indexing gets its own thread
+        let embeddings = self.embeddings.clone();
+        let branch_clone = branch.clone();
+        std::thread::spawn(move || {
+            let rt = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("Failed to create runtime");
+            rt.block_on(async move {
+                let _lock = BranchLockGuard::new(&project.db_path, lock);
+                let result = async {
+                    #[allow(clippy::arc_with_non_send_sync)]
+                    let store = Arc::new(VectorStore::new(&project.db_path)?);
+                    if force {
+                        store.clear_branch(&branch_clone)?;
+                    }
+                    CodeIndexer::new(embeddings, store, project.embedding_model, 350, 100)
+                        .with_roots(&project.roots)
+                        .index_branch(&repo_path, &branch_clone, &commit_sha, None)
+                        .await
+                }
+                .await;
+                match result {
+                    Ok(status) => info!(
+                        branch = %branch_clone,
+                        chunks = status.chunk_count,
+                        "Indexing finished"
+                    ),
+                    Err(e) => warn!(branch = %branch_clone, error = %e, "Indexing failed"),
+                }
+            });
+        });
+
+        Ok(CallToolResult::success(vec![Content::text(format!(
+            "Indexing of branch '{}' started. Follow it with get_index_status.",
+            branch
+        ))]))
+    }
+
+    #[tool(
+        description = "Regenerate one wiki page from the current content of the files it references, e.g. a page found stale during review. Runs in the background; read the page again with get_documentation."
+    )]
+    async fn regenerate_page(
+        &self,
+        Parameters(request): Parameters<RegeneratePageRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let project = self.project(request.project.as_deref())?;
+        let branch = request.branch.clone().unwrap_or_else(|| "main".to_string());
+        let slug = request.slug.trim().to_string();
+        info!(project = %project.name, branch = %branch, slug = %slug, "Regenerating wiki page");
+
+        let Some(repo_path) = project.repo_path.clone() else {
+            return Ok(CallToolResult::error(vec![Content::text(format!(
+                "Project '{}' has no repository path. Set repo_path in the project registry.",
+                project.name
+            ))]));
+        };
+
+        let db_path = project.db_path.clone();
+        let (branch_clone, slug_clone) = (branch.clone(), slug.clone());
+        let attempt = tokio::task::spawn_blocking(move || {
+            let store = VectorStore::new(&db_path)?;
+            let page = store
+                .get_wiki_page_in_branch(&slug_clone, Some(&branch_clone))?
+                .ok_or(wiki::WikiError::PageNotFound { slug: slug_clone })?;
+            WikiGenerator::check_regenerable(&page)?;
+            store.acquire_branch_lock(&branch_clone, "page regeneration")
+        })
+        .await
+        .map_err(|e| McpError {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("Task join error: {}", e)),
+            data: None,
+        })?;
+        let lock = match attempt {
+            Ok(BranchLockAttempt::Acquired(lock)) => lock,
+            Ok(BranchLockAttempt::Held(held)) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Branch '{}' is being processed by {} job {}. Try again once it finished.",
+                    branch, held.operation, held.job_id
+                ))]));
+            }
+            Err(
+                e @ (wiki::WikiError::PageNotFound { .. }
+                | wiki::WikiError::PageNotRegenerable { .. }),
+            ) => {
+                return Ok(CallToolResult::error(vec![Content::text(e.to_string())]));
+            }
+            Err(e) => {
+                return Err(McpError {
+                    code: ErrorCode(-32603),
+                    message: Cow::from(format!("Failed to look up page: {}", e)),
+                    data: None,
+                })
+            }
+        };
+
+        let commit_sha = wiki::git::get_head_sha(&repo_path).map_err(|e| McpError {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!(
+                "Failed to read the commit of {}: {}",
+                repo_path.display(),
+                e
+            )),
+            data: None,
+        })?;
+
+        // The vector store is not Send, so generation gets its own thread
+        let openrouter = self.openrouter.clone();
+        let (branch_clone, slug_clone) = (branch.clone(), slug.clone());
+        std::thread::spawn(move || {
+            let rt = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("Failed to create runtime");
+            rt.block_on(async move {
+                let _lock = BranchLockGuard::new(&project.db_path, lock);
+                let result = async {
+                    #[allow(clippy::arc_with_non_send_sync)]
+                    let store = Arc::new(VectorStore::new(&project.db_path)?);
+                    WikiGenerator::new(openrouter, store, project.chat_model, 350, 100)
+                        .regenerate_page(&repo_path, &branch_clone, &commit_sha, &slug_clone)
+                        .await
+                }
+                .await;
+                match result {
+                    Ok(_) => info!(branch = %branch_clone, slug = %slug_clone, "Page regenerated"),
+                    Err(e) => warn!(
+                        branch = %branch_clone,
+                        slug = %slug_clone,
+                        error = %e,
+                        "Page regeneration failed"
+                    ),
+                }
+            });
+        });
+
+        Ok(CallToolResult::success(vec![Content::text(format!(
+            "Regeneration of page '{}' on branch '{}' started. Read it again with get_documentation in a minute.",
+            slug, branch
+        ))]))
+    }
+}
+
+/// Conversations are kept per project, so a conversation ID reused across
+/// projects does not mix their history
+fn conversation_key(project: &WikiProject, conversation_id: &str) -> String {
+    format!("{}:{}", project.name, conversation_id)
+}
 
 /// Build context string from search results
 fn build_context(results: &[SearchResult]) -> String {
@@ -493,18 +1592,29 @@ fn build_context(results: &[SearchResult]) -> String {
     let mut total_length = 0;
 
     for (i, result) in results.iter().enumerate() {
+        let expansion_note = result
+            .expansion
+            .as_ref()
+            .map(|e| format!(" [{}]", e.describe()))
+            .unwrap_or_default();
         let chunk_header = format!(
-            "\n--- Source {}: {} (lines {}-{}) ---\n",
+            "\n--- Source {}: {} (lines {}-{}){} ---\n",
             i + 1,
             result.file_path,
             result.start_line,
-            result.end_line
+            result.end_line,
+            expansion_note
         );
 
+        let summary = result
+            .summary
+            .as_ref()
+            .map(|s| format!("Summary: {}\n", s))
+            .unwrap_or_default();
         let chunk_content = if let Some(lang) = &result.language {
-            format!("```{}\n{}\n```\n", lang, result.content)
+            format!("{}```{}\n{}\n```\n", summary, lang, result.content)
         } else {
-            format!("```\n{}\n```\n", result.content)
+            format!("{}```\n{}\n```\n", summary, result.content)
         };
 
         let chunk_total = chunk_header.len() + chunk_content.len();
@@ -537,7 +1647,6 @@ Please provide a clear and helpful answer based on the code context above."#,
     )
 }
 
-#[tool_handler]
 impl ServerHandler for WikiService {
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
@@ -550,15 +1659,71 @@ impl ServerHandler for WikiService {
             instructions: Some(
                 "Use this server to search code and ask questions about the codebase.\n\n\
                  Available tools:\n\
-                 - search_code: Find relevant code using semantic search\n\
+                 - search_code: Find relevant code using semantic, keyword or hybrid search\n\
                  - get_documentation: Retrieve wiki documentation pages\n\
                  - ask_codebase: Ask questions and get AI-generated answers\n\
                  - list_wiki_pages: Browse available documentation\n\
-                 - get_index_status: Check wiki indexing status"
+                 - get_index_status: Check wiki indexing status\n\
+                 - find_untested_modules: Find source modules without associated tests\n\
+                 - trace_flow: Document the path of a request across modules\n\
+                 - recall_related_work: Find earlier tasks, findings and sessions related to a query\n\
+                 - list_indexed_files: Check which files are indexed and how many chunks they have\n\
+                 - list_projects: List the projects whose wikis this server serves\n\
+                 - trigger_indexing: Index a branch, if this server allows it\n\
+                 - regenerate_page: Refresh a stale wiki page from the files it references"
                     .to_string(),
             ),
         }
     }
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, McpError> {
+        let tools = self
+            .tool_router
+            .list_all()
+            .into_iter()
+            .filter(|tool| self.tool_enabled(&tool.name))
+            .collect();
+        Ok(ListToolsResult::with_all_items(tools))
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        let (called_at, started) = (Utc::now(), Instant::now());
+        let tool = request.name.to_string();
+        let result = if !self.tool_enabled(&tool) {
+            warn!(tool = %tool, "Denied call to a tool this phase may not use");
+            Ok(CallToolResult::error(vec![Content::text(format!(
+                "Permission denied: tool '{}' is not allowed in this phase",
+                tool
+            ))]))
+        } else {
+            let context = ToolCallContext::new(self, request, context);
+            self.tool_router.call(context).await
+        };
+        self.record_call(&tool, called_at, started, &result);
+        result
+    }
+}
+
+/// Why a tool call failed, if it did
+fn call_error(result: &Result<CallToolResult, McpError>) -> Option<String> {
+    match result {
+        Ok(result) if result.is_error == Some(true) => Some(
+            result
+                .content
+                .iter()
+                .find_map(|content| content.as_text().map(|text| text.text.clone()))
+                .unwrap_or_else(|| "Tool returned an error".to_string()),
+        ),
+        Ok(_) => None,
+        Err(e) => Some(e.message.to_string()),
+    }
 }
 
 /// Configuration from environment variables
@@ -568,6 +1733,16 @@ pub struct WikiServiceConfig {
     pub embedding_model: String,
     pub chat_model: String,
     pub api_base_url: String,
+    pub embedding_provider: EmbeddingProviderKind,
+    pub openai_api_key: Option<String>,
+    /// Model directory for local embeddings
+    pub local_model_path: Option<PathBuf>,
+    /// Vector dimension of the embedding model, when not its first embedding's
+    pub embedding_dimension: Option<usize>,
+    /// Registry of the projects to serve instead of `db_path`
+    pub projects_file: Option<PathBuf>,
+    /// Offer the `trigger_indexing` tool
+    pub allow_indexing: bool,
 }
 
 impl WikiServiceConfig {
@@ -579,8 +1754,36 @@ impl WikiServiceConfig {
             .map(PathBuf::from)
             .unwrap_or_else(|_| PathBuf::from(".opencode-studio/wiki.db"));
 
-        let openrouter_api_key = std::env::var("OPENROUTER_API_KEY")
-            .context("OPENROUTER_API_KEY environment variable not set")?;
+        let embedding_provider = match std::env::var("OPENCODE_WIKI_EMBEDDING_PROVIDER") {
+            Ok(name) if !name.is_empty() => serde_json::from_value(serde_json::json!(name))
+                .with_context(|| format!("Unknown embedding provider '{}'", name))?,
+            _ => EmbeddingProviderKind::default(),
+        };
+
+        // Only chat needs OpenRouter when embeddings are computed locally
+        let openrouter_api_key = match std::env::var("OPENROUTER_API_KEY") {
+            Ok(key) => key,
+            Err(_) if embedding_provider == EmbeddingProviderKind::Local => String::new(),
+            Err(_) => anyhow::bail!("OPENROUTER_API_KEY environment variable not set"),
+        };
+
+        let openai_api_key = std::env::var("OPENAI_API_KEY")
+            .ok()
+            .filter(|k| !k.is_empty());
+
+        let local_model_path = std::env::var("OPENCODE_WIKI_LOCAL_MODEL_PATH")
+            .ok()
+            .filter(|p| !p.is_empty())
+            .map(PathBuf::from);
+
+        let embedding_dimension = match std::env::var("OPENCODE_WIKI_EMBEDDING_DIMENSION") {
+            Ok(value) if !value.is_empty() => Some(
+                value
+                    .parse()
+                    .with_context(|| format!("Invalid embedding dimension '{}'", value))?,
+            ),
+            _ => None,
+        };
 
         let embedding_model = std::env::var("OPENCODE_WIKI_EMBEDDING_MODEL")
             .unwrap_or_else(|_| "openai/text-embedding-3-small".to_string());
@@ -591,15 +1794,40 @@ impl WikiServiceConfig {
         let api_base_url = std::env::var("OPENROUTER_API_BASE_URL")
             .unwrap_or_else(|_| "https://openrouter.ai/api/v1".to_string());
 
+        let projects_file = std::env::var("OPENCODE_WIKI_PROJECTS")
+            .ok()
+            .filter(|p| !p.is_empty())
+            .map(PathBuf::from);
+
+        let allow_indexing = std::env::var("OPENCODE_WIKI_ALLOW_INDEXING")
+            .map(|v| matches!(v.trim(), "1" | "true" | "yes"))
+            .unwrap_or(false);
+
         Ok(Self {
             db_path,
             openrouter_api_key,
             embedding_model,
             chat_model,
             api_base_url,
+            embedding_provider,
+            openai_api_key,
+            local_model_path,
+            embedding_dimension,
+            projects_file,
+            allow_indexing,
         })
     }
 
+    /// The projects to serve: those of the registry file if one is
+    /// configured, otherwise just `db_path`
+    pub fn project_registry(&self) -> Result<ProjectRegistry, RegistryError> {
+        let config = self.to_wiki_config();
+        match &self.projects_file {
+            Some(path) => ProjectRegistry::load(path, &config),
+            None => Ok(ProjectRegistry::single(&config)),
+        }
+    }
+
     /// Convert to WikiConfig
     pub fn to_wiki_config(&self) -> WikiConfig {
         WikiConfig {
@@ -608,6 +1836,10 @@ impl WikiServiceConfig {
             embedding_model: self.embedding_model.clone(),
             chat_model: self.chat_model.clone(),
             api_base_url: self.api_base_url.clone(),
+            embedding_provider: self.embedding_provider,
+            openai_api_key: self.openai_api_key.clone(),
+            local_model_path: self.local_model_path.clone(),
+            embedding_dimension: self.embedding_dimension,
             ..Default::default()
         }
     }
@@ -656,11 +1888,84 @@ mod tests {
             end_line: 10,
             score: 0.95,
             snippet: "fn main()".to_string(),
+            summary: Some("Entry point of the CLI.".to_string()),
+            expansion: None,
+            sub_queries: Vec::new(),
         }];
 
         let output = WikiService::format_sources(&sources);
         assert!(output.contains("src/main.rs"));
         assert!(output.contains("95%"));
+        assert!(output.contains("   Entry point of the CLI.\n"));
+    }
+
+    #[test]
+    fn test_format_sources_notes_expansion() {
+        let sources = vec![RagSource {
+            file_path: "src/validate.rs".to_string(),
+            start_line: 3,
+            end_line: 9,
+            score: 0.8,
+            snippet: "fn validate()".to_string(),
+            summary: None,
+            expansion: Some("callee `validate` of src/main.rs:1-10".to_string()),
+            sub_queries: Vec::new(),
+        }];
+
+        let output = WikiService::format_sources(&sources);
+        assert!(output.contains("added as callee `validate` of src/main.rs:1-10"));
+        assert!(!output.contains("relevance"));
+    }
+
+    #[test]
+    fn test_format_untested_modules() {
+        let module = |path: &str, inline: bool| wiki::ModuleCoverage {
+            file_path: path.to_string(),
+            language: "rust".to_string(),
+            test_files: vec![],
+            has_inline_tests: inline,
+        };
+        let coverage = TestCoverageMap {
+            modules: vec![
+                module("src/a.rs", true),
+                module("src/b.rs", false),
+                module("src/c.rs", false),
+            ],
+            test_files: vec![],
+        };
+
+        let all = WikiService::format_untested_modules(&coverage, "main", None);
+        assert!(all.starts_with("2 of 3 source modules on branch 'main'"));
+        assert!(all.contains("- src/b.rs (rust)"));
+        assert!(all.contains("- src/c.rs (rust)"));
+
+        let paths = vec!["src/a.rs".to_string(), "src/c.rs".to_string()];
+        let scoped = WikiService::format_untested_modules(&coverage, "main", Some(&paths));
+        assert!(scoped.starts_with("1 of 2 requested files"));
+        assert!(!scoped.contains("src/b.rs"));
+    }
+
+    #[test]
+    fn test_format_indexed_files() {
+        let file = |path: &str| IndexedFile {
+            file_path: path.to_string(),
+            chunk_count: 3,
+            commit_sha: "0123456789abcdef".to_string(),
+            indexed_at: chrono::Utc::now(),
+        };
+        let files = vec![file("src/a.rs"), file("src/b.rs")];
+
+        let output = WikiService::format_indexed_files(&files, 5, 0, "main", Some("src/"));
+        assert!(output.starts_with("Indexed files under 'src/' on branch 'main' (1-2 of 5)"));
+        assert!(output.contains("- src/a.rs (3 chunks, commit 01234567"));
+        assert!(output.contains("use offset 2"));
+
+        let last = WikiService::format_indexed_files(&files, 4, 2, "main", None);
+        assert!(last.contains("(3-4 of 4)"));
+        assert!(!last.contains("use offset"));
+
+        let empty = WikiService::format_indexed_files(&[], 0, 0, "main", None);
+        assert_eq!(empty, "No indexed files on branch 'main' (total: 0).");
     }
 
     #[test]
@@ -671,13 +1976,116 @@ mod tests {
             embedding_model: "test-embed".to_string(),
             chat_model: "test-chat".to_string(),
             api_base_url: "https://test.api".to_string(),
+            embedding_provider: EmbeddingProviderKind::OpenAi,
+            openai_api_key: Some("openai-key".to_string()),
+            local_model_path: None,
+            embedding_dimension: Some(3072),
+            projects_file: None,
+            allow_indexing: false,
         };
 
         let wiki_config = config.to_wiki_config();
         assert_eq!(wiki_config.db_path, PathBuf::from("/tmp/wiki.db"));
         assert_eq!(wiki_config.openrouter_api_key, "test-key");
+        assert_eq!(
+            wiki_config.embedding_provider,
+            EmbeddingProviderKind::OpenAi
+        );
+        assert_eq!(wiki_config.openai_api_key.as_deref(), Some("openai-key"));
         assert_eq!(wiki_config.embedding_model, "test-embed");
         assert_eq!(wiki_config.chat_model, "test-chat");
+        assert_eq!(wiki_config.embedding_dimension, Some(3072));
+
+        let registry = config.project_registry().unwrap();
+        assert_eq!(registry.projects().len(), 1);
+        assert_eq!(
+            registry.projects()[0].db_path,
+            PathBuf::from("/tmp/wiki.db")
+        );
+    }
+
+    #[test]
+    fn test_format_projects() {
+        let project = |name: &str, db_path: &str| WikiProject {
+            name: name.to_string(),
+            db_path: PathBuf::from(db_path),
+            embedding_model: "small".to_string(),
+            chat_model: "chat".to_string(),
+            repo_path: None,
+            roots: Vec::new(),
+        };
+        let output = WikiService::format_projects(&[
+            project("studio", "/nonexistent/studio.db"),
+            project("billing", "/nonexistent/billing.db"),
+        ]);
+        assert!(output.starts_with("2 projects available:"));
+        assert!(output.contains(
+            "- studio (default): /nonexistent/studio.db (embeddings: small, not indexed yet)"
+        ));
+        assert!(output.contains("- billing: /nonexistent/billing.db"));
+    }
+
+    #[tokio::test]
+    async fn test_missing_index_guidance() {
+        let service = WikiService::new(create_test_config()).unwrap();
+        let project = service.project(None).unwrap();
+
+        let result = service
+            .missing_index(&project, None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.content.len(), 2);
+        let text = result.content[0].as_text().unwrap();
+        assert!(text
+            .text
+            .starts_with("Wiki index unavailable (not_indexed)"));
+        assert!(!text.text.contains("trigger_indexing"));
+
+        let service = service.with_indexing(true);
+        let result = service
+            .missing_index(&project, Some("main"))
+            .await
+            .unwrap()
+            .unwrap();
+        let text = result.content[0].as_text().unwrap();
+        assert!(text
+            .text
+            .contains("Call trigger_indexing with branch 'main'"));
+
+        let mut status = wiki::IndexStatus::new("main".to_string());
+        status.state = wiki::IndexState::Indexed;
+        status.chunk_count = 3;
+        VectorStore::new(&project.db_path)
+            .unwrap()
+            .update_index_status(&status)
+            .unwrap();
+        assert!(service
+            .missing_index(&project, None)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_trigger_indexing_hidden_unless_enabled() {
+        let service = WikiService::new(create_test_config()).unwrap();
+        assert!(!service.tool_enabled(TRIGGER_INDEXING_TOOL));
+        assert!(service.tool_enabled("search_code"));
+        assert!(service
+            .with_indexing(true)
+            .tool_enabled(TRIGGER_INDEXING_TOOL));
+    }
+
+    #[test]
+    fn test_unknown_project_is_rejected() {
+        let service = WikiService::new(create_test_config()).unwrap();
+        assert_eq!(
+            service.project(None).unwrap().name,
+            projects::DEFAULT_PROJECT
+        );
+        let err = service.project(Some("missing")).unwrap_err();
+        assert_eq!(err.code, ErrorCode(-32602));
     }
 
     #[test]
@@ -694,11 +2102,16 @@ mod tests {
             ChunkType::Function,
             Some("rust".to_string()),
             0.95,
-        )];
+        )
+        .with_summary(Some("Starts the program.".to_string()))];
 
         let context = build_context(&results);
         assert!(context.contains("src/lib.rs"));
         assert!(context.contains("fn main()"));
+        assert!(context.contains("Summary: Starts the program.\n```rust"));
+
+        let output = WikiService::format_search_results(&results);
+        assert!(output.contains("Type: Function\nSummary: Starts the program.\n\n```rust"));
     }
 
     #[test]
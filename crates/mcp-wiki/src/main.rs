@@ -4,14 +4,26 @@
 //! It communicates via stdio and is configured through environment variables.
 //!
 //! Environment variables:
-//! - OPENROUTER_API_KEY: API key for OpenRouter (required)
+//! - OPENROUTER_API_KEY: API key for OpenRouter (required unless embeddings are local)
 //! - OPENCODE_WIKI_DB_PATH: Path to wiki database (default: .opencode-studio/wiki.db)
 //! - OPENCODE_WIKI_EMBEDDING_MODEL: Embedding model (default: openai/text-embedding-3-small)
 //! - OPENCODE_WIKI_CHAT_MODEL: Chat model (default: anthropic/claude-3.5-sonnet)
 //! - OPENROUTER_API_BASE_URL: OpenRouter API base URL (default: https://openrouter.ai/api/v1)
+//! - OPENCODE_WIKI_EMBEDDING_PROVIDER: openrouter, openai or local (default: openrouter)
+//! - OPENAI_API_KEY: API key for the openai embedding provider
+//! - OPENCODE_WIKI_LOCAL_MODEL_PATH: Model directory for the local embedding provider
+//! - OPENCODE_WIKI_EMBEDDING_DIMENSION: Vector dimension of the embedding model
+//!   (default: that of its first embedding)
+//! - OPENCODE_MCP_ALLOWED_TOOLS: Comma-separated tools the session may call (default: all)
+//! - OPENCODE_WIKI_PROJECTS: Registry of several wiki databases to serve instead of
+//!   OPENCODE_WIKI_DB_PATH (see `mcp_wiki::projects`)
+//! - OPENCODE_WIKI_ALLOW_INDEXING: Set to 1 to offer the trigger_indexing tool (default: off)
+//! - OPENCODE_MCP_CALL_LOG: File to record tool calls in (default: none)
+//! - OPENCODE_SESSION_ID: Session the server is attached to, recorded with its tool calls
 
 use anyhow::Result;
-use mcp_wiki::{WikiService, WikiServiceConfig};
+use mcp_wiki::{ToolCallLog, WikiService, WikiServiceConfig};
+use opencode_core::{ToolAllowlist, ALLOWED_TOOLS_ENV, SESSION_ID_ENV, TOOL_CALL_LOG_ENV};
 use rmcp::{transport::stdio, ServiceExt};
 use tracing::info;
 
@@ -36,9 +48,31 @@ async fn main() -> Result<()> {
         "Starting MCP Wiki Server"
     );
 
-    // Create wiki config and service
-    let wiki_config = service_config.to_wiki_config();
-    let service = WikiService::new(wiki_config)?;
+    let projects = service_config.project_registry()?;
+    if service_config.projects_file.is_some() {
+        let names: Vec<&str> = projects
+            .projects()
+            .iter()
+            .map(|p| p.name.as_str())
+            .collect();
+        info!(projects = ?names, "Serving multiple projects");
+    }
+
+    let allowed_tools = std::env::var(ALLOWED_TOOLS_ENV).ok();
+    let call_log = std::env::var(TOOL_CALL_LOG_ENV)
+        .ok()
+        .filter(|path| !path.trim().is_empty())
+        .map(ToolCallLog::new)
+        .unwrap_or_default();
+
+    // Create wiki config and service, opening the default project's database
+    let mut wiki_config = service_config.to_wiki_config();
+    wiki_config.db_path = projects.projects()[0].db_path.clone();
+    let service = WikiService::new(wiki_config)?
+        .with_projects(projects)
+        .with_indexing(service_config.allow_indexing)
+        .with_allowed_tools(ToolAllowlist::new(allowed_tools.as_deref()))
+        .with_call_log(call_log, std::env::var(SESSION_ID_ENV).ok());
 
     // Start serving
     let server = service.serve(stdio()).await?;
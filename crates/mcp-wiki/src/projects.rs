@@ -0,0 +1,302 @@
+//! Wiki databases served by one server instance
+//!
+//! By default the server serves the single database given by
+//! `OPENCODE_WIKI_DB_PATH`. A registry file lets it serve several projects
+//! instead; tools then take an optional `project` argument naming one of
+//! them. The registry is JSON:
+//!
+//! ```json
+//! {
+//!   "default": "studio",
+//!   "projects": [
+//!     { "name": "studio", "db_path": "/work/studio/.opencode-studio/wiki.db" },
+//!     {
+//!       "name": "billing",
+//!       "db_path": "/work/billing/.opencode-studio/wiki.db",
+//!       "embedding_model": "openai/text-embedding-3-large",
+//!       "repo_path": "/work/billing",
+//!       "roots": ["backend/", "frontend/"]
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Projects without models use the server's; without `default` the first
+//! project is used when a tool call names none. `repo_path`, the checkout
+//! `trigger_indexing` reads, defaults to the directory holding
+//! `.opencode-studio/wiki.db`. `roots` limits indexing to sub-directories of
+//! a monorepo, as the `wiki.roots` project setting does.
+
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+use wiki::WikiConfig;
+
+/// Name of the only project when no registry is configured
+pub const DEFAULT_PROJECT: &str = "default";
+
+#[derive(Debug, Error)]
+pub enum RegistryError {
+    #[error("Failed to read project registry {path}: {source}")]
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Invalid project registry {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    #[error("Invalid project registry: {0}")]
+    Invalid(String),
+}
+
+/// A wiki database and the models its embeddings were made with
+#[derive(Debug, Clone, PartialEq)]
+pub struct WikiProject {
+    pub name: String,
+    pub db_path: PathBuf,
+    pub embedding_model: String,
+    pub chat_model: String,
+    /// Checkout indexed by `trigger_indexing`
+    pub repo_path: Option<PathBuf>,
+    /// Workspace roots `trigger_indexing` indexes; empty for the whole
+    /// checkout
+    pub roots: Vec<String>,
+}
+
+/// The repository a database in the default location belongs to
+fn default_repo_path(db_path: &Path) -> Option<PathBuf> {
+    let studio_dir = db_path.parent()?;
+    if studio_dir.file_name()? != ".opencode-studio" {
+        return None;
+    }
+    match studio_dir.parent()? {
+        p if p.as_os_str().is_empty() => Some(PathBuf::from(".")),
+        p => Some(p.to_path_buf()),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct RegistryFile {
+    #[serde(default)]
+    default: Option<String>,
+    projects: Vec<ProjectEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ProjectEntry {
+    name: String,
+    db_path: PathBuf,
+    #[serde(default)]
+    embedding_model: Option<String>,
+    #[serde(default)]
+    chat_model: Option<String>,
+    #[serde(default)]
+    repo_path: Option<PathBuf>,
+    #[serde(default)]
+    roots: Vec<String>,
+}
+
+/// The projects a server instance serves, the first being the default
+#[derive(Debug, Clone)]
+pub struct ProjectRegistry {
+    projects: Vec<WikiProject>,
+}
+
+impl ProjectRegistry {
+    /// A registry of the one database in `config`
+    pub fn single(config: &WikiConfig) -> Self {
+        Self {
+            projects: vec![WikiProject {
+                name: DEFAULT_PROJECT.to_string(),
+                db_path: config.db_path.clone(),
+                embedding_model: config.embedding_model.clone(),
+                chat_model: config.chat_model.clone(),
+                repo_path: default_repo_path(&config.db_path),
+                roots: config.roots.clone(),
+            }],
+        }
+    }
+
+    /// Read a registry file. Relative database paths are resolved against
+    /// the file's directory; missing models fall back to `config`'s.
+    pub fn load(path: &Path, config: &WikiConfig) -> Result<Self, RegistryError> {
+        let content = std::fs::read_to_string(path).map_err(|source| RegistryError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let base = path.parent().unwrap_or_else(|| Path::new(""));
+        Self::parse(&content, base, config).map_err(|e| match e {
+            RegistryError::Parse { source, .. } => RegistryError::Parse {
+                path: path.to_path_buf(),
+                source,
+            },
+            other => other,
+        })
+    }
+
+    fn parse(content: &str, base: &Path, config: &WikiConfig) -> Result<Self, RegistryError> {
+        let file: RegistryFile =
+            serde_json::from_str(content).map_err(|source| RegistryError::Parse {
+                path: PathBuf::new(),
+                source,
+            })?;
+
+        let mut projects: Vec<WikiProject> = Vec::with_capacity(file.projects.len());
+        for entry in file.projects {
+            let name = entry.name.trim().to_string();
+            if name.is_empty() {
+                return Err(RegistryError::Invalid("project without a name".to_string()));
+            }
+            if projects.iter().any(|p| p.name == name) {
+                return Err(RegistryError::Invalid(format!(
+                    "project '{}' is listed twice",
+                    name
+                )));
+            }
+            let db_path = base.join(entry.db_path);
+            let repo_path = match entry.repo_path {
+                Some(repo_path) => Some(base.join(repo_path)),
+                None => default_repo_path(&db_path),
+            };
+            projects.push(WikiProject {
+                name,
+                db_path,
+                repo_path,
+                roots: entry.roots,
+                embedding_model: entry
+                    .embedding_model
+                    .unwrap_or_else(|| config.embedding_model.clone()),
+                chat_model: entry
+                    .chat_model
+                    .unwrap_or_else(|| config.chat_model.clone()),
+            });
+        }
+        if projects.is_empty() {
+            return Err(RegistryError::Invalid("no projects listed".to_string()));
+        }
+
+        if let Some(default) = file.default {
+            let index = projects
+                .iter()
+                .position(|p| p.name == default)
+                .ok_or_else(|| {
+                    RegistryError::Invalid(format!("default project '{}' is not listed", default))
+                })?;
+            projects.swap(0, index);
+        }
+
+        Ok(Self { projects })
+    }
+
+    /// Every project, the default first
+    pub fn projects(&self) -> &[WikiProject] {
+        &self.projects
+    }
+
+    /// The project named `name`, or the default one
+    pub fn resolve(&self, name: Option<&str>) -> Result<&WikiProject, String> {
+        let Some(name) = name.map(str::trim).filter(|n| !n.is_empty()) else {
+            return Ok(&self.projects[0]);
+        };
+        self.projects
+            .iter()
+            .find(|p| p.name == name)
+            .ok_or_else(|| {
+                let names: Vec<&str> = self.projects.iter().map(|p| p.name.as_str()).collect();
+                format!(
+                    "Unknown project '{}'. Available: {}",
+                    name,
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> WikiConfig {
+        WikiConfig {
+            db_path: PathBuf::from("/tmp/wiki.db"),
+            embedding_model: "small".to_string(),
+            chat_model: "chat".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_single_registry() {
+        let registry = ProjectRegistry::single(&config());
+        let project = registry.resolve(None).unwrap();
+        assert_eq!(project.name, DEFAULT_PROJECT);
+        assert_eq!(project.db_path, PathBuf::from("/tmp/wiki.db"));
+        assert!(registry.resolve(Some("other")).is_err());
+    }
+
+    #[test]
+    fn test_parse_registry() {
+        let content = r#"{
+            "default": "billing",
+            "projects": [
+                { "name": "studio", "db_path": "studio/wiki.db" },
+                {
+                    "name": "billing",
+                    "db_path": "/abs/wiki.db",
+                    "embedding_model": "large",
+                    "roots": ["backend/"]
+                }
+            ]
+        }"#;
+        let registry = ProjectRegistry::parse(content, Path::new("/work"), &config()).unwrap();
+
+        let default = registry.resolve(None).unwrap();
+        assert_eq!(default.name, "billing");
+        assert_eq!(default.db_path, PathBuf::from("/abs/wiki.db"));
+        assert_eq!(default.embedding_model, "large");
+        assert_eq!(default.chat_model, "chat");
+        assert_eq!(default.roots, vec!["backend/".to_string()]);
+
+        let studio = registry.resolve(Some(" studio ")).unwrap();
+        assert_eq!(studio.db_path, PathBuf::from("/work/studio/wiki.db"));
+        assert_eq!(studio.repo_path, None);
+        assert_eq!(studio.embedding_model, "small");
+
+        let err = registry.resolve(Some("missing")).unwrap_err();
+        assert!(err.contains("Available: billing, studio"));
+    }
+
+    #[test]
+    fn test_default_repo_path() {
+        assert_eq!(
+            default_repo_path(Path::new("/work/app/.opencode-studio/wiki.db")),
+            Some(PathBuf::from("/work/app"))
+        );
+        assert_eq!(
+            default_repo_path(Path::new(".opencode-studio/wiki.db")),
+            Some(PathBuf::from("."))
+        );
+        assert_eq!(default_repo_path(Path::new("/data/wiki.db")), None);
+    }
+
+    #[test]
+    fn test_parse_registry_rejects_invalid() {
+        let base = Path::new("/work");
+        for content in [
+            r#"{ "projects": [] }"#,
+            r#"{ "projects": [{ "name": "a", "db_path": "a.db" }, { "name": "a", "db_path": "b.db" }] }"#,
+            r#"{ "default": "b", "projects": [{ "name": "a", "db_path": "a.db" }] }"#,
+        ] {
+            assert!(matches!(
+                ProjectRegistry::parse(content, base, &config()),
+                Err(RegistryError::Invalid(_))
+            ));
+        }
+        assert!(matches!(
+            ProjectRegistry::parse("not json", base, &config()),
+            Err(RegistryError::Parse { .. })
+        ));
+    }
+}
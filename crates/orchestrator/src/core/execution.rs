@@ -134,6 +134,7 @@ impl ExecutionEngine {
                 &config.mcp_servers,
                 task.id,
                 session_id,
+                config.metadata.phase_type(),
             )
             .await?;
             resources = resources.with_mcp_guard(guard);
@@ -48,6 +48,9 @@ pub enum OrchestratorError {
     #[error("Findings not found for task: {0}")]
     FindingsNotFound(Uuid),
 
+    #[error("Findings file for task {task_id} is corrupted and has no intact backup: {reason}")]
+    FindingsCorrupted { task_id: Uuid, reason: String },
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
@@ -7,9 +7,11 @@ use uuid::Uuid;
 
 use crate::activity_store::SessionActivityMsg;
 use crate::error::{OrchestratorError, Result};
+use crate::files::{ReleaseReviewReport, ReviewFindings};
 use crate::prompts::PhasePrompts;
 use crate::services::{
-    ExecutorContext, FixPhase, ImplementationPhase, MessageParser, PlanningPhase, ReviewPhase,
+    ExecutorContext, FindingTranslation, FixPhase, ImplementationPhase, MergedTask, MessageParser,
+    PlanningPhase, ReleaseReview, ReviewPhase,
 };
 
 pub use crate::services::executor_context::ExecutorConfig;
@@ -85,6 +87,11 @@ impl TaskExecutor {
         self
     }
 
+    pub fn with_usage_store(mut self, store: Arc<crate::usage::UsageStore>) -> Self {
+        self.ctx = self.ctx.with_usage_store(store);
+        self
+    }
+
     pub fn with_event_bus(mut self, bus: events::EventBus) -> Self {
         self.ctx = self.ctx.with_event_bus(bus);
         self
@@ -106,8 +113,12 @@ impl TaskExecutor {
         &self.ctx.opencode_config
     }
 
+    pub fn config(&self) -> &ExecutorConfig {
+        &self.ctx.config
+    }
+
     pub fn transition(&self, task: &mut Task, to: TaskStatus) -> Result<()> {
-        self.ctx.transition(task, to)
+        self.ctx.apply_transition(task, to)
     }
 
     pub fn extract_text_from_parts(parts: &[Part]) -> String {
@@ -129,18 +140,25 @@ impl TaskExecutor {
             "Executing phase for task"
         );
 
+        if task.status == TaskStatus::Todo {
+            debug!("Task in TODO, transitioning to PLANNING");
+            self.ctx.transition(task, TaskStatus::Planning)?;
+        }
+
         let result = match task.status {
             TaskStatus::Todo => {
-                debug!("Task in TODO, transitioning to PLANNING");
-                self.ctx.transition(task, TaskStatus::Planning)?;
-                PlanningPhase::run(&self.ctx, task).await
+                info!("Starting the task requires approval, awaiting human action");
+                Ok(PhaseResult::AwaitingApproval {
+                    phase: SessionPhase::Planning,
+                })
             }
+            TaskStatus::Blocked => Err(blocked_error()),
             TaskStatus::Planning => {
                 debug!("Task in PLANNING, running planning session");
                 PlanningPhase::run(&self.ctx, task).await
             }
             TaskStatus::PlanningReview => {
-                if self.ctx.config.require_plan_approval {
+                if self.plan_needs_approval() {
                     info!("Plan requires approval, awaiting human review");
                     Ok(PhaseResult::AwaitingApproval {
                         phase: SessionPhase::Planning,
@@ -164,7 +182,7 @@ impl TaskExecutor {
                 FixPhase::run(&self.ctx, task).await
             }
             TaskStatus::Review => {
-                if self.ctx.config.require_human_review {
+                if self.review_needs_approval() {
                     info!("Implementation requires human review, awaiting approval");
                     Ok(PhaseResult::AwaitingApproval {
                         phase: SessionPhase::Review,
@@ -197,20 +215,46 @@ impl TaskExecutor {
         result
     }
 
+    fn plan_needs_approval(&self) -> bool {
+        self.ctx.config.require_plan_approval
+            || self
+                .ctx
+                .config
+                .workflow
+                .requires_approval(&TaskStatus::PlanningReview, &TaskStatus::InProgress)
+    }
+
+    fn review_needs_approval(&self) -> bool {
+        self.ctx.config.require_human_review
+            || self
+                .ctx
+                .config
+                .workflow
+                .requires_approval(&TaskStatus::Review, &TaskStatus::Done)
+    }
+
     pub async fn run_full_cycle(&self, task: &mut Task) -> Result<PhaseResult> {
         info!("Starting full cycle for task {}: {}", task.id, task.title);
 
         if task.status == TaskStatus::Done {
             return Ok(PhaseResult::Completed);
         }
+        if task.status == TaskStatus::Blocked {
+            return Err(blocked_error());
+        }
 
         if task.status == TaskStatus::Todo {
             self.ctx.transition(task, TaskStatus::Planning)?;
+            if task.status == TaskStatus::Todo {
+                return Ok(PhaseResult::AwaitingApproval {
+                    phase: SessionPhase::Planning,
+                });
+            }
         }
 
         if task.status == TaskStatus::Planning {
             let result = PlanningPhase::run(&self.ctx, task).await?;
-            if self.ctx.config.require_plan_approval {
+            if self.plan_needs_approval() {
                 return Ok(result);
             }
         }
@@ -230,7 +274,7 @@ impl TaskExecutor {
             let result = ReviewPhase::run(&self.ctx, task, iteration).await?;
             match result {
                 PhaseResult::ReviewPassed { .. } => {
-                    if self.ctx.config.require_human_review {
+                    if self.review_needs_approval() {
                         return Ok(PhaseResult::AwaitingApproval {
                             phase: SessionPhase::Review,
                         });
@@ -239,12 +283,16 @@ impl TaskExecutor {
                         return Ok(PhaseResult::Completed);
                     }
                 }
-                PhaseResult::ReviewFailed { feedback, .. } => {
+                PhaseResult::ReviewFailed { ref feedback, .. } => {
+                    if !matches!(task.status, TaskStatus::AiReview | TaskStatus::InProgress) {
+                        // The workflow's rule moved the task out of the fix loop
+                        return Ok(result);
+                    }
                     info!(
                         "AI review failed (iteration {}), running fix iteration",
                         iteration
                     );
-                    FixPhase::run_iteration(&self.ctx, task, &feedback).await?;
+                    FixPhase::run_iteration(&self.ctx, task, feedback).await?;
                     iteration += 1;
                 }
                 _ => return Ok(result),
@@ -262,7 +310,7 @@ impl TaskExecutor {
         }
 
         if task.status == TaskStatus::Review {
-            if self.ctx.config.require_human_review {
+            if self.review_needs_approval() {
                 return Ok(PhaseResult::AwaitingApproval {
                     phase: SessionPhase::Review,
                 });
@@ -285,9 +333,11 @@ impl TaskExecutor {
         }
 
         match task.status {
-            TaskStatus::Todo | TaskStatus::Planning => {
-                PlanningPhase::start_async(&self.ctx, task).await
-            }
+            TaskStatus::Todo => Err(OrchestratorError::ExecutionFailed(
+                "Starting the task requires approval".to_string(),
+            )),
+            TaskStatus::Blocked => Err(blocked_error()),
+            TaskStatus::Planning => PlanningPhase::start_async(&self.ctx, task).await,
             TaskStatus::PlanningReview | TaskStatus::InProgress => {
                 ImplementationPhase::start_async(&self.ctx, task).await
             }
@@ -300,6 +350,27 @@ impl TaskExecutor {
         }
     }
 
+    /// Start the phase a workflow rule queued after moving the task
+    pub async fn start_queued_phase(
+        &self,
+        task: &mut Task,
+        phase: SessionPhase,
+    ) -> Result<StartedExecution> {
+        info!(
+            task_id = %task.id,
+            phase = %phase.as_str(),
+            current_status = %task.status.as_str(),
+            "Starting queued phase"
+        );
+
+        match phase {
+            SessionPhase::Planning => PlanningPhase::start_async(&self.ctx, task).await,
+            SessionPhase::Implementation => ImplementationPhase::start_async(&self.ctx, task).await,
+            SessionPhase::Review => ReviewPhase::start_async(&self.ctx, task).await,
+            SessionPhase::Fix => FixPhase::start_async(&self.ctx, task).await,
+        }
+    }
+
     pub async fn start_fix_with_comments(
         &self,
         task: &Task,
@@ -321,7 +392,7 @@ impl TaskExecutor {
                 to: "InProgress (plan approval)".to_string(),
             });
         }
-        self.ctx.transition(task, TaskStatus::InProgress)?;
+        self.ctx.apply_transition(task, TaskStatus::InProgress)?;
         info!(task_id = %task.id, "Task ready for implementation");
         Ok(())
     }
@@ -343,7 +414,7 @@ impl TaskExecutor {
                 to: "Planning (plan rejection)".to_string(),
             });
         }
-        self.ctx.transition(task, TaskStatus::Planning)?;
+        self.ctx.apply_transition(task, TaskStatus::Planning)?;
 
         let mut session = Session::new(task.id, SessionPhase::Planning);
         let opencode_session = self
@@ -411,6 +482,24 @@ impl TaskExecutor {
         })
     }
 
+    /// The task's review findings in `language`, translated on first request
+    pub async fn translate_findings(
+        &self,
+        task_id: Uuid,
+        language: &str,
+    ) -> Result<Option<ReviewFindings>> {
+        FindingTranslation::translate(&self.ctx, task_id, language).await
+    }
+
+    /// Review `tasks` together, completing and writing `report`
+    pub async fn review_release(
+        &self,
+        report: &mut ReleaseReviewReport,
+        tasks: &[MergedTask],
+    ) -> Result<()> {
+        ReleaseReview::run(&self.ctx, report, tasks).await
+    }
+
     pub async fn approve_review(&self, task: &mut Task) -> Result<()> {
         info!(task_id = %task.id, "Implementation APPROVED by human reviewer");
 
@@ -424,7 +513,7 @@ impl TaskExecutor {
                 to: "Done (review approval)".to_string(),
             });
         }
-        self.ctx.transition(task, TaskStatus::Done)?;
+        self.ctx.apply_transition(task, TaskStatus::Done)?;
         info!(task_id = %task.id, "Task COMPLETED successfully");
         Ok(())
     }
@@ -459,6 +548,12 @@ impl TaskExecutor {
     }
 }
 
+fn blocked_error() -> OrchestratorError {
+    OrchestratorError::ExecutionFailed(
+        "The task is blocked until the tasks it depends on are done".to_string(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4,14 +4,23 @@
 //! structured findings JSON in the `.opencode-studio/kanban/` directory structure.
 
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use tokio::fs;
-use tracing::{debug, info};
+use tokio::io::AsyncWriteExt;
+use tracing::{debug, info, warn};
 use uuid::Uuid;
+use vcs::{normalize_path, normalize_relative};
 
 use crate::error::{OrchestratorError, Result};
+use crate::patch::parse_patch;
+use crate::path_guard::PATH_VIOLATION_ID_PREFIX;
+use crate::seen_context::SeenContext;
+use crate::services::PhaseModels;
+use crate::task_env::EnvVar;
 
 /// Base directory for OpenCode Studio files
 const STUDIO_DIR: &str = ".opencode-studio";
@@ -25,6 +34,19 @@ const REVIEWS_DIR: &str = "reviews";
 const FINDINGS_DIR: &str = "findings";
 /// Directory for phase summaries
 const PHASES_DIR: &str = "phases";
+/// Directory for task environment variables
+const ENV_DIR: &str = "env";
+/// Directory for the phase models of tasks that override the project's
+const MODELS_DIR: &str = "models";
+/// Directory for the review history of each task
+const HISTORY_DIR: &str = "history";
+/// Directory for release review reports
+const RELEASES_DIR: &str = "releases";
+/// Directory for the CI gate of each task
+const CI_DIR: &str = "ci";
+
+/// Lines of source a finding keeps as its snippet
+const MAX_SNIPPET_LINES: i32 = 40;
 
 // ============================================================================
 // Review Findings Types
@@ -62,6 +84,19 @@ pub enum FindingStatus {
     Pending,
     Fixed,
     Skipped,
+    /// Not worth fixing, e.g. a false positive
+    Dismissed,
+}
+
+impl FindingStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FindingStatus::Pending => "pending",
+            FindingStatus::Fixed => "fixed",
+            FindingStatus::Skipped => "skipped",
+            FindingStatus::Dismissed => "dismissed",
+        }
+    }
 }
 
 /// A single review finding
@@ -81,6 +116,126 @@ pub struct ReviewFinding {
     pub description: String,
     pub severity: FindingSeverity,
     pub status: FindingStatus,
+    /// Severity the reviewer reported, when calibration changed it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_severity: Option<FindingSeverity>,
+    /// Why calibration changed the severity
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity_rationale: Option<String>,
+    /// Steps or input that show the problem
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reproduction: Option<String>,
+    /// What must hold once the finding is fixed; required for errors
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub acceptance_criteria: Option<String>,
+    /// Fix the reviewer proposes, as a unified diff against the workspace
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggested_fix: Option<String>,
+    /// Share of review samples that reported the finding, with consensus on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consensus_score: Option<f32>,
+    /// Discussion between the human reviewer and the agents, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub comments: Vec<FindingComment>,
+    /// The lines the finding points at, as they read when it was created
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+}
+
+impl ReviewFinding {
+    /// Check that the finding gives the fix phase enough to act on
+    ///
+    /// Error findings block the merge, so they must say what a fix has to
+    /// achieve.
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        let has_criteria = self
+            .acceptance_criteria
+            .as_deref()
+            .is_some_and(|c| !c.trim().is_empty());
+        if self.severity == FindingSeverity::Error && !has_criteria {
+            return Err(format!(
+                "Finding '{}' has severity error but no acceptance criteria",
+                self.title
+            ));
+        }
+        Ok(())
+    }
+
+    /// Fit the finding's line range to `content`, the file it names, and
+    /// keep the lines it covers as its snippet. A start below 1 or an end
+    /// past the last line is clamped; a start past the last line is an
+    /// error. Returns whether the range was changed.
+    pub fn anchor_to_source(&mut self, content: &str) -> std::result::Result<bool, String> {
+        let Some(requested_start) = self.line_start.or(self.line_end) else {
+            return Ok(false);
+        };
+        let lines: Vec<&str> = content.lines().collect();
+        let line_count = lines.len() as i32;
+        let path = self.file_path.as_deref().unwrap_or("the file");
+        let start = requested_start.max(1);
+        if start > line_count {
+            return Err(format!(
+                "Line {} is past the end of {}, which has {} lines",
+                start, path, line_count
+            ));
+        }
+
+        let end = self.line_end.unwrap_or(start).clamp(start, line_count);
+        let adjusted = self.line_start != Some(start)
+            || self.line_end.is_some_and(|requested| requested != end);
+        self.line_start = Some(start);
+        if self.line_end.is_some() {
+            self.line_end = Some(end);
+        }
+
+        let shown_end = end.min(start + MAX_SNIPPET_LINES - 1);
+        let width = shown_end.to_string().len();
+        let mut snippet = String::new();
+        for (number, line) in (start..=shown_end).zip(&lines[start as usize - 1..]) {
+            snippet.push_str(&format!("{:>width$} | {}\n", number, line, width = width));
+        }
+        if shown_end < end {
+            snippet.push_str(&format!("... {} more lines\n", end - shown_end));
+        }
+        self.snippet = Some(snippet);
+        Ok(adjusted)
+    }
+}
+
+/// Who wrote a comment on a finding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum FindingCommentAuthor {
+    /// The person reviewing the task
+    Human,
+    /// A review or fix session
+    Agent,
+}
+
+/// A comment in the discussion of a finding
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FindingComment {
+    pub id: Uuid,
+    pub author: FindingCommentAuthor,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl FindingComment {
+    pub fn new(author: FindingCommentAuthor, body: impl Into<String>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            author,
+            body: body.into(),
+            created_at: Utc::now(),
+        }
+    }
 }
 
 /// Collection of findings from an AI review
@@ -135,6 +290,297 @@ impl ReviewFindings {
     }
 }
 
+/// Where the GitHub checks gating a task's completion stand
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum CiGateState {
+    Pending,
+    Passed,
+    Failed,
+    /// The checks did not finish within the gate's wait
+    TimedOut,
+}
+
+impl CiGateState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CiGateState::Pending => "pending",
+            CiGateState::Passed => "passed",
+            CiGateState::Failed => "failed",
+            CiGateState::TimedOut => "timed_out",
+        }
+    }
+
+    /// Whether the gate stopped polling
+    pub fn is_finished(&self) -> bool {
+        !matches!(self, CiGateState::Pending)
+    }
+}
+
+/// GitHub checks a task waits on after its workspace was merged or a pull
+/// request was opened from it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CiGateRecord {
+    pub task_id: Uuid,
+    /// Branch or commit the checks run on
+    pub ref_name: String,
+    pub state: CiGateState,
+    pub passed: u32,
+    pub failed: u32,
+    pub pending: u32,
+    /// Names of the checks that failed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failed_checks: Vec<String>,
+    /// Move the task to done once the checks pass
+    #[serde(default)]
+    pub complete_on_pass: bool,
+    pub started_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl CiGateRecord {
+    /// Gate of `task_id` that has not polled the checks on `ref_name` yet
+    pub fn pending(task_id: Uuid, ref_name: impl Into<String>, complete_on_pass: bool) -> Self {
+        let now = Utc::now();
+        Self {
+            task_id,
+            ref_name: ref_name.into(),
+            state: CiGateState::Pending,
+            passed: 0,
+            failed: 0,
+            pending: 0,
+            failed_checks: Vec::new(),
+            complete_on_pass,
+            started_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// Translated text of a task's findings, cached next to the originals
+///
+/// Only prose is kept; severities and statuses always come from the findings
+/// file, so a cached translation stays usable as findings get fixed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FindingsTranslation {
+    pub language: String,
+    /// [`FindingsTranslation::source_checksum`] of the findings translated
+    pub source_checksum: String,
+    pub summary: String,
+    pub findings: Vec<TranslatedFinding>,
+}
+
+/// Translated text of one finding
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranslatedFinding {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub acceptance_criteria: Option<String>,
+}
+
+impl FindingsTranslation {
+    /// SHA-256 over the text a translation covers; a new review or a reworded
+    /// finding changes it, a status change does not
+    pub fn source_checksum(findings: &ReviewFindings) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(findings.summary.as_bytes());
+        for finding in &findings.findings {
+            for text in [
+                finding.id.as_str(),
+                finding.title.as_str(),
+                finding.description.as_str(),
+                finding.acceptance_criteria.as_deref().unwrap_or_default(),
+            ] {
+                hasher.update([0]);
+                hasher.update(text.as_bytes());
+            }
+        }
+        hex::encode(hasher.finalize())
+    }
+
+    /// Whether this translation was made from `findings` as they are now
+    pub fn matches(&self, findings: &ReviewFindings) -> bool {
+        self.source_checksum == Self::source_checksum(findings)
+    }
+
+    /// `findings` with the translated text in place of the original; findings
+    /// the translation misses keep their text
+    pub fn apply(&self, findings: &ReviewFindings) -> ReviewFindings {
+        let mut translated = findings.clone();
+        if !self.summary.trim().is_empty() {
+            translated.summary = self.summary.clone();
+        }
+        for finding in &mut translated.findings {
+            let Some(text) = self.findings.iter().find(|t| t.id == finding.id) else {
+                continue;
+            };
+            finding.title = text.title.clone();
+            finding.description = text.description.clone();
+            if finding.acceptance_criteria.is_some() && text.acceptance_criteria.is_some() {
+                finding.acceptance_criteria = text.acceptance_criteria.clone();
+            }
+        }
+        translated
+    }
+}
+
+/// Files a task's changes touched when a review ran
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangedFiles {
+    pub recorded_at: DateTime<Utc>,
+    pub files: Vec<String>,
+}
+
+impl ChangedFiles {
+    /// Files named in a unified diff, in diff order
+    pub fn from_diff(diff: &str) -> Self {
+        let mut files: Vec<String> = Vec::new();
+        for line in diff.lines() {
+            let Some(paths) = line.strip_prefix("diff --git a/") else {
+                continue;
+            };
+            let file = match paths.rfind(" b/") {
+                Some(i) => &paths[i + 3..],
+                None => paths,
+            };
+            if !files.iter().any(|f| f == file) {
+                files.push(file.to_string());
+            }
+        }
+        Self {
+            recorded_at: Utc::now(),
+            files,
+        }
+    }
+}
+
+/// Every review of a task, oldest first
+#[derive(Debug, Clone, Default)]
+pub struct ReviewHistory {
+    /// Findings of each review, as last written
+    pub findings: Vec<ReviewFindings>,
+    /// Changed files at the start of each review
+    pub changes: Vec<ChangedFiles>,
+}
+
+// ============================================================================
+// Release Review Types
+// ============================================================================
+
+/// State of a release review
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum ReleaseReviewStatus {
+    Running,
+    Completed,
+    Failed,
+}
+
+/// A merged task a release review covers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ReleaseReviewTask {
+    pub task_id: Uuid,
+    pub title: String,
+    /// Files the task's merged changes touched
+    pub files: Vec<String>,
+}
+
+/// A release review finding and the tasks that changed its file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ReleaseFinding {
+    pub finding: ReviewFinding,
+    /// Originating tasks; empty when the finding names no file of theirs
+    pub task_ids: Vec<Uuid>,
+}
+
+/// One review over the changes of several merged tasks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ReleaseReviewReport {
+    pub id: Uuid,
+    /// Tag the covered merges follow, if the tasks were chosen by tag
+    pub since_tag: Option<String>,
+    pub status: ReleaseReviewStatus,
+    /// Why the review failed
+    pub error: Option<String>,
+    pub approved: bool,
+    pub summary: String,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub tasks: Vec<ReleaseReviewTask>,
+    pub findings: Vec<ReleaseFinding>,
+}
+
+impl ReleaseReviewReport {
+    /// A running review of `tasks`
+    pub fn start(since_tag: Option<String>, tasks: Vec<ReleaseReviewTask>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            since_tag,
+            status: ReleaseReviewStatus::Running,
+            error: None,
+            approved: false,
+            summary: String::new(),
+            created_at: Utc::now(),
+            completed_at: None,
+            tasks,
+            findings: Vec::new(),
+        }
+    }
+
+    /// Link each finding to the tasks whose changes touched its file
+    pub fn complete(&mut self, findings: ReviewFindings) {
+        self.findings = findings
+            .findings
+            .into_iter()
+            .map(|finding| {
+                let task_ids = finding
+                    .file_path
+                    .as_deref()
+                    .map(|path| {
+                        let path = path.trim_start_matches("./");
+                        self.tasks
+                            .iter()
+                            .filter(|t| t.files.iter().any(|f| f == path))
+                            .map(|t| t.task_id)
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                ReleaseFinding { finding, task_ids }
+            })
+            .collect();
+        self.approved = findings.approved;
+        self.summary = findings.summary;
+        self.status = ReleaseReviewStatus::Completed;
+        self.completed_at = Some(Utc::now());
+    }
+
+    pub fn fail(&mut self, error: String) {
+        self.error = Some(error);
+        self.status = ReleaseReviewStatus::Failed;
+        self.completed_at = Some(Utc::now());
+    }
+}
+
 // ============================================================================
 // Multi-Phase Implementation Types
 // ============================================================================
@@ -254,6 +700,91 @@ impl PhaseSummary {
     }
 }
 
+/// Schema version written in the header of findings files
+pub const FINDINGS_SCHEMA_VERSION: u32 = 1;
+
+/// How long a writer waits for another writer's findings lock
+const FINDINGS_LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+/// Age after which a findings lock counts as left behind by a crashed writer
+const FINDINGS_LOCK_STALE_AFTER: Duration = Duration::from_secs(30);
+/// Delay between attempts to take a findings lock
+const FINDINGS_LOCK_RETRY: Duration = Duration::from_millis(50);
+
+/// Header of a findings file, read before the findings themselves
+#[derive(Deserialize)]
+struct FindingsHeader {
+    /// Missing in files written before the header existed
+    #[serde(default)]
+    schema_version: u32,
+    checksum: Option<String>,
+}
+
+/// Findings file layout: the header fields next to the findings
+#[derive(Serialize)]
+struct FindingsFile<T> {
+    schema_version: u32,
+    checksum: String,
+    #[serde(flatten)]
+    findings: T,
+}
+
+/// Why a findings file could not be decoded
+enum FindingsDecodeError {
+    /// Written by a newer build with this schema version
+    Unsupported(u32),
+    /// Unparseable or failing its checksum
+    Damaged(String),
+}
+
+/// SHA-256 of the compact JSON of `findings`
+fn findings_checksum(findings: &ReviewFindings) -> serde_json::Result<String> {
+    let json = serde_json::to_vec(findings)?;
+    Ok(hex::encode(Sha256::digest(&json)))
+}
+
+fn encode_findings(findings: &ReviewFindings) -> Result<String> {
+    let serialize_error = |e: serde_json::Error| {
+        OrchestratorError::ExecutionFailed(format!("Failed to serialize findings: {}", e))
+    };
+    let file = FindingsFile {
+        schema_version: FINDINGS_SCHEMA_VERSION,
+        checksum: findings_checksum(findings).map_err(serialize_error)?,
+        findings,
+    };
+    serde_json::to_string_pretty(&file).map_err(serialize_error)
+}
+
+/// Decode a findings file, checking its checksum if it has one
+fn decode_findings(content: &str) -> std::result::Result<ReviewFindings, FindingsDecodeError> {
+    let damaged = |e: serde_json::Error| FindingsDecodeError::Damaged(e.to_string());
+
+    let header: FindingsHeader = serde_json::from_str(content).map_err(damaged)?;
+    if header.schema_version > FINDINGS_SCHEMA_VERSION {
+        return Err(FindingsDecodeError::Unsupported(header.schema_version));
+    }
+
+    let findings: ReviewFindings = serde_json::from_str(content).map_err(damaged)?;
+    if let Some(expected) = header.checksum {
+        if findings_checksum(&findings).map_err(damaged)? != expected {
+            return Err(FindingsDecodeError::Damaged(
+                "checksum mismatch".to_string(),
+            ));
+        }
+    }
+    Ok(findings)
+}
+
+/// Lock file held while a task's findings file is rewritten, removed on drop
+struct FindingsLock {
+    path: PathBuf,
+}
+
+impl Drop for FindingsLock {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
 /// Manages plan and review files for tasks
 #[derive(Debug, Clone)]
 pub struct FileManager {
@@ -265,7 +796,7 @@ impl FileManager {
     /// Create a new FileManager with the given repository base path
     pub fn new(base_path: impl Into<PathBuf>) -> Self {
         Self {
-            base_path: base_path.into(),
+            base_path: normalize_path(&base_path.into()),
         }
     }
 
@@ -313,6 +844,12 @@ impl FileManager {
         self.findings_dir().join(format!("{}.json", task_id))
     }
 
+    /// Get the path to the cached translation of a task's findings
+    pub fn findings_translation_path(&self, task_id: Uuid, language: &str) -> PathBuf {
+        self.findings_dir()
+            .join(format!("{}.{}.json", task_id, language.to_lowercase()))
+    }
+
     /// Ensure all required directories exist
     pub async fn ensure_directories(&self) -> Result<()> {
         let plans_dir = self.plans_dir();
@@ -373,6 +910,26 @@ impl FileManager {
         Ok(path)
     }
 
+    /// Write a plan file followed by the sources consulted while planning
+    pub async fn write_plan_with_sources(
+        &self,
+        task_id: Uuid,
+        content: &str,
+        sources: &[String],
+    ) -> Result<PathBuf> {
+        if sources.is_empty() {
+            return self.write_plan(task_id, content).await;
+        }
+
+        let mut plan = content.trim_end().to_string();
+        plan.push_str("\n\n## Consulted Sources\n\n");
+        plan.push_str("Retrieved from the project wiki while planning:\n\n");
+        for source in sources {
+            plan.push_str(&format!("- {}\n", source));
+        }
+        self.write_plan(task_id, &plan).await
+    }
+
     /// Read a plan file for a task
     pub async fn read_plan(&self, task_id: Uuid) -> Result<String> {
         let path = self.plan_path(task_id);
@@ -470,28 +1027,94 @@ impl FileManager {
     // Findings Methods
     // ========================================================================
 
+    /// Get the path to the last good copy of a task's findings file
+    pub fn findings_backup_path(&self, task_id: Uuid) -> PathBuf {
+        self.findings_dir().join(format!(".{}.json.bak", task_id))
+    }
+
     /// Write findings to a JSON file for a task (atomic write)
+    ///
+    /// The file is written to a temp file, synced and renamed over the old
+    /// one, which is kept as a backup first if it is intact. Writers of the
+    /// same task are serialized by a lock file. Path violations recorded by
+    /// the session runner are kept, since reviewers don't know about them.
     pub async fn write_findings(
         &self,
         task_id: Uuid,
         findings: &ReviewFindings,
     ) -> Result<PathBuf> {
-        self.ensure_directories().await?;
+        let _lock = self.lock_findings(task_id).await?;
+        let mut findings = findings.clone();
+        if let Ok(Some(existing)) = self.read_findings(task_id).await {
+            let violations: Vec<ReviewFinding> = existing
+                .findings
+                .into_iter()
+                .filter(|f| f.id.starts_with(PATH_VIOLATION_ID_PREFIX))
+                .filter(|f| !findings.findings.iter().any(|kept| kept.id == f.id))
+                .collect();
+            if violations
+                .iter()
+                .any(|f| f.status == FindingStatus::Pending)
+            {
+                findings.approved = false;
+            }
+            findings.findings.extend(violations);
+        }
+        self.write_findings_locked(task_id, &findings).await
+    }
+
+    async fn write_findings_locked(
+        &self,
+        task_id: Uuid,
+        findings: &ReviewFindings,
+    ) -> Result<PathBuf> {
         let path = self.findings_path(task_id);
         let temp_path = self.findings_dir().join(format!(".{}.tmp", task_id));
 
         info!("Writing findings to {:?}", path);
 
-        let json = serde_json::to_string_pretty(findings).map_err(|e| {
-            OrchestratorError::ExecutionFailed(format!("Failed to serialize findings: {}", e))
-        })?;
+        // Reviewers on Windows report paths with backslashes
+        let mut findings = findings.clone();
+        for finding in &mut findings.findings {
+            if let Some(path) = &finding.file_path {
+                finding.file_path = Some(normalize_relative(path));
+            }
+        }
+        let json = encode_findings(&findings)?;
 
-        fs::write(&temp_path, &json).await.map_err(|e| {
+        let mut file = fs::File::create(&temp_path).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to create temp findings file {:?}: {}",
+                temp_path, e
+            ))
+        })?;
+        file.write_all(json.as_bytes()).await.map_err(|e| {
             OrchestratorError::ExecutionFailed(format!(
                 "Failed to write temp findings file {:?}: {}",
                 temp_path, e
             ))
         })?;
+        file.sync_all().await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to sync temp findings file {:?}: {}",
+                temp_path, e
+            ))
+        })?;
+        drop(file);
+
+        // Only an intact file becomes the backup, so a damaged one can never
+        // replace the last good copy
+        if let Ok(current) = fs::read_to_string(&path).await {
+            if decode_findings(&current).is_ok() {
+                let backup_path = self.findings_backup_path(task_id);
+                fs::write(&backup_path, &current).await.map_err(|e| {
+                    OrchestratorError::ExecutionFailed(format!(
+                        "Failed to back up findings file {:?}: {}",
+                        backup_path, e
+                    ))
+                })?;
+            }
+        }
 
         fs::rename(&temp_path, &path).await.map_err(|e| {
             OrchestratorError::ExecutionFailed(format!(
@@ -500,10 +1123,68 @@ impl FileManager {
             ))
         })?;
 
+        // Persist the rename itself; directories cannot be opened for syncing
+        // on every platform, so this is best effort
+        if let Ok(dir) = fs::File::open(self.findings_dir()).await {
+            let _ = dir.sync_all().await;
+        }
+
+        // Status updates rewrite the same review, so they replace its copy
+        let archived = format!("findings-{}.json", findings.created_at.timestamp_millis());
+        self.write_history_file(task_id, &archived, &json).await?;
+
         Ok(path)
     }
 
+    /// Take the lock serializing writers of a task's findings file, waiting
+    /// for other writers and breaking locks left behind by crashed ones
+    async fn lock_findings(&self, task_id: Uuid) -> Result<FindingsLock> {
+        self.ensure_directories().await?;
+        let path = self.findings_dir().join(format!(".{}.lock", task_id));
+        let started = Instant::now();
+
+        loop {
+            match fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+                .await
+            {
+                Ok(_) => return Ok(FindingsLock { path }),
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                    let stale = fs::metadata(&path)
+                        .await
+                        .and_then(|m| m.modified())
+                        .ok()
+                        .and_then(|modified| modified.elapsed().ok())
+                        .is_some_and(|age| age >= FINDINGS_LOCK_STALE_AFTER);
+                    if stale {
+                        warn!("Breaking stale findings lock {:?}", path);
+                        let _ = fs::remove_file(&path).await;
+                        continue;
+                    }
+                    if started.elapsed() >= FINDINGS_LOCK_TIMEOUT {
+                        return Err(OrchestratorError::ResourceAcquisitionFailed(format!(
+                            "Timed out waiting for findings lock {:?}",
+                            path
+                        )));
+                    }
+                    tokio::time::sleep(FINDINGS_LOCK_RETRY).await;
+                }
+                Err(e) => {
+                    return Err(OrchestratorError::ExecutionFailed(format!(
+                        "Failed to create findings lock {:?}: {}",
+                        path, e
+                    )))
+                }
+            }
+        }
+    }
+
     /// Read findings from a JSON file for a task
+    ///
+    /// A file that fails to parse or whose checksum does not match falls back
+    /// to the last good backup; the next write replaces the damaged file.
     pub async fn read_findings(&self, task_id: Uuid) -> Result<Option<ReviewFindings>> {
         let path = self.findings_path(task_id);
 
@@ -519,14 +1200,31 @@ impl FileManager {
             ))
         })?;
 
-        let findings: ReviewFindings = serde_json::from_str(&content).map_err(|e| {
-            OrchestratorError::ExecutionFailed(format!(
-                "Failed to parse findings file {:?}: {}",
-                path, e
-            ))
-        })?;
+        let reason = match decode_findings(&content) {
+            Ok(findings) => return Ok(Some(findings)),
+            Err(FindingsDecodeError::Unsupported(version)) => {
+                return Err(OrchestratorError::Serialization(format!(
+                    "Findings file {:?} has schema version {}, newer than supported version {}",
+                    path, version, FINDINGS_SCHEMA_VERSION
+                )))
+            }
+            Err(FindingsDecodeError::Damaged(reason)) => reason,
+        };
+
+        warn!(
+            "Findings file {:?} is damaged ({}), recovering from backup",
+            path, reason
+        );
+        let backup_path = self.findings_backup_path(task_id);
+        let backup = fs::read_to_string(&backup_path)
+            .await
+            .ok()
+            .and_then(|backup| decode_findings(&backup).ok());
 
-        Ok(Some(findings))
+        match backup {
+            Some(findings) => Ok(Some(findings)),
+            None => Err(OrchestratorError::FindingsCorrupted { task_id, reason }),
+        }
     }
 
     /// Check if findings exist for a task
@@ -536,44 +1234,215 @@ impl FileManager {
             .unwrap_or(false)
     }
 
-    /// Delete findings file for a task
+    /// Delete findings file for a task, along with its backup and
+    /// translations
     pub async fn delete_findings(&self, task_id: Uuid) -> Result<()> {
-        let path = self.findings_path(task_id);
-        if fs::try_exists(&path).await.unwrap_or(false) {
-            fs::remove_file(&path).await.map_err(|e| {
-                OrchestratorError::ExecutionFailed(format!(
-                    "Failed to delete findings file {:?}: {}",
-                    path, e
-                ))
-            })?;
+        let mut paths = vec![
+            self.findings_path(task_id),
+            self.findings_backup_path(task_id),
+        ];
+        let translation_prefix = format!("{}.", task_id);
+        if let Ok(mut entries) = fs::read_dir(self.findings_dir()).await {
+            while let Ok(Some(entry)) = entries.next_entry().await {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                if name.starts_with(&translation_prefix)
+                    && name.ends_with(".json")
+                    && entry.path() != paths[0]
+                {
+                    paths.push(entry.path());
+                }
+            }
+        }
+        for path in paths {
+            if fs::try_exists(&path).await.unwrap_or(false) {
+                fs::remove_file(&path).await.map_err(|e| {
+                    OrchestratorError::ExecutionFailed(format!(
+                        "Failed to delete findings file {:?}: {}",
+                        path, e
+                    ))
+                })?;
+            }
         }
         Ok(())
     }
 
-    /// Update status of specific findings in the file
-    pub async fn update_findings_status(
+    /// Read the cached translation of a task's findings, if there is one
+    pub async fn read_findings_translation(
         &self,
         task_id: Uuid,
-        finding_ids: &[String],
-        status: FindingStatus,
-    ) -> Result<()> {
-        let mut findings = self
-            .read_findings(task_id)
-            .await?
-            .ok_or_else(|| OrchestratorError::ExecutionFailed("Findings file not found".into()))?;
-
-        for finding in &mut findings.findings {
-            if finding_ids.contains(&finding.id) {
-                finding.status = status;
+        language: &str,
+    ) -> Result<Option<FindingsTranslation>> {
+        let path = self.findings_translation_path(task_id, language);
+        let content = match fs::read_to_string(&path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(OrchestratorError::ExecutionFailed(format!(
+                    "Failed to read findings translation {:?}: {}",
+                    path, e
+                )))
+            }
+        };
+        match serde_json::from_str(&content) {
+            Ok(translation) => Ok(Some(translation)),
+            Err(e) => {
+                // A damaged cache is only a missed translation
+                warn!(path = ?path, error = %e, "Ignoring unreadable findings translation");
+                Ok(None)
             }
         }
-
-        self.write_findings(task_id, &findings).await?;
-        Ok(())
     }
 
-    /// Mark all pending findings as skipped
+    /// Cache a translation of a task's findings (atomic write)
+    pub async fn write_findings_translation(
+        &self,
+        task_id: Uuid,
+        translation: &FindingsTranslation,
+    ) -> Result<PathBuf> {
+        let path = self.findings_translation_path(task_id, &translation.language);
+        let temp_path = self.findings_dir().join(format!(".{}.tmp", Uuid::new_v4()));
+        let json = serde_json::to_string_pretty(translation)
+            .map_err(|e| OrchestratorError::Serialization(e.to_string()))?;
+
+        fs::create_dir_all(self.findings_dir()).await?;
+        fs::write(&temp_path, json).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to write findings translation {:?}: {}",
+                temp_path, e
+            ))
+        })?;
+        fs::rename(&temp_path, &path).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to rename findings translation to {:?}: {}",
+                path, e
+            ))
+        })?;
+        debug!("Findings translation written to {:?}", path);
+        Ok(path)
+    }
+
+    /// Update status of specific findings in the file
+    pub async fn update_findings_status(
+        &self,
+        task_id: Uuid,
+        finding_ids: &[String],
+        status: FindingStatus,
+    ) -> Result<()> {
+        let _lock = self.lock_findings(task_id).await?;
+        let mut findings = self
+            .read_findings(task_id)
+            .await?
+            .ok_or_else(|| OrchestratorError::ExecutionFailed("Findings file not found".into()))?;
+
+        for finding in &mut findings.findings {
+            if finding_ids.contains(&finding.id) {
+                finding.status = status;
+            }
+        }
+
+        self.write_findings_locked(task_id, &findings).await?;
+        Ok(())
+    }
+
+    /// Set the status of several findings with a single write of the
+    /// findings file. Returns each requested ID with the finding's previous
+    /// status, or `None` if the task has no finding with that ID.
+    pub async fn set_findings_status(
+        &self,
+        task_id: Uuid,
+        finding_ids: &[String],
+        status: FindingStatus,
+    ) -> Result<Vec<(String, Option<FindingStatus>)>> {
+        let _lock = self.lock_findings(task_id).await?;
+        let mut findings = self
+            .read_findings(task_id)
+            .await?
+            .ok_or(OrchestratorError::FindingsNotFound(task_id))?;
+
+        let mut changed = false;
+        let results = finding_ids
+            .iter()
+            .map(|id| {
+                let previous = findings
+                    .findings
+                    .iter_mut()
+                    .find(|f| &f.id == id)
+                    .map(|finding| {
+                        let previous = finding.status;
+                        changed |= previous != status;
+                        finding.status = status;
+                        previous
+                    });
+                (id.clone(), previous)
+            })
+            .collect();
+
+        if changed {
+            self.write_findings_locked(task_id, &findings).await?;
+        }
+        Ok(results)
+    }
+
+    /// Add a comment to the discussion of a finding. Returns `None` if the
+    /// task has no finding with that ID.
+    pub async fn add_finding_comment(
+        &self,
+        task_id: Uuid,
+        finding_id: &str,
+        comment: FindingComment,
+    ) -> Result<Option<FindingComment>> {
+        let _lock = self.lock_findings(task_id).await?;
+        let mut findings = self
+            .read_findings(task_id)
+            .await?
+            .ok_or(OrchestratorError::FindingsNotFound(task_id))?;
+
+        let Some(finding) = findings.findings.iter_mut().find(|f| f.id == finding_id) else {
+            return Ok(None);
+        };
+        finding.comments.push(comment.clone());
+
+        self.write_findings_locked(task_id, &findings).await?;
+        Ok(Some(comment))
+    }
+
+    /// Comments on a finding, oldest first. Returns `None` if the task has
+    /// no finding with that ID.
+    pub async fn finding_comments(
+        &self,
+        task_id: Uuid,
+        finding_id: &str,
+    ) -> Result<Option<Vec<FindingComment>>> {
+        let findings = self
+            .read_findings(task_id)
+            .await?
+            .ok_or(OrchestratorError::FindingsNotFound(task_id))?;
+        Ok(findings
+            .findings
+            .into_iter()
+            .find(|f| f.id == finding_id)
+            .map(|f| f.comments))
+    }
+
+    /// Add `finding` to the task's findings, starting them if the task was
+    /// never reviewed; the review no longer counts as approved
+    pub async fn add_finding(&self, task_id: Uuid, finding: ReviewFinding) -> Result<()> {
+        let _lock = self.lock_findings(task_id).await?;
+        let mut findings = match self.read_findings(task_id).await? {
+            Some(findings) => findings,
+            None => ReviewFindings::with_findings(task_id, Uuid::nil(), String::new(), Vec::new()),
+        };
+
+        findings.findings.push(finding);
+        findings.approved = false;
+
+        self.write_findings_locked(task_id, &findings).await?;
+        Ok(())
+    }
+
+    /// Mark all pending findings as skipped
     pub async fn skip_all_findings(&self, task_id: Uuid) -> Result<()> {
+        let _lock = self.lock_findings(task_id).await?;
         let mut findings = self
             .read_findings(task_id)
             .await?
@@ -585,7 +1454,7 @@ impl FileManager {
             }
         }
 
-        self.write_findings(task_id, &findings).await?;
+        self.write_findings_locked(task_id, &findings).await?;
         Ok(())
     }
 
@@ -620,6 +1489,47 @@ impl FileManager {
         self.phases_dir(task_id).join("context.json")
     }
 
+    /// Get the path to the record of code already shown to a task's sessions
+    pub fn seen_context_path(&self, task_id: Uuid) -> PathBuf {
+        self.phases_dir(task_id).join("seen-context.json")
+    }
+
+    /// Get the path to the task environment directory
+    pub fn env_dir(&self) -> PathBuf {
+        self.base_path
+            .join(STUDIO_DIR)
+            .join(KANBAN_DIR)
+            .join(ENV_DIR)
+    }
+
+    /// Get the path to a task's environment variable definitions
+    pub fn task_env_path(&self, task_id: Uuid) -> PathBuf {
+        self.env_dir().join(format!("{}.json", task_id))
+    }
+
+    /// Get the path to the resolved env file sessions of a task source
+    pub fn task_env_file_path(&self, task_id: Uuid) -> PathBuf {
+        self.env_dir().join(format!("{}.env", task_id))
+    }
+
+    /// Get the path to a task's CI gate
+    pub fn ci_gate_path(&self, task_id: Uuid) -> PathBuf {
+        self.base_path
+            .join(STUDIO_DIR)
+            .join(KANBAN_DIR)
+            .join(CI_DIR)
+            .join(format!("{}.json", task_id))
+    }
+
+    /// Get the path to a task's phase models
+    pub fn task_models_path(&self, task_id: Uuid) -> PathBuf {
+        self.base_path
+            .join(STUDIO_DIR)
+            .join(KANBAN_DIR)
+            .join(MODELS_DIR)
+            .join(format!("{}.json", task_id))
+    }
+
     /// Get the path to a phase summary file
     pub fn phase_summary_path(&self, task_id: Uuid, phase_number: u32) -> PathBuf {
         self.phases_dir(task_id)
@@ -699,7 +1609,272 @@ impl FileManager {
             ))
         })?;
 
-        Ok(Some(context))
+        Ok(Some(context))
+    }
+
+    /// Write the record of code shown to a task's sessions (atomic write)
+    pub async fn write_seen_context(&self, seen: &SeenContext) -> Result<PathBuf> {
+        self.ensure_phases_dir(seen.task_id).await?;
+        let path = self.seen_context_path(seen.task_id);
+        let temp_path = self.phases_dir(seen.task_id).join(".seen-context.tmp");
+
+        debug!(
+            task_id = %seen.task_id,
+            ranges = seen.ranges.len(),
+            "Writing seen context"
+        );
+
+        let json = serde_json::to_string_pretty(seen).map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!("Failed to serialize seen context: {}", e))
+        })?;
+
+        fs::write(&temp_path, &json).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to write temp seen context {:?}: {}",
+                temp_path, e
+            ))
+        })?;
+
+        fs::rename(&temp_path, &path).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to rename seen context {:?} -> {:?}: {}",
+                temp_path, path, e
+            ))
+        })?;
+
+        Ok(path)
+    }
+
+    /// Read the record of code shown to a task's sessions; empty when nothing
+    /// was recorded yet
+    pub async fn read_seen_context(&self, task_id: Uuid) -> Result<SeenContext> {
+        let path = self.seen_context_path(task_id);
+
+        if !fs::try_exists(&path).await.unwrap_or(false) {
+            return Ok(SeenContext::new(task_id));
+        }
+
+        let content = fs::read_to_string(&path).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to read seen context {:?}: {}",
+                path, e
+            ))
+        })?;
+
+        serde_json::from_str(&content).map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to parse seen context {:?}: {}",
+                path, e
+            ))
+        })
+    }
+
+    async fn ensure_env_dir(&self) -> Result<()> {
+        let dir = self.env_dir();
+        fs::create_dir_all(&dir).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to create env directory {:?}: {}",
+                dir, e
+            ))
+        })?;
+
+        // Resolved env files hold secret values and must never be committed
+        let gitignore = dir.join(".gitignore");
+        if !fs::try_exists(&gitignore).await.unwrap_or(false) {
+            fs::write(&gitignore, "*.env\n").await.map_err(|e| {
+                OrchestratorError::ExecutionFailed(format!(
+                    "Failed to write {:?}: {}",
+                    gitignore, e
+                ))
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Write a task's environment variable definitions (atomic write)
+    pub async fn write_task_env(&self, task_id: Uuid, vars: &[EnvVar]) -> Result<PathBuf> {
+        self.ensure_env_dir().await?;
+        let path = self.task_env_path(task_id);
+        let temp_path = self.env_dir().join(format!(".{}.tmp", task_id));
+
+        debug!(task_id = %task_id, vars = vars.len(), "Writing task environment");
+
+        let json = serde_json::to_string_pretty(vars).map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!("Failed to serialize task env: {}", e))
+        })?;
+
+        fs::write(&temp_path, &json).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to write temp task env {:?}: {}",
+                temp_path, e
+            ))
+        })?;
+
+        fs::rename(&temp_path, &path).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to rename task env {:?} -> {:?}: {}",
+                temp_path, path, e
+            ))
+        })?;
+
+        Ok(path)
+    }
+
+    /// Read a task's environment variable definitions; empty when it has none
+    pub async fn read_task_env(&self, task_id: Uuid) -> Result<Vec<EnvVar>> {
+        let path = self.task_env_path(task_id);
+
+        if !fs::try_exists(&path).await.unwrap_or(false) {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(&path).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!("Failed to read task env {:?}: {}", path, e))
+        })?;
+
+        serde_json::from_str(&content).map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to parse task env {:?}: {}",
+                path, e
+            ))
+        })
+    }
+
+    /// Write the resolved env file for a task's sessions, readable only by
+    /// the owner since it holds secret values
+    pub async fn write_task_env_file(&self, task_id: Uuid, content: &str) -> Result<PathBuf> {
+        self.ensure_env_dir().await?;
+        let path = self.task_env_file_path(task_id);
+
+        fs::write(&path, content).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to write env file {:?}: {}",
+                path, e
+            ))
+        })?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600))
+                .await
+                .map_err(|e| {
+                    OrchestratorError::ExecutionFailed(format!(
+                        "Failed to restrict env file {:?}: {}",
+                        path, e
+                    ))
+                })?;
+        }
+
+        Ok(path)
+    }
+
+    /// Write the phase models a task uses instead of the project's (atomic
+    /// write)
+    pub async fn write_task_models(&self, task_id: Uuid, models: &PhaseModels) -> Result<PathBuf> {
+        let path = self.task_models_path(task_id);
+        let dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        fs::create_dir_all(&dir).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to create models directory {:?}: {}",
+                dir, e
+            ))
+        })?;
+        let temp_path = dir.join(format!(".{}.tmp", task_id));
+
+        let json = serde_json::to_string_pretty(models).map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!("Failed to serialize task models: {}", e))
+        })?;
+
+        fs::write(&temp_path, &json).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to write temp task models {:?}: {}",
+                temp_path, e
+            ))
+        })?;
+
+        fs::rename(&temp_path, &path).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to rename task models {:?} -> {:?}: {}",
+                temp_path, path, e
+            ))
+        })?;
+
+        Ok(path)
+    }
+
+    /// Read a task's phase models; `None` when it uses the project's
+    pub async fn read_task_models(&self, task_id: Uuid) -> Result<Option<PhaseModels>> {
+        let path = self.task_models_path(task_id);
+
+        if !fs::try_exists(&path).await.unwrap_or(false) {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(&path).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to read task models {:?}: {}",
+                path, e
+            ))
+        })?;
+
+        serde_json::from_str(&content).map(Some).map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to parse task models {:?}: {}",
+                path, e
+            ))
+        })
+    }
+
+    /// Write the CI gate of a task (atomic write)
+    pub async fn write_ci_gate(&self, record: &CiGateRecord) -> Result<PathBuf> {
+        let path = self.ci_gate_path(record.task_id);
+        let dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        fs::create_dir_all(&dir).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to create CI directory {:?}: {}",
+                dir, e
+            ))
+        })?;
+        let temp_path = dir.join(format!(".{}.tmp", record.task_id));
+
+        let json = serde_json::to_string_pretty(record).map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!("Failed to serialize CI gate: {}", e))
+        })?;
+
+        fs::write(&temp_path, &json).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to write temp CI gate {:?}: {}",
+                temp_path, e
+            ))
+        })?;
+
+        fs::rename(&temp_path, &path).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to rename CI gate {:?} -> {:?}: {}",
+                temp_path, path, e
+            ))
+        })?;
+
+        Ok(path)
+    }
+
+    /// Read the CI gate of a task; `None` when its completion waits on no
+    /// checks
+    pub async fn read_ci_gate(&self, task_id: Uuid) -> Result<Option<CiGateRecord>> {
+        let path = self.ci_gate_path(task_id);
+
+        if !fs::try_exists(&path).await.unwrap_or(false) {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(&path).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!("Failed to read CI gate {:?}: {}", path, e))
+        })?;
+
+        serde_json::from_str(&content).map(Some).map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!("Failed to parse CI gate {:?}: {}", path, e))
+        })
     }
 
     /// Write a phase summary to file (atomic write)
@@ -863,6 +2038,286 @@ impl FileManager {
             .await
             .unwrap_or(false)
     }
+
+    // ========================================================================
+    // Review History Methods
+    // ========================================================================
+
+    /// Get the path to the review history directory of a task
+    pub fn history_dir(&self, task_id: Uuid) -> PathBuf {
+        self.base_path
+            .join(STUDIO_DIR)
+            .join(KANBAN_DIR)
+            .join(HISTORY_DIR)
+            .join(task_id.to_string())
+    }
+
+    /// Record the files a task's changes touch as a review starts
+    pub async fn record_changed_files(
+        &self,
+        task_id: Uuid,
+        changes: &ChangedFiles,
+    ) -> Result<PathBuf> {
+        let json = serde_json::to_string_pretty(changes).map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!("Failed to serialize changed files: {}", e))
+        })?;
+        let name = format!("changes-{}.json", changes.recorded_at.timestamp_millis());
+        self.write_history_file(task_id, &name, &json).await
+    }
+
+    async fn write_history_file(&self, task_id: Uuid, name: &str, json: &str) -> Result<PathBuf> {
+        let dir = self.history_dir(task_id);
+        fs::create_dir_all(&dir).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to create history directory {:?}: {}",
+                dir, e
+            ))
+        })?;
+
+        let path = dir.join(name);
+        fs::write(&path, json).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to write history file {:?}: {}",
+                path, e
+            ))
+        })?;
+        Ok(path)
+    }
+
+    /// Read a file of the workspace by its relative path, or its absolute
+    /// path inside the workspace. `None` if the file doesn't exist; paths
+    /// that lead out of the workspace are an error.
+    pub async fn read_workspace_file(&self, path: &str) -> Result<Option<String>> {
+        let full = normalize_path(&self.base_path.join(path));
+        if !full.starts_with(&self.base_path) {
+            return Err(OrchestratorError::ExecutionFailed(format!(
+                "{} is outside the workspace",
+                path
+            )));
+        }
+        match fs::read_to_string(&full).await {
+            Ok(content) => Ok(Some(content)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(OrchestratorError::ExecutionFailed(format!(
+                "Failed to read {}: {}",
+                path, e
+            ))),
+        }
+    }
+
+    /// Apply a unified diff to the workspace, returning the paths it changed.
+    ///
+    /// Every file is patched in memory first, so a hunk that doesn't match
+    /// leaves the workspace untouched.
+    pub async fn apply_patch(&self, patch: &str) -> Result<Vec<String>> {
+        let files = parse_patch(patch).map_err(OrchestratorError::ExecutionFailed)?;
+
+        let mut changes: Vec<(PathBuf, Option<String>)> = Vec::with_capacity(files.len());
+        for file in &files {
+            let path = self.base_path.join(file.path());
+            let original = match &file.old_path {
+                Some(old_path) => {
+                    let old = self.base_path.join(old_path);
+                    fs::read_to_string(&old).await.map_err(|e| {
+                        OrchestratorError::ExecutionFailed(format!(
+                            "Failed to read {} for patching: {}",
+                            old_path, e
+                        ))
+                    })?
+                }
+                None if fs::try_exists(&path).await.unwrap_or(false) => {
+                    return Err(OrchestratorError::ExecutionFailed(format!(
+                        "Patch creates {}, which already exists",
+                        file.path()
+                    )));
+                }
+                None => String::new(),
+            };
+            if file.is_deletion() {
+                changes.push((path, None));
+                continue;
+            }
+            let patched = file
+                .apply(&original)
+                .map_err(OrchestratorError::ExecutionFailed)?;
+            changes.push((path, Some(patched)));
+        }
+
+        for (file, (path, content)) in files.iter().zip(changes) {
+            match content {
+                Some(content) => {
+                    if let Some(parent) = path.parent() {
+                        fs::create_dir_all(parent).await?;
+                    }
+                    fs::write(&path, content).await?;
+                }
+                None => fs::remove_file(&path).await?,
+            }
+            // A rename leaves the old path behind
+            if let (Some(old), Some(new)) = (&file.old_path, &file.new_path) {
+                if old != new {
+                    fs::remove_file(self.base_path.join(old)).await?;
+                }
+            }
+        }
+
+        let paths: Vec<String> = files.iter().map(|f| f.path().to_string()).collect();
+        info!("Applied patch to {}", paths.join(", "));
+        Ok(paths)
+    }
+
+    /// Read every review recorded for a task, oldest first
+    pub async fn read_review_history(&self, task_id: Uuid) -> Result<ReviewHistory> {
+        let dir = self.history_dir(task_id);
+        let mut history = ReviewHistory::default();
+
+        if !fs::try_exists(&dir).await.unwrap_or(false) {
+            return Ok(history);
+        }
+
+        let mut entries = fs::read_dir(&dir).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to read history directory {:?}: {}",
+                dir, e
+            ))
+        })?;
+
+        while let Some(entry) = entries.next_entry().await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!("Failed to read directory entry: {}", e))
+        })? {
+            let path = entry.path();
+            let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            let is_findings = file_name.starts_with("findings-");
+            if !file_name.ends_with(".json") || !(is_findings || file_name.starts_with("changes-"))
+            {
+                continue;
+            }
+
+            let content = fs::read_to_string(&path).await.map_err(|e| {
+                OrchestratorError::ExecutionFailed(format!(
+                    "Failed to read history file {:?}: {}",
+                    path, e
+                ))
+            })?;
+            let parsed = if is_findings {
+                serde_json::from_str(&content).map(|f| history.findings.push(f))
+            } else {
+                serde_json::from_str(&content).map(|c| history.changes.push(c))
+            };
+            if let Err(e) = parsed {
+                debug!("Skipping unreadable history file {:?}: {}", path, e);
+            }
+        }
+
+        history.findings.sort_by_key(|f| f.created_at);
+        history.changes.sort_by_key(|c| c.recorded_at);
+        Ok(history)
+    }
+
+    // ========================================================================
+    // Release Review Methods
+    // ========================================================================
+
+    /// Get the path to the release reviews directory
+    pub fn releases_dir(&self) -> PathBuf {
+        self.base_path
+            .join(STUDIO_DIR)
+            .join(KANBAN_DIR)
+            .join(RELEASES_DIR)
+    }
+
+    /// Get the path to a release review report
+    pub fn release_review_path(&self, id: Uuid) -> PathBuf {
+        self.releases_dir().join(format!("{}.json", id))
+    }
+
+    /// Write a release review report (atomic write)
+    pub async fn write_release_review(&self, report: &ReleaseReviewReport) -> Result<PathBuf> {
+        let dir = self.releases_dir();
+        fs::create_dir_all(&dir).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to create releases directory {:?}: {}",
+                dir, e
+            ))
+        })?;
+
+        let path = self.release_review_path(report.id);
+        let temp_path = dir.join(format!(".{}.tmp", report.id));
+        let json = serde_json::to_string_pretty(report)
+            .map_err(|e| OrchestratorError::Serialization(e.to_string()))?;
+        fs::write(&temp_path, json).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to write release review {:?}: {}",
+                temp_path, e
+            ))
+        })?;
+        fs::rename(&temp_path, &path).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to rename release review to {:?}: {}",
+                path, e
+            ))
+        })?;
+        Ok(path)
+    }
+
+    /// Read a release review report, if there is one
+    pub async fn read_release_review(&self, id: Uuid) -> Result<Option<ReleaseReviewReport>> {
+        let path = self.release_review_path(id);
+        let content = match fs::read_to_string(&path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(OrchestratorError::ExecutionFailed(format!(
+                    "Failed to read release review {:?}: {}",
+                    path, e
+                )))
+            }
+        };
+        let report = serde_json::from_str(&content).map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to parse release review {:?}: {}",
+                path, e
+            ))
+        })?;
+        Ok(Some(report))
+    }
+
+    /// Read every release review report, newest first
+    pub async fn list_release_reviews(&self) -> Result<Vec<ReleaseReviewReport>> {
+        let dir = self.releases_dir();
+        let mut reports: Vec<ReleaseReviewReport> = Vec::new();
+
+        if !fs::try_exists(&dir).await.unwrap_or(false) {
+            return Ok(reports);
+        }
+
+        let mut entries = fs::read_dir(&dir).await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to read releases directory {:?}: {}",
+                dir, e
+            ))
+        })?;
+        while let Some(entry) = entries.next_entry().await.map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!("Failed to read directory entry: {}", e))
+        })? {
+            let path = entry.path();
+            let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            if file_name.starts_with('.') || !file_name.ends_with(".json") {
+                continue;
+            }
+            let parsed = fs::read_to_string(&path)
+                .await
+                .map_err(|e| e.to_string())
+                .and_then(|c| serde_json::from_str(&c).map_err(|e| e.to_string()));
+            match parsed {
+                Ok(report) => reports.push(report),
+                Err(e) => debug!("Skipping unreadable release review {:?}: {}", path, e),
+            }
+        }
+
+        reports.sort_by_key(|r| std::cmp::Reverse(r.created_at));
+        Ok(reports)
+    }
 }
 
 impl Default for FileManager {
@@ -874,6 +2329,7 @@ impl Default for FileManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::path_guard::PathViolation;
     use tempfile::TempDir;
 
     async fn setup_test_file_manager() -> (FileManager, TempDir) {
@@ -905,6 +2361,395 @@ mod tests {
         assert_eq!(read_content, content);
     }
 
+    #[tokio::test]
+    async fn test_write_plan_with_sources() {
+        let (fm, _temp_dir) = setup_test_file_manager().await;
+        let task_id = Uuid::new_v4();
+        let sources = vec!["`src/auth/login.rs:3-12`".to_string()];
+
+        fm.write_plan_with_sources(task_id, "# Plan\n", &sources)
+            .await
+            .unwrap();
+        let plan = fm.read_plan(task_id).await.unwrap();
+        assert!(plan.starts_with("# Plan\n\n## Consulted Sources"));
+        assert!(plan.contains("- `src/auth/login.rs:3-12`"));
+
+        fm.write_plan_with_sources(task_id, "# Plan", &[])
+            .await
+            .unwrap();
+        assert_eq!(fm.read_plan(task_id).await.unwrap(), "# Plan");
+    }
+
+    #[test]
+    fn test_validate_requires_criteria_for_errors() {
+        let mut finding = ReviewFinding {
+            id: "finding-1".to_string(),
+            file_path: None,
+            line_start: None,
+            line_end: None,
+            title: "Title".to_string(),
+            description: "Description".to_string(),
+            severity: FindingSeverity::Warning,
+            status: FindingStatus::Pending,
+            original_severity: None,
+            severity_rationale: None,
+            reproduction: None,
+            acceptance_criteria: None,
+            suggested_fix: None,
+            consensus_score: None,
+            comments: Vec::new(),
+            snippet: None,
+        };
+        assert!(finding.validate().is_ok());
+
+        finding.severity = FindingSeverity::Error;
+        assert!(finding.validate().is_err());
+
+        finding.acceptance_criteria = Some("  ".to_string());
+        assert!(finding.validate().is_err());
+
+        finding.acceptance_criteria = Some("Returns an error".to_string());
+        assert!(finding.validate().is_ok());
+    }
+
+    #[test]
+    fn test_anchor_to_source_clamps_range() {
+        let finding = |line_start: Option<i32>, line_end: Option<i32>| ReviewFinding {
+            id: "finding-1".to_string(),
+            file_path: Some("src/lib.rs".to_string()),
+            line_start,
+            line_end,
+            title: "Title".to_string(),
+            description: "Description".to_string(),
+            severity: FindingSeverity::Warning,
+            status: FindingStatus::Pending,
+            original_severity: None,
+            severity_rationale: None,
+            reproduction: None,
+            acceptance_criteria: None,
+            suggested_fix: None,
+            consensus_score: None,
+            comments: Vec::new(),
+            snippet: None,
+        };
+        let source: String = (1..=100).map(|i| format!("line {}\n", i)).collect();
+
+        let mut single = finding(Some(9), None);
+        assert_eq!(single.anchor_to_source(&source), Ok(false));
+        assert_eq!(single.snippet.as_deref(), Some("9 | line 9\n"));
+
+        let mut clamped = finding(Some(0), Some(120));
+        assert_eq!(clamped.anchor_to_source(&source), Ok(true));
+        assert_eq!((clamped.line_start, clamped.line_end), (Some(1), Some(100)));
+        let snippet = clamped.snippet.unwrap();
+        assert!(snippet.starts_with(" 1 | line 1\n"));
+        assert!(snippet.ends_with("40 | line 40\n... 60 more lines\n"));
+
+        assert!(finding(Some(101), None).anchor_to_source(&source).is_err());
+        assert!(finding(Some(1), None).anchor_to_source("").is_err());
+
+        let mut whole_file = finding(None, None);
+        assert_eq!(whole_file.anchor_to_source(&source), Ok(false));
+        assert!(whole_file.snippet.is_none());
+    }
+
+    #[test]
+    fn test_changed_files_from_diff() {
+        let diff = "diff --git a/src/lib.rs b/src/lib.rs\n--- a/src/lib.rs\n+++ b/src/lib.rs\n\
+                    @@ -1 +1 @@\n-old\n+new\n\
+                    diff --git a/old.rs b/new.rs\nrename from old.rs\n\
+                    diff --git a/src/lib.rs b/src/lib.rs\n";
+
+        let changes = ChangedFiles::from_diff(diff);
+
+        assert_eq!(changes.files, vec!["src/lib.rs", "new.rs"]);
+    }
+
+    #[tokio::test]
+    async fn test_review_history() {
+        let (fm, _temp_dir) = setup_test_file_manager().await;
+        let task_id = Uuid::new_v4();
+        assert!(fm
+            .read_review_history(task_id)
+            .await
+            .unwrap()
+            .findings
+            .is_empty());
+
+        let mut first =
+            ReviewFindings::approved(task_id, Uuid::new_v4(), "First review".to_string());
+        first.created_at = Utc::now() - chrono::Duration::hours(1);
+        fm.write_findings(task_id, &first).await.unwrap();
+        // Rewriting the same review replaces its copy
+        first.summary = "First review, updated".to_string();
+        fm.write_findings(task_id, &first).await.unwrap();
+        let second = ReviewFindings::approved(task_id, Uuid::new_v4(), "Second".to_string());
+        fm.write_findings(task_id, &second).await.unwrap();
+        fm.record_changed_files(
+            task_id,
+            &ChangedFiles::from_diff("diff --git a/a.rs b/a.rs"),
+        )
+        .await
+        .unwrap();
+
+        let history = fm.read_review_history(task_id).await.unwrap();
+
+        assert_eq!(history.findings.len(), 2);
+        assert_eq!(history.findings[0].summary, "First review, updated");
+        assert_eq!(history.findings[1].summary, "Second");
+        assert_eq!(history.changes.len(), 1);
+        assert_eq!(history.changes[0].files, vec!["a.rs"]);
+    }
+
+    #[tokio::test]
+    async fn test_set_findings_status() {
+        let (fm, _temp_dir) = setup_test_file_manager().await;
+        let task_id = Uuid::new_v4();
+        let finding = |id: &str| ReviewFinding {
+            id: id.to_string(),
+            file_path: None,
+            line_start: None,
+            line_end: None,
+            title: "Title".to_string(),
+            description: "Description".to_string(),
+            severity: FindingSeverity::Warning,
+            status: FindingStatus::Pending,
+            original_severity: None,
+            severity_rationale: None,
+            reproduction: None,
+            acceptance_criteria: None,
+            suggested_fix: None,
+            consensus_score: None,
+            comments: Vec::new(),
+            snippet: None,
+        };
+        let findings = ReviewFindings::with_findings(
+            task_id,
+            Uuid::new_v4(),
+            "Summary".to_string(),
+            vec![finding("finding-1"), finding("finding-2")],
+        );
+        fm.write_findings(task_id, &findings).await.unwrap();
+
+        let results = fm
+            .set_findings_status(
+                task_id,
+                &["finding-1".to_string(), "missing".to_string()],
+                FindingStatus::Dismissed,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            results,
+            vec![
+                ("finding-1".to_string(), Some(FindingStatus::Pending)),
+                ("missing".to_string(), None),
+            ]
+        );
+
+        let findings = fm.read_findings(task_id).await.unwrap().unwrap();
+        assert_eq!(findings.findings[0].status, FindingStatus::Dismissed);
+        assert_eq!(findings.findings[1].status, FindingStatus::Pending);
+
+        assert!(fm
+            .set_findings_status(Uuid::new_v4(), &[], FindingStatus::Fixed)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_finding_comments() {
+        let (fm, _temp_dir) = setup_test_file_manager().await;
+        let task_id = Uuid::new_v4();
+        let finding = ReviewFinding {
+            id: "finding-1".to_string(),
+            file_path: None,
+            line_start: None,
+            line_end: None,
+            title: "Title".to_string(),
+            description: "Description".to_string(),
+            severity: FindingSeverity::Warning,
+            status: FindingStatus::Pending,
+            original_severity: None,
+            severity_rationale: None,
+            reproduction: None,
+            acceptance_criteria: None,
+            suggested_fix: None,
+            consensus_score: None,
+            comments: Vec::new(),
+            snippet: None,
+        };
+        let findings = ReviewFindings::with_findings(
+            task_id,
+            Uuid::new_v4(),
+            "Summary".to_string(),
+            vec![finding],
+        );
+        fm.write_findings(task_id, &findings).await.unwrap();
+
+        let question = FindingComment::new(FindingCommentAuthor::Human, "Is this reachable?");
+        fm.add_finding_comment(task_id, "finding-1", question)
+            .await
+            .unwrap()
+            .unwrap();
+        let answer = FindingComment::new(FindingCommentAuthor::Agent, "Yes, from the parser");
+        fm.add_finding_comment(task_id, "finding-1", answer)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(fm
+            .add_finding_comment(
+                task_id,
+                "missing",
+                FindingComment::new(FindingCommentAuthor::Human, "?")
+            )
+            .await
+            .unwrap()
+            .is_none());
+
+        let comments = fm
+            .finding_comments(task_id, "finding-1")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(comments.len(), 2);
+        assert_eq!(comments[0].author, FindingCommentAuthor::Human);
+        assert_eq!(comments[1].body, "Yes, from the parser");
+        assert!(fm
+            .finding_comments(task_id, "missing")
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_write_findings_keeps_path_violations() {
+        let (fm, _temp_dir) = setup_test_file_manager().await;
+        let task_id = Uuid::new_v4();
+        let violation = PathViolation {
+            tool: "write".to_string(),
+            path: "/etc/hosts".to_string(),
+        }
+        .finding(false);
+        fm.add_finding(task_id, violation.clone()).await.unwrap();
+
+        // A review written afterwards replaces the reviewer's findings only
+        let review = ReviewFindings::approved(task_id, Uuid::new_v4(), "Looks good".to_string());
+        fm.write_findings(task_id, &review).await.unwrap();
+        fm.write_findings(task_id, &review).await.unwrap();
+
+        let stored = fm.read_findings(task_id).await.unwrap().unwrap();
+        assert_eq!(stored.summary, "Looks good");
+        assert_eq!(stored.findings.len(), 1);
+        assert_eq!(stored.findings[0].id, violation.id);
+        assert!(!stored.approved);
+    }
+
+    #[tokio::test]
+    async fn test_findings_integrity_and_recovery() {
+        let (fm, _temp_dir) = setup_test_file_manager().await;
+        let task_id = Uuid::new_v4();
+
+        let first = ReviewFindings::approved(task_id, Uuid::new_v4(), "First".to_string());
+        fm.write_findings(task_id, &first).await.unwrap();
+        let written: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(fm.findings_path(task_id)).unwrap())
+                .unwrap();
+        assert_eq!(written["schema_version"], FINDINGS_SCHEMA_VERSION);
+        assert_eq!(written["checksum"].as_str().unwrap().len(), 64);
+
+        let second = ReviewFindings::approved(task_id, Uuid::new_v4(), "Second".to_string());
+        fm.write_findings(task_id, &second).await.unwrap();
+
+        // A tampered file fails its checksum and the previous write is used
+        let tampered = std::fs::read_to_string(fm.findings_path(task_id))
+            .unwrap()
+            .replace("Second", "Tampered");
+        std::fs::write(fm.findings_path(task_id), tampered).unwrap();
+        let recovered = fm.read_findings(task_id).await.unwrap().unwrap();
+        assert_eq!(recovered.summary, "First");
+
+        // A torn write with no intact backup is an error, not missing findings
+        std::fs::write(fm.findings_path(task_id), "{\"task_id\":").unwrap();
+        std::fs::remove_file(fm.findings_backup_path(task_id)).unwrap();
+        assert!(matches!(
+            fm.read_findings(task_id).await,
+            Err(OrchestratorError::FindingsCorrupted { .. })
+        ));
+
+        // Files from before the header existed are read as they are
+        let legacy = serde_json::to_string(&first).unwrap();
+        std::fs::write(fm.findings_path(task_id), legacy).unwrap();
+        assert_eq!(
+            fm.read_findings(task_id).await.unwrap().unwrap().summary,
+            "First"
+        );
+
+        let newer = format!(
+            "{{\"schema_version\":{},\"checksum\":\"\"}}",
+            FINDINGS_SCHEMA_VERSION + 1
+        );
+        std::fs::write(fm.findings_path(task_id), newer).unwrap();
+        assert!(matches!(
+            fm.read_findings(task_id).await,
+            Err(OrchestratorError::Serialization(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_findings_updates_are_serialized() {
+        let (fm, _temp_dir) = setup_test_file_manager().await;
+        let task_id = Uuid::new_v4();
+        let ids: Vec<String> = (0..8).map(|i| format!("finding-{}", i)).collect();
+        let findings = ReviewFindings::with_findings(
+            task_id,
+            Uuid::new_v4(),
+            "Summary".to_string(),
+            ids.iter()
+                .map(|id| ReviewFinding {
+                    id: id.clone(),
+                    file_path: None,
+                    line_start: None,
+                    line_end: None,
+                    title: "Title".to_string(),
+                    description: "Description".to_string(),
+                    severity: FindingSeverity::Warning,
+                    status: FindingStatus::Pending,
+                    original_severity: None,
+                    severity_rationale: None,
+                    reproduction: None,
+                    acceptance_criteria: None,
+                    suggested_fix: None,
+                    consensus_score: None,
+                    comments: Vec::new(),
+                    snippet: None,
+                })
+                .collect(),
+        );
+        fm.write_findings(task_id, &findings).await.unwrap();
+
+        let updates = ids.iter().cloned().map(|id| {
+            let fm = fm.clone();
+            tokio::spawn(async move {
+                fm.set_findings_status(task_id, &[id], FindingStatus::Fixed)
+                    .await
+            })
+        });
+        for update in futures::future::join_all(updates).await {
+            update.unwrap().unwrap();
+        }
+
+        let findings = fm.read_findings(task_id).await.unwrap().unwrap();
+        assert!(findings
+            .findings
+            .iter()
+            .all(|f| f.status == FindingStatus::Fixed));
+        assert!(!fm
+            .findings_dir()
+            .join(format!(".{}.lock", task_id))
+            .exists());
+    }
+
     #[tokio::test]
     async fn test_write_and_read_review() {
         let (fm, _temp_dir) = setup_test_file_manager().await;
@@ -918,6 +2763,72 @@ mod tests {
         assert_eq!(read_content, content);
     }
 
+    #[tokio::test]
+    async fn test_write_and_read_seen_context() {
+        let (fm, _temp_dir) = setup_test_file_manager().await;
+        let task_id = Uuid::new_v4();
+
+        assert!(fm.read_seen_context(task_id).await.unwrap().is_empty());
+
+        let mut seen = SeenContext::new(task_id);
+        seen.record(crate::seen_context::SeenRange::new(
+            "src/lib.rs",
+            1,
+            20,
+            opencode_core::SessionPhase::Review,
+            "pub fn run() {}",
+        ));
+        fm.write_seen_context(&seen).await.unwrap();
+
+        assert_eq!(fm.read_seen_context(task_id).await.unwrap(), seen);
+    }
+
+    #[tokio::test]
+    async fn test_write_and_read_task_env() {
+        let (fm, _temp_dir) = setup_test_file_manager().await;
+        let task_id = Uuid::new_v4();
+
+        assert!(fm.read_task_env(task_id).await.unwrap().is_empty());
+
+        let vars = vec![
+            EnvVar::literal("FEATURE_X", "on"),
+            EnvVar::secret("DATABASE_URL", "TEST_DATABASE_URL"),
+        ];
+        fm.write_task_env(task_id, &vars).await.unwrap();
+        assert_eq!(fm.read_task_env(task_id).await.unwrap(), vars);
+
+        let path = fm
+            .write_task_env_file(task_id, "export FEATURE_X='on'\n")
+            .await
+            .unwrap();
+        assert_eq!(path, fm.task_env_file_path(task_id));
+        let gitignore = tokio::fs::read_to_string(fm.env_dir().join(".gitignore"))
+            .await
+            .unwrap();
+        assert_eq!(gitignore, "*.env\n");
+    }
+
+    #[tokio::test]
+    async fn test_write_and_read_task_models() {
+        let (fm, _temp_dir) = setup_test_file_manager().await;
+        let task_id = Uuid::new_v4();
+
+        assert!(fm.read_task_models(task_id).await.unwrap().is_none());
+
+        let models = PhaseModels {
+            implementation: Some(crate::services::ModelSelection::new(
+                "anthropic",
+                "claude-opus",
+            )),
+            ..Default::default()
+        };
+        fm.write_task_models(task_id, &models).await.unwrap();
+
+        let read = fm.read_task_models(task_id).await.unwrap().unwrap();
+        assert!(read.planning.is_none());
+        assert_eq!(read.implementation.unwrap().model_id, "claude-opus");
+    }
+
     #[tokio::test]
     async fn test_plan_exists() {
         let (fm, _temp_dir) = setup_test_file_manager().await;
@@ -956,4 +2867,94 @@ mod tests {
             ".opencode-studio/kanban/reviews/550e8400-e29b-41d4-a716-446655440000.md"
         );
     }
+
+    #[tokio::test]
+    async fn test_apply_patch_is_all_or_nothing() {
+        let (fm, temp_dir) = setup_test_file_manager().await;
+        let root = temp_dir.path();
+        std::fs::write(root.join("a.txt"), "one\ntwo\n").unwrap();
+        std::fs::write(root.join("b.txt"), "three\n").unwrap();
+
+        // The second file's hunk doesn't match, so neither file changes
+        let bad = "--- a/a.txt\n+++ b/a.txt\n@@ -1,2 +1,2 @@\n one\n-two\n+2\n--- a/b.txt\n+++ b/b.txt\n@@ -1 +1 @@\n-four\n+4\n";
+        assert!(fm.apply_patch(bad).await.is_err());
+        assert_eq!(
+            std::fs::read_to_string(root.join("a.txt")).unwrap(),
+            "one\ntwo\n"
+        );
+
+        let good = "--- a/a.txt\n+++ b/a.txt\n@@ -1,2 +1,2 @@\n one\n-two\n+2\n--- /dev/null\n+++ b/new/c.txt\n@@ -0,0 +1 @@\n+five\n";
+        let paths = fm.apply_patch(good).await.unwrap();
+        assert_eq!(paths, vec!["a.txt", "new/c.txt"]);
+        assert_eq!(
+            std::fs::read_to_string(root.join("a.txt")).unwrap(),
+            "one\n2\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(root.join("new/c.txt")).unwrap(),
+            "five\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_release_review_links_findings_to_tasks() {
+        let (fm, _temp_dir) = setup_test_file_manager().await;
+        let (first, second) = (Uuid::new_v4(), Uuid::new_v4());
+        let task = |task_id, files: &[&str]| ReleaseReviewTask {
+            task_id,
+            title: "Task".to_string(),
+            files: files.iter().map(|f| f.to_string()).collect(),
+        };
+        let mut report = ReleaseReviewReport::start(
+            Some("v1.0.0".to_string()),
+            vec![
+                task(first, &["src/lib.rs", "src/a.rs"]),
+                task(second, &["src/lib.rs"]),
+            ],
+        );
+        fm.write_release_review(&report).await.unwrap();
+
+        let finding = |id: &str, file_path: Option<&str>| ReviewFinding {
+            id: id.to_string(),
+            file_path: file_path.map(String::from),
+            line_start: None,
+            line_end: None,
+            title: "Title".to_string(),
+            description: "Description".to_string(),
+            severity: FindingSeverity::Warning,
+            status: FindingStatus::Pending,
+            original_severity: None,
+            severity_rationale: None,
+            reproduction: None,
+            acceptance_criteria: None,
+            suggested_fix: None,
+            consensus_score: None,
+            comments: Vec::new(),
+            snippet: None,
+        };
+        report.complete(ReviewFindings::with_findings(
+            report.id,
+            Uuid::new_v4(),
+            "Summary".to_string(),
+            vec![
+                finding("finding-1", Some("./src/lib.rs")),
+                finding("finding-2", Some("src/a.rs")),
+                finding("finding-3", None),
+            ],
+        ));
+        assert_eq!(report.findings[0].task_ids, vec![first, second]);
+        assert_eq!(report.findings[1].task_ids, vec![first]);
+        assert!(report.findings[2].task_ids.is_empty());
+        fm.write_release_review(&report).await.unwrap();
+
+        let read = fm.read_release_review(report.id).await.unwrap().unwrap();
+        assert_eq!(read.status, ReleaseReviewStatus::Completed);
+        assert_eq!(read.findings.len(), 3);
+        assert_eq!(fm.list_release_reviews().await.unwrap().len(), 1);
+        assert!(fm
+            .read_release_review(Uuid::new_v4())
+            .await
+            .unwrap()
+            .is_none());
+    }
 }
@@ -5,31 +5,57 @@ pub mod executor;
 pub mod files;
 pub mod mcp_config;
 pub mod opencode_events;
+pub mod patch;
+pub mod path_guard;
 pub mod phases;
+pub mod plan_context;
 pub mod plan_parser;
 pub mod prompts;
 pub mod resources;
+pub mod review_report;
+pub mod sarif;
+pub mod seen_context;
 pub mod services;
 pub mod session_runner;
 pub mod state_machine;
+pub mod task_env;
+pub mod usage;
+pub mod workflow;
 
 pub use activity_store::{SessionActivityMsg, SessionActivityRegistry, SessionActivityStore};
 pub use error::{OrchestratorError, Result};
 pub use executor::{ExecutorConfig, PhaseResult, ReviewResult, StartedExecution, TaskExecutor};
 pub use files::{
-    FileManager, FindingSeverity, FindingStatus, ParsedPlan, PhaseContext, PhaseSummary, PlanPhase,
-    ReviewFinding, ReviewFindings,
+    ChangedFiles, CiGateRecord, CiGateState, FileManager, FindingComment, FindingCommentAuthor,
+    FindingSeverity, FindingStatus, FindingsTranslation, ParsedPlan, PhaseContext, PhaseSummary,
+    PlanPhase, ReleaseFinding, ReleaseReviewReport, ReleaseReviewStatus, ReleaseReviewTask,
+    ReviewFinding, ReviewFindings, ReviewHistory, TranslatedFinding,
+};
+pub use mcp_config::{
+    expand_env_vars, McpBinarySource, McpServerSpec, McpToolPermissions, PhaseMcpConfig,
 };
-pub use mcp_config::{expand_env_vars, McpBinarySource, McpServerSpec, PhaseMcpConfig};
 pub use opencode_events::{
     ExecutorEvent, OpenCodeEventSubscriber, SessionStatus as OpenCodeSessionStatus,
 };
+pub use path_guard::{PathGuard, PathViolation};
+pub use plan_context::{ContextFile, ContextFileSelection, DEFAULT_CONTEXT_FILE_TOKENS};
 pub use plan_parser::{extract_phase_summary, parse_plan_phases, ExtractedSummary};
 pub use prompts::UserReviewComment;
+pub use review_report::{CodeExcerpt, ReportFormat, ReviewReport};
+pub use seen_context::{SeenContext, SeenRange};
 pub use services::{
-    McpManager, MessageParser, ModelSelection, OpenCodeClient, PhaseModels, WikiMcpConfig,
+    CiGate, CiGateConfig, CiStatusSource, McpManager, MergedTask, MessageParser, ModelSelection,
+    OpenCodeClient, PhaseModels, PlanningWikiContext, PublicApiIndex, ReviewConsensusConfig,
+    ReviewPolicy, WikiContextConfig, WikiMcpConfig,
 };
 pub use session_runner::{
     McpConfig, SessionConfig, SessionDependencies, SessionResult, SessionRunner,
 };
-pub use state_machine::TaskStateMachine;
+pub use state_machine::{
+    TaskStateMachine, TransitionCheck, TransitionContext, TransitionGuard, TASK_STATUSES,
+};
+pub use task_env::{EnvVar, EnvVarSource, TaskEnvironment};
+pub use usage::UsageStore;
+pub use workflow::{
+    RuleEvaluation, TransitionRule, TransitionTrigger, WorkflowDefinition, WorkflowTransition,
+};
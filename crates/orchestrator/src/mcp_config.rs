@@ -4,7 +4,7 @@
 //! allowing different tools to be exposed based on the current task phase.
 //! This helps optimize context window usage by only loading relevant tools.
 
-use opencode_core::SessionPhase;
+use opencode_core::{SessionPhase, ToolAllowlist};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -25,6 +25,10 @@ pub struct McpServerSpec {
     /// Timeout in milliseconds for MCP operations
     #[serde(default = "default_timeout")]
     pub timeout_ms: u32,
+
+    /// Tools the phase may call on this server; `None` allows all of them
+    #[serde(default)]
+    pub allowed_tools: Option<Vec<String>>,
 }
 
 fn default_timeout() -> u32 {
@@ -70,6 +74,67 @@ impl McpBinarySource {
     }
 }
 
+/// Findings tools the review phase may call: it reports findings but doesn't
+/// resolve them
+pub const REVIEW_FINDINGS_TOOLS: &[&str] = &[
+    "create_finding",
+    "list_findings",
+    "get_finding",
+    "approve_review",
+    "complete_review",
+    "get_seen_context",
+    "get_diff",
+    "add_finding_comment",
+    "list_finding_comments",
+];
+
+/// Findings tools the fix phase may call: it resolves findings but doesn't
+/// report new ones or judge the review
+pub const FIX_FINDINGS_TOOLS: &[&str] = &[
+    "list_findings",
+    "get_finding",
+    "apply_suggestion",
+    "mark_fixed",
+    "get_seen_context",
+    "add_finding_comment",
+    "list_finding_comments",
+];
+
+/// MCP tools each phase may call, across all servers attached to it
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct McpToolPermissions {
+    phase_tools: HashMap<SessionPhase, Vec<String>>,
+}
+
+impl McpToolPermissions {
+    /// Built-in profiles: review and fix get their own findings tools,
+    /// planning and implementation may use every tool they are given
+    pub fn default_profiles() -> Self {
+        Self::default()
+            .with_phase(SessionPhase::Review, REVIEW_FINDINGS_TOOLS.iter().copied())
+            .with_phase(SessionPhase::Fix, FIX_FINDINGS_TOOLS.iter().copied())
+    }
+
+    /// Restrict `phase` to `tools`, replacing its earlier allowlist
+    pub fn with_phase<I, S>(mut self, phase: SessionPhase, tools: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.phase_tools
+            .insert(phase, tools.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Tools `phase` may call
+    pub fn allowlist(&self, phase: SessionPhase) -> ToolAllowlist {
+        match self.phase_tools.get(&phase) {
+            Some(tools) => ToolAllowlist::only(tools.iter().cloned()),
+            None => ToolAllowlist::all(),
+        }
+    }
+}
+
 /// Configuration for MCP servers per session phase.
 #[derive(Debug, Clone, Default)]
 pub struct PhaseMcpConfig {
@@ -107,13 +172,27 @@ impl PhaseMcpConfig {
             .into_iter()
             .collect(),
             timeout_ms: 10000,
+            allowed_tools: None,
         };
+        let tools = |list: &[&str]| Some(list.iter().map(|t| t.to_string()).collect());
 
         // AI Review phase gets findings server (to create findings)
-        config.add_server(SessionPhase::Review, findings_server.clone());
+        config.add_server(
+            SessionPhase::Review,
+            McpServerSpec {
+                allowed_tools: tools(REVIEW_FINDINGS_TOOLS),
+                ..findings_server.clone()
+            },
+        );
 
         // Fix phase gets findings server (to read and mark findings as fixed)
-        config.add_server(SessionPhase::Fix, findings_server);
+        config.add_server(
+            SessionPhase::Fix,
+            McpServerSpec {
+                allowed_tools: tools(FIX_FINDINGS_TOOLS),
+                ..findings_server
+            },
+        );
 
         config
     }
@@ -168,6 +247,41 @@ mod tests {
         assert!(!config.has_servers(&SessionPhase::Implementation));
     }
 
+    #[test]
+    fn test_default_tool_profiles() {
+        let permissions = McpToolPermissions::default_profiles();
+
+        let review = permissions.allowlist(SessionPhase::Review);
+        assert!(review.allows("create_finding"));
+        assert!(!review.allows("mark_fixed"));
+
+        let fix = permissions.allowlist(SessionPhase::Fix);
+        assert!(fix.allows("mark_fixed"));
+        assert!(!fix.allows("approve_review"));
+
+        assert!(!permissions
+            .allowlist(SessionPhase::Implementation)
+            .is_restricted());
+
+        let config = PhaseMcpConfig::default_config();
+        let fix_server = &config.get_servers(&SessionPhase::Fix).unwrap()[0];
+        assert_eq!(
+            fix_server.allowed_tools.as_deref(),
+            Some(
+                &[
+                    "list_findings",
+                    "get_finding",
+                    "apply_suggestion",
+                    "mark_fixed",
+                    "get_seen_context",
+                    "add_finding_comment",
+                    "list_finding_comments"
+                ]
+                .map(String::from)[..]
+            )
+        );
+    }
+
     #[test]
     fn test_expand_env_vars() {
         let result = expand_env_vars(
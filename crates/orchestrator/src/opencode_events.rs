@@ -23,6 +23,8 @@ pub enum OpenCodeEvent {
     MessagePartUpdated { properties: MessagePartUpdatedProps },
     #[serde(rename = "message.updated")]
     MessageUpdated { properties: MessageUpdatedProps },
+    #[serde(rename = "permission.updated")]
+    PermissionUpdated { properties: PermissionUpdatedProps },
     // Direct activity events (streamed during execution)
     #[serde(rename = "step_start")]
     StepStart {
@@ -123,6 +125,20 @@ pub struct MessageUpdatedProps {
     pub message: serde_json::Value,
 }
 
+/// A tool call waiting for permission to run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PermissionUpdatedProps {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub permission_type: String,
+    #[serde(rename = "sessionID")]
+    pub session_id: String,
+    #[serde(rename = "callID", default)]
+    pub call_id: Option<String>,
+    #[serde(default)]
+    pub metadata: serde_json::Value,
+}
+
 /// Parsed session status from OpenCode
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SessionStatus {
@@ -169,6 +185,15 @@ pub enum ExecutorEvent {
         part: serde_json::Value,
         delta: Option<String>,
     },
+    /// A tool call is waiting for permission to run
+    PermissionRequested {
+        session_id: String,
+        permission_id: String,
+        permission_type: String,
+        /// Tool call the permission is for
+        call_id: Option<String>,
+        metadata: serde_json::Value,
+    },
     /// Direct activity event (step_start, reasoning, agent_message, finished)
     DirectActivity {
         activity: crate::activity_store::SessionActivityMsg,
@@ -398,6 +423,24 @@ impl OpenCodeEventSubscriber {
                 None
             }
             OpenCodeEvent::MessageUpdated { .. } => None,
+            OpenCodeEvent::PermissionUpdated { properties } => {
+                if properties.session_id == target_session_id {
+                    info!(
+                        session_id = %properties.session_id,
+                        permission_type = %properties.permission_type,
+                        "Session asks for permission"
+                    );
+                    Some(ExecutorEvent::PermissionRequested {
+                        session_id: properties.session_id,
+                        permission_id: properties.id,
+                        permission_type: properties.permission_type,
+                        call_id: properties.call_id,
+                        metadata: properties.metadata,
+                    })
+                } else {
+                    None
+                }
+            }
             // Direct activity events - convert to SessionActivityMsg and forward
             OpenCodeEvent::StepStart {
                 id,
@@ -574,6 +617,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_permission_updated() {
+        let json = r#"{"type":"permission.updated","properties":{"id":"per_1","type":"edit","sessionID":"ses_789","messageID":"msg_1","callID":"call_1","title":"Edit /etc/hosts","metadata":{"filePath":"/etc/hosts"},"time":{"created":1}}}"#;
+        let event = SseEvent {
+            data: json.to_string(),
+            ..Default::default()
+        };
+
+        match OpenCodeEventSubscriber::process_sse_event(&event, "ses_789") {
+            Some(ExecutorEvent::PermissionRequested {
+                permission_id,
+                permission_type,
+                call_id,
+                metadata,
+                ..
+            }) => {
+                assert_eq!(permission_id, "per_1");
+                assert_eq!(call_id.as_deref(), Some("call_1"));
+                assert_eq!(permission_type, "edit");
+                assert_eq!(metadata["filePath"], "/etc/hosts");
+            }
+            other => panic!("Expected PermissionRequested, got {:?}", other),
+        }
+        assert!(OpenCodeEventSubscriber::process_sse_event(&event, "ses_other").is_none());
+    }
+
     #[test]
     fn test_parse_unknown_event() {
         let json = r#"{"type":"some.unknown.event","properties":{}}"#;
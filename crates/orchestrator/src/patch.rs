@@ -0,0 +1,378 @@
+//! Unified diffs proposed with review findings
+//!
+//! A reviewer can attach the fix it has in mind to a finding as a unified
+//! diff. The fix phase applies it through [`crate::FileManager::apply_patch`]
+//! instead of writing the change again. Hunks are located by their context,
+//! starting at the line the hunk names, so a patch still applies after
+//! unrelated edits moved the code; context that no longer matches rejects the
+//! whole patch.
+
+use std::path::{Component, Path};
+
+/// A line of a hunk
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum HunkLine {
+    Context(String),
+    Remove(String),
+    Add(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Hunk {
+    /// 1-based line of the original file the hunk starts at
+    old_start: usize,
+    lines: Vec<HunkLine>,
+}
+
+impl Hunk {
+    fn old_lines(&self) -> Vec<&str> {
+        self.lines
+            .iter()
+            .filter_map(|line| match line {
+                HunkLine::Context(s) | HunkLine::Remove(s) => Some(s.as_str()),
+                HunkLine::Add(_) => None,
+            })
+            .collect()
+    }
+
+    fn new_lines(&self) -> Vec<&str> {
+        self.lines
+            .iter()
+            .filter_map(|line| match line {
+                HunkLine::Context(s) | HunkLine::Add(s) => Some(s.as_str()),
+                HunkLine::Remove(_) => None,
+            })
+            .collect()
+    }
+}
+
+/// The changes a patch makes to one file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilePatch {
+    /// Path before the change; `None` for a new file
+    pub old_path: Option<String>,
+    /// Path after the change; `None` for a deleted file
+    pub new_path: Option<String>,
+    hunks: Vec<Hunk>,
+}
+
+impl FilePatch {
+    /// The file the patch reads or writes
+    pub fn path(&self) -> &str {
+        self.new_path
+            .as_deref()
+            .or(self.old_path.as_deref())
+            .unwrap_or_default()
+    }
+
+    pub fn is_new_file(&self) -> bool {
+        self.old_path.is_none()
+    }
+
+    pub fn is_deletion(&self) -> bool {
+        self.new_path.is_none()
+    }
+
+    /// `original` with the hunks applied, in order
+    pub fn apply(&self, original: &str) -> Result<String, String> {
+        let had_trailing_newline = original.ends_with('\n');
+        let mut lines: Vec<&str> = original.lines().collect();
+        // Lines the result moved against the original
+        let mut offset: isize = 0;
+        // Earliest line the next hunk may touch
+        let mut floor = 0;
+
+        for (index, hunk) in self.hunks.iter().enumerate() {
+            let old = hunk.old_lines();
+            let new = hunk.new_lines();
+            let expected =
+                ((hunk.old_start.saturating_sub(1)) as isize + offset).max(floor as isize) as usize;
+            let at = if old.is_empty() {
+                // A pure insertion after line `old_start`
+                (hunk.old_start as isize + offset).clamp(floor as isize, lines.len() as isize)
+                    as usize
+            } else {
+                find_block(&lines, &old, expected, floor).ok_or_else(|| {
+                    format!(
+                        "Hunk {} of {} does not match the file near line {}",
+                        index + 1,
+                        self.path(),
+                        hunk.old_start
+                    )
+                })?
+            };
+
+            lines.splice(at..at + old.len(), new.iter().copied());
+            offset += new.len() as isize - old.len() as isize;
+            floor = at + new.len();
+        }
+
+        let mut result = lines.join("\n");
+        if !result.is_empty() && (had_trailing_newline || original.is_empty()) {
+            result.push('\n');
+        }
+        Ok(result)
+    }
+}
+
+/// Start of `block` in `lines` at or after `floor`, closest to `expected`.
+/// Lines differing only in trailing whitespace match.
+fn find_block(lines: &[&str], block: &[&str], expected: usize, floor: usize) -> Option<usize> {
+    let matches_at = |start: usize| {
+        start + block.len() <= lines.len()
+            && lines[start..start + block.len()]
+                .iter()
+                .zip(block)
+                .all(|(a, b)| a.trim_end() == b.trim_end())
+    };
+    let last = lines.len().checked_sub(block.len())?;
+    if floor > last {
+        return None;
+    }
+    let expected = expected.clamp(floor, last);
+    let reach = (expected - floor).max(last - expected);
+    (0..=reach).find_map(|distance| {
+        let before = expected.checked_sub(distance).filter(|&s| s >= floor);
+        let after = Some(expected + distance).filter(|&s| s <= last && distance > 0);
+        before
+            .filter(|&s| matches_at(s))
+            .or(after.filter(|&s| matches_at(s)))
+    })
+}
+
+/// Path of a `---`/`+++` header, without its `a/`/`b/` prefix or timestamp;
+/// `None` for `/dev/null`
+fn header_path(rest: &str) -> Option<String> {
+    let path = rest.split('\t').next().unwrap_or_default().trim();
+    if path == "/dev/null" || path.eq_ignore_ascii_case("nul") {
+        return None;
+    }
+    let path = path
+        .strip_prefix("a/")
+        .or_else(|| path.strip_prefix("b/"))
+        .unwrap_or(path);
+    Some(path.to_string())
+}
+
+/// Start line and length of one side of a `@@ -1,3 +1,4 @@` range
+fn parse_range(range: &str) -> Option<(usize, usize)> {
+    let (start, len) = match range.split_once(',') {
+        Some((start, len)) => (start, len.parse().ok()?),
+        None => (range, 1),
+    };
+    Some((start.parse().ok()?, len))
+}
+
+/// Check that a patched path stays inside the workspace
+fn validate_path(path: &str) -> Result<(), String> {
+    // Windows separators and drive letters escape just the same
+    let slashed = path.replace('\\', "/");
+    let has_drive = slashed.as_bytes().get(1) == Some(&b':');
+    let escapes = has_drive
+        || Path::new(&slashed).components().any(|c| {
+            matches!(
+                c,
+                Component::ParentDir | Component::RootDir | Component::Prefix(_)
+            )
+        });
+    if path.is_empty() || escapes {
+        return Err(format!("Patch path '{}' is outside the workspace", path));
+    }
+    Ok(())
+}
+
+/// The file patches of a unified diff, as written by `git diff` or `diff -u`
+pub fn parse_patch(patch: &str) -> Result<Vec<FilePatch>, String> {
+    let mut files: Vec<FilePatch> = Vec::new();
+    let mut old_path: Option<Option<String>> = None;
+    let mut lines = patch.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        if let Some(rest) = line.strip_prefix("--- ") {
+            old_path = Some(header_path(rest));
+            continue;
+        }
+        if let Some(rest) = line.strip_prefix("+++ ") {
+            let Some(old) = old_path.take() else {
+                return Err("Patch has a '+++' header without a '---' header".to_string());
+            };
+            let new = header_path(rest);
+            for path in old.iter().chain(new.iter()) {
+                validate_path(path)?;
+            }
+            if old.is_none() && new.is_none() {
+                return Err("Patch names no file".to_string());
+            }
+            files.push(FilePatch {
+                old_path: old,
+                new_path: new,
+                hunks: Vec::new(),
+            });
+            continue;
+        }
+        let Some(ranges) = line.strip_prefix("@@ -") else {
+            // `diff --git`, `index` and mode lines carry nothing we need
+            continue;
+        };
+
+        let file = files
+            .last_mut()
+            .ok_or_else(|| "Patch has a hunk before any file header".to_string())?;
+        let parsed = ranges.split_once(" @@").and_then(|(ranges, _)| {
+            let (old, new) = ranges.split_once(" +")?;
+            Some((parse_range(old)?, parse_range(new)?))
+        });
+        let Some(((old_start, mut old_remaining), (_, mut new_remaining))) = parsed else {
+            return Err(format!("Malformed hunk header: {}", line));
+        };
+
+        let mut hunk = Hunk {
+            old_start,
+            lines: Vec::new(),
+        };
+        while old_remaining > 0 || new_remaining > 0 {
+            let Some(line) = lines.next() else {
+                return Err(format!(
+                    "Hunk at line {} of {} is cut short",
+                    old_start,
+                    file.path()
+                ));
+            };
+            if line.starts_with('\\') {
+                continue;
+            }
+            let (kind, text) = match line.chars().next() {
+                Some(kind @ (' ' | '-' | '+')) => (kind, &line[1..]),
+                // Some tools strip the space of blank context lines
+                None => (' ', ""),
+                Some(_) => {
+                    return Err(format!(
+                        "Unexpected line in hunk at line {} of {}: {}",
+                        old_start,
+                        file.path(),
+                        line
+                    ))
+                }
+            };
+            match kind {
+                ' ' if old_remaining > 0 && new_remaining > 0 => {
+                    old_remaining -= 1;
+                    new_remaining -= 1;
+                    hunk.lines.push(HunkLine::Context(text.to_string()));
+                }
+                '-' if old_remaining > 0 => {
+                    old_remaining -= 1;
+                    hunk.lines.push(HunkLine::Remove(text.to_string()));
+                }
+                '+' if new_remaining > 0 => {
+                    new_remaining -= 1;
+                    hunk.lines.push(HunkLine::Add(text.to_string()));
+                }
+                _ => {
+                    return Err(format!(
+                        "Hunk at line {} of {} has more lines than its header counts",
+                        old_start,
+                        file.path()
+                    ))
+                }
+            }
+        }
+        // A trailing "no newline" marker belongs to the hunk just read
+        if lines.peek().is_some_and(|l| l.starts_with('\\')) {
+            lines.next();
+        }
+        file.hunks.push(hunk);
+    }
+
+    if files.is_empty() {
+        return Err("Patch contains no file changes".to_string());
+    }
+    if let Some(file) = files
+        .iter()
+        .find(|f| f.hunks.is_empty() && !f.is_deletion())
+    {
+        return Err(format!("Patch for {} has no hunks", file.path()));
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORIGINAL: &str = "fn main() {\n    let x = 1;\n    println!(\"{}\", x);\n}\n";
+
+    #[test]
+    fn test_apply_patch() {
+        let patch = "\
+diff --git a/src/main.rs b/src/main.rs
+--- a/src/main.rs
++++ b/src/main.rs
+@@ -1,4 +1,4 @@
+ fn main() {
+-    let x = 1;
++    let x = 2;
+     println!(\"{}\", x);
+ }
+";
+        let files = parse_patch(patch).unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path(), "src/main.rs");
+        assert_eq!(
+            files[0].apply(ORIGINAL).unwrap(),
+            "fn main() {\n    let x = 2;\n    println!(\"{}\", x);\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_apply_patch_after_lines_moved() {
+        let patch = "--- a/main.rs\n+++ b/main.rs\n@@ -2,2 +2,3 @@\n     let x = 1;\n+    let y = x;\n     println!(\"{}\", x);\n";
+        let moved = format!("use std::fmt;\n\n{}", ORIGINAL);
+        let files = parse_patch(patch).unwrap();
+        let patched = files[0].apply(&moved).unwrap();
+        assert!(patched.contains("    let x = 1;\n    let y = x;\n    println!"));
+
+        let rewritten = ORIGINAL.replace("let x = 1", "let x = 3");
+        let err = files[0].apply(&rewritten).unwrap_err();
+        assert!(err.contains("does not match"));
+    }
+
+    #[test]
+    fn test_new_and_deleted_files() {
+        let patch = "\
+--- /dev/null
++++ b/src/util.rs
+@@ -0,0 +1,2 @@
++pub fn helper() {}
++
+--- a/src/old.rs
++++ /dev/null
+@@ -1 +0,0 @@
+-pub fn old() {}
+";
+        let files = parse_patch(patch).unwrap();
+        assert!(files[0].is_new_file());
+        assert_eq!(files[0].apply("").unwrap(), "pub fn helper() {}\n\n");
+        assert!(files[1].is_deletion());
+        assert_eq!(files[1].path(), "src/old.rs");
+    }
+
+    #[test]
+    fn test_rejects_invalid_patches() {
+        assert!(parse_patch("just some text").is_err());
+        assert!(
+            parse_patch("--- a/../etc/passwd\n+++ b/../etc/passwd\n@@ -1 +1 @@\n-a\n+b\n")
+                .unwrap_err()
+                .contains("outside the workspace")
+        );
+        assert!(parse_patch("--- a/x\n+++ b/x\n@@ -1,2 +1,2 @@\n-a\n+b\n")
+            .unwrap_err()
+            .contains("cut short"));
+        for path in [r"..\..\secrets", r"C:\Windows\win.ini"] {
+            let patch = format!("--- a/{0}\n+++ b/{0}\n@@ -1 +1 @@\n-a\n+b\n", path);
+            assert!(parse_patch(&patch)
+                .unwrap_err()
+                .contains("outside the workspace"));
+        }
+    }
+}
@@ -0,0 +1,263 @@
+//! Keeps session file writes inside the workspace
+//!
+//! A session works in its task's workspace, but nothing stops the model from
+//! handing a write tool an absolute path or one that climbs out with `..`.
+//! The guard checks the files write tools name against the workspace root
+//! and the paths the project shares with sessions on purpose. The session
+//! runner has OpenCode ask for `edit` and `external_directory` permission
+//! before every write, then rejects the writes outside them and approves the
+//! rest. Writes that still run without asking, e.g. because the permission
+//! settings could not be changed, are reported after the fact.
+//!
+//! Paths are compared lexically, so a symlink inside the workspace that
+//! points out of it is not caught, and neither are writes made by shell
+//! commands.
+
+use std::path::{Component, Path, PathBuf};
+
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::files::{FindingSeverity, FindingStatus, ReviewFinding};
+
+/// Tools whose input names a single file they write
+const FILE_WRITE_TOOLS: &[&str] = &["write", "edit", "multiedit"];
+
+/// Headers of the `patch` tool naming the files it touches
+const PATCH_FILE_HEADERS: &[&str] = &[
+    "*** Add File: ",
+    "*** Update File: ",
+    "*** Delete File: ",
+    "*** Move to: ",
+];
+
+/// OpenCode permissions asked for before a session writes files
+pub const GUARDED_PERMISSIONS: &[&str] = &["edit", "external_directory"];
+
+/// Prefix of the IDs of findings recording path violations
+pub const PATH_VIOLATION_ID_PREFIX: &str = "path-";
+
+/// A write outside the workspace and the shared paths
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathViolation {
+    pub tool: String,
+    /// The path as the tool was given it
+    pub path: String,
+}
+
+impl PathViolation {
+    /// The finding recording the violation on its task; `blocked` tells
+    /// whether the write was denied or already happened
+    pub fn finding(&self, blocked: bool) -> ReviewFinding {
+        let (title, description) = if blocked {
+            (
+                format!("Blocked a write outside the workspace: {}", self.path),
+                format!(
+                    "The session asked `{}` to write `{}`, which is outside the task's workspace and the shared paths. The write was denied.",
+                    self.tool, self.path
+                ),
+            )
+        } else {
+            (
+                format!("Wrote a file outside the workspace: {}", self.path),
+                format!(
+                    "The session used `{}` to write `{}`, which is outside the task's workspace and the shared paths. Check the file and undo the change if it was not intended.",
+                    self.tool, self.path
+                ),
+            )
+        };
+
+        ReviewFinding {
+            id: format!("{}{}", PATH_VIOLATION_ID_PREFIX, Uuid::new_v4().simple()),
+            file_path: Some(self.path.clone()),
+            line_start: None,
+            line_end: None,
+            title,
+            description,
+            severity: if blocked {
+                FindingSeverity::Warning
+            } else {
+                FindingSeverity::Error
+            },
+            status: FindingStatus::Pending,
+            original_severity: None,
+            severity_rationale: None,
+            reproduction: None,
+            acceptance_criteria: Some(
+                "The task only changes files inside its workspace or the shared paths".to_string(),
+            ),
+            suggested_fix: None,
+            consensus_score: None,
+            comments: Vec::new(),
+            snippet: None,
+        }
+    }
+}
+
+/// Decides which paths a session may write
+#[derive(Debug, Clone)]
+pub struct PathGuard {
+    root: PathBuf,
+    shared: Vec<PathBuf>,
+}
+
+impl PathGuard {
+    /// Guard writes to `root`. Shared paths may be absolute or relative to
+    /// `root`, and cover everything below them.
+    pub fn new(root: impl AsRef<Path>, shared: &[PathBuf]) -> Self {
+        let root = normalize(root.as_ref());
+        let shared = shared.iter().map(|p| normalize(&root.join(p))).collect();
+        Self { root, shared }
+    }
+
+    /// Whether a write to `path`, relative to the workspace root unless
+    /// absolute, stays inside the workspace or a shared path
+    pub fn allows(&self, path: &str) -> bool {
+        let path = normalize(&self.root.join(path));
+        path.starts_with(&self.root) || self.shared.iter().any(|s| path.starts_with(s))
+    }
+
+    /// Files a call of `tool` with `input` writes outside the allowed paths
+    pub fn violations(&self, tool: &str, input: &Value) -> Vec<PathViolation> {
+        written_paths(tool, input)
+            .into_iter()
+            .filter(|path| !self.allows(path))
+            .map(|path| PathViolation {
+                tool: tool.to_string(),
+                path,
+            })
+            .collect()
+    }
+
+    /// The file an OpenCode permission request would write outside the
+    /// allowed paths, if it names one
+    pub fn permission_violation(
+        &self,
+        permission_type: &str,
+        metadata: &Value,
+    ) -> Option<PathViolation> {
+        ["filePath", "filepath", "path"]
+            .iter()
+            .find_map(|key| metadata.get(key).and_then(Value::as_str))
+            .filter(|path| !self.allows(path))
+            .map(|path| PathViolation {
+                tool: permission_type.to_string(),
+                path: path.to_string(),
+            })
+    }
+}
+
+/// Files a call of `tool` with `input` writes, as the call names them
+pub fn written_paths(tool: &str, input: &Value) -> Vec<String> {
+    if FILE_WRITE_TOOLS.contains(&tool) {
+        return input
+            .get("filePath")
+            .and_then(Value::as_str)
+            .map(|p| vec![p.to_string()])
+            .unwrap_or_default();
+    }
+    if tool == "patch" {
+        let text = input
+            .get("patchText")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        return text
+            .lines()
+            .filter_map(|line| {
+                PATCH_FILE_HEADERS
+                    .iter()
+                    .find_map(|header| line.strip_prefix(header))
+            })
+            .map(|path| path.trim().to_string())
+            .filter(|path| !path.is_empty())
+            .collect();
+    }
+    Vec::new()
+}
+
+/// Resolve `.` and `..` without touching the filesystem; files a write
+/// creates don't exist yet
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn guard() -> PathGuard {
+        PathGuard::new(
+            "/work/task-1",
+            &[PathBuf::from("/tmp/cache"), PathBuf::from("../shared")],
+        )
+    }
+
+    #[test]
+    fn test_allows_workspace_and_shared_paths() {
+        let guard = guard();
+        assert!(guard.allows("src/lib.rs"));
+        assert!(guard.allows("./src/../Cargo.toml"));
+        assert!(guard.allows("/work/task-1/src/main.rs"));
+        assert!(guard.allows("/tmp/cache/build.log"));
+        assert!(guard.allows("/work/shared/notes.md"));
+
+        assert!(!guard.allows("../task-2/src/lib.rs"));
+        assert!(!guard.allows("src/../../task-2/a.rs"));
+        assert!(!guard.allows("/etc/hosts"));
+        assert!(!guard.allows("/work/task-10/a.rs"));
+        assert!(!guard.allows("/tmp/cache-other/a"));
+    }
+
+    #[test]
+    fn test_violations_of_write_tools() {
+        let guard = guard();
+        assert_eq!(
+            guard.violations("write", &json!({"filePath": "/etc/hosts", "content": ""})),
+            [PathViolation {
+                tool: "write".to_string(),
+                path: "/etc/hosts".to_string(),
+            }]
+        );
+        assert!(guard
+            .violations("edit", &json!({"filePath": "src/lib.rs"}))
+            .is_empty());
+        assert!(guard
+            .violations("read", &json!({"filePath": "/etc/hosts"}))
+            .is_empty());
+
+        let patch = "*** Begin Patch\n*** Update File: src/lib.rs\n@@\n*** Add File: ../other/x.rs\n+x\n*** End Patch";
+        let violations = guard.violations("patch", &json!({ "patchText": patch }));
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].path, "../other/x.rs");
+    }
+
+    #[test]
+    fn test_permission_violation() {
+        let guard = guard();
+        let violation = guard
+            .permission_violation(
+                "external_directory",
+                &json!({"filepath": "/home/me/.bashrc"}),
+            )
+            .unwrap();
+        assert_eq!(violation.tool, "external_directory");
+        assert_eq!(violation.path, "/home/me/.bashrc");
+        assert!(guard
+            .permission_violation("edit", &json!({"filePath": "src/lib.rs"}))
+            .is_none());
+        assert!(guard
+            .permission_violation("bash", &json!({"command": "ls"}))
+            .is_none());
+    }
+}
@@ -14,6 +14,7 @@ use crate::core::{
 use crate::error::Result;
 use crate::prompts::{PhasePrompts, UserReviewComment};
 use crate::services::ExecutorContext;
+use crate::workflow::TransitionTrigger;
 
 /// Mode of fix operation.
 #[derive(Debug, Clone)]
@@ -96,6 +97,7 @@ impl Phase for FixPhase {
             FixMode::Feedback(feedback) => PhasePrompts::fix_issues(task, feedback),
             FixMode::UserComments(comments) => PhasePrompts::fix_user_comments(task, comments),
         };
+        let prompt = PhasePrompts::in_language(prompt, ctx.config.review_language.as_deref());
 
         let mcp_servers = if self.mode.requires_mcp() {
             vec![McpServerSpec::findings()]
@@ -144,11 +146,10 @@ impl Phase for FixPhase {
             "Fix phase completed"
         );
 
-        // After fix, always go back to AI review for verification
-        ctx.transition(task, TaskStatus::AiReview)?;
+        ctx.complete_phase(task, TransitionTrigger::FixCompleted)?;
 
         Ok(PhaseOutcome::Transition {
-            next_status: TaskStatus::AiReview,
+            next_status: task.status,
         })
     }
 }
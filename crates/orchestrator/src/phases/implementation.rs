@@ -18,6 +18,7 @@ use crate::files::{ParsedPlan, PhaseContext as FilePhaseContext, PhaseSummary};
 use crate::plan_parser::parse_plan_phases;
 use crate::prompts::PhasePrompts;
 use crate::services::ExecutorContext;
+use crate::workflow::TransitionTrigger;
 use chrono::Utc;
 
 /// Thread-safe atomic phase context for multi-phase execution.
@@ -301,18 +302,18 @@ impl Phase for ImplementationPhase {
             );
 
             if phase_ctx.is_complete().await {
-                // All phases done - transition to review
-                ctx.transition(task, TaskStatus::AiReview)?;
+                // All phases done
+                ctx.complete_phase(task, TransitionTrigger::ImplementationCompleted)?;
                 Ok(PhaseOutcome::Transition {
-                    next_status: TaskStatus::AiReview,
+                    next_status: task.status,
                 })
             } else {
                 // Continue to next phase
                 Ok(PhaseOutcome::Continue)
             }
         } else {
-            // Single phase: done - transition to review
-            ctx.transition(task, TaskStatus::AiReview)?;
+            // Single phase: done
+            ctx.complete_phase(task, TransitionTrigger::ImplementationCompleted)?;
 
             info!(
                 task_id = %task.id,
@@ -320,7 +321,7 @@ impl Phase for ImplementationPhase {
             );
 
             Ok(PhaseOutcome::Transition {
-                next_status: TaskStatus::AiReview,
+                next_status: task.status,
             })
         }
     }
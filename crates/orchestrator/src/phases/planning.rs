@@ -13,6 +13,7 @@ use crate::core::{
 use crate::error::Result;
 use crate::prompts::PhasePrompts;
 use crate::services::ExecutorContext;
+use crate::workflow::TransitionTrigger;
 
 /// Planning phase - generates implementation plan for a task.
 ///
@@ -47,7 +48,9 @@ impl Phase for PlanningPhase {
     }
 
     async fn build_config(&self, ctx: &ExecutorContext, task: &Task) -> Result<PhaseConfig> {
-        let prompt = PhasePrompts::planning(task);
+        let wiki_context = ctx.planning_wiki_context(task).await;
+        let prompt =
+            PhasePrompts::planning(task, wiki_context.as_ref().map(|c| c.markdown.as_str()));
         let working_dir = ctx.config.repo_path.clone();
 
         debug!(
@@ -88,8 +91,7 @@ impl Phase for PlanningPhase {
             "Plan saved"
         );
 
-        // Transition to planning review
-        ctx.transition(task, TaskStatus::PlanningReview)?;
+        ctx.complete_phase(task, TransitionTrigger::PlanningCompleted)?;
 
         if ctx.config.require_plan_approval {
             // Wait for human approval
@@ -127,6 +129,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::default_constructed_unit_structs)]
     fn test_planning_phase_default() {
         let phase = PlanningPhase::default();
         assert_eq!(phase.phase_type(), SessionPhase::Planning);
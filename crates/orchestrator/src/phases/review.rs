@@ -16,7 +16,10 @@ use crate::core::{
 use crate::error::{OrchestratorError, Result};
 use crate::prompts::PhasePrompts;
 use crate::services::message_parser::ReviewResult;
-use crate::services::{ExecutorContext, MessageParser};
+use crate::services::{
+    ExecutorContext, MessageParser, PublicApiSeverity, ReviewConsensus, SeverityCalibration,
+};
+use crate::workflow::TransitionTrigger;
 
 /// Review phase - performs AI-driven code review.
 ///
@@ -70,13 +73,22 @@ impl ReviewPhase {
     ) -> Result<ReviewResult> {
         // Try to read findings from MCP server output
         if self.use_mcp {
-            if let Ok(Some(findings)) = ctx.file_manager.read_findings(task.id).await {
-                if !findings.findings.is_empty() {
-                    return Ok(ReviewResult::FindingsDetected(findings.findings.len()));
+            if let Ok(Some(mut findings)) = ctx.file_manager.read_findings(task.id).await {
+                let mut changed = false;
+                if ctx.config.review_consensus.is_enabled() {
+                    let diff = Self::get_workspace_diff(ctx, task).await?;
+                    let wiki_context = ctx.review_wiki_context(task, &diff).await;
+                    findings =
+                        ReviewConsensus::reach(ctx, task, &diff, wiki_context.as_deref(), findings)
+                            .await;
+                    changed = true;
                 }
-                if findings.approved {
-                    return Ok(ReviewResult::Approved);
+                let recalibrated = SeverityCalibration::calibrate(ctx, &mut findings).await
+                    + PublicApiSeverity::escalate(ctx, &mut findings).await;
+                if recalibrated > 0 || changed {
+                    ctx.file_manager.write_findings(task.id, &findings).await?;
                 }
+                return Ok(ctx.config.review_policy.review_result(&findings));
             }
         }
 
@@ -130,12 +142,15 @@ impl Phase for ReviewPhase {
             );
             OrchestratorError::WorkspaceRequired(task.id)
         })?;
+        ctx.record_review_changes(task, &diff).await;
 
+        let wiki_context = ctx.review_wiki_context(task, &diff).await;
         let prompt = if self.use_mcp {
-            PhasePrompts::review_with_mcp(task, &diff)
+            PhasePrompts::review_with_mcp(task, &diff, wiki_context.as_deref())
         } else {
-            PhasePrompts::review(task, &diff)
+            PhasePrompts::review(task, &diff, wiki_context.as_deref())
         };
+        let prompt = PhasePrompts::in_language(prompt, ctx.config.review_language.as_deref());
 
         let mcp_servers = if self.use_mcp {
             vec![McpServerSpec::findings()]
@@ -192,8 +207,7 @@ impl Phase for ReviewPhase {
 
         match review_result {
             ReviewResult::Approved => {
-                // Transition to human review
-                ctx.transition(task, TaskStatus::Review)?;
+                ctx.complete_phase(task, TransitionTrigger::ReviewApproved)?;
 
                 if ctx.config.require_human_review {
                     Ok(PhaseOutcome::AwaitingApproval {
@@ -0,0 +1,241 @@
+//! Files an implementation session starts with
+//!
+//! Plans name the files they touch under "Files to Modify" and each phase's
+//! "Files", and list the code the wiki retrieved while planning under
+//! "Consulted Sources". Instead of the model searching for them again, the
+//! implementation prompt attaches the cited files that exist, in the order
+//! the plan cites them, until a token budget is spent.
+
+use std::path::{Component, Path};
+
+use crate::files::{ParsedPlan, PlanPhase};
+
+const CHARS_PER_TOKEN: usize = 4;
+
+/// Default token budget for the files attached to an implementation prompt
+pub const DEFAULT_CONTEXT_FILE_TOKENS: usize = 20_000;
+
+/// A cited file attached to a prompt
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContextFile {
+    /// Path relative to the session's working directory
+    pub path: String,
+    /// Estimated tokens of the file's content
+    pub tokens: usize,
+}
+
+/// Cited files picked for a prompt
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContextFileSelection {
+    pub files: Vec<ContextFile>,
+    /// Existing cited files left out because they did not fit the budget
+    pub over_budget: Vec<String>,
+}
+
+impl ContextFileSelection {
+    pub fn paths(&self) -> Vec<String> {
+        self.files.iter().map(|f| f.path.clone()).collect()
+    }
+
+    pub fn tokens(&self) -> usize {
+        self.files.iter().map(|f| f.tokens).sum()
+    }
+}
+
+/// Repository files cited in `text`, in order of first citation. Citations
+/// are backticked paths, optionally with a line range like `src/a.rs:3-12`,
+/// and paths opening a list item, like `- src/a.rs - what changes`.
+pub fn cited_files(text: &str) -> Vec<String> {
+    let mut files: Vec<String> = Vec::new();
+
+    for line in text.lines() {
+        let mut candidates: Vec<&str> = line.split('`').skip(1).step_by(2).collect();
+        let trimmed = line.trim_start();
+        if let Some(item) = trimmed
+            .strip_prefix("- ")
+            .or_else(|| trimmed.strip_prefix("* "))
+        {
+            if let Some(first) = item.split_whitespace().next() {
+                candidates.push(first);
+            }
+        }
+
+        for candidate in candidates {
+            if let Some(path) = citation_path(candidate) {
+                if !files.contains(&path) {
+                    files.push(path);
+                }
+            }
+        }
+    }
+
+    files
+}
+
+/// Files cited for one phase of a plan: the phase's own first, then those
+/// of the overview and the other phases, where the consulted sources end up
+pub fn cited_files_for_phase(plan: &ParsedPlan, phase: &PlanPhase) -> Vec<String> {
+    let mut text = format!("{}\n{}", phase.content, plan.preamble);
+    for other in plan.phases.iter().filter(|p| p.number != phase.number) {
+        text.push('\n');
+        text.push_str(&other.content);
+    }
+    cited_files(&text)
+}
+
+/// The path a citation names, if it looks like a relative file path
+fn citation_path(citation: &str) -> Option<String> {
+    let citation = citation.trim_matches(|c: char| matches!(c, '*' | ',' | ';' | '(' | ')'));
+    let citation = citation.strip_suffix(':').unwrap_or(citation);
+    if citation.contains("://") || citation.chars().any(char::is_whitespace) {
+        return None;
+    }
+
+    // Drop a line range
+    let path = match citation.rsplit_once(':') {
+        Some((path, lines)) if lines.chars().all(|c| c.is_ascii_digit() || c == '-') => path,
+        _ => citation,
+    };
+    let path = path.strip_prefix("./").unwrap_or(path);
+
+    let is_relative = Path::new(path)
+        .components()
+        .all(|c| matches!(c, Component::Normal(_)));
+    let file_name = path.rsplit('/').next().unwrap_or(path);
+    let has_extension = match file_name.rsplit_once('.') {
+        Some((stem, ext)) => {
+            !stem.is_empty()
+                && (1..=10).contains(&ext.len())
+                && ext.chars().all(|c| c.is_ascii_alphanumeric())
+                && ext.chars().any(|c| c.is_ascii_alphabetic())
+        }
+        None => false,
+    };
+
+    (is_relative && has_extension && !path.is_empty()).then(|| path.to_string())
+}
+
+/// Pick the cited files that exist under `working_dir`, in citation order,
+/// skipping any that would take the total past `max_tokens`. Files a plan
+/// only creates don't exist yet and are left out.
+pub async fn select_context_files(
+    working_dir: &Path,
+    cited: &[String],
+    max_tokens: usize,
+) -> ContextFileSelection {
+    let mut selection = ContextFileSelection::default();
+    if max_tokens == 0 {
+        return selection;
+    }
+
+    for path in cited {
+        let metadata = match tokio::fs::metadata(working_dir.join(path)).await {
+            Ok(metadata) if metadata.is_file() => metadata,
+            _ => continue,
+        };
+        let tokens = (metadata.len() as usize).div_ceil(CHARS_PER_TOKEN);
+        if selection.tokens() + tokens > max_tokens {
+            selection.over_budget.push(path.clone());
+            continue;
+        }
+        selection.files.push(ContextFile {
+            path: path.clone(),
+            tokens,
+        });
+    }
+
+    selection
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plan_parser::parse_plan_phases;
+
+    const PLAN: &str = r#"# Implementation Plan: Add login
+
+## Overview
+Touches `src/auth/mod.rs` and the session store, e.g. the cookie handling.
+
+## Files to Modify
+- src/auth/login.rs - add the handler
+- `src/routes.rs` - register it
+- Step 1.1: nothing to see here
+
+## Phase 1: Handler
+
+### Files
+- src/auth/login.rs
+
+## Phase 2: Routes
+
+### Files
+- ./src/routes.rs
+
+## Consulted Sources
+
+Retrieved from the project wiki while planning:
+
+- `src/auth/session.rs:3-12`
+- [Auth](https://wiki.example/auth.md)
+"#;
+
+    #[test]
+    fn test_cited_files() {
+        assert_eq!(
+            cited_files(PLAN),
+            [
+                "src/auth/mod.rs",
+                "src/auth/login.rs",
+                "src/routes.rs",
+                "src/auth/session.rs",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_citation_path_rejects_non_paths() {
+        assert_eq!(citation_path("Step"), None);
+        assert_eq!(citation_path("1.1:"), None);
+        assert_eq!(citation_path("../secrets.env"), None);
+        assert_eq!(citation_path("/etc/passwd.txt"), None);
+        assert_eq!(citation_path("https://x.dev/a.md"), None);
+        assert_eq!(citation_path("cargo test"), None);
+        assert_eq!(citation_path("Cargo.toml"), Some("Cargo.toml".to_string()));
+        assert_eq!(
+            citation_path("**src/lib.rs:10**"),
+            Some("src/lib.rs".to_string())
+        );
+    }
+
+    #[test]
+    fn test_cited_files_for_phase_puts_the_phase_first() {
+        let parsed = parse_plan_phases(PLAN);
+        let files = cited_files_for_phase(&parsed, &parsed.phases[1]);
+        assert_eq!(files[0], "src/routes.rs");
+        assert!(files.contains(&"src/auth/session.rs".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_select_context_files_keeps_to_budget() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("src")).unwrap();
+        std::fs::write(dir.path().join("src/big.rs"), "x".repeat(400)).unwrap();
+        std::fs::write(dir.path().join("src/small.rs"), "x".repeat(40)).unwrap();
+        std::fs::write(dir.path().join("src/tiny.rs"), "x".repeat(8)).unwrap();
+        let cited: Vec<String> = ["src/small.rs", "src/new.rs", "src/big.rs", "src/tiny.rs"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+
+        let selection = select_context_files(dir.path(), &cited, 50).await;
+
+        assert_eq!(selection.paths(), ["src/small.rs", "src/tiny.rs"]);
+        assert_eq!(selection.tokens(), 12);
+        assert_eq!(selection.over_budget, ["src/big.rs"]);
+        assert!(select_context_files(dir.path(), &cited, 0)
+            .await
+            .files
+            .is_empty());
+    }
+}
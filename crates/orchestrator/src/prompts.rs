@@ -13,14 +13,14 @@ pub struct UserReviewComment {
 pub struct PhasePrompts;
 
 impl PhasePrompts {
-    pub fn planning(task: &Task) -> String {
+    pub fn planning(task: &Task, wiki_context: Option<&str>) -> String {
         format!(
             r#"You are analyzing a development task. Create a detailed implementation plan.
 
 ## Task
 **Title:** {title}
 **Description:** {description}
-
+{context}
 ## CRITICAL: Output the ENTIRE plan in your response
 
 Do NOT use any tools to write files. Simply output the complete plan as your response.
@@ -76,10 +76,27 @@ Your response text will be automatically saved to the plan file.
 Output the complete plan now. Do NOT implement anything."#,
             title = task.title,
             description = task.description,
-            id = task.id
+            id = task.id,
+            context = Self::planning_context_section(wiki_context)
         )
     }
 
+    /// Related code section of a planning prompt, empty without context
+    fn planning_context_section(wiki_context: Option<&str>) -> String {
+        match wiki_context {
+            Some(context) => format!(
+                r#"
+## Related Code and Documentation
+Retrieved from the project wiki by semantic search over the task. Base the
+plan on the existing code it shows, and verify it before relying on it.
+
+{context}
+"#
+            ),
+            None => String::new(),
+        }
+    }
+
     pub fn implementation(task: &Task) -> String {
         let plan_path = format!(".opencode-studio/kanban/plans/{}.md", task.id);
 
@@ -146,7 +163,7 @@ Start implementation now."#,
         }
     }
 
-    pub fn review(task: &Task, diff: &str) -> String {
+    pub fn review(task: &Task, diff: &str, wiki_context: Option<&str>) -> String {
         format!(
             r#"Review the following code changes for task: {title}
 
@@ -157,7 +174,7 @@ Start implementation now."#,
 ```
 {diff}
 ```
-
+{context}
 ## Review Criteria
 1. Code quality and style
 2. Correctness - does it solve the task?
@@ -189,7 +206,9 @@ If there are issues, include them in the findings array:
       "line_end": 45,
       "title": "Missing error handling",
       "description": "The function does not handle the case when the input is invalid. This could lead to a panic at runtime.",
-      "severity": "error"
+      "severity": "error",
+      "reproduction": "Call parse_config with an empty string",
+      "acceptance_criteria": "parse_config returns an error for empty input and a test covers it"
     }},
     {{
       "file_path": "src/utils.rs",
@@ -207,15 +226,19 @@ Severity levels:
 - "warning" - Should be fixed but not blocking
 - "info" - Suggestion for improvement
 
+"reproduction" (how to see the problem) is optional. "acceptance_criteria"
+(what must be true once it is fixed) is required for "error" findings.
+
 Respond ONLY with the JSON object, no additional text."#,
             title = task.title,
             description = task.description,
-            diff = diff
+            diff = diff,
+            context = Self::review_context_section(wiki_context)
         )
     }
 
     /// Generate prompt for AI review using MCP tools
-    pub fn review_with_mcp(task: &Task, diff: &str) -> String {
+    pub fn review_with_mcp(task: &Task, diff: &str, wiki_context: Option<&str>) -> String {
         format!(
             r#"Review the following code changes for task: {title}
 
@@ -226,7 +249,7 @@ Respond ONLY with the JSON object, no additional text."#,
 ```
 {diff}
 ```
-
+{context}
 ## Review Criteria
 1. Code quality and style
 2. Correctness - does it solve the task?
@@ -245,6 +268,10 @@ You have access to the "opencode-findings" MCP server with the following tools:
    - `title`: Short description of the issue (max 100 chars)
    - `description`: Detailed explanation of the issue
    - `severity`: "error" (must fix), "warning" (should fix), or "info" (suggestion)
+   - `reproduction`: Steps, input or a command that show the problem (optional)
+   - `acceptance_criteria`: What must be true once the issue is fixed (required for "error")
+   - `suggested_fix`: The fix you propose as a unified diff against the workspace,
+     e.g. the output of `git diff` (optional; only when the fix is small and certain)
 
 2. **list_findings** - Use this to see all findings you've created
 
@@ -256,6 +283,9 @@ You have access to the "opencode-findings" MCP server with the following tools:
    - `summary`: Overall assessment of the changes
    - `approved`: false (if there are error-level issues)
 
+5. **get_diff** - Use this to read the workspace diff again, optionally only the
+   changes to one file (`file_path`)
+
 ## Instructions
 
 1. Analyze the diff carefully
@@ -267,7 +297,217 @@ You have access to the "opencode-findings" MCP server with the following tools:
 Start reviewing now."#,
             title = task.title,
             description = task.description,
-            diff = diff
+            diff = diff,
+            context = Self::review_context_section(wiki_context)
+        )
+    }
+
+    /// Generate prompt for one review over the changes of several merged tasks
+    pub fn release_review(tasks: &[crate::services::MergedTask]) -> String {
+        let changes = tasks
+            .iter()
+            .map(|t| {
+                format!(
+                    "### Task: {}\n{}\n\n```\n{}\n```",
+                    t.title, t.description, t.diff
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        format!(
+            r#"Review the combined changes of {count} tasks merged for the next release.
+The workspace holds the code with all of them merged. Each task's changes are
+reviewed on their own already; look for what only shows in combination:
+tasks that conflict or duplicate each other, changes that break code another
+task relies on, and inconsistencies across the release.
+
+## Merged Changes
+{changes}
+
+## Review Criteria
+1. Interactions between the tasks' changes
+2. Correctness of the merged code as a whole
+3. Breaking changes to public interfaces and data formats
+4. Security concerns
+5. Tests that no longer cover the merged behavior
+
+## Output Format
+You MUST respond with a JSON object in this exact format:
+
+```json
+{{
+  "approved": false,
+  "summary": "Overall assessment of the release...",
+  "findings": [
+    {{
+      "file_path": "src/main.rs",
+      "line_start": 42,
+      "title": "Two tasks parse the config differently",
+      "description": "One task reads the timeout in seconds, the other writes it in milliseconds.",
+      "severity": "error",
+      "acceptance_criteria": "Both use the same unit and a test covers the round trip"
+    }}
+  ]
+}}
+```
+
+Give the file_path of every finding that concerns a file, so it can be
+traced back to the tasks that changed it. Severity is "error" (must be fixed
+before release), "warning" (should be fixed) or "info" (suggestion), and
+"acceptance_criteria" is required for "error" findings.
+
+Respond ONLY with the JSON object, no additional text."#,
+            count = tasks.len(),
+        )
+    }
+
+    /// Codebase context section of a review prompt, empty without context
+    fn review_context_section(wiki_context: Option<&str>) -> String {
+        match wiki_context {
+            Some(context) => format!(
+                r#"
+## Codebase Context
+Wiki documentation and related code for the changed files. Use it to judge
+whether the changes fit how the surrounding code is meant to work.
+
+{context}
+"#
+            ),
+            None => String::new(),
+        }
+    }
+
+    /// Generate prompt for re-rating the severity of review findings
+    pub fn severity_calibration(
+        findings: &[crate::files::ReviewFinding],
+        guidelines: Option<&str>,
+    ) -> String {
+        let findings_text = findings
+            .iter()
+            .map(|f| {
+                let location = match (&f.file_path, f.line_start) {
+                    (Some(path), Some(line)) => format!("{path}:{line}"),
+                    (Some(path), None) => path.clone(),
+                    _ => "Unknown location".to_string(),
+                };
+                format!(
+                    "- id: {}\n  severity: {}\n  title: {}\n  location: {}\n  description: {}\n",
+                    f.id,
+                    f.severity.as_str(),
+                    f.title,
+                    location,
+                    f.description
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let guidelines = match guidelines {
+            Some(guidelines) => format!(
+                r#"
+## Project Guidelines
+Findings that break these guidelines matter more than general style advice.
+
+{guidelines}
+"#
+            ),
+            None => String::new(),
+        };
+
+        format!(
+            r#"A code reviewer reported the findings below. Reviewers often rate
+nitpicks as errors. Re-rate the severity of each finding using the rubric;
+do not review the code again or add findings.
+
+## Severity Rubric
+- "error": a bug, security issue, data loss, broken build or test, or a
+  breaking change the task did not ask for. Must be fixed before merge.
+- "warning": a real problem that is not blocking: missing tests or error
+  handling for unlikely cases, maintainability issues, guideline violations.
+- "info": style, naming, formatting, and optional suggestions.
+{guidelines}
+## Findings
+{findings_text}
+## Output Format
+Respond ONLY with a JSON object listing every finding:
+
+```json
+{{
+  "findings": [
+    {{
+      "id": "finding-1",
+      "severity": "warning",
+      "rationale": "One sentence on why this severity fits"
+    }}
+  ]
+}}
+```"#
+        )
+    }
+
+    /// `prompt` asking for prose in `language`; unchanged without one
+    ///
+    /// Only what people read is translated. JSON keys, severities, statuses,
+    /// file paths and code stay as they are, since they are parsed.
+    pub fn in_language(prompt: String, language: Option<&str>) -> String {
+        let Some(language) = language.map(str::trim).filter(|l| !l.is_empty()) else {
+            return prompt;
+        };
+        format!(
+            r#"{prompt}
+
+## Output Language
+Write the review summary, finding titles, descriptions, acceptance criteria
+and any other prose in {language}. Keep JSON keys, severity and status values,
+finding IDs, file paths, identifiers and code exactly as they are."#
+        )
+    }
+
+    /// Prompt translating the prose of review findings into `language`
+    pub fn translate_findings(findings: &crate::files::ReviewFindings, language: &str) -> String {
+        let findings_text = findings
+            .findings
+            .iter()
+            .map(|f| {
+                let mut text = format!(
+                    "- id: {}\n  title: {}\n  description: {}\n",
+                    f.id, f.title, f.description
+                );
+                if let Some(criteria) = &f.acceptance_criteria {
+                    text.push_str(&format!("  acceptance_criteria: {}\n", criteria));
+                }
+                text
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!(
+            r#"Translate the code review below into {language}. Translate prose only;
+keep identifiers, file paths, code and anything in backticks as they are.
+Do not add, drop or reword findings beyond translating them.
+
+## Summary
+{summary}
+
+## Findings
+{findings_text}
+## Output Format
+Respond ONLY with a JSON object holding every finding by its id:
+
+```json
+{{
+  "summary": "Translated summary",
+  "findings": [
+    {{
+      "id": "finding-1",
+      "title": "Translated title",
+      "description": "Translated description",
+      "acceptance_criteria": "Translated criteria, or null"
+    }}
+  ]
+}}
+```"#,
+            summary = findings.summary
         )
     }
 
@@ -282,14 +522,34 @@ Start reviewing now."#,
                     (Some(path), None) => path.clone(),
                     _ => "Unknown location".to_string(),
                 };
-                format!(
+                let mut text = format!(
                     "{}. [{:?}] {} ({})\n   {}\n",
                     i + 1,
                     f.severity,
                     f.title,
                     location,
                     f.description
-                )
+                );
+                if let Some(reproduction) = &f.reproduction {
+                    text.push_str(&format!("   Reproduction: {reproduction}\n"));
+                }
+                if let Some(criteria) = &f.acceptance_criteria {
+                    text.push_str(&format!("   Acceptance criteria: {criteria}\n"));
+                }
+                if let Some(fix) = &f.suggested_fix {
+                    text.push_str(&format!("   Suggested fix:\n```diff\n{fix}\n```\n"));
+                }
+                if !f.comments.is_empty() {
+                    text.push_str("   Discussion:\n");
+                    for comment in &f.comments {
+                        let author = match comment.author {
+                            crate::files::FindingCommentAuthor::Human => "Reviewer",
+                            crate::files::FindingCommentAuthor::Agent => "Agent",
+                        };
+                        text.push_str(&format!("   - {author}: {}\n", comment.body));
+                    }
+                }
+                text
             })
             .collect::<Vec<_>>()
             .join("\n");
@@ -303,8 +563,10 @@ Start reviewing now."#,
 ## Instructions
 1. Address each issue mentioned above
 2. Make minimal changes - only fix what's needed
-3. Ensure the fix is complete and correct
-4. Update tests if the fix requires it
+3. Ensure the fix is complete and correct, and meets the acceptance criteria
+   where an issue lists them
+4. Use the reproduction steps, where given, to confirm the issue is gone
+5. Update tests if the fix requires it
 
 Fix the issues now."#,
             title = task.title,
@@ -327,19 +589,31 @@ You have access to the "opencode-findings" MCP server with the following tools:
 1. **list_findings** - First, use this to see all findings that need to be fixed
    - Returns a list of findings with their IDs, locations, and descriptions
 
-2. **get_finding** - Get details about a specific finding
+2. **get_finding** - Get details about a specific finding, including its
+   reproduction steps and acceptance criteria when the reviewer gave them
    - `finding_id`: The ID of the finding
 
-3. **mark_fixed** - After fixing an issue, mark it as fixed
+3. **apply_suggestion** - Apply the fix the reviewer proposed for a finding
+   - `finding_id`: The ID of a finding whose details include a suggested fix
+
+4. **mark_fixed** - After fixing an issue, mark it as fixed
    - `finding_id`: The ID of the finding you fixed
 
+5. **add_finding_comment** - Reply in the discussion of a finding
+   - `finding_id`: The ID of the finding
+   - `body`: Your answer to the reviewer, or how you fixed the finding
+
 ## Instructions
 
 1. Call `list_findings` to see all issues that need fixing
 2. For each finding:
-   - Read the finding details
-   - Navigate to the file and line mentioned
-   - Fix the issue
+   - Read the finding details with `get_finding`, including the discussion
+     with the human reviewer; follow what they asked and answer their
+     questions with `add_finding_comment`
+   - If it has a suggested fix, call `apply_suggestion` and check the result;
+     fix it by hand if the suggestion doesn't apply or isn't enough
+   - Otherwise navigate to the file and line mentioned
+   - Fix the issue so that its acceptance criteria hold
    - Call `mark_fixed` with the finding ID
 3. After fixing all issues, the review will be re-run automatically
 
@@ -640,6 +914,8 @@ mod tests {
             status: opencode_core::TaskStatus::Todo,
             roadmap_item_id: None,
             workspace_path: None,
+            depends_on: Vec::new(),
+            pr_number: None,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         }
@@ -648,11 +924,22 @@ mod tests {
     #[test]
     fn test_planning_prompt_contains_task_info() {
         let task = sample_task();
-        let prompt = PhasePrompts::planning(&task);
+        let prompt = PhasePrompts::planning(&task, None);
 
         assert!(prompt.contains(&task.title));
         assert!(prompt.contains(&task.description));
         assert!(prompt.contains(&task.id.to_string()));
+        assert!(!prompt.contains("## Related Code and Documentation"));
+    }
+
+    #[test]
+    fn test_planning_prompt_contains_wiki_context() {
+        let task = sample_task();
+        let context = "**Related code:** `src/auth/login.rs:3-12`";
+        let prompt = PhasePrompts::planning(&task, Some(context));
+
+        assert!(prompt.contains("## Related Code and Documentation"));
+        assert!(prompt.contains(context));
     }
 
     #[test]
@@ -689,11 +976,75 @@ mod tests {
     fn test_review_prompt_contains_diff() {
         let task = sample_task();
         let diff = "+ added line\n- removed line";
-        let prompt = PhasePrompts::review(&task, diff);
+        let prompt = PhasePrompts::review(&task, diff, None);
 
         assert!(prompt.contains(diff));
         assert!(prompt.contains("approved"));
         assert!(prompt.contains("findings"));
+        assert!(!prompt.contains("## Codebase Context"));
+    }
+
+    #[test]
+    fn test_review_prompt_contains_wiki_context() {
+        let task = sample_task();
+        let context = "### `src/auth.rs`\n\n**Wiki: Authentication** (`auth`)";
+        let prompt = PhasePrompts::review_with_mcp(&task, "+ added line", Some(context));
+
+        assert!(prompt.contains("## Codebase Context"));
+        assert!(prompt.contains(context));
+        assert!(prompt.find("## Codebase Context") < prompt.find("## Review Criteria"));
+    }
+
+    #[test]
+    fn test_release_review_prompt_lists_each_task() {
+        let merged = |title: &str, diff: &str| crate::services::MergedTask {
+            task_id: Uuid::new_v4(),
+            title: title.to_string(),
+            description: format!("{} description", title),
+            diff: diff.to_string(),
+        };
+        let prompt = PhasePrompts::release_review(&[
+            merged("Add login", "+ fn login()"),
+            merged("Add logout", "+ fn logout()"),
+        ]);
+
+        assert!(prompt.contains("changes of 2 tasks"));
+        assert!(prompt.contains("### Task: Add login"));
+        assert!(prompt.contains("Add logout description"));
+        assert!(prompt.contains("+ fn logout()"));
+        assert!(prompt.find("+ fn login()") < prompt.find("### Task: Add logout"));
+    }
+
+    #[test]
+    fn test_severity_calibration_prompt_lists_findings() {
+        let finding = crate::files::ReviewFinding {
+            id: "finding-1".to_string(),
+            file_path: Some("src/lib.rs".to_string()),
+            line_start: Some(7),
+            line_end: None,
+            title: "Rename variable".to_string(),
+            description: "`x` is not descriptive".to_string(),
+            severity: crate::files::FindingSeverity::Error,
+            status: crate::files::FindingStatus::Pending,
+            original_severity: None,
+            severity_rationale: None,
+            reproduction: None,
+            acceptance_criteria: None,
+            suggested_fix: None,
+            consensus_score: None,
+            comments: Vec::new(),
+            snippet: None,
+        };
+
+        let prompt = PhasePrompts::severity_calibration(std::slice::from_ref(&finding), None);
+        assert!(prompt.contains("id: finding-1"));
+        assert!(prompt.contains("severity: error"));
+        assert!(prompt.contains("location: src/lib.rs:7"));
+        assert!(!prompt.contains("## Project Guidelines"));
+
+        let prompt = PhasePrompts::severity_calibration(&[finding], Some("Use descriptive names"));
+        assert!(prompt.contains("## Project Guidelines"));
+        assert!(prompt.contains("Use descriptive names"));
     }
 
     #[test]
@@ -706,6 +1057,38 @@ mod tests {
         assert!(prompt.contains(feedback));
     }
 
+    #[test]
+    fn test_fix_findings_includes_reproduction_and_criteria() {
+        let task = sample_task();
+        let finding = crate::files::ReviewFinding {
+            id: "finding-1".to_string(),
+            file_path: Some("src/parse.rs".to_string()),
+            line_start: Some(12),
+            line_end: None,
+            title: "Crash on empty input".to_string(),
+            description: "Indexes the first byte unchecked".to_string(),
+            severity: crate::files::FindingSeverity::Error,
+            status: crate::files::FindingStatus::Pending,
+            original_severity: None,
+            severity_rationale: None,
+            reproduction: Some("parse(\"\")".to_string()),
+            acceptance_criteria: Some("Empty input returns an error".to_string()),
+            suggested_fix: None,
+            consensus_score: None,
+            comments: vec![crate::files::FindingComment::new(
+                crate::files::FindingCommentAuthor::Human,
+                "Return ParseError::Empty",
+            )],
+            snippet: None,
+        };
+
+        let prompt = PhasePrompts::fix_findings(&task, &[finding]);
+
+        assert!(prompt.contains("Reproduction: parse(\"\")"));
+        assert!(prompt.contains("Acceptance criteria: Empty input returns an error"));
+        assert!(prompt.contains("- Reviewer: Return ParseError::Empty"));
+    }
+
     #[test]
     fn test_replan_contains_feedback() {
         let task = sample_task();
@@ -716,4 +1099,17 @@ mod tests {
         assert!(prompt.contains(feedback));
         assert!(prompt.contains("revised plan"));
     }
+
+    #[test]
+    fn test_in_language_appends_output_language() {
+        let task = sample_task();
+        let prompt = PhasePrompts::fix_with_mcp(&task);
+
+        assert_eq!(PhasePrompts::in_language(prompt.clone(), None), prompt);
+        assert_eq!(PhasePrompts::in_language(prompt.clone(), Some(" ")), prompt);
+        let localized = PhasePrompts::in_language(prompt.clone(), Some("German"));
+        assert!(localized.starts_with(&prompt));
+        assert!(localized.contains("## Output Language"));
+        assert!(localized.contains("in German"));
+    }
 }
@@ -3,6 +3,7 @@
 //! This module provides automatic cleanup of MCP server connections
 //! when the guard goes out of scope, ensuring no resource leaks.
 
+use opencode_core::SessionPhase;
 use std::path::PathBuf;
 use tracing::{debug, warn};
 use uuid::Uuid;
@@ -19,7 +20,7 @@ use crate::services::McpManager;
 /// # Example
 ///
 /// ```ignore
-/// let guard = McpGuard::connect(manager.clone(), workspace_path, project_path, &servers, task_id, session_id).await?;
+/// let guard = McpGuard::connect(manager.clone(), workspace_path, project_path, &servers, task_id, session_id, phase).await?;
 /// // ... use MCP servers ...
 /// // guard is automatically cleaned up when it goes out of scope
 /// ```
@@ -41,6 +42,7 @@ impl McpGuard {
     /// * `servers` - List of MCP server specifications to connect
     /// * `task_id` - Task ID for the session
     /// * `session_id` - Session ID for the connection
+    /// * `phase` - Phase the session runs, which decides the tools it may call
     ///
     /// # Returns
     ///
@@ -52,6 +54,7 @@ impl McpGuard {
         servers: &[McpServerSpec],
         task_id: Uuid,
         session_id: Uuid,
+        phase: SessionPhase,
     ) -> Result<Self> {
         let mut guard = Self {
             manager,
@@ -69,7 +72,7 @@ impl McpGuard {
 
             guard
                 .manager
-                .setup_findings_server(task_id, session_id, &workspace_path, &project_path)
+                .setup_findings_server(task_id, session_id, phase, &workspace_path, &project_path)
                 .await?;
 
             guard.servers.push(server.name.clone());
@@ -0,0 +1,550 @@
+//! Review of a task as a document
+//!
+//! A report sums up a task's AI review for readers who don't use the board,
+//! ready to attach to a pull request or ticket: the reviewer's summary, the
+//! findings grouped by severity and file with the code they point at, what
+//! became of each and the model that reviewed. It renders as Markdown or as a
+//! standalone HTML page.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use tracing::debug;
+
+use crate::files::{FindingSeverity, FindingStatus, ReviewFinding, ReviewFindings};
+use crate::path_guard::PathGuard;
+use crate::services::ModelSelection;
+
+/// Lines shown around the lines a finding points at
+const EXCERPT_CONTEXT_LINES: usize = 2;
+/// Longest excerpt; findings spanning more are cut off
+const MAX_EXCERPT_LINES: usize = 40;
+
+const SEVERITIES: [FindingSeverity; 3] = [
+    FindingSeverity::Error,
+    FindingSeverity::Warning,
+    FindingSeverity::Info,
+];
+
+/// Format a report renders in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Markdown,
+    Html,
+}
+
+impl ReportFormat {
+    /// `md`, `markdown` or `html`
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "md" | "markdown" => Some(Self::Markdown),
+            "html" => Some(Self::Html),
+            _ => None,
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Markdown => "text/markdown; charset=utf-8",
+            Self::Html => "text/html; charset=utf-8",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Html => "html",
+        }
+    }
+}
+
+/// Source lines a finding points at, with some context around them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeExcerpt {
+    /// 1-based number of the first line
+    pub start_line: usize,
+    pub lines: Vec<String>,
+}
+
+impl CodeExcerpt {
+    /// Lines `start..=end` of `source`, 1-based; `None` when the range lies
+    /// outside the file
+    pub fn from_source(source: &str, start: usize, end: usize) -> Option<Self> {
+        let total = source.lines().count();
+        if start == 0 || start > total {
+            return None;
+        }
+        let first = start.saturating_sub(EXCERPT_CONTEXT_LINES).max(1);
+        let last = (end.max(start) + EXCERPT_CONTEXT_LINES)
+            .min(total)
+            .min(first + MAX_EXCERPT_LINES - 1);
+        let lines = source
+            .lines()
+            .skip(first - 1)
+            .take(last - first + 1)
+            .map(str::to_string)
+            .collect();
+        Some(Self {
+            start_line: first,
+            lines,
+        })
+    }
+
+    /// The lines prefixed with their numbers
+    fn numbered(&self) -> String {
+        let width = (self.start_line + self.lines.len()).to_string().len();
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| format!("{:>width$} | {}", self.start_line + i, line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// A task's review, ready to render
+#[derive(Debug, Clone)]
+pub struct ReviewReport<'a> {
+    pub task_title: &'a str,
+    pub findings: &'a ReviewFindings,
+    /// Model the review phase ran with; `None` when OpenCode's default
+    pub reviewer_model: Option<ModelSelection>,
+    /// Code the findings point at, by finding id
+    pub excerpts: HashMap<String, CodeExcerpt>,
+}
+
+impl<'a> ReviewReport<'a> {
+    pub fn new(task_title: &'a str, findings: &'a ReviewFindings) -> Self {
+        Self {
+            task_title,
+            findings,
+            reviewer_model: None,
+            excerpts: HashMap::new(),
+        }
+    }
+
+    pub fn with_reviewer_model(mut self, model: Option<ModelSelection>) -> Self {
+        self.reviewer_model = model;
+        self
+    }
+
+    /// Read the code each located finding points at from `root`, the task's
+    /// workspace. Files outside it or no longer there are left out.
+    pub async fn load_excerpts(mut self, root: &Path) -> Self {
+        let guard = PathGuard::new(root, &[]);
+        for finding in &self.findings.findings {
+            let (Some(path), Some(start)) = (finding.file_path.as_deref(), finding.line_start)
+            else {
+                continue;
+            };
+            if start < 1 || !guard.allows(path) {
+                continue;
+            }
+            let source = match tokio::fs::read_to_string(root.join(path)).await {
+                Ok(source) => source,
+                Err(e) => {
+                    debug!(path, error = %e, "No excerpt for finding");
+                    continue;
+                }
+            };
+            let start = start as usize;
+            let end = finding.line_end.map_or(start, |end| end.max(0) as usize);
+            if let Some(excerpt) = CodeExcerpt::from_source(&source, start, end) {
+                self.excerpts.insert(finding.id.clone(), excerpt);
+            }
+        }
+        self
+    }
+
+    pub fn render(&self, format: ReportFormat) -> String {
+        match format {
+            ReportFormat::Markdown => self.to_markdown(),
+            ReportFormat::Html => self.to_html(),
+        }
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("# Review: {}\n\n", self.task_title);
+        for (label, value) in self.metadata() {
+            out.push_str(&format!("- **{}:** {}\n", label, value));
+        }
+
+        if !self.findings.summary.trim().is_empty() {
+            out.push_str("\n## Summary\n\n");
+            out.push_str(self.findings.summary.trim());
+            out.push('\n');
+        }
+
+        for (severity, files) in self.grouped() {
+            let count: usize = files.iter().map(|(_, f)| f.len()).sum();
+            out.push_str(&format!(
+                "\n## {} ({})\n",
+                severity_heading(severity),
+                count
+            ));
+            for (path, findings) in files {
+                match path {
+                    Some(path) => out.push_str(&format!("\n### `{}`\n", path)),
+                    None => out.push_str("\n### General\n"),
+                }
+                for finding in findings {
+                    out.push('\n');
+                    self.finding_markdown(&mut out, finding);
+                }
+            }
+        }
+        out
+    }
+
+    fn finding_markdown(&self, out: &mut String, finding: &ReviewFinding) {
+        out.push_str(&format!(
+            "#### {} — {}\n\n",
+            finding.title,
+            finding.status.as_str()
+        ));
+        if let Some(lines) = line_range(finding) {
+            out.push_str(&format!("{}\n\n", lines));
+        }
+        out.push_str(finding.description.trim());
+        out.push('\n');
+        if let Some(excerpt) = self.excerpts.get(&finding.id) {
+            out.push('\n');
+            out.push_str(&fenced(&excerpt.numbered(), ""));
+        }
+        if let Some(rationale) = &finding.severity_rationale {
+            out.push_str(&format!("\n**Severity:** {}\n", rationale));
+        }
+        if let Some(criteria) = &finding.acceptance_criteria {
+            out.push_str(&format!("\n**Acceptance criteria:** {}\n", criteria));
+        }
+        if let Some(fix) = &finding.suggested_fix {
+            out.push_str("\n**Suggested fix:**\n\n");
+            out.push_str(&fenced(fix.trim_end(), "diff"));
+        }
+    }
+
+    pub fn to_html(&self) -> String {
+        let title = format!("Review: {}", self.task_title);
+        let mut out = format!(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>\n<h1>{}</h1>\n<ul>\n",
+            escape_html(&title),
+            HTML_STYLE,
+            escape_html(&title)
+        );
+        for (label, value) in self.metadata() {
+            out.push_str(&format!(
+                "<li><strong>{}:</strong> {}</li>\n",
+                label,
+                escape_html(&value)
+            ));
+        }
+        out.push_str("</ul>\n");
+
+        if !self.findings.summary.trim().is_empty() {
+            out.push_str(&format!(
+                "<h2>Summary</h2>\n<p class=\"text\">{}</p>\n",
+                escape_html(self.findings.summary.trim())
+            ));
+        }
+
+        for (severity, files) in self.grouped() {
+            let count: usize = files.iter().map(|(_, f)| f.len()).sum();
+            out.push_str(&format!(
+                "<h2>{} ({})</h2>\n",
+                severity_heading(severity),
+                count
+            ));
+            for (path, findings) in files {
+                match path {
+                    Some(path) => {
+                        out.push_str(&format!("<h3><code>{}</code></h3>\n", escape_html(path)))
+                    }
+                    None => out.push_str("<h3>General</h3>\n"),
+                }
+                for finding in findings {
+                    self.finding_html(&mut out, finding);
+                }
+            }
+        }
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+
+    fn finding_html(&self, out: &mut String, finding: &ReviewFinding) {
+        out.push_str(&format!(
+            "<section class=\"finding\">\n<h4>{} <span class=\"status {}\">{}</span></h4>\n",
+            escape_html(&finding.title),
+            finding.status.as_str(),
+            finding.status.as_str()
+        ));
+        if let Some(lines) = line_range(finding) {
+            out.push_str(&format!("<p class=\"lines\">{}</p>\n", lines));
+        }
+        out.push_str(&format!(
+            "<p class=\"text\">{}</p>\n",
+            escape_html(finding.description.trim())
+        ));
+        if let Some(excerpt) = self.excerpts.get(&finding.id) {
+            out.push_str(&format!(
+                "<pre><code>{}</code></pre>\n",
+                escape_html(&excerpt.numbered())
+            ));
+        }
+        if let Some(rationale) = &finding.severity_rationale {
+            out.push_str(&format!(
+                "<p><strong>Severity:</strong> {}</p>\n",
+                escape_html(rationale)
+            ));
+        }
+        if let Some(criteria) = &finding.acceptance_criteria {
+            out.push_str(&format!(
+                "<p><strong>Acceptance criteria:</strong> {}</p>\n",
+                escape_html(criteria)
+            ));
+        }
+        if let Some(fix) = &finding.suggested_fix {
+            out.push_str(&format!(
+                "<p><strong>Suggested fix:</strong></p>\n<pre><code>{}</code></pre>\n",
+                escape_html(fix.trim_end())
+            ));
+        }
+        out.push_str("</section>\n");
+    }
+
+    /// Labelled facts shown under the title
+    fn metadata(&self) -> Vec<(&'static str, String)> {
+        let findings = self.findings;
+        let outcome = if findings.approved {
+            "Approved"
+        } else {
+            "Changes requested"
+        };
+        let model = self
+            .reviewer_model
+            .as_ref()
+            .map(|m| format!("{}/{}", m.provider_id, m.model_id))
+            .unwrap_or_else(|| "OpenCode default".to_string());
+
+        let counts: Vec<String> = [
+            FindingStatus::Fixed,
+            FindingStatus::Dismissed,
+            FindingStatus::Skipped,
+            FindingStatus::Pending,
+        ]
+        .into_iter()
+        .filter_map(|status| {
+            let count = findings
+                .findings
+                .iter()
+                .filter(|f| f.status == status)
+                .count();
+            (count > 0).then(|| format!("{} {}", count, status.as_str()))
+        })
+        .collect();
+        let total = if counts.is_empty() {
+            "none".to_string()
+        } else {
+            format!("{} ({})", findings.findings.len(), counts.join(", "))
+        };
+
+        vec![
+            ("Task", findings.task_id.to_string()),
+            (
+                "Reviewed",
+                findings.created_at.format("%Y-%m-%d %H:%M UTC").to_string(),
+            ),
+            ("Reviewer model", model),
+            ("Outcome", outcome.to_string()),
+            ("Findings", total),
+        ]
+    }
+
+    /// Findings by severity, most severe first, then by file; findings
+    /// without a file come last under `None`
+    fn grouped(&self) -> Vec<(FindingSeverity, Vec<FileFindings<'_>>)> {
+        SEVERITIES
+            .into_iter()
+            .filter_map(|severity| {
+                let mut files: BTreeMap<Option<&str>, Vec<&ReviewFinding>> = BTreeMap::new();
+                for finding in &self.findings.findings {
+                    if finding.severity == severity {
+                        let path = finding
+                            .file_path
+                            .as_deref()
+                            .map(|p| p.trim_start_matches("./"));
+                        files.entry(path).or_default().push(finding);
+                    }
+                }
+                let mut files: Vec<_> = files.into_iter().collect();
+                // `None` sorts first; general findings read better after the
+                // located ones
+                if files.first().is_some_and(|(path, _)| path.is_none()) {
+                    files.rotate_left(1);
+                }
+                (!files.is_empty()).then_some((severity, files))
+            })
+            .collect()
+    }
+}
+
+/// A file, or `None` for findings without one, and its findings
+type FileFindings<'a> = (Option<&'a str>, Vec<&'a ReviewFinding>);
+
+const HTML_STYLE: &str = "body{font-family:system-ui,sans-serif;max-width:60rem;margin:2rem auto;padding:0 1rem;line-height:1.5}\
+pre{background:#f5f5f5;padding:.75rem;overflow-x:auto}\
+.text{white-space:pre-wrap}\
+.finding{border-top:1px solid #ddd;margin-top:1rem}\
+.lines{color:#666}\
+.status{font-size:.8em;padding:.1em .4em;border-radius:.3em;background:#eee}\
+.status.fixed{background:#d4f4dd}\
+.status.pending{background:#fde2c8}";
+
+fn severity_heading(severity: FindingSeverity) -> &'static str {
+    match severity {
+        FindingSeverity::Error => "Errors",
+        FindingSeverity::Warning => "Warnings",
+        FindingSeverity::Info => "Info",
+    }
+}
+
+fn line_range(finding: &ReviewFinding) -> Option<String> {
+    let start = finding.line_start.filter(|&l| l >= 1)?;
+    Some(match finding.line_end.filter(|&l| l > start) {
+        Some(end) => format!("Lines {}-{}", start, end),
+        None => format!("Line {}", start),
+    })
+}
+
+/// `content` in a code fence longer than any backtick run inside it
+fn fenced(content: &str, lang: &str) -> String {
+    let longest = content.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest.max(2) + 1);
+    format!("{}{}\n{}\n{}\n", fence, lang, content, fence)
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+    use uuid::Uuid;
+
+    fn finding(id: &str, path: Option<&str>, severity: FindingSeverity) -> ReviewFinding {
+        ReviewFinding {
+            id: id.to_string(),
+            file_path: path.map(str::to_string),
+            line_start: path.map(|_| 3),
+            line_end: path.map(|_| 4),
+            title: format!("Finding {}", id),
+            description: "Something is <off>".to_string(),
+            severity,
+            status: FindingStatus::Pending,
+            original_severity: None,
+            severity_rationale: None,
+            reproduction: None,
+            acceptance_criteria: None,
+            suggested_fix: None,
+            consensus_score: None,
+            comments: Vec::new(),
+            snippet: None,
+        }
+    }
+
+    fn findings() -> ReviewFindings {
+        let mut fixed = finding("b", Some("./src/lib.rs"), FindingSeverity::Error);
+        fixed.status = FindingStatus::Fixed;
+        ReviewFindings::with_findings(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "Two problems".to_string(),
+            vec![
+                finding("a", None, FindingSeverity::Error),
+                fixed,
+                finding("c", Some("src/main.rs"), FindingSeverity::Info),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_code_excerpt_from_source() {
+        let source = (1..=10).map(|i| format!("line {}", i)).collect::<Vec<_>>();
+        let source = source.join("\n");
+
+        let excerpt = CodeExcerpt::from_source(&source, 1, 1).unwrap();
+        assert_eq!(excerpt.start_line, 1);
+        assert_eq!(excerpt.lines, ["line 1", "line 2", "line 3"]);
+
+        let excerpt = CodeExcerpt::from_source(&source, 5, 6).unwrap();
+        assert_eq!(excerpt.start_line, 3);
+        assert_eq!(excerpt.lines.len(), 6);
+        assert_eq!(excerpt.numbered().lines().next().unwrap(), "3 | line 3");
+
+        assert!(CodeExcerpt::from_source(&source, 11, 12).is_none());
+        assert!(CodeExcerpt::from_source(&source, 0, 2).is_none());
+    }
+
+    #[test]
+    fn test_to_markdown_groups_by_severity_and_file() {
+        let findings = findings();
+        let report = ReviewReport::new("Add login", &findings)
+            .with_reviewer_model(Some(ModelSelection::new("anthropic", "claude")));
+        let markdown = report.to_markdown();
+
+        assert!(markdown.starts_with("# Review: Add login\n"));
+        assert!(markdown.contains("- **Reviewer model:** anthropic/claude\n"));
+        assert!(markdown.contains("- **Findings:** 3 (1 fixed, 2 pending)\n"));
+
+        let errors = markdown.find("## Errors (2)").unwrap();
+        let lib = markdown.find("### `src/lib.rs`").unwrap();
+        let general = markdown.find("### General").unwrap();
+        let info = markdown.find("## Info (1)").unwrap();
+        assert!(errors < lib && lib < general && general < info);
+        assert!(markdown.contains("#### Finding b — fixed\n\nLines 3-4\n"));
+        assert!(!markdown.contains("## Warnings"));
+    }
+
+    #[tokio::test]
+    async fn test_load_excerpts() {
+        let dir = tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("src")).unwrap();
+        std::fs::write(dir.path().join("src/lib.rs"), "a\nb\nc\nd\ne\nf\ng\n").unwrap();
+
+        let mut findings = findings();
+        findings.findings.push(finding(
+            "d",
+            Some("../outside.rs"),
+            FindingSeverity::Warning,
+        ));
+        let report = ReviewReport::new("Add login", &findings)
+            .load_excerpts(dir.path())
+            .await;
+
+        assert_eq!(report.excerpts.len(), 1);
+        let excerpt = &report.excerpts["b"];
+        assert_eq!(excerpt.start_line, 1);
+        assert_eq!(excerpt.lines, ["a", "b", "c", "d", "e", "f"]);
+        assert!(report.to_markdown().contains("```\n1 | a\n"));
+    }
+
+    #[test]
+    fn test_to_html_escapes_text() {
+        let findings = findings();
+        let html = ReviewReport::new("<script>", &findings).to_html();
+        assert!(html.contains("<title>Review: &lt;script&gt;</title>"));
+        assert!(html.contains("Something is &lt;off&gt;"));
+        assert!(html.contains("<h2>Errors (2)</h2>"));
+        assert!(html.contains("Changes requested"));
+    }
+
+    #[test]
+    fn test_fenced_outgrows_backticks() {
+        assert_eq!(fenced("a", ""), "```\na\n```\n");
+        assert_eq!(fenced("```x```", "md"), "````md\n```x```\n````\n");
+    }
+}
@@ -0,0 +1,169 @@
+//! Review findings as SARIF
+//!
+//! SARIF 2.1.0 is what GitHub code scanning accepts for upload and what
+//! several IDEs show inline. Each finding becomes a result with a rule of its
+//! own. Fixed findings are left out; dismissed ones are kept as suppressed
+//! results, so code scanning does not raise them again.
+
+use serde_json::{json, Map, Value};
+
+use crate::files::{FindingSeverity, FindingStatus, ReviewFinding, ReviewFindings};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+const TOOL_NAME: &str = "OpenCode Studio review";
+
+impl FindingSeverity {
+    /// SARIF result level
+    pub fn sarif_level(&self) -> &'static str {
+        match self {
+            FindingSeverity::Error => "error",
+            FindingSeverity::Warning => "warning",
+            FindingSeverity::Info => "note",
+        }
+    }
+}
+
+impl ReviewFindings {
+    /// The findings as a SARIF 2.1.0 log with a single run
+    pub fn to_sarif(&self) -> Value {
+        let reported: Vec<&ReviewFinding> = self
+            .findings
+            .iter()
+            .filter(|f| f.status != FindingStatus::Fixed)
+            .collect();
+
+        json!({
+            "$schema": SARIF_SCHEMA,
+            "version": SARIF_VERSION,
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": TOOL_NAME,
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": reported.iter().map(|f| sarif_rule(f)).collect::<Vec<_>>(),
+                    }
+                },
+                "automationDetails": { "id": format!("opencode-review/{}/", self.task_id) },
+                "results": reported.iter().map(|f| sarif_result(f)).collect::<Vec<_>>(),
+                "properties": {
+                    "taskId": self.task_id.to_string(),
+                    "sessionId": self.session_id.to_string(),
+                    "approved": self.approved,
+                    "summary": self.summary,
+                },
+            }],
+        })
+    }
+}
+
+fn sarif_rule(finding: &ReviewFinding) -> Value {
+    let mut rule = json!({
+        "id": finding.id,
+        "name": finding.title,
+        "shortDescription": { "text": finding.title },
+        "fullDescription": { "text": finding.description },
+        "defaultConfiguration": { "level": finding.severity.sarif_level() },
+    });
+    if let Some(criteria) = &finding.acceptance_criteria {
+        rule["help"] = json!({ "text": format!("Acceptance criteria: {}", criteria) });
+    }
+    rule
+}
+
+fn sarif_result(finding: &ReviewFinding) -> Value {
+    let mut result = json!({
+        "ruleId": finding.id,
+        "level": finding.severity.sarif_level(),
+        "message": { "text": format!("{}\n\n{}", finding.title, finding.description).trim_end() },
+    });
+
+    if let Some(path) = &finding.file_path {
+        let mut location = json!({ "artifactLocation": { "uri": path.trim_start_matches("./") } });
+        // SARIF lines are 1-based; a finding without a usable line points at
+        // the whole file
+        if let Some(start) = finding.line_start.filter(|&l| l >= 1) {
+            let mut region = Map::new();
+            region.insert("startLine".to_string(), json!(start));
+            if let Some(end) = finding.line_end.filter(|&l| l >= start) {
+                region.insert("endLine".to_string(), json!(end));
+            }
+            location["region"] = Value::Object(region);
+        }
+        result["locations"] = json!([{ "physicalLocation": location }]);
+    }
+
+    if finding.status == FindingStatus::Dismissed {
+        let mut suppression = json!({ "kind": "external", "status": "accepted" });
+        if let Some(rationale) = &finding.severity_rationale {
+            suppression["justification"] = json!(rationale);
+        }
+        result["suppressions"] = json!([suppression]);
+    }
+
+    if let Some(fix) = &finding.suggested_fix {
+        result["properties"] = json!({ "suggestedFix": fix });
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn finding(id: &str, severity: FindingSeverity, status: FindingStatus) -> ReviewFinding {
+        ReviewFinding {
+            id: id.to_string(),
+            file_path: Some("./src/main.rs".to_string()),
+            line_start: Some(10),
+            line_end: Some(12),
+            title: format!("Finding {}", id),
+            description: "Something is off".to_string(),
+            severity,
+            status,
+            original_severity: None,
+            severity_rationale: None,
+            reproduction: None,
+            acceptance_criteria: None,
+            suggested_fix: None,
+            consensus_score: None,
+            comments: Vec::new(),
+            snippet: None,
+        }
+    }
+
+    #[test]
+    fn test_to_sarif() {
+        let mut unlocated = finding("c", FindingSeverity::Info, FindingStatus::Dismissed);
+        unlocated.file_path = None;
+        let findings = ReviewFindings::with_findings(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "Two problems".to_string(),
+            vec![
+                finding("a", FindingSeverity::Error, FindingStatus::Pending),
+                finding("b", FindingSeverity::Warning, FindingStatus::Fixed),
+                unlocated,
+            ],
+        );
+
+        let sarif = findings.to_sarif();
+        assert_eq!(sarif["version"], "2.1.0");
+        let run = &sarif["runs"][0];
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), 2);
+
+        assert_eq!(results[0]["ruleId"], "a");
+        assert_eq!(results[0]["level"], "error");
+        let location = &results[0]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/main.rs");
+        assert_eq!(location["region"]["startLine"], 10);
+        assert_eq!(location["region"]["endLine"], 12);
+
+        assert_eq!(results[1]["level"], "note");
+        assert!(results[1].get("locations").is_none());
+        assert_eq!(results[1]["suppressions"][0]["kind"], "external");
+    }
+}
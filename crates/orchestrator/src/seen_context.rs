@@ -0,0 +1,222 @@
+//! Code already shown to a task's sessions
+//!
+//! Planning and every review iteration pre-fetch related code into their
+//! prompts, and without a record the same ranges are sent again each time.
+//! The ranges a prompt included are recorded per task; later injections leave
+//! covered ranges out and list them instead, and the `get_seen_context`
+//! findings tool gives the model a refresher on what it was shown.
+
+use chrono::{DateTime, Utc};
+use opencode_core::SessionPhase;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use vcs::same_relative_path;
+
+/// Characters of a range's first line kept as its summary
+const SUMMARY_LENGTH: usize = 120;
+
+/// A file range included in a prompt
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeenRange {
+    pub file_path: String,
+    pub start_line: u32,
+    pub end_line: u32,
+    /// Phase whose prompt included the range
+    pub phase: String,
+    /// First meaningful line of the range, usually a signature
+    pub summary: String,
+    pub shown_at: DateTime<Utc>,
+}
+
+impl SeenRange {
+    pub fn new(
+        file_path: impl Into<String>,
+        start_line: u32,
+        end_line: u32,
+        phase: SessionPhase,
+        content: &str,
+    ) -> Self {
+        Self {
+            file_path: file_path.into(),
+            start_line,
+            end_line,
+            phase: phase.as_str().to_string(),
+            summary: summarize(content),
+            shown_at: Utc::now(),
+        }
+    }
+
+    /// Whether this range contains lines `start_line..=end_line` of `file_path`
+    pub fn covers(&self, file_path: &str, start_line: u32, end_line: u32) -> bool {
+        same_relative_path(&self.file_path, file_path)
+            && self.start_line <= start_line
+            && end_line <= self.end_line
+    }
+
+    pub fn location(&self) -> String {
+        format!("{}:{}-{}", self.file_path, self.start_line, self.end_line)
+    }
+
+    fn to_markdown_line(&self) -> String {
+        if self.summary.is_empty() {
+            format!("- `{}` ({})", self.location(), self.phase)
+        } else {
+            format!(
+                "- `{}` ({}): `{}`",
+                self.location(),
+                self.phase,
+                self.summary
+            )
+        }
+    }
+}
+
+/// The ranges shown to one task's sessions, in the order they were shown
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeenContext {
+    pub task_id: Uuid,
+    pub ranges: Vec<SeenRange>,
+}
+
+impl SeenContext {
+    pub fn new(task_id: Uuid) -> Self {
+        Self {
+            task_id,
+            ranges: Vec::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The recorded range covering lines `start_line..=end_line` of
+    /// `file_path`, if any
+    pub fn covering(&self, file_path: &str, start_line: u32, end_line: u32) -> Option<&SeenRange> {
+        self.ranges
+            .iter()
+            .find(|r| r.covers(file_path, start_line, end_line))
+    }
+
+    /// Record a shown range; returns false if an earlier range covers it
+    pub fn record(&mut self, range: SeenRange) -> bool {
+        if self
+            .covering(&range.file_path, range.start_line, range.end_line)
+            .is_some()
+        {
+            return false;
+        }
+        self.ranges.push(range);
+        true
+    }
+
+    /// Ranges of `file_path`, by start line
+    pub fn for_file(&self, file_path: &str) -> Vec<&SeenRange> {
+        let mut ranges: Vec<&SeenRange> = self
+            .ranges
+            .iter()
+            .filter(|r| same_relative_path(&r.file_path, file_path))
+            .collect();
+        ranges.sort_by_key(|r| r.start_line);
+        ranges
+    }
+
+    /// Everything shown so far, grouped by file
+    pub fn to_markdown(&self) -> String {
+        let mut files: Vec<&str> = self.ranges.iter().map(|r| r.file_path.as_str()).collect();
+        files.sort_unstable();
+        files.dedup();
+
+        let mut markdown = String::new();
+        for file in files {
+            markdown.push_str(&format!("### `{}`\n\n", file));
+            for range in self.for_file(file) {
+                markdown.push_str(&range.to_markdown_line());
+                markdown.push('\n');
+            }
+            markdown.push('\n');
+        }
+        markdown.trim_end().to_string()
+    }
+}
+
+/// Prompt note listing ranges left out because an earlier session was shown
+/// them; empty when nothing was left out
+pub fn already_shown_note(ranges: &[SeenRange]) -> String {
+    if ranges.is_empty() {
+        return String::new();
+    }
+    let lines: Vec<String> = ranges.iter().map(SeenRange::to_markdown_line).collect();
+    format!(
+        "**Already shown earlier in this task** (not repeated; use `get_seen_context` for a refresher, or read the file if you need it in full):\n\n{}",
+        lines.join("\n")
+    )
+}
+
+/// The first meaningful line of `content`, shortened
+fn summarize(content: &str) -> String {
+    let line = content
+        .lines()
+        .map(str::trim)
+        .find(|line| {
+            !line.is_empty()
+                && !line.starts_with("//")
+                && !line.starts_with('#')
+                && !line.starts_with("/*")
+                && !line.starts_with('*')
+        })
+        .unwrap_or_default();
+    match line.char_indices().nth(SUMMARY_LENGTH) {
+        Some((end, _)) => format!("{}…", &line[..end]),
+        None => line.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_skips_covered_ranges() {
+        let mut seen = SeenContext::new(Uuid::new_v4());
+        let content = "/// Logs a user in\npub fn login(user: &User) -> Session {\n}";
+        assert!(seen.record(SeenRange::new(
+            "src/auth.rs",
+            10,
+            40,
+            SessionPhase::Planning,
+            content
+        )));
+        assert!(!seen.record(SeenRange::new(
+            "src/auth.rs",
+            12,
+            30,
+            SessionPhase::Review,
+            ""
+        )));
+        assert!(seen.record(SeenRange::new(
+            "src/auth.rs",
+            35,
+            60,
+            SessionPhase::Review,
+            "fn logout() {}"
+        )));
+
+        assert_eq!(seen.ranges.len(), 2);
+        assert_eq!(
+            seen.ranges[0].summary,
+            "pub fn login(user: &User) -> Session {"
+        );
+        assert!(seen.covering("src/auth.rs", 20, 25).is_some());
+        assert!(seen.covering("src/db.rs", 20, 25).is_none());
+
+        let markdown = seen.to_markdown();
+        assert!(markdown.contains("### `src/auth.rs`"));
+        assert!(markdown.contains("`src/auth.rs:10-40` (planning)"));
+
+        let note = already_shown_note(&seen.ranges[1..]);
+        assert!(note.contains("get_seen_context"));
+        assert!(note.contains("`src/auth.rs:35-60` (review): `fn logout() {}`"));
+        assert!(already_shown_note(&[]).is_empty());
+    }
+}
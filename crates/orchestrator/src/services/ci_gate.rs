@@ -0,0 +1,388 @@
+//! Gate on the GitHub checks of a task's changes
+//!
+//! Once a task's workspace is merged or a pull request is opened from it,
+//! the gate polls the check runs of the branch and records where they stand
+//! in the task's CI gate file, which holds the task back from done until
+//! they pass. A failure becomes a finding, so the fix phase can take it up;
+//! every change is published on the event bus.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use events::{Event, EventBus, EventEnvelope};
+use github::{CheckRun, CiStatus, GitHubClient};
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::error::Result;
+use crate::files::{
+    CiGateRecord, CiGateState, FileManager, FindingSeverity, FindingStatus, ReviewFinding,
+};
+
+/// How often and how long the gate polls
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CiGateConfig {
+    pub poll_interval: Duration,
+    /// Polling stops after this, leaving the gate timed out
+    pub max_wait: Duration,
+}
+
+impl Default for CiGateConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_secs(30),
+            max_wait: Duration::from_secs(60 * 60),
+        }
+    }
+}
+
+/// Where the gate reads the check runs of a ref from
+#[async_trait]
+pub trait CiStatusSource: Send + Sync {
+    async fn ci_status(&self, ref_name: &str) -> github::Result<CiStatus>;
+}
+
+#[async_trait]
+impl CiStatusSource for GitHubClient {
+    async fn ci_status(&self, ref_name: &str) -> github::Result<CiStatus> {
+        self.get_ci_status(ref_name).await
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CheckOutcome {
+    Passed,
+    Failed,
+    Pending,
+}
+
+/// Outcome of a check run; conclusions are the GitHub client's names for
+/// them, e.g. `Success` or `TimedOut`
+fn check_outcome(check: &CheckRun) -> CheckOutcome {
+    match check.conclusion.as_deref() {
+        None => CheckOutcome::Pending,
+        Some(c) if c.contains("Failure") || c.contains("Cancelled") || c.contains("TimedOut") => {
+            CheckOutcome::Failed
+        }
+        Some(c) if c.contains("ActionRequired") => CheckOutcome::Pending,
+        Some(_) => CheckOutcome::Passed,
+    }
+}
+
+/// Fold `status` into `record`; returns whether anything changed. A single
+/// failed check fails the gate, and a ref without checks keeps it pending.
+fn apply_status(record: &mut CiGateRecord, status: &CiStatus) -> bool {
+    let count = |outcome| {
+        status
+            .checks
+            .iter()
+            .filter(|c| check_outcome(c) == outcome)
+            .count() as u32
+    };
+    let (passed, failed, pending) = (
+        count(CheckOutcome::Passed),
+        count(CheckOutcome::Failed),
+        count(CheckOutcome::Pending),
+    );
+    let state = if failed > 0 {
+        CiGateState::Failed
+    } else if pending > 0 || status.checks.is_empty() {
+        CiGateState::Pending
+    } else {
+        CiGateState::Passed
+    };
+    let failed_checks: Vec<String> = status
+        .checks
+        .iter()
+        .filter(|c| check_outcome(c) == CheckOutcome::Failed)
+        .map(|c| c.name.clone())
+        .collect();
+
+    let changed = (record.state, record.passed, record.failed, record.pending)
+        != (state, passed, failed, pending);
+    record.state = state;
+    record.passed = passed;
+    record.failed = failed;
+    record.pending = pending;
+    record.failed_checks = failed_checks;
+    changed
+}
+
+/// Finding the fix phase gets for the checks that failed on the gate's ref
+fn ci_finding(record: &CiGateRecord, status: &CiStatus) -> ReviewFinding {
+    let failed: Vec<String> = status
+        .checks
+        .iter()
+        .filter(|c| check_outcome(c) == CheckOutcome::Failed)
+        .map(|c| match &c.html_url {
+            Some(url) => format!("- {} ({})", c.name, url),
+            None => format!("- {}", c.name),
+        })
+        .collect();
+
+    ReviewFinding {
+        id: format!("ci-{}", record.updated_at.timestamp()),
+        file_path: None,
+        line_start: None,
+        line_end: None,
+        title: format!("GitHub checks failed on {}", record.ref_name),
+        description: format!("These checks failed:\n\n{}", failed.join("\n")),
+        severity: FindingSeverity::Error,
+        status: FindingStatus::Pending,
+        original_severity: None,
+        severity_rationale: None,
+        reproduction: None,
+        acceptance_criteria: Some(format!("Every check on {} passes", record.ref_name)),
+        suggested_fix: None,
+        consensus_score: None,
+        comments: Vec::new(),
+        snippet: None,
+    }
+}
+
+pub struct CiGate {
+    source: Arc<dyn CiStatusSource>,
+    file_manager: FileManager,
+    event_bus: Option<EventBus>,
+    config: CiGateConfig,
+}
+
+impl CiGate {
+    pub fn new(source: Arc<dyn CiStatusSource>, file_manager: FileManager) -> Self {
+        Self {
+            source,
+            file_manager,
+            event_bus: None,
+            config: CiGateConfig::default(),
+        }
+    }
+
+    pub fn with_event_bus(mut self, bus: EventBus) -> Self {
+        self.event_bus = Some(bus);
+        self
+    }
+
+    pub fn with_config(mut self, config: CiGateConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Hold `task_id` back until the checks on `ref_name` pass, replacing any
+    /// earlier gate of the task. Call [`CiGate::watch`] with the result to
+    /// poll them.
+    pub async fn begin(
+        &self,
+        task_id: Uuid,
+        ref_name: &str,
+        complete_on_pass: bool,
+    ) -> Result<CiGateRecord> {
+        let mut record = CiGateRecord::pending(task_id, ref_name, complete_on_pass);
+        self.save(&mut record).await?;
+        info!(task_id = %task_id, ref_name = %ref_name, "CI gate started");
+        Ok(record)
+    }
+
+    /// Poll the checks of `record` until they finish or the wait runs out;
+    /// failed checks are added to the task's findings
+    pub async fn watch(&self, mut record: CiGateRecord) -> Result<CiGateRecord> {
+        let started = Instant::now();
+        let mut last_status = None;
+
+        loop {
+            match self.source.ci_status(&record.ref_name).await {
+                Ok(status) => {
+                    if apply_status(&mut record, &status) {
+                        self.save(&mut record).await?;
+                    }
+                    last_status = Some(status);
+                    if record.state.is_finished() {
+                        break;
+                    }
+                }
+                // GitHub may not know a just-pushed ref yet
+                Err(e) => {
+                    warn!(task_id = %record.task_id, ref_name = %record.ref_name, error = %e, "Failed to poll CI status")
+                }
+            }
+
+            if started.elapsed() >= self.config.max_wait {
+                record.state = CiGateState::TimedOut;
+                self.save(&mut record).await?;
+                break;
+            }
+            debug!(task_id = %record.task_id, pending = record.pending, "CI checks still running");
+            tokio::time::sleep(self.config.poll_interval).await;
+        }
+
+        if let (CiGateState::Failed, Some(status)) = (record.state, &last_status) {
+            self.file_manager
+                .add_finding(record.task_id, ci_finding(&record, status))
+                .await?;
+        }
+        info!(task_id = %record.task_id, state = record.state.as_str(), "CI gate finished");
+
+        Ok(record)
+    }
+
+    async fn save(&self, record: &mut CiGateRecord) -> Result<()> {
+        record.updated_at = Utc::now();
+        self.file_manager.write_ci_gate(record).await?;
+
+        if let Some(bus) = &self.event_bus {
+            bus.publish(EventEnvelope::new(Event::CiStatusChanged {
+                task_id: record.task_id,
+                ref_name: record.ref_name.clone(),
+                state: record.state.as_str().to_string(),
+                passed: record.passed,
+                failed: record.failed,
+                pending: record.pending,
+            }));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::sync::Mutex;
+
+    use github::CiState;
+
+    use super::*;
+
+    struct ScriptedSource(Mutex<VecDeque<CiStatus>>);
+
+    #[async_trait]
+    impl CiStatusSource for ScriptedSource {
+        async fn ci_status(&self, _ref_name: &str) -> github::Result<CiStatus> {
+            let mut statuses = self.0.lock().unwrap();
+            // The last status repeats once the script runs out
+            let status = if statuses.len() > 1 {
+                statuses.pop_front()
+            } else {
+                statuses.front().cloned()
+            };
+            Ok(status.unwrap_or(CiStatus {
+                state: CiState::Pending,
+                total_count: 0,
+                checks: Vec::new(),
+            }))
+        }
+    }
+
+    fn status(conclusions: &[Option<&str>]) -> CiStatus {
+        CiStatus {
+            state: CiState::Pending,
+            total_count: conclusions.len() as u32,
+            checks: conclusions
+                .iter()
+                .enumerate()
+                .map(|(i, conclusion)| CheckRun {
+                    name: format!("check-{}", i),
+                    status: "unknown".to_string(),
+                    conclusion: conclusion.map(str::to_string),
+                    html_url: Some(format!("https://ci.example/{}", i)),
+                })
+                .collect(),
+        }
+    }
+
+    fn gate(dir: &std::path::Path, statuses: Vec<CiStatus>) -> CiGate {
+        CiGate::new(
+            Arc::new(ScriptedSource(Mutex::new(statuses.into()))),
+            FileManager::new(dir),
+        )
+        .with_config(CiGateConfig {
+            poll_interval: Duration::from_millis(1),
+            max_wait: Duration::from_millis(200),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_gate_passes_once_checks_succeed() {
+        let dir = tempfile::tempdir().unwrap();
+        let task_id = Uuid::new_v4();
+        let gate = gate(
+            dir.path(),
+            vec![
+                status(&[Some("Success"), None]),
+                status(&[Some("Success"), Some("Skipped")]),
+            ],
+        );
+        let bus = EventBus::new();
+        let mut events = bus.subscribe();
+        let gate = gate.with_event_bus(bus);
+
+        let record = gate.begin(task_id, "task-1", true).await.unwrap();
+        let record = gate.watch(record).await.unwrap();
+
+        assert_eq!(record.state, CiGateState::Passed);
+        assert_eq!((record.passed, record.failed, record.pending), (2, 0, 0));
+        let stored = gate
+            .file_manager
+            .read_ci_gate(task_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.state, CiGateState::Passed);
+        assert!(stored.complete_on_pass);
+        assert!(gate
+            .file_manager
+            .read_findings(task_id)
+            .await
+            .unwrap()
+            .is_none());
+
+        let mut states = Vec::new();
+        while let Ok(envelope) = events.try_recv() {
+            if let Event::CiStatusChanged { state, .. } = envelope.event {
+                states.push(state);
+            }
+        }
+        assert_eq!(states, ["pending", "pending", "passed"]);
+    }
+
+    #[tokio::test]
+    async fn test_failed_checks_become_a_finding() {
+        let dir = tempfile::tempdir().unwrap();
+        let task_id = Uuid::new_v4();
+        let gate = gate(
+            dir.path(),
+            vec![status(&[Some("Success"), Some("Failure")])],
+        );
+
+        let record = gate.begin(task_id, "task-1", false).await.unwrap();
+        let record = gate.watch(record).await.unwrap();
+
+        assert_eq!(record.state, CiGateState::Failed);
+        assert_eq!(record.failed_checks, ["check-1"]);
+        let findings = gate
+            .file_manager
+            .read_findings(task_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!findings.approved);
+        assert_eq!(findings.pending_count(), 1);
+        let finding = &findings.findings[0];
+        assert_eq!(finding.severity, FindingSeverity::Error);
+        assert!(finding
+            .description
+            .contains("check-1 (https://ci.example/1)"));
+        assert!(finding.validate().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_gate_times_out_without_checks() {
+        let dir = tempfile::tempdir().unwrap();
+        let gate = gate(dir.path(), Vec::new());
+
+        let record = gate.begin(Uuid::new_v4(), "main", false).await.unwrap();
+        let record = gate.watch(record).await.unwrap();
+
+        assert_eq!(record.state, CiGateState::TimedOut);
+    }
+}
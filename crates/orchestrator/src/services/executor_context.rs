@@ -1,20 +1,31 @@
 use db::{SessionRepository, TaskRepository};
 use events::{Event, EventBus, EventEnvelope};
 use opencode_client::apis::configuration::Configuration;
-use opencode_core::{Session, SessionPhase, Task, TaskStatus, UpdateTaskRequest};
+use opencode_core::{
+    tool_call_log_path, Session, SessionPhase, Task, TaskStatus, UpdateTaskRequest,
+};
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use std::sync::Arc;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 use uuid::Uuid;
 use vcs::WorkspaceManager;
 
 use crate::activity_store::{SessionActivityRegistry, SessionActivityStore};
+use crate::core::OrderedEventEmitter;
 use crate::error::{OrchestratorError, Result};
-use crate::files::FileManager;
-use crate::services::{McpManager, OpenCodeClient, WikiMcpConfig};
-use crate::state_machine::TaskStateMachine;
-
-#[derive(Debug, Clone, Default)]
+use crate::files::{ChangedFiles, FileManager};
+use crate::mcp_config::McpToolPermissions;
+use crate::plan_context::DEFAULT_CONTEXT_FILE_TOKENS;
+use crate::seen_context::{already_shown_note, SeenContext, SeenRange};
+use crate::services::{
+    McpManager, OpenCodeClient, PhaseProgress, ReviewConsensusConfig, ReviewPolicy, WikiMcpConfig,
+};
+use crate::task_env::{EnvVar, TaskEnvironment};
+use crate::usage::UsageStore;
+use crate::workflow::{RuleEvaluation, TransitionTrigger, WorkflowDefinition};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ModelSelection {
     pub provider_id: String,
     pub model_id: String,
@@ -29,14 +40,80 @@ impl ModelSelection {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PhaseModels {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub planning: Option<ModelSelection>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub implementation: Option<ModelSelection>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub review: Option<ModelSelection>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fix: Option<ModelSelection>,
 }
 
+impl PhaseModels {
+    /// Model selected for `phase`, if any
+    pub fn for_phase(&self, phase: SessionPhase) -> Option<&ModelSelection> {
+        match phase {
+            SessionPhase::Planning => self.planning.as_ref(),
+            SessionPhase::Implementation => self.implementation.as_ref(),
+            SessionPhase::Review => self.review.as_ref(),
+            SessionPhase::Fix => self.fix.as_ref(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.planning.is_none()
+            && self.implementation.is_none()
+            && self.review.is_none()
+            && self.fix.is_none()
+    }
+}
+
+/// Wiki context pre-fetched into phase prompts
+#[derive(Debug, Clone)]
+pub struct WikiContextConfig {
+    pub wiki: WikiMcpConfig,
+    /// Wiki branch the context is read from
+    pub branch: String,
+    /// Token cap for the context section of the prompt
+    pub max_tokens: usize,
+}
+
+/// Wiki index whose public API tags raise the severity of review findings
+#[derive(Debug, Clone)]
+pub struct PublicApiIndex {
+    pub db_path: PathBuf,
+    /// Wiki branch the tags are read from
+    pub branch: String,
+}
+
+/// Wiki context rendered for a planning prompt
+#[derive(Debug, Clone)]
+pub struct PlanningWikiContext {
+    pub markdown: String,
+    /// Sources included in `markdown`, listed in the plan file
+    pub sources: Vec<String>,
+}
+
+impl WikiContextConfig {
+    /// Embedding provider and model for the configured wiki, reporting
+    /// usage to `usage`
+    fn embeddings(
+        &self,
+        usage: Option<wiki::UsageTracker>,
+    ) -> wiki::WikiResult<(Arc<dyn wiki::EmbeddingProvider>, String)> {
+        let provider = self.wiki.embedding_provider_config().build_tracked(usage)?;
+        let embedding_model = self
+            .wiki
+            .embedding_model
+            .clone()
+            .unwrap_or_else(|| wiki::WikiConfig::default().embedding_model);
+        Ok((provider, embedding_model))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ExecutorConfig {
     pub require_plan_approval: bool,
@@ -45,6 +122,30 @@ pub struct ExecutorConfig {
     pub repo_path: PathBuf,
     pub phase_models: PhaseModels,
     pub wiki_config: Option<WikiMcpConfig>,
+    pub review_context: Option<WikiContextConfig>,
+    pub planning_context: Option<WikiContextConfig>,
+    /// MCP tools each phase may call
+    pub tool_permissions: McpToolPermissions,
+    /// Environment variables for every task's sessions
+    pub environment: Vec<EnvVar>,
+    /// Re-rate review finding severities against a rubric after each review
+    pub calibrate_severity: bool,
+    /// Raise findings on code the wiki tagged as public API
+    pub public_api_index: Option<PublicApiIndex>,
+    /// Review samples per round and how many must agree on a finding
+    pub review_consensus: ReviewConsensusConfig,
+    /// Which findings send a reviewed task to the fix phase
+    pub review_policy: ReviewPolicy,
+    /// Language review and fix sessions write their prose in
+    pub review_language: Option<String>,
+    /// Token budget for the plan's cited files attached to implementation
+    /// prompts; 0 attaches none
+    pub context_file_tokens: usize,
+    /// Paths outside the workspace that implementation and fix sessions may
+    /// write, absolute or relative to the workspace
+    pub shared_paths: Vec<PathBuf>,
+    /// Statuses and transitions tasks move through
+    pub workflow: WorkflowDefinition,
 }
 
 impl Default for ExecutorConfig {
@@ -56,6 +157,18 @@ impl Default for ExecutorConfig {
             repo_path: PathBuf::from("."),
             phase_models: PhaseModels::default(),
             wiki_config: None,
+            review_context: None,
+            planning_context: None,
+            tool_permissions: McpToolPermissions::default_profiles(),
+            environment: Vec::new(),
+            calibrate_severity: true,
+            public_api_index: None,
+            review_consensus: ReviewConsensusConfig::default(),
+            review_policy: ReviewPolicy::default(),
+            review_language: None,
+            context_file_tokens: DEFAULT_CONTEXT_FILE_TOKENS,
+            shared_paths: Vec::new(),
+            workflow: WorkflowDefinition::builtin(),
         }
     }
 }
@@ -92,6 +205,66 @@ impl ExecutorConfig {
         self.wiki_config = Some(wiki_config);
         self
     }
+
+    pub fn with_review_context(mut self, review_context: WikiContextConfig) -> Self {
+        self.review_context = Some(review_context);
+        self
+    }
+
+    pub fn with_planning_context(mut self, planning_context: WikiContextConfig) -> Self {
+        self.planning_context = Some(planning_context);
+        self
+    }
+
+    pub fn with_tool_permissions(mut self, tool_permissions: McpToolPermissions) -> Self {
+        self.tool_permissions = tool_permissions;
+        self
+    }
+
+    pub fn with_environment(mut self, environment: Vec<EnvVar>) -> Self {
+        self.environment = environment;
+        self
+    }
+
+    pub fn with_workflow(mut self, workflow: WorkflowDefinition) -> Self {
+        self.workflow = workflow;
+        self
+    }
+
+    pub fn with_severity_calibration(mut self, calibrate: bool) -> Self {
+        self.calibrate_severity = calibrate;
+        self
+    }
+
+    pub fn with_public_api_index(mut self, public_api_index: PublicApiIndex) -> Self {
+        self.public_api_index = Some(public_api_index);
+        self
+    }
+
+    pub fn with_review_consensus(mut self, review_consensus: ReviewConsensusConfig) -> Self {
+        self.review_consensus = review_consensus;
+        self
+    }
+
+    pub fn with_review_policy(mut self, review_policy: ReviewPolicy) -> Self {
+        self.review_policy = review_policy;
+        self
+    }
+
+    pub fn with_review_language(mut self, review_language: Option<String>) -> Self {
+        self.review_language = review_language.filter(|l| !l.trim().is_empty());
+        self
+    }
+
+    pub fn with_context_file_tokens(mut self, tokens: usize) -> Self {
+        self.context_file_tokens = tokens;
+        self
+    }
+
+    pub fn with_shared_paths(mut self, shared_paths: Vec<PathBuf>) -> Self {
+        self.shared_paths = shared_paths;
+        self
+    }
 }
 
 pub struct ExecutorContext {
@@ -102,7 +275,11 @@ pub struct ExecutorContext {
     pub session_repo: Option<Arc<SessionRepository>>,
     pub task_repo: Option<Arc<TaskRepository>>,
     pub event_bus: Option<EventBus>,
+    /// Emits step progress of running phases on `event_bus`
+    pub emitter: Option<OrderedEventEmitter>,
     pub activity_registry: Option<SessionActivityRegistry>,
+    /// Stores the usage of wiki lookups made for tasks
+    pub usage_store: Option<Arc<UsageStore>>,
     pub mcp_manager: McpManager,
     pub opencode_client: OpenCodeClient,
 }
@@ -110,7 +287,9 @@ pub struct ExecutorContext {
 impl ExecutorContext {
     pub fn new(opencode_config: Arc<Configuration>, config: ExecutorConfig) -> Self {
         let file_manager = FileManager::new(&config.repo_path);
-        let mcp_manager = McpManager::new(Arc::clone(&opencode_config));
+        let mcp_manager = McpManager::new(Arc::clone(&opencode_config))
+            .with_tool_permissions(config.tool_permissions.clone())
+            .with_call_log(tool_call_log_path(&config.repo_path));
         let opencode_client = OpenCodeClient::new(Arc::clone(&opencode_config));
         Self {
             opencode_config,
@@ -120,7 +299,9 @@ impl ExecutorContext {
             session_repo: None,
             task_repo: None,
             event_bus: None,
+            emitter: None,
             activity_registry: None,
+            usage_store: None,
             mcp_manager,
             opencode_client,
         }
@@ -132,6 +313,7 @@ impl ExecutorContext {
     }
 
     pub fn with_workspace_manager(mut self, manager: Arc<WorkspaceManager>) -> Self {
+        self.mcp_manager = self.mcp_manager.with_workspace_backend(manager.backend());
         self.workspace_manager = Some(manager);
         self
     }
@@ -147,6 +329,7 @@ impl ExecutorContext {
     }
 
     pub fn with_event_bus(mut self, bus: EventBus) -> Self {
+        self.emitter = Some(OrderedEventEmitter::new(bus.clone()));
         self.event_bus = Some(bus);
         self
     }
@@ -156,18 +339,49 @@ impl ExecutorContext {
         self
     }
 
+    pub fn with_usage_store(mut self, store: Arc<UsageStore>) -> Self {
+        self.usage_store = Some(store);
+        self
+    }
+
+    /// Tracker for wiki calls made for `task` against `branch`
+    fn usage_tracker(&self, task: &Task, branch: &str) -> Option<wiki::UsageTracker> {
+        self.usage_store.as_ref().map(|store| {
+            store.tracker(wiki::UsageScope::task(task.id.to_string()).with_wiki_branch(branch))
+        })
+    }
+
     pub fn file_manager(&self) -> &FileManager {
         &self.file_manager
     }
 
     pub fn opencode_client_for_phase(&self, phase: SessionPhase) -> OpenCodeClient {
-        let model = match phase {
-            SessionPhase::Planning => &self.config.phase_models.planning,
-            SessionPhase::Implementation => &self.config.phase_models.implementation,
-            SessionPhase::Review => &self.config.phase_models.review,
-            SessionPhase::Fix => &self.config.phase_models.fix,
-        };
+        self.opencode_client_with_model(self.config.phase_models.for_phase(phase))
+    }
 
+    /// Client for a phase of `task_id`, using the task's own model for the
+    /// phase when it has one and the project's otherwise
+    pub async fn opencode_client_for_task(
+        &self,
+        task_id: Uuid,
+        phase: SessionPhase,
+    ) -> OpenCodeClient {
+        let task_models = self
+            .file_manager
+            .read_task_models(task_id)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!(error = %e, "Failed to read task models");
+                None
+            });
+        let model = task_models
+            .as_ref()
+            .and_then(|models| models.for_phase(phase))
+            .or_else(|| self.config.phase_models.for_phase(phase));
+        self.opencode_client_with_model(model)
+    }
+
+    fn opencode_client_with_model(&self, model: Option<&ModelSelection>) -> OpenCodeClient {
         match model {
             Some(m) => self
                 .opencode_client
@@ -181,7 +395,48 @@ impl ExecutorContext {
         self.opencode_client_for_phase(SessionPhase::Fix)
     }
 
+    /// Move the task on after a phase finished in `to`. Statuses the workflow
+    /// leaves out are skipped, and transitions gated on approval are left for
+    /// a person to make.
     pub fn transition(&self, task: &mut Task, to: TaskStatus) -> Result<()> {
+        let workflow = &self.config.workflow;
+        let to = workflow.resolve_target(to);
+        if task.status == to {
+            return Ok(());
+        }
+        if workflow.requires_approval(&task.status, &to) {
+            info!(
+                task_id = %task.id,
+                from = %task.status.as_str(),
+                to = %to.as_str(),
+                "Transition requires approval, awaiting human action"
+            );
+            return Ok(());
+        }
+        self.apply_transition(task, to)
+    }
+
+    /// Apply the workflow's rule for a phase that finished with `trigger`,
+    /// reporting the evaluation as an event. Returns the phase the rule
+    /// queues next, if the task moved.
+    pub fn complete_phase(
+        &self,
+        task: &mut Task,
+        trigger: TransitionTrigger,
+    ) -> Result<Option<SessionPhase>> {
+        let evaluation = self.config.workflow.evaluate(trigger, task.status);
+        log_rule_evaluation(task.id, &evaluation);
+        self.emit_event(rule_evaluated_event(task.id, &evaluation));
+
+        if !evaluation.changes_status() {
+            return Ok(None);
+        }
+        self.apply_transition(task, evaluation.to)?;
+        Ok(evaluation.queue_phase)
+    }
+
+    /// Make a transition a person asked for, including approval-gated ones
+    pub fn apply_transition(&self, task: &mut Task, to: TaskStatus) -> Result<()> {
         let from = task.status;
         info!(
             from = %from.as_str(),
@@ -189,7 +444,18 @@ impl ExecutorContext {
             "Task state transition"
         );
 
-        TaskStateMachine::validate_transition(&task.status, &to)?;
+        if self.config.workflow.transition(&from, &to).is_none() {
+            warn!(
+                from = %from.as_str(),
+                to = %to.as_str(),
+                workflow = %self.config.workflow.name,
+                "Invalid state transition attempted"
+            );
+            return Err(OrchestratorError::InvalidTransition {
+                from: from.as_str().to_string(),
+                to: to.as_str().to_string(),
+            });
+        }
         task.status = to;
         task.updated_at = chrono::Utc::now();
 
@@ -214,6 +480,11 @@ impl ExecutorContext {
         }
     }
 
+    /// Progress reporter for a phase of `task_id`
+    pub fn phase_progress(&self, task_id: Uuid) -> PhaseProgress {
+        PhaseProgress::new(self.emitter.clone(), task_id)
+    }
+
     pub async fn persist_session(&self, session: &Session) -> Result<()> {
         if let Some(ref repo) = self.session_repo {
             repo.create(session).await?;
@@ -307,6 +578,10 @@ impl ExecutorContext {
         description: &str,
     ) -> Result<()> {
         if let Some(ref wm) = self.workspace_manager {
+            if !wm.capabilities().commit {
+                debug!(task_id = %task.id, phase = %phase, "VCS has no commits, skipping phase commit");
+                return Ok(());
+            }
             if task.workspace_path.is_some() {
                 // Find workspace by task ID from list
                 let task_id_str = task.id.to_string();
@@ -349,4 +624,261 @@ impl ExecutorContext {
         }
         Ok(())
     }
+
+    /// Record the files `diff` touches in the task's review history
+    pub async fn record_review_changes(&self, task: &Task, diff: &str) {
+        let changes = ChangedFiles::from_diff(diff);
+        if let Err(e) = self
+            .file_manager
+            .record_changed_files(task.id, &changes)
+            .await
+        {
+            debug!(task_id = %task.id, error = %e, "Failed to record reviewed changes");
+        }
+    }
+
+    /// Pre-fetch wiki pages and related code for the files changed by
+    /// `diff`, rendered within the configured token cap. Code an earlier
+    /// session of the task was already shown is listed instead of repeated.
+    /// Returns `None` when review context is disabled, the wiki has nothing
+    /// relevant, or the lookup fails - the review then proceeds without it.
+    pub async fn review_wiki_context(&self, task: &Task, diff: &str) -> Option<String> {
+        let config = self.config.review_context.as_ref()?;
+        let (embeddings, embedding_model) = config
+            .embeddings(self.usage_tracker(task, &config.branch))
+            .map_err(|e| tracing::warn!(error = %e, "Wiki embedding provider unavailable"))
+            .ok()?;
+
+        let mut context =
+            match wiki::ReviewContextBuilder::new(embeddings.as_ref(), embedding_model)
+                .build(&config.wiki.db_path, &config.branch, diff)
+                .await
+            {
+                Ok(context) => context,
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to gather wiki context for review");
+                    return None;
+                }
+            };
+
+        let seen = self.seen_context(task.id).await;
+        let mut skipped = Vec::new();
+        for file in &mut context.files {
+            retain_unseen(&seen, &mut file.chunks, &mut skipped);
+        }
+
+        let markdown = context.to_markdown(config.max_tokens);
+        self.remember_shown(
+            seen,
+            SessionPhase::Review,
+            &context.shown_chunks(config.max_tokens),
+        )
+        .await;
+        let markdown = join_sections(markdown, already_shown_note(&skipped));
+        debug!(
+            files = context.files.len(),
+            already_shown = skipped.len(),
+            context_length = markdown.len(),
+            "Wiki context gathered for review"
+        );
+        (!markdown.is_empty()).then_some(markdown)
+    }
+
+    /// Retrieve the code and wiki pages related to `task`, rendered within
+    /// the configured token cap. Returns `None` when planning context is
+    /// disabled, nothing relevant is indexed, or the lookup fails.
+    pub async fn planning_wiki_context(&self, task: &Task) -> Option<PlanningWikiContext> {
+        let config = self.config.planning_context.as_ref()?;
+        let (embeddings, embedding_model) = config
+            .embeddings(self.usage_tracker(task, &config.branch))
+            .map_err(|e| tracing::warn!(error = %e, "Wiki embedding provider unavailable"))
+            .ok()?;
+        let query = format!("{}\n\n{}", task.title, task.description);
+
+        let mut context =
+            match wiki::PlanningContextBuilder::new(embeddings.as_ref(), embedding_model)
+                .build(&config.wiki.db_path, &config.branch, &query)
+                .await
+            {
+                Ok(context) => context,
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to gather wiki context for planning");
+                    return None;
+                }
+            };
+
+        let seen = self.seen_context(task.id).await;
+        let mut skipped = Vec::new();
+        retain_unseen(&seen, &mut context.chunks, &mut skipped);
+
+        let markdown = context.to_markdown(config.max_tokens);
+        self.remember_shown(
+            seen,
+            SessionPhase::Planning,
+            &context.shown_chunks(config.max_tokens),
+        )
+        .await;
+        let markdown = join_sections(markdown, already_shown_note(&skipped));
+        if markdown.is_empty() {
+            return None;
+        }
+        let sources = context.sources(config.max_tokens);
+        debug!(
+            sources = sources.len(),
+            already_shown = skipped.len(),
+            context_length = markdown.len(),
+            "Wiki context gathered for planning"
+        );
+        Some(PlanningWikiContext { markdown, sources })
+    }
+
+    /// The project's environment variables with the task's applied on top,
+    /// secrets looked up in the server's environment
+    pub async fn task_environment(&self, task_id: Uuid) -> TaskEnvironment {
+        let task_vars = self
+            .file_manager
+            .read_task_env(task_id)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!(error = %e, "Failed to read task environment");
+                Vec::new()
+            });
+        TaskEnvironment::resolve(&self.config.environment, &task_vars, |name| {
+            std::env::var(name).ok()
+        })
+    }
+
+    /// Write the task's env file and return the prompt section telling a
+    /// session to load it; empty when the task has no variables
+    pub async fn session_environment_note(&self, task: &Task) -> String {
+        let environment = self.task_environment(task.id).await;
+        if environment.is_empty() {
+            return String::new();
+        }
+        for (name, secret) in &environment.missing_secrets {
+            tracing::warn!(
+                task_id = %task.id,
+                var = %name,
+                secret = %secret,
+                "Secret for task environment variable is not set"
+            );
+        }
+
+        match self
+            .file_manager
+            .write_task_env_file(task.id, &environment.to_env_file())
+            .await
+        {
+            Ok(env_file) => {
+                debug!(
+                    task_id = %task.id,
+                    vars = environment.vars.len(),
+                    "Injecting task environment into prompt"
+                );
+                environment.prompt_note(&env_file.display().to_string())
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to write task env file");
+                String::new()
+            }
+        }
+    }
+
+    /// `prompt` with the task's environment section appended
+    pub async fn with_session_environment(&self, task: &Task, prompt: String) -> String {
+        join_sections(prompt, self.session_environment_note(task).await)
+    }
+
+    /// What the task's sessions were already shown. An unreadable record is
+    /// treated as empty, so context is sent in full.
+    async fn seen_context(&self, task_id: Uuid) -> SeenContext {
+        self.file_manager
+            .read_seen_context(task_id)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!(error = %e, "Failed to read seen context");
+                SeenContext::new(task_id)
+            })
+    }
+
+    /// Record the chunks a prompt of `phase` included
+    async fn remember_shown(
+        &self,
+        mut seen: SeenContext,
+        phase: SessionPhase,
+        chunks: &[&wiki::SearchResult],
+    ) {
+        let mut added = 0;
+        for chunk in chunks {
+            let range = SeenRange::new(
+                &chunk.file_path,
+                chunk.start_line,
+                chunk.end_line,
+                phase,
+                &chunk.content,
+            );
+            if seen.record(range) {
+                added += 1;
+            }
+        }
+        if added == 0 {
+            return;
+        }
+        if let Err(e) = self.file_manager.write_seen_context(&seen).await {
+            tracing::warn!(error = %e, "Failed to record seen context");
+        }
+    }
+}
+
+pub(crate) fn log_rule_evaluation(task_id: Uuid, evaluation: &RuleEvaluation) {
+    info!(
+        task_id = %task_id,
+        outcome = %evaluation.trigger.as_str(),
+        matched = evaluation.matched,
+        from = %evaluation.from.as_str(),
+        to = %evaluation.to.as_str(),
+        queued_phase = ?evaluation.queue_phase,
+        reason = ?evaluation.reason,
+        "Transition rule evaluated"
+    );
+}
+
+pub(crate) fn rule_evaluated_event(task_id: Uuid, evaluation: &RuleEvaluation) -> Event {
+    Event::TransitionRuleEvaluated {
+        task_id,
+        outcome: evaluation.trigger.as_str().to_string(),
+        matched: evaluation.matched,
+        from_status: evaluation.from.as_str().to_string(),
+        to_status: evaluation.to.as_str().to_string(),
+        queued_phase: evaluation.queue_phase.map(|p| p.as_str().to_string()),
+        reason: evaluation.reason.clone(),
+    }
+}
+
+/// Drop the chunks covered by ranges in `seen`, collecting those ranges
+fn retain_unseen(
+    seen: &SeenContext,
+    chunks: &mut Vec<wiki::SearchResult>,
+    skipped: &mut Vec<SeenRange>,
+) {
+    chunks.retain(|chunk| {
+        match seen.covering(&chunk.file_path, chunk.start_line, chunk.end_line) {
+            Some(range) => {
+                if !skipped.contains(range) {
+                    skipped.push(range.clone());
+                }
+                false
+            }
+            None => true,
+        }
+    });
+}
+
+/// Join two prompt sections, either of which may be empty
+pub(crate) fn join_sections(first: String, second: String) -> String {
+    match (first.is_empty(), second.is_empty()) {
+        (_, true) => first,
+        (true, false) => second,
+        (false, false) => format!("{}\n\n{}", first, second),
+    }
 }
@@ -0,0 +1,171 @@
+//! Translation of review findings on demand
+//!
+//! Reviews run with a review language are written in it directly. Findings
+//! written before the setting, or wanted in another language, are translated
+//! with one prompt and cached next to the findings file. The cache is keyed
+//! by a checksum of the original text, so a new review is translated afresh
+//! while status changes reuse it.
+
+use opencode_core::SessionPhase;
+use serde::Deserialize;
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use crate::error::{OrchestratorError, Result};
+use crate::files::{FindingsTranslation, ReviewFindings, TranslatedFinding};
+use crate::prompts::PhasePrompts;
+use crate::services::{ExecutorContext, MessageParser};
+
+#[derive(Debug, Deserialize)]
+struct TranslationResponse {
+    #[serde(default)]
+    summary: String,
+    findings: Vec<TranslatedFinding>,
+}
+
+pub struct FindingTranslation;
+
+impl FindingTranslation {
+    /// The task's findings in `language`, or `None` without findings
+    ///
+    /// A cached translation of the current findings is used when there is
+    /// one; otherwise the findings are translated and the result cached.
+    pub async fn translate(
+        ctx: &ExecutorContext,
+        task_id: Uuid,
+        language: &str,
+    ) -> Result<Option<ReviewFindings>> {
+        let Some(findings) = ctx.file_manager.read_findings(task_id).await? else {
+            return Ok(None);
+        };
+
+        if let Some(cached) = ctx
+            .file_manager
+            .read_findings_translation(task_id, language)
+            .await?
+            .filter(|t| t.matches(&findings))
+        {
+            debug!(task_id = %task_id, language = %language, "Using cached findings translation");
+            return Ok(Some(cached.apply(&findings)));
+        }
+
+        let prompt = PhasePrompts::translate_findings(&findings, language);
+        let client = ctx.opencode_client_for_phase(SessionPhase::Review);
+        let session = client.create_session(&ctx.config.repo_path).await?;
+        debug!(session_id = %session.id, "Created findings translation session");
+        let response = client
+            .send_prompt(&session.id, &prompt, &ctx.config.repo_path, None)
+            .await?;
+
+        let translation = Self::parse_response(&response, &findings, language)?;
+        ctx.file_manager
+            .write_findings_translation(task_id, &translation)
+            .await?;
+        info!(
+            task_id = %task_id,
+            language = %language,
+            findings = translation.findings.len(),
+            "Review findings translated"
+        );
+        Ok(Some(translation.apply(&findings)))
+    }
+
+    pub fn parse_response(
+        response: &str,
+        findings: &ReviewFindings,
+        language: &str,
+    ) -> Result<FindingsTranslation> {
+        let json = MessageParser::extract_json_from_response(response);
+        let parsed: TranslationResponse = serde_json::from_str(&json).map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to parse findings translation JSON: {}",
+                e
+            ))
+        })?;
+        Ok(FindingsTranslation {
+            language: language.to_lowercase(),
+            source_checksum: FindingsTranslation::source_checksum(findings),
+            summary: parsed.summary,
+            findings: parsed.findings,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::files::{FindingSeverity, FindingStatus, ReviewFinding};
+
+    fn findings() -> ReviewFindings {
+        ReviewFindings::with_findings(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "One problem".to_string(),
+            vec![ReviewFinding {
+                id: "finding-1".to_string(),
+                file_path: Some("src/main.rs".to_string()),
+                line_start: Some(3),
+                line_end: None,
+                title: "Unchecked index".to_string(),
+                description: "The index can be out of bounds".to_string(),
+                severity: FindingSeverity::Error,
+                status: FindingStatus::Pending,
+                original_severity: None,
+                severity_rationale: None,
+                reproduction: None,
+                acceptance_criteria: Some("Indexing is bounds checked".to_string()),
+                suggested_fix: None,
+                consensus_score: None,
+                comments: Vec::new(),
+                snippet: None,
+            }],
+        )
+    }
+
+    #[test]
+    fn test_parse_and_apply_translation() {
+        let mut original = findings();
+        let response = r#"```json
+{
+  "summary": "Ein Problem",
+  "findings": [{
+    "id": "finding-1",
+    "title": "Ungeprüfter Index",
+    "description": "Der Index kann außerhalb der Grenzen liegen",
+    "acceptance_criteria": "Indizes werden geprüft"
+  }, {
+    "id": "unknown",
+    "title": "Ignoriert",
+    "description": "Ignoriert"
+  }]
+}
+```"#;
+        let translation = FindingTranslation::parse_response(response, &original, "DE").unwrap();
+        assert_eq!(translation.language, "de");
+        assert!(translation.matches(&original));
+
+        original.findings[0].status = FindingStatus::Fixed;
+        assert!(translation.matches(&original));
+        let translated = translation.apply(&original);
+        assert_eq!(translated.summary, "Ein Problem");
+        assert_eq!(translated.findings.len(), 1);
+        assert_eq!(translated.findings[0].title, "Ungeprüfter Index");
+        assert_eq!(translated.findings[0].status, FindingStatus::Fixed);
+        assert_eq!(
+            translated.findings[0].file_path.as_deref(),
+            Some("src/main.rs")
+        );
+        assert_eq!(
+            translated.findings[0].acceptance_criteria.as_deref(),
+            Some("Indizes werden geprüft")
+        );
+
+        original.findings[0].description = "Reworded".to_string();
+        assert!(!translation.matches(&original));
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_response() {
+        assert!(FindingTranslation::parse_response("not json", &findings(), "de").is_err());
+    }
+}
@@ -1,13 +1,15 @@
-use opencode_core::{Session, SessionPhase, Task, TaskStatus};
+use opencode_core::{Session, SessionPhase, Task};
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 use uuid::Uuid;
 
 use crate::error::{OrchestratorError, Result};
 use crate::executor::{PhaseResult, StartedExecution};
+use crate::path_guard::PathGuard;
 use crate::prompts::{PhasePrompts, UserReviewComment};
 use crate::services::ExecutorContext;
 use crate::session_runner::{McpConfig, SessionConfig, SessionDependencies, SessionRunner};
+use crate::workflow::TransitionTrigger;
 
 pub struct FixPhase;
 
@@ -21,7 +23,9 @@ impl FixPhase {
         let mut session = Session::new(task.id, SessionPhase::Fix);
 
         debug!("Creating OpenCode session for fix");
-        let client = ctx.opencode_client_for_fix();
+        let client = ctx
+            .opencode_client_for_task(task.id, SessionPhase::Fix)
+            .await;
         let opencode_session = client.create_session(&ctx.config.repo_path).await?;
         let session_id_str = opencode_session.id.to_string();
 
@@ -41,7 +45,13 @@ impl FixPhase {
 
         if let Err(e) = ctx
             .mcp_manager
-            .setup_findings_server(task.id, session.id, &workspace_path, project_path)
+            .setup_findings_server(
+                task.id,
+                session.id,
+                SessionPhase::Fix,
+                &workspace_path,
+                project_path,
+            )
             .await
         {
             warn!(error = %e, "Failed to add MCP server for fix session");
@@ -59,6 +69,8 @@ impl FixPhase {
         }
 
         let prompt = PhasePrompts::fix_with_mcp(task);
+        let prompt = PhasePrompts::in_language(prompt, ctx.config.review_language.as_deref());
+        let prompt = ctx.with_session_environment(task, prompt).await;
         debug!(
             prompt_length = prompt.len(),
             "Sending fix prompt to OpenCode"
@@ -109,8 +121,12 @@ impl FixPhase {
         ctx.commit_phase_changes(task, "Fix", "Fixed issues from AI review")
             .await?;
 
-        info!(task_id = %task.id, "Fix session completed, transitioning to AI Review");
-        ctx.transition(task, TaskStatus::AiReview)?;
+        ctx.complete_phase(task, TransitionTrigger::FixCompleted)?;
+        info!(
+            task_id = %task.id,
+            new_status = %task.status.as_str(),
+            "Fix session completed"
+        );
 
         Ok(PhaseResult::FixCompleted {
             session_id: session_id_str,
@@ -131,7 +147,9 @@ impl FixPhase {
         let mut session = Session::new(task.id, SessionPhase::Implementation);
 
         debug!("Creating OpenCode session for fix iteration");
-        let client = ctx.opencode_client_for_fix();
+        let client = ctx
+            .opencode_client_for_task(task.id, SessionPhase::Fix)
+            .await;
         let opencode_session = client.create_session(&ctx.config.repo_path).await?;
         let session_id_str = opencode_session.id.to_string();
 
@@ -147,6 +165,8 @@ impl FixPhase {
         ctx.emit_session_started(&session, task.id);
 
         let prompt = PhasePrompts::fix_issues(task, feedback);
+        let prompt = PhasePrompts::in_language(prompt, ctx.config.review_language.as_deref());
+        let prompt = ctx.with_session_environment(task, prompt).await;
         debug!(
             prompt_length = prompt.len(),
             "Sending fix prompt to OpenCode"
@@ -183,11 +203,12 @@ impl FixPhase {
 
         ctx.emit_session_ended(session.id, task.id, true);
 
-        ctx.transition(task, TaskStatus::AiReview)?;
+        ctx.complete_phase(task, TransitionTrigger::FixCompleted)?;
 
         info!(
             task_id = %task.id,
-            "FIX iteration completed, returning to AI review"
+            new_status = %task.status.as_str(),
+            "FIX iteration completed"
         );
 
         Ok(PhaseResult::SessionCreated {
@@ -205,7 +226,13 @@ impl FixPhase {
         let temp_session_id = Uuid::new_v4();
         let mcp_config = match ctx
             .mcp_manager
-            .setup_findings_server(task.id, temp_session_id, &working_dir, project_path)
+            .setup_findings_server(
+                task.id,
+                temp_session_id,
+                SessionPhase::Fix,
+                &working_dir,
+                project_path,
+            )
             .await
         {
             Ok(_) => {
@@ -222,7 +249,11 @@ impl FixPhase {
         };
 
         let prompt = PhasePrompts::fix_with_mcp(task);
-        let client = ctx.opencode_client_for_fix();
+        let prompt = PhasePrompts::in_language(prompt, ctx.config.review_language.as_deref());
+        let prompt = ctx.with_session_environment(task, prompt).await;
+        let client = ctx
+            .opencode_client_for_task(task.id, SessionPhase::Fix)
+            .await;
 
         let config = SessionConfig {
             task_id: task.id,
@@ -235,6 +266,9 @@ impl FixPhase {
             mcp_config,
             implementation_phase: None,
             skip_task_status_update: false,
+            review_files: Vec::new(),
+            plan_sources: Vec::new(),
+            context_files: Vec::new(),
         };
 
         let deps = SessionDependencies::new(
@@ -244,7 +278,12 @@ impl FixPhase {
             ctx.event_bus.clone(),
             ctx.activity_registry.clone(),
             ctx.file_manager.clone(),
-        );
+        )
+        .with_workflow(ctx.config.workflow.clone())
+        .with_path_guard(PathGuard::new(
+            &config.working_dir,
+            &ctx.config.shared_paths,
+        ));
 
         let result = SessionRunner::start(config, deps).await?;
 
@@ -274,7 +313,11 @@ impl FixPhase {
         });
 
         let prompt = PhasePrompts::fix_user_comments(task, comments);
-        let client = ctx.opencode_client_for_fix();
+        let prompt = PhasePrompts::in_language(prompt, ctx.config.review_language.as_deref());
+        let prompt = ctx.with_session_environment(task, prompt).await;
+        let client = ctx
+            .opencode_client_for_task(task.id, SessionPhase::Fix)
+            .await;
 
         let config = SessionConfig {
             task_id: task.id,
@@ -287,6 +330,9 @@ impl FixPhase {
             mcp_config,
             implementation_phase: None,
             skip_task_status_update: false,
+            review_files: Vec::new(),
+            plan_sources: Vec::new(),
+            context_files: Vec::new(),
         };
 
         let deps = SessionDependencies::new(
@@ -296,7 +342,12 @@ impl FixPhase {
             ctx.event_bus.clone(),
             ctx.activity_registry.clone(),
             ctx.file_manager.clone(),
-        );
+        )
+        .with_workflow(ctx.config.workflow.clone())
+        .with_path_guard(PathGuard::new(
+            &config.working_dir,
+            &ctx.config.shared_paths,
+        ));
 
         let result = SessionRunner::start(config, deps).await?;
 
@@ -1,19 +1,24 @@
 use db::{SessionRepository, TaskRepository};
 use events::{Event, EventBus, EventEnvelope};
 use opencode_client::apis::configuration::Configuration;
-use opencode_core::{Session, SessionPhase, Task, TaskStatus, UpdateTaskRequest};
-use std::path::PathBuf;
+use opencode_core::{Session, SessionPhase, Task};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tracing::{debug, error, info, warn};
+use uuid::Uuid;
 
 use crate::activity_store::SessionActivityRegistry;
 use crate::error::{OrchestratorError, Result};
 use crate::executor::{PhaseResult, StartedExecution};
 use crate::files::{FileManager, ParsedPlan, PhaseContext, PhaseSummary};
+use crate::path_guard::PathGuard;
+use crate::plan_context::{cited_files, cited_files_for_phase, select_context_files};
 use crate::plan_parser::{extract_phase_summary, parse_plan_phases};
 use crate::prompts::PhasePrompts;
+use crate::services::executor_context::join_sections;
 use crate::services::{ExecutorContext, OpenCodeClient};
 use crate::session_runner::{SessionConfig, SessionDependencies, SessionRunner};
+use crate::workflow::{TransitionTrigger, WorkflowDefinition};
 
 pub struct ImplementationPhase;
 
@@ -79,7 +84,12 @@ impl ImplementationPhase {
         let wiki_setup = if let Some(ref wiki_config) = ctx.config.wiki_config {
             match ctx
                 .mcp_manager
-                .setup_wiki_server(&working_dir, wiki_config)
+                .setup_wiki_server(
+                    session.id,
+                    SessionPhase::Implementation,
+                    &working_dir,
+                    wiki_config,
+                )
                 .await
             {
                 Ok(()) => {
@@ -100,7 +110,9 @@ impl ImplementationPhase {
             has_workspace = task.workspace_path.is_some(),
             "Creating OpenCode session for implementation"
         );
-        let client = ctx.opencode_client_for_phase(SessionPhase::Implementation);
+        let client = ctx
+            .opencode_client_for_task(task.id, SessionPhase::Implementation)
+            .await;
         let opencode_session = client.create_session(&working_dir).await?;
         let session_id_str = opencode_session.id.to_string();
 
@@ -129,15 +141,24 @@ impl ImplementationPhase {
             "Generating implementation prompt"
         );
         let prompt = PhasePrompts::implementation_with_plan(task, plan.as_deref());
+        let prompt = ctx.with_session_environment(task, prompt).await;
+        let context_files = Self::context_files(
+            task.id,
+            &working_dir,
+            plan.as_deref().map(cited_files).unwrap_or_default(),
+            ctx.config.context_file_tokens,
+        )
+        .await;
         debug!(
             prompt_length = prompt.len(),
             "Sending implementation prompt to OpenCode"
         );
 
         let response = client
-            .send_prompt(
+            .send_prompt_with_files(
                 &session_id_str,
                 &prompt,
+                &context_files,
                 &working_dir,
                 activity_store.as_deref(),
             )
@@ -177,11 +198,12 @@ impl ImplementationPhase {
         )
         .await?;
 
-        ctx.transition(task, TaskStatus::AiReview)?;
+        ctx.complete_phase(task, TransitionTrigger::ImplementationCompleted)?;
 
         info!(
             task_id = %task.id,
-            "IMPLEMENTATION session completed, proceeding to AI review"
+            new_status = %task.status.as_str(),
+            "IMPLEMENTATION session completed"
         );
 
         Ok(PhaseResult::SessionCreated {
@@ -233,7 +255,9 @@ impl ImplementationPhase {
                 &current_phase.title,
             );
 
-            let client = ctx.opencode_client_for_phase(SessionPhase::Implementation);
+            let client = ctx
+                .opencode_client_for_task(task.id, SessionPhase::Implementation)
+                .await;
             let opencode_session = client.create_session(&working_dir).await?;
             let session_id_str = opencode_session.id.to_string();
 
@@ -245,11 +269,20 @@ impl ImplementationPhase {
 
             let prompt =
                 PhasePrompts::implementation_phase(task, current_phase, &context, &parsed_plan);
+            let prompt = ctx.with_session_environment(task, prompt).await;
+            let context_files = Self::context_files(
+                task.id,
+                &working_dir,
+                cited_files_for_phase(&parsed_plan, current_phase),
+                ctx.config.context_file_tokens,
+            )
+            .await;
 
             let response = client
-                .send_prompt(
+                .send_prompt_with_files(
                     &session_id_str,
                     &prompt,
+                    &context_files,
                     &working_dir,
                     activity_store.as_deref(),
                 )
@@ -313,12 +346,13 @@ impl ImplementationPhase {
             }
         }
 
-        ctx.transition(task, TaskStatus::AiReview)?;
+        ctx.complete_phase(task, TransitionTrigger::ImplementationCompleted)?;
 
         info!(
             task_id = %task.id,
             total_phases = context.total_phases,
-            "All implementation phases completed, proceeding to AI review"
+            new_status = %task.status.as_str(),
+            "All implementation phases completed"
         );
 
         Ok(PhaseResult::PhasedImplementationComplete {
@@ -326,6 +360,28 @@ impl ImplementationPhase {
         })
     }
 
+    /// The cited plan files to attach to an implementation prompt, within
+    /// `max_tokens`
+    async fn context_files(
+        task_id: Uuid,
+        working_dir: &Path,
+        cited: Vec<String>,
+        max_tokens: usize,
+    ) -> Vec<String> {
+        let selection = select_context_files(working_dir, &cited, max_tokens).await;
+        if !selection.files.is_empty() || !selection.over_budget.is_empty() {
+            info!(
+                task_id = %task_id,
+                files = ?selection.paths(),
+                tokens = selection.tokens(),
+                max_tokens,
+                over_budget = ?selection.over_budget,
+                "Attaching cited plan files to implementation prompt"
+            );
+        }
+        selection.paths()
+    }
+
     fn extract_or_create_summary(
         response: &str,
         phase_number: u32,
@@ -389,7 +445,17 @@ impl ImplementationPhase {
             None
         };
         let prompt = PhasePrompts::implementation_with_plan(task, plan.as_deref());
-        let client = ctx.opencode_client_for_phase(SessionPhase::Implementation);
+        let prompt = ctx.with_session_environment(task, prompt).await;
+        let context_files = Self::context_files(
+            task.id,
+            &working_dir,
+            plan.as_deref().map(cited_files).unwrap_or_default(),
+            ctx.config.context_file_tokens,
+        )
+        .await;
+        let client = ctx
+            .opencode_client_for_task(task.id, SessionPhase::Implementation)
+            .await;
 
         let config = SessionConfig {
             task_id: task.id,
@@ -402,6 +468,9 @@ impl ImplementationPhase {
             mcp_config: None,
             implementation_phase: None,
             skip_task_status_update: false,
+            review_files: Vec::new(),
+            plan_sources: Vec::new(),
+            context_files,
         };
 
         let deps = SessionDependencies::new(
@@ -411,7 +480,12 @@ impl ImplementationPhase {
             ctx.event_bus.clone(),
             ctx.activity_registry.clone(),
             ctx.file_manager.clone(),
-        );
+        )
+        .with_workflow(ctx.config.workflow.clone())
+        .with_path_guard(PathGuard::new(
+            &config.working_dir,
+            &ctx.config.shared_paths,
+        ));
 
         let result = SessionRunner::start(config, deps).await?;
 
@@ -442,7 +516,9 @@ impl ImplementationPhase {
             ))
         })?;
 
-        let client = ctx.opencode_client_for_phase(SessionPhase::Implementation);
+        let client = ctx
+            .opencode_client_for_task(task.id, SessionPhase::Implementation)
+            .await;
         let opencode_session = client.create_session(&working_dir).await?;
         let opencode_session_id = opencode_session.id.to_string();
 
@@ -467,6 +543,10 @@ impl ImplementationPhase {
         let opencode_config = Arc::clone(&ctx.opencode_config);
         let provider_id = client.provider_id().to_string();
         let model_id = client.model_id().to_string();
+        let environment_note = ctx.session_environment_note(task).await;
+        let workflow = ctx.config.workflow.clone();
+        let context_file_tokens = ctx.config.context_file_tokens;
+        let shared_paths = ctx.config.shared_paths.clone();
 
         tokio::spawn(async move {
             let mut task = task_clone;
@@ -484,6 +564,10 @@ impl ImplementationPhase {
                 opencode_config,
                 provider_id,
                 model_id,
+                environment_note,
+                workflow,
+                context_file_tokens,
+                shared_paths,
             )
             .await
             {
@@ -518,6 +602,10 @@ impl ImplementationPhase {
         opencode_config: Arc<Configuration>,
         provider_id: String,
         model_id: String,
+        environment_note: String,
+        workflow: WorkflowDefinition,
+        context_file_tokens: usize,
+        shared_paths: Vec<PathBuf>,
     ) -> Result<()> {
         let mut context = file_manager
             .read_phase_context(task.id)
@@ -590,6 +678,14 @@ impl ImplementationPhase {
 
             let prompt =
                 PhasePrompts::implementation_phase(task, current_phase, &context, &parsed_plan);
+            let prompt = join_sections(prompt, environment_note.clone());
+            let context_files = Self::context_files(
+                task.id,
+                &working_dir,
+                cited_files_for_phase(&parsed_plan, current_phase),
+                context_file_tokens,
+            )
+            .await;
             let config = SessionConfig {
                 task_id: task.id,
                 task_status: task.status,
@@ -601,6 +697,9 @@ impl ImplementationPhase {
                 mcp_config: None,
                 implementation_phase: Some((context.phase_number, current_phase.title.clone())),
                 skip_task_status_update: true,
+                review_files: Vec::new(),
+                plan_sources: Vec::new(),
+                context_files,
             };
 
             let deps = SessionDependencies::new(
@@ -610,7 +709,9 @@ impl ImplementationPhase {
                 event_bus.clone(),
                 activity_registry.clone(),
                 file_manager.clone(),
-            );
+            )
+            .with_workflow(workflow.clone())
+            .with_path_guard(PathGuard::new(&config.working_dir, &shared_paths));
 
             let opencode_session_id_clone = opencode_session_id.clone();
             let (success, response_text) = SessionRunner::execute_and_complete(
@@ -663,28 +764,29 @@ impl ImplementationPhase {
             }
         }
 
-        task.status = TaskStatus::AiReview;
-
-        if let Some(ref repo) = task_repo {
-            let update = UpdateTaskRequest {
-                status: Some(TaskStatus::AiReview),
-                ..Default::default()
-            };
-            let _ = repo.update(task.id, &update).await;
-        }
-
-        if let Some(ref bus) = event_bus {
-            bus.publish(EventEnvelope::new(Event::TaskStatusChanged {
-                task_id: task.id,
-                from_status: TaskStatus::InProgress.as_str().to_string(),
-                to_status: TaskStatus::AiReview.as_str().to_string(),
-            }));
-        }
+        let deps = SessionDependencies::new(
+            opencode_config,
+            session_repo,
+            task_repo,
+            event_bus,
+            activity_registry,
+            file_manager,
+        )
+        .with_workflow(workflow);
+        let evaluation = SessionRunner::apply_transition_rule(
+            &deps,
+            task.id,
+            task.status,
+            TransitionTrigger::ImplementationCompleted,
+        )
+        .await;
+        task.status = evaluation.to;
 
         info!(
             task_id = %task.id,
             total_phases = context.total_phases,
-            "All phases completed, proceeding to AI review"
+            new_status = %task.status.as_str(),
+            "All phases completed"
         );
 
         Ok(())
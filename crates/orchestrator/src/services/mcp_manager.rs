@@ -1,13 +1,16 @@
 use opencode_client::apis::configuration::Configuration;
 use opencode_client::apis::default_api;
 use opencode_client::models::{McpAddRequest, McpAddRequestConfig};
+use opencode_core::{SessionPhase, ALLOWED_TOOLS_ENV, SESSION_ID_ENV, TOOL_CALL_LOG_ENV};
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 use uuid::Uuid;
+use vcs::WorkspaceBackend;
 
 use crate::error::{OrchestratorError, Result};
+use crate::mcp_config::McpToolPermissions;
 
 const MCP_FINDINGS_NAME: &str = "opencode-findings";
 const MCP_FINDINGS_BINARY: &str = "opencode-mcp-findings";
@@ -18,17 +21,67 @@ const MCP_WIKI_BINARY: &str = "opencode-mcp-wiki";
 #[derive(Clone)]
 pub struct McpManager {
     opencode_config: Arc<Configuration>,
+    tool_permissions: McpToolPermissions,
+    /// File the MCP servers record their tool calls in
+    call_log: Option<PathBuf>,
+    /// How the workspaces the servers read from were created
+    workspace_backend: WorkspaceBackend,
 }
 
 impl McpManager {
     pub fn new(opencode_config: Arc<Configuration>) -> Self {
-        Self { opencode_config }
+        Self {
+            opencode_config,
+            tool_permissions: McpToolPermissions::default_profiles(),
+            call_log: None,
+            workspace_backend: WorkspaceBackend::default(),
+        }
+    }
+
+    pub fn with_tool_permissions(mut self, tool_permissions: McpToolPermissions) -> Self {
+        self.tool_permissions = tool_permissions;
+        self
+    }
+
+    /// Have the MCP servers record their tool calls in `call_log`
+    pub fn with_call_log(mut self, call_log: PathBuf) -> Self {
+        self.call_log = Some(call_log);
+        self
+    }
+
+    pub fn with_workspace_backend(mut self, workspace_backend: WorkspaceBackend) -> Self {
+        self.workspace_backend = workspace_backend;
+        self
+    }
+
+    fn log_calls(&self, environment: &mut HashMap<String, String>) {
+        if let Some(call_log) = &self.call_log {
+            environment.insert(
+                TOOL_CALL_LOG_ENV.to_string(),
+                call_log.to_string_lossy().to_string(),
+            );
+        }
+    }
+
+    /// Pass the tools `phase` may call to an MCP server, which hides the
+    /// others and refuses calls to them
+    fn restrict_tools(&self, environment: &mut HashMap<String, String>, phase: SessionPhase) {
+        let allowlist = self.tool_permissions.allowlist(phase);
+        if allowlist.is_restricted() {
+            debug!(
+                phase = phase.as_str(),
+                tools = %allowlist.to_env_value(),
+                "Restricting MCP tools"
+            );
+            environment.insert(ALLOWED_TOOLS_ENV.to_string(), allowlist.to_env_value());
+        }
     }
 
     pub async fn setup_findings_server(
         &self,
         task_id: Uuid,
         session_id: Uuid,
+        phase: SessionPhase,
         workspace_path: &Path,
         project_path: &Path,
     ) -> Result<()> {
@@ -36,16 +89,22 @@ impl McpManager {
 
         let mut environment = HashMap::new();
         environment.insert("OPENCODE_TASK_ID".to_string(), task_id.to_string());
-        environment.insert("OPENCODE_SESSION_ID".to_string(), session_id.to_string());
+        environment.insert(SESSION_ID_ENV.to_string(), session_id.to_string());
         environment.insert(
             "OPENCODE_WORKSPACE_PATH".to_string(),
             workspace_path.to_string_lossy().to_string(),
         );
+        environment.insert(
+            "OPENCODE_WORKSPACE_BACKEND".to_string(),
+            self.workspace_backend.as_str().to_string(),
+        );
         // Project path is where findings are stored (main repo, not worktree)
         environment.insert(
             "OPENCODE_PROJECT_PATH".to_string(),
             project_path.to_string_lossy().to_string(),
         );
+        self.restrict_tools(&mut environment, phase);
+        self.log_calls(&mut environment);
 
         let mut config = McpAddRequestConfig::local(vec![mcp_binary]);
         config.environment = Some(environment);
@@ -96,12 +155,18 @@ impl McpManager {
     /// Setup Wiki MCP server for code search and RAG Q&A
     ///
     /// This server provides:
-    /// - search_code: Semantic code search
+    /// - search_code: Semantic, keyword or hybrid code search
     /// - get_documentation: Retrieve wiki pages
     /// - ask_codebase: RAG Q&A over codebase
     /// - list_wiki_pages: Browse wiki structure
+    /// - find_untested_modules: Flag source modules without associated tests
+    /// - recall_related_work: Earlier tasks, findings and sessions similar to a query
+    /// - list_indexed_files: Indexed files with their chunk counts
+    /// - regenerate_page: Regenerate a stale page from the files it references
     pub async fn setup_wiki_server(
         &self,
+        session_id: Uuid,
+        phase: SessionPhase,
         workspace_path: &Path,
         wiki_config: &WikiMcpConfig,
     ) -> Result<()> {
@@ -125,6 +190,23 @@ impl McpManager {
         if let Some(ref base_url) = wiki_config.api_base_url {
             environment.insert("OPENROUTER_API_BASE_URL".to_string(), base_url.clone());
         }
+        if let Ok(serde_json::Value::String(provider)) =
+            serde_json::to_value(wiki_config.embedding_provider)
+        {
+            environment.insert("OPENCODE_WIKI_EMBEDDING_PROVIDER".to_string(), provider);
+        }
+        if let Some(ref key) = wiki_config.openai_api_key {
+            environment.insert("OPENAI_API_KEY".to_string(), key.clone());
+        }
+        if let Some(ref path) = wiki_config.local_model_path {
+            environment.insert(
+                "OPENCODE_WIKI_LOCAL_MODEL_PATH".to_string(),
+                path.to_string_lossy().to_string(),
+            );
+        }
+        environment.insert(SESSION_ID_ENV.to_string(), session_id.to_string());
+        self.restrict_tools(&mut environment, phase);
+        self.log_calls(&mut environment);
 
         let mut config = McpAddRequestConfig::local(vec![mcp_binary]);
         config.environment = Some(environment);
@@ -211,6 +293,12 @@ pub struct WikiMcpConfig {
     pub chat_model: Option<String>,
     /// OpenRouter API base URL (optional)
     pub api_base_url: Option<String>,
+    /// Where embeddings come from
+    pub embedding_provider: wiki::EmbeddingProviderKind,
+    /// OpenAI API key, for the `openai` embedding provider
+    pub openai_api_key: Option<String>,
+    /// Model directory, for the `local` embedding provider
+    pub local_model_path: Option<std::path::PathBuf>,
 }
 
 impl WikiMcpConfig {
@@ -225,6 +313,9 @@ impl WikiMcpConfig {
             embedding_model: None,
             chat_model: None,
             api_base_url: None,
+            embedding_provider: wiki::EmbeddingProviderKind::default(),
+            openai_api_key: None,
+            local_model_path: None,
         }
     }
 
@@ -245,6 +336,30 @@ impl WikiMcpConfig {
         self.api_base_url = Some(url.into());
         self
     }
+
+    /// Set the embedding provider and what it needs
+    pub fn with_embedding_provider(
+        mut self,
+        provider: wiki::EmbeddingProviderKind,
+        openai_api_key: Option<String>,
+        local_model_path: Option<std::path::PathBuf>,
+    ) -> Self {
+        self.embedding_provider = provider;
+        self.openai_api_key = openai_api_key;
+        self.local_model_path = local_model_path;
+        self
+    }
+
+    /// Settings for the configured embedding provider
+    pub fn embedding_provider_config(&self) -> wiki::EmbeddingProviderConfig {
+        wiki::EmbeddingProviderConfig {
+            kind: self.embedding_provider,
+            openrouter_api_key: Some(self.openrouter_api_key.clone()).filter(|k| !k.is_empty()),
+            openrouter_base_url: self.api_base_url.clone(),
+            openai_api_key: self.openai_api_key.clone(),
+            local_model_path: self.local_model_path.clone(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -26,6 +26,12 @@ pub struct RawFinding {
     pub description: String,
     #[serde(default = "default_severity")]
     pub severity: String,
+    #[serde(default)]
+    pub reproduction: Option<String>,
+    #[serde(default)]
+    pub acceptance_criteria: Option<String>,
+    #[serde(default)]
+    pub suggested_fix: Option<String>,
 }
 
 fn default_severity() -> String {
@@ -139,6 +145,10 @@ impl MessageParser {
                     .and_then(|s| s.get("status"))
                     .and_then(|v| v.as_str())
                     .unwrap_or("pending");
+                let args = state
+                    .and_then(|s| s.get("input"))
+                    .filter(|v| v.is_object())
+                    .cloned();
 
                 if status == "completed" || status == "error" {
                     let success = status == "completed";
@@ -153,10 +163,10 @@ impl MessageParser {
                     let result = if success { output } else { error };
 
                     Some(SessionActivityMsg::tool_result(
-                        call_id, tool_name, None, result, success,
+                        call_id, tool_name, args, result, success,
                     ))
                 } else {
-                    Some(SessionActivityMsg::tool_call(call_id, tool_name, None))
+                    Some(SessionActivityMsg::tool_call(call_id, tool_name, args))
                 }
             }
             "step-start" => Some(SessionActivityMsg::StepStart {
@@ -204,9 +214,27 @@ impl MessageParser {
                     _ => FindingSeverity::Warning,
                 },
                 status: FindingStatus::Pending,
+                original_severity: None,
+                severity_rationale: None,
+                reproduction: f.reproduction,
+                acceptance_criteria: f.acceptance_criteria,
+                suggested_fix: f.suggested_fix,
+                consensus_score: None,
+                comments: Vec::new(),
+                snippet: None,
             })
             .collect();
 
+        for finding in &findings {
+            if let Err(reason) = finding.validate() {
+                tracing::warn!(
+                    finding_id = %finding.id,
+                    reason = %reason,
+                    "Review finding is incomplete"
+                );
+            }
+        }
+
         Ok(ReviewFindings::with_findings(
             task_id,
             session_id,
@@ -324,4 +352,30 @@ mod tests {
         assert!(json.starts_with('{'));
         assert!(json.ends_with('}'));
     }
+
+    #[test]
+    fn test_parse_review_json_reproduction_and_criteria() {
+        let content = r#"{
+            "approved": false,
+            "summary": "One bug",
+            "findings": [{
+                "title": "Crash on empty input",
+                "description": "Indexes the first element unchecked",
+                "severity": "error",
+                "reproduction": "Call parse(\"\")",
+                "acceptance_criteria": "parse(\"\") returns an error"
+            }]
+        }"#;
+
+        let review =
+            MessageParser::parse_review_json(content, Uuid::new_v4(), Uuid::new_v4()).unwrap();
+
+        let finding = &review.findings[0];
+        assert_eq!(finding.reproduction.as_deref(), Some("Call parse(\"\")"));
+        assert_eq!(
+            finding.acceptance_criteria.as_deref(),
+            Some("parse(\"\") returns an error")
+        );
+        assert!(finding.validate().is_ok());
+    }
 }
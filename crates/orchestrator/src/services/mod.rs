@@ -1,25 +1,44 @@
+pub mod ci_gate;
 pub mod executor_context;
+pub mod finding_translation;
 pub mod fix_phase;
 pub mod implementation_phase;
 pub mod mcp_manager;
 pub mod message_parser;
 pub mod opencode_client;
+pub mod phase_progress;
 pub mod planning_phase;
+pub mod public_api_severity;
+pub mod release_review;
+pub mod review_consensus;
 pub mod review_phase;
+pub mod review_policy;
 pub mod roadmap_prompts;
 pub mod roadmap_service;
 pub mod roadmap_store;
+pub mod severity_calibration;
 
-pub use executor_context::{ExecutorConfig, ExecutorContext, ModelSelection, PhaseModels};
+pub use ci_gate::{CiGate, CiGateConfig, CiStatusSource};
+pub use executor_context::{
+    ExecutorConfig, ExecutorContext, ModelSelection, PhaseModels, PlanningWikiContext,
+    PublicApiIndex, WikiContextConfig,
+};
+pub use finding_translation::FindingTranslation;
 pub use fix_phase::FixPhase;
 pub use implementation_phase::ImplementationPhase;
 pub use mcp_manager::{McpManager, WikiMcpConfig};
 pub use message_parser::MessageParser;
 pub use opencode_client::OpenCodeClient;
+pub use phase_progress::{PhaseProgress, PlanningStep, ReviewTracker};
 pub use planning_phase::PlanningPhase;
+pub use public_api_severity::PublicApiSeverity;
+pub use release_review::{MergedTask, ReleaseReview};
+pub use review_consensus::{ReviewConsensus, ReviewConsensusConfig};
 pub use review_phase::ReviewPhase;
+pub use review_policy::ReviewPolicy;
 pub use roadmap_prompts::{
     get_features_prompt_with_discovery, ROADMAP_DISCOVERY_PROMPT, ROADMAP_FEATURES_PROMPT,
 };
 pub use roadmap_service::{RoadmapService, SharedGenerationId, SharedRoadmapStatus};
 pub use roadmap_store::RoadmapStore;
+pub use severity_calibration::SeverityCalibration;
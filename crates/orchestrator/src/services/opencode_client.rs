@@ -74,14 +74,29 @@ impl OpenCodeClient {
         prompt: &str,
         working_dir: &Path,
         activity_store: Option<&SessionActivityStore>,
+    ) -> Result<String> {
+        self.send_prompt_with_files(session_id, prompt, &[], working_dir, activity_store)
+            .await
+    }
+
+    /// Send a prompt with `files`, relative to `working_dir`, attached
+    pub async fn send_prompt_with_files(
+        &self,
+        session_id: &str,
+        prompt: &str,
+        files: &[String],
+        working_dir: &Path,
+        activity_store: Option<&SessionActivityStore>,
     ) -> Result<String> {
         let model = opencode_client::models::SessionPromptRequestModel {
             provider_id: self.provider_id.clone(),
             model_id: self.model_id.clone(),
         };
 
+        let mut parts = vec![Self::create_text_part(prompt)];
+        parts.extend(files.iter().map(|f| Self::create_file_part(working_dir, f)));
         let request = SessionPromptRequest {
-            parts: vec![Self::create_text_part(prompt)],
+            parts,
             model: Some(Box::new(model)),
             message_id: None,
             agent: None,
@@ -177,6 +192,21 @@ impl OpenCodeClient {
             })
     }
 
+    /// Part attaching the file at `path`, relative to `working_dir`; OpenCode
+    /// reads it into the conversation
+    pub(crate) fn create_file_part(
+        working_dir: &Path,
+        path: &str,
+    ) -> SessionPromptRequestPartsInner {
+        SessionPromptRequestPartsInner {
+            r#type: opencode_client::models::session_prompt_request_parts_inner::Type::File,
+            mime: "text/plain".to_string(),
+            filename: Some(path.to_string()),
+            url: format!("file://{}", working_dir.join(path).display()),
+            ..Self::create_text_part("")
+        }
+    }
+
     fn create_text_part(text: &str) -> SessionPromptRequestPartsInner {
         SessionPromptRequestPartsInner {
             r#type: opencode_client::models::session_prompt_request_parts_inner::Type::Text,
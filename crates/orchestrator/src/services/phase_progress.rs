@@ -0,0 +1,333 @@
+//! Step-level progress of planning and review sessions
+//!
+//! A planning or review session can run for many minutes with nothing to
+//! show but the activity log. Planning reports which of its steps it is in;
+//! review reports how many of the changed files the agent has looked at and
+//! how many findings it created so far. Both go out as `phase.progress`
+//! events through the [`OrderedEventEmitter`].
+
+use std::collections::HashSet;
+
+use events::Event;
+use opencode_core::SessionPhase;
+use tokio::sync::broadcast::error::RecvError;
+use uuid::Uuid;
+
+use crate::activity_store::{SessionActivityMsg, SessionActivityStore};
+use crate::core::OrderedEventEmitter;
+use crate::files::ChangedFiles;
+
+/// Steps of the planning phase, in order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanningStep {
+    /// Gathering context before the prompt is sent
+    Analysis,
+    /// The agent is writing the plan
+    Drafting,
+    /// Saving and committing the plan
+    Finalizing,
+}
+
+impl PlanningStep {
+    pub const ALL: [PlanningStep; 3] = [Self::Analysis, Self::Drafting, Self::Finalizing];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Analysis => "analysis",
+            Self::Drafting => "drafting",
+            Self::Finalizing => "finalizing",
+        }
+    }
+
+    /// 1-based position of the step
+    pub fn number(&self) -> u32 {
+        match self {
+            Self::Analysis => 1,
+            Self::Drafting => 2,
+            Self::Finalizing => 3,
+        }
+    }
+
+    fn message(&self) -> &'static str {
+        match self {
+            Self::Analysis => "Gathering context for the plan",
+            Self::Drafting => "Drafting the plan",
+            Self::Finalizing => "Saving the plan",
+        }
+    }
+}
+
+/// Review step while the agent works through the changed files
+pub const REVIEW_STEP_FILES: &str = "reviewing_files";
+/// Review step once the session finished and findings are settled
+pub const REVIEW_STEP_FINALIZING: &str = "finalizing";
+
+/// Follows a review session's activity to count the changed files the agent
+/// looked at and the findings it created
+#[derive(Debug, Clone, Default)]
+pub struct ReviewTracker {
+    files: Vec<String>,
+    reviewed: HashSet<usize>,
+    findings: u32,
+}
+
+impl ReviewTracker {
+    pub fn new(files: Vec<String>) -> Self {
+        Self {
+            files,
+            ..Default::default()
+        }
+    }
+
+    /// Tracker for the files named in `diff`
+    pub fn from_diff(diff: &str) -> Self {
+        Self::new(ChangedFiles::from_diff(diff).files)
+    }
+
+    /// Files looked at so far
+    pub fn reviewed(&self) -> u32 {
+        self.reviewed.len() as u32
+    }
+
+    /// Files under review
+    pub fn total(&self) -> u32 {
+        self.files.len() as u32
+    }
+
+    /// Findings created so far
+    pub fn findings(&self) -> u32 {
+        self.findings
+    }
+
+    /// Account for a finished tool call; returns whether progress changed.
+    ///
+    /// A tool whose arguments name a changed file, by its path in the
+    /// repository or a path ending in it, marks that file as reviewed.
+    pub fn observe(&mut self, activity: &SessionActivityMsg) -> bool {
+        let SessionActivityMsg::ToolResult {
+            tool_name,
+            args,
+            success: true,
+            ..
+        } = activity
+        else {
+            return false;
+        };
+
+        let mut changed = false;
+        if tool_name.ends_with("create_finding") {
+            self.findings += 1;
+            changed = true;
+        }
+
+        let paths = args
+            .as_ref()
+            .and_then(|a| a.as_object())
+            .into_iter()
+            .flat_map(|a| a.values())
+            .filter_map(|v| v.as_str());
+        for path in paths {
+            let path = path.trim_start_matches("./");
+            for (index, file) in self.files.iter().enumerate() {
+                let matches = path == file
+                    || path
+                        .strip_suffix(file.as_str())
+                        .is_some_and(|prefix| prefix.ends_with('/'));
+                if matches && self.reviewed.insert(index) {
+                    changed = true;
+                }
+            }
+        }
+        changed
+    }
+}
+
+/// Publishes `phase.progress` events for one task
+#[derive(Clone)]
+pub struct PhaseProgress {
+    emitter: Option<OrderedEventEmitter>,
+    task_id: Uuid,
+    session_id: Option<Uuid>,
+}
+
+impl PhaseProgress {
+    pub fn new(emitter: Option<OrderedEventEmitter>, task_id: Uuid) -> Self {
+        Self {
+            emitter,
+            task_id,
+            session_id: None,
+        }
+    }
+
+    pub fn with_session(mut self, session_id: Uuid) -> Self {
+        self.session_id = Some(session_id);
+        self
+    }
+
+    /// Announce that planning reached `step`
+    pub fn planning(&self, step: PlanningStep) {
+        self.emit(
+            SessionPhase::Planning,
+            step.as_str(),
+            step.number(),
+            PlanningStep::ALL.len() as u32,
+            None,
+            Some(step.message().to_string()),
+        );
+    }
+
+    /// Announce the files reviewed and findings created so far
+    pub fn review(&self, tracker: &ReviewTracker) {
+        self.emit(
+            SessionPhase::Review,
+            REVIEW_STEP_FILES,
+            tracker.reviewed(),
+            tracker.total(),
+            Some(tracker.findings()),
+            Some(format!(
+                "Reviewed {} of {} files",
+                tracker.reviewed(),
+                tracker.total()
+            )),
+        );
+    }
+
+    /// Announce that the review session of `total` files is done and its
+    /// findings are being settled
+    pub fn review_finalizing(&self, total: u32, findings: Option<u32>) {
+        self.emit(
+            SessionPhase::Review,
+            REVIEW_STEP_FINALIZING,
+            total,
+            total,
+            findings,
+            Some("Settling findings".to_string()),
+        );
+    }
+
+    fn emit(
+        &self,
+        phase: SessionPhase,
+        step: &str,
+        current: u32,
+        total: u32,
+        findings_count: Option<u32>,
+        message: Option<String>,
+    ) {
+        let Some(ref emitter) = self.emitter else {
+            return;
+        };
+        emitter.emit(Event::PhaseProgress {
+            task_id: self.task_id,
+            session_id: self.session_id,
+            phase: phase.as_str().to_string(),
+            step: step.to_string(),
+            current,
+            total,
+            findings_count,
+            message,
+        });
+    }
+
+    /// Report review progress from the activity of `store` until the
+    /// returned task is aborted or the session finishes
+    pub fn track_review(
+        &self,
+        store: &SessionActivityStore,
+        mut tracker: ReviewTracker,
+    ) -> tokio::task::JoinHandle<()> {
+        let mut rx = store.subscribe();
+        let progress = self.clone();
+        tokio::spawn(async move {
+            progress.review(&tracker);
+            loop {
+                let activity = match rx.recv().await {
+                    Ok(SessionActivityMsg::Finished { .. }) => break,
+                    Ok(activity) => activity,
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                };
+                if tracker.observe(&activity) {
+                    progress.review(&tracker);
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use events::EventBus;
+    use serde_json::json;
+
+    fn tool_result(tool_name: &str, args: serde_json::Value) -> SessionActivityMsg {
+        SessionActivityMsg::tool_result("call", tool_name, Some(args), "ok", true)
+    }
+
+    #[test]
+    fn test_planning_steps_are_ordered() {
+        let numbers: Vec<u32> = PlanningStep::ALL.iter().map(|s| s.number()).collect();
+        assert_eq!(numbers, vec![1, 2, 3]);
+        assert_eq!(PlanningStep::Drafting.as_str(), "drafting");
+    }
+
+    #[test]
+    fn test_review_tracker_counts_files_and_findings() {
+        let mut tracker = ReviewTracker::from_diff(
+            "diff --git a/src/lib.rs b/src/lib.rs\ndiff --git a/src/main.rs b/src/main.rs\n",
+        );
+        assert_eq!((tracker.reviewed(), tracker.total()), (0, 2));
+
+        assert!(tracker.observe(&tool_result(
+            "read",
+            json!({ "filePath": "/work/repo/src/lib.rs" })
+        )));
+        // Reading the same file again is no progress
+        assert!(!tracker.observe(&tool_result("read", json!({ "filePath": "src/lib.rs" }))));
+        // Only whole path components match
+        assert!(!tracker.observe(&tool_result(
+            "read",
+            json!({ "filePath": "/x/mysrc/main.rs" })
+        )));
+        assert_eq!(tracker.reviewed(), 1);
+
+        assert!(tracker.observe(&tool_result(
+            "opencode-findings_create_finding",
+            json!({ "file_path": "src/main.rs", "title": "Unchecked unwrap" })
+        )));
+        assert_eq!((tracker.reviewed(), tracker.findings()), (2, 1));
+
+        let failed = SessionActivityMsg::tool_result("c", "create_finding", None, "err", false);
+        assert!(!tracker.observe(&failed));
+        assert_eq!(tracker.findings(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_phase_progress_events() {
+        let bus = EventBus::new();
+        let mut rx = bus.subscribe();
+        let task_id = Uuid::new_v4();
+        let progress = PhaseProgress::new(Some(OrderedEventEmitter::new(bus)), task_id);
+
+        progress.planning(PlanningStep::Drafting);
+        match rx.recv().await.unwrap().event {
+            Event::PhaseProgress {
+                task_id: id,
+                phase,
+                step,
+                current,
+                total,
+                ..
+            } => {
+                assert_eq!(id, task_id);
+                assert_eq!((phase.as_str(), step.as_str()), ("planning", "drafting"));
+                assert_eq!((current, total), (2, 3));
+            }
+            other => panic!("Unexpected event {:?}", other),
+        }
+
+        // Without an emitter nothing is published
+        PhaseProgress::new(None, task_id).planning(PlanningStep::Analysis);
+    }
+}
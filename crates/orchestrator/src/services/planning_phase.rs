@@ -1,12 +1,13 @@
-use opencode_core::{Session, SessionPhase, Task, TaskStatus};
+use opencode_core::{Session, SessionPhase, Task};
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 
 use crate::error::Result;
 use crate::executor::{PhaseResult, StartedExecution};
 use crate::prompts::PhasePrompts;
-use crate::services::ExecutorContext;
+use crate::services::{ExecutorContext, PlanningStep};
 use crate::session_runner::{SessionConfig, SessionDependencies, SessionRunner};
+use crate::workflow::TransitionTrigger;
 
 pub struct PlanningPhase;
 
@@ -23,7 +24,12 @@ impl PlanningPhase {
         let wiki_setup = if let Some(ref wiki_config) = ctx.config.wiki_config {
             match ctx
                 .mcp_manager
-                .setup_wiki_server(&ctx.config.repo_path, wiki_config)
+                .setup_wiki_server(
+                    session.id,
+                    SessionPhase::Planning,
+                    &ctx.config.repo_path,
+                    wiki_config,
+                )
                 .await
             {
                 Ok(()) => {
@@ -40,7 +46,9 @@ impl PlanningPhase {
         };
 
         debug!("Creating OpenCode session for planning");
-        let client = ctx.opencode_client_for_phase(SessionPhase::Planning);
+        let client = ctx
+            .opencode_client_for_task(task.id, SessionPhase::Planning)
+            .await;
         let opencode_session = client.create_session(&ctx.config.repo_path).await?;
         let session_id_str = opencode_session.id.to_string();
 
@@ -54,13 +62,19 @@ impl PlanningPhase {
 
         let activity_store = ctx.get_activity_store(session.id);
         ctx.emit_session_started(&session, task.id);
+        let progress = ctx.phase_progress(task.id).with_session(session.id);
+        progress.planning(PlanningStep::Analysis);
 
         debug!("Generating planning prompt");
-        let prompt = PhasePrompts::planning(task);
+        let wiki_context = ctx.planning_wiki_context(task).await;
+        let prompt =
+            PhasePrompts::planning(task, wiki_context.as_ref().map(|c| c.markdown.as_str()));
+        let prompt = ctx.with_session_environment(task, prompt).await;
         debug!(
             prompt_length = prompt.len(),
             "Sending planning prompt to OpenCode"
         );
+        progress.planning(PlanningStep::Drafting);
 
         let response_content = client
             .send_prompt(
@@ -90,10 +104,12 @@ impl PlanningPhase {
             response_length = response_content.len(),
             "Received planning response"
         );
+        progress.planning(PlanningStep::Finalizing);
 
+        let plan_sources = wiki_context.map(|c| c.sources).unwrap_or_default();
         let plan_path = ctx
             .file_manager
-            .write_plan(task.id, &response_content)
+            .write_plan_with_sources(task.id, &response_content, &plan_sources)
             .await?;
 
         info!(plan_path = %plan_path.display(), "Plan saved to file");
@@ -120,7 +136,7 @@ impl PlanningPhase {
             }
         }
 
-        ctx.transition(task, TaskStatus::PlanningReview)?;
+        ctx.complete_phase(task, TransitionTrigger::PlanningCompleted)?;
 
         info!(
             task_id = %task.id,
@@ -135,9 +151,15 @@ impl PlanningPhase {
 
     pub async fn start_async(ctx: &ExecutorContext, task: &Task) -> Result<StartedExecution> {
         info!(task_id = %task.id, "Starting planning with SessionRunner");
-
-        let prompt = PhasePrompts::planning(task);
-        let client = ctx.opencode_client_for_phase(SessionPhase::Planning);
+        ctx.phase_progress(task.id).planning(PlanningStep::Analysis);
+
+        let wiki_context = ctx.planning_wiki_context(task).await;
+        let prompt =
+            PhasePrompts::planning(task, wiki_context.as_ref().map(|c| c.markdown.as_str()));
+        let prompt = ctx.with_session_environment(task, prompt).await;
+        let client = ctx
+            .opencode_client_for_task(task.id, SessionPhase::Planning)
+            .await;
 
         let config = SessionConfig {
             task_id: task.id,
@@ -150,6 +172,9 @@ impl PlanningPhase {
             mcp_config: None,
             implementation_phase: None,
             skip_task_status_update: false,
+            review_files: Vec::new(),
+            plan_sources: wiki_context.map(|c| c.sources).unwrap_or_default(),
+            context_files: Vec::new(),
         };
 
         let deps = SessionDependencies::new(
@@ -159,7 +184,9 @@ impl PlanningPhase {
             ctx.event_bus.clone(),
             ctx.activity_registry.clone(),
             ctx.file_manager.clone(),
-        );
+        )
+        .with_workflow(ctx.config.workflow.clone())
+        .with_emitter(ctx.emitter.clone());
 
         let result = SessionRunner::start(config, deps).await?;
 
@@ -0,0 +1,244 @@
+//! Stricter severities for findings on public API code
+//!
+//! Indexing tags chunks that define public interfaces: HTTP handlers,
+//! exported items and CLI commands. A problem there reaches callers the
+//! reviewer cannot see, so after a review every pending finding whose lines
+//! overlap a tagged chunk is raised one severity level. Raised findings keep
+//! the earlier severity and say which chunk caused the change.
+
+use std::collections::{BTreeSet, HashMap};
+
+use tracing::{debug, info, warn};
+
+use crate::files::{FindingSeverity, FindingStatus, ReviewFindings};
+use crate::services::ExecutorContext;
+
+/// Lines of one public API chunk
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicApiRange {
+    pub start_line: u32,
+    pub end_line: u32,
+}
+
+pub struct PublicApiSeverity;
+
+impl PublicApiSeverity {
+    /// Raise the severity of findings on public API code in place
+    ///
+    /// Returns how many findings changed. Without a configured index, or
+    /// when it cannot be read, findings are left as they are.
+    pub async fn escalate(ctx: &ExecutorContext, findings: &mut ReviewFindings) -> usize {
+        let Some(index) = ctx.config.public_api_index.clone() else {
+            return 0;
+        };
+        let files: BTreeSet<String> = findings
+            .findings
+            .iter()
+            .filter(|f| f.status == FindingStatus::Pending && f.line_start.is_some())
+            .filter_map(|f| f.file_path.as_deref().map(normalize_path))
+            .collect();
+        if files.is_empty() {
+            return 0;
+        }
+
+        // The vector store is not Send, so it is read on a blocking thread
+        let ranges = tokio::task::spawn_blocking(move || {
+            let store = wiki::VectorStore::new(&index.db_path)?;
+            let mut ranges: HashMap<String, Vec<PublicApiRange>> = HashMap::new();
+            for file in files {
+                let public: Vec<PublicApiRange> = store
+                    .get_chunks_for_file(&index.branch, &file)?
+                    .into_iter()
+                    .filter(|chunk| chunk.is_public_api)
+                    .map(|chunk| PublicApiRange {
+                        start_line: chunk.start_line,
+                        end_line: chunk.end_line,
+                    })
+                    .collect();
+                if !public.is_empty() {
+                    ranges.insert(file, public);
+                }
+            }
+            Ok::<_, wiki::WikiError>(ranges)
+        })
+        .await;
+
+        let ranges = match ranges {
+            Ok(Ok(ranges)) => ranges,
+            Ok(Err(e)) => {
+                warn!(error = %e, "Failed to read public API tags, keeping finding severities");
+                return 0;
+            }
+            Err(e) => {
+                warn!(error = %e, "Public API lookup panicked, keeping finding severities");
+                return 0;
+            }
+        };
+
+        let changed = Self::apply(findings, &ranges);
+        if changed > 0 {
+            info!(
+                task_id = %findings.task_id,
+                changed = changed,
+                approved = findings.approved,
+                "Raised severity of findings on public API code"
+            );
+        }
+        changed
+    }
+
+    /// Raise pending findings that overlap `ranges`, keyed by file path, and
+    /// return how many changed
+    ///
+    /// When anything changed, `approved` is re-derived the way the review
+    /// prompt defines it.
+    pub fn apply(
+        findings: &mut ReviewFindings,
+        ranges: &HashMap<String, Vec<PublicApiRange>>,
+    ) -> usize {
+        let mut changed = 0;
+        for finding in &mut findings.findings {
+            if finding.status != FindingStatus::Pending {
+                continue;
+            }
+            let (Some(path), Some(start)) = (finding.file_path.as_deref(), finding.line_start)
+            else {
+                continue;
+            };
+            let path = normalize_path(path);
+            let start = i64::from(start);
+            let end = finding.line_end.map(i64::from).unwrap_or(start).max(start);
+            let Some(range) = ranges.get(&path).and_then(|r| {
+                r.iter()
+                    .find(|r| i64::from(r.start_line) <= end && start <= i64::from(r.end_line))
+            }) else {
+                continue;
+            };
+            let raised = match finding.severity {
+                FindingSeverity::Info => FindingSeverity::Warning,
+                FindingSeverity::Warning => FindingSeverity::Error,
+                FindingSeverity::Error => continue,
+            };
+
+            debug!(
+                finding_id = %finding.id,
+                from = finding.severity.as_str(),
+                to = raised.as_str(),
+                "Finding on public API raised"
+            );
+            let note = format!(
+                "Raised because it touches public API at {}:{}-{}",
+                path, range.start_line, range.end_line
+            );
+            finding.original_severity.get_or_insert(finding.severity);
+            finding.severity = raised;
+            finding.severity_rationale = Some(match finding.severity_rationale.take() {
+                Some(rationale) if !rationale.is_empty() => format!("{}. {}", rationale, note),
+                _ => note,
+            });
+            changed += 1;
+        }
+
+        if changed > 0 {
+            findings.approved = !findings
+                .findings
+                .iter()
+                .any(|f| f.status == FindingStatus::Pending && f.severity != FindingSeverity::Info);
+        }
+        changed
+    }
+}
+
+/// `path` as the index stores it: relative, with forward slashes
+fn normalize_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    path.trim_start_matches("./").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::files::ReviewFinding;
+    use uuid::Uuid;
+
+    fn finding(id: &str, line: Option<i32>, severity: FindingSeverity) -> ReviewFinding {
+        ReviewFinding {
+            id: id.to_string(),
+            file_path: Some("./src/api.rs".to_string()),
+            line_start: line,
+            line_end: None,
+            title: "Problem".to_string(),
+            description: "Details".to_string(),
+            severity,
+            status: FindingStatus::Pending,
+            original_severity: None,
+            severity_rationale: None,
+            reproduction: None,
+            acceptance_criteria: None,
+            suggested_fix: None,
+            consensus_score: None,
+            comments: Vec::new(),
+            snippet: None,
+        }
+    }
+
+    fn ranges() -> HashMap<String, Vec<PublicApiRange>> {
+        HashMap::from([(
+            "src/api.rs".to_string(),
+            vec![PublicApiRange {
+                start_line: 10,
+                end_line: 30,
+            }],
+        )])
+    }
+
+    #[test]
+    fn test_raises_findings_on_public_api() {
+        let mut findings = ReviewFindings::with_findings(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "Review".to_string(),
+            vec![
+                finding("on-api", Some(12), FindingSeverity::Info),
+                finding("elsewhere", Some(50), FindingSeverity::Info),
+                finding("no-line", None, FindingSeverity::Info),
+                finding("already-error", Some(20), FindingSeverity::Error),
+            ],
+        );
+        findings.approved = true;
+
+        assert_eq!(PublicApiSeverity::apply(&mut findings, &ranges()), 1);
+        let raised = &findings.findings[0];
+        assert_eq!(raised.severity, FindingSeverity::Warning);
+        assert_eq!(raised.original_severity, Some(FindingSeverity::Info));
+        assert_eq!(
+            raised.severity_rationale.as_deref(),
+            Some("Raised because it touches public API at src/api.rs:10-30")
+        );
+        assert_eq!(findings.findings[1].severity, FindingSeverity::Info);
+        assert_eq!(findings.findings[2].severity, FindingSeverity::Info);
+        assert!(!findings.approved);
+    }
+
+    #[test]
+    fn test_keeps_calibration_rationale_and_original_severity() {
+        let mut calibrated = finding("calibrated", Some(30), FindingSeverity::Warning);
+        calibrated.original_severity = Some(FindingSeverity::Error);
+        calibrated.severity_rationale = Some("Style only".to_string());
+        let mut findings = ReviewFindings::with_findings(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "Review".to_string(),
+            vec![calibrated],
+        );
+
+        assert_eq!(PublicApiSeverity::apply(&mut findings, &ranges()), 1);
+        let raised = &findings.findings[0];
+        assert_eq!(raised.severity, FindingSeverity::Error);
+        assert_eq!(raised.original_severity, Some(FindingSeverity::Error));
+        assert_eq!(
+            raised.severity_rationale.as_deref(),
+            Some("Style only. Raised because it touches public API at src/api.rs:10-30")
+        );
+    }
+}
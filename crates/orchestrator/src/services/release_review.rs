@@ -0,0 +1,124 @@
+//! Combined review of the tasks merged for a release
+//!
+//! Every task is reviewed on its own before it merges, which misses problems
+//! that only appear once several changes land together. A release review
+//! checks out the main branch with all of them merged into a temporary
+//! workspace, reviews their diffs in one session and links each finding back
+//! to the tasks whose changes touched its file.
+
+use opencode_core::SessionPhase;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::error::{OrchestratorError, Result};
+use crate::files::{ChangedFiles, ReleaseReviewReport, ReleaseReviewTask, ReviewFindings};
+use crate::prompts::PhasePrompts;
+use crate::services::{ExecutorContext, MessageParser, PublicApiSeverity, SeverityCalibration};
+
+/// A merged task and the diff it merged
+#[derive(Debug, Clone)]
+pub struct MergedTask {
+    pub task_id: Uuid,
+    pub title: String,
+    pub description: String,
+    pub diff: String,
+}
+
+impl MergedTask {
+    /// The task as listed in a release review report
+    pub fn review_task(&self) -> ReleaseReviewTask {
+        ReleaseReviewTask {
+            task_id: self.task_id,
+            title: self.title.clone(),
+            files: ChangedFiles::from_diff(&self.diff).files,
+        }
+    }
+}
+
+pub struct ReleaseReview;
+
+impl ReleaseReview {
+    /// Review `tasks` together and complete `report` with the findings
+    ///
+    /// A failed review is recorded in the report rather than returned, so
+    /// the only errors are those writing the report.
+    pub async fn run(
+        ctx: &ExecutorContext,
+        report: &mut ReleaseReviewReport,
+        tasks: &[MergedTask],
+    ) -> Result<()> {
+        info!(
+            release_review_id = %report.id,
+            tasks = tasks.len(),
+            "Starting release review"
+        );
+
+        match Self::review(ctx, report.id, tasks).await {
+            Ok(findings) => {
+                report.complete(findings);
+                info!(
+                    release_review_id = %report.id,
+                    approved = report.approved,
+                    findings = report.findings.len(),
+                    "Release review completed"
+                );
+            }
+            Err(e) => {
+                warn!(release_review_id = %report.id, error = %e, "Release review failed");
+                report.fail(e.to_string());
+            }
+        }
+
+        ctx.file_manager.write_release_review(report).await?;
+        Ok(())
+    }
+
+    async fn review(
+        ctx: &ExecutorContext,
+        review_id: Uuid,
+        tasks: &[MergedTask],
+    ) -> Result<ReviewFindings> {
+        let workspace_manager = ctx.workspace_manager.as_ref().ok_or_else(|| {
+            OrchestratorError::ExecutionFailed(
+                "Release reviews need a workspace manager".to_string(),
+            )
+        })?;
+        let workspace = workspace_manager
+            .setup_workspace(&format!("release-{}", review_id))
+            .await
+            .map_err(|e| {
+                OrchestratorError::ExecutionFailed(format!("Failed to setup workspace: {}", e))
+            })?;
+        debug!(path = ?workspace.path, "Created release review workspace");
+
+        let findings = Self::review_in(ctx, review_id, tasks, &workspace.path).await;
+
+        if let Err(e) = workspace_manager.cleanup_workspace(&workspace).await {
+            warn!(error = %e, "Failed to clean up release review workspace");
+        }
+        findings
+    }
+
+    async fn review_in(
+        ctx: &ExecutorContext,
+        review_id: Uuid,
+        tasks: &[MergedTask],
+        workspace_path: &std::path::Path,
+    ) -> Result<ReviewFindings> {
+        let prompt = PhasePrompts::release_review(tasks);
+        let prompt = PhasePrompts::in_language(prompt, ctx.config.review_language.as_deref());
+
+        let client = ctx.opencode_client_for_phase(SessionPhase::Review);
+        let session = client.create_session(workspace_path).await?;
+        debug!(session_id = %session.id, "Created release review session");
+        let response = client
+            .send_prompt(&session.id, &prompt, workspace_path, None)
+            .await?;
+
+        // Findings are only kept in the report, which records no session
+        let mut findings = MessageParser::parse_review_json(&response, review_id, Uuid::nil())?;
+        SeverityCalibration::calibrate(ctx, &mut findings).await;
+        PublicApiSeverity::escalate(ctx, &mut findings).await;
+        Ok(findings)
+    }
+}
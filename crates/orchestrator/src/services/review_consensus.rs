@@ -0,0 +1,453 @@
+//! Review self-consistency across several review samples
+//!
+//! A single review pass is noisy: run it again and some findings disappear
+//! while new ones show up. With consensus enabled, the review runs several
+//! times (optionally with a different focus each time), findings that
+//! describe the same issue are merged by fingerprint, and only those
+//! reported by enough runs are kept. Each kept finding records the share of
+//! runs that reported it.
+
+use std::collections::BTreeSet;
+
+use futures::future::join_all;
+use opencode_core::{SessionPhase, Task};
+use tracing::{debug, info, warn};
+
+use crate::error::Result;
+use crate::files::{FindingSeverity, FindingStatus, ReviewFinding, ReviewFindings};
+use crate::prompts::PhasePrompts;
+use crate::services::{ExecutorContext, MessageParser};
+
+/// Findings within this many lines of each other in a file are the same issue
+const LINE_TOLERANCE: i32 = 3;
+
+/// Share of title words two unlocated findings must have in common
+const MIN_TITLE_OVERLAP: f32 = 0.5;
+
+/// Rough number of characters per token, for cost estimates
+const CHARS_PER_TOKEN: usize = 4;
+
+/// Focus given to each extra review sample when personas are enabled
+const REVIEW_PERSONAS: &[(&str, &str)] = &[
+    (
+        "correctness",
+        "Concentrate on logic errors, unhandled edge cases and whether the change does what the task asks.",
+    ),
+    (
+        "security",
+        "Concentrate on input validation, injection, secrets, permissions and unsafe data handling.",
+    ),
+    (
+        "maintainability",
+        "Concentrate on tests, error handling, naming and how well the change fits the surrounding code.",
+    ),
+];
+
+/// How many review samples to run and how many must agree on a finding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReviewConsensusConfig {
+    /// Reviews run per review round; 1 disables consensus
+    pub samples: u32,
+    /// Runs that must report a finding for it to be kept
+    pub min_agreement: u32,
+    /// Give each extra sample a different review focus
+    pub personas: bool,
+}
+
+impl Default for ReviewConsensusConfig {
+    fn default() -> Self {
+        Self {
+            samples: 1,
+            min_agreement: 1,
+            personas: false,
+        }
+    }
+}
+
+impl ReviewConsensusConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.samples > 1
+    }
+
+    /// Estimated input tokens the extra samples add to a review round
+    pub fn estimated_extra_tokens(&self, prompt: &str) -> u64 {
+        let per_sample = prompt.len().div_ceil(CHARS_PER_TOKEN) as u64;
+        per_sample * u64::from(self.samples.saturating_sub(1))
+    }
+}
+
+/// What identifies a finding across review runs
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Fingerprint {
+    file_path: Option<String>,
+    line: Option<i32>,
+    title_words: BTreeSet<String>,
+}
+
+impl Fingerprint {
+    fn of(finding: &ReviewFinding) -> Self {
+        Self {
+            file_path: finding
+                .file_path
+                .as_deref()
+                .map(|p| p.trim_start_matches("./").to_string()),
+            line: finding.line_start,
+            title_words: finding
+                .title
+                .split(|c: char| !c.is_alphanumeric())
+                .filter(|w| w.len() > 3)
+                .map(str::to_lowercase)
+                .collect(),
+        }
+    }
+
+    /// Whether both fingerprints describe the same issue
+    ///
+    /// Located findings match by file and nearby lines; the title decides
+    /// only when a line is missing.
+    fn matches(&self, other: &Fingerprint) -> bool {
+        if self.file_path != other.file_path {
+            return false;
+        }
+        if let (Some(a), Some(b)) = (self.line, other.line) {
+            return (a - b).abs() <= LINE_TOLERANCE;
+        }
+        let shared = self.title_words.intersection(&other.title_words).count();
+        let total = self.title_words.union(&other.title_words).count();
+        total > 0 && shared as f32 / total as f32 >= MIN_TITLE_OVERLAP
+    }
+}
+
+struct FindingGroup {
+    fingerprint: Fingerprint,
+    /// Indexes of the runs that reported the finding
+    runs: BTreeSet<usize>,
+    findings: Vec<ReviewFinding>,
+}
+
+pub struct ReviewConsensus;
+
+impl ReviewConsensus {
+    /// Run the extra review samples and merge them with `primary`
+    ///
+    /// Returns `primary` unchanged when consensus is disabled. Samples that
+    /// fail are left out of the vote.
+    pub async fn reach(
+        ctx: &ExecutorContext,
+        task: &Task,
+        diff: &str,
+        wiki_context: Option<&str>,
+        primary: ReviewFindings,
+    ) -> ReviewFindings {
+        let config = ctx.config.review_consensus;
+        if !config.is_enabled() {
+            return primary;
+        }
+
+        let prompt = PhasePrompts::review(task, diff, wiki_context);
+        let prompt = PhasePrompts::in_language(prompt, ctx.config.review_language.as_deref());
+        let prompt = ctx.with_session_environment(task, prompt).await;
+        info!(
+            task_id = %task.id,
+            samples = config.samples,
+            min_agreement = config.min_agreement,
+            estimated_extra_tokens = config.estimated_extra_tokens(&prompt),
+            "Running review consensus samples"
+        );
+
+        let samples = (1..config.samples as usize).map(|sample| {
+            let prompt = if config.personas {
+                Self::with_persona(&prompt, sample - 1)
+            } else {
+                prompt.clone()
+            };
+            async move { Self::sample(ctx, task, &prompt).await }
+        });
+
+        let mut runs = Vec::new();
+        for (sample, result) in join_all(samples).await.into_iter().enumerate() {
+            match result {
+                Ok(findings) => runs.push(findings),
+                Err(e) => warn!(
+                    task_id = %task.id,
+                    sample = sample + 1,
+                    error = %e,
+                    "Review consensus sample failed"
+                ),
+            }
+        }
+
+        let merged = Self::merge(primary, runs, config.min_agreement);
+        info!(
+            task_id = %task.id,
+            kept = merged.findings.len(),
+            approved = merged.approved,
+            "Review consensus reached"
+        );
+        merged
+    }
+
+    async fn sample(ctx: &ExecutorContext, task: &Task, prompt: &str) -> Result<ReviewFindings> {
+        let client = ctx
+            .opencode_client_for_task(task.id, SessionPhase::Review)
+            .await;
+        let workspace_path = ctx.working_dir_for_task(task);
+        let session = client.create_session(&workspace_path).await?;
+        debug!(session_id = %session.id, "Created review consensus session");
+
+        let response = client
+            .send_prompt(&session.id, prompt, &workspace_path, None)
+            .await?;
+        MessageParser::parse_review_json(&response, task.id, uuid::Uuid::new_v4())
+    }
+
+    fn with_persona(prompt: &str, index: usize) -> String {
+        let (name, focus) = REVIEW_PERSONAS[index % REVIEW_PERSONAS.len()];
+        format!("{prompt}\n\n## Review Focus\nReview as the {name} reviewer. {focus}")
+    }
+
+    /// Merge the findings of the primary review with the extra samples
+    ///
+    /// The primary review's summary is kept. A finding survives when at
+    /// least `min_agreement` runs reported it (capped at the number of runs);
+    /// it takes the severity most runs gave it, ties going to the more
+    /// severe one. Approval is re-derived from the kept findings.
+    pub fn merge(
+        mut primary: ReviewFindings,
+        samples: Vec<ReviewFindings>,
+        min_agreement: u32,
+    ) -> ReviewFindings {
+        let run_count = samples.len() + 1;
+        let min_agreement = (min_agreement as usize).clamp(1, run_count);
+
+        let mut groups: Vec<FindingGroup> = Vec::new();
+        let all_runs = std::iter::once(std::mem::take(&mut primary.findings))
+            .chain(samples.into_iter().map(|review| review.findings));
+        for (run, findings) in all_runs.enumerate() {
+            for finding in findings {
+                let fingerprint = Fingerprint::of(&finding);
+                match groups
+                    .iter_mut()
+                    .find(|g| !g.runs.contains(&run) && g.fingerprint.matches(&fingerprint))
+                {
+                    Some(group) => {
+                        group.runs.insert(run);
+                        group.findings.push(finding);
+                    }
+                    None => groups.push(FindingGroup {
+                        fingerprint,
+                        runs: BTreeSet::from([run]),
+                        findings: vec![finding],
+                    }),
+                }
+            }
+        }
+
+        let dropped = groups
+            .iter()
+            .filter(|g| g.runs.len() < min_agreement)
+            .count();
+        primary.findings = groups
+            .into_iter()
+            .filter(|g| g.runs.len() >= min_agreement)
+            .enumerate()
+            .map(|(i, group)| {
+                let score = group.runs.len() as f32 / run_count as f32;
+                Self::merge_group(group, format!("finding-{}", i + 1), score)
+            })
+            .collect();
+        primary.approved = !primary
+            .findings
+            .iter()
+            .any(|f| f.status == FindingStatus::Pending && f.severity != FindingSeverity::Info);
+
+        debug!(
+            runs = run_count,
+            kept = primary.findings.len(),
+            dropped = dropped,
+            "Merged review runs"
+        );
+        primary
+    }
+
+    fn merge_group(group: FindingGroup, id: String, score: f32) -> ReviewFinding {
+        let severity = [
+            FindingSeverity::Error,
+            FindingSeverity::Warning,
+            FindingSeverity::Info,
+        ]
+        .into_iter()
+        .max_by_key(|&severity| {
+            // Levels are declared most severe first, so ties go to the more severe
+            let votes = group
+                .findings
+                .iter()
+                .filter(|f| f.severity == severity)
+                .count();
+            (votes, std::cmp::Reverse(severity as u8))
+        })
+        .unwrap_or(FindingSeverity::Warning);
+
+        let mut findings = group.findings.into_iter();
+        let mut merged = findings.next().expect("finding groups are never empty");
+        for other in findings {
+            if merged.reproduction.is_none() {
+                merged.reproduction = other.reproduction;
+            }
+            if merged.acceptance_criteria.is_none() {
+                merged.acceptance_criteria = other.acceptance_criteria;
+            }
+            if merged.suggested_fix.is_none() {
+                merged.suggested_fix = other.suggested_fix;
+            }
+        }
+
+        merged.id = id;
+        merged.severity = severity;
+        merged.consensus_score = Some(score);
+        merged
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn finding(file: Option<&str>, line: Option<i32>, title: &str) -> ReviewFinding {
+        ReviewFinding {
+            id: String::new(),
+            file_path: file.map(str::to_string),
+            line_start: line,
+            line_end: None,
+            title: title.to_string(),
+            description: "Description".to_string(),
+            severity: FindingSeverity::Warning,
+            status: FindingStatus::Pending,
+            original_severity: None,
+            severity_rationale: None,
+            reproduction: None,
+            acceptance_criteria: None,
+            suggested_fix: None,
+            consensus_score: None,
+            comments: Vec::new(),
+            snippet: None,
+        }
+    }
+
+    fn run(findings: Vec<ReviewFinding>) -> ReviewFindings {
+        ReviewFindings::with_findings(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "Summary".to_string(),
+            findings,
+        )
+    }
+
+    #[test]
+    fn test_fingerprint_matches_nearby_lines() {
+        let a = Fingerprint::of(&finding(Some("src/lib.rs"), Some(10), "Missing check"));
+        let b = Fingerprint::of(&finding(Some("./src/lib.rs"), Some(12), "Unchecked input"));
+        let far = Fingerprint::of(&finding(Some("src/lib.rs"), Some(40), "Missing check"));
+        let other_file = Fingerprint::of(&finding(Some("src/main.rs"), Some(10), "Missing check"));
+
+        assert!(a.matches(&b));
+        assert!(!a.matches(&far));
+        assert!(!a.matches(&other_file));
+    }
+
+    #[test]
+    fn test_fingerprint_matches_titles_without_lines() {
+        let a = Fingerprint::of(&finding(None, None, "No tests for the parser"));
+        let b = Fingerprint::of(&finding(None, None, "Parser has no tests"));
+        let c = Fingerprint::of(&finding(None, None, "Unused import"));
+
+        assert!(a.matches(&b));
+        assert!(!a.matches(&c));
+    }
+
+    #[test]
+    fn test_merge_keeps_findings_with_agreement() {
+        let primary = run(vec![
+            finding(Some("src/lib.rs"), Some(10), "Missing check"),
+            finding(Some("src/lib.rs"), Some(80), "Odd naming"),
+        ]);
+        let samples = vec![
+            run(vec![finding(
+                Some("src/lib.rs"),
+                Some(11),
+                "Input not checked",
+            )]),
+            run(vec![finding(
+                Some("src/lib.rs"),
+                Some(9),
+                "Missing validation",
+            )]),
+        ];
+
+        let merged = ReviewConsensus::merge(primary, samples, 2);
+
+        assert_eq!(merged.findings.len(), 1);
+        let kept = &merged.findings[0];
+        assert_eq!(kept.id, "finding-1");
+        assert_eq!(kept.title, "Missing check");
+        assert_eq!(kept.consensus_score, Some(1.0));
+        assert!(!merged.approved);
+    }
+
+    #[test]
+    fn test_merge_takes_majority_severity() {
+        let mut info = finding(Some("src/lib.rs"), Some(10), "Crash");
+        info.severity = FindingSeverity::Info;
+        let mut error = finding(Some("src/lib.rs"), Some(10), "Crash");
+        error.severity = FindingSeverity::Error;
+        error.acceptance_criteria = Some("No panic".to_string());
+
+        let merged = ReviewConsensus::merge(
+            run(vec![info.clone()]),
+            vec![run(vec![error.clone()]), run(vec![error]), run(vec![])],
+            2,
+        );
+
+        let kept = &merged.findings[0];
+        assert_eq!(kept.severity, FindingSeverity::Error);
+        assert_eq!(kept.acceptance_criteria.as_deref(), Some("No panic"));
+        assert_eq!(kept.consensus_score, Some(0.75));
+    }
+
+    #[test]
+    fn test_merge_severity_ties_go_to_more_severe() {
+        let mut warning = finding(None, None, "Slow loop");
+        warning.severity = FindingSeverity::Warning;
+        let mut info = finding(None, None, "Slow loop");
+        info.severity = FindingSeverity::Info;
+
+        let merged = ReviewConsensus::merge(run(vec![info]), vec![run(vec![warning])], 2);
+
+        assert_eq!(merged.findings[0].severity, FindingSeverity::Warning);
+    }
+
+    #[test]
+    fn test_merge_caps_agreement_at_run_count() {
+        let merged =
+            ReviewConsensus::merge(run(vec![finding(None, None, "Missing docs")]), vec![], 3);
+
+        assert_eq!(merged.findings.len(), 1);
+        assert!(!merged.approved);
+    }
+
+    #[test]
+    fn test_estimated_extra_tokens() {
+        let config = ReviewConsensusConfig {
+            samples: 3,
+            min_agreement: 2,
+            personas: false,
+        };
+
+        assert_eq!(config.estimated_extra_tokens(&"x".repeat(400)), 200);
+        assert_eq!(
+            ReviewConsensusConfig::default().estimated_extra_tokens("prompt"),
+            0
+        );
+    }
+}
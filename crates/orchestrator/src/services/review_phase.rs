@@ -7,10 +7,15 @@ use vcs::Workspace;
 
 use crate::error::{OrchestratorError, Result};
 use crate::executor::{PhaseResult, StartedExecution};
+use crate::files::ChangedFiles;
 use crate::prompts::PhasePrompts;
 use crate::services::message_parser::ReviewResult;
-use crate::services::{ExecutorContext, MessageParser};
+use crate::services::{
+    ExecutorContext, MessageParser, PublicApiSeverity, ReviewConsensus, ReviewTracker,
+    SeverityCalibration,
+};
 use crate::session_runner::{McpConfig, SessionConfig, SessionDependencies, SessionRunner};
+use crate::workflow::TransitionTrigger;
 
 pub struct ReviewPhase;
 
@@ -30,7 +35,9 @@ impl ReviewPhase {
         let mut session = Session::new(task.id, SessionPhase::Review);
 
         debug!("Creating OpenCode session for AI review");
-        let client = ctx.opencode_client_for_phase(SessionPhase::Review);
+        let client = ctx
+            .opencode_client_for_task(task.id, SessionPhase::Review)
+            .await;
         let opencode_session = client.create_session(&ctx.config.repo_path).await?;
         let session_id_str = opencode_session.id.to_string();
 
@@ -50,7 +57,13 @@ impl ReviewPhase {
 
         if let Err(e) = ctx
             .mcp_manager
-            .setup_findings_server(task.id, session.id, &workspace_path, project_path)
+            .setup_findings_server(
+                task.id,
+                session.id,
+                SessionPhase::Review,
+                &workspace_path,
+                project_path,
+            )
             .await
         {
             warn!(error = %e, "Failed to add MCP server, falling back to JSON parsing");
@@ -68,13 +81,24 @@ impl ReviewPhase {
         debug!("Getting workspace diff for review");
         let diff = Self::get_workspace_diff(ctx, task).await?;
         debug!(diff_length = diff.len(), "Workspace diff retrieved");
+        ctx.record_review_changes(task, &diff).await;
 
-        let prompt = PhasePrompts::review_with_mcp(task, &diff);
+        let wiki_context = ctx.review_wiki_context(task, &diff).await;
+        let prompt = PhasePrompts::review_with_mcp(task, &diff, wiki_context.as_deref());
+        let prompt = PhasePrompts::in_language(prompt, ctx.config.review_language.as_deref());
+        let prompt = ctx.with_session_environment(task, prompt).await;
         debug!(
             prompt_length = prompt.len(),
             "Sending MCP review prompt to OpenCode"
         );
 
+        let tracker = ReviewTracker::from_diff(&diff);
+        let total_files = tracker.total();
+        let progress = ctx.phase_progress(task.id).with_session(session.id);
+        let tracking = activity_store
+            .as_deref()
+            .map(|store| progress.track_review(store, tracker));
+
         let response_content = client
             .send_prompt(
                 &session_id_str,
@@ -83,6 +107,9 @@ impl ReviewPhase {
                 activity_store.as_deref(),
             )
             .await;
+        if let Some(tracking) = tracking {
+            tracking.abort();
+        }
 
         if let Err(e) = ctx
             .mcp_manager
@@ -106,6 +133,7 @@ impl ReviewPhase {
             response_length = response_content.len(),
             "Received AI review response"
         );
+        progress.review_finalizing(total_files, None);
 
         let _review_path = ctx
             .file_manager
@@ -123,19 +151,41 @@ impl ReviewPhase {
                     "AI review findings read from MCP server"
                 );
 
-                if findings.approved || findings.findings.is_empty() {
-                    ReviewResult::Approved
-                } else {
-                    ReviewResult::FindingsDetected(findings.findings.len())
+                let consensus = ctx.config.review_consensus.is_enabled();
+                let mut findings =
+                    ReviewConsensus::reach(ctx, task, &diff, wiki_context.as_deref(), findings)
+                        .await;
+                let changed = SeverityCalibration::calibrate(ctx, &mut findings).await
+                    + PublicApiSeverity::escalate(ctx, &mut findings).await;
+                if changed > 0 || consensus {
+                    let _ = ctx.file_manager.write_findings(task.id, &findings).await;
                 }
+
+                ctx.config.review_policy.review_result(&findings)
             }
             Ok(None) => {
                 warn!("No MCP findings file found, falling back to JSON parsing");
-                Self::parse_and_save_findings(ctx, &response_content, task.id, session.id).await
+                Self::parse_and_save_findings(
+                    ctx,
+                    task,
+                    &response_content,
+                    session.id,
+                    &diff,
+                    wiki_context.as_deref(),
+                )
+                .await
             }
             Err(e) => {
                 warn!(error = %e, "Failed to read MCP findings, falling back to JSON parsing");
-                Self::parse_and_save_findings(ctx, &response_content, task.id, session.id).await
+                Self::parse_and_save_findings(
+                    ctx,
+                    task,
+                    &response_content,
+                    session.id,
+                    &diff,
+                    wiki_context.as_deref(),
+                )
+                .await
             }
         };
 
@@ -166,15 +216,28 @@ impl ReviewPhase {
         debug!("Getting workspace diff for review");
         let diff = Self::get_workspace_diff(ctx, task).await?;
         debug!(diff_length = diff.len(), "Workspace diff retrieved");
+        ctx.record_review_changes(task, &diff).await;
 
-        let prompt = PhasePrompts::review(task, &diff);
+        let wiki_context = ctx.review_wiki_context(task, &diff).await;
+        let prompt = PhasePrompts::review(task, &diff, wiki_context.as_deref());
+        let prompt = PhasePrompts::in_language(prompt, ctx.config.review_language.as_deref());
+        let prompt = ctx.with_session_environment(task, prompt).await;
         debug!(
             prompt_length = prompt.len(),
             "Sending review prompt to OpenCode"
         );
 
+        let tracker = ReviewTracker::from_diff(&diff);
+        let total_files = tracker.total();
+        let progress = ctx.phase_progress(task.id).with_session(session.id);
+        let tracking = activity_store
+            .as_deref()
+            .map(|store| progress.track_review(store, tracker));
+
         let workspace_path = ctx.working_dir_for_task(task);
-        let client = ctx.opencode_client_for_phase(SessionPhase::Review);
+        let client = ctx
+            .opencode_client_for_task(task.id, SessionPhase::Review)
+            .await;
         let response_content = client
             .send_prompt(
                 &session_id_str,
@@ -183,6 +246,9 @@ impl ReviewPhase {
                 activity_store.as_deref(),
             )
             .await;
+        if let Some(tracking) = tracking {
+            tracking.abort();
+        }
 
         let response_content = match response_content {
             Ok(content) => content,
@@ -198,6 +264,7 @@ impl ReviewPhase {
             response_length = response_content.len(),
             "Received AI review response"
         );
+        progress.review_finalizing(total_files, None);
 
         let _review_path = ctx
             .file_manager
@@ -207,8 +274,15 @@ impl ReviewPhase {
         session.complete();
         ctx.update_session(&session).await?;
 
-        let review_result =
-            Self::parse_and_save_findings(ctx, &response_content, task.id, session.id).await;
+        let review_result = Self::parse_and_save_findings(
+            ctx,
+            task,
+            &response_content,
+            session.id,
+            &diff,
+            wiki_context.as_deref(),
+        )
+        .await;
 
         let success = matches!(review_result, ReviewResult::Approved);
 
@@ -223,18 +297,20 @@ impl ReviewPhase {
 
     async fn parse_and_save_findings(
         ctx: &ExecutorContext,
+        task: &Task,
         response_content: &str,
-        task_id: Uuid,
         session_id: Uuid,
+        diff: &str,
+        wiki_context: Option<&str>,
     ) -> ReviewResult {
-        match MessageParser::parse_review_json(response_content, task_id, session_id) {
+        match MessageParser::parse_review_json(response_content, task.id, session_id) {
             Ok(findings) => {
-                let _ = ctx.file_manager.write_findings(task_id, &findings).await;
-                if findings.approved || findings.findings.is_empty() {
-                    ReviewResult::Approved
-                } else {
-                    ReviewResult::FindingsDetected(findings.findings.len())
-                }
+                let mut findings =
+                    ReviewConsensus::reach(ctx, task, diff, wiki_context, findings).await;
+                SeverityCalibration::calibrate(ctx, &mut findings).await;
+                PublicApiSeverity::escalate(ctx, &mut findings).await;
+                let _ = ctx.file_manager.write_findings(task.id, &findings).await;
+                ctx.config.review_policy.review_result(&findings)
             }
             Err(_) => {
                 warn!("Falling back to legacy text-based review parsing");
@@ -252,8 +328,8 @@ impl ReviewPhase {
     ) -> Result<PhaseResult> {
         match review_result {
             ReviewResult::Approved => {
-                info!(task_id = %task.id, "AI review APPROVED, proceeding to human review");
-                ctx.transition(task, TaskStatus::Review)?;
+                info!(task_id = %task.id, "AI review APPROVED");
+                ctx.complete_phase(task, TransitionTrigger::ReviewApproved)?;
                 Ok(PhaseResult::ReviewPassed {
                     session_id: session_id_str,
                 })
@@ -280,7 +356,7 @@ impl ReviewPhase {
                     feedback_preview = %feedback.chars().take(200).collect::<String>(),
                     "AI review REJECTED (legacy format), changes requested"
                 );
-                ctx.transition(task, TaskStatus::InProgress)?;
+                ctx.complete_phase(task, TransitionTrigger::ReviewRejected)?;
                 Ok(PhaseResult::ReviewFailed {
                     session_id: session_id_str,
                     feedback,
@@ -317,7 +393,13 @@ impl ReviewPhase {
             let temp_session_id = Uuid::new_v4();
             match ctx
                 .mcp_manager
-                .setup_findings_server(task.id, temp_session_id, &working_dir, project_path)
+                .setup_findings_server(
+                    task.id,
+                    temp_session_id,
+                    SessionPhase::Review,
+                    &working_dir,
+                    project_path,
+                )
                 .await
             {
                 Ok(_) => {
@@ -340,12 +422,18 @@ impl ReviewPhase {
             warn!(error = %e, task_id = %task.id, "Failed to get workspace diff, proceeding without diff");
             String::new()
         });
+        ctx.record_review_changes(task, &diff).await;
+        let wiki_context = ctx.review_wiki_context(task, &diff).await;
         let prompt = if mcp_config.is_some() {
-            PhasePrompts::review_with_mcp(task, &diff)
+            PhasePrompts::review_with_mcp(task, &diff, wiki_context.as_deref())
         } else {
-            PhasePrompts::review(task, &diff)
+            PhasePrompts::review(task, &diff, wiki_context.as_deref())
         };
-        let client = ctx.opencode_client_for_phase(SessionPhase::Review);
+        let prompt = PhasePrompts::in_language(prompt, ctx.config.review_language.as_deref());
+        let prompt = ctx.with_session_environment(task, prompt).await;
+        let client = ctx
+            .opencode_client_for_task(task.id, SessionPhase::Review)
+            .await;
 
         let config = SessionConfig {
             task_id: task.id,
@@ -358,6 +446,9 @@ impl ReviewPhase {
             mcp_config,
             implementation_phase: None,
             skip_task_status_update: false,
+            review_files: ChangedFiles::from_diff(&diff).files,
+            plan_sources: Vec::new(),
+            context_files: Vec::new(),
         };
 
         let deps = SessionDependencies::new(
@@ -367,7 +458,9 @@ impl ReviewPhase {
             ctx.event_bus.clone(),
             ctx.activity_registry.clone(),
             ctx.file_manager.clone(),
-        );
+        )
+        .with_workflow(ctx.config.workflow.clone())
+        .with_emitter(ctx.emitter.clone());
 
         let result = SessionRunner::start(config, deps).await?;
 
@@ -0,0 +1,167 @@
+//! Which review findings hold a task back
+//!
+//! The reviewer's own verdict only weighs errors. A review policy lets a
+//! project decide which severities send the task to the fix phase, and how
+//! many warnings it tolerates before they do. Only pending findings count;
+//! fixed, skipped and dismissed ones never block.
+
+use serde::{Deserialize, Serialize};
+
+use crate::files::{FindingSeverity, FindingStatus, ReviewFinding, ReviewFindings};
+use crate::services::message_parser::ReviewResult;
+
+/// Severities that block a review by default
+fn default_block_on() -> Vec<FindingSeverity> {
+    vec![FindingSeverity::Error, FindingSeverity::Warning]
+}
+
+/// Review gating policy
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ReviewPolicy {
+    /// Severities of which a single pending finding blocks the review
+    #[serde(default = "default_block_on")]
+    pub block_on: Vec<FindingSeverity>,
+    /// Pending warnings tolerated when warnings don't block on their own;
+    /// one more blocks the review
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_warnings: Option<u32>,
+}
+
+impl Default for ReviewPolicy {
+    fn default() -> Self {
+        Self {
+            block_on: default_block_on(),
+            max_warnings: None,
+        }
+    }
+}
+
+impl ReviewPolicy {
+    /// Pending findings that block the review under this policy
+    pub fn blocking<'a>(&self, findings: &'a ReviewFindings) -> Vec<&'a ReviewFinding> {
+        let pending: Vec<&ReviewFinding> = findings
+            .findings
+            .iter()
+            .filter(|f| f.status == FindingStatus::Pending)
+            .collect();
+        let warnings = pending
+            .iter()
+            .filter(|f| f.severity == FindingSeverity::Warning)
+            .count();
+        let too_many_warnings = self.max_warnings.is_some_and(|max| warnings > max as usize);
+
+        pending
+            .into_iter()
+            .filter(|f| {
+                self.block_on.contains(&f.severity)
+                    || (too_many_warnings && f.severity == FindingSeverity::Warning)
+            })
+            .collect()
+    }
+
+    /// Whether the review passes under this policy
+    pub fn passes(&self, findings: &ReviewFindings) -> bool {
+        self.blocking(findings).is_empty()
+    }
+
+    /// Outcome of a review with `findings`: approved, or the pending findings
+    /// go to the fix phase
+    pub fn review_result(&self, findings: &ReviewFindings) -> ReviewResult {
+        if self.passes(findings) {
+            ReviewResult::Approved
+        } else {
+            ReviewResult::FindingsDetected(findings.pending_count())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn finding(id: &str, severity: FindingSeverity, status: FindingStatus) -> ReviewFinding {
+        ReviewFinding {
+            id: id.to_string(),
+            file_path: None,
+            line_start: None,
+            line_end: None,
+            title: id.to_string(),
+            description: String::new(),
+            severity,
+            status,
+            original_severity: None,
+            severity_rationale: None,
+            reproduction: None,
+            acceptance_criteria: None,
+            suggested_fix: None,
+            consensus_score: None,
+            comments: Vec::new(),
+            snippet: None,
+        }
+    }
+
+    fn review(findings: Vec<ReviewFinding>) -> ReviewFindings {
+        ReviewFindings::with_findings(Uuid::new_v4(), Uuid::new_v4(), String::new(), findings)
+    }
+
+    #[test]
+    fn test_default_policy_blocks_errors_and_warnings() {
+        let policy = ReviewPolicy::default();
+        assert!(policy.passes(&review(vec![])));
+        assert!(policy.passes(&review(vec![finding(
+            "a",
+            FindingSeverity::Info,
+            FindingStatus::Pending
+        )])));
+        assert!(!policy.passes(&review(vec![finding(
+            "a",
+            FindingSeverity::Warning,
+            FindingStatus::Pending
+        )])));
+        // Resolved findings never block
+        assert!(policy.passes(&review(vec![finding(
+            "a",
+            FindingSeverity::Error,
+            FindingStatus::Dismissed
+        )])));
+    }
+
+    #[test]
+    fn test_max_warnings() {
+        let policy = ReviewPolicy {
+            block_on: vec![FindingSeverity::Error],
+            max_warnings: Some(1),
+        };
+        let one = review(vec![
+            finding("a", FindingSeverity::Warning, FindingStatus::Pending),
+            finding("b", FindingSeverity::Warning, FindingStatus::Fixed),
+        ]);
+        assert_eq!(policy.review_result(&one), ReviewResult::Approved);
+
+        let two = review(vec![
+            finding("a", FindingSeverity::Warning, FindingStatus::Pending),
+            finding("b", FindingSeverity::Warning, FindingStatus::Pending),
+            finding("c", FindingSeverity::Info, FindingStatus::Pending),
+        ]);
+        assert_eq!(policy.blocking(&two).len(), 2);
+        assert_eq!(
+            policy.review_result(&two),
+            ReviewResult::FindingsDetected(3)
+        );
+    }
+
+    #[test]
+    fn test_policy_deserializes_with_defaults() {
+        let policy: ReviewPolicy = serde_json::from_str("{}").unwrap();
+        assert_eq!(policy, ReviewPolicy::default());
+
+        let policy: ReviewPolicy =
+            serde_json::from_str(r#"{"block_on": ["error"], "max_warnings": 3}"#).unwrap();
+        assert_eq!(policy.block_on, vec![FindingSeverity::Error]);
+        assert_eq!(policy.max_warnings, Some(3));
+    }
+}
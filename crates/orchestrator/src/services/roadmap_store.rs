@@ -3,7 +3,7 @@
 //! Handles persistence of roadmap data to JSON files in the `.opencode-studio/roadmap/` directory.
 //!
 //! File structure:
-//! ```
+//! ```text
 //! .opencode-studio/
 //! └── roadmap/
 //!     ├── roadmap.json           # Main roadmap data
@@ -0,0 +1,293 @@
+//! Severity calibration for review findings
+//!
+//! Reviewers often report nitpicks as errors. After a review, the findings
+//! are re-rated against a severity rubric and the project's guidelines with
+//! one prompt per batch. Changed findings keep the reviewer's severity and
+//! the reason for the change.
+
+use std::path::Path;
+
+use opencode_core::SessionPhase;
+use serde::Deserialize;
+use tracing::{debug, info, warn};
+
+use crate::error::{OrchestratorError, Result};
+use crate::files::{FindingSeverity, FindingStatus, ReviewFindings};
+use crate::prompts::PhasePrompts;
+use crate::services::{ExecutorContext, MessageParser};
+
+/// Findings re-rated per prompt
+const CALIBRATION_BATCH_SIZE: usize = 20;
+
+/// Guideline files read from the repository root, first match wins
+const GUIDELINE_FILES: &[&str] = &[
+    ".opencode-studio/review-guidelines.md",
+    "AGENTS.md",
+    "CONTRIBUTING.md",
+];
+
+/// Longest guidelines text included in the prompt, in characters
+const MAX_GUIDELINES_CHARS: usize = 8000;
+
+/// Severity the calibration prompt settled on for one finding
+#[derive(Debug, Clone, Deserialize)]
+pub struct CalibratedSeverity {
+    pub id: String,
+    pub severity: FindingSeverity,
+    #[serde(default)]
+    pub rationale: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CalibrationResponse {
+    findings: Vec<CalibratedSeverity>,
+}
+
+pub struct SeverityCalibration;
+
+impl SeverityCalibration {
+    /// Re-rate the severity of `findings` in place
+    ///
+    /// Returns how many findings changed. A batch that fails leaves its
+    /// findings as the reviewer rated them; calibration never fails a review.
+    pub async fn calibrate(ctx: &ExecutorContext, findings: &mut ReviewFindings) -> usize {
+        if !ctx.config.calibrate_severity || findings.findings.is_empty() {
+            return 0;
+        }
+
+        let guidelines = read_guidelines(&ctx.config.repo_path).await;
+        let mut calibrated = Vec::new();
+        for batch in findings.findings.chunks(CALIBRATION_BATCH_SIZE) {
+            let prompt = PhasePrompts::severity_calibration(batch, guidelines.as_deref());
+            match Self::request(ctx, &prompt).await {
+                Ok(batch) => calibrated.extend(batch),
+                Err(e) => warn!(
+                    task_id = %findings.task_id,
+                    error = %e,
+                    "Severity calibration failed, keeping reviewer severities"
+                ),
+            }
+        }
+
+        let changed = Self::apply(findings, &calibrated);
+        info!(
+            task_id = %findings.task_id,
+            findings = findings.findings.len(),
+            changed = changed,
+            approved = findings.approved,
+            "Review finding severities calibrated"
+        );
+        changed
+    }
+
+    async fn request(ctx: &ExecutorContext, prompt: &str) -> Result<Vec<CalibratedSeverity>> {
+        let client = ctx.opencode_client_for_phase(SessionPhase::Review);
+        let session = client.create_session(&ctx.config.repo_path).await?;
+        debug!(session_id = %session.id, "Created severity calibration session");
+
+        let response = client
+            .send_prompt(&session.id, prompt, &ctx.config.repo_path, None)
+            .await?;
+        Self::parse_response(&response)
+    }
+
+    pub fn parse_response(response: &str) -> Result<Vec<CalibratedSeverity>> {
+        let json = MessageParser::extract_json_from_response(response);
+        let parsed: CalibrationResponse = serde_json::from_str(&json).map_err(|e| {
+            OrchestratorError::ExecutionFailed(format!(
+                "Failed to parse severity calibration JSON: {}",
+                e
+            ))
+        })?;
+        Ok(parsed.findings)
+    }
+
+    /// Apply calibrated severities and return how many findings changed
+    ///
+    /// When anything changed, `approved` is re-derived the way the review
+    /// prompt defines it: approved unless a pending finding is an error or
+    /// a warning.
+    pub fn apply(findings: &mut ReviewFindings, calibrated: &[CalibratedSeverity]) -> usize {
+        let mut changed = 0;
+        for calibration in calibrated {
+            let Some(finding) = findings
+                .findings
+                .iter_mut()
+                .find(|f| f.id == calibration.id)
+            else {
+                continue;
+            };
+            if finding.severity == calibration.severity {
+                continue;
+            }
+
+            debug!(
+                finding_id = %finding.id,
+                from = finding.severity.as_str(),
+                to = calibration.severity.as_str(),
+                "Finding severity calibrated"
+            );
+            finding.original_severity.get_or_insert(finding.severity);
+            finding.severity = calibration.severity;
+            finding.severity_rationale = Some(calibration.rationale.clone());
+            changed += 1;
+        }
+
+        if changed > 0 {
+            findings.approved = !findings
+                .findings
+                .iter()
+                .any(|f| f.status == FindingStatus::Pending && f.severity != FindingSeverity::Info);
+        }
+        changed
+    }
+}
+
+async fn read_guidelines(repo_path: &Path) -> Option<String> {
+    for name in GUIDELINE_FILES {
+        if let Ok(content) = tokio::fs::read_to_string(repo_path.join(name)).await {
+            if content.trim().is_empty() {
+                continue;
+            }
+            return Some(content.chars().take(MAX_GUIDELINES_CHARS).collect());
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::files::ReviewFinding;
+    use uuid::Uuid;
+
+    fn finding(id: &str, severity: FindingSeverity) -> ReviewFinding {
+        ReviewFinding {
+            id: id.to_string(),
+            file_path: None,
+            line_start: None,
+            line_end: None,
+            title: "Title".to_string(),
+            description: "Description".to_string(),
+            severity,
+            status: FindingStatus::Pending,
+            original_severity: None,
+            severity_rationale: None,
+            reproduction: None,
+            acceptance_criteria: None,
+            suggested_fix: None,
+            consensus_score: None,
+            comments: Vec::new(),
+            snippet: None,
+        }
+    }
+
+    fn review(findings: Vec<ReviewFinding>) -> ReviewFindings {
+        ReviewFindings::with_findings(Uuid::new_v4(), Uuid::new_v4(), String::new(), findings)
+    }
+
+    fn calibrated(id: &str, severity: FindingSeverity) -> CalibratedSeverity {
+        CalibratedSeverity {
+            id: id.to_string(),
+            severity,
+            rationale: format!("Rated {}", severity.as_str()),
+        }
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let response = r#"Here you go:
+```json
+{"findings": [{"id": "finding-1", "severity": "info", "rationale": "Naming only"}]}
+```"#;
+
+        let parsed = SeverityCalibration::parse_response(response).unwrap();
+
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].id, "finding-1");
+        assert_eq!(parsed[0].severity, FindingSeverity::Info);
+        assert_eq!(parsed[0].rationale, "Naming only");
+    }
+
+    #[test]
+    fn test_parse_response_invalid() {
+        assert!(SeverityCalibration::parse_response("not json").is_err());
+    }
+
+    #[test]
+    fn test_apply_records_original_severity() {
+        let mut findings = review(vec![
+            finding("finding-1", FindingSeverity::Error),
+            finding("finding-2", FindingSeverity::Warning),
+        ]);
+
+        let changed = SeverityCalibration::apply(
+            &mut findings,
+            &[
+                calibrated("finding-1", FindingSeverity::Info),
+                calibrated("finding-2", FindingSeverity::Warning),
+                calibrated("finding-9", FindingSeverity::Error),
+            ],
+        );
+
+        assert_eq!(changed, 1);
+        let downgraded = &findings.findings[0];
+        assert_eq!(downgraded.severity, FindingSeverity::Info);
+        assert_eq!(downgraded.original_severity, Some(FindingSeverity::Error));
+        assert_eq!(downgraded.severity_rationale.as_deref(), Some("Rated info"));
+        assert_eq!(findings.findings[1].original_severity, None);
+        assert!(!findings.approved);
+    }
+
+    #[test]
+    fn test_apply_approves_when_only_info_remains() {
+        let mut findings = review(vec![finding("finding-1", FindingSeverity::Error)]);
+
+        SeverityCalibration::apply(
+            &mut findings,
+            &[calibrated("finding-1", FindingSeverity::Info)],
+        );
+
+        assert!(findings.approved);
+    }
+
+    #[test]
+    fn test_apply_upgrade_revokes_approval() {
+        let mut findings = review(vec![finding("finding-1", FindingSeverity::Info)]);
+        findings.approved = true;
+
+        SeverityCalibration::apply(
+            &mut findings,
+            &[calibrated("finding-1", FindingSeverity::Error)],
+        );
+
+        assert!(!findings.approved);
+        assert_eq!(
+            findings.findings[0].original_severity,
+            Some(FindingSeverity::Info)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_read_guidelines_prefers_review_guidelines() {
+        let dir = tempfile::TempDir::new().unwrap();
+        assert_eq!(read_guidelines(dir.path()).await, None);
+
+        std::fs::write(dir.path().join("CONTRIBUTING.md"), "Contributing").unwrap();
+        assert_eq!(
+            read_guidelines(dir.path()).await.as_deref(),
+            Some("Contributing")
+        );
+
+        std::fs::create_dir(dir.path().join(".opencode-studio")).unwrap();
+        std::fs::write(
+            dir.path().join(".opencode-studio/review-guidelines.md"),
+            "Review rules",
+        )
+        .unwrap();
+        assert_eq!(
+            read_guidelines(dir.path()).await.as_deref(),
+            Some("Review rules")
+        );
+    }
+}
@@ -9,18 +9,29 @@ use db::{SessionRepository, TaskRepository};
 use events::{Event, EventBus, EventEnvelope};
 use opencode_client::apis::configuration::Configuration;
 use opencode_client::apis::default_api;
-use opencode_client::models::{SessionPromptRequest, SessionPromptRequestPartsInner};
+use opencode_client::models::agent_config_permission::{Edit, ExternalDirectory};
+use opencode_client::models::permission_respond_request::Response as PermissionResponse;
+use opencode_client::models::{
+    AgentConfigPermission, Config, PermissionRespondRequest, SessionPromptRequest,
+    SessionPromptRequestPartsInner,
+};
 use opencode_core::{Session, SessionPhase, SessionStatus, TaskStatus, UpdateTaskRequest};
+use std::collections::HashSet;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
-use crate::activity_store::SessionActivityRegistry;
+use crate::activity_store::{SessionActivityMsg, SessionActivityRegistry};
+use crate::core::OrderedEventEmitter;
 use crate::error::{OrchestratorError, Result};
 use crate::executor::TaskExecutor;
 use crate::files::FileManager;
 use crate::opencode_events::{ExecutorEvent, OpenCodeEventSubscriber};
+use crate::path_guard::{PathGuard, PathViolation, GUARDED_PERMISSIONS};
+use crate::services::executor_context::{log_rule_evaluation, rule_evaluated_event};
+use crate::services::{OpenCodeClient, PhaseProgress, PlanningStep, ReviewTracker};
+use crate::workflow::{RuleEvaluation, TransitionTrigger, WorkflowDefinition};
 
 /// Configuration for running a session
 #[derive(Debug, Clone)]
@@ -45,6 +56,12 @@ pub struct SessionConfig {
     pub implementation_phase: Option<(u32, String)>,
     /// Skip task status update after completion (for phased implementation)
     pub skip_task_status_update: bool,
+    /// Changed files a review walks through, for its progress
+    pub review_files: Vec<String>,
+    /// Wiki sources consulted while planning, listed in the plan file
+    pub plan_sources: Vec<String>,
+    /// Files attached to the prompt, relative to the working directory
+    pub context_files: Vec<String>,
 }
 
 /// MCP server configuration
@@ -74,6 +91,12 @@ pub struct SessionDependencies {
     pub activity_registry: Option<SessionActivityRegistry>,
     pub file_manager: FileManager,
     pub base_url: String,
+    /// Rules deciding where the task goes once the session completes
+    pub workflow: WorkflowDefinition,
+    /// Emits step progress of planning and review sessions
+    pub emitter: Option<OrderedEventEmitter>,
+    /// Paths the session may write; unguarded sessions may write anywhere
+    pub path_guard: Option<PathGuard>,
 }
 
 impl SessionDependencies {
@@ -99,6 +122,158 @@ impl SessionDependencies {
             activity_registry,
             file_manager,
             base_url,
+            workflow: WorkflowDefinition::builtin(),
+            emitter: None,
+            path_guard: None,
+        }
+    }
+
+    pub fn with_workflow(mut self, workflow: WorkflowDefinition) -> Self {
+        self.workflow = workflow;
+        self
+    }
+
+    pub fn with_emitter(mut self, emitter: Option<OrderedEventEmitter>) -> Self {
+        self.emitter = emitter;
+        self
+    }
+
+    pub fn with_path_guard(mut self, path_guard: PathGuard) -> Self {
+        self.path_guard = Some(path_guard);
+        self
+    }
+}
+
+/// Enforces a session's [`PathGuard`] on the tool calls it streams
+struct SessionPathGuard {
+    guard: PathGuard,
+    task_id: Uuid,
+    session_id: Uuid,
+    opencode_session_id: String,
+    directory: String,
+    opencode_config: Arc<Configuration>,
+    file_manager: FileManager,
+    event_bus: Option<EventBus>,
+    /// Tool calls already reported, so a call is reported once however
+    /// often its part updates
+    reported: HashSet<String>,
+}
+
+impl SessionPathGuard {
+    /// Have OpenCode ask before the session writes files, so that every
+    /// write passes `on_permission`. Without this OpenCode writes without
+    /// asking and writes outside the allowed paths can only be reported.
+    async fn ask_for_writes(&self) {
+        let mut permission = AgentConfigPermission::new();
+        permission.edit = Some(Edit::Ask);
+        permission.external_directory = Some(ExternalDirectory::Ask);
+        let mut config = Config::new();
+        config.permission = Some(Box::new(permission));
+
+        if let Err(e) =
+            default_api::config_update(&self.opencode_config, Some(&self.directory), Some(config))
+                .await
+        {
+            warn!(task_id = %self.task_id, error = %e, "Failed to make OpenCode ask before writes, writes outside the workspace are only reported");
+        }
+    }
+
+    /// Answer a write permission request: reject it if it would write
+    /// outside the allowed paths, approve it otherwise. Other requests are
+    /// left for whoever answers them.
+    async fn on_permission(
+        &mut self,
+        permission_id: &str,
+        permission_type: &str,
+        call_id: Option<String>,
+        metadata: &serde_json::Value,
+    ) {
+        if !GUARDED_PERMISSIONS.contains(&permission_type) {
+            return;
+        }
+        let violation = self.guard.permission_violation(permission_type, metadata);
+
+        let response = PermissionRespondRequest::new(if violation.is_some() {
+            PermissionResponse::Reject
+        } else {
+            PermissionResponse::Once
+        });
+        let answered = match default_api::permission_respond(
+            &self.opencode_config,
+            &self.opencode_session_id,
+            permission_id,
+            Some(&self.directory),
+            Some(response),
+        )
+        .await
+        {
+            Ok(_) => true,
+            Err(e) => {
+                error!(task_id = %self.task_id, permission_id = %permission_id, error = %e, "Failed to answer write permission request");
+                false
+            }
+        };
+
+        let Some(violation) = violation else {
+            return;
+        };
+        if let Some(call_id) = call_id {
+            self.reported.insert(call_id);
+        }
+        self.report(&violation, answered).await;
+    }
+
+    /// Report writes outside the allowed paths that ran without asking
+    async fn on_activity(&mut self, activity: &SessionActivityMsg) {
+        let (id, tool_name, args) = match activity {
+            SessionActivityMsg::ToolCall {
+                id,
+                tool_name,
+                args: Some(args),
+                ..
+            }
+            | SessionActivityMsg::ToolResult {
+                id,
+                tool_name,
+                args: Some(args),
+                ..
+            } => (id, tool_name, args),
+            _ => return,
+        };
+
+        let violations = self.guard.violations(tool_name, args);
+        if violations.is_empty() || !self.reported.insert(id.clone()) {
+            return;
+        }
+        for violation in &violations {
+            self.report(violation, false).await;
+        }
+    }
+
+    async fn report(&self, violation: &PathViolation, blocked: bool) {
+        warn!(
+            task_id = %self.task_id,
+            tool = %violation.tool,
+            path = %violation.path,
+            blocked = blocked,
+            "Session wrote outside its workspace"
+        );
+
+        if let Err(e) = self
+            .file_manager
+            .add_finding(self.task_id, violation.finding(blocked))
+            .await
+        {
+            error!(task_id = %self.task_id, error = %e, "Failed to record path violation finding");
+        }
+        if let Some(bus) = &self.event_bus {
+            bus.publish(EventEnvelope::new(Event::PathViolation {
+                task_id: self.task_id,
+                session_id: self.session_id,
+                tool: violation.tool.clone(),
+                path: violation.path.clone(),
+                blocked,
+            }));
         }
     }
 }
@@ -214,8 +389,15 @@ impl SessionRunner {
             model_id: config.model_id.clone(),
         };
 
+        let mut parts = vec![Self::create_text_part(&config.prompt)];
+        parts.extend(
+            config
+                .context_files
+                .iter()
+                .map(|f| OpenCodeClient::create_file_part(&config.working_dir, f)),
+        );
         let request = SessionPromptRequest {
-            parts: vec![Self::create_text_part(&config.prompt)],
+            parts,
             model: Some(Box::new(model)),
             message_id: None,
             agent: None,
@@ -235,6 +417,28 @@ impl SessionRunner {
         let activity_store_for_sse = activity_store.clone();
         let opencode_session_id_for_sse = opencode_session_id.clone();
         let task_id_for_sse = config.task_id;
+        let progress =
+            PhaseProgress::new(deps.emitter.clone(), config.task_id).with_session(session_id);
+        let progress_for_sse = progress.clone();
+        let mut review_tracker = (config.phase == SessionPhase::Review)
+            .then(|| ReviewTracker::new(config.review_files.clone()));
+        if let Some(ref tracker) = review_tracker {
+            progress.review(tracker);
+        }
+        let mut path_guard = deps.path_guard.clone().map(|guard| SessionPathGuard {
+            guard,
+            task_id: config.task_id,
+            session_id,
+            opencode_session_id: opencode_session_id.clone(),
+            directory: config.working_dir.to_string_lossy().to_string(),
+            opencode_config: Arc::clone(&deps.opencode_config),
+            file_manager: deps.file_manager.clone(),
+            event_bus: deps.event_bus.clone(),
+            reported: HashSet::new(),
+        });
+        if let Some(ref guard) = path_guard {
+            guard.ask_for_writes().await;
+        }
 
         let sse_task = tokio::spawn(async move {
             debug!("SSE event processor started");
@@ -249,13 +453,37 @@ impl SessionRunner {
                         break;
                     }
                     ExecutorEvent::MessagePartUpdated { part, .. } => {
-                        if let Some(ref store) = activity_store_for_sse {
-                            if let Some(activity) = TaskExecutor::parse_sse_part(&part) {
+                        if let Some(activity) = TaskExecutor::parse_sse_part(&part) {
+                            if let Some(ref mut guard) = path_guard {
+                                guard.on_activity(&activity).await;
+                            }
+                            if let Some(ref mut tracker) = review_tracker {
+                                if tracker.observe(&activity) {
+                                    progress_for_sse.review(tracker);
+                                }
+                            }
+                            if let Some(ref store) = activity_store_for_sse {
                                 store.push(activity);
                             }
                         }
                     }
+                    ExecutorEvent::PermissionRequested {
+                        permission_id,
+                        permission_type,
+                        call_id,
+                        metadata,
+                        ..
+                    } => {
+                        if let Some(ref mut guard) = path_guard {
+                            guard
+                                .on_permission(&permission_id, &permission_type, call_id, &metadata)
+                                .await;
+                        }
+                    }
                     ExecutorEvent::DirectActivity { activity } => {
+                        if let Some(ref mut guard) = path_guard {
+                            guard.on_activity(&activity).await;
+                        }
                         if let Some(ref store) = activity_store_for_sse {
                             store.push(activity);
                         }
@@ -272,6 +500,7 @@ impl SessionRunner {
                 }
             }
             debug!("SSE event processor finished");
+            review_tracker
         });
 
         // Send prompt asynchronously (returns immediately)
@@ -295,9 +524,14 @@ impl SessionRunner {
                     opencode_session_id = %opencode_session_id,
                     "Prompt sent, streaming via SSE"
                 );
+                if config.phase == SessionPhase::Planning {
+                    progress.planning(PlanningStep::Drafting);
+                }
 
                 // Wait for SSE to signal completion
-                let _ = sse_task.await;
+                if let Ok(Some(tracker)) = sse_task.await {
+                    progress.review_finalizing(tracker.total(), Some(tracker.findings()));
+                }
 
                 // Extract response text for artifacts
                 response_text = Self::extract_response_text(
@@ -374,9 +608,12 @@ impl SessionRunner {
         // Save artifacts based on phase
         if success {
             if config.phase == SessionPhase::Planning && !response_text.is_empty() {
+                PhaseProgress::new(deps.emitter.clone(), config.task_id)
+                    .with_session(session_id)
+                    .planning(PlanningStep::Finalizing);
                 if let Err(e) = deps
                     .file_manager
-                    .write_plan(config.task_id, response_text)
+                    .write_plan_with_sources(config.task_id, response_text, &config.plan_sources)
                     .await
                 {
                     error!(error = %e, "Failed to save plan");
@@ -387,31 +624,9 @@ impl SessionRunner {
 
             // Update task status (skip for phased implementation - handled separately)
             if !config.skip_task_status_update {
-                let next_status = Self::determine_next_status(config.phase, config.task_status);
-                if let Some(ref repo) = deps.task_repo {
-                    let update = UpdateTaskRequest {
-                        status: Some(next_status),
-                        ..Default::default()
-                    };
-                    if let Err(e) = repo.update(config.task_id, &update).await {
-                        error!(error = %e, "Failed to update task status");
-                    } else {
-                        info!(
-                            task_id = %config.task_id,
-                            new_status = %next_status.as_str(),
-                            "Task status updated"
-                        );
-                    }
-                }
-
-                // Emit task status changed event
-                if let Some(ref bus) = deps.event_bus {
-                    bus.publish(EventEnvelope::new(Event::TaskStatusChanged {
-                        task_id: config.task_id,
-                        from_status: config.task_status.as_str().to_string(),
-                        to_status: next_status.as_str().to_string(),
-                    }));
-                }
+                let trigger = Self::completion_trigger(config, deps).await;
+                Self::apply_transition_rule(deps, config.task_id, config.task_status, trigger)
+                    .await;
             }
         }
 
@@ -441,22 +656,81 @@ impl SessionRunner {
         }
     }
 
-    /// Determine next task status based on current phase
-    fn determine_next_status(phase: SessionPhase, current_status: TaskStatus) -> TaskStatus {
-        match phase {
-            SessionPhase::Planning => TaskStatus::PlanningReview,
-            SessionPhase::Implementation => TaskStatus::AiReview,
+    /// How the session's phase finished. A review is approved when it left
+    /// no findings to act on.
+    async fn completion_trigger(
+        config: &SessionConfig,
+        deps: &SessionDependencies,
+    ) -> TransitionTrigger {
+        match config.phase {
+            SessionPhase::Planning => TransitionTrigger::PlanningCompleted,
+            SessionPhase::Implementation => TransitionTrigger::ImplementationCompleted,
+            SessionPhase::Fix => TransitionTrigger::FixCompleted,
             SessionPhase::Review => {
-                if current_status == TaskStatus::AiReview {
-                    TaskStatus::Review
-                } else {
-                    TaskStatus::Done
+                let findings = match deps.file_manager.read_findings(config.task_id).await {
+                    Ok(findings) => findings,
+                    Err(e) => {
+                        warn!(error = %e, "Failed to read review findings");
+                        None
+                    }
+                };
+                match findings {
+                    Some(f) if !f.approved && f.pending_count() > 0 => {
+                        TransitionTrigger::ReviewFindings
+                    }
+                    _ => TransitionTrigger::ReviewApproved,
                 }
             }
-            SessionPhase::Fix => TaskStatus::AiReview,
         }
     }
 
+    /// Apply the workflow's rule for a phase that finished with `trigger`:
+    /// report the evaluation and, when the task moves, persist the new
+    /// status and announce it
+    pub(crate) async fn apply_transition_rule(
+        deps: &SessionDependencies,
+        task_id: Uuid,
+        from: TaskStatus,
+        trigger: TransitionTrigger,
+    ) -> RuleEvaluation {
+        let evaluation = deps.workflow.evaluate(trigger, from);
+        log_rule_evaluation(task_id, &evaluation);
+        if let Some(ref bus) = deps.event_bus {
+            bus.publish(EventEnvelope::new(rule_evaluated_event(
+                task_id,
+                &evaluation,
+            )));
+        }
+        if !evaluation.changes_status() {
+            return evaluation;
+        }
+
+        if let Some(ref repo) = deps.task_repo {
+            let update = UpdateTaskRequest {
+                status: Some(evaluation.to),
+                ..Default::default()
+            };
+            if let Err(e) = repo.update(task_id, &update).await {
+                error!(error = %e, "Failed to update task status");
+            } else {
+                info!(
+                    task_id = %task_id,
+                    new_status = %evaluation.to.as_str(),
+                    "Task status updated"
+                );
+            }
+        }
+
+        if let Some(ref bus) = deps.event_bus {
+            bus.publish(EventEnvelope::new(Event::TaskStatusChanged {
+                task_id,
+                from_status: from.as_str().to_string(),
+                to_status: evaluation.to.as_str().to_string(),
+            }));
+        }
+        evaluation
+    }
+
     /// Create OpenCode session
     async fn create_opencode_session(
         config: &Configuration,
@@ -523,3 +797,107 @@ impl SessionRunner {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use axum::extract::{Path, State};
+    use axum::routing::{patch, post};
+    use axum::{Json, Router};
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::path_guard::PATH_VIOLATION_ID_PREFIX;
+
+    /// Requests made to the fake OpenCode server
+    type Requests = Arc<Mutex<Vec<(String, Value)>>>;
+
+    async fn fake_opencode() -> (Configuration, Requests) {
+        async fn respond(
+            State(requests): State<Requests>,
+            Path((_, permission_id)): Path<(String, String)>,
+            Json(body): Json<Value>,
+        ) -> Json<bool> {
+            requests.lock().unwrap().push((permission_id, body));
+            Json(true)
+        }
+        async fn update_config(
+            State(requests): State<Requests>,
+            Json(body): Json<Value>,
+        ) -> Json<Value> {
+            requests.lock().unwrap().push(("config".to_string(), body));
+            Json(json!({}))
+        }
+
+        let requests = Requests::default();
+        let app = Router::new()
+            .route("/session/{session}/permissions/{permission}", post(respond))
+            .route("/config", patch(update_config))
+            .with_state(requests.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_path = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let config = Configuration {
+            base_path,
+            ..Configuration::default()
+        };
+        (config, requests)
+    }
+
+    #[tokio::test]
+    async fn test_path_guard_rejects_writes_outside_workspace() {
+        let project = tempfile::tempdir().unwrap();
+        let workspace = project.path().join("task-1");
+        let (opencode_config, requests) = fake_opencode().await;
+        let file_manager = FileManager::new(project.path());
+        let task_id = Uuid::new_v4();
+        let mut guard = SessionPathGuard {
+            guard: PathGuard::new(&workspace, &[]),
+            task_id,
+            session_id: Uuid::new_v4(),
+            opencode_session_id: "ses_1".to_string(),
+            directory: workspace.to_string_lossy().to_string(),
+            opencode_config: Arc::new(opencode_config),
+            file_manager: file_manager.clone(),
+            event_bus: None,
+            reported: HashSet::new(),
+        };
+
+        guard.ask_for_writes().await;
+        guard
+            .on_permission(
+                "per_out",
+                "edit",
+                None,
+                &json!({ "filePath": "../../etc/hosts" }),
+            )
+            .await;
+        guard
+            .on_permission("per_in", "edit", None, &json!({ "filePath": "src/lib.rs" }))
+            .await;
+        guard
+            .on_permission("per_bash", "bash", None, &json!({ "command": "ls" }))
+            .await;
+
+        let requests = requests.lock().unwrap().clone();
+        assert_eq!(
+            requests,
+            vec![
+                (
+                    "config".to_string(),
+                    json!({ "permission": { "edit": "ask", "external_directory": "ask" } })
+                ),
+                ("per_out".to_string(), json!({ "response": "reject" })),
+                ("per_in".to_string(), json!({ "response": "once" })),
+            ]
+        );
+
+        let findings = file_manager.read_findings(task_id).await.unwrap().unwrap();
+        assert_eq!(findings.findings.len(), 1);
+        let finding = &findings.findings[0];
+        assert!(finding.id.starts_with(PATH_VIOLATION_ID_PREFIX));
+        assert!(finding.title.starts_with("Blocked a write"));
+    }
+}
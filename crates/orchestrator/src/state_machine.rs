@@ -2,9 +2,113 @@ use opencode_core::TaskStatus;
 use tracing::{debug, warn};
 
 use crate::error::{OrchestratorError, Result};
+use crate::files::CiGateState;
+use crate::workflow::WorkflowDefinition;
 
 pub struct TaskStateMachine;
 
+/// Every task status, in workflow order
+pub const TASK_STATUSES: [TaskStatus; 9] = [
+    TaskStatus::Todo,
+    TaskStatus::Blocked,
+    TaskStatus::Planning,
+    TaskStatus::PlanningReview,
+    TaskStatus::InProgress,
+    TaskStatus::AiReview,
+    TaskStatus::Fix,
+    TaskStatus::Review,
+    TaskStatus::Done,
+];
+
+/// A condition beyond the status graph that a transition requires
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransitionGuard {
+    /// No session of the task may be running
+    NoRunningSession,
+    /// The task must have a plan to implement
+    PlanWritten,
+    /// The AI review must have left pending findings to fix
+    PendingFindings,
+    /// Every task the task depends on must be done
+    DependenciesDone,
+    /// The GitHub checks the task waits on, if any, must have passed
+    CiPassed,
+}
+
+impl TransitionGuard {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NoRunningSession => "no_running_session",
+            Self::PlanWritten => "plan_written",
+            Self::PendingFindings => "pending_findings",
+            Self::DependenciesDone => "dependencies_done",
+            Self::CiPassed => "ci_passed",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::NoRunningSession => "No session of the task is running",
+            Self::PlanWritten => "A plan has been written for the task",
+            Self::PendingFindings => "The review left pending findings",
+            Self::DependenciesDone => "The tasks the task depends on are done",
+            Self::CiPassed => "The GitHub checks of the task's changes passed",
+        }
+    }
+
+    /// Why the guard blocks a transition of a task in `context`, if it does
+    pub fn blocked_reason(&self, context: &TransitionContext) -> Option<String> {
+        match self {
+            Self::NoRunningSession if context.running_sessions > 0 => Some(format!(
+                "{} session(s) of the task are still running",
+                context.running_sessions
+            )),
+            Self::PlanWritten if !context.has_plan => {
+                Some("No plan has been written for the task".to_string())
+            }
+            Self::PendingFindings if context.pending_findings == 0 => {
+                Some("The review left no pending findings to fix".to_string())
+            }
+            Self::DependenciesDone if context.unfinished_dependencies > 0 => Some(format!(
+                "{} task(s) the task depends on are not done",
+                context.unfinished_dependencies
+            )),
+            Self::CiPassed => match context.ci_gate? {
+                CiGateState::Passed => None,
+                CiGateState::Pending => Some("GitHub checks are still running".to_string()),
+                CiGateState::Failed => Some("GitHub checks failed".to_string()),
+                CiGateState::TimedOut => Some("GitHub checks did not finish in time".to_string()),
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Facts about a task that transition guards check
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransitionContext {
+    pub running_sessions: usize,
+    pub has_plan: bool,
+    pub pending_findings: usize,
+    pub unfinished_dependencies: usize,
+    /// State of the GitHub checks the task waits on, if it waits on any
+    pub ci_gate: Option<CiGateState>,
+}
+
+/// A transition out of a task's current status and whether it is blocked
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransitionCheck {
+    pub to: TaskStatus,
+    /// Why the transition cannot be made now, `None` if it can
+    pub blocked_reason: Option<String>,
+}
+
+impl TransitionCheck {
+    pub fn is_allowed(&self) -> bool {
+        self.blocked_reason.is_none()
+    }
+}
+
 impl TaskStateMachine {
     pub fn validate_transition(from: &TaskStatus, to: &TaskStatus) -> Result<()> {
         let allowed = Self::allowed_transitions(from);
@@ -30,9 +134,11 @@ impl TaskStateMachine {
         }
     }
 
-    fn allowed_transitions(from: &TaskStatus) -> Vec<TaskStatus> {
+    /// Statuses the graph allows moving to from `from`, ignoring guards
+    pub fn allowed_transitions(from: &TaskStatus) -> Vec<TaskStatus> {
         match from {
-            TaskStatus::Todo => vec![TaskStatus::Planning],
+            TaskStatus::Todo => vec![TaskStatus::Planning, TaskStatus::Blocked],
+            TaskStatus::Blocked => vec![TaskStatus::Todo],
             TaskStatus::Planning => vec![TaskStatus::PlanningReview, TaskStatus::Todo],
             TaskStatus::PlanningReview => vec![TaskStatus::InProgress, TaskStatus::Planning],
             TaskStatus::InProgress => vec![TaskStatus::AiReview, TaskStatus::PlanningReview],
@@ -48,6 +154,41 @@ impl TaskStateMachine {
         }
     }
 
+    /// Conditions the transition from `from` to `to` requires
+    pub fn guards(from: &TaskStatus, to: &TaskStatus) -> Vec<TransitionGuard> {
+        let mut guards = vec![TransitionGuard::NoRunningSession];
+        match (from, to) {
+            (TaskStatus::PlanningReview, TaskStatus::InProgress) => {
+                guards.push(TransitionGuard::PlanWritten)
+            }
+            (_, TaskStatus::Fix) => guards.push(TransitionGuard::PendingFindings),
+            (TaskStatus::Todo, TaskStatus::Planning) | (TaskStatus::Blocked, TaskStatus::Todo) => {
+                guards.push(TransitionGuard::DependenciesDone)
+            }
+            (_, TaskStatus::Done) => guards.push(TransitionGuard::CiPassed),
+            _ => {}
+        }
+        guards
+    }
+
+    /// Every transition `workflow` allows from `from`, with the reason the
+    /// first failing guard gives for blocking it in `context`
+    pub fn check_transitions(
+        workflow: &WorkflowDefinition,
+        from: &TaskStatus,
+        context: &TransitionContext,
+    ) -> Vec<TransitionCheck> {
+        workflow
+            .transitions_from(from)
+            .map(|t| TransitionCheck {
+                to: t.to,
+                blocked_reason: Self::guards(from, &t.to)
+                    .iter()
+                    .find_map(|guard| guard.blocked_reason(context)),
+            })
+            .collect()
+    }
+
     pub fn can_transition(from: &TaskStatus, to: &TaskStatus) -> bool {
         Self::validate_transition(from, to).is_ok()
     }
@@ -55,6 +196,8 @@ impl TaskStateMachine {
     pub fn next_status(current: &TaskStatus) -> Option<TaskStatus> {
         match current {
             TaskStatus::Todo => Some(TaskStatus::Planning),
+            // A blocked task waits for its dependencies
+            TaskStatus::Blocked => None,
             TaskStatus::Planning => Some(TaskStatus::PlanningReview),
             TaskStatus::PlanningReview => Some(TaskStatus::InProgress),
             TaskStatus::InProgress => Some(TaskStatus::AiReview),
@@ -71,6 +214,7 @@ impl TaskStateMachine {
     pub fn previous_status(current: &TaskStatus) -> Option<TaskStatus> {
         match current {
             TaskStatus::Todo => None,
+            TaskStatus::Blocked => Some(TaskStatus::Todo),
             TaskStatus::Planning => Some(TaskStatus::Todo),
             TaskStatus::PlanningReview => Some(TaskStatus::Planning),
             TaskStatus::InProgress => Some(TaskStatus::PlanningReview),
@@ -131,6 +275,115 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_check_transitions_reports_blocking_guards() {
+        let context = TransitionContext {
+            running_sessions: 0,
+            has_plan: false,
+            pending_findings: 0,
+            unfinished_dependencies: 0,
+            ci_gate: None,
+        };
+        let workflow = WorkflowDefinition::builtin();
+        let checks =
+            TaskStateMachine::check_transitions(&workflow, &TaskStatus::PlanningReview, &context);
+        assert_eq!(checks[0].to, TaskStatus::InProgress);
+        assert_eq!(
+            checks[0].blocked_reason.as_deref(),
+            Some("No plan has been written for the task")
+        );
+        assert!(checks[1].is_allowed());
+
+        let checks = TaskStateMachine::check_transitions(
+            &workflow,
+            &TaskStatus::AiReview,
+            &TransitionContext {
+                pending_findings: 2,
+                ..context
+            },
+        );
+        assert!(checks.iter().all(TransitionCheck::is_allowed));
+
+        let running = TransitionContext {
+            running_sessions: 1,
+            has_plan: true,
+            pending_findings: 2,
+            unfinished_dependencies: 0,
+            ci_gate: None,
+        };
+        assert!(
+            TaskStateMachine::check_transitions(&workflow, &TaskStatus::AiReview, &running)
+                .iter()
+                .all(|c| c.blocked_reason.as_deref()
+                    == Some("1 session(s) of the task are still running"))
+        );
+        assert!(
+            TaskStateMachine::check_transitions(&workflow, &TaskStatus::Done, &running).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_unfinished_dependencies_block_starting() {
+        let workflow = WorkflowDefinition::builtin();
+        let context = TransitionContext {
+            unfinished_dependencies: 1,
+            ..Default::default()
+        };
+        let checks = TaskStateMachine::check_transitions(&workflow, &TaskStatus::Todo, &context);
+        let planning = checks
+            .iter()
+            .find(|c| c.to == TaskStatus::Planning)
+            .unwrap();
+        assert_eq!(
+            planning.blocked_reason.as_deref(),
+            Some("1 task(s) the task depends on are not done")
+        );
+        assert!(checks
+            .iter()
+            .find(|c| c.to == TaskStatus::Blocked)
+            .unwrap()
+            .is_allowed());
+        assert!(
+            !TaskStateMachine::check_transitions(&workflow, &TaskStatus::Blocked, &context)[0]
+                .is_allowed()
+        );
+        assert!(TaskStateMachine::check_transitions(
+            &workflow,
+            &TaskStatus::Blocked,
+            &TransitionContext::default()
+        )[0]
+        .is_allowed());
+    }
+
+    #[test]
+    fn test_ci_gate_blocks_done_until_checks_pass() {
+        let workflow = WorkflowDefinition::builtin();
+        let done = |ci_gate| {
+            TaskStateMachine::check_transitions(
+                &workflow,
+                &TaskStatus::Review,
+                &TransitionContext {
+                    ci_gate,
+                    ..Default::default()
+                },
+            )
+            .into_iter()
+            .find(|c| c.to == TaskStatus::Done)
+            .unwrap()
+        };
+
+        assert!(done(None).is_allowed());
+        assert!(done(Some(CiGateState::Passed)).is_allowed());
+        assert_eq!(
+            done(Some(CiGateState::Pending)).blocked_reason.as_deref(),
+            Some("GitHub checks are still running")
+        );
+        assert_eq!(
+            done(Some(CiGateState::Failed)).blocked_reason.as_deref(),
+            Some("GitHub checks failed")
+        );
+    }
+
     #[test]
     fn test_next_status() {
         assert_eq!(
@@ -0,0 +1,256 @@
+//! Environment variables for task sessions
+//!
+//! Implementation, review and fix sessions often need variables such as a
+//! test `DATABASE_URL` or feature flags. Projects define a set in
+//! `config.json` and tasks can add or override variables of their own. A
+//! variable holds either a literal value or a secret reference naming a
+//! variable of the server's environment, so secrets stay out of the project
+//! files. The resolved set is written to a git-ignored env file under
+//! `.opencode-studio` that sessions are told to source; prompts and API
+//! responses only ever show secret values masked.
+
+use serde::{Deserialize, Serialize};
+
+/// Shown in place of secret values
+pub const MASKED_VALUE: &str = "********";
+
+/// A variable to set for task sessions
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct EnvVar {
+    pub name: String,
+    /// Literal value
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// Name of the server environment variable holding the value
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+}
+
+impl EnvVar {
+    pub fn literal(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: Some(value.into()),
+            secret: None,
+        }
+    }
+
+    pub fn secret(name: impl Into<String>, secret: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: None,
+            secret: Some(secret.into()),
+        }
+    }
+
+    /// Why the variable can't be used, if it can't
+    pub fn validate(&self) -> Result<(), String> {
+        if !is_valid_name(&self.name) {
+            return Err(format!(
+                "Invalid environment variable name: {:?}",
+                self.name
+            ));
+        }
+        match (&self.value, &self.secret) {
+            (Some(_), Some(_)) => Err(format!("{} has both a value and a secret", self.name)),
+            (None, None) => Err(format!("{} needs a value or a secret", self.name)),
+            (None, Some(secret)) if !is_valid_name(secret) => Err(format!(
+                "Invalid secret name for {}: {:?}",
+                self.name, secret
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Whether `name` is usable as a shell variable name
+pub fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Where a resolved variable was defined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum EnvVarSource {
+    Project,
+    Task,
+}
+
+/// A variable with its value looked up
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedEnvVar {
+    pub name: String,
+    pub value: String,
+    pub is_secret: bool,
+    pub source: EnvVarSource,
+}
+
+impl ResolvedEnvVar {
+    /// The value, or the mask for secrets
+    pub fn display_value(&self) -> &str {
+        if self.is_secret {
+            MASKED_VALUE
+        } else {
+            &self.value
+        }
+    }
+}
+
+/// The variables of one task's sessions
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TaskEnvironment {
+    /// In definition order, project variables first
+    pub vars: Vec<ResolvedEnvVar>,
+    /// Variables left out because their secret isn't set, with the secret name
+    pub missing_secrets: Vec<(String, String)>,
+}
+
+impl TaskEnvironment {
+    /// Resolve the project's variables overridden by the task's, looking
+    /// secrets up with `lookup`
+    pub fn resolve(
+        project: &[EnvVar],
+        task: &[EnvVar],
+        lookup: impl Fn(&str) -> Option<String>,
+    ) -> Self {
+        let mut environment = Self::default();
+        let defined = project
+            .iter()
+            .map(|var| (var, EnvVarSource::Project))
+            .chain(task.iter().map(|var| (var, EnvVarSource::Task)));
+
+        for (var, source) in defined {
+            if var.validate().is_err() {
+                continue;
+            }
+            environment.vars.retain(|v| v.name != var.name);
+            environment
+                .missing_secrets
+                .retain(|(name, _)| *name != var.name);
+
+            let resolved = match (&var.value, &var.secret) {
+                (Some(value), _) => Some((value.clone(), false)),
+                (None, Some(secret)) => lookup(secret).map(|value| (value, true)),
+                (None, None) => None,
+            };
+            match resolved {
+                Some((value, is_secret)) => environment.vars.push(ResolvedEnvVar {
+                    name: var.name.clone(),
+                    value,
+                    is_secret,
+                    source,
+                }),
+                None => environment
+                    .missing_secrets
+                    .push((var.name.clone(), var.secret.clone().unwrap_or_default())),
+            }
+        }
+
+        environment
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vars.is_empty() && self.missing_secrets.is_empty()
+    }
+
+    /// `export` lines for a shell to source
+    pub fn to_env_file(&self) -> String {
+        self.vars
+            .iter()
+            .map(|var| format!("export {}={}\n", var.name, shell_quote(&var.value)))
+            .collect()
+    }
+
+    /// Prompt section telling a session how to load the variables, with
+    /// secrets masked; empty when there are none
+    pub fn prompt_note(&self, env_file: &str) -> String {
+        if self.is_empty() {
+            return String::new();
+        }
+
+        let mut note = String::from("## Environment\n\n");
+        if !self.vars.is_empty() {
+            note.push_str(&format!(
+                "Commands you run for this task (builds, tests, migrations) need these environment variables. Load them with `. {}` at the start of each shell command:\n\n",
+                shell_quote(env_file)
+            ));
+            for var in &self.vars {
+                note.push_str(&format!("- `{}={}`\n", var.name, var.display_value()));
+            }
+        }
+        if !self.missing_secrets.is_empty() {
+            note.push_str("\nNot available (their secrets are not set on the server):\n\n");
+            for (name, secret) in &self.missing_secrets {
+                note.push_str(&format!("- `{}` (secret `{}`)\n", name, secret));
+            }
+        }
+        note.trim_end().to_string()
+    }
+}
+
+/// Quote `value` for POSIX shells
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_task_overrides_project() {
+        let project = vec![
+            EnvVar::literal("FEATURE_X", "off"),
+            EnvVar::secret("DATABASE_URL", "STUDIO_TEST_DB"),
+            EnvVar::secret("API_TOKEN", "UNSET_TOKEN"),
+        ];
+        let task = vec![
+            EnvVar::literal("FEATURE_X", "on"),
+            EnvVar::literal("bad name", "x"),
+        ];
+        let lookup = |name: &str| (name == "STUDIO_TEST_DB").then(|| "postgres://it's".to_string());
+
+        let environment = TaskEnvironment::resolve(&project, &task, lookup);
+        let names: Vec<&str> = environment.vars.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(names, vec!["DATABASE_URL", "FEATURE_X"]);
+        assert_eq!(environment.vars[1].value, "on");
+        assert_eq!(environment.vars[1].source, EnvVarSource::Task);
+        assert_eq!(
+            environment.missing_secrets,
+            vec![("API_TOKEN".to_string(), "UNSET_TOKEN".to_string())]
+        );
+
+        assert_eq!(
+            environment.to_env_file(),
+            "export DATABASE_URL='postgres://it'\\''s'\nexport FEATURE_X='on'\n"
+        );
+
+        let note = environment.prompt_note("/tmp/task.env");
+        assert!(note.contains(". '/tmp/task.env'"));
+        assert!(note.contains("`DATABASE_URL=********`"));
+        assert!(note.contains("`FEATURE_X=on`"));
+        assert!(note.contains("`API_TOKEN` (secret `UNSET_TOKEN`)"));
+        assert!(!note.contains("postgres"));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(EnvVar::literal("RUST_LOG", "debug").validate().is_ok());
+        assert!(EnvVar::literal("1ABC", "x").validate().is_err());
+        assert!(EnvVar::secret("TOKEN", "not valid").validate().is_err());
+        let both = EnvVar {
+            name: "X".to_string(),
+            value: Some("a".to_string()),
+            secret: Some("B".to_string()),
+        };
+        assert!(both.validate().is_err());
+    }
+}
@@ -0,0 +1,44 @@
+//! Keeps the usage of wiki OpenRouter calls in the project database
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use db::{CreateUsageRecord, UsageRepository};
+use tracing::warn;
+use wiki::{UsageRecord, UsageScope, UsageSink, UsageTracker};
+
+/// Stores usage records in the `usage` table
+#[derive(Clone)]
+pub struct UsageStore {
+    repository: UsageRepository,
+}
+
+impl UsageStore {
+    pub fn new(repository: UsageRepository) -> Arc<Self> {
+        Arc::new(Self { repository })
+    }
+
+    /// A tracker storing the calls made for `scope`
+    pub fn tracker(self: &Arc<Self>, scope: UsageScope) -> UsageTracker {
+        UsageTracker::new(Arc::clone(self) as Arc<dyn UsageSink>, scope)
+    }
+}
+
+#[async_trait]
+impl UsageSink for UsageStore {
+    async fn record(&self, record: UsageRecord) {
+        let row = CreateUsageRecord {
+            task_id: record.scope.task_id,
+            session_id: record.scope.session_id,
+            wiki_branch: record.scope.wiki_branch,
+            model: record.model,
+            kind: record.kind.as_str().to_string(),
+            prompt_tokens: record.prompt_tokens as i64,
+            completion_tokens: record.completion_tokens as i64,
+            cost_usd: record.cost_usd,
+        };
+        if let Err(e) = self.repository.create(&row).await {
+            warn!(model = %row.model, error = %e, "Failed to record OpenRouter usage");
+        }
+    }
+}
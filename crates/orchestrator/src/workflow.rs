@@ -0,0 +1,527 @@
+//! Per-project task workflows
+//!
+//! A workflow picks which of the built-in task statuses a project uses and
+//! which transitions connect them. Projects without a workflow file get
+//! [`WorkflowDefinition::builtin`], the todo → planning → … → done pipeline.
+//!
+//! Phases still report the status they finish in (e.g. planning ends in
+//! `planning_review`). When a workflow leaves that status out, the task moves
+//! on along the built-in order to the next status the workflow has.
+//!
+//! What happens when a phase finishes is decided by the workflow's rules:
+//! each maps a phase outcome (e.g. an approved review) to the status the
+//! task moves to, and optionally a phase to queue right after.
+
+use std::collections::HashSet;
+
+use opencode_core::{SessionPhase, TaskStatus};
+use serde::{Deserialize, Serialize};
+
+use crate::state_machine::{TaskStateMachine, TASK_STATUSES};
+
+/// A transition of a workflow
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowTransition {
+    pub from: TaskStatus,
+    pub to: TaskStatus,
+    /// Start the target status' phase right after the transition
+    #[serde(default)]
+    pub run_phase: bool,
+    /// Only a person may make the transition; phases stop and wait instead
+    #[serde(default)]
+    pub requires_approval: bool,
+}
+
+/// How a phase finished
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransitionTrigger {
+    PlanningCompleted,
+    ImplementationCompleted,
+    /// Review passed without findings
+    ReviewApproved,
+    /// Review left findings for a person to triage
+    ReviewFindings,
+    /// Review asked for changes
+    ReviewRejected,
+    FixCompleted,
+}
+
+impl TransitionTrigger {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::PlanningCompleted => "planning_completed",
+            Self::ImplementationCompleted => "implementation_completed",
+            Self::ReviewApproved => "review_approved",
+            Self::ReviewFindings => "review_findings",
+            Self::ReviewRejected => "review_rejected",
+            Self::FixCompleted => "fix_completed",
+        }
+    }
+
+    pub const ALL: [TransitionTrigger; 6] = [
+        Self::PlanningCompleted,
+        Self::ImplementationCompleted,
+        Self::ReviewApproved,
+        Self::ReviewFindings,
+        Self::ReviewRejected,
+        Self::FixCompleted,
+    ];
+}
+
+/// Where a task goes when a phase finishes with `on`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransitionRule {
+    pub on: TransitionTrigger,
+    pub to: TaskStatus,
+    /// Phase to start once the task is in `to`
+    #[serde(default)]
+    pub queue_phase: Option<SessionPhase>,
+}
+
+/// Result of applying a workflow's rule to a finished phase
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleEvaluation {
+    pub trigger: TransitionTrigger,
+    /// Whether the workflow has a rule for the trigger
+    pub matched: bool,
+    pub from: TaskStatus,
+    /// Status the task ends up in; `from` when it stays
+    pub to: TaskStatus,
+    /// Phase to start next, only set when the task moved
+    pub queue_phase: Option<SessionPhase>,
+    /// Why the task stayed in `from`
+    pub reason: Option<String>,
+}
+
+impl RuleEvaluation {
+    pub fn changes_status(&self) -> bool {
+        self.from != self.to
+    }
+}
+
+/// Statuses and transitions a project's tasks move through
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowDefinition {
+    pub name: String,
+    /// Statuses in use, in workflow order; must include `todo` and `done`
+    pub states: Vec<TaskStatus>,
+    pub transitions: Vec<WorkflowTransition>,
+    /// What each phase outcome does to the task; defaults to the built-in
+    /// pipeline's behaviour
+    #[serde(default = "builtin_rules")]
+    pub rules: Vec<TransitionRule>,
+}
+
+/// The built-in pipeline's reaction to each phase outcome
+pub fn builtin_rules() -> Vec<TransitionRule> {
+    let rule = |on, to, queue_phase| TransitionRule {
+        on,
+        to,
+        queue_phase,
+    };
+    vec![
+        rule(
+            TransitionTrigger::PlanningCompleted,
+            TaskStatus::PlanningReview,
+            None,
+        ),
+        rule(
+            TransitionTrigger::ImplementationCompleted,
+            TaskStatus::AiReview,
+            None,
+        ),
+        rule(TransitionTrigger::ReviewApproved, TaskStatus::Review, None),
+        rule(TransitionTrigger::ReviewFindings, TaskStatus::Review, None),
+        rule(
+            TransitionTrigger::ReviewRejected,
+            TaskStatus::InProgress,
+            Some(SessionPhase::Fix),
+        ),
+        rule(TransitionTrigger::FixCompleted, TaskStatus::AiReview, None),
+    ]
+}
+
+impl Default for WorkflowDefinition {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl WorkflowDefinition {
+    /// The built-in pipeline, used when a project defines no workflow
+    pub fn builtin() -> Self {
+        let transitions = TASK_STATUSES
+            .iter()
+            .flat_map(|from| {
+                TaskStateMachine::allowed_transitions(from)
+                    .into_iter()
+                    .map(move |to| WorkflowTransition {
+                        from: *from,
+                        to,
+                        run_phase: matches!(
+                            to,
+                            TaskStatus::Planning | TaskStatus::InProgress | TaskStatus::AiReview
+                        ),
+                        requires_approval: false,
+                    })
+            })
+            .collect();
+
+        Self {
+            name: "default".to_string(),
+            states: TASK_STATUSES.to_vec(),
+            transitions,
+            rules: builtin_rules(),
+        }
+    }
+
+    /// Check that every status can be reached from `todo` and can reach
+    /// `done`, and that transitions only connect statuses of the workflow
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Workflow has no name".to_string());
+        }
+
+        let mut states = HashSet::new();
+        for state in &self.states {
+            if !states.insert(*state) {
+                return Err(format!("State '{}' is listed twice", state.as_str()));
+            }
+        }
+        for required in [TaskStatus::Todo, TaskStatus::Done] {
+            if !states.contains(&required) {
+                return Err(format!("Workflow must include '{}'", required.as_str()));
+            }
+        }
+
+        let mut seen = HashSet::new();
+        for t in &self.transitions {
+            let edge = format!("{} -> {}", t.from.as_str(), t.to.as_str());
+            if !states.contains(&t.from) || !states.contains(&t.to) {
+                return Err(format!(
+                    "Transition {} uses a state not in the workflow",
+                    edge
+                ));
+            }
+            if t.from == t.to {
+                return Err(format!("Transition {} does not change the state", edge));
+            }
+            if t.from == TaskStatus::Done {
+                return Err(format!("Transition {} leaves 'done'", edge));
+            }
+            if !seen.insert((t.from, t.to)) {
+                return Err(format!("Transition {} is defined twice", edge));
+            }
+        }
+
+        let mut triggers = HashSet::new();
+        for rule in &self.rules {
+            if !triggers.insert(rule.on) {
+                return Err(format!("Rule for '{}' is defined twice", rule.on.as_str()));
+            }
+        }
+
+        let reachable = self.reachable(TaskStatus::Todo, |t| (t.from, t.to));
+        if let Some(state) = self.states.iter().find(|s| !reachable.contains(s)) {
+            return Err(format!(
+                "State '{}' cannot be reached from 'todo'",
+                state.as_str()
+            ));
+        }
+
+        let finishing = self.reachable(TaskStatus::Done, |t| (t.to, t.from));
+        if let Some(state) = self.states.iter().find(|s| !finishing.contains(s)) {
+            return Err(format!("State '{}' cannot reach 'done'", state.as_str()));
+        }
+
+        Ok(())
+    }
+
+    /// States reachable from `start` following transitions in the direction
+    /// `edge` gives
+    fn reachable(
+        &self,
+        start: TaskStatus,
+        edge: impl Fn(&WorkflowTransition) -> (TaskStatus, TaskStatus),
+    ) -> HashSet<TaskStatus> {
+        let mut reached = HashSet::from([start]);
+        let mut frontier = vec![start];
+        while let Some(state) = frontier.pop() {
+            for (from, to) in self.transitions.iter().map(&edge) {
+                if from == state && reached.insert(to) {
+                    frontier.push(to);
+                }
+            }
+        }
+        reached
+    }
+
+    pub fn has_state(&self, status: &TaskStatus) -> bool {
+        self.states.contains(status)
+    }
+
+    /// Transitions leaving `from`, in definition order
+    pub fn transitions_from<'a>(
+        &'a self,
+        from: &'a TaskStatus,
+    ) -> impl Iterator<Item = &'a WorkflowTransition> + 'a {
+        self.transitions.iter().filter(move |t| &t.from == from)
+    }
+
+    pub fn transition(&self, from: &TaskStatus, to: &TaskStatus) -> Option<&WorkflowTransition> {
+        self.transitions
+            .iter()
+            .find(|t| &t.from == from && &t.to == to)
+    }
+
+    /// The status a phase reporting `to` actually moves the task to: `to`
+    /// itself, or the next status along the built-in order the workflow has
+    pub fn resolve_target(&self, to: TaskStatus) -> TaskStatus {
+        let mut target = to;
+        while !self.has_state(&target) {
+            match TaskStateMachine::next_status(&target) {
+                Some(next) => target = next,
+                None => return to,
+            }
+        }
+        target
+    }
+
+    /// The default next status, following the built-in order to the first
+    /// status the workflow can move to from `from`
+    pub fn next_status(&self, from: &TaskStatus) -> Option<TaskStatus> {
+        let mut candidate = TaskStateMachine::next_status(from);
+        while let Some(status) = candidate {
+            if self.transition(from, &status).is_some() {
+                return Some(status);
+            }
+            candidate = TaskStateMachine::next_status(&status);
+        }
+        None
+    }
+
+    /// Whether the transition from `from` to `to` waits for a person
+    pub fn requires_approval(&self, from: &TaskStatus, to: &TaskStatus) -> bool {
+        self.transition(from, to)
+            .is_some_and(|t| t.requires_approval)
+    }
+
+    pub fn rule(&self, trigger: TransitionTrigger) -> Option<&TransitionRule> {
+        self.rules.iter().find(|r| r.on == trigger)
+    }
+
+    /// Where a task in `from` goes after a phase finished with `trigger`.
+    /// The task stays put when no rule matches, the rule's target is not a
+    /// transition of the workflow, or the transition waits for approval.
+    pub fn evaluate(&self, trigger: TransitionTrigger, from: TaskStatus) -> RuleEvaluation {
+        let stay = |matched: bool, reason: String| RuleEvaluation {
+            trigger,
+            matched,
+            from,
+            to: from,
+            queue_phase: None,
+            reason: Some(reason),
+        };
+
+        let Some(rule) = self.rule(trigger) else {
+            return stay(false, format!("No rule for '{}'", trigger.as_str()));
+        };
+        let to = self.resolve_target(rule.to);
+        if to == from {
+            return RuleEvaluation {
+                trigger,
+                matched: true,
+                from,
+                to,
+                queue_phase: None,
+                reason: None,
+            };
+        }
+        if self.transition(&from, &to).is_none() {
+            return stay(
+                true,
+                format!(
+                    "'{}' -> '{}' is not a transition of the workflow",
+                    from.as_str(),
+                    to.as_str()
+                ),
+            );
+        }
+        if self.requires_approval(&from, &to) {
+            return stay(
+                true,
+                format!("'{}' -> '{}' requires approval", from.as_str(), to.as_str()),
+            );
+        }
+
+        RuleEvaluation {
+            trigger,
+            matched: true,
+            from,
+            to,
+            queue_phase: rule.queue_phase,
+            reason: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transition(from: TaskStatus, to: TaskStatus) -> WorkflowTransition {
+        WorkflowTransition {
+            from,
+            to,
+            run_phase: false,
+            requires_approval: false,
+        }
+    }
+
+    fn lightweight() -> WorkflowDefinition {
+        WorkflowDefinition {
+            name: "lightweight".to_string(),
+            states: vec![TaskStatus::Todo, TaskStatus::InProgress, TaskStatus::Done],
+            transitions: vec![
+                transition(TaskStatus::Todo, TaskStatus::InProgress),
+                transition(TaskStatus::InProgress, TaskStatus::Done),
+                transition(TaskStatus::InProgress, TaskStatus::Todo),
+            ],
+            rules: builtin_rules(),
+        }
+    }
+
+    #[test]
+    fn test_builtin_matches_state_machine() {
+        let workflow = WorkflowDefinition::builtin();
+        assert!(workflow.validate().is_ok());
+        for from in TASK_STATUSES {
+            for to in TASK_STATUSES {
+                assert_eq!(
+                    workflow.transition(&from, &to).is_some(),
+                    TaskStateMachine::can_transition(&from, &to)
+                );
+            }
+            assert_eq!(
+                workflow.next_status(&from),
+                TaskStateMachine::next_status(&from)
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_broken_workflows() {
+        let mut missing_done = lightweight();
+        missing_done.states.retain(|s| *s != TaskStatus::Done);
+        assert!(missing_done.validate().unwrap_err().contains("'done'"));
+
+        let mut unknown_state = lightweight();
+        unknown_state
+            .transitions
+            .push(transition(TaskStatus::Todo, TaskStatus::Review));
+        assert!(unknown_state
+            .validate()
+            .unwrap_err()
+            .contains("not in the workflow"));
+
+        let mut dead_end = lightweight();
+        dead_end.states.push(TaskStatus::Review);
+        dead_end
+            .transitions
+            .push(transition(TaskStatus::InProgress, TaskStatus::Review));
+        assert_eq!(
+            dead_end.validate().unwrap_err(),
+            "State 'review' cannot reach 'done'"
+        );
+
+        let mut unreachable = lightweight();
+        unreachable.states.push(TaskStatus::Review);
+        unreachable
+            .transitions
+            .push(transition(TaskStatus::Review, TaskStatus::Done));
+        assert_eq!(
+            unreachable.validate().unwrap_err(),
+            "State 'review' cannot be reached from 'todo'"
+        );
+    }
+
+    #[test]
+    fn test_resolve_target_skips_missing_states() {
+        let workflow = lightweight();
+        assert!(workflow.validate().is_ok());
+
+        // Planning would end in planning_review, which this workflow skips
+        assert_eq!(
+            workflow.resolve_target(TaskStatus::PlanningReview),
+            TaskStatus::InProgress
+        );
+        assert_eq!(
+            workflow.resolve_target(TaskStatus::AiReview),
+            TaskStatus::Done
+        );
+        assert_eq!(
+            workflow.next_status(&TaskStatus::Todo),
+            Some(TaskStatus::InProgress)
+        );
+        assert_eq!(workflow.next_status(&TaskStatus::Done), None);
+    }
+
+    #[test]
+    fn test_builtin_rules() {
+        let workflow = WorkflowDefinition::builtin();
+
+        let approved = workflow.evaluate(TransitionTrigger::ReviewApproved, TaskStatus::AiReview);
+        assert!(approved.matched);
+        assert_eq!(approved.to, TaskStatus::Review);
+        assert_eq!(approved.queue_phase, None);
+
+        let rejected = workflow.evaluate(TransitionTrigger::ReviewRejected, TaskStatus::AiReview);
+        assert_eq!(rejected.to, TaskStatus::InProgress);
+        assert_eq!(rejected.queue_phase, Some(SessionPhase::Fix));
+
+        for trigger in TransitionTrigger::ALL {
+            assert!(workflow.rule(trigger).is_some(), "{}", trigger.as_str());
+        }
+    }
+
+    #[test]
+    fn test_evaluate_custom_rules() {
+        let mut workflow = lightweight();
+        workflow.rules = vec![TransitionRule {
+            on: TransitionTrigger::ImplementationCompleted,
+            to: TaskStatus::Done,
+            queue_phase: None,
+        }];
+        assert!(workflow.validate().is_ok());
+
+        let done = workflow.evaluate(
+            TransitionTrigger::ImplementationCompleted,
+            TaskStatus::InProgress,
+        );
+        assert!(done.changes_status());
+        assert_eq!(done.to, TaskStatus::Done);
+
+        let unmatched = workflow.evaluate(TransitionTrigger::FixCompleted, TaskStatus::InProgress);
+        assert!(!unmatched.matched);
+        assert!(!unmatched.changes_status());
+
+        // Not a transition out of todo
+        let invalid =
+            workflow.evaluate(TransitionTrigger::ImplementationCompleted, TaskStatus::Todo);
+        assert_eq!(invalid.to, TaskStatus::Todo);
+        assert!(invalid.reason.unwrap().contains("not a transition"));
+
+        workflow.transitions[1].requires_approval = true;
+        let gated = workflow.evaluate(
+            TransitionTrigger::ImplementationCompleted,
+            TaskStatus::InProgress,
+        );
+        assert!(gated.matched);
+        assert_eq!(gated.to, TaskStatus::InProgress);
+        assert!(gated.reason.unwrap().contains("requires approval"));
+
+        workflow.rules.push(workflow.rules[0].clone());
+        assert!(workflow.validate().unwrap_err().contains("defined twice"));
+    }
+}
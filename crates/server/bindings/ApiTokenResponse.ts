@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ApiTokenResponse = { id: string, name: string, 
+/**
+ * `read:tasks`, `read:wiki`, `write:tasks` or `admin`
+ */
+scopes: Array<string>, created_at: bigint, last_used_at: bigint | null, revoked_at: bigint | null, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AskFeedbackRating } from "./AskFeedbackRating";
+import type { AskFeedbackSource } from "./AskFeedbackSource";
+
+export type AskFeedbackRequest = { rating: AskFeedbackRating, question: string, answer: string, sources: Array<AskFeedbackSource>, 
+/**
+ * What was wrong or right about the answer
+ */
+comment: string | null, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AskFeedbackRating } from "./AskFeedbackRating";
+import type { AskFeedbackSource } from "./AskFeedbackSource";
+
+export type AskFeedbackResponse = { id: string, conversation_id: string, rating: AskFeedbackRating, question: string, answer: string, sources: Array<AskFeedbackSource>, comment: string | null, created_at: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Code range an answer was built from, as returned in `AskResponse.sources`
+ */
+export type AskFeedbackSource = { file_path: string, start_line: number, end_line: number, };
@@ -1,3 +1,12 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AskAnswerMode } from "./AskAnswerMode";
 
-export type AskRequest = { question: string, conversation_id: string | null, };
+export type AskRequest = { question: string, conversation_id: string | null, 
+/**
+ * Shape of the answer; `standard` when left out
+ */
+mode: AskAnswerMode | null, 
+/**
+ * Only answer from code under this workspace root
+ */
+root: string | null, };
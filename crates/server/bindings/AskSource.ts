@@ -1,3 +1,11 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type AskSource = { file_path: string, start_line: number, end_line: number, score: number, snippet: string, };
+export type AskSource = { file_path: string, start_line: number, end_line: number, score: number, snippet: string, 
+/**
+ * One-line summary of the source's code, written at index time
+ */
+summary: string | null, 
+/**
+ * Set when the source was added by call-graph expansion rather than search
+ */
+expansion: string | null, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AskAnswerMode } from "./AskAnswerMode";
+import type { AskSource } from "./AskSource";
+
+/**
+ * Server-sent event of `/api/wiki/ask/stream`; the SSE event name is the
+ * `type` tag
+ */
+export type AskStreamEvent = { "type": "sources", conversation_id: string, mode: AskAnswerMode, sources: Array<AskSource>, } | { "type": "token", content: string, } | { "type": "done", answer: string, } | { "type": "error", message: string, };
@@ -1,3 +1,20 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type BranchStatus = { branch: string, state: string, file_count: number, chunk_count: number, page_count: number, last_indexed_at: string | null, progress_percent: number, error_message: string | null, current_phase: string | null, current_item: string | null, };
+export type BranchStatus = { branch: string, state: string, file_count: number, chunk_count: number, page_count: number, last_indexed_at: string | null, progress_percent: number, error_message: string | null, current_phase: string | null, current_item: string | null, 
+/**
+ * Embedding model serving searches on the branch
+ */
+embedding_model: string | null, embedding_version: number | null, 
+/**
+ * Model a re-embed is migrating the branch to
+ */
+migrating_to_model: string | null, 
+/**
+ * Commits of earlier indexes that searches can target with `as_of`,
+ * newest first
+ */
+kept_commits: Array<string>, 
+/**
+ * Indexing or generation job running on the branch
+ */
+active_job_id: string | null, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type BulkFindingResult = { finding_id: string, 
+/**
+ * `updated`, `unchanged` (already had the status) or `not_found`
+ */
+result: string, };
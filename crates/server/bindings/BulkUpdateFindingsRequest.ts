@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FindingStatus } from "./FindingStatus";
+
+export type BulkUpdateFindingsRequest = { finding_ids: Array<string>, 
+/**
+ * Target status: fixed, skipped or dismissed
+ */
+status: FindingStatus, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BulkFindingResult } from "./BulkFindingResult";
+import type { FindingStatus } from "./FindingStatus";
+
+export type BulkUpdateFindingsResponse = { status: FindingStatus, 
+/**
+ * Number of findings whose status changed
+ */
+updated: number, results: Array<BulkFindingResult>, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChunkSettingResponse } from "./ChunkSettingResponse";
+
+export type ChunkSettingScoreResponse = { setting: ChunkSettingResponse, 
+/**
+ * Chunks the sample was split into
+ */
+chunks: number, 
+/**
+ * Share of queries that found their definition among the top 5 chunks
+ */
+hit_rate: number, mean_reciprocal_rank: number, };
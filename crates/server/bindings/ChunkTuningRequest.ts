@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ChunkTuningRequest = { 
+/**
+ * Files to sample, at most 200 (default: 40)
+ */
+max_files: number | null, 
+/**
+ * Store the recommended setting for the next indexing run
+ */
+apply: boolean, };
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChunkSettingResponse } from "./ChunkSettingResponse";
+import type { ChunkSettingScoreResponse } from "./ChunkSettingScoreResponse";
+
+export type ChunkTuningResponse = { 
+/**
+ * Files sampled
+ */
+files: number, 
+/**
+ * Function definitions searched for
+ */
+queries: number, embedding_model: string, scores: Array<ChunkSettingScoreResponse>, recommended: ChunkSettingResponse, 
+/**
+ * Whether the recommended setting was stored for the next indexing run
+ */
+applied: boolean, };
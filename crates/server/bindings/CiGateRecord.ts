@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CiGateState } from "./CiGateState";
+
+/**
+ * GitHub checks a task waits on after its workspace was merged or a pull
+ * request was opened from it
+ */
+export type CiGateRecord = { task_id: string, 
+/**
+ * Branch or commit the checks run on
+ */
+ref_name: string, state: CiGateState, passed: number, failed: number, pending: number, 
+/**
+ * Names of the checks that failed
+ */
+failed_checks: Array<string>, 
+/**
+ * Move the task to done once the checks pass
+ */
+complete_on_pass: boolean, started_at: string, updated_at: string, };
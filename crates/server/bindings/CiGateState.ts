@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Where the GitHub checks gating a task's completion stand
+ */
+export type CiGateState = "pending" | "passed" | "failed" | "timed_out";
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CommitResolutionResponse = { task_id: string, 
+/**
+ * Identifier of the resolution commit (a change id for jujutsu)
+ */
+commit: string, 
+/**
+ * Whether the commit completed a merge into main that had recorded
+ * conflicts
+ */
+merged: boolean, };
@@ -2,4 +2,8 @@
 import type { MergeResultInfo } from "./MergeResultInfo";
 import type { PrInfo } from "./PrInfo";
 
-export type CompleteTaskResponse = { success: boolean, pr: PrInfo | null, merge_result: MergeResultInfo | null, worktree_cleaned: boolean, };
+export type CompleteTaskResponse = { success: boolean, pr: PrInfo | null, merge_result: MergeResultInfo | null, worktree_cleaned: boolean, 
+/**
+ * The task stays in review until the GitHub checks of its changes pass
+ */
+ci_pending: boolean, };
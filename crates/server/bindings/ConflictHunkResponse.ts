@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ConflictHunkResponse = { 
+/**
+ * 1-based line of the opening conflict marker
+ */
+start_line: number, 
+/**
+ * 1-based line of the closing conflict marker
+ */
+end_line: number, ours: string, theirs: string, 
+/**
+ * Common ancestor's lines, when the conflict markers include them
+ */
+base: string | null, };
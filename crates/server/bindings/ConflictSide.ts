@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Side of a conflict to keep. For a rebase onto main, as jujutsu merges,
+ * ours is main's and theirs the workspace's.
+ */
+export type ConflictSide = "ours" | "theirs";
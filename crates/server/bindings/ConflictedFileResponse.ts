@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ConflictHunkResponse } from "./ConflictHunkResponse";
+import type { ConflictType } from "./ConflictType";
+
+export type ConflictedFileResponse = { path: string, conflict_type: ConflictType, 
+/**
+ * Conflicted regions, in file order; empty for files without conflict
+ * markers, such as deleted or binary ones
+ */
+hunks: Array<ConflictHunkResponse>, };
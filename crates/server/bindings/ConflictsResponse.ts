@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ConflictedFileResponse } from "./ConflictedFileResponse";
+
+export type ConflictsResponse = { task_id: string, 
+/**
+ * Conflicted files; empty once every conflict is resolved
+ */
+files: Array<ConflictedFileResponse>, };
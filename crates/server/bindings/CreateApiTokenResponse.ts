@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ApiTokenResponse } from "./ApiTokenResponse";
+
+export type CreateApiTokenResponse = { 
+/**
+ * The token itself; it is only shown once
+ */
+token: string, api_token: ApiTokenResponse, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CreateSessionShareRequest = { 
+/**
+ * How long the link stays open, at most 720 hours (default: 72)
+ */
+expires_in_hours: number | null, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SessionShareResponse } from "./SessionShareResponse";
+
+export type CreateSessionShareResponse = { 
+/**
+ * The link's token; it is only shown once
+ */
+token: string, 
+/**
+ * Path of the read-only view, relative to the server
+ */
+url: string, share: SessionShareResponse, };
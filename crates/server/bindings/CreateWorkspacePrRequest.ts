@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CreateWorkspacePrRequest = { 
+/**
+ * Pull request title; the task title by default
+ */
+title: string | null, 
+/**
+ * Branch to merge into; the main branch by default
+ */
+base_branch: string | null, draft: boolean, };
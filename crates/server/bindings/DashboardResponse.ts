@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ColumnCount } from "./ColumnCount";
+import type { EventEnvelope } from "./EventEnvelope";
+import type { MonthlySpend } from "./MonthlySpend";
+import type { PendingApproval } from "./PendingApproval";
+import type { RunningExecution } from "./RunningExecution";
+import type { WikiFreshness } from "./WikiFreshness";
+
+export type DashboardResponse = { 
+/**
+ * Tasks per board column, in board order
+ */
+task_counts: Array<ColumnCount>, running_executions: Array<RunningExecution>, 
+/**
+ * Latest events, newest first; empty when event history is off
+ */
+recent_events: Array<EventEnvelope>, 
+/**
+ * Index state of the configured wiki branches; empty when the wiki is
+ * disabled
+ */
+wiki: Array<WikiFreshness>, spend: MonthlySpend, 
+/**
+ * Oldest first
+ */
+pending_approvals: Array<PendingApproval>, generated_at: string, };
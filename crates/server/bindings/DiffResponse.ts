@@ -1,3 +1,9 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ConflictedFileResponse } from "./ConflictedFileResponse";
 
-export type DiffResponse = { task_id: string, diff: string, };
+export type DiffResponse = { task_id: string, diff: string, 
+/**
+ * Files a merge left conflicted, with their conflicted regions; empty
+ * unless the workspace holds an unresolved merge
+ */
+conflicts: Array<ConflictedFileResponse>, };
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DiffFileSummary } from "./DiffFileSummary";
+import type { DiffRisk } from "./DiffRisk";
+
+export type DiffSummaryResponse = { task_id: string, 
+/**
+ * Head commit the summary describes, with a digest of the diff appended
+ * when the workspace has uncommitted changes
+ */
+head_commit: string, 
+/**
+ * Whether the summary was cached from an earlier request
+ */
+cached: boolean, 
+/**
+ * Per-file summaries, in diff order
+ */
+files: Array<DiffFileSummary>, 
+/**
+ * Changes a reviewer should look at closely
+ */
+risks: Array<DiffRisk>, created_at: string, };
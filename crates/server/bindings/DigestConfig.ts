@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Email digests of board activity, sent over SMTP
+ */
+export type DigestConfig = { 
+/**
+ * Send digests to users who scheduled them
+ */
+enabled: boolean, 
+/**
+ * SMTP server, e.g. "smtp.example.com"
+ */
+smtp_host: string | null, smtp_port: number, smtp_username: string | null, smtp_password: string | null, 
+/**
+ * Upgrade the connection with STARTTLS; off only for local relays
+ */
+smtp_starttls: boolean, 
+/**
+ * Sender address, e.g. "OpenCode Studio <studio@example.com>"
+ */
+from: string | null, 
+/**
+ * Model spend in USD per digest period the usage section is compared to
+ */
+budget_usd: number | null, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EnvVarSource } from "./EnvVarSource";
+
+export type EffectiveEnvVar = { name: string, value: string, is_secret: boolean, source: EnvVarSource, };
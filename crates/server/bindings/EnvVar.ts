@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type EnvVar = { name: string, 
+/**
+ * Literal value
+ */
+value: string | null, 
+/**
+ * Name of the server environment variable holding the value
+ */
+secret: string | null, };
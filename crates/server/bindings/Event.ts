@@ -0,0 +1,158 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AgentMessageData } from "./AgentMessageData";
+import type { ToolExecutionData } from "./ToolExecutionData";
+import type { WikiGenerationPhase } from "./WikiGenerationPhase";
+
+export type Event = { "type": "task.created", task_id: string, title: string, } | { "type": "task.updated", task_id: string, } | { "type": "task.status_changed", task_id: string, from_status: string, to_status: string, } | { "type": "findings.updated", task_id: string, finding_ids: Array<string>, 
+/**
+ * New status (fixed, skipped, dismissed)
+ */
+status: string, } | { "type": "task.transition_rule_evaluated", task_id: string, 
+/**
+ * How the phase finished (e.g. review_approved, review_rejected)
+ */
+outcome: string, 
+/**
+ * Whether the workflow has a rule for the outcome
+ */
+matched: boolean, from_status: string, 
+/**
+ * Status the task moved to; `from_status` when it stayed
+ */
+to_status: string, 
+/**
+ * Phase started after the transition
+ */
+queued_phase: string | null, 
+/**
+ * Why the task stayed where it was
+ */
+reason: string | null, } | { "type": "session.started", session_id: string, task_id: string, 
+/**
+ * Session phase (planning, implementation, review, etc.)
+ */
+phase: string, 
+/**
+ * Session status (pending, running, completed, failed)
+ */
+status: string, 
+/**
+ * OpenCode session ID (when connected)
+ */
+opencode_session_id: string | null, 
+/**
+ * When the session was created
+ */
+created_at: string, } | { "type": "session.ended", session_id: string, task_id: string, success: boolean, } | { "type": "phase.completed", task_id: string, session_id: string, 
+/**
+ * The phase number that was completed (1-indexed)
+ */
+phase_number: number, 
+/**
+ * Total number of phases
+ */
+total_phases: number, 
+/**
+ * Title of the completed phase
+ */
+phase_title: string, } | { "type": "phase.continuing", task_id: string, 
+/**
+ * The next phase number (1-indexed)
+ */
+next_phase_number: number, 
+/**
+ * Total number of phases
+ */
+total_phases: number, } | { "type": "phase.progress", task_id: string, 
+/**
+ * Session doing the work, once it was created
+ */
+session_id: string | null, 
+/**
+ * Session phase (planning, review)
+ */
+phase: string, 
+/**
+ * Step within the phase (analysis, drafting, finalizing, reviewing_files)
+ */
+step: string, 
+/**
+ * Position of the step (1-based), or files reviewed
+ */
+current: number, 
+/**
+ * Number of steps, or files to review
+ */
+total: number, 
+/**
+ * Findings created so far (review only)
+ */
+findings_count: number | null, message: string | null, } | { "type": "agent.message", session_id: string, task_id: string, message: AgentMessageData, } | { "type": "tool.execution", session_id: string, task_id: string, tool: ToolExecutionData, } | { "type": "workspace.created", task_id: string, path: string, } | { "type": "workspace.merged", task_id: string, success: boolean, } | { "type": "workspace.deleted", task_id: string, } | { "type": "workspace.path_violation", task_id: string, session_id: string, 
+/**
+ * Tool that wrote, or asked to write, the file
+ */
+tool: string, path: string, 
+/**
+ * Whether the write was denied before it happened
+ */
+blocked: boolean, } | { "type": "ci.status_changed", task_id: string, 
+/**
+ * Branch or commit the checks ran on
+ */
+ref_name: string, 
+/**
+ * Gate state (pending, passed, failed, timed_out)
+ */
+state: string, passed: number, failed: number, pending: number, } | { "type": "project.opened", path: string, name: string, was_initialized: boolean, } | { "type": "project.closed", path: string, } | { "type": "wiki.generation_progress", branch: string, phase: WikiGenerationPhase, current: number, total: number, current_item: string | null, message: string | null, } | { "type": "wiki.index_refreshed", branch: string, 
+/**
+ * Commit the index was built from
+ */
+commit_sha: string | null, chunk_count: number, } | { "type": "wiki.docs_refreshed", task_id: string, branch: string, 
+/**
+ * Pages updated or suggested for update
+ */
+pages: number, 
+/**
+ * `suggested` or `regenerated`
+ */
+action: string, } | { "type": "roadmap.generation_started" } | { "type": "roadmap.generation_progress", 
+/**
+ * Current phase (analyzing, discovering, generating, complete, error)
+ */
+phase: string, 
+/**
+ * Progress percentage (0-100)
+ */
+progress: number, 
+/**
+ * Status message
+ */
+message: string, } | { "type": "roadmap.generation_completed", 
+/**
+ * Number of features generated
+ */
+feature_count: number, 
+/**
+ * Number of phases generated
+ */
+phase_count: number, } | { "type": "roadmap.generation_failed", 
+/**
+ * Error message
+ */
+error: string, } | { "type": "roadmap.feature_updated", 
+/**
+ * Feature ID
+ */
+feature_id: string, 
+/**
+ * New status (if changed)
+ */
+status: string | null, } | { "type": "roadmap.feature_converted", 
+/**
+ * Feature ID
+ */
+feature_id: string, 
+/**
+ * Created task ID
+ */
+task_id: string, } | { "type": "error", message: string, context: string | null, };
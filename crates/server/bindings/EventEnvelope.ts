@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Event } from "./Event";
+
+export type EventEnvelope = { 
+/**
+ * Unique event ID
+ */
+id: string, 
+/**
+ * Version of the event schema the envelope follows
+ */
+schema_version: number, 
+/**
+ * When the event occurred
+ */
+timestamp: string, 
+/**
+ * The actual event
+ */
+event: Event, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Event history configuration
+ */
+export type EventsConfig = { 
+/**
+ * Store published events so they can be queried and replayed later
+ */
+persist: boolean, 
+/**
+ * Days stored events are kept for
+ */
+retention_days: number, };
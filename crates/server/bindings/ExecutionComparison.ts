@@ -0,0 +1,28 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ReviewFinding } from "./ReviewFinding";
+import type { TaskExecution } from "./TaskExecution";
+
+/**
+ * Differences between two runs of a task
+ */
+export type ExecutionComparison = { from: TaskExecution, to: TaskExecution, 
+/**
+ * Findings of `from` that `to` no longer reports
+ */
+resolved_findings: Array<ReviewFinding>, 
+/**
+ * Findings `to` reports that `from` did not
+ */
+new_findings: Array<ReviewFinding>, 
+/**
+ * Findings both runs report, as `to` reported them
+ */
+persisting_findings: Array<ReviewFinding>, 
+/**
+ * Files only `to` changed
+ */
+added_files: Array<string>, 
+/**
+ * Files only `from` changed
+ */
+removed_files: Array<string>, };
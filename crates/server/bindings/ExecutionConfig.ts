@@ -0,0 +1,58 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ReviewPolicy } from "./ReviewPolicy";
+
+/**
+ * Session execution configuration
+ */
+export type ExecutionConfig = { 
+/**
+ * How many times a failed phase session can be retried
+ */
+max_session_retries: number, 
+/**
+ * Re-rate review finding severities against a rubric and the project guidelines
+ */
+calibrate_review_severity: boolean, 
+/**
+ * Raise the severity of review findings on code the wiki index tagged
+ * as public API: HTTP handlers, exported items and CLI commands
+ */
+escalate_public_api_findings: boolean, 
+/**
+ * Reviews run per review round; findings need agreement across them.
+ * Each extra sample costs about one more review prompt of tokens
+ */
+review_samples: number, 
+/**
+ * Review samples that must report a finding for it to be kept
+ */
+review_min_agreement: number, 
+/**
+ * Give each extra review sample a different focus, e.g. security
+ */
+review_personas: boolean, 
+/**
+ * Which review findings send a task to the fix phase
+ */
+review_policy: ReviewPolicy, 
+/**
+ * Language review summaries and findings are written in, e.g. `de` or
+ * `Japanese`; English when unset
+ */
+review_language: string | null, 
+/**
+ * Keep tasks from done until the GitHub checks of their merged or
+ * pushed changes pass; needs a GitHub token
+ */
+ci_gate: boolean, 
+/**
+ * Token budget for the files a plan cites, attached to implementation
+ * prompts so the model doesn't have to look for them; 0 attaches none
+ */
+context_file_tokens: number, 
+/**
+ * Paths outside the workspace that implementation and fix sessions may
+ * write, absolute or relative to the workspace; any other write outside
+ * it is denied and reported as a finding
+ */
+shared_paths: Array<string>, };
@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SessionPhase } from "./SessionPhase";
+import type { SessionStatus } from "./SessionStatus";
+
+/**
+ * Sessions of one phase within a run
+ */
+export type ExecutionPhase = { phase: SessionPhase, 
+/**
+ * Sessions that ran the phase, including retries and review rounds
+ */
+sessions: number, 
+/**
+ * Status of the phase's latest session
+ */
+status: SessionStatus, 
+/**
+ * Time spent in the phase's finished sessions
+ */
+duration_secs: bigint, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FindingCommentAuthor } from "./FindingCommentAuthor";
+
+/**
+ * A comment in the discussion of a finding
+ */
+export type FindingComment = { id: string, author: FindingCommentAuthor, body: string, created_at: string, };
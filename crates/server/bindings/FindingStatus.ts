@@ -3,4 +3,4 @@
 /**
  * Status of a finding
  */
-export type FindingStatus = "pending" | "fixed" | "skipped";
+export type FindingStatus = "pending" | "fixed" | "skipped" | "dismissed";
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A wiki page documenting flagged sources; a candidate for regeneration
+ */
+export type FlaggedPageResponse = { slug: string, title: string, 
+/**
+ * Flagged files the page documents or cites
+ */
+files: Array<string>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A code range mostly seen in rated-down answers; a candidate for re-chunking
+ */
+export type FlaggedSourceResponse = { file_path: string, start_line: number, end_line: number, positive: number, negative: number, };
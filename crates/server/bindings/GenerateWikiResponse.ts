@@ -1,3 +1,8 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type GenerateWikiResponse = { started: boolean, branch: string, message: string, };
+export type GenerateWikiResponse = { started: boolean, branch: string, message: string, 
+/**
+ * Job generating the branch: the one started, or when `started` is false
+ * the one already running, whose progress events to follow instead
+ */
+job_id: string | null, };
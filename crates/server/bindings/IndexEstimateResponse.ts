@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type IndexEstimateResponse = { files: number, chunks: number, tokens: bigint, 
+/**
+ * Embedding requests the chunks are sent in
+ */
+batches: number, embedding_model: string, 
+/**
+ * USD per million input tokens, unset when the model's price is unknown
+ */
+price_per_million_tokens: number | null, estimated_cost_usd: number | null, estimated_seconds: bigint, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type JobLogLine = { 
+/**
+ * Position of the line in the job's log, from 0
+ */
+seq: bigint, timestamp: string, level: string, 
+/**
+ * Module the line was logged from
+ */
+target: string, message: string, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JobLogLine } from "./JobLogLine";
+
+export type JobLogsResponse = { job_id: string, 
+/**
+ * Whether the job has finished; its log is complete if so
+ */
+finished: boolean, lines: Array<JobLogLine>, };
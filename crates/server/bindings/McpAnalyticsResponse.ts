@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { McpToolCallResponse } from "./McpToolCallResponse";
+import type { McpToolStats } from "./McpToolStats";
+
+export type McpAnalyticsResponse = { total_calls: bigint, total_errors: bigint, 
+/**
+ * Error rate at which tools are flagged
+ */
+error_rate_threshold: number, 
+/**
+ * Flagged tools first, then most called
+ */
+tools: Array<McpToolStats>, 
+/**
+ * Latest failed calls, newest first
+ */
+recent_errors: Array<McpToolCallResponse>, };
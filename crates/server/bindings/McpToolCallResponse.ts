@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type McpToolCallResponse = { server: string, tool: string, session_id: string | null, called_at: string, duration_ms: bigint, error: string | null, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Calls of one tool of one MCP server
+ */
+export type McpToolStats = { server: string, tool: string, calls: bigint, errors: bigint, 
+/**
+ * Share of failed calls, from 0 to 1
+ */
+error_rate: number, avg_duration_ms: bigint, p95_duration_ms: bigint, 
+/**
+ * Sessions that called the tool
+ */
+sessions: bigint, last_called_at: string, last_error: string | null, 
+/**
+ * Whether the tool fails often enough to look into
+ */
+flagged: boolean, };
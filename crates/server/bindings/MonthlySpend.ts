@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Model spend since the start of the month
+ */
+export type MonthlySpend = { period_start: string, tokens: bigint, 
+/**
+ * Cost in USD as reported by OpenCode
+ */
+cost: number, 
+/**
+ * Sessions OpenCode could not report usage for
+ */
+unreported_sessions: number, 
+/**
+ * Monthly budget from the digest settings
+ */
+budget_usd: number | null, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TaskStatus } from "./TaskStatus";
+
+/**
+ * A task waiting for someone to approve its plan or implementation
+ */
+export type PendingApproval = { task_id: string, title: string, status: TaskStatus, 
+/**
+ * When the task started waiting
+ */
+since: string, };
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * MCP tools each phase may call; phases left out use the built-in profile
+ */
+export type PhaseMcpTools = { 
+/**
+ * Tools for planning phase
+ */
+planning: Array<string> | null, 
+/**
+ * Tools for implementation phase
+ */
+implementation: Array<string> | null, 
+/**
+ * Tools for review phase
+ */
+review: Array<string> | null, 
+/**
+ * Tools for fix phase
+ */
+fix: Array<string> | null, };
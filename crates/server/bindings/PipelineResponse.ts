@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PipelineStage } from "./PipelineStage";
+import type { TaskStatus } from "./TaskStatus";
+
+export type PipelineResponse = { task_id: string, task_status: TaskStatus, stages: Array<PipelineStage>, };
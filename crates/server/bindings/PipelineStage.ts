@@ -0,0 +1,39 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PipelineStageStatus } from "./PipelineStageStatus";
+import type { SessionPhase } from "./SessionPhase";
+import type { TokenUsage } from "./TokenUsage";
+
+export type PipelineStage = { phase: SessionPhase, status: PipelineStageStatus, 
+/**
+ * When the first session of the phase started
+ */
+started_at: string | null, 
+/**
+ * When the latest session of the phase finished
+ */
+completed_at: string | null, 
+/**
+ * Sessions that ran the phase, oldest first
+ */
+session_ids: Array<string>, 
+/**
+ * `None` when OpenCode could not report usage for any session
+ */
+tokens: TokenUsage | null, 
+/**
+ * Whether a human has to approve the phase before the task moves on
+ */
+requires_approval: boolean, 
+/**
+ * Most review/fix rounds before the task goes to human review
+ */
+max_iterations: number | null, 
+/**
+ * Reviews run per review round, when review consensus is on
+ */
+review_samples: number | null, 
+/**
+ * Extra tokens the consensus samples of the next review round are
+ * expected to cost, going by the latest review session
+ */
+estimated_consensus_tokens: bigint | null, };
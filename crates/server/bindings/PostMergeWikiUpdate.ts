@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What happens to stale wiki pages after a task is merged
+ */
+export type PostMergeWikiUpdate = "off" | "suggest" | "regenerate";
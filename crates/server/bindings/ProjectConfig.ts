@@ -1,4 +1,9 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DigestConfig } from "./DigestConfig";
+import type { EnvVar } from "./EnvVar";
+import type { EventsConfig } from "./EventsConfig";
+import type { ExecutionConfig } from "./ExecutionConfig";
+import type { PhaseMcpTools } from "./PhaseMcpTools";
 import type { PhaseModels } from "./PhaseModels";
 import type { RoadmapConfig } from "./RoadmapConfig";
 import type { UserMode } from "./UserMode";
@@ -7,4 +12,8 @@ import type { WikiConfig } from "./WikiConfig";
 /**
  * Project-level configuration stored in .opencode-studio/config.json
  */
-export type ProjectConfig = { phase_models: PhaseModels, user_mode: UserMode, wiki: WikiConfig, roadmap: RoadmapConfig, };
+export type ProjectConfig = { phase_models: PhaseModels, user_mode: UserMode, wiki: WikiConfig, roadmap: RoadmapConfig, execution: ExecutionConfig, events: EventsConfig, digest: DigestConfig, mcp_tools: PhaseMcpTools, 
+/**
+ * Environment variables for every task's sessions
+ */
+environment: Array<EnvVar>, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ReembedRequest = { branch: string | null, 
+/**
+ * Target embedding model, defaults to the configured one
+ */
+model: string | null, 
+/**
+ * Stop after embedding this many chunks; a later request resumes
+ */
+max_chunks: number | null, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ConflictSide } from "./ConflictSide";
+
+export type ResolveConflictRequest = { 
+/**
+ * Conflicted file, relative to the workspace root
+ */
+path: string, 
+/**
+ * Side whose version of the file to keep
+ */
+side: ConflictSide, };
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Session } from "./Session";
+import type { Task } from "./Task";
+
+export type RetrySessionResponse = { task: Task, 
+/**
+ * The new session, linked to the failed one through `retry_of`
+ */
+session: Session, 
+/**
+ * Retry number of the new session, starting at 1
+ */
+attempt: number, max_retries: number, };
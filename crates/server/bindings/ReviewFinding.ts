@@ -1,8 +1,41 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FindingComment } from "./FindingComment";
 import type { FindingSeverity } from "./FindingSeverity";
 import type { FindingStatus } from "./FindingStatus";
 
 /**
  * A single review finding
  */
-export type ReviewFinding = { id: string, file_path: string | null, line_start: number | null, line_end: number | null, title: string, description: string, severity: FindingSeverity, status: FindingStatus, };
+export type ReviewFinding = { id: string, file_path: string | null, line_start: number | null, line_end: number | null, title: string, description: string, severity: FindingSeverity, status: FindingStatus, 
+/**
+ * Severity the reviewer reported, when calibration changed it
+ */
+original_severity: FindingSeverity | null, 
+/**
+ * Why calibration changed the severity
+ */
+severity_rationale: string | null, 
+/**
+ * Steps or input that show the problem
+ */
+reproduction: string | null, 
+/**
+ * What must hold once the finding is fixed; required for errors
+ */
+acceptance_criteria: string | null, 
+/**
+ * Fix the reviewer proposes, as a unified diff against the workspace
+ */
+suggested_fix: string | null, 
+/**
+ * Share of review samples that reported the finding, with consensus on
+ */
+consensus_score: number | null, 
+/**
+ * Discussion between the human reviewer and the agents, oldest first
+ */
+comments: Array<FindingComment>, 
+/**
+ * The lines the finding points at, as they read when it was created
+ */
+snippet: string | null, };
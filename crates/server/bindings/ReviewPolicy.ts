@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FindingSeverity } from "./FindingSeverity";
+
+/**
+ * Review gating policy
+ */
+export type ReviewPolicy = { 
+/**
+ * Severities of which a single pending finding blocks the review
+ */
+block_on: Array<FindingSeverity>, 
+/**
+ * Pending warnings tolerated when warnings don't block on their own;
+ * one more blocks the review
+ */
+max_warnings: number | null, };
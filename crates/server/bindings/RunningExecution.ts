@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SessionPhase } from "./SessionPhase";
+import type { SessionStatus } from "./SessionStatus";
+
+/**
+ * A session that is running or about to
+ */
+export type RunningExecution = { session_id: string, task_id: string, task_title: string, phase: SessionPhase, status: SessionStatus, started_at: string | null, };
@@ -1,3 +1,30 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type SearchRequest = { query: string, limit: number | null, };
+export type SearchRequest = { query: string, limit: number | null, 
+/**
+ * Most results kept from one file
+ */
+max_per_file: number | null, 
+/**
+ * Drop results whose lines overlap a better result of the same file by
+ * at least this share (0.0-1.0) of the shorter range
+ */
+dedup_overlap: number | null, 
+/**
+ * Scale scores so the best result scores 1.0
+ */
+normalize_scores: boolean | null, 
+/**
+ * Only search code under this workspace root
+ */
+root: string | null, 
+/**
+ * Keep only code tagged as public API: HTTP handlers, exported items
+ * and CLI commands
+ */
+public_api_only: boolean | null, 
+/**
+ * Search the index as of this commit (a SHA or a prefix of at least
+ * four hex digits) instead of the current one
+ */
+as_of: string | null, };
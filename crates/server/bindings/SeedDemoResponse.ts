@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SeedDemoResponse = { 
+/**
+ * Path of the demo project, which is now the open project
+ */
+project_path: string, tasks: number, sessions: number, findings: number, wiki_pages: number, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SemanticSearchResult = { id: string, 
+/**
+ * `task`, `finding` or `session`
+ */
+scope: string, task_id: string, title: string, snippet: string, score: number, };
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SessionActivityMsg = { "type": "tool_call", id: string, tool_name: string, args: Record<string, unknown> | null, timestamp: string, } | { "type": "tool_result", id: string, tool_name: string, args: Record<string, unknown> | null, result: string, success: boolean, timestamp: string, } | { "type": "agent_message", id: string, content: string, is_partial: boolean, timestamp: string, } | { "type": "reasoning", id: string, content: string, timestamp: string, } | { "type": "step_start", id: string, step_name: string | null, timestamp: string, } | { "type": "json_patch", patch: unknown[], timestamp: string, } | { "type": "finished", success: boolean, error: string | null, timestamp: string, };
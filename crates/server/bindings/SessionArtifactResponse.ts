@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SessionArtifactResponse = { id: string, session_id: string, task_id: string, 
+/**
+ * `plan`, `review`, `findings`, `phase_summary` or `diff`
+ */
+kind: string, name: string, 
+/**
+ * SHA-256 of the content
+ */
+content_hash: string, size_bytes: bigint, media_type: string, created_at: bigint, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SessionShareResponse = { id: string, session_id: string, created_at: bigint, expires_at: bigint, revoked_at: bigint | null, 
+/**
+ * Whether the link still opens the session
+ */
+active: boolean, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { UsageTotals } from "./UsageTotals";
+
+export type SessionUsageTotals = { 
+/**
+ * `None` for calls made for the task outside its sessions
+ */
+session_id: string | null, usage: UsageTotals, };
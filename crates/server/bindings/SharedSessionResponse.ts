@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Session } from "./Session";
+import type { SessionActivityMsg } from "./SessionActivityMsg";
+
+/**
+ * What a share link shows
+ */
+export type SharedSessionResponse = { session: Session, task_title: string, 
+/**
+ * Messages, reasoning and tool calls of the session, oldest first
+ */
+transcript: Array<SessionActivityMsg>, 
+/**
+ * Diff the session produced, if it recorded one
+ */
+diff: string | null, expires_at: bigint, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A planned page left out by the generation limits or that failed to generate
+ */
+export type SkippedWikiPageResponse = { id: string, title: string, section_id: string, importance: string, file_paths: Array<string>, description: string, reason: string, error: string | null, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type StartReleaseReviewRequest = { 
+/**
+ * Review the tasks merged since this tag (default: the latest tag)
+ */
+since_tag: string | null, 
+/**
+ * Review these merged tasks instead of those since a tag
+ */
+task_ids: Array<string> | null, };
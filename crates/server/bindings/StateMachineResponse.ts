@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { StateMachineStatus } from "./StateMachineStatus";
+import type { StateMachineTransition } from "./StateMachineTransition";
+import type { TaskStatus } from "./TaskStatus";
+
+export type StateMachineResponse = { 
+/**
+ * Name of the project's workflow, `default` for the built-in one
+ */
+workflow: string, initial_status: TaskStatus, 
+/**
+ * Statuses in workflow order
+ */
+statuses: Array<StateMachineStatus>, transitions: Array<StateMachineTransition>, };
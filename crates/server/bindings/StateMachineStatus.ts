@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TaskStatus } from "./TaskStatus";
+
+export type StateMachineStatus = { status: TaskStatus, next_status: TaskStatus | null, previous_status: TaskStatus | null, 
+/**
+ * No transitions leave the status
+ */
+terminal: boolean, };
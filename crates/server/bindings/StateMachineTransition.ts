@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { StateMachineGuard } from "./StateMachineGuard";
+import type { TaskStatus } from "./TaskStatus";
+
+export type StateMachineTransition = { from: TaskStatus, to: TaskStatus, 
+/**
+ * Conditions checked before the transition is made
+ */
+guards: Array<StateMachineGuard>, 
+/**
+ * The workflow starts the target status' phase after the transition
+ */
+run_phase: boolean, 
+/**
+ * Phases stop before this transition and wait for a person to make it
+ */
+requires_approval: boolean, };
@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Task } from "./Task";
+
+export type TaskDependenciesResponse = { 
+/**
+ * The task, with its status after blocking or unblocking
+ */
+task: Task, 
+/**
+ * Tasks the task depends on
+ */
+depends_on: Array<Task>, 
+/**
+ * Tasks that depend on the task
+ */
+dependents: Array<Task>, 
+/**
+ * Dependencies that are not done yet
+ */
+unfinished: Array<string>, };
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EffectiveEnvVar } from "./EffectiveEnvVar";
+import type { EnvVar } from "./EnvVar";
+
+export type TaskEnvResponse = { 
+/**
+ * The task's own variables, as defined
+ */
+vars: Array<EnvVar>, 
+/**
+ * Project and task variables combined, as sessions get them
+ */
+effective: Array<EffectiveEnvVar>, 
+/**
+ * Variables left out because their secret is not set on the server
+ */
+missing_secrets: Array<string>, };
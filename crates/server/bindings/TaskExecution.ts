@@ -0,0 +1,41 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ExecutionOutcome } from "./ExecutionOutcome";
+import type { ExecutionPhase } from "./ExecutionPhase";
+import type { TokenUsage } from "./TokenUsage";
+
+/**
+ * One run of a task
+ */
+export type TaskExecution = { 
+/**
+ * Run number, starting at 1 for the oldest run
+ */
+number: number, started_at: string, 
+/**
+ * `None` while the run is still going
+ */
+completed_at: string | null, duration_secs: bigint | null, 
+/**
+ * Phases in the order the run first reached them
+ */
+phases: Array<ExecutionPhase>, 
+/**
+ * `provider/model` of every model the run used
+ */
+models: Array<string>, 
+/**
+ * `None` when OpenCode could not report usage for any session
+ */
+tokens: TokenUsage | null, 
+/**
+ * Cost in USD reported by OpenCode
+ */
+cost: number | null, 
+/**
+ * Findings of the run's last review, `None` without a review
+ */
+findings_count: number | null, 
+/**
+ * Files changed when the run's last review started
+ */
+changed_files: Array<string>, outcome: ExecutionOutcome, session_ids: Array<string>, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TaskExecution } from "./TaskExecution";
+
+export type TaskExecutionsResponse = { task_id: string, 
+/**
+ * Oldest run first
+ */
+executions: Array<TaskExecution>, };
@@ -1,3 +1,3 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type TaskStatus = "todo" | "planning" | "planning_review" | "in_progress" | "ai_review" | "fix" | "review" | "done";
+export type TaskStatus = "todo" | "blocked" | "planning" | "planning_review" | "in_progress" | "ai_review" | "fix" | "review" | "done";
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PhaseModels } from "./PhaseModels";
+
+export type TaskTemplateRequest = { name: string, 
+/**
+ * Title of created tasks; may hold `{{name}}` placeholders
+ */
+title: string, description: string, 
+/**
+ * Appended to the description of created tasks
+ */
+acceptance_criteria: string, phase_models: PhaseModels, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PhaseModels } from "./PhaseModels";
+
+export type TaskTemplateResponse = { id: string, name: string, title: string, description: string, acceptance_criteria: string, 
+/**
+ * Models the phases of created tasks use instead of the project's
+ */
+phase_models: PhaseModels, 
+/**
+ * Placeholders a task created from the template needs values for
+ */
+placeholders: Array<string>, created_at: bigint, updated_at: bigint, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TaskStatus } from "./TaskStatus";
+
+export type TaskTransition = { to: TaskStatus, allowed: boolean, 
+/**
+ * Why the transition is blocked right now
+ */
+blocked_reason: string | null, 
+/**
+ * The workflow starts the target status' phase after the transition
+ */
+run_phase: boolean, 
+/**
+ * Phases stop before this transition and wait for a person to make it
+ */
+requires_approval: boolean, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TaskStatus } from "./TaskStatus";
+import type { TaskTransition } from "./TaskTransition";
+
+export type TaskTransitionsResponse = { status: TaskStatus, 
+/**
+ * Status the workflow moves to next by default, if any
+ */
+next_status: TaskStatus | null, transitions: Array<TaskTransition>, };
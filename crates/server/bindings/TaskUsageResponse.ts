@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ModelUsageTotals } from "./ModelUsageTotals";
+import type { SessionUsageTotals } from "./SessionUsageTotals";
+import type { UsageTotals } from "./UsageTotals";
+
+export type TaskUsageResponse = { task_id: string, total: UsageTotals, by_session: Array<SessionUsageTotals>, by_model: Array<ModelUsageTotals>, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type TaskWikiUpdateResponse = { id: string, task_id: string, branch: string, page_slug: string, page_title: string, 
+/**
+ * `suggested` or `regenerated`
+ */
+action: string, changed_files: Array<string>, 
+/**
+ * Edit suggestions, or the rewritten page content
+ */
+content: string, created_at: bigint, };
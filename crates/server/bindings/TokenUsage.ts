@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type TokenUsage = { input: bigint, output: bigint, reasoning: bigint, cache_read: bigint, cache_write: bigint, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ToolExecutionData = { 
+/**
+ * Tool name
+ */
+name: string, 
+/**
+ * Tool input (JSON string or summary)
+ */
+input: string | null, 
+/**
+ * Tool output (truncated if large)
+ */
+output: string | null, 
+/**
+ * Whether the tool succeeded
+ */
+success: boolean, };
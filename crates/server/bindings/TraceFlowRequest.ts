@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type TraceFlowRequest = { 
+/**
+ * HTTP method of the request to trace, e.g. `POST`
+ */
+method: string, 
+/**
+ * Request path, e.g. `/api/tasks/{id}/execute`
+ */
+path: string, branch: string | null, 
+/**
+ * Save the flow document as a wiki page
+ */
+persist: boolean, };
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WikiEndpoint } from "./WikiEndpoint";
+import type { WikiFlowStep } from "./WikiFlowStep";
+
+export type TraceFlowResponse = { branch: string, route: WikiEndpoint, steps: Array<WikiFlowStep>, 
+/**
+ * Markdown flow document
+ */
+content: string, 
+/**
+ * Slug of the saved wiki page, when `persist` was set
+ */
+page_slug: string | null, };
@@ -2,4 +2,8 @@
 import type { Task } from "./Task";
 import type { TaskStatus } from "./TaskStatus";
 
-export type TransitionResponse = { task: Task, previous_status: TaskStatus, };
+export type TransitionResponse = { task: Task, previous_status: TaskStatus, 
+/**
+ * The workflow starts the new status' phase after this transition
+ */
+run_phase: boolean, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EnvVar } from "./EnvVar";
+
+export type UpdateTaskEnvRequest = { 
+/**
+ * Replaces the task's variables
+ */
+vars: Array<EnvVar>, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UpdateUserPreferencesRequest = { board_filters: { [key in string]?: string } | null, notification_channels: Array<string> | null, event_subscriptions: Array<string> | null, 
+/**
+ * Empty to fall back to the project's chat model
+ */
+ask_model: string | null, 
+/**
+ * Empty to stop sending digests anywhere
+ */
+digest_email: string | null, 
+/**
+ * `off`, `daily` or `weekly`
+ */
+digest_schedule: string | null, };
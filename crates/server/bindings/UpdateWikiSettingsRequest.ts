@@ -1,3 +1,22 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PostMergeWikiUpdate } from "./PostMergeWikiUpdate";
+import type { WikiEmbeddingProvider } from "./WikiEmbeddingProvider";
+import type { WikiPageImportance } from "./WikiPageImportance";
 
-export type UpdateWikiSettingsRequest = { enabled: boolean | null, branches: Array<string> | null, openrouter_api_key: string | null, embedding_model: string | null, chat_model: string | null, auto_sync: boolean | null, repo_url: string | null, access_token: string | null, };
+export type UpdateWikiSettingsRequest = { enabled: boolean | null, branches: Array<string> | null, openrouter_api_key: string | null, embedding_model: string | null, chat_model: string | null, auto_sync: boolean | null, repo_url: string | null, access_token: string | null, post_merge_update: PostMergeWikiUpdate | null, review_context: boolean | null, review_context_max_tokens: number | null, planning_context: boolean | null, planning_context_max_tokens: number | null, max_pages: number | null, max_pages_per_section: number | null, min_page_importance: WikiPageImportance | null, page_concurrency: number | null, embedding_concurrency: number | null, chunk_summaries: boolean | null, roots: Array<string> | null, embedding_provider: WikiEmbeddingProvider | null, 
+/**
+ * Empty clears the key
+ */
+openai_api_key: string | null, 
+/**
+ * Empty clears the path
+ */
+local_embedding_model_path: string | null, 
+/**
+ * Zero clears the dimension
+ */
+embedding_dimension: number | null, 
+/**
+ * Earlier indexes kept per branch; zero keeps none
+ */
+history_generations: number | null, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BranchUsageTotals } from "./BranchUsageTotals";
+import type { ModelUsageTotals } from "./ModelUsageTotals";
+import type { TaskUsageTotals } from "./TaskUsageTotals";
+import type { UsageTotals } from "./UsageTotals";
+
+export type UsageReportResponse = { total: UsageTotals, by_model: Array<ModelUsageTotals>, 
+/**
+ * Calls made for tasks, by task
+ */
+by_task: Array<TaskUsageTotals>, 
+/**
+ * Calls made for the wiki, by branch; task calls that read the wiki
+ * count here too
+ */
+by_wiki_branch: Array<BranchUsageTotals>, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Usage summed over a set of calls
+ */
+export type UsageTotals = { requests: bigint, prompt_tokens: bigint, completion_tokens: bigint, 
+/**
+ * Estimated cost in USD of the calls whose model price is known;
+ * `None` when no price was known
+ */
+cost_usd: number | null, };
@@ -0,0 +1,32 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserPreferencesResponse = { user_id: string, 
+/**
+ * Board filters restored when the board is opened
+ */
+board_filters: { [key in string]?: string }, 
+/**
+ * Channels notifications are delivered to (`in_app`)
+ */
+notification_channels: Array<string>, 
+/**
+ * Event types the UI shows notifications for, e.g. `session.ended` or
+ * `task.*`; empty means every event. Events are streamed regardless.
+ */
+event_subscriptions: Array<string>, 
+/**
+ * Chat model for wiki questions, overriding the project's
+ */
+ask_model: string | null, 
+/**
+ * Address email digests are sent to
+ */
+digest_email: string | null, 
+/**
+ * How often digests are sent: `off`, `daily` or `weekly`
+ */
+digest_schedule: string, 
+/**
+ * Unix timestamp of the last digest sent
+ */
+digest_last_sent_at: bigint | null, updated_at: bigint, };
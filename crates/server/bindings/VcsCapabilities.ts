@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Operations a VCS backend supports
+ */
+export type VcsCapabilities = { 
+/**
+ * Workspaces are worktrees on their own branch rather than plain copies
+ */
+branch_workspaces: boolean, 
+/**
+ * Changes can be committed to history
+ */
+commit: boolean, 
+/**
+ * Branches can be pushed to a remote, which pull requests need
+ */
+push: boolean, 
+/**
+ * Workspace changes can be merged back into the project
+ */
+merge: boolean, 
+/**
+ * Merges record conflicts in the merge commit, to be resolved in the
+ * workspace, rather than failing
+ */
+conflict_commits: boolean, };
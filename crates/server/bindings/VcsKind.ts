@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Version control system a project directory uses
+ */
+export type VcsKind = "jujutsu" | "git" | "none";
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WebhookPullRequest } from "./WebhookPullRequest";
+
+/**
+ * The parts of a GitHub `pull_request` webhook payload the wiki reads
+ */
+export type WebhookPullRequestEvent = { action: string, pull_request: WebhookPullRequest, };
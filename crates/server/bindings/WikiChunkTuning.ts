@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Chunk settings a tuning pass measured as retrieving best
+ */
+export type WikiChunkTuning = { max_chunk_tokens: number, chunk_overlap: number, 
+/**
+ * Mean reciprocal rank the setting scored
+ */
+mean_reciprocal_rank: number, 
+/**
+ * Embedding model the setting was tuned with
+ */
+embedding_model: string, tuned_at: bigint, };
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WikiComparedPage } from "./WikiComparedPage";
+import type { WikiPageChange } from "./WikiPageChange";
+
+export type WikiCompareResponse = { base: string, head: string, 
+/**
+ * Pages only in `head`
+ */
+added: Array<WikiComparedPage>, 
+/**
+ * Pages only in `base`
+ */
+removed: Array<WikiComparedPage>, 
+/**
+ * Pages in both branches whose title or content differs
+ */
+changed: Array<WikiPageChange>, unchanged_count: number, };
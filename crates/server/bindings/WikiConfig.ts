@@ -1,4 +1,8 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PostMergeWikiUpdate } from "./PostMergeWikiUpdate";
+import type { WikiChunkTuning } from "./WikiChunkTuning";
+import type { WikiEmbeddingProvider } from "./WikiEmbeddingProvider";
+import type { WikiPageImportance } from "./WikiPageImportance";
 
 /**
  * Wiki feature configuration
@@ -35,4 +39,84 @@ repo_url: string | null,
 /**
  * Access token for private repositories
  */
-access_token: string | null, };
+access_token: string | null, 
+/**
+ * Handling of wiki pages affected by a merged task
+ */
+post_merge_update: PostMergeWikiUpdate, 
+/**
+ * Attach wiki pages and related code for changed files to AI review prompts
+ */
+review_context: boolean, 
+/**
+ * Token cap for the wiki context attached to AI review prompts
+ */
+review_context_max_tokens: number, 
+/**
+ * Ground planning prompts in code and wiki pages related to the task
+ */
+planning_context: boolean, 
+/**
+ * Token cap for the wiki context attached to planning prompts
+ */
+planning_context_max_tokens: number, 
+/**
+ * Maximum pages generated in one wiki generation run
+ */
+max_pages: number, 
+/**
+ * Maximum pages generated for one wiki section
+ */
+max_pages_per_section: number, 
+/**
+ * Planned pages below this importance are skipped
+ */
+min_page_importance: WikiPageImportance, 
+/**
+ * Wiki pages generated at once; lowered automatically when rate limited
+ */
+page_concurrency: number, 
+/**
+ * Embedding batches requested at once while indexing
+ */
+embedding_concurrency: number, 
+/**
+ * Summarize each chunk with the chat model while indexing; summaries
+ * are shown with search results and embedded with the code
+ */
+chunk_summaries: boolean, 
+/**
+ * Sub-directories of a monorepo to index, e.g. `["backend/", "frontend/"]`;
+ * searches and questions can be scoped to one of them. Empty indexes
+ * the whole repository.
+ */
+roots: Array<string>, 
+/**
+ * Where embeddings come from; chat features always use OpenRouter
+ */
+embedding_provider: WikiEmbeddingProvider, 
+/**
+ * OpenAI API key for the `openai` embedding provider
+ */
+openai_api_key: string | null, 
+/**
+ * Directory with `config.json`, `tokenizer.json` and `model.safetensors`
+ * of the model for the `local` embedding provider
+ */
+local_embedding_model_path: string | null, 
+/**
+ * Vector dimension of the embedding model, e.g. 3072 for
+ * `text-embedding-3-large`; taken from its first embedding when unset.
+ * Changing it empties the model's vectors and marks its branches stale.
+ */
+embedding_dimension: number | null, 
+/**
+ * Earlier indexes of each branch kept for searches as of their commit;
+ * zero keeps none
+ */
+history_generations: number, 
+/**
+ * Chunk size and overlap a tuning pass picked for the repository;
+ * indexing uses 350 and 100 tokens when unset
+ */
+chunk_tuning: WikiChunkTuning | null, };
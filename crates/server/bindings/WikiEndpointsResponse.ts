@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WikiEndpoint } from "./WikiEndpoint";
+
+export type WikiEndpointsResponse = { branch: string, endpoints: Array<WikiEndpoint>, total_count: number, };
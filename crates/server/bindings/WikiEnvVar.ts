@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WikiEnvVarUsage } from "./WikiEnvVarUsage";
+
+export type WikiEnvVar = { name: string, default_value: string | null, description: string | null, usages: Array<WikiEnvVarUsage>, };
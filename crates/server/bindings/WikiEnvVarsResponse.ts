@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WikiEnvVar } from "./WikiEnvVar";
+
+export type WikiEnvVarsResponse = { branch: string, variables: Array<WikiEnvVar>, total_count: number, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AskFeedbackResponse } from "./AskFeedbackResponse";
+import type { FlaggedPageResponse } from "./FlaggedPageResponse";
+import type { FlaggedSourceResponse } from "./FlaggedSourceResponse";
+
+export type WikiFeedbackStatsResponse = { total: number, positive: number, negative: number, 
+/**
+ * Most negative votes first
+ */
+flagged_sources: Array<FlaggedSourceResponse>, 
+/**
+ * Pages of `branch` citing flagged sources, most affected first
+ */
+flagged_pages: Array<FlaggedPageResponse>, branch: string, 
+/**
+ * Latest rated-down answers, newest first
+ */
+recent_negative: Array<AskFeedbackResponse>, };
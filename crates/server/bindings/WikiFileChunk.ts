@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type WikiFileChunk = { id: string, chunk_index: number, start_line: number, end_line: number, chunk_type: string, language: string | null, token_count: number, content: string, 
+/**
+ * One-line summary written at index time
+ */
+summary: string | null, commit_sha: string, indexed_at: string, };
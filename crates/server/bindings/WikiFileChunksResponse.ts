@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WikiFileChunk } from "./WikiFileChunk";
+
+export type WikiFileChunksResponse = { branch: string, file_path: string, 
+/**
+ * Chunks of the file, ordered by chunk index
+ */
+chunks: Array<WikiFileChunk>, };
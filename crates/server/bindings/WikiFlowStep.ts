@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type WikiFlowStep = { symbol: string, file_path: string, line: number, end_line: number, depth: number, called_from: string | null, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How current the wiki index of a branch is
+ */
+export type WikiFreshness = { branch: string, state: string, 
+/**
+ * Commit the index was built from
+ */
+indexed_commit: string | null, last_indexed_at: string | null, };
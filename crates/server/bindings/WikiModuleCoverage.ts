@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type WikiModuleCoverage = { file_path: string, language: string, test_files: Array<string>, has_inline_tests: boolean, tested: boolean, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type WikiPageChange = { slug: string, 
+/**
+ * Title in `head`
+ */
+title: string, 
+/**
+ * Title in `base`, when it differs
+ */
+previous_title: string | null, lines_added: number, lines_removed: number, 
+/**
+ * Unified diff of the page content, empty when only the title changed
+ */
+diff: string, };
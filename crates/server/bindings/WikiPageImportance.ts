@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Lowest importance of planned wiki pages that get generated
+ */
+export type WikiPageImportance = "low" | "medium" | "high";
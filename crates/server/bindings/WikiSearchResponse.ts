@@ -1,4 +1,8 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { WikiSearchResult } from "./WikiSearchResult";
 
-export type WikiSearchResponse = { query: string, results: Array<WikiSearchResult>, total_count: number, duration_ms: bigint, };
+export type WikiSearchResponse = { query: string, 
+/**
+ * Synonyms from the project glossary added to the query before embedding
+ */
+expanded_terms: Array<string>, results: Array<WikiSearchResult>, total_count: number, duration_ms: bigint, };
@@ -1,3 +1,11 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type WikiSearchResult = { file_path: string, start_line: number, end_line: number, content: string, language: string | null, score: number, };
+export type WikiSearchResult = { file_path: string, start_line: number, end_line: number, content: string, 
+/**
+ * One-line summary of the code, written at index time
+ */
+summary: string | null, language: string | null, score: number, 
+/**
+ * Whether the code defines public API
+ */
+is_public_api: boolean, };
@@ -1,3 +1,15 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PostMergeWikiUpdate } from "./PostMergeWikiUpdate";
+import type { WikiChunkTuning } from "./WikiChunkTuning";
+import type { WikiEmbeddingProvider } from "./WikiEmbeddingProvider";
+import type { WikiPageImportance } from "./WikiPageImportance";
 
-export type WikiSettingsResponse = { enabled: boolean, branches: Array<string>, has_api_key: boolean, embedding_model: string | null, chat_model: string | null, auto_sync: boolean, repo_url: string | null, has_access_token: boolean, };
+export type WikiSettingsResponse = { enabled: boolean, branches: Array<string>, has_api_key: boolean, embedding_model: string | null, chat_model: string | null, auto_sync: boolean, repo_url: string | null, has_access_token: boolean, post_merge_update: PostMergeWikiUpdate, review_context: boolean, review_context_max_tokens: number, planning_context: boolean, planning_context_max_tokens: number, max_pages: number, max_pages_per_section: number, min_page_importance: WikiPageImportance, page_concurrency: number, embedding_concurrency: number, chunk_summaries: boolean, 
+/**
+ * Workspace roots indexed; empty when the whole repository is
+ */
+roots: Array<string>, embedding_provider: WikiEmbeddingProvider, has_openai_api_key: boolean, local_embedding_model_path: string | null, embedding_dimension: number | null, history_generations: number, 
+/**
+ * Chunk settings picked by the last applied tuning pass
+ */
+chunk_tuning: WikiChunkTuning | null, };
@@ -1,5 +1,6 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SkippedWikiPageResponse } from "./SkippedWikiPageResponse";
 import type { WikiSectionResponse } from "./WikiSectionResponse";
 import type { WikiTreeNode } from "./WikiTreeNode";
 
-export type WikiStructureResponse = { branch: string, root: WikiTreeNode, page_count: number, sections: Array<WikiSectionResponse>, };
+export type WikiStructureResponse = { branch: string, root: WikiTreeNode, page_count: number, sections: Array<WikiSectionResponse>, skipped_pages: Array<SkippedWikiPageResponse>, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WikiModuleCoverage } from "./WikiModuleCoverage";
+
+export type WikiTestCoverageResponse = { branch: string, modules: Array<WikiModuleCoverage>, tested_count: number, untested_count: number, test_file_count: number, };
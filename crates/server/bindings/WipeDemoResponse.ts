@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type WipeDemoResponse = { 
+/**
+ * Whether there was a demo project to remove
+ */
+removed: boolean, };
@@ -0,0 +1,347 @@
+//! API token authentication
+//!
+//! Dashboards and integrations authenticate with `Authorization: Bearer
+//! <token>`. Tokens carry scopes that limit what they can reach. Admin
+//! endpoints always need an admin token, or the bootstrap secret from
+//! `STUDIO_ADMIN_SECRET` to create the first one with. Other requests
+//! without a token are served as the local user, unless
+//! `STUDIO_REQUIRE_TOKEN` is set.
+
+use axum::extract::{Request, State};
+use axum::http::{header, Method};
+use axum::middleware::Next;
+use axum::response::Response;
+use db::ApiTokenRepository;
+use sha2::{Digest, Sha256};
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::state::AppState;
+
+/// Prefix of generated tokens, so they are recognisable in config files
+const TOKEN_PREFIX: &str = "ocs_";
+
+/// Prefix of the tokens in session share links
+const SHARE_TOKEN_PREFIX: &str = "ocs_share_";
+
+/// Secret accepted as an admin token, e.g. to create the first API tokens
+pub const ADMIN_SECRET_ENV: &str = "STUDIO_ADMIN_SECRET";
+
+/// Set to `true` or `1` to reject API requests without a token
+pub const REQUIRE_TOKEN_ENV: &str = "STUDIO_REQUIRE_TOKEN";
+
+/// How requests must authenticate
+#[derive(Clone, Default)]
+pub struct AuthConfig {
+    /// Bootstrap secret, accepted wherever an admin token is
+    pub admin_secret: Option<String>,
+    /// Reject API requests without a token, not only admin ones
+    pub require_token: bool,
+}
+
+impl AuthConfig {
+    pub fn from_env() -> Self {
+        Self {
+            admin_secret: std::env::var(ADMIN_SECRET_ENV)
+                .ok()
+                .filter(|secret| !secret.trim().is_empty()),
+            require_token: std::env::var(REQUIRE_TOKEN_ENV)
+                .is_ok_and(|value| matches!(value.trim(), "1" | "true")),
+        }
+    }
+
+    fn is_admin_secret(&self, token: &str) -> bool {
+        self.admin_secret
+            .as_deref()
+            .is_some_and(|secret| hash_token(secret) == hash_token(token))
+    }
+}
+
+/// What an API token is allowed to do
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenScope {
+    ReadTasks,
+    ReadWiki,
+    WriteTasks,
+    Admin,
+}
+
+impl TokenScope {
+    pub const ALL: [TokenScope; 4] = [
+        TokenScope::ReadTasks,
+        TokenScope::ReadWiki,
+        TokenScope::WriteTasks,
+        TokenScope::Admin,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenScope::ReadTasks => "read:tasks",
+            TokenScope::ReadWiki => "read:wiki",
+            TokenScope::WriteTasks => "write:tasks",
+            TokenScope::Admin => "admin",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|scope| scope.as_str() == s)
+    }
+
+    /// Whether a token with this scope may make requests needing `required`
+    fn grants(&self, required: TokenScope) -> bool {
+        *self == required
+            || *self == TokenScope::Admin
+            || (*self == TokenScope::WriteTasks && required == TokenScope::ReadTasks)
+    }
+}
+
+/// The scope a request to `path` with `method` needs
+fn required_scope(method: &Method, path: &str) -> TokenScope {
+    let is_read = *method == Method::GET || *method == Method::HEAD;
+
+    if path.starts_with("/api/admin") {
+        return TokenScope::Admin;
+    }
+
+    let is_wiki = path.starts_with("/api/wiki")
+        || path.starts_with("/api/settings/wiki")
+        || path.ends_with("/wiki-updates");
+    if is_wiki {
+        // Searching and asking the wiki don't change it, and whoever can
+        // ask can rate the answers
+        let is_query = matches!(
+            path,
+            "/api/wiki/search" | "/api/wiki/ask" | "/api/wiki/ask/stream" | "/api/wiki/trace-flow"
+        ) || (path.starts_with("/api/wiki/ask/") && path.ends_with("/feedback"));
+        return if is_read || is_query {
+            TokenScope::ReadWiki
+        } else {
+            TokenScope::Admin
+        };
+    }
+
+    if path.starts_with("/api/settings") || path.starts_with("/api/projects") {
+        return if is_read {
+            TokenScope::ReadTasks
+        } else {
+            TokenScope::Admin
+        };
+    }
+
+    if is_read {
+        TokenScope::ReadTasks
+    } else {
+        TokenScope::WriteTasks
+    }
+}
+
+fn random_token(prefix: &str) -> String {
+    format!(
+        "{}{}{}",
+        prefix,
+        Uuid::new_v4().simple(),
+        Uuid::new_v4().simple()
+    )
+}
+
+/// Whether a request to `path` needs a token when tokens are mandatory.
+/// Health checks, API docs and session share links stay public.
+fn needs_token(path: &str) -> bool {
+    path.starts_with("/api/") && path != "/api/openapi.json" && !path.starts_with("/api/shared/")
+}
+
+/// Generate a new random token
+pub fn generate_token() -> String {
+    random_token(TOKEN_PREFIX)
+}
+
+/// Generate the token of a session share link; like API tokens, only its
+/// hash is stored
+pub fn generate_share_token() -> String {
+    random_token(SHARE_TOKEN_PREFIX)
+}
+
+/// Hex-encoded SHA-256 of a token, as stored in the database
+pub fn hash_token(token: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(token.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+/// Reject requests whose bearer token is unknown, revoked or lacks the scope
+/// the request needs, and requests without one where a token is required
+pub async fn require_token_scope(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let Some(authorization) = request.headers().get(header::AUTHORIZATION) else {
+        let path = request.uri().path();
+        if path.starts_with("/api/admin") {
+            return Err(AppError::Unauthorized(
+                "Admin endpoints need an admin API token".to_string(),
+            ));
+        }
+        if state.auth.require_token && needs_token(path) {
+            return Err(AppError::Unauthorized(
+                "An API token is required".to_string(),
+            ));
+        }
+        return Ok(next.run(request).await);
+    };
+
+    let token = authorization
+        .to_str()
+        .ok()
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .ok_or_else(|| {
+            AppError::Unauthorized("Expected 'Authorization: Bearer <token>'".to_string())
+        })?;
+
+    if state.auth.is_admin_secret(token) {
+        debug!("Authenticated with the admin secret");
+        return Ok(next.run(request).await);
+    }
+
+    let project = state.project().await?;
+    let repo = ApiTokenRepository::new(project.pool.clone());
+    let api_token = repo
+        .find_by_hash(&hash_token(token))
+        .await?
+        .filter(|t| !t.is_revoked())
+        .ok_or_else(|| AppError::Unauthorized("Invalid or revoked API token".to_string()))?;
+
+    let required = required_scope(request.method(), request.uri().path());
+    let allowed = api_token
+        .scopes()
+        .iter()
+        .filter_map(|s| TokenScope::parse(s))
+        .any(|scope| scope.grants(required));
+    if !allowed {
+        warn!(
+            token = %api_token.name,
+            path = %request.uri().path(),
+            "API token lacks scope {}",
+            required.as_str()
+        );
+        return Err(AppError::Forbidden(format!(
+            "API token '{}' lacks the '{}' scope",
+            api_token.name,
+            required.as_str()
+        )));
+    }
+
+    debug!(token = %api_token.name, "Authenticated API token");
+    repo.touch(&api_token.id).await?;
+
+    Ok(next.run(request).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::StatusCode;
+    use axum_test::TestServer;
+
+    #[test]
+    fn test_required_scope() {
+        assert_eq!(
+            required_scope(&Method::GET, "/api/tasks"),
+            TokenScope::ReadTasks
+        );
+        assert_eq!(
+            required_scope(&Method::POST, "/api/tasks/1/transition"),
+            TokenScope::WriteTasks
+        );
+        assert_eq!(
+            required_scope(&Method::GET, "/api/wiki/pages/overview"),
+            TokenScope::ReadWiki
+        );
+        assert_eq!(
+            required_scope(&Method::POST, "/api/wiki/ask"),
+            TokenScope::ReadWiki
+        );
+        assert_eq!(
+            required_scope(&Method::POST, "/api/wiki/ask/stream"),
+            TokenScope::ReadWiki
+        );
+        assert_eq!(
+            required_scope(&Method::POST, "/api/wiki/ask/abc/feedback"),
+            TokenScope::ReadWiki
+        );
+        assert_eq!(
+            required_scope(&Method::POST, "/api/wiki/index"),
+            TokenScope::Admin
+        );
+        assert_eq!(
+            required_scope(&Method::PUT, "/api/settings/github"),
+            TokenScope::Admin
+        );
+        assert_eq!(
+            required_scope(&Method::GET, "/api/admin/tokens"),
+            TokenScope::Admin
+        );
+    }
+
+    #[test]
+    fn test_scope_grants() {
+        assert!(TokenScope::WriteTasks.grants(TokenScope::ReadTasks));
+        assert!(!TokenScope::ReadTasks.grants(TokenScope::WriteTasks));
+        assert!(!TokenScope::ReadTasks.grants(TokenScope::ReadWiki));
+        assert!(TokenScope::Admin.grants(TokenScope::ReadWiki));
+        assert_eq!(TokenScope::parse("read:wiki"), Some(TokenScope::ReadWiki));
+        assert_eq!(TokenScope::parse("write:wiki"), None);
+    }
+
+    fn test_server(auth: AuthConfig) -> TestServer {
+        let state = AppState::new("http://localhost:4096").with_auth(auth);
+        TestServer::new(crate::create_router(state)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_admin_routes_need_a_token() {
+        let server = test_server(AuthConfig::default());
+
+        server
+            .post("/api/admin/tokens")
+            .json(&serde_json::json!({ "name": "ci", "scopes": ["admin"] }))
+            .await
+            .assert_status(StatusCode::UNAUTHORIZED);
+        server.get("/health").await.assert_status_ok();
+    }
+
+    #[tokio::test]
+    async fn test_admin_secret_and_mandatory_tokens() {
+        let server = test_server(AuthConfig {
+            admin_secret: Some("bootstrap".to_string()),
+            require_token: true,
+        });
+
+        server
+            .get("/api/tasks")
+            .await
+            .assert_status(StatusCode::UNAUTHORIZED);
+        server.get("/health").await.assert_status_ok();
+        server.get("/api/openapi.json").await.assert_status_ok();
+
+        // The secret gets past authentication; no project is open to list from
+        let response = server
+            .get("/api/admin/tokens")
+            .authorization_bearer("bootstrap")
+            .await;
+        assert_ne!(response.status_code(), StatusCode::UNAUTHORIZED);
+        assert_ne!(response.status_code(), StatusCode::FORBIDDEN);
+    }
+
+    #[test]
+    fn test_token_hash() {
+        let token = generate_token();
+        assert!(token.starts_with(TOKEN_PREFIX));
+        assert_eq!(hash_token(&token), hash_token(&token));
+        assert_ne!(hash_token(&token), hash_token(&generate_token()));
+        assert!(generate_share_token().starts_with(SHARE_TOKEN_PREFIX));
+    }
+}
@@ -1,3 +1,4 @@
+use orchestrator::{EnvVar, ReviewPolicy};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 use tokio::fs;
@@ -36,6 +37,80 @@ pub struct PhaseModels {
     pub fix: Option<ModelSelection>,
 }
 
+/// MCP tools each phase may call; phases left out use the built-in profile
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct PhaseMcpTools {
+    /// Tools for planning phase
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub planning: Option<Vec<String>>,
+    /// Tools for implementation phase
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub implementation: Option<Vec<String>>,
+    /// Tools for review phase
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub review: Option<Vec<String>>,
+    /// Tools for fix phase
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<Vec<String>>,
+}
+
+/// What happens to stale wiki pages after a task is merged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+#[serde(rename_all = "lowercase")]
+pub enum PostMergeWikiUpdate {
+    /// Leave pages alone
+    Off,
+    /// Record edit suggestions for affected pages on the task
+    #[default]
+    Suggest,
+    /// Rewrite affected pages in place
+    Regenerate,
+}
+
+/// Where wiki embeddings come from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+#[serde(rename_all = "lowercase")]
+pub enum WikiEmbeddingProvider {
+    /// OpenRouter, with the wiki's API key
+    #[default]
+    OpenRouter,
+    /// The OpenAI API directly
+    OpenAi,
+    /// A model run by the server; code is not sent anywhere
+    Local,
+}
+
+impl From<WikiEmbeddingProvider> for wiki::EmbeddingProviderKind {
+    fn from(provider: WikiEmbeddingProvider) -> Self {
+        match provider {
+            WikiEmbeddingProvider::OpenRouter => wiki::EmbeddingProviderKind::OpenRouter,
+            WikiEmbeddingProvider::OpenAi => wiki::EmbeddingProviderKind::OpenAi,
+            WikiEmbeddingProvider::Local => wiki::EmbeddingProviderKind::Local,
+        }
+    }
+}
+
+/// Lowest importance of planned wiki pages that get generated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+#[serde(rename_all = "lowercase")]
+pub enum WikiPageImportance {
+    /// Generate every planned page
+    #[default]
+    Low,
+    /// Skip pages planned as low importance
+    Medium,
+    /// Only generate pages planned as high importance
+    High,
+}
+
 /// Wiki feature configuration
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
@@ -65,6 +140,145 @@ pub struct WikiConfig {
     /// Access token for private repositories
     #[serde(skip_serializing_if = "Option::is_none")]
     pub access_token: Option<String>,
+    /// Handling of wiki pages affected by a merged task
+    #[serde(default)]
+    pub post_merge_update: PostMergeWikiUpdate,
+    /// Attach wiki pages and related code for changed files to AI review prompts
+    #[serde(default = "default_review_context")]
+    pub review_context: bool,
+    /// Token cap for the wiki context attached to AI review prompts
+    #[serde(default = "default_review_context_max_tokens")]
+    pub review_context_max_tokens: u32,
+    /// Ground planning prompts in code and wiki pages related to the task
+    #[serde(default = "default_planning_context")]
+    pub planning_context: bool,
+    /// Token cap for the wiki context attached to planning prompts
+    #[serde(default = "default_planning_context_max_tokens")]
+    pub planning_context_max_tokens: u32,
+    /// Maximum pages generated in one wiki generation run
+    #[serde(default = "default_max_pages")]
+    pub max_pages: u32,
+    /// Maximum pages generated for one wiki section
+    #[serde(default = "default_max_pages_per_section")]
+    pub max_pages_per_section: u32,
+    /// Planned pages below this importance are skipped
+    #[serde(default)]
+    pub min_page_importance: WikiPageImportance,
+    /// Wiki pages generated at once; lowered automatically when rate limited
+    #[serde(default = "default_page_concurrency")]
+    pub page_concurrency: u32,
+    /// Embedding batches requested at once while indexing
+    #[serde(default = "default_embedding_concurrency")]
+    pub embedding_concurrency: u32,
+    /// Summarize each chunk with the chat model while indexing; summaries
+    /// are shown with search results and embedded with the code
+    #[serde(default)]
+    pub chunk_summaries: bool,
+    /// Sub-directories of a monorepo to index, e.g. `["backend/", "frontend/"]`;
+    /// searches and questions can be scoped to one of them. Empty indexes
+    /// the whole repository.
+    #[serde(default)]
+    pub roots: Vec<String>,
+    /// Where embeddings come from; chat features always use OpenRouter
+    #[serde(default)]
+    pub embedding_provider: WikiEmbeddingProvider,
+    /// OpenAI API key for the `openai` embedding provider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub openai_api_key: Option<String>,
+    /// Directory with `config.json`, `tokenizer.json` and `model.safetensors`
+    /// of the model for the `local` embedding provider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub local_embedding_model_path: Option<String>,
+    /// Vector dimension of the embedding model, e.g. 3072 for
+    /// `text-embedding-3-large`; taken from its first embedding when unset.
+    /// Changing it empties the model's vectors and marks its branches stale.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embedding_dimension: Option<u32>,
+    /// Earlier indexes of each branch kept for searches as of their commit;
+    /// zero keeps none
+    #[serde(default = "default_history_generations")]
+    pub history_generations: u32,
+    /// Chunk size and overlap a tuning pass picked for the repository;
+    /// indexing uses 350 and 100 tokens when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chunk_tuning: Option<WikiChunkTuning>,
+}
+
+/// Chunk settings a tuning pass measured as retrieving best
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct WikiChunkTuning {
+    pub max_chunk_tokens: u32,
+    pub chunk_overlap: u32,
+    /// Mean reciprocal rank the setting scored
+    pub mean_reciprocal_rank: f64,
+    /// Embedding model the setting was tuned with
+    pub embedding_model: String,
+    pub tuned_at: i64,
+}
+
+impl WikiConfig {
+    /// Settings for the configured embedding provider
+    pub fn embedding_provider_config(&self) -> wiki::EmbeddingProviderConfig {
+        wiki::EmbeddingProviderConfig {
+            kind: self.embedding_provider.into(),
+            openrouter_api_key: self.openrouter_api_key.clone(),
+            openrouter_base_url: None,
+            openai_api_key: self.openai_api_key.clone(),
+            local_model_path: self.local_embedding_model_path.as_ref().map(Into::into),
+        }
+    }
+
+    /// Chunk size and overlap indexing splits files with, in tokens
+    pub fn chunk_setting(&self) -> (usize, usize) {
+        match &self.chunk_tuning {
+            Some(tuning) => (
+                tuning.max_chunk_tokens as usize,
+                tuning.chunk_overlap as usize,
+            ),
+            None => (DEFAULT_MAX_CHUNK_TOKENS, DEFAULT_CHUNK_OVERLAP),
+        }
+    }
+}
+
+const DEFAULT_MAX_CHUNK_TOKENS: usize = 350;
+const DEFAULT_CHUNK_OVERLAP: usize = 100;
+
+fn default_history_generations() -> u32 {
+    wiki::DEFAULT_HISTORY_GENERATIONS as u32
+}
+
+fn default_review_context() -> bool {
+    true
+}
+
+fn default_review_context_max_tokens() -> u32 {
+    4000
+}
+
+fn default_planning_context() -> bool {
+    true
+}
+
+fn default_planning_context_max_tokens() -> u32 {
+    6000
+}
+
+fn default_max_pages() -> u32 {
+    30
+}
+
+fn default_max_pages_per_section() -> u32 {
+    8
+}
+
+fn default_page_concurrency() -> u32 {
+    3
+}
+
+fn default_embedding_concurrency() -> u32 {
+    wiki::DEFAULT_EMBEDDING_CONCURRENCY as u32
 }
 
 impl Default for WikiConfig {
@@ -78,6 +292,24 @@ impl Default for WikiConfig {
             auto_sync: false,
             repo_url: None,
             access_token: None,
+            post_merge_update: PostMergeWikiUpdate::default(),
+            review_context: default_review_context(),
+            review_context_max_tokens: default_review_context_max_tokens(),
+            planning_context: default_planning_context(),
+            planning_context_max_tokens: default_planning_context_max_tokens(),
+            max_pages: default_max_pages(),
+            max_pages_per_section: default_max_pages_per_section(),
+            min_page_importance: WikiPageImportance::default(),
+            page_concurrency: default_page_concurrency(),
+            embedding_concurrency: default_embedding_concurrency(),
+            chunk_summaries: false,
+            roots: Vec::new(),
+            embedding_provider: WikiEmbeddingProvider::default(),
+            openai_api_key: None,
+            local_embedding_model_path: None,
+            embedding_dimension: None,
+            history_generations: default_history_generations(),
+            chunk_tuning: None,
         }
     }
 }
@@ -103,6 +335,176 @@ pub struct RoadmapConfig {
     pub model: Option<ModelSelection>,
 }
 
+/// Session execution configuration
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct ExecutionConfig {
+    /// How many times a failed phase session can be retried
+    #[serde(default = "default_max_session_retries")]
+    pub max_session_retries: u32,
+    /// Re-rate review finding severities against a rubric and the project guidelines
+    #[serde(default = "default_calibrate_review_severity")]
+    pub calibrate_review_severity: bool,
+    /// Raise the severity of review findings on code the wiki index tagged
+    /// as public API: HTTP handlers, exported items and CLI commands
+    #[serde(default = "default_escalate_public_api_findings")]
+    pub escalate_public_api_findings: bool,
+    /// Reviews run per review round; findings need agreement across them.
+    /// Each extra sample costs about one more review prompt of tokens
+    #[serde(default = "default_review_samples")]
+    pub review_samples: u32,
+    /// Review samples that must report a finding for it to be kept
+    #[serde(default = "default_review_min_agreement")]
+    pub review_min_agreement: u32,
+    /// Give each extra review sample a different focus, e.g. security
+    #[serde(default)]
+    pub review_personas: bool,
+    /// Which review findings send a task to the fix phase
+    #[serde(default)]
+    pub review_policy: ReviewPolicy,
+    /// Language review summaries and findings are written in, e.g. `de` or
+    /// `Japanese`; English when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review_language: Option<String>,
+    /// Keep tasks from done until the GitHub checks of their merged or
+    /// pushed changes pass; needs a GitHub token
+    #[serde(default)]
+    pub ci_gate: bool,
+    /// Token budget for the files a plan cites, attached to implementation
+    /// prompts so the model doesn't have to look for them; 0 attaches none
+    #[serde(default = "default_context_file_tokens")]
+    pub context_file_tokens: u32,
+    /// Paths outside the workspace that implementation and fix sessions may
+    /// write, absolute or relative to the workspace; any other write outside
+    /// it is denied and reported as a finding
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub shared_paths: Vec<String>,
+}
+
+fn default_max_session_retries() -> u32 {
+    3
+}
+
+fn default_calibrate_review_severity() -> bool {
+    true
+}
+
+fn default_escalate_public_api_findings() -> bool {
+    true
+}
+
+fn default_review_samples() -> u32 {
+    1
+}
+
+fn default_review_min_agreement() -> u32 {
+    2
+}
+
+fn default_context_file_tokens() -> u32 {
+    orchestrator::DEFAULT_CONTEXT_FILE_TOKENS as u32
+}
+
+impl Default for ExecutionConfig {
+    fn default() -> Self {
+        Self {
+            max_session_retries: default_max_session_retries(),
+            calibrate_review_severity: default_calibrate_review_severity(),
+            escalate_public_api_findings: default_escalate_public_api_findings(),
+            review_samples: default_review_samples(),
+            review_min_agreement: default_review_min_agreement(),
+            review_personas: false,
+            review_policy: ReviewPolicy::default(),
+            review_language: None,
+            ci_gate: false,
+            context_file_tokens: default_context_file_tokens(),
+            shared_paths: Vec::new(),
+        }
+    }
+}
+
+/// Event history configuration
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct EventsConfig {
+    /// Store published events so they can be queried and replayed later
+    #[serde(default = "default_persist_events")]
+    pub persist: bool,
+    /// Days stored events are kept for
+    #[serde(default = "default_event_retention_days")]
+    pub retention_days: u32,
+}
+
+fn default_persist_events() -> bool {
+    true
+}
+
+fn default_event_retention_days() -> u32 {
+    30
+}
+
+impl Default for EventsConfig {
+    fn default() -> Self {
+        Self {
+            persist: default_persist_events(),
+            retention_days: default_event_retention_days(),
+        }
+    }
+}
+
+/// Email digests of board activity, sent over SMTP
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct DigestConfig {
+    /// Send digests to users who scheduled them
+    #[serde(default)]
+    pub enabled: bool,
+    /// SMTP server, e.g. "smtp.example.com"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smtp_host: Option<String>,
+    #[serde(default = "default_smtp_port")]
+    pub smtp_port: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smtp_username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smtp_password: Option<String>,
+    /// Upgrade the connection with STARTTLS; off only for local relays
+    #[serde(default = "default_smtp_starttls")]
+    pub smtp_starttls: bool,
+    /// Sender address, e.g. "OpenCode Studio <studio@example.com>"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    /// Model spend in USD per digest period the usage section is compared to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget_usd: Option<f64>,
+}
+
+fn default_smtp_port() -> u16 {
+    587
+}
+
+fn default_smtp_starttls() -> bool {
+    true
+}
+
+impl Default for DigestConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            smtp_host: None,
+            smtp_port: default_smtp_port(),
+            smtp_username: None,
+            smtp_password: None,
+            smtp_starttls: default_smtp_starttls(),
+            from: None,
+            budget_usd: None,
+        }
+    }
+}
+
 /// Project-level configuration stored in .opencode-studio/config.json
 #[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
@@ -116,6 +518,17 @@ pub struct ProjectConfig {
     pub wiki: WikiConfig,
     #[serde(default)]
     pub roadmap: RoadmapConfig,
+    #[serde(default)]
+    pub execution: ExecutionConfig,
+    #[serde(default)]
+    pub events: EventsConfig,
+    #[serde(default)]
+    pub digest: DigestConfig,
+    #[serde(default)]
+    pub mcp_tools: PhaseMcpTools,
+    /// Environment variables for every task's sessions
+    #[serde(default)]
+    pub environment: Vec<EnvVar>,
 }
 
 impl ProjectConfig {
@@ -177,6 +590,23 @@ mod tests {
         assert!(config.phase_models.implementation.is_none());
         assert!(config.phase_models.review.is_none());
         assert!(config.phase_models.fix.is_none());
+        assert_eq!(config.execution.max_session_retries, 3);
+        assert!(config.execution.calibrate_review_severity);
+        assert!(config.execution.escalate_public_api_findings);
+        assert_eq!(config.execution.review_samples, 1);
+        assert_eq!(config.execution.review_min_agreement, 2);
+        assert!(!config.execution.review_personas);
+        assert_eq!(config.execution.review_policy, ReviewPolicy::default());
+        assert!(config.events.persist);
+        assert_eq!(config.events.retention_days, 30);
+        assert!(!config.digest.enabled);
+        assert_eq!(config.digest.smtp_port, 587);
+        assert_eq!(config.wiki.max_pages, 30);
+        assert_eq!(config.wiki.max_pages_per_section, 8);
+        assert_eq!(config.wiki.min_page_importance, WikiPageImportance::Low);
+        assert_eq!(config.wiki.page_concurrency, 3);
+        assert_eq!(config.wiki.embedding_concurrency, 4);
+        assert_eq!(config.wiki.history_generations, 5);
     }
 
     #[tokio::test]
@@ -206,6 +636,14 @@ mod tests {
             user_mode: UserMode::default(),
             wiki: WikiConfig::default(),
             roadmap: RoadmapConfig::default(),
+            execution: ExecutionConfig::default(),
+            events: EventsConfig::default(),
+            digest: DigestConfig::default(),
+            mcp_tools: PhaseMcpTools {
+                fix: Some(vec!["list_findings".to_string(), "mark_fixed".to_string()]),
+                ..Default::default()
+            },
+            environment: vec![EnvVar::secret("DATABASE_URL", "TEST_DATABASE_URL")],
         };
 
         config.write(temp_dir.path()).await.unwrap();
@@ -221,5 +659,11 @@ mod tests {
             loaded.phase_models.review.as_ref().unwrap().provider_id,
             "openai"
         );
+        assert!(loaded.mcp_tools.review.is_none());
+        assert_eq!(
+            loaded.mcp_tools.fix.unwrap(),
+            vec!["list_findings", "mark_fixed"]
+        );
+        assert_eq!(loaded.environment, config.environment);
     }
 }
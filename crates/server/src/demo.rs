@@ -0,0 +1,565 @@
+//! Demo mode
+//!
+//! Seeds a bundled fixture, a small sample repository with tasks in every
+//! column, their sessions and activity, review findings and a pre-built
+//! wiki, into a project of its own under `~/.opencode-studio/demo`. Nothing
+//! runs an agent, so Studio can be shown populated without OpenCode. The
+//! project carries a marker file so the UI can label it, and `wipe` removes
+//! it together with its database in one call.
+
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Duration, Utc};
+use db::{CreateSessionActivity, SessionActivityRepository};
+use opencode_core::{Session, SessionPhase, SessionStatus, Task, TaskStatus};
+use orchestrator::{ReviewFinding, ReviewFindings, SessionActivityMsg};
+use serde::{Deserialize, Serialize};
+use tokio::process::Command;
+use tracing::{info, warn};
+use uuid::Uuid;
+use wiki::{
+    ChunkType, CodeChunk, IndexState, IndexStatus, PageType, TextSplitter, VectorStore, WikiPage,
+    WikiStructure, WikiTree,
+};
+
+use crate::config::ProjectConfig as JsonProjectConfig;
+use crate::project_manager::{get_project_data_dir, ProjectError, ProjectManager};
+use crate::routes::wiki::get_wiki_db_path;
+
+const DEMO_DIR: &str = "demo";
+const DEMO_PROJECT_DIR: &str = "bookmarks";
+const DEMO_MARKER: &str = "demo.json";
+const DEMO_BRANCH: &str = "main";
+const FIXTURE: &str = include_str!("../fixtures/demo.json");
+
+/// Time between the starts of consecutive seeded sessions
+const SESSION_SPACING_MINUTES: i64 = 12;
+/// Time between the activity entries of a seeded session
+const ACTIVITY_SPACING_SECONDS: i64 = 40;
+
+#[derive(Debug, Deserialize)]
+struct DemoFixture {
+    files: Vec<FixtureFile>,
+    tasks: Vec<FixtureTask>,
+    wiki: Vec<FixturePage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FixtureFile {
+    path: String,
+    content: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct FixtureTask {
+    title: String,
+    description: String,
+    status: TaskStatus,
+    #[serde(default)]
+    plan: Option<String>,
+    #[serde(default)]
+    sessions: Vec<FixtureSession>,
+    #[serde(default)]
+    findings: Option<FixtureFindings>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FixtureSession {
+    phase: SessionPhase,
+    status: SessionStatus,
+    #[serde(default)]
+    activity: Vec<FixtureActivity>,
+}
+
+/// Activity of a seeded session; a tool call is stored as its call and its
+/// result
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum FixtureActivity {
+    AgentMessage {
+        content: String,
+    },
+    Reasoning {
+        content: String,
+    },
+    ToolCall {
+        tool_name: String,
+        #[serde(default)]
+        args: Option<serde_json::Value>,
+        result: String,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+struct FixtureFindings {
+    summary: String,
+    approved: bool,
+    findings: Vec<ReviewFinding>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FixturePage {
+    slug: String,
+    title: String,
+    page_type: PageType,
+    file_paths: Vec<String>,
+    content: String,
+}
+
+/// What seeding the demo project created
+#[derive(Debug, Clone, Serialize)]
+pub struct DemoSeed {
+    pub project_path: PathBuf,
+    pub tasks: usize,
+    pub sessions: usize,
+    pub findings: usize,
+    pub wiki_pages: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DemoMarker {
+    seeded_at: DateTime<Utc>,
+}
+
+/// Directory holding the demo project and its task workspaces
+pub fn demo_root() -> Result<PathBuf, ProjectError> {
+    dirs::home_dir()
+        .map(|home| home.join(".opencode-studio").join(DEMO_DIR))
+        .ok_or_else(|| ProjectError::Config("Could not determine home directory".into()))
+}
+
+/// Path of the demo project
+pub fn demo_project_path() -> Result<PathBuf, ProjectError> {
+    Ok(demo_root()?.join(DEMO_PROJECT_DIR))
+}
+
+/// Whether `path` is a project seeded by demo mode
+pub fn is_demo_project(path: &Path) -> bool {
+    marker_path(path).exists()
+}
+
+fn marker_path(path: &Path) -> PathBuf {
+    path.join(".opencode-studio").join(DEMO_MARKER)
+}
+
+/// Seed the demo project from the bundled fixture and open it
+///
+/// An existing demo project is wiped first, so seeding always starts from
+/// the fixture. The project is opened without being added to the recent
+/// projects.
+pub async fn seed(project_manager: &ProjectManager) -> Result<DemoSeed, ProjectError> {
+    let fixture: DemoFixture =
+        serde_json::from_str(FIXTURE).map_err(|e| seed_error(format!("invalid fixture: {}", e)))?;
+
+    wipe(project_manager).await?;
+    let path = demo_project_path()?;
+    tokio::fs::create_dir_all(&path).await?;
+
+    for file in &fixture.files {
+        let file_path = path.join(&file.path);
+        if let Some(parent) = file_path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(&file_path, &file.content).await?;
+    }
+    let commit_sha = commit_files(&path).await;
+
+    project_manager.init(&path, false).await?;
+    let marker = serde_json::to_string_pretty(&DemoMarker {
+        seeded_at: Utc::now(),
+    })
+    .map_err(seed_error)?;
+    tokio::fs::write(marker_path(&path), marker).await?;
+
+    // The wiki routes need an API key to build their engine, though reading
+    // the seeded pages never calls OpenRouter
+    let mut config = JsonProjectConfig::default();
+    config.wiki.enabled = true;
+    config.wiki.branches = vec![DEMO_BRANCH.to_string()];
+    config.wiki.openrouter_api_key = Some("demo".to_string());
+    config.write(&path).await?;
+
+    let wiki_pages = {
+        let path = path.clone();
+        let files = fixture.files;
+        let pages = fixture.wiki;
+        let commit_sha = commit_sha.clone();
+        tokio::task::spawn_blocking(move || {
+            seed_wiki(&get_wiki_db_path(&path), &files, &pages, &commit_sha)
+        })
+        .await
+        .map_err(seed_error)??
+    };
+
+    project_manager.open(&path).await?;
+    let project = project_manager
+        .current()
+        .await
+        .ok_or(ProjectError::NoProjectOpen)?;
+    let activity_repository = SessionActivityRepository::new(project.pool.clone());
+    let file_manager = project.task_executor.file_manager();
+
+    let session_count: usize = fixture.tasks.iter().map(|t| t.sessions.len()).sum();
+    let mut next_start =
+        Utc::now() - Duration::minutes(SESSION_SPACING_MINUTES * session_count as i64);
+    let mut findings = 0;
+
+    for fixture_task in &fixture.tasks {
+        let mut task = Task::new(&fixture_task.title, &fixture_task.description);
+        task.status = fixture_task.status;
+        let task = project
+            .task_repository
+            .create(&task)
+            .await
+            .map_err(seed_error)?;
+
+        if let Some(plan) = &fixture_task.plan {
+            file_manager
+                .write_plan(task.id, plan)
+                .await
+                .map_err(seed_error)?;
+        }
+
+        let mut review_session_id = None;
+        for fixture_session in &fixture_task.sessions {
+            let mut session = Session::new(task.id, fixture_session.phase);
+            session.status = fixture_session.status;
+            session.started_at = Some(next_start);
+            if fixture_session.status == SessionStatus::Completed {
+                session.completed_at =
+                    Some(next_start + Duration::minutes(SESSION_SPACING_MINUTES / 2));
+            }
+            let session = project
+                .session_repository
+                .create(&session)
+                .await
+                .map_err(seed_error)?;
+            if session.phase == SessionPhase::Review {
+                review_session_id = Some(session.id);
+            }
+
+            let mut timestamp = next_start;
+            for activity in &fixture_session.activity {
+                for msg in activity_messages(activity, timestamp) {
+                    let activity_type = match msg {
+                        SessionActivityMsg::ToolCall { .. } => "tool_call",
+                        SessionActivityMsg::ToolResult { .. } => "tool_result",
+                        SessionActivityMsg::AgentMessage { .. } => "agent_message",
+                        _ => "reasoning",
+                    };
+                    let data = serde_json::to_value(&msg).map_err(seed_error)?;
+                    activity_repository
+                        .create(&CreateSessionActivity::new(
+                            session.id,
+                            activity_type,
+                            msg.id().map(String::from),
+                            data,
+                        ))
+                        .await
+                        .map_err(seed_error)?;
+                }
+                timestamp += Duration::seconds(ACTIVITY_SPACING_SECONDS);
+            }
+            next_start += Duration::minutes(SESSION_SPACING_MINUTES);
+        }
+
+        if let Some(fixture_findings) = &fixture_task.findings {
+            let mut review = ReviewFindings::with_findings(
+                task.id,
+                review_session_id.unwrap_or_else(Uuid::nil),
+                fixture_findings.summary.clone(),
+                fixture_findings.findings.clone(),
+            );
+            review.approved = fixture_findings.approved;
+            file_manager
+                .write_findings(task.id, &review)
+                .await
+                .map_err(seed_error)?;
+            findings += review.findings.len();
+        }
+    }
+
+    info!(
+        path = %path.display(),
+        tasks = fixture.tasks.len(),
+        "Seeded demo project"
+    );
+
+    Ok(DemoSeed {
+        project_path: path,
+        tasks: fixture.tasks.len(),
+        sessions: session_count,
+        findings,
+        wiki_pages,
+    })
+}
+
+/// Remove the demo project, its workspaces and its database
+///
+/// Closes the demo project first when it is open. Returns whether there
+/// was a demo project to remove.
+pub async fn wipe(project_manager: &ProjectManager) -> Result<bool, ProjectError> {
+    let root = demo_root()?;
+    let path = demo_project_path()?;
+    if !root.exists() {
+        return Ok(false);
+    }
+
+    if let Some(current) = project_manager.current().await {
+        if current.path == path {
+            project_manager.close().await?;
+        }
+    }
+
+    // The data directory is keyed by the canonical path, so find it before
+    // the project is gone
+    let data_dir = if path.exists() {
+        Some(get_project_data_dir(&path)?)
+    } else {
+        None
+    };
+
+    tokio::fs::remove_dir_all(&root).await?;
+    if let Some(data_dir) = data_dir.filter(|dir| dir.exists()) {
+        tokio::fs::remove_dir_all(&data_dir).await?;
+    }
+
+    info!(path = %path.display(), "Wiped demo project");
+    Ok(true)
+}
+
+/// Commit the fixture files so tasks get workspaces; returns the commit,
+/// or a placeholder when git is unavailable and the project runs without
+/// version control
+async fn commit_files(path: &Path) -> String {
+    let steps: [&[&str]; 3] = [
+        &["init", "-b", DEMO_BRANCH],
+        &["add", "-A"],
+        &[
+            "-c",
+            "user.name=OpenCode Studio",
+            "-c",
+            "user.email=demo@opencode-studio.local",
+            "commit",
+            "-m",
+            "Initial commit",
+        ],
+    ];
+    for args in steps {
+        let succeeded = Command::new("git")
+            .args(args)
+            .current_dir(path)
+            .output()
+            .await
+            .map(|output| output.status.success())
+            .unwrap_or(false);
+        if !succeeded {
+            warn!(command = ?args, "git failed; the demo project has no version control");
+            return "demo".to_string();
+        }
+    }
+
+    Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .current_dir(path)
+        .output()
+        .await
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|sha| sha.trim().to_string())
+        .unwrap_or_else(|| "demo".to_string())
+}
+
+fn activity_messages(
+    activity: &FixtureActivity,
+    timestamp: DateTime<Utc>,
+) -> Vec<SessionActivityMsg> {
+    let id = Uuid::new_v4().to_string();
+    match activity {
+        FixtureActivity::AgentMessage { content } => vec![SessionActivityMsg::AgentMessage {
+            id,
+            content: content.clone(),
+            is_partial: false,
+            timestamp,
+        }],
+        FixtureActivity::Reasoning { content } => vec![SessionActivityMsg::Reasoning {
+            id,
+            content: content.clone(),
+            timestamp,
+        }],
+        FixtureActivity::ToolCall {
+            tool_name,
+            args,
+            result,
+        } => vec![
+            SessionActivityMsg::ToolCall {
+                id: id.clone(),
+                tool_name: tool_name.clone(),
+                args: args.clone(),
+                timestamp,
+            },
+            SessionActivityMsg::ToolResult {
+                id,
+                tool_name: tool_name.clone(),
+                args: args.clone(),
+                result: result.clone(),
+                success: true,
+                timestamp: timestamp + Duration::seconds(ACTIVITY_SPACING_SECONDS / 2),
+            },
+        ],
+    }
+}
+
+/// Write the fixture wiki: one chunk per file, without embeddings, and the
+/// pages under the first one. Returns the number of pages.
+fn seed_wiki(
+    db_path: &Path,
+    files: &[FixtureFile],
+    pages: &[FixturePage],
+    commit_sha: &str,
+) -> Result<usize, ProjectError> {
+    let store = VectorStore::new(db_path).map_err(seed_error)?;
+
+    let chunks: Vec<CodeChunk> = files
+        .iter()
+        .map(|file| {
+            CodeChunk::new(
+                DEMO_BRANCH.to_string(),
+                file.path.clone(),
+                1,
+                file.content.lines().count().max(1) as u32,
+                file.content.clone(),
+                ChunkType::File,
+                TextSplitter::detect_language(&file.path),
+                wiki::chunker::count_tokens(&file.content) as u32,
+                0,
+                commit_sha.to_string(),
+            )
+        })
+        .collect();
+    store.insert_chunks_batch(&chunks).map_err(seed_error)?;
+
+    let (root_page, child_pages) = pages
+        .split_first()
+        .ok_or_else(|| seed_error("fixture has no wiki pages"))?;
+    let mut root = WikiTree::new(
+        root_page.slug.clone(),
+        root_page.title.clone(),
+        root_page.page_type,
+        0,
+    );
+    for (i, page) in pages.iter().enumerate() {
+        let parent_slug = (i > 0).then(|| root_page.slug.clone());
+        store
+            .insert_wiki_page(&WikiPage::new(
+                DEMO_BRANCH.to_string(),
+                page.slug.clone(),
+                page.title.clone(),
+                page.content.clone(),
+                page.page_type,
+                parent_slug,
+                i as u32,
+                page.file_paths.clone(),
+                commit_sha.to_string(),
+            ))
+            .map_err(seed_error)?;
+    }
+    for (i, page) in child_pages.iter().enumerate() {
+        root.add_child(WikiTree::new(
+            page.slug.clone(),
+            page.title.clone(),
+            page.page_type,
+            i as u32 + 1,
+        ));
+    }
+    store
+        .save_wiki_structure(&WikiStructure::new(DEMO_BRANCH.to_string(), root))
+        .map_err(seed_error)?;
+
+    let mut status = IndexStatus::new(DEMO_BRANCH.to_string());
+    status.state = IndexState::Indexed;
+    status.last_commit_sha = Some(commit_sha.to_string());
+    status.file_count = files.len() as u32;
+    status.chunk_count = chunks.len() as u32;
+    status.page_count = pages.len() as u32;
+    status.last_indexed_at = Some(Utc::now());
+    status.progress_percent = 100;
+    store.update_index_status(&status).map_err(seed_error)?;
+
+    Ok(pages.len())
+}
+
+fn seed_error(e: impl std::fmt::Display) -> ProjectError {
+    ProjectError::InitFailed(format!("Failed to seed demo project: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn fixture() -> DemoFixture {
+        serde_json::from_str(FIXTURE).unwrap()
+    }
+
+    #[test]
+    fn test_fixture_fills_every_column() {
+        let fixture = fixture();
+        for status in [
+            TaskStatus::Todo,
+            TaskStatus::Planning,
+            TaskStatus::PlanningReview,
+            TaskStatus::InProgress,
+            TaskStatus::AiReview,
+            TaskStatus::Fix,
+            TaskStatus::Review,
+            TaskStatus::Done,
+        ] {
+            assert!(
+                fixture.tasks.iter().any(|t| t.status == status),
+                "no demo task in {}",
+                status.as_str()
+            );
+        }
+        // Findings belong to the review session that reported them
+        for task in fixture.tasks.iter().filter(|t| t.findings.is_some()) {
+            assert!(task
+                .sessions
+                .iter()
+                .any(|s| s.phase == SessionPhase::Review));
+        }
+    }
+
+    #[test]
+    fn test_seed_wiki() {
+        let tmp = TempDir::new().unwrap();
+        let db_path = tmp.path().join("wiki.db");
+        let fixture = fixture();
+
+        let pages = seed_wiki(&db_path, &fixture.files, &fixture.wiki, "abc123").unwrap();
+        assert_eq!(pages, fixture.wiki.len());
+
+        let store = VectorStore::new(&db_path).unwrap();
+        let structure = store.get_wiki_structure(DEMO_BRANCH).unwrap().unwrap();
+        assert_eq!(structure.root.slug, "overview");
+        assert_eq!(structure.root.children.len(), fixture.wiki.len() - 1);
+        assert!(store.get_wiki_page("architecture").unwrap().is_some());
+
+        let status = store.get_index_status(DEMO_BRANCH).unwrap().unwrap();
+        assert_eq!(status.state, IndexState::Indexed);
+        assert_eq!(status.file_count, fixture.files.len() as u32);
+    }
+
+    #[test]
+    fn test_tool_call_activity_records_call_and_result() {
+        let activity = FixtureActivity::ToolCall {
+            tool_name: "read".to_string(),
+            args: None,
+            result: "ok".to_string(),
+        };
+        let messages = activity_messages(&activity, Utc::now());
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].id(), messages[1].id());
+        assert!(matches!(messages[1], SessionActivityMsg::ToolResult { .. }));
+    }
+}
@@ -0,0 +1,454 @@
+//! Email digests of board activity
+//!
+//! A digest sums up a period for stakeholders who don't use the UI: tasks
+//! completed, reviews waiting for approval, failed executions and model
+//! spend. Subject and body are rendered from templates in
+//! `.opencode-studio/templates/` when the project has them, otherwise from
+//! the built-in ones, and sent over the project's SMTP server.
+
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use futures::future::join_all;
+use lettre::message::header::ContentType;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use opencode_core::{Session, SessionStatus, Task, TaskStatus};
+use thiserror::Error;
+use tracing::debug;
+
+use crate::config::DigestConfig;
+use crate::project_manager::ProjectContext;
+use crate::routes::pipeline::session_usage;
+
+/// Directory of the project's digest templates, relative to the project
+const TEMPLATES_DIR: &str = ".opencode-studio/templates";
+const SUBJECT_TEMPLATE_FILE: &str = "digest_subject.txt";
+const BODY_TEMPLATE_FILE: &str = "digest.txt";
+
+const DEFAULT_SUBJECT_TEMPLATE: &str =
+    "[{{project}}] Board digest {{period_start}} to {{period_end}}";
+
+const DEFAULT_BODY_TEMPLATE: &str = r#"Activity on {{project}} from {{period_start}} to {{period_end}}
+
+Completed tasks ({{completed_count}})
+{{completed_tasks}}
+
+Waiting for approval ({{pending_review_count}})
+{{pending_reviews}}
+
+Failed executions ({{failed_count}})
+{{failed_executions}}
+
+Model usage
+{{usage}}
+"#;
+
+#[derive(Debug, Error)]
+pub enum DigestError {
+    #[error("Email digests are not configured: {0}")]
+    NotConfigured(String),
+    #[error("Invalid email address '{0}'")]
+    Address(String),
+    #[error("Failed to send digest: {0}")]
+    Smtp(String),
+    #[error(transparent)]
+    Database(#[from] db::DbError),
+}
+
+/// A task waiting for someone to approve its plan or implementation
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingReview {
+    pub title: String,
+    pub status: TaskStatus,
+}
+
+/// A session that failed during the period
+#[derive(Debug, Clone, PartialEq)]
+pub struct FailedExecution {
+    pub task_title: String,
+    pub phase: String,
+    pub failed_at: DateTime<Utc>,
+}
+
+/// What a period's sessions cost
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DigestUsage {
+    pub tokens: u64,
+    /// Cost in USD as reported by OpenCode
+    pub cost: f64,
+    /// Sessions OpenCode could not report usage for
+    pub unreported_sessions: usize,
+    pub budget_usd: Option<f64>,
+}
+
+impl DigestUsage {
+    /// Usage of the sessions of `tasks` started between `period_start` and
+    /// `period_end`, as reported by OpenCode
+    pub async fn collect(
+        project: &ProjectContext,
+        tasks: &[Task],
+        sessions: &[Session],
+        budget_usd: Option<f64>,
+        period_start: DateTime<Utc>,
+        period_end: DateTime<Utc>,
+    ) -> Self {
+        let in_period = |at: DateTime<Utc>| at >= period_start && at < period_end;
+        let executor = &project.task_executor;
+        let period_sessions: Vec<_> = sessions
+            .iter()
+            .filter(|s| in_period(s.started_at.unwrap_or(s.created_at)))
+            .filter_map(|s| {
+                let opencode_id = s.opencode_session_id.as_deref()?;
+                let workspace = tasks
+                    .iter()
+                    .find(|t| t.id == s.task_id)
+                    .and_then(|t| t.workspace_path.as_deref());
+                Some((opencode_id, workspace))
+            })
+            .collect();
+        let reports = join_all(period_sessions.iter().map(|(opencode_id, workspace)| {
+            session_usage(executor.opencode_config(), opencode_id, *workspace)
+        }))
+        .await;
+
+        let mut usage = Self {
+            budget_usd,
+            ..Default::default()
+        };
+        for report in reports {
+            match report {
+                Some(report) => {
+                    let tokens = &report.tokens;
+                    usage.tokens += tokens.input + tokens.output + tokens.reasoning;
+                    usage.cost += report.cost;
+                }
+                None => usage.unreported_sessions += 1,
+            }
+        }
+
+        usage
+    }
+}
+
+/// Board activity over a period
+#[derive(Debug, Clone, PartialEq)]
+pub struct Digest {
+    pub project: String,
+    pub period_start: DateTime<Utc>,
+    pub period_end: DateTime<Utc>,
+    pub completed_tasks: Vec<String>,
+    pub pending_reviews: Vec<PendingReview>,
+    pub failed_executions: Vec<FailedExecution>,
+    pub usage: DigestUsage,
+}
+
+impl Digest {
+    /// Gather the activity of `project` between `period_start` and
+    /// `period_end`. Pending reviews are those waiting at `period_end`.
+    pub async fn collect(
+        project: &ProjectContext,
+        config: &DigestConfig,
+        period_start: DateTime<Utc>,
+        period_end: DateTime<Utc>,
+    ) -> Result<Self, DigestError> {
+        let in_period = |at: DateTime<Utc>| at >= period_start && at < period_end;
+        let tasks = project.task_repository.find_all().await?;
+        let sessions = project.session_repository.find_all().await?;
+
+        let completed_tasks = tasks
+            .iter()
+            .filter(|t| t.status == TaskStatus::Done && in_period(t.updated_at))
+            .map(|t| t.title.clone())
+            .collect();
+        let pending_reviews = tasks
+            .iter()
+            .filter(|t| matches!(t.status, TaskStatus::PlanningReview | TaskStatus::Review))
+            .map(|t| PendingReview {
+                title: t.title.clone(),
+                status: t.status,
+            })
+            .collect();
+
+        let task_title = |task_id: uuid::Uuid| {
+            tasks
+                .iter()
+                .find(|t| t.id == task_id)
+                .map(|t| t.title.clone())
+                .unwrap_or_else(|| task_id.to_string())
+        };
+        let failed_executions = sessions
+            .iter()
+            .filter(|s| s.status == SessionStatus::Failed)
+            .filter_map(|s| {
+                let failed_at = s.completed_at.unwrap_or(s.created_at);
+                in_period(failed_at).then(|| FailedExecution {
+                    task_title: task_title(s.task_id),
+                    phase: s.phase.as_str().to_string(),
+                    failed_at,
+                })
+            })
+            .collect();
+
+        let usage = DigestUsage::collect(
+            project,
+            &tasks,
+            &sessions,
+            config.budget_usd,
+            period_start,
+            period_end,
+        )
+        .await;
+
+        Ok(Self {
+            project: project_name(&project.path),
+            period_start,
+            period_end,
+            completed_tasks,
+            pending_reviews,
+            failed_executions,
+            usage,
+        })
+    }
+
+    /// `template` with each `{{name}}` placeholder replaced by its value;
+    /// unknown placeholders are left as they are
+    pub fn render(&self, template: &str) -> String {
+        let bullets = |items: Vec<String>| {
+            if items.is_empty() {
+                "- None".to_string()
+            } else {
+                items
+                    .iter()
+                    .map(|item| format!("- {}", item))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+        };
+
+        let values = [
+            ("project", self.project.clone()),
+            (
+                "period_start",
+                self.period_start.format("%Y-%m-%d").to_string(),
+            ),
+            ("period_end", self.period_end.format("%Y-%m-%d").to_string()),
+            ("completed_count", self.completed_tasks.len().to_string()),
+            ("completed_tasks", bullets(self.completed_tasks.clone())),
+            (
+                "pending_review_count",
+                self.pending_reviews.len().to_string(),
+            ),
+            (
+                "pending_reviews",
+                bullets(
+                    self.pending_reviews
+                        .iter()
+                        .map(|r| match r.status {
+                            TaskStatus::PlanningReview => format!("{} (plan)", r.title),
+                            _ => format!("{} (implementation)", r.title),
+                        })
+                        .collect(),
+                ),
+            ),
+            ("failed_count", self.failed_executions.len().to_string()),
+            (
+                "failed_executions",
+                bullets(
+                    self.failed_executions
+                        .iter()
+                        .map(|f| {
+                            format!(
+                                "{}: {} phase, {}",
+                                f.task_title,
+                                f.phase,
+                                f.failed_at.format("%Y-%m-%d %H:%M UTC")
+                            )
+                        })
+                        .collect(),
+                ),
+            ),
+            ("usage", self.usage.to_text()),
+        ];
+
+        values
+            .iter()
+            .fold(template.to_string(), |text, (name, value)| {
+                text.replace(&format!("{{{{{}}}}}", name), value)
+            })
+    }
+}
+
+impl DigestUsage {
+    fn to_text(&self) -> String {
+        let mut text = format!("{} tokens, ${:.2}", self.tokens, self.cost);
+        if let Some(budget) = self.budget_usd.filter(|b| *b > 0.0) {
+            text.push_str(&format!(
+                " of ${:.2} budget ({:.0}%)",
+                budget,
+                self.cost / budget * 100.0
+            ));
+        }
+        if self.unreported_sessions > 0 {
+            text.push_str(&format!(
+                "\n{} sessions did not report usage",
+                self.unreported_sessions
+            ));
+        }
+        text
+    }
+}
+
+fn project_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+/// Subject and body templates of `project_path`, falling back to the
+/// built-in ones for files that don't exist
+pub async fn load_templates(project_path: &Path) -> (String, String) {
+    let dir = project_path.join(TEMPLATES_DIR);
+    let read = |file: &'static str, default: &'static str| {
+        let path = dir.join(file);
+        async move {
+            match tokio::fs::read_to_string(&path).await {
+                Ok(template) => {
+                    debug!(path = %path.display(), "Using project digest template");
+                    template
+                }
+                Err(_) => default.to_string(),
+            }
+        }
+    };
+
+    (
+        read(SUBJECT_TEMPLATE_FILE, DEFAULT_SUBJECT_TEMPLATE)
+            .await
+            .trim()
+            .to_string(),
+        read(BODY_TEMPLATE_FILE, DEFAULT_BODY_TEMPLATE).await,
+    )
+}
+
+/// Sends digests over the project's SMTP server
+pub struct DigestMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: String,
+}
+
+impl DigestMailer {
+    pub fn new(config: &DigestConfig) -> Result<Self, DigestError> {
+        let host = config
+            .smtp_host
+            .as_deref()
+            .filter(|h| !h.trim().is_empty())
+            .ok_or_else(|| DigestError::NotConfigured("no SMTP host".to_string()))?;
+        let from = config
+            .from
+            .clone()
+            .ok_or_else(|| DigestError::NotConfigured("no sender address".to_string()))?;
+
+        let mut builder = if config.smtp_starttls {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)
+                .map_err(|e| DigestError::Smtp(e.to_string()))?
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host)
+        };
+        builder = builder.port(config.smtp_port);
+        if let (Some(username), Some(password)) = (&config.smtp_username, &config.smtp_password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        Ok(Self {
+            transport: builder.build(),
+            from,
+        })
+    }
+
+    pub async fn send(&self, to: &str, subject: &str, body: &str) -> Result<(), DigestError> {
+        let message = Message::builder()
+            .from(
+                self.from
+                    .parse()
+                    .map_err(|_| DigestError::Address(self.from.clone()))?,
+            )
+            .to(to
+                .parse()
+                .map_err(|_| DigestError::Address(to.to_string()))?)
+            .subject(subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(body.to_string())
+            .map_err(|e| DigestError::Smtp(e.to_string()))?;
+
+        self.transport
+            .send(message)
+            .await
+            .map_err(|e| DigestError::Smtp(e.to_string()))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn digest() -> Digest {
+        Digest {
+            project: "shop".to_string(),
+            period_start: Utc.with_ymd_and_hms(2026, 3, 1, 8, 0, 0).unwrap(),
+            period_end: Utc.with_ymd_and_hms(2026, 3, 2, 8, 0, 0).unwrap(),
+            completed_tasks: vec!["Add checkout retries".to_string()],
+            pending_reviews: vec![PendingReview {
+                title: "Refund flow".to_string(),
+                status: TaskStatus::PlanningReview,
+            }],
+            failed_executions: Vec::new(),
+            usage: DigestUsage {
+                tokens: 120_000,
+                cost: 1.5,
+                unreported_sessions: 0,
+                budget_usd: Some(10.0),
+            },
+        }
+    }
+
+    #[test]
+    fn test_render_default_templates() {
+        let digest = digest();
+
+        assert_eq!(
+            digest.render(DEFAULT_SUBJECT_TEMPLATE),
+            "[shop] Board digest 2026-03-01 to 2026-03-02"
+        );
+        let body = digest.render(DEFAULT_BODY_TEMPLATE);
+        assert!(body.contains("Completed tasks (1)\n- Add checkout retries\n"));
+        assert!(body.contains("Waiting for approval (1)\n- Refund flow (plan)\n"));
+        assert!(body.contains("Failed executions (0)\n- None\n"));
+        assert!(body.contains("120000 tokens, $1.50 of $10.00 budget (15%)"));
+    }
+
+    #[test]
+    fn test_render_leaves_unknown_placeholders() {
+        assert_eq!(
+            digest().render("{{project}}: {{unknown}}"),
+            "shop: {{unknown}}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_templates_prefers_project_files() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let (subject, body) = load_templates(temp_dir.path()).await;
+        assert_eq!(subject, DEFAULT_SUBJECT_TEMPLATE);
+        assert_eq!(body, DEFAULT_BODY_TEMPLATE);
+
+        let dir = temp_dir.path().join(TEMPLATES_DIR);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(SUBJECT_TEMPLATE_FILE), "Weekly {{project}}\n").unwrap();
+        let (subject, body) = load_templates(temp_dir.path()).await;
+        assert_eq!(subject, "Weekly {{project}}");
+        assert_eq!(body, DEFAULT_BODY_TEMPLATE);
+    }
+}
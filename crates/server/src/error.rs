@@ -10,6 +10,8 @@ use crate::project_manager::ProjectError;
 pub enum AppError {
     NotFound(String),
     BadRequest(String),
+    Unauthorized(String),
+    Forbidden(String),
     Conflict(String),
     Internal(String),
     Database(db::DbError),
@@ -29,6 +31,8 @@ impl IntoResponse for AppError {
         let (status, error_type, message) = match self {
             AppError::NotFound(msg) => (StatusCode::NOT_FOUND, "not_found", msg),
             AppError::BadRequest(msg) => (StatusCode::BAD_REQUEST, "bad_request", msg),
+            AppError::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, "unauthorized", msg),
+            AppError::Forbidden(msg) => (StatusCode::FORBIDDEN, "forbidden", msg),
             AppError::Conflict(msg) => (StatusCode::CONFLICT, "conflict", msg),
             AppError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, "internal_error", msg),
             AppError::Database(err) => {
@@ -67,6 +71,11 @@ impl IntoResponse for AppError {
                     vcs::VcsError::MergeConflict(msg) => {
                         (StatusCode::CONFLICT, "merge_conflict", msg)
                     }
+                    vcs::VcsError::Unsupported { .. } => (
+                        StatusCode::BAD_REQUEST,
+                        "unsupported_operation",
+                        err.to_string(),
+                    ),
                     _ => (
                         StatusCode::INTERNAL_SERVER_ERROR,
                         "vcs_error",
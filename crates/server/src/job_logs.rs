@@ -0,0 +1,327 @@
+//! Logs of background wiki jobs, kept per job id
+//!
+//! Indexing and generation jobs run under a `wiki_job` span carrying the
+//! job id of their branch lock. [`JobLogLayer`] copies every event emitted
+//! inside such a span into a ring buffer of that job, so a failed job's
+//! logs can be read, or followed while it runs, without access to the
+//! server's output.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Write as _;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use chrono::Utc;
+use serde::Serialize;
+use tokio::sync::broadcast;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+use utoipa::ToSchema;
+
+/// Name of the span that marks a job's work
+pub const JOB_SPAN: &str = "wiki_job";
+
+/// Lines kept per job; older lines are dropped first
+pub const JOB_LOG_CAPACITY: usize = 2000;
+
+/// Jobs whose logs are kept; the logs of the oldest job are dropped first
+pub const MAX_JOB_LOGS: usize = 50;
+
+/// Lines a follower may fall behind before it skips ahead
+const FOLLOW_BUFFER: usize = 256;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct JobLogLine {
+    /// Position of the line in the job's log, from 0
+    pub seq: u64,
+    pub timestamp: String,
+    pub level: String,
+    /// Module the line was logged from
+    pub target: String,
+    pub message: String,
+}
+
+/// What a reader sees of a job's log
+pub struct JobLogSnapshot {
+    pub lines: Vec<JobLogLine>,
+    pub finished: bool,
+    /// New lines until the job finishes; `None` once it has
+    pub follow: Option<broadcast::Receiver<JobLogLine>>,
+}
+
+struct JobLog {
+    lines: VecDeque<JobLogLine>,
+    next_seq: u64,
+    /// Dropped when the job finishes, which ends its followers' streams
+    tx: Option<broadcast::Sender<JobLogLine>>,
+}
+
+impl JobLog {
+    fn new() -> Self {
+        Self {
+            lines: VecDeque::new(),
+            next_seq: 0,
+            tx: Some(broadcast::channel(FOLLOW_BUFFER).0),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Jobs {
+    /// Job ids, oldest first
+    order: VecDeque<String>,
+    logs: HashMap<String, JobLog>,
+}
+
+/// Ring buffers of job logs
+pub struct JobLogs {
+    jobs: Mutex<Jobs>,
+    capacity: usize,
+    max_jobs: usize,
+}
+
+static SHARED: OnceLock<Arc<JobLogs>> = OnceLock::new();
+
+impl JobLogs {
+    pub fn new(capacity: usize, max_jobs: usize) -> Self {
+        Self {
+            jobs: Mutex::new(Jobs::default()),
+            capacity: capacity.max(1),
+            max_jobs: max_jobs.max(1),
+        }
+    }
+
+    /// The logs [`JobLogLayer::shared`] writes and the jobs API reads
+    pub fn shared() -> Arc<Self> {
+        Arc::clone(SHARED.get_or_init(|| Arc::new(Self::new(JOB_LOG_CAPACITY, MAX_JOB_LOGS))))
+    }
+
+    /// Start the log of `job_id`, dropping the oldest job's if too many are
+    /// kept. A job that already has a log keeps it.
+    pub fn start(&self, job_id: &str) {
+        let mut jobs = self.jobs.lock().unwrap();
+        if jobs.logs.contains_key(job_id) {
+            return;
+        }
+        while jobs.order.len() >= self.max_jobs {
+            if let Some(oldest) = jobs.order.pop_front() {
+                jobs.logs.remove(&oldest);
+            }
+        }
+        jobs.order.push_back(job_id.to_string());
+        jobs.logs.insert(job_id.to_string(), JobLog::new());
+    }
+
+    /// Append a line to the log of `job_id`, if it was started
+    pub fn push(&self, job_id: &str, level: &str, target: &str, message: String) {
+        let mut jobs = self.jobs.lock().unwrap();
+        let Some(log) = jobs.logs.get_mut(job_id) else {
+            return;
+        };
+
+        let line = JobLogLine {
+            seq: log.next_seq,
+            timestamp: Utc::now().to_rfc3339(),
+            level: level.to_string(),
+            target: target.to_string(),
+            message,
+        };
+        log.next_seq += 1;
+        if log.lines.len() >= self.capacity {
+            log.lines.pop_front();
+        }
+        if let Some(tx) = &log.tx {
+            let _ = tx.send(line.clone());
+        }
+        log.lines.push_back(line);
+    }
+
+    /// Mark the job as finished, ending the streams that follow it
+    pub fn finish(&self, job_id: &str) {
+        if let Some(log) = self.jobs.lock().unwrap().logs.get_mut(job_id) {
+            log.tx = None;
+        }
+    }
+
+    /// The kept lines of `job_id` and, while it runs, a receiver of the
+    /// lines that follow them
+    pub fn snapshot(&self, job_id: &str) -> Option<JobLogSnapshot> {
+        let jobs = self.jobs.lock().unwrap();
+        let log = jobs.logs.get(job_id)?;
+        Some(JobLogSnapshot {
+            lines: log.lines.iter().cloned().collect(),
+            finished: log.tx.is_none(),
+            follow: log.tx.as_ref().map(|tx| tx.subscribe()),
+        })
+    }
+}
+
+/// Job id of a `wiki_job` span
+struct JobSpan(String);
+
+/// Tracing layer writing events of job spans to [`JobLogs`]
+pub struct JobLogLayer {
+    logs: Arc<JobLogs>,
+}
+
+impl JobLogLayer {
+    pub fn new(logs: Arc<JobLogs>) -> Self {
+        Self { logs }
+    }
+
+    /// A layer writing to [`JobLogs::shared`]
+    pub fn shared() -> Self {
+        Self::new(JobLogs::shared())
+    }
+}
+
+impl<S> Layer<S> for JobLogLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        if attrs.metadata().name() != JOB_SPAN {
+            return;
+        }
+        let mut visitor = JobIdVisitor::default();
+        attrs.record(&mut visitor);
+        let (Some(job_id), Some(span)) = (visitor.job_id, ctx.span(id)) else {
+            return;
+        };
+
+        self.logs.start(&job_id);
+        span.extensions_mut().insert(JobSpan(job_id));
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let Some(scope) = ctx.event_scope(event) else {
+            return;
+        };
+        let Some(job_id) = scope
+            .from_root()
+            .find_map(|span| span.extensions().get::<JobSpan>().map(|job| job.0.clone()))
+        else {
+            return;
+        };
+
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let metadata = event.metadata();
+        self.logs.push(
+            &job_id,
+            metadata.level().as_str(),
+            metadata.target(),
+            visitor.message,
+        );
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(&id) {
+            if let Some(job) = span.extensions().get::<JobSpan>() {
+                self.logs.finish(&job.0);
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct JobIdVisitor {
+    job_id: Option<String>,
+}
+
+impl Visit for JobIdVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "job_id" {
+            self.job_id = Some(value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "job_id" {
+            self.job_id = Some(format!("{:?}", value));
+        }
+    }
+}
+
+/// Renders an event as its message followed by `field=value` pairs
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let fields = std::mem::take(&mut self.message);
+            let _ = write!(self.message, "{:?}{}", value, fields);
+        } else {
+            let _ = write!(self.message, " {}={:?}", field.name(), value);
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.record_debug(field, &format_args!("{}", value));
+        } else {
+            let _ = write!(self.message, " {}={}", field.name(), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing::Instrument;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn test_job_logs_keep_latest_lines_and_jobs() {
+        let logs = JobLogs::new(2, 2);
+        logs.start("a");
+        for i in 0..3 {
+            logs.push("a", "INFO", "wiki", format!("line {}", i));
+        }
+        logs.push("unknown", "INFO", "wiki", "dropped".to_string());
+
+        let snapshot = logs.snapshot("a").unwrap();
+        let messages: Vec<_> = snapshot.lines.iter().map(|l| l.message.as_str()).collect();
+        assert_eq!(messages, vec!["line 1", "line 2"]);
+        assert_eq!(snapshot.lines[0].seq, 1);
+        assert!(!snapshot.finished);
+        assert!(logs.snapshot("unknown").is_none());
+
+        logs.start("b");
+        logs.start("c");
+        assert!(logs.snapshot("a").is_none());
+        assert!(logs.snapshot("c").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_layer_captures_events_of_job_span() {
+        let logs = Arc::new(JobLogs::new(10, 10));
+        let subscriber = tracing_subscriber::registry().with(JobLogLayer::new(logs.clone()));
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        tracing::info!("outside any job");
+        let follow = async {
+            tracing::warn!(files = 3, "Indexing {}", "main");
+        }
+        .instrument(tracing::info_span!(JOB_SPAN, job_id = "job-1"));
+        logs.start("job-1");
+        let mut rx = logs.snapshot("job-1").unwrap().follow.unwrap();
+        follow.await;
+
+        let snapshot = logs.snapshot("job-1").unwrap();
+        assert!(snapshot.finished);
+        assert!(snapshot.follow.is_none());
+        assert_eq!(snapshot.lines.len(), 1);
+        assert_eq!(snapshot.lines[0].level, "WARN");
+        assert_eq!(snapshot.lines[0].message, "Indexing main files=3");
+        assert_eq!(rx.recv().await.unwrap().seq, 0);
+        assert!(rx.recv().await.is_err());
+    }
+}
@@ -1,10 +1,16 @@
+pub mod auth;
 pub mod config;
+pub mod demo;
+pub mod digest;
 pub mod error;
+pub mod job_logs;
 pub mod opencode_manager;
 pub mod project_manager;
 pub mod routes;
 pub mod state;
+pub mod studio_migrations;
 
+use axum::middleware;
 use axum::routing::{get, post};
 use axum::Router;
 use tower_http::cors::CorsLayer;
@@ -25,6 +31,7 @@ use state::AppState;
     paths(
         routes::health_check,
         routes::project::get_project_info,
+        routes::dashboard::get_dashboard,
         routes::projects::open_project,
         routes::projects::init_project,
         routes::projects::get_current_project,
@@ -38,24 +45,65 @@ use state::AppState;
         routes::update_task,
         routes::delete_task,
         routes::transition_task,
+        routes::get_task_transitions,
+        routes::get_state_machine,
         routes::execute_task,
         routes::get_task_plan,
         routes::get_task_findings,
+        routes::get_task_findings_sarif,
+        routes::get_task_review_report,
+        routes::translate_findings,
         routes::fix_findings,
         routes::skip_findings,
+        routes::bulk_update_findings,
+        routes::list_finding_comments,
+        routes::add_finding_comment,
         routes::get_task_phases,
+        routes::get_task_pipeline,
+        routes::get_task_usage,
+        routes::get_usage,
+        routes::list_task_executions,
+        routes::compare_task_executions,
+        routes::task_dependencies::get_task_dependencies,
+        routes::task_dependencies::add_task_dependency,
+        routes::task_dependencies::remove_task_dependency,
+        routes::task_templates::list_task_templates,
+        routes::task_templates::create_task_template,
+        routes::task_templates::get_task_template,
+        routes::task_templates::update_task_template,
+        routes::task_templates::delete_task_template,
+        routes::task_templates::create_task_from_template,
+        routes::get_task_env,
+        routes::update_task_env,
         routes::list_sessions,
         routes::get_session,
         routes::list_sessions_for_task,
         routes::delete_session,
+        routes::retry_session,
+        routes::create_session_share,
+        routes::list_session_shares,
+        routes::revoke_session_share,
+        routes::get_shared_session,
+        routes::artifacts::list_session_artifacts,
+        routes::artifacts::download_session_artifact,
+        routes::releases::start_release_review,
+        routes::releases::list_release_reviews,
+        routes::releases::get_release_review,
 
         routes::sse::events_stream,
+        routes::event_history::list_event_history,
         routes::sse::session_activity_stream,
+        routes::jobs::get_job_logs,
         routes::list_workspaces,
         routes::create_workspace_for_task,
         routes::get_workspace_status,
         routes::get_workspace_diff,
+        routes::get_workspace_diff_summary,
         routes::merge_workspace,
+        routes::create_workspace_pull_request,
+        routes::get_workspace_conflicts,
+        routes::resolve_workspace_conflict,
+        routes::commit_workspace_resolution,
         routes::delete_workspace,
         routes::get_viewed_files,
         routes::set_file_viewed,
@@ -67,11 +115,15 @@ use state::AppState;
         routes::opencode::get_providers,
         routes::settings::get_phase_models,
         routes::settings::update_phase_models,
+        routes::settings::get_review_policy,
+        routes::settings::update_review_policy,
         routes::settings::get_github_settings,
         routes::settings::update_github_settings,
         routes::settings::delete_github_token,
         routes::complete::get_complete_preview,
         routes::complete::complete_task,
+        routes::complete::get_task_ci_gate,
+        routes::complete::restart_task_ci_gate,
         routes::complete::get_user_mode,
         routes::complete::update_user_mode,
         routes::pull_requests::list_pull_requests,
@@ -86,14 +138,41 @@ use state::AppState;
         routes::wiki::get_wiki_status,
         routes::wiki::get_remote_branches,
         routes::wiki::start_indexing,
+        routes::wiki::estimate_indexing,
+        routes::wiki_tuning::tune_wiki_chunks,
+        routes::wiki::reembed_wiki,
         routes::wiki::generate_wiki,
         routes::wiki::get_wiki_structure,
         routes::wiki::get_wiki_page,
+        routes::wiki::generate_skipped_wiki_page,
+        routes::wiki::regenerate_wiki_page,
+        routes::wiki::get_wiki_endpoints,
+        routes::wiki::get_wiki_env_vars,
+        routes::wiki::get_wiki_test_coverage,
+        routes::wiki::get_wiki_onboarding,
+        routes::wiki::get_wiki_compare,
+        routes::wiki::get_wiki_file_chunks,
         routes::wiki::search_wiki,
         routes::wiki::ask_wiki,
+        routes::wiki::ask_wiki_stream,
+        routes::wiki_feedback::submit_ask_feedback,
+        routes::wiki::trace_flow,
+        routes::wiki::list_task_wiki_updates,
         routes::wiki::handle_push_webhook,
+        routes::wiki::handle_pull_request_webhook,
         routes::wiki::get_wiki_settings,
         routes::wiki::update_wiki_settings,
+        routes::search::semantic_search,
+        routes::preferences::get_user_preferences,
+        routes::preferences::update_user_preferences,
+        routes::digest::send_test_digest,
+        routes::tokens::list_api_tokens,
+        routes::tokens::create_api_token,
+        routes::tokens::revoke_api_token,
+        routes::demo::seed_demo,
+        routes::demo::wipe_demo,
+        routes::wiki_feedback::get_wiki_feedback_stats,
+        routes::analytics::get_mcp_analytics,
         routes::roadmap::get_roadmap,
         routes::roadmap::generate_roadmap,
         routes::roadmap::get_generation_status,
@@ -107,6 +186,12 @@ use state::AppState;
     components(schemas(
         routes::HealthResponse,
         routes::projects::ProjectInfo,
+        routes::dashboard::DashboardResponse,
+        routes::dashboard::ColumnCount,
+        routes::dashboard::RunningExecution,
+        routes::dashboard::WikiFreshness,
+        routes::dashboard::MonthlySpend,
+        routes::dashboard::PendingApproval,
         routes::projects::OpenProjectRequest,
         routes::projects::OpenProjectResponse,
         routes::projects::InitProjectRequest,
@@ -122,18 +207,68 @@ use state::AppState;
         routes::projects::ClearRecentResponse,
         routes::TransitionRequest,
         routes::TransitionResponse,
+        routes::TaskTransitionsResponse,
+        routes::TaskTransition,
+        routes::StateMachineResponse,
+        routes::StateMachineStatus,
+        routes::StateMachineTransition,
+        routes::StateMachineGuard,
         routes::ExecuteResponse,
+        routes::RetrySessionResponse,
         routes::PlanResponse,
         routes::FindingsResponse,
         routes::FixFindingsRequest,
+        routes::BulkUpdateFindingsRequest,
+        routes::AddFindingCommentRequest,
+        orchestrator::FindingComment,
+        orchestrator::FindingCommentAuthor,
+        routes::BulkUpdateFindingsResponse,
+        routes::BulkFindingResult,
         routes::PhasesResponse,
         routes::PhaseInfo,
+        routes::PipelineResponse,
+        routes::UsageTotals,
+        routes::ModelUsageTotals,
+        routes::TaskUsageTotals,
+        routes::BranchUsageTotals,
+        routes::SessionUsageTotals,
+        routes::UsageReportResponse,
+        routes::TaskUsageResponse,
+        routes::PipelineStage,
+        routes::PipelineStageStatus,
+        routes::TaskDependenciesResponse,
+        routes::AddTaskDependencyRequest,
+        routes::TaskEnvResponse,
+        routes::EffectiveEnvVar,
+        routes::UpdateTaskEnvRequest,
+        routes::TaskTemplateResponse,
+        routes::TaskTemplateRequest,
+        routes::CreateTaskFromTemplateRequest,
+        orchestrator::EnvVar,
+        orchestrator::EnvVarSource,
+        routes::TokenUsage,
+        routes::TaskExecutionsResponse,
+        routes::TaskExecution,
+        routes::ExecutionPhase,
+        routes::ExecutionOutcome,
+        routes::ExecutionComparison,
         routes::PhaseStatus,
         routes::WorkspaceResponse,
         routes::WorkspaceStatusResponse,
         routes::DiffResponse,
+        routes::DiffSummaryResponse,
+        routes::DiffFileSummary,
+        routes::DiffRisk,
         routes::MergeRequest,
         routes::MergeResponse,
+        routes::CreateWorkspacePrRequest,
+        routes::WorkspacePrResponse,
+        routes::ConflictsResponse,
+        routes::ConflictedFileResponse,
+        routes::ConflictHunkResponse,
+        routes::ResolveConflictRequest,
+        routes::CommitResolutionRequest,
+        routes::CommitResolutionResponse,
         routes::ViewedFilesResponse,
         routes::SetViewedRequest,
         routes::ReviewCommentResponse,
@@ -149,10 +284,12 @@ use state::AppState;
         routes::opencode::ProvidersResponse,
         routes::settings::PhaseModelsResponse,
         routes::settings::UpdatePhaseModelsRequest,
+        routes::settings::ReviewPolicyResponse,
         routes::settings::GitHubSettingsResponse,
         routes::settings::UpdateGitHubTokenRequest,
         config::ModelSelection,
         config::PhaseModels,
+        config::PhaseMcpTools,
         config::ProjectConfig,
         config::UserMode,
         routes::complete::CompletePreviewResponse,
@@ -174,27 +311,103 @@ use state::AppState;
         routes::pull_requests::FixFromCommentsRequest,
         routes::pull_requests::FixFromCommentsResponse,
         vcs::DiffSummary,
+        vcs::VcsCapabilities,
+        vcs::ConflictType,
+        vcs::ConflictSide,
         config::WikiConfig,
+        config::PostMergeWikiUpdate,
+        config::WikiPageImportance,
+        config::WikiEmbeddingProvider,
+        config::WikiChunkTuning,
         routes::wiki::WikiStatusResponse,
         routes::wiki::RemoteBranchesResponse,
         routes::wiki::BranchStatus,
         routes::wiki::IndexRequest,
         routes::wiki::IndexResponse,
+        routes::wiki::IndexEstimateRequest,
+        routes::wiki::IndexEstimateResponse,
+        routes::wiki_tuning::ChunkTuningRequest,
+        routes::wiki_tuning::ChunkTuningResponse,
+        routes::wiki_tuning::ChunkSettingResponse,
+        routes::wiki_tuning::ChunkSettingScoreResponse,
+        routes::wiki::ReembedRequest,
+        routes::wiki::ReembedResponse,
         routes::wiki::GenerateWikiRequest,
         routes::wiki::GenerateWikiResponse,
+        routes::wiki::GenerateWikiPageRequest,
         routes::wiki::WikiStructureResponse,
+        routes::wiki::SkippedWikiPageResponse,
         routes::wiki::WikiTreeNode,
         routes::wiki::WikiPageResponse,
+        routes::wiki::WikiEndpointsResponse,
+        routes::wiki::WikiEndpoint,
+        routes::wiki::WikiEnvVarsResponse,
+        routes::wiki::WikiEnvVar,
+        routes::wiki::WikiEnvVarUsage,
+        routes::wiki::WikiTestCoverageResponse,
+        routes::wiki::WikiModuleCoverage,
+        routes::wiki::WikiOnboardingResponse,
+        routes::wiki::WikiOnboardingStep,
+        routes::wiki::WikiCompareResponse,
+        routes::wiki::WikiComparedPage,
+        routes::wiki::WikiPageChange,
+        routes::wiki::WikiFileChunksResponse,
+        routes::wiki::WikiFileChunk,
+        routes::wiki::TraceFlowRequest,
+        routes::wiki::TraceFlowResponse,
+        routes::wiki::WikiFlowStep,
+        routes::wiki::TaskWikiUpdateResponse,
+        routes::artifacts::SessionArtifactResponse,
+        routes::SessionShareResponse,
+        routes::CreateSessionShareRequest,
+        routes::CreateSessionShareResponse,
+        routes::SharedSessionResponse,
+        routes::releases::StartReleaseReviewRequest,
+        orchestrator::ReleaseReviewReport,
+        orchestrator::ReleaseReviewStatus,
+        orchestrator::ReleaseReviewTask,
+        orchestrator::ReleaseFinding,
+        orchestrator::CiGateRecord,
+        orchestrator::CiGateState,
+        routes::wiki::TaskWikiUpdatesResponse,
         routes::wiki::SearchRequest,
         routes::wiki::WikiSearchResponse,
         routes::wiki::WikiSearchResult,
         routes::wiki::AskRequest,
+        routes::wiki::AskAnswerMode,
         routes::wiki::AskResponse,
         routes::wiki::AskSource,
+        routes::wiki::AskStreamEvent,
         routes::wiki::WebhookPushRequest,
+        routes::wiki::WebhookPullRequestEvent,
+        routes::wiki::WebhookPullRequest,
+        routes::wiki::WebhookPullRequestBase,
         routes::wiki::WebhookResponse,
+        routes::jobs::JobLogsResponse,
+        job_logs::JobLogLine,
         routes::wiki::WikiSettingsResponse,
         routes::wiki::UpdateWikiSettingsRequest,
+        routes::search::SemanticSearchResponse,
+        routes::search::SemanticSearchResult,
+        routes::preferences::UserPreferencesResponse,
+        routes::preferences::UpdateUserPreferencesRequest,
+        routes::digest::SendTestDigestRequest,
+        routes::digest::SendTestDigestResponse,
+        routes::tokens::ApiTokenResponse,
+        routes::tokens::CreateApiTokenRequest,
+        routes::tokens::CreateApiTokenResponse,
+        routes::demo::SeedDemoResponse,
+        routes::demo::WipeDemoResponse,
+        routes::wiki_feedback::AskFeedbackRating,
+        routes::wiki_feedback::AskFeedbackSource,
+        routes::wiki_feedback::AskFeedbackRequest,
+        routes::wiki_feedback::AskFeedbackResponse,
+        routes::wiki_feedback::FlaggedSourceResponse,
+        routes::wiki_feedback::FlaggedPageResponse,
+        routes::wiki_feedback::WikiFeedbackStatsResponse,
+        routes::analytics::McpAnalyticsResponse,
+        routes::analytics::McpToolStats,
+        routes::analytics::McpToolCallResponse,
         opencode_core::Task,
         opencode_core::TaskStatus,
         opencode_core::CreateTaskRequest,
@@ -223,14 +436,18 @@ use state::AppState;
         routes::roadmap::RoadmapSettingsResponse,
         routes::roadmap::UpdateRoadmapSettingsRequest,
         config::RoadmapConfig,
+        config::ExecutionConfig,
+        config::EventsConfig,
+        config::DigestConfig,
     )),
     tags(
         (name = "health", description = "Health check endpoints"),
         (name = "project", description = "Legacy project info endpoints"),
         (name = "projects", description = "Project management endpoints"),
         (name = "tasks", description = "Task management endpoints"),
+        (name = "task-templates", description = "Reusable task scaffolds with placeholders"),
         (name = "sessions", description = "Session management endpoints"),
-        (name = "events", description = "Real-time event streaming (SSE)"),
+        (name = "events", description = "Real-time event streaming (SSE) and event history"),
         (name = "workspaces", description = "Workspace management endpoints"),
         (name = "comments", description = "Review comments endpoints"),
         (name = "filesystem", description = "Filesystem browsing endpoints"),
@@ -240,6 +457,13 @@ use state::AppState;
         (name = "pull-requests", description = "GitHub Pull Request management endpoints"),
         (name = "wiki", description = "Wiki documentation and search endpoints"),
         (name = "roadmap", description = "Roadmap generation and management endpoints"),
+        (name = "search", description = "Semantic search over past tasks, findings and sessions"),
+        (name = "preferences", description = "Per-user preferences and notification settings"),
+        (name = "admin", description = "API token management, wiki answer feedback and demo data"),
+        (name = "releases", description = "Combined reviews of the tasks merged for a release"),
+        (name = "analytics", description = "Usage statistics of the MCP tools sessions call"),
+        (name = "usage", description = "Tokens and estimated cost of OpenRouter calls"),
+        (name = "jobs", description = "Logs of background indexing and generation jobs"),
     )
 )]
 pub struct ApiDoc;
@@ -251,6 +475,8 @@ pub fn create_router(state: AppState) -> Router {
         .merge(SwaggerUi::new("/swagger-ui").url("/api/openapi.json", ApiDoc::openapi()))
         .route("/health", get(routes::health_check))
         .route("/api/project", get(routes::project::get_project_info))
+        .route("/api/dashboard", get(routes::dashboard::get_dashboard))
+        .route("/api/usage", get(routes::get_usage))
         .route("/api/projects/open", post(routes::projects::open_project))
         .route("/api/projects/init", post(routes::projects::init_project))
         .route(
@@ -284,12 +510,74 @@ pub fn create_router(state: AppState) -> Router {
                 .delete(routes::delete_task),
         )
         .route("/api/tasks/{id}/transition", post(routes::transition_task))
+        .route(
+            "/api/tasks/{id}/transitions",
+            get(routes::get_task_transitions),
+        )
+        .route("/api/state-machine", get(routes::get_state_machine))
         .route("/api/tasks/{id}/execute", post(routes::execute_task))
         .route("/api/tasks/{id}/plan", get(routes::get_task_plan))
         .route("/api/tasks/{id}/findings", get(routes::get_task_findings))
+        .route(
+            "/api/tasks/{id}/findings/sarif",
+            get(routes::get_task_findings_sarif),
+        )
+        .route(
+            "/api/tasks/{id}/review-report",
+            get(routes::get_task_review_report),
+        )
+        .route(
+            "/api/tasks/{id}/findings/translate",
+            post(routes::translate_findings),
+        )
         .route("/api/tasks/{id}/findings/fix", post(routes::fix_findings))
         .route("/api/tasks/{id}/findings/skip", post(routes::skip_findings))
+        .route(
+            "/api/tasks/{id}/findings/bulk",
+            axum::routing::patch(routes::bulk_update_findings),
+        )
+        .route(
+            "/api/tasks/{id}/findings/{finding_id}/comments",
+            get(routes::list_finding_comments).post(routes::add_finding_comment),
+        )
         .route("/api/tasks/{id}/phases", get(routes::get_task_phases))
+        .route("/api/tasks/{id}/pipeline", get(routes::get_task_pipeline))
+        .route("/api/tasks/{id}/usage", get(routes::get_task_usage))
+        .route(
+            "/api/tasks/{id}/executions",
+            get(routes::list_task_executions),
+        )
+        .route(
+            "/api/tasks/{id}/executions/compare",
+            get(routes::compare_task_executions),
+        )
+        .route(
+            "/api/tasks/{id}/dependencies",
+            get(routes::task_dependencies::get_task_dependencies)
+                .post(routes::task_dependencies::add_task_dependency),
+        )
+        .route(
+            "/api/tasks/{id}/dependencies/{depends_on}",
+            axum::routing::delete(routes::task_dependencies::remove_task_dependency),
+        )
+        .route(
+            "/api/task-templates",
+            get(routes::list_task_templates).post(routes::create_task_template),
+        )
+        .route(
+            "/api/task-templates/{id}",
+            get(routes::get_task_template)
+                .put(routes::update_task_template)
+                .delete(routes::delete_task_template),
+        )
+        .route(
+            "/api/task-templates/{id}/tasks",
+            post(routes::create_task_from_template),
+        )
+        .route(
+            "/api/tasks/{id}/env",
+            get(routes::get_task_env).put(routes::update_task_env),
+        )
         .route(
             "/api/tasks/{id}/diff/viewed",
             get(routes::get_viewed_files).post(routes::set_file_viewed),
@@ -319,18 +607,74 @@ pub fn create_router(state: AppState) -> Router {
             "/api/sessions/{id}",
             get(routes::get_session).delete(routes::delete_session),
         )
+        .route("/api/sessions/{id}/retry", post(routes::retry_session))
+        .route(
+            "/api/sessions/{id}/share",
+            post(routes::create_session_share),
+        )
+        .route(
+            "/api/sessions/{id}/shares",
+            get(routes::list_session_shares),
+        )
+        .route(
+            "/api/sessions/{id}/shares/{share_id}",
+            axum::routing::delete(routes::revoke_session_share),
+        )
+        .route("/api/shared/{token}", get(routes::get_shared_session))
+        .route(
+            "/api/sessions/{id}/artifacts",
+            get(routes::artifacts::list_session_artifacts),
+        )
+        .route(
+            "/api/sessions/{id}/artifacts/{artifact_id}",
+            get(routes::artifacts::download_session_artifact),
+        )
         .route(
             "/api/sessions/{id}/activity",
             get(routes::sse::session_activity_stream),
         )
         .route("/api/events", get(routes::sse::events_stream))
+        .route("/api/jobs/{id}/logs", get(routes::jobs::get_job_logs))
+        .route(
+            "/api/events/history",
+            get(routes::event_history::list_event_history),
+        )
         .route("/api/workspaces", get(routes::list_workspaces))
         .route(
             "/api/workspaces/{id}",
             get(routes::get_workspace_status).delete(routes::delete_workspace),
         )
         .route("/api/workspaces/{id}/diff", get(routes::get_workspace_diff))
+        .route(
+            "/api/workspaces/{id}/diff/summary",
+            get(routes::get_workspace_diff_summary),
+        )
         .route("/api/workspaces/{id}/merge", post(routes::merge_workspace))
+        .route(
+            "/api/workspaces/{id}/pull-request",
+            post(routes::create_workspace_pull_request),
+        )
+        .route(
+            "/api/workspaces/{id}/conflicts",
+            get(routes::get_workspace_conflicts),
+        )
+        .route(
+            "/api/workspaces/{id}/conflicts/resolve",
+            post(routes::resolve_workspace_conflict),
+        )
+        .route(
+            "/api/workspaces/{id}/conflicts/commit",
+            post(routes::commit_workspace_resolution),
+        )
+        .route(
+            "/api/releases/reviews",
+            get(routes::releases::list_release_reviews)
+                .post(routes::releases::start_release_review),
+        )
+        .route(
+            "/api/releases/reviews/{id}",
+            get(routes::releases::get_release_review),
+        )
         .route(
             "/api/filesystem/browse",
             get(routes::filesystem::browse_directory),
@@ -343,6 +687,10 @@ pub fn create_router(state: AppState) -> Router {
             "/api/settings/models",
             get(routes::settings::get_phase_models).put(routes::settings::update_phase_models),
         )
+        .route(
+            "/api/settings/review-policy",
+            get(routes::settings::get_review_policy).put(routes::settings::update_review_policy),
+        )
         .route(
             "/api/settings/user-mode",
             get(routes::complete::get_user_mode).put(routes::complete::update_user_mode),
@@ -361,6 +709,10 @@ pub fn create_router(state: AppState) -> Router {
             "/api/tasks/{id}/complete",
             post(routes::complete::complete_task),
         )
+        .route(
+            "/api/tasks/{id}/ci-gate",
+            get(routes::complete::get_task_ci_gate).post(routes::complete::restart_task_ci_gate),
+        )
         // Pull Requests routes
         .route(
             "/api/pull-requests",
@@ -401,19 +753,95 @@ pub fn create_router(state: AppState) -> Router {
             get(routes::wiki::get_remote_branches),
         )
         .route("/api/wiki/index", post(routes::wiki::start_indexing))
+        .route(
+            "/api/wiki/index/estimate",
+            post(routes::wiki::estimate_indexing),
+        )
+        .route(
+            "/api/wiki/chunk-tuning",
+            post(routes::wiki_tuning::tune_wiki_chunks),
+        )
+        .route("/api/wiki/reembed", post(routes::wiki::reembed_wiki))
         .route("/api/wiki/generate", post(routes::wiki::generate_wiki))
         .route("/api/wiki/structure", get(routes::wiki::get_wiki_structure))
         .route("/api/wiki/pages/{slug}", get(routes::wiki::get_wiki_page))
+        .route(
+            "/api/wiki/pages/{slug}/generate",
+            post(routes::wiki::generate_skipped_wiki_page),
+        )
+        .route(
+            "/api/wiki/pages/{slug}/regenerate",
+            post(routes::wiki::regenerate_wiki_page),
+        )
+        .route("/api/wiki/endpoints", get(routes::wiki::get_wiki_endpoints))
+        .route("/api/wiki/env-vars", get(routes::wiki::get_wiki_env_vars))
+        .route(
+            "/api/wiki/test-coverage",
+            get(routes::wiki::get_wiki_test_coverage),
+        )
+        .route(
+            "/api/wiki/onboarding",
+            get(routes::wiki::get_wiki_onboarding),
+        )
+        .route("/api/wiki/compare", get(routes::wiki::get_wiki_compare))
+        .route(
+            "/api/wiki/files/{path}/chunks",
+            get(routes::wiki::get_wiki_file_chunks),
+        )
         .route("/api/wiki/search", post(routes::wiki::search_wiki))
         .route("/api/wiki/ask", post(routes::wiki::ask_wiki))
+        .route("/api/wiki/ask/stream", post(routes::wiki::ask_wiki_stream))
+        .route(
+            "/api/wiki/ask/{conversation_id}/feedback",
+            post(routes::wiki_feedback::submit_ask_feedback),
+        )
+        .route("/api/wiki/trace-flow", post(routes::wiki::trace_flow))
+        .route(
+            "/api/tasks/{id}/wiki-updates",
+            get(routes::wiki::list_task_wiki_updates),
+        )
         .route(
             "/api/wiki/webhook/push",
             post(routes::wiki::handle_push_webhook),
         )
+        .route(
+            "/api/wiki/webhook/pull-request",
+            post(routes::wiki::handle_pull_request_webhook),
+        )
         .route(
             "/api/settings/wiki",
             get(routes::wiki::get_wiki_settings).put(routes::wiki::update_wiki_settings),
         )
+        .route("/api/search/semantic", get(routes::search::semantic_search))
+        .route(
+            "/api/me/preferences",
+            get(routes::preferences::get_user_preferences)
+                .put(routes::preferences::update_user_preferences),
+        )
+        .route(
+            "/api/me/digest/test",
+            post(routes::digest::send_test_digest),
+        )
+        .route(
+            "/api/admin/tokens",
+            get(routes::tokens::list_api_tokens).post(routes::tokens::create_api_token),
+        )
+        .route(
+            "/api/admin/tokens/{id}",
+            axum::routing::delete(routes::tokens::revoke_api_token),
+        )
+        .route(
+            "/api/admin/seed",
+            post(routes::demo::seed_demo).delete(routes::demo::wipe_demo),
+        )
+        .route(
+            "/api/admin/wiki-feedback",
+            get(routes::wiki_feedback::get_wiki_feedback_stats),
+        )
+        .route(
+            "/api/analytics/mcp",
+            get(routes::analytics::get_mcp_analytics),
+        )
         .route(
             "/api/roadmap",
             get(routes::roadmap::get_roadmap).delete(routes::roadmap::delete_roadmap),
@@ -439,6 +867,10 @@ pub fn create_router(state: AppState) -> Router {
             get(routes::roadmap::get_roadmap_settings)
                 .put(routes::roadmap::update_roadmap_settings),
         )
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth::require_token_scope,
+        ))
         .layer(TraceLayer::new_for_http())
         .layer(CorsLayer::permissive())
         .with_state(state);
@@ -1,4 +1,6 @@
-use server::{create_router, opencode_manager::OpenCodeManager, state::AppState};
+use server::{
+    create_router, opencode_manager::OpenCodeManager, state::AppState, studio_migrations,
+};
 use std::path::PathBuf;
 use tokio::signal;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -20,39 +22,80 @@ fn find_app_dir() -> Option<PathBuf> {
     // 2. dist/frontend (npm package structure - relative to binary)
     let npm_path = exe_dir.join("../dist/frontend");
     if npm_path.join("index.html").exists() {
-        return Some(npm_path.canonicalize().ok()?);
+        return npm_path.canonicalize().ok();
     }
 
     // 3. frontend/dist (development structure - relative to cwd)
     let dev_path = PathBuf::from("frontend/dist");
     if dev_path.join("index.html").exists() {
-        return Some(dev_path.canonicalize().ok()?);
+        return dev_path.canonicalize().ok();
     }
 
     // 4. Relative to binary (for release builds)
     let release_path = exe_dir.join("../../frontend/dist");
     if release_path.join("index.html").exists() {
-        return Some(release_path.canonicalize().ok()?);
+        return release_path.canonicalize().ok();
     }
 
     None
 }
 
+/// `migrate [--dry-run] [PROJECT_PATH]`: upgrade a project's
+/// `.opencode-studio` directory and exit. The project defaults to
+/// `PROJECT_PATH`, then the current directory.
+fn run_migrate(args: &[String]) -> anyhow::Result<()> {
+    let dry_run = args.iter().any(|a| a == "--dry-run");
+    let project_path = args
+        .iter()
+        .find(|a| !a.starts_with("--"))
+        .map(PathBuf::from)
+        .or_else(|| std::env::var("PROJECT_PATH").ok().map(PathBuf::from))
+        .map_or_else(std::env::current_dir, Ok)?;
+
+    let outcome = studio_migrations::migrate(&project_path, dry_run)?;
+    println!("{}", outcome.plan);
+    if dry_run && !outcome.plan.is_up_to_date() {
+        println!("Dry run: nothing was written");
+    }
+    if let Some(backup_dir) = outcome.backup_dir {
+        println!("Backed up changed files to {}", backup_dir.display());
+    }
+    Ok(())
+}
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     tracing_subscriber::registry()
         .with(tracing_subscriber::fmt::layer())
+        .with(server::job_logs::JobLogLayer::shared())
         .with(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| "server=debug,orchestrator=debug,wiki=info,tower_http=debug".into()),
+            tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| {
+                "server=debug,orchestrator=debug,wiki=info,tower_http=debug".into()
+            }),
         )
         .init();
 
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("migrate") {
+        return run_migrate(&args[1..]);
+    }
+
     let opencode_url =
         std::env::var("OPENCODE_URL").unwrap_or_else(|_| "http://localhost:4096".to_string());
 
     tracing::info!("OpenCode server URL: {}", opencode_url);
 
+    // Indexing and wiki queries share one OpenRouter rate-limit budget
+    if let Some(max_concurrency) = std::env::var("OPENROUTER_MAX_CONCURRENCY")
+        .ok()
+        .and_then(|n| n.parse().ok())
+    {
+        wiki::RequestScheduler::configure_shared(wiki::RateLimitConfig {
+            max_concurrency,
+            ..Default::default()
+        });
+    }
+
     // Ensure OpenCode server is running
     let mut _opencode_manager = OpenCodeManager::new(&opencode_url);
     _opencode_manager.ensure_running().await?;
@@ -2,26 +2,35 @@
 //!
 //! Handles opening, initializing, and switching between projects at runtime.
 
-use db::{SessionActivityRepository, SessionRepository, TaskRepository};
+use db::{
+    EventRepository, SessionActivityRepository, SessionRepository, TaskRepository, UsageRepository,
+};
 use events::EventBus;
 use opencode_client::apis::configuration::Configuration as OpenCodeConfig;
+use opencode_core::SessionPhase;
 use orchestrator::{
-    ExecutorConfig, ModelSelection, PhaseModels, SessionActivityRegistry, TaskExecutor,
+    ExecutorConfig, McpToolPermissions, ModelSelection, PhaseModels, PublicApiIndex,
+    ReviewConsensusConfig, SessionActivityRegistry, TaskExecutor, UsageStore, WikiContextConfig,
+    WikiMcpConfig, WorkflowDefinition,
 };
 use serde::{Deserialize, Serialize};
 use sqlx::SqlitePool;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use vcs::{GitVcs, JujutsuVcs, VersionControl, WorkspaceConfig, WorkspaceManager};
+use vcs::{VcsCapabilities, VcsKind, WorkspaceBackend, WorkspaceConfig, WorkspaceManager};
 
 use sha2::{Digest, Sha256};
 
 use crate::config::ProjectConfig as JsonProjectConfig;
+use crate::config::WikiEmbeddingProvider;
+use crate::studio_migrations::{self, CURRENT_SCHEMA_VERSION};
 
 const STUDIO_DIR: &str = ".opencode-studio";
 const GLOBAL_STUDIO_DIR: &str = ".opencode-studio";
 const PROJECT_CONFIG_FILE: &str = "config.toml";
+/// Files a project workflow may be defined in, as TOML or YAML
+const WORKFLOW_FILES: &[&str] = &["workflow.toml", "workflow.yaml", "workflow.yml"];
 const KANBAN_DIR: &str = "kanban";
 const PLANS_DIR: &str = "plans";
 const REVIEWS_DIR: &str = "reviews";
@@ -150,6 +159,10 @@ impl From<&ProjectError> for ProjectErrorCode {
 /// Project-specific configuration stored in .opencode-studio/config.toml
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectConfig {
+    /// Layout version of `.opencode-studio`; see `studio_migrations`
+    #[serde(default)]
+    pub schema_version: u32,
+
     #[serde(default)]
     pub name: Option<String>,
 
@@ -161,15 +174,21 @@ pub struct ProjectConfig {
 
     #[serde(default = "default_max_iterations")]
     pub max_iterations: u32,
+
+    /// `copy` for filesystems where git worktrees break
+    #[serde(default)]
+    pub workspace_backend: WorkspaceBackend,
 }
 
 impl Default for ProjectConfig {
     fn default() -> Self {
         Self {
+            schema_version: CURRENT_SCHEMA_VERSION,
             name: None,
             require_plan_approval: true,
             require_human_review: true,
             max_iterations: 3,
+            workspace_backend: WorkspaceBackend::default(),
         }
     }
 }
@@ -192,8 +211,11 @@ pub struct ProjectInfo {
     pub name: String,
     pub path: String,
     pub vcs: String,
+    pub vcs_capabilities: VcsCapabilities,
     pub tasks_count: i64,
     pub initialized: bool,
+    /// Seeded by demo mode
+    pub demo: bool,
 }
 
 /// Result of opening a project.
@@ -221,9 +243,12 @@ pub struct ProjectContext {
     pub pool: SqlitePool,
     pub task_repository: TaskRepository,
     pub session_repository: SessionRepository,
+    pub event_repository: EventRepository,
     pub task_executor: Arc<TaskExecutor>,
     pub workspace_manager: Arc<WorkspaceManager>,
     pub activity_registry: SessionActivityRegistry,
+    /// Stores the tokens and cost of OpenRouter calls
+    pub usage_store: Arc<UsageStore>,
     pub config: ProjectConfig,
 }
 
@@ -243,13 +268,18 @@ impl ProjectContext {
             return Err(ProjectError::NotDirectory(path));
         }
 
-        let vcs_type = detect_vcs(&path);
-        if vcs_type == "none" {
-            return Err(ProjectError::NotVcsRepo(path));
+        // Upgrade files written by older versions before reading any of them
+        let migration = studio_migrations::migrate(&path, false)?;
+        if let Some(backup_dir) = migration.backup_dir {
+            tracing::info!(
+                backup = %backup_dir.display(),
+                "Backed up .opencode-studio files before migrating"
+            );
         }
 
         let studio_dir = path.join(STUDIO_DIR);
         let config = load_project_config(&studio_dir);
+        let workflow = load_workflow(&studio_dir)?;
 
         // Migrate database from old per-project location if needed
         migrate_db_if_needed(&path).await?;
@@ -268,30 +298,81 @@ impl ProjectContext {
             .map(|p| p.join(".workspaces"))
             .unwrap_or_else(|| PathBuf::from("../.workspaces"));
 
-        let vcs = detect_vcs_impl(&path, &workspace_base);
-        let ws_config = WorkspaceConfig::new(workspace_base.clone());
-        let workspace_manager = Arc::new(WorkspaceManager::new(vcs, ws_config, path.clone()));
+        let ws_config =
+            WorkspaceConfig::new(workspace_base.clone()).with_backend(config.workspace_backend);
+        let workspace_manager = Arc::new(WorkspaceManager::detect(&path, ws_config));
 
         let session_repository = SessionRepository::new(pool.clone());
         let task_repository = TaskRepository::new(pool.clone());
         let activity_repository = SessionActivityRepository::new(pool.clone());
+        let event_repository = EventRepository::new(pool.clone());
 
         let activity_registry = SessionActivityRegistry::new().with_repository(activity_repository);
+        let usage_store = UsageStore::new(UsageRepository::new(pool.clone()));
 
         let mut opencode_config = OpenCodeConfig::new();
         opencode_config.base_path = opencode_url.to_string();
         let opencode_config = Arc::new(opencode_config);
 
-        let executor_config = ExecutorConfig::new(&path)
+        let json_config = JsonProjectConfig::read(&path).await;
+        let mut executor_config = ExecutorConfig::new(&path)
             .with_plan_approval(config.require_plan_approval)
             .with_human_review(config.require_human_review)
             .with_max_iterations(config.max_iterations)
-            .with_phase_models(convert_phase_models(&path).await);
+            .with_phase_models(convert_phase_models(&path).await)
+            .with_tool_permissions(convert_mcp_tools(&path).await)
+            .with_environment(json_config.environment)
+            .with_workflow(workflow)
+            .with_severity_calibration(json_config.execution.calibrate_review_severity)
+            .with_review_consensus(ReviewConsensusConfig {
+                samples: json_config.execution.review_samples.max(1),
+                min_agreement: json_config.execution.review_min_agreement,
+                personas: json_config.execution.review_personas,
+            })
+            .with_review_policy(json_config.execution.review_policy)
+            .with_review_language(json_config.execution.review_language)
+            .with_context_file_tokens(json_config.execution.context_file_tokens as usize)
+            .with_shared_paths(
+                json_config
+                    .execution
+                    .shared_paths
+                    .iter()
+                    .map(PathBuf::from)
+                    .collect(),
+            );
+        let wiki_config = json_config.wiki;
+        let main_branch = workspace_manager.vcs().main_branch();
+        if json_config.execution.escalate_public_api_findings {
+            if let Some(index) = convert_public_api_index(&path, &wiki_config, main_branch) {
+                executor_config = executor_config.with_public_api_index(index);
+            }
+        }
+        if wiki_config.review_context {
+            if let Some(context) = convert_wiki_context(
+                &path,
+                &wiki_config,
+                main_branch,
+                wiki_config.review_context_max_tokens,
+            ) {
+                executor_config = executor_config.with_review_context(context);
+            }
+        }
+        if wiki_config.planning_context {
+            if let Some(context) = convert_wiki_context(
+                &path,
+                &wiki_config,
+                main_branch,
+                wiki_config.planning_context_max_tokens,
+            ) {
+                executor_config = executor_config.with_planning_context(context);
+            }
+        }
 
         let task_executor = TaskExecutor::new(opencode_config, executor_config)
             .with_workspace_manager(workspace_manager.clone())
             .with_session_repo(Arc::new(session_repository.clone()))
             .with_task_repo(Arc::new(task_repository.clone()))
+            .with_usage_store(usage_store.clone())
             .with_event_bus(event_bus)
             .with_activity_registry(activity_registry.clone());
 
@@ -301,9 +382,11 @@ impl ProjectContext {
             pool,
             task_repository,
             session_repository,
+            event_repository,
             task_executor: Arc::new(task_executor),
             workspace_manager,
             activity_registry,
+            usage_store,
             config,
         })
     }
@@ -345,9 +428,11 @@ impl ProjectContext {
         ProjectInfo {
             name,
             path: self.path.display().to_string(),
-            vcs: detect_vcs(&self.path).to_string(),
+            vcs: self.workspace_manager.vcs().name().to_string(),
+            vcs_capabilities: self.workspace_manager.capabilities(),
             tasks_count,
             initialized: true,
+            demo: crate::demo::is_demo_project(&self.path),
         }
     }
 }
@@ -377,11 +462,6 @@ impl ProjectManager {
             return Err(ProjectError::NotDirectory(path.to_path_buf()));
         }
 
-        let vcs_type = detect_vcs(path);
-        if vcs_type == "none" {
-            return Err(ProjectError::NotVcsRepo(path.to_path_buf()));
-        }
-
         let studio_dir = path.join(STUDIO_DIR);
         let was_initialized = if !studio_dir.exists() {
             init_project_structure(path)?;
@@ -400,6 +480,12 @@ impl ProjectManager {
         .await?;
 
         let project_info = ctx.info().await;
+        crate::routes::search::spawn_recall_sync(ctx.clone(), &self.event_bus);
+        crate::routes::event_history::spawn_event_persistence(ctx.clone(), &self.event_bus);
+        crate::routes::spawn_queued_phases(ctx.clone(), &self.event_bus);
+        crate::routes::spawn_dependency_unblocking(ctx.clone(), &self.event_bus);
+        crate::routes::spawn_artifact_recording(ctx.clone(), &self.event_bus);
+        crate::routes::spawn_digest_scheduler(ctx.clone(), &self.event_bus);
 
         let mut guard = self.context.write().await;
         *guard = Some(ctx);
@@ -426,10 +512,7 @@ impl ProjectManager {
             return Err(ProjectError::NotDirectory(path.to_path_buf()));
         }
 
-        let vcs_type = detect_vcs(path);
-        if vcs_type == "none" {
-            return Err(ProjectError::NotVcsRepo(path.to_path_buf()));
-        }
+        let vcs_kind = VcsKind::detect(path);
 
         let studio_dir = path.join(STUDIO_DIR);
         let already_initialized = studio_dir.exists();
@@ -448,9 +531,11 @@ impl ProjectManager {
             project: ProjectInfo {
                 name,
                 path: path.display().to_string(),
-                vcs: vcs_type.to_string(),
+                vcs: vcs_kind.as_str().to_string(),
+                vcs_capabilities: vcs_kind.capabilities(),
                 tasks_count: 0,
                 initialized: true,
+                demo: crate::demo::is_demo_project(path),
             },
             already_initialized,
         })
@@ -515,46 +600,150 @@ fn load_project_config(studio_dir: &Path) -> ProjectConfig {
         .unwrap_or_default()
 }
 
+/// Load the task workflow from .opencode-studio/workflow.toml (or
+/// workflow.yaml), falling back to the built-in one when the project defines
+/// none
+fn load_workflow(studio_dir: &Path) -> Result<WorkflowDefinition, ProjectError> {
+    let mut defined = WORKFLOW_FILES
+        .iter()
+        .map(|file| studio_dir.join(file))
+        .filter(|path| path.exists());
+    let Some(workflow_path) = defined.next() else {
+        return Ok(WorkflowDefinition::builtin());
+    };
+    if let Some(other) = defined.next() {
+        return Err(ProjectError::Config(format!(
+            "{} and {} both define the workflow; keep one",
+            workflow_path.display(),
+            other.display()
+        )));
+    }
+
+    let content = std::fs::read_to_string(&workflow_path)?;
+    let workflow: WorkflowDefinition = if workflow_path.extension().is_some_and(|e| e == "toml") {
+        toml::from_str(&content).map_err(|e| e.to_string())
+    } else {
+        serde_yaml::from_str(&content).map_err(|e| e.to_string())
+    }
+    .map_err(|e| ProjectError::Config(format!("{}: {}", workflow_path.display(), e)))?;
+    workflow
+        .validate()
+        .map_err(|e| ProjectError::Config(format!("{}: {}", workflow_path.display(), e)))?;
+
+    tracing::info!(workflow = %workflow.name, "Loaded project workflow");
+    Ok(workflow)
+}
+
 /// Detect VCS type from a path.
 pub fn detect_vcs(path: &Path) -> &'static str {
-    if path.join(".jj").exists() {
-        "jujutsu"
-    } else if path.join(".git").exists() {
-        "git"
+    VcsKind::detect(path).as_str()
+}
+
+/// Wiki branch phase context is read from: the main branch when it is
+/// indexed and the first indexed branch otherwise
+fn wiki_context_branch(
+    wiki_config: &crate::config::WikiConfig,
+    main_branch: &str,
+) -> Option<String> {
+    if wiki_config.branches.iter().any(|b| b == main_branch) {
+        Some(main_branch.to_string())
     } else {
-        "none"
+        wiki_config.branches.first().cloned()
+    }
+}
+
+/// Wiki index whose public API tags raise review finding severities
+fn convert_public_api_index(
+    project_path: &Path,
+    wiki_config: &crate::config::WikiConfig,
+    main_branch: &str,
+) -> Option<PublicApiIndex> {
+    if !wiki_config.enabled {
+        return None;
+    }
+    Some(PublicApiIndex {
+        db_path: project_path.join(".opencode-studio").join("wiki.db"),
+        branch: wiki_context_branch(wiki_config, main_branch)?,
+    })
+}
+
+/// Wiki context for phase prompts, read from the main branch's index when
+/// it is indexed and the first indexed branch otherwise
+fn convert_wiki_context(
+    project_path: &Path,
+    wiki_config: &crate::config::WikiConfig,
+    main_branch: &str,
+    max_tokens: u32,
+) -> Option<WikiContextConfig> {
+    if !wiki_config.enabled {
+        return None;
+    }
+    // Embedding with a local model needs no OpenRouter key
+    let api_key = match wiki_config.openrouter_api_key.clone() {
+        Some(key) => key,
+        None if wiki_config.embedding_provider == WikiEmbeddingProvider::Local => String::new(),
+        None => return None,
+    };
+
+    let branch = wiki_context_branch(wiki_config, main_branch)?;
+
+    let db_path = project_path.join(".opencode-studio").join("wiki.db");
+    let mut wiki = WikiMcpConfig::new(api_key, db_path);
+    if let Some(ref model) = wiki_config.embedding_model {
+        wiki = wiki.with_embedding_model(model);
     }
+    wiki = wiki.with_embedding_provider(
+        wiki_config.embedding_provider.into(),
+        wiki_config.openai_api_key.clone(),
+        wiki_config
+            .local_embedding_model_path
+            .as_ref()
+            .map(Into::into),
+    );
+
+    Some(WikiContextConfig {
+        wiki,
+        branch,
+        max_tokens: max_tokens as usize,
+    })
 }
 
 async fn convert_phase_models(project_path: &Path) -> PhaseModels {
     let json_config = JsonProjectConfig::read(project_path).await;
+    executor_phase_models(json_config.phase_models)
+}
 
+/// Phase models from `config.json` in the executor's form
+pub(crate) fn executor_phase_models(models: crate::config::PhaseModels) -> PhaseModels {
     let convert_model = |m: Option<crate::config::ModelSelection>| -> Option<ModelSelection> {
         m.map(|s| ModelSelection::new(s.provider_id, s.model_id))
     };
 
     PhaseModels {
-        planning: convert_model(json_config.phase_models.planning),
-        implementation: convert_model(json_config.phase_models.implementation),
-        review: convert_model(json_config.phase_models.review),
-        fix: convert_model(json_config.phase_models.fix),
+        planning: convert_model(models.planning),
+        implementation: convert_model(models.implementation),
+        review: convert_model(models.review),
+        fix: convert_model(models.fix),
     }
 }
 
-fn detect_vcs_impl(repo_path: &Path, workspace_base: &Path) -> Arc<dyn VersionControl> {
-    if repo_path.join(".jj").exists() {
-        tracing::info!("Detected Jujutsu repository");
-        Arc::new(JujutsuVcs::new(
-            repo_path.to_path_buf(),
-            workspace_base.to_path_buf(),
-        ))
-    } else {
-        tracing::info!("Using Git as VCS backend");
-        Arc::new(GitVcs::new(
-            repo_path.to_path_buf(),
-            workspace_base.to_path_buf(),
-        ))
-    }
+async fn convert_mcp_tools(project_path: &Path) -> McpToolPermissions {
+    let mcp_tools = JsonProjectConfig::read(project_path).await.mcp_tools;
+
+    [
+        (SessionPhase::Planning, mcp_tools.planning),
+        (SessionPhase::Implementation, mcp_tools.implementation),
+        (SessionPhase::Review, mcp_tools.review),
+        (SessionPhase::Fix, mcp_tools.fix),
+    ]
+    .into_iter()
+    .fold(
+        McpToolPermissions::default_profiles(),
+        |permissions, (phase, tools)| match tools {
+            Some(tools) => permissions.with_phase(phase, tools),
+            None => permissions,
+        },
+    )
 }
 
 const GLOBAL_CONFIG_FILE: &str = "global.toml";
@@ -777,6 +966,104 @@ mod tests {
         assert!(config.require_plan_approval);
         assert!(config.require_human_review);
         assert_eq!(config.max_iterations, 3);
+        assert_eq!(config.workspace_backend, WorkspaceBackend::Native);
+    }
+
+    #[test]
+    fn test_load_project_config_workspace_backend() {
+        let tmp = TempDir::new().unwrap();
+        std::fs::write(
+            tmp.path().join(PROJECT_CONFIG_FILE),
+            "workspace_backend = \"copy\"\n",
+        )
+        .unwrap();
+
+        let config = load_project_config(tmp.path());
+        assert_eq!(config.workspace_backend, WorkspaceBackend::Copy);
+    }
+
+    #[test]
+    fn test_load_workflow() {
+        let tmp = TempDir::new().unwrap();
+        assert_eq!(
+            load_workflow(tmp.path()).unwrap(),
+            WorkflowDefinition::builtin()
+        );
+
+        std::fs::write(
+            tmp.path().join("workflow.toml"),
+            r#"
+name = "lightweight"
+states = ["todo", "in_progress", "done"]
+
+[[transitions]]
+from = "todo"
+to = "in_progress"
+run_phase = true
+
+[[transitions]]
+from = "in_progress"
+to = "done"
+requires_approval = true
+
+[[rules]]
+on = "implementation_completed"
+to = "done"
+"#,
+        )
+        .unwrap();
+        let workflow = load_workflow(tmp.path()).unwrap();
+        assert_eq!(workflow.name, "lightweight");
+        assert!(workflow.requires_approval(
+            &opencode_core::TaskStatus::InProgress,
+            &opencode_core::TaskStatus::Done
+        ));
+        assert_eq!(workflow.rules.len(), 1);
+        assert!(workflow
+            .rule(orchestrator::TransitionTrigger::ImplementationCompleted)
+            .is_some());
+
+        std::fs::write(
+            tmp.path().join("workflow.toml"),
+            "name = \"broken\"\nstates = [\"todo\"]\ntransitions = []\n",
+        )
+        .unwrap();
+        let err = load_workflow(tmp.path()).unwrap_err();
+        assert!(err.to_string().contains("must include 'done'"));
+    }
+
+    #[test]
+    fn test_load_workflow_yaml() {
+        let tmp = TempDir::new().unwrap();
+        std::fs::write(
+            tmp.path().join("workflow.yaml"),
+            r#"
+name: lightweight
+states: [todo, in_progress, done]
+transitions:
+  - from: todo
+    to: in_progress
+    run_phase: true
+  - from: in_progress
+    to: done
+    requires_approval: true
+rules:
+  - on: implementation_completed
+    to: done
+"#,
+        )
+        .unwrap();
+        let workflow = load_workflow(tmp.path()).unwrap();
+        assert_eq!(workflow.name, "lightweight");
+        assert!(workflow.requires_approval(
+            &opencode_core::TaskStatus::InProgress,
+            &opencode_core::TaskStatus::Done
+        ));
+        assert_eq!(workflow.rules.len(), 1);
+
+        std::fs::write(tmp.path().join("workflow.toml"), "name = \"other\"\n").unwrap();
+        let err = load_workflow(tmp.path()).unwrap_err();
+        assert!(err.to_string().contains("both define the workflow"));
     }
 
     #[test]
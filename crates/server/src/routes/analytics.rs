@@ -0,0 +1,384 @@
+//! Usage analytics of the MCP servers attached to sessions
+//!
+//! The servers record every tool call in the project's tool call log (see
+//! `opencode_core::McpToolCall`); this aggregates it per tool and flags tools
+//! that fail often, e.g. because of a broken index or a misleading schema.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+use axum::extract::{Query, State};
+use axum::Json;
+use chrono::{DateTime, Utc};
+use opencode_core::{rotated_tool_call_log_path, tool_call_log_path, McpToolCall};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::error::AppError;
+use crate::state::AppState;
+
+/// Share of failed calls at which a tool is flagged
+pub const ERROR_RATE_THRESHOLD: f64 = 0.25;
+
+/// Calls a tool needs before it can be flagged, so that one early failure
+/// does not flag it
+const MIN_CALLS_TO_FLAG: u64 = 5;
+
+/// Failed calls listed in the analytics
+const RECENT_ERRORS_LIMIT: usize = 20;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct McpToolCallResponse {
+    pub server: String,
+    pub tool: String,
+    pub session_id: Option<String>,
+    pub called_at: DateTime<Utc>,
+    pub duration_ms: u64,
+    pub error: Option<String>,
+}
+
+impl From<&McpToolCall> for McpToolCallResponse {
+    fn from(call: &McpToolCall) -> Self {
+        Self {
+            server: call.server.clone(),
+            tool: call.tool.clone(),
+            session_id: call.session_id.clone(),
+            called_at: call.called_at,
+            duration_ms: call.duration_ms,
+            error: call.error.clone(),
+        }
+    }
+}
+
+/// Calls of one tool of one MCP server
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct McpToolStats {
+    pub server: String,
+    pub tool: String,
+    pub calls: u64,
+    pub errors: u64,
+    /// Share of failed calls, from 0 to 1
+    pub error_rate: f64,
+    pub avg_duration_ms: u64,
+    pub p95_duration_ms: u64,
+    /// Sessions that called the tool
+    pub sessions: u64,
+    pub last_called_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+    /// Whether the tool fails often enough to look into
+    pub flagged: bool,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct McpAnalyticsResponse {
+    pub total_calls: u64,
+    pub total_errors: u64,
+    /// Error rate at which tools are flagged
+    pub error_rate_threshold: f64,
+    /// Flagged tools first, then most called
+    pub tools: Vec<McpToolStats>,
+    /// Latest failed calls, newest first
+    pub recent_errors: Vec<McpToolCallResponse>,
+}
+
+/// Which recorded calls to aggregate
+#[derive(Debug, Default)]
+struct CallFilter {
+    /// Case-insensitive part of the tool or server name
+    query: Option<String>,
+    server: Option<String>,
+    session_id: Option<String>,
+    since: Option<DateTime<Utc>>,
+}
+
+impl CallFilter {
+    fn from_query(params: &HashMap<String, String>) -> Result<Self, AppError> {
+        let value = |key: &str| params.get(key).filter(|s| !s.is_empty()).cloned();
+        let since = value("since")
+            .map(|since| {
+                DateTime::parse_from_rfc3339(&since)
+                    .map(|dt| dt.with_timezone(&Utc))
+                    .map_err(|_| AppError::BadRequest(format!("Invalid since '{}'", since)))
+            })
+            .transpose()?;
+
+        Ok(Self {
+            query: value("q").map(|q| q.to_lowercase()),
+            server: value("server"),
+            session_id: value("session_id"),
+            since,
+        })
+    }
+
+    fn matches(&self, call: &McpToolCall) -> bool {
+        if let Some(query) = &self.query {
+            if !call.tool.to_lowercase().contains(query)
+                && !call.server.to_lowercase().contains(query)
+            {
+                return false;
+            }
+        }
+        if let Some(server) = &self.server {
+            if call.server != *server {
+                return false;
+            }
+        }
+        if let Some(session_id) = &self.session_id {
+            if call.session_id.as_ref() != Some(session_id) {
+                return false;
+            }
+        }
+        if let Some(since) = self.since {
+            if call.called_at < since {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Nearest-rank percentile of sorted `values`
+fn percentile(sorted: &[u64], pct: f64) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = (pct * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn tool_stats(calls: &[&McpToolCall]) -> McpToolStats {
+    let mut durations: Vec<u64> = calls.iter().map(|c| c.duration_ms).collect();
+    durations.sort_unstable();
+    let count = calls.len() as u64;
+    let errors = calls.iter().filter(|c| !c.success).count() as u64;
+    let error_rate = errors as f64 / count as f64;
+    let sessions: BTreeSet<&str> = calls
+        .iter()
+        .filter_map(|c| c.session_id.as_deref())
+        .collect();
+    let last = calls
+        .iter()
+        .max_by_key(|c| c.called_at)
+        .expect("stats of a tool with calls");
+    let last_error = calls
+        .iter()
+        .filter(|c| !c.success)
+        .max_by_key(|c| c.called_at)
+        .and_then(|c| c.error.clone());
+
+    McpToolStats {
+        server: last.server.clone(),
+        tool: last.tool.clone(),
+        calls: count,
+        errors,
+        error_rate,
+        avg_duration_ms: durations.iter().sum::<u64>() / count,
+        p95_duration_ms: percentile(&durations, 0.95),
+        sessions: sessions.len() as u64,
+        last_called_at: last.called_at,
+        last_error,
+        flagged: count >= MIN_CALLS_TO_FLAG && error_rate >= ERROR_RATE_THRESHOLD,
+    }
+}
+
+/// Calls recorded in the tool call log at `log_path`, including those rotated
+/// out of it, oldest first. Lines are read one at a time; those that do not
+/// parse, e.g. a torn last line, are skipped.
+fn read_tool_calls(log_path: &Path) -> io::Result<impl Iterator<Item = McpToolCall>> {
+    let mut files = Vec::new();
+    for path in [rotated_tool_call_log_path(log_path), log_path.to_path_buf()] {
+        match File::open(&path) {
+            Ok(file) => files.push(BufReader::new(file)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(files
+        .into_iter()
+        .flat_map(|file| file.lines())
+        .map_while(Result::ok)
+        .filter_map(|line| serde_json::from_str(&line).ok()))
+}
+
+/// Aggregate the `calls` that match `filter`, keeping only those
+fn aggregate(
+    calls: impl IntoIterator<Item = McpToolCall>,
+    filter: &CallFilter,
+) -> McpAnalyticsResponse {
+    let calls: Vec<McpToolCall> = calls.into_iter().filter(|c| filter.matches(c)).collect();
+
+    let mut by_tool: BTreeMap<(&str, &str), Vec<&McpToolCall>> = BTreeMap::new();
+    for call in &calls {
+        by_tool
+            .entry((call.server.as_str(), call.tool.as_str()))
+            .or_default()
+            .push(call);
+    }
+    let mut tools: Vec<McpToolStats> = by_tool.values().map(|calls| tool_stats(calls)).collect();
+    tools.sort_by(|a, b| b.flagged.cmp(&a.flagged).then(b.calls.cmp(&a.calls)));
+
+    let mut errors: Vec<&McpToolCall> = calls.iter().filter(|c| !c.success).collect();
+    errors.sort_by_key(|c| Reverse(c.called_at));
+
+    McpAnalyticsResponse {
+        total_calls: calls.len() as u64,
+        total_errors: errors.len() as u64,
+        error_rate_threshold: ERROR_RATE_THRESHOLD,
+        tools,
+        recent_errors: errors
+            .into_iter()
+            .take(RECENT_ERRORS_LIMIT)
+            .map(McpToolCallResponse::from)
+            .collect(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/analytics/mcp",
+    params(
+        ("q" = Option<String>, Query, description = "Only tools whose name or server contains this text"),
+        ("server" = Option<String>, Query, description = "Only tools of this MCP server, e.g. opencode-wiki"),
+        ("session_id" = Option<String>, Query, description = "Only calls made by this session"),
+        ("since" = Option<String>, Query, description = "Only calls at or after this RFC 3339 time")
+    ),
+    responses(
+        (status = 200, description = "Tool call statistics per MCP tool, flagging tools with high error rates", body = McpAnalyticsResponse),
+        (status = 400, description = "Invalid filter")
+    ),
+    tag = "analytics"
+)]
+pub async fn get_mcp_analytics(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<McpAnalyticsResponse>, AppError> {
+    let filter = CallFilter::from_query(&params)?;
+    let project = state.project().await?;
+    let log_path = tool_call_log_path(&project.project_path);
+
+    let analytics = tokio::task::spawn_blocking(move || {
+        read_tool_calls(&log_path).map(|calls| aggregate(calls, &filter))
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("Task join error: {}", e)))?
+    .map_err(|e| AppError::Internal(format!("Failed to read tool call log: {}", e)))?;
+
+    Ok(Json(analytics))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn call(
+        tool: &str,
+        session: &str,
+        minutes_ago: i64,
+        ms: u64,
+        error: Option<&str>,
+    ) -> McpToolCall {
+        McpToolCall::new(
+            if tool == "search_code" {
+                "opencode-wiki"
+            } else {
+                "opencode-findings"
+            },
+            tool,
+            Some(session.to_string()),
+            Utc::now() - chrono::Duration::minutes(minutes_ago),
+            Duration::from_millis(ms),
+            error.map(str::to_string),
+        )
+    }
+
+    fn calls() -> Vec<McpToolCall> {
+        let mut calls: Vec<McpToolCall> = (0..10)
+            .map(|i| call("list_findings", "s1", i, 10 * (i as u64 + 1), None))
+            .collect();
+        calls.extend([
+            call("search_code", "s1", 50, 200, Some("index missing")),
+            call("search_code", "s2", 40, 300, None),
+            call("search_code", "s2", 30, 100, Some("index stale")),
+            call("search_code", "s2", 20, 400, None),
+            call("search_code", "s2", 10, 500, Some("timeout")),
+        ]);
+        calls
+    }
+
+    #[test]
+    fn test_aggregate_flags_failing_tools() {
+        let analytics = aggregate(calls(), &CallFilter::default());
+
+        assert_eq!(analytics.total_calls, 15);
+        assert_eq!(analytics.total_errors, 3);
+        assert_eq!(analytics.tools.len(), 2);
+
+        let search = &analytics.tools[0];
+        assert_eq!(search.tool, "search_code");
+        assert!(search.flagged);
+        assert_eq!((search.calls, search.errors), (5, 3));
+        assert!((search.error_rate - 0.6).abs() < f64::EPSILON);
+        assert_eq!(search.avg_duration_ms, 300);
+        assert_eq!(search.p95_duration_ms, 500);
+        assert_eq!(search.sessions, 2);
+        assert_eq!(search.last_error.as_deref(), Some("timeout"));
+
+        let findings = &analytics.tools[1];
+        assert!(!findings.flagged);
+        assert_eq!(findings.p95_duration_ms, 100);
+        assert_eq!(analytics.recent_errors[0].error.as_deref(), Some("timeout"));
+    }
+
+    #[test]
+    fn test_aggregate_filters_calls() {
+        let filter = CallFilter::from_query(&HashMap::from([
+            ("q".to_string(), "SEARCH".to_string()),
+            ("session_id".to_string(), "s2".to_string()),
+        ]))
+        .unwrap();
+        let analytics = aggregate(calls(), &filter);
+        assert_eq!(analytics.total_calls, 4);
+        // Too few calls to flag the tool
+        assert!(!analytics.tools[0].flagged);
+
+        let since = (Utc::now() - chrono::Duration::seconds(270)).to_rfc3339();
+        let filter =
+            CallFilter::from_query(&HashMap::from([("since".to_string(), since)])).unwrap();
+        assert_eq!(aggregate(calls(), &filter).total_calls, 5);
+
+        assert!(CallFilter::from_query(&HashMap::from([(
+            "since".to_string(),
+            "today".to_string()
+        )]))
+        .is_err());
+    }
+
+    #[test]
+    fn test_read_tool_calls_streams_rotated_log_first() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = tool_call_log_path(dir.path());
+        assert_eq!(read_tool_calls(&path).unwrap().count(), 0);
+
+        let line = |call: &McpToolCall| serde_json::to_string(call).unwrap() + "\n";
+        let old = call("search_code", "s1", 20, 200, Some("index missing"));
+        let new = call("list_findings", "s2", 10, 20, None);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(rotated_tool_call_log_path(&path), line(&old)).unwrap();
+        std::fs::write(&path, line(&new) + "{\"server\":").unwrap();
+
+        let read: Vec<McpToolCall> = read_tool_calls(&path).unwrap().collect();
+        assert_eq!(read, vec![old, new]);
+        assert_eq!(aggregate(read, &CallFilter::default()).total_errors, 1);
+    }
+}
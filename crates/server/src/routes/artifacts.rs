@@ -0,0 +1,406 @@
+//! Files sessions produce
+//!
+//! Each phase leaves something behind: the plan, the review and its findings,
+//! phase summaries, the diff of an implementation. When a session ends they
+//! are copied into a content-addressed store under
+//! `.opencode-studio/artifacts` and recorded against the session, so they
+//! survive the task's kanban directory being rewritten or removed.
+
+use std::io;
+use std::path::{Path as FsPath, PathBuf};
+
+use axum::extract::{Path, State};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use db::{CreateSessionArtifact, SessionArtifactRepository};
+use events::{Event, EventBus};
+use opencode_core::SessionPhase;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{debug, warn};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::project_manager::ProjectContext;
+use crate::state::AppState;
+
+const ARTIFACTS_DIR: &str = "artifacts";
+
+const MARKDOWN: &str = "text/markdown; charset=utf-8";
+const JSON: &str = "application/json";
+const DIFF: &str = "text/x-diff; charset=utf-8";
+
+/// Content-addressed blob store; a blob is named by the SHA-256 of its
+/// content, so the same file registered twice is stored once
+#[derive(Debug, Clone)]
+pub struct ArtifactStore {
+    dir: PathBuf,
+}
+
+impl ArtifactStore {
+    pub fn new(project_path: &FsPath) -> Self {
+        Self {
+            dir: project_path.join(".opencode-studio").join(ARTIFACTS_DIR),
+        }
+    }
+
+    fn blob_path(&self, hash: &str) -> PathBuf {
+        self.dir.join(&hash[..2]).join(hash)
+    }
+
+    /// Store `content`, returning its hash
+    pub async fn put(&self, content: &[u8]) -> io::Result<String> {
+        let hash = hex::encode(Sha256::digest(content));
+        let path = self.blob_path(&hash);
+        if tokio::fs::try_exists(&path).await.unwrap_or(false) {
+            return Ok(hash);
+        }
+
+        let parent = path.parent().unwrap_or(&self.dir);
+        tokio::fs::create_dir_all(parent).await?;
+        let temp_path = parent.join(format!(".{}.tmp", Uuid::new_v4()));
+        tokio::fs::write(&temp_path, content).await?;
+        tokio::fs::rename(&temp_path, &path).await?;
+        Ok(hash)
+    }
+
+    pub async fn read(&self, hash: &str) -> io::Result<Vec<u8>> {
+        if hash.len() < 2 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Invalid artifact hash '{}'", hash),
+            ));
+        }
+        tokio::fs::read(self.blob_path(hash)).await
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct SessionArtifactResponse {
+    pub id: String,
+    pub session_id: String,
+    pub task_id: String,
+    /// `plan`, `review`, `findings`, `phase_summary` or `diff`
+    pub kind: String,
+    pub name: String,
+    /// SHA-256 of the content
+    pub content_hash: String,
+    pub size_bytes: i64,
+    pub media_type: String,
+    pub created_at: i64,
+}
+
+impl From<db::SessionArtifact> for SessionArtifactResponse {
+    fn from(artifact: db::SessionArtifact) -> Self {
+        Self {
+            id: artifact.id,
+            session_id: artifact.session_id,
+            task_id: artifact.task_id,
+            kind: artifact.kind,
+            name: artifact.name,
+            content_hash: artifact.content_hash,
+            size_bytes: artifact.size_bytes,
+            media_type: artifact.media_type,
+            created_at: artifact.created_at,
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/sessions/{id}/artifacts",
+    params(
+        ("id" = Uuid, Path, description = "Session ID")
+    ),
+    responses(
+        (status = 200, description = "Artifacts of the session, oldest first", body = Vec<SessionArtifactResponse>),
+        (status = 404, description = "Session not found")
+    ),
+    tag = "sessions"
+)]
+pub async fn list_session_artifacts(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<SessionArtifactResponse>>, AppError> {
+    let project = state.project().await?;
+    project
+        .session_repository
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Session not found: {}", id)))?;
+
+    let artifacts = SessionArtifactRepository::new(project.pool.clone())
+        .find_by_session_id(&id.to_string())
+        .await?;
+    Ok(Json(artifacts.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/sessions/{id}/artifacts/{artifact_id}",
+    params(
+        ("id" = Uuid, Path, description = "Session ID"),
+        ("artifact_id" = String, Path, description = "Artifact ID")
+    ),
+    responses(
+        (status = 200, description = "Artifact content", content_type = "application/octet-stream", body = Vec<u8>),
+        (status = 404, description = "Artifact not found")
+    ),
+    tag = "sessions"
+)]
+pub async fn download_session_artifact(
+    State(state): State<AppState>,
+    Path((id, artifact_id)): Path<(Uuid, String)>,
+) -> Result<Response, AppError> {
+    let project = state.project().await?;
+    let artifact = SessionArtifactRepository::new(project.pool.clone())
+        .find_by_id(&artifact_id)
+        .await?
+        .filter(|a| a.session_id == id.to_string())
+        .ok_or_else(|| AppError::NotFound(format!("Artifact not found: {}", artifact_id)))?;
+
+    let content = ArtifactStore::new(&project.project_path)
+        .read(&artifact.content_hash)
+        .await
+        .map_err(|e| match e.kind() {
+            io::ErrorKind::NotFound => {
+                AppError::NotFound(format!("Content of artifact {} is missing", artifact.id))
+            }
+            _ => AppError::Internal(format!("Failed to read artifact {}: {}", artifact.id, e)),
+        })?;
+
+    let disposition = format!(
+        "attachment; filename=\"{}\"",
+        artifact.name.replace(['"', '\\'], "_")
+    );
+    Ok((
+        [
+            (header::CONTENT_TYPE, artifact.media_type),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        content,
+    )
+        .into_response())
+}
+
+/// Store `content` and record it as an artifact of the session
+pub(crate) async fn register_artifact(
+    project: &ProjectContext,
+    session_id: Uuid,
+    task_id: Uuid,
+    kind: &str,
+    name: &str,
+    media_type: &str,
+    content: &[u8],
+) -> Result<db::SessionArtifact, AppError> {
+    let hash = ArtifactStore::new(&project.project_path)
+        .put(content)
+        .await
+        .map_err(|e| AppError::Internal(format!("Failed to store artifact {}: {}", name, e)))?;
+
+    let artifact = SessionArtifactRepository::new(project.pool.clone())
+        .create(&CreateSessionArtifact {
+            session_id: &session_id.to_string(),
+            task_id: &task_id.to_string(),
+            kind,
+            name,
+            content_hash: &hash,
+            size_bytes: content.len() as i64,
+            media_type,
+        })
+        .await?;
+    debug!(session_id = %session_id, name = %name, hash = %hash, "Artifact registered");
+    Ok(artifact)
+}
+
+/// Register the outputs of sessions as they end, until the project is closed
+pub fn spawn_artifact_recording(project: ProjectContext, event_bus: &EventBus) {
+    let mut rx = event_bus.subscribe();
+    let project_path = project.path.display().to_string();
+
+    tokio::spawn(async move {
+        loop {
+            match rx.recv().await {
+                Ok(envelope) => match envelope.event {
+                    Event::SessionEnded {
+                        session_id,
+                        task_id,
+                        success: true,
+                    } => {
+                        record_session_outputs(&project, session_id, task_id).await;
+                    }
+                    // Summaries of async phases are written after the
+                    // session ends
+                    Event::PhaseCompleted {
+                        task_id,
+                        session_id,
+                        phase_number,
+                        ..
+                    } => {
+                        let path = project
+                            .task_executor
+                            .file_manager()
+                            .phase_summary_path(task_id, phase_number);
+                        let name = format!("phase-{}-summary.json", phase_number);
+                        record_file(
+                            &project,
+                            session_id,
+                            task_id,
+                            "phase_summary",
+                            &name,
+                            JSON,
+                            &path,
+                        )
+                        .await;
+                    }
+                    Event::ProjectClosed { path } if path == project_path => break,
+                    _ => {}
+                },
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
+async fn record_session_outputs(project: &ProjectContext, session_id: Uuid, task_id: Uuid) {
+    let session = match project.session_repository.find_by_id(session_id).await {
+        Ok(Some(session)) => session,
+        Ok(None) => return,
+        Err(e) => {
+            warn!(session_id = %session_id, error = %e, "Failed to load session for its artifacts");
+            return;
+        }
+    };
+    let files = project.task_executor.file_manager();
+
+    match session.phase {
+        SessionPhase::Planning => {
+            let path = files.plan_path(task_id);
+            record_file(
+                project, session_id, task_id, "plan", "plan.md", MARKDOWN, &path,
+            )
+            .await;
+        }
+        SessionPhase::Review => {
+            let path = files.review_path(task_id);
+            record_file(
+                project,
+                session_id,
+                task_id,
+                "review",
+                "review.md",
+                MARKDOWN,
+                &path,
+            )
+            .await;
+            let path = files.findings_path(task_id);
+            record_file(
+                project,
+                session_id,
+                task_id,
+                "findings",
+                "findings.json",
+                JSON,
+                &path,
+            )
+            .await;
+        }
+        SessionPhase::Implementation | SessionPhase::Fix => {
+            record_diff(project, session_id, task_id).await;
+        }
+    }
+}
+
+/// Register the file at `path`, if the phase wrote it
+async fn record_file(
+    project: &ProjectContext,
+    session_id: Uuid,
+    task_id: Uuid,
+    kind: &str,
+    name: &str,
+    media_type: &str,
+    path: &FsPath,
+) {
+    let content = match tokio::fs::read(path).await {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return,
+        Err(e) => {
+            warn!(path = %path.display(), error = %e, "Failed to read session output");
+            return;
+        }
+    };
+    if let Err(e) = register_artifact(
+        project, session_id, task_id, kind, name, media_type, &content,
+    )
+    .await
+    {
+        warn!(session_id = %session_id, name = %name, error = ?e, "Failed to register artifact");
+    }
+}
+
+/// Register the changes in the task's workspace
+async fn record_diff(project: &ProjectContext, session_id: Uuid, task_id: Uuid) {
+    let task_id_str = task_id.to_string();
+    let workspace = match project.workspace_manager.list_workspaces().await {
+        Ok(workspaces) => workspaces.into_iter().find(|ws| ws.task_id == task_id_str),
+        Err(e) => {
+            warn!(task_id = %task_id, error = %e, "Failed to list workspaces for the diff artifact");
+            return;
+        }
+    };
+    let Some(workspace) = workspace else {
+        return;
+    };
+    let diff = match project.workspace_manager.get_diff(&workspace).await {
+        Ok(diff) => diff,
+        Err(e) => {
+            warn!(task_id = %task_id, error = %e, "Failed to capture the diff artifact");
+            return;
+        }
+    };
+    if diff.trim().is_empty() {
+        return;
+    }
+    if let Err(e) = register_artifact(
+        project,
+        session_id,
+        task_id,
+        "diff",
+        "changes.diff",
+        DIFF,
+        diff.as_bytes(),
+    )
+    .await
+    {
+        warn!(session_id = %session_id, error = ?e, "Failed to register diff artifact");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_store_dedupes_content() {
+        let dir = TempDir::new().unwrap();
+        let store = ArtifactStore::new(dir.path());
+
+        let hash = store.put(b"# Plan\n").await.unwrap();
+        assert_eq!(hash.len(), 64);
+        assert_eq!(store.put(b"# Plan\n").await.unwrap(), hash);
+        assert_ne!(store.put(b"# Other plan\n").await.unwrap(), hash);
+        assert_eq!(store.read(&hash).await.unwrap(), b"# Plan\n");
+
+        assert!(store
+            .blob_path(&hash)
+            .starts_with(dir.path().join(".opencode-studio")));
+        assert!(store.read("../../etc/passwd").await.is_err());
+    }
+}
@@ -1,14 +1,23 @@
+use std::sync::Arc;
+
 use axum::extract::{Path, State};
+use axum::http::StatusCode;
 use axum::Json;
-use github::{CreatePrRequest, GhCli, RepoConfig};
+use events::{Event, EventEnvelope};
+use github::{CreatePrRequest, GhCli, PullRequest, RepoConfig};
 use opencode_core::{TaskStatus, UpdateTaskRequest};
+use orchestrator::{CiGate, CiGateRecord, CiGateState};
 use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
 use utoipa::ToSchema;
 use uuid::Uuid;
-use vcs::DiffSummary;
+use vcs::{DiffSummary, Workspace};
 
-use crate::config::UserMode;
+use crate::config::{ProjectConfig, UserMode};
 use crate::error::AppError;
+use crate::project_manager::ProjectContext;
+use crate::routes::releases::record_merge;
+use crate::routes::wiki::{schedule_merge_reindex, schedule_post_merge_update};
 use crate::state::AppState;
 
 // ============================================================================
@@ -106,7 +115,58 @@ pub async fn get_complete_preview(
     }))
 }
 
-fn generate_pr_body(title: &str, description: Option<&str>, summary: &DiffSummary) -> String {
+/// Push the workspace's branch and open `pr_request` on GitHub, through the
+/// API when a token is configured and the gh CLI otherwise
+pub(crate) async fn open_pull_request(
+    state: &AppState,
+    project: &ProjectContext,
+    workspace: &Workspace,
+    pr_request: CreatePrRequest,
+) -> Result<PullRequest, AppError> {
+    if let Ok(github_client) = state.github_client().await {
+        // Push branch to remote first
+        project
+            .workspace_manager
+            .vcs()
+            .push(workspace, "origin")
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to push branch: {}", e)))?;
+
+        // Create PR via GitHub API (with token)
+        github_client
+            .create_pull_request(pr_request)
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to create PR: {}", e)))
+    } else if GhCli::is_available().await {
+        // Use gh CLI (uses user's local authentication)
+        let repo_config = RepoConfig::from_git_remote(&project.path)
+            .await
+            .ok_or_else(|| {
+                AppError::BadRequest(
+                    "Could not detect GitHub repository from git remote".to_string(),
+                )
+            })?;
+
+        let gh_cli = GhCli::new(repo_config, &workspace.path);
+
+        // gh CLI handles push + PR creation
+        gh_cli
+            .push_and_create_pr(pr_request)
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to create PR via gh: {}", e)))
+    } else {
+        Err(AppError::BadRequest(
+            "GitHub not available. Please set GITHUB_TOKEN or install and authenticate gh CLI."
+                .to_string(),
+        ))
+    }
+}
+
+pub(crate) fn generate_pr_body(
+    title: &str,
+    description: Option<&str>,
+    summary: &DiffSummary,
+) -> String {
     let mut body = String::new();
 
     body.push_str("## Summary\n\n");
@@ -195,6 +255,8 @@ pub struct CompleteTaskResponse {
     pub pr: Option<PrInfo>,
     pub merge_result: Option<MergeResultInfo>,
     pub worktree_cleaned: bool,
+    /// The task stays in review until the GitHub checks of its changes pass
+    pub ci_pending: bool,
 }
 
 #[utoipa::path(
@@ -245,6 +307,7 @@ pub async fn complete_task(
         pr: None,
         merge_result: None,
         worktree_cleaned: false,
+        ci_pending: false,
     };
 
     match payload.action {
@@ -280,43 +343,11 @@ pub async fn complete_task(
                 pr_request
             };
 
-            // Try GitHub API first, fall back to gh CLI
-            let pr = if let Ok(github_client) = state.github_client().await {
-                // Push branch to remote first
-                project
-                    .workspace_manager
-                    .vcs()
-                    .push(&workspace, "origin")
-                    .await
-                    .map_err(|e| AppError::Internal(format!("Failed to push branch: {}", e)))?;
-
-                // Create PR via GitHub API (with token)
-                github_client
-                    .create_pull_request(pr_request)
-                    .await
-                    .map_err(|e| AppError::Internal(format!("Failed to create PR: {}", e)))?
-            } else if GhCli::is_available().await {
-                // Use gh CLI (uses user's local authentication)
-                let repo_config = RepoConfig::from_git_remote(&project.path)
-                    .await
-                    .ok_or_else(|| {
-                        AppError::BadRequest(
-                            "Could not detect GitHub repository from git remote".to_string(),
-                        )
-                    })?;
-
-                let gh_cli = GhCli::new(repo_config, &workspace.path);
-
-                // gh CLI handles push + PR creation
-                gh_cli
-                    .push_and_create_pr(pr_request)
-                    .await
-                    .map_err(|e| AppError::Internal(format!("Failed to create PR via gh: {}", e)))?
-            } else {
-                return Err(AppError::BadRequest(
-                    "GitHub not available. Please set GITHUB_TOKEN or install and authenticate gh CLI.".to_string(),
-                ));
-            };
+            let pr = open_pull_request(&state, &project, &workspace, pr_request).await?;
+            project
+                .task_repository
+                .set_pr_number(task_id, pr.number)
+                .await?;
 
             response.pr = Some(PrInfo {
                 number: pr.number,
@@ -330,6 +361,17 @@ pub async fn complete_task(
                 commit_message: format!("Merge task: {}", task.title),
             });
 
+            // Capture the diff first: once merged, the workspace matches main
+            let merged_diff = project
+                .workspace_manager
+                .vcs()
+                .get_diff(&workspace)
+                .await
+                .unwrap_or_else(|e| {
+                    warn!(task_id = %task_id, error = %e, "Failed to capture diff before merge");
+                    String::new()
+                });
+
             let merge_result = project
                 .workspace_manager
                 .merge_workspace(&workspace, &merge_opts.commit_message)
@@ -338,6 +380,14 @@ pub async fn complete_task(
             match merge_result {
                 vcs::MergeResult::Success => {
                     response.merge_result = Some(MergeResultInfo::Success { commit_sha: None });
+                    record_merge(&project, task_id, &merged_diff).await;
+                    schedule_post_merge_update(&state, &project, task_id, merged_diff).await;
+                    let main_branch = project.workspace_manager.vcs().main_branch().to_string();
+                    schedule_merge_reindex(&state, &project, &main_branch).await;
+                    response.ci_pending =
+                        start_ci_gate(&state, &project, task_id, &main_branch, true)
+                            .await
+                            .is_some();
                 }
                 vcs::MergeResult::Conflicts { files } => {
                     let conflict_paths: Vec<String> =
@@ -367,11 +417,12 @@ pub async fn complete_task(
         response.worktree_cleaned = true;
     }
 
-    // Transition task to done
+    // Transition task to done, unless it waits for its checks; the CI gate
+    // moves it once they pass
     let update_request = UpdateTaskRequest {
         title: None,
         description: None,
-        status: Some(TaskStatus::Done),
+        status: (!response.ci_pending).then_some(TaskStatus::Done),
         workspace_path: Some(String::new()), // Clear workspace path
     };
     project
@@ -383,6 +434,163 @@ pub async fn complete_task(
     Ok(Json(response))
 }
 
+// ============================================================================
+// CI Gate Endpoints
+// ============================================================================
+
+/// Hold the task back from done until the GitHub checks on `ref_name` pass,
+/// polling them in the background; `complete_on_pass` moves it to done once
+/// they do. Returns `None` when the project does not gate on CI or GitHub
+/// cannot be queried.
+pub(crate) async fn start_ci_gate(
+    state: &AppState,
+    project: &ProjectContext,
+    task_id: Uuid,
+    ref_name: &str,
+    complete_on_pass: bool,
+) -> Option<CiGateRecord> {
+    let config = ProjectConfig::read(&project.project_path).await;
+    if !config.execution.ci_gate {
+        return None;
+    }
+    let github_client = match state.github_client().await {
+        Ok(client) => client,
+        Err(e) => {
+            warn!(task_id = %task_id, error = %e, "GitHub unavailable, not gating task on CI");
+            return None;
+        }
+    };
+
+    let gate = CiGate::new(
+        Arc::new(github_client),
+        project.task_executor.file_manager().clone(),
+    )
+    .with_event_bus(state.event_bus.clone());
+    let record = match gate.begin(task_id, ref_name, complete_on_pass).await {
+        Ok(record) => record,
+        Err(e) => {
+            warn!(task_id = %task_id, error = %e, "Failed to start CI gate");
+            return None;
+        }
+    };
+
+    let started = record.clone();
+    let task_repository = project.task_repository.clone();
+    let event_bus = state.event_bus.clone();
+    tokio::spawn(async move {
+        let record = match gate.watch(record).await {
+            Ok(record) => record,
+            Err(e) => {
+                warn!(task_id = %task_id, error = %e, "CI gate failed");
+                return;
+            }
+        };
+        if record.state != CiGateState::Passed || !record.complete_on_pass {
+            return;
+        }
+
+        // The task may have moved on, e.g. to fix, while the checks ran
+        match task_repository.find_by_id(task_id).await {
+            Ok(Some(task)) if task.status == TaskStatus::Review => {}
+            _ => return,
+        }
+        let update = UpdateTaskRequest {
+            status: Some(TaskStatus::Done),
+            ..Default::default()
+        };
+        match task_repository.update(task_id, &update).await {
+            Ok(_) => {
+                info!(task_id = %task_id, "CI passed, task done");
+                event_bus.publish(EventEnvelope::new(Event::TaskStatusChanged {
+                    task_id,
+                    from_status: TaskStatus::Review.as_str().to_string(),
+                    to_status: TaskStatus::Done.as_str().to_string(),
+                }));
+            }
+            Err(e) => warn!(task_id = %task_id, error = %e, "Failed to complete task after CI"),
+        }
+    });
+
+    Some(started)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{task_id}/ci-gate",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "GitHub checks the task waits on", body = CiGateRecord),
+        (status = 404, description = "Task not found or not gated on CI")
+    ),
+    tag = "complete"
+)]
+pub async fn get_task_ci_gate(
+    State(state): State<AppState>,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<CiGateRecord>, AppError> {
+    let project = state.project().await?;
+    let record = project
+        .task_executor
+        .file_manager()
+        .read_ci_gate(task_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Task {} is not gated on CI", task_id)))?;
+
+    Ok(Json(record))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/tasks/{task_id}/ci-gate",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 202, description = "Polling the checks again, e.g. after a fix was pushed", body = CiGateRecord),
+        (status = 400, description = "CI gating is off or GitHub is not available"),
+        (status = 404, description = "Task not found or not gated on CI"),
+        (status = 409, description = "The checks are still being polled")
+    ),
+    tag = "complete"
+)]
+pub async fn restart_task_ci_gate(
+    State(state): State<AppState>,
+    Path(task_id): Path<Uuid>,
+) -> Result<(StatusCode, Json<CiGateRecord>), AppError> {
+    let project = state.project().await?;
+    let previous = project
+        .task_executor
+        .file_manager()
+        .read_ci_gate(task_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Task {} is not gated on CI", task_id)))?;
+    if !previous.state.is_finished() {
+        return Err(AppError::Conflict(format!(
+            "CI checks of task {} are still being polled",
+            task_id
+        )));
+    }
+
+    let record = start_ci_gate(
+        &state,
+        &project,
+        task_id,
+        &previous.ref_name,
+        previous.complete_on_pass,
+    )
+    .await
+    .ok_or_else(|| {
+        AppError::BadRequest(
+            "CI gating is off, or GitHub is not available. Set execution.ci_gate and GITHUB_TOKEN."
+                .to_string(),
+        )
+    })?;
+
+    Ok((StatusCode::ACCEPTED, Json(record)))
+}
+
 // ============================================================================
 // User Mode Endpoints
 // ============================================================================
@@ -0,0 +1,334 @@
+//! Project dashboard
+//!
+//! The home screen shows the board, what is running, recent activity, wiki
+//! freshness, spend and what waits for approval. One endpoint gathers all of
+//! it; the summary is cached for a few seconds, since asking OpenCode for the
+//! month's usage takes a request per session.
+
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use axum::extract::State;
+use axum::Json;
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+use db::{EventFilter, PageRequest};
+use events::EventEnvelope;
+use opencode_core::{Session, SessionPhase, SessionStatus, Task, TaskStatus};
+use serde::Serialize;
+use tracing::{debug, warn};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::config::ProjectConfig;
+use crate::digest::DigestUsage;
+use crate::error::AppError;
+use crate::project_manager::ProjectContext;
+use crate::routes::event_history::stored_envelope;
+use crate::routes::wiki::get_wiki_db_path;
+use crate::state::AppState;
+
+/// How long a dashboard summary is served from the cache
+const DASHBOARD_CACHE_TTL: Duration = Duration::from_secs(10);
+
+/// Events listed under recent activity
+const RECENT_EVENTS_LIMIT: usize = 20;
+
+/// Board columns, in display order
+const BOARD_COLUMNS: [TaskStatus; 8] = [
+    TaskStatus::Todo,
+    TaskStatus::Planning,
+    TaskStatus::PlanningReview,
+    TaskStatus::InProgress,
+    TaskStatus::AiReview,
+    TaskStatus::Fix,
+    TaskStatus::Review,
+    TaskStatus::Done,
+];
+
+/// Last dashboard summary, with the project it belongs to and when it was
+/// built
+pub type SharedDashboardCache = Arc<RwLock<Option<(PathBuf, Instant, DashboardResponse)>>>;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct ColumnCount {
+    pub status: TaskStatus,
+    pub count: u32,
+}
+
+/// A session that is running or about to
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct RunningExecution {
+    pub session_id: Uuid,
+    pub task_id: Uuid,
+    pub task_title: String,
+    pub phase: SessionPhase,
+    pub status: SessionStatus,
+    pub started_at: Option<DateTime<Utc>>,
+}
+
+/// How current the wiki index of a branch is
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct WikiFreshness {
+    pub branch: String,
+    pub state: String,
+    /// Commit the index was built from
+    pub indexed_commit: Option<String>,
+    pub last_indexed_at: Option<DateTime<Utc>>,
+}
+
+/// Model spend since the start of the month
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct MonthlySpend {
+    pub period_start: DateTime<Utc>,
+    pub tokens: u64,
+    /// Cost in USD as reported by OpenCode
+    pub cost: f64,
+    /// Sessions OpenCode could not report usage for
+    pub unreported_sessions: u32,
+    /// Monthly budget from the digest settings
+    pub budget_usd: Option<f64>,
+}
+
+/// A task waiting for someone to approve its plan or implementation
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct PendingApproval {
+    pub task_id: Uuid,
+    pub title: String,
+    pub status: TaskStatus,
+    /// When the task started waiting
+    pub since: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct DashboardResponse {
+    /// Tasks per board column, in board order
+    pub task_counts: Vec<ColumnCount>,
+    pub running_executions: Vec<RunningExecution>,
+    /// Latest events, newest first; empty when event history is off
+    pub recent_events: Vec<EventEnvelope>,
+    /// Index state of the configured wiki branches; empty when the wiki is
+    /// disabled
+    pub wiki: Vec<WikiFreshness>,
+    pub spend: MonthlySpend,
+    /// Oldest first
+    pub pending_approvals: Vec<PendingApproval>,
+    pub generated_at: DateTime<Utc>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/dashboard",
+    responses(
+        (status = 200, description = "Project dashboard summary", body = DashboardResponse),
+        (status = 500, description = "Failed to gather the summary")
+    ),
+    tag = "project"
+)]
+pub async fn get_dashboard(
+    State(state): State<AppState>,
+) -> Result<Json<DashboardResponse>, AppError> {
+    let project = state.project().await?;
+
+    {
+        let cache = state.dashboard_cache.read().unwrap();
+        if let Some((path, built_at, dashboard)) = cache.as_ref() {
+            if *path == project.path && built_at.elapsed() < DASHBOARD_CACHE_TTL {
+                return Ok(Json(dashboard.clone()));
+            }
+        }
+    }
+
+    debug!("Building dashboard summary");
+    let dashboard = build_dashboard(&project).await?;
+    {
+        let mut cache = state.dashboard_cache.write().unwrap();
+        *cache = Some((project.path.clone(), Instant::now(), dashboard.clone()));
+    }
+
+    Ok(Json(dashboard))
+}
+
+async fn build_dashboard(project: &ProjectContext) -> Result<DashboardResponse, AppError> {
+    let config = ProjectConfig::read(&project.project_path).await;
+    let tasks = project.task_repository.find_all().await?;
+    let sessions = project.session_repository.find_all().await?;
+    let now = Utc::now();
+
+    let period_start = month_start(now);
+    let usage = DigestUsage::collect(
+        project,
+        &tasks,
+        &sessions,
+        config.digest.budget_usd,
+        period_start,
+        now,
+    )
+    .await;
+
+    let recent_events = if config.events.persist {
+        let page = PageRequest {
+            limit: Some(RECENT_EVENTS_LIMIT as u32),
+            ..Default::default()
+        }
+        .with_sort("-created_at");
+        project
+            .event_repository
+            .find_page(&EventFilter::default(), &page)
+            .await?
+            .items
+            .iter()
+            .filter_map(stored_envelope)
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let wiki = if config.wiki.enabled {
+        wiki_freshness(project, &config.wiki.branches)
+    } else {
+        Vec::new()
+    };
+
+    Ok(DashboardResponse {
+        task_counts: task_counts(&tasks),
+        running_executions: running_executions(&tasks, &sessions),
+        recent_events,
+        wiki,
+        spend: MonthlySpend {
+            period_start,
+            tokens: usage.tokens,
+            cost: usage.cost,
+            unreported_sessions: usage.unreported_sessions as u32,
+            budget_usd: usage.budget_usd,
+        },
+        pending_approvals: pending_approvals(&tasks),
+        generated_at: now,
+    })
+}
+
+/// Midnight UTC on the first of `now`'s month
+fn month_start(now: DateTime<Utc>) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(now.year(), now.month(), 1, 0, 0, 0)
+        .single()
+        .unwrap_or(now)
+}
+
+fn task_counts(tasks: &[Task]) -> Vec<ColumnCount> {
+    BOARD_COLUMNS
+        .iter()
+        .map(|&status| ColumnCount {
+            status,
+            count: tasks.iter().filter(|t| t.status == status).count() as u32,
+        })
+        .collect()
+}
+
+fn running_executions(tasks: &[Task], sessions: &[Session]) -> Vec<RunningExecution> {
+    sessions
+        .iter()
+        .filter(|s| matches!(s.status, SessionStatus::Pending | SessionStatus::Running))
+        .map(|s| RunningExecution {
+            session_id: s.id,
+            task_id: s.task_id,
+            task_title: tasks
+                .iter()
+                .find(|t| t.id == s.task_id)
+                .map(|t| t.title.clone())
+                .unwrap_or_default(),
+            phase: s.phase,
+            status: s.status,
+            started_at: s.started_at,
+        })
+        .collect()
+}
+
+fn pending_approvals(tasks: &[Task]) -> Vec<PendingApproval> {
+    let mut pending: Vec<PendingApproval> = tasks
+        .iter()
+        .filter(|t| matches!(t.status, TaskStatus::PlanningReview | TaskStatus::Review))
+        .map(|t| PendingApproval {
+            task_id: t.id,
+            title: t.title.clone(),
+            status: t.status,
+            since: t.updated_at,
+        })
+        .collect();
+    pending.sort_by_key(|p| p.since);
+    pending
+}
+
+/// Index state of `branches`; a branch never indexed reports as pending
+fn wiki_freshness(project: &ProjectContext, branches: &[String]) -> Vec<WikiFreshness> {
+    let vector_store = match wiki::VectorStore::new(&get_wiki_db_path(&project.project_path)) {
+        Ok(vector_store) => vector_store,
+        Err(e) => {
+            warn!(error = %e, "Failed to open vector store for the dashboard");
+            return Vec::new();
+        }
+    };
+
+    branches
+        .iter()
+        .map(|branch| {
+            let status = vector_store
+                .get_index_status(branch)
+                .ok()
+                .flatten()
+                .unwrap_or_else(|| wiki::IndexStatus::new(branch.clone()));
+            WikiFreshness {
+                branch: status.branch,
+                state: status.state.as_str().to_string(),
+                indexed_commit: status.last_commit_sha,
+                last_indexed_at: status.last_indexed_at,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_month_start() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 17, 14, 5, 9).unwrap();
+        assert_eq!(
+            month_start(now),
+            Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_task_counts_and_pending_approvals() {
+        let mut review = Task::new("Review me", "");
+        review.status = TaskStatus::Review;
+        let mut plan = Task::new("Approve plan", "");
+        plan.status = TaskStatus::PlanningReview;
+        plan.updated_at = review.updated_at - chrono::Duration::hours(1);
+        let todo = Task::new("Todo", "");
+        let tasks = vec![review, plan, todo];
+
+        let counts = task_counts(&tasks);
+        assert_eq!(counts.len(), BOARD_COLUMNS.len());
+        assert_eq!(counts[0].status, TaskStatus::Todo);
+        assert_eq!(counts[0].count, 1);
+        assert_eq!(counts.iter().map(|c| c.count).sum::<u32>(), 3);
+
+        let pending = pending_approvals(&tasks);
+        assert_eq!(pending.len(), 2);
+        assert_eq!(pending[0].title, "Approve plan");
+    }
+}
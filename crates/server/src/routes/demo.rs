@@ -0,0 +1,72 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use serde::Serialize;
+use tracing::info;
+use utoipa::ToSchema;
+
+use crate::demo::{self, DemoSeed};
+use crate::error::AppError;
+use crate::state::AppState;
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct SeedDemoResponse {
+    /// Path of the demo project, which is now the open project
+    pub project_path: String,
+    pub tasks: u32,
+    pub sessions: u32,
+    pub findings: u32,
+    pub wiki_pages: u32,
+}
+
+impl From<DemoSeed> for SeedDemoResponse {
+    fn from(seed: DemoSeed) -> Self {
+        Self {
+            project_path: seed.project_path.display().to_string(),
+            tasks: seed.tasks as u32,
+            sessions: seed.sessions as u32,
+            findings: seed.findings as u32,
+            wiki_pages: seed.wiki_pages as u32,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct WipeDemoResponse {
+    /// Whether there was a demo project to remove
+    pub removed: bool,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/seed",
+    responses(
+        (status = 201, description = "Demo project seeded from the bundled fixtures and opened", body = SeedDemoResponse)
+    ),
+    tag = "admin"
+)]
+pub async fn seed_demo(
+    State(state): State<AppState>,
+) -> Result<(StatusCode, Json<SeedDemoResponse>), AppError> {
+    let seed = demo::seed(&state.project_manager).await?;
+    info!(path = %seed.project_path.display(), "API: Demo project seeded");
+    Ok((StatusCode::CREATED, Json(seed.into())))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/admin/seed",
+    responses(
+        (status = 200, description = "Demo project and its data removed", body = WipeDemoResponse)
+    ),
+    tag = "admin"
+)]
+pub async fn wipe_demo(State(state): State<AppState>) -> Result<Json<WipeDemoResponse>, AppError> {
+    let removed = demo::wipe(&state.project_manager).await?;
+    info!(removed, "API: Demo project wiped");
+    Ok(Json(WipeDemoResponse { removed }))
+}
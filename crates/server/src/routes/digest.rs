@@ -0,0 +1,187 @@
+//! Email digests
+//!
+//! Users schedule daily or weekly digests in their preferences. While a
+//! project is open the scheduler checks hourly and sends the digests that
+//! are due; the test-send endpoint sends one right away.
+
+use std::time::Duration;
+
+use axum::extract::State;
+use axum::Json;
+use chrono::Utc;
+use db::{DigestSchedule, UserPreferencesRepository};
+use events::{Event, EventBus};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{debug, error, info};
+use utoipa::ToSchema;
+
+use crate::config::ProjectConfig;
+use crate::digest::{load_templates, Digest, DigestError, DigestMailer};
+use crate::error::AppError;
+use crate::project_manager::ProjectContext;
+use crate::routes::preferences::current_user_preferences;
+use crate::state::AppState;
+
+/// How often the scheduler looks for digests that are due
+const DIGEST_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+impl From<DigestError> for AppError {
+    fn from(err: DigestError) -> Self {
+        match err {
+            DigestError::NotConfigured(_) | DigestError::Address(_) => {
+                AppError::BadRequest(err.to_string())
+            }
+            DigestError::Smtp(_) => AppError::Internal(err.to_string()),
+            DigestError::Database(e) => AppError::Database(e),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct SendTestDigestRequest {
+    /// Address to send to instead of the user's digest email
+    pub to: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct SendTestDigestResponse {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/me/digest/test",
+    request_body = SendTestDigestRequest,
+    responses(
+        (status = 200, description = "Digest sent", body = SendTestDigestResponse),
+        (status = 400, description = "SMTP is not configured, or no valid address to send to"),
+        (status = 500, description = "The SMTP server did not accept the digest")
+    ),
+    tag = "preferences"
+)]
+pub async fn send_test_digest(
+    State(state): State<AppState>,
+    Json(payload): Json<SendTestDigestRequest>,
+) -> Result<Json<SendTestDigestResponse>, AppError> {
+    let preferences = current_user_preferences(&state).await?;
+    let to = payload
+        .to
+        .filter(|to| !to.trim().is_empty())
+        .or(preferences.digest_email.clone())
+        .ok_or_else(|| {
+            AppError::BadRequest("No address given and no digest email set".to_string())
+        })?;
+    info!(to = %to, "Sending test digest");
+
+    let project = state.project().await?;
+    let config = ProjectConfig::read(&project.project_path).await.digest;
+    let mailer = DigestMailer::new(&config)?;
+
+    // A test covers the user's period, or a day when digests are off
+    let period = preferences
+        .digest_schedule()
+        .period()
+        .or(DigestSchedule::Daily.period())
+        .unwrap_or_default();
+    let now = Utc::now();
+    let digest = Digest::collect(&project, &config, now - period, now).await?;
+
+    let (subject_template, body_template) = load_templates(&project.project_path).await;
+    let subject = digest.render(&subject_template);
+    let body = digest.render(&body_template);
+    mailer.send(&to, &subject, &body).await?;
+
+    Ok(Json(SendTestDigestResponse { to, subject, body }))
+}
+
+/// Send scheduled digests while `project` is open
+pub fn spawn_digest_scheduler(project: ProjectContext, event_bus: &EventBus) {
+    let mut rx = event_bus.subscribe();
+    let project_path = project.path.display().to_string();
+
+    tokio::spawn(async move {
+        let mut check = tokio::time::interval(DIGEST_CHECK_INTERVAL);
+        loop {
+            tokio::select! {
+                _ = check.tick() => send_due_digests(&project).await,
+                result = rx.recv() => match result {
+                    Ok(envelope) => match envelope.event {
+                        Event::ProjectClosed { path } if path == project_path => break,
+                        _ => {}
+                    },
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                },
+            }
+        }
+    });
+}
+
+async fn send_due_digests(project: &ProjectContext) {
+    let config = ProjectConfig::read(&project.project_path).await.digest;
+    if !config.enabled {
+        return;
+    }
+    let repo = UserPreferencesRepository::new(project.pool.clone());
+    let users = match repo.find_all().await {
+        Ok(users) => users,
+        Err(e) => {
+            error!(error = %e, "Failed to read digest schedules");
+            return;
+        }
+    };
+
+    let now = Utc::now();
+    let due: Vec<_> = users
+        .into_iter()
+        .filter_map(|user| {
+            let start = user.digest_due(now.timestamp())?;
+            let to = user.digest_email.clone()?;
+            Some((user.user_id, to, start))
+        })
+        .collect();
+    if due.is_empty() {
+        return;
+    }
+
+    let mailer = match DigestMailer::new(&config) {
+        Ok(mailer) => mailer,
+        Err(e) => {
+            error!(error = %e, "Cannot send scheduled digests");
+            return;
+        }
+    };
+    let (subject_template, body_template) = load_templates(&project.project_path).await;
+
+    for (user_id, to, start) in due {
+        let start = chrono::DateTime::from_timestamp(start, 0).unwrap_or(now);
+        let digest = match Digest::collect(project, &config, start, now).await {
+            Ok(digest) => digest,
+            Err(e) => {
+                error!(error = %e, "Failed to collect digest");
+                return;
+            }
+        };
+        let subject = digest.render(&subject_template);
+        let body = digest.render(&body_template);
+
+        match mailer.send(&to, &subject, &body).await {
+            Ok(()) => {
+                info!(user_id = %user_id, to = %to, "Digest sent");
+                if let Err(e) = repo.mark_digest_sent(&user_id, now.timestamp()).await {
+                    error!(user_id = %user_id, error = %e, "Failed to record sent digest");
+                }
+            }
+            // Left due, so the next check tries again
+            Err(e) => error!(user_id = %user_id, to = %to, error = %e, "Failed to send digest"),
+        }
+    }
+    debug!("Scheduled digests checked");
+}
@@ -0,0 +1,250 @@
+//! Event history
+//!
+//! Published events are stored in the project database, so the UI can
+//! reconstruct what happened after the fact and reconnecting SSE clients can
+//! replay more than the in-memory buffer holds.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use axum::extract::{Query, State};
+use axum::http::{HeaderValue, Uri};
+use axum::response::Response;
+use chrono::{DateTime, Utc};
+use db::{EventFilter, EventRepository, Page, StoredEvent};
+use events::{Event, EventBus, EventEnvelope};
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::config::ProjectConfig;
+use crate::error::AppError;
+use crate::project_manager::ProjectContext;
+use crate::routes::pagination::{list_response, ListParams};
+use crate::routes::sse::EVENT_SEQ_HEADER;
+use crate::state::AppState;
+
+/// How often events past the retention period are deleted
+const RETENTION_SWEEP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Store every event published while `project` is open, and delete events
+/// older than the configured retention period
+///
+/// Partial agent messages are not stored; the final message supersedes them.
+pub fn spawn_event_persistence(project: ProjectContext, event_bus: &EventBus) {
+    let mut rx = event_bus.subscribe();
+    let project_path = project.path.display().to_string();
+
+    tokio::spawn(async move {
+        let config = ProjectConfig::read(&project.project_path).await.events;
+        if !config.persist {
+            debug!("Event persistence disabled");
+            return;
+        }
+        let repository = &project.event_repository;
+        let retention = chrono::Duration::days(i64::from(config.retention_days));
+        let mut sweep = tokio::time::interval(RETENTION_SWEEP_INTERVAL);
+
+        loop {
+            tokio::select! {
+                _ = sweep.tick() => {
+                    match repository.delete_before(Utc::now() - retention).await {
+                        Ok(0) => {}
+                        Ok(deleted) => info!(deleted = deleted, "Deleted expired events"),
+                        Err(e) => warn!(error = %e, "Failed to delete expired events"),
+                    }
+                }
+                result = rx.recv() => match result {
+                    Ok(envelope) => match &envelope.event {
+                        Event::ProjectClosed { path } if *path == project_path => break,
+                        Event::AgentMessage { message, .. } if message.is_partial => {}
+                        _ => store_event(repository, &envelope).await,
+                    },
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!(skipped = skipped, "Event persistence lagged, events were not stored");
+                    }
+                    Err(RecvError::Closed) => break,
+                },
+            }
+        }
+    });
+}
+
+async fn store_event(repository: &EventRepository, envelope: &EventEnvelope) {
+    let payload = match serde_json::to_string(envelope) {
+        Ok(payload) => payload,
+        Err(e) => {
+            warn!(event_id = %envelope.id, error = %e, "Failed to serialize event");
+            return;
+        }
+    };
+
+    if let Err(e) = repository
+        .create(
+            envelope.id,
+            envelope.event.event_type(),
+            envelope.event.task_id(),
+            &payload,
+            envelope.timestamp,
+        )
+        .await
+    {
+        debug!(event_id = %envelope.id, error = %e, "Failed to store event");
+    }
+}
+
+/// The envelope a stored event was serialized from
+pub(crate) fn stored_envelope(stored: &StoredEvent) -> Option<EventEnvelope> {
+    serde_json::from_str(&stored.payload)
+        .map_err(|e| {
+            debug!(event_id = %stored.event_id, error = %e, "Skipping unreadable stored event");
+        })
+        .ok()
+}
+
+fn parse_filter(params: &HashMap<String, String>) -> Result<EventFilter, AppError> {
+    let since = params
+        .get("since")
+        .filter(|s| !s.is_empty())
+        .map(|since| {
+            DateTime::parse_from_rfc3339(since)
+                .map(|since| since.with_timezone(&Utc))
+                .map_err(|_| AppError::BadRequest(format!("Invalid since '{}'", since)))
+        })
+        .transpose()?;
+    let after_seq = params
+        .get("since_seq")
+        .filter(|s| !s.is_empty())
+        .map(|seq| {
+            seq.parse::<i64>()
+                .map_err(|_| AppError::BadRequest(format!("Invalid since_seq '{}'", seq)))
+        })
+        .transpose()?;
+    let task_id = params
+        .get("task_id")
+        .filter(|s| !s.is_empty())
+        .map(|id| {
+            id.parse::<Uuid>()
+                .map_err(|_| AppError::BadRequest(format!("Invalid task_id '{}'", id)))
+        })
+        .transpose()?;
+
+    Ok(EventFilter {
+        since,
+        after_seq,
+        event_type: params.get("type").filter(|s| !s.is_empty()).cloned(),
+        task_id,
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/events/history",
+    params(
+        ("since" = Option<String>, Query, description = "Only events at or after this RFC 3339 time"),
+        ("since_seq" = Option<i64>, Query, description = "Only events stored after this sequence number, e.g. the X-Event-Seq of an earlier response"),
+        ("type" = Option<String>, Query, description = "Only events of this type, e.g. task.status_changed"),
+        ("task_id" = Option<Uuid>, Query, description = "Only events of this task"),
+        ("limit" = Option<u32>, Query, description = "Maximum number of items (max: 500; default: all)"),
+        ("offset" = Option<u32>, Query, description = "Number of items to skip"),
+        ("sort" = Option<String>, Query, description = "Field to sort by, prefixed with '-' for descending order (timestamp or type; default: oldest first)")
+    ),
+    responses(
+        (status = 200, description = "Stored events", body = Vec<EventEnvelope>, headers(
+            ("X-Total-Count" = u64, description = "Number of items across all pages"),
+            ("Link" = String, description = "Links to the first, previous, next and last pages"),
+            ("X-Event-Seq" = i64, description = "Sequence number of the newest stored event")
+        )),
+        (status = 400, description = "Invalid filter")
+    ),
+    tag = "events"
+)]
+pub async fn list_event_history(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+    uri: Uri,
+) -> Result<Response, AppError> {
+    let filter = parse_filter(&params)?;
+    let mut params = ListParams::from_query(&params)?;
+    params.page.sort = params.page.sort.map(|sort| match sort.as_str() {
+        "timestamp" => "created_at".to_string(),
+        "type" => "event_type".to_string(),
+        _ => sort,
+    });
+
+    let project = state.project().await?;
+    let latest_seq = project.event_repository.latest_seq().await?;
+    let page = project
+        .event_repository
+        .find_page(&filter, &params.page)
+        .await?;
+    let page = Page {
+        items: page.items.iter().filter_map(stored_envelope).collect(),
+        total: page.total,
+    };
+
+    let mut response = list_response(&uri, page, &params)?;
+    response
+        .headers_mut()
+        .insert(EVENT_SEQ_HEADER, HeaderValue::from(latest_seq));
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_filter() {
+        let task_id = Uuid::new_v4();
+        let filter = parse_filter(&query(&[
+            ("since", "2026-01-02T03:04:05Z"),
+            ("type", "task.created"),
+            ("task_id", &task_id.to_string()),
+            ("since_seq", "42"),
+        ]))
+        .unwrap();
+        assert_eq!(filter.after_seq, Some(42));
+        assert_eq!(
+            filter.since.unwrap().to_rfc3339(),
+            "2026-01-02T03:04:05+00:00"
+        );
+        assert_eq!(filter.event_type.as_deref(), Some("task.created"));
+        assert_eq!(filter.task_id, Some(task_id));
+
+        let filter = parse_filter(&query(&[("type", "")])).unwrap();
+        assert!(filter.event_type.is_none());
+
+        assert!(parse_filter(&query(&[("since", "yesterday")])).is_err());
+        assert!(parse_filter(&query(&[("task_id", "42")])).is_err());
+    }
+
+    #[test]
+    fn test_stored_envelope() {
+        let envelope = EventEnvelope::new(Event::TaskUpdated {
+            task_id: Uuid::new_v4(),
+        });
+        let stored = StoredEvent {
+            id: 1,
+            event_id: envelope.id.to_string(),
+            event_type: envelope.event.event_type().to_string(),
+            task_id: envelope.event.task_id().map(|id| id.to_string()),
+            payload: serde_json::to_string(&envelope).unwrap(),
+            created_at: envelope.timestamp.timestamp(),
+        };
+        assert_eq!(stored_envelope(&stored).unwrap().id, envelope.id);
+
+        let unreadable = StoredEvent {
+            payload: "{".to_string(),
+            ..stored
+        };
+        assert!(stored_envelope(&unreadable).is_none());
+    }
+}
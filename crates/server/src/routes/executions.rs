@@ -0,0 +1,571 @@
+//! Execution history of a task
+//!
+//! A task that is re-run (re-planned, or re-implemented after a review) has
+//! several runs. Runs are derived from the task's sessions and its review
+//! history, so users can see whether a re-run improved things and compare
+//! two runs' findings and changed files.
+
+use axum::extract::{Path, Query, State};
+use axum::Json;
+use chrono::{DateTime, Utc};
+use futures::future::join_all;
+use opencode_core::{Session, SessionPhase, SessionStatus};
+use orchestrator::{ReviewFinding, ReviewFindings, ReviewHistory};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::pipeline::{session_usage, SessionUsage, TokenUsage};
+use crate::error::AppError;
+use crate::state::AppState;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutionOutcome {
+    Running,
+    Failed,
+    /// The run's last review approved the changes
+    Approved,
+    /// The run's last review reported issues
+    FindingsReported,
+    /// Finished without a review
+    Completed,
+}
+
+/// Sessions of one phase within a run
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct ExecutionPhase {
+    pub phase: SessionPhase,
+    /// Sessions that ran the phase, including retries and review rounds
+    pub sessions: u32,
+    /// Status of the phase's latest session
+    pub status: SessionStatus,
+    /// Time spent in the phase's finished sessions
+    pub duration_secs: i64,
+}
+
+/// One run of a task
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct TaskExecution {
+    /// Run number, starting at 1 for the oldest run
+    pub number: u32,
+    pub started_at: DateTime<Utc>,
+    /// `None` while the run is still going
+    pub completed_at: Option<DateTime<Utc>>,
+    pub duration_secs: Option<i64>,
+    /// Phases in the order the run first reached them
+    pub phases: Vec<ExecutionPhase>,
+    /// `provider/model` of every model the run used
+    pub models: Vec<String>,
+    /// `None` when OpenCode could not report usage for any session
+    pub tokens: Option<TokenUsage>,
+    /// Cost in USD reported by OpenCode
+    pub cost: Option<f64>,
+    /// Findings of the run's last review, `None` without a review
+    pub findings_count: Option<u32>,
+    /// Files changed when the run's last review started
+    pub changed_files: Vec<String>,
+    pub outcome: ExecutionOutcome,
+    pub session_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct TaskExecutionsResponse {
+    pub task_id: Uuid,
+    /// Oldest run first
+    pub executions: Vec<TaskExecution>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CompareExecutionsQuery {
+    /// Run number to compare from
+    pub from: u32,
+    /// Run number to compare to
+    pub to: u32,
+}
+
+/// Differences between two runs of a task
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct ExecutionComparison {
+    pub from: TaskExecution,
+    pub to: TaskExecution,
+    /// Findings of `from` that `to` no longer reports
+    pub resolved_findings: Vec<ReviewFinding>,
+    /// Findings `to` reports that `from` did not
+    pub new_findings: Vec<ReviewFinding>,
+    /// Findings both runs report, as `to` reported them
+    pub persisting_findings: Vec<ReviewFinding>,
+    /// Files only `to` changed
+    pub added_files: Vec<String>,
+    /// Files only `from` changed
+    pub removed_files: Vec<String>,
+}
+
+/// Split a task's sessions, oldest first, into runs
+///
+/// A run starts with a planning session, or with an implementation session
+/// that follows a review or fix. Retries stay in the run they retry.
+fn split_runs(sessions: &[Session]) -> Vec<Vec<&Session>> {
+    let mut runs: Vec<Vec<&Session>> = Vec::new();
+    for session in sessions {
+        let previous = runs.last().and_then(|run| run.last()).map(|s| s.phase);
+        let starts_run = session.retry_of.is_none()
+            && match session.phase {
+                SessionPhase::Planning => true,
+                SessionPhase::Implementation => {
+                    matches!(previous, Some(SessionPhase::Review | SessionPhase::Fix))
+                }
+                SessionPhase::Review | SessionPhase::Fix => false,
+            };
+        match runs.last_mut() {
+            Some(run) if !starts_run => run.push(session),
+            _ => runs.push(vec![session]),
+        }
+    }
+    runs
+}
+
+fn session_start(session: &Session) -> DateTime<Utc> {
+    session.started_at.unwrap_or(session.created_at)
+}
+
+/// The last entry recorded at or after `start` and before `end`
+fn latest_in<T>(
+    items: &[T],
+    at: impl Fn(&T) -> DateTime<Utc>,
+    start: DateTime<Utc>,
+    end: Option<DateTime<Utc>>,
+) -> Option<&T> {
+    items
+        .iter()
+        .rev()
+        .find(|item| at(item) >= start && !matches!(end, Some(end) if at(item) >= end))
+}
+
+fn summarize_phases(sessions: &[&Session]) -> Vec<ExecutionPhase> {
+    let mut phases: Vec<ExecutionPhase> = Vec::new();
+    for session in sessions {
+        let duration = match (session.started_at, session.completed_at) {
+            (Some(started), Some(completed)) => (completed - started).num_seconds(),
+            _ => 0,
+        };
+        match phases.iter_mut().find(|p| p.phase == session.phase) {
+            Some(phase) => {
+                phase.sessions += 1;
+                phase.status = session.status;
+                phase.duration_secs += duration;
+            }
+            None => phases.push(ExecutionPhase {
+                phase: session.phase,
+                sessions: 1,
+                status: session.status,
+                duration_secs: duration,
+            }),
+        }
+    }
+    phases
+}
+
+fn outcome(sessions: &[&Session], findings: Option<&ReviewFindings>) -> ExecutionOutcome {
+    if sessions
+        .iter()
+        .any(|s| matches!(s.status, SessionStatus::Pending | SessionStatus::Running))
+    {
+        return ExecutionOutcome::Running;
+    }
+    if sessions
+        .last()
+        .is_some_and(|s| matches!(s.status, SessionStatus::Failed | SessionStatus::Aborted))
+    {
+        return ExecutionOutcome::Failed;
+    }
+    match findings {
+        Some(findings) if findings.approved => ExecutionOutcome::Approved,
+        Some(_) => ExecutionOutcome::FindingsReported,
+        None => ExecutionOutcome::Completed,
+    }
+}
+
+/// Build the run summaries from sessions, oldest first, and their usage
+fn build_executions(
+    sessions: &[Session],
+    usage: &[Option<SessionUsage>],
+    history: &ReviewHistory,
+) -> Vec<TaskExecution> {
+    let runs = split_runs(sessions);
+    let starts: Vec<DateTime<Utc>> = runs.iter().map(|run| session_start(run[0])).collect();
+
+    let mut offset = 0;
+    runs.iter()
+        .enumerate()
+        .map(|(i, run)| {
+            let run_usage = &usage[offset..offset + run.len()];
+            offset += run.len();
+
+            let started_at = starts[i];
+            let next_start = starts.get(i + 1).copied();
+            let findings = latest_in(&history.findings, |f| f.created_at, started_at, next_start);
+            let changes = latest_in(&history.changes, |c| c.recorded_at, started_at, next_start);
+
+            let outcome = outcome(run, findings);
+            let completed_at = (outcome != ExecutionOutcome::Running)
+                .then(|| run.iter().filter_map(|s| s.completed_at).max())
+                .flatten();
+
+            let mut tokens: Option<TokenUsage> = None;
+            let mut cost: Option<f64> = None;
+            let mut models: Vec<String> = Vec::new();
+            for session_usage in run_usage.iter().flatten() {
+                tokens
+                    .get_or_insert_with(TokenUsage::default)
+                    .add(&session_usage.tokens);
+                *cost.get_or_insert(0.0) += session_usage.cost;
+                for model in &session_usage.models {
+                    if !models.contains(model) {
+                        models.push(model.clone());
+                    }
+                }
+            }
+
+            TaskExecution {
+                number: i as u32 + 1,
+                started_at,
+                completed_at,
+                duration_secs: completed_at.map(|end| (end - started_at).num_seconds()),
+                phases: summarize_phases(run),
+                models,
+                tokens,
+                cost,
+                findings_count: findings.map(|f| f.findings.len() as u32),
+                changed_files: changes.map(|c| c.files.clone()).unwrap_or_default(),
+                outcome,
+                session_ids: run.iter().map(|s| s.id).collect(),
+            }
+        })
+        .collect()
+}
+
+/// Review snapshot recorded during run `index`, before the next run started
+fn run_findings<'a>(
+    executions: &[TaskExecution],
+    index: usize,
+    history: &'a ReviewHistory,
+) -> Option<&'a ReviewFindings> {
+    latest_in(
+        &history.findings,
+        |f| f.created_at,
+        executions[index].started_at,
+        executions.get(index + 1).map(|e| e.started_at),
+    )
+}
+
+/// Whether two findings from different runs report the same issue
+///
+/// IDs are assigned per review, so findings match by file and title.
+fn same_finding(a: &ReviewFinding, b: &ReviewFinding) -> bool {
+    let same_file = match (&a.file_path, &b.file_path) {
+        (Some(a), Some(b)) => vcs::same_relative_path(a, b),
+        (a, b) => a == b,
+    };
+    same_file && a.title.trim().eq_ignore_ascii_case(b.title.trim())
+}
+
+fn compare(
+    from: TaskExecution,
+    from_findings: &[ReviewFinding],
+    to: TaskExecution,
+    to_findings: &[ReviewFinding],
+) -> ExecutionComparison {
+    let resolved_findings = from_findings
+        .iter()
+        .filter(|f| !to_findings.iter().any(|t| same_finding(f, t)))
+        .cloned()
+        .collect();
+    let (persisting_findings, new_findings) = to_findings
+        .iter()
+        .cloned()
+        .partition(|t| from_findings.iter().any(|f| same_finding(f, t)));
+
+    let added_files = to
+        .changed_files
+        .iter()
+        .filter(|f| !from.changed_files.contains(f))
+        .cloned()
+        .collect();
+    let removed_files = from
+        .changed_files
+        .iter()
+        .filter(|f| !to.changed_files.contains(f))
+        .cloned()
+        .collect();
+
+    ExecutionComparison {
+        from,
+        to,
+        resolved_findings,
+        new_findings,
+        persisting_findings,
+        added_files,
+        removed_files,
+    }
+}
+
+/// Runs of a task with the review history they are built from
+async fn load_executions(
+    state: &AppState,
+    id: Uuid,
+) -> Result<(Vec<TaskExecution>, ReviewHistory), AppError> {
+    let project = state.project().await?;
+    let task = project
+        .task_repository
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Task not found: {}", id)))?;
+
+    let mut sessions = project.session_repository.find_by_task_id(id).await?;
+    sessions.sort_by_key(|s| s.created_at);
+
+    let executor = &project.task_executor;
+    let usage = join_all(sessions.iter().map(|session| async {
+        match &session.opencode_session_id {
+            Some(opencode_id) => {
+                session_usage(
+                    executor.opencode_config(),
+                    opencode_id,
+                    task.workspace_path.as_deref(),
+                )
+                .await
+            }
+            None => None,
+        }
+    }))
+    .await;
+
+    let history = executor
+        .file_manager()
+        .read_review_history(id)
+        .await
+        .unwrap_or_else(|e| {
+            warn!(task_id = %id, error = %e, "Failed to read review history");
+            ReviewHistory::default()
+        });
+
+    let executions = build_executions(&sessions, &usage, &history);
+    Ok((executions, history))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{id}/executions",
+    params(
+        ("id" = Uuid, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "Runs of the task, oldest first", body = TaskExecutionsResponse),
+        (status = 404, description = "Task not found")
+    ),
+    tag = "tasks"
+)]
+pub async fn list_task_executions(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<TaskExecutionsResponse>, AppError> {
+    let (executions, _) = load_executions(&state, id).await?;
+    Ok(Json(TaskExecutionsResponse {
+        task_id: id,
+        executions,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{id}/executions/compare",
+    params(
+        ("id" = Uuid, Path, description = "Task ID"),
+        ("from" = u32, Query, description = "Run number to compare from"),
+        ("to" = u32, Query, description = "Run number to compare to")
+    ),
+    responses(
+        (status = 200, description = "Differences between the two runs", body = ExecutionComparison),
+        (status = 404, description = "Task or run not found")
+    ),
+    tag = "tasks"
+)]
+pub async fn compare_task_executions(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<CompareExecutionsQuery>,
+) -> Result<Json<ExecutionComparison>, AppError> {
+    let (executions, history) = load_executions(&state, id).await?;
+
+    let run = |number: u32| -> Result<(TaskExecution, Vec<ReviewFinding>), AppError> {
+        let index = executions
+            .iter()
+            .position(|e| e.number == number)
+            .ok_or_else(|| AppError::NotFound(format!("Run not found: {}", number)))?;
+        let findings = run_findings(&executions, index, &history)
+            .map(|f| f.findings.clone())
+            .unwrap_or_default();
+        Ok((executions[index].clone(), findings))
+    };
+
+    let (from, from_findings) = run(query.from)?;
+    let (to, to_findings) = run(query.to)?;
+    Ok(Json(compare(from, &from_findings, to, &to_findings)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use orchestrator::{ChangedFiles, FindingSeverity, FindingStatus};
+
+    fn session(phase: SessionPhase, minute: i64, status: SessionStatus) -> Session {
+        let mut session = Session::new(Uuid::nil(), phase);
+        session.created_at = DateTime::<Utc>::UNIX_EPOCH + Duration::minutes(minute);
+        session.started_at = Some(session.created_at);
+        session.completed_at =
+            (status != SessionStatus::Running).then(|| session.created_at + Duration::seconds(30));
+        session.status = status;
+        session
+    }
+
+    fn finding(file: &str, title: &str) -> ReviewFinding {
+        ReviewFinding {
+            id: title.to_string(),
+            file_path: Some(file.to_string()),
+            line_start: None,
+            line_end: None,
+            title: title.to_string(),
+            description: String::new(),
+            severity: FindingSeverity::Warning,
+            status: FindingStatus::Pending,
+            original_severity: None,
+            severity_rationale: None,
+            reproduction: None,
+            acceptance_criteria: None,
+            suggested_fix: None,
+            consensus_score: None,
+            comments: Vec::new(),
+            snippet: None,
+        }
+    }
+
+    fn review_at(minute: i64, findings: Vec<ReviewFinding>) -> ReviewFindings {
+        let mut review =
+            ReviewFindings::with_findings(Uuid::nil(), Uuid::nil(), String::new(), findings);
+        review.created_at = DateTime::<Utc>::UNIX_EPOCH + Duration::minutes(minute);
+        review
+    }
+
+    #[test]
+    fn test_split_runs() {
+        let failed = session(SessionPhase::Implementation, 1, SessionStatus::Failed);
+        let mut retry = session(SessionPhase::Implementation, 2, SessionStatus::Completed);
+        retry.retry_of = Some(failed.id);
+        let sessions = vec![
+            session(SessionPhase::Planning, 0, SessionStatus::Completed),
+            failed,
+            retry,
+            session(SessionPhase::Review, 3, SessionStatus::Completed),
+            session(SessionPhase::Fix, 4, SessionStatus::Completed),
+            session(SessionPhase::Review, 5, SessionStatus::Completed),
+            session(SessionPhase::Implementation, 6, SessionStatus::Completed),
+            session(SessionPhase::Review, 7, SessionStatus::Completed),
+            session(SessionPhase::Planning, 8, SessionStatus::Running),
+        ];
+
+        let runs: Vec<usize> = split_runs(&sessions).iter().map(Vec::len).collect();
+
+        assert_eq!(runs, vec![6, 2, 1]);
+    }
+
+    #[test]
+    fn test_build_executions() {
+        let sessions = vec![
+            session(SessionPhase::Implementation, 0, SessionStatus::Completed),
+            session(SessionPhase::Review, 1, SessionStatus::Completed),
+            session(SessionPhase::Implementation, 10, SessionStatus::Completed),
+            session(SessionPhase::Review, 11, SessionStatus::Completed),
+            session(SessionPhase::Implementation, 20, SessionStatus::Failed),
+        ];
+        let usage = vec![
+            Some(SessionUsage {
+                tokens: TokenUsage {
+                    input: 100,
+                    ..Default::default()
+                },
+                cost: 0.5,
+                models: vec!["anthropic/claude".to_string()],
+            }),
+            None,
+            None,
+            None,
+            None,
+        ];
+        let mut approved = review_at(12, Vec::new());
+        approved.approved = true;
+        let mut changes = ChangedFiles::from_diff("diff --git a/src/lib.rs b/src/lib.rs\n");
+        changes.recorded_at = DateTime::<Utc>::UNIX_EPOCH + Duration::minutes(1);
+        let history = ReviewHistory {
+            findings: vec![review_at(2, vec![finding("src/lib.rs", "Bug")]), approved],
+            changes: vec![changes],
+        };
+
+        let executions = build_executions(&sessions, &usage, &history);
+
+        assert_eq!(executions.len(), 3);
+        assert_eq!(executions[0].outcome, ExecutionOutcome::FindingsReported);
+        assert_eq!(executions[0].findings_count, Some(1));
+        assert_eq!(executions[0].changed_files, vec!["src/lib.rs"]);
+        assert_eq!(executions[0].tokens.as_ref().unwrap().input, 100);
+        assert_eq!(executions[0].cost, Some(0.5));
+        assert_eq!(executions[0].models, vec!["anthropic/claude"]);
+        assert_eq!(executions[0].duration_secs, Some(90));
+        assert_eq!(executions[0].phases.len(), 2);
+        assert_eq!(executions[1].outcome, ExecutionOutcome::Approved);
+        assert_eq!(executions[1].tokens, None);
+        assert!(executions[1].changed_files.is_empty());
+        assert_eq!(executions[2].outcome, ExecutionOutcome::Failed);
+        assert_eq!(executions[2].findings_count, None);
+    }
+
+    #[test]
+    fn test_compare() {
+        let sessions = vec![
+            session(SessionPhase::Planning, 0, SessionStatus::Completed),
+            session(SessionPhase::Planning, 10, SessionStatus::Completed),
+        ];
+        let history = ReviewHistory::default();
+        let mut executions = build_executions(&sessions, &[None, None], &history);
+        executions[0].changed_files = vec!["a.rs".to_string(), "b.rs".to_string()];
+        executions[1].changed_files = vec!["b.rs".to_string(), "c.rs".to_string()];
+
+        let comparison = compare(
+            executions[0].clone(),
+            &[finding("a.rs", "Bug"), finding("b.rs", "Leak")],
+            executions[1].clone(),
+            &[finding("b.rs", " leak "), finding("c.rs", "Race")],
+        );
+
+        assert_eq!(comparison.resolved_findings.len(), 1);
+        assert_eq!(comparison.resolved_findings[0].title, "Bug");
+        assert_eq!(comparison.persisting_findings.len(), 1);
+        assert_eq!(comparison.persisting_findings[0].title, " leak ");
+        assert_eq!(comparison.new_findings.len(), 1);
+        assert_eq!(comparison.new_findings[0].title, "Race");
+        assert_eq!(comparison.added_files, vec!["c.rs"]);
+        assert_eq!(comparison.removed_files, vec!["a.rs"]);
+    }
+}
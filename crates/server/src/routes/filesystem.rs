@@ -98,7 +98,7 @@ fn browse_path(path: &PathBuf) -> BrowseResponse {
         })
         .unwrap_or_default();
 
-    entries.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+    entries.sort_by_key(|e| e.name.to_lowercase());
 
     BrowseResponse {
         current_path: path.display().to_string(),
@@ -0,0 +1,98 @@
+use std::convert::Infallible;
+
+use axum::extract::{Path, Query};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use futures::stream::StreamExt;
+use serde::{Deserialize, Serialize};
+use tokio_stream::wrappers::BroadcastStream;
+use utoipa::ToSchema;
+
+use crate::error::AppError;
+use crate::job_logs::{JobLogLine, JobLogs};
+use crate::routes::sse::SSE_KEEP_ALIVE_INTERVAL;
+
+#[derive(Debug, Deserialize)]
+pub struct JobLogsQuery {
+    /// Stream the log as server-sent events until the job finishes
+    #[serde(default)]
+    pub follow: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct JobLogsResponse {
+    pub job_id: String,
+    /// Whether the job has finished; its log is complete if so
+    pub finished: bool,
+    pub lines: Vec<JobLogLine>,
+}
+
+/// Path of the logs of `job_id`, for messages that point users at them
+pub fn job_logs_path(job_id: &str) -> String {
+    format!("/api/jobs/{}/logs", job_id)
+}
+
+fn line_to_sse_event(line: &JobLogLine) -> Result<Event, Infallible> {
+    let data = serde_json::to_string(line).unwrap_or_else(|_| "{}".to_string());
+    Ok(Event::default()
+        .id(line.seq.to_string())
+        .event("log")
+        .data(data))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/jobs/{id}/logs",
+    params(
+        ("id" = String, Path, description = "Job ID, as returned when indexing or generation starts"),
+        ("follow" = Option<bool>, Query, description = "Stream the log as server-sent events (`log` events, then `end` once the job finishes) instead of returning it"),
+    ),
+    responses(
+        (status = 200, description = "Log lines of the job kept so far", body = JobLogsResponse),
+        (status = 404, description = "No logs kept for the job")
+    ),
+    tag = "jobs"
+)]
+pub async fn get_job_logs(
+    Path(id): Path<String>,
+    Query(query): Query<JobLogsQuery>,
+) -> Result<Response, AppError> {
+    let snapshot = JobLogs::shared()
+        .snapshot(&id)
+        .ok_or_else(|| AppError::NotFound(format!("No logs kept for job {}", id)))?;
+
+    if !query.follow {
+        return Ok(Json(JobLogsResponse {
+            job_id: id,
+            finished: snapshot.finished,
+            lines: snapshot.lines,
+        })
+        .into_response());
+    }
+
+    // The receiver was subscribed along with the snapshot, so it starts
+    // right after the kept lines
+    let kept = futures::stream::iter(snapshot.lines.into_iter().map(|l| line_to_sse_event(&l)));
+    let live = match snapshot.follow {
+        Some(rx) => BroadcastStream::new(rx)
+            .filter_map(move |result| async move {
+                match result {
+                    Ok(line) => Some(line_to_sse_event(&line)),
+                    Err(e) => {
+                        tracing::debug!("Job log follower fell behind: {:?}", e);
+                        None
+                    }
+                }
+            })
+            .boxed(),
+        None => futures::stream::empty().boxed(),
+    };
+    let end = futures::stream::once(async { Ok(Event::default().event("end").data("{}")) });
+
+    Ok(Sse::new(kept.chain(live).chain(end))
+        .keep_alive(KeepAlive::new().interval(SSE_KEEP_ALIVE_INTERVAL))
+        .into_response())
+}
@@ -1,31 +1,72 @@
+pub mod analytics;
+pub mod artifacts;
 mod comments;
 pub mod complete;
+pub mod dashboard;
+pub mod demo;
+pub mod digest;
+pub mod event_history;
+pub mod executions;
 pub mod filesystem;
 mod health;
+pub mod jobs;
 pub mod opencode;
+mod pagination;
+pub mod pipeline;
+pub mod preferences;
 pub mod project;
 pub mod projects;
 pub mod pull_requests;
+pub mod releases;
 pub mod roadmap;
+pub mod search;
+mod session_shares;
 mod sessions;
 pub mod settings;
 pub mod sse;
+pub mod task_dependencies;
+pub mod task_env;
+pub mod task_templates;
 mod tasks;
+pub mod tokens;
+pub mod usage;
 pub mod wiki;
+pub mod wiki_feedback;
+pub mod wiki_tuning;
 mod workspaces;
 
+pub use analytics::*;
+pub use artifacts::*;
 pub use comments::*;
 pub use complete::*;
+pub use dashboard::*;
+pub use demo::*;
+pub use digest::*;
+pub use event_history::*;
+pub use executions::*;
 pub use filesystem::*;
 pub use health::*;
+pub use jobs::*;
 pub use opencode::*;
+pub use pipeline::*;
+pub use preferences::*;
 pub use project::*;
 pub use projects::*;
 pub use pull_requests::*;
+pub use releases::*;
 pub use roadmap::*;
+pub use search::*;
+pub use session_shares::*;
 pub use sessions::*;
 pub use settings::*;
 pub use sse::*;
+pub use task_dependencies::*;
+pub use task_env::*;
+pub use task_templates::*;
 pub use tasks::*;
+pub use tokens::*;
+pub use usage::*;
 pub use wiki::*;
+pub use wiki_feedback::*;
+pub use wiki_tuning::*;
 pub use workspaces::*;
@@ -0,0 +1,241 @@
+//! Pagination, sorting and sparse fieldsets for list endpoints
+//!
+//! List endpoints accept `limit`, `offset`, `sort` (e.g. `-created_at`) and
+//! `fields` (e.g. `id,title,status`) query parameters. The body stays a
+//! plain array; the size of the whole list is returned in `X-Total-Count`
+//! and links to neighbouring pages in `Link`.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use axum::http::{header, HeaderValue, Uri};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use db::{Page, PageRequest};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::error::AppError;
+
+/// Largest page a client can request
+const MAX_PAGE_SIZE: u32 = 500;
+
+const TOTAL_COUNT_HEADER: &str = "x-total-count";
+
+/// Parsed list query parameters
+#[derive(Debug, Default)]
+pub(crate) struct ListParams {
+    pub page: PageRequest,
+    /// Fields to keep in each item; `None` keeps all of them
+    pub fields: Option<Vec<String>>,
+}
+
+impl ListParams {
+    pub fn from_query(params: &HashMap<String, String>) -> Result<Self, AppError> {
+        let mut page = PageRequest::default();
+
+        if let Some(limit) = params.get("limit") {
+            let limit = limit
+                .parse::<u32>()
+                .map_err(|_| AppError::BadRequest(format!("Invalid limit '{}'", limit)))?;
+            page.limit = Some(limit.clamp(1, MAX_PAGE_SIZE));
+        }
+        if let Some(offset) = params.get("offset") {
+            page.offset = offset
+                .parse()
+                .map_err(|_| AppError::BadRequest(format!("Invalid offset '{}'", offset)))?;
+        }
+        if let Some(sort) = params.get("sort").filter(|s| !s.is_empty()) {
+            page = page.with_sort(sort);
+        }
+
+        let fields = params.get("fields").map(|fields| {
+            fields
+                .split(',')
+                .map(str::trim)
+                .filter(|f| !f.is_empty())
+                .map(String::from)
+                .collect()
+        });
+
+        Ok(Self { page, fields })
+    }
+}
+
+/// Sort and slice an in-memory list the way repositories do in SQL, for
+/// lists that don't come from the database
+pub(crate) fn paginate<T: Serialize>(
+    items: Vec<T>,
+    page: &PageRequest,
+    sortable: &[&str],
+) -> Page<Value> {
+    let mut values: Vec<Value> = items
+        .into_iter()
+        .filter_map(|item| serde_json::to_value(item).ok())
+        .collect();
+    let total = values.len() as u64;
+
+    if let Some(field) = page.sort.as_deref().filter(|s| sortable.contains(s)) {
+        values.sort_by(|a, b| {
+            let ordering = compare_values(&a[field], &b[field]);
+            if page.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+    }
+
+    let items = values
+        .into_iter()
+        .skip(page.offset as usize)
+        .take(page.limit.map_or(usize::MAX, |l| l as usize))
+        .collect();
+
+    Page { items, total }
+}
+
+fn compare_values(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a
+            .as_f64()
+            .partial_cmp(&b.as_f64())
+            .unwrap_or(Ordering::Equal),
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        (Value::Null, Value::Null) => Ordering::Equal,
+        (Value::Null, _) => Ordering::Less,
+        (_, Value::Null) => Ordering::Greater,
+        _ => Ordering::Equal,
+    }
+}
+
+/// Respond with one page of a list, keeping only the requested fields and
+/// adding the total count and page links
+pub(crate) fn list_response<T: Serialize>(
+    uri: &Uri,
+    page: Page<T>,
+    params: &ListParams,
+) -> Result<Response, AppError> {
+    let items = page
+        .items
+        .into_iter()
+        .map(|item| {
+            let mut value = serde_json::to_value(item)
+                .map_err(|e| AppError::Internal(format!("Failed to serialize item: {}", e)))?;
+            if let (Some(fields), Value::Object(map)) = (&params.fields, &mut value) {
+                map.retain(|key, _| fields.iter().any(|f| f == key));
+            }
+            Ok(value)
+        })
+        .collect::<Result<Vec<_>, AppError>>()?;
+
+    let mut response = Json(items).into_response();
+    let headers = response.headers_mut();
+    headers.insert(TOTAL_COUNT_HEADER, HeaderValue::from(page.total));
+    if let Some(links) = page_links(uri, &params.page, page.total) {
+        if let Ok(value) = HeaderValue::from_str(&links) {
+            headers.insert(header::LINK, value);
+        }
+    }
+
+    Ok(response)
+}
+
+/// `Link` header value pointing at the first, previous, next and last pages
+fn page_links(uri: &Uri, page: &PageRequest, total: u64) -> Option<String> {
+    let limit = u64::from(page.limit?);
+    let offset = u64::from(page.offset);
+
+    let base_query: Vec<&str> = uri
+        .query()
+        .unwrap_or_default()
+        .split('&')
+        .filter(|pair| !pair.is_empty() && !pair.starts_with("offset="))
+        .collect();
+    let link = |offset: u64, rel: &str| {
+        let mut query = base_query.clone();
+        let offset = format!("offset={}", offset);
+        query.push(&offset);
+        format!("<{}?{}>; rel=\"{}\"", uri.path(), query.join("&"), rel)
+    };
+
+    let last = total.saturating_sub(1) / limit * limit;
+    let mut links = vec![link(0, "first")];
+    if offset > 0 {
+        links.push(link(offset.saturating_sub(limit), "prev"));
+    }
+    if offset + limit < total {
+        links.push(link(offset + limit, "next"));
+    }
+    links.push(link(last, "last"));
+
+    Some(links.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_list_params() {
+        let params = ListParams::from_query(&query(&[
+            ("limit", "10000"),
+            ("offset", "20"),
+            ("sort", "-created_at"),
+            ("fields", "id, title"),
+        ]))
+        .unwrap();
+        assert_eq!(params.page.limit, Some(MAX_PAGE_SIZE));
+        assert_eq!(params.page.offset, 20);
+        assert_eq!(params.page.sort.as_deref(), Some("created_at"));
+        assert!(params.page.descending);
+        assert_eq!(params.fields.unwrap(), vec!["id", "title"]);
+
+        assert!(ListParams::from_query(&query(&[("limit", "ten")])).is_err());
+    }
+
+    #[test]
+    fn test_page_links() {
+        let uri: Uri = "/api/tasks?limit=10&offset=10&sort=title".parse().unwrap();
+        let page = PageRequest {
+            limit: Some(10),
+            offset: 10,
+            ..Default::default()
+        };
+        let links = page_links(&uri, &page, 35).unwrap();
+        assert_eq!(
+            links,
+            "</api/tasks?limit=10&sort=title&offset=0>; rel=\"first\", \
+             </api/tasks?limit=10&sort=title&offset=0>; rel=\"prev\", \
+             </api/tasks?limit=10&sort=title&offset=20>; rel=\"next\", \
+             </api/tasks?limit=10&sort=title&offset=30>; rel=\"last\""
+        );
+
+        assert!(page_links(&uri, &PageRequest::default(), 35).is_none());
+    }
+
+    #[test]
+    fn test_paginate_in_memory() {
+        #[derive(Serialize)]
+        struct Item {
+            name: &'static str,
+        }
+        let items = vec![Item { name: "b" }, Item { name: "c" }, Item { name: "a" }];
+        let page = PageRequest {
+            limit: Some(2),
+            ..PageRequest::default().with_sort("-name")
+        };
+        let page = paginate(items, &page, &["name"]);
+        assert_eq!(page.total, 3);
+        assert_eq!(page.items[0]["name"], "c");
+        assert_eq!(page.items[1]["name"], "b");
+    }
+}
@@ -0,0 +1,368 @@
+//! Execution pipeline of a task
+//!
+//! The phases a task runs through in order, with the state of each one, for
+//! drawing the pipeline in the UI. Clients refetch it on `session.*` and
+//! `task.status_changed` events.
+
+use axum::extract::{Path, State};
+use axum::Json;
+use chrono::{DateTime, Utc};
+use futures::future::join_all;
+use opencode_client::apis::configuration::Configuration;
+use opencode_client::apis::default_api;
+use opencode_core::{Session, SessionPhase, SessionStatus, TaskStatus};
+use orchestrator::ExecutorConfig;
+use serde::Serialize;
+use tracing::debug;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::state::AppState;
+
+/// Phases in the order tasks run through them
+const PIPELINE: [SessionPhase; 4] = [
+    SessionPhase::Planning,
+    SessionPhase::Implementation,
+    SessionPhase::Review,
+    SessionPhase::Fix,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum PipelineStageStatus {
+    /// Not reached yet
+    Pending,
+    Running,
+    /// Finished and waiting for a human to approve it
+    AwaitingApproval,
+    Completed,
+    Failed,
+    /// Passed over without running, e.g. a fix when the review found nothing
+    Skipped,
+}
+
+/// Tokens used by the OpenCode sessions of a phase
+#[derive(Debug, Clone, Default, PartialEq, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct TokenUsage {
+    pub input: u64,
+    pub output: u64,
+    pub reasoning: u64,
+    pub cache_read: u64,
+    pub cache_write: u64,
+}
+
+impl TokenUsage {
+    pub(crate) fn add(&mut self, other: &TokenUsage) {
+        self.input += other.input;
+        self.output += other.output;
+        self.reasoning += other.reasoning;
+        self.cache_read += other.cache_read;
+        self.cache_write += other.cache_write;
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct PipelineStage {
+    pub phase: SessionPhase,
+    pub status: PipelineStageStatus,
+    /// When the first session of the phase started
+    pub started_at: Option<DateTime<Utc>>,
+    /// When the latest session of the phase finished
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Sessions that ran the phase, oldest first
+    pub session_ids: Vec<Uuid>,
+    /// `None` when OpenCode could not report usage for any session
+    pub tokens: Option<TokenUsage>,
+    /// Whether a human has to approve the phase before the task moves on
+    pub requires_approval: bool,
+    /// Most review/fix rounds before the task goes to human review
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_iterations: Option<u32>,
+    /// Reviews run per review round, when review consensus is on
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub review_samples: Option<u32>,
+    /// Extra tokens the consensus samples of the next review round are
+    /// expected to cost, going by the latest review session
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_consensus_tokens: Option<u64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct PipelineResponse {
+    pub task_id: Uuid,
+    pub task_status: TaskStatus,
+    pub stages: Vec<PipelineStage>,
+}
+
+/// How far along the pipeline a task in `status` has got, as an index into
+/// `PIPELINE`; human review and done are past the fix phase
+fn reached(status: TaskStatus) -> usize {
+    match status {
+        TaskStatus::Todo
+        | TaskStatus::Blocked
+        | TaskStatus::Planning
+        | TaskStatus::PlanningReview => 0,
+        TaskStatus::InProgress => 1,
+        TaskStatus::AiReview => 2,
+        TaskStatus::Fix => 3,
+        TaskStatus::Review => 4,
+        TaskStatus::Done => 5,
+    }
+}
+
+/// Extra tokens of a consensus review round: one more review per extra sample
+fn consensus_cost(latest_review: &TokenUsage, samples: u32) -> u64 {
+    let per_review = latest_review.input
+        + latest_review.output
+        + latest_review.reasoning
+        + latest_review.cache_read;
+    per_review * u64::from(samples.saturating_sub(1))
+}
+
+fn requires_approval(phase: SessionPhase, config: &ExecutorConfig) -> bool {
+    match phase {
+        SessionPhase::Planning => config.require_plan_approval,
+        SessionPhase::Review => config.require_human_review,
+        SessionPhase::Implementation | SessionPhase::Fix => false,
+    }
+}
+
+/// State of the phase at `index` given its sessions, newest first
+fn stage_status(
+    index: usize,
+    sessions: &[&Session],
+    task_status: TaskStatus,
+    requires_approval: bool,
+) -> PipelineStageStatus {
+    let phase = PIPELINE[index];
+    let latest = sessions.first().map(|s| s.status);
+
+    if matches!(
+        latest,
+        Some(SessionStatus::Pending | SessionStatus::Running)
+    ) {
+        return PipelineStageStatus::Running;
+    }
+
+    let awaiting_approval = match phase {
+        SessionPhase::Planning => task_status == TaskStatus::PlanningReview,
+        SessionPhase::Review => task_status == TaskStatus::Review,
+        SessionPhase::Implementation | SessionPhase::Fix => false,
+    };
+    if requires_approval && awaiting_approval && !sessions.is_empty() {
+        return PipelineStageStatus::AwaitingApproval;
+    }
+
+    match latest {
+        Some(SessionStatus::Completed) => PipelineStageStatus::Completed,
+        Some(_) => PipelineStageStatus::Failed,
+        None if index < reached(task_status) => PipelineStageStatus::Skipped,
+        None => PipelineStageStatus::Pending,
+    }
+}
+
+/// What an OpenCode session used, summed over its assistant messages
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SessionUsage {
+    pub tokens: TokenUsage,
+    /// Cost in USD as reported by OpenCode
+    pub cost: f64,
+    /// `provider/model` of each model that answered, in first-use order
+    pub models: Vec<String>,
+}
+
+/// Usage of an OpenCode session, `None` when OpenCode cannot report it
+pub(crate) async fn session_usage(
+    config: &Configuration,
+    opencode_session_id: &str,
+    directory: Option<&str>,
+) -> Option<SessionUsage> {
+    let messages = default_api::session_messages(config, opencode_session_id, directory, None)
+        .await
+        .map_err(|e| {
+            debug!(session_id = %opencode_session_id, error = %e, "No token usage for session");
+        })
+        .ok()?;
+
+    let mut usage = SessionUsage::default();
+    for info in messages.iter().map(|m| &m.info) {
+        if let Some(tokens) = info.tokens.as_ref() {
+            usage.tokens.add(&TokenUsage {
+                input: tokens.input as u64,
+                output: tokens.output as u64,
+                reasoning: tokens.reasoning as u64,
+                cache_read: tokens.cache.read as u64,
+                cache_write: tokens.cache.write as u64,
+            });
+        }
+        usage.cost += info.cost.unwrap_or_default();
+        if let (Some(provider), Some(model)) = (&info.provider_id, &info.model_id) {
+            let model = format!("{}/{}", provider, model);
+            if !usage.models.contains(&model) {
+                usage.models.push(model);
+            }
+        }
+    }
+    Some(usage)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{id}/pipeline",
+    params(
+        ("id" = Uuid, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "Phases of the task's pipeline in order", body = PipelineResponse),
+        (status = 404, description = "Task not found")
+    ),
+    tag = "tasks"
+)]
+pub async fn get_task_pipeline(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<PipelineResponse>, AppError> {
+    let project = state.project().await?;
+    let task = project
+        .task_repository
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Task not found: {}", id)))?;
+
+    let sessions = project.session_repository.find_by_task_id(id).await?;
+    let executor = &project.task_executor;
+    let config = executor.config();
+
+    let usage = join_all(sessions.iter().map(|session| async {
+        match &session.opencode_session_id {
+            Some(opencode_id) => session_usage(
+                executor.opencode_config(),
+                opencode_id,
+                task.workspace_path.as_deref(),
+            )
+            .await
+            .map(|usage| usage.tokens),
+            None => None,
+        }
+    }))
+    .await;
+
+    let stages = PIPELINE
+        .iter()
+        .enumerate()
+        .map(|(index, &phase)| {
+            let phase_sessions: Vec<(&Session, &Option<TokenUsage>)> = sessions
+                .iter()
+                .zip(&usage)
+                .filter(|(s, _)| s.phase == phase)
+                .collect();
+            let newest_first: Vec<&Session> = phase_sessions.iter().map(|(s, _)| *s).collect();
+
+            let tokens = phase_sessions
+                .iter()
+                .filter_map(|(_, tokens)| tokens.as_ref())
+                .fold(None, |total: Option<TokenUsage>, tokens| {
+                    let mut total = total.unwrap_or_default();
+                    total.add(tokens);
+                    Some(total)
+                });
+
+            let requires_approval = requires_approval(phase, config);
+            let status = stage_status(index, &newest_first, task.status, requires_approval);
+
+            let consensus = &config.review_consensus;
+            let review_samples = (phase == SessionPhase::Review && consensus.is_enabled())
+                .then_some(consensus.samples);
+            let estimated_consensus_tokens = review_samples.and_then(|samples| {
+                let latest = phase_sessions.first()?.1.as_ref()?;
+                Some(consensus_cost(latest, samples))
+            });
+
+            PipelineStage {
+                phase,
+                status,
+                started_at: newest_first.iter().filter_map(|s| s.started_at).min(),
+                completed_at: newest_first.first().and_then(|s| s.completed_at),
+                session_ids: newest_first.iter().rev().map(|s| s.id).collect(),
+                tokens,
+                requires_approval,
+                max_iterations: (phase == SessionPhase::Review)
+                    .then_some(config.max_review_iterations),
+                review_samples,
+                estimated_consensus_tokens,
+            }
+        })
+        .collect();
+
+    Ok(Json(PipelineResponse {
+        task_id: task.id,
+        task_status: task.status,
+        stages,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(phase: SessionPhase, status: SessionStatus) -> Session {
+        let mut session = Session::new(Uuid::new_v4(), phase);
+        session.status = status;
+        session
+    }
+
+    #[test]
+    fn test_stage_status() {
+        let completed = session(SessionPhase::Planning, SessionStatus::Completed);
+        let running = session(SessionPhase::Implementation, SessionStatus::Running);
+        let failed = session(SessionPhase::Review, SessionStatus::Failed);
+
+        assert_eq!(
+            stage_status(0, &[&completed], TaskStatus::PlanningReview, true),
+            PipelineStageStatus::AwaitingApproval
+        );
+        assert_eq!(
+            stage_status(0, &[&completed], TaskStatus::PlanningReview, false),
+            PipelineStageStatus::Completed
+        );
+        assert_eq!(
+            stage_status(1, &[&running], TaskStatus::InProgress, false),
+            PipelineStageStatus::Running
+        );
+        assert_eq!(
+            stage_status(2, &[&failed], TaskStatus::AiReview, true),
+            PipelineStageStatus::Failed
+        );
+        assert_eq!(
+            stage_status(3, &[], TaskStatus::Review, false),
+            PipelineStageStatus::Skipped
+        );
+        assert_eq!(
+            stage_status(3, &[], TaskStatus::AiReview, false),
+            PipelineStageStatus::Pending
+        );
+    }
+
+    #[test]
+    fn test_consensus_cost() {
+        let review = TokenUsage {
+            input: 1000,
+            output: 200,
+            reasoning: 50,
+            cache_read: 250,
+            cache_write: 400,
+        };
+
+        assert_eq!(consensus_cost(&review, 3), 3000);
+        assert_eq!(consensus_cost(&review, 1), 0);
+    }
+}
@@ -0,0 +1,165 @@
+use axum::extract::State;
+use axum::Json;
+use db::{
+    DigestSchedule, UserPreferences, UserPreferencesRepository, IN_APP_CHANNEL, LOCAL_USER_ID,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::{debug, info};
+use utoipa::ToSchema;
+
+use crate::error::AppError;
+use crate::state::AppState;
+
+/// Channels notifications can be delivered to
+const NOTIFICATION_CHANNELS: &[&str] = &[IN_APP_CHANNEL];
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct UserPreferencesResponse {
+    pub user_id: String,
+    /// Board filters restored when the board is opened
+    pub board_filters: HashMap<String, String>,
+    /// Channels notifications are delivered to (`in_app`)
+    pub notification_channels: Vec<String>,
+    /// Event types the UI shows notifications for, e.g. `session.ended` or
+    /// `task.*`; empty means every event. Events are streamed regardless.
+    pub event_subscriptions: Vec<String>,
+    /// Chat model for wiki questions, overriding the project's
+    pub ask_model: Option<String>,
+    /// Address email digests are sent to
+    pub digest_email: Option<String>,
+    /// How often digests are sent: `off`, `daily` or `weekly`
+    pub digest_schedule: String,
+    /// Unix timestamp of the last digest sent
+    pub digest_last_sent_at: Option<i64>,
+    pub updated_at: i64,
+}
+
+impl From<UserPreferences> for UserPreferencesResponse {
+    fn from(preferences: UserPreferences) -> Self {
+        let board_filters = serde_json::from_value(preferences.board_filters()).unwrap_or_default();
+        Self {
+            notification_channels: preferences.notification_channels(),
+            event_subscriptions: preferences.event_subscriptions(),
+            user_id: preferences.user_id,
+            board_filters,
+            ask_model: preferences.ask_model,
+            digest_email: preferences.digest_email,
+            digest_schedule: preferences.digest_schedule,
+            digest_last_sent_at: preferences.digest_last_sent_at,
+            updated_at: preferences.updated_at,
+        }
+    }
+}
+
+/// Fields left out are kept unchanged
+#[derive(Debug, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct UpdateUserPreferencesRequest {
+    pub board_filters: Option<HashMap<String, String>>,
+    pub notification_channels: Option<Vec<String>>,
+    pub event_subscriptions: Option<Vec<String>>,
+    /// Empty to fall back to the project's chat model
+    pub ask_model: Option<String>,
+    /// Empty to stop sending digests anywhere
+    pub digest_email: Option<String>,
+    /// `off`, `daily` or `weekly`
+    pub digest_schedule: Option<String>,
+}
+
+/// Preferences of the user making the request. The server does not
+/// authenticate users yet, so every request acts as the local user.
+pub(crate) async fn current_user_preferences(
+    state: &AppState,
+) -> Result<UserPreferences, AppError> {
+    let project = state.project().await?;
+    let repo = UserPreferencesRepository::new(project.pool.clone());
+    Ok(repo.find_or_default(LOCAL_USER_ID).await?)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/me/preferences",
+    responses(
+        (status = 200, description = "Preferences of the current user", body = UserPreferencesResponse),
+        (status = 500, description = "Failed to read preferences")
+    ),
+    tag = "preferences"
+)]
+pub async fn get_user_preferences(
+    State(state): State<AppState>,
+) -> Result<Json<UserPreferencesResponse>, AppError> {
+    debug!("Reading user preferences");
+
+    let preferences = current_user_preferences(&state).await?;
+    Ok(Json(UserPreferencesResponse::from(preferences)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/me/preferences",
+    request_body = UpdateUserPreferencesRequest,
+    responses(
+        (status = 200, description = "Preferences updated", body = UserPreferencesResponse),
+        (status = 400, description = "Unknown notification channel or digest schedule"),
+        (status = 500, description = "Failed to save preferences")
+    ),
+    tag = "preferences"
+)]
+pub async fn update_user_preferences(
+    State(state): State<AppState>,
+    Json(payload): Json<UpdateUserPreferencesRequest>,
+) -> Result<Json<UserPreferencesResponse>, AppError> {
+    info!("Updating user preferences");
+
+    let mut preferences = current_user_preferences(&state).await?;
+
+    if let Some(board_filters) = payload.board_filters {
+        preferences.board_filters =
+            serde_json::to_string(&board_filters).unwrap_or_else(|_| "{}".to_string());
+    }
+    if let Some(channels) = payload.notification_channels {
+        if let Some(unknown) = channels
+            .iter()
+            .find(|c| !NOTIFICATION_CHANNELS.contains(&c.as_str()))
+        {
+            return Err(AppError::BadRequest(format!(
+                "Unknown notification channel '{}'. Available: {}",
+                unknown,
+                NOTIFICATION_CHANNELS.join(", ")
+            )));
+        }
+        preferences.notification_channels =
+            serde_json::to_string(&channels).unwrap_or_else(|_| "[]".to_string());
+    }
+    if let Some(subscriptions) = payload.event_subscriptions {
+        preferences.event_subscriptions =
+            serde_json::to_string(&subscriptions).unwrap_or_else(|_| "[]".to_string());
+    }
+    if let Some(ask_model) = payload.ask_model {
+        let ask_model = ask_model.trim();
+        preferences.ask_model = (!ask_model.is_empty()).then(|| ask_model.to_string());
+    }
+    if let Some(digest_email) = payload.digest_email {
+        let digest_email = digest_email.trim();
+        preferences.digest_email = (!digest_email.is_empty()).then(|| digest_email.to_string());
+    }
+    if let Some(schedule) = payload.digest_schedule {
+        let schedule = DigestSchedule::parse(&schedule).ok_or_else(|| {
+            AppError::BadRequest(format!(
+                "Unknown digest schedule '{}'. Available: off, daily, weekly",
+                schedule
+            ))
+        })?;
+        preferences.digest_schedule = schedule.as_str().to_string();
+    }
+
+    let project = state.project().await?;
+    let repo = UserPreferencesRepository::new(project.pool.clone());
+    let preferences = repo.upsert(&preferences).await?;
+
+    Ok(Json(UserPreferencesResponse::from(preferences)))
+}
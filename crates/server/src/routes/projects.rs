@@ -3,6 +3,7 @@ use axum::Json;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use utoipa::ToSchema;
+use vcs::VcsKind;
 
 use crate::error::AppError;
 use crate::project_manager::{detect_vcs, ProjectErrorCode, ProjectInfo as ManagerProjectInfo};
@@ -12,9 +13,15 @@ use crate::state::AppState;
 pub struct ProjectInfo {
     pub name: String,
     pub path: String,
+    /// `git`, `jujutsu`, or `none` for directories without version control
     pub vcs: String,
+    /// What the project's VCS supports; without version control, workspaces
+    /// are plain copies and commit, push and pull requests are unavailable
+    pub vcs_capabilities: vcs::VcsCapabilities,
     pub tasks_count: i64,
     pub initialized: bool,
+    /// Seeded by demo mode; wipe it with `DELETE /api/admin/seed`
+    pub demo: bool,
 }
 
 impl From<ManagerProjectInfo> for ProjectInfo {
@@ -23,8 +30,10 @@ impl From<ManagerProjectInfo> for ProjectInfo {
             name: info.name,
             path: info.path,
             vcs: info.vcs,
+            vcs_capabilities: info.vcs_capabilities,
             tasks_count: info.tasks_count,
             initialized: info.initialized,
+            demo: info.demo,
         }
     }
 }
@@ -279,6 +288,8 @@ pub struct ValidatePathResponse {
     pub vcs: Option<String>,
     pub name: Option<String>,
     pub error: Option<String>,
+    /// Set when the project can be opened with reduced capabilities
+    pub warning: Option<String>,
 }
 
 #[utoipa::path(
@@ -303,6 +314,7 @@ pub async fn validate_project_path(
             vcs: None,
             name: None,
             error: Some("Path does not exist".to_string()),
+            warning: None,
         });
     }
 
@@ -314,28 +326,27 @@ pub async fn validate_project_path(
             vcs: None,
             name: None,
             error: Some("Path is not a directory".to_string()),
+            warning: None,
         });
     }
 
-    let vcs = detect_vcs(&path);
-    let is_vcs_repo = vcs != "none";
+    let vcs = VcsKind::detect(&path);
+    let is_vcs_repo = vcs != VcsKind::None;
 
     let name = path.file_name().and_then(|n| n.to_str()).map(String::from);
 
     Json(ValidatePathResponse {
-        valid: is_vcs_repo,
+        valid: true,
         exists: true,
         is_vcs_repo,
-        vcs: if is_vcs_repo {
-            Some(vcs.to_string())
-        } else {
-            None
-        },
+        vcs: is_vcs_repo.then(|| vcs.as_str().to_string()),
         name,
-        error: if !is_vcs_repo {
-            Some("Not a git or jujutsu repository".to_string())
-        } else {
-            None
-        },
+        error: None,
+        warning: (!is_vcs_repo).then(|| {
+            format!(
+                "Not a git or jujutsu repository. Tasks will work in copies of the directory without: {}",
+                vcs.capabilities().unsupported_operations().join(", ")
+            )
+        }),
     })
 }
@@ -0,0 +1,289 @@
+//! Release reviews
+//!
+//! Each task is reviewed alone before it merges. Before a release, the
+//! tasks merged since the last tag are reviewed once more as a whole: the
+//! diff every merge captured is kept in `task_merges`, and a release review
+//! runs them together in a temporary workspace, producing one report whose
+//! findings name the tasks they came from.
+
+use std::collections::HashMap;
+use std::path::Path as FsPath;
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use db::{TaskMerge, TaskMergeRepository};
+use opencode_core::Task;
+use orchestrator::{MergedTask, ReleaseReviewReport};
+use serde::Deserialize;
+use tokio::process::Command;
+use tracing::{error, info, warn};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::project_manager::ProjectContext;
+use crate::state::AppState;
+
+#[derive(Debug, Default, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct StartReleaseReviewRequest {
+    /// Review the tasks merged since this tag (default: the latest tag)
+    #[serde(default)]
+    pub since_tag: Option<String>,
+    /// Review these merged tasks instead of those since a tag
+    #[serde(default)]
+    pub task_ids: Option<Vec<Uuid>>,
+}
+
+/// Keep the diff of a merged task for later release reviews
+pub async fn record_merge(project: &ProjectContext, task_id: Uuid, diff: &str) {
+    if diff.trim().is_empty() {
+        return;
+    }
+    let branch = project.workspace_manager.vcs().main_branch().to_string();
+    if let Err(e) = TaskMergeRepository::new(project.pool.clone())
+        .create(&task_id.to_string(), &branch, diff)
+        .await
+    {
+        warn!(task_id = %task_id, error = %e, "Failed to record merged diff");
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/releases/reviews",
+    request_body = StartReleaseReviewRequest,
+    responses(
+        (status = 202, description = "Release review started", body = ReleaseReviewReport),
+        (status = 400, description = "Unknown tag, both selections given, or nothing merged to review")
+    ),
+    tag = "releases"
+)]
+pub async fn start_release_review(
+    State(state): State<AppState>,
+    Json(payload): Json<StartReleaseReviewRequest>,
+) -> Result<(StatusCode, Json<ReleaseReviewReport>), AppError> {
+    let project = state.project().await?;
+    let repo = TaskMergeRepository::new(project.pool.clone());
+
+    let (since_tag, merges) = match (payload.task_ids, payload.since_tag) {
+        (Some(_), Some(_)) => {
+            return Err(AppError::BadRequest(
+                "Give either since_tag or task_ids, not both".to_string(),
+            ))
+        }
+        (Some(task_ids), None) => {
+            let mut merges = Vec::new();
+            for task_id in task_ids {
+                let task_merges = repo.find_by_task_id(&task_id.to_string()).await?;
+                if task_merges.is_empty() {
+                    return Err(AppError::BadRequest(format!(
+                        "Task {} has no recorded merge",
+                        task_id
+                    )));
+                }
+                merges.extend(task_merges);
+            }
+            merges.sort_by_key(|m| m.merged_at);
+            (None, merges)
+        }
+        (None, since_tag) => {
+            let tag = match since_tag {
+                Some(tag) => Some(tag),
+                None => latest_tag(&project.project_path).await,
+            };
+            let since = match &tag {
+                Some(tag) => tag_time(&project.project_path, tag)
+                    .await
+                    .ok_or_else(|| AppError::BadRequest(format!("Unknown tag: {}", tag)))?,
+                None => 0,
+            };
+            (tag, repo.find_since(since).await?)
+        }
+    };
+
+    let mut tasks: HashMap<Uuid, Task> = HashMap::new();
+    for merge in &merges {
+        let Ok(task_id) = Uuid::parse_str(&merge.task_id) else {
+            continue;
+        };
+        if let Some(task) = project.task_repository.find_by_id(task_id).await? {
+            tasks.insert(task_id, task);
+        }
+    }
+    let merged = merged_tasks(&merges, &tasks);
+    if merged.is_empty() {
+        return Err(AppError::BadRequest(match &since_tag {
+            Some(tag) => format!("No tasks merged since {}", tag),
+            None => "No merged tasks to review".to_string(),
+        }));
+    }
+
+    let report = ReleaseReviewReport::start(
+        since_tag,
+        merged.iter().map(MergedTask::review_task).collect(),
+    );
+    project
+        .task_executor
+        .file_manager()
+        .write_release_review(&report)
+        .await
+        .map_err(|e| AppError::Internal(e.to_string()))?;
+    info!(
+        release_review_id = %report.id,
+        tasks = merged.len(),
+        "API: Release review started"
+    );
+
+    let executor = project.task_executor.clone();
+    let mut running = report.clone();
+    tokio::spawn(async move {
+        if let Err(e) = executor.review_release(&mut running, &merged).await {
+            error!(release_review_id = %running.id, error = %e, "Failed to write release review");
+        }
+    });
+
+    Ok((StatusCode::ACCEPTED, Json(report)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/releases/reviews",
+    responses(
+        (status = 200, description = "Release reviews, newest first", body = Vec<ReleaseReviewReport>)
+    ),
+    tag = "releases"
+)]
+pub async fn list_release_reviews(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<ReleaseReviewReport>>, AppError> {
+    let project = state.project().await?;
+    let reports = project
+        .task_executor
+        .file_manager()
+        .list_release_reviews()
+        .await
+        .map_err(|e| AppError::Internal(e.to_string()))?;
+    Ok(Json(reports))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/releases/reviews/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Release review ID")
+    ),
+    responses(
+        (status = 200, description = "Release review report", body = ReleaseReviewReport),
+        (status = 404, description = "Release review not found")
+    ),
+    tag = "releases"
+)]
+pub async fn get_release_review(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ReleaseReviewReport>, AppError> {
+    let project = state.project().await?;
+    project
+        .task_executor
+        .file_manager()
+        .read_release_review(id)
+        .await
+        .map_err(|e| AppError::Internal(e.to_string()))?
+        .map(Json)
+        .ok_or_else(|| AppError::NotFound(format!("Release review not found: {}", id)))
+}
+
+/// Merges grouped by task in the order the tasks first merged. A task
+/// merged more than once contributes every diff; merges of deleted tasks
+/// are left out.
+fn merged_tasks(merges: &[TaskMerge], tasks: &HashMap<Uuid, Task>) -> Vec<MergedTask> {
+    let mut merged: Vec<MergedTask> = Vec::new();
+    for merge in merges {
+        let Some(task) = Uuid::parse_str(&merge.task_id)
+            .ok()
+            .and_then(|id| tasks.get(&id))
+        else {
+            continue;
+        };
+        match merged.iter_mut().find(|m| m.task_id == task.id) {
+            Some(existing) => {
+                existing.diff.push('\n');
+                existing.diff.push_str(&merge.diff);
+            }
+            None => merged.push(MergedTask {
+                task_id: task.id,
+                title: task.title.clone(),
+                description: task.description.clone(),
+                diff: merge.diff.clone(),
+            }),
+        }
+    }
+    merged
+}
+
+/// The most recent tag reachable from HEAD
+async fn latest_tag(project_path: &FsPath) -> Option<String> {
+    git_output(project_path, &["describe", "--tags", "--abbrev=0"]).await
+}
+
+/// Commit time of a tag, in unix seconds
+async fn tag_time(project_path: &FsPath, tag: &str) -> Option<i64> {
+    let rev = format!("{}^{{commit}}", tag);
+    git_output(project_path, &["log", "-1", "--format=%ct", &rev, "--"])
+        .await
+        .and_then(|out| out.parse().ok())
+}
+
+async fn git_output(project_path: &FsPath, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(project_path)
+        .output()
+        .await
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let out = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (!out.is_empty()).then_some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn merge(task_id: Uuid, diff: &str, merged_at: i64) -> TaskMerge {
+        TaskMerge {
+            id: Uuid::new_v4().to_string(),
+            task_id: task_id.to_string(),
+            branch: "main".to_string(),
+            diff: diff.to_string(),
+            merged_at,
+        }
+    }
+
+    #[test]
+    fn test_merged_tasks_groups_merges_by_task() {
+        let first = Task::new("First", "");
+        let second = Task::new("Second", "");
+        let tasks: HashMap<Uuid, Task> = [first.clone(), second.clone()]
+            .into_iter()
+            .map(|t| (t.id, t))
+            .collect();
+        let merges = vec![
+            merge(first.id, "diff one", 1),
+            merge(second.id, "diff two", 2),
+            merge(Uuid::new_v4(), "deleted task", 3),
+            merge(first.id, "diff three", 4),
+        ];
+
+        let merged = merged_tasks(&merges, &tasks);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].task_id, first.id);
+        assert_eq!(merged[0].diff, "diff one\ndiff three");
+        assert_eq!(merged[1].title, "Second");
+    }
+}
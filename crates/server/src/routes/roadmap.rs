@@ -81,10 +81,7 @@ pub async fn generate_roadmap(
     let service = create_roadmap_service(&state, &project.path, &project_config);
 
     // Increment generation ID to cancel any previous generation
-    let generation_id = state
-        .roadmap_generation_id
-        .fetch_add(1, Ordering::SeqCst)
-        + 1;
+    let generation_id = state.roadmap_generation_id.fetch_add(1, Ordering::SeqCst) + 1;
 
     info!(
         project_path = %project.path.display(),
@@ -115,13 +112,13 @@ pub async fn generate_roadmap(
     }
 
     // Publish progress event with reset status so frontend updates immediately
-    state.event_bus.publish(EventEnvelope::new(
-        Event::RoadmapGenerationProgress {
+    state
+        .event_bus
+        .publish(EventEnvelope::new(Event::RoadmapGenerationProgress {
             phase: "idle".to_string(),
             progress: 0,
             message: "Starting...".to_string(),
-        },
-    ));
+        }));
 
     state
         .event_bus
@@ -301,11 +298,10 @@ pub async fn convert_feature_to_task(
         .feature_by_id(&feature_id)
         .ok_or_else(|| AppError::NotFound(format!("Feature {} not found", feature_id)))?;
 
-    if feature.linked_task_id.is_some() {
+    if let Some(linked_task_id) = &feature.linked_task_id {
         return Err(AppError::BadRequest(format!(
             "Feature {} is already linked to task {}",
-            feature_id,
-            feature.linked_task_id.as_ref().unwrap()
+            feature_id, linked_task_id
         )));
     }
 
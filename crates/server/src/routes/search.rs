@@ -0,0 +1,293 @@
+use axum::extract::{Query, State};
+use axum::Json;
+use events::{Event, EventBus};
+use opencode_core::SessionPhase;
+use serde::Serialize;
+use std::collections::HashMap;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{debug, error, info};
+use utoipa::ToSchema;
+
+use crate::config::ProjectConfig;
+use crate::error::AppError;
+use crate::project_manager::ProjectContext;
+use crate::routes::wiki::{embedding_provider, get_wiki_db_path};
+use crate::state::AppState;
+
+use wiki::{RecallIndex, RecallItem, RecallMatch, RecallScope};
+
+/// Characters of an item's content returned as its snippet
+const SNIPPET_LENGTH: usize = 500;
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct SemanticSearchResponse {
+    pub query: String,
+    pub results: Vec<SemanticSearchResult>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct SemanticSearchResult {
+    pub id: String,
+    /// `task`, `finding` or `session`
+    pub scope: String,
+    pub task_id: String,
+    pub title: String,
+    pub snippet: String,
+    pub score: f32,
+}
+
+impl From<RecallMatch> for SemanticSearchResult {
+    fn from(m: RecallMatch) -> Self {
+        let snippet = if m.item.content.len() > SNIPPET_LENGTH {
+            let mut end = SNIPPET_LENGTH;
+            while !m.item.content.is_char_boundary(end) {
+                end -= 1;
+            }
+            format!("{}...", &m.item.content[..end])
+        } else {
+            m.item.content
+        };
+        Self {
+            id: m.item.id,
+            scope: m.item.scope.as_str().to_string(),
+            task_id: m.item.task_id.to_string(),
+            title: m.item.title,
+            snippet,
+            score: m.score,
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/search/semantic",
+    params(
+        ("q" = String, Query, description = "Natural language query"),
+        ("scope" = Option<String>, Query, description = "tasks, findings or sessions (default: all)"),
+        ("limit" = Option<u32>, Query, description = "Maximum number of results (default: 10, max: 50)")
+    ),
+    responses(
+        (status = 200, description = "Past work similar to the query", body = SemanticSearchResponse),
+        (status = 400, description = "Invalid scope or wiki not configured"),
+        (status = 500, description = "Search failed")
+    ),
+    tag = "search"
+)]
+pub async fn semantic_search(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<SemanticSearchResponse>, AppError> {
+    let query = params
+        .get("q")
+        .filter(|q| !q.trim().is_empty())
+        .cloned()
+        .ok_or_else(|| AppError::BadRequest("Query parameter 'q' is required".to_string()))?;
+    let scope = params
+        .get("scope")
+        .map(String::as_str)
+        .map(|s| {
+            RecallScope::parse(s).ok_or_else(|| {
+                AppError::BadRequest(format!(
+                    "Invalid scope '{}'. Use tasks, findings or sessions",
+                    s
+                ))
+            })
+        })
+        .transpose()?;
+    let limit = params
+        .get("limit")
+        .and_then(|l| l.parse::<usize>().ok())
+        .unwrap_or(10)
+        .clamp(1, 50);
+
+    let project = state.project().await?;
+    let config = ProjectConfig::read(&project.project_path).await;
+    if !config.wiki.enabled {
+        return Err(AppError::BadRequest(
+            "Semantic search requires the wiki to be enabled".to_string(),
+        ));
+    }
+
+    let provider = embedding_provider(
+        &config.wiki,
+        project.usage_store.tracker(wiki::UsageScope::default()),
+    )?;
+    let index = RecallIndex::new(provider.as_ref(), embedding_model(&config));
+    let db_path = get_wiki_db_path(&project.project_path);
+
+    // Embed whatever changed since the last sync so results are current
+    let items = collect_recall_items(&project).await?;
+    index
+        .sync(&db_path, &items)
+        .await
+        .map_err(|e| AppError::Internal(format!("Failed to update search index: {}", e)))?;
+
+    let matches = index
+        .search(&db_path, &query, scope, limit)
+        .await
+        .map_err(|e| AppError::Internal(format!("Search failed: {}", e)))?;
+
+    Ok(Json(SemanticSearchResponse {
+        query,
+        results: matches
+            .into_iter()
+            .map(SemanticSearchResult::from)
+            .collect(),
+    }))
+}
+
+/// Keep the recall index of `project` current by syncing it whenever a
+/// session ends, until the project is closed
+pub fn spawn_recall_sync(project: ProjectContext, event_bus: &EventBus) {
+    let mut rx = event_bus.subscribe();
+    let project_path = project.path.display().to_string();
+
+    tokio::spawn(async move {
+        loop {
+            match rx.recv().await {
+                Ok(envelope) => match envelope.event {
+                    Event::SessionEnded { task_id, .. } => {
+                        debug!(task_id = %task_id, "Session ended, syncing recall index");
+                        sync_recall_index(&project).await;
+                    }
+                    Event::ProjectClosed { path } if path == project_path => break,
+                    _ => {}
+                },
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
+async fn sync_recall_index(project: &ProjectContext) {
+    let config = ProjectConfig::read(&project.project_path).await;
+    if !config.wiki.enabled {
+        return;
+    }
+    let usage = project.usage_store.tracker(wiki::UsageScope::default());
+    let provider = match config
+        .wiki
+        .embedding_provider_config()
+        .build_tracked(Some(usage))
+    {
+        Ok(provider) => provider,
+        Err(e) => {
+            debug!(error = %e, "No embedding provider, skipping recall index sync");
+            return;
+        }
+    };
+
+    let items = match collect_recall_items(project).await {
+        Ok(items) => items,
+        Err(e) => {
+            error!(error = %e, "Failed to collect recall items");
+            return;
+        }
+    };
+
+    let db_path = get_wiki_db_path(&project.project_path);
+    match RecallIndex::new(provider.as_ref(), embedding_model(&config))
+        .sync(&db_path, &items)
+        .await
+    {
+        Ok(embedded) => info!(embedded = embedded, "Recall index synced"),
+        Err(e) => error!(error = %e, "Failed to sync recall index"),
+    }
+}
+
+fn embedding_model(config: &ProjectConfig) -> String {
+    config
+        .wiki
+        .embedding_model
+        .clone()
+        .unwrap_or_else(|| "openai/text-embedding-3-small".to_string())
+}
+
+/// Every task, finding and session summary of the project as recall items
+async fn collect_recall_items(project: &ProjectContext) -> Result<Vec<RecallItem>, db::DbError> {
+    let file_manager = project.task_executor.file_manager();
+    let mut items = Vec::new();
+
+    for task in project.task_repository.find_all().await? {
+        items.push(RecallItem::new(
+            format!("task:{}", task.id),
+            RecallScope::Task,
+            task.id,
+            &task.title,
+            &task.description,
+        ));
+
+        let findings = file_manager.read_findings(task.id).await.ok().flatten();
+        for finding in findings.iter().flat_map(|f| &f.findings) {
+            let content = match &finding.file_path {
+                Some(path) => format!("{}\n\nFile: {}", finding.description, path),
+                None => finding.description.clone(),
+            };
+            items.push(RecallItem::new(
+                format!("finding:{}:{}", task.id, finding.id),
+                RecallScope::Finding,
+                task.id,
+                &finding.title,
+                content,
+            ));
+        }
+
+        let sessions = project.session_repository.find_by_task_id(task.id).await?;
+        let phase_summaries = file_manager
+            .read_phase_summaries(task.id)
+            .await
+            .unwrap_or_default();
+        // The plan file is rewritten by every planning session
+        let latest_planning = sessions
+            .iter()
+            .filter(|s| s.phase == SessionPhase::Planning)
+            .max_by_key(|s| s.created_at)
+            .map(|s| s.id);
+
+        for session in &sessions {
+            let summary = match session.phase {
+                SessionPhase::Planning if Some(session.id) == latest_planning => {
+                    file_manager.read_plan(task.id).await.ok()
+                }
+                SessionPhase::Implementation => session
+                    .implementation_phase_number
+                    .and_then(|n| phase_summaries.iter().find(|s| s.phase_number == n))
+                    .map(|s| {
+                        let mut summary = format!("{}\n\n{}", s.title, s.summary);
+                        if !s.files_changed.is_empty() {
+                            summary.push_str(&format!(
+                                "\n\nFiles changed: {}",
+                                s.files_changed.join(", ")
+                            ));
+                        }
+                        if let Some(notes) = &s.notes {
+                            summary.push_str(&format!("\n\nNotes: {}", notes));
+                        }
+                        summary
+                    }),
+                SessionPhase::Review => findings
+                    .as_ref()
+                    .filter(|f| f.session_id == session.id)
+                    .map(|f| f.summary.clone()),
+                _ => None,
+            };
+
+            if let Some(summary) = summary.filter(|s| !s.trim().is_empty()) {
+                items.push(RecallItem::new(
+                    format!("session:{}", session.id),
+                    RecallScope::Session,
+                    task.id,
+                    format!("{} session: {}", session.phase.as_str(), task.title),
+                    summary,
+                ));
+            }
+        }
+    }
+
+    Ok(items)
+}
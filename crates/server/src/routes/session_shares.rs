@@ -0,0 +1,277 @@
+//! Read-only links to a session
+//!
+//! A share link lets a teammate see what a session did, its transcript and
+//! the diff it produced, without access to anything else. The token in the
+//! link is the only credential; links expire and can be revoked.
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use chrono::{Duration, Utc};
+use db::{SessionArtifactRepository, SessionShare, SessionShareRepository};
+use opencode_core::Session;
+use orchestrator::SessionActivityMsg;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::auth::{generate_share_token, hash_token};
+use crate::error::AppError;
+use crate::routes::artifacts::ArtifactStore;
+use crate::state::AppState;
+
+const DEFAULT_SHARE_HOURS: u32 = 72;
+const MAX_SHARE_HOURS: u32 = 30 * 24;
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct SessionShareResponse {
+    pub id: String,
+    pub session_id: String,
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub revoked_at: Option<i64>,
+    /// Whether the link still opens the session
+    pub active: bool,
+}
+
+impl From<SessionShare> for SessionShareResponse {
+    fn from(share: SessionShare) -> Self {
+        Self {
+            active: share.is_active(Utc::now().timestamp()),
+            id: share.id,
+            session_id: share.session_id,
+            created_at: share.created_at,
+            expires_at: share.expires_at,
+            revoked_at: share.revoked_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct CreateSessionShareRequest {
+    /// How long the link stays open, at most 720 hours (default: 72)
+    #[serde(default)]
+    pub expires_in_hours: Option<u32>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct CreateSessionShareResponse {
+    /// The link's token; it is only shown once
+    pub token: String,
+    /// Path of the read-only view, relative to the server
+    pub url: String,
+    pub share: SessionShareResponse,
+}
+
+/// What a share link shows
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct SharedSessionResponse {
+    pub session: Session,
+    pub task_title: String,
+    /// Messages, reasoning and tool calls of the session, oldest first
+    #[schema(value_type = Vec<Object>)]
+    pub transcript: Vec<SessionActivityMsg>,
+    /// Diff the session produced, if it recorded one
+    pub diff: Option<String>,
+    pub expires_at: i64,
+}
+
+fn share_url(token: &str) -> String {
+    format!("/api/shared/{}", token)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/sessions/{id}/share",
+    params(
+        ("id" = Uuid, Path, description = "Session ID")
+    ),
+    request_body = CreateSessionShareRequest,
+    responses(
+        (status = 201, description = "Share link created", body = CreateSessionShareResponse),
+        (status = 400, description = "Expiry out of range"),
+        (status = 404, description = "Session not found")
+    ),
+    tag = "sessions"
+)]
+pub async fn create_session_share(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<CreateSessionShareRequest>,
+) -> Result<(StatusCode, Json<CreateSessionShareResponse>), AppError> {
+    let hours = payload.expires_in_hours.unwrap_or(DEFAULT_SHARE_HOURS);
+    if hours == 0 || hours > MAX_SHARE_HOURS {
+        return Err(AppError::BadRequest(format!(
+            "expires_in_hours must be between 1 and {}",
+            MAX_SHARE_HOURS
+        )));
+    }
+
+    let project = state.project().await?;
+    project
+        .session_repository
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Session not found: {}", id)))?;
+
+    let token = generate_share_token();
+    let expires_at = (Utc::now() + Duration::hours(i64::from(hours))).timestamp();
+    let share = SessionShareRepository::new(project.pool.clone())
+        .create(&id.to_string(), &hash_token(&token), expires_at)
+        .await?;
+    info!(session_id = %id, share_id = %share.id, hours, "Shared session");
+
+    Ok((
+        StatusCode::CREATED,
+        Json(CreateSessionShareResponse {
+            url: share_url(&token),
+            token,
+            share: SessionShareResponse::from(share),
+        }),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/sessions/{id}/shares",
+    params(
+        ("id" = Uuid, Path, description = "Session ID")
+    ),
+    responses(
+        (status = 200, description = "Share links of the session, newest first, including revoked and expired ones", body = Vec<SessionShareResponse>),
+        (status = 404, description = "Session not found")
+    ),
+    tag = "sessions"
+)]
+pub async fn list_session_shares(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<SessionShareResponse>>, AppError> {
+    let project = state.project().await?;
+    project
+        .session_repository
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Session not found: {}", id)))?;
+
+    let shares = SessionShareRepository::new(project.pool.clone())
+        .find_by_session_id(&id.to_string())
+        .await?;
+    Ok(Json(shares.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/sessions/{id}/shares/{share_id}",
+    params(
+        ("id" = Uuid, Path, description = "Session ID"),
+        ("share_id" = String, Path, description = "Share link ID")
+    ),
+    responses(
+        (status = 204, description = "Share link revoked"),
+        (status = 404, description = "Share link not found or already revoked")
+    ),
+    tag = "sessions"
+)]
+pub async fn revoke_session_share(
+    State(state): State<AppState>,
+    Path((id, share_id)): Path<(Uuid, String)>,
+) -> Result<StatusCode, AppError> {
+    let project = state.project().await?;
+    let revoked = SessionShareRepository::new(project.pool.clone())
+        .revoke(&id.to_string(), &share_id)
+        .await?;
+
+    if revoked {
+        info!(session_id = %id, share_id = %share_id, "Revoked session share");
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(AppError::NotFound(format!(
+            "Share link not found: {}",
+            share_id
+        )))
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/shared/{token}",
+    params(
+        ("token" = String, Path, description = "Token of the share link")
+    ),
+    responses(
+        (status = 200, description = "The shared session", body = SharedSessionResponse),
+        (status = 404, description = "Unknown, expired or revoked link")
+    ),
+    tag = "sessions"
+)]
+pub async fn get_shared_session(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<Json<SharedSessionResponse>, AppError> {
+    // Unknown, expired and revoked links look the same, so a link reveals
+    // nothing once it stops working
+    let not_found = || AppError::NotFound("Share link not found or expired".to_string());
+
+    let project = state.project().await?;
+    let share = SessionShareRepository::new(project.pool.clone())
+        .find_by_hash(&hash_token(&token))
+        .await?
+        .filter(|s| s.is_active(Utc::now().timestamp()))
+        .ok_or_else(not_found)?;
+    let session_id = Uuid::parse_str(&share.session_id).map_err(|_| not_found())?;
+    let session = project
+        .session_repository
+        .find_by_id(session_id)
+        .await?
+        .ok_or_else(not_found)?;
+    let task_title = project
+        .task_repository
+        .find_by_id(session.task_id)
+        .await?
+        .map(|t| t.title)
+        .unwrap_or_default();
+
+    let transcript = project
+        .activity_registry
+        .get_or_create_with_history(session_id)
+        .await
+        .get_history();
+
+    let diff_artifact = SessionArtifactRepository::new(project.pool.clone())
+        .find_by_session_id(&share.session_id)
+        .await?
+        .into_iter()
+        .rev()
+        .find(|a| a.kind == "diff");
+    let diff = match diff_artifact {
+        Some(artifact) => match ArtifactStore::new(&project.project_path)
+            .read(&artifact.content_hash)
+            .await
+        {
+            Ok(content) => Some(String::from_utf8_lossy(&content).into_owned()),
+            Err(e) => {
+                warn!(session_id = %session_id, error = %e, "Failed to read shared diff");
+                None
+            }
+        },
+        None => None,
+    };
+
+    Ok(Json(SharedSessionResponse {
+        session,
+        task_title,
+        transcript,
+        diff,
+        expires_at: share.expires_at,
+    }))
+}
@@ -1,24 +1,68 @@
-use axum::extract::{Path, State};
-use axum::http::StatusCode;
+use axum::extract::{Path, Query, State};
+use axum::http::{StatusCode, Uri};
+use axum::response::Response;
 use axum::Json;
-use opencode_core::Session;
+use opencode_core::{Session, SessionPhase, SessionStatus, Task, TaskStatus, UpdateTaskRequest};
+use serde::Serialize;
+use std::collections::HashMap;
+use tracing::{error, info, instrument};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::error::AppError;
+use crate::routes::pagination::{list_response, ListParams};
 use crate::state::AppState;
 
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct RetrySessionResponse {
+    pub task: Task,
+    /// The new session, linked to the failed one through `retry_of`
+    pub session: Session,
+    /// Retry number of the new session, starting at 1
+    pub attempt: u32,
+    pub max_retries: u32,
+}
+
+/// Whether a task in `status` is still in the phase a session ran
+fn is_in_phase(status: TaskStatus, phase: SessionPhase) -> bool {
+    match phase {
+        SessionPhase::Planning => matches!(status, TaskStatus::Todo | TaskStatus::Planning),
+        SessionPhase::Implementation => {
+            matches!(status, TaskStatus::PlanningReview | TaskStatus::InProgress)
+        }
+        SessionPhase::Review => matches!(status, TaskStatus::AiReview | TaskStatus::Review),
+        SessionPhase::Fix => status == TaskStatus::Fix,
+    }
+}
+
 #[utoipa::path(
     get,
     path = "/api/sessions",
+    params(
+        ("limit" = Option<u32>, Query, description = "Maximum number of items (max: 500; default: all)"),
+        ("offset" = Option<u32>, Query, description = "Number of items to skip"),
+        ("sort" = Option<String>, Query, description = "Field to sort by, prefixed with '-' for descending order (phase, status, started_at, completed_at or created_at; default: -created_at)"),
+        ("fields" = Option<String>, Query, description = "Comma-separated fields to return (default: all)")
+    ),
     responses(
-        (status = 200, description = "List of all sessions", body = Vec<Session>)
+        (status = 200, description = "List of sessions", body = Vec<Session>, headers(
+            ("X-Total-Count" = u64, description = "Number of items across all pages"),
+            ("Link" = String, description = "Links to the first, previous, next and last pages")
+        ))
     ),
     tag = "sessions"
 )]
-pub async fn list_sessions(State(state): State<AppState>) -> Result<Json<Vec<Session>>, AppError> {
+pub async fn list_sessions(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+    uri: Uri,
+) -> Result<Response, AppError> {
+    let params = ListParams::from_query(&params)?;
     let project = state.project().await?;
-    let sessions = project.session_repository.find_all().await?;
-    Ok(Json(sessions))
+    let page = project.session_repository.find_page(&params.page).await?;
+    list_response(&uri, page, &params)
 }
 
 #[utoipa::path(
@@ -91,3 +135,116 @@ pub async fn delete_session(
         Err(AppError::NotFound(format!("Session not found: {}", id)))
     }
 }
+
+#[utoipa::path(
+    post,
+    path = "/api/sessions/{id}/retry",
+    params(
+        ("id" = Uuid, Path, description = "ID of the failed session")
+    ),
+    responses(
+        (status = 202, description = "Phase restarted in a new session", body = RetrySessionResponse),
+        (status = 404, description = "Session or task not found"),
+        (status = 409, description = "Session did not fail, was already retried, the task moved on or the retry budget is used up"),
+        (status = 500, description = "Execution failed to start")
+    ),
+    tag = "sessions"
+)]
+#[instrument(skip(state), fields(session_id = %id))]
+pub async fn retry_session(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<(StatusCode, Json<RetrySessionResponse>), AppError> {
+    let project = state.project().await?;
+    let repo = &project.session_repository;
+
+    let failed = repo
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Session not found: {}", id)))?;
+    if !matches!(
+        failed.status,
+        SessionStatus::Failed | SessionStatus::Aborted
+    ) {
+        return Err(AppError::Conflict(format!(
+            "Only failed or aborted sessions can be retried; session is {}",
+            failed.status.as_str()
+        )));
+    }
+    if !repo.find_retries(id).await?.is_empty() {
+        return Err(AppError::Conflict(format!(
+            "Session {} was already retried",
+            id
+        )));
+    }
+
+    let mut task = project
+        .task_repository
+        .find_by_id(failed.task_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Task not found: {}", failed.task_id)))?;
+    if !is_in_phase(task.status, failed.phase) {
+        return Err(AppError::Conflict(format!(
+            "Task is {} and no longer in the {} phase",
+            task.status.as_str(),
+            failed.phase.as_str()
+        )));
+    }
+
+    let max_retries = project.get_config().await.execution.max_session_retries;
+    let attempt = repo.count_retries(id).await? + 1;
+    if attempt > max_retries {
+        return Err(AppError::Conflict(format!(
+            "Retry budget used up: the {} phase was already retried {} times",
+            failed.phase.as_str(),
+            max_retries
+        )));
+    }
+
+    info!(
+        task_id = %task.id,
+        phase = %failed.phase.as_str(),
+        attempt,
+        max_retries,
+        "API: Retrying failed session"
+    );
+
+    // Starting the phase again reuses the task's workspace and phase context
+    // and opens a fresh OpenCode session
+    let started = project
+        .task_executor
+        .start_phase_async(&mut task)
+        .await
+        .map_err(|e| {
+            error!(task_id = %task.id, error = %e, "API: Retry failed to start");
+            AppError::Internal(e.to_string())
+        })?;
+
+    let update = UpdateTaskRequest {
+        status: Some(task.status),
+        ..Default::default()
+    };
+    project.task_repository.update(task.id, &update).await?;
+
+    repo.set_retry_of(started.session_id, id).await?;
+    let session = repo.find_by_id(started.session_id).await?.ok_or_else(|| {
+        AppError::Internal(format!("Retried session not found: {}", started.session_id))
+    })?;
+
+    info!(
+        task_id = %task.id,
+        session_id = %session.id,
+        retry_of = %id,
+        "API: Retry started"
+    );
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(RetrySessionResponse {
+            task,
+            session,
+            attempt,
+            max_retries,
+        }),
+    ))
+}
@@ -1,6 +1,7 @@
 use axum::extract::State;
 use axum::http::StatusCode;
 use axum::Json;
+use orchestrator::ReviewPolicy;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, error, info};
 use utoipa::ToSchema;
@@ -86,6 +87,69 @@ pub async fn update_phase_models(
     }))
 }
 
+// Review Policy Settings
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct ReviewPolicyResponse {
+    pub review_policy: ReviewPolicy,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/settings/review-policy",
+    responses(
+        (status = 200, description = "Current review gating policy", body = ReviewPolicyResponse),
+        (status = 500, description = "Failed to read settings")
+    ),
+    tag = "settings"
+)]
+pub async fn get_review_policy(
+    State(state): State<AppState>,
+) -> Result<Json<ReviewPolicyResponse>, AppError> {
+    debug!("Reading review policy");
+
+    let project = state.project().await?;
+    let config = ProjectConfig::read(&project.project_path).await;
+
+    Ok(Json(ReviewPolicyResponse {
+        review_policy: config.execution.review_policy,
+    }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/settings/review-policy",
+    request_body = ReviewPolicy,
+    responses(
+        (status = 200, description = "Review policy updated", body = ReviewPolicyResponse),
+        (status = 500, description = "Failed to save settings")
+    ),
+    tag = "settings"
+)]
+pub async fn update_review_policy(
+    State(state): State<AppState>,
+    Json(payload): Json<ReviewPolicy>,
+) -> Result<Json<ReviewPolicyResponse>, AppError> {
+    info!(block_on = ?payload.block_on, max_warnings = ?payload.max_warnings, "Updating review policy");
+
+    let project = state.project().await?;
+    let mut config = ProjectConfig::read(&project.project_path).await;
+    config.execution.review_policy = payload;
+
+    config.write(&project.project_path).await.map_err(|e| {
+        error!(error = %e, "Failed to save config");
+        AppError::Internal(format!("Failed to save settings: {}", e))
+    })?;
+
+    debug!("Review policy saved successfully");
+
+    Ok(Json(ReviewPolicyResponse {
+        review_policy: config.execution.review_policy,
+    }))
+}
+
 // GitHub Token Settings
 
 #[derive(Debug, Serialize, ToSchema)]
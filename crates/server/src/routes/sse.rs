@@ -5,21 +5,43 @@ use std::time::Duration;
 
 use axum::extract::{Path, Query, State};
 use axum::response::sse::{Event, KeepAlive, Sse};
-use axum::response::IntoResponse;
-use futures::stream::{Stream, StreamExt};
+use axum::response::{AppendHeaders, IntoResponse};
+use futures::stream::StreamExt;
 use serde::Deserialize;
 use tokio_stream::wrappers::BroadcastStream;
 use uuid::Uuid;
 
 use crate::error::AppError;
+use crate::routes::event_history::stored_envelope;
 use crate::state::AppState;
 
 pub const DEFAULT_EVENT_BUFFER_SIZE: usize = 1000;
 pub const SSE_KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
 
+/// Response header with the event schema version the stream is sent in
+pub const EVENT_SCHEMA_VERSION_HEADER: &str = "x-event-schema-version";
+
+/// Response header with the sequence number of the newest stored event,
+/// to pass as `since_seq` when catching up later
+pub const EVENT_SEQ_HEADER: &str = "x-event-seq";
+
+/// Response header with the `since_seq` to continue a replay from, sent
+/// when more stored events follow those replayed
+pub const EVENT_REPLAY_NEXT_SEQ_HEADER: &str = "x-event-replay-next-seq";
+
+/// Most stored events replayed for `since_seq` on one connection
+pub const MAX_REPLAY_EVENTS: u32 = 500;
+
 #[derive(Debug, Deserialize)]
 pub struct EventsQuery {
     pub task_ids: Option<String>,
+    /// Comma-separated event types, e.g. `task.created`, or classes like
+    /// `session.*`
+    pub types: Option<String>,
+    /// Replay stored events after this sequence number before live ones
+    pub since_seq: Option<i64>,
+    /// Highest event schema version the client understands
+    pub version: Option<u32>,
 }
 
 pub struct EventBuffer {
@@ -63,6 +85,11 @@ impl EventBuffer {
     pub fn len(&self) -> usize {
         self.events.len()
     }
+
+    #[cfg(test)]
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
 }
 
 pub type SharedEventBuffer = Arc<RwLock<EventBuffer>>;
@@ -75,31 +102,55 @@ fn parse_task_ids(task_ids: Option<&str>) -> Option<Vec<Uuid>> {
     })
 }
 
+fn parse_event_types(types: Option<&str>) -> Option<Vec<String>> {
+    types.map(|s| {
+        s.split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(str::to_string)
+            .collect()
+    })
+}
+
+/// Events a client of the stream asked for
+#[derive(Debug, Clone, Default)]
+struct StreamFilter {
+    /// Tasks whose events are sent; events of no task are always sent
+    task_ids: Option<Vec<Uuid>>,
+    /// Event types or `class.*` patterns that are sent
+    types: Option<Vec<String>>,
+}
+
+impl StreamFilter {
+    fn from_query(query: &EventsQuery) -> Self {
+        Self {
+            task_ids: parse_task_ids(query.task_ids.as_deref()),
+            types: parse_event_types(query.types.as_deref()),
+        }
+    }
+
+    fn matches(&self, event: &events::Event) -> bool {
+        if let (Some(ids), Some(task_id)) = (&self.task_ids, event.task_id()) {
+            if !ids.contains(&task_id) {
+                return false;
+            }
+        }
+
+        match &self.types {
+            Some(types) => {
+                let event_type = event.event_type();
+                types.iter().any(|pattern| match pattern.strip_suffix('*') {
+                    Some(prefix) => event_type.starts_with(prefix),
+                    None => pattern == event_type,
+                })
+            }
+            None => true,
+        }
+    }
+}
+
 fn envelope_to_sse_event(envelope: &events::EventEnvelope) -> Result<Event, Infallible> {
-    let event_type = match &envelope.event {
-        events::Event::TaskCreated { .. } => "task.created",
-        events::Event::TaskUpdated { .. } => "task.updated",
-        events::Event::TaskStatusChanged { .. } => "task.status_changed",
-        events::Event::SessionStarted { .. } => "session.started",
-        events::Event::SessionEnded { .. } => "session.ended",
-        events::Event::PhaseCompleted { .. } => "phase.completed",
-        events::Event::PhaseContinuing { .. } => "phase.continuing",
-        events::Event::AgentMessage { .. } => "agent.message",
-        events::Event::ToolExecution { .. } => "tool.execution",
-        events::Event::WorkspaceCreated { .. } => "workspace.created",
-        events::Event::WorkspaceMerged { .. } => "workspace.merged",
-        events::Event::WorkspaceDeleted { .. } => "workspace.deleted",
-        events::Event::ProjectOpened { .. } => "project.opened",
-        events::Event::ProjectClosed { .. } => "project.closed",
-        events::Event::WikiGenerationProgress { .. } => "wiki.generation_progress",
-        events::Event::RoadmapGenerationStarted => "roadmap.generation_started",
-        events::Event::RoadmapGenerationProgress { .. } => "roadmap.generation_progress",
-        events::Event::RoadmapGenerationCompleted { .. } => "roadmap.generation_completed",
-        events::Event::RoadmapGenerationFailed { .. } => "roadmap.generation_failed",
-        events::Event::RoadmapFeatureUpdated { .. } => "roadmap.feature_updated",
-        events::Event::RoadmapFeatureConverted { .. } => "roadmap.feature_converted",
-        events::Event::Error { .. } => "error",
-    };
+    let event_type = envelope.event.event_type();
 
     let data = serde_json::to_string(&envelope).unwrap_or_else(|_| "{}".to_string());
 
@@ -131,14 +182,92 @@ fn activity_to_sse_event(
         .data(data))
 }
 
+/// Events published after `event_id`
+///
+/// Reads the project's event history first, so clients can catch up on more
+/// than the in-memory buffer holds, then adds buffered events that were not
+/// stored (yet). Without a stored match only the buffer is used.
+async fn missed_events(state: &AppState, event_id: Uuid) -> Vec<events::EventEnvelope> {
+    let stored = match state.project().await {
+        Ok(project) => project
+            .event_repository
+            .find_after(event_id)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!(error = %e, "Failed to read event history for replay");
+                None
+            }),
+        Err(_) => None,
+    };
+
+    let buffer = state
+        .event_buffer
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let Some(stored) = stored else {
+        return buffer.events_after(event_id);
+    };
+
+    let mut events: Vec<events::EventEnvelope> =
+        stored.iter().filter_map(stored_envelope).collect();
+    let last_id = events.last().map_or(event_id, |e| e.id);
+    events.extend(buffer.events_after(last_id));
+    events
+}
+
+/// Up to [`MAX_REPLAY_EVENTS`] stored events after sequence number `seq`, in
+/// publish order, and the sequence number to continue from if there are more
+async fn events_since_seq(state: &AppState, seq: i64) -> (Vec<events::EventEnvelope>, Option<i64>) {
+    let stored = match state.project().await {
+        Ok(project) => {
+            project
+                .event_repository
+                .find_since_seq(seq, MAX_REPLAY_EVENTS)
+                .await
+        }
+        Err(_) => return (vec![], None),
+    };
+
+    match stored {
+        Ok((stored, next_seq)) => (
+            stored.iter().filter_map(stored_envelope).collect(),
+            next_seq,
+        ),
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to read event history for replay");
+            (vec![], None)
+        }
+    }
+}
+
+/// Sequence number of the newest stored event, 0 without a history
+async fn latest_seq(state: &AppState) -> i64 {
+    match state.project().await {
+        Ok(project) => project
+            .event_repository
+            .latest_seq()
+            .await
+            .unwrap_or_default(),
+        Err(_) => 0,
+    }
+}
+
 #[utoipa::path(
     get,
     path = "/api/events",
     params(
         ("task_ids" = Option<String>, Query, description = "Comma-separated task IDs to filter events"),
+        ("types" = Option<String>, Query, description = "Comma-separated event types to send, e.g. task.created, or classes like session.* (default: all)"),
+        ("since_seq" = Option<i64>, Query, description = "Replay stored events after this sequence number first, e.g. the X-Event-Seq of an earlier response; a Last-Event-ID header takes precedence. At most 500 events are replayed; X-Event-Replay-Next-Seq then gives the since_seq to continue from"),
+        ("version" = Option<u32>, Query, description = "Highest event schema version the client understands (default: current)"),
     ),
     responses(
-        (status = 200, description = "SSE event stream"),
+        (status = 200, description = "SSE event stream", headers(
+            ("X-Event-Schema-Version" = u32, description = "Event schema version the stream is sent in"),
+            ("X-Event-Seq" = i64, description = "Sequence number of the newest stored event when the stream opened"),
+            ("X-Event-Replay-Next-Seq" = i64, description = "Sent when the replay was cut short: the since_seq to replay the remaining stored events from")
+        )),
+        (status = 400, description = "Requested schema version is no longer supported"),
     ),
     tag = "events"
 )]
@@ -146,32 +275,42 @@ pub async fn events_stream(
     State(state): State<AppState>,
     Query(query): Query<EventsQuery>,
     headers: axum::http::HeaderMap,
-) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
-    let task_ids = parse_task_ids(query.task_ids.as_deref());
+) -> Result<impl IntoResponse, AppError> {
+    let version = events::negotiate_schema_version(query.version).ok_or_else(|| {
+        AppError::BadRequest(format!(
+            "Event schema version {} is no longer supported (minimum: {})",
+            query.version.unwrap_or_default(),
+            events::MIN_EVENT_SCHEMA_VERSION
+        ))
+    })?;
+    let filter = StreamFilter::from_query(&query);
     let last_event_id = headers
         .get("Last-Event-ID")
         .and_then(|v| v.to_str().ok())
         .and_then(|s| s.parse::<Uuid>().ok());
 
-    let buffer = Arc::clone(&state.event_buffer);
-    let buffer_for_live = Arc::clone(&buffer);
+    let buffer_for_live = Arc::clone(&state.event_buffer);
 
     let rx = state.event_bus.subscribe();
 
-    let missed_events = if let Some(event_id) = last_event_id {
-        buffer
-            .read()
-            .unwrap_or_else(|poisoned| poisoned.into_inner())
-            .events_after(event_id)
-    } else {
-        vec![]
+    let seq = latest_seq(&state).await;
+    let (missed_events, replay_next_seq) = match (last_event_id, query.since_seq) {
+        (Some(event_id), _) => (missed_events(&state, event_id).await, None),
+        (None, Some(since_seq)) => events_since_seq(&state, since_seq).await,
+        (None, None) => (vec![], None),
     };
 
-    let missed_stream =
-        futures::stream::iter(missed_events.into_iter().map(|e| envelope_to_sse_event(&e)));
+    let missed_filter = filter.clone();
+    let missed_stream = futures::stream::iter(
+        missed_events
+            .into_iter()
+            .filter(move |e| missed_filter.matches(&e.event))
+            .filter_map(move |e| e.for_version(version))
+            .map(|e| envelope_to_sse_event(&e)),
+    );
 
     let live_stream = BroadcastStream::new(rx).filter_map(move |result| {
-        let task_ids = task_ids.clone();
+        let filter = filter.clone();
         let buffer = Arc::clone(&buffer_for_live);
 
         async move {
@@ -182,15 +321,13 @@ pub async fn events_stream(
                         .unwrap_or_else(|poisoned| poisoned.into_inner())
                         .push(envelope.clone());
 
-                    if let Some(ref ids) = task_ids {
-                        if let Some(event_task_id) = envelope.event.task_id() {
-                            if !ids.contains(&event_task_id) {
-                                return None;
-                            }
-                        }
+                    if !filter.matches(&envelope.event) {
+                        return None;
                     }
 
-                    Some(envelope_to_sse_event(&envelope))
+                    envelope
+                        .for_version(version)
+                        .map(|envelope| envelope_to_sse_event(&envelope))
                 }
                 Err(e) => {
                     tracing::warn!("SSE broadcast error: {:?}", e);
@@ -202,11 +339,19 @@ pub async fn events_stream(
 
     let stream = missed_stream.chain(live_stream);
 
-    Sse::new(stream).keep_alive(
+    let sse = Sse::new(stream).keep_alive(
         KeepAlive::new()
             .interval(SSE_KEEP_ALIVE_INTERVAL)
             .text("keep-alive"),
-    )
+    );
+    let mut response_headers = vec![
+        (EVENT_SCHEMA_VERSION_HEADER, version.to_string()),
+        (EVENT_SEQ_HEADER, seq.to_string()),
+    ];
+    if let Some(next_seq) = replay_next_seq {
+        response_headers.push((EVENT_REPLAY_NEXT_SEQ_HEADER, next_seq.to_string()));
+    }
+    Ok((AppendHeaders(response_headers), sse))
 }
 
 #[utoipa::path(
@@ -344,6 +489,44 @@ mod tests {
         assert_eq!(result.len(), 2);
     }
 
+    #[test]
+    fn test_event_filter() {
+        let task_id = Uuid::new_v4();
+        let session_ended = events::Event::SessionEnded {
+            session_id: Uuid::new_v4(),
+            task_id,
+            success: true,
+        };
+        let other_task = events::Event::TaskCreated {
+            task_id: Uuid::new_v4(),
+            title: "Other".to_string(),
+        };
+        let project_closed = events::Event::ProjectClosed {
+            path: "/tmp/project".to_string(),
+        };
+
+        let filter = StreamFilter::from_query(&EventsQuery {
+            task_ids: Some(task_id.to_string()),
+            types: Some("session.*, project.closed".to_string()),
+            since_seq: None,
+            version: None,
+        });
+        assert!(filter.matches(&session_ended));
+        assert!(!filter.matches(&other_task));
+        // Events of no task pass the task filter
+        assert!(filter.matches(&project_closed));
+
+        let filter = StreamFilter::from_query(&EventsQuery {
+            task_ids: None,
+            types: Some("task.created".to_string()),
+            since_seq: None,
+            version: None,
+        });
+        assert!(filter.matches(&other_task));
+        assert!(!filter.matches(&session_ended));
+        assert!(StreamFilter::default().matches(&project_closed));
+    }
+
     #[test]
     fn test_event_buffer_events_after() {
         let mut buffer = EventBuffer::new(3);
@@ -0,0 +1,317 @@
+//! Dependencies between tasks
+//!
+//! A task can depend on other tasks, and does not run until they are done.
+//! A todo task with unfinished dependencies is moved to `blocked`, and back
+//! to `todo` once the last of them is done or the dependency is removed.
+//! Dependencies may not form a cycle.
+
+use std::collections::{HashMap, HashSet};
+
+use axum::extract::{Path, State};
+use axum::Json;
+use events::{Event, EventBus};
+use opencode_core::{Task, TaskStatus, UpdateTaskRequest};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{info, warn};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::project_manager::ProjectContext;
+use crate::state::AppState;
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct TaskDependenciesResponse {
+    /// The task, with its status after blocking or unblocking
+    pub task: Task,
+    /// Tasks the task depends on
+    pub depends_on: Vec<Task>,
+    /// Tasks that depend on the task
+    pub dependents: Vec<Task>,
+    /// Dependencies that are not done yet
+    pub unfinished: Vec<Uuid>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct AddTaskDependencyRequest {
+    /// Task that must be done first
+    pub depends_on: Uuid,
+}
+
+/// Tasks `task` depends on that are not done
+pub(crate) async fn unfinished_dependencies(
+    project: &ProjectContext,
+    task: &Task,
+) -> Result<Vec<Task>, AppError> {
+    let mut unfinished = Vec::new();
+    for id in &task.depends_on {
+        if let Some(dependency) = project.task_repository.find_by_id(*id).await? {
+            if dependency.status != TaskStatus::Done {
+                unfinished.push(dependency);
+            }
+        }
+    }
+    Ok(unfinished)
+}
+
+/// Whether making `task_id` depend on `depends_on` closes a cycle, i.e.
+/// `depends_on` already depends on `task_id`, directly or not
+fn creates_cycle(tasks: &[Task], task_id: Uuid, depends_on: Uuid) -> bool {
+    let edges: HashMap<Uuid, &[Uuid]> = tasks
+        .iter()
+        .map(|t| (t.id, t.depends_on.as_slice()))
+        .collect();
+    let mut seen = HashSet::new();
+    let mut frontier = vec![depends_on];
+    while let Some(id) = frontier.pop() {
+        if id == task_id {
+            return true;
+        }
+        if seen.insert(id) {
+            frontier.extend(edges.get(&id).copied().unwrap_or_default());
+        }
+    }
+    false
+}
+
+/// Move a todo task with unfinished dependencies to `blocked`, and a blocked
+/// one without to `todo`. Workflows without a `blocked` status leave the
+/// task where it is.
+async fn sync_blocked_status(project: &ProjectContext, task: &mut Task) -> Result<(), AppError> {
+    let unfinished = unfinished_dependencies(project, task).await?;
+    let to = match task.status {
+        TaskStatus::Todo if !unfinished.is_empty() => TaskStatus::Blocked,
+        TaskStatus::Blocked if unfinished.is_empty() => TaskStatus::Todo,
+        _ => return Ok(()),
+    };
+    let executor = &project.task_executor;
+    if executor
+        .config()
+        .workflow
+        .transition(&task.status, &to)
+        .is_none()
+    {
+        return Ok(());
+    }
+
+    executor.transition(task, to)?;
+    let update = UpdateTaskRequest {
+        status: Some(task.status),
+        ..Default::default()
+    };
+    project.task_repository.update(task.id, &update).await?;
+    info!(task_id = %task.id, status = %task.status.as_str(), "Task dependencies changed its status");
+    Ok(())
+}
+
+async fn dependencies_response(
+    project: &ProjectContext,
+    task: Task,
+) -> Result<TaskDependenciesResponse, AppError> {
+    let mut depends_on = Vec::new();
+    for id in &task.depends_on {
+        if let Some(dependency) = project.task_repository.find_by_id(*id).await? {
+            depends_on.push(dependency);
+        }
+    }
+    let dependents = project.task_repository.find_dependents(task.id).await?;
+    let unfinished = depends_on
+        .iter()
+        .filter(|t| t.status != TaskStatus::Done)
+        .map(|t| t.id)
+        .collect();
+
+    Ok(TaskDependenciesResponse {
+        task,
+        depends_on,
+        dependents,
+        unfinished,
+    })
+}
+
+async fn find_task(project: &ProjectContext, id: Uuid) -> Result<Task, AppError> {
+    project
+        .task_repository
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Task not found: {}", id)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{id}/dependencies",
+    params(
+        ("id" = Uuid, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "Dependencies of the task", body = TaskDependenciesResponse),
+        (status = 404, description = "Task not found")
+    ),
+    tag = "tasks"
+)]
+pub async fn get_task_dependencies(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<TaskDependenciesResponse>, AppError> {
+    let project = state.project().await?;
+    let task = find_task(&project, id).await?;
+    Ok(Json(dependencies_response(&project, task).await?))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/tasks/{id}/dependencies",
+    params(
+        ("id" = Uuid, Path, description = "Task ID")
+    ),
+    request_body = AddTaskDependencyRequest,
+    responses(
+        (status = 200, description = "Dependency added", body = TaskDependenciesResponse),
+        (status = 400, description = "The task would depend on itself"),
+        (status = 404, description = "Task not found"),
+        (status = 409, description = "The dependency would create a cycle")
+    ),
+    tag = "tasks"
+)]
+pub async fn add_task_dependency(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<AddTaskDependencyRequest>,
+) -> Result<Json<TaskDependenciesResponse>, AppError> {
+    let project = state.project().await?;
+    find_task(&project, id).await?;
+    find_task(&project, payload.depends_on).await?;
+    if payload.depends_on == id {
+        return Err(AppError::BadRequest(
+            "A task cannot depend on itself".to_string(),
+        ));
+    }
+    let tasks = project.task_repository.find_all().await?;
+    if creates_cycle(&tasks, id, payload.depends_on) {
+        return Err(AppError::Conflict(format!(
+            "Task {} already depends on task {}",
+            payload.depends_on, id
+        )));
+    }
+
+    if project
+        .task_repository
+        .add_dependency(id, payload.depends_on)
+        .await?
+    {
+        info!(task_id = %id, depends_on = %payload.depends_on, "Task dependency added");
+    }
+    let mut task = find_task(&project, id).await?;
+    sync_blocked_status(&project, &mut task).await?;
+    Ok(Json(dependencies_response(&project, task).await?))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/tasks/{id}/dependencies/{depends_on}",
+    params(
+        ("id" = Uuid, Path, description = "Task ID"),
+        ("depends_on" = Uuid, Path, description = "ID of the task depended on")
+    ),
+    responses(
+        (status = 200, description = "Dependency removed", body = TaskDependenciesResponse),
+        (status = 404, description = "Task or dependency not found")
+    ),
+    tag = "tasks"
+)]
+pub async fn remove_task_dependency(
+    State(state): State<AppState>,
+    Path((id, depends_on)): Path<(Uuid, Uuid)>,
+) -> Result<Json<TaskDependenciesResponse>, AppError> {
+    let project = state.project().await?;
+    find_task(&project, id).await?;
+    if !project
+        .task_repository
+        .remove_dependency(id, depends_on)
+        .await?
+    {
+        return Err(AppError::NotFound(format!(
+            "Task {} does not depend on task {}",
+            id, depends_on
+        )));
+    }
+    info!(task_id = %id, depends_on = %depends_on, "Task dependency removed");
+
+    let mut task = find_task(&project, id).await?;
+    sync_blocked_status(&project, &mut task).await?;
+    Ok(Json(dependencies_response(&project, task).await?))
+}
+
+/// Unblock the dependents of tasks that are done, until the project is
+/// closed
+pub fn spawn_dependency_unblocking(project: ProjectContext, event_bus: &EventBus) {
+    let mut rx = event_bus.subscribe();
+    let project_path = project.path.display().to_string();
+
+    tokio::spawn(async move {
+        loop {
+            match rx.recv().await {
+                Ok(envelope) => match envelope.event {
+                    Event::TaskStatusChanged {
+                        task_id, to_status, ..
+                    } if to_status == TaskStatus::Done.as_str() => {
+                        unblock_dependents(&project, task_id).await;
+                    }
+                    Event::ProjectClosed { path } if path == project_path => break,
+                    _ => {}
+                },
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
+async fn unblock_dependents(project: &ProjectContext, task_id: Uuid) {
+    let dependents = match project.task_repository.find_dependents(task_id).await {
+        Ok(dependents) => dependents,
+        Err(e) => {
+            warn!(task_id = %task_id, error = %e, "Failed to load dependent tasks");
+            return;
+        }
+    };
+    for mut dependent in dependents {
+        if dependent.status != TaskStatus::Blocked {
+            continue;
+        }
+        if let Err(e) = sync_blocked_status(project, &mut dependent).await {
+            warn!(task_id = %dependent.id, error = ?e, "Failed to unblock task");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task_depending_on(depends_on: &[Uuid]) -> Task {
+        let mut task = Task::new("Task", "");
+        task.depends_on = depends_on.to_vec();
+        task
+    }
+
+    #[test]
+    fn test_creates_cycle() {
+        let a = task_depending_on(&[]);
+        let b = task_depending_on(&[a.id]);
+        let c = task_depending_on(&[b.id]);
+        let tasks = vec![a.clone(), b.clone(), c.clone()];
+
+        // a -> c would close c -> b -> a
+        assert!(creates_cycle(&tasks, a.id, c.id));
+        assert!(creates_cycle(&tasks, a.id, b.id));
+        assert!(!creates_cycle(&tasks, c.id, a.id));
+        let d = task_depending_on(&[]);
+        assert!(!creates_cycle(&tasks, d.id, c.id));
+    }
+}
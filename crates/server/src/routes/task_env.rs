@@ -0,0 +1,149 @@
+//! Environment variables for a task's sessions
+//!
+//! Tasks add to or override the project's `environment` from `config.json`.
+//! Responses show the effective set the next session gets, with secret values
+//! masked.
+
+use axum::extract::{Path, State};
+use axum::Json;
+use orchestrator::{EnvVar, EnvVarSource, TaskEnvironment};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::project_manager::ProjectContext;
+use crate::state::AppState;
+
+/// A variable a session gets, secrets masked
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct EffectiveEnvVar {
+    pub name: String,
+    pub value: String,
+    pub is_secret: bool,
+    pub source: EnvVarSource,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct TaskEnvResponse {
+    /// The task's own variables, as defined
+    pub vars: Vec<EnvVar>,
+    /// Project and task variables combined, as sessions get them
+    pub effective: Vec<EffectiveEnvVar>,
+    /// Variables left out because their secret is not set on the server
+    pub missing_secrets: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct UpdateTaskEnvRequest {
+    /// Replaces the task's variables
+    pub vars: Vec<EnvVar>,
+}
+
+fn task_env_response(project: &ProjectContext, vars: Vec<EnvVar>) -> TaskEnvResponse {
+    let environment =
+        TaskEnvironment::resolve(&project.task_executor.config().environment, &vars, |name| {
+            std::env::var(name).ok()
+        });
+    TaskEnvResponse {
+        vars,
+        effective: environment
+            .vars
+            .iter()
+            .map(|var| EffectiveEnvVar {
+                name: var.name.clone(),
+                value: var.display_value().to_string(),
+                is_secret: var.is_secret,
+                source: var.source,
+            })
+            .collect(),
+        missing_secrets: environment
+            .missing_secrets
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{id}/env",
+    params(
+        ("id" = Uuid, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "Task variables and the effective environment, secrets masked", body = TaskEnvResponse),
+        (status = 404, description = "Task not found")
+    ),
+    tag = "tasks"
+)]
+pub async fn get_task_env(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<TaskEnvResponse>, AppError> {
+    let project = state.project().await?;
+    if project.task_repository.find_by_id(id).await?.is_none() {
+        return Err(AppError::NotFound(format!("Task not found: {}", id)));
+    }
+
+    let vars = project
+        .task_executor
+        .file_manager()
+        .read_task_env(id)
+        .await
+        .map_err(|e| AppError::Internal(format!("Failed to read task environment: {}", e)))?;
+
+    Ok(Json(task_env_response(&project, vars)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/tasks/{id}/env",
+    params(
+        ("id" = Uuid, Path, description = "Task ID")
+    ),
+    request_body = UpdateTaskEnvRequest,
+    responses(
+        (status = 200, description = "Variables saved; applies from the next session", body = TaskEnvResponse),
+        (status = 400, description = "Invalid variable"),
+        (status = 404, description = "Task not found")
+    ),
+    tag = "tasks"
+)]
+pub async fn update_task_env(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateTaskEnvRequest>,
+) -> Result<Json<TaskEnvResponse>, AppError> {
+    let project = state.project().await?;
+    if project.task_repository.find_by_id(id).await?.is_none() {
+        return Err(AppError::NotFound(format!("Task not found: {}", id)));
+    }
+
+    for (i, var) in payload.vars.iter().enumerate() {
+        var.validate().map_err(AppError::BadRequest)?;
+        if payload.vars[..i].iter().any(|v| v.name == var.name) {
+            return Err(AppError::BadRequest(format!(
+                "{} is defined more than once",
+                var.name
+            )));
+        }
+    }
+
+    info!(task_id = %id, vars = payload.vars.len(), "Updating task environment");
+    project
+        .task_executor
+        .file_manager()
+        .write_task_env(id, &payload.vars)
+        .await
+        .map_err(|e| AppError::Internal(format!("Failed to save task environment: {}", e)))?;
+
+    Ok(Json(task_env_response(&project, payload.vars)))
+}
@@ -0,0 +1,438 @@
+//! Task templates
+//!
+//! Scaffolds for tasks a team creates over and over, like "add an endpoint
+//! with tests". The title, description and acceptance criteria may hold
+//! `{{name}}` placeholders that are filled in when a task is created from
+//! the template, and the template's phase models become the task's own.
+
+use std::collections::HashMap;
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use db::{TaskTemplate, TaskTemplateRepository};
+use events::{Event, EventEnvelope};
+use opencode_core::Task;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+use utoipa::ToSchema;
+
+use crate::config::PhaseModels;
+use crate::error::AppError;
+use crate::project_manager::executor_phase_models;
+use crate::state::AppState;
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct TaskTemplateResponse {
+    pub id: String,
+    pub name: String,
+    pub title: String,
+    pub description: String,
+    pub acceptance_criteria: String,
+    /// Models the phases of created tasks use instead of the project's
+    pub phase_models: PhaseModels,
+    /// Placeholders a task created from the template needs values for
+    pub placeholders: Vec<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl From<TaskTemplate> for TaskTemplateResponse {
+    fn from(template: TaskTemplate) -> Self {
+        Self {
+            placeholders: template_placeholders(&template),
+            phase_models: serde_json::from_str(&template.phase_models).unwrap_or_default(),
+            id: template.id,
+            name: template.name,
+            title: template.title,
+            description: template.description,
+            acceptance_criteria: template.acceptance_criteria,
+            created_at: template.created_at,
+            updated_at: template.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct TaskTemplateRequest {
+    pub name: String,
+    /// Title of created tasks; may hold `{{name}}` placeholders
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    /// Appended to the description of created tasks
+    #[serde(default)]
+    pub acceptance_criteria: String,
+    #[serde(default)]
+    pub phase_models: PhaseModels,
+}
+
+#[derive(Debug, Default, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct CreateTaskFromTemplateRequest {
+    /// Value of each placeholder, by name
+    #[serde(default)]
+    pub values: HashMap<String, String>,
+}
+
+fn validate_request(payload: &TaskTemplateRequest) -> Result<String, AppError> {
+    if payload.name.trim().is_empty() {
+        return Err(AppError::BadRequest(
+            "Template name cannot be empty".to_string(),
+        ));
+    }
+    if payload.title.trim().is_empty() {
+        return Err(AppError::BadRequest(
+            "Template title cannot be empty".to_string(),
+        ));
+    }
+    serde_json::to_string(&payload.phase_models).map_err(|e| AppError::Internal(e.to_string()))
+}
+
+async fn find_template(state: &AppState, id: &str) -> Result<TaskTemplate, AppError> {
+    let project = state.project().await?;
+    TaskTemplateRepository::new(project.pool.clone())
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Task template not found: {}", id)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/task-templates",
+    responses(
+        (status = 200, description = "All task templates, by name", body = Vec<TaskTemplateResponse>)
+    ),
+    tag = "task-templates"
+)]
+pub async fn list_task_templates(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<TaskTemplateResponse>>, AppError> {
+    let project = state.project().await?;
+    let templates = TaskTemplateRepository::new(project.pool.clone())
+        .find_all()
+        .await?;
+    Ok(Json(
+        templates
+            .into_iter()
+            .map(TaskTemplateResponse::from)
+            .collect(),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/task-templates",
+    request_body = TaskTemplateRequest,
+    responses(
+        (status = 201, description = "Task template created", body = TaskTemplateResponse),
+        (status = 400, description = "Empty name or title")
+    ),
+    tag = "task-templates"
+)]
+pub async fn create_task_template(
+    State(state): State<AppState>,
+    Json(payload): Json<TaskTemplateRequest>,
+) -> Result<(StatusCode, Json<TaskTemplateResponse>), AppError> {
+    let phase_models = validate_request(&payload)?;
+    let project = state.project().await?;
+    let template = TaskTemplateRepository::new(project.pool.clone())
+        .create(&TaskTemplate::new(
+            payload.name,
+            payload.title,
+            payload.description,
+            payload.acceptance_criteria,
+            phase_models,
+        ))
+        .await?;
+
+    info!(template_id = %template.id, name = %template.name, "API: Task template created");
+    Ok((StatusCode::CREATED, Json(template.into())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/task-templates/{id}",
+    params(
+        ("id" = String, Path, description = "Task template ID")
+    ),
+    responses(
+        (status = 200, description = "Task template", body = TaskTemplateResponse),
+        (status = 404, description = "Task template not found")
+    ),
+    tag = "task-templates"
+)]
+pub async fn get_task_template(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<TaskTemplateResponse>, AppError> {
+    Ok(Json(find_template(&state, &id).await?.into()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/task-templates/{id}",
+    params(
+        ("id" = String, Path, description = "Task template ID")
+    ),
+    request_body = TaskTemplateRequest,
+    responses(
+        (status = 200, description = "Task template updated", body = TaskTemplateResponse),
+        (status = 400, description = "Empty name or title"),
+        (status = 404, description = "Task template not found")
+    ),
+    tag = "task-templates"
+)]
+pub async fn update_task_template(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(payload): Json<TaskTemplateRequest>,
+) -> Result<Json<TaskTemplateResponse>, AppError> {
+    let phase_models = validate_request(&payload)?;
+    let template = TaskTemplate {
+        name: payload.name,
+        title: payload.title,
+        description: payload.description,
+        acceptance_criteria: payload.acceptance_criteria,
+        phase_models,
+        ..find_template(&state, &id).await?
+    };
+
+    let project = state.project().await?;
+    let updated = TaskTemplateRepository::new(project.pool.clone())
+        .update(&template)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Task template not found: {}", id)))?;
+    Ok(Json(updated.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/task-templates/{id}",
+    params(
+        ("id" = String, Path, description = "Task template ID")
+    ),
+    responses(
+        (status = 204, description = "Task template deleted"),
+        (status = 404, description = "Task template not found")
+    ),
+    tag = "task-templates"
+)]
+pub async fn delete_task_template(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, AppError> {
+    let project = state.project().await?;
+    if !TaskTemplateRepository::new(project.pool.clone())
+        .delete(&id)
+        .await?
+    {
+        return Err(AppError::NotFound(format!(
+            "Task template not found: {}",
+            id
+        )));
+    }
+    info!(template_id = %id, "API: Task template deleted");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/task-templates/{id}/tasks",
+    params(
+        ("id" = String, Path, description = "Task template ID")
+    ),
+    request_body = CreateTaskFromTemplateRequest,
+    responses(
+        (status = 201, description = "Task created from the template", body = Task),
+        (status = 400, description = "A placeholder has no value"),
+        (status = 404, description = "Task template not found")
+    ),
+    tag = "task-templates"
+)]
+pub async fn create_task_from_template(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(payload): Json<CreateTaskFromTemplateRequest>,
+) -> Result<(StatusCode, Json<Task>), AppError> {
+    let template = find_template(&state, &id).await?;
+
+    let missing: Vec<String> = template_placeholders(&template)
+        .into_iter()
+        .filter(|name| !payload.values.contains_key(name))
+        .collect();
+    if !missing.is_empty() {
+        return Err(AppError::BadRequest(format!(
+            "Missing values for placeholders: {}",
+            missing.join(", ")
+        )));
+    }
+
+    let title = expand(&template.title, &payload.values);
+    if title.trim().is_empty() {
+        return Err(AppError::BadRequest("Title cannot be empty".to_string()));
+    }
+    let description = task_description(
+        &expand(&template.description, &payload.values),
+        &expand(&template.acceptance_criteria, &payload.values),
+    );
+
+    let project = state.project().await?;
+    let task = project
+        .task_repository
+        .create(&Task::new(title.clone(), description))
+        .await?;
+
+    let phase_models: PhaseModels =
+        serde_json::from_str(&template.phase_models).unwrap_or_default();
+    let phase_models = executor_phase_models(phase_models);
+    if !phase_models.is_empty() {
+        project
+            .task_executor
+            .file_manager()
+            .write_task_models(task.id, &phase_models)
+            .await
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+    }
+
+    info!(
+        task_id = %task.id,
+        template_id = %template.id,
+        "API: Task created from template"
+    );
+    state
+        .event_bus
+        .publish(EventEnvelope::new(Event::TaskCreated {
+            task_id: task.id,
+            title,
+        }));
+
+    Ok((StatusCode::CREATED, Json(task)))
+}
+
+/// Placeholders of the template's text fields, each once, in order of
+/// appearance
+fn template_placeholders(template: &TaskTemplate) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for text in [
+        &template.title,
+        &template.description,
+        &template.acceptance_criteria,
+    ] {
+        for name in placeholders(text) {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+    names
+}
+
+/// Names of the `{{name}}` placeholders in `text`
+fn placeholders(text: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            break;
+        };
+        let name = after[..end].trim();
+        if !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            names.push(name.to_string());
+            rest = &after[end + 2..];
+        } else {
+            rest = after;
+        }
+    }
+    names
+}
+
+/// `text` with each placeholder replaced by its value
+fn expand(text: &str, values: &HashMap<String, String>) -> String {
+    placeholders(text)
+        .iter()
+        .fold(text.to_string(), |text, name| match values.get(name) {
+            Some(value) => text
+                .replace(&format!("{{{{{}}}}}", name), value)
+                .replace(&format!("{{{{ {} }}}}", name), value),
+            None => text,
+        })
+}
+
+fn task_description(description: &str, acceptance_criteria: &str) -> String {
+    let description = description.trim();
+    let acceptance_criteria = acceptance_criteria.trim();
+    if acceptance_criteria.is_empty() {
+        return description.to_string();
+    }
+    format!(
+        "{}\n\n## Acceptance criteria\n\n{}",
+        description, acceptance_criteria
+    )
+    .trim_start()
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_placeholders() {
+        assert_eq!(
+            placeholders("Add {{method}} {{ path }} to {{method}}; {{not a name}} {{"),
+            vec!["method", "path", "method"]
+        );
+        assert!(placeholders("no placeholders").is_empty());
+    }
+
+    #[test]
+    fn test_template_placeholders_are_listed_once() {
+        let template = TaskTemplate::new(
+            "Endpoint",
+            "Add {{method}} {{path}}",
+            "Handle {{path}} in {{module}}",
+            "{{method}} {{path}} returns 200",
+            "{}",
+        );
+        assert_eq!(
+            template_placeholders(&template),
+            vec!["method", "path", "module"]
+        );
+    }
+
+    #[test]
+    fn test_expand() {
+        let values = HashMap::from([
+            ("method".to_string(), "GET".to_string()),
+            ("path".to_string(), "/api/items".to_string()),
+        ]);
+        assert_eq!(
+            expand("Add {{method}} {{ path }} ({{other}})", &values),
+            "Add GET /api/items ({{other}})"
+        );
+    }
+
+    #[test]
+    fn test_task_description_appends_acceptance_criteria() {
+        assert_eq!(task_description("Do it", ""), "Do it");
+        assert_eq!(
+            task_description("Do it", "- Tests pass"),
+            "Do it\n\n## Acceptance criteria\n\n- Tests pass"
+        );
+        assert_eq!(
+            task_description("", "- Tests pass"),
+            "## Acceptance criteria\n\n- Tests pass"
+        );
+    }
+}
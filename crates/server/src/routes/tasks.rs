@@ -1,30 +1,54 @@
-use axum::extract::{Path, State};
-use axum::http::StatusCode;
+use axum::extract::{Path, Query, State};
+use axum::http::{header, StatusCode, Uri};
+use axum::response::{IntoResponse, Response};
 use axum::Json;
-use events::{Event, EventEnvelope};
-use opencode_core::{CreateTaskRequest, Task, TaskStatus, UpdateTaskRequest};
-use orchestrator::ReviewFinding;
+use events::{Event, EventBus, EventEnvelope};
+use opencode_core::{CreateTaskRequest, SessionPhase, Task, TaskStatus, UpdateTaskRequest};
+use orchestrator::{
+    FindingComment, FindingCommentAuthor, FindingStatus, ReportFormat, ReviewFinding, ReviewReport,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::broadcast::error::RecvError;
 use tracing::{debug, error, info, instrument, warn};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::error::AppError;
+use crate::project_manager::ProjectContext;
+use crate::routes::pagination::{list_response, ListParams};
+use crate::routes::task_dependencies::unfinished_dependencies;
 use crate::state::AppState;
-use orchestrator::{parse_plan_phases, PhaseContext, PhaseSummary};
+use orchestrator::{
+    parse_plan_phases, PhaseContext, PhaseSummary, TaskStateMachine, TransitionContext,
+};
 
 #[utoipa::path(
     get,
     path = "/api/tasks",
+    params(
+        ("limit" = Option<u32>, Query, description = "Maximum number of items (max: 500; default: all)"),
+        ("offset" = Option<u32>, Query, description = "Number of items to skip"),
+        ("sort" = Option<String>, Query, description = "Field to sort by, prefixed with '-' for descending order (title, status, created_at or updated_at; default: -created_at)"),
+        ("fields" = Option<String>, Query, description = "Comma-separated fields to return (default: all)")
+    ),
     responses(
-        (status = 200, description = "List of all tasks", body = Vec<Task>)
+        (status = 200, description = "List of tasks", body = Vec<Task>, headers(
+            ("X-Total-Count" = u64, description = "Number of items across all pages"),
+            ("Link" = String, description = "Links to the first, previous, next and last pages")
+        ))
     ),
     tag = "tasks"
 )]
-pub async fn list_tasks(State(state): State<AppState>) -> Result<Json<Vec<Task>>, AppError> {
+pub async fn list_tasks(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+    uri: Uri,
+) -> Result<Response, AppError> {
+    let params = ListParams::from_query(&params)?;
     let project = state.project().await?;
-    let tasks = project.task_repository.find_all().await?;
-    Ok(Json(tasks))
+    let page = project.task_repository.find_page(&params.page).await?;
+    list_response(&uri, page, &params)
 }
 
 #[utoipa::path(
@@ -163,6 +187,8 @@ pub struct TransitionRequest {
 pub struct TransitionResponse {
     pub task: Task,
     pub previous_status: TaskStatus,
+    /// The workflow starts the new status' phase after this transition
+    pub run_phase: bool,
 }
 
 #[utoipa::path(
@@ -205,6 +231,29 @@ pub async fn transition_task(
         "Attempting state transition"
     );
 
+    let workflow = &project.task_executor.config().workflow;
+    let run_phase = workflow
+        .transition(&task.status, &payload.status)
+        .is_some_and(|t| t.run_phase);
+    let context = transition_context(&project, &task).await?;
+    if let Some(reason) = TaskStateMachine::check_transitions(workflow, &task.status, &context)
+        .into_iter()
+        .find(|check| check.to == payload.status)
+        .and_then(|check| check.blocked_reason)
+    {
+        warn!(
+            task_id = %id,
+            to = %payload.status.as_str(),
+            reason = %reason,
+            "API: Task transition blocked"
+        );
+        return Err(AppError::BadRequest(format!(
+            "Cannot transition to {}: {}",
+            payload.status.as_str(),
+            reason
+        )));
+    }
+
     project
         .task_executor
         .transition(&mut task, payload.status)
@@ -237,6 +286,209 @@ pub async fn transition_task(
     Ok(Json(TransitionResponse {
         task,
         previous_status,
+        run_phase,
+    }))
+}
+
+/// Facts about a task that the state machine's transition guards check
+async fn transition_context(
+    project: &ProjectContext,
+    task: &Task,
+) -> Result<TransitionContext, AppError> {
+    let file_manager = project.task_executor.file_manager();
+    let running_sessions = project
+        .session_repository
+        .find_by_task_id(task.id)
+        .await?
+        .iter()
+        .filter(|s| s.status == opencode_core::SessionStatus::Running)
+        .count();
+    let pending_findings = file_manager
+        .read_findings(task.id)
+        .await
+        .ok()
+        .flatten()
+        .map(|f| f.pending_count())
+        .unwrap_or(0);
+
+    Ok(TransitionContext {
+        running_sessions,
+        has_plan: file_manager.plan_exists(task.id).await,
+        pending_findings,
+        unfinished_dependencies: unfinished_dependencies(project, task).await?.len(),
+        ci_gate: file_manager
+            .read_ci_gate(task.id)
+            .await
+            .ok()
+            .flatten()
+            .map(|gate| gate.state),
+    })
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct TaskTransitionsResponse {
+    pub status: TaskStatus,
+    /// Status the workflow moves to next by default, if any
+    pub next_status: Option<TaskStatus>,
+    pub transitions: Vec<TaskTransition>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct TaskTransition {
+    pub to: TaskStatus,
+    pub allowed: bool,
+    /// Why the transition is blocked right now
+    pub blocked_reason: Option<String>,
+    /// The workflow starts the target status' phase after the transition
+    pub run_phase: bool,
+    /// Phases stop before this transition and wait for a person to make it
+    pub requires_approval: bool,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{id}/transitions",
+    params(
+        ("id" = Uuid, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "Transitions out of the task's current status", body = TaskTransitionsResponse),
+        (status = 404, description = "Task not found")
+    ),
+    tag = "tasks"
+)]
+pub async fn get_task_transitions(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<TaskTransitionsResponse>, AppError> {
+    let project = state.project().await?;
+    let task = project
+        .task_repository
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Task not found: {}", id)))?;
+
+    let workflow = &project.task_executor.config().workflow;
+    let context = transition_context(&project, &task).await?;
+    let transitions = TaskStateMachine::check_transitions(workflow, &task.status, &context)
+        .into_iter()
+        .map(|check| {
+            let transition = workflow.transition(&task.status, &check.to);
+            TaskTransition {
+                to: check.to,
+                allowed: check.is_allowed(),
+                blocked_reason: check.blocked_reason,
+                run_phase: transition.is_some_and(|t| t.run_phase),
+                requires_approval: transition.is_some_and(|t| t.requires_approval),
+            }
+        })
+        .collect();
+
+    Ok(Json(TaskTransitionsResponse {
+        status: task.status,
+        next_status: workflow.next_status(&task.status),
+        transitions,
+    }))
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct StateMachineResponse {
+    /// Name of the project's workflow, `default` for the built-in one
+    pub workflow: String,
+    pub initial_status: TaskStatus,
+    /// Statuses in workflow order
+    pub statuses: Vec<StateMachineStatus>,
+    pub transitions: Vec<StateMachineTransition>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct StateMachineStatus {
+    pub status: TaskStatus,
+    pub next_status: Option<TaskStatus>,
+    pub previous_status: Option<TaskStatus>,
+    /// No transitions leave the status
+    pub terminal: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct StateMachineTransition {
+    pub from: TaskStatus,
+    pub to: TaskStatus,
+    /// Conditions checked before the transition is made
+    pub guards: Vec<StateMachineGuard>,
+    /// The workflow starts the target status' phase after the transition
+    pub run_phase: bool,
+    /// Phases stop before this transition and wait for a person to make it
+    pub requires_approval: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct StateMachineGuard {
+    pub name: String,
+    pub description: String,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/state-machine",
+    responses(
+        (status = 200, description = "The project workflow's statuses and the transitions between them", body = StateMachineResponse)
+    ),
+    tag = "tasks"
+)]
+pub async fn get_state_machine(
+    State(state): State<AppState>,
+) -> Result<Json<StateMachineResponse>, AppError> {
+    let project = state.project().await?;
+    let workflow = &project.task_executor.config().workflow;
+
+    let statuses = workflow
+        .states
+        .iter()
+        .map(|status| StateMachineStatus {
+            status: *status,
+            next_status: workflow.next_status(status),
+            previous_status: TaskStateMachine::previous_status(status)
+                .filter(|previous| workflow.transition(status, previous).is_some()),
+            terminal: workflow.transitions_from(status).next().is_none(),
+        })
+        .collect();
+
+    let transitions = workflow
+        .transitions
+        .iter()
+        .map(|t| StateMachineTransition {
+            from: t.from,
+            to: t.to,
+            guards: TaskStateMachine::guards(&t.from, &t.to)
+                .into_iter()
+                .map(|guard| StateMachineGuard {
+                    name: guard.as_str().to_string(),
+                    description: guard.description().to_string(),
+                })
+                .collect(),
+            run_phase: t.run_phase,
+            requires_approval: t.requires_approval,
+        })
+        .collect();
+
+    Ok(Json(StateMachineResponse {
+        workflow: workflow.name.clone(),
+        initial_status: TaskStatus::default(),
+        statuses,
+        transitions,
     }))
 }
 
@@ -259,6 +511,7 @@ pub struct ExecuteResponse {
     responses(
         (status = 202, description = "Execution started", body = ExecuteResponse),
         (status = 404, description = "Task not found"),
+        (status = 409, description = "Tasks the task depends on are not done"),
         (status = 500, description = "Execution failed to start")
     ),
     tag = "tasks"
@@ -277,6 +530,19 @@ pub async fn execute_task(
         return Err(AppError::NotFound(format!("Task not found: {}", id)));
     };
 
+    let unfinished = unfinished_dependencies(&project, &task).await?;
+    if !unfinished.is_empty() {
+        warn!(task_id = %id, unfinished = unfinished.len(), "API: Task is blocked by its dependencies");
+        return Err(AppError::Conflict(format!(
+            "Task depends on tasks that are not done: {}",
+            unfinished
+                .iter()
+                .map(|t| t.title.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )));
+    }
+
     info!(
         task_id = %id,
         task_title = %task.title,
@@ -322,6 +588,65 @@ pub async fn execute_task(
     ))
 }
 
+/// Start the phases workflow rules queue after a transition (e.g. a fix
+/// after a rejected review), until the project is closed
+pub fn spawn_queued_phases(project: ProjectContext, event_bus: &EventBus) {
+    let mut rx = event_bus.subscribe();
+    let project_path = project.path.display().to_string();
+
+    tokio::spawn(async move {
+        loop {
+            match rx.recv().await {
+                Ok(envelope) => match envelope.event {
+                    Event::TransitionRuleEvaluated {
+                        task_id,
+                        queued_phase: Some(phase),
+                        ..
+                    } => start_queued_phase(&project, task_id, &phase).await,
+                    Event::ProjectClosed { path } if path == project_path => break,
+                    _ => {}
+                },
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
+async fn start_queued_phase(project: &ProjectContext, task_id: Uuid, phase: &str) {
+    let Some(phase) = SessionPhase::parse(phase) else {
+        warn!(task_id = %task_id, phase = %phase, "Unknown queued phase");
+        return;
+    };
+    let mut task = match project.task_repository.find_by_id(task_id).await {
+        Ok(Some(task)) => task,
+        Ok(None) => return,
+        Err(e) => {
+            error!(task_id = %task_id, error = %e, "Failed to load task for queued phase");
+            return;
+        }
+    };
+
+    match project
+        .task_executor
+        .start_queued_phase(&mut task, phase)
+        .await
+    {
+        Ok(started) => info!(
+            task_id = %task_id,
+            session_id = %started.session_id,
+            phase = %phase.as_str(),
+            "Queued phase started"
+        ),
+        Err(e) => error!(
+            task_id = %task_id,
+            phase = %phase.as_str(),
+            error = %e,
+            "Failed to start queued phase"
+        ),
+    }
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(export))]
@@ -441,6 +766,191 @@ pub async fn get_task_findings(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{id}/findings/sarif",
+    params(
+        ("id" = Uuid, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "Task findings as a SARIF 2.1.0 log", content_type = "application/sarif+json", body = Object),
+        (status = 404, description = "Task not found or not reviewed yet")
+    ),
+    tag = "tasks"
+)]
+pub async fn get_task_findings_sarif(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let project = state.project().await?;
+    if project.task_repository.find_by_id(id).await?.is_none() {
+        return Err(AppError::NotFound(format!("Task not found: {}", id)));
+    }
+
+    let findings = project
+        .task_executor
+        .file_manager()
+        .read_findings(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Task {} has no review findings", id)))?;
+
+    Ok((
+        [(header::CONTENT_TYPE, "application/sarif+json")],
+        Json(findings.to_sarif()),
+    )
+        .into_response())
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ReviewReportQuery {
+    /// `md` (default) or `html`
+    pub format: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{id}/review-report",
+    params(
+        ("id" = Uuid, Path, description = "Task ID"),
+        ("format" = Option<String>, Query, description = "md (default) or html")
+    ),
+    responses(
+        (status = 200, description = "The task's review summary and findings as a document", content(
+            (String = "text/markdown"),
+            (String = "text/html")
+        )),
+        (status = 400, description = "Unknown format"),
+        (status = 404, description = "Task not found or not reviewed yet")
+    ),
+    tag = "tasks"
+)]
+pub async fn get_task_review_report(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ReviewReportQuery>,
+) -> Result<Response, AppError> {
+    let format = match query.format.as_deref() {
+        None => ReportFormat::Markdown,
+        Some(format) => ReportFormat::parse(format).ok_or_else(|| {
+            AppError::BadRequest(format!(
+                "Unknown report format '{}', expected md or html",
+                format
+            ))
+        })?,
+    };
+
+    let project = state.project().await?;
+    let task = project
+        .task_repository
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Task not found: {}", id)))?;
+
+    let executor = &project.task_executor;
+    let file_manager = executor.file_manager();
+    let findings = file_manager
+        .read_findings(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Task {} has no review findings", id)))?;
+
+    // The task's own review model wins over the project's, as when the
+    // review ran
+    let task_models = file_manager.read_task_models(id).await.unwrap_or_else(|e| {
+        warn!(task_id = %id, error = %e, "Failed to read task models");
+        None
+    });
+    let reviewer_model = task_models
+        .and_then(|models| models.review)
+        .or_else(|| executor.config().phase_models.review.clone());
+
+    // Once merged, the workspace is gone and the code lives in the project
+    let root = task
+        .workspace_path
+        .as_deref()
+        .filter(|path| !path.is_empty())
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|| project.project_path.clone());
+    let report = ReviewReport::new(&task.title, &findings)
+        .with_reviewer_model(reviewer_model)
+        .load_excerpts(&root)
+        .await;
+
+    let disposition = format!("inline; filename=\"review-{}.{}\"", id, format.extension());
+    Ok((
+        [
+            (header::CONTENT_TYPE, format.content_type().to_string()),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        report.render(format),
+    )
+        .into_response())
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct TranslateFindingsQuery {
+    /// Language to translate into; the configured review language by default
+    pub lang: Option<String>,
+}
+
+/// Language names and codes such as `de`, `pt-BR` or `Japanese`; they end up
+/// in a file name
+fn valid_language(lang: &str) -> bool {
+    !lang.is_empty()
+        && lang.len() <= 32
+        && lang
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/tasks/{id}/findings/translate",
+    params(
+        ("id" = Uuid, Path, description = "Task ID"),
+        ("lang" = Option<String>, Query, description = "Language to translate into, e.g. de or pt-BR (default: the configured review language)")
+    ),
+    responses(
+        (status = 200, description = "Findings with their text translated", body = FindingsResponse),
+        (status = 400, description = "No or invalid language"),
+        (status = 404, description = "Task or findings not found")
+    ),
+    tag = "tasks"
+)]
+pub async fn translate_findings(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<TranslateFindingsQuery>,
+) -> Result<Json<FindingsResponse>, AppError> {
+    let project = state.project().await?;
+    if project.task_repository.find_by_id(id).await?.is_none() {
+        return Err(AppError::NotFound(format!("Task not found: {}", id)));
+    }
+
+    let lang = query
+        .lang
+        .or_else(|| project.task_executor.config().review_language.clone())
+        .map(|l| l.trim().to_string())
+        .ok_or_else(|| {
+            AppError::BadRequest("No language given and no review language configured".to_string())
+        })?;
+    if !valid_language(&lang) {
+        return Err(AppError::BadRequest(format!("Invalid language: {}", lang)));
+    }
+
+    let findings = project
+        .task_executor
+        .translate_findings(id, &lang)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Task {} has no review findings", id)))?;
+
+    Ok(Json(FindingsResponse {
+        findings: findings.findings,
+        summary: findings.summary,
+        approved: findings.approved,
+        exists: true,
+    }))
+}
+
 #[derive(Debug, Deserialize, ToSchema)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(export))]
@@ -629,6 +1139,213 @@ pub async fn skip_findings(
     Ok(Json(task))
 }
 
+#[derive(Debug, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct BulkUpdateFindingsRequest {
+    pub finding_ids: Vec<String>,
+    /// Target status: fixed, skipped or dismissed
+    pub status: FindingStatus,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct BulkFindingResult {
+    pub finding_id: String,
+    /// `updated`, `unchanged` (already had the status) or `not_found`
+    pub result: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct BulkUpdateFindingsResponse {
+    pub status: FindingStatus,
+    /// Number of findings whose status changed
+    pub updated: u32,
+    pub results: Vec<BulkFindingResult>,
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/tasks/{id}/findings/bulk",
+    params(
+        ("id" = Uuid, Path, description = "Task ID")
+    ),
+    request_body = BulkUpdateFindingsRequest,
+    responses(
+        (status = 200, description = "Per-finding results", body = BulkUpdateFindingsResponse),
+        (status = 400, description = "No finding IDs or invalid target status"),
+        (status = 404, description = "Task or findings not found")
+    ),
+    tag = "tasks"
+)]
+#[instrument(skip(state, payload), fields(task_id = %id))]
+pub async fn bulk_update_findings(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<BulkUpdateFindingsRequest>,
+) -> Result<Json<BulkUpdateFindingsResponse>, AppError> {
+    if payload.finding_ids.is_empty() {
+        return Err(AppError::BadRequest(
+            "At least one finding ID is required".to_string(),
+        ));
+    }
+    if payload.status == FindingStatus::Pending {
+        return Err(AppError::BadRequest(
+            "Target status must be fixed, skipped or dismissed".to_string(),
+        ));
+    }
+
+    let project = state.project().await?;
+    if project.task_repository.find_by_id(id).await?.is_none() {
+        return Err(AppError::NotFound(format!("Task not found: {}", id)));
+    }
+
+    let changes = project
+        .task_executor
+        .file_manager()
+        .set_findings_status(id, &payload.finding_ids, payload.status)
+        .await
+        .map_err(|e| match e {
+            orchestrator::OrchestratorError::FindingsNotFound(_) => {
+                AppError::NotFound(format!("No findings for task: {}", id))
+            }
+            e => AppError::Orchestrator(e),
+        })?;
+
+    let mut updated_ids = Vec::new();
+    let results: Vec<BulkFindingResult> = changes
+        .into_iter()
+        .map(|(finding_id, previous)| {
+            let result = match previous {
+                None => "not_found",
+                Some(previous) if previous == payload.status => "unchanged",
+                Some(_) => {
+                    updated_ids.push(finding_id.clone());
+                    "updated"
+                }
+            };
+            BulkFindingResult {
+                finding_id,
+                result: result.to_string(),
+            }
+        })
+        .collect();
+
+    info!(
+        task_id = %id,
+        status = payload.status.as_str(),
+        updated = updated_ids.len(),
+        "API: Findings status updated"
+    );
+
+    let updated = updated_ids.len() as u32;
+    if !updated_ids.is_empty() {
+        state
+            .event_bus
+            .publish(EventEnvelope::new(Event::FindingsUpdated {
+                task_id: id,
+                finding_ids: updated_ids,
+                status: payload.status.as_str().to_string(),
+            }));
+    }
+
+    Ok(Json(BulkUpdateFindingsResponse {
+        status: payload.status,
+        updated,
+        results,
+    }))
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct AddFindingCommentRequest {
+    pub body: String,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{id}/findings/{finding_id}/comments",
+    params(
+        ("id" = Uuid, Path, description = "Task ID"),
+        ("finding_id" = String, Path, description = "Finding ID")
+    ),
+    responses(
+        (status = 200, description = "Comments on the finding, oldest first", body = Vec<FindingComment>),
+        (status = 404, description = "Task or finding not found")
+    ),
+    tag = "tasks"
+)]
+pub async fn list_finding_comments(
+    State(state): State<AppState>,
+    Path((id, finding_id)): Path<(Uuid, String)>,
+) -> Result<Json<Vec<FindingComment>>, AppError> {
+    let project = state.project().await?;
+    project
+        .task_executor
+        .file_manager()
+        .finding_comments(id, &finding_id)
+        .await
+        .map_err(|e| finding_comment_error(id, e))?
+        .map(Json)
+        .ok_or_else(|| AppError::NotFound(format!("Finding not found: {}", finding_id)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/tasks/{id}/findings/{finding_id}/comments",
+    params(
+        ("id" = Uuid, Path, description = "Task ID"),
+        ("finding_id" = String, Path, description = "Finding ID")
+    ),
+    request_body = AddFindingCommentRequest,
+    responses(
+        (status = 201, description = "Comment added", body = FindingComment),
+        (status = 400, description = "Empty comment"),
+        (status = 404, description = "Task or finding not found")
+    ),
+    tag = "tasks"
+)]
+#[instrument(skip(state, payload), fields(task_id = %id))]
+pub async fn add_finding_comment(
+    State(state): State<AppState>,
+    Path((id, finding_id)): Path<(Uuid, String)>,
+    Json(payload): Json<AddFindingCommentRequest>,
+) -> Result<(StatusCode, Json<FindingComment>), AppError> {
+    let body = payload.body.trim();
+    if body.is_empty() {
+        return Err(AppError::BadRequest("Comment body is empty".to_string()));
+    }
+
+    let project = state.project().await?;
+    let comment = project
+        .task_executor
+        .file_manager()
+        .add_finding_comment(
+            id,
+            &finding_id,
+            FindingComment::new(FindingCommentAuthor::Human, body),
+        )
+        .await
+        .map_err(|e| finding_comment_error(id, e))?
+        .ok_or_else(|| AppError::NotFound(format!("Finding not found: {}", finding_id)))?;
+
+    info!(task_id = %id, finding_id = %finding_id, "API: Finding comment added");
+    Ok((StatusCode::CREATED, Json(comment)))
+}
+
+fn finding_comment_error(task_id: Uuid, e: orchestrator::OrchestratorError) -> AppError {
+    match e {
+        orchestrator::OrchestratorError::FindingsNotFound(_) => {
+            AppError::NotFound(format!("No findings for task: {}", task_id))
+        }
+        e => AppError::Orchestrator(e),
+    }
+}
+
 // ============================================================================
 // Phases API
 // ============================================================================
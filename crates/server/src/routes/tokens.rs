@@ -0,0 +1,156 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use db::{ApiToken, ApiTokenRepository};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+use utoipa::ToSchema;
+
+use crate::auth::{generate_token, hash_token, TokenScope};
+use crate::error::AppError;
+use crate::state::AppState;
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct ApiTokenResponse {
+    pub id: String,
+    pub name: String,
+    /// `read:tasks`, `read:wiki`, `write:tasks` or `admin`
+    pub scopes: Vec<String>,
+    pub created_at: i64,
+    pub last_used_at: Option<i64>,
+    pub revoked_at: Option<i64>,
+}
+
+impl From<ApiToken> for ApiTokenResponse {
+    fn from(token: ApiToken) -> Self {
+        Self {
+            scopes: token.scopes(),
+            id: token.id,
+            name: token.name,
+            created_at: token.created_at,
+            last_used_at: token.last_used_at,
+            revoked_at: token.revoked_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct CreateApiTokenRequest {
+    pub name: String,
+    pub scopes: Vec<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct CreateApiTokenResponse {
+    /// The token itself; it is only shown once
+    pub token: String,
+    pub api_token: ApiTokenResponse,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/tokens",
+    responses(
+        (status = 200, description = "All API tokens, including revoked ones", body = Vec<ApiTokenResponse>)
+    ),
+    tag = "admin"
+)]
+pub async fn list_api_tokens(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<ApiTokenResponse>>, AppError> {
+    let project = state.project().await?;
+    let tokens = ApiTokenRepository::new(project.pool.clone())
+        .find_all()
+        .await?;
+
+    Ok(Json(
+        tokens.into_iter().map(ApiTokenResponse::from).collect(),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/tokens",
+    request_body = CreateApiTokenRequest,
+    responses(
+        (status = 201, description = "Token created", body = CreateApiTokenResponse),
+        (status = 400, description = "Missing name or unknown scope")
+    ),
+    tag = "admin"
+)]
+pub async fn create_api_token(
+    State(state): State<AppState>,
+    Json(payload): Json<CreateApiTokenRequest>,
+) -> Result<(StatusCode, Json<CreateApiTokenResponse>), AppError> {
+    let name = payload.name.trim();
+    if name.is_empty() {
+        return Err(AppError::BadRequest("Token name is required".to_string()));
+    }
+    if payload.scopes.is_empty() {
+        return Err(AppError::BadRequest(
+            "At least one scope is required".to_string(),
+        ));
+    }
+    if let Some(unknown) = payload
+        .scopes
+        .iter()
+        .find(|s| TokenScope::parse(s).is_none())
+    {
+        return Err(AppError::BadRequest(format!(
+            "Unknown scope '{}'. Available: {}",
+            unknown,
+            TokenScope::ALL.map(|s| s.as_str()).join(", ")
+        )));
+    }
+
+    info!(name = %name, scopes = ?payload.scopes, "Creating API token");
+
+    let token = generate_token();
+    let project = state.project().await?;
+    let api_token = ApiTokenRepository::new(project.pool.clone())
+        .create(name, &hash_token(&token), &payload.scopes)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(CreateApiTokenResponse {
+            token,
+            api_token: ApiTokenResponse::from(api_token),
+        }),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/admin/tokens/{id}",
+    params(
+        ("id" = String, Path, description = "Token ID")
+    ),
+    responses(
+        (status = 204, description = "Token revoked"),
+        (status = 404, description = "Token not found or already revoked")
+    ),
+    tag = "admin"
+)]
+pub async fn revoke_api_token(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, AppError> {
+    let project = state.project().await?;
+    let revoked = ApiTokenRepository::new(project.pool.clone())
+        .revoke(&id)
+        .await?;
+
+    if revoked {
+        info!(id = %id, "Revoked API token");
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(AppError::NotFound(format!("API token not found: {}", id)))
+    }
+}
@@ -0,0 +1,307 @@
+//! Tokens and cost of OpenRouter calls
+//!
+//! Every chat and embedding call the wiki makes is recorded with the task
+//! and session, or the wiki branch, it was made for. These routes sum the
+//! records for cost reports; OpenCode session tokens are reported by the
+//! task pipeline instead.
+
+use std::collections::{BTreeMap, HashMap};
+
+use axum::extract::{Path, Query, State};
+use axum::Json;
+use chrono::{DateTime, Utc};
+use db::{UsageFilter, UsageGroup, UsageRepository};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::state::AppState;
+
+/// Usage summed over a set of calls
+#[derive(Debug, Clone, Default, PartialEq, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct UsageTotals {
+    pub requests: u64,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    /// Estimated cost in USD of the calls whose model price is known;
+    /// `None` when no price was known
+    pub cost_usd: Option<f64>,
+}
+
+impl UsageTotals {
+    fn add(&mut self, group: &UsageGroup) {
+        self.requests += group.requests as u64;
+        self.prompt_tokens += group.prompt_tokens as u64;
+        self.completion_tokens += group.completion_tokens as u64;
+        if let Some(cost) = group.cost_usd {
+            *self.cost_usd.get_or_insert(0.0) += cost;
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct ModelUsageTotals {
+    pub model: String,
+    /// `chat` or `embedding`
+    pub kind: String,
+    pub usage: UsageTotals,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct TaskUsageTotals {
+    pub task_id: String,
+    pub usage: UsageTotals,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct BranchUsageTotals {
+    pub branch: String,
+    pub usage: UsageTotals,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct SessionUsageTotals {
+    /// `None` for calls made for the task outside its sessions
+    pub session_id: Option<String>,
+    pub usage: UsageTotals,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct UsageReportResponse {
+    pub total: UsageTotals,
+    pub by_model: Vec<ModelUsageTotals>,
+    /// Calls made for tasks, by task
+    pub by_task: Vec<TaskUsageTotals>,
+    /// Calls made for the wiki, by branch; task calls that read the wiki
+    /// count here too
+    pub by_wiki_branch: Vec<BranchUsageTotals>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct TaskUsageResponse {
+    pub task_id: Uuid,
+    pub total: UsageTotals,
+    pub by_session: Vec<SessionUsageTotals>,
+    pub by_model: Vec<ModelUsageTotals>,
+}
+
+/// Sum `groups` per key, ordered by key; groups without a key are left out
+fn sum_by<K: Ord>(
+    groups: &[UsageGroup],
+    key: impl Fn(&UsageGroup) -> Option<K>,
+) -> Vec<(K, UsageTotals)> {
+    let mut sums: BTreeMap<K, UsageTotals> = BTreeMap::new();
+    for group in groups {
+        if let Some(key) = key(group) {
+            sums.entry(key).or_default().add(group);
+        }
+    }
+    sums.into_iter().collect()
+}
+
+fn total(groups: &[UsageGroup]) -> UsageTotals {
+    let mut total = UsageTotals::default();
+    for group in groups {
+        total.add(group);
+    }
+    total
+}
+
+fn by_model(groups: &[UsageGroup]) -> Vec<ModelUsageTotals> {
+    sum_by(groups, |g| Some((g.model.clone(), g.kind.clone())))
+        .into_iter()
+        .map(|((model, kind), usage)| ModelUsageTotals { model, kind, usage })
+        .collect()
+}
+
+fn report(groups: &[UsageGroup]) -> UsageReportResponse {
+    UsageReportResponse {
+        total: total(groups),
+        by_model: by_model(groups),
+        by_task: sum_by(groups, |g| g.task_id.clone())
+            .into_iter()
+            .map(|(task_id, usage)| TaskUsageTotals { task_id, usage })
+            .collect(),
+        by_wiki_branch: sum_by(groups, |g| g.wiki_branch.clone())
+            .into_iter()
+            .map(|(branch, usage)| BranchUsageTotals { branch, usage })
+            .collect(),
+    }
+}
+
+fn parse_filter(params: &HashMap<String, String>) -> Result<UsageFilter, AppError> {
+    let since = params
+        .get("since")
+        .filter(|s| !s.is_empty())
+        .map(|since| {
+            DateTime::parse_from_rfc3339(since)
+                .map(|since| since.with_timezone(&Utc))
+                .map_err(|_| AppError::BadRequest(format!("Invalid since '{}'", since)))
+        })
+        .transpose()?;
+    let task_id = params
+        .get("task_id")
+        .filter(|s| !s.is_empty())
+        .map(|id| {
+            id.parse::<Uuid>()
+                .map_err(|_| AppError::BadRequest(format!("Invalid task_id '{}'", id)))
+        })
+        .transpose()?;
+
+    Ok(UsageFilter {
+        task_id,
+        wiki_branch: params.get("wiki_branch").filter(|s| !s.is_empty()).cloned(),
+        since,
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/usage",
+    params(
+        ("task_id" = Option<Uuid>, Query, description = "Only calls made for this task"),
+        ("wiki_branch" = Option<String>, Query, description = "Only calls made for the wiki of this branch"),
+        ("since" = Option<String>, Query, description = "Only calls at or after this RFC 3339 time")
+    ),
+    responses(
+        (status = 200, description = "Tokens and estimated cost of OpenRouter calls", body = UsageReportResponse),
+        (status = 400, description = "Invalid filter")
+    ),
+    tag = "usage"
+)]
+pub async fn get_usage(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<UsageReportResponse>, AppError> {
+    let filter = parse_filter(&params)?;
+    let project = state.project().await?;
+    let groups = UsageRepository::new(project.pool.clone())
+        .summarize(&filter)
+        .await?;
+
+    Ok(Json(report(&groups)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{id}/usage",
+    params(
+        ("id" = Uuid, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "Tokens and estimated cost of the OpenRouter calls made for the task", body = TaskUsageResponse),
+        (status = 404, description = "Task not found")
+    ),
+    tag = "usage"
+)]
+pub async fn get_task_usage(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<TaskUsageResponse>, AppError> {
+    let project = state.project().await?;
+    if project.task_repository.find_by_id(id).await?.is_none() {
+        return Err(AppError::NotFound(format!("Task not found: {}", id)));
+    }
+
+    let groups = UsageRepository::new(project.pool.clone())
+        .summarize(&UsageFilter {
+            task_id: Some(id),
+            ..Default::default()
+        })
+        .await?;
+
+    let mut by_session: BTreeMap<Option<String>, UsageTotals> = BTreeMap::new();
+    for group in &groups {
+        by_session
+            .entry(group.session_id.clone())
+            .or_default()
+            .add(group);
+    }
+
+    Ok(Json(TaskUsageResponse {
+        task_id: id,
+        total: total(&groups),
+        by_session: by_session
+            .into_iter()
+            .map(|(session_id, usage)| SessionUsageTotals { session_id, usage })
+            .collect(),
+        by_model: by_model(&groups),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group(
+        task_id: Option<&str>,
+        branch: Option<&str>,
+        model: &str,
+        cost: Option<f64>,
+    ) -> UsageGroup {
+        UsageGroup {
+            task_id: task_id.map(String::from),
+            session_id: None,
+            wiki_branch: branch.map(String::from),
+            model: model.to_string(),
+            kind: "chat".to_string(),
+            requests: 2,
+            prompt_tokens: 100,
+            completion_tokens: 10,
+            cost_usd: cost,
+        }
+    }
+
+    #[test]
+    fn test_report_sums_groups() {
+        let groups = [
+            group(Some("t1"), Some("main"), "a", Some(0.25)),
+            group(Some("t1"), None, "b", None),
+            group(None, Some("main"), "a", Some(0.5)),
+        ];
+        let report = report(&groups);
+
+        assert_eq!(report.total.requests, 6);
+        assert_eq!(report.total.prompt_tokens, 300);
+        assert_eq!(report.total.cost_usd, Some(0.75));
+
+        assert_eq!(report.by_model.len(), 2);
+        assert_eq!(report.by_model[0].model, "a");
+        assert_eq!(report.by_model[0].usage.cost_usd, Some(0.75));
+        assert_eq!(report.by_model[1].usage.cost_usd, None);
+
+        assert_eq!(report.by_task.len(), 1);
+        assert_eq!(report.by_task[0].usage.requests, 4);
+        assert_eq!(report.by_wiki_branch.len(), 1);
+        assert_eq!(report.by_wiki_branch[0].usage.requests, 4);
+    }
+
+    #[test]
+    fn test_parse_filter() {
+        let params = HashMap::from([
+            ("wiki_branch".to_string(), "main".to_string()),
+            ("since".to_string(), "2026-01-01T00:00:00Z".to_string()),
+        ]);
+        let filter = parse_filter(&params).unwrap();
+        assert_eq!(filter.wiki_branch.as_deref(), Some("main"));
+        assert!(filter.since.is_some());
+
+        let params = HashMap::from([("task_id".to_string(), "nope".to_string())]);
+        assert!(parse_filter(&params).is_err());
+    }
+}
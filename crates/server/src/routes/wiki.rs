@@ -1,21 +1,37 @@
 use axum::extract::{Path, Query, State};
+use axum::response::sse::{Event as SseEvent, KeepAlive, Sse};
 use axum::Json;
+use db::TaskWikiUpdateRepository;
+use futures::{Stream, StreamExt};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::convert::Infallible;
 use std::path::PathBuf;
 use std::sync::Arc;
-use std::time::Instant;
-use tracing::{debug, error, info};
+use std::time::{Duration, Instant};
+use tracing::{debug, error, info, warn};
 use utoipa::ToSchema;
+use uuid::Uuid;
 
 use crate::config::ProjectConfig;
-use crate::config::WikiConfig as ProjectWikiConfig;
+use crate::config::{
+    PostMergeWikiUpdate, WikiChunkTuning, WikiConfig as ProjectWikiConfig, WikiEmbeddingProvider,
+    WikiPageImportance,
+};
 use crate::error::AppError;
+use crate::job_logs::JOB_SPAN;
+use crate::project_manager::ProjectContext;
+use crate::routes::jobs::job_logs_path;
+use crate::routes::preferences::current_user_preferences;
 use crate::state::AppState;
 
 use wiki::{
-    CodeIndexer, GenerationMode, IndexStatus, SearchResult, SourceCitation,
-    WikiConfig as WikiEngineConfig, WikiEngine, WikiPage, WikiSection, WikiStructure, WikiTree,
+    BranchLock, BranchLockAttempt, BranchLockGuard, CallGraphExpansion, ChunkGeneration, CodeChunk,
+    CodeIndexer, ComparedPage, EmbeddingIndex, Endpoint, EnvVar, EnvVarUsage, FlowStep, FlowTracer,
+    GenerationMode, IndexEstimate, IndexEstimator, IndexStatus, ModuleCoverage, OnboardingStep,
+    PageChange, PageUpdateAction, PageUpdater, ReembedProgress, Reembedder, SearchResult,
+    SkippedPage, SourceCitation, WikiConfig as WikiEngineConfig, WikiEngine, WikiPage, WikiSection,
+    WikiStructure, WikiTree,
 };
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -41,6 +57,16 @@ pub struct BranchStatus {
     pub error_message: Option<String>,
     pub current_phase: Option<String>,
     pub current_item: Option<String>,
+    /// Embedding model serving searches on the branch
+    pub embedding_model: Option<String>,
+    pub embedding_version: Option<u32>,
+    /// Model a re-embed is migrating the branch to
+    pub migrating_to_model: Option<String>,
+    /// Commits of earlier indexes that searches can target with `as_of`,
+    /// newest first
+    pub kept_commits: Vec<String>,
+    /// Indexing or generation job running on the branch
+    pub active_job_id: Option<String>,
 }
 
 impl From<IndexStatus> for BranchStatus {
@@ -56,7 +82,36 @@ impl From<IndexStatus> for BranchStatus {
             error_message: status.error_message,
             current_phase: status.current_phase,
             current_item: status.current_item,
+            embedding_model: None,
+            embedding_version: None,
+            migrating_to_model: None,
+            kept_commits: Vec::new(),
+            active_job_id: None,
+        }
+    }
+}
+
+impl BranchStatus {
+    fn with_embedding_indexes(mut self, indexes: Vec<EmbeddingIndex>) -> Self {
+        for index in indexes {
+            if index.is_active() {
+                self.embedding_model = Some(index.model);
+                self.embedding_version = Some(index.version);
+            } else {
+                self.migrating_to_model = Some(index.model);
+            }
         }
+        self
+    }
+
+    fn with_history(mut self, generations: Vec<ChunkGeneration>) -> Self {
+        self.kept_commits = generations.into_iter().map(|g| g.commit_sha).collect();
+        self
+    }
+
+    fn with_lock(mut self, lock: Option<BranchLock>) -> Self {
+        self.active_job_id = lock.map(|lock| lock.job_id);
+        self
     }
 }
 
@@ -70,6 +125,67 @@ pub struct IndexRequest {
     pub index_only: Option<bool>,
 }
 
+#[derive(Debug, Default, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct IndexEstimateRequest {
+    /// Sub-directories to estimate, defaults to the configured roots
+    #[serde(default)]
+    pub roots: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct IndexEstimateResponse {
+    pub files: u32,
+    pub chunks: u32,
+    pub tokens: u64,
+    /// Embedding requests the chunks are sent in
+    pub batches: u32,
+    pub embedding_model: String,
+    /// USD per million input tokens, unset when the model's price is unknown
+    pub price_per_million_tokens: Option<f64>,
+    pub estimated_cost_usd: Option<f64>,
+    pub estimated_seconds: u64,
+}
+
+impl From<IndexEstimate> for IndexEstimateResponse {
+    fn from(estimate: IndexEstimate) -> Self {
+        Self {
+            files: estimate.files as u32,
+            chunks: estimate.chunks as u32,
+            tokens: estimate.tokens as u64,
+            batches: estimate.batches as u32,
+            embedding_model: estimate.embedding_model,
+            price_per_million_tokens: estimate.price_per_million_tokens,
+            estimated_cost_usd: estimate.estimated_cost_usd,
+            estimated_seconds: estimate.estimated_seconds,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct ReembedRequest {
+    pub branch: Option<String>,
+    /// Target embedding model, defaults to the configured one
+    pub model: Option<String>,
+    /// Stop after embedding this many chunks; a later request resumes
+    pub max_chunks: Option<u32>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct ReembedResponse {
+    pub started: bool,
+    pub branch: String,
+    pub model: String,
+    pub message: String,
+}
+
 #[derive(Debug, Deserialize, ToSchema)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(export))]
@@ -85,6 +201,16 @@ pub struct GenerateWikiResponse {
     pub started: bool,
     pub branch: String,
     pub message: String,
+    /// Job generating the branch: the one started, or when `started` is false
+    /// the one already running, whose progress events to follow instead
+    pub job_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct GenerateWikiPageRequest {
+    pub branch: Option<String>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -94,6 +220,9 @@ pub struct IndexResponse {
     pub started: bool,
     pub branch: String,
     pub message: String,
+    /// Job indexing the branch: the one started, or when `started` is false
+    /// the one already running, whose progress events to follow instead
+    pub job_id: Option<String>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -104,6 +233,37 @@ pub struct WikiStructureResponse {
     pub root: WikiTreeNode,
     pub page_count: u32,
     pub sections: Vec<WikiSectionResponse>,
+    pub skipped_pages: Vec<SkippedWikiPageResponse>,
+}
+
+/// A planned page left out by the generation limits or that failed to generate
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct SkippedWikiPageResponse {
+    pub id: String,
+    pub title: String,
+    pub section_id: String,
+    pub importance: String,
+    pub file_paths: Vec<String>,
+    pub description: String,
+    pub reason: String,
+    pub error: Option<String>,
+}
+
+impl From<SkippedPage> for SkippedWikiPageResponse {
+    fn from(page: SkippedPage) -> Self {
+        Self {
+            id: page.id,
+            title: page.title,
+            section_id: page.section_id,
+            importance: page.importance.as_str().to_string(),
+            file_paths: page.file_paths,
+            description: page.description,
+            reason: page.reason.as_str().to_string(),
+            error: page.error,
+        }
+    }
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -164,6 +324,11 @@ impl From<WikiStructure> for WikiStructureResponse {
                 .into_iter()
                 .map(WikiSectionResponse::from)
                 .collect(),
+            skipped_pages: structure
+                .skipped_pages
+                .into_iter()
+                .map(SkippedWikiPageResponse::from)
+                .collect(),
         }
     }
 }
@@ -228,12 +393,378 @@ impl From<WikiPage> for WikiPageResponse {
     }
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct WikiEndpointsResponse {
+    pub branch: String,
+    pub endpoints: Vec<WikiEndpoint>,
+    pub total_count: u32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct WikiEndpoint {
+    pub method: String,
+    pub path: String,
+    pub handler: Option<String>,
+    pub framework: String,
+    pub file_path: String,
+    pub line: u32,
+}
+
+impl From<Endpoint> for WikiEndpoint {
+    fn from(endpoint: Endpoint) -> Self {
+        Self {
+            method: endpoint.method,
+            path: endpoint.path,
+            handler: endpoint.handler,
+            framework: endpoint.framework.as_str().to_string(),
+            file_path: endpoint.file_path,
+            line: endpoint.line,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct WikiEnvVarsResponse {
+    pub branch: String,
+    pub variables: Vec<WikiEnvVar>,
+    pub total_count: u32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct WikiEnvVar {
+    pub name: String,
+    pub default_value: Option<String>,
+    pub description: Option<String>,
+    pub usages: Vec<WikiEnvVarUsage>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct WikiEnvVarUsage {
+    pub file_path: String,
+    pub line: u32,
+}
+
+impl From<EnvVar> for WikiEnvVar {
+    fn from(var: EnvVar) -> Self {
+        Self {
+            name: var.name,
+            default_value: var.default_value,
+            description: var.description,
+            usages: var.usages.into_iter().map(WikiEnvVarUsage::from).collect(),
+        }
+    }
+}
+
+impl From<EnvVarUsage> for WikiEnvVarUsage {
+    fn from(usage: EnvVarUsage) -> Self {
+        Self {
+            file_path: usage.file_path,
+            line: usage.line,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct WikiTestCoverageResponse {
+    pub branch: String,
+    pub modules: Vec<WikiModuleCoverage>,
+    pub tested_count: u32,
+    pub untested_count: u32,
+    pub test_file_count: u32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct WikiModuleCoverage {
+    pub file_path: String,
+    pub language: String,
+    pub test_files: Vec<String>,
+    pub has_inline_tests: bool,
+    pub tested: bool,
+}
+
+impl From<ModuleCoverage> for WikiModuleCoverage {
+    fn from(module: ModuleCoverage) -> Self {
+        Self {
+            tested: module.is_tested(),
+            file_path: module.file_path,
+            language: module.language,
+            test_files: module.test_files,
+            has_inline_tests: module.has_inline_tests,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct WikiOnboardingResponse {
+    pub branch: String,
+    pub steps: Vec<WikiOnboardingStep>,
+    pub total_minutes: u32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct WikiOnboardingStep {
+    pub slug: String,
+    pub title: String,
+    pub stage: String,
+    pub reason: String,
+    pub reading_minutes: u32,
+}
+
+impl From<OnboardingStep> for WikiOnboardingStep {
+    fn from(step: OnboardingStep) -> Self {
+        Self {
+            slug: step.slug,
+            title: step.title,
+            stage: step.stage.as_str().to_string(),
+            reason: step.reason,
+            reading_minutes: step.reading_minutes,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct WikiCompareResponse {
+    pub base: String,
+    pub head: String,
+    /// Pages only in `head`
+    pub added: Vec<WikiComparedPage>,
+    /// Pages only in `base`
+    pub removed: Vec<WikiComparedPage>,
+    /// Pages in both branches whose title or content differs
+    pub changed: Vec<WikiPageChange>,
+    pub unchanged_count: u32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct WikiComparedPage {
+    pub slug: String,
+    pub title: String,
+}
+
+impl From<ComparedPage> for WikiComparedPage {
+    fn from(page: ComparedPage) -> Self {
+        Self {
+            slug: page.slug,
+            title: page.title,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct WikiPageChange {
+    pub slug: String,
+    /// Title in `head`
+    pub title: String,
+    /// Title in `base`, when it differs
+    pub previous_title: Option<String>,
+    pub lines_added: u32,
+    pub lines_removed: u32,
+    /// Unified diff of the page content, empty when only the title changed
+    pub diff: String,
+}
+
+impl From<PageChange> for WikiPageChange {
+    fn from(change: PageChange) -> Self {
+        Self {
+            slug: change.slug,
+            title: change.title,
+            previous_title: change.previous_title,
+            lines_added: change.lines_added,
+            lines_removed: change.lines_removed,
+            diff: change.diff,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct WikiFileChunksResponse {
+    pub branch: String,
+    pub file_path: String,
+    /// Chunks of the file, ordered by chunk index
+    pub chunks: Vec<WikiFileChunk>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct WikiFileChunk {
+    pub id: String,
+    pub chunk_index: u32,
+    pub start_line: u32,
+    pub end_line: u32,
+    pub chunk_type: String,
+    pub language: Option<String>,
+    pub token_count: u32,
+    pub content: String,
+    /// One-line summary written at index time
+    pub summary: Option<String>,
+    pub commit_sha: String,
+    pub indexed_at: String,
+}
+
+impl From<CodeChunk> for WikiFileChunk {
+    fn from(chunk: CodeChunk) -> Self {
+        Self {
+            id: chunk.id.to_string(),
+            chunk_index: chunk.chunk_index,
+            start_line: chunk.start_line,
+            end_line: chunk.end_line,
+            chunk_type: chunk.chunk_type.as_str().to_string(),
+            language: chunk.language,
+            token_count: chunk.token_count,
+            content: chunk.content,
+            summary: chunk.summary,
+            commit_sha: chunk.commit_sha,
+            indexed_at: chunk.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct TraceFlowRequest {
+    /// HTTP method of the request to trace, e.g. `POST`
+    pub method: String,
+    /// Request path, e.g. `/api/tasks/{id}/execute`
+    pub path: String,
+    pub branch: Option<String>,
+    /// Save the flow document as a wiki page
+    #[serde(default)]
+    pub persist: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct TraceFlowResponse {
+    pub branch: String,
+    pub route: WikiEndpoint,
+    pub steps: Vec<WikiFlowStep>,
+    /// Markdown flow document
+    pub content: String,
+    /// Slug of the saved wiki page, when `persist` was set
+    pub page_slug: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct WikiFlowStep {
+    pub symbol: String,
+    pub file_path: String,
+    pub line: u32,
+    pub end_line: u32,
+    pub depth: u32,
+    pub called_from: Option<String>,
+}
+
+impl From<FlowStep> for WikiFlowStep {
+    fn from(step: FlowStep) -> Self {
+        Self {
+            symbol: step.symbol,
+            file_path: step.file_path,
+            line: step.line,
+            end_line: step.end_line,
+            depth: step.depth,
+            called_from: step.called_from,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct TaskWikiUpdateResponse {
+    pub id: String,
+    pub task_id: String,
+    pub branch: String,
+    pub page_slug: String,
+    pub page_title: String,
+    /// `suggested` or `regenerated`
+    pub action: String,
+    pub changed_files: Vec<String>,
+    /// Edit suggestions, or the rewritten page content
+    pub content: String,
+    pub created_at: i64,
+}
+
+impl From<db::TaskWikiUpdate> for TaskWikiUpdateResponse {
+    fn from(update: db::TaskWikiUpdate) -> Self {
+        Self {
+            changed_files: update.changed_files(),
+            id: update.id,
+            task_id: update.task_id,
+            branch: update.branch,
+            page_slug: update.page_slug,
+            page_title: update.page_title,
+            action: update.action,
+            content: update.content,
+            created_at: update.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct TaskWikiUpdatesResponse {
+    pub updates: Vec<TaskWikiUpdateResponse>,
+}
+
 #[derive(Debug, Deserialize, ToSchema)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(export))]
 pub struct SearchRequest {
     pub query: String,
     pub limit: Option<usize>,
+    /// Most results kept from one file
+    #[serde(default)]
+    pub max_per_file: Option<usize>,
+    /// Drop results whose lines overlap a better result of the same file by
+    /// at least this share (0.0-1.0) of the shorter range
+    #[serde(default)]
+    pub dedup_overlap: Option<f32>,
+    /// Scale scores so the best result scores 1.0
+    #[serde(default)]
+    pub normalize_scores: Option<bool>,
+    /// Only search code under this workspace root
+    #[serde(default)]
+    pub root: Option<String>,
+    /// Keep only code tagged as public API: HTTP handlers, exported items
+    /// and CLI commands
+    #[serde(default)]
+    pub public_api_only: Option<bool>,
+    /// Search the index as of this commit (a SHA or a prefix of at least
+    /// four hex digits) instead of the current one
+    #[serde(default)]
+    pub as_of: Option<String>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -241,6 +772,8 @@ pub struct SearchRequest {
 #[cfg_attr(feature = "typescript", ts(export))]
 pub struct WikiSearchResponse {
     pub query: String,
+    /// Synonyms from the project glossary added to the query before embedding
+    pub expanded_terms: Vec<String>,
     pub results: Vec<WikiSearchResult>,
     pub total_count: u32,
     pub duration_ms: u64,
@@ -254,8 +787,12 @@ pub struct WikiSearchResult {
     pub start_line: u32,
     pub end_line: u32,
     pub content: String,
+    /// One-line summary of the code, written at index time
+    pub summary: Option<String>,
     pub language: Option<String>,
     pub score: f32,
+    /// Whether the code defines public API
+    pub is_public_api: bool,
 }
 
 impl From<SearchResult> for WikiSearchResult {
@@ -265,8 +802,10 @@ impl From<SearchResult> for WikiSearchResult {
             start_line: result.start_line,
             end_line: result.end_line,
             content: result.content,
+            summary: result.summary,
             language: result.language,
             score: result.score,
+            is_public_api: result.is_public_api,
         }
     }
 }
@@ -277,6 +816,40 @@ impl From<SearchResult> for WikiSearchResult {
 pub struct AskRequest {
     pub question: String,
     pub conversation_id: Option<String>,
+    /// Shape of the answer; `standard` when left out
+    pub mode: Option<AskAnswerMode>,
+    /// Only answer from code under this workspace root
+    #[serde(default)]
+    pub root: Option<String>,
+}
+
+/// Shape of a wiki answer
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum AskAnswerMode {
+    /// Balanced explanation with citations
+    #[default]
+    Standard,
+    /// A few sentences
+    Concise,
+    /// Step-by-step walkthrough
+    Detailed,
+    /// Relevant code snippets without prose
+    #[serde(alias = "code-only")]
+    CodeOnly,
+}
+
+impl From<AskAnswerMode> for wiki::AnswerMode {
+    fn from(mode: AskAnswerMode) -> Self {
+        match mode {
+            AskAnswerMode::Standard => wiki::AnswerMode::Standard,
+            AskAnswerMode::Concise => wiki::AnswerMode::Concise,
+            AskAnswerMode::Detailed => wiki::AnswerMode::Detailed,
+            AskAnswerMode::CodeOnly => wiki::AnswerMode::CodeOnly,
+        }
+    }
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -286,6 +859,8 @@ pub struct AskResponse {
     pub answer: String,
     pub sources: Vec<AskSource>,
     pub conversation_id: String,
+    /// Mode the answer was written in
+    pub mode: AskAnswerMode,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -297,6 +872,10 @@ pub struct AskSource {
     pub end_line: u32,
     pub score: f32,
     pub snippet: String,
+    /// One-line summary of the source's code, written at index time
+    pub summary: Option<String>,
+    /// Set when the source was added by call-graph expansion rather than search
+    pub expansion: Option<String>,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -308,11 +887,39 @@ pub struct WebhookPushRequest {
     pub after: String,
 }
 
-#[derive(Debug, Serialize, ToSchema)]
+/// The parts of a GitHub `pull_request` webhook payload the wiki reads
+#[derive(Debug, Deserialize, ToSchema)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(export))]
-pub struct WebhookResponse {
-    pub accepted: bool,
+pub struct WebhookPullRequestEvent {
+    pub action: String,
+    pub pull_request: WebhookPullRequest,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct WebhookPullRequest {
+    pub number: u64,
+    #[serde(default)]
+    pub merged: bool,
+    pub base: WebhookPullRequestBase,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct WebhookPullRequestBase {
+    /// Branch the pull request merged into
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct WebhookResponse {
+    pub accepted: bool,
     pub message: String,
 }
 
@@ -337,6 +944,26 @@ pub struct WikiSettingsResponse {
     pub auto_sync: bool,
     pub repo_url: Option<String>,
     pub has_access_token: bool,
+    pub post_merge_update: PostMergeWikiUpdate,
+    pub review_context: bool,
+    pub review_context_max_tokens: u32,
+    pub planning_context: bool,
+    pub planning_context_max_tokens: u32,
+    pub max_pages: u32,
+    pub max_pages_per_section: u32,
+    pub min_page_importance: WikiPageImportance,
+    pub page_concurrency: u32,
+    pub embedding_concurrency: u32,
+    pub chunk_summaries: bool,
+    /// Workspace roots indexed; empty when the whole repository is
+    pub roots: Vec<String>,
+    pub embedding_provider: WikiEmbeddingProvider,
+    pub has_openai_api_key: bool,
+    pub local_embedding_model_path: Option<String>,
+    pub embedding_dimension: Option<u32>,
+    pub history_generations: u32,
+    /// Chunk settings picked by the last applied tuning pass
+    pub chunk_tuning: Option<WikiChunkTuning>,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -351,12 +978,70 @@ pub struct UpdateWikiSettingsRequest {
     pub auto_sync: Option<bool>,
     pub repo_url: Option<String>,
     pub access_token: Option<String>,
+    pub post_merge_update: Option<PostMergeWikiUpdate>,
+    pub review_context: Option<bool>,
+    pub review_context_max_tokens: Option<u32>,
+    pub planning_context: Option<bool>,
+    pub planning_context_max_tokens: Option<u32>,
+    pub max_pages: Option<u32>,
+    pub max_pages_per_section: Option<u32>,
+    pub min_page_importance: Option<WikiPageImportance>,
+    pub page_concurrency: Option<u32>,
+    pub embedding_concurrency: Option<u32>,
+    pub chunk_summaries: Option<bool>,
+    pub roots: Option<Vec<String>>,
+    pub embedding_provider: Option<WikiEmbeddingProvider>,
+    /// Empty clears the key
+    pub openai_api_key: Option<String>,
+    /// Empty clears the path
+    pub local_embedding_model_path: Option<String>,
+    /// Zero clears the dimension
+    pub embedding_dimension: Option<u32>,
+    /// Earlier indexes kept per branch; zero keeps none
+    pub history_generations: Option<u32>,
+}
+
+/// Embedding model mismatches are the user's to resolve (re-embed or switch
+/// the model back), so they surface as conflicts rather than server errors
+fn search_error(e: wiki::WikiError) -> AppError {
+    match e {
+        wiki::WikiError::EmbeddingModelMismatch { .. } => AppError::Conflict(e.to_string()),
+        wiki::WikiError::CommitNotIndexed { .. } => AppError::NotFound(e.to_string()),
+        wiki::WikiError::InvalidConfig(msg) => AppError::BadRequest(msg),
+        e => AppError::Internal(format!("Search failed: {}", e)),
+    }
 }
 
-fn get_wiki_db_path(project_path: &std::path::Path) -> PathBuf {
+pub(crate) fn get_wiki_db_path(project_path: &std::path::Path) -> PathBuf {
     project_path.join(".opencode-studio").join("wiki.db")
 }
 
+/// The configured embedding provider, reporting its usage to `usage`
+pub(crate) fn embedding_provider(
+    wiki_config: &ProjectWikiConfig,
+    usage: wiki::UsageTracker,
+) -> Result<Arc<dyn wiki::EmbeddingProvider>, AppError> {
+    wiki_config
+        .embedding_provider_config()
+        .build_tracked(Some(usage))
+        .map_err(|e| AppError::BadRequest(format!("Embedding provider unavailable: {}", e)))
+}
+
+/// Tracker storing the usage of OpenRouter calls made for the wiki of
+/// `branch`
+pub(crate) fn wiki_usage(project: &ProjectContext, branch: &str) -> wiki::UsageTracker {
+    project.usage_store.tracker(wiki::UsageScope::wiki(branch))
+}
+
+/// The branch wiki requests read when they don't name one
+fn default_branch(wiki_config: &ProjectWikiConfig) -> String {
+    wiki_config
+        .branches
+        .first()
+        .cloned()
+        .unwrap_or_else(|| "main".to_string())
+}
+
 fn create_wiki_engine(
     project_path: &std::path::Path,
     wiki_config: &ProjectWikiConfig,
@@ -366,6 +1051,7 @@ fn create_wiki_engine(
         .clone()
         .ok_or_else(|| AppError::BadRequest("Wiki API key not configured".to_string()))?;
 
+    let (max_chunk_tokens, chunk_overlap) = wiki_config.chunk_setting();
     let engine_config = WikiEngineConfig {
         branches: wiki_config.branches.clone(),
         openrouter_api_key: api_key,
@@ -379,6 +1065,18 @@ fn create_wiki_engine(
             .unwrap_or_else(|| "anthropic/claude-3.5-sonnet".to_string()),
         db_path: get_wiki_db_path(project_path),
         auto_sync: wiki_config.auto_sync,
+        roots: wiki_config.roots.clone(),
+        embedding_provider: wiki_config.embedding_provider.into(),
+        openai_api_key: wiki_config.openai_api_key.clone(),
+        local_model_path: wiki_config
+            .local_embedding_model_path
+            .as_ref()
+            .map(Into::into),
+        embedding_dimension: wiki_config.embedding_dimension.map(|d| d as usize),
+        history_generations: wiki_config.history_generations as usize,
+        embedding_concurrency: wiki_config.embedding_concurrency as usize,
+        max_chunk_tokens,
+        chunk_overlap,
         ..Default::default()
     };
 
@@ -388,6 +1086,18 @@ fn create_wiki_engine(
     })
 }
 
+fn page_limits(wiki_config: &ProjectWikiConfig) -> wiki::PageLimits {
+    wiki::PageLimits {
+        max_pages: wiki_config.max_pages as usize,
+        max_pages_per_section: wiki_config.max_pages_per_section as usize,
+        min_importance: match wiki_config.min_page_importance {
+            WikiPageImportance::Low => wiki::Importance::Low,
+            WikiPageImportance::Medium => wiki::Importance::Medium,
+            WikiPageImportance::High => wiki::Importance::High,
+        },
+    }
+}
+
 #[utoipa::path(
     get,
     path = "/api/wiki/status",
@@ -416,12 +1126,29 @@ pub async fn get_wiki_status(
     let engine = create_wiki_engine(&project.project_path, &config.wiki)?;
     let mut branches = Vec::new();
 
+    let vector_store = wiki::VectorStore::new(&get_wiki_db_path(&project.project_path))
+        .map_err(|e| AppError::Internal(format!("Failed to open vector store: {}", e)))?;
+
     for branch_name in &config.wiki.branches {
         let status = engine
             .get_index_status(branch_name)
             .map_err(|e| AppError::Internal(format!("Failed to get index status: {}", e)))?
             .unwrap_or_else(|| IndexStatus::new(branch_name.clone()));
-        branches.push(BranchStatus::from(status));
+        let indexes = vector_store
+            .get_embedding_indexes(branch_name)
+            .map_err(|e| AppError::Internal(format!("Failed to get embedding indexes: {}", e)))?;
+        let generations = vector_store
+            .get_chunk_generations(branch_name)
+            .map_err(|e| AppError::Internal(format!("Failed to get kept indexes: {}", e)))?;
+        let lock = vector_store
+            .get_branch_lock(branch_name)
+            .map_err(|e| AppError::Internal(format!("Failed to get branch lock: {}", e)))?;
+        branches.push(
+            BranchStatus::from(status)
+                .with_embedding_indexes(indexes)
+                .with_history(generations)
+                .with_lock(lock),
+        );
     }
 
     Ok(Json(WikiStatusResponse {
@@ -501,33 +1228,47 @@ pub async fn start_indexing(
         .as_ref()
         .and_then(|m| GenerationMode::parse(m))
         .unwrap_or_default();
-    let engine = create_wiki_engine(&project.project_path, &config.wiki)?;
+    // Fails early when the wiki is not configured
+    create_wiki_engine(&project.project_path, &config.wiki)?;
 
-    let status = engine
-        .get_index_status(&branch)
-        .map_err(|e| AppError::Internal(format!("Failed to get index status: {}", e)))?;
-
-    if let Some(ref s) = status {
-        if s.is_indexing() && !force {
+    let db_path = get_wiki_db_path(&project.project_path);
+    let lock = match lock_branch(&db_path, &branch, "indexing")? {
+        BranchLockAttempt::Acquired(lock) => lock,
+        BranchLockAttempt::Held(held) => {
+            info!(branch = %branch, job_id = %held.job_id, "Indexing not started, branch is locked");
             return Ok(Json(IndexResponse {
                 started: false,
                 branch,
-                message: "Indexing already in progress. Use force=true to restart.".to_string(),
+                message: already_running_message(&held),
+                job_id: Some(held.job_id),
             }));
         }
-    }
+    };
+    let job_id = lock.job_id.clone();
 
     let project_path = project.project_path.clone();
     let wiki_config = config.wiki.clone();
     let branch_clone = branch.clone();
     let index_only = payload.index_only.unwrap_or(false);
     let event_bus = state.event_bus.clone();
+    let usage = wiki_usage(&project, &branch);
 
     std::thread::spawn(move || {
+        let _job = job_span(&lock).entered();
+        let job_id = lock.job_id.clone();
         let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
         rt.block_on(async {
+            let _lock = BranchLockGuard::new(&db_path, lock);
             let result = if index_only {
-                run_code_indexing(project_path, wiki_config, branch_clone.clone(), force).await
+                run_code_indexing(
+                    project_path,
+                    wiki_config,
+                    branch_clone.clone(),
+                    force,
+                    usage,
+                )
+                .await
+                .map(|_| ())
             } else {
                 run_full_indexing(
                     project_path,
@@ -536,11 +1277,13 @@ pub async fn start_indexing(
                     force,
                     mode,
                     Some(event_bus),
+                    usage,
                 )
                 .await
             };
             if let Err(e) = result {
                 error!(error = %e, branch = %branch_clone, "Indexing failed");
+                link_job_logs(&db_path, &branch_clone, &job_id);
             }
         });
     });
@@ -555,6 +1298,157 @@ pub async fn start_indexing(
         started: true,
         branch,
         message: message.to_string(),
+        job_id: Some(job_id),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/wiki/index/estimate",
+    request_body = IndexEstimateRequest,
+    responses(
+        (status = 200, description = "Estimated indexing cost and time", body = IndexEstimateResponse),
+        (status = 500, description = "Failed to read the repository")
+    ),
+    tag = "wiki"
+)]
+pub async fn estimate_indexing(
+    State(state): State<AppState>,
+    Json(payload): Json<IndexEstimateRequest>,
+) -> Result<Json<IndexEstimateResponse>, AppError> {
+    debug!("Estimating wiki indexing cost");
+
+    let project = state.project().await?;
+    let config = ProjectConfig::read(&project.project_path).await;
+
+    let embedding_model = config
+        .wiki
+        .embedding_model
+        .clone()
+        .unwrap_or_else(|| "openai/text-embedding-3-small".to_string());
+    let roots = payload.roots.unwrap_or_else(|| config.wiki.roots.clone());
+    let project_path = project.project_path.clone();
+    let model = embedding_model.clone();
+    let (max_chunk_tokens, chunk_overlap) = config.wiki.chunk_setting();
+
+    let estimate = tokio::task::spawn_blocking(move || {
+        IndexEstimator::new(max_chunk_tokens, chunk_overlap)
+            .with_roots(&roots)
+            .estimate(&project_path, &model)
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("Estimate task failed: {}", e)))?
+    .map_err(|e| AppError::Internal(format!("Failed to estimate indexing: {}", e)))?
+    .priced(&config.wiki.embedding_provider_config())
+    .await;
+
+    info!(
+        files = estimate.files,
+        tokens = estimate.tokens,
+        model = %embedding_model,
+        cost = ?estimate.estimated_cost_usd,
+        "Estimated wiki indexing"
+    );
+
+    Ok(Json(estimate.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/wiki/reembed",
+    request_body = ReembedRequest,
+    responses(
+        (status = 200, description = "Re-embedding started", body = ReembedResponse),
+        (status = 400, description = "Invalid request"),
+        (status = 500, description = "Failed to start re-embedding")
+    ),
+    tag = "wiki"
+)]
+pub async fn reembed_wiki(
+    State(state): State<AppState>,
+    Json(payload): Json<ReembedRequest>,
+) -> Result<Json<ReembedResponse>, AppError> {
+    let project = state.project().await?;
+    let config = ProjectConfig::read(&project.project_path).await;
+
+    if !config.wiki.enabled {
+        return Err(AppError::BadRequest("Wiki is not enabled".to_string()));
+    }
+    let branch = payload
+        .branch
+        .unwrap_or_else(|| default_branch(&config.wiki));
+    let provider = embedding_provider(&config.wiki, wiki_usage(&project, &branch))?;
+    let model = payload.model.unwrap_or_else(|| {
+        config
+            .wiki
+            .embedding_model
+            .clone()
+            .unwrap_or_else(|| "openai/text-embedding-3-small".to_string())
+    });
+    info!(branch = %branch, model = %model, "Starting re-embedding");
+
+    let db_path = get_wiki_db_path(&project.project_path);
+    {
+        let vector_store = wiki::VectorStore::new(&db_path)
+            .map_err(|e| AppError::Internal(format!("Failed to open vector store: {}", e)))?;
+        let indexes = vector_store
+            .get_embedding_indexes(&branch)
+            .map_err(|e| AppError::Internal(format!("Failed to get embedding indexes: {}", e)))?;
+        match indexes.iter().find(|index| index.is_active()) {
+            None => {
+                return Err(AppError::BadRequest(format!(
+                    "Branch '{}' is not indexed",
+                    branch
+                )))
+            }
+            Some(active) if active.model == model => {
+                return Ok(Json(ReembedResponse {
+                    started: false,
+                    branch,
+                    model,
+                    message: "Branch already uses this embedding model".to_string(),
+                }));
+            }
+            Some(_) => {}
+        }
+    }
+
+    let branch_clone = branch.clone();
+    let model_clone = model.clone();
+    let max_chunks = payload.max_chunks;
+
+    std::thread::spawn(move || {
+        let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+        rt.block_on(async {
+            let result: Result<ReembedProgress, wiki::WikiError> = async {
+                let vector_store = wiki::VectorStore::new(&db_path)?;
+                let mut reembedder = Reembedder::new(provider.as_ref(), &vector_store);
+                if let Some(max_chunks) = max_chunks {
+                    reembedder = reembedder.with_max_chunks(max_chunks as usize);
+                }
+                reembedder.reembed(&branch_clone, &model_clone).await
+            }
+            .await;
+            match result {
+                Ok(progress) => info!(
+                    branch = %progress.branch,
+                    model = %progress.model,
+                    embedded = progress.embedded,
+                    remaining = progress.remaining,
+                    completed = progress.completed,
+                    "Re-embedding finished"
+                ),
+                Err(e) => error!(error = %e, branch = %branch_clone, "Re-embedding failed"),
+            }
+        });
+    });
+
+    Ok(Json(ReembedResponse {
+        started: true,
+        branch,
+        model,
+        message: "Re-embedding started; searches use the current model until it completes"
+            .to_string(),
     }))
 }
 
@@ -611,34 +1505,44 @@ pub async fn generate_wiki(
         ));
     }
 
-    if let Some(ref s) = status {
-        if s.state.as_str() == "generating" {
+    let lock = match lock_branch(&db_path, &branch, "generation")? {
+        BranchLockAttempt::Acquired(lock) => lock,
+        BranchLockAttempt::Held(held) => {
+            info!(branch = %branch, job_id = %held.job_id, "Generation not started, branch is locked");
             return Ok(Json(GenerateWikiResponse {
                 started: false,
                 branch,
-                message: "Wiki generation already in progress".to_string(),
+                message: already_running_message(&held),
+                job_id: Some(held.job_id),
             }));
         }
-    }
+    };
+    let job_id = lock.job_id.clone();
 
     let project_path = project.project_path.clone();
     let wiki_config = config.wiki.clone();
     let branch_clone = branch.clone();
     let event_bus = state.event_bus.clone();
+    let usage = wiki_usage(&project, &branch);
 
     std::thread::spawn(move || {
+        let _job = job_span(&lock).entered();
+        let job_id = lock.job_id.clone();
         let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
         rt.block_on(async {
+            let _lock = BranchLockGuard::new(&db_path, lock);
             if let Err(e) = run_wiki_generation(
                 project_path,
                 wiki_config,
                 branch_clone.clone(),
                 mode,
                 event_bus,
+                usage,
             )
             .await
             {
                 error!(error = %e, branch = %branch_clone, "Wiki generation failed");
+                link_job_logs(&db_path, &branch_clone, &job_id);
             }
         });
     });
@@ -647,16 +1551,75 @@ pub async fn generate_wiki(
         started: true,
         branch,
         message: "Wiki generation started".to_string(),
+        job_id: Some(job_id),
     }))
 }
 
+/// Lock `branch` for a new indexing or generation job, so that concurrent
+/// requests attach to the running job instead of starting another
+fn lock_branch(
+    db_path: &std::path::Path,
+    branch: &str,
+    operation: &str,
+) -> Result<BranchLockAttempt, AppError> {
+    wiki::VectorStore::new(db_path)
+        .and_then(|store| store.acquire_branch_lock(branch, operation))
+        .map_err(|e| AppError::Internal(format!("Failed to lock branch: {}", e)))
+}
+
+fn already_running_message(held: &BranchLock) -> String {
+    format!(
+        "Wiki {} of branch '{}' is already running (job {}); follow its progress instead",
+        held.operation, held.branch, held.job_id
+    )
+}
+
+/// Span of the job holding `lock`; what is logged in it is kept as the
+/// job's log
+fn job_span(lock: &BranchLock) -> tracing::Span {
+    tracing::info_span!(
+        JOB_SPAN,
+        job_id = %lock.job_id,
+        branch = %lock.branch,
+        operation = %lock.operation
+    )
+}
+
+/// Point the error of the branch's failed index status at the log of the
+/// job that failed
+fn link_job_logs(db_path: &std::path::Path, branch: &str, job_id: &str) {
+    let Ok(store) = wiki::VectorStore::new(db_path) else {
+        return;
+    };
+    let Ok(Some(mut status)) = store.get_index_status(branch) else {
+        return;
+    };
+    if status.state != wiki::IndexState::Failed {
+        return;
+    }
+    let Some(error) = status.error_message.as_mut() else {
+        return;
+    };
+    if !error.contains(job_id) {
+        error.push_str(&format!(
+            " (job {}, logs at {})",
+            job_id,
+            job_logs_path(job_id)
+        ));
+        if let Err(e) = store.update_index_status(&status) {
+            warn!(branch = %branch, error = %e, "Failed to link job logs to index status");
+        }
+    }
+}
+
 #[allow(clippy::arc_with_non_send_sync)]
 async fn run_code_indexing(
     project_path: PathBuf,
     wiki_config: ProjectWikiConfig,
     branch: String,
     force: bool,
-) -> Result<(), wiki::WikiError> {
+    usage: wiki::UsageTracker,
+) -> Result<IndexStatus, wiki::WikiError> {
     use wiki::IndexState;
 
     let is_remote = wiki_config.repo_url.is_some();
@@ -680,30 +1643,63 @@ async fn run_code_indexing(
         }
     };
 
-    let api_key = match wiki_config.openrouter_api_key {
-        Some(key) => key,
-        None => {
-            let err = "API key not configured";
-            update_failed_status(&vector_store, &branch, err);
-            return Err(wiki::WikiError::InvalidConfig(err.to_string()));
+    let embeddings = match wiki_config
+        .embedding_provider_config()
+        .build_tracked(Some(usage.clone()))
+    {
+        Ok(embeddings) => embeddings,
+        Err(e) => {
+            update_failed_status(&vector_store, &branch, &e.to_string());
+            return Err(e);
         }
     };
 
     let embedding_model = wiki_config
         .embedding_model
+        .clone()
         .unwrap_or_else(|| "openai/text-embedding-3-small".to_string());
 
-    let openrouter = Arc::new(wiki::OpenRouterClient::new(
-        api_key,
-        "https://openrouter.ai/api/v1".to_string(),
-    ));
+    if let Some(dimension) = wiki_config.embedding_dimension {
+        if let Err(e) =
+            vector_store.configure_embedding_dimension(&embedding_model, dimension as usize)
+        {
+            update_failed_status(&vector_store, &branch, &e.to_string());
+            return Err(e);
+        }
+    }
 
     if force {
         info!(branch = %branch, "Force flag set, clearing existing data");
         vector_store.clear_branch(&branch)?;
     }
 
-    let indexer = CodeIndexer::new(openrouter, vector_store.clone(), embedding_model, 350, 100);
+    let (max_chunk_tokens, chunk_overlap) = wiki_config.chunk_setting();
+    let mut indexer = CodeIndexer::new(
+        embeddings,
+        vector_store.clone(),
+        embedding_model,
+        max_chunk_tokens,
+        chunk_overlap,
+    )
+    .with_roots(&wiki_config.roots)
+    .with_history(wiki_config.history_generations as usize)
+    .with_embedding_concurrency(wiki_config.embedding_concurrency as usize);
+    if wiki_config.chunk_summaries {
+        let Some(api_key) = wiki_config.openrouter_api_key.clone() else {
+            let err = "API key not configured";
+            update_failed_status(&vector_store, &branch, err);
+            return Err(wiki::WikiError::InvalidConfig(err.to_string()));
+        };
+        let openrouter = Arc::new(
+            wiki::OpenRouterClient::new(api_key, "https://openrouter.ai/api/v1".to_string())
+                .with_usage_tracker(usage),
+        );
+        let chat_model = wiki_config
+            .chat_model
+            .clone()
+            .unwrap_or_else(|| "anthropic/claude-3.5-sonnet".to_string());
+        indexer = indexer.with_summaries(openrouter, chat_model);
+    }
 
     let result = if let Some(repo_url) = wiki_config.repo_url {
         info!(repo_url = %repo_url, branch = %branch, "Indexing remote repository");
@@ -738,7 +1734,7 @@ async fn run_code_indexing(
         "Code indexing completed"
     );
 
-    Ok(())
+    Ok(status)
 }
 
 #[allow(clippy::arc_with_non_send_sync)]
@@ -748,11 +1744,14 @@ async fn run_wiki_generation(
     branch: String,
     mode: GenerationMode,
     event_bus: events::EventBus,
+    usage: wiki::UsageTracker,
 ) -> Result<(), wiki::WikiError> {
     use wiki::IndexState;
 
     info!(branch = %branch, mode = ?mode, "Starting wiki generation");
 
+    let page_limits = page_limits(&wiki_config);
+    let page_concurrency = wiki_config.page_concurrency as usize;
     let db_path = get_wiki_db_path(&project_path);
     let vector_store = Arc::new(wiki::VectorStore::new(&db_path)?);
 
@@ -812,10 +1811,10 @@ async fn run_wiki_generation(
         .chat_model
         .unwrap_or_else(|| "anthropic/claude-sonnet-4-20250514".to_string());
 
-    let openrouter = Arc::new(wiki::OpenRouterClient::new(
-        api_key,
-        "https://openrouter.ai/api/v1".to_string(),
-    ));
+    let openrouter = Arc::new(
+        wiki::OpenRouterClient::new(api_key, "https://openrouter.ai/api/v1".to_string())
+            .with_usage_tracker(usage),
+    );
 
     let current_status = vector_store.get_index_status(&branch)?;
     if current_status.is_none() || current_status.as_ref().map(|s| s.chunk_count).unwrap_or(0) == 0
@@ -852,7 +1851,9 @@ async fn run_wiki_generation(
     info!(branch = %branch, "Wiki generation started");
 
     let generator =
-        wiki::WikiGenerator::new(openrouter, vector_store.clone(), chat_model, 350, 100);
+        wiki::WikiGenerator::new(openrouter, vector_store.clone(), chat_model, 350, 100)
+            .with_page_limits(page_limits)
+            .with_page_concurrency(page_concurrency);
 
     let project_name = project_path
         .file_name()
@@ -887,6 +1888,23 @@ async fn run_wiki_generation(
                         },
                     ));
                 }
+                wiki::IndexProgress::StreamingPage {
+                    current,
+                    total,
+                    current_page,
+                    characters,
+                } => {
+                    event_bus_clone.publish(events::EventEnvelope::new(
+                        events::Event::WikiGenerationProgress {
+                            branch: branch_clone.clone(),
+                            phase: events::WikiGenerationPhase::GeneratingPages,
+                            current,
+                            total,
+                            current_item: Some(current_page),
+                            message: Some(format!("{} characters written", characters)),
+                        },
+                    ));
+                }
                 wiki::IndexProgress::Completed { page_count, .. } => {
                     event_bus_clone.publish(events::EventEnvelope::new(
                         events::Event::WikiGenerationProgress {
@@ -999,19 +2017,21 @@ async fn run_full_indexing(
     force: bool,
     mode: GenerationMode,
     event_bus: Option<events::EventBus>,
+    usage: wiki::UsageTracker,
 ) -> Result<(), wiki::WikiError> {
     run_code_indexing(
         project_path.clone(),
         wiki_config.clone(),
         branch.clone(),
         force,
+        usage.clone(),
     )
     .await?;
     if let Some(bus) = event_bus {
-        run_wiki_generation(project_path, wiki_config, branch, mode, bus).await
+        run_wiki_generation(project_path, wiki_config, branch, mode, bus, usage).await
     } else {
         let dummy_bus = events::EventBus::new();
-        run_wiki_generation(project_path, wiki_config, branch, mode, dummy_bus).await
+        run_wiki_generation(project_path, wiki_config, branch, mode, dummy_bus, usage).await
     }
 }
 
@@ -1117,20 +2137,25 @@ pub async fn get_wiki_page(
 
 #[utoipa::path(
     post,
-    path = "/api/wiki/search",
-    request_body = SearchRequest,
+    path = "/api/wiki/pages/{slug}/generate",
+    params(
+        ("slug" = String, Path, description = "Slug of a page skipped by the generation limits or that failed to generate")
+    ),
+    request_body = GenerateWikiPageRequest,
     responses(
-        (status = 200, description = "Search results", body = WikiSearchResponse),
-        (status = 400, description = "Invalid request"),
-        (status = 500, description = "Search failed")
+        (status = 200, description = "Generated page", body = WikiPageResponse),
+        (status = 400, description = "Wiki not enabled or API key not configured"),
+        (status = 404, description = "No skipped page with this slug"),
+        (status = 500, description = "Failed to generate page")
     ),
     tag = "wiki"
 )]
-pub async fn search_wiki(
+pub async fn generate_skipped_wiki_page(
     State(state): State<AppState>,
-    Json(payload): Json<SearchRequest>,
-) -> Result<Json<WikiSearchResponse>, AppError> {
-    info!(query = %payload.query, "Searching wiki");
+    Path(slug): Path<String>,
+    Json(payload): Json<GenerateWikiPageRequest>,
+) -> Result<Json<WikiPageResponse>, AppError> {
+    info!(slug = %slug, "Generating skipped wiki page");
 
     let project = state.project().await?;
     let config = ProjectConfig::read(&project.project_path).await;
@@ -1139,69 +2164,100 @@ pub async fn search_wiki(
         return Err(AppError::BadRequest("Wiki is not enabled".to_string()));
     }
 
+    let branch = payload.branch.unwrap_or_else(|| {
+        config
+            .wiki
+            .branches
+            .first()
+            .cloned()
+            .unwrap_or_else(|| "main".to_string())
+    });
     let api_key = config
         .wiki
         .openrouter_api_key
         .clone()
         .ok_or_else(|| AppError::BadRequest("Wiki API key not configured".to_string()))?;
-    let embedding_model = config
+    let chat_model = config
         .wiki
-        .embedding_model
+        .chat_model
         .clone()
-        .unwrap_or_else(|| "openai/text-embedding-3-small".to_string());
-    let db_path = get_wiki_db_path(&project.project_path);
-    let query = payload.query.clone();
-    let limit = payload.limit.unwrap_or(10);
+        .unwrap_or_else(|| "anthropic/claude-sonnet-4-20250514".to_string());
+    let project_path = project.project_path.clone();
+    let usage = wiki_usage(&project, &branch);
 
-    let start = Instant::now();
+    // Like full generation, this runs on its own runtime because the vector
+    // store can't be shared across threads
+    let (result_tx, result_rx) = tokio::sync::oneshot::channel();
+    std::thread::spawn(move || {
+        let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+        let result = rt.block_on(run_skipped_page_generation(
+            project_path,
+            api_key,
+            chat_model,
+            branch,
+            slug,
+            usage,
+        ));
+        let _ = result_tx.send(result);
+    });
 
-    let openrouter =
-        wiki::OpenRouterClient::new(api_key, "https://openrouter.ai/api/v1".to_string());
-    let query_embedding = openrouter
-        .create_embedding(&query, &embedding_model)
+    let page = result_rx
         .await
-        .map_err(|e| AppError::Internal(format!("Failed to create embedding: {}", e)))?;
-
-    let results = tokio::task::spawn_blocking(move || {
-        let vector_store = wiki::VectorStore::new(&db_path)
-            .map_err(|e| AppError::Internal(format!("Failed to open vector store: {}", e)))?;
-        vector_store
-            .search_similar(&query_embedding, limit)
-            .map_err(|e| AppError::Internal(format!("Search failed: {}", e)))
-    })
-    .await
-    .map_err(|e| AppError::Internal(format!("Task join error: {}", e)))??;
+        .map_err(|_| AppError::Internal("Page generation stopped unexpectedly".to_string()))?
+        .map_err(|e| match e {
+            wiki::WikiError::PageNotFound { .. } | wiki::WikiError::IndexNotFound { .. } => {
+                AppError::NotFound(e.to_string())
+            }
+            e => AppError::Internal(format!("Failed to generate page: {}", e)),
+        })?;
 
-    let duration_ms = start.elapsed().as_millis() as u64;
+    Ok(Json(WikiPageResponse::from(page)))
+}
 
-    let total_count = results.len() as u32;
-    let search_results: Vec<WikiSearchResult> =
-        results.into_iter().map(WikiSearchResult::from).collect();
+#[allow(clippy::arc_with_non_send_sync)]
+async fn run_skipped_page_generation(
+    project_path: PathBuf,
+    api_key: String,
+    chat_model: String,
+    branch: String,
+    slug: String,
+    usage: wiki::UsageTracker,
+) -> Result<WikiPage, wiki::WikiError> {
+    let vector_store = Arc::new(wiki::VectorStore::new(&get_wiki_db_path(&project_path))?);
+    let openrouter = Arc::new(
+        wiki::OpenRouterClient::new(api_key, "https://openrouter.ai/api/v1".to_string())
+            .with_usage_tracker(usage),
+    );
+    let generator = wiki::WikiGenerator::new(openrouter, vector_store, chat_model, 350, 100);
+    let commit_sha = get_current_commit_sha(&project_path).unwrap_or_else(|| "unknown".to_string());
 
-    Ok(Json(WikiSearchResponse {
-        query: payload.query,
-        results: search_results,
-        total_count,
-        duration_ms,
-    }))
+    generator
+        .generate_skipped_page(&project_path, &branch, &commit_sha, &slug)
+        .await
 }
 
 #[utoipa::path(
     post,
-    path = "/api/wiki/ask",
-    request_body = AskRequest,
+    path = "/api/wiki/pages/{slug}/regenerate",
+    params(
+        ("slug" = String, Path, description = "Slug of the page to regenerate")
+    ),
+    request_body = GenerateWikiPageRequest,
     responses(
-        (status = 200, description = "RAG response", body = AskResponse),
-        (status = 400, description = "Invalid request"),
-        (status = 500, description = "Ask failed")
+        (status = 200, description = "Page regenerated from the current content of the files it references", body = WikiPageResponse),
+        (status = 400, description = "Wiki not enabled, API key not configured, or page not regenerable"),
+        (status = 404, description = "Page not found"),
+        (status = 409, description = "Indexing or generation of the branch is running"),
+        (status = 500, description = "Failed to regenerate page")
     ),
     tag = "wiki"
 )]
-pub async fn ask_wiki(
+pub async fn regenerate_wiki_page(
     State(state): State<AppState>,
-    Json(payload): Json<AskRequest>,
-) -> Result<Json<AskResponse>, AppError> {
-    info!(question = %payload.question, "Asking wiki");
+    Path(slug): Path<String>,
+    Json(payload): Json<GenerateWikiPageRequest>,
+) -> Result<Json<WikiPageResponse>, AppError> {
+    info!(slug = %slug, "Regenerating wiki page");
 
     let project = state.project().await?;
     let config = ProjectConfig::read(&project.project_path).await;
@@ -1210,102 +2266,1220 @@ pub async fn ask_wiki(
         return Err(AppError::BadRequest("Wiki is not enabled".to_string()));
     }
 
-    let api_key = config
+    let branch = payload.branch.unwrap_or_else(|| {
+        config
+            .wiki
+            .branches
+            .first()
+            .cloned()
+            .unwrap_or_else(|| "main".to_string())
+    });
+    let api_key = config
+        .wiki
+        .openrouter_api_key
+        .clone()
+        .ok_or_else(|| AppError::BadRequest("Wiki API key not configured".to_string()))?;
+    let chat_model = config
+        .wiki
+        .chat_model
+        .clone()
+        .unwrap_or_else(|| "anthropic/claude-sonnet-4-20250514".to_string());
+    let project_path = project.project_path.clone();
+    let usage = wiki_usage(&project, &branch);
+
+    let db_path = get_wiki_db_path(&project_path);
+    let lock = match lock_branch(&db_path, &branch, "page regeneration")? {
+        BranchLockAttempt::Acquired(lock) => lock,
+        BranchLockAttempt::Held(held) => {
+            return Err(AppError::Conflict(already_running_message(&held)));
+        }
+    };
+
+    let (result_tx, result_rx) = tokio::sync::oneshot::channel();
+    std::thread::spawn(move || {
+        let _job = job_span(&lock).entered();
+        let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+        let result = rt.block_on(async {
+            let _lock = BranchLockGuard::new(&db_path, lock);
+            run_page_regeneration(project_path, api_key, chat_model, branch, slug, usage).await
+        });
+        let _ = result_tx.send(result);
+    });
+
+    let page = result_rx
+        .await
+        .map_err(|_| AppError::Internal("Page regeneration stopped unexpectedly".to_string()))?
+        .map_err(|e| match e {
+            wiki::WikiError::PageNotFound { .. } => AppError::NotFound(e.to_string()),
+            wiki::WikiError::PageNotRegenerable { .. } => AppError::BadRequest(e.to_string()),
+            e => AppError::Internal(format!("Failed to regenerate page: {}", e)),
+        })?;
+
+    Ok(Json(WikiPageResponse::from(page)))
+}
+
+#[allow(clippy::arc_with_non_send_sync)]
+async fn run_page_regeneration(
+    project_path: PathBuf,
+    api_key: String,
+    chat_model: String,
+    branch: String,
+    slug: String,
+    usage: wiki::UsageTracker,
+) -> Result<WikiPage, wiki::WikiError> {
+    let vector_store = Arc::new(wiki::VectorStore::new(&get_wiki_db_path(&project_path))?);
+    let openrouter = Arc::new(
+        wiki::OpenRouterClient::new(api_key, "https://openrouter.ai/api/v1".to_string())
+            .with_usage_tracker(usage),
+    );
+    let generator = wiki::WikiGenerator::new(openrouter, vector_store, chat_model, 350, 100);
+    let commit_sha = get_current_commit_sha(&project_path).unwrap_or_else(|| "unknown".to_string());
+
+    generator
+        .regenerate_page(&project_path, &branch, &commit_sha, &slug)
+        .await
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/wiki/endpoints",
+    params(
+        ("branch" = Option<String>, Query, description = "Branch name (default: first configured branch)")
+    ),
+    responses(
+        (status = 200, description = "HTTP endpoints found in the indexed code", body = WikiEndpointsResponse),
+        (status = 400, description = "Wiki not enabled"),
+        (status = 500, description = "Failed to extract endpoints")
+    ),
+    tag = "wiki"
+)]
+pub async fn get_wiki_endpoints(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<WikiEndpointsResponse>, AppError> {
+    debug!("Getting wiki endpoint inventory");
+
+    let project = state.project().await?;
+    let config = ProjectConfig::read(&project.project_path).await;
+
+    if !config.wiki.enabled {
+        return Err(AppError::BadRequest("Wiki is not enabled".to_string()));
+    }
+
+    let branch = params.get("branch").cloned().unwrap_or_else(|| {
+        config
+            .wiki
+            .branches
+            .first()
+            .cloned()
+            .unwrap_or_else(|| "main".to_string())
+    });
+
+    let engine = create_wiki_engine(&project.project_path, &config.wiki)?;
+
+    let inventory = engine
+        .get_endpoints(&branch)
+        .map_err(|e| AppError::Internal(format!("Failed to extract endpoints: {}", e)))?;
+
+    let endpoints: Vec<WikiEndpoint> = inventory
+        .endpoints
+        .into_iter()
+        .map(WikiEndpoint::from)
+        .collect();
+
+    Ok(Json(WikiEndpointsResponse {
+        branch,
+        total_count: endpoints.len() as u32,
+        endpoints,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/wiki/env-vars",
+    params(
+        ("branch" = Option<String>, Query, description = "Branch name (default: first configured branch)")
+    ),
+    responses(
+        (status = 200, description = "Environment variables read by the indexed code", body = WikiEnvVarsResponse),
+        (status = 400, description = "Wiki not enabled"),
+        (status = 500, description = "Failed to extract environment variables")
+    ),
+    tag = "wiki"
+)]
+pub async fn get_wiki_env_vars(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<WikiEnvVarsResponse>, AppError> {
+    debug!("Getting wiki environment variable reference");
+
+    let project = state.project().await?;
+    let config = ProjectConfig::read(&project.project_path).await;
+
+    if !config.wiki.enabled {
+        return Err(AppError::BadRequest("Wiki is not enabled".to_string()));
+    }
+
+    let branch = params.get("branch").cloned().unwrap_or_else(|| {
+        config
+            .wiki
+            .branches
+            .first()
+            .cloned()
+            .unwrap_or_else(|| "main".to_string())
+    });
+
+    let engine = create_wiki_engine(&project.project_path, &config.wiki)?;
+
+    let reference = engine.get_env_vars(&branch).map_err(|e| {
+        AppError::Internal(format!("Failed to extract environment variables: {}", e))
+    })?;
+
+    let variables: Vec<WikiEnvVar> = reference
+        .variables
+        .into_iter()
+        .map(WikiEnvVar::from)
+        .collect();
+
+    Ok(Json(WikiEnvVarsResponse {
+        branch,
+        total_count: variables.len() as u32,
+        variables,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/wiki/test-coverage",
+    params(
+        ("branch" = Option<String>, Query, description = "Branch name (default: first configured branch)"),
+        ("untested_only" = Option<bool>, Query, description = "Only return modules without associated tests")
+    ),
+    responses(
+        (status = 200, description = "Source modules and their associated tests", body = WikiTestCoverageResponse),
+        (status = 400, description = "Wiki not enabled"),
+        (status = 500, description = "Failed to analyze test coverage")
+    ),
+    tag = "wiki"
+)]
+pub async fn get_wiki_test_coverage(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<WikiTestCoverageResponse>, AppError> {
+    debug!("Getting wiki test coverage map");
+
+    let project = state.project().await?;
+    let config = ProjectConfig::read(&project.project_path).await;
+
+    if !config.wiki.enabled {
+        return Err(AppError::BadRequest("Wiki is not enabled".to_string()));
+    }
+
+    let branch = params.get("branch").cloned().unwrap_or_else(|| {
+        config
+            .wiki
+            .branches
+            .first()
+            .cloned()
+            .unwrap_or_else(|| "main".to_string())
+    });
+    let untested_only = params
+        .get("untested_only")
+        .map(|v| v == "true")
+        .unwrap_or(false);
+
+    let engine = create_wiki_engine(&project.project_path, &config.wiki)?;
+
+    let coverage = engine
+        .get_test_coverage(&branch)
+        .map_err(|e| AppError::Internal(format!("Failed to analyze test coverage: {}", e)))?;
+
+    let tested_count = coverage.tested_count() as u32;
+    let untested_count = coverage.untested_count() as u32;
+    let test_file_count = coverage.test_files.len() as u32;
+
+    let modules: Vec<WikiModuleCoverage> = coverage
+        .modules
+        .into_iter()
+        .filter(|m| !untested_only || !m.is_tested())
+        .map(WikiModuleCoverage::from)
+        .collect();
+
+    Ok(Json(WikiTestCoverageResponse {
+        branch,
+        modules,
+        tested_count,
+        untested_count,
+        test_file_count,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/wiki/onboarding",
+    params(
+        ("branch" = Option<String>, Query, description = "Branch name (default: first configured branch)")
+    ),
+    responses(
+        (status = 200, description = "Suggested reading order for new contributors", body = WikiOnboardingResponse),
+        (status = 400, description = "Wiki not enabled"),
+        (status = 500, description = "Failed to compute onboarding path")
+    ),
+    tag = "wiki"
+)]
+pub async fn get_wiki_onboarding(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<WikiOnboardingResponse>, AppError> {
+    debug!("Getting wiki onboarding path");
+
+    let project = state.project().await?;
+    let config = ProjectConfig::read(&project.project_path).await;
+
+    if !config.wiki.enabled {
+        return Err(AppError::BadRequest("Wiki is not enabled".to_string()));
+    }
+
+    let branch = params.get("branch").cloned().unwrap_or_else(|| {
+        config
+            .wiki
+            .branches
+            .first()
+            .cloned()
+            .unwrap_or_else(|| "main".to_string())
+    });
+
+    let engine = create_wiki_engine(&project.project_path, &config.wiki)?;
+
+    let path = engine
+        .get_onboarding(&branch)
+        .map_err(|e| AppError::Internal(format!("Failed to compute onboarding path: {}", e)))?;
+
+    let total_minutes = path.total_minutes();
+    let steps: Vec<WikiOnboardingStep> = path
+        .steps
+        .into_iter()
+        .map(WikiOnboardingStep::from)
+        .collect();
+
+    Ok(Json(WikiOnboardingResponse {
+        branch,
+        steps,
+        total_minutes,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/wiki/compare",
+    params(
+        ("base" = String, Query, description = "Branch to compare from"),
+        ("head" = String, Query, description = "Branch to compare to")
+    ),
+    responses(
+        (status = 200, description = "Documentation differences between the branches", body = WikiCompareResponse),
+        (status = 400, description = "Wiki not enabled or branch missing"),
+        (status = 404, description = "Branch is not indexed"),
+        (status = 500, description = "Failed to compare branches")
+    ),
+    tag = "wiki"
+)]
+pub async fn get_wiki_compare(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<WikiCompareResponse>, AppError> {
+    let (Some(base), Some(head)) = (params.get("base").cloned(), params.get("head").cloned())
+    else {
+        return Err(AppError::BadRequest(
+            "Both base and head branches are required".to_string(),
+        ));
+    };
+    debug!(base = %base, head = %head, "Comparing wiki branches");
+
+    let project = state.project().await?;
+    let config = ProjectConfig::read(&project.project_path).await;
+
+    if !config.wiki.enabled {
+        return Err(AppError::BadRequest("Wiki is not enabled".to_string()));
+    }
+
+    let engine = create_wiki_engine(&project.project_path, &config.wiki)?;
+
+    for branch in [&base, &head] {
+        let status = engine
+            .get_index_status(branch)
+            .map_err(|e| AppError::Internal(format!("Failed to get index status: {}", e)))?;
+        if status.is_none() {
+            return Err(AppError::NotFound(format!(
+                "Branch is not indexed: {}",
+                branch
+            )));
+        }
+    }
+
+    let comparison = engine
+        .compare_branches(&base, &head)
+        .map_err(|e| AppError::Internal(format!("Failed to compare branches: {}", e)))?;
+
+    Ok(Json(WikiCompareResponse {
+        base,
+        head,
+        added: comparison
+            .added
+            .into_iter()
+            .map(WikiComparedPage::from)
+            .collect(),
+        removed: comparison
+            .removed
+            .into_iter()
+            .map(WikiComparedPage::from)
+            .collect(),
+        changed: comparison
+            .changed
+            .into_iter()
+            .map(WikiPageChange::from)
+            .collect(),
+        unchanged_count: comparison.unchanged_count,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/wiki/files/{path}/chunks",
+    params(
+        ("path" = String, Path, description = "URL-encoded file path relative to the repository root"),
+        ("branch" = Option<String>, Query, description = "Branch name (default: first configured branch)"),
+        ("as_of" = Option<String>, Query, description = "Commit whose index to read instead of the current one")
+    ),
+    responses(
+        (status = 200, description = "Indexed chunks of the file", body = WikiFileChunksResponse),
+        (status = 400, description = "Wiki not enabled or invalid commit"),
+        (status = 404, description = "File or commit is not indexed"),
+        (status = 500, description = "Failed to get chunks")
+    ),
+    tag = "wiki"
+)]
+pub async fn get_wiki_file_chunks(
+    State(state): State<AppState>,
+    Path(path): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<WikiFileChunksResponse>, AppError> {
+    debug!(path = %path, "Getting indexed chunks of file");
+
+    let project = state.project().await?;
+    let config = ProjectConfig::read(&project.project_path).await;
+
+    if !config.wiki.enabled {
+        return Err(AppError::BadRequest("Wiki is not enabled".to_string()));
+    }
+
+    let branch = params.get("branch").cloned().unwrap_or_else(|| {
+        config
+            .wiki
+            .branches
+            .first()
+            .cloned()
+            .unwrap_or_else(|| "main".to_string())
+    });
+
+    let vector_store = wiki::VectorStore::new(&get_wiki_db_path(&project.project_path))
+        .map_err(|e| AppError::Internal(format!("Failed to open vector store: {}", e)))?;
+
+    let as_of = params.get("as_of").filter(|sha| !sha.is_empty());
+    let chunks = match as_of {
+        Some(as_of) => vector_store.get_chunks_for_file_as_of(&branch, &path, as_of),
+        None => vector_store.get_chunks_for_file(&branch, &path),
+    }
+    .map_err(|e| match e {
+        wiki::WikiError::CommitNotIndexed { .. } => AppError::NotFound(e.to_string()),
+        wiki::WikiError::InvalidConfig(msg) => AppError::BadRequest(msg),
+        e => AppError::Internal(format!("Failed to get chunks: {}", e)),
+    })?;
+
+    if chunks.is_empty() {
+        return Err(AppError::NotFound(match as_of {
+            Some(as_of) => format!(
+                "File not indexed on branch {} at {}: {}",
+                branch, as_of, path
+            ),
+            None => format!("File not indexed on branch {}: {}", branch, path),
+        }));
+    }
+
+    Ok(Json(WikiFileChunksResponse {
+        branch,
+        file_path: path,
+        chunks: chunks.into_iter().map(WikiFileChunk::from).collect(),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/wiki/search",
+    request_body = SearchRequest,
+    responses(
+        (status = 200, description = "Search results", body = WikiSearchResponse),
+        (status = 400, description = "Invalid request"),
+        (status = 500, description = "Search failed")
+    ),
+    tag = "wiki"
+)]
+pub async fn search_wiki(
+    State(state): State<AppState>,
+    Json(payload): Json<SearchRequest>,
+) -> Result<Json<WikiSearchResponse>, AppError> {
+    info!(query = %payload.query, "Searching wiki");
+
+    let project = state.project().await?;
+    let config = ProjectConfig::read(&project.project_path).await;
+
+    if !config.wiki.enabled {
+        return Err(AppError::BadRequest("Wiki is not enabled".to_string()));
+    }
+
+    let provider = embedding_provider(
+        &config.wiki,
+        wiki_usage(&project, &default_branch(&config.wiki)),
+    )?;
+    let embedding_model = config
+        .wiki
+        .embedding_model
+        .clone()
+        .unwrap_or_else(|| "openai/text-embedding-3-small".to_string());
+    let db_path = get_wiki_db_path(&project.project_path);
+    let query = payload.query.clone();
+    let limit = payload.limit.unwrap_or(10);
+    if let Some(overlap) = payload.dedup_overlap {
+        if !(0.0..=1.0).contains(&overlap) {
+            return Err(AppError::BadRequest(
+                "dedup_overlap must be between 0.0 and 1.0".to_string(),
+            ));
+        }
+    }
+    let filter = wiki::SearchFilter {
+        max_per_file: payload.max_per_file,
+        dedup_overlap: payload.dedup_overlap,
+        normalize_scores: payload.normalize_scores.unwrap_or(false),
+        public_api_only: payload.public_api_only.unwrap_or(false),
+    };
+
+    let start = Instant::now();
+
+    let glossary_db_path = db_path.clone();
+    let glossary = tokio::task::spawn_blocking(move || {
+        let vector_store = wiki::VectorStore::open_read_only(&glossary_db_path)
+            .map_err(|e| AppError::Internal(format!("Failed to open vector store: {}", e)))?;
+        wiki::Glossary::load(&glossary_db_path, &vector_store)
+            .map_err(|e| AppError::Internal(format!("Failed to load glossary: {}", e)))
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("Task join error: {}", e)))??;
+    let expansion = glossary.expand(&query);
+    if expansion.is_expanded() {
+        debug!(
+            original = %expansion.original,
+            expanded = %expansion.text(),
+            matched = ?expansion.matched,
+            "Expanded query with glossary"
+        );
+    }
+
+    let query_embedding = provider
+        .create_embedding(&expansion.text(), &embedding_model)
+        .await
+        .map_err(|e| AppError::Internal(format!("Failed to create embedding: {}", e)))?;
+
+    let root = payload.root.clone();
+    let as_of = payload.as_of.clone().filter(|sha| !sha.is_empty());
+    let symbol_query = query.clone();
+    let results = tokio::task::spawn_blocking(move || {
+        let vector_store = wiki::VectorStore::open_read_only(&db_path)
+            .map_err(|e| AppError::Internal(format!("Failed to open vector store: {}", e)))?;
+        let mut results = match as_of {
+            Some(as_of) => vector_store.search_similar_as_of(
+                &query_embedding,
+                &embedding_model,
+                filter.candidates(limit),
+                None,
+                root.as_deref(),
+                &as_of,
+            ),
+            None => vector_store.search_similar_in_branch(
+                &query_embedding,
+                &embedding_model,
+                filter.candidates(limit),
+                None,
+                root.as_deref(),
+            ),
+        }
+        .map_err(search_error)?;
+        wiki::boost_symbol_matches(&mut results, &symbol_query);
+        Ok::<_, AppError>(filter.apply(results, limit))
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("Task join error: {}", e)))??;
+
+    let duration_ms = start.elapsed().as_millis() as u64;
+
+    let total_count = results.len() as u32;
+    let search_results: Vec<WikiSearchResult> =
+        results.into_iter().map(WikiSearchResult::from).collect();
+
+    Ok(Json(WikiSearchResponse {
+        query: payload.query,
+        expanded_terms: expansion.added,
+        results: search_results,
+        total_count,
+        duration_ms,
+    }))
+}
+
+/// Answer given when the search finds nothing to answer from
+const NO_RELEVANT_CODE_ANSWER: &str =
+    "I couldn't find any relevant code in the indexed codebase to answer your question.";
+
+/// A question ready for the chat model, shared by the blocking and the
+/// streaming ask endpoints
+struct PreparedAsk {
+    openrouter: wiki::OpenRouterClient,
+    chat_model: String,
+    answer_mode: wiki::AnswerMode,
+    conversation_id: String,
+    mode: AskAnswerMode,
+    sources: Vec<AskSource>,
+    /// `None` when the search found nothing to answer from
+    messages: Option<Vec<wiki::ChatMessage>>,
+}
+
+async fn prepare_ask(state: &AppState, payload: AskRequest) -> Result<PreparedAsk, AppError> {
+    let mode = payload.mode.unwrap_or_default();
+    info!(question = %payload.question, mode = ?mode, "Asking wiki");
+
+    let project = state.project().await?;
+    let config = ProjectConfig::read(&project.project_path).await;
+
+    if !config.wiki.enabled {
+        return Err(AppError::BadRequest("Wiki is not enabled".to_string()));
+    }
+
+    let api_key = config
+        .wiki
+        .openrouter_api_key
+        .clone()
+        .ok_or_else(|| AppError::BadRequest("Wiki API key not configured".to_string()))?;
+    let embedding_model = config
+        .wiki
+        .embedding_model
+        .clone()
+        .unwrap_or_else(|| "openai/text-embedding-3-small".to_string());
+    let chat_model = current_user_preferences(state)
+        .await?
+        .ask_model
+        .or_else(|| config.wiki.chat_model.clone())
+        .unwrap_or_else(|| "anthropic/claude-3.5-sonnet".to_string());
+    let db_path = get_wiki_db_path(&project.project_path);
+    let question = payload.question;
+    let conversation_id = payload
+        .conversation_id
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+    let usage = wiki_usage(&project, &default_branch(&config.wiki));
+    let openrouter =
+        wiki::OpenRouterClient::new(api_key, "https://openrouter.ai/api/v1".to_string())
+            .with_usage_tracker(usage.clone());
+
+    let query_embedding = embedding_provider(&config.wiki, usage)?
+        .create_embedding(&question, &embedding_model)
+        .await
+        .map_err(|e| AppError::Internal(format!("Failed to create embedding: {}", e)))?;
+
+    let root = payload.root;
+    let search_results = tokio::task::spawn_blocking(move || {
+        let vector_store = wiki::VectorStore::open_read_only(&db_path)
+            .map_err(|e| AppError::Internal(format!("Failed to open vector store: {}", e)))?;
+        let results = vector_store
+            .search_similar_in_branch(
+                &query_embedding,
+                &embedding_model,
+                10,
+                None,
+                root.as_deref(),
+            )
+            .map_err(search_error)?;
+        CallGraphExpansion::default()
+            .expand(&vector_store, results)
+            .map_err(|e| AppError::Internal(format!("Context expansion failed: {}", e)))
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("Task join error: {}", e)))??;
+
+    let answer_mode = wiki::AnswerMode::from(mode);
+    let mut prepared = PreparedAsk {
+        openrouter,
+        chat_model,
+        answer_mode,
+        conversation_id,
+        mode,
+        sources: Vec::new(),
+        messages: None,
+    };
+    if search_results.is_empty() {
+        return Ok(prepared);
+    }
+
+    let context = build_rag_context(&search_results);
+    prepared.sources = search_results
+        .iter()
+        .map(|r| AskSource {
+            file_path: r.file_path.clone(),
+            start_line: r.start_line,
+            end_line: r.end_line,
+            score: r.score,
+            snippet: truncate_string(&r.content, 200),
+            summary: r.summary.clone(),
+            expansion: r.expansion.as_ref().map(|e| e.describe()),
+        })
+        .collect();
+    prepared.messages = Some(vec![
+        wiki::ChatMessage::system(answer_mode.system_prompt()),
+        wiki::ChatMessage::user(format_rag_prompt(&question, &context)),
+    ]);
+    Ok(prepared)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/wiki/ask",
+    request_body = AskRequest,
+    responses(
+        (status = 200, description = "RAG response", body = AskResponse),
+        (status = 400, description = "Invalid request"),
+        (status = 500, description = "Ask failed")
+    ),
+    tag = "wiki"
+)]
+pub async fn ask_wiki(
+    State(state): State<AppState>,
+    Json(payload): Json<AskRequest>,
+) -> Result<Json<AskResponse>, AppError> {
+    let prepared = prepare_ask(&state, payload).await?;
+
+    let answer = match prepared.messages {
+        Some(messages) => prepared
+            .openrouter
+            .chat_completion(
+                messages,
+                &prepared.chat_model,
+                Some(0.3),
+                Some(prepared.answer_mode.max_tokens()),
+            )
+            .await
+            .map_err(|e| AppError::Internal(format!("Chat completion failed: {}", e)))?,
+        None => NO_RELEVANT_CODE_ANSWER.to_string(),
+    };
+
+    Ok(Json(AskResponse {
+        answer,
+        sources: prepared.sources,
+        conversation_id: prepared.conversation_id,
+        mode: prepared.mode,
+    }))
+}
+
+/// Server-sent event of `/api/wiki/ask/stream`; the SSE event name is the
+/// `type` tag
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AskStreamEvent {
+    /// Sent first, before any of the answer
+    Sources {
+        conversation_id: String,
+        mode: AskAnswerMode,
+        sources: Vec<AskSource>,
+    },
+    /// The next piece of the answer
+    Token { content: String },
+    /// The answer is complete
+    Done { answer: String },
+    /// The completion failed; no further events follow
+    Error { message: String },
+}
+
+impl AskStreamEvent {
+    fn to_sse(&self) -> Result<SseEvent, Infallible> {
+        let name = match self {
+            Self::Sources { .. } => "sources",
+            Self::Token { .. } => "token",
+            Self::Done { .. } => "done",
+            Self::Error { .. } => "error",
+        };
+        let data = serde_json::to_string(self).unwrap_or_else(|_| "{}".to_string());
+        Ok(SseEvent::default().event(name).data(data))
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/wiki/ask/stream",
+    request_body = AskRequest,
+    responses(
+        (status = 200, description = "SSE stream of AskStreamEvent: sources, then tokens as the model writes them, then done or error"),
+        (status = 400, description = "Invalid request"),
+        (status = 500, description = "Ask failed")
+    ),
+    tag = "wiki"
+)]
+pub async fn ask_wiki_stream(
+    State(state): State<AppState>,
+    Json(payload): Json<AskRequest>,
+) -> Result<Sse<impl Stream<Item = Result<SseEvent, Infallible>>>, AppError> {
+    let prepared = prepare_ask(&state, payload).await?;
+    let (tx, rx) = tokio::sync::mpsc::channel::<AskStreamEvent>(32);
+
+    let _ = tx
+        .send(AskStreamEvent::Sources {
+            conversation_id: prepared.conversation_id.clone(),
+            mode: prepared.mode,
+            sources: prepared.sources,
+        })
+        .await;
+
+    tokio::spawn(async move {
+        let Some(messages) = prepared.messages else {
+            let answer = NO_RELEVANT_CODE_ANSWER.to_string();
+            let _ = tx
+                .send(AskStreamEvent::Token {
+                    content: answer.clone(),
+                })
+                .await;
+            let _ = tx.send(AskStreamEvent::Done { answer }).await;
+            return;
+        };
+
+        let stream = match prepared
+            .openrouter
+            .chat_completion_stream(
+                messages,
+                &prepared.chat_model,
+                Some(0.3),
+                Some(prepared.answer_mode.max_tokens()),
+            )
+            .await
+        {
+            Ok(stream) => stream,
+            Err(e) => {
+                error!(error = %e, "Streaming chat completion failed");
+                let _ = tx
+                    .send(AskStreamEvent::Error {
+                        message: format!("Chat completion failed: {}", e),
+                    })
+                    .await;
+                return;
+            }
+        };
+        tokio::pin!(stream);
+
+        let mut answer = String::new();
+        while let Some(piece) = stream.next().await {
+            let event = match piece {
+                Ok(content) => {
+                    answer.push_str(&content);
+                    AskStreamEvent::Token { content }
+                }
+                Err(e) => {
+                    error!(error = %e, "Chat completion stream failed");
+                    let _ = tx
+                        .send(AskStreamEvent::Error {
+                            message: format!("Chat completion failed: {}", e),
+                        })
+                        .await;
+                    return;
+                }
+            };
+            if tx.send(event).await.is_err() {
+                debug!("Ask stream client disconnected");
+                return;
+            }
+        }
+        let _ = tx.send(AskStreamEvent::Done { answer }).await;
+    });
+
+    let stream = tokio_stream::wrappers::ReceiverStream::new(rx).map(|event| event.to_sse());
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/wiki/trace-flow",
+    request_body = TraceFlowRequest,
+    responses(
+        (status = 200, description = "Step-by-step flow document for the request", body = TraceFlowResponse),
+        (status = 400, description = "Invalid request"),
+        (status = 404, description = "No route matches the request"),
+        (status = 500, description = "Flow tracing failed")
+    ),
+    tag = "wiki"
+)]
+pub async fn trace_flow(
+    State(state): State<AppState>,
+    Json(payload): Json<TraceFlowRequest>,
+) -> Result<Json<TraceFlowResponse>, AppError> {
+    info!(method = %payload.method, path = %payload.path, "Tracing request flow");
+
+    let project = state.project().await?;
+    let config = ProjectConfig::read(&project.project_path).await;
+
+    if !config.wiki.enabled {
+        return Err(AppError::BadRequest("Wiki is not enabled".to_string()));
+    }
+
+    let api_key = config
         .wiki
         .openrouter_api_key
         .clone()
         .ok_or_else(|| AppError::BadRequest("Wiki API key not configured".to_string()))?;
-    let embedding_model = config
-        .wiki
-        .embedding_model
-        .clone()
-        .unwrap_or_else(|| "openai/text-embedding-3-small".to_string());
     let chat_model = config
         .wiki
         .chat_model
         .clone()
         .unwrap_or_else(|| "anthropic/claude-3.5-sonnet".to_string());
+    let branch = payload.branch.clone().unwrap_or_else(|| {
+        config
+            .wiki
+            .branches
+            .first()
+            .cloned()
+            .unwrap_or_else(|| "main".to_string())
+    });
     let db_path = get_wiki_db_path(&project.project_path);
-    let question = payload.question.clone();
-    let conversation_id = payload
-        .conversation_id
-        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+    let chunks = {
+        let db_path = db_path.clone();
+        let branch = branch.clone();
+        tokio::task::spawn_blocking(move || {
+            wiki::VectorStore::new(&db_path)
+                .and_then(|store| store.get_branch_chunks(&branch))
+                .map_err(|e| AppError::Internal(format!("Failed to load indexed code: {}", e)))
+        })
+        .await
+        .map_err(|e| AppError::Internal(format!("Task join error: {}", e)))??
+    };
 
     let openrouter =
-        wiki::OpenRouterClient::new(api_key, "https://openrouter.ai/api/v1".to_string());
+        wiki::OpenRouterClient::new(api_key, "https://openrouter.ai/api/v1".to_string())
+            .with_usage_tracker(wiki_usage(&project, &branch));
+    let document = FlowTracer::new(&openrouter, chat_model)
+        .trace(&chunks, &payload.method, &payload.path)
+        .await
+        .map_err(|e| match e {
+            wiki::WikiError::RouteNotFound { .. } => AppError::NotFound(e.to_string()),
+            _ => AppError::Internal(format!("Flow tracing failed: {}", e)),
+        })?;
 
-    let query_embedding = openrouter
-        .create_embedding(&question, &embedding_model)
+    let page_slug = if payload.persist {
+        let document = document.clone();
+        let branch = branch.clone();
+        let page = tokio::task::spawn_blocking(move || {
+            wiki::VectorStore::new(&db_path)
+                .and_then(|store| wiki::flow::save_flow_page(&store, &branch, &document))
+                .map_err(|e| AppError::Internal(format!("Failed to save flow page: {}", e)))
+        })
         .await
-        .map_err(|e| AppError::Internal(format!("Failed to create embedding: {}", e)))?;
+        .map_err(|e| AppError::Internal(format!("Task join error: {}", e)))??;
+        Some(page.slug)
+    } else {
+        None
+    };
 
-    let search_results = tokio::task::spawn_blocking(move || {
-        let vector_store = wiki::VectorStore::new(&db_path)
-            .map_err(|e| AppError::Internal(format!("Failed to open vector store: {}", e)))?;
-        vector_store
-            .search_similar(&query_embedding, 10)
-            .map_err(|e| AppError::Internal(format!("Search failed: {}", e)))
-    })
-    .await
-    .map_err(|e| AppError::Internal(format!("Task join error: {}", e)))??;
+    Ok(Json(TraceFlowResponse {
+        branch,
+        route: WikiEndpoint::from(document.trace.endpoint),
+        steps: document
+            .trace
+            .steps
+            .into_iter()
+            .map(WikiFlowStep::from)
+            .collect(),
+        content: document.content,
+        page_slug,
+    }))
+}
 
-    if search_results.is_empty() {
-        return Ok(Json(AskResponse {
-            answer:
-                "I couldn't find any relevant code in the indexed codebase to answer your question."
-                    .to_string(),
-            sources: Vec::new(),
-            conversation_id,
-        }));
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{id}/wiki-updates",
+    params(
+        ("id" = String, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "Wiki pages updated or flagged after the task was merged", body = TaskWikiUpdatesResponse)
+    ),
+    tag = "wiki"
+)]
+pub async fn list_task_wiki_updates(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<TaskWikiUpdatesResponse>, AppError> {
+    let project = state.project().await?;
+    let repo = TaskWikiUpdateRepository::new(project.pool.clone());
+
+    let updates = repo.find_by_task_id(&id).await?;
+
+    Ok(Json(TaskWikiUpdatesResponse {
+        updates: updates
+            .into_iter()
+            .map(TaskWikiUpdateResponse::from)
+            .collect(),
+    }))
+}
+
+/// Update the wiki pages affected by a merged task in the background,
+/// according to the project's `post_merge_update` setting. `diff` is the
+/// task's diff against the main branch, captured before merging.
+pub async fn schedule_post_merge_update(
+    state: &AppState,
+    project: &ProjectContext,
+    task_id: Uuid,
+    diff: String,
+) {
+    let config = ProjectConfig::read(&project.project_path).await;
+
+    let action = match config.wiki.post_merge_update {
+        PostMergeWikiUpdate::Off => return,
+        PostMergeWikiUpdate::Suggest => PageUpdateAction::Suggested,
+        PostMergeWikiUpdate::Regenerate => PageUpdateAction::Regenerated,
+    };
+    if !config.wiki.enabled || config.wiki.openrouter_api_key.is_none() {
+        return;
+    }
+    if diff.trim().is_empty() {
+        debug!(task_id = %task_id, "Merged diff is empty, skipping wiki update");
+        return;
     }
 
-    let context = build_rag_context(&search_results);
-    let sources: Vec<AskSource> = search_results
-        .iter()
-        .map(|r| AskSource {
-            file_path: r.file_path.clone(),
-            start_line: r.start_line,
-            end_line: r.end_line,
-            score: r.score,
-            snippet: truncate_string(&r.content, 200),
-        })
-        .collect();
+    let branch = project.workspace_manager.vcs().main_branch().to_string();
+    if !config.wiki.branches.contains(&branch) {
+        debug!(branch = %branch, "Merge target is not indexed, skipping wiki update");
+        return;
+    }
 
-    let messages = vec![
-        wiki::ChatMessage::system(RAG_SYSTEM_PROMPT),
-        wiki::ChatMessage::user(format_rag_prompt(&question, &context)),
-    ];
+    let db_path = get_wiki_db_path(&project.project_path);
+    let repo = TaskWikiUpdateRepository::new(project.pool.clone());
+    let wiki_config = config.wiki;
+    let usage = project
+        .usage_store
+        .tracker(wiki::UsageScope::task(task_id.to_string()).with_wiki_branch(&branch));
+    let event_bus = state.event_bus.clone();
 
-    let answer = openrouter
-        .chat_completion(messages, &chat_model, Some(0.3), Some(2048))
+    tokio::spawn(async move {
+        match run_post_merge_update(
+            db_path,
+            wiki_config,
+            repo,
+            task_id,
+            branch.clone(),
+            diff,
+            action,
+            usage,
+        )
         .await
-        .map_err(|e| AppError::Internal(format!("Chat completion failed: {}", e)))?;
+        {
+            Ok(count) => {
+                info!(task_id = %task_id, pages = count, "Post-merge wiki update finished");
+                if count > 0 {
+                    event_bus.publish(events::EventEnvelope::new(
+                        events::Event::WikiDocsRefreshed {
+                            task_id,
+                            branch,
+                            pages: count as u32,
+                            action: action.as_str().to_string(),
+                        },
+                    ));
+                }
+            }
+            Err(e) => error!(task_id = %task_id, error = %e, "Post-merge wiki update failed"),
+        }
+    });
+}
 
-    Ok(Json(AskResponse {
-        answer,
-        sources,
-        conversation_id,
-    }))
+/// How long a merged branch waits for further merges before re-indexing, so a
+/// burst of merges shares one index run
+const MERGE_REINDEX_DEBOUNCE: Duration = Duration::from_secs(30);
+
+/// Incrementally re-index `branch` in the background after a merge changed
+/// it, once no other merge has landed on it for [`MERGE_REINDEX_DEBOUNCE`].
+/// Only branches the wiki indexes with `auto_sync` on are re-indexed; returns
+/// whether one was queued.
+pub async fn schedule_merge_reindex(
+    state: &AppState,
+    project: &ProjectContext,
+    branch: &str,
+) -> bool {
+    let config = ProjectConfig::read(&project.project_path).await;
+    if !config.wiki.enabled || !config.wiki.auto_sync {
+        return false;
+    }
+    if !config.wiki.branches.iter().any(|b| b == branch) {
+        debug!(branch = %branch, "Merge target is not indexed, skipping re-index");
+        return false;
+    }
+
+    let project_path = project.project_path.clone();
+    let ticket = state
+        .merge_reindex
+        .lock()
+        .unwrap()
+        .schedule(&project_path, branch);
+    let queue = state.merge_reindex.clone();
+    let event_bus = state.event_bus.clone();
+    let usage = wiki_usage(project, branch);
+    let branch = branch.to_string();
+
+    tokio::spawn(async move {
+        let db_path = get_wiki_db_path(&project_path);
+        let lock = loop {
+            tokio::time::sleep(MERGE_REINDEX_DEBOUNCE).await;
+            let latest = queue
+                .lock()
+                .unwrap()
+                .is_latest(&project_path, &branch, ticket);
+            if !latest {
+                debug!(branch = %branch, "Re-index superseded by a later merge");
+                return;
+            }
+            match lock_branch(&db_path, &branch, "indexing") {
+                Ok(BranchLockAttempt::Acquired(lock)) => break lock,
+                Ok(BranchLockAttempt::Held(held)) => {
+                    debug!(branch = %branch, job_id = %held.job_id, "Branch is busy, retrying re-index later");
+                }
+                Err(e) => {
+                    warn!(branch = %branch, error = ?e, "Failed to queue merge re-index");
+                    return;
+                }
+            }
+        };
+        queue.lock().unwrap().finish(&project_path, &branch, ticket);
+
+        // Settings may have changed while the re-index waited
+        let wiki_config = ProjectConfig::read(&project_path).await.wiki;
+        std::thread::spawn(move || {
+            let _job = job_span(&lock).entered();
+            let job_id = lock.job_id.clone();
+            let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+            rt.block_on(async {
+                let _lock = BranchLockGuard::new(&db_path, lock);
+                match run_code_indexing(project_path, wiki_config, branch.clone(), false, usage)
+                    .await
+                {
+                    Ok(status) => {
+                        event_bus.publish(events::EventEnvelope::new(
+                            events::Event::WikiIndexRefreshed {
+                                branch,
+                                commit_sha: status.last_commit_sha,
+                                chunk_count: status.chunk_count,
+                            },
+                        ));
+                    }
+                    Err(e) => {
+                        error!(branch = %branch, error = %e, "Merge re-index failed");
+                        link_job_logs(&db_path, &branch, &job_id);
+                    }
+                }
+            });
+        });
+    });
+
+    true
 }
 
-const RAG_SYSTEM_PROMPT: &str = r#"You are a knowledgeable code assistant helping developers understand a codebase.
-When answering:
-- Reference specific files and line numbers when relevant (format: `file_path:line_number`)
-- Provide concise but complete explanations
-- Include code examples when helpful
-- If the context doesn't contain enough information, say so clearly
-- Don't make up information that's not in the provided context"#;
+#[allow(clippy::too_many_arguments)]
+async fn run_post_merge_update(
+    db_path: PathBuf,
+    wiki_config: ProjectWikiConfig,
+    repo: TaskWikiUpdateRepository,
+    task_id: Uuid,
+    branch: String,
+    diff: String,
+    action: PageUpdateAction,
+    usage: wiki::UsageTracker,
+) -> Result<usize, wiki::WikiError> {
+    let api_key = wiki_config
+        .openrouter_api_key
+        .ok_or_else(|| wiki::WikiError::InvalidConfig("Wiki API key not configured".to_string()))?;
+    let chat_model = wiki_config
+        .chat_model
+        .unwrap_or_else(|| "anthropic/claude-3.5-sonnet".to_string());
+
+    let pages = {
+        let db_path = db_path.clone();
+        let branch = branch.clone();
+        tokio::task::spawn_blocking(move || {
+            wiki::VectorStore::new(&db_path)?.get_branch_pages(&branch)
+        })
+        .await
+        .map_err(|e| wiki::WikiError::GenerationFailed(format!("Task join error: {}", e)))??
+    };
+
+    let openrouter =
+        wiki::OpenRouterClient::new(api_key, "https://openrouter.ai/api/v1".to_string())
+            .with_usage_tracker(usage);
+    let updates = PageUpdater::new(&openrouter, chat_model)
+        .update(&pages, &diff, action)
+        .await?;
+
+    if action == PageUpdateAction::Regenerated {
+        let updates = updates.clone();
+        let branch = branch.clone();
+        tokio::task::spawn_blocking(move || {
+            let store = wiki::VectorStore::new(&db_path)?;
+            wiki::page_updates::apply_page_updates(&store, &branch, &updates)
+        })
+        .await
+        .map_err(|e| wiki::WikiError::GenerationFailed(format!("Task join error: {}", e)))??;
+    }
+
+    let task_id = task_id.to_string();
+    for update in &updates {
+        if let Err(e) = repo
+            .create(
+                &task_id,
+                &branch,
+                &update.slug,
+                &update.title,
+                update.action.as_str(),
+                &update.changed_files,
+                &update.content,
+            )
+            .await
+        {
+            warn!(page = %update.slug, error = %e, "Failed to record wiki update on task");
+        }
+    }
+
+    Ok(updates.len())
+}
 
 fn build_rag_context(results: &[SearchResult]) -> String {
     let mut context = String::new();
     for (i, result) in results.iter().enumerate() {
+        let note = result
+            .expansion
+            .as_ref()
+            .map(|e| format!(" [{}]", e.describe()))
+            .unwrap_or_default();
         context.push_str(&format!(
-            "\n--- Source {}: {} (lines {}-{}) ---\n",
+            "\n--- Source {}: {} (lines {}-{}){} ---\n",
             i + 1,
             result.file_path,
             result.start_line,
-            result.end_line
+            result.end_line,
+            note
         ));
+        if let Some(ref summary) = result.summary {
+            context.push_str(&format!("Summary: {}\n", summary));
+        }
         if let Some(ref lang) = result.language {
             context.push_str(&format!("```{}\n{}\n```\n", lang, result.content));
         } else {
@@ -1382,22 +3556,42 @@ pub async fn handle_push_webhook(
         }));
     }
 
+    let db_path = get_wiki_db_path(&project.project_path);
+    let lock = match lock_branch(&db_path, &branch, "indexing")? {
+        BranchLockAttempt::Acquired(lock) => lock,
+        BranchLockAttempt::Held(held) => {
+            return Ok(Json(WebhookResponse {
+                accepted: false,
+                message: already_running_message(&held),
+            }));
+        }
+    };
+
     let project_path = project.project_path.clone();
     let wiki_config = config.wiki.clone();
     let branch_clone = branch.clone();
     let event_bus = state.event_bus.clone();
+    let usage = wiki_usage(&project, &branch);
 
     std::thread::spawn(move || {
+        let _job = job_span(&lock).entered();
+        let job_id = lock.job_id.clone();
         let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
-        if let Err(e) = rt.block_on(run_full_indexing(
-            project_path,
-            wiki_config,
-            branch_clone,
-            true,
-            GenerationMode::default(),
-            Some(event_bus),
-        )) {
+        if let Err(e) = rt.block_on(async {
+            let _lock = BranchLockGuard::new(&db_path, lock);
+            run_full_indexing(
+                project_path,
+                wiki_config,
+                branch_clone.clone(),
+                true,
+                GenerationMode::default(),
+                Some(event_bus),
+                usage,
+            )
+            .await
+        }) {
             error!(error = %e, "Auto-sync indexing failed");
+            link_job_logs(&db_path, &branch_clone, &job_id);
         }
     });
 
@@ -1407,6 +3601,54 @@ pub async fn handle_push_webhook(
     }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/wiki/webhook/pull-request",
+    request_body = WebhookPullRequestEvent,
+    responses(
+        (status = 200, description = "Webhook processed", body = WebhookResponse),
+        (status = 400, description = "Invalid request")
+    ),
+    tag = "wiki"
+)]
+pub async fn handle_pull_request_webhook(
+    State(state): State<AppState>,
+    Json(payload): Json<WebhookPullRequestEvent>,
+) -> Result<Json<WebhookResponse>, AppError> {
+    let pr = payload.pull_request;
+    info!(action = %payload.action, pr = pr.number, merged = pr.merged, "Received pull request webhook");
+
+    if payload.action != "closed" || !pr.merged {
+        return Ok(Json(WebhookResponse {
+            accepted: false,
+            message: format!("Pull request #{} was not merged", pr.number),
+        }));
+    }
+
+    let project = state.project().await?;
+    let branch = pr
+        .base
+        .git_ref
+        .strip_prefix("refs/heads/")
+        .unwrap_or(&pr.base.git_ref)
+        .to_string();
+
+    if !schedule_merge_reindex(&state, &project, &branch).await {
+        return Ok(Json(WebhookResponse {
+            accepted: false,
+            message: format!("Auto-sync does not index branch '{}'", branch),
+        }));
+    }
+
+    Ok(Json(WebhookResponse {
+        accepted: true,
+        message: format!(
+            "Re-index of branch '{}' queued after pull request #{}",
+            branch, pr.number
+        ),
+    }))
+}
+
 #[utoipa::path(
     get,
     path = "/api/settings/wiki",
@@ -1432,6 +3674,24 @@ pub async fn get_wiki_settings(
         auto_sync: config.wiki.auto_sync,
         repo_url: config.wiki.repo_url,
         has_access_token: config.wiki.access_token.is_some(),
+        post_merge_update: config.wiki.post_merge_update,
+        review_context: config.wiki.review_context,
+        review_context_max_tokens: config.wiki.review_context_max_tokens,
+        planning_context: config.wiki.planning_context,
+        planning_context_max_tokens: config.wiki.planning_context_max_tokens,
+        max_pages: config.wiki.max_pages,
+        max_pages_per_section: config.wiki.max_pages_per_section,
+        min_page_importance: config.wiki.min_page_importance,
+        page_concurrency: config.wiki.page_concurrency,
+        embedding_concurrency: config.wiki.embedding_concurrency,
+        chunk_summaries: config.wiki.chunk_summaries,
+        roots: config.wiki.roots,
+        embedding_provider: config.wiki.embedding_provider,
+        has_openai_api_key: config.wiki.openai_api_key.is_some(),
+        local_embedding_model_path: config.wiki.local_embedding_model_path,
+        embedding_dimension: config.wiki.embedding_dimension,
+        history_generations: config.wiki.history_generations,
+        chunk_tuning: config.wiki.chunk_tuning,
     }))
 }
 
@@ -1491,6 +3751,82 @@ pub async fn update_wiki_settings(
         };
     }
 
+    if let Some(post_merge_update) = payload.post_merge_update {
+        config.wiki.post_merge_update = post_merge_update;
+    }
+
+    if let Some(review_context) = payload.review_context {
+        config.wiki.review_context = review_context;
+    }
+
+    if let Some(max_tokens) = payload.review_context_max_tokens {
+        config.wiki.review_context_max_tokens = max_tokens;
+    }
+
+    if let Some(planning_context) = payload.planning_context {
+        config.wiki.planning_context = planning_context;
+    }
+
+    if let Some(max_tokens) = payload.planning_context_max_tokens {
+        config.wiki.planning_context_max_tokens = max_tokens;
+    }
+
+    if let Some(max_pages) = payload.max_pages {
+        config.wiki.max_pages = max_pages;
+    }
+
+    if let Some(max_pages) = payload.max_pages_per_section {
+        config.wiki.max_pages_per_section = max_pages;
+    }
+
+    if let Some(importance) = payload.min_page_importance {
+        config.wiki.min_page_importance = importance;
+    }
+
+    if let Some(page_concurrency) = payload.page_concurrency {
+        config.wiki.page_concurrency = page_concurrency.max(1);
+    }
+
+    if let Some(embedding_concurrency) = payload.embedding_concurrency {
+        config.wiki.embedding_concurrency = embedding_concurrency.max(1);
+    }
+
+    if let Some(chunk_summaries) = payload.chunk_summaries {
+        config.wiki.chunk_summaries = chunk_summaries;
+    }
+
+    if let Some(roots) = payload.roots {
+        config.wiki.roots = roots
+            .iter()
+            .map(|root| wiki::normalize_root(root))
+            .filter(|root| !root.is_empty())
+            .collect();
+    }
+
+    if let Some(provider) = payload.embedding_provider {
+        config.wiki.embedding_provider = provider;
+    }
+
+    if let Some(api_key) = payload.openai_api_key {
+        config.wiki.openai_api_key = if api_key.is_empty() {
+            None
+        } else {
+            Some(api_key)
+        };
+    }
+
+    if let Some(path) = payload.local_embedding_model_path {
+        config.wiki.local_embedding_model_path = if path.is_empty() { None } else { Some(path) };
+    }
+
+    if let Some(dimension) = payload.embedding_dimension {
+        config.wiki.embedding_dimension = (dimension > 0).then_some(dimension);
+    }
+
+    if let Some(generations) = payload.history_generations {
+        config.wiki.history_generations = generations;
+    }
+
     config.write(&project.project_path).await.map_err(|e| {
         error!(error = %e, "Failed to save wiki config");
         AppError::Internal(format!("Failed to save settings: {}", e))
@@ -1507,5 +3843,23 @@ pub async fn update_wiki_settings(
         auto_sync: config.wiki.auto_sync,
         repo_url: config.wiki.repo_url,
         has_access_token: config.wiki.access_token.is_some(),
+        post_merge_update: config.wiki.post_merge_update,
+        review_context: config.wiki.review_context,
+        review_context_max_tokens: config.wiki.review_context_max_tokens,
+        planning_context: config.wiki.planning_context,
+        planning_context_max_tokens: config.wiki.planning_context_max_tokens,
+        max_pages: config.wiki.max_pages,
+        max_pages_per_section: config.wiki.max_pages_per_section,
+        min_page_importance: config.wiki.min_page_importance,
+        page_concurrency: config.wiki.page_concurrency,
+        embedding_concurrency: config.wiki.embedding_concurrency,
+        chunk_summaries: config.wiki.chunk_summaries,
+        roots: config.wiki.roots,
+        embedding_provider: config.wiki.embedding_provider,
+        has_openai_api_key: config.wiki.openai_api_key.is_some(),
+        local_embedding_model_path: config.wiki.local_embedding_model_path,
+        embedding_dimension: config.wiki.embedding_dimension,
+        history_generations: config.wiki.history_generations,
+        chunk_tuning: config.wiki.chunk_tuning,
     }))
 }
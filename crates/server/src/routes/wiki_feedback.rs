@@ -0,0 +1,304 @@
+//! Feedback on wiki answers
+//!
+//! Users rate answers from `/api/wiki/ask` up or down. Each rating is kept
+//! with the question, answer and retrieved sources; code ranges that keep
+//! turning up in rated-down answers are flagged for re-chunking, and the wiki
+//! pages citing them for regeneration.
+
+use std::collections::BTreeSet;
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+use utoipa::ToSchema;
+use wiki::page_updates::affected_pages;
+use wiki::{AskFeedback, AskRating, FeedbackSource, SourceVotes, VectorStore};
+
+use crate::config::ProjectConfig;
+use crate::error::AppError;
+use crate::routes::wiki::get_wiki_db_path;
+use crate::state::AppState;
+
+/// Rated-down answers listed in the feedback stats
+const RECENT_NEGATIVE_LIMIT: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+#[serde(rename_all = "lowercase")]
+pub enum AskFeedbackRating {
+    Up,
+    Down,
+}
+
+impl From<AskFeedbackRating> for AskRating {
+    fn from(rating: AskFeedbackRating) -> Self {
+        match rating {
+            AskFeedbackRating::Up => AskRating::Up,
+            AskFeedbackRating::Down => AskRating::Down,
+        }
+    }
+}
+
+impl From<AskRating> for AskFeedbackRating {
+    fn from(rating: AskRating) -> Self {
+        match rating {
+            AskRating::Up => AskFeedbackRating::Up,
+            AskRating::Down => AskFeedbackRating::Down,
+        }
+    }
+}
+
+/// Code range an answer was built from, as returned in `AskResponse.sources`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct AskFeedbackSource {
+    pub file_path: String,
+    pub start_line: u32,
+    pub end_line: u32,
+}
+
+impl From<FeedbackSource> for AskFeedbackSource {
+    fn from(source: FeedbackSource) -> Self {
+        Self {
+            file_path: source.file_path,
+            start_line: source.start_line,
+            end_line: source.end_line,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct AskFeedbackRequest {
+    pub rating: AskFeedbackRating,
+    pub question: String,
+    pub answer: String,
+    #[serde(default)]
+    pub sources: Vec<AskFeedbackSource>,
+    /// What was wrong or right about the answer
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct AskFeedbackResponse {
+    pub id: String,
+    pub conversation_id: String,
+    pub rating: AskFeedbackRating,
+    pub question: String,
+    pub answer: String,
+    pub sources: Vec<AskFeedbackSource>,
+    pub comment: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<AskFeedback> for AskFeedbackResponse {
+    fn from(feedback: AskFeedback) -> Self {
+        Self {
+            id: feedback.id,
+            conversation_id: feedback.conversation_id,
+            rating: feedback.rating.into(),
+            question: feedback.question,
+            answer: feedback.answer,
+            sources: feedback.sources.into_iter().map(Into::into).collect(),
+            comment: feedback.comment,
+            created_at: feedback.created_at,
+        }
+    }
+}
+
+/// A code range mostly seen in rated-down answers; a candidate for re-chunking
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct FlaggedSourceResponse {
+    pub file_path: String,
+    pub start_line: u32,
+    pub end_line: u32,
+    pub positive: u32,
+    pub negative: u32,
+}
+
+impl From<SourceVotes> for FlaggedSourceResponse {
+    fn from(votes: SourceVotes) -> Self {
+        Self {
+            file_path: votes.source.file_path,
+            start_line: votes.source.start_line,
+            end_line: votes.source.end_line,
+            positive: votes.positive,
+            negative: votes.negative,
+        }
+    }
+}
+
+/// A wiki page documenting flagged sources; a candidate for regeneration
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct FlaggedPageResponse {
+    pub slug: String,
+    pub title: String,
+    /// Flagged files the page documents or cites
+    pub files: Vec<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct WikiFeedbackStatsResponse {
+    pub total: u32,
+    pub positive: u32,
+    pub negative: u32,
+    /// Most negative votes first
+    pub flagged_sources: Vec<FlaggedSourceResponse>,
+    /// Pages of `branch` citing flagged sources, most affected first
+    pub flagged_pages: Vec<FlaggedPageResponse>,
+    pub branch: String,
+    /// Latest rated-down answers, newest first
+    pub recent_negative: Vec<AskFeedbackResponse>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/wiki/ask/{conversation_id}/feedback",
+    params(
+        ("conversation_id" = String, Path, description = "Conversation ID returned by the ask endpoint")
+    ),
+    request_body = AskFeedbackRequest,
+    responses(
+        (status = 201, description = "Feedback recorded, replacing an earlier rating of the same question", body = AskFeedbackResponse),
+        (status = 400, description = "Missing question or wiki not enabled")
+    ),
+    tag = "wiki"
+)]
+pub async fn submit_ask_feedback(
+    State(state): State<AppState>,
+    Path(conversation_id): Path<String>,
+    Json(payload): Json<AskFeedbackRequest>,
+) -> Result<(StatusCode, Json<AskFeedbackResponse>), AppError> {
+    if payload.question.trim().is_empty() {
+        return Err(AppError::BadRequest("Question is required".to_string()));
+    }
+
+    let project = state.project().await?;
+    let config = ProjectConfig::read(&project.project_path).await;
+    if !config.wiki.enabled {
+        return Err(AppError::BadRequest("Wiki is not enabled".to_string()));
+    }
+
+    info!(
+        conversation_id = %conversation_id,
+        rating = ?payload.rating,
+        "Recording wiki answer feedback"
+    );
+
+    let sources = payload
+        .sources
+        .into_iter()
+        .map(|s| FeedbackSource {
+            file_path: s.file_path,
+            start_line: s.start_line,
+            end_line: s.end_line,
+        })
+        .collect();
+    let mut feedback = AskFeedback::new(
+        conversation_id,
+        payload.rating.into(),
+        payload.question,
+        payload.answer,
+        sources,
+    );
+    if let Some(comment) = payload.comment.filter(|c| !c.trim().is_empty()) {
+        feedback = feedback.with_comment(comment);
+    }
+
+    let db_path = get_wiki_db_path(&project.project_path);
+    let feedback = tokio::task::spawn_blocking(move || {
+        let vector_store = VectorStore::new(&db_path)
+            .map_err(|e| AppError::Internal(format!("Failed to open vector store: {}", e)))?;
+        vector_store
+            .record_ask_feedback(&feedback)
+            .map_err(|e| AppError::Internal(format!("Failed to store feedback: {}", e)))?;
+        Ok::<_, AppError>(feedback)
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("Task join error: {}", e)))??;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(AskFeedbackResponse::from(feedback)),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/wiki-feedback",
+    responses(
+        (status = 200, description = "Feedback totals with the sources and pages it flags", body = WikiFeedbackStatsResponse)
+    ),
+    tag = "admin"
+)]
+pub async fn get_wiki_feedback_stats(
+    State(state): State<AppState>,
+) -> Result<Json<WikiFeedbackStatsResponse>, AppError> {
+    let project = state.project().await?;
+    let config = ProjectConfig::read(&project.project_path).await;
+    let branch = config
+        .wiki
+        .branches
+        .first()
+        .cloned()
+        .unwrap_or_else(|| "main".to_string());
+
+    let db_path = get_wiki_db_path(&project.project_path);
+    let page_branch = branch.clone();
+    let (stats, recent_negative, pages) = tokio::task::spawn_blocking(move || {
+        let vector_store = VectorStore::new(&db_path)
+            .map_err(|e| AppError::Internal(format!("Failed to open vector store: {}", e)))?;
+        let stats = vector_store
+            .ask_feedback_stats()
+            .map_err(|e| AppError::Internal(format!("Failed to read feedback: {}", e)))?;
+        let recent_negative = vector_store
+            .list_ask_feedback(Some(AskRating::Down), RECENT_NEGATIVE_LIMIT)
+            .map_err(|e| AppError::Internal(format!("Failed to read feedback: {}", e)))?;
+        let pages = vector_store
+            .get_branch_pages(&page_branch)
+            .map_err(|e| AppError::Internal(format!("Failed to read wiki pages: {}", e)))?;
+        Ok::<_, AppError>((stats, recent_negative, pages))
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("Task join error: {}", e)))??;
+
+    let flagged_files: Vec<String> = stats
+        .flagged_sources
+        .iter()
+        .map(|votes| votes.source.file_path.clone())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let flagged_pages = affected_pages(&pages, &flagged_files)
+        .into_iter()
+        .map(|affected| FlaggedPageResponse {
+            slug: affected.page.slug.clone(),
+            title: affected.page.title.clone(),
+            files: affected.changed_files,
+        })
+        .collect();
+
+    Ok(Json(WikiFeedbackStatsResponse {
+        total: stats.total,
+        positive: stats.positive,
+        negative: stats.negative,
+        flagged_sources: stats.flagged_sources.into_iter().map(Into::into).collect(),
+        flagged_pages,
+        branch,
+        recent_negative: recent_negative.into_iter().map(Into::into).collect(),
+    }))
+}
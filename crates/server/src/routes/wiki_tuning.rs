@@ -0,0 +1,186 @@
+//! Chunk size tuning for the project's wiki index
+//!
+//! Measures retrieval quality on a sample of the repository at several chunk
+//! sizes and, when asked to, stores the best setting in the project config so
+//! the next indexing run splits files with it.
+
+use axum::extract::State;
+use axum::Json;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{error, info};
+use utoipa::ToSchema;
+use wiki::{ChunkSetting, ChunkSettingScore, ChunkTuner, ChunkTuningReport, UsageScope};
+
+use crate::config::{ProjectConfig, WikiChunkTuning};
+use crate::error::AppError;
+use crate::state::AppState;
+
+/// Files sampled at most, whatever the request asks for
+const MAX_TUNING_FILES: u32 = 200;
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct ChunkTuningRequest {
+    /// Files to sample, at most 200 (default: 40)
+    #[serde(default)]
+    pub max_files: Option<u32>,
+    /// Store the recommended setting for the next indexing run
+    #[serde(default)]
+    pub apply: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct ChunkSettingResponse {
+    pub max_chunk_tokens: u32,
+    pub chunk_overlap: u32,
+}
+
+impl From<ChunkSetting> for ChunkSettingResponse {
+    fn from(setting: ChunkSetting) -> Self {
+        Self {
+            max_chunk_tokens: setting.max_chunk_tokens as u32,
+            chunk_overlap: setting.chunk_overlap as u32,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct ChunkSettingScoreResponse {
+    pub setting: ChunkSettingResponse,
+    /// Chunks the sample was split into
+    pub chunks: u32,
+    /// Share of queries that found their definition among the top 5 chunks
+    pub hit_rate: f64,
+    pub mean_reciprocal_rank: f64,
+}
+
+impl From<ChunkSettingScore> for ChunkSettingScoreResponse {
+    fn from(score: ChunkSettingScore) -> Self {
+        Self {
+            setting: score.setting.into(),
+            chunks: score.chunks as u32,
+            hit_rate: score.hit_rate,
+            mean_reciprocal_rank: score.mean_reciprocal_rank,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct ChunkTuningResponse {
+    /// Files sampled
+    pub files: u32,
+    /// Function definitions searched for
+    pub queries: u32,
+    pub embedding_model: String,
+    pub scores: Vec<ChunkSettingScoreResponse>,
+    pub recommended: ChunkSettingResponse,
+    /// Whether the recommended setting was stored for the next indexing run
+    pub applied: bool,
+}
+
+impl ChunkTuningResponse {
+    fn new(report: ChunkTuningReport, applied: bool) -> Self {
+        Self {
+            files: report.files as u32,
+            queries: report.queries as u32,
+            embedding_model: report.embedding_model,
+            scores: report.scores.into_iter().map(Into::into).collect(),
+            recommended: report.recommended.into(),
+            applied,
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/wiki/chunk-tuning",
+    request_body = ChunkTuningRequest,
+    responses(
+        (status = 200, description = "Retrieval quality at each chunk setting and the recommended one", body = ChunkTuningResponse),
+        (status = 400, description = "Embedding provider not configured or too little code to tune on"),
+        (status = 500, description = "Failed to read the repository or save the config")
+    ),
+    tag = "wiki"
+)]
+pub async fn tune_wiki_chunks(
+    State(state): State<AppState>,
+    Json(payload): Json<ChunkTuningRequest>,
+) -> Result<Json<ChunkTuningResponse>, AppError> {
+    let project = state.project().await?;
+    let mut config = ProjectConfig::read(&project.project_path).await;
+
+    let embeddings = config
+        .wiki
+        .embedding_provider_config()
+        .build_tracked(Some(project.usage_store.tracker(UsageScope::default())))
+        .map_err(|e| AppError::BadRequest(format!("Embedding provider unavailable: {}", e)))?;
+    let embedding_model = config
+        .wiki
+        .embedding_model
+        .clone()
+        .unwrap_or_else(|| "openai/text-embedding-3-small".to_string());
+    let max_files = payload
+        .max_files
+        .unwrap_or(wiki::DEFAULT_TUNING_FILES as u32)
+        .clamp(1, MAX_TUNING_FILES);
+
+    let project_path = project.project_path.clone();
+    let sampler = Arc::clone(&embeddings);
+    let sampled_model = embedding_model.clone();
+    let roots = config.wiki.roots.clone();
+    let sample = tokio::task::spawn_blocking(move || {
+        ChunkTuner::new(sampler.as_ref(), sampled_model)
+            .with_max_files(max_files as usize)
+            .with_roots(&roots)
+            .sample(&project_path)
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("Sampling task failed: {}", e)))?
+    .map_err(|e| AppError::Internal(format!("Failed to sample the repository: {}", e)))?;
+
+    let tuner = ChunkTuner::new(embeddings.as_ref(), embedding_model.clone());
+    let report = tuner.tune(&sample).await.map_err(|e| match e {
+        wiki::WikiError::IndexingFailed(message) => AppError::BadRequest(message),
+        e => AppError::Internal(format!("Chunk tuning failed: {}", e)),
+    })?;
+
+    if payload.apply {
+        let best = report
+            .scores
+            .iter()
+            .find(|s| s.setting == report.recommended)
+            .map(|s| s.mean_reciprocal_rank)
+            .unwrap_or_default();
+        config.wiki.chunk_tuning = Some(WikiChunkTuning {
+            max_chunk_tokens: report.recommended.max_chunk_tokens as u32,
+            chunk_overlap: report.recommended.chunk_overlap as u32,
+            mean_reciprocal_rank: best,
+            embedding_model: embedding_model.clone(),
+            tuned_at: Utc::now().timestamp(),
+        });
+        config.write(&project.project_path).await.map_err(|e| {
+            error!(error = %e, "Failed to save chunk tuning");
+            AppError::Internal(format!("Failed to save settings: {}", e))
+        })?;
+    }
+
+    info!(
+        files = report.files,
+        queries = report.queries,
+        max_chunk_tokens = report.recommended.max_chunk_tokens,
+        chunk_overlap = report.recommended.chunk_overlap,
+        applied = payload.apply,
+        "Tuned wiki chunk size"
+    );
+
+    Ok(Json(ChunkTuningResponse::new(report, payload.apply)))
+}
@@ -1,13 +1,25 @@
-use axum::extract::{Path, State};
-use axum::http::StatusCode;
+use axum::extract::{Path, Query, State};
+use axum::http::{StatusCode, Uri};
+use axum::response::Response;
 use axum::Json;
 use db::DiffViewedRepository;
+use github::CreatePrRequest;
+use orchestrator::{FindingStatus, ReviewFinding, ReviewFindings};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use tracing::{debug, warn};
 use utoipa::ToSchema;
 use uuid::Uuid;
-use vcs::{MergeResult, Workspace};
+use vcs::{ConflictHunk, ConflictSide, ConflictType, MergeResult, Workspace, WorkspaceManager};
+use wiki::{ChangeRisk, ChangeSummarizer, ChangeSummary, FileChangeSummary};
 
+use crate::config::ProjectConfig;
 use crate::error::AppError;
+use crate::routes::complete::{generate_pr_body, open_pull_request, start_ci_gate, PrInfo};
+use crate::routes::pagination::{list_response, paginate, ListParams};
+use crate::routes::releases::record_merge;
+use crate::routes::wiki::{get_wiki_db_path, schedule_merge_reindex, schedule_post_merge_update};
 use crate::state::AppState;
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -73,17 +85,42 @@ pub async fn create_workspace_for_task(
 #[utoipa::path(
     get,
     path = "/api/workspaces",
+    params(
+        ("limit" = Option<u32>, Query, description = "Maximum number of items (max: 500; default: all)"),
+        ("offset" = Option<u32>, Query, description = "Number of items to skip"),
+        ("sort" = Option<String>, Query, description = "Field to sort by, prefixed with '-' for descending order (task_id, branch_name, status or created_at)"),
+        ("fields" = Option<String>, Query, description = "Comma-separated fields to return (default: all)")
+    ),
     responses(
-        (status = 200, description = "List of all workspaces", body = Vec<WorkspaceResponse>)
+        (status = 200, description = "List of workspaces", body = Vec<WorkspaceResponse>, headers(
+            ("X-Total-Count" = u64, description = "Number of items across all pages"),
+            ("Link" = String, description = "Links to the first, previous, next and last pages")
+        ))
     ),
     tag = "workspaces"
 )]
 pub async fn list_workspaces(
     State(state): State<AppState>,
-) -> Result<Json<Vec<WorkspaceResponse>>, AppError> {
+    Query(params): Query<HashMap<String, String>>,
+    uri: Uri,
+) -> Result<Response, AppError> {
+    let params = ListParams::from_query(&params)?;
     let project = state.project().await?;
-    let workspaces = project.workspace_manager.list_workspaces().await?;
-    Ok(Json(workspaces.into_iter().map(Into::into).collect()))
+    let workspaces: Vec<WorkspaceResponse> = project
+        .workspace_manager
+        .list_workspaces()
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    // Workspaces come from the VCS rather than the database
+    let page = paginate(
+        workspaces,
+        &params.page,
+        &["task_id", "branch_name", "status", "created_at"],
+    );
+    list_response(&uri, page, &params)
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -92,6 +129,9 @@ pub async fn list_workspaces(
 pub struct DiffResponse {
     pub task_id: String,
     pub diff: String,
+    /// Files a merge left conflicted, with their conflicted regions; empty
+    /// unless the workspace holds an unresolved merge
+    pub conflicts: Vec<ConflictedFileResponse>,
 }
 
 #[utoipa::path(
@@ -101,7 +141,7 @@ pub struct DiffResponse {
         ("task_id" = String, Path, description = "Task ID")
     ),
     responses(
-        (status = 200, description = "Workspace diff", body = DiffResponse),
+        (status = 200, description = "Workspace diff and the files a merge left conflicted", body = DiffResponse),
         (status = 404, description = "Workspace not found")
     ),
     tag = "workspaces"
@@ -119,13 +159,176 @@ pub async fn get_workspace_diff(
         .ok_or_else(|| AppError::NotFound(format!("Workspace not found: {}", task_id)))?;
 
     let diff = project.workspace_manager.get_diff(&workspace).await?;
+    let conflicts = conflicts_response(&project.workspace_manager, &workspace)
+        .await?
+        .files;
 
     Ok(Json(DiffResponse {
         task_id: workspace.task_id,
         diff,
+        conflicts,
     }))
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct DiffSummaryResponse {
+    pub task_id: String,
+    /// Head commit the summary describes, with a digest of the diff appended
+    /// when the workspace has uncommitted changes
+    pub head_commit: String,
+    /// Whether the summary was cached from an earlier request
+    pub cached: bool,
+    /// Per-file summaries, in diff order
+    pub files: Vec<DiffFileSummary>,
+    /// Changes a reviewer should look at closely
+    pub risks: Vec<DiffRisk>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct DiffFileSummary {
+    pub file_path: String,
+    pub bullets: Vec<String>,
+}
+
+impl From<FileChangeSummary> for DiffFileSummary {
+    fn from(file: FileChangeSummary) -> Self {
+        Self {
+            file_path: file.file_path,
+            bullets: file.bullets,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct DiffRisk {
+    pub file_path: String,
+    pub description: String,
+}
+
+impl From<ChangeRisk> for DiffRisk {
+    fn from(risk: ChangeRisk) -> Self {
+        Self {
+            file_path: risk.file_path,
+            description: risk.description,
+        }
+    }
+}
+
+impl DiffSummaryResponse {
+    fn new(task_id: String, summary: ChangeSummary, cached: bool) -> Self {
+        Self {
+            task_id,
+            head_commit: summary.head_commit,
+            cached,
+            files: summary.files.into_iter().map(Into::into).collect(),
+            risks: summary.risks.into_iter().map(Into::into).collect(),
+            created_at: summary.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/workspaces/{task_id}/diff/summary",
+    params(
+        ("task_id" = String, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "Summary of the workspace diff", body = DiffSummaryResponse),
+        (status = 400, description = "Wiki not enabled or no OpenRouter API key"),
+        (status = 404, description = "Workspace not found"),
+        (status = 500, description = "Failed to summarize the diff")
+    ),
+    tag = "workspaces"
+)]
+pub async fn get_workspace_diff_summary(
+    State(state): State<AppState>,
+    Path(task_id): Path<String>,
+) -> Result<Json<DiffSummaryResponse>, AppError> {
+    let project = state.project().await?;
+    let config = ProjectConfig::read(&project.project_path).await;
+    let api_key = match (&config.wiki.openrouter_api_key, config.wiki.enabled) {
+        (Some(key), true) => key.clone(),
+        _ => {
+            return Err(AppError::BadRequest(
+                "Diff summaries require the wiki to be enabled with an OpenRouter API key"
+                    .to_string(),
+            ))
+        }
+    };
+
+    let workspace = project
+        .workspace_manager
+        .list_workspaces()
+        .await?
+        .into_iter()
+        .find(|ws| ws.task_id == task_id)
+        .ok_or_else(|| AppError::NotFound(format!("Workspace not found: {}", task_id)))?;
+
+    let diff = project.workspace_manager.get_diff(&workspace).await?;
+    let head_commit = summary_cache_key(&project.workspace_manager, &workspace, &diff).await;
+    debug!(task_id = %task_id, head_commit = %head_commit, "Summarizing workspace diff");
+
+    let chat_model = config
+        .wiki
+        .chat_model
+        .clone()
+        .unwrap_or_else(|| "anthropic/claude-3.5-sonnet".to_string());
+    let openrouter =
+        wiki::OpenRouterClient::new(api_key, "https://openrouter.ai/api/v1".to_string())
+            .with_usage_tracker(
+                project
+                    .usage_store
+                    .tracker(wiki::UsageScope::task(task_id.to_string())),
+            );
+    let (summary, cached) = ChangeSummarizer::new(&openrouter, &chat_model)
+        .summarize(
+            &get_wiki_db_path(&project.project_path),
+            &task_id,
+            &head_commit,
+            &diff,
+        )
+        .await
+        .map_err(|e| AppError::Internal(format!("Failed to summarize diff: {}", e)))?;
+
+    Ok(Json(DiffSummaryResponse::new(task_id, summary, cached)))
+}
+
+/// Key the diff summary of `workspace` is cached under: its head commit, or
+/// when uncommitted changes (or a VCS without commits) leave that ambiguous,
+/// the head commit plus a digest of the diff
+async fn summary_cache_key(
+    workspace_manager: &vcs::WorkspaceManager,
+    workspace: &Workspace,
+    diff: &str,
+) -> String {
+    let head = workspace_manager.head_commit(workspace).await.ok();
+    let dirty = workspace_manager
+        .vcs()
+        .has_uncommitted_changes(workspace)
+        .await
+        .unwrap_or(true);
+
+    match head {
+        Some(head) if !dirty => head,
+        head => {
+            let digest = Sha256::digest(diff.as_bytes());
+            format!(
+                "{}+{}",
+                head.as_deref().unwrap_or("worktree"),
+                hex::encode(&digest[..8])
+            )
+        }
+    }
+}
+
 #[utoipa::path(
     get,
     path = "/api/workspaces/{task_id}",
@@ -214,14 +417,469 @@ pub async fn merge_workspace(
         .find(|ws| ws.task_id == task_id)
         .ok_or_else(|| AppError::NotFound(format!("Workspace not found: {}", task_id)))?;
 
+    // Capture the diff first: once merged, the workspace matches main
+    let merged_diff = project
+        .workspace_manager
+        .vcs()
+        .get_diff(&workspace)
+        .await
+        .unwrap_or_else(|e| {
+            warn!(task_id = %task_id, error = %e, "Failed to capture diff before merge");
+            String::new()
+        });
+
     let result = project
         .workspace_manager
         .merge_workspace(&workspace, &payload.message)
         .await?;
 
+    if result.is_success() {
+        if let Ok(task_uuid) = Uuid::parse_str(&task_id) {
+            record_merge(&project, task_uuid, &merged_diff).await;
+            schedule_post_merge_update(&state, &project, task_uuid, merged_diff).await;
+            let main_branch = project.workspace_manager.vcs().main_branch().to_string();
+            schedule_merge_reindex(&state, &project, &main_branch).await;
+            start_ci_gate(&state, &project, task_uuid, &main_branch, false).await;
+        }
+    }
+
     Ok(Json(result.into()))
 }
 
+// ============================================================================
+// Pull Request Endpoint
+// ============================================================================
+
+#[derive(Debug, Default, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct CreateWorkspacePrRequest {
+    /// Pull request title; the task title by default
+    pub title: Option<String>,
+    /// Branch to merge into; the main branch by default
+    pub base_branch: Option<String>,
+    #[serde(default)]
+    pub draft: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct WorkspacePrResponse {
+    pub task_id: String,
+    pub pr: PrInfo,
+}
+
+/// Pull request section summing up the task's review: the reviewer's
+/// summary, how the findings were dealt with and those left open
+fn review_pr_section(findings: &ReviewFindings) -> String {
+    let mut section = String::from("## Review\n\n");
+    if !findings.summary.trim().is_empty() {
+        section.push_str(findings.summary.trim());
+        section.push_str("\n\n");
+    }
+
+    if findings.findings.is_empty() {
+        section.push_str("No findings.\n");
+        return section;
+    }
+
+    let counts: Vec<String> = [
+        FindingStatus::Fixed,
+        FindingStatus::Dismissed,
+        FindingStatus::Skipped,
+        FindingStatus::Pending,
+    ]
+    .into_iter()
+    .filter_map(|status| {
+        let count = findings
+            .findings
+            .iter()
+            .filter(|f| f.status == status)
+            .count();
+        (count > 0).then(|| format!("{} {}", count, status.as_str()))
+    })
+    .collect();
+    section.push_str(&format!(
+        "- **{}** findings: {}\n",
+        findings.findings.len(),
+        counts.join(", ")
+    ));
+
+    let open: Vec<&ReviewFinding> = findings
+        .findings
+        .iter()
+        .filter(|f| matches!(f.status, FindingStatus::Pending | FindingStatus::Skipped))
+        .collect();
+    if !open.is_empty() {
+        section.push_str("\n### Open findings\n\n");
+        for finding in open {
+            section.push_str(&format!(
+                "- **{}** {}",
+                finding.severity.as_str(),
+                finding.title
+            ));
+            if let Some(path) = &finding.file_path {
+                match finding.line_start {
+                    Some(line) => section.push_str(&format!(" (`{}:{}`)", path, line)),
+                    None => section.push_str(&format!(" (`{}`)", path)),
+                }
+            }
+            section.push('\n');
+        }
+    }
+
+    section
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/workspaces/{task_id}/pull-request",
+    params(
+        ("task_id" = String, Path, description = "Task ID")
+    ),
+    request_body = CreateWorkspacePrRequest,
+    responses(
+        (status = 201, description = "Branch pushed and pull request opened", body = WorkspacePrResponse),
+        (status = 400, description = "GitHub or pushing is not available"),
+        (status = 404, description = "Task or workspace not found"),
+        (status = 409, description = "The task already has a pull request")
+    ),
+    tag = "workspaces"
+)]
+pub async fn create_workspace_pull_request(
+    State(state): State<AppState>,
+    Path(task_id): Path<String>,
+    Json(payload): Json<CreateWorkspacePrRequest>,
+) -> Result<(StatusCode, Json<WorkspacePrResponse>), AppError> {
+    let task_uuid = Uuid::parse_str(&task_id)
+        .map_err(|_| AppError::BadRequest(format!("Invalid task ID: {}", task_id)))?;
+    let project = state.project().await?;
+    let task = project
+        .task_repository
+        .find_by_id(task_uuid)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Task not found: {}", task_id)))?;
+    if let Some(number) = task.pr_number {
+        return Err(AppError::Conflict(format!(
+            "Task {} already has pull request #{}",
+            task_id, number
+        )));
+    }
+
+    let workspace_manager = &project.workspace_manager;
+    let workspace = find_workspace(workspace_manager, &task_id).await?;
+    let vcs = workspace_manager.vcs();
+
+    let title = payload
+        .title
+        .filter(|t| !t.trim().is_empty())
+        .unwrap_or_else(|| task.title.clone());
+    let base_branch = payload
+        .base_branch
+        .filter(|b| !b.trim().is_empty())
+        .unwrap_or_else(|| vcs.main_branch().to_string());
+
+    let diff_summary = vcs.get_diff_summary(&workspace).await?;
+    let mut body = generate_pr_body(&task.title, Some(task.description.as_str()), &diff_summary);
+    match project
+        .task_executor
+        .file_manager()
+        .read_findings(task_uuid)
+        .await
+    {
+        Ok(Some(findings)) => {
+            body.push('\n');
+            body.push_str(&review_pr_section(&findings));
+        }
+        Ok(None) => {}
+        Err(e) => warn!(task_id = %task_id, error = %e, "Failed to read findings for pull request"),
+    }
+
+    if vcs.has_uncommitted_changes(&workspace).await? {
+        vcs.commit(&workspace, &format!("{}\n\n{}", title, task.description))
+            .await?;
+    }
+
+    let pr_request =
+        CreatePrRequest::new(&title, &workspace.branch_name, &base_branch).with_body(&body);
+    let pr_request = if payload.draft {
+        pr_request.as_draft()
+    } else {
+        pr_request
+    };
+
+    let pr = open_pull_request(&state, &project, &workspace, pr_request).await?;
+    project
+        .task_repository
+        .set_pr_number(task_uuid, pr.number)
+        .await?;
+    debug!(task_id = %task_id, pr_number = pr.number, "Opened pull request");
+    start_ci_gate(&state, &project, task_uuid, &workspace.branch_name, false).await;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(WorkspacePrResponse {
+            task_id,
+            pr: PrInfo {
+                number: pr.number,
+                url: pr.html_url,
+                title: pr.title,
+            },
+        }),
+    ))
+}
+
+// ============================================================================
+// Conflict Resolution Endpoints
+// ============================================================================
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct ConflictsResponse {
+    pub task_id: String,
+    /// Conflicted files; empty once every conflict is resolved
+    pub files: Vec<ConflictedFileResponse>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct ConflictedFileResponse {
+    pub path: String,
+    pub conflict_type: ConflictType,
+    /// Conflicted regions, in file order; empty for files without conflict
+    /// markers, such as deleted or binary ones
+    pub hunks: Vec<ConflictHunkResponse>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct ConflictHunkResponse {
+    /// 1-based line of the opening conflict marker
+    pub start_line: u32,
+    /// 1-based line of the closing conflict marker
+    pub end_line: u32,
+    pub ours: String,
+    pub theirs: String,
+    /// Common ancestor's lines, when the conflict markers include them
+    pub base: Option<String>,
+}
+
+impl From<ConflictHunk> for ConflictHunkResponse {
+    fn from(hunk: ConflictHunk) -> Self {
+        Self {
+            start_line: hunk.start_line,
+            end_line: hunk.end_line,
+            ours: hunk.ours,
+            theirs: hunk.theirs,
+            base: hunk.base,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct ResolveConflictRequest {
+    /// Conflicted file, relative to the workspace root
+    pub path: String,
+    /// Side whose version of the file to keep
+    pub side: ConflictSide,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct CommitResolutionRequest {
+    pub message: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct CommitResolutionResponse {
+    pub task_id: String,
+    /// Identifier of the resolution commit (a change id for jujutsu)
+    pub commit: String,
+    /// Whether the commit completed a merge into main that had recorded
+    /// conflicts
+    pub merged: bool,
+}
+
+async fn find_workspace(
+    workspace_manager: &WorkspaceManager,
+    task_id: &str,
+) -> Result<Workspace, AppError> {
+    workspace_manager
+        .list_workspaces()
+        .await?
+        .into_iter()
+        .find(|ws| ws.task_id == task_id)
+        .ok_or_else(|| AppError::NotFound(format!("Workspace not found: {}", task_id)))
+}
+
+async fn conflicts_response(
+    workspace_manager: &WorkspaceManager,
+    workspace: &Workspace,
+) -> Result<ConflictsResponse, AppError> {
+    let conflicts = workspace_manager.get_conflicts(workspace).await?;
+    let hunks = workspace_manager.list_conflict_hunks(workspace).await?;
+
+    let files = conflicts
+        .into_iter()
+        .map(|conflict| ConflictedFileResponse {
+            path: vcs::to_slash(&conflict.path),
+            conflict_type: conflict.conflict_type,
+            hunks: hunks
+                .iter()
+                .filter(|hunk| hunk.path == conflict.path)
+                .cloned()
+                .map(ConflictHunkResponse::from)
+                .collect(),
+        })
+        .collect();
+
+    Ok(ConflictsResponse {
+        task_id: workspace.task_id.clone(),
+        files,
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/workspaces/{task_id}/conflicts",
+    params(
+        ("task_id" = String, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "Conflicted files of the workspace and their conflicted regions", body = ConflictsResponse),
+        (status = 404, description = "Workspace not found")
+    ),
+    tag = "workspaces"
+)]
+pub async fn get_workspace_conflicts(
+    State(state): State<AppState>,
+    Path(task_id): Path<String>,
+) -> Result<Json<ConflictsResponse>, AppError> {
+    let project = state.project().await?;
+    let workspace = find_workspace(&project.workspace_manager, &task_id).await?;
+
+    Ok(Json(
+        conflicts_response(&project.workspace_manager, &workspace).await?,
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/workspaces/{task_id}/conflicts/resolve",
+    params(
+        ("task_id" = String, Path, description = "Task ID")
+    ),
+    request_body = ResolveConflictRequest,
+    responses(
+        (status = 200, description = "File resolved; the conflicts that remain", body = ConflictsResponse),
+        (status = 400, description = "File not conflicted, or the VCS cannot resolve conflicts"),
+        (status = 404, description = "Workspace not found")
+    ),
+    tag = "workspaces"
+)]
+pub async fn resolve_workspace_conflict(
+    State(state): State<AppState>,
+    Path(task_id): Path<String>,
+    Json(payload): Json<ResolveConflictRequest>,
+) -> Result<Json<ConflictsResponse>, AppError> {
+    let project = state.project().await?;
+    let workspace_manager = &project.workspace_manager;
+    let workspace = find_workspace(workspace_manager, &task_id).await?;
+
+    let conflict = workspace_manager
+        .get_conflicts(&workspace)
+        .await?
+        .into_iter()
+        .find(|c| vcs::same_relative_path(&vcs::to_slash(&c.path), &payload.path))
+        .ok_or_else(|| AppError::BadRequest(format!("{} is not conflicted", payload.path)))?;
+
+    workspace_manager
+        .resolve_conflict(&workspace, &conflict.path, payload.side)
+        .await?;
+    debug!(task_id = %task_id, path = %payload.path, side = payload.side.as_str(), "Resolved conflict");
+
+    Ok(Json(
+        conflicts_response(workspace_manager, &workspace).await?,
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/workspaces/{task_id}/conflicts/commit",
+    params(
+        ("task_id" = String, Path, description = "Task ID")
+    ),
+    request_body = CommitResolutionRequest,
+    responses(
+        (status = 201, description = "Resolution committed, completing the merge that recorded the conflicts, if any", body = CommitResolutionResponse),
+        (status = 400, description = "The VCS cannot resolve conflicts"),
+        (status = 404, description = "Workspace not found"),
+        (status = 409, description = "Conflicts remain unresolved")
+    ),
+    tag = "workspaces"
+)]
+pub async fn commit_workspace_resolution(
+    State(state): State<AppState>,
+    Path(task_id): Path<String>,
+    Json(payload): Json<CommitResolutionRequest>,
+) -> Result<(StatusCode, Json<CommitResolutionResponse>), AppError> {
+    if payload.message.trim().is_empty() {
+        return Err(AppError::BadRequest(
+            "Commit message must not be empty".to_string(),
+        ));
+    }
+
+    let project = state.project().await?;
+    let workspace_manager = &project.workspace_manager;
+    let workspace = find_workspace(workspace_manager, &task_id).await?;
+
+    // A jujutsu merge that recorded conflicts lands once they are resolved
+    let merged = workspace_manager.has_pending_merge(&workspace).await?;
+    let merged_diff = if merged {
+        workspace_manager
+            .get_diff(&workspace)
+            .await
+            .unwrap_or_else(|e| {
+                warn!(task_id = %task_id, error = %e, "Failed to capture diff before merge");
+                String::new()
+            })
+    } else {
+        String::new()
+    };
+
+    let commit = workspace_manager
+        .commit_resolution(&workspace, &payload.message)
+        .await?;
+
+    if merged {
+        if let Ok(task_uuid) = Uuid::parse_str(&task_id) {
+            record_merge(&project, task_uuid, &merged_diff).await;
+            schedule_post_merge_update(&state, &project, task_uuid, merged_diff).await;
+            let main_branch = workspace_manager.vcs().main_branch().to_string();
+            schedule_merge_reindex(&state, &project, &main_branch).await;
+            start_ci_gate(&state, &project, task_uuid, &main_branch, false).await;
+        }
+    }
+
+    Ok((
+        StatusCode::CREATED,
+        Json(CommitResolutionResponse {
+            task_id,
+            commit,
+            merged,
+        }),
+    ))
+}
+
 #[utoipa::path(
     delete,
     path = "/api/workspaces/{task_id}",
@@ -324,3 +982,47 @@ pub async fn set_file_viewed(
 
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finding(id: &str, status: &str, file: Option<&str>) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "file_path": file,
+            "line_start": file.map(|_| 12),
+            "title": format!("Finding {}", id),
+            "description": "Details",
+            "severity": "warning",
+            "status": status,
+        })
+    }
+
+    #[test]
+    fn test_review_pr_section() {
+        let findings: ReviewFindings = serde_json::from_value(serde_json::json!({
+            "task_id": Uuid::new_v4(),
+            "session_id": Uuid::new_v4(),
+            "approved": false,
+            "created_at": chrono::Utc::now(),
+            "summary": "Mostly fine.",
+            "findings": [
+                finding("1", "fixed", None),
+                finding("2", "fixed", None),
+                finding("3", "pending", Some("src/lib.rs")),
+                finding("4", "dismissed", None),
+            ],
+        }))
+        .unwrap();
+
+        let section = review_pr_section(&findings);
+        assert!(section.starts_with("## Review\n\nMostly fine.\n\n"));
+        assert!(section.contains("- **4** findings: 2 fixed, 1 dismissed, 1 pending\n"));
+        assert!(section.contains("- **warning** Finding 3 (`src/lib.rs:12`)\n"));
+        assert!(!section.contains("Finding 1"));
+
+        let approved = ReviewFindings::approved(Uuid::new_v4(), Uuid::new_v4(), String::new());
+        assert_eq!(review_pr_section(&approved), "## Review\n\nNo findings.\n");
+    }
+}
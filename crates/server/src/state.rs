@@ -1,15 +1,54 @@
+use crate::auth::AuthConfig;
 use crate::project_manager::{GlobalConfigManager, ProjectContext, ProjectError, ProjectManager};
+use crate::routes::dashboard::SharedDashboardCache;
 use crate::routes::sse::{EventBuffer, SharedEventBuffer, DEFAULT_EVENT_BUFFER_SIZE};
 use events::EventBus;
 use github::{GitHubClient, RepoConfig};
 use opencode_core::RoadmapGenerationStatus;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicU64;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock};
 use tokio::sync::RwLock as TokioRwLock;
 
 pub type SharedRoadmapStatus = Arc<TokioRwLock<RoadmapGenerationStatus>>;
 pub type GenerationId = Arc<AtomicU64>;
+pub type SharedReindexQueue = Arc<Mutex<ReindexQueue>>;
+
+/// Merge-triggered re-indexes waiting out their debounce, keyed by project
+/// and branch. Each merge takes a new ticket; only the latest ticket for a
+/// branch goes on to re-index it.
+#[derive(Debug, Default)]
+pub struct ReindexQueue {
+    next_ticket: u64,
+    pending: HashMap<(PathBuf, String), u64>,
+}
+
+impl ReindexQueue {
+    /// Queue a re-index of `branch`, superseding any still waiting
+    pub fn schedule(&mut self, project_path: &Path, branch: &str) -> u64 {
+        self.next_ticket += 1;
+        self.pending.insert(
+            (project_path.to_path_buf(), branch.to_string()),
+            self.next_ticket,
+        );
+        self.next_ticket
+    }
+
+    pub fn is_latest(&self, project_path: &Path, branch: &str, ticket: u64) -> bool {
+        self.pending
+            .get(&(project_path.to_path_buf(), branch.to_string()))
+            .is_some_and(|latest| *latest == ticket)
+    }
+
+    /// Drop `ticket` once its re-index starts; later tickets stay queued
+    pub fn finish(&mut self, project_path: &Path, branch: &str, ticket: u64) {
+        let key = (project_path.to_path_buf(), branch.to_string());
+        if self.pending.get(&key) == Some(&ticket) {
+            self.pending.remove(&key);
+        }
+    }
+}
 
 #[derive(Clone)]
 pub struct AppState {
@@ -21,9 +60,15 @@ pub struct AppState {
     pub app_dir: Option<PathBuf>,
     /// Cached GitHub client - token hash is stored to detect when token changes
     github_client: Arc<RwLock<Option<(String, GitHubClient)>>>,
+    /// Dashboard summary served until it expires
+    pub(crate) dashboard_cache: SharedDashboardCache,
     pub roadmap_status: SharedRoadmapStatus,
     /// Current roadmap generation ID - incremented on each new generation to invalidate old tasks
     pub roadmap_generation_id: GenerationId,
+    /// Wiki re-indexes queued by merges
+    pub merge_reindex: SharedReindexQueue,
+    /// How requests must authenticate
+    pub auth: AuthConfig,
 }
 
 impl AppState {
@@ -44,8 +89,11 @@ impl AppState {
             opencode_url: opencode_url.to_string(),
             app_dir: None,
             github_client: Arc::new(RwLock::new(None)),
+            dashboard_cache: Arc::new(RwLock::new(None)),
             roadmap_status: Arc::new(TokioRwLock::new(RoadmapGenerationStatus::default())),
             roadmap_generation_id: Arc::new(AtomicU64::new(0)),
+            merge_reindex: Arc::new(Mutex::new(ReindexQueue::default())),
+            auth: AuthConfig::from_env(),
         }
     }
 
@@ -54,6 +102,11 @@ impl AppState {
         self
     }
 
+    pub fn with_auth(mut self, auth: AuthConfig) -> Self {
+        self.auth = auth;
+        self
+    }
+
     pub async fn project(&self) -> Result<ProjectContext, ProjectError> {
         self.project_manager
             .current()
@@ -154,3 +207,29 @@ impl AppState {
         Ok(client)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reindex_queue_keeps_latest_ticket() {
+        let mut queue = ReindexQueue::default();
+        let project = Path::new("/tmp/project");
+
+        let first = queue.schedule(project, "main");
+        let second = queue.schedule(project, "main");
+        let other = queue.schedule(project, "develop");
+
+        assert!(!queue.is_latest(project, "main", first));
+        assert!(queue.is_latest(project, "main", second));
+        assert!(queue.is_latest(project, "develop", other));
+
+        queue.finish(project, "main", first);
+        assert!(queue.is_latest(project, "main", second));
+
+        queue.finish(project, "main", second);
+        assert!(!queue.is_latest(project, "main", second));
+        assert!(queue.is_latest(project, "develop", other));
+    }
+}
@@ -0,0 +1,532 @@
+//! Versioned migrations for the `.opencode-studio` directory.
+//!
+//! `config.toml` records the schema version the directory was last written
+//! with; projects from before versioning count as version 0. When a project
+//! is opened, every migration newer than that version runs in order. The files
+//! they change are backed up under `.opencode-studio/backups/` first, then
+//! rewritten atomically, and the version is bumped last so an interrupted run
+//! starts over. `opencode-studio migrate --dry-run` reports the same changes
+//! without writing anything.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use serde_json::Value;
+use tracing::{info, warn};
+
+use crate::project_manager::ProjectError;
+
+const STUDIO_DIR: &str = ".opencode-studio";
+const PROJECT_CONFIG_FILE: &str = "config.toml";
+const BACKUPS_DIR: &str = "backups";
+const SCHEMA_VERSION_KEY: &str = "schema_version";
+
+/// Schema version written by this build
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+/// A migration to `version`, recording the file changes it makes
+struct Migration {
+    version: u32,
+    description: &'static str,
+    run: fn(&mut StudioFiles) -> Result<(), ProjectError>,
+}
+
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "Add a status to findings written before statuses existed and lowercase their severities",
+        run: upgrade_findings,
+    },
+    Migration {
+        version: 2,
+        description: "Convert plans and reviews to LF line endings so phase headers parse",
+        run: normalize_line_endings,
+    },
+];
+
+/// A file rewritten by a migration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileChange {
+    /// Path relative to `.opencode-studio`
+    pub path: PathBuf,
+    pub content: String,
+}
+
+/// One migration and the files it changes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationStep {
+    pub version: u32,
+    pub description: &'static str,
+    /// Changed files, relative to `.opencode-studio`
+    pub files: Vec<PathBuf>,
+}
+
+/// The migrations pending for a project and the files they change
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationPlan {
+    pub studio_dir: PathBuf,
+    pub from_version: u32,
+    pub to_version: u32,
+    pub steps: Vec<MigrationStep>,
+    /// Final content of every changed file, `config.toml` included
+    pub changes: Vec<FileChange>,
+}
+
+impl MigrationPlan {
+    pub fn is_up_to_date(&self) -> bool {
+        self.from_version >= self.to_version
+    }
+}
+
+impl fmt::Display for MigrationPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_up_to_date() {
+            return write!(
+                f,
+                "{} is up to date (schema version {})",
+                self.studio_dir.display(),
+                self.from_version
+            );
+        }
+        writeln!(
+            f,
+            "{}: schema version {} -> {}",
+            self.studio_dir.display(),
+            self.from_version,
+            self.to_version
+        )?;
+        for step in &self.steps {
+            writeln!(f, "  v{}: {}", step.version, step.description)?;
+            if step.files.is_empty() {
+                writeln!(f, "    no files to change")?;
+            }
+            for file in &step.files {
+                writeln!(f, "    {}", file.display())?;
+            }
+        }
+        write!(f, "  {} file(s) to write", self.changes.len())
+    }
+}
+
+/// Result of applying a migration plan
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationOutcome {
+    pub plan: MigrationPlan,
+    /// Where the files were backed up before being rewritten
+    pub backup_dir: Option<PathBuf>,
+}
+
+/// Files of a studio directory with the changes made so far layered on top,
+/// so later migrations see the output of earlier ones
+struct StudioFiles {
+    studio_dir: PathBuf,
+    changes: BTreeMap<PathBuf, String>,
+}
+
+impl StudioFiles {
+    fn new(studio_dir: &Path) -> Self {
+        Self {
+            studio_dir: studio_dir.to_path_buf(),
+            changes: BTreeMap::new(),
+        }
+    }
+
+    fn read(&self, path: &Path) -> Result<Option<String>, ProjectError> {
+        if let Some(content) = self.changes.get(path) {
+            return Ok(Some(content.clone()));
+        }
+        let full_path = self.studio_dir.join(path);
+        if !full_path.exists() {
+            return Ok(None);
+        }
+        Ok(Some(std::fs::read_to_string(full_path)?))
+    }
+
+    fn write(&mut self, path: PathBuf, content: String) {
+        self.changes.insert(path, content);
+    }
+
+    /// Files in `dir` with the given extension, relative to the studio
+    /// directory and sorted
+    fn list(&self, dir: &Path, extension: &str) -> Result<Vec<PathBuf>, ProjectError> {
+        let full_dir = self.studio_dir.join(dir);
+        if !full_dir.is_dir() {
+            return Ok(Vec::new());
+        }
+        let mut files = Vec::new();
+        for entry in std::fs::read_dir(full_dir)? {
+            let path = entry?.path();
+            let is_match = path.is_file()
+                && path.extension().and_then(|e| e.to_str()) == Some(extension)
+                && !path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| n.starts_with('.'));
+            if let (true, Some(name)) = (is_match, path.file_name()) {
+                files.push(dir.join(name));
+            }
+        }
+        files.sort();
+        Ok(files)
+    }
+}
+
+/// Schema version recorded in `config.toml`; 0 when it predates versioning
+pub fn read_schema_version(studio_dir: &Path) -> Result<u32, ProjectError> {
+    let config_path = studio_dir.join(PROJECT_CONFIG_FILE);
+    if !config_path.exists() {
+        return Ok(0);
+    }
+    let table = read_config_table(&std::fs::read_to_string(config_path)?)?;
+    match table.get(SCHEMA_VERSION_KEY) {
+        None => Ok(0),
+        Some(value) => value
+            .as_integer()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| {
+                ProjectError::Config(format!("Invalid {} in config.toml", SCHEMA_VERSION_KEY))
+            }),
+    }
+}
+
+/// Work out the pending migrations of the project at `project_path` without
+/// writing anything
+pub fn plan(project_path: &Path) -> Result<MigrationPlan, ProjectError> {
+    let studio_dir = project_path.join(STUDIO_DIR);
+    let from_version = read_schema_version(&studio_dir)?;
+    if from_version > CURRENT_SCHEMA_VERSION {
+        return Err(ProjectError::Config(format!(
+            "{} has schema version {}, newer than the {} this build supports",
+            studio_dir.display(),
+            from_version,
+            CURRENT_SCHEMA_VERSION
+        )));
+    }
+
+    let mut files = StudioFiles::new(&studio_dir);
+    let mut steps = Vec::new();
+    if from_version < CURRENT_SCHEMA_VERSION {
+        for migration in MIGRATIONS.iter().filter(|m| m.version > from_version) {
+            let before = files.changes.clone();
+            (migration.run)(&mut files)?;
+            let changed = files
+                .changes
+                .iter()
+                .filter(|(path, content)| before.get(*path) != Some(*content))
+                .map(|(path, _)| path.clone())
+                .collect();
+            steps.push(MigrationStep {
+                version: migration.version,
+                description: migration.description,
+                files: changed,
+            });
+        }
+
+        let config_path = PathBuf::from(PROJECT_CONFIG_FILE);
+        let config = files.read(&config_path)?.unwrap_or_default();
+        let mut table = read_config_table(&config)?;
+        table.insert(
+            SCHEMA_VERSION_KEY.to_string(),
+            toml::Value::Integer(i64::from(CURRENT_SCHEMA_VERSION)),
+        );
+        let config = toml::to_string_pretty(&table)
+            .map_err(|e| ProjectError::Config(format!("Failed to write config.toml: {}", e)))?;
+        files.write(config_path, config);
+    }
+
+    Ok(MigrationPlan {
+        studio_dir,
+        from_version,
+        to_version: CURRENT_SCHEMA_VERSION,
+        steps,
+        changes: files
+            .changes
+            .into_iter()
+            .map(|(path, content)| FileChange { path, content })
+            .collect(),
+    })
+}
+
+/// Back up and rewrite the files changed by `plan`. `config.toml` is written
+/// last, so the version only moves once everything else is in place.
+pub fn apply(plan: MigrationPlan) -> Result<MigrationOutcome, ProjectError> {
+    if plan.is_up_to_date() {
+        return Ok(MigrationOutcome {
+            plan,
+            backup_dir: None,
+        });
+    }
+
+    let backup_dir = plan.studio_dir.join(BACKUPS_DIR).join(format!(
+        "{}-v{}",
+        Utc::now().format("%Y%m%d-%H%M%S"),
+        plan.from_version
+    ));
+    let mut backed_up = false;
+    for change in &plan.changes {
+        let source = plan.studio_dir.join(&change.path);
+        if !source.exists() {
+            continue;
+        }
+        let target = backup_dir.join(&change.path);
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::copy(&source, &target)?;
+        backed_up = true;
+    }
+
+    let config_path = Path::new(PROJECT_CONFIG_FILE);
+    let (config, others): (Vec<&FileChange>, Vec<&FileChange>) =
+        plan.changes.iter().partition(|c| c.path == config_path);
+    for change in others.into_iter().chain(config) {
+        write_atomic(&plan.studio_dir.join(&change.path), &change.content)?;
+    }
+
+    info!(
+        studio_dir = %plan.studio_dir.display(),
+        from = plan.from_version,
+        to = plan.to_version,
+        files = plan.changes.len(),
+        "Migrated .opencode-studio directory"
+    );
+
+    Ok(MigrationOutcome {
+        plan,
+        backup_dir: backed_up.then_some(backup_dir),
+    })
+}
+
+/// Bring the project's `.opencode-studio` directory up to the current schema
+/// version; with `dry_run` only report what would change
+pub fn migrate(project_path: &Path, dry_run: bool) -> Result<MigrationOutcome, ProjectError> {
+    let plan = plan(project_path)?;
+    if dry_run {
+        return Ok(MigrationOutcome {
+            plan,
+            backup_dir: None,
+        });
+    }
+    apply(plan)
+}
+
+fn read_config_table(content: &str) -> Result<toml::Table, ProjectError> {
+    toml::from_str(content)
+        .map_err(|e| ProjectError::Config(format!("Failed to parse config.toml: {}", e)))
+}
+
+fn write_atomic(path: &Path, content: &str) -> Result<(), ProjectError> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let file_name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("migrated");
+    let temp_path = path.with_file_name(format!(".{}.tmp", file_name));
+    std::fs::write(&temp_path, content)?;
+    std::fs::rename(&temp_path, path)?;
+    Ok(())
+}
+
+// ============================================================================
+// Migrations
+// ============================================================================
+
+/// v1: findings files from before finding statuses have no `status`, only a
+/// `fixed` flag when anything was fixed, and some spell severities in
+/// capitals. Neither parses as `ReviewFindings` any more.
+fn upgrade_findings(files: &mut StudioFiles) -> Result<(), ProjectError> {
+    for path in files.list(&Path::new("kanban").join("findings"), "json")? {
+        let Some(content) = files.read(&path)? else {
+            continue;
+        };
+        let mut document: Value = match serde_json::from_str(&content) {
+            Ok(document) => document,
+            Err(e) => {
+                warn!(path = %path.display(), error = %e, "Skipping unreadable findings file");
+                continue;
+            }
+        };
+        if upgrade_findings_document(&mut document) {
+            let content = serde_json::to_string_pretty(&document).map_err(|e| {
+                ProjectError::Config(format!("Failed to serialize findings: {}", e))
+            })?;
+            files.write(path, content);
+        }
+    }
+    Ok(())
+}
+
+/// Upgrade the findings in a findings document; returns whether any changed
+fn upgrade_findings_document(document: &mut Value) -> bool {
+    let Some(findings) = document.get_mut("findings").and_then(Value::as_array_mut) else {
+        return false;
+    };
+
+    let mut changed = false;
+    for finding in findings.iter_mut().filter_map(Value::as_object_mut) {
+        if let Some(fixed) = finding.remove("fixed") {
+            changed = true;
+            if !finding.contains_key("status") {
+                let status = if fixed.as_bool() == Some(true) {
+                    "fixed"
+                } else {
+                    "pending"
+                };
+                finding.insert("status".to_string(), Value::from(status));
+            }
+        }
+        if !finding.contains_key("status") {
+            finding.insert("status".to_string(), Value::from("pending"));
+            changed = true;
+        }
+
+        let severity = match finding.get("severity").and_then(Value::as_str) {
+            Some(severity) => severity.to_lowercase(),
+            None => continue,
+        };
+        let severity = match severity.as_str() {
+            "warn" => "warning".to_string(),
+            _ => severity,
+        };
+        if finding.get("severity").and_then(Value::as_str) != Some(severity.as_str()) {
+            finding.insert("severity".to_string(), Value::from(severity));
+            changed = true;
+        }
+    }
+    changed
+}
+
+/// v2: plans saved with CRLF line endings keep a trailing `\r` in every phase
+/// title and never match the phase header patterns when phases are marked
+/// complete.
+fn normalize_line_endings(files: &mut StudioFiles) -> Result<(), ProjectError> {
+    for dir in ["plans", "reviews"] {
+        for path in files.list(&Path::new("kanban").join(dir), "md")? {
+            let Some(content) = files.read(&path)? else {
+                continue;
+            };
+            if content.contains('\r') {
+                files.write(path, content.replace("\r\n", "\n"));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn legacy_project() -> TempDir {
+        let tmp = TempDir::new().unwrap();
+        let studio_dir = tmp.path().join(STUDIO_DIR);
+        let kanban = studio_dir.join("kanban");
+        std::fs::create_dir_all(kanban.join("findings")).unwrap();
+        std::fs::create_dir_all(kanban.join("plans")).unwrap();
+        std::fs::write(
+            studio_dir.join(PROJECT_CONFIG_FILE),
+            "name = \"legacy\"\nmax_iterations = 5\n",
+        )
+        .unwrap();
+        std::fs::write(
+            kanban.join("findings").join("task.json"),
+            r#"{"findings": [
+                {"id": "f1", "title": "A", "description": "", "severity": "Error", "fixed": true},
+                {"id": "f2", "title": "B", "description": "", "severity": "warn"},
+                {"id": "f3", "title": "C", "description": "", "severity": "info", "status": "skipped"}
+            ]}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            kanban.join("plans").join("task.md"),
+            "## Phase 1: Setup\r\nDo it\r\n",
+        )
+        .unwrap();
+        std::fs::write(kanban.join("plans").join("other.md"), "## Phase 1: Done\n").unwrap();
+        tmp
+    }
+
+    #[test]
+    fn test_dry_run_writes_nothing() {
+        let tmp = legacy_project();
+        let outcome = migrate(tmp.path(), true).unwrap();
+        let plan = outcome.plan;
+
+        assert_eq!(plan.from_version, 0);
+        assert_eq!(plan.to_version, CURRENT_SCHEMA_VERSION);
+        assert_eq!(
+            plan.steps[0].files,
+            vec![PathBuf::from("kanban/findings/task.json")]
+        );
+        assert_eq!(
+            plan.steps[1].files,
+            vec![PathBuf::from("kanban/plans/task.md")]
+        );
+        assert_eq!(plan.changes.len(), 3);
+        assert!(plan.to_string().contains("v1: Add a status"));
+
+        let studio_dir = tmp.path().join(STUDIO_DIR);
+        assert_eq!(read_schema_version(&studio_dir).unwrap(), 0);
+        assert!(!studio_dir.join(BACKUPS_DIR).exists());
+    }
+
+    #[test]
+    fn test_apply_migrates_and_backs_up() {
+        let tmp = legacy_project();
+        let studio_dir = tmp.path().join(STUDIO_DIR);
+
+        let outcome = migrate(tmp.path(), false).unwrap();
+        assert_eq!(
+            read_schema_version(&studio_dir).unwrap(),
+            CURRENT_SCHEMA_VERSION
+        );
+
+        let config = std::fs::read_to_string(studio_dir.join(PROJECT_CONFIG_FILE)).unwrap();
+        assert!(config.contains("max_iterations = 5"));
+
+        let findings: Value = serde_json::from_str(
+            &std::fs::read_to_string(studio_dir.join("kanban/findings/task.json")).unwrap(),
+        )
+        .unwrap();
+        let findings = findings["findings"].as_array().unwrap();
+        assert_eq!(findings[0]["status"], "fixed");
+        assert_eq!(findings[0]["severity"], "error");
+        assert!(findings[0].get("fixed").is_none());
+        assert_eq!(findings[1]["status"], "pending");
+        assert_eq!(findings[1]["severity"], "warning");
+        assert_eq!(findings[2]["status"], "skipped");
+
+        let plan = std::fs::read_to_string(studio_dir.join("kanban/plans/task.md")).unwrap();
+        assert_eq!(plan, "## Phase 1: Setup\nDo it\n");
+
+        let backup_dir = outcome.backup_dir.unwrap();
+        assert!(backup_dir.starts_with(studio_dir.join(BACKUPS_DIR)));
+        let backup = std::fs::read_to_string(backup_dir.join("kanban/plans/task.md")).unwrap();
+        assert!(backup.contains("\r\n"));
+        assert!(backup_dir.join(PROJECT_CONFIG_FILE).exists());
+
+        // Nothing left to do on the next run
+        let outcome = migrate(tmp.path(), false).unwrap();
+        assert!(outcome.plan.is_up_to_date());
+        assert!(outcome.plan.changes.is_empty());
+        assert!(outcome.backup_dir.is_none());
+    }
+
+    #[test]
+    fn test_newer_schema_is_rejected() {
+        let tmp = TempDir::new().unwrap();
+        let studio_dir = tmp.path().join(STUDIO_DIR);
+        std::fs::create_dir_all(&studio_dir).unwrap();
+        std::fs::write(
+            studio_dir.join(PROJECT_CONFIG_FILE),
+            format!("schema_version = {}\n", CURRENT_SCHEMA_VERSION + 1),
+        )
+        .unwrap();
+        assert!(matches!(plan(tmp.path()), Err(ProjectError::Config(_))));
+    }
+}
@@ -0,0 +1,247 @@
+//! Blocking client for scripts without an async runtime
+//!
+//! Each call runs the async client on a runtime owned by the client, so it
+//! must not be used from within an async context.
+
+use std::time::Duration;
+
+use opencode_core::{CreateTaskRequest, Session, Task, TaskStatus, UpdateTaskRequest};
+use tokio::runtime::Runtime;
+use uuid::Uuid;
+
+use crate::error::{Result, StudioError};
+use crate::retry::RetryPolicy;
+use crate::types::{
+    DiffResponse, ExecuteResponse, FindingComment, FindingsResponse, FixFindingsRequest,
+    IndexRequest, IndexResponse, ListParams, MergeResponse, Page, SearchRequest,
+    TransitionResponse, WikiPageResponse, WikiSearchResponse, WikiStatusResponse,
+    WorkspaceResponse, WorkspaceStatusResponse,
+};
+
+/// Blocking client of the Studio HTTP API
+#[derive(Debug)]
+pub struct StudioClient {
+    inner: crate::StudioClient,
+    runtime: Runtime,
+}
+
+impl StudioClient {
+    /// A client of the server at `base_url`, e.g. `http://localhost:3001`
+    pub fn new(base_url: impl Into<String>) -> Result<Self> {
+        Self::from_async(crate::StudioClient::new(base_url)?)
+    }
+
+    /// A client configured by `STUDIO_URL` and `STUDIO_TOKEN`
+    pub fn from_env() -> Result<Self> {
+        Self::from_async(crate::StudioClient::from_env()?)
+    }
+
+    /// Run `client` in blocking calls
+    pub fn from_async(client: crate::StudioClient) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| StudioError::Config(format!("Failed to start runtime: {}", e)))?;
+        Ok(Self {
+            inner: client,
+            runtime,
+        })
+    }
+
+    /// Authenticate with an API token
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.inner = self.inner.with_token(token);
+        self
+    }
+
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.inner = self.inner.with_retry(retry);
+        self
+    }
+
+    /// Give up on an attempt after `timeout`
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.inner = self.inner.with_timeout(timeout);
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.inner.base_url()
+    }
+
+    // Tasks
+
+    pub fn list_tasks(&self, params: &ListParams) -> Result<Page<Task>> {
+        self.runtime.block_on(self.inner.list_tasks(params))
+    }
+
+    pub fn get_task(&self, id: Uuid) -> Result<Task> {
+        self.runtime.block_on(self.inner.get_task(id))
+    }
+
+    pub fn create_task(&self, request: &CreateTaskRequest) -> Result<Task> {
+        self.runtime.block_on(self.inner.create_task(request))
+    }
+
+    pub fn update_task(&self, id: Uuid, request: &UpdateTaskRequest) -> Result<Task> {
+        self.runtime.block_on(self.inner.update_task(id, request))
+    }
+
+    pub fn delete_task(&self, id: Uuid) -> Result<()> {
+        self.runtime.block_on(self.inner.delete_task(id))
+    }
+
+    /// Move a task to `status`, which may start that status' phase
+    pub fn transition_task(&self, id: Uuid, status: TaskStatus) -> Result<TransitionResponse> {
+        self.runtime
+            .block_on(self.inner.transition_task(id, status))
+    }
+
+    /// Start the phase of the task's current status
+    pub fn execute_task(&self, id: Uuid) -> Result<ExecuteResponse> {
+        self.runtime.block_on(self.inner.execute_task(id))
+    }
+
+    // Sessions
+
+    pub fn list_sessions(&self, params: &ListParams) -> Result<Page<Session>> {
+        self.runtime.block_on(self.inner.list_sessions(params))
+    }
+
+    pub fn get_session(&self, id: Uuid) -> Result<Session> {
+        self.runtime.block_on(self.inner.get_session(id))
+    }
+
+    /// Sessions of a task, oldest first
+    pub fn list_task_sessions(&self, task_id: Uuid) -> Result<Vec<Session>> {
+        self.runtime
+            .block_on(self.inner.list_task_sessions(task_id))
+    }
+
+    // Workspaces
+
+    pub fn list_workspaces(&self, params: &ListParams) -> Result<Page<WorkspaceResponse>> {
+        self.runtime.block_on(self.inner.list_workspaces(params))
+    }
+
+    pub fn create_workspace(&self, task_id: Uuid) -> Result<WorkspaceResponse> {
+        self.runtime.block_on(self.inner.create_workspace(task_id))
+    }
+
+    pub fn get_workspace_status(&self, task_id: Uuid) -> Result<WorkspaceStatusResponse> {
+        self.runtime
+            .block_on(self.inner.get_workspace_status(task_id))
+    }
+
+    pub fn get_workspace_diff(&self, task_id: Uuid) -> Result<DiffResponse> {
+        self.runtime
+            .block_on(self.inner.get_workspace_diff(task_id))
+    }
+
+    /// Merge the task's workspace into the main branch
+    pub fn merge_workspace(
+        &self,
+        task_id: Uuid,
+        message: impl Into<String>,
+    ) -> Result<MergeResponse> {
+        self.runtime
+            .block_on(self.inner.merge_workspace(task_id, message))
+    }
+
+    pub fn delete_workspace(&self, task_id: Uuid) -> Result<()> {
+        self.runtime.block_on(self.inner.delete_workspace(task_id))
+    }
+
+    // Wiki
+
+    pub fn get_wiki_status(&self) -> Result<WikiStatusResponse> {
+        self.runtime.block_on(self.inner.get_wiki_status())
+    }
+
+    /// Start indexing a branch; follow it by the returned job ID
+    pub fn start_indexing(&self, request: &IndexRequest) -> Result<IndexResponse> {
+        self.runtime.block_on(self.inner.start_indexing(request))
+    }
+
+    pub fn search_wiki(&self, request: &SearchRequest) -> Result<WikiSearchResponse> {
+        self.runtime.block_on(self.inner.search_wiki(request))
+    }
+
+    pub fn get_wiki_page(&self, slug: &str) -> Result<WikiPageResponse> {
+        self.runtime.block_on(self.inner.get_wiki_page(slug))
+    }
+
+    // Findings
+
+    pub fn get_findings(&self, task_id: Uuid) -> Result<FindingsResponse> {
+        self.runtime.block_on(self.inner.get_findings(task_id))
+    }
+
+    /// Start a fix session for the selected findings
+    pub fn fix_findings(
+        &self,
+        task_id: Uuid,
+        request: &FixFindingsRequest,
+    ) -> Result<ExecuteResponse> {
+        self.runtime
+            .block_on(self.inner.fix_findings(task_id, request))
+    }
+
+    /// Skip the remaining findings and move the task on to review
+    pub fn skip_findings(&self, task_id: Uuid) -> Result<Task> {
+        self.runtime.block_on(self.inner.skip_findings(task_id))
+    }
+
+    pub fn list_finding_comments(
+        &self,
+        task_id: Uuid,
+        finding_id: &str,
+    ) -> Result<Vec<FindingComment>> {
+        self.runtime
+            .block_on(self.inner.list_finding_comments(task_id, finding_id))
+    }
+
+    pub fn add_finding_comment(
+        &self,
+        task_id: Uuid,
+        finding_id: &str,
+        body: impl Into<String>,
+    ) -> Result<FindingComment> {
+        self.runtime
+            .block_on(self.inner.add_finding_comment(task_id, finding_id, body))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn test_blocking_client_calls_server() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        let task_id = Uuid::new_v4();
+        runtime.block_on(
+            Mock::given(method("POST"))
+                .and(path(format!("/api/workspaces/{}/merge", task_id)))
+                .and(body_json(serde_json::json!({ "message": "Add login" })))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "result": "conflicts",
+                    "files": ["src/login.rs"]
+                })))
+                .expect(1)
+                .mount(&server),
+        );
+
+        let client = StudioClient::new(server.uri()).unwrap();
+        let result = client.merge_workspace(task_id, "Add login").unwrap();
+        assert_eq!(
+            result,
+            MergeResponse::Conflicts {
+                files: vec!["src/login.rs".to_string()]
+            }
+        );
+    }
+}
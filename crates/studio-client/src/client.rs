@@ -0,0 +1,603 @@
+use std::time::Duration;
+
+use opencode_core::{CreateTaskRequest, Session, Task, TaskStatus, UpdateTaskRequest};
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Method, Response};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::debug;
+use uuid::Uuid;
+
+use crate::error::{Result, StudioError};
+use crate::retry::{jitter, Failure, RetryPolicy};
+use crate::types::{
+    AddFindingCommentRequest, DiffResponse, ExecuteResponse, FindingComment, FindingsResponse,
+    FixFindingsRequest, IndexRequest, IndexResponse, ListParams, MergeRequest, MergeResponse, Page,
+    SearchRequest, TransitionRequest, TransitionResponse, WikiPageResponse, WikiSearchResponse,
+    WikiStatusResponse, WorkspaceResponse, WorkspaceStatusResponse,
+};
+
+/// Server the client talks to unless told otherwise
+pub const DEFAULT_BASE_URL: &str = "http://localhost:3001";
+
+/// Longest a single attempt may take
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+const TOTAL_COUNT_HEADER: &str = "x-total-count";
+
+/// Body of the server's error responses
+#[derive(Deserialize)]
+struct ErrorBody {
+    error: String,
+    message: String,
+}
+
+/// Async client of the Studio HTTP API
+#[derive(Debug, Clone)]
+pub struct StudioClient {
+    http: reqwest::Client,
+    base_url: String,
+    token: Option<String>,
+    retry: RetryPolicy,
+    timeout: Duration,
+}
+
+impl StudioClient {
+    /// A client of the server at `base_url`, e.g. `http://localhost:3001`
+    pub fn new(base_url: impl Into<String>) -> Result<Self> {
+        let base_url = base_url.into().trim_end_matches('/').to_string();
+        if !base_url.starts_with("http://") && !base_url.starts_with("https://") {
+            return Err(StudioError::Config(format!(
+                "Base URL must start with http:// or https://: {}",
+                base_url
+            )));
+        }
+        let http = reqwest::Client::builder()
+            .user_agent(concat!("studio-client/", env!("CARGO_PKG_VERSION")))
+            .build()?;
+
+        Ok(Self {
+            http,
+            base_url,
+            token: None,
+            retry: RetryPolicy::default(),
+            timeout: DEFAULT_TIMEOUT,
+        })
+    }
+
+    /// A client configured by `STUDIO_URL` (default [`DEFAULT_BASE_URL`])
+    /// and `STUDIO_TOKEN`
+    pub fn from_env() -> Result<Self> {
+        let base_url = std::env::var("STUDIO_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        let client = Self::new(base_url)?;
+        Ok(match std::env::var("STUDIO_TOKEN") {
+            Ok(token) if !token.trim().is_empty() => client.with_token(token),
+            _ => client,
+        })
+    }
+
+    /// Authenticate with an API token
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Give up on an attempt after `timeout`
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    // ------------------------------------------------------------------
+    // Tasks
+    // ------------------------------------------------------------------
+
+    pub async fn list_tasks(&self, params: &ListParams) -> Result<Page<Task>> {
+        self.list("/api/tasks", params).await
+    }
+
+    pub async fn get_task(&self, id: Uuid) -> Result<Task> {
+        self.get(&format!("/api/tasks/{}", id)).await
+    }
+
+    pub async fn create_task(&self, request: &CreateTaskRequest) -> Result<Task> {
+        self.send_json(Method::POST, "/api/tasks", request).await
+    }
+
+    pub async fn update_task(&self, id: Uuid, request: &UpdateTaskRequest) -> Result<Task> {
+        self.send_json(Method::PATCH, &format!("/api/tasks/{}", id), request)
+            .await
+    }
+
+    pub async fn delete_task(&self, id: Uuid) -> Result<()> {
+        self.delete(&format!("/api/tasks/{}", id)).await
+    }
+
+    /// Move a task to `status`, which may start that status' phase
+    pub async fn transition_task(
+        &self,
+        id: Uuid,
+        status: TaskStatus,
+    ) -> Result<TransitionResponse> {
+        self.send_json(
+            Method::POST,
+            &format!("/api/tasks/{}/transition", id),
+            &TransitionRequest { status },
+        )
+        .await
+    }
+
+    /// Start the phase of the task's current status
+    pub async fn execute_task(&self, id: Uuid) -> Result<ExecuteResponse> {
+        self.send_json(
+            Method::POST,
+            &format!("/api/tasks/{}/execute", id),
+            &Value::Null,
+        )
+        .await
+    }
+
+    // ------------------------------------------------------------------
+    // Sessions
+    // ------------------------------------------------------------------
+
+    pub async fn list_sessions(&self, params: &ListParams) -> Result<Page<Session>> {
+        self.list("/api/sessions", params).await
+    }
+
+    pub async fn get_session(&self, id: Uuid) -> Result<Session> {
+        self.get(&format!("/api/sessions/{}", id)).await
+    }
+
+    /// Sessions of a task, oldest first
+    pub async fn list_task_sessions(&self, task_id: Uuid) -> Result<Vec<Session>> {
+        self.get(&format!("/api/tasks/{}/sessions", task_id)).await
+    }
+
+    // ------------------------------------------------------------------
+    // Workspaces
+    // ------------------------------------------------------------------
+
+    pub async fn list_workspaces(&self, params: &ListParams) -> Result<Page<WorkspaceResponse>> {
+        self.list("/api/workspaces", params).await
+    }
+
+    pub async fn create_workspace(&self, task_id: Uuid) -> Result<WorkspaceResponse> {
+        self.send_json(
+            Method::POST,
+            &format!("/api/tasks/{}/workspace", task_id),
+            &Value::Null,
+        )
+        .await
+    }
+
+    pub async fn get_workspace_status(&self, task_id: Uuid) -> Result<WorkspaceStatusResponse> {
+        self.get(&format!("/api/workspaces/{}", task_id)).await
+    }
+
+    pub async fn get_workspace_diff(&self, task_id: Uuid) -> Result<DiffResponse> {
+        self.get(&format!("/api/workspaces/{}/diff", task_id)).await
+    }
+
+    /// Merge the task's workspace into the main branch
+    pub async fn merge_workspace(
+        &self,
+        task_id: Uuid,
+        message: impl Into<String>,
+    ) -> Result<MergeResponse> {
+        self.send_json(
+            Method::POST,
+            &format!("/api/workspaces/{}/merge", task_id),
+            &MergeRequest {
+                message: message.into(),
+            },
+        )
+        .await
+    }
+
+    pub async fn delete_workspace(&self, task_id: Uuid) -> Result<()> {
+        self.delete(&format!("/api/workspaces/{}", task_id)).await
+    }
+
+    // ------------------------------------------------------------------
+    // Wiki
+    // ------------------------------------------------------------------
+
+    pub async fn get_wiki_status(&self) -> Result<WikiStatusResponse> {
+        self.get("/api/wiki/status").await
+    }
+
+    /// Start indexing a branch; follow it by the returned job ID
+    pub async fn start_indexing(&self, request: &IndexRequest) -> Result<IndexResponse> {
+        self.send_json(Method::POST, "/api/wiki/index", request)
+            .await
+    }
+
+    pub async fn search_wiki(&self, request: &SearchRequest) -> Result<WikiSearchResponse> {
+        self.send_json(Method::POST, "/api/wiki/search", request)
+            .await
+    }
+
+    pub async fn get_wiki_page(&self, slug: &str) -> Result<WikiPageResponse> {
+        self.get(&format!("/api/wiki/pages/{}", slug)).await
+    }
+
+    // ------------------------------------------------------------------
+    // Findings
+    // ------------------------------------------------------------------
+
+    pub async fn get_findings(&self, task_id: Uuid) -> Result<FindingsResponse> {
+        self.get(&format!("/api/tasks/{}/findings", task_id)).await
+    }
+
+    /// Start a fix session for the selected findings
+    pub async fn fix_findings(
+        &self,
+        task_id: Uuid,
+        request: &FixFindingsRequest,
+    ) -> Result<ExecuteResponse> {
+        self.send_json(
+            Method::POST,
+            &format!("/api/tasks/{}/findings/fix", task_id),
+            request,
+        )
+        .await
+    }
+
+    /// Skip the remaining findings and move the task on to review
+    pub async fn skip_findings(&self, task_id: Uuid) -> Result<Task> {
+        self.send_json(
+            Method::POST,
+            &format!("/api/tasks/{}/findings/skip", task_id),
+            &Value::Null,
+        )
+        .await
+    }
+
+    pub async fn list_finding_comments(
+        &self,
+        task_id: Uuid,
+        finding_id: &str,
+    ) -> Result<Vec<FindingComment>> {
+        self.get(&format!(
+            "/api/tasks/{}/findings/{}/comments",
+            task_id, finding_id
+        ))
+        .await
+    }
+
+    pub async fn add_finding_comment(
+        &self,
+        task_id: Uuid,
+        finding_id: &str,
+        body: impl Into<String>,
+    ) -> Result<FindingComment> {
+        self.send_json(
+            Method::POST,
+            &format!("/api/tasks/{}/findings/{}/comments", task_id, finding_id),
+            &AddFindingCommentRequest { body: body.into() },
+        )
+        .await
+    }
+
+    // ------------------------------------------------------------------
+    // Transport
+    // ------------------------------------------------------------------
+
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let response = self.send(Method::GET, path, &[], None).await?;
+        decode(response).await
+    }
+
+    async fn send_json<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        body: &impl Serialize,
+    ) -> Result<T> {
+        let body = serde_json::to_value(body).map_err(|e| StudioError::Decode(e.to_string()))?;
+        let response = self.send(method, path, &[], Some(&body)).await?;
+        decode(response).await
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        self.send(Method::DELETE, path, &[], None).await?;
+        Ok(())
+    }
+
+    async fn list<T: DeserializeOwned>(&self, path: &str, params: &ListParams) -> Result<Page<T>> {
+        let mut query = Vec::new();
+        if let Some(limit) = params.limit {
+            query.push(("limit", limit.to_string()));
+        }
+        if let Some(offset) = params.offset {
+            query.push(("offset", offset.to_string()));
+        }
+        if let Some(sort) = &params.sort {
+            query.push(("sort", sort.clone()));
+        }
+
+        let response = self.send(Method::GET, path, &query, None).await?;
+        let total = response
+            .headers()
+            .get(TOTAL_COUNT_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok());
+        let items = decode(response).await?;
+        Ok(Page { items, total })
+    }
+
+    /// Send a request, retrying failures the retry policy allows, and
+    /// return the first successful response
+    async fn send(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, String)],
+        body: Option<&Value>,
+    ) -> Result<Response> {
+        let url = format!("{}{}", self.base_url, path);
+        let mut attempt = 0;
+        loop {
+            let mut request = self
+                .http
+                .request(method.clone(), &url)
+                .timeout(self.timeout)
+                .query(query);
+            if let Some(token) = &self.token {
+                request = request.bearer_auth(token);
+            }
+            if let Some(body) = body.filter(|b| !b.is_null()) {
+                request = request.json(body);
+            }
+
+            let (failure, retry_after) = match request.send().await {
+                Ok(response) if response.status().is_success() => return Ok(response),
+                Ok(response) => {
+                    let failure = Failure::Status(response.status());
+                    if !self.retry.should_retry(&method, failure, attempt) {
+                        return Err(api_error(response).await);
+                    }
+                    (failure, retry_after(&response))
+                }
+                Err(e) => {
+                    let failure = if e.is_connect() {
+                        Failure::Connect
+                    } else if e.is_timeout() {
+                        Failure::Timeout
+                    } else {
+                        return Err(e.into());
+                    };
+                    if !self.retry.should_retry(&method, failure, attempt) {
+                        return Err(e.into());
+                    }
+                    (failure, None)
+                }
+            };
+
+            let delay = self.retry.backoff(attempt, retry_after, jitter());
+            debug!(
+                method = %method,
+                path = %path,
+                attempt = attempt + 1,
+                delay_ms = delay.as_millis() as u64,
+                "Retrying request after {:?}",
+                failure
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+/// Seconds the server asked to wait before retrying
+fn retry_after(response: &Response) -> Option<u64> {
+    response
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse().ok())
+}
+
+async fn decode<T: DeserializeOwned>(response: Response) -> Result<T> {
+    let bytes = response.bytes().await?;
+    // Endpoints without a body decode as `()` and the like
+    let bytes: &[u8] = if bytes.is_empty() { b"null" } else { &bytes };
+    serde_json::from_slice(bytes).map_err(|e| StudioError::Decode(e.to_string()))
+}
+
+async fn api_error(response: Response) -> StudioError {
+    let status = response.status();
+    let text = response.text().await.unwrap_or_default();
+    match serde_json::from_str::<ErrorBody>(&text) {
+        Ok(body) => StudioError::Api {
+            status,
+            error: body.error,
+            message: body.message,
+        },
+        Err(_) => StudioError::Api {
+            status,
+            error: status
+                .canonical_reason()
+                .unwrap_or("error")
+                .to_lowercase()
+                .replace(' ', "_"),
+            message: if text.is_empty() {
+                status.to_string()
+            } else {
+                text
+            },
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::FindingSeverity;
+    use wiremock::matchers::{body_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn task_json(id: Uuid, title: &str) -> Value {
+        serde_json::json!({
+            "id": id,
+            "title": title,
+            "description": "",
+            "status": "todo",
+            "roadmap_item_id": null,
+            "workspace_path": null,
+            "depends_on": [],
+            "pr_number": null,
+            "created_at": "2026-10-01T12:00:00Z",
+            "updated_at": "2026-10-01T12:00:00Z"
+        })
+    }
+
+    fn client(server: &MockServer) -> StudioClient {
+        StudioClient::new(server.uri())
+            .unwrap()
+            .with_retry(RetryPolicy {
+                max_retries: 2,
+                initial_backoff: Duration::from_millis(1),
+                max_backoff: Duration::from_millis(5),
+            })
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_reads_page_and_sends_token() {
+        let server = MockServer::start().await;
+        let id = Uuid::new_v4();
+        Mock::given(method("GET"))
+            .and(path("/api/tasks"))
+            .and(query_param("limit", "1"))
+            .and(query_param("sort", "-updated_at"))
+            .and(header("authorization", "Bearer ocs_test"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("X-Total-Count", "7")
+                    .set_body_json(vec![task_json(id, "Add login")]),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let page = client(&server)
+            .with_token("ocs_test")
+            .list_tasks(&ListParams::page(1, 0).with_sort("-updated_at"))
+            .await
+            .unwrap();
+        assert_eq!(page.total, Some(7));
+        assert_eq!(page.items[0].id, id);
+        assert_eq!(page.items[0].title, "Add login");
+    }
+
+    #[tokio::test]
+    async fn test_retries_busy_server_then_succeeds() {
+        let server = MockServer::start().await;
+        let task_id = Uuid::new_v4();
+        let findings_path = format!("/api/tasks/{}/findings", task_id);
+        Mock::given(method("GET"))
+            .and(path(findings_path.as_str()))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(findings_path.as_str()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "findings": [{
+                    "id": "finding-1",
+                    "file_path": "src/lib.rs",
+                    "line_start": 3,
+                    "title": "Unchecked index",
+                    "description": "Panics on empty input",
+                    "severity": "error",
+                    "status": "pending",
+                    "consensus_score": 0.5
+                }],
+                "summary": "One issue",
+                "approved": false,
+                "exists": true
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let findings = client(&server).get_findings(task_id).await.unwrap();
+        assert_eq!(findings.findings.len(), 1);
+        assert_eq!(findings.findings[0].severity, FindingSeverity::Error);
+        assert!(findings.findings[0].comments.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_api_errors_are_not_retried() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/tasks"))
+            .and(body_json(serde_json::json!({
+                "title": "",
+                "description": "",
+                "roadmap_item_id": null
+            })))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": "bad_request",
+                "message": "Title cannot be empty"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/wiki/search"))
+            .respond_with(ResponseTemplate::new(502))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        let err = client
+            .create_task(&CreateTaskRequest {
+                title: String::new(),
+                description: String::new(),
+                roadmap_item_id: None,
+            })
+            .await
+            .unwrap_err();
+        match err {
+            StudioError::Api {
+                status,
+                error,
+                message,
+            } => {
+                assert_eq!(status, reqwest::StatusCode::BAD_REQUEST);
+                assert_eq!(error, "bad_request");
+                assert_eq!(message, "Title cannot be empty");
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+
+        // A POST may have been acted on behind a bad gateway
+        let err = client
+            .search_wiki(&SearchRequest::new("login"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), Some(reqwest::StatusCode::BAD_GATEWAY));
+    }
+
+    #[test]
+    fn test_new_rejects_urls_without_scheme() {
+        assert!(StudioClient::new("localhost:3001").is_err());
+        let client = StudioClient::new("http://localhost:3001/").unwrap();
+        assert_eq!(client.base_url(), "http://localhost:3001");
+    }
+}
@@ -0,0 +1,40 @@
+use reqwest::StatusCode;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum StudioError {
+    /// The server answered with an error status
+    #[error("{message} ({status}, {error})")]
+    Api {
+        status: StatusCode,
+        /// Error type the server reported, e.g. `not_found`
+        error: String,
+        message: String,
+    },
+
+    #[error("Network error: {0}")]
+    Network(#[from] reqwest::Error),
+
+    #[error("Failed to decode response: {0}")]
+    Decode(String),
+
+    #[error("Invalid configuration: {0}")]
+    Config(String),
+}
+
+impl StudioError {
+    /// Status the server answered with, for API errors
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            StudioError::Api { status, .. } => Some(*status),
+            StudioError::Network(e) => e.status(),
+            _ => None,
+        }
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(StatusCode::NOT_FOUND)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, StudioError>;
@@ -0,0 +1,36 @@
+//! Typed client for the OpenCode Studio HTTP API
+//!
+//! Covers tasks, sessions, workspaces, the wiki and review findings, for
+//! scripts that drive a running server:
+//!
+//! ```no_run
+//! # async fn run() -> studio_client::Result<()> {
+//! use studio_client::{ListParams, StudioClient};
+//!
+//! let client = StudioClient::from_env()?;
+//! for task in client.list_tasks(&ListParams::default()).await?.items {
+//!     println!("{} {:?}", task.title, task.status);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`blocking::StudioClient`] offers the same calls without an async
+//! runtime. Failed requests are retried as [`RetryPolicy`] allows.
+
+pub mod blocking;
+pub mod client;
+pub mod error;
+pub mod retry;
+pub mod types;
+
+pub use client::{StudioClient, DEFAULT_BASE_URL, DEFAULT_TIMEOUT};
+pub use error::{Result, StudioError};
+pub use retry::RetryPolicy;
+pub use types::{
+    AddFindingCommentRequest, BranchStatus, ConflictedFile, DiffResponse, ExecuteResponse,
+    FindingComment, FindingSeverity, FindingStatus, FindingsResponse, FixFindingsRequest,
+    IndexRequest, IndexResponse, ListParams, MergeRequest, MergeResponse, Page, ReviewFinding,
+    SearchRequest, TransitionRequest, TransitionResponse, WikiPageResponse, WikiSearchResponse,
+    WikiSearchResult, WikiStatusResponse, WorkspaceResponse, WorkspaceStatusResponse,
+};
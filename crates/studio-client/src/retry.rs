@@ -0,0 +1,130 @@
+//! When and how long to wait before retrying a request
+//!
+//! Requests that never reached the server, and ones it turned away because
+//! it was busy (429, 502, 503, 504), are retried with exponential backoff and
+//! jitter. Requests that aren't safe to repeat (`POST`, `PATCH`) are only
+//! retried when the server can't have acted on them: on connection errors
+//! and on 429 or 503.
+
+use std::time::Duration;
+
+use reqwest::{Method, StatusCode};
+use uuid::Uuid;
+
+/// Retries of a failed request
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt; 0 disables retrying
+    pub max_retries: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(250),
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+}
+
+/// How an attempt failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Failure {
+    /// The request never reached the server
+    Connect,
+    /// The request was sent but no response came back in time
+    Timeout,
+    Status(StatusCode),
+}
+
+impl RetryPolicy {
+    /// Never retry
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Whether a `method` request that failed with `failure` on retry
+    /// `attempt` (counting from zero) should be tried again
+    pub(crate) fn should_retry(&self, method: &Method, failure: Failure, attempt: u32) -> bool {
+        if attempt >= self.max_retries {
+            return false;
+        }
+        let idempotent = matches!(
+            *method,
+            Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS
+        );
+        match failure {
+            Failure::Connect => true,
+            Failure::Timeout => idempotent,
+            Failure::Status(StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE) => {
+                true
+            }
+            Failure::Status(StatusCode::BAD_GATEWAY | StatusCode::GATEWAY_TIMEOUT) => idempotent,
+            Failure::Status(_) => false,
+        }
+    }
+
+    /// Delay before retry `attempt` (counting from zero). The server's
+    /// `Retry-After` wins when given; otherwise the backoff doubles each
+    /// attempt, and `jitter` in `[0, 1)` spreads the upper half of it.
+    pub(crate) fn backoff(&self, attempt: u32, retry_after: Option<u64>, jitter: f64) -> Duration {
+        if let Some(secs) = retry_after {
+            return Duration::from_secs(secs).min(self.max_backoff);
+        }
+        let exponential = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_backoff);
+        exponential / 2 + exponential.mul_f64(jitter.clamp(0.0, 1.0) / 2.0)
+    }
+}
+
+/// A value in `[0, 1)` to spread retries with
+pub(crate) fn jitter() -> f64 {
+    (Uuid::new_v4().as_u128() as u64 >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_safe_failures_are_retried() {
+        let policy = RetryPolicy::default();
+        let unavailable = Failure::Status(StatusCode::SERVICE_UNAVAILABLE);
+        let bad_gateway = Failure::Status(StatusCode::BAD_GATEWAY);
+
+        assert!(policy.should_retry(&Method::POST, Failure::Connect, 0));
+        assert!(policy.should_retry(&Method::POST, unavailable, 2));
+        assert!(!policy.should_retry(&Method::POST, unavailable, 3));
+        assert!(!policy.should_retry(&Method::POST, Failure::Timeout, 0));
+        assert!(!policy.should_retry(&Method::PATCH, bad_gateway, 0));
+        assert!(policy.should_retry(&Method::GET, bad_gateway, 0));
+        assert!(!policy.should_retry(
+            &Method::GET,
+            Failure::Status(StatusCode::INTERNAL_SERVER_ERROR),
+            0
+        ));
+        assert!(!RetryPolicy::none().should_retry(&Method::GET, Failure::Connect, 0));
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_max() {
+        let policy = RetryPolicy {
+            max_retries: 5,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(300),
+        };
+
+        assert_eq!(policy.backoff(0, None, 0.0), Duration::from_millis(50));
+        assert_eq!(policy.backoff(1, None, 0.5), Duration::from_millis(150));
+        assert_eq!(policy.backoff(4, None, 0.0), Duration::from_millis(150));
+        assert_eq!(policy.backoff(0, Some(60), 0.0), Duration::from_millis(300));
+    }
+}
@@ -0,0 +1,296 @@
+//! Request and response bodies of the Studio API
+//!
+//! Tasks and sessions use the domain types of `opencode_core`. The rest
+//! mirror the server's route types; responses keep the fields scripts need,
+//! and fields the server adds later are ignored.
+
+use chrono::{DateTime, Utc};
+use opencode_core::{Task, TaskStatus};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Paging of list endpoints
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ListParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+    /// Field to sort by, prefixed with `-` for descending order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+}
+
+impl ListParams {
+    pub fn page(limit: u32, offset: u32) -> Self {
+        Self {
+            limit: Some(limit),
+            offset: Some(offset),
+            sort: None,
+        }
+    }
+
+    pub fn with_sort(mut self, sort: impl Into<String>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+}
+
+/// One page of a list
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Items across all pages, from `X-Total-Count`
+    pub total: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TransitionRequest {
+    pub status: TaskStatus,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TransitionResponse {
+    pub task: Task,
+    pub previous_status: TaskStatus,
+    /// The workflow starts the new status' phase after this transition
+    pub run_phase: bool,
+}
+
+/// A phase session started for a task
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExecuteResponse {
+    pub task: Task,
+    pub session_id: String,
+    pub opencode_session_id: String,
+    pub phase: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FindingSeverity {
+    Error,
+    Warning,
+    Info,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FindingStatus {
+    Pending,
+    Fixed,
+    Skipped,
+    Dismissed,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReviewFinding {
+    pub id: String,
+    #[serde(default)]
+    pub file_path: Option<String>,
+    #[serde(default)]
+    pub line_start: Option<i32>,
+    #[serde(default)]
+    pub line_end: Option<i32>,
+    pub title: String,
+    pub description: String,
+    pub severity: FindingSeverity,
+    pub status: FindingStatus,
+    #[serde(default)]
+    pub acceptance_criteria: Option<String>,
+    /// Fix the reviewer proposes, as a unified diff against the workspace
+    #[serde(default)]
+    pub suggested_fix: Option<String>,
+    /// The lines the finding points at, as they read when it was created
+    #[serde(default)]
+    pub snippet: Option<String>,
+    #[serde(default)]
+    pub comments: Vec<FindingComment>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FindingComment {
+    pub id: Uuid,
+    /// `human` or `agent`
+    pub author: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FindingsResponse {
+    pub findings: Vec<ReviewFinding>,
+    pub summary: String,
+    pub approved: bool,
+    /// Whether the task has been reviewed at all
+    pub exists: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FixFindingsRequest {
+    /// IDs of findings to fix, or empty to fix all
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finding_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix_all: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AddFindingCommentRequest {
+    pub body: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkspaceResponse {
+    pub task_id: String,
+    pub path: String,
+    pub branch_name: String,
+    pub status: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkspaceStatusResponse {
+    pub task_id: String,
+    pub status: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DiffResponse {
+    pub task_id: String,
+    pub diff: String,
+    /// Files a merge left conflicted; empty unless the workspace holds an
+    /// unresolved merge
+    #[serde(default)]
+    pub conflicts: Vec<ConflictedFile>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConflictedFile {
+    pub path: String,
+    /// e.g. `content` or `modify_delete`
+    pub conflict_type: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MergeRequest {
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+pub enum MergeResponse {
+    Success,
+    Conflicts { files: Vec<String> },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WikiStatusResponse {
+    pub enabled: bool,
+    pub configured: bool,
+    pub branches: Vec<BranchStatus>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BranchStatus {
+    pub branch: String,
+    /// e.g. `indexing`, `ready` or `failed`
+    pub state: String,
+    pub file_count: u32,
+    pub chunk_count: u32,
+    pub page_count: u32,
+    #[serde(default)]
+    pub last_indexed_at: Option<String>,
+    pub progress_percent: u8,
+    #[serde(default)]
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IndexRequest {
+    /// Branch to index, defaults to the project's default branch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// Re-index every file, not only changed ones
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+    /// Index the code without generating wiki pages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index_only: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IndexResponse {
+    pub started: bool,
+    pub branch: String,
+    pub message: String,
+    /// Job indexing the branch, whose logs are at `/api/jobs/{id}/logs`
+    #[serde(default)]
+    pub job_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SearchRequest {
+    pub query: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+    /// Most results kept from one file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_per_file: Option<usize>,
+    /// Only search code under this workspace root
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub root: Option<String>,
+    /// Keep only code tagged as public API
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_api_only: Option<bool>,
+    /// Search the index as of this commit instead of the current one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub as_of: Option<String>,
+}
+
+impl SearchRequest {
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            query: query.into(),
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WikiSearchResponse {
+    pub query: String,
+    #[serde(default)]
+    pub expanded_terms: Vec<String>,
+    pub results: Vec<WikiSearchResult>,
+    pub total_count: u32,
+    pub duration_ms: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WikiSearchResult {
+    pub file_path: String,
+    pub start_line: u32,
+    pub end_line: u32,
+    pub content: String,
+    #[serde(default)]
+    pub summary: Option<String>,
+    #[serde(default)]
+    pub language: Option<String>,
+    pub score: f32,
+    #[serde(default)]
+    pub is_public_api: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WikiPageResponse {
+    pub slug: String,
+    pub title: String,
+    pub content: String,
+    pub page_type: String,
+    #[serde(default)]
+    pub parent_slug: Option<String>,
+    #[serde(default)]
+    pub file_paths: Vec<String>,
+    pub updated_at: String,
+}
@@ -0,0 +1,264 @@
+//! Parsing of the conflict markers git and jujutsu write into conflicted files
+
+use std::path::Path;
+
+use crate::error::VcsError;
+use crate::paths::to_slash;
+use crate::traits::{ConflictFile, ConflictHunk};
+
+/// Shortest run of marker characters that opens a marker line; jujutsu uses
+/// longer runs when the file's content contains a run this long
+const MARKER_LEN: usize = 7;
+
+/// Where the lines inside a conflict go
+#[derive(Clone, Copy)]
+enum Section {
+    /// A side's lines verbatim (git's first and second sections, jujutsu's
+    /// `+++++++`)
+    Side,
+    /// The base's lines verbatim (git's `|||||||`, jujutsu's `-------`)
+    Base,
+    /// Jujutsu's `%%%%%%%`: a diff from the base to a side
+    Diff,
+}
+
+#[derive(Default)]
+struct Hunk {
+    start_line: u32,
+    sides: Vec<String>,
+    bases: Vec<String>,
+    section: Option<Section>,
+    /// Whether the markers are jujutsu's rather than git's
+    jujutsu: bool,
+}
+
+impl Hunk {
+    fn start(&mut self, section: Section) {
+        self.section = Some(section);
+        match section {
+            Section::Side => self.sides.push(String::new()),
+            Section::Base => self.bases.push(String::new()),
+            Section::Diff => {
+                self.sides.push(String::new());
+                self.bases.push(String::new());
+            }
+        }
+    }
+
+    fn push(&mut self, line: &str) {
+        let (side, base) = match self.section {
+            Some(Section::Side) => (Some(line), None),
+            Some(Section::Base) => (None, Some(line)),
+            Some(Section::Diff) => {
+                if let Some(rest) = line.strip_prefix('+') {
+                    (Some(rest), None)
+                } else if let Some(rest) = line.strip_prefix('-') {
+                    (None, Some(rest))
+                } else {
+                    // Unprefixed empty lines are unchanged empty lines
+                    let rest = line.strip_prefix(' ').unwrap_or(line);
+                    (Some(rest), Some(rest))
+                }
+            }
+            None => (None, None),
+        };
+        if let (Some(line), Some(lines)) = (side, self.sides.last_mut()) {
+            lines.push_str(line);
+            lines.push('\n');
+        }
+        if let (Some(line), Some(lines)) = (base, self.bases.last_mut()) {
+            lines.push_str(line);
+            lines.push('\n');
+        }
+    }
+
+    fn finish(self, path: &Path, end_line: u32) -> ConflictHunk {
+        let mut sides = self.sides.into_iter();
+        ConflictHunk {
+            path: path.to_path_buf(),
+            start_line: self.start_line,
+            end_line,
+            ours: sides.next().unwrap_or_default(),
+            theirs: sides.next().unwrap_or_default(),
+            base: self.bases.into_iter().next(),
+        }
+    }
+}
+
+/// Whether `line` is a marker made of `ch`: a run of at least seven of it,
+/// alone or followed by a space and a label
+fn is_marker(line: &str, ch: char) -> bool {
+    let run = line.chars().take_while(|c| *c == ch).count();
+    run >= MARKER_LEN && matches!(line[run..].chars().next(), None | Some(' '))
+}
+
+/// Conflicted regions of `content`, the conflicted file at `path`.
+///
+/// Understands git's markers, with or without the base section, and
+/// jujutsu's, whose sections are either a side's or the base's lines or a
+/// diff from the base to a side. With jujutsu the first side is ours and
+/// the second theirs, as for `jj resolve --tool :ours`; conflicts between
+/// more than two sides keep only the first two.
+pub fn parse_conflict_hunks(path: &Path, content: &str) -> Vec<ConflictHunk> {
+    let mut hunks = Vec::new();
+    let mut current: Option<Hunk> = None;
+
+    for (index, line) in content.lines().enumerate() {
+        let line_number = index as u32 + 1;
+
+        let Some(hunk) = current.as_mut() else {
+            if is_marker(line, '<') {
+                current = Some(Hunk {
+                    start_line: line_number,
+                    ..Hunk::default()
+                });
+            }
+            continue;
+        };
+
+        if is_marker(line, '>') {
+            if let Some(hunk) = current.take() {
+                hunks.push(hunk.finish(path, line_number));
+            }
+            continue;
+        }
+
+        let jj_section = if is_marker(line, '%') {
+            Some(Section::Diff)
+        } else if is_marker(line, '+') {
+            Some(Section::Side)
+        } else if is_marker(line, '-') {
+            Some(Section::Base)
+        } else {
+            None
+        };
+
+        // The first line after the opening marker tells the styles apart
+        if hunk.section.is_none() {
+            match jj_section {
+                Some(section) => {
+                    hunk.jujutsu = true;
+                    hunk.start(section);
+                    continue;
+                }
+                // Git's first side starts right after the opening marker
+                None => hunk.start(Section::Side),
+            }
+        }
+
+        match jj_section {
+            Some(section) if hunk.jujutsu => hunk.start(section),
+            // Newer jujutsu follows `%%%%%%%` with a `\\\\\\\` line
+            // naming the side the diff leads to
+            None if hunk.jujutsu
+                && matches!(hunk.section, Some(Section::Diff))
+                && is_marker(line, '\\') => {}
+            _ if !hunk.jujutsu && is_marker(line, '|') => hunk.start(Section::Base),
+            _ if !hunk.jujutsu && is_marker(line, '=') => hunk.start(Section::Side),
+            _ => hunk.push(line),
+        }
+    }
+
+    hunks
+}
+
+/// Error for committing a resolution while `conflicts` remain
+pub(crate) fn unresolved(conflicts: &[ConflictFile]) -> VcsError {
+    let paths: Vec<String> = conflicts.iter().map(|c| to_slash(&c.path)).collect();
+    VcsError::MergeConflict(format!(
+        "Unresolved conflicts remain in: {}",
+        paths.join(", ")
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(content: &str) -> Vec<ConflictHunk> {
+        parse_conflict_hunks(Path::new("src/lib.rs"), content)
+    }
+
+    #[test]
+    fn test_git_markers() {
+        let hunks = parse(
+            "fn main() {\n\
+             <<<<<<< HEAD\n\
+             \x20   println!(\"main\");\n\
+             =======\n\
+             \x20   println!(\"task\");\n\
+             >>>>>>> task-1\n\
+             }\n",
+        );
+
+        assert_eq!(hunks.len(), 1);
+        assert_eq!(hunks[0].path, Path::new("src/lib.rs"));
+        assert_eq!((hunks[0].start_line, hunks[0].end_line), (2, 6));
+        assert_eq!(hunks[0].ours, "    println!(\"main\");\n");
+        assert_eq!(hunks[0].theirs, "    println!(\"task\");\n");
+        assert_eq!(hunks[0].base, None);
+    }
+
+    #[test]
+    fn test_git_diff3_markers() {
+        let hunks = parse(
+            "<<<<<<< ours\na = 2\n||||||| base\na = 1\n=======\na = 3\n>>>>>>> theirs\n\
+             ok\n<<<<<<< ours\n=======\nb = 1\n>>>>>>> theirs\n",
+        );
+
+        assert_eq!(hunks.len(), 2);
+        assert_eq!(hunks[0].ours, "a = 2\n");
+        assert_eq!(hunks[0].base.as_deref(), Some("a = 1\n"));
+        assert_eq!(hunks[0].theirs, "a = 3\n");
+        assert_eq!(hunks[1].start_line, 9);
+        assert_eq!(hunks[1].ours, "");
+        assert_eq!(hunks[1].theirs, "b = 1\n");
+    }
+
+    #[test]
+    fn test_jujutsu_diff_markers() {
+        let hunks = parse(
+            "<<<<<<< Conflict 1 of 1\n\
+             %%%%%%% Changes from base to side #1\n\
+             -a = 1\n\
+             +a = 2\n\
+             \x20b = 1\n\
+             +++++++ Contents of side #2\n\
+             a = 3\n\
+             b = 1\n\
+             >>>>>>> Conflict 1 of 1 ends\n",
+        );
+
+        assert_eq!(hunks.len(), 1);
+        assert_eq!((hunks[0].start_line, hunks[0].end_line), (1, 9));
+        assert_eq!(hunks[0].ours, "a = 2\nb = 1\n");
+        assert_eq!(hunks[0].base.as_deref(), Some("a = 1\nb = 1\n"));
+        assert_eq!(hunks[0].theirs, "a = 3\nb = 1\n");
+    }
+
+    #[test]
+    fn test_jujutsu_snapshot_markers() {
+        let hunks = parse(
+            "<<<<<<<<<<< conflict 1 of 1\n\
+             +++++++++++ side #1\n\
+             x = 2\n\
+             ----------- base\n\
+             x = 1\n\
+             +++++++++++ side #2\n\
+             ======= not a marker here\n\
+             >>>>>>>>>>> conflict 1 of 1 ends\n",
+        );
+
+        assert_eq!(hunks.len(), 1);
+        assert_eq!(hunks[0].ours, "x = 2\n");
+        assert_eq!(hunks[0].base.as_deref(), Some("x = 1\n"));
+        assert_eq!(hunks[0].theirs, "======= not a marker here\n");
+    }
+
+    #[test]
+    fn test_no_markers() {
+        assert!(parse("<<<<<< six is too short\nplain\n").is_empty());
+        // An unterminated conflict is not reported
+        assert!(parse("<<<<<<< HEAD\na\n=======\n").is_empty());
+    }
+}
@@ -0,0 +1,576 @@
+//! Workspaces for projects without version control
+//!
+//! Without branches or worktrees, a task workspace is a plain copy of the
+//! project directory. A second copy taken at the same time is kept as the
+//! workspace's baseline: diffs compare the workspace against it, and merging
+//! copies changed files back once it has checked that the project's own copy
+//! of each file still matches the baseline. There is no history to write to,
+//! so commit and push fail with [`VcsError::Unsupported`].
+
+use async_trait::async_trait;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use tokio::process::Command;
+use tracing::debug;
+
+use crate::error::{Result, VcsError};
+use crate::paths::{long_path, to_slash};
+use crate::traits::{
+    ConflictFile, ConflictType, DiffSummary, MergeResult, VcsCapabilities, VersionControl,
+    Workspace,
+};
+
+/// Directories never copied into workspaces. Dependency and build
+/// directories are symlinked into workspaces by the workspace manager.
+pub(crate) const SKIPPED_DIRS: &[&str] = &[".opencode-studio", "node_modules", "target", ".venv"];
+
+/// Directory under the workspace base holding the baselines
+pub(crate) const BASELINES_DIR: &str = ".baselines";
+
+const NO_VCS_REASON: &str = "the project is not under version control";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FileChange {
+    Added,
+    Modified,
+    Deleted,
+}
+
+impl FileChange {
+    fn code(&self) -> char {
+        match self {
+            Self::Added => 'A',
+            Self::Modified => 'M',
+            Self::Deleted => 'D',
+        }
+    }
+}
+
+pub struct CopyVcs {
+    repo_path: PathBuf,
+    workspace_base: PathBuf,
+}
+
+impl CopyVcs {
+    pub fn new(repo_path: PathBuf, workspace_base: PathBuf) -> Self {
+        Self {
+            repo_path,
+            workspace_base,
+        }
+    }
+
+    fn workspace_name(&self, task_id: &str) -> String {
+        format!("task-{}", task_id)
+    }
+
+    fn workspace_path(&self, task_id: &str) -> PathBuf {
+        self.workspace_base.join(self.workspace_name(task_id))
+    }
+
+    fn baseline_path(&self, task_id: &str) -> PathBuf {
+        self.workspace_base
+            .join(BASELINES_DIR)
+            .join(self.workspace_name(task_id))
+    }
+
+    fn unsupported(&self, operation: &str) -> VcsError {
+        self.capabilities().unsupported(operation, NO_VCS_REASON)
+    }
+
+    /// Files changed in the workspace since it was copied
+    async fn changes(&self, workspace: &Workspace) -> Result<Vec<(PathBuf, FileChange)>> {
+        if !workspace.path.exists() {
+            return Err(VcsError::WorkspaceNotFound(workspace.task_id.clone()));
+        }
+
+        let baseline = self.baseline_path(&workspace.task_id);
+        let workspace_path = workspace.path.clone();
+        blocking(move || changed_files(&baseline, &workspace_path)).await
+    }
+
+    /// Check the workspace's changes against the project, copying them into
+    /// the project when `apply` is set and nothing conflicts
+    async fn reconcile(&self, workspace: &Workspace, apply: bool) -> Result<Vec<ConflictFile>> {
+        let changes = self.changes(workspace).await?;
+        let repo_path = self.repo_path.clone();
+        let baseline = self.baseline_path(&workspace.task_id);
+        let workspace_path = workspace.path.clone();
+
+        blocking(move || {
+            let conflicts = find_conflicts(&repo_path, &baseline, &workspace_path, &changes)?;
+            if apply && conflicts.is_empty() {
+                apply_changes(&repo_path, &baseline, &workspace_path, &changes)?;
+            }
+            Ok(conflicts)
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl VersionControl for CopyVcs {
+    fn name(&self) -> &'static str {
+        "none"
+    }
+
+    fn capabilities(&self) -> VcsCapabilities {
+        VcsCapabilities::copy_based()
+    }
+
+    async fn is_available(&self) -> bool {
+        true
+    }
+
+    async fn is_initialized(&self) -> Result<bool> {
+        Ok(self.repo_path.is_dir())
+    }
+
+    async fn create_workspace(&self, task_id: &str) -> Result<Workspace> {
+        let workspace_path = self.workspace_path(task_id);
+        if workspace_path.exists() {
+            return Err(VcsError::WorkspaceAlreadyExists(task_id.to_string()));
+        }
+
+        let repo_path = self.repo_path.clone();
+        let baseline = self.baseline_path(task_id);
+        let target = workspace_path.clone();
+        // Never copy the workspaces into themselves
+        let excluded = self
+            .workspace_base
+            .strip_prefix(&self.repo_path)
+            .ok()
+            .map(Path::to_path_buf);
+
+        debug!("Copying {:?} to {:?}", repo_path, workspace_path);
+        blocking(move || {
+            let files = list_files(&repo_path, excluded.as_deref())?;
+            for root in [&baseline, &target] {
+                std::fs::create_dir_all(long_path(root))?;
+                for file in &files {
+                    copy_file(&repo_path.join(file), &root.join(file))?;
+                }
+            }
+            Ok(())
+        })
+        .await?;
+
+        Ok(Workspace::new(
+            task_id,
+            workspace_path,
+            self.workspace_name(task_id),
+        ))
+    }
+
+    async fn get_diff(&self, workspace: &Workspace) -> Result<String> {
+        if !workspace.path.exists() {
+            return Err(VcsError::WorkspaceNotFound(workspace.task_id.clone()));
+        }
+
+        let old_prefix = format!("{}/{}", BASELINES_DIR, workspace.branch_name);
+        let new_prefix = workspace.branch_name.clone();
+        let mut command = Command::new("diff");
+        command.arg("-ruN");
+        for dir in SKIPPED_DIRS {
+            command.args(["-x", dir]);
+        }
+        let output = command
+            .arg(&old_prefix)
+            .arg(&new_prefix)
+            .current_dir(&self.workspace_base)
+            .output()
+            .await
+            .map_err(|e| match e.kind() {
+                std::io::ErrorKind::NotFound => VcsError::CommandNotFound("diff".to_string()),
+                _ => VcsError::Io(e),
+            })?;
+
+        // diff exits with 1 when the trees differ
+        if !matches!(output.status.code(), Some(0) | Some(1)) {
+            return Err(VcsError::CommandFailed(format!(
+                "diff failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )));
+        }
+
+        Ok(to_git_diff(
+            &String::from_utf8_lossy(&output.stdout),
+            &old_prefix,
+            &new_prefix,
+        ))
+    }
+
+    async fn get_status(&self, workspace: &Workspace) -> Result<String> {
+        Ok(self
+            .changes(workspace)
+            .await?
+            .into_iter()
+            .map(|(path, change)| format!("{} {}\n", change.code(), to_slash(&path)))
+            .collect())
+    }
+
+    async fn merge_workspace(&self, workspace: &Workspace, message: &str) -> Result<MergeResult> {
+        debug!(
+            "Copying workspace {} into the project ({})",
+            workspace.task_id, message
+        );
+        let conflicts = self.reconcile(workspace, true).await?;
+        if conflicts.is_empty() {
+            Ok(MergeResult::Success)
+        } else {
+            Ok(MergeResult::Conflicts { files: conflicts })
+        }
+    }
+
+    async fn cleanup_workspace(&self, workspace: &Workspace) -> Result<()> {
+        if workspace.path.exists() {
+            tokio::fs::remove_dir_all(long_path(&workspace.path)).await?;
+        }
+        let baseline = self.baseline_path(&workspace.task_id);
+        if baseline.exists() {
+            tokio::fs::remove_dir_all(long_path(&baseline)).await?;
+        }
+        Ok(())
+    }
+
+    async fn list_workspaces(&self) -> Result<Vec<Workspace>> {
+        list_copied_workspaces(&self.workspace_base).await
+    }
+
+    async fn get_conflicts(&self, workspace: &Workspace) -> Result<Vec<ConflictFile>> {
+        self.reconcile(workspace, false).await
+    }
+
+    async fn commit(&self, _workspace: &Workspace, _message: &str) -> Result<String> {
+        Err(self.unsupported("commit"))
+    }
+
+    async fn push(&self, _workspace: &Workspace, _remote: &str) -> Result<()> {
+        Err(self.unsupported("push"))
+    }
+
+    async fn head_commit(&self, _workspace: &Workspace) -> Result<String> {
+        Err(self.unsupported("head commit"))
+    }
+
+    async fn get_diff_summary(&self, workspace: &Workspace) -> Result<DiffSummary> {
+        let files_changed = self.changes(workspace).await?.len() as u32;
+        let diff = self.get_diff(workspace).await?;
+
+        let mut additions = 0;
+        let mut deletions = 0;
+        for line in diff.lines() {
+            if line.starts_with('+') && !line.starts_with("+++") {
+                additions += 1;
+            } else if line.starts_with('-') && !line.starts_with("---") {
+                deletions += 1;
+            }
+        }
+
+        Ok(DiffSummary {
+            files_changed,
+            additions,
+            deletions,
+        })
+    }
+
+    fn main_branch(&self) -> &str {
+        "main"
+    }
+
+    async fn has_uncommitted_changes(&self, workspace: &Workspace) -> Result<bool> {
+        Ok(!self.changes(workspace).await?.is_empty())
+    }
+}
+
+/// Workspaces are the `task-*` directories under the workspace base
+pub(crate) async fn list_copied_workspaces(workspace_base: &Path) -> Result<Vec<Workspace>> {
+    let mut workspaces = Vec::new();
+    if !workspace_base.exists() {
+        return Ok(workspaces);
+    }
+
+    let mut entries = tokio::fs::read_dir(workspace_base).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if let Some(task_id) = name.strip_prefix("task-") {
+            if entry.file_type().await?.is_dir() {
+                workspaces.push(Workspace::new(task_id, entry.path(), name.clone()));
+            }
+        }
+    }
+    Ok(workspaces)
+}
+
+pub(crate) async fn blocking<T: Send + 'static>(
+    f: impl FnOnce() -> std::io::Result<T> + Send + 'static,
+) -> Result<T> {
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| VcsError::CommandFailed(format!("File copy task failed: {}", e)))?
+        .map_err(VcsError::from)
+}
+
+/// Regular files under `root`, relative to it, leaving out `SKIPPED_DIRS` and
+/// `excluded`
+fn list_files(root: &Path, excluded: Option<&Path>) -> std::io::Result<BTreeSet<PathBuf>> {
+    let mut files = BTreeSet::new();
+    if !root.exists() {
+        return Ok(files);
+    }
+
+    let mut dirs = vec![PathBuf::new()];
+    while let Some(dir) = dirs.pop() {
+        for entry in std::fs::read_dir(long_path(&root.join(&dir)))? {
+            let entry = entry?;
+            let relative = dir.join(entry.file_name());
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                let skipped = SKIPPED_DIRS.iter().any(|d| entry.file_name() == *d)
+                    || excluded == Some(relative.as_path());
+                if !skipped {
+                    dirs.push(relative);
+                }
+            } else if file_type.is_file() {
+                files.insert(relative);
+            }
+        }
+    }
+    Ok(files)
+}
+
+pub(crate) fn copy_file(from: &Path, to: &Path) -> std::io::Result<()> {
+    let to = long_path(to);
+    if let Some(parent) = to.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::copy(long_path(from), to)?;
+    Ok(())
+}
+
+fn remove_file(path: &Path) -> std::io::Result<()> {
+    match std::fs::remove_file(long_path(path)) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+/// Whether `a` and `b` are both missing or both exist with the same content
+fn same_content(a: &Path, b: &Path) -> std::io::Result<bool> {
+    let (a, b) = (long_path(a), long_path(b));
+    match (a.is_file(), b.is_file()) {
+        (false, false) => Ok(true),
+        (true, true) => {
+            if std::fs::metadata(&a)?.len() != std::fs::metadata(&b)?.len() {
+                return Ok(false);
+            }
+            Ok(std::fs::read(&a)? == std::fs::read(&b)?)
+        }
+        _ => Ok(false),
+    }
+}
+
+fn changed_files(baseline: &Path, workspace: &Path) -> std::io::Result<Vec<(PathBuf, FileChange)>> {
+    let before = list_files(baseline, None)?;
+    let after = list_files(workspace, None)?;
+
+    let mut changes = Vec::new();
+    for path in before.union(&after) {
+        let change = match (before.contains(path), after.contains(path)) {
+            (true, false) => FileChange::Deleted,
+            (false, true) => FileChange::Added,
+            _ if same_content(&baseline.join(path), &workspace.join(path))? => continue,
+            _ => FileChange::Modified,
+        };
+        changes.push((path.clone(), change));
+    }
+    Ok(changes)
+}
+
+/// Changes that would overwrite edits made to the project since the
+/// workspace was copied
+fn find_conflicts(
+    repo: &Path,
+    baseline: &Path,
+    workspace: &Path,
+    changes: &[(PathBuf, FileChange)],
+) -> std::io::Result<Vec<ConflictFile>> {
+    let mut conflicts = Vec::new();
+    for (path, change) in changes {
+        let current = repo.join(path);
+        // The project already has exactly what the workspace has
+        if same_content(&current, &workspace.join(path))?
+            || same_content(&current, &baseline.join(path))?
+        {
+            continue;
+        }
+        let conflict_type = match (change, current.is_file()) {
+            (FileChange::Added, _) => ConflictType::AddAdd,
+            (FileChange::Modified, false) => ConflictType::ModifyDelete,
+            (FileChange::Deleted, _) => ConflictType::DeleteModify,
+            (FileChange::Modified, true) => ConflictType::Content,
+        };
+        conflicts.push(ConflictFile {
+            path: path.clone(),
+            conflict_type,
+        });
+    }
+    Ok(conflicts)
+}
+
+/// Copy the workspace's changes into the project and the baseline, so the
+/// workspace matches both afterwards
+fn apply_changes(
+    repo: &Path,
+    baseline: &Path,
+    workspace: &Path,
+    changes: &[(PathBuf, FileChange)],
+) -> std::io::Result<()> {
+    for (path, change) in changes {
+        for target in [repo, baseline] {
+            match change {
+                FileChange::Deleted => remove_file(&target.join(path))?,
+                _ => copy_file(&workspace.join(path), &target.join(path))?,
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Rewrite `diff -ruN` output between the baseline and workspace directories
+/// into git's `a/` and `b/` path style
+fn to_git_diff(raw: &str, old_prefix: &str, new_prefix: &str) -> String {
+    let old_prefix = format!("{}/", old_prefix);
+    let new_prefix = format!("{}/", new_prefix);
+    let new_separator = format!(" {}", new_prefix);
+
+    let mut diff = String::with_capacity(raw.len());
+    for line in raw.lines() {
+        let header = line
+            .strip_prefix("diff ")
+            .and_then(|rest| rest.split_once(&old_prefix))
+            .and_then(|(_, paths)| paths.rsplit_once(&new_separator))
+            .map(|(path, _)| format!("diff --git a/{} b/{}", path, path));
+        let old = line
+            .strip_prefix("--- ")
+            .and_then(|rest| rest.strip_prefix(&old_prefix))
+            .map(|rest| format!("--- a/{}", strip_timestamp(rest)));
+        let new = line
+            .strip_prefix("+++ ")
+            .and_then(|rest| rest.strip_prefix(&new_prefix))
+            .map(|rest| format!("+++ b/{}", strip_timestamp(rest)));
+
+        match header.or(old).or(new) {
+            Some(rewritten) => diff.push_str(&rewritten),
+            None => diff.push_str(line),
+        }
+        diff.push('\n');
+    }
+    diff
+}
+
+fn strip_timestamp(path: &str) -> &str {
+    path.split_once('\t').map_or(path, |(path, _)| path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn project() -> (TempDir, CopyVcs) {
+        let tmp = TempDir::new().unwrap();
+        let repo = tmp.path().join("project");
+        std::fs::create_dir_all(repo.join("src")).unwrap();
+        std::fs::create_dir_all(repo.join("node_modules/pkg")).unwrap();
+        std::fs::write(repo.join("src/main.py"), "print('hi')\n").unwrap();
+        std::fs::write(repo.join("README.md"), "# Project\n").unwrap();
+        std::fs::write(repo.join("node_modules/pkg/index.js"), "").unwrap();
+        let vcs = CopyVcs::new(repo, tmp.path().join(".workspaces"));
+        (tmp, vcs)
+    }
+
+    #[tokio::test]
+    async fn test_copy_workspace_merge() {
+        let (_tmp, vcs) = project();
+        let ws = vcs.create_workspace("1").await.unwrap();
+        assert!(ws.path.join("src/main.py").exists());
+        assert!(!ws.path.join("node_modules").exists());
+        assert!(!vcs.has_uncommitted_changes(&ws).await.unwrap());
+        assert_eq!(vcs.list_workspaces().await.unwrap().len(), 1);
+
+        std::fs::write(ws.path.join("src/main.py"), "print('hello')\n").unwrap();
+        std::fs::write(ws.path.join("src/util.py"), "X = 1\n").unwrap();
+        std::fs::remove_file(ws.path.join("README.md")).unwrap();
+
+        let status = vcs.get_status(&ws).await.unwrap();
+        assert_eq!(status, "D README.md\nM src/main.py\nA src/util.py\n");
+
+        let diff = vcs.get_diff(&ws).await.unwrap();
+        assert!(diff.contains("diff --git a/src/util.py b/src/util.py"));
+        assert!(diff.contains("+print('hello')"));
+        let summary = vcs.get_diff_summary(&ws).await.unwrap();
+        assert_eq!(summary.files_changed, 3);
+        assert_eq!(summary.additions, 2);
+        assert_eq!(summary.deletions, 2);
+
+        let result = vcs.merge_workspace(&ws, "Task 1").await.unwrap();
+        assert!(result.is_success());
+        let repo = &vcs.repo_path;
+        assert_eq!(
+            std::fs::read_to_string(repo.join("src/main.py")).unwrap(),
+            "print('hello')\n"
+        );
+        assert!(repo.join("src/util.py").exists());
+        assert!(!repo.join("README.md").exists());
+        assert!(!vcs.has_uncommitted_changes(&ws).await.unwrap());
+
+        vcs.cleanup_workspace(&ws).await.unwrap();
+        assert!(vcs.list_workspaces().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_merge_reports_conflicts_without_writing() {
+        let (_tmp, vcs) = project();
+        let ws = vcs.create_workspace("2").await.unwrap();
+        std::fs::write(ws.path.join("src/main.py"), "print('workspace')\n").unwrap();
+        std::fs::write(ws.path.join("README.md"), "# Workspace\n").unwrap();
+        std::fs::write(vcs.repo_path.join("src/main.py"), "print('project')\n").unwrap();
+
+        let result = vcs.merge_workspace(&ws, "Task 2").await.unwrap();
+        let conflicts = result.conflicts().unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].path, PathBuf::from("src/main.py"));
+        assert_eq!(conflicts[0].conflict_type, ConflictType::Content);
+        assert_eq!(
+            std::fs::read_to_string(vcs.repo_path.join("README.md")).unwrap(),
+            "# Project\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_commit_and_push_are_unsupported() {
+        let (_tmp, vcs) = project();
+        let ws = vcs.create_workspace("3").await.unwrap();
+
+        let err = vcs.commit(&ws, "message").await.unwrap_err();
+        assert!(matches!(err, VcsError::Unsupported { .. }));
+        assert!(err
+            .to_string()
+            .starts_with("commit is not supported: the project is not under version control"));
+        assert!(vcs.push(&ws, "origin").await.is_err());
+    }
+
+    #[test]
+    fn test_to_git_diff() {
+        let raw = "diff -ruN -x target .baselines/task-1/src/a b.rs task-1/src/a b.rs\n\
+                   --- .baselines/task-1/src/a b.rs\t2024-01-01 00:00:00\n\
+                   +++ task-1/src/a b.rs\t2024-01-02 00:00:00\n\
+                   @@ -1 +1 @@\n\
+                   -old\n\
+                   +new\n";
+        assert_eq!(
+            to_git_diff(raw, ".baselines/task-1", "task-1"),
+            "diff --git a/src/a b.rs b/src/a b.rs\n--- a/src/a b.rs\n+++ b/src/a b.rs\n@@ -1 +1 @@\n-old\n+new\n"
+        );
+    }
+}
@@ -28,6 +28,17 @@ pub enum VcsError {
 
     #[error("Parse error: {0}")]
     Parse(String),
+
+    #[error(
+        "{operation} is not supported: {reason} (unsupported operations: {})",
+        .unsupported.join(", ")
+    )]
+    Unsupported {
+        operation: String,
+        reason: String,
+        /// Every operation the backend lacks
+        unsupported: Vec<String>,
+    },
 }
 
 pub type Result<T> = std::result::Result<T, VcsError>;
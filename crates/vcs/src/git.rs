@@ -1,13 +1,25 @@
 use async_trait::async_trait;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tokio::process::Command;
 use tracing::{debug, info, warn};
 
+use crate::conflicts::unresolved;
 use crate::error::{Result, VcsError};
+use crate::paths::{long_path, to_slash};
 use crate::traits::{
-    ConflictFile, ConflictType, DiffSummary, MergeResult, VersionControl, Workspace,
+    ConflictFile, ConflictSide, ConflictType, DiffSummary, MergeResult, VersionControl, Workspace,
 };
 
+/// A git command; on Windows it lifts the 260 character path limit, which
+/// deep worktrees under the workspace base soon reach
+pub(crate) fn git_command() -> Command {
+    let mut command = Command::new("git");
+    if cfg!(windows) {
+        command.args(["-c", "core.longpaths=true"]);
+    }
+    command
+}
+
 pub struct GitVcs {
     repo_path: PathBuf,
     workspace_base: PathBuf,
@@ -31,11 +43,7 @@ impl GitVcs {
     async fn run_git(&self, args: &[&str], cwd: &PathBuf) -> Result<String> {
         debug!("Running git {:?} in {:?}", args, cwd);
 
-        let output = Command::new("git")
-            .args(args)
-            .current_dir(cwd)
-            .output()
-            .await?;
+        let output = git_command().args(args).current_dir(cwd).output().await?;
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
@@ -53,7 +61,7 @@ impl GitVcs {
     async fn run_git_checked(&self, args: &[&str], cwd: &PathBuf) -> bool {
         debug!("Running git (checked) {:?} in {:?}", args, cwd);
 
-        Command::new("git")
+        git_command()
             .args(args)
             .current_dir(cwd)
             .output()
@@ -70,6 +78,19 @@ impl GitVcs {
         format!("task-{}", task_id)
     }
 
+    /// Whether the workspace is partway through a rebase
+    async fn is_rebasing(&self, workspace: &Workspace) -> Result<bool> {
+        for dir in ["rebase-merge", "rebase-apply"] {
+            let path = self
+                .run_git(&["rev-parse", "--git-path", dir], &workspace.path)
+                .await?;
+            if workspace.path.join(path.trim()).exists() {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
     async fn get_repo_conflicts(&self) -> Result<Vec<ConflictFile>> {
         let output = self
             .run_git(&["diff", "--name-only", "--diff-filter=U"], &self.repo_path)
@@ -153,7 +174,7 @@ impl VersionControl for GitVcs {
     }
 
     async fn is_available(&self) -> bool {
-        Command::new("git")
+        git_command()
             .arg("--version")
             .output()
             .await
@@ -303,7 +324,7 @@ impl VersionControl for GitVcs {
             .await?;
 
         // Try merge with no-commit first to handle conflicts manually
-        let merge_result = Command::new("git")
+        let merge_result = git_command()
             .args(["merge", "--no-ff", "--no-commit", &workspace.branch_name])
             .current_dir(&self.repo_path)
             .output()
@@ -411,7 +432,7 @@ impl VersionControl for GitVcs {
             .await;
 
         if workspace.path.exists() {
-            tokio::fs::remove_dir_all(&workspace.path).await?;
+            tokio::fs::remove_dir_all(long_path(&workspace.path)).await?;
         }
 
         Ok(())
@@ -476,6 +497,72 @@ impl VersionControl for GitVcs {
         }
     }
 
+    async fn resolve_conflict(
+        &self,
+        workspace: &Workspace,
+        path: &Path,
+        side: ConflictSide,
+    ) -> Result<()> {
+        if !workspace.path.exists() {
+            return Err(VcsError::WorkspaceNotFound(workspace.task_id.clone()));
+        }
+
+        let path = to_slash(path);
+        let stages = self
+            .run_git(&["ls-files", "-u", "--", &path], &workspace.path)
+            .await?;
+        if stages.trim().is_empty() {
+            return Err(VcsError::InvalidPath(format!("{} is not conflicted", path)));
+        }
+
+        // Unmerged entries are "<mode> <object> <stage>\t<path>"; stage 2 is
+        // ours and 3 theirs, and a side without one deleted the file
+        let stage = match side {
+            ConflictSide::Ours => "2",
+            ConflictSide::Theirs => "3",
+        };
+        let side_has_file = stages
+            .lines()
+            .any(|line| line.split_whitespace().nth(2) == Some(stage));
+
+        if side_has_file {
+            let side_flag = format!("--{}", side.as_str());
+            self.run_git(&["checkout", &side_flag, "--", &path], &workspace.path)
+                .await?;
+            self.run_git(&["add", "--", &path], &workspace.path).await?;
+        } else {
+            self.run_git(&["rm", "--quiet", "--", &path], &workspace.path)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn commit_resolution(&self, workspace: &Workspace, message: &str) -> Result<String> {
+        let conflicts = self.get_conflicts(workspace).await?;
+        if !conflicts.is_empty() {
+            return Err(unresolved(&conflicts));
+        }
+
+        if self.is_rebasing(workspace).await? {
+            // The rebase keeps the message of the commit being replayed
+            self.run_git(
+                &["-c", "core.editor=true", "rebase", "--continue"],
+                &workspace.path,
+            )
+            .await?;
+        } else {
+            self.run_git(&["commit", "-m", message], &workspace.path)
+                .await?;
+        }
+
+        let output = self
+            .run_git(&["rev-parse", "HEAD"], &workspace.path)
+            .await?;
+
+        Ok(output.trim().to_string())
+    }
+
     async fn commit(&self, workspace: &Workspace, message: &str) -> Result<String> {
         if !workspace.path.exists() {
             return Err(VcsError::WorkspaceNotFound(workspace.task_id.clone()));
@@ -506,6 +593,18 @@ impl VersionControl for GitVcs {
         Ok(())
     }
 
+    async fn head_commit(&self, workspace: &Workspace) -> Result<String> {
+        if !workspace.path.exists() {
+            return Err(VcsError::WorkspaceNotFound(workspace.task_id.clone()));
+        }
+
+        let output = self
+            .run_git(&["rev-parse", "HEAD"], &workspace.path)
+            .await?;
+
+        Ok(output.trim().to_string())
+    }
+
     async fn get_diff_summary(&self, workspace: &Workspace) -> Result<DiffSummary> {
         if !workspace.path.exists() {
             return Err(VcsError::WorkspaceNotFound(workspace.task_id.clone()));
@@ -581,6 +680,77 @@ mod tests {
         assert_eq!(name, "task-abc-456");
     }
 
+    fn git(dir: &Path, args: &[&str]) -> bool {
+        std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap()
+            .status
+            .success()
+    }
+
+    #[tokio::test]
+    async fn test_resolve_rebase_conflicts() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let repo = tmp.path().join("project");
+        std::fs::create_dir_all(&repo).unwrap();
+        std::fs::write(repo.join("a.txt"), "a = 1\n").unwrap();
+        std::fs::write(repo.join("b.txt"), "b = 1\n").unwrap();
+        for args in [
+            &["init", "-q", "-b", "main"][..],
+            &["config", "user.name", "Test"],
+            &["config", "user.email", "test@example.com"],
+            &["add", "-A"],
+            &["commit", "-q", "-m", "Initial"],
+        ] {
+            assert!(git(&repo, args));
+        }
+
+        let vcs = GitVcs::new(repo.clone(), tmp.path().join(".workspaces"));
+        let ws = vcs.create_workspace("1").await.unwrap();
+        std::fs::write(ws.path.join("a.txt"), "a = 3\n").unwrap();
+        std::fs::write(ws.path.join("b.txt"), "b = 3\n").unwrap();
+        assert!(git(&ws.path, &["commit", "-q", "-am", "Task"]));
+        std::fs::write(repo.join("a.txt"), "a = 2\n").unwrap();
+        std::fs::write(repo.join("b.txt"), "b = 2\n").unwrap();
+        assert!(git(&repo, &["commit", "-q", "-am", "Main"]));
+        assert!(!git(&ws.path, &["rebase", "main"]));
+
+        let hunks = vcs.list_conflict_hunks(&ws).await.unwrap();
+        assert_eq!(hunks.len(), 2);
+        assert_eq!(hunks[0].path, PathBuf::from("a.txt"));
+        assert_eq!(hunks[0].ours, "a = 2\n");
+        assert_eq!(hunks[0].theirs, "a = 3\n");
+
+        let err = vcs.commit_resolution(&ws, "Resolve").await.unwrap_err();
+        assert!(matches!(err, VcsError::MergeConflict(_)), "{err}");
+
+        vcs.resolve_conflict(&ws, Path::new("a.txt"), ConflictSide::Ours)
+            .await
+            .unwrap();
+        vcs.resolve_conflict(&ws, Path::new("b.txt"), ConflictSide::Theirs)
+            .await
+            .unwrap();
+        assert!(vcs.get_conflicts(&ws).await.unwrap().is_empty());
+        assert!(vcs
+            .resolve_conflict(&ws, Path::new("a.txt"), ConflictSide::Ours)
+            .await
+            .is_err());
+
+        let head = vcs.commit_resolution(&ws, "Resolve").await.unwrap();
+        assert_eq!(head, vcs.head_commit(&ws).await.unwrap());
+        assert!(!vcs.is_rebasing(&ws).await.unwrap());
+        assert_eq!(
+            std::fs::read_to_string(ws.path.join("a.txt")).unwrap(),
+            "a = 2\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(ws.path.join("b.txt")).unwrap(),
+            "b = 3\n"
+        );
+    }
+
     #[test]
     fn test_with_main_branch() {
         let vcs = GitVcs::new(PathBuf::from("/repo"), PathBuf::from("/workspaces"))
@@ -0,0 +1,613 @@
+//! Copy-based workspaces for git repositories
+//!
+//! Some filesystems, such as Windows network drives, break git worktrees.
+//! This backend instead copies the files git would track (respecting
+//! `.gitignore`) into the workspace. The copy is snapshotted into a private
+//! index file under the workspace base, so diffs compare git trees, and
+//! merging applies the resulting patch to the project and commits it there.
+//! Workspaces have no branch of their own, so commit and push fail with
+//! [`VcsError::Unsupported`].
+
+use async_trait::async_trait;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::io::AsyncWriteExt;
+use tracing::{debug, info};
+
+use crate::copy::{blocking, copy_file, list_copied_workspaces, BASELINES_DIR, SKIPPED_DIRS};
+use crate::error::{Result, VcsError};
+use crate::git::git_command;
+use crate::paths::long_path;
+use crate::traits::{
+    ConflictFile, ConflictType, DiffSummary, MergeResult, VcsCapabilities, VersionControl,
+    Workspace,
+};
+
+const NO_BRANCH_REASON: &str = "copy-based workspaces have no branch of their own";
+
+/// Baseline and current trees of a workspace. The scratch index recording the
+/// current tree is removed on drop.
+struct Trees {
+    baseline: String,
+    current: String,
+    scratch: PathBuf,
+}
+
+impl Drop for Trees {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.scratch);
+    }
+}
+
+pub struct GitCopyVcs {
+    repo_path: PathBuf,
+    workspace_base: PathBuf,
+    main_branch: String,
+    /// Distinguishes the scratch index files of concurrent snapshots
+    snapshot_counter: AtomicU64,
+}
+
+impl GitCopyVcs {
+    pub fn new(repo_path: PathBuf, workspace_base: PathBuf) -> Self {
+        Self {
+            repo_path,
+            workspace_base,
+            main_branch: "main".to_string(),
+            snapshot_counter: AtomicU64::new(0),
+        }
+    }
+
+    pub fn with_main_branch(mut self, branch: impl Into<String>) -> Self {
+        self.main_branch = branch.into();
+        self
+    }
+
+    fn workspace_name(&self, task_id: &str) -> String {
+        format!("task-{}", task_id)
+    }
+
+    fn workspace_path(&self, task_id: &str) -> PathBuf {
+        self.workspace_base.join(self.workspace_name(task_id))
+    }
+
+    /// Index file holding the snapshot the workspace was copied from
+    fn baseline_index(&self, task_id: &str) -> PathBuf {
+        self.workspace_base
+            .join(BASELINES_DIR)
+            .join(format!("{}.index", self.workspace_name(task_id)))
+    }
+
+    fn unsupported(&self, operation: &str) -> VcsError {
+        self.capabilities().unsupported(operation, NO_BRANCH_REASON)
+    }
+
+    /// Run git in the project, optionally against another index file and
+    /// feeding `input` to stdin
+    async fn git_output(
+        &self,
+        args: &[&str],
+        index: Option<&Path>,
+        input: Option<Vec<u8>>,
+    ) -> Result<std::process::Output> {
+        debug!("Running git {:?} in {:?}", args, self.repo_path);
+
+        let mut command = git_command();
+        command
+            .args(args)
+            .current_dir(&self.repo_path)
+            .stdin(if input.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        if let Some(index) = index {
+            command.env("GIT_INDEX_FILE", index);
+        }
+
+        let mut child = command.spawn().map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => VcsError::CommandNotFound("git".to_string()),
+            _ => VcsError::Io(e),
+        })?;
+        if let (Some(input), Some(mut stdin)) = (input, child.stdin.take()) {
+            // Write from a task so a chatty command can't block on a full stdout
+            tokio::spawn(async move {
+                let _ = stdin.write_all(&input).await;
+            });
+        }
+        Ok(child.wait_with_output().await?)
+    }
+
+    async fn git(
+        &self,
+        args: &[&str],
+        index: Option<&Path>,
+        input: Option<Vec<u8>>,
+    ) -> Result<String> {
+        let output = self.git_output(args, index, input).await?;
+        if !output.status.success() {
+            return Err(VcsError::CommandFailed(format!(
+                "git {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr)
+            )));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    /// Files git would track in `work_tree`, leaving out `SKIPPED_DIRS`
+    async fn tracked_files(&self, work_tree: &Path, index: Option<&Path>) -> Result<Vec<String>> {
+        let work_tree_arg = format!("--work-tree={}", work_tree.display());
+        let output = self
+            .git(
+                &[
+                    &work_tree_arg,
+                    "ls-files",
+                    "--cached",
+                    "--others",
+                    "--exclude-standard",
+                    "-z",
+                ],
+                index,
+                None,
+            )
+            .await?;
+
+        let mut files: Vec<String> = output
+            .split('\0')
+            .filter(|path| !path.is_empty())
+            .filter(|path| !path.split('/').any(|part| SKIPPED_DIRS.contains(&part)))
+            .map(String::from)
+            .collect();
+        files.dedup();
+        Ok(files)
+    }
+
+    /// Record `work_tree` in `index` and return the id of the resulting tree
+    async fn snapshot(&self, work_tree: &Path, index: &Path) -> Result<String> {
+        let files = self.tracked_files(work_tree, Some(index)).await?;
+        let mut input = Vec::new();
+        for file in &files {
+            input.extend_from_slice(file.as_bytes());
+            input.push(0);
+        }
+
+        let work_tree_arg = format!("--work-tree={}", work_tree.display());
+        self.git(
+            &[
+                &work_tree_arg,
+                "update-index",
+                "--add",
+                "--remove",
+                "-z",
+                "--stdin",
+            ],
+            Some(index),
+            Some(input),
+        )
+        .await?;
+        let tree = self.git(&["write-tree"], Some(index), None).await?;
+        Ok(tree.trim().to_string())
+    }
+
+    /// Snapshot the workspace and pair it with its baseline
+    async fn trees(&self, workspace: &Workspace) -> Result<Trees> {
+        if !workspace.path.exists() {
+            return Err(VcsError::WorkspaceNotFound(workspace.task_id.clone()));
+        }
+
+        let baseline_index = self.baseline_index(&workspace.task_id);
+        let baseline = self
+            .git(&["write-tree"], Some(&baseline_index), None)
+            .await?;
+
+        // Start from the baseline index so unchanged files aren't rehashed
+        let scratch = baseline_index.with_extension(format!(
+            "{}.index",
+            self.snapshot_counter.fetch_add(1, Ordering::Relaxed)
+        ));
+        tokio::fs::copy(&baseline_index, &scratch).await?;
+        let mut trees = Trees {
+            baseline: baseline.trim().to_string(),
+            current: String::new(),
+            scratch,
+        };
+        trees.current = self.snapshot(&workspace.path, &trees.scratch).await?;
+        Ok(trees)
+    }
+
+    /// Paths changed between two trees, with git's status letter
+    async fn changed_paths(&self, baseline: &str, current: &str) -> Result<Vec<(char, String)>> {
+        let output = self
+            .git(
+                &[
+                    "diff",
+                    "--name-status",
+                    "--no-renames",
+                    "-z",
+                    baseline,
+                    current,
+                ],
+                None,
+                None,
+            )
+            .await?;
+
+        let mut parts = output.split('\0').filter(|part| !part.is_empty());
+        let mut changes = Vec::new();
+        while let (Some(status), Some(path)) = (parts.next(), parts.next()) {
+            changes.push((status.chars().next().unwrap_or('M'), path.to_string()));
+        }
+        Ok(changes)
+    }
+
+    /// Files of `patch` that don't apply cleanly to the project
+    async fn patch_conflicts(
+        &self,
+        patch: &[u8],
+        changes: &[(char, String)],
+    ) -> Result<Vec<ConflictFile>> {
+        let output = self
+            .git_output(&["apply", "--check", "-"], None, Some(patch.to_vec()))
+            .await?;
+        if output.status.success() {
+            return Ok(Vec::new());
+        }
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let conflicts: Vec<ConflictFile> = changes
+            .iter()
+            .filter(|(_, path)| {
+                let prefix = format!("error: {}: ", path);
+                stderr.lines().any(|line| line.starts_with(&prefix))
+            })
+            .map(|(status, path)| ConflictFile {
+                path: PathBuf::from(path),
+                conflict_type: match (status, self.repo_path.join(path).is_file()) {
+                    ('A', _) => ConflictType::AddAdd,
+                    ('D', _) => ConflictType::DeleteModify,
+                    (_, false) => ConflictType::ModifyDelete,
+                    _ => ConflictType::Content,
+                },
+            })
+            .collect();
+
+        if conflicts.is_empty() {
+            return Err(VcsError::CommandFailed(format!(
+                "git apply --check failed: {}",
+                stderr
+            )));
+        }
+        Ok(conflicts)
+    }
+
+    async fn patch(&self, baseline: &str, current: &str) -> Result<Vec<u8>> {
+        let output = self
+            .git_output(
+                &[
+                    "diff",
+                    "--binary",
+                    "--full-index",
+                    "--no-renames",
+                    baseline,
+                    current,
+                ],
+                None,
+                None,
+            )
+            .await?;
+        if !output.status.success() {
+            return Err(VcsError::CommandFailed(format!(
+                "git diff failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )));
+        }
+        Ok(output.stdout)
+    }
+}
+
+#[async_trait]
+impl VersionControl for GitCopyVcs {
+    fn name(&self) -> &'static str {
+        "git"
+    }
+
+    fn capabilities(&self) -> VcsCapabilities {
+        VcsCapabilities::copy_based()
+    }
+
+    async fn is_available(&self) -> bool {
+        git_command()
+            .arg("--version")
+            .output()
+            .await
+            .map(|o| o.status.success())
+            .unwrap_or(false)
+    }
+
+    async fn is_initialized(&self) -> Result<bool> {
+        Ok(self.repo_path.join(".git").exists())
+    }
+
+    async fn create_workspace(&self, task_id: &str) -> Result<Workspace> {
+        let workspace_path = self.workspace_path(task_id);
+        if workspace_path.exists() {
+            return Err(VcsError::WorkspaceAlreadyExists(task_id.to_string()));
+        }
+
+        let files = self.tracked_files(&self.repo_path, None).await?;
+        debug!(
+            "Copying {} files from {:?} to {:?}",
+            files.len(),
+            self.repo_path,
+            workspace_path
+        );
+        let repo_path = self.repo_path.clone();
+        let target = workspace_path.clone();
+        blocking(move || {
+            std::fs::create_dir_all(&target)?;
+            for file in &files {
+                let from = repo_path.join(file);
+                // Tracked files deleted from the working directory aren't copied
+                if from.is_file() {
+                    copy_file(&from, &target.join(file))?;
+                }
+            }
+            Ok(())
+        })
+        .await?;
+
+        let baseline_index = self.baseline_index(task_id);
+        if let Some(parent) = baseline_index.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        self.snapshot(&workspace_path, &baseline_index).await?;
+
+        Ok(Workspace::new(
+            task_id,
+            workspace_path,
+            self.workspace_name(task_id),
+        ))
+    }
+
+    async fn get_diff(&self, workspace: &Workspace) -> Result<String> {
+        let trees = self.trees(workspace).await?;
+        self.git(
+            &["diff", "--no-renames", &trees.baseline, &trees.current],
+            None,
+            None,
+        )
+        .await
+    }
+
+    async fn get_status(&self, workspace: &Workspace) -> Result<String> {
+        let trees = self.trees(workspace).await?;
+        Ok(self
+            .changed_paths(&trees.baseline, &trees.current)
+            .await?
+            .into_iter()
+            .map(|(status, path)| format!("{} {}\n", status, path))
+            .collect())
+    }
+
+    async fn merge_workspace(&self, workspace: &Workspace, message: &str) -> Result<MergeResult> {
+        let trees = self.trees(workspace).await?;
+        let changes = self.changed_paths(&trees.baseline, &trees.current).await?;
+        if changes.is_empty() {
+            return Ok(MergeResult::Success);
+        }
+
+        let patch = self.patch(&trees.baseline, &trees.current).await?;
+        let conflicts = self.patch_conflicts(&patch, &changes).await?;
+        if !conflicts.is_empty() {
+            return Ok(MergeResult::Conflicts { files: conflicts });
+        }
+
+        self.git(&["apply", "-"], None, Some(patch)).await?;
+        let mut args = vec!["add", "-A", "--"];
+        args.extend(changes.iter().map(|(_, path)| path.as_str()));
+        self.git(&args, None, None).await?;
+        // Commit only the applied paths, leaving anything else staged alone
+        args.splice(0..2, ["commit", "-m", message]);
+        self.git(&args, None, None).await?;
+
+        info!(
+            "Applied {} changed files from workspace {}",
+            changes.len(),
+            workspace.task_id
+        );
+        // The workspace now matches what was merged
+        tokio::fs::rename(&trees.scratch, self.baseline_index(&workspace.task_id)).await?;
+        Ok(MergeResult::Success)
+    }
+
+    async fn cleanup_workspace(&self, workspace: &Workspace) -> Result<()> {
+        if workspace.path.exists() {
+            tokio::fs::remove_dir_all(long_path(&workspace.path)).await?;
+        }
+        let baseline_index = self.baseline_index(&workspace.task_id);
+        if baseline_index.exists() {
+            tokio::fs::remove_file(&baseline_index).await?;
+        }
+        Ok(())
+    }
+
+    async fn list_workspaces(&self) -> Result<Vec<Workspace>> {
+        list_copied_workspaces(&self.workspace_base).await
+    }
+
+    async fn get_conflicts(&self, workspace: &Workspace) -> Result<Vec<ConflictFile>> {
+        let trees = self.trees(workspace).await?;
+        let changes = self.changed_paths(&trees.baseline, &trees.current).await?;
+        if changes.is_empty() {
+            return Ok(Vec::new());
+        }
+        let patch = self.patch(&trees.baseline, &trees.current).await?;
+        self.patch_conflicts(&patch, &changes).await
+    }
+
+    async fn commit(&self, _workspace: &Workspace, _message: &str) -> Result<String> {
+        Err(self.unsupported("commit"))
+    }
+
+    async fn push(&self, _workspace: &Workspace, _remote: &str) -> Result<()> {
+        Err(self.unsupported("push"))
+    }
+
+    /// The tree of the workspace's current contents, as there are no commits
+    async fn head_commit(&self, workspace: &Workspace) -> Result<String> {
+        Ok(self.trees(workspace).await?.current.clone())
+    }
+
+    async fn get_diff_summary(&self, workspace: &Workspace) -> Result<DiffSummary> {
+        let trees = self.trees(workspace).await?;
+        let output = self
+            .git(
+                &[
+                    "diff",
+                    "--numstat",
+                    "--no-renames",
+                    &trees.baseline,
+                    &trees.current,
+                ],
+                None,
+                None,
+            )
+            .await?;
+
+        let mut summary = DiffSummary {
+            files_changed: 0,
+            additions: 0,
+            deletions: 0,
+        };
+        for line in output.lines().filter(|line| !line.is_empty()) {
+            let parts: Vec<&str> = line.split('\t').collect();
+            if parts.len() >= 2 {
+                summary.files_changed += 1;
+                // Binary files show "-" for additions/deletions
+                summary.additions += parts[0].parse::<u32>().unwrap_or(0);
+                summary.deletions += parts[1].parse::<u32>().unwrap_or(0);
+            }
+        }
+        Ok(summary)
+    }
+
+    fn main_branch(&self) -> &str {
+        &self.main_branch
+    }
+
+    async fn has_uncommitted_changes(&self, workspace: &Workspace) -> Result<bool> {
+        let trees = self.trees(workspace).await?;
+        Ok(trees.baseline != trees.current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    fn project() -> (TempDir, GitCopyVcs) {
+        let tmp = TempDir::new().unwrap();
+        let repo = tmp.path().join("project");
+        std::fs::create_dir_all(repo.join("src")).unwrap();
+        std::fs::write(repo.join(".gitignore"), "*.log\n").unwrap();
+        std::fs::write(repo.join("src/main.py"), "print('hi')\n").unwrap();
+        std::fs::write(repo.join("README.md"), "# Project\n").unwrap();
+        git(&repo, &["init", "-q", "-b", "main"]);
+        git(&repo, &["config", "user.name", "Test"]);
+        git(&repo, &["config", "user.email", "test@example.com"]);
+        git(&repo, &["add", "-A"]);
+        git(&repo, &["commit", "-q", "-m", "Initial"]);
+        std::fs::write(repo.join("debug.log"), "ignored\n").unwrap();
+
+        let vcs = GitCopyVcs::new(repo, tmp.path().join(".workspaces"));
+        (tmp, vcs)
+    }
+
+    #[tokio::test]
+    async fn test_copy_respects_gitignore_and_merges_patch() {
+        let (_tmp, vcs) = project();
+        let ws = vcs.create_workspace("1").await.unwrap();
+        assert!(ws.path.join("src/main.py").exists());
+        assert!(!ws.path.join("debug.log").exists());
+        assert!(!ws.path.join(".git").exists());
+        assert!(!vcs.has_uncommitted_changes(&ws).await.unwrap());
+
+        std::fs::write(ws.path.join("src/main.py"), "print('hello')\n").unwrap();
+        std::fs::write(ws.path.join("src/util.py"), "X = 1\n").unwrap();
+        std::fs::write(ws.path.join("build.log"), "ignored\n").unwrap();
+        std::fs::remove_file(ws.path.join("README.md")).unwrap();
+
+        let status = vcs.get_status(&ws).await.unwrap();
+        assert_eq!(status, "D README.md\nM src/main.py\nA src/util.py\n");
+        let summary = vcs.get_diff_summary(&ws).await.unwrap();
+        assert_eq!(summary.files_changed, 3);
+        assert_eq!(summary.additions, 2);
+        assert_eq!(summary.deletions, 2);
+        assert!(vcs.get_diff(&ws).await.unwrap().contains("+print('hello')"));
+
+        let result = vcs.merge_workspace(&ws, "Task 1").await.unwrap();
+        assert!(result.is_success());
+        let repo = &vcs.repo_path;
+        assert_eq!(
+            std::fs::read_to_string(repo.join("src/main.py")).unwrap(),
+            "print('hello')\n"
+        );
+        assert!(!repo.join("README.md").exists());
+        assert!(!repo.join("build.log").exists());
+        assert!(!vcs.has_uncommitted_changes(&ws).await.unwrap());
+
+        let log = std::process::Command::new("git")
+            .args(["log", "-1", "--format=%s"])
+            .current_dir(repo)
+            .output()
+            .unwrap();
+        assert_eq!(String::from_utf8_lossy(&log.stdout).trim(), "Task 1");
+
+        vcs.cleanup_workspace(&ws).await.unwrap();
+        assert!(vcs.list_workspaces().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_merge_reports_conflicts_without_applying() {
+        let (_tmp, vcs) = project();
+        let ws = vcs.create_workspace("2").await.unwrap();
+        std::fs::write(ws.path.join("src/main.py"), "print('workspace')\n").unwrap();
+        std::fs::write(ws.path.join("README.md"), "# Workspace\n").unwrap();
+        std::fs::write(vcs.repo_path.join("src/main.py"), "print('project')\n").unwrap();
+
+        let result = vcs.merge_workspace(&ws, "Task 2").await.unwrap();
+        let conflicts = result.conflicts().unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].path, PathBuf::from("src/main.py"));
+        assert_eq!(conflicts[0].conflict_type, ConflictType::Content);
+        assert_eq!(
+            std::fs::read_to_string(vcs.repo_path.join("README.md")).unwrap(),
+            "# Project\n"
+        );
+        assert!(vcs.has_uncommitted_changes(&ws).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_commit_and_push_are_unsupported() {
+        let (_tmp, vcs) = project();
+        let ws = vcs.create_workspace("3").await.unwrap();
+
+        let err = vcs.commit(&ws, "message").await.unwrap_err();
+        assert!(matches!(err, VcsError::Unsupported { .. }));
+        assert!(vcs.push(&ws, "origin").await.is_err());
+    }
+}
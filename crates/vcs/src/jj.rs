@@ -1,13 +1,20 @@
 use async_trait::async_trait;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tokio::process::Command;
 use tracing::{debug, warn};
 
+use crate::conflicts::unresolved;
 use crate::error::{Result, VcsError};
+use crate::paths::{long_path, to_slash};
 use crate::traits::{
-    ConflictFile, ConflictType, DiffSummary, MergeResult, VersionControl, Workspace,
+    ConflictFile, ConflictSide, ConflictType, DiffSummary, MergeResult, VcsCapabilities,
+    VersionControl, Workspace,
 };
 
+/// Revset of the working-copy commit when it is a merge, i.e. a merge of the
+/// workspace into main that recorded conflicts
+const PENDING_MERGE_REVSET: &str = "@ & merges()";
+
 pub struct JujutsuVcs {
     repo_path: PathBuf,
     workspace_base: PathBuf,
@@ -49,6 +56,13 @@ impl JujutsuVcs {
     fn workspace_name(&self, task_id: &str) -> String {
         format!("task-{}", task_id)
     }
+
+    /// Point main at `revision`, completing a merge
+    async fn advance_main(&self, revision: &str, cwd: &PathBuf) -> Result<()> {
+        self.run_jj(&["bookmark", "set", "main", "-r", revision], cwd)
+            .await?;
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -57,6 +71,10 @@ impl VersionControl for JujutsuVcs {
         "jujutsu"
     }
 
+    fn capabilities(&self) -> VcsCapabilities {
+        VcsCapabilities::jujutsu()
+    }
+
     async fn is_available(&self) -> bool {
         Command::new("jj")
             .arg("--version")
@@ -128,32 +146,43 @@ impl VersionControl for JujutsuVcs {
             return Err(VcsError::WorkspaceNotFound(workspace.task_id.clone()));
         }
 
-        self.run_jj(&["describe", "-m", message], &workspace.path)
+        // Merging again after a conflicted merge picks up the merge commit
+        // rather than merging the merge
+        if !self.has_pending_merge(workspace).await? {
+            self.run_jj(&["describe", "-m", message], &workspace.path)
+                .await?;
+
+            // The merge commit records any conflicts instead of failing, so
+            // they can be resolved in the workspace
+            self.run_jj(
+                &[
+                    "new",
+                    "main",
+                    "@",
+                    "-m",
+                    &merge_description(&workspace.task_id),
+                ],
+                &workspace.path,
+            )
             .await?;
+        }
 
-        let result = self
-            .run_jj(&["rebase", "-d", "main"], &workspace.path)
-            .await;
-
-        match result {
-            Ok(_) => {
-                let conflicts = self.get_conflicts(workspace).await?;
-                if conflicts.is_empty() {
-                    Ok(MergeResult::Success)
-                } else {
-                    Ok(MergeResult::Conflicts { files: conflicts })
-                }
-            }
-            Err(e) => {
-                warn!("Rebase failed: {}", e);
-                let conflicts = self.get_conflicts(workspace).await.unwrap_or_default();
-                if conflicts.is_empty() {
-                    Err(e)
-                } else {
-                    Ok(MergeResult::Conflicts { files: conflicts })
-                }
-            }
+        let conflicts = self.get_conflicts(workspace).await?;
+        if !conflicts.is_empty() {
+            warn!(
+                "Merge of workspace {} recorded {} conflicted file(s)",
+                workspace.task_id,
+                conflicts.len()
+            );
+            return Ok(MergeResult::Conflicts { files: conflicts });
         }
+
+        self.advance_main("@", &workspace.path).await?;
+        // Start a new change so later work in the workspace does not amend
+        // the merge
+        self.run_jj(&["new"], &workspace.path).await?;
+
+        Ok(MergeResult::Success)
     }
 
     async fn cleanup_workspace(&self, workspace: &Workspace) -> Result<()> {
@@ -164,7 +193,7 @@ impl VersionControl for JujutsuVcs {
             .await;
 
         if workspace.path.exists() {
-            tokio::fs::remove_dir_all(&workspace.path).await?;
+            tokio::fs::remove_dir_all(long_path(&workspace.path)).await?;
         }
 
         Ok(())
@@ -220,6 +249,76 @@ impl VersionControl for JujutsuVcs {
         }
     }
 
+    async fn resolve_conflict(
+        &self,
+        workspace: &Workspace,
+        path: &Path,
+        side: ConflictSide,
+    ) -> Result<()> {
+        if !workspace.path.exists() {
+            return Err(VcsError::WorkspaceNotFound(workspace.task_id.clone()));
+        }
+
+        // The built-in tools take the first or second side whole
+        let tool = format!(":{}", side.as_str());
+        self.run_jj(
+            &["resolve", "--tool", &tool, &root_file_pattern(path)],
+            &workspace.path,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn has_pending_merge(&self, workspace: &Workspace) -> Result<bool> {
+        if !workspace.path.exists() {
+            return Err(VcsError::WorkspaceNotFound(workspace.task_id.clone()));
+        }
+
+        let output = self
+            .run_jj(
+                &[
+                    "log",
+                    "-r",
+                    PENDING_MERGE_REVSET,
+                    "--no-graph",
+                    "-T",
+                    "change_id",
+                ],
+                &workspace.path,
+            )
+            .await?;
+
+        Ok(!output.trim().is_empty())
+    }
+
+    async fn commit_resolution(&self, workspace: &Workspace, message: &str) -> Result<String> {
+        let conflicts = self.get_conflicts(workspace).await?;
+        if !conflicts.is_empty() {
+            return Err(unresolved(&conflicts));
+        }
+
+        let pending_merge = self.has_pending_merge(workspace).await?;
+
+        // `jj commit` describes the resolved change and starts a new one on
+        // top, so later work does not amend the resolution
+        self.run_jj(&["commit", "-m", message], &workspace.path)
+            .await?;
+
+        if pending_merge {
+            self.advance_main("@-", &workspace.path).await?;
+        }
+
+        let output = self
+            .run_jj(
+                &["log", "-r", "@-", "--no-graph", "-T", "change_id"],
+                &workspace.path,
+            )
+            .await?;
+
+        Ok(output.trim().to_string())
+    }
+
     async fn commit(&self, workspace: &Workspace, message: &str) -> Result<String> {
         if !workspace.path.exists() {
             return Err(VcsError::WorkspaceNotFound(workspace.task_id.clone()));
@@ -238,6 +337,23 @@ impl VersionControl for JujutsuVcs {
         Ok(output.trim().to_string())
     }
 
+    async fn head_commit(&self, workspace: &Workspace) -> Result<String> {
+        if !workspace.path.exists() {
+            return Err(VcsError::WorkspaceNotFound(workspace.task_id.clone()));
+        }
+
+        // The working-copy commit is snapshotted first, so it includes any
+        // changes not yet described
+        let output = self
+            .run_jj(
+                &["log", "-r", "@", "--no-graph", "-T", "commit_id"],
+                &workspace.path,
+            )
+            .await?;
+
+        Ok(output.trim().to_string())
+    }
+
     async fn push(&self, workspace: &Workspace, remote: &str) -> Result<()> {
         if !workspace.path.exists() {
             return Err(VcsError::WorkspaceNotFound(workspace.task_id.clone()));
@@ -333,6 +449,21 @@ impl VersionControl for JujutsuVcs {
     }
 }
 
+/// Description of the commit merging the workspace of `task_id` into main
+fn merge_description(task_id: &str) -> String {
+    format!("Merge task-{} into main", task_id)
+}
+
+/// Fileset pattern matching exactly `path`, relative to the workspace root;
+/// quoted so characters fileset expressions treat specially stay literal
+fn root_file_pattern(path: &Path) -> String {
+    let path = to_slash(path);
+    format!(
+        "root-file:\"{}\"",
+        path.replace('\\', "\\\\").replace('"', "\\\"")
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -352,4 +483,22 @@ mod tests {
         let name = vcs.workspace_name("abc-456");
         assert_eq!(name, "task-abc-456");
     }
+
+    #[test]
+    fn test_merge_description() {
+        assert_eq!(merge_description("abc-456"), "Merge task-abc-456 into main");
+        assert!(VcsCapabilities::jujutsu().conflict_commits);
+    }
+
+    #[test]
+    fn test_root_file_pattern() {
+        assert_eq!(
+            root_file_pattern(Path::new("src/a b.rs")),
+            r#"root-file:"src/a b.rs""#
+        );
+        assert_eq!(
+            root_file_pattern(Path::new(r#"say "hi".txt"#)),
+            r#"root-file:"say \"hi\".txt""#
+        );
+    }
 }
@@ -1,14 +1,21 @@
+pub mod conflicts;
+pub mod copy;
 pub mod error;
 pub mod git;
+pub mod git_copy;
 pub mod jj;
+pub mod paths;
 pub mod traits;
 pub mod workspace;
 
+pub use copy::CopyVcs;
 pub use error::{Result, VcsError};
 pub use git::GitVcs;
+pub use git_copy::GitCopyVcs;
 pub use jj::JujutsuVcs;
+pub use paths::{long_path, normalize_path, normalize_relative, same_relative_path, to_slash};
 pub use traits::{
-    ConflictFile, ConflictType, DiffSummary, MergeResult, VersionControl, Workspace,
-    WorkspaceStatus,
+    ConflictFile, ConflictHunk, ConflictSide, ConflictType, DiffSummary, MergeResult,
+    VcsCapabilities, VcsKind, VersionControl, Workspace, WorkspaceStatus,
 };
-pub use workspace::{WorkspaceConfig, WorkspaceManager};
+pub use workspace::{WorkspaceBackend, WorkspaceConfig, WorkspaceManager};
@@ -0,0 +1,151 @@
+//! Paths that work on Windows as well
+//!
+//! Workspaces nest the project under a workspace base, so deep trees soon
+//! pass the 260 character limit of Win32 paths; [`long_path`] gives file
+//! system calls the `\\?\` form that lifts it. Paths inside a repository are
+//! compared in git's form, with forward slashes, and without regard to case
+//! on Windows, whose file systems ignore it.
+
+use std::path::{Component, Path, PathBuf};
+
+/// `path` with `.` and `..` resolved without touching the file system.
+/// Extended-length paths take components literally, so they must not contain
+/// either.
+pub fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                let can_pop = matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                );
+                if can_pop {
+                    normalized.pop();
+                } else if !normalized.has_root() {
+                    normalized.push("..");
+                }
+            }
+            other => normalized.push(other.as_os_str()),
+        }
+    }
+    normalized
+}
+
+/// `path` in the form Windows accepts beyond 260 characters: absolute paths
+/// get the `\\?\` prefix, or `\\?\UNC\` for network shares. Relative and
+/// already prefixed paths, and any path on other platforms, come back as
+/// they are.
+pub fn long_path(path: &Path) -> PathBuf {
+    #[cfg(windows)]
+    {
+        use std::ffi::OsString;
+        use std::path::Prefix;
+
+        let normalized = normalize_path(path);
+        let mut components = normalized.components();
+        if let Some(Component::Prefix(prefix)) = components.next() {
+            let mut long = OsString::new();
+            match prefix.kind() {
+                Prefix::Disk(_) if normalized.has_root() => {
+                    long.push(r"\\?\");
+                    long.push(prefix.as_os_str());
+                }
+                Prefix::UNC(server, share) => {
+                    long.push(r"\\?\UNC\");
+                    long.push(server);
+                    long.push(r"\");
+                    long.push(share);
+                }
+                _ => return path.to_path_buf(),
+            }
+            // The root and everything after it
+            long.push(components.as_path().as_os_str());
+            return PathBuf::from(long);
+        }
+        path.to_path_buf()
+    }
+    #[cfg(not(windows))]
+    {
+        path.to_path_buf()
+    }
+}
+
+/// A repository-relative path as git writes it: forward slashes, no leading
+/// `./` and no repeated separators
+pub fn normalize_relative(path: &str) -> String {
+    let slashed = path.replace('\\', "/");
+    let mut parts: Vec<&str> = slashed
+        .split('/')
+        .filter(|part| !part.is_empty() && *part != ".")
+        .collect();
+    if parts.is_empty() {
+        parts.push(".");
+    }
+    parts.join("/")
+}
+
+/// A relative [`Path`] in git's form
+pub fn to_slash(path: &Path) -> String {
+    normalize_relative(&path.to_string_lossy())
+}
+
+/// Whether two repository-relative paths name the same file; case matters
+/// everywhere but on Windows
+pub fn same_relative_path(a: &str, b: &str) -> bool {
+    let (a, b) = (normalize_relative(a), normalize_relative(b));
+    if cfg!(windows) {
+        a.to_lowercase() == b.to_lowercase()
+    } else {
+        a == b
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_path() {
+        assert_eq!(
+            normalize_path(Path::new("repo/../.workspaces/./task-1")),
+            PathBuf::from(".workspaces/task-1")
+        );
+        assert_eq!(
+            normalize_path(Path::new("../.workspaces")),
+            PathBuf::from("../.workspaces")
+        );
+        assert_eq!(
+            normalize_path(Path::new("/repo/../../x")),
+            PathBuf::from("/x")
+        );
+    }
+
+    #[test]
+    fn test_normalize_relative() {
+        assert_eq!(normalize_relative(r"src\lib.rs"), "src/lib.rs");
+        assert_eq!(normalize_relative("./src//main.rs"), "src/main.rs");
+        assert_eq!(normalize_relative("./"), ".");
+        assert_eq!(to_slash(Path::new("src/util.py")), "src/util.py");
+    }
+
+    #[test]
+    fn test_same_relative_path() {
+        assert!(same_relative_path(r".\src\lib.rs", "src/lib.rs"));
+        assert!(!same_relative_path("src/lib.rs", "src/main.rs"));
+        assert_eq!(
+            same_relative_path("SRC/Lib.rs", "src/lib.rs"),
+            cfg!(windows)
+        );
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn test_long_path_is_unchanged_off_windows() {
+        assert_eq!(
+            long_path(Path::new("/repo/src")),
+            PathBuf::from("/repo/src")
+        );
+    }
+}
@@ -1,10 +1,13 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use utoipa::ToSchema;
 
-use crate::error::Result;
+use crate::conflicts::parse_conflict_hunks;
+use crate::error::{Result, VcsError};
+
+const NO_CONFLICT_STATE_REASON: &str = "this backend keeps no conflict state to resolve";
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
@@ -85,6 +88,46 @@ pub enum ConflictType {
     Rename,
 }
 
+/// One conflicted region of a file, as its conflict markers describe it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct ConflictHunk {
+    #[schema(value_type = String)]
+    #[cfg_attr(feature = "typescript", ts(type = "string"))]
+    pub path: PathBuf,
+    /// 1-based line of the opening conflict marker
+    pub start_line: u32,
+    /// 1-based line of the closing conflict marker
+    pub end_line: u32,
+    /// Lines of the first side, the VCS's `ours`
+    pub ours: String,
+    /// Lines of the second side, the VCS's `theirs`
+    pub theirs: String,
+    /// Lines of the common ancestor, when the markers include it
+    pub base: Option<String>,
+}
+
+/// Side of a conflict to keep. For a rebase onto main, as jujutsu merges,
+/// ours is main's and theirs the workspace's.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictSide {
+    Ours,
+    Theirs,
+}
+
+impl ConflictSide {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ours => "ours",
+            Self::Theirs => "theirs",
+        }
+    }
+}
+
 /// Summary of changes in a workspace
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
@@ -98,12 +141,144 @@ pub struct DiffSummary {
     pub deletions: u32,
 }
 
+/// Version control system a project directory uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum VcsKind {
+    Jujutsu,
+    Git,
+    /// Plain directory without version control
+    None,
+}
+
+impl VcsKind {
+    /// Detect the VCS of `path`; jujutsu wins for colocated repositories
+    pub fn detect(path: &Path) -> Self {
+        if path.join(".jj").exists() {
+            Self::Jujutsu
+        } else if path.join(".git").exists() {
+            Self::Git
+        } else {
+            Self::None
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Jujutsu => "jujutsu",
+            Self::Git => "git",
+            Self::None => "none",
+        }
+    }
+
+    /// Operations the backend for this kind supports
+    pub fn capabilities(&self) -> VcsCapabilities {
+        match self {
+            Self::Jujutsu => VcsCapabilities::jujutsu(),
+            Self::Git => VcsCapabilities::full(),
+            Self::None => VcsCapabilities::copy_based(),
+        }
+    }
+}
+
+/// Operations a VCS backend supports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(export))]
+pub struct VcsCapabilities {
+    /// Workspaces are worktrees on their own branch rather than plain copies
+    pub branch_workspaces: bool,
+    /// Changes can be committed to history
+    pub commit: bool,
+    /// Branches can be pushed to a remote, which pull requests need
+    pub push: bool,
+    /// Workspace changes can be merged back into the project
+    pub merge: bool,
+    /// Merges record conflicts in the merge commit, to be resolved in the
+    /// workspace, rather than failing
+    pub conflict_commits: bool,
+}
+
+impl VcsCapabilities {
+    /// Branch workspaces, commits, pushes and merges, as for git
+    pub const fn full() -> Self {
+        Self {
+            branch_workspaces: true,
+            commit: true,
+            push: true,
+            merge: true,
+            conflict_commits: false,
+        }
+    }
+
+    /// Everything git supports, plus merges that carry their conflicts
+    pub const fn jujutsu() -> Self {
+        Self {
+            conflict_commits: true,
+            ..Self::full()
+        }
+    }
+
+    /// Plain directory copies: no branches, commits or pushes, but changes can
+    /// still be merged back
+    pub const fn copy_based() -> Self {
+        Self {
+            branch_workspaces: false,
+            commit: false,
+            push: false,
+            merge: true,
+            conflict_commits: false,
+        }
+    }
+
+    /// Names of the operations not supported
+    pub fn unsupported_operations(&self) -> Vec<&'static str> {
+        [
+            (self.branch_workspaces, "branch workspaces"),
+            (self.commit, "commit"),
+            (self.push, "push"),
+            (self.push, "pull requests"),
+            (self.merge, "merge"),
+        ]
+        .into_iter()
+        .filter(|(supported, _)| !supported)
+        .map(|(_, name)| name)
+        .collect()
+    }
+
+    /// Error for attempting `operation`, which `reason` rules out
+    pub fn unsupported(&self, operation: &str, reason: &str) -> VcsError {
+        VcsError::Unsupported {
+            operation: operation.to_string(),
+            reason: reason.to_string(),
+            unsupported: self
+                .unsupported_operations()
+                .into_iter()
+                .map(String::from)
+                .collect(),
+        }
+    }
+}
+
+impl Default for VcsCapabilities {
+    fn default() -> Self {
+        Self::full()
+    }
+}
+
 /// Trait for version control system operations
 #[async_trait]
 pub trait VersionControl: Send + Sync {
     /// Get the name of the VCS backend
     fn name(&self) -> &'static str;
 
+    /// Operations this backend supports
+    fn capabilities(&self) -> VcsCapabilities {
+        VcsCapabilities::full()
+    }
+
     /// Check if the VCS is available (command exists)
     async fn is_available(&self) -> bool;
 
@@ -131,12 +306,56 @@ pub trait VersionControl: Send + Sync {
     /// Get conflicts in a workspace (if any)
     async fn get_conflicts(&self, workspace: &Workspace) -> Result<Vec<ConflictFile>>;
 
+    /// Get the conflicted regions of the workspace's conflicted files, read
+    /// from the conflict markers in the working copy. Files without markers,
+    /// such as deleted or binary ones, have no hunks.
+    async fn list_conflict_hunks(&self, workspace: &Workspace) -> Result<Vec<ConflictHunk>> {
+        let mut hunks = Vec::new();
+        for conflict in self.get_conflicts(workspace).await? {
+            let Ok(content) = tokio::fs::read_to_string(workspace.path.join(&conflict.path)).await
+            else {
+                continue;
+            };
+            hunks.extend(parse_conflict_hunks(&conflict.path, &content));
+        }
+        Ok(hunks)
+    }
+
+    /// Resolve a conflicted file by taking `side`'s version of it whole
+    async fn resolve_conflict(
+        &self,
+        _workspace: &Workspace,
+        _path: &Path,
+        _side: ConflictSide,
+    ) -> Result<()> {
+        Err(self
+            .capabilities()
+            .unsupported("conflict resolution", NO_CONFLICT_STATE_REASON))
+    }
+
+    /// Whether the workspace holds a merge that recorded conflicts; once
+    /// they are resolved, committing the resolution completes the merge
+    async fn has_pending_merge(&self, _workspace: &Workspace) -> Result<bool> {
+        Ok(false)
+    }
+
+    /// Record the resolved conflicts in a commit, returning its identifier.
+    /// Fails with [`VcsError::MergeConflict`] while any file is conflicted.
+    async fn commit_resolution(&self, _workspace: &Workspace, _message: &str) -> Result<String> {
+        Err(self
+            .capabilities()
+            .unsupported("conflict resolution", NO_CONFLICT_STATE_REASON))
+    }
+
     /// Commit changes in a workspace
     async fn commit(&self, workspace: &Workspace, message: &str) -> Result<String>;
 
     /// Push changes to remote (if applicable)
     async fn push(&self, workspace: &Workspace, remote: &str) -> Result<()>;
 
+    /// Get the identifier of the workspace's current commit
+    async fn head_commit(&self, workspace: &Workspace) -> Result<String>;
+
     /// Get a summary of changes in a workspace (files changed, additions, deletions)
     async fn get_diff_summary(&self, workspace: &Workspace) -> Result<DiffSummary>;
 
@@ -194,6 +413,32 @@ mod tests {
         assert_eq!(json, "\"merged\"");
     }
 
+    #[test]
+    fn test_vcs_kind_detect() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        assert_eq!(VcsKind::detect(tmp.path()), VcsKind::None);
+        std::fs::create_dir(tmp.path().join(".git")).unwrap();
+        assert_eq!(VcsKind::detect(tmp.path()), VcsKind::Git);
+        std::fs::create_dir(tmp.path().join(".jj")).unwrap();
+        assert_eq!(VcsKind::detect(tmp.path()).as_str(), "jujutsu");
+    }
+
+    #[test]
+    fn test_unsupported_error_lists_operations() {
+        let capabilities = VcsKind::None.capabilities();
+        assert_eq!(
+            capabilities.unsupported_operations(),
+            vec!["branch workspaces", "commit", "push", "pull requests"]
+        );
+        assert!(VcsCapabilities::full().unsupported_operations().is_empty());
+
+        let err = capabilities.unsupported("push", "no remote");
+        assert_eq!(
+            err.to_string(),
+            "push is not supported: no remote (unsupported operations: branch workspaces, commit, push, pull requests)"
+        );
+    }
+
     #[test]
     fn test_conflict_type_serialization() {
         let ct = ConflictType::ModifyDelete;
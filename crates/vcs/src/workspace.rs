@@ -1,14 +1,54 @@
-use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::process::Command;
 use tracing::{debug, info, warn};
 
+use crate::copy::CopyVcs;
 use crate::error::{Result, VcsError};
-use crate::traits::{MergeResult, VersionControl, Workspace};
+use crate::git::GitVcs;
+use crate::git_copy::GitCopyVcs;
+use crate::jj::JujutsuVcs;
+use crate::paths::{long_path, normalize_path};
+use crate::traits::{
+    ConflictFile, ConflictHunk, ConflictSide, MergeResult, VcsCapabilities, VcsKind,
+    VersionControl, Workspace,
+};
+
+/// How task workspaces are created
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkspaceBackend {
+    /// Git worktrees or jujutsu workspaces
+    #[default]
+    Native,
+    /// Copies of the project, for filesystems where worktrees break. Git
+    /// repositories copy only what git would track and merge back by applying
+    /// a patch; anything else is copied whole.
+    Copy,
+}
+
+impl WorkspaceBackend {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Native => "native",
+            Self::Copy => "copy",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "native" => Some(Self::Native),
+            "copy" => Some(Self::Copy),
+            _ => None,
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct WorkspaceConfig {
     pub workspace_base: PathBuf,
+    pub backend: WorkspaceBackend,
     pub init_scripts: Vec<PathBuf>,
     pub cleanup_scripts: Vec<PathBuf>,
     pub copy_files: Vec<String>,
@@ -19,6 +59,7 @@ impl Default for WorkspaceConfig {
     fn default() -> Self {
         Self {
             workspace_base: PathBuf::from("../.workspaces"),
+            backend: WorkspaceBackend::default(),
             init_scripts: Vec::new(),
             cleanup_scripts: Vec::new(),
             copy_files: vec![".env".to_string(), ".env.local".to_string()],
@@ -39,6 +80,11 @@ impl WorkspaceConfig {
         }
     }
 
+    pub fn with_backend(mut self, backend: WorkspaceBackend) -> Self {
+        self.backend = backend;
+        self
+    }
+
     pub fn with_init_scripts(mut self, scripts: Vec<PathBuf>) -> Self {
         self.init_scripts = scripts;
         self
@@ -65,6 +111,50 @@ impl WorkspaceManager {
         }
     }
 
+    /// Create a manager for `repo_path` with the backend matching its VCS.
+    /// Directories without version control, and projects configured with
+    /// [`WorkspaceBackend::Copy`], get copy-based workspaces, which can't
+    /// commit or push.
+    pub fn detect(repo_path: &Path, config: WorkspaceConfig) -> Self {
+        let repo = repo_path.to_path_buf();
+        // `..` in the base would survive into workspace paths, which Windows
+        // can't extend past 260 characters
+        let workspace_base = normalize_path(&config.workspace_base);
+        let kind = VcsKind::detect(repo_path);
+        let vcs: Arc<dyn VersionControl> = match kind {
+            _ if config.backend == WorkspaceBackend::Copy && kind != VcsKind::None => {
+                if repo_path.join(".git").exists() {
+                    info!("Using copy-based workspaces for Git repository");
+                    Arc::new(GitCopyVcs::new(repo, workspace_base))
+                } else {
+                    let vcs = CopyVcs::new(repo, workspace_base);
+                    warn!(
+                        "Copy-based workspaces need a Git repository, copying whole directory (unsupported: {})",
+                        vcs.capabilities().unsupported_operations().join(", ")
+                    );
+                    Arc::new(vcs)
+                }
+            }
+            VcsKind::Jujutsu => {
+                info!("Detected Jujutsu repository");
+                Arc::new(JujutsuVcs::new(repo, workspace_base))
+            }
+            VcsKind::Git => {
+                info!("Using Git as VCS backend");
+                Arc::new(GitVcs::new(repo, workspace_base))
+            }
+            VcsKind::None => {
+                let vcs = CopyVcs::new(repo, workspace_base);
+                warn!(
+                    "No version control found, using copy-based workspaces (unsupported: {})",
+                    vcs.capabilities().unsupported_operations().join(", ")
+                );
+                Arc::new(vcs)
+            }
+        };
+        Self::new(vcs, config, repo_path.to_path_buf())
+    }
+
     pub async fn setup_workspace(&self, task_id: &str) -> Result<Workspace> {
         info!("Setting up workspace for task {}", task_id);
 
@@ -95,8 +185,7 @@ impl WorkspaceManager {
 
             debug!("Running init script: {:?}", script);
 
-            let output = Command::new("bash")
-                .arg(script)
+            let output = script_command(script)
                 .arg(&workspace.path)
                 .arg(&workspace.task_id)
                 .arg(&self.repo_path)
@@ -118,8 +207,8 @@ impl WorkspaceManager {
 
     async fn setup_files(&self, workspace: &Workspace) -> Result<()> {
         for file in &self.config.copy_files {
-            let src = self.repo_path.join(file);
-            let dst = workspace.path.join(file);
+            let src = long_path(&self.repo_path.join(file));
+            let dst = long_path(&workspace.path.join(file));
 
             if src.exists() {
                 debug!("Copying {} to workspace", file);
@@ -157,8 +246,7 @@ impl WorkspaceManager {
 
             debug!("Running cleanup script: {:?}", script);
 
-            match Command::new("bash")
-                .arg(script)
+            match script_command(script)
                 .arg(&workspace.path)
                 .arg(&workspace.task_id)
                 .current_dir(&self.repo_path)
@@ -191,6 +279,10 @@ impl WorkspaceManager {
         self.vcs.get_diff(workspace).await
     }
 
+    pub async fn head_commit(&self, workspace: &Workspace) -> Result<String> {
+        self.vcs.head_commit(workspace).await
+    }
+
     pub async fn get_status(&self, workspace: &Workspace) -> Result<String> {
         self.vcs.get_status(workspace).await
     }
@@ -203,6 +295,31 @@ impl WorkspaceManager {
         self.vcs.merge_workspace(workspace, message).await
     }
 
+    pub async fn get_conflicts(&self, workspace: &Workspace) -> Result<Vec<ConflictFile>> {
+        self.vcs.get_conflicts(workspace).await
+    }
+
+    pub async fn list_conflict_hunks(&self, workspace: &Workspace) -> Result<Vec<ConflictHunk>> {
+        self.vcs.list_conflict_hunks(workspace).await
+    }
+
+    pub async fn resolve_conflict(
+        &self,
+        workspace: &Workspace,
+        path: &Path,
+        side: ConflictSide,
+    ) -> Result<()> {
+        self.vcs.resolve_conflict(workspace, path, side).await
+    }
+
+    pub async fn has_pending_merge(&self, workspace: &Workspace) -> Result<bool> {
+        self.vcs.has_pending_merge(workspace).await
+    }
+
+    pub async fn commit_resolution(&self, workspace: &Workspace, message: &str) -> Result<String> {
+        self.vcs.commit_resolution(workspace, message).await
+    }
+
     pub async fn list_workspaces(&self) -> Result<Vec<Workspace>> {
         self.vcs.list_workspaces().await
     }
@@ -215,12 +332,46 @@ impl WorkspaceManager {
         self.vcs.push(workspace, remote).await
     }
 
+    /// How this manager creates workspaces
+    pub fn backend(&self) -> WorkspaceBackend {
+        self.config.backend
+    }
+
+    /// Operations the underlying VCS supports
+    pub fn capabilities(&self) -> VcsCapabilities {
+        self.vcs.capabilities()
+    }
+
     /// Get a reference to the underlying VCS implementation
     pub fn vcs(&self) -> &dyn VersionControl {
         self.vcs.as_ref()
     }
 }
 
+/// A command running `script`. Scripts run with bash, except for batch and
+/// PowerShell scripts on Windows.
+fn script_command(script: &Path) -> Command {
+    let extension = script
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let mut command = match extension.as_str() {
+        "cmd" | "bat" if cfg!(windows) => {
+            let mut command = Command::new("cmd");
+            command.arg("/C");
+            command
+        }
+        "ps1" if cfg!(windows) => {
+            let mut command = Command::new("powershell");
+            command.args(["-NoProfile", "-ExecutionPolicy", "Bypass", "-File"]);
+            command
+        }
+        _ => Command::new("bash"),
+    };
+    command.arg(script);
+    command
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -243,4 +394,27 @@ mod tests {
         assert_eq!(config.init_scripts.len(), 1);
         assert_eq!(config.cleanup_scripts.len(), 1);
     }
+
+    #[test]
+    fn test_detect_without_vcs() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let config = WorkspaceConfig::new(tmp.path().join("workspaces"));
+        let manager = WorkspaceManager::detect(tmp.path(), config);
+
+        assert_eq!(manager.vcs().name(), "none");
+        assert!(!manager.capabilities().commit);
+        assert!(manager.capabilities().merge);
+    }
+
+    #[test]
+    fn test_detect_copy_backend_for_git() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir(tmp.path().join(".git")).unwrap();
+        let config = WorkspaceConfig::new(tmp.path().join("workspaces"))
+            .with_backend(WorkspaceBackend::Copy);
+        let manager = WorkspaceManager::detect(tmp.path(), config);
+
+        assert_eq!(manager.vcs().name(), "git");
+        assert!(!manager.capabilities().branch_workspaces);
+    }
 }
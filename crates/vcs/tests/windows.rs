@@ -0,0 +1,69 @@
+//! Windows path handling, run by the Windows CI job
+#![cfg(windows)]
+
+use std::path::{Path, PathBuf};
+
+use tempfile::TempDir;
+use vcs::{long_path, same_relative_path, CopyVcs, VersionControl};
+
+/// A relative path of nested directories longer than 260 characters
+fn deep_dir() -> PathBuf {
+    (0..12)
+        .map(|i| format!("directory-{:02}-of-a-deep-tree", i))
+        .collect()
+}
+
+#[test]
+fn test_long_path_prefixes() {
+    assert_eq!(
+        long_path(Path::new(r"C:\repo\..\.workspaces\task-1")),
+        PathBuf::from(r"\\?\C:\.workspaces\task-1")
+    );
+    assert_eq!(
+        long_path(Path::new(r"\\server\share\repo")),
+        PathBuf::from(r"\\?\UNC\server\share\repo")
+    );
+    assert_eq!(
+        long_path(Path::new(r"\\?\C:\repo")),
+        PathBuf::from(r"\\?\C:\repo")
+    );
+    assert_eq!(
+        long_path(Path::new(r"src\lib.rs")),
+        PathBuf::from(r"src\lib.rs")
+    );
+}
+
+#[test]
+fn test_paths_match_without_case() {
+    assert!(same_relative_path(r"Src\Lib.rs", "src/lib.rs"));
+}
+
+#[tokio::test]
+async fn test_copy_workspace_with_long_paths() {
+    let tmp = TempDir::new().unwrap();
+    let repo = tmp.path().join("project");
+    let file = repo.join(deep_dir()).join("module.rs");
+    assert!(file.as_os_str().len() > 260);
+    std::fs::create_dir_all(long_path(file.parent().unwrap())).unwrap();
+    std::fs::write(long_path(&file), "fn main() {}\n").unwrap();
+
+    let vcs = CopyVcs::new(repo, tmp.path().join(".workspaces"));
+    let ws = vcs.create_workspace("long").await.unwrap();
+    let copied = ws.path.join(deep_dir()).join("module.rs");
+    std::fs::write(long_path(&copied), "fn main() { run(); }\n").unwrap();
+
+    let status = vcs.get_status(&ws).await.unwrap();
+    let expected = format!("M {}/module.rs\n", vcs::to_slash(&deep_dir()));
+    assert_eq!(status, expected);
+
+    assert!(vcs
+        .merge_workspace(&ws, "Long paths")
+        .await
+        .unwrap()
+        .is_success());
+    assert_eq!(
+        std::fs::read_to_string(long_path(&file)).unwrap(),
+        "fn main() { run(); }\n"
+    );
+    vcs.cleanup_workspace(&ws).await.unwrap();
+}
@@ -0,0 +1,248 @@
+//! Indexing benchmarks over a synthetic monorepo
+//!
+//! Measures chunking throughput, chunk and embedding insert rates, and
+//! search latency. Run with:
+//!
+//! ```sh
+//! cargo bench -p wiki --bench indexing
+//! ```
+//!
+//! The repository is sized with `WIKI_BENCH_FILES` (default 500) and
+//! `WIKI_BENCH_FILE_LINES` (default 200). After the run, a JSON report
+//! comparing each metric with `benches/budget.json` is written to
+//! `WIKI_BENCH_REPORT` (default `target/wiki-bench-report.json`). Setting
+//! `WIKI_BENCH_ENFORCE_BUDGET=1` makes the run fail when a metric is over
+//! budget. The budget is set for the default repository size.
+
+mod report;
+mod synthetic;
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use criterion::{BenchmarkId, Criterion, Throughput};
+use tempfile::TempDir;
+use wiki::{ChunkType, CodeChunk, TextSplitter, VectorStore, WikiConfig};
+
+use report::{Measure, MetricSpec};
+use synthetic::{EmbeddingGenerator, RepoShape, SyntheticRepo};
+
+const BRANCH: &str = "main";
+const COMMIT_SHA: &str = "0000000000000000000000000000000000000000";
+/// Dimension of openai/text-embedding-3-small, the default model
+const EMBEDDING_DIMENSION: usize = 1536;
+/// Chunks per insert batch, as in the indexer
+const INSERT_BATCH_SIZE: usize = 100;
+const SEARCH_LIMIT: usize = 10;
+
+fn split_repository(repo: &SyntheticRepo, splitter: &TextSplitter) -> Vec<CodeChunk> {
+    repo.files
+        .iter()
+        .flat_map(|file| {
+            splitter.split(&file.content).into_iter().enumerate().map(
+                |(index, (content, start_line, end_line))| {
+                    let token_count = splitter.count_tokens(&content) as u32;
+                    CodeChunk::new(
+                        BRANCH.to_string(),
+                        file.relative_path.clone(),
+                        start_line,
+                        end_line,
+                        content,
+                        ChunkType::Code,
+                        file.language.clone(),
+                        token_count,
+                        index as u32,
+                        COMMIT_SHA.to_string(),
+                    )
+                },
+            )
+        })
+        .collect()
+}
+
+fn open_store(dir: &TempDir) -> VectorStore {
+    VectorStore::new(&dir.path().join("wiki.db")).expect("open vector store")
+}
+
+fn bench_chunking(c: &mut Criterion, repo: &SyntheticRepo, splitter: &TextSplitter) {
+    let mut group = c.benchmark_group("chunking");
+    group.throughput(Throughput::Bytes(repo.total_bytes));
+    group.sample_size(10);
+    group.bench_function("split_repository", |b| {
+        b.iter_custom(|iters| {
+            let start = Instant::now();
+            for _ in 0..iters {
+                black_box(split_repository(repo, splitter));
+            }
+            let elapsed = start.elapsed();
+            report::record("chunking/split_repository", iters, elapsed);
+            elapsed
+        })
+    });
+    group.finish();
+}
+
+fn bench_inserts(c: &mut Criterion, chunks: &[CodeChunk], embeddings: &[Vec<f32>], model: &str) {
+    let chunk_ids: Vec<_> = chunks.iter().map(|c| c.id).collect();
+
+    let mut group = c.benchmark_group("vector_store");
+    group.throughput(Throughput::Elements(chunks.len() as u64));
+    group.sample_size(10);
+
+    group.bench_function(BenchmarkId::new("insert_chunks", chunks.len()), |b| {
+        b.iter_custom(|iters| {
+            let mut elapsed = Duration::ZERO;
+            for _ in 0..iters {
+                let dir = TempDir::new().expect("create temp dir");
+                let store = open_store(&dir);
+                let start = Instant::now();
+                for batch in chunks.chunks(INSERT_BATCH_SIZE) {
+                    store.insert_chunks_batch(batch).expect("insert chunks");
+                }
+                elapsed += start.elapsed();
+            }
+            report::record("vector_store/insert_chunks", iters, elapsed);
+            elapsed
+        })
+    });
+
+    group.bench_function(BenchmarkId::new("insert_embeddings", chunks.len()), |b| {
+        b.iter_custom(|iters| {
+            let mut elapsed = Duration::ZERO;
+            for _ in 0..iters {
+                let dir = TempDir::new().expect("create temp dir");
+                let store = open_store(&dir);
+                store.insert_chunks_batch(chunks).expect("insert chunks");
+                let start = Instant::now();
+                for (ids, batch) in chunk_ids
+                    .chunks(INSERT_BATCH_SIZE)
+                    .zip(embeddings.chunks(INSERT_BATCH_SIZE))
+                {
+                    store
+                        .insert_embeddings_batch(model, ids, batch)
+                        .expect("insert embeddings");
+                }
+                elapsed += start.elapsed();
+            }
+            report::record("vector_store/insert_embeddings", iters, elapsed);
+            elapsed
+        })
+    });
+
+    group.finish();
+}
+
+fn bench_search(c: &mut Criterion, chunks: &[CodeChunk], embeddings: &[Vec<f32>], model: &str) {
+    let dir = TempDir::new().expect("create temp dir");
+    let store = open_store(&dir);
+    store
+        .activate_embedding_model(BRANCH, model)
+        .expect("activate embedding model");
+    store.insert_chunks_batch(chunks).expect("insert chunks");
+    let chunk_ids: Vec<_> = chunks.iter().map(|c| c.id).collect();
+    store
+        .insert_embeddings_batch(model, &chunk_ids, embeddings)
+        .expect("insert embeddings");
+
+    let mut queries = EmbeddingGenerator::new(7, EMBEDDING_DIMENSION);
+    let queries: Vec<Vec<f32>> = (0..16).map(|_| queries.next_embedding()).collect();
+
+    let mut group = c.benchmark_group("search");
+    group.bench_function(BenchmarkId::new("similar", chunks.len()), |b| {
+        b.iter_custom(|iters| {
+            let start = Instant::now();
+            for i in 0..iters {
+                let query = &queries[i as usize % queries.len()];
+                black_box(
+                    store
+                        .search_similar_in_branch(query, model, SEARCH_LIMIT, Some(BRANCH), None)
+                        .expect("search"),
+                );
+            }
+            let elapsed = start.elapsed();
+            report::record("search/similar", iters, elapsed);
+            elapsed
+        })
+    });
+    group.finish();
+}
+
+fn main() {
+    let config = WikiConfig::default();
+    let shape = RepoShape::from_env();
+    let repo = SyntheticRepo::generate(shape, config.max_chunk_tokens, config.chunk_overlap);
+    let splitter = TextSplitter::new(config.max_chunk_tokens, config.chunk_overlap);
+
+    let chunks = split_repository(&repo, &splitter);
+    let mut generator = EmbeddingGenerator::new(42, EMBEDDING_DIMENSION);
+    let embeddings: Vec<Vec<f32>> = chunks.iter().map(|_| generator.next_embedding()).collect();
+    println!(
+        "Synthetic repository at {}: {} files, {} bytes, {} chunks",
+        repo.path().display(),
+        repo.files.len(),
+        repo.total_bytes,
+        chunks.len()
+    );
+
+    let mut criterion = Criterion::default().configure_from_args();
+    bench_chunking(&mut criterion, &repo, &splitter);
+    bench_inserts(
+        &mut criterion,
+        &chunks,
+        &embeddings,
+        &config.embedding_model,
+    );
+    bench_search(
+        &mut criterion,
+        &chunks,
+        &embeddings,
+        &config.embedding_model,
+    );
+    criterion.final_summary();
+
+    // `cargo test --benches` runs each benchmark once, which says nothing
+    // about performance
+    if !std::env::args().any(|arg| arg == "--bench") {
+        return;
+    }
+
+    let mib = repo.total_bytes as f64 / (1024.0 * 1024.0);
+    let specs = [
+        MetricSpec {
+            name: "chunking_throughput",
+            benchmark: "chunking/split_repository",
+            unit: "MiB/s",
+            measure: Measure::Throughput { amount: mib },
+        },
+        MetricSpec {
+            name: "chunk_insert_rate",
+            benchmark: "vector_store/insert_chunks",
+            unit: "chunks/s",
+            measure: Measure::Throughput {
+                amount: chunks.len() as f64,
+            },
+        },
+        MetricSpec {
+            name: "embedding_insert_rate",
+            benchmark: "vector_store/insert_embeddings",
+            unit: "vectors/s",
+            measure: Measure::Throughput {
+                amount: chunks.len() as f64,
+            },
+        },
+        MetricSpec {
+            name: "search_latency",
+            benchmark: "search/similar",
+            unit: "ms",
+            measure: Measure::LatencyMs,
+        },
+    ];
+
+    let within_budget = report::write_report(shape, repo.total_bytes, chunks.len(), &specs)
+        .expect("write benchmark report");
+    let enforce = std::env::var("WIKI_BENCH_ENFORCE_BUDGET").is_ok_and(|v| v == "1");
+    if enforce && !within_budget {
+        eprintln!("Indexing performance is over budget; see the report above");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,187 @@
+//! Performance budget and JSON report
+//!
+//! Criterion's own reports are meant for people; CI needs one file with a
+//! pass/fail verdict per metric. Benchmarks record their per-iteration times
+//! here, and [`write_report`] turns the median into a metric, compares it
+//! with `budget.json` and writes the result as JSON.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::synthetic::RepoShape;
+
+static SAMPLES: Mutex<BTreeMap<&'static str, Vec<f64>>> = Mutex::new(BTreeMap::new());
+
+/// Record that `iters` iterations of `benchmark` took `elapsed`
+pub fn record(benchmark: &'static str, iters: u64, elapsed: Duration) {
+    if iters == 0 {
+        return;
+    }
+    SAMPLES
+        .lock()
+        .unwrap()
+        .entry(benchmark)
+        .or_default()
+        .push(elapsed.as_secs_f64() / iters as f64);
+}
+
+/// Median seconds per iteration of `benchmark`, if it ran
+fn median_secs(benchmark: &str) -> Option<f64> {
+    let samples = SAMPLES.lock().unwrap();
+    let mut times = samples.get(benchmark)?.clone();
+    if times.is_empty() {
+        return None;
+    }
+    times.sort_by(f64::total_cmp);
+    Some(times[times.len() / 2])
+}
+
+/// How a metric derives from a benchmark's time per iteration
+pub enum Measure {
+    /// `amount` units processed per iteration, reported per second
+    Throughput { amount: f64 },
+    /// Time per iteration in milliseconds
+    LatencyMs,
+}
+
+pub struct MetricSpec {
+    pub name: &'static str,
+    pub benchmark: &'static str,
+    pub unit: &'static str,
+    pub measure: Measure,
+}
+
+/// Limits for one metric; throughputs have a minimum, latencies a maximum
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Budget {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+}
+
+impl Budget {
+    fn allows(&self, value: f64) -> bool {
+        self.min.map_or(true, |min| value >= min) && self.max.map_or(true, |max| value <= max)
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Metric {
+    name: &'static str,
+    unit: &'static str,
+    /// `None` when the benchmark was filtered out
+    value: Option<f64>,
+    budget: Option<Budget>,
+    within_budget: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct RepositoryStats {
+    files: usize,
+    lines_per_file: usize,
+    total_bytes: u64,
+    chunks: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct Report {
+    generated_at: String,
+    repository: RepositoryStats,
+    metrics: Vec<Metric>,
+    within_budget: bool,
+}
+
+pub fn budget_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("benches/budget.json")
+}
+
+/// Path of the JSON report, from `WIKI_BENCH_REPORT`
+pub fn report_path() -> PathBuf {
+    std::env::var_os("WIKI_BENCH_REPORT")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("../../target/wiki-bench-report.json")
+        })
+}
+
+fn load_budget() -> BTreeMap<String, Budget> {
+    let path = budget_path();
+    match std::fs::read_to_string(&path) {
+        Ok(content) => serde_json::from_str(&content)
+            .unwrap_or_else(|e| panic!("invalid budget file {}: {}", path.display(), e)),
+        Err(_) => BTreeMap::new(),
+    }
+}
+
+/// Write the report and return whether every measured metric is within budget
+pub fn write_report(
+    shape: RepoShape,
+    total_bytes: u64,
+    chunks: usize,
+    specs: &[MetricSpec],
+) -> std::io::Result<bool> {
+    let budgets = load_budget();
+
+    let metrics: Vec<Metric> = specs
+        .iter()
+        .map(|spec| {
+            let value = median_secs(spec.benchmark).map(|secs| match spec.measure {
+                Measure::Throughput { amount } => amount / secs,
+                Measure::LatencyMs => secs * 1000.0,
+            });
+            let budget = budgets.get(spec.name).cloned();
+            let within_budget = match (&budget, value) {
+                (Some(budget), Some(value)) => budget.allows(value),
+                _ => true,
+            };
+            Metric {
+                name: spec.name,
+                unit: spec.unit,
+                value,
+                budget,
+                within_budget,
+            }
+        })
+        .collect();
+
+    let report = Report {
+        generated_at: chrono::Utc::now().to_rfc3339(),
+        repository: RepositoryStats {
+            files: shape.files,
+            lines_per_file: shape.lines_per_file,
+            total_bytes,
+            chunks,
+        },
+        within_budget: metrics.iter().all(|m| m.within_budget),
+        metrics,
+    };
+
+    let path = report_path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(&report).map_err(std::io::Error::other)?;
+    std::fs::write(&path, json)?;
+
+    for metric in &report.metrics {
+        if let Some(value) = metric.value {
+            let verdict = if metric.within_budget {
+                "ok"
+            } else {
+                "OVER BUDGET"
+            };
+            println!(
+                "{:<28} {:>12.2} {:<10} {}",
+                metric.name, value, metric.unit, verdict
+            );
+        }
+    }
+    println!("Report written to {}", path.display());
+
+    Ok(report.within_budget)
+}
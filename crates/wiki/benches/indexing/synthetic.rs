@@ -0,0 +1,182 @@
+//! Synthetic repository generator
+//!
+//! Generates a deterministic monorepo-like tree of Rust, TypeScript and
+//! Python files, so runs on different machines index the same content.
+
+use std::path::Path;
+
+use tempfile::TempDir;
+use wiki::indexer::reader::{FileInfo, FileReader};
+
+const DEFAULT_FILES: usize = 500;
+const DEFAULT_LINES_PER_FILE: usize = 200;
+
+/// Files per package directory
+const FILES_PER_PACKAGE: usize = 40;
+
+/// Size of the synthetic repository
+#[derive(Debug, Clone, Copy)]
+pub struct RepoShape {
+    pub files: usize,
+    pub lines_per_file: usize,
+}
+
+impl RepoShape {
+    /// Shape from `WIKI_BENCH_FILES` and `WIKI_BENCH_FILE_LINES`
+    pub fn from_env() -> Self {
+        Self {
+            files: env_usize("WIKI_BENCH_FILES").unwrap_or(DEFAULT_FILES),
+            lines_per_file: env_usize("WIKI_BENCH_FILE_LINES").unwrap_or(DEFAULT_LINES_PER_FILE),
+        }
+    }
+}
+
+fn env_usize(name: &str) -> Option<usize> {
+    std::env::var(name).ok()?.parse().ok().filter(|n| *n > 0)
+}
+
+/// A generated repository, removed on drop
+pub struct SyntheticRepo {
+    dir: TempDir,
+    /// Files as the indexer reads them
+    pub files: Vec<FileInfo>,
+    pub total_bytes: u64,
+}
+
+impl SyntheticRepo {
+    pub fn generate(shape: RepoShape, max_chunk_tokens: usize, chunk_overlap: usize) -> Self {
+        let dir = TempDir::new().expect("create temp dir");
+        for index in 0..shape.files {
+            write_file(dir.path(), index, shape.lines_per_file);
+        }
+
+        let files = FileReader::new(max_chunk_tokens, chunk_overlap)
+            .read_directory(dir.path())
+            .expect("read synthetic repository");
+        let total_bytes = files.iter().map(|f| f.content.len() as u64).sum();
+
+        Self {
+            dir,
+            files,
+            total_bytes,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        self.dir.path()
+    }
+}
+
+fn write_file(root: &Path, index: usize, lines: usize) {
+    let package = root
+        .join("packages")
+        .join(format!("pkg_{:03}", index / FILES_PER_PACKAGE))
+        .join("src");
+    std::fs::create_dir_all(&package).expect("create package directory");
+
+    let (extension, content) = match index % 3 {
+        0 => ("rs", rust_source(index, lines)),
+        1 => ("ts", typescript_source(index, lines)),
+        _ => ("py", python_source(index, lines)),
+    };
+    std::fs::write(
+        package.join(format!("module_{:05}.{}", index, extension)),
+        content,
+    )
+    .expect("write synthetic file");
+}
+
+/// Repeat `block` (which renders function `n`) until `lines` lines are written
+fn fill(header: &str, lines: usize, block: impl Fn(usize) -> String) -> String {
+    let mut content = String::from(header);
+    let mut written = header.lines().count();
+    let mut n = 0;
+    while written < lines {
+        let rendered = block(n);
+        written += rendered.lines().count();
+        content.push_str(&rendered);
+        n += 1;
+    }
+    content
+}
+
+fn rust_source(index: usize, lines: usize) -> String {
+    let header = format!(
+        "//! Module {index} of the synthetic workspace\n\nuse std::collections::HashMap;\n\n"
+    );
+    fill(&header, lines, |n| {
+        format!(
+            "/// Computes the weighted score for bucket {n} of module {index}\n\
+             pub fn score_{index}_{n}(values: &[u64], weights: &HashMap<u64, f64>) -> f64 {{\n    \
+                 let mut total = 0.0;\n    \
+                 for value in values {{\n        \
+                     total += *value as f64 * weights.get(value).copied().unwrap_or(1.0);\n    \
+                 }}\n    \
+                 total / (values.len().max(1) as f64)\n\
+             }}\n\n"
+        )
+    })
+}
+
+fn typescript_source(index: usize, lines: usize) -> String {
+    let header = format!(
+        "// Module {index} of the synthetic workspace\n\nimport {{ fetchJson }} from './http';\n\n"
+    );
+    fill(&header, lines, |n| {
+        format!(
+            "/** Loads page {n} of the records owned by module {index} */\n\
+             export async function loadRecords{index}x{n}(cursor?: string): Promise<string[]> {{\n  \
+                 const response = await fetchJson(`/api/records/{index}/{n}?cursor=${{cursor ?? ''}}`);\n  \
+                 if (!response.ok) {{\n    \
+                     throw new Error(`request failed with ${{response.status}}`);\n  \
+                 }}\n  \
+                 return response.items.map((item: {{ id: string }}) => item.id);\n\
+             }}\n\n"
+        )
+    })
+}
+
+fn python_source(index: usize, lines: usize) -> String {
+    let header =
+        format!("\"\"\"Module {index} of the synthetic workspace.\"\"\"\n\nimport json\n\n\n");
+    fill(&header, lines, |n| {
+        format!(
+            "def parse_event_{index}_{n}(payload: str) -> dict:\n    \
+                 \"\"\"Parse event {n} emitted by module {index}.\"\"\"\n    \
+                 data = json.loads(payload)\n    \
+                 if \"kind\" not in data:\n        \
+                     raise ValueError(\"event without kind\")\n    \
+                 return {{\"kind\": data[\"kind\"], \"size\": len(payload)}}\n\n\n"
+        )
+    })
+}
+
+/// Deterministic unit-length vectors standing in for real embeddings
+pub struct EmbeddingGenerator {
+    state: u64,
+    dimension: usize,
+}
+
+impl EmbeddingGenerator {
+    pub fn new(seed: u64, dimension: usize) -> Self {
+        Self {
+            state: seed.max(1),
+            dimension,
+        }
+    }
+
+    pub fn next_embedding(&mut self) -> Vec<f32> {
+        let mut embedding: Vec<f32> = (0..self.dimension).map(|_| self.next_f32()).collect();
+        let norm = embedding.iter().map(|v| v * v).sum::<f32>().sqrt();
+        embedding.iter_mut().for_each(|v| *v /= norm);
+        embedding
+    }
+
+    /// Uniform in [-1, 1) from an xorshift generator
+    fn next_f32(&mut self) -> f32 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        (self.state >> 40) as f32 / (1u64 << 23) as f32 - 1.0
+    }
+}
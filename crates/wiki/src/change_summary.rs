@@ -0,0 +1,226 @@
+//! Summaries of workspace diffs for reviewers
+//!
+//! A big diff is split per file and the files are packed into batches that
+//! fit a chat request. The model answers with a few bullets per file and
+//! flags risky changes; the merged result is cached per task and head
+//! commit, so reopening a review doesn't ask again.
+
+use std::path::Path;
+
+use chrono::Utc;
+use tracing::debug;
+
+use crate::domain::change_summary::{ChangeRisk, ChangeSummary, FileChangeSummary};
+use crate::error::WikiResult;
+use crate::openrouter::client::OpenRouterClient;
+use crate::openrouter::types::ChatMessage;
+use crate::page_updates::{split_diff, FileDiff};
+use crate::review_context::excerpt;
+use crate::vector_store::VectorStore;
+
+/// Characters of patches sent per chat request
+const MAX_BATCH_CHARS: usize = 24_000;
+
+/// Characters of a single file's patch included; the rest is cut
+const MAX_PATCH_CHARS: usize = 8_000;
+
+const SUMMARY_PROMPT: &str = r#"You summarize code changes for a reviewer who has not opened the files yet.
+
+For each file in the diff, reply with a `FILE: <path>` line followed by one to three `- ` bullets, each a short sentence saying what changed and why it matters.
+After a file's bullets, add a `RISK: <description>` line for any change that could break behaviour, lose data, weaken security or needs a careful look. Most files have no risks.
+Use only these line forms and no other text."#;
+
+/// Summarizes workspace diffs with a chat model
+pub struct ChangeSummarizer<'a> {
+    openrouter: &'a OpenRouterClient,
+    model: &'a str,
+}
+
+impl<'a> ChangeSummarizer<'a> {
+    pub fn new(openrouter: &'a OpenRouterClient, model: &'a str) -> Self {
+        Self { openrouter, model }
+    }
+
+    /// Summary of `diff`, the diff of `task_id`'s workspace at `head_commit`.
+    /// A summary cached in the wiki database at `db_path` for the same head
+    /// commit is returned as is; the flag tells whether it was.
+    pub async fn summarize(
+        &self,
+        db_path: &Path,
+        task_id: &str,
+        head_commit: &str,
+        diff: &str,
+    ) -> WikiResult<(ChangeSummary, bool)> {
+        if let Some(cached) = VectorStore::new(db_path)?.get_change_summary(task_id, head_commit)? {
+            debug!(task_id, head_commit, "Using cached change summary");
+            return Ok((cached, true));
+        }
+
+        let file_diffs = split_diff(diff);
+        let mut summary = ChangeSummary {
+            head_commit: head_commit.to_string(),
+            files: Vec::new(),
+            risks: Vec::new(),
+            created_at: Utc::now(),
+        };
+
+        for batch in batch_file_diffs(&file_diffs) {
+            let messages = vec![
+                ChatMessage::system(SUMMARY_PROMPT),
+                ChatMessage::user(format_batch(&batch)),
+            ];
+            let reply = self
+                .openrouter
+                .chat_completion(messages, self.model, Some(0.0), Some(1500))
+                .await?;
+
+            let paths: Vec<&str> = batch.iter().map(|d| d.file_path.as_str()).collect();
+            let (files, risks) = parse_reply(&reply, &paths);
+            summary.files.extend(files);
+            summary.risks.extend(risks);
+        }
+        debug!(
+            task_id,
+            files = summary.files.len(),
+            risks = summary.risks.len(),
+            "Summarized workspace diff"
+        );
+
+        VectorStore::new(db_path)?.upsert_change_summary(task_id, &summary)?;
+        Ok((summary, false))
+    }
+}
+
+/// Group file patches into batches of about `MAX_BATCH_CHARS`, in diff order
+fn batch_file_diffs(file_diffs: &[FileDiff]) -> Vec<Vec<FileDiff>> {
+    let mut batches: Vec<Vec<FileDiff>> = Vec::new();
+    let mut size = 0;
+
+    for file_diff in file_diffs {
+        let patch = excerpt(&file_diff.patch, MAX_PATCH_CHARS);
+        match batches.last_mut() {
+            Some(batch) if size + patch.len() <= MAX_BATCH_CHARS => {
+                size += patch.len();
+                batch.push(FileDiff {
+                    file_path: file_diff.file_path.clone(),
+                    patch,
+                });
+            }
+            _ => {
+                size = patch.len();
+                batches.push(vec![FileDiff {
+                    file_path: file_diff.file_path.clone(),
+                    patch,
+                }]);
+            }
+        }
+    }
+
+    batches
+}
+
+fn format_batch(batch: &[FileDiff]) -> String {
+    batch
+        .iter()
+        .map(|d| format!("```diff\n{}\n```", d.patch))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// File summaries and risks from a model reply. Every file of the batch gets
+/// a summary, empty if the reply skipped it; sections naming files outside
+/// the batch are ignored.
+fn parse_reply(reply: &str, paths: &[&str]) -> (Vec<FileChangeSummary>, Vec<ChangeRisk>) {
+    let mut files: Vec<FileChangeSummary> = paths
+        .iter()
+        .map(|path| FileChangeSummary {
+            file_path: path.to_string(),
+            bullets: Vec::new(),
+        })
+        .collect();
+    let mut risks = Vec::new();
+    let mut current: Option<usize> = None;
+
+    for line in reply.lines() {
+        let line = line.trim();
+        if let Some(path) = line.strip_prefix("FILE:") {
+            let path = path.trim().trim_matches('`');
+            current = paths.iter().position(|p| *p == path);
+            continue;
+        }
+        let Some(index) = current else {
+            continue;
+        };
+        if let Some(risk) = line.strip_prefix("RISK:") {
+            let risk = risk.trim();
+            if !risk.is_empty() {
+                risks.push(ChangeRisk {
+                    file_path: files[index].file_path.clone(),
+                    description: risk.to_string(),
+                });
+            }
+        } else if let Some(bullet) = line.strip_prefix(['-', '*']) {
+            let bullet = bullet.trim();
+            if !bullet.is_empty() {
+                files[index].bullets.push(bullet.to_string());
+            }
+        }
+    }
+
+    (files, risks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_reply() {
+        let reply = "FILE: src/upload.rs\n\
+                     - Retries failed uploads three times.\n\
+                     RISK: Retries are not idempotent for partial uploads.\n\
+                     FILE: `README.md`\n\
+                     * Documents the retry setting.\n\
+                     FILE: src/unrelated.rs\n\
+                     - Not part of the batch.\n";
+        let (files, risks) = parse_reply(reply, &["src/upload.rs", "README.md", "src/config.rs"]);
+
+        assert_eq!(files.len(), 3);
+        assert_eq!(
+            files[0].bullets,
+            vec!["Retries failed uploads three times."]
+        );
+        assert_eq!(files[1].bullets, vec!["Documents the retry setting."]);
+        assert!(files[2].bullets.is_empty());
+        assert_eq!(
+            risks,
+            vec![ChangeRisk {
+                file_path: "src/upload.rs".to_string(),
+                description: "Retries are not idempotent for partial uploads.".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_batch_file_diffs() {
+        let file_diff = |path: &str, size: usize| FileDiff {
+            file_path: path.to_string(),
+            patch: format!("diff --git a/{0} b/{0}\n{1}", path, "+x\n".repeat(size / 3)),
+        };
+        let diffs = vec![
+            file_diff("a.rs", 20_000),
+            file_diff("b.rs", 20_000),
+            file_diff("c.rs", 6_000),
+            file_diff("d.rs", 6_000),
+        ];
+
+        let batches = batch_file_diffs(&diffs);
+        let paths: Vec<Vec<&str>> = batches
+            .iter()
+            .map(|b| b.iter().map(|d| d.file_path.as_str()).collect())
+            .collect();
+        assert_eq!(paths, vec![vec!["a.rs", "b.rs", "c.rs"], vec!["d.rs"]]);
+        assert!(batches[0][0].patch.ends_with('…'));
+        assert!(!batches[0][2].patch.ends_with('…'));
+    }
+}
@@ -11,6 +11,29 @@ fn get_tokenizer() -> Option<&'static CoreBPE> {
     BPE_TOKENIZER.get_or_init(|| cl100k_base().ok()).as_ref()
 }
 
+/// Tokens in `text` by the cached tokenizer, or a length estimate when it
+/// failed to load
+pub fn count_tokens(text: &str) -> usize {
+    match get_tokenizer() {
+        Some(bpe) => bpe.encode_ordinary(text).len(),
+        None => text.len() / 4,
+    }
+}
+
+/// Content over this many times the budget is truncated to head and tail
+const HEAD_TAIL_THRESHOLD: usize = 2;
+
+/// Number of leading lines, by token cost, that fit in `budget`
+fn take_lines(line_tokens: impl Iterator<Item = usize>, budget: usize) -> usize {
+    let mut used = 0;
+    line_tokens
+        .take_while(|tokens| {
+            used += tokens;
+            used <= budget
+        })
+        .count()
+}
+
 /// Text splitter that chunks content with overlap
 pub struct TextSplitter {
     /// Maximum tokens per chunk
@@ -138,10 +161,50 @@ impl TextSplitter {
     }
 
     pub fn count_tokens(&self, text: &str) -> usize {
-        match get_tokenizer() {
-            Some(bpe) => bpe.encode_ordinary(text).len(),
-            None => text.len() / 4,
+        count_tokens(text)
+    }
+
+    /// Truncate `content` to at most `max_tokens`, keeping whole lines
+    ///
+    /// Content up to twice the budget keeps its head. Larger content keeps
+    /// its head and tail, since the end of a big file (exports, `main`,
+    /// tests) often says as much about it as the start. The omitted part is
+    /// replaced with a marker line.
+    pub fn truncate(&self, content: &str, max_tokens: usize) -> String {
+        if self.count_tokens(content) <= max_tokens {
+            return content.to_string();
+        }
+
+        let lines: Vec<&str> = content.lines().collect();
+        // Each line costs its own tokens plus roughly one for the line break
+        let line_tokens: Vec<usize> = lines.iter().map(|l| self.count_tokens(l) + 1).collect();
+        let total: usize = line_tokens.iter().sum();
+
+        let (head_budget, tail_budget) = if total > max_tokens * HEAD_TAIL_THRESHOLD {
+            let head = max_tokens * 2 / 3;
+            (head, max_tokens - head)
+        } else {
+            (max_tokens, 0)
+        };
+
+        let head = take_lines(line_tokens.iter().copied(), head_budget);
+        let tail = take_lines(line_tokens[head..].iter().rev().copied(), tail_budget);
+
+        if head == 0 && tail == 0 {
+            // A single line over the budget, e.g. minified code
+            let truncated: String = lines[0].chars().take(max_tokens * 4).collect();
+            return format!("{}\n\n... (truncated)", truncated);
         }
+
+        let omitted = lines.len() - head - tail;
+        let mut truncated = lines[..head].join("\n");
+        if tail == 0 {
+            truncated.push_str(&format!("\n\n... (truncated, {} more lines)", omitted));
+        } else {
+            truncated.push_str(&format!("\n\n... ({} lines omitted) ...\n\n", omitted));
+            truncated.push_str(&lines[lines.len() - tail..].join("\n"));
+        }
+        truncated
     }
 
     /// Detect programming language from file extension
@@ -222,6 +285,57 @@ mod tests {
         assert!(tokens < 10);
     }
 
+    #[test]
+    fn test_truncate_keeps_short_content() {
+        let splitter = TextSplitter::new(350, 100);
+        assert_eq!(splitter.truncate("fn main() {}", 100), "fn main() {}");
+    }
+
+    #[test]
+    fn test_truncate_keeps_whole_lines() {
+        let splitter = TextSplitter::new(350, 100);
+        let content = (0..30)
+            .map(|i| format!("let value_{} = compute({});", i, i))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let budget = splitter.count_tokens(&content) * 2 / 3;
+
+        let truncated = splitter.truncate(&content, budget);
+
+        let (kept, marker) = truncated.split_once("\n\n").unwrap();
+        assert!(marker.starts_with("... (truncated,"));
+        assert!(splitter.count_tokens(kept) <= budget);
+        assert!(kept.lines().all(|line| content.lines().any(|l| l == line)));
+        assert!(kept.starts_with("let value_0 "));
+    }
+
+    #[test]
+    fn test_truncate_samples_head_and_tail() {
+        let splitter = TextSplitter::new(350, 100);
+        let content = (0..500)
+            .map(|i| format!("line {}", i))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let truncated = splitter.truncate(&content, 90);
+
+        assert!(truncated.starts_with("line 0\n"));
+        assert!(truncated.ends_with("line 499"));
+        assert!(truncated.contains("lines omitted"));
+        assert!(splitter.count_tokens(&truncated) < 110);
+    }
+
+    #[test]
+    fn test_truncate_long_single_line() {
+        let splitter = TextSplitter::new(350, 100);
+        let content = "x".repeat(10000);
+
+        let truncated = splitter.truncate(&content, 100);
+
+        assert!(truncated.contains("... (truncated)"));
+        assert!(truncated.len() < content.len());
+    }
+
     #[test]
     fn test_detect_language() {
         assert_eq!(
@@ -0,0 +1,307 @@
+//! Branch comparison: how the wiki of one branch differs from another's
+//!
+//! Pages are matched by slug. A page only in `head` was added, one only in
+//! `base` was removed, and one in both whose title or content differs was
+//! changed. Content changes come with a unified line diff. Everything is
+//! computed from stored pages; no model is called.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::wiki_page::WikiPage;
+
+/// Unchanged lines shown around each change in a diff
+const DIFF_CONTEXT_LINES: usize = 3;
+
+/// Largest line-count product diffed line by line. Bigger pages are shown
+/// as replaced wholesale instead of running the quadratic diff.
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+/// A page that exists in only one of the compared branches
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComparedPage {
+    pub slug: String,
+    pub title: String,
+}
+
+impl From<&WikiPage> for ComparedPage {
+    fn from(page: &WikiPage) -> Self {
+        Self {
+            slug: page.slug.clone(),
+            title: page.title.clone(),
+        }
+    }
+}
+
+/// A page present in both branches whose title or content differs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageChange {
+    pub slug: String,
+    /// Title in `head`
+    pub title: String,
+    /// Title in `base`, when it differs
+    pub previous_title: Option<String>,
+    pub lines_added: u32,
+    pub lines_removed: u32,
+    /// Unified diff of the content, empty when only the title changed
+    pub diff: String,
+}
+
+/// Documentation differences between two branches
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BranchComparison {
+    pub added: Vec<ComparedPage>,
+    pub removed: Vec<ComparedPage>,
+    pub changed: Vec<PageChange>,
+    pub unchanged_count: u32,
+}
+
+impl BranchComparison {
+    /// Compare the pages of `base` with those of `head`; each list is
+    /// ordered by slug
+    pub fn from_pages(base: &[WikiPage], head: &[WikiPage]) -> Self {
+        let base: BTreeMap<&str, &WikiPage> = base.iter().map(|p| (p.slug.as_str(), p)).collect();
+        let head: BTreeMap<&str, &WikiPage> = head.iter().map(|p| (p.slug.as_str(), p)).collect();
+
+        let mut comparison = Self::default();
+        for (slug, page) in &head {
+            let Some(old) = base.get(slug) else {
+                comparison.added.push(ComparedPage::from(*page));
+                continue;
+            };
+            if old.title == page.title && old.content == page.content {
+                comparison.unchanged_count += 1;
+                continue;
+            }
+
+            let diff = LineDiff::new(&old.content, &page.content);
+            comparison.changed.push(PageChange {
+                slug: slug.to_string(),
+                title: page.title.clone(),
+                previous_title: (old.title != page.title).then(|| old.title.clone()),
+                lines_added: diff.added(),
+                lines_removed: diff.removed(),
+                diff: diff.unified(&format!("{}.md", slug)),
+            });
+        }
+        comparison.removed = base
+            .iter()
+            .filter(|(slug, _)| !head.contains_key(*slug))
+            .map(|(_, page)| ComparedPage::from(*page))
+            .collect();
+
+        comparison
+    }
+
+    /// Whether the two branches document the same pages with the same content
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Keep,
+    Remove,
+    Add,
+}
+
+/// Line-level edit script between two texts
+struct LineDiff<'a> {
+    old: Vec<&'a str>,
+    new: Vec<&'a str>,
+    ops: Vec<Op>,
+}
+
+impl<'a> LineDiff<'a> {
+    fn new(old: &'a str, new: &'a str) -> Self {
+        let old: Vec<&str> = old.lines().collect();
+        let new: Vec<&str> = new.lines().collect();
+        let ops = if old.len().saturating_mul(new.len()) > MAX_DIFF_CELLS {
+            let mut ops = vec![Op::Remove; old.len()];
+            ops.resize(old.len() + new.len(), Op::Add);
+            ops
+        } else {
+            edit_script(&old, &new)
+        };
+        Self { old, new, ops }
+    }
+
+    fn added(&self) -> u32 {
+        self.ops.iter().filter(|op| **op == Op::Add).count() as u32
+    }
+
+    fn removed(&self) -> u32 {
+        self.ops.iter().filter(|op| **op == Op::Remove).count() as u32
+    }
+
+    /// The diff in unified format, with hunks of `DIFF_CONTEXT_LINES` context
+    fn unified(&self, name: &str) -> String {
+        // Position in old and new before each op, plus the end positions
+        let mut positions = Vec::with_capacity(self.ops.len() + 1);
+        let (mut o, mut n) = (0, 0);
+        for op in &self.ops {
+            positions.push((o, n));
+            match op {
+                Op::Keep => {
+                    o += 1;
+                    n += 1;
+                }
+                Op::Remove => o += 1,
+                Op::Add => n += 1,
+            }
+        }
+        positions.push((o, n));
+
+        let changes: Vec<usize> = (0..self.ops.len())
+            .filter(|&i| self.ops[i] != Op::Keep)
+            .collect();
+        if changes.is_empty() {
+            return String::new();
+        }
+
+        // Group changes whose context windows touch into hunks of op indexes
+        let mut hunks: Vec<(usize, usize)> = Vec::new();
+        for &i in &changes {
+            let start = i.saturating_sub(DIFF_CONTEXT_LINES);
+            let end = (i + 1 + DIFF_CONTEXT_LINES).min(self.ops.len());
+            match hunks.last_mut() {
+                Some(last) if start <= last.1 => last.1 = end,
+                _ => hunks.push((start, end)),
+            }
+        }
+
+        let mut out = format!("--- a/{}\n+++ b/{}\n", name, name);
+        for (start, end) in hunks {
+            let (old_start, new_start) = positions[start];
+            let (old_end, new_end) = positions[end];
+            out.push_str(&format!(
+                "@@ -{} +{} @@\n",
+                hunk_range(old_start, old_end - old_start),
+                hunk_range(new_start, new_end - new_start)
+            ));
+            for (op, (o, n)) in self.ops[start..end].iter().zip(&positions[start..end]) {
+                match op {
+                    Op::Keep => out.push_str(&format!(" {}\n", self.old[*o])),
+                    Op::Remove => out.push_str(&format!("-{}\n", self.old[*o])),
+                    Op::Add => out.push_str(&format!("+{}\n", self.new[*n])),
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Hunk header range: 1-based start and line count, `start,0` when empty
+fn hunk_range(start: usize, len: usize) -> String {
+    match len {
+        0 => format!("{},0", start),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{}", start + 1, len),
+    }
+}
+
+/// Shortest edit script from `old` to `new` via longest common subsequence,
+/// removals before additions within each change
+fn edit_script(old: &[&str], new: &[&str]) -> Vec<Op> {
+    let width = new.len() + 1;
+    // lcs[i * width + j]: LCS length of old[i..] and new[j..]
+    let mut lcs = vec![0u32; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i * width + j] = if old[i] == new[j] {
+                lcs[(i + 1) * width + j + 1] + 1
+            } else {
+                lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut ops = Vec::with_capacity(old.len() + new.len());
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            ops.push(Op::Keep);
+            i += 1;
+            j += 1;
+        } else if j == new.len()
+            || (i < old.len() && lcs[(i + 1) * width + j] >= lcs[i * width + j + 1])
+        {
+            ops.push(Op::Remove);
+            i += 1;
+        } else {
+            ops.push(Op::Add);
+            j += 1;
+        }
+    }
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::wiki_page::PageType;
+
+    fn page(branch: &str, slug: &str, title: &str, content: &str) -> WikiPage {
+        WikiPage::new(
+            branch.to_string(),
+            slug.to_string(),
+            title.to_string(),
+            content.to_string(),
+            PageType::Module,
+            None,
+            0,
+            Vec::new(),
+            "abc123".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_compare_branches() {
+        let base = vec![
+            page("release", "overview", "Overview", "Intro\n"),
+            page("release", "auth", "Auth", "Login\n"),
+            page("release", "legacy", "Legacy API", "Old\n"),
+        ];
+        let head = vec![
+            page("main", "overview", "Overview", "Intro\n"),
+            page("main", "auth", "Authentication", "Login\nTokens\n"),
+            page("main", "billing", "Billing", "Invoices\n"),
+        ];
+
+        let comparison = BranchComparison::from_pages(&base, &head);
+
+        assert_eq!(comparison.unchanged_count, 1);
+        assert_eq!(comparison.added[0].slug, "billing");
+        assert_eq!(comparison.removed[0].title, "Legacy API");
+
+        let change = &comparison.changed[0];
+        assert_eq!(change.slug, "auth");
+        assert_eq!(change.previous_title.as_deref(), Some("Auth"));
+        assert_eq!((change.lines_added, change.lines_removed), (1, 0));
+        assert!(change.diff.contains(" Login\n+Tokens\n"));
+        assert!(!comparison.is_empty());
+    }
+
+    #[test]
+    fn test_unified_diff_hunks() {
+        let old: String = (1..=20).map(|i| format!("line {}\n", i)).collect();
+        let new = old
+            .replace("line 2\n", "line two\n")
+            .replace("line 18\n", "");
+
+        let diff = LineDiff::new(&old, &new);
+        assert_eq!((diff.added(), diff.removed()), (1, 2));
+
+        let unified = diff.unified("page.md");
+        assert!(unified.starts_with(
+            "--- a/page.md\n+++ b/page.md\n@@ -1,5 +1,5 @@\n line 1\n-line 2\n+line two\n line 3\n"
+        ));
+        assert!(unified.contains(
+            "@@ -15,6 +15,5 @@\n line 15\n line 16\n line 17\n-line 18\n line 19\n line 20\n"
+        ));
+
+        assert_eq!(LineDiff::new(&old, &old).unified("page.md"), "");
+    }
+}
@@ -0,0 +1,1006 @@
+//! Database schema extraction for the "Data Model" wiki page
+//!
+//! Replays SQL migrations (in file path order, which matches the usual
+//! numbered/timestamped naming) and diesel `table!` schema files into a
+//! table/column/relationship model. The Mermaid ER diagram is rendered from
+//! that model, so the page is deterministic and never hallucinated.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::OnceLock;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::chunk::CodeChunk;
+use crate::domain::wiki_page::{Importance, PageType, SourceCitation, WikiPage};
+
+/// Slug of the generated data model page
+pub const DATA_MODEL_PAGE_SLUG: &str = "data-model";
+
+/// A table column
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Column {
+    pub name: String,
+    /// Declared type as written (may be empty, e.g. SQLite untyped columns)
+    pub data_type: String,
+    pub nullable: bool,
+    pub primary_key: bool,
+}
+
+/// A database table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Table {
+    pub name: String,
+    pub columns: Vec<Column>,
+    /// Files that create or alter this table
+    pub source_files: Vec<String>,
+}
+
+/// A foreign key relationship (`from_table.from_column` -> `to_table.to_column`)
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Relationship {
+    pub from_table: String,
+    pub from_column: String,
+    pub to_table: String,
+    /// Referenced column; `None` when the reference targets the primary key implicitly
+    pub to_column: Option<String>,
+}
+
+/// Tables and relationships extracted from schema sources
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DataModel {
+    pub tables: Vec<Table>,
+    pub relationships: Vec<Relationship>,
+    /// Files the model was built from, in the order they were applied
+    pub source_files: Vec<String>,
+}
+
+fn create_table_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"(?is)^CREATE\s+(?:(?:GLOBAL\s+|LOCAL\s+)?(?:TEMP|TEMPORARY)\s+)?(?:UNLOGGED\s+)?TABLE\s+(?:IF\s+NOT\s+EXISTS\s+)?([^\s(]+)\s*\((.*)\)",
+        )
+        .expect("valid create table regex")
+    })
+}
+
+fn alter_table_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?is)^ALTER\s+TABLE\s+(?:IF\s+EXISTS\s+)?(?:ONLY\s+)?(\S+)\s+(.*)$")
+            .expect("valid alter table regex")
+    })
+}
+
+fn drop_table_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?is)^DROP\s+TABLE\s+(?:IF\s+EXISTS\s+)?(.+?)(?:\s+(?:CASCADE|RESTRICT))?$")
+            .expect("valid drop table regex")
+    })
+}
+
+fn constraint_keyword_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"(?i)\b(?:NOT\s+NULL|NULL|PRIMARY\s+KEY|REFERENCES|DEFAULT|UNIQUE|CHECK|CONSTRAINT|GENERATED|COLLATE|AUTOINCREMENT|AUTO_INCREMENT|IDENTITY)\b",
+        )
+        .expect("valid constraint keyword regex")
+    })
+}
+
+fn references_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?i)\bREFERENCES\s+([^\s(]+)\s*(?:\(\s*([^)]*?)\s*\))?")
+            .expect("valid references regex")
+    })
+}
+
+fn foreign_key_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?is)^FOREIGN\s+KEY\s*\(([^)]*)\)\s*REFERENCES\s+([^\s(]+)\s*(?:\(([^)]*)\))?")
+            .expect("valid foreign key regex")
+    })
+}
+
+fn primary_key_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?is)^PRIMARY\s+KEY\s*\(([^)]*)\)").expect("valid primary key regex")
+    })
+}
+
+fn diesel_table_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?s)table!\s*\{(?:\s*(?:use\s[^;]*;|#\[[^\]]*\]))*\s*([\w.]+)\s*(?:\(([^)]*)\))?\s*\{(.*?)\}\s*\}")
+            .expect("valid diesel table regex")
+    })
+}
+
+fn diesel_column_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?m)^\s*(?:r#)?(\w+)\s*->\s*([^,\n]+?)\s*,?\s*$")
+            .expect("valid diesel column regex")
+    })
+}
+
+fn diesel_joinable_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"joinable!\s*\(\s*(\w+)\s*->\s*(\w+)\s*\(\s*(\w+)\s*\)\s*\)")
+            .expect("valid diesel joinable regex")
+    })
+}
+
+/// Strip quoting (`"x"`, `` `x` ``, `[x]`) from each part of a possibly qualified identifier
+fn unquote(ident: &str) -> String {
+    ident
+        .trim()
+        .split('.')
+        .map(|part| {
+            part.trim_matches(|c| matches!(c, '"' | '`' | '[' | ']'))
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn ident_list(list: &str) -> Vec<String> {
+    list.split(',')
+        .map(unquote)
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Remove `--` and `/* */` comments, leaving string literals intact
+fn strip_sql_comments(sql: &str) -> String {
+    let mut out = String::with_capacity(sql.len());
+    let mut chars = sql.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            if c == '\'' {
+                in_string = false;
+            }
+            continue;
+        }
+        match c {
+            '\'' => {
+                in_string = true;
+                out.push(c);
+            }
+            '-' if chars.peek() == Some(&'-') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = ' ';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+                out.push(' ');
+            }
+            _ => out.push(c),
+        }
+    }
+
+    out
+}
+
+/// Split `text` on `separator` outside of parentheses, string literals and `$$` blocks
+fn split_top_level(text: &str, separator: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0i32;
+    let mut in_string = false;
+    let mut in_dollar = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '$' && !in_string && chars.peek() == Some(&'$') {
+            chars.next();
+            in_dollar = !in_dollar;
+            current.push_str("$$");
+            continue;
+        }
+        match c {
+            '\'' if !in_dollar => in_string = !in_string,
+            '(' if !in_string && !in_dollar => depth += 1,
+            ')' if !in_string && !in_dollar => depth -= 1,
+            _ => {}
+        }
+        if c == separator && depth == 0 && !in_string && !in_dollar {
+            parts.push(std::mem::take(&mut current));
+        } else {
+            current.push(c);
+        }
+    }
+    parts.push(current);
+
+    parts
+        .into_iter()
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty())
+        .collect()
+}
+
+/// Split a leading (possibly quoted) identifier from the rest of a definition
+fn split_ident(def: &str) -> (String, &str) {
+    let def = def.trim_start();
+    let end = match def.chars().next() {
+        Some(q @ ('"' | '`' | '[')) => {
+            let close = if q == '[' { ']' } else { q };
+            def[1..].find(close).map(|i| i + 2).unwrap_or(def.len())
+        }
+        _ => def.find(char::is_whitespace).unwrap_or(def.len()),
+    };
+    (unquote(&def[..end]), &def[end..])
+}
+
+fn starts_with_keyword(text: &str, keyword: &str) -> bool {
+    text.get(..keyword.len())
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(keyword))
+        && text[keyword.len()..]
+            .chars()
+            .next()
+            .map_or(true, |c| !c.is_alphanumeric() && c != '_')
+}
+
+impl DataModel {
+    /// Build the model from indexed chunks by reassembling schema files.
+    ///
+    /// SQL files and Rust files containing diesel `table!` macros are used;
+    /// everything else is ignored.
+    pub fn from_chunks(chunks: &[CodeChunk]) -> Self {
+        let mut files: BTreeMap<&str, BTreeMap<u32, &str>> = BTreeMap::new();
+        for chunk in chunks {
+            let is_sql = chunk.file_path.to_lowercase().ends_with(".sql");
+            let is_rust = chunk.file_path.ends_with(".rs");
+            if !is_sql && !is_rust {
+                continue;
+            }
+            let lines = files.entry(chunk.file_path.as_str()).or_default();
+            for (i, line) in chunk.content.lines().enumerate() {
+                lines.entry(chunk.start_line + i as u32).or_insert(line);
+            }
+        }
+
+        let mut model = DataModel::default();
+        for (path, lines) in files {
+            let content = lines.into_values().collect::<Vec<_>>().join("\n");
+            if path.to_lowercase().ends_with(".sql") {
+                model.apply_sql(path, &content);
+            } else if content.contains("table!") {
+                model.apply_diesel_schema(path, &content);
+            }
+        }
+        model.finish();
+        model
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tables.is_empty()
+    }
+
+    pub fn table(&self, name: &str) -> Option<&Table> {
+        self.tables.iter().find(|t| t.name == name)
+    }
+
+    fn table_mut(&mut self, name: &str) -> Option<&mut Table> {
+        self.tables.iter_mut().find(|t| t.name == name)
+    }
+
+    fn touch(&mut self, table: &str, file: &str) {
+        if let Some(t) = self.table_mut(table) {
+            if !t.source_files.iter().any(|f| f == file) {
+                t.source_files.push(file.to_string());
+            }
+            if !self.source_files.iter().any(|f| f == file) {
+                self.source_files.push(file.to_string());
+            }
+        }
+    }
+
+    /// Apply the DDL statements of one SQL file
+    pub fn apply_sql(&mut self, file: &str, sql: &str) {
+        for statement in split_top_level(&strip_sql_comments(sql), ';') {
+            if let Some(cap) = create_table_regex().captures(&statement) {
+                self.create_table(file, &unquote(&cap[1]), &cap[2]);
+            } else if let Some(cap) = alter_table_regex().captures(&statement) {
+                let table = unquote(&cap[1]);
+                for action in split_top_level(&cap[2], ',') {
+                    self.alter_table(file, &table, &action);
+                }
+            } else if let Some(cap) = drop_table_regex().captures(&statement) {
+                for name in ident_list(&cap[1]) {
+                    self.drop_table(&name);
+                }
+            }
+        }
+    }
+
+    fn create_table(&mut self, file: &str, name: &str, body: &str) {
+        self.drop_table(name);
+        self.tables.push(Table {
+            name: name.to_string(),
+            columns: Vec::new(),
+            source_files: Vec::new(),
+        });
+
+        for item in split_top_level(body, ',') {
+            if !self.apply_table_constraint(name, &item) {
+                self.add_column(name, &item);
+            }
+        }
+        self.touch(name, file);
+    }
+
+    /// Handle a table-level constraint; returns `false` if `item` is not one
+    fn apply_table_constraint(&mut self, table: &str, item: &str) -> bool {
+        let mut item = item.trim();
+        if starts_with_keyword(item, "CONSTRAINT") {
+            item = split_ident(&item["CONSTRAINT".len()..]).1.trim_start();
+        }
+
+        if let Some(cap) = primary_key_regex().captures(item) {
+            let columns = ident_list(&cap[1]);
+            if let Some(t) = self.table_mut(table) {
+                for column in t.columns.iter_mut() {
+                    if columns.contains(&column.name) {
+                        column.primary_key = true;
+                        column.nullable = false;
+                    }
+                }
+            }
+            return true;
+        }
+
+        if let Some(cap) = foreign_key_regex().captures(item) {
+            let from = ident_list(&cap[1]);
+            let to_table = unquote(&cap[2]);
+            let to = cap
+                .get(3)
+                .map(|m| ident_list(m.as_str()))
+                .unwrap_or_default();
+            for (i, from_column) in from.into_iter().enumerate() {
+                self.relationships.push(Relationship {
+                    from_table: table.to_string(),
+                    from_column,
+                    to_table: to_table.clone(),
+                    to_column: to.get(i).cloned(),
+                });
+            }
+            return true;
+        }
+
+        ["UNIQUE", "CHECK", "INDEX", "KEY", "EXCLUDE"]
+            .iter()
+            .any(|k| starts_with_keyword(item, k))
+    }
+
+    fn add_column(&mut self, table: &str, def: &str) {
+        let (name, rest) = split_ident(def);
+        if name.is_empty() {
+            return;
+        }
+
+        let type_end = constraint_keyword_regex()
+            .find(rest)
+            .map(|m| m.start())
+            .unwrap_or(rest.len());
+        let data_type = rest[..type_end].trim().to_string();
+        let upper = rest.to_uppercase();
+        let primary_key = upper.contains("PRIMARY KEY");
+        let nullable = !primary_key && !upper.contains("NOT NULL");
+
+        if let Some(cap) = references_regex().captures(rest) {
+            self.relationships.push(Relationship {
+                from_table: table.to_string(),
+                from_column: name.clone(),
+                to_table: unquote(&cap[1]),
+                to_column: cap
+                    .get(2)
+                    .map(|m| unquote(m.as_str()))
+                    .filter(|c| !c.is_empty()),
+            });
+        }
+
+        if let Some(t) = self.table_mut(table) {
+            t.columns.retain(|c| c.name != name);
+            t.columns.push(Column {
+                name,
+                data_type,
+                nullable,
+                primary_key,
+            });
+        }
+    }
+
+    fn alter_table(&mut self, file: &str, table: &str, action: &str) {
+        let action = action.trim();
+        let words: Vec<&str> = action.split_whitespace().collect();
+        let upper: Vec<String> = words.iter().map(|w| w.to_uppercase()).collect();
+        let is = |i: usize, word: &str| upper.get(i).is_some_and(|w| w == word);
+
+        if is(0, "RENAME") && is(1, "TO") {
+            if let Some(new_name) = words.get(2).map(|w| unquote(w)) {
+                self.rename_table(table, &new_name);
+                self.touch(&new_name, file);
+            }
+            return;
+        }
+
+        if is(0, "RENAME") {
+            let skip = if is(1, "COLUMN") { 2 } else { 1 };
+            if let (Some(old), true, Some(new)) = (
+                words.get(skip).map(|w| unquote(w)),
+                is(skip + 1, "TO"),
+                words.get(skip + 2).map(|w| unquote(w)),
+            ) {
+                self.rename_column(table, &old, &new);
+            }
+        } else if is(0, "ADD") {
+            let rest = action[words[0].len()..].trim_start();
+            if !self.apply_table_constraint(table, rest) {
+                let mut rest = rest;
+                for keyword in ["COLUMN", "IF NOT EXISTS"] {
+                    if starts_with_keyword(rest, keyword) {
+                        rest = rest[keyword.len()..].trim_start();
+                    }
+                }
+                self.add_column(table, rest);
+            }
+        } else if is(0, "DROP") && !is(1, "CONSTRAINT") {
+            let mut skip = if is(1, "COLUMN") { 2 } else { 1 };
+            if is(skip, "IF") && is(skip + 1, "EXISTS") {
+                skip += 2;
+            }
+            if let Some(column) = words.get(skip).map(|w| unquote(w)) {
+                self.drop_column(table, &column);
+            }
+        } else if is(0, "ALTER") {
+            let skip = if is(1, "COLUMN") { 2 } else { 1 };
+            let column = words.get(skip).map(|w| unquote(w)).unwrap_or_default();
+            let tail = upper.get(skip + 1..).unwrap_or_default().join(" ");
+            let new_type = upper.iter().position(|w| w == "TYPE").map(|i| {
+                words[i + 1..]
+                    .iter()
+                    .take_while(|w| !matches!(w.to_uppercase().as_str(), "USING" | "COLLATE"))
+                    .copied()
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            });
+            if let Some(c) = self
+                .table_mut(table)
+                .and_then(|t| t.columns.iter_mut().find(|c| c.name == column))
+            {
+                if tail.starts_with("SET NOT NULL") {
+                    c.nullable = false;
+                } else if tail.starts_with("DROP NOT NULL") {
+                    c.nullable = true;
+                } else if let Some(data_type) = new_type {
+                    c.data_type = data_type;
+                }
+            }
+        }
+
+        self.touch(table, file);
+    }
+
+    fn drop_table(&mut self, name: &str) {
+        self.tables.retain(|t| t.name != name);
+        self.relationships
+            .retain(|r| r.from_table != name && r.to_table != name);
+    }
+
+    fn drop_column(&mut self, table: &str, column: &str) {
+        if let Some(t) = self.table_mut(table) {
+            t.columns.retain(|c| c.name != column);
+        }
+        self.relationships
+            .retain(|r| !(r.from_table == table && r.from_column == column));
+    }
+
+    fn rename_table(&mut self, old: &str, new: &str) {
+        self.drop_table(new);
+        if let Some(t) = self.table_mut(old) {
+            t.name = new.to_string();
+        }
+        for r in self.relationships.iter_mut() {
+            if r.from_table == old {
+                r.from_table = new.to_string();
+            }
+            if r.to_table == old {
+                r.to_table = new.to_string();
+            }
+        }
+    }
+
+    fn rename_column(&mut self, table: &str, old: &str, new: &str) {
+        if let Some(c) = self
+            .table_mut(table)
+            .and_then(|t| t.columns.iter_mut().find(|c| c.name == old))
+        {
+            c.name = new.to_string();
+        }
+        for r in self.relationships.iter_mut() {
+            if r.from_table == table && r.from_column == old {
+                r.from_column = new.to_string();
+            }
+            if r.to_table == table && r.to_column.as_deref() == Some(old) {
+                r.to_column = Some(new.to_string());
+            }
+        }
+    }
+
+    /// Apply diesel `table!` and `joinable!` declarations from a Rust schema file
+    pub fn apply_diesel_schema(&mut self, file: &str, source: &str) {
+        for cap in diesel_table_regex().captures_iter(source) {
+            let name = cap[1].to_string();
+            let primary_keys = cap
+                .get(2)
+                .map(|m| ident_list(m.as_str()))
+                .unwrap_or_else(|| vec!["id".to_string()]);
+
+            let columns = diesel_column_regex()
+                .captures_iter(&cap[3])
+                .map(|col| {
+                    let ty = col[2].trim();
+                    let (data_type, nullable) = match ty
+                        .strip_prefix("Nullable<")
+                        .and_then(|t| t.strip_suffix('>'))
+                    {
+                        Some(inner) => (inner.to_string(), true),
+                        None => (ty.to_string(), false),
+                    };
+                    Column {
+                        primary_key: primary_keys.contains(&col[1].to_string()),
+                        name: col[1].to_string(),
+                        data_type,
+                        nullable,
+                    }
+                })
+                .collect();
+
+            self.drop_table(&name);
+            self.tables.push(Table {
+                name: name.clone(),
+                columns,
+                source_files: Vec::new(),
+            });
+            self.touch(&name, file);
+        }
+
+        for cap in diesel_joinable_regex().captures_iter(source) {
+            self.relationships.push(Relationship {
+                from_table: cap[1].to_string(),
+                from_column: cap[3].to_string(),
+                to_table: cap[2].to_string(),
+                to_column: None,
+            });
+        }
+    }
+
+    /// Resolve implicit primary key references, drop dangling relationships
+    /// and sort everything for deterministic output
+    fn finish(&mut self) {
+        let primary_keys: BTreeMap<String, Vec<String>> = self
+            .tables
+            .iter()
+            .map(|t| {
+                let pks = t
+                    .columns
+                    .iter()
+                    .filter(|c| c.primary_key)
+                    .map(|c| c.name.clone())
+                    .collect();
+                (t.name.clone(), pks)
+            })
+            .collect();
+
+        for r in self.relationships.iter_mut() {
+            if r.to_column.is_none() {
+                if let Some([pk]) = primary_keys.get(&r.to_table).map(Vec::as_slice) {
+                    r.to_column = Some(pk.clone());
+                }
+            }
+        }
+
+        let tables: BTreeSet<&str> = self.tables.iter().map(|t| t.name.as_str()).collect();
+        let mut relationships: Vec<Relationship> = self
+            .relationships
+            .iter()
+            .filter(|r| tables.contains(r.from_table.as_str()))
+            .cloned()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        relationships.sort();
+
+        self.relationships = relationships;
+        self.tables.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+
+    fn foreign_key(&self, table: &str, column: &str) -> Option<&Relationship> {
+        self.relationships
+            .iter()
+            .find(|r| r.from_table == table && r.from_column == column)
+    }
+
+    /// Render the model as a Mermaid ER diagram
+    pub fn to_mermaid(&self) -> String {
+        fn sanitize(s: &str) -> String {
+            let cleaned: String = s
+                .chars()
+                .map(|c| {
+                    if c.is_alphanumeric() || c == '_' {
+                        c
+                    } else {
+                        '_'
+                    }
+                })
+                .collect();
+            let cleaned = cleaned.trim_matches('_').to_string();
+            if cleaned.is_empty() {
+                "unknown".to_string()
+            } else {
+                cleaned
+            }
+        }
+
+        let mut out = String::from("erDiagram\n");
+        for table in &self.tables {
+            out.push_str(&format!("    {} {{\n", sanitize(&table.name)));
+            for column in &table.columns {
+                let is_fk = self.foreign_key(&table.name, &column.name).is_some();
+                let keys = match (column.primary_key, is_fk) {
+                    (true, true) => " PK, FK",
+                    (true, false) => " PK",
+                    (false, true) => " FK",
+                    (false, false) => "",
+                };
+                out.push_str(&format!(
+                    "        {} {}{}\n",
+                    sanitize(&column.data_type),
+                    sanitize(&column.name),
+                    keys
+                ));
+            }
+            out.push_str("    }\n");
+        }
+
+        for r in &self.relationships {
+            let nullable = self
+                .table(&r.from_table)
+                .and_then(|t| t.columns.iter().find(|c| c.name == r.from_column))
+                .map_or(true, |c| c.nullable);
+            out.push_str(&format!(
+                "    {} {}--o{{ {} : \"{}\"\n",
+                sanitize(&r.to_table),
+                if nullable { "|o" } else { "||" },
+                sanitize(&r.from_table),
+                r.from_column
+            ));
+        }
+
+        out
+    }
+
+    /// Render the model as markdown with an ER diagram and per-table column lists
+    pub fn to_markdown(&self) -> String {
+        let mut md = String::from("# Data Model\n\n");
+        md.push_str(&format!(
+            "This page is generated from database schema sources found in the indexed code: \
+             {} table{} and {} relationship{} from {} file{}.\n\n",
+            self.tables.len(),
+            if self.tables.len() == 1 { "" } else { "s" },
+            self.relationships.len(),
+            if self.relationships.len() == 1 {
+                ""
+            } else {
+                "s"
+            },
+            self.source_files.len(),
+            if self.source_files.len() == 1 {
+                ""
+            } else {
+                "s"
+            },
+        ));
+
+        md.push_str("```mermaid\n");
+        md.push_str(&self.to_mermaid());
+        md.push_str("```\n\n## Tables\n");
+
+        for table in &self.tables {
+            md.push_str(&format!("\n### `{}`\n\n", table.name));
+            md.push_str("| Column | Type | Nullable | Key |\n");
+            md.push_str("|--------|------|----------|-----|\n");
+            for column in &table.columns {
+                let mut keys = Vec::new();
+                if column.primary_key {
+                    keys.push("PK".to_string());
+                }
+                if let Some(fk) = self.foreign_key(&table.name, &column.name) {
+                    keys.push(match &fk.to_column {
+                        Some(col) => format!("FK → `{}.{}`", fk.to_table, col),
+                        None => format!("FK → `{}`", fk.to_table),
+                    });
+                }
+                md.push_str(&format!(
+                    "| `{}` | {} | {} | {} |\n",
+                    column.name,
+                    if column.data_type.is_empty() {
+                        "-".to_string()
+                    } else {
+                        format!("`{}`", column.data_type)
+                    },
+                    if column.nullable { "yes" } else { "no" },
+                    if keys.is_empty() {
+                        "-".to_string()
+                    } else {
+                        keys.join(", ")
+                    }
+                ));
+            }
+            md.push_str(&format!(
+                "\nDefined in: {}\n",
+                table
+                    .source_files
+                    .iter()
+                    .map(|f| format!("`{}`", f))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+
+        md
+    }
+
+    /// Render the model as a wiki page
+    pub fn to_wiki_page(&self, branch: &str, commit_sha: &str, order: u32) -> WikiPage {
+        let citations = self
+            .source_files
+            .iter()
+            .map(|f| SourceCitation::new(f.clone(), None, None))
+            .collect();
+
+        WikiPage::new_advanced(
+            branch.to_string(),
+            DATA_MODEL_PAGE_SLUG.to_string(),
+            "Data Model".to_string(),
+            self.to_markdown(),
+            PageType::Architecture,
+            None,
+            order,
+            self.source_files.clone(),
+            commit_sha.to_string(),
+            Importance::High,
+            Vec::new(),
+            None,
+            citations,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::mermaid::MermaidValidator;
+
+    fn column_names(table: &Table) -> Vec<&str> {
+        table.columns.iter().map(|c| c.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_apply_sql_migrations() {
+        let mut model = DataModel::default();
+        model.apply_sql(
+            "migrations/001_init.sql",
+            r#"
+-- Users; with a semicolon in a comment
+CREATE TABLE IF NOT EXISTS "users" (
+    id TEXT PRIMARY KEY NOT NULL,
+    email VARCHAR(255) NOT NULL UNIQUE,
+    name TEXT
+);
+
+CREATE TABLE posts (
+    id INTEGER,
+    user_id TEXT NOT NULL,
+    title TEXT DEFAULT 'a;b',
+    PRIMARY KEY (id),
+    CONSTRAINT fk_user FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+"#,
+        );
+        model.apply_sql(
+            "migrations/002_more.sql",
+            r#"
+ALTER TABLE posts ADD COLUMN reviewer_id TEXT REFERENCES users;
+ALTER TABLE posts DROP COLUMN title;
+ALTER TABLE users RENAME COLUMN name TO display_name;
+ALTER TABLE users ALTER COLUMN email TYPE TEXT USING email::text, ALTER COLUMN display_name SET NOT NULL;
+CREATE TABLE scratch (id INTEGER);
+DROP TABLE IF EXISTS scratch;
+"#,
+        );
+        model.finish();
+
+        assert_eq!(model.tables.len(), 2);
+        let posts = model.table("posts").unwrap();
+        assert_eq!(column_names(posts), vec!["id", "user_id", "reviewer_id"]);
+        assert!(posts.columns[0].primary_key);
+        assert!(!posts.columns[1].nullable);
+        assert_eq!(
+            posts.source_files,
+            vec!["migrations/001_init.sql", "migrations/002_more.sql"]
+        );
+
+        let users = model.table("users").unwrap();
+        assert_eq!(column_names(users), vec!["id", "email", "display_name"]);
+        assert_eq!(users.columns[1].data_type, "TEXT");
+        assert!(!users.columns[2].nullable);
+
+        assert_eq!(
+            model.relationships,
+            vec![
+                Relationship {
+                    from_table: "posts".into(),
+                    from_column: "reviewer_id".into(),
+                    to_table: "users".into(),
+                    to_column: Some("id".into()),
+                },
+                Relationship {
+                    from_table: "posts".into(),
+                    from_column: "user_id".into(),
+                    to_table: "users".into(),
+                    to_column: Some("id".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_apply_sql_rename_table() {
+        let mut model = DataModel::default();
+        model.apply_sql(
+            "m.sql",
+            "CREATE TABLE a (id INTEGER PRIMARY KEY);\n\
+             CREATE TABLE b (a_id INTEGER REFERENCES a(id));\n\
+             ALTER TABLE a RENAME TO accounts;",
+        );
+        model.finish();
+
+        assert!(model.table("a").is_none());
+        assert!(model.table("accounts").is_some());
+        assert_eq!(model.relationships[0].to_table, "accounts");
+    }
+
+    #[test]
+    fn test_apply_sql_non_ascii_column_names() {
+        let mut model = DataModel::default();
+        model.apply_sql(
+            "m.sql",
+            "CREATE TABLE people (é_id INTEGER PRIMARY KEY, prénom TEXT, größe INTEGER);\n\
+             ALTER TABLE people ADD prüfung TEXT;",
+        );
+        model.finish();
+
+        let names: Vec<_> = model
+            .table("people")
+            .unwrap()
+            .columns
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["é_id", "prénom", "größe", "prüfung"]);
+    }
+
+    #[test]
+    fn test_apply_diesel_schema() {
+        let mut model = DataModel::default();
+        model.apply_diesel_schema(
+            "src/schema.rs",
+            r#"
+// @generated automatically by Diesel CLI.
+
+diesel::table! {
+    posts (id) {
+        id -> Int4,
+        user_id -> Int4,
+        body -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    users {
+        id -> Int4,
+        name -> Varchar,
+    }
+}
+
+diesel::joinable!(posts -> users (user_id));
+"#,
+        );
+        model.finish();
+
+        assert_eq!(model.tables.len(), 2);
+        let posts = model.table("posts").unwrap();
+        assert_eq!(column_names(posts), vec!["id", "user_id", "body"]);
+        assert!(posts.columns[2].nullable);
+        assert_eq!(posts.columns[2].data_type, "Text");
+        assert!(model.table("users").unwrap().columns[0].primary_key);
+        assert_eq!(model.relationships[0].to_column.as_deref(), Some("id"));
+    }
+
+    #[test]
+    fn test_from_chunks_reassembles_overlapping_chunks() {
+        let chunk = |start: u32, content: &str, index: u32| {
+            CodeChunk::new(
+                "main".to_string(),
+                "migrations/001.sql".to_string(),
+                start,
+                start + content.lines().count() as u32 - 1,
+                content.to_string(),
+                crate::domain::chunk::ChunkType::Code,
+                Some("sql".to_string()),
+                10,
+                index,
+                "abc".to_string(),
+            )
+        };
+        let chunks = vec![
+            chunk(1, "CREATE TABLE tasks (\n    id TEXT PRIMARY KEY,", 0),
+            chunk(
+                2,
+                "    id TEXT PRIMARY KEY,\n    title TEXT NOT NULL\n);",
+                1,
+            ),
+        ];
+
+        let model = DataModel::from_chunks(&chunks);
+        let tasks = model.table("tasks").unwrap();
+        assert_eq!(column_names(tasks), vec!["id", "title"]);
+    }
+
+    #[test]
+    fn test_render_page() {
+        let mut model = DataModel::default();
+        model.apply_sql(
+            "migrations/001.sql",
+            "CREATE TABLE users (id TEXT PRIMARY KEY);\n\
+             CREATE TABLE posts (id TEXT PRIMARY KEY, user_id TEXT NOT NULL REFERENCES users(id));",
+        );
+        model.finish();
+
+        let mermaid = model.to_mermaid();
+        assert!(mermaid.contains("        TEXT user_id FK\n"));
+        assert!(mermaid.contains("    users ||--o{ posts : \"user_id\"\n"));
+        assert!(MermaidValidator::validate(&mermaid).is_valid);
+
+        let page = model.to_wiki_page("main", "abc", 3);
+        assert_eq!(page.slug, DATA_MODEL_PAGE_SLUG);
+        assert!(page.has_diagrams);
+        assert!(page
+            .content
+            .contains("| `user_id` | `TEXT` | no | FK → `users.id` |"));
+        assert_eq!(page.file_paths, vec!["migrations/001.sql".to_string()]);
+    }
+}
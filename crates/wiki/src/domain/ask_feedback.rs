@@ -0,0 +1,104 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Negative votes a source needs before it is flagged
+pub const FLAG_MIN_NEGATIVE_VOTES: u32 = 2;
+
+/// A user's verdict on a wiki answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AskRating {
+    Up,
+    Down,
+}
+
+impl AskRating {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AskRating::Up => "up",
+            AskRating::Down => "down",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "up" => Some(AskRating::Up),
+            "down" => Some(AskRating::Down),
+            _ => None,
+        }
+    }
+}
+
+/// Code range retrieved to answer a question
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeedbackSource {
+    pub file_path: String,
+    pub start_line: u32,
+    pub end_line: u32,
+}
+
+/// Feedback on one answer, with what was retrieved to produce it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AskFeedback {
+    pub id: String,
+    pub conversation_id: String,
+    pub rating: AskRating,
+    pub question: String,
+    pub answer: String,
+    pub sources: Vec<FeedbackSource>,
+    pub comment: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AskFeedback {
+    pub fn new(
+        conversation_id: impl Into<String>,
+        rating: AskRating,
+        question: impl Into<String>,
+        answer: impl Into<String>,
+        sources: Vec<FeedbackSource>,
+    ) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            conversation_id: conversation_id.into(),
+            rating,
+            question: question.into(),
+            answer: answer.into(),
+            sources,
+            comment: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn with_comment(mut self, comment: impl Into<String>) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
+}
+
+/// A retrieved code range with its votes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceVotes {
+    pub source: FeedbackSource,
+    pub positive: u32,
+    pub negative: u32,
+}
+
+impl SourceVotes {
+    /// Whether answers built on the source are mostly rated down, making it a
+    /// candidate for re-chunking
+    pub fn is_flagged(&self) -> bool {
+        self.negative >= FLAG_MIN_NEGATIVE_VOTES && self.negative > self.positive
+    }
+}
+
+/// Totals over all feedback
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AskFeedbackStats {
+    pub total: u32,
+    pub positive: u32,
+    pub negative: u32,
+    /// Sources of rated-down answers that meet the flagging threshold, most
+    /// negative votes first
+    pub flagged_sources: Vec<SourceVotes>,
+}
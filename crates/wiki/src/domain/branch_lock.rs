@@ -0,0 +1,37 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// How often a running job refreshes its branch lock
+pub const BRANCH_LOCK_HEARTBEAT_SECS: u64 = 30;
+
+/// How long a lock outlives its last heartbeat before another job may take
+/// it over, e.g. after the process holding it crashed
+pub const BRANCH_LOCK_STALE_SECS: i64 = 300;
+
+/// Indexing or generation job holding a branch, so concurrent runs do not
+/// write the same branch rows
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BranchLock {
+    pub branch: String,
+    pub job_id: String,
+    /// What the job does, e.g. `indexing` or `generation`
+    pub operation: String,
+    pub acquired_at: DateTime<Utc>,
+    pub heartbeat_at: DateTime<Utc>,
+}
+
+impl BranchLock {
+    /// Whether the holder stopped refreshing the lock
+    pub fn is_stale(&self, now: DateTime<Utc>) -> bool {
+        now - self.heartbeat_at > Duration::seconds(BRANCH_LOCK_STALE_SECS)
+    }
+}
+
+/// Outcome of trying to lock a branch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BranchLockAttempt {
+    /// The branch is now locked by a new job
+    Acquired(BranchLock),
+    /// Another job holds the branch; callers attach to its progress instead
+    Held(BranchLock),
+}
@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Plain-language summary of a workspace diff, for reviewers
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangeSummary {
+    /// Head commit the diff was taken at; summaries are reused while it
+    /// stays the same
+    pub head_commit: String,
+    /// Per-file summaries, in diff order
+    pub files: Vec<FileChangeSummary>,
+    /// Changes a reviewer should look at closely
+    pub risks: Vec<ChangeRisk>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// What changed in one file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileChangeSummary {
+    pub file_path: String,
+    /// One short sentence per notable change
+    pub bullets: Vec<String>,
+}
+
+/// A change that could break something or needs a careful look
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangeRisk {
+    pub file_path: String,
+    pub description: String,
+}
@@ -93,6 +93,28 @@ pub struct CodeChunk {
 
     /// Timestamp when created
     pub created_at: chrono::DateTime<chrono::Utc>,
+
+    /// One-line natural-language summary, when chunk summaries are enabled
+    #[serde(default)]
+    pub summary: Option<String>,
+
+    /// Configured workspace root the file belongs to, e.g. `backend`; `None`
+    /// when the whole repository is indexed as one root
+    #[serde(default)]
+    pub root: Option<String>,
+
+    /// Whether the chunk defines public API: an HTTP handler, an exported
+    /// item or a CLI command
+    #[serde(default)]
+    pub is_public_api: bool,
+
+    /// Names of the functions and types defined in the chunk
+    #[serde(default)]
+    pub symbols: Vec<String>,
+
+    /// Modules the chunk imports
+    #[serde(default)]
+    pub imports: Vec<String>,
 }
 
 impl CodeChunk {
@@ -123,6 +145,20 @@ impl CodeChunk {
             chunk_index,
             commit_sha,
             created_at: chrono::Utc::now(),
+            summary: None,
+            root: None,
+            is_public_api: false,
+            symbols: Vec::new(),
+            imports: Vec::new(),
+        }
+    }
+
+    /// Text embedded for the chunk: the code, preceded by its summary if it
+    /// has one so conceptual queries can match it
+    pub fn embedding_text(&self) -> String {
+        match &self.summary {
+            Some(summary) => format!("{}\n\n{}", summary, self.content),
+            None => self.content.clone(),
         }
     }
 
@@ -136,6 +172,22 @@ impl CodeChunk {
     }
 }
 
+/// An indexed file and how much of it the index covers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexedFile {
+    /// Relative file path
+    pub file_path: String,
+
+    /// Number of chunks stored for the file
+    pub chunk_count: u32,
+
+    /// Git commit SHA the file was last indexed at
+    pub commit_sha: String,
+
+    /// When the file was last indexed
+    pub indexed_at: chrono::DateTime<chrono::Utc>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Earlier indexes kept per branch unless configured otherwise
+pub const DEFAULT_HISTORY_GENERATIONS: usize = 5;
+
+/// The chunks of a branch as indexed at one commit, kept after the branch
+/// was re-indexed so it can still be searched
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChunkGeneration {
+    pub branch: String,
+    pub commit_sha: String,
+    /// Model of the kept vectors; `None` when the branch had no vectors
+    pub model: Option<String>,
+    pub chunk_count: u32,
+    /// When the branch was re-indexed past this commit
+    pub archived_at: DateTime<Utc>,
+}
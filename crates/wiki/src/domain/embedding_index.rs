@@ -0,0 +1,55 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Embedding model assumed for vectors stored before models were tracked
+pub const LEGACY_EMBEDDING_MODEL: &str = "openai/text-embedding-3-small";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmbeddingIndexState {
+    /// Serves searches for the branch
+    Active,
+    /// Being filled by a re-embed; becomes active once every chunk has a vector
+    Migrating,
+}
+
+impl EmbeddingIndexState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmbeddingIndexState::Active => "active",
+            EmbeddingIndexState::Migrating => "migrating",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "active" => Some(EmbeddingIndexState::Active),
+            "migrating" => Some(EmbeddingIndexState::Migrating),
+            _ => None,
+        }
+    }
+}
+
+/// The vectors of one branch created with one embedding model.
+///
+/// A branch has exactly one active index; during a re-embed a second,
+/// migrating index for the new model exists side by side.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmbeddingIndex {
+    pub branch: String,
+    pub model: String,
+    /// Incremented every time the branch switches to a new model
+    pub version: u32,
+    pub state: EmbeddingIndexState,
+    /// Vector dimension, known once the first vector is stored
+    pub dimension: Option<usize>,
+    /// Chunks of the branch that have a vector from this model
+    pub embedded_chunks: u32,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl EmbeddingIndex {
+    pub fn is_active(&self) -> bool {
+        self.state == EmbeddingIndexState::Active
+    }
+}
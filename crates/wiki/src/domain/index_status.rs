@@ -109,6 +109,14 @@ pub enum IndexProgress {
         current_page: String,
     },
 
+    /// Streaming the content of a wiki page
+    StreamingPage {
+        current: u32,
+        total: u32,
+        current_page: String,
+        characters: u32,
+    },
+
     /// Completed successfully
     Completed {
         branch: String,
@@ -141,7 +149,8 @@ impl IndexProgress {
                     30 + (((*current as f64 / *total as f64) * 40.0) as u8).min(40)
                 }
             }
-            IndexProgress::GeneratingWiki { current, total, .. } => {
+            IndexProgress::GeneratingWiki { current, total, .. }
+            | IndexProgress::StreamingPage { current, total, .. } => {
                 if *total == 0 {
                     70
                 } else {
@@ -205,6 +214,14 @@ mod tests {
         };
         assert_eq!(progress.percent(), 15);
 
+        let progress = IndexProgress::StreamingPage {
+            current: 5,
+            total: 10,
+            current_page: "Architecture".to_string(),
+            characters: 1200,
+        };
+        assert_eq!(progress.percent(), 85);
+
         let progress = IndexProgress::Completed {
             branch: "main".to_string(),
             file_count: 100,
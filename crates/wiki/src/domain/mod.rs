@@ -1,7 +1,13 @@
 //! Domain models for the Wiki crate
 
+pub mod ask_feedback;
+pub mod branch_lock;
+pub mod change_summary;
 pub mod chunk;
+pub mod chunk_generation;
+pub mod embedding_index;
 pub mod index_status;
+pub mod recall_item;
 pub mod search_result;
 pub mod wiki_page;
 pub mod wiki_section;
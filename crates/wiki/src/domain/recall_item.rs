@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Kind of past work a recall item describes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecallScope {
+    /// A task's title and description
+    Task,
+    /// A review finding's title and description
+    Finding,
+    /// The summary a session left behind (plan, phase summary or review)
+    Session,
+}
+
+impl RecallScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecallScope::Task => "task",
+            RecallScope::Finding => "finding",
+            RecallScope::Session => "session",
+        }
+    }
+
+    /// Parse a scope, accepting the plural forms used in query strings
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "task" | "tasks" => Some(RecallScope::Task),
+            "finding" | "findings" => Some(RecallScope::Finding),
+            "session" | "sessions" => Some(RecallScope::Session),
+            _ => None,
+        }
+    }
+}
+
+/// A piece of past work embedded for cross-task recall
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecallItem {
+    /// Stable identifier, e.g. `finding:<task_id>:finding-1`
+    pub id: String,
+    pub scope: RecallScope,
+    /// Task the work belongs to
+    pub task_id: Uuid,
+    pub title: String,
+    pub content: String,
+}
+
+impl RecallItem {
+    pub fn new(
+        id: impl Into<String>,
+        scope: RecallScope,
+        task_id: Uuid,
+        title: impl Into<String>,
+        content: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            scope,
+            task_id,
+            title: title.into(),
+            content: content.into(),
+        }
+    }
+
+    /// Text embedded for the item
+    pub fn embedding_text(&self) -> String {
+        format!("{}\n\n{}", self.title, self.content)
+    }
+}
+
+/// A recall item matching a query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecallMatch {
+    pub item: RecallItem,
+    /// Cosine similarity to the query
+    pub score: f32,
+}
@@ -21,6 +21,10 @@ pub struct SearchResult {
     /// Matched content
     pub content: String,
 
+    /// One-line summary of the chunk, if one was generated at index time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+
     /// Chunk type
     pub chunk_type: ChunkType,
 
@@ -30,11 +34,70 @@ pub struct SearchResult {
     /// Similarity score (0.0 - 1.0)
     pub score: f32,
 
+    /// Whether the chunk defines public API
+    #[serde(default)]
+    pub is_public_api: bool,
+
+    /// Names of the functions and types the chunk defines
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub symbols: Vec<String>,
+
     /// Context before the match (previous chunk if available)
     pub context_before: Option<String>,
 
     /// Context after the match (next chunk if available)
     pub context_after: Option<String>,
+
+    /// Why this result was added to the context when it was not a direct match
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expansion: Option<ContextExpansion>,
+}
+
+/// How an expanded result relates to the result it was expanded from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExpansionRelation {
+    /// The result defines a function called by the origin
+    Callee,
+    /// The result calls a function defined in the origin
+    Caller,
+}
+
+impl ExpansionRelation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExpansionRelation::Callee => "callee",
+            ExpansionRelation::Caller => "caller",
+        }
+    }
+}
+
+/// Reason a search result was pulled in by context expansion
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContextExpansion {
+    /// Relation to the origin result
+    pub relation: ExpansionRelation,
+
+    /// Symbol linking the two results
+    pub symbol: String,
+
+    /// Location of the result this one was expanded from
+    pub origin: String,
+
+    /// Number of hops from the original search hit
+    pub depth: u32,
+}
+
+impl ContextExpansion {
+    /// Human-readable description, e.g. "callee `parse` of src/lib.rs:10-20"
+    pub fn describe(&self) -> String {
+        format!(
+            "{} `{}` of {}",
+            self.relation.as_str(),
+            self.symbol,
+            self.origin
+        )
+    }
 }
 
 impl SearchResult {
@@ -56,11 +119,15 @@ impl SearchResult {
             start_line,
             end_line,
             content,
+            summary: None,
             chunk_type,
             language,
             score,
+            is_public_api: false,
+            symbols: Vec::new(),
             context_before: None,
             context_after: None,
+            expansion: None,
         }
     }
 
@@ -84,6 +151,248 @@ impl SearchResult {
         self.context_after = after;
         self
     }
+
+    /// Set the chunk's summary
+    pub fn with_summary(mut self, summary: Option<String>) -> Self {
+        self.summary = summary;
+        self
+    }
+
+    /// Set whether the chunk defines public API
+    pub fn with_public_api(mut self, is_public_api: bool) -> Self {
+        self.is_public_api = is_public_api;
+        self
+    }
+
+    /// Set the names of the functions and types the chunk defines
+    pub fn with_symbols(mut self, symbols: Vec<String>) -> Self {
+        self.symbols = symbols;
+        self
+    }
+
+    /// Whether the chunk defines a symbol named by one of `terms`, which
+    /// must be lowercase
+    pub fn defines_any(&self, terms: &[String]) -> bool {
+        self.symbols
+            .iter()
+            .any(|symbol| terms.contains(&symbol.to_lowercase()))
+    }
+
+    /// Mark the result as added by context expansion
+    pub fn with_expansion(mut self, expansion: ContextExpansion) -> Self {
+        self.expansion = Some(expansion);
+        self
+    }
+}
+
+/// How chunks are matched against a search query
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchMode {
+    /// Cosine similarity of embeddings
+    #[default]
+    Semantic,
+    /// Full-text match of the query terms, so exact identifiers are found
+    Keyword,
+    /// Semantic and keyword rankings merged by reciprocal rank fusion
+    Hybrid,
+}
+
+impl SearchMode {
+    pub const ALL: [SearchMode; 3] = [
+        SearchMode::Semantic,
+        SearchMode::Keyword,
+        SearchMode::Hybrid,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchMode::Semantic => "semantic",
+            SearchMode::Keyword => "keyword",
+            SearchMode::Hybrid => "hybrid",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim() {
+            "semantic" => Some(SearchMode::Semantic),
+            "keyword" => Some(SearchMode::Keyword),
+            "hybrid" => Some(SearchMode::Hybrid),
+            _ => None,
+        }
+    }
+
+    /// Whether the query has to be embedded
+    pub fn needs_embedding(&self) -> bool {
+        !matches!(self, SearchMode::Keyword)
+    }
+}
+
+/// Results fetched per requested result when a [`SearchFilter`] may drop some
+const FILTER_CANDIDATES_PER_RESULT: usize = 3;
+
+/// Post-processing of ranked search results
+///
+/// Neighbouring chunks of one file often rank together and crowd out other
+/// files. The default keeps results as ranked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct SearchFilter {
+    /// Most results kept from one file
+    #[serde(default)]
+    pub max_per_file: Option<usize>,
+    /// Drop a result whose lines overlap a better result of the same file by
+    /// at least this share (0.0-1.0) of the shorter of the two ranges
+    #[serde(default)]
+    pub dedup_overlap: Option<f32>,
+    /// Scale scores so the best result scores 1.0
+    #[serde(default)]
+    pub normalize_scores: bool,
+    /// Keep only chunks tagged as public API
+    #[serde(default)]
+    pub public_api_only: bool,
+}
+
+impl SearchFilter {
+    /// Whether the filter can drop results
+    pub fn drops_results(&self) -> bool {
+        self.max_per_file.is_some() || self.dedup_overlap.is_some() || self.public_api_only
+    }
+
+    /// Results to fetch so that `limit` remain after filtering
+    pub fn candidates(&self, limit: usize) -> usize {
+        if self.drops_results() {
+            limit * FILTER_CANDIDATES_PER_RESULT
+        } else {
+            limit
+        }
+    }
+
+    /// Filter `results`, ranked best first, down to at most `limit`
+    pub fn apply(&self, results: Vec<SearchResult>, limit: usize) -> Vec<SearchResult> {
+        let mut kept: Vec<SearchResult> = Vec::with_capacity(limit.min(results.len()));
+        for result in results {
+            if kept.len() >= limit {
+                break;
+            }
+            if self.public_api_only && !result.is_public_api {
+                continue;
+            }
+            if let Some(max) = self.max_per_file {
+                let from_file = kept
+                    .iter()
+                    .filter(|r| r.file_path == result.file_path)
+                    .count();
+                if from_file >= max {
+                    continue;
+                }
+            }
+            if let Some(threshold) = self.dedup_overlap {
+                if kept.iter().any(|r| line_overlap(r, &result) >= threshold) {
+                    continue;
+                }
+            }
+            kept.push(result);
+        }
+
+        if self.normalize_scores {
+            let best = kept.iter().map(|r| r.score).fold(0.0_f32, f32::max);
+            if best > 0.0 {
+                for result in &mut kept {
+                    result.score /= best;
+                }
+            }
+        }
+        kept
+    }
+}
+
+/// Chunks a search considers, applied in SQL before ranking
+///
+/// Unlike [`SearchFilter`], which prunes ranked results, chunks outside the
+/// scope never take up result slots.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchScope {
+    /// Glob over file paths, e.g. `crates/server/**` or `*.rs`. `*` also
+    /// matches `/`, and matching is case-sensitive.
+    #[serde(default)]
+    pub path_glob: Option<String>,
+    /// Language of the chunks, e.g. `rust`, or a file extension such as `rs`
+    #[serde(default)]
+    pub language: Option<String>,
+}
+
+impl SearchScope {
+    /// The glob as an SQLite `GLOB` pattern; a leading `./` is dropped and
+    /// a leading `**/` also matches files at the top level
+    pub fn sql_glob(&self) -> Option<String> {
+        let glob = self.path_glob.as_deref()?.trim();
+        let glob = glob.trim_start_matches("./");
+        if glob.is_empty() {
+            return None;
+        }
+        Some(match glob.strip_prefix("**/") {
+            Some(rest) => format!("*{}", rest),
+            None => glob.to_string(),
+        })
+    }
+
+    /// The language as chunks store it, e.g. `rust` for `Rust` or `rs`
+    pub fn sql_language(&self) -> Option<String> {
+        let language = self.language.as_deref()?.trim().to_lowercase();
+        if language.is_empty() {
+            return None;
+        }
+        Some(
+            crate::chunker::TextSplitter::detect_language(&format!("file.{}", language))
+                .unwrap_or(language),
+        )
+    }
+}
+
+/// Share of the gap to a perfect score that a result defining a symbol named
+/// in the query gains
+const SYMBOL_MATCH_BOOST: f32 = 0.5;
+
+/// Identifiers in `query` that could name a symbol, lowercased
+fn symbol_terms(query: &str) -> Vec<String> {
+    query
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .filter(|term| term.len() >= 3)
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Rank results that define a symbol named in `query` ahead of those that
+/// only mention it, so "where is X defined" finds the definition of X. A
+/// matching result's score moves [`SYMBOL_MATCH_BOOST`] of the way to 1.0;
+/// results are then re-sorted best first.
+pub fn boost_symbol_matches(results: &mut [SearchResult], query: &str) {
+    let terms = symbol_terms(query);
+    if terms.is_empty() {
+        return;
+    }
+
+    for result in results.iter_mut() {
+        if result.defines_any(&terms) {
+            result.score += (1.0 - result.score).max(0.0) * SYMBOL_MATCH_BOOST;
+        }
+    }
+    results.sort_by(|a, b| b.score.total_cmp(&a.score));
+}
+
+/// Share of the shorter line range that two results of one file have in
+/// common; 0.0 for results of different files
+fn line_overlap(a: &SearchResult, b: &SearchResult) -> f32 {
+    if a.file_path != b.file_path {
+        return 0.0;
+    }
+    let start = a.start_line.max(b.start_line);
+    let end = a.end_line.min(b.end_line);
+    if end < start {
+        return 0.0;
+    }
+    let shorter = (a.end_line - a.start_line).min(b.end_line - b.start_line) + 1;
+    (end - start + 1) as f32 / shorter as f32
 }
 
 /// Aggregated search results
@@ -197,4 +506,139 @@ mod tests {
         assert!(response.top_result().is_some());
         assert_eq!(response.top_result().unwrap().score, 0.9);
     }
+
+    #[test]
+    fn test_search_mode_parse() {
+        for mode in SearchMode::ALL {
+            assert_eq!(SearchMode::parse(mode.as_str()), Some(mode));
+        }
+        assert_eq!(SearchMode::parse("fuzzy"), None);
+        assert!(!SearchMode::Keyword.needs_embedding());
+    }
+
+    #[test]
+    fn test_search_filter() {
+        let result = |path: &str, start: u32, end: u32, score: f32| {
+            SearchResult::new(
+                Uuid::new_v4(),
+                path.to_string(),
+                start,
+                end,
+                String::new(),
+                ChunkType::Code,
+                None,
+                score,
+            )
+        };
+        let results = vec![
+            result("a.rs", 1, 20, 0.8),
+            result("a.rs", 15, 30, 0.7),
+            result("a.rs", 40, 50, 0.6),
+            result("a.rs", 60, 70, 0.5),
+            result("b.rs", 1, 10, 0.4),
+        ];
+        let locations = |results: &[SearchResult]| -> Vec<String> {
+            results.iter().map(SearchResult::location).collect()
+        };
+
+        let unfiltered = SearchFilter::default();
+        assert_eq!(unfiltered.apply(results.clone(), 10).len(), 5);
+        assert_eq!(unfiltered.candidates(10), 10);
+
+        let filter = SearchFilter {
+            max_per_file: Some(2),
+            dedup_overlap: Some(0.3),
+            normalize_scores: true,
+            ..Default::default()
+        };
+        assert_eq!(filter.candidates(10), 30);
+        let filtered = filter.apply(results.clone(), 10);
+        // a.rs:15-30 overlaps a.rs:1-20 by 6 of its 16 lines, over 0.3
+        assert_eq!(
+            locations(&filtered),
+            vec!["a.rs:1-20", "a.rs:40-50", "b.rs:1-10"]
+        );
+        assert_eq!(filtered[0].score, 1.0);
+        assert_eq!(filtered[2].score, 0.5);
+
+        let capped = SearchFilter {
+            max_per_file: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(
+            locations(&capped.apply(results.clone(), 1)),
+            vec!["a.rs:1-20"]
+        );
+
+        let mut tagged = results;
+        tagged[2].is_public_api = true;
+        let public = SearchFilter {
+            public_api_only: true,
+            ..Default::default()
+        };
+        assert_eq!(public.candidates(10), 30);
+        assert_eq!(locations(&public.apply(tagged, 10)), vec!["a.rs:40-50"]);
+    }
+
+    #[test]
+    fn test_search_scope() {
+        let scope = |glob: Option<&str>, language: Option<&str>| SearchScope {
+            path_glob: glob.map(String::from),
+            language: language.map(String::from),
+        };
+        assert_eq!(scope(None, None).sql_glob(), None);
+        assert_eq!(scope(Some("  "), None).sql_glob(), None);
+        assert_eq!(
+            scope(Some("./crates/server/**"), None)
+                .sql_glob()
+                .as_deref(),
+            Some("crates/server/**")
+        );
+        assert_eq!(
+            scope(Some("**/*.rs"), None).sql_glob().as_deref(),
+            Some("**.rs")
+        );
+
+        assert_eq!(
+            scope(None, Some("Rust")).sql_language().as_deref(),
+            Some("rust")
+        );
+        assert_eq!(
+            scope(None, Some("rs")).sql_language().as_deref(),
+            Some("rust")
+        );
+        assert_eq!(scope(None, Some("")).sql_language(), None);
+    }
+
+    #[test]
+    fn test_boost_symbol_matches_ranks_definitions_first() {
+        let result = |path: &str, score: f32, symbols: &[&str]| {
+            SearchResult::new(
+                Uuid::new_v4(),
+                path.to_string(),
+                1,
+                10,
+                String::new(),
+                ChunkType::Code,
+                None,
+                score,
+            )
+            .with_symbols(symbols.iter().map(|s| s.to_string()).collect())
+        };
+        let mut results = vec![
+            result("src/caller.rs", 0.8, &["run"]),
+            result("src/other.rs", 0.75, &[]),
+            result("src/config.rs", 0.7, &["Config", "load_config"]),
+        ];
+
+        boost_symbol_matches(&mut results, "where is load_config defined?");
+
+        assert_eq!(results[0].file_path, "src/config.rs");
+        assert!((results[0].score - 0.85).abs() < 1e-6);
+        assert_eq!(results[1].file_path, "src/caller.rs");
+
+        let before: Vec<f32> = results.iter().map(|r| r.score).collect();
+        boost_symbol_matches(&mut results, "a b");
+        assert_eq!(before, results.iter().map(|r| r.score).collect::<Vec<_>>());
+    }
 }
@@ -371,6 +371,63 @@ impl WikiTree {
     }
 }
 
+/// Why a planned page was left out of a generation run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkipReason {
+    /// Importance below the configured minimum
+    BelowMinImportance,
+    /// Its section already had the maximum number of pages
+    SectionLimit,
+    /// The wiki already had the maximum number of pages
+    PageLimit,
+    /// Generation failed, including the retry at the end of the run
+    GenerationFailed,
+}
+
+impl SkipReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SkipReason::BelowMinImportance => "below_min_importance",
+            SkipReason::SectionLimit => "section_limit",
+            SkipReason::PageLimit => "page_limit",
+            SkipReason::GenerationFailed => "generation_failed",
+        }
+    }
+}
+
+/// A planned page that was not generated, kept so it can be generated on demand
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkippedPage {
+    /// Page slug
+    pub id: String,
+
+    /// Page title
+    pub title: String,
+
+    /// Section the page was planned in
+    pub section_id: String,
+
+    /// Planned importance
+    pub importance: Importance,
+
+    /// Source files the page would document
+    pub file_paths: Vec<String>,
+
+    /// Slugs of related wiki pages
+    pub related_pages: Vec<String>,
+
+    /// What the page would cover
+    pub description: String,
+
+    /// Why the page was skipped
+    pub reason: SkipReason,
+
+    /// Last generation error, for pages that failed to generate
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
 /// Complete wiki structure for a branch
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WikiStructure {
@@ -393,6 +450,10 @@ pub struct WikiStructure {
     /// IDs of root-level sections
     #[serde(default)]
     pub root_section_ids: Vec<String>,
+
+    /// Planned pages left out by the generation limits
+    #[serde(default)]
+    pub skipped_pages: Vec<SkippedPage>,
 }
 
 impl WikiStructure {
@@ -406,6 +467,7 @@ impl WikiStructure {
             updated_at: Utc::now(),
             sections: Vec::new(),
             root_section_ids: Vec::new(),
+            skipped_pages: Vec::new(),
         }
     }
 
@@ -425,6 +487,7 @@ impl WikiStructure {
             updated_at: Utc::now(),
             sections,
             root_section_ids,
+            skipped_pages: Vec::new(),
         }
     }
 
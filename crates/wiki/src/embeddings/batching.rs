@@ -0,0 +1,148 @@
+//! Embedding batches sized to the provider's token limit
+//!
+//! A fixed number of chunks per request can exceed the tokens an embedding
+//! API accepts when the chunks are content-heavy, failing the whole batch.
+//! Batches are planned by their token totals instead, and a batch the
+//! provider still rejects as too large is split in half and retried.
+
+use std::ops::Range;
+
+use crate::chunker::count_tokens;
+use crate::error::WikiError;
+
+/// Tokens OpenAI embedding models accept per request
+const OPENAI_BATCH_TOKEN_LIMIT: usize = 300_000;
+
+/// Tokens per request for models without a known limit
+const DEFAULT_BATCH_TOKEN_LIMIT: usize = 100_000;
+
+/// Share of the limit a planned batch may use; the local tokenizer only
+/// approximates the provider's
+const TOKEN_LIMIT_MARGIN: f64 = 0.8;
+
+/// Tokens a planned batch of embeddings for `model` may hold at a hosted
+/// provider
+pub fn batch_token_limit(model: &str) -> usize {
+    let limit = if model.starts_with("openai/") || model.starts_with("text-embedding-") {
+        OPENAI_BATCH_TOKEN_LIMIT
+    } else {
+        DEFAULT_BATCH_TOKEN_LIMIT
+    };
+    (limit as f64 * TOKEN_LIMIT_MARGIN) as usize
+}
+
+/// Consecutive ranges of `texts` holding at most `max_items` texts and, when
+/// more than one, at most `max_tokens` tokens. A single text over the limit
+/// gets a batch of its own.
+pub fn plan_batches(texts: &[String], max_items: usize, max_tokens: usize) -> Vec<Range<usize>> {
+    let token_counts: Vec<usize> = texts.iter().map(|t| count_tokens(t)).collect();
+    plan_by_token_counts(&token_counts, max_items, max_tokens)
+}
+
+pub(crate) fn plan_by_token_counts(
+    token_counts: &[usize],
+    max_items: usize,
+    max_tokens: usize,
+) -> Vec<Range<usize>> {
+    let max_items = max_items.max(1);
+    let mut batches = Vec::new();
+    let mut start = 0;
+    let mut tokens = 0;
+
+    for (index, &count) in token_counts.iter().enumerate() {
+        let len = index - start;
+        if len > 0 && (len >= max_items || tokens + count > max_tokens) {
+            batches.push(start..index);
+            start = index;
+            tokens = 0;
+        }
+        tokens += count;
+    }
+    if start < token_counts.len() {
+        batches.push(start..token_counts.len());
+    }
+    batches
+}
+
+/// Whether the provider rejected a request for carrying too much input
+pub fn is_payload_too_large(error: &WikiError) -> bool {
+    match error {
+        WikiError::OpenRouterApi {
+            status_code: Some(413),
+            ..
+        } => true,
+        WikiError::OpenRouterApi {
+            message,
+            status_code: Some(400),
+        } => {
+            let message = message.to_lowercase();
+            [
+                "too many tokens",
+                "too large",
+                "maximum context length",
+                "max_tokens_per_request",
+                "token limit",
+            ]
+            .iter()
+            .any(|needle| message.contains(needle))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn api_error(status: u16, message: &str) -> WikiError {
+        WikiError::OpenRouterApi {
+            message: message.to_string(),
+            status_code: Some(status),
+        }
+    }
+
+    #[test]
+    fn test_plan_by_token_counts() {
+        assert_eq!(
+            plan_by_token_counts(&[10, 10, 10, 10, 10], 2, 1000),
+            vec![0..2, 2..4, 4..5]
+        );
+        assert_eq!(
+            plan_by_token_counts(&[40, 40, 40, 10], 100, 100),
+            vec![0..2, 2..4]
+        );
+        // An oversized text still gets sent, on its own
+        assert_eq!(
+            plan_by_token_counts(&[10, 500, 10], 100, 100),
+            vec![0..1, 1..2, 2..3]
+        );
+        assert!(plan_by_token_counts(&[], 100, 100).is_empty());
+    }
+
+    #[test]
+    fn test_plan_batches_counts_tokens() {
+        let texts = vec!["fn main() {}".to_string(); 4];
+        let tokens = count_tokens(&texts[0]);
+        assert_eq!(plan_batches(&texts, 100, tokens * 2), vec![0..2, 2..4]);
+    }
+
+    #[test]
+    fn test_batch_token_limit() {
+        assert_eq!(batch_token_limit("openai/text-embedding-3-small"), 240_000);
+        assert_eq!(batch_token_limit("mistral/mistral-embed"), 80_000);
+    }
+
+    #[test]
+    fn test_is_payload_too_large() {
+        assert!(is_payload_too_large(&api_error(413, "")));
+        assert!(is_payload_too_large(&api_error(
+            400,
+            "Requested 320000 tokens, max 300000 tokens per request (max_tokens_per_request)"
+        )));
+        assert!(!is_payload_too_large(&api_error(400, "Invalid model")));
+        assert!(!is_payload_too_large(&api_error(500, "too large")));
+        assert!(!is_payload_too_large(&WikiError::RateLimited {
+            retry_after: None
+        }));
+    }
+}
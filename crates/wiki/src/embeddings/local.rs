@@ -0,0 +1,147 @@
+//! Embeddings from a model run in-process
+//!
+//! Loads a BERT-style sentence embedding model, such as all-MiniLM-L6-v2,
+//! from a directory with its `config.json`, `tokenizer.json` and
+//! `model.safetensors`, and runs it on the CPU with candle. Nothing is
+//! downloaded; the directory has to be provisioned beforehand. Vectors are
+//! mean-pooled over the tokens and L2-normalized.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use candle_core::{DType, Device, Tensor};
+use candle_nn::VarBuilder;
+use candle_transformers::models::bert::{BertModel, Config, DTYPE};
+use tokenizers::{PaddingParams, PaddingStrategy, Tokenizer, TruncationParams};
+use tracing::{debug, info};
+
+use super::EmbeddingProvider;
+use crate::error::{WikiError, WikiResult};
+
+/// Tokens per batch; inputs are padded to the longest text, so this bounds
+/// the memory of one forward pass
+const LOCAL_BATCH_TOKEN_LIMIT: usize = 8_192;
+
+#[derive(Clone)]
+pub struct LocalEmbeddings {
+    model: Arc<LocalModel>,
+}
+
+struct LocalModel {
+    bert: BertModel,
+    tokenizer: Tokenizer,
+    device: Device,
+}
+
+fn local_error(e: impl std::fmt::Display) -> WikiError {
+    WikiError::EmbeddingProvider(format!("Local embedding model: {}", e))
+}
+
+impl LocalEmbeddings {
+    /// Load the model in `model_dir`
+    pub fn load(model_dir: &Path) -> WikiResult<Self> {
+        let config: Config =
+            serde_json::from_str(&std::fs::read_to_string(model_dir.join("config.json"))?)?;
+
+        let mut tokenizer =
+            Tokenizer::from_file(model_dir.join("tokenizer.json")).map_err(local_error)?;
+        tokenizer.with_padding(Some(PaddingParams {
+            strategy: PaddingStrategy::BatchLongest,
+            ..Default::default()
+        }));
+        tokenizer
+            .with_truncation(Some(TruncationParams {
+                max_length: config.max_position_embeddings,
+                ..Default::default()
+            }))
+            .map_err(local_error)?;
+
+        let device = Device::Cpu;
+        let weights = model_dir.join("model.safetensors");
+        // SAFETY: the weights are memory-mapped read-only and must not be
+        // modified while the model is loaded
+        let vb = unsafe { VarBuilder::from_mmaped_safetensors(&[weights], DTYPE, &device) }
+            .map_err(local_error)?;
+        let bert = BertModel::load(vb, &config).map_err(local_error)?;
+
+        info!(
+            path = %model_dir.display(),
+            dimension = config.hidden_size,
+            "Local embedding model loaded"
+        );
+        Ok(Self {
+            model: Arc::new(LocalModel {
+                bert,
+                tokenizer,
+                device,
+            }),
+        })
+    }
+}
+
+impl LocalModel {
+    fn embed(&self, texts: &[String]) -> WikiResult<Vec<Vec<f32>>> {
+        let encodings = self
+            .tokenizer
+            .encode_batch(texts.to_vec(), true)
+            .map_err(local_error)?;
+
+        let ids = encodings
+            .iter()
+            .map(|e| Tensor::new(e.get_ids(), &self.device))
+            .collect::<candle_core::Result<Vec<_>>>()
+            .map_err(local_error)?;
+        let masks = encodings
+            .iter()
+            .map(|e| Tensor::new(e.get_attention_mask(), &self.device))
+            .collect::<candle_core::Result<Vec<_>>>()
+            .map_err(local_error)?;
+
+        let pooled = (|| {
+            let input_ids = Tensor::stack(&ids, 0)?;
+            let attention_mask = Tensor::stack(&masks, 0)?;
+            let token_type_ids = input_ids.zeros_like()?;
+            let hidden = self
+                .bert
+                .forward(&input_ids, &token_type_ids, Some(&attention_mask))?;
+
+            // Mean over the tokens that are not padding
+            let mask = attention_mask.to_dtype(DType::F32)?.unsqueeze(2)?;
+            let summed = hidden.broadcast_mul(&mask)?.sum(1)?;
+            let mean = summed.broadcast_div(&mask.sum(1)?)?;
+            let norm = mean.sqr()?.sum_keepdim(1)?.sqrt()?;
+            mean.broadcast_div(&norm)?.to_vec2::<f32>()
+        })()
+        .map_err(local_error)?;
+
+        debug!("Embedded {} texts locally", texts.len());
+        Ok(pooled)
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for LocalEmbeddings {
+    fn name(&self) -> &'static str {
+        "local"
+    }
+
+    async fn create_embeddings_batch(
+        &self,
+        texts: &[String],
+        _model: &str,
+    ) -> WikiResult<Vec<Vec<f32>>> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+        let model = Arc::clone(&self.model);
+        let texts = texts.to_vec();
+        tokio::task::spawn_blocking(move || model.embed(&texts))
+            .await
+            .map_err(local_error)?
+    }
+
+    fn batch_token_limit(&self, _model: &str) -> usize {
+        LOCAL_BATCH_TOKEN_LIMIT
+    }
+}
@@ -0,0 +1,266 @@
+//! Embedding providers
+//!
+//! Chunks, queries and recall items are embedded through an
+//! [`EmbeddingProvider`]. OpenRouter is the default; OpenAI can be called
+//! directly, and with the `local-embeddings` feature a BERT-style model is
+//! run in-process, so nothing leaves the machine while indexing.
+
+pub mod batching;
+#[cfg(feature = "local-embeddings")]
+pub mod local;
+pub mod openai;
+
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::error::{WikiError, WikiResult};
+use crate::openrouter::OpenRouterClient;
+use crate::usage::UsageTracker;
+use batching::{batch_token_limit, is_payload_too_large, plan_batches};
+
+#[cfg(feature = "local-embeddings")]
+pub use local::LocalEmbeddings;
+pub use openai::OpenAiEmbeddings;
+
+/// Source of embedding vectors
+#[async_trait]
+pub trait EmbeddingProvider: Send + Sync {
+    /// Provider name for logs
+    fn name(&self) -> &'static str;
+
+    /// Embeddings of `texts`, in order, in one request
+    async fn create_embeddings_batch(
+        &self,
+        texts: &[String],
+        model: &str,
+    ) -> WikiResult<Vec<Vec<f32>>>;
+
+    async fn create_embedding(&self, text: &str, model: &str) -> WikiResult<Vec<f32>> {
+        self.create_embeddings_batch(&[text.to_string()], model)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| WikiError::EmbeddingProvider("No embedding returned".to_string()))
+    }
+
+    /// Tokens one batch for `model` may hold
+    fn batch_token_limit(&self, model: &str) -> usize {
+        batch_token_limit(model)
+    }
+
+    /// Embeddings of `texts`, in order, sent in batches under the token
+    /// limit of `model`. A batch rejected as too large is halved and
+    /// retried; only a single text the provider refuses fails the call.
+    async fn create_embeddings_split(
+        &self,
+        texts: &[String],
+        model: &str,
+    ) -> WikiResult<Vec<Vec<f32>>> {
+        let mut pending: VecDeque<Range<usize>> =
+            plan_batches(texts, texts.len(), self.batch_token_limit(model)).into();
+        let mut embeddings = Vec::with_capacity(texts.len());
+
+        while let Some(range) = pending.pop_front() {
+            match self
+                .create_embeddings_batch(&texts[range.clone()], model)
+                .await
+            {
+                Ok(batch) => embeddings.extend(batch),
+                Err(e) if range.len() > 1 && is_payload_too_large(&e) => {
+                    let mid = range.start + range.len() / 2;
+                    warn!(
+                        "Embedding batch of {} texts too large, splitting: {}",
+                        range.len(),
+                        e
+                    );
+                    pending.push_front(mid..range.end);
+                    pending.push_front(range.start..mid);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(embeddings)
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for OpenRouterClient {
+    fn name(&self) -> &'static str {
+        "openrouter"
+    }
+
+    async fn create_embeddings_batch(
+        &self,
+        texts: &[String],
+        model: &str,
+    ) -> WikiResult<Vec<Vec<f32>>> {
+        OpenRouterClient::create_embeddings_batch(self, texts, model).await
+    }
+
+    async fn create_embedding(&self, text: &str, model: &str) -> WikiResult<Vec<f32>> {
+        OpenRouterClient::create_embedding(self, text, model).await
+    }
+}
+
+/// Which provider embeds code and queries
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmbeddingProviderKind {
+    #[default]
+    OpenRouter,
+    OpenAi,
+    /// A model run in-process; needs the `local-embeddings` feature
+    Local,
+}
+
+/// Settings for creating an [`EmbeddingProvider`]
+#[derive(Debug, Clone, Default)]
+pub struct EmbeddingProviderConfig {
+    pub kind: EmbeddingProviderKind,
+    pub openrouter_api_key: Option<String>,
+    /// OpenRouter API base URL; the public API when unset
+    pub openrouter_base_url: Option<String>,
+    pub openai_api_key: Option<String>,
+    /// Directory holding `config.json`, `tokenizer.json` and
+    /// `model.safetensors` of the local model
+    pub local_model_path: Option<PathBuf>,
+}
+
+impl EmbeddingProviderConfig {
+    /// Create the configured provider
+    pub fn build(&self) -> WikiResult<Arc<dyn EmbeddingProvider>> {
+        self.build_tracked(None)
+    }
+
+    /// Create the configured provider, reporting the usage of hosted
+    /// providers to `usage`
+    pub fn build_tracked(
+        &self,
+        usage: Option<UsageTracker>,
+    ) -> WikiResult<Arc<dyn EmbeddingProvider>> {
+        match self.kind {
+            EmbeddingProviderKind::OpenRouter => {
+                let api_key = self.openrouter_api_key.clone().ok_or_else(|| {
+                    WikiError::InvalidConfig("OpenRouter API key not configured".to_string())
+                })?;
+                let base_url = self
+                    .openrouter_base_url
+                    .clone()
+                    .unwrap_or_else(|| "https://openrouter.ai/api/v1".to_string());
+                let client = OpenRouterClient::new(api_key, base_url);
+                Ok(Arc::new(match usage {
+                    Some(tracker) => client.with_usage_tracker(tracker),
+                    None => client,
+                }))
+            }
+            EmbeddingProviderKind::OpenAi => {
+                let api_key = self.openai_api_key.clone().ok_or_else(|| {
+                    WikiError::InvalidConfig("OpenAI API key not configured".to_string())
+                })?;
+                let embeddings = OpenAiEmbeddings::new(api_key);
+                Ok(Arc::new(match usage {
+                    Some(tracker) => embeddings.with_usage_tracker(tracker),
+                    None => embeddings,
+                }))
+            }
+            EmbeddingProviderKind::Local => self.build_local(),
+        }
+    }
+
+    /// Whether indexing needs no hosted API for embeddings
+    pub fn is_local(&self) -> bool {
+        self.kind == EmbeddingProviderKind::Local
+    }
+
+    #[cfg(feature = "local-embeddings")]
+    fn build_local(&self) -> WikiResult<Arc<dyn EmbeddingProvider>> {
+        let path = self.local_model_path.as_ref().ok_or_else(|| {
+            WikiError::InvalidConfig("Local embedding model path not configured".to_string())
+        })?;
+        Ok(Arc::new(LocalEmbeddings::load(path)?))
+    }
+
+    #[cfg(not(feature = "local-embeddings"))]
+    fn build_local(&self) -> WikiResult<Arc<dyn EmbeddingProvider>> {
+        Err(WikiError::InvalidConfig(
+            "Local embeddings need a build with the local-embeddings feature".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Rejects batches of more than two texts as too large
+    struct SmallBatches;
+
+    #[async_trait]
+    impl EmbeddingProvider for SmallBatches {
+        fn name(&self) -> &'static str {
+            "small"
+        }
+
+        async fn create_embeddings_batch(
+            &self,
+            texts: &[String],
+            _model: &str,
+        ) -> WikiResult<Vec<Vec<f32>>> {
+            if texts.len() > 2 {
+                return Err(WikiError::OpenRouterApi {
+                    message: String::new(),
+                    status_code: Some(413),
+                });
+            }
+            Ok(texts.iter().map(|t| vec![t.len() as f32]).collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_split_keeps_order() {
+        let texts: Vec<String> = (1..=5).map(|n| "x".repeat(n)).collect();
+        let embeddings = SmallBatches
+            .create_embeddings_split(&texts, "model")
+            .await
+            .unwrap();
+        assert_eq!(
+            embeddings,
+            vec![vec![1.0], vec![2.0], vec![3.0], vec![4.0], vec![5.0]]
+        );
+        assert_eq!(
+            SmallBatches.create_embedding("abc", "model").await.unwrap(),
+            vec![3.0]
+        );
+    }
+
+    #[test]
+    fn test_provider_kind_names() {
+        let kind: EmbeddingProviderKind = serde_json::from_str("\"openai\"").unwrap();
+        assert_eq!(kind, EmbeddingProviderKind::OpenAi);
+        assert_eq!(
+            serde_json::to_string(&EmbeddingProviderKind::OpenRouter).unwrap(),
+            "\"openrouter\""
+        );
+    }
+
+    #[test]
+    fn test_build_requires_keys() {
+        let config = EmbeddingProviderConfig {
+            kind: EmbeddingProviderKind::OpenAi,
+            ..Default::default()
+        };
+        assert!(matches!(config.build(), Err(WikiError::InvalidConfig(_))));
+
+        let config = EmbeddingProviderConfig {
+            openrouter_api_key: Some("key".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(config.build().unwrap().name(), "openrouter");
+    }
+}
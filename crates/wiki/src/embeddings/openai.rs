@@ -0,0 +1,103 @@
+//! Embeddings straight from the OpenAI API
+//!
+//! OpenAI serves the embeddings endpoint OpenRouter mirrors, so the request
+//! and retry handling of [`OpenRouterClient`] are reused against OpenAI's
+//! base URL. Model names may keep OpenRouter's `openai/` prefix; it is
+//! dropped before the request, so an index built through OpenRouter stays
+//! usable.
+
+use async_trait::async_trait;
+
+use super::EmbeddingProvider;
+use crate::error::WikiResult;
+use crate::openrouter::OpenRouterClient;
+use crate::usage::UsageTracker;
+
+const OPENAI_BASE_URL: &str = "https://api.openai.com/v1";
+
+#[derive(Clone)]
+pub struct OpenAiEmbeddings {
+    client: OpenRouterClient,
+}
+
+impl OpenAiEmbeddings {
+    pub fn new(api_key: String) -> Self {
+        Self::with_base_url(api_key, OPENAI_BASE_URL.to_string())
+    }
+
+    /// Against an OpenAI-compatible API elsewhere, e.g. a proxy
+    pub fn with_base_url(api_key: String, base_url: String) -> Self {
+        Self {
+            client: OpenRouterClient::new(api_key, base_url),
+        }
+    }
+
+    /// Report the tokens of every request to `tracker`
+    pub fn with_usage_tracker(mut self, tracker: UsageTracker) -> Self {
+        self.client = self.client.with_usage_tracker(tracker);
+        self
+    }
+}
+
+/// `model` as OpenAI names it
+fn openai_model(model: &str) -> &str {
+    model.strip_prefix("openai/").unwrap_or(model)
+}
+
+#[async_trait]
+impl EmbeddingProvider for OpenAiEmbeddings {
+    fn name(&self) -> &'static str {
+        "openai"
+    }
+
+    async fn create_embeddings_batch(
+        &self,
+        texts: &[String],
+        model: &str,
+    ) -> WikiResult<Vec<Vec<f32>>> {
+        self.client
+            .create_embeddings_batch(texts, openai_model(model))
+            .await
+    }
+
+    async fn create_embedding(&self, text: &str, model: &str) -> WikiResult<Vec<f32>> {
+        self.client
+            .create_embedding(text, openai_model(model))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_drops_openrouter_prefix() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/embeddings"))
+            .and(body_partial_json(
+                serde_json::json!({ "model": "text-embedding-3-small" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{ "embedding": [0.5, 0.25], "index": 0 }],
+                "model": "text-embedding-3-small",
+                "usage": { "prompt_tokens": 2, "total_tokens": 2 }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let provider = OpenAiEmbeddings::with_base_url("key".to_string(), server.uri());
+        let embedding = EmbeddingProvider::create_embedding(
+            &provider,
+            "fn main() {}",
+            "openai/text-embedding-3-small",
+        )
+        .await
+        .unwrap();
+        assert_eq!(embedding, vec![0.5, 0.25]);
+    }
+}
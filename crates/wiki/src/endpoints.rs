@@ -0,0 +1,516 @@
+//! HTTP endpoint inventory extraction
+//!
+//! Pattern-based detection of route registrations in indexed code, used to
+//! render a deterministic "API Endpoints" wiki page. Supported forms:
+//!
+//! - **axum**: `.route("/path", get(handler).post(other))`
+//! - **actix-web**: `#[get("/path")] async fn handler` and
+//!   `.route("/path", web::get().to(handler))`
+//! - **express**: `app.get("/path", handler)` / `router.post(...)`
+//! - **FastAPI**: `@app.get("/path")` / `@router.post(...)` followed by `def handler`
+
+use std::collections::{BTreeSet, HashSet};
+use std::sync::OnceLock;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::chunker::TextSplitter;
+use crate::domain::chunk::CodeChunk;
+use crate::domain::wiki_page::{Importance, PageType, SourceCitation, WikiPage};
+
+/// Slug of the generated endpoint inventory page
+pub const ENDPOINTS_PAGE_SLUG: &str = "api-endpoints";
+
+/// Web framework a route registration was recognised from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HttpFramework {
+    Axum,
+    Actix,
+    Express,
+    FastApi,
+}
+
+impl HttpFramework {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HttpFramework::Axum => "axum",
+            HttpFramework::Actix => "actix",
+            HttpFramework::Express => "express",
+            HttpFramework::FastApi => "fastapi",
+        }
+    }
+}
+
+/// A single HTTP endpoint found in the code
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Endpoint {
+    /// Upper-case HTTP method (`ANY` for catch-all registrations)
+    pub method: String,
+    /// Route path as written in the source
+    pub path: String,
+    /// Handler function name, when it could be determined
+    pub handler: Option<String>,
+    pub framework: HttpFramework,
+    pub file_path: String,
+    /// 1-based line of the registration
+    pub line: u32,
+}
+
+/// Sorted, de-duplicated set of endpoints for a branch
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EndpointInventory {
+    pub endpoints: Vec<Endpoint>,
+}
+
+const HTTP_METHODS: &str = "get|post|put|delete|patch|head|options|trace|any|all";
+
+fn axum_route_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r#"\.route\(\s*"([^"]*)"\s*,"#).expect("valid axum route regex"))
+}
+
+fn axum_method_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(&format!(r"\b({})\(\s*([A-Za-z_][\w:]*)\s*\)", HTTP_METHODS))
+            .expect("valid axum method regex")
+    })
+}
+
+fn actix_method_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(&format!(
+            r"\bweb::({})\(\)\s*\.to\(\s*([A-Za-z_][\w:]*)\s*\)",
+            HTTP_METHODS
+        ))
+        .expect("valid actix method regex")
+    })
+}
+
+fn actix_attribute_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r#"#\[(get|post|put|delete|patch|head|options|trace)\(\s*"([^"]*)"[^\]]*\]\s*(?:#\[[^\]]*\]\s*)*(?:pub(?:\([^)]*\))?\s+)?(?:async\s+)?fn\s+([A-Za-z_]\w*)"#,
+        )
+        .expect("valid actix attribute regex")
+    })
+}
+
+fn express_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(&format!(
+            r#"\b(?:app|server|\w*[Rr]outer|\w*[Rr]outes)\.({})\(\s*['"`](/[^'"`]*)['"`]"#,
+            HTTP_METHODS
+        ))
+        .expect("valid express regex")
+    })
+}
+
+fn fastapi_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r#"@\w+\.(get|post|put|delete|patch|head|options|trace)\(\s*['"]([^'"]*)['"]"#)
+            .expect("valid fastapi regex")
+    })
+}
+
+fn python_def_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?m)^\s*(?:async\s+)?def\s+([A-Za-z_]\w*)").expect("valid def regex")
+    })
+}
+
+fn js_identifier_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"^[A-Za-z_$][\w$.]*$").expect("valid identifier regex"))
+}
+
+/// Extract endpoints from `content`, a slice of `file_path` starting at `start_line`.
+///
+/// The language is derived from the file extension.
+pub fn extract_endpoints(file_path: &str, content: &str, start_line: u32) -> Vec<Endpoint> {
+    let language = TextSplitter::detect_language(file_path);
+    let line_at = |offset: usize| start_line + content[..offset].matches('\n').count() as u32;
+    let endpoint =
+        |method: &str, path: &str, handler: Option<String>, framework, offset| Endpoint {
+            method: method.to_uppercase(),
+            path: path.to_string(),
+            handler,
+            framework,
+            file_path: file_path.to_string(),
+            line: line_at(offset),
+        };
+
+    let mut endpoints = Vec::new();
+
+    match language.as_deref() {
+        Some("rust") => {
+            for cap in axum_route_regex().captures_iter(content) {
+                let whole = cap.get(0).expect("match");
+                let args = balanced_args(content, whole.end());
+
+                for m in actix_method_regex().captures_iter(args) {
+                    endpoints.push(endpoint(
+                        &m[1],
+                        &cap[1],
+                        Some(m[2].to_string()),
+                        HttpFramework::Actix,
+                        whole.start(),
+                    ));
+                }
+                for m in axum_method_regex().captures_iter(args) {
+                    endpoints.push(endpoint(
+                        &m[1],
+                        &cap[1],
+                        Some(m[2].to_string()),
+                        HttpFramework::Axum,
+                        whole.start(),
+                    ));
+                }
+            }
+
+            for cap in actix_attribute_regex().captures_iter(content) {
+                let start = cap.get(0).expect("match").start();
+                endpoints.push(endpoint(
+                    &cap[1],
+                    &cap[2],
+                    Some(cap[3].to_string()),
+                    HttpFramework::Actix,
+                    start,
+                ));
+            }
+        }
+        Some("javascript") | Some("typescript") => {
+            for cap in express_regex().captures_iter(content) {
+                let whole = cap.get(0).expect("match");
+                let handler = balanced_args(content, whole.end())
+                    .rsplit(',')
+                    .next()
+                    .map(str::trim)
+                    .filter(|arg| js_identifier_regex().is_match(arg))
+                    .map(str::to_string);
+                endpoints.push(endpoint(
+                    &cap[1],
+                    &cap[2],
+                    handler,
+                    HttpFramework::Express,
+                    whole.start(),
+                ));
+            }
+        }
+        Some("python") => {
+            for cap in fastapi_regex().captures_iter(content) {
+                let whole = cap.get(0).expect("match");
+                let handler = python_def_regex()
+                    .captures_at(content, whole.end())
+                    .map(|def| def[1].to_string());
+                endpoints.push(endpoint(
+                    &cap[1],
+                    &cap[2],
+                    handler,
+                    HttpFramework::FastApi,
+                    whole.start(),
+                ));
+            }
+        }
+        _ => {}
+    }
+
+    endpoints
+}
+
+/// Text of the call arguments starting at `from` up to the matching `)`,
+/// or to the end of `content` if the call is cut off (e.g. at a chunk boundary)
+fn balanced_args(content: &str, from: usize) -> &str {
+    let mut depth = 0usize;
+    for (i, c) in content[from..].char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => {
+                if depth == 0 {
+                    return &content[from..from + i];
+                }
+                depth -= 1;
+            }
+            _ => {}
+        }
+    }
+    &content[from..]
+}
+
+impl EndpointInventory {
+    /// Build the inventory from indexed chunks; overlapping chunks are de-duplicated
+    pub fn from_chunks(chunks: &[CodeChunk]) -> Self {
+        let mut seen = HashSet::new();
+        let mut endpoints: Vec<Endpoint> = chunks
+            .iter()
+            .flat_map(|c| extract_endpoints(&c.file_path, &c.content, c.start_line))
+            .filter(|e| {
+                seen.insert((
+                    e.method.clone(),
+                    e.path.clone(),
+                    e.file_path.clone(),
+                    e.line,
+                ))
+            })
+            .collect();
+
+        endpoints.sort_by(|a, b| {
+            (&a.path, &a.method, &a.file_path, a.line).cmp(&(
+                &b.path,
+                &b.method,
+                &b.file_path,
+                b.line,
+            ))
+        });
+
+        Self { endpoints }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.endpoints.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.endpoints.len()
+    }
+
+    /// Unique source files containing endpoints, sorted
+    pub fn file_paths(&self) -> Vec<String> {
+        self.endpoints
+            .iter()
+            .map(|e| e.file_path.clone())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Render the inventory as a markdown table.
+    ///
+    /// Output depends only on the inventory, so regenerating an unchanged
+    /// codebase yields an identical page.
+    pub fn to_markdown(&self) -> String {
+        let files = self.file_paths();
+        let frameworks: BTreeSet<&str> = self
+            .endpoints
+            .iter()
+            .map(|e| e.framework.as_str())
+            .collect();
+
+        let mut md = String::from("# API Endpoints\n\n");
+        md.push_str(&format!(
+            "This page is generated from route registrations found in the indexed code: \
+             {} endpoint{} across {} file{} ({}).\n\n",
+            self.len(),
+            if self.len() == 1 { "" } else { "s" },
+            files.len(),
+            if files.len() == 1 { "" } else { "s" },
+            frameworks.into_iter().collect::<Vec<_>>().join(", ")
+        ));
+
+        md.push_str("| Method | Path | Handler | Framework | Source |\n");
+        md.push_str("|--------|------|---------|-----------|--------|\n");
+        for e in &self.endpoints {
+            md.push_str(&format!(
+                "| {} | `{}` | {} | {} | `{}:{}` |\n",
+                e.method,
+                e.path.replace('|', "\\|"),
+                e.handler
+                    .as_ref()
+                    .map(|h| format!("`{}`", h))
+                    .unwrap_or_else(|| "-".to_string()),
+                e.framework.as_str(),
+                e.file_path,
+                e.line
+            ));
+        }
+
+        md
+    }
+
+    /// Render the inventory as a wiki page
+    pub fn to_wiki_page(&self, branch: &str, commit_sha: &str, order: u32) -> WikiPage {
+        let citations = self
+            .endpoints
+            .iter()
+            .map(|e| SourceCitation::new(e.file_path.clone(), Some(e.line), Some(e.line)))
+            .collect();
+
+        WikiPage::new_advanced(
+            branch.to_string(),
+            ENDPOINTS_PAGE_SLUG.to_string(),
+            "API Endpoints".to_string(),
+            self.to_markdown(),
+            PageType::Api,
+            None,
+            order,
+            self.file_paths(),
+            commit_sha.to_string(),
+            Importance::High,
+            Vec::new(),
+            None,
+            citations,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn methods_and_paths(endpoints: &[Endpoint]) -> Vec<(String, String, Option<String>)> {
+        endpoints
+            .iter()
+            .map(|e| (e.method.clone(), e.path.clone(), e.handler.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_extract_axum_routes() {
+        let content = r#"Router::new()
+        .route("/api/tasks", get(list_tasks).post(create_task))
+        .route(
+            "/api/tasks/{id}",
+            routing::delete(routes::delete_task),
+        )"#;
+        let endpoints = extract_endpoints("src/lib.rs", content, 10);
+
+        assert_eq!(
+            methods_and_paths(&endpoints),
+            vec![
+                ("GET".into(), "/api/tasks".into(), Some("list_tasks".into())),
+                (
+                    "POST".into(),
+                    "/api/tasks".into(),
+                    Some("create_task".into())
+                ),
+                (
+                    "DELETE".into(),
+                    "/api/tasks/{id}".into(),
+                    Some("routes::delete_task".into())
+                ),
+            ]
+        );
+        assert_eq!(endpoints[0].line, 11);
+        assert_eq!(endpoints[2].line, 12);
+        assert!(endpoints.iter().all(|e| e.framework == HttpFramework::Axum));
+    }
+
+    #[test]
+    fn test_extract_actix_routes() {
+        let content = r#"
+#[get("/users/{id}")]
+async fn get_user(path: web::Path<u32>) -> impl Responder {}
+
+cfg.route("/users", web::post().to(create_user));
+"#;
+        let endpoints = extract_endpoints("src/api.rs", content, 1);
+
+        assert_eq!(
+            methods_and_paths(&endpoints),
+            vec![
+                ("POST".into(), "/users".into(), Some("create_user".into())),
+                ("GET".into(), "/users/{id}".into(), Some("get_user".into())),
+            ]
+        );
+        assert!(endpoints
+            .iter()
+            .all(|e| e.framework == HttpFramework::Actix));
+    }
+
+    #[test]
+    fn test_extract_express_routes() {
+        let content = r#"
+app.get('/health', healthCheck);
+router.post("/orders", auth, orders.create);
+userRouter.delete(`/users/:id`, async (req, res) => {});
+cache.get('/not-a-route');
+"#;
+        let endpoints = extract_endpoints("server/app.ts", content, 1);
+
+        assert_eq!(
+            methods_and_paths(&endpoints),
+            vec![
+                ("GET".into(), "/health".into(), Some("healthCheck".into())),
+                (
+                    "POST".into(),
+                    "/orders".into(),
+                    Some("orders.create".into())
+                ),
+                ("DELETE".into(), "/users/:id".into(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_fastapi_routes() {
+        let content = r#"
+@app.get("/items/{item_id}")
+async def read_item(item_id: int):
+    return {}
+
+@router.post(
+    "/items",
+    status_code=201,
+)
+def create_item(item: Item):
+    pass
+"#;
+        let endpoints = extract_endpoints("app/main.py", content, 1);
+
+        assert_eq!(
+            methods_and_paths(&endpoints),
+            vec![
+                (
+                    "GET".into(),
+                    "/items/{item_id}".into(),
+                    Some("read_item".into())
+                ),
+                ("POST".into(), "/items".into(), Some("create_item".into())),
+            ]
+        );
+        assert_eq!(endpoints[1].line, 6);
+    }
+
+    #[test]
+    fn test_inventory_dedupes_overlapping_chunks_and_renders_table() {
+        let chunk = |start: u32, content: &str, index: u32| {
+            CodeChunk::new(
+                "main".to_string(),
+                "src/lib.rs".to_string(),
+                start,
+                start + 2,
+                content.to_string(),
+                crate::domain::chunk::ChunkType::Code,
+                Some("rust".to_string()),
+                10,
+                index,
+                "abc".to_string(),
+            )
+        };
+        let chunks = vec![
+            chunk(1, "r\n.route(\"/b\", get(b))\n.route(\"/a\", post(a))", 0),
+            chunk(3, ".route(\"/a\", post(a))", 1),
+        ];
+
+        let inventory = EndpointInventory::from_chunks(&chunks);
+        assert_eq!(inventory.len(), 2);
+        assert_eq!(inventory.endpoints[0].path, "/a");
+        assert_eq!(inventory.endpoints[0].line, 3);
+
+        let page = inventory.to_wiki_page("main", "abc", 0);
+        assert_eq!(page.slug, ENDPOINTS_PAGE_SLUG);
+        assert_eq!(page.page_type, PageType::Api);
+        assert_eq!(page.file_paths, vec!["src/lib.rs".to_string()]);
+        assert!(page
+            .content
+            .contains("| POST | `/a` | `a` | axum | `src/lib.rs:3` |"));
+        assert_eq!(page.content, inventory.to_markdown());
+    }
+}
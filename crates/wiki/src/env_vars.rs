@@ -0,0 +1,537 @@
+//! Environment variable reference extraction
+//!
+//! Pattern-based detection of environment variable reads in indexed code
+//! (`std::env::var`, clap `env = "..."`, `process.env`, `import.meta.env`,
+//! `os.environ`/`os.getenv`, `os.Getenv`), together with literal defaults and
+//! adjacent comments, aggregated into a configuration reference.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::OnceLock;
+
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+
+use crate::chunker::TextSplitter;
+use crate::domain::chunk::CodeChunk;
+use crate::domain::wiki_page::{Importance, PageType, SourceCitation, WikiPage};
+
+/// Slug of the generated environment variable reference page
+pub const ENV_VARS_PAGE_SLUG: &str = "environment-variables";
+
+/// Maximum number of read locations listed per variable on the wiki page
+const MAX_LISTED_USAGES: usize = 3;
+
+/// A single place where an environment variable is read
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct EnvVarUsage {
+    pub file_path: String,
+    /// 1-based line of the read
+    pub line: u32,
+}
+
+/// A single environment variable read found in the code
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnvVarRead {
+    pub name: String,
+    /// Literal fallback used when the variable is unset
+    pub default_value: Option<String>,
+    /// Comment adjacent to the read
+    pub description: Option<String>,
+    pub usage: EnvVarUsage,
+}
+
+/// An environment variable with all places it is read
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnvVar {
+    pub name: String,
+    pub default_value: Option<String>,
+    pub description: Option<String>,
+    pub usages: Vec<EnvVarUsage>,
+}
+
+/// Environment variables of a branch, sorted by name
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnvVarReference {
+    pub variables: Vec<EnvVar>,
+}
+
+fn rust_read_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r#"(?:\b(?:std::)?env::var(?:_os)?|\bdotenvy?::var|\b(?:option_)?env!)\(\s*"(?P<name>[A-Za-z_][A-Za-z0-9_]*)"\s*\)"#,
+        )
+        .expect("valid rust read regex")
+    })
+}
+
+fn rust_default_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r#"^[^;]*?\.unwrap_or(?:_else)?\(\s*(?:\|[^|]*\|\s*)?(?:String::from\(\s*)?(?:"(?P<default>[^"]*)"|(?P<literal>[0-9][\w.]*))"#,
+        )
+        .expect("valid rust default regex")
+    })
+}
+
+fn clap_env_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r#"#\[(?:arg|clap)\([^\]]*?\benv\s*=\s*"(?P<name>[A-Za-z_][A-Za-z0-9_]*)"[^\]]*\]"#,
+        )
+        .expect("valid clap env regex")
+    })
+}
+
+fn clap_default_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r#"\bdefault_value(?:_t)?\s*=\s*(?:"(?P<default>[^"]*)"|(?P<literal>[\w.]+))"#)
+            .expect("valid clap default regex")
+    })
+}
+
+fn js_read_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r#"\b(?:process\.env|import\.meta\.env)(?:\.(?P<name>[A-Za-z_][A-Za-z0-9_]*)|\[\s*['"](?P<quoted>[A-Za-z_][A-Za-z0-9_]*)['"]\s*\])"#,
+        )
+        .expect("valid js read regex")
+    })
+}
+
+fn js_default_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r#"^\s*(?:\|\||\?\?)\s*(?:['"`](?P<default>[^'"`]*)['"`]|(?P<literal>[0-9][\w.]*|true|false))"#,
+        )
+        .expect("valid js default regex")
+    })
+}
+
+fn python_read_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r#"\b(?:os\.environ\[\s*['"](?P<name>[A-Za-z_][A-Za-z0-9_]*)['"]\s*\]|(?:os\.environ\.get|os\.getenv|environ\.get)\(\s*['"](?P<quoted>[A-Za-z_][A-Za-z0-9_]*)['"]\s*(?:,\s*(?:['"](?P<default>[^'"]*)['"]|(?P<literal>[\w.]+)))?)"#,
+        )
+        .expect("valid python read regex")
+    })
+}
+
+fn go_read_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r#"\bos\.(?:Getenv|LookupEnv)\(\s*"(?P<name>[A-Za-z_][A-Za-z0-9_]*)"\s*\)"#)
+            .expect("valid go read regex")
+    })
+}
+
+/// Name from the `name` or `quoted` group
+fn captured_name(cap: &Captures) -> Option<String> {
+    cap.name("name")
+        .or_else(|| cap.name("quoted"))
+        .map(|m| m.as_str().to_string())
+}
+
+/// Default from the `default` or `literal` group (`None` literals are ignored)
+fn captured_default(cap: &Captures) -> Option<String> {
+    cap.name("default")
+        .or_else(|| cap.name("literal"))
+        .map(|m| m.as_str().to_string())
+        .filter(|d| d != "None")
+}
+
+/// Comment on the line of `offset`, or on the line directly above it
+fn adjacent_comment(content: &str, offset: usize, markers: &[&str]) -> Option<String> {
+    let line_start = content[..offset].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let line_end = content[offset..]
+        .find('\n')
+        .map(|i| offset + i)
+        .unwrap_or(content.len());
+
+    let strip = |line: &str| -> Option<String> {
+        let line = line.trim();
+        markers
+            .iter()
+            .find_map(|m| line.strip_prefix(m))
+            .map(|c| c.trim_start_matches(['/', '!', '*']).trim().to_string())
+            .filter(|c| !c.is_empty())
+    };
+
+    // Only markers preceded by whitespace start a comment, so `"http://..."` is skipped
+    let trailing = &content[offset..line_end];
+    let same_line = markers
+        .iter()
+        .find_map(|m| {
+            trailing
+                .match_indices(m)
+                .find(|(i, _)| trailing[..*i].ends_with(char::is_whitespace))
+                .map(|(i, _)| &trailing[i..])
+        })
+        .and_then(strip);
+
+    same_line.or_else(|| {
+        let previous_line = content[..line_start.checked_sub(1)?].rsplit('\n').next()?;
+        strip(previous_line)
+    })
+}
+
+/// Extract environment variable reads from `content`, a slice of `file_path`
+/// starting at `start_line`. The language is derived from the file extension.
+pub fn extract_env_reads(file_path: &str, content: &str, start_line: u32) -> Vec<EnvVarRead> {
+    let language = TextSplitter::detect_language(file_path);
+    let line_at = |offset: usize| start_line + content[..offset].matches('\n').count() as u32;
+    let read =
+        |name: String, default_value: Option<String>, offset: usize, markers: &[&str]| EnvVarRead {
+            name,
+            default_value,
+            description: adjacent_comment(content, offset, markers),
+            usage: EnvVarUsage {
+                file_path: file_path.to_string(),
+                line: line_at(offset),
+            },
+        };
+
+    let mut reads = Vec::new();
+
+    match language.as_deref() {
+        Some("rust") => {
+            for cap in rust_read_regex().captures_iter(content) {
+                let whole = cap.get(0).expect("match");
+                let Some(name) = captured_name(&cap) else {
+                    continue;
+                };
+                // Cargo sets these at build time, they are not configuration
+                if whole.as_str().contains("env!") && name.starts_with("CARGO_") {
+                    continue;
+                }
+                let default = rust_default_regex()
+                    .captures(&content[whole.end()..])
+                    .and_then(|d| captured_default(&d));
+                reads.push(read(name, default, whole.start(), &["//"]));
+            }
+
+            for cap in clap_env_regex().captures_iter(content) {
+                let whole = cap.get(0).expect("match");
+                let Some(name) = captured_name(&cap) else {
+                    continue;
+                };
+                let default = clap_default_regex()
+                    .captures(whole.as_str())
+                    .and_then(|d| captured_default(&d));
+                reads.push(read(name, default, whole.start(), &["//"]));
+            }
+        }
+        Some("javascript") | Some("typescript") => {
+            for cap in js_read_regex().captures_iter(content) {
+                let whole = cap.get(0).expect("match");
+                let Some(name) = captured_name(&cap) else {
+                    continue;
+                };
+                let default = js_default_regex()
+                    .captures(&content[whole.end()..])
+                    .and_then(|d| captured_default(&d));
+                reads.push(read(name, default, whole.start(), &["//"]));
+            }
+        }
+        Some("python") => {
+            for cap in python_read_regex().captures_iter(content) {
+                let whole = cap.get(0).expect("match");
+                if let Some(name) = captured_name(&cap) {
+                    reads.push(read(name, captured_default(&cap), whole.start(), &["#"]));
+                }
+            }
+        }
+        Some("go") => {
+            for cap in go_read_regex().captures_iter(content) {
+                let whole = cap.get(0).expect("match");
+                if let Some(name) = captured_name(&cap) {
+                    reads.push(read(name, None, whole.start(), &["//"]));
+                }
+            }
+        }
+        _ => {}
+    }
+
+    reads
+}
+
+impl EnvVarReference {
+    /// Build the reference from indexed chunks; overlapping chunks are de-duplicated
+    pub fn from_chunks(chunks: &[CodeChunk]) -> Self {
+        let mut reads: Vec<EnvVarRead> = chunks
+            .iter()
+            .flat_map(|c| extract_env_reads(&c.file_path, &c.content, c.start_line))
+            .collect();
+        reads.sort_by(|a, b| (&a.name, &a.usage).cmp(&(&b.name, &b.usage)));
+
+        let mut variables: BTreeMap<String, EnvVar> = BTreeMap::new();
+        let mut seen = BTreeSet::new();
+        for r in reads {
+            if !seen.insert((r.name.clone(), r.usage.clone())) {
+                continue;
+            }
+            let var = variables.entry(r.name.clone()).or_insert_with(|| EnvVar {
+                name: r.name.clone(),
+                default_value: None,
+                description: None,
+                usages: Vec::new(),
+            });
+            if var.default_value.is_none() {
+                var.default_value = r.default_value;
+            }
+            if var.description.is_none() {
+                var.description = r.description;
+            }
+            var.usages.push(r.usage);
+        }
+
+        Self {
+            variables: variables.into_values().collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.variables.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.variables.len()
+    }
+
+    /// Unique source files reading environment variables, sorted
+    pub fn file_paths(&self) -> Vec<String> {
+        self.variables
+            .iter()
+            .flat_map(|v| v.usages.iter().map(|u| u.file_path.clone()))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Render the reference as a markdown table
+    pub fn to_markdown(&self) -> String {
+        let files = self.file_paths().len();
+
+        let mut md = String::from("# Environment Variables\n\n");
+        md.push_str(&format!(
+            "This page is generated from environment variable reads found in the indexed code: \
+             {} variable{} read in {} file{}.\n\n",
+            self.len(),
+            if self.len() == 1 { "" } else { "s" },
+            files,
+            if files == 1 { "" } else { "s" },
+        ));
+
+        md.push_str("| Variable | Default | Description | Read in |\n");
+        md.push_str("|----------|---------|-------------|---------|\n");
+        for var in &self.variables {
+            let mut locations = var
+                .usages
+                .iter()
+                .take(MAX_LISTED_USAGES)
+                .map(|u| format!("`{}:{}`", u.file_path, u.line))
+                .collect::<Vec<_>>()
+                .join(", ");
+            if var.usages.len() > MAX_LISTED_USAGES {
+                locations.push_str(&format!(
+                    " (+{} more)",
+                    var.usages.len() - MAX_LISTED_USAGES
+                ));
+            }
+
+            md.push_str(&format!(
+                "| `{}` | {} | {} | {} |\n",
+                var.name,
+                var.default_value
+                    .as_ref()
+                    .map(|d| format!("`{}`", d.replace('|', "\\|")))
+                    .unwrap_or_else(|| "-".to_string()),
+                var.description
+                    .as_ref()
+                    .map(|d| d.replace('|', "\\|"))
+                    .unwrap_or_else(|| "-".to_string()),
+                locations
+            ));
+        }
+
+        md
+    }
+
+    /// Render the reference as a wiki page
+    pub fn to_wiki_page(&self, branch: &str, commit_sha: &str, order: u32) -> WikiPage {
+        let citations = self
+            .variables
+            .iter()
+            .flat_map(|v| &v.usages)
+            .map(|u| SourceCitation::new(u.file_path.clone(), Some(u.line), Some(u.line)))
+            .collect();
+
+        WikiPage::new_advanced(
+            branch.to_string(),
+            ENV_VARS_PAGE_SLUG.to_string(),
+            "Environment Variables".to_string(),
+            self.to_markdown(),
+            PageType::Custom,
+            None,
+            order,
+            self.file_paths(),
+            commit_sha.to_string(),
+            Importance::Medium,
+            Vec::new(),
+            None,
+            citations,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(reads: &[EnvVarRead]) -> Vec<(&str, Option<&str>, Option<&str>, u32)> {
+        reads
+            .iter()
+            .map(|r| {
+                (
+                    r.name.as_str(),
+                    r.default_value.as_deref(),
+                    r.description.as_deref(),
+                    r.usage.line,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_extract_rust_reads() {
+        let content = r#"
+// Address the HTTP server binds to
+let host = std::env::var("HOST").unwrap_or_else(|_| "127.0.0.1".to_string());
+let port: u16 = env::var("PORT").ok().and_then(|p| p.parse().ok()).unwrap_or(3001);
+let key = env::var("API_KEY")?; // OpenRouter key
+let version = env!("CARGO_PKG_VERSION");
+
+struct Args {
+    /// Database location
+    #[arg(long, env = "DATABASE_URL", default_value = "sqlite://studio.db")]
+    database_url: String,
+}
+"#;
+        let reads = extract_env_reads("src/main.rs", content, 1);
+
+        assert_eq!(
+            summary(&reads),
+            vec![
+                (
+                    "HOST",
+                    Some("127.0.0.1"),
+                    Some("Address the HTTP server binds to"),
+                    3
+                ),
+                ("PORT", Some("3001"), None, 4),
+                ("API_KEY", None, Some("OpenRouter key"), 5),
+                (
+                    "DATABASE_URL",
+                    Some("sqlite://studio.db"),
+                    Some("Database location"),
+                    10
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_js_reads() {
+        let content = r#"const port = process.env.PORT || 3000;
+const url = process.env["API_URL"] ?? "http://localhost";
+const mode = import.meta.env.MODE;"#;
+        let reads = extract_env_reads("src/config.ts", content, 10);
+
+        assert_eq!(
+            summary(&reads),
+            vec![
+                ("PORT", Some("3000"), None, 10),
+                ("API_URL", Some("http://localhost"), None, 11),
+                ("MODE", None, None, 12),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_python_and_go_reads() {
+        let python = r#"
+# Log verbosity
+LOG_LEVEL = os.environ.get("LOG_LEVEL", "info")
+SECRET = os.environ["SECRET_KEY"]
+TIMEOUT = os.getenv('TIMEOUT', None)
+"#;
+        assert_eq!(
+            summary(&extract_env_reads("app/settings.py", python, 1)),
+            vec![
+                ("LOG_LEVEL", Some("info"), Some("Log verbosity"), 3),
+                ("SECRET_KEY", None, None, 4),
+                ("TIMEOUT", None, None, 5),
+            ]
+        );
+
+        let go = r#"addr := os.Getenv("LISTEN_ADDR")"#;
+        assert_eq!(
+            summary(&extract_env_reads("main.go", go, 1)),
+            vec![("LISTEN_ADDR", None, None, 1)]
+        );
+    }
+
+    #[test]
+    fn test_reference_aggregates_usages() {
+        let chunk = |path: &str, start: u32, content: &str| {
+            CodeChunk::new(
+                "main".to_string(),
+                path.to_string(),
+                start,
+                start,
+                content.to_string(),
+                crate::domain::chunk::ChunkType::Code,
+                None,
+                10,
+                0,
+                "abc".to_string(),
+            )
+        };
+        let chunks = vec![
+            chunk("src/b.rs", 4, r#"let a = env::var("PORT");"#),
+            chunk(
+                "src/a.rs",
+                2,
+                r#"let b = env::var("PORT").unwrap_or("8080".into());"#,
+            ),
+            chunk(
+                "src/a.rs",
+                2,
+                r#"let b = env::var("PORT").unwrap_or("8080".into());"#,
+            ),
+            chunk("web/app.js", 1, "const u = process.env.API_URL;"),
+        ];
+
+        let reference = EnvVarReference::from_chunks(&chunks);
+        assert_eq!(reference.len(), 2);
+
+        let port = &reference.variables[1];
+        assert_eq!(port.name, "PORT");
+        assert_eq!(port.default_value.as_deref(), Some("8080"));
+        assert_eq!(port.usages.len(), 2);
+        assert_eq!(port.usages[0].file_path, "src/a.rs");
+
+        let page = reference.to_wiki_page("main", "abc", 0);
+        assert_eq!(page.slug, ENV_VARS_PAGE_SLUG);
+        assert!(page
+            .content
+            .contains("| `PORT` | `8080` | - | `src/a.rs:2`, `src/b.rs:4` |"));
+        assert_eq!(page.file_paths.len(), 3);
+    }
+}
@@ -33,6 +33,12 @@ pub enum WikiError {
     #[error("Wiki page not found: {slug}")]
     PageNotFound { slug: String },
 
+    #[error("Wiki page {slug} cannot be regenerated: {reason}")]
+    PageNotRegenerable { slug: String, reason: String },
+
+    #[error("No route found for {method} {path}")]
+    RouteNotFound { method: String, path: String },
+
     #[error("Invalid configuration: {0}")]
     InvalidConfig(String),
 
@@ -51,8 +57,24 @@ pub enum WikiError {
     #[error("Chunk too large: {size} tokens (max: {max})")]
     ChunkTooLarge { size: usize, max: usize },
 
+    #[error(
+        "Branch '{branch}' is indexed with embedding model '{indexed}', not '{requested}'. \
+         Re-embed the branch with '{requested}' or switch the embedding model back to '{indexed}'"
+    )]
+    EmbeddingModelMismatch {
+        branch: String,
+        indexed: String,
+        requested: String,
+    },
+
+    #[error("Embedding provider error: {0}")]
+    EmbeddingProvider(String),
+
     #[error("Embedding dimension mismatch: expected {expected}, got {actual}")]
     DimensionMismatch { expected: usize, actual: usize },
+
+    #[error("No index of commit '{commit}' is kept")]
+    CommitNotIndexed { commit: String },
 }
 
 /// Result type alias for wiki operations
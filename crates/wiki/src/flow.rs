@@ -0,0 +1,637 @@
+//! Request flow tracing
+//!
+//! Answers "what happens when `POST /api/tasks/{id}/execute` is called?" by
+//! locating the route registration, following the call graph from its handler
+//! and asking the LLM to narrate the collected code as a step-by-step flow
+//! document with `file:line` citations.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+
+use crate::domain::chunk::CodeChunk;
+use crate::domain::wiki_page::{Importance, PageType, SourceCitation, WikiPage, WikiTree};
+use crate::endpoints::{Endpoint, EndpointInventory};
+use crate::error::{WikiError, WikiResult};
+use crate::openrouter::client::OpenRouterClient;
+use crate::openrouter::types::ChatMessage;
+use crate::symbols;
+use crate::test_coverage::is_test_path;
+use crate::vector_store::VectorStore;
+
+/// Slug prefix of persisted flow pages
+pub const FLOW_PAGE_PREFIX: &str = "flow-";
+
+/// Default maximum number of call hops followed from the handler
+const DEFAULT_MAX_DEPTH: u32 = 4;
+
+/// Default maximum number of functions on a traced flow
+const DEFAULT_MAX_STEPS: usize = 12;
+
+/// Maximum number of calls followed out of a single function
+const MAX_CALLS_PER_STEP: usize = 8;
+
+/// Maximum context length in characters
+const MAX_CONTEXT_LENGTH: usize = 24000;
+
+/// System prompt for flow documentation
+const FLOW_SYSTEM_PROMPT: &str = r#"You are a senior engineer documenting how a request travels through a codebase.
+
+You are given the route registration for an HTTP endpoint and the functions reached from its handler, in call order.
+Write a step-by-step flow document:
+- Use a numbered list, one step per meaningful action (validation, database access, external calls, events, response)
+- Cite every step with its code location in the format `file_path:line`
+- Mention error paths and early returns when the code shows them
+- Only describe what the provided code does; if the trail ends (e.g. in a library call), say so
+- Do not repeat the code, and do not add a top-level heading"#;
+
+/// A function reached while tracing a flow
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlowStep {
+    pub symbol: String,
+    pub file_path: String,
+    /// 1-based line of the function definition
+    pub line: u32,
+    /// 1-based last line of the function body as far as it was indexed
+    pub end_line: u32,
+    /// Call hops from the handler (the handler itself is 0)
+    pub depth: u32,
+    /// Function this one was reached from
+    pub called_from: Option<String>,
+    /// Source of the function
+    pub content: String,
+}
+
+/// Route and call path of a request, without any LLM involvement
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlowTrace {
+    pub endpoint: Endpoint,
+    /// Functions reached from the handler, breadth-first
+    pub steps: Vec<FlowStep>,
+}
+
+/// Narrated flow document for a request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlowDocument {
+    pub method: String,
+    pub path: String,
+    pub trace: FlowTrace,
+    /// Markdown flow document
+    pub content: String,
+}
+
+impl FlowTrace {
+    /// Find the route matching `method` and `path` and follow the call graph
+    /// from its handler. Returns `None` when no registered route matches.
+    pub fn from_chunks(
+        chunks: &[CodeChunk],
+        method: &str,
+        path: &str,
+        max_depth: u32,
+        max_steps: usize,
+    ) -> Option<Self> {
+        let inventory = EndpointInventory::from_chunks(chunks);
+        let endpoint = find_route(&inventory.endpoints, method, path)?.clone();
+
+        let steps = match &endpoint.handler {
+            Some(handler) => {
+                // Handlers may be path-qualified (`routes::tasks::execute`, `controller.run`)
+                let symbol = handler.rsplit([':', '.']).next().unwrap_or(handler);
+                follow_calls(chunks, symbol, &endpoint.file_path, max_depth, max_steps)
+            }
+            None => Vec::new(),
+        };
+
+        Some(Self { endpoint, steps })
+    }
+
+    /// Deterministic outline of the call path, indented by depth
+    pub fn to_outline(&self) -> String {
+        let mut md = format!(
+            "- Route `{} {}` registered at `{}:{}`\n",
+            self.endpoint.method, self.endpoint.path, self.endpoint.file_path, self.endpoint.line
+        );
+        for step in &self.steps {
+            md.push_str(&format!(
+                "{}- `{}` at `{}:{}`\n",
+                "  ".repeat(step.depth as usize + 1),
+                step.symbol,
+                step.file_path,
+                step.line
+            ));
+        }
+        md
+    }
+
+    /// Unique files touched by the flow, route registration first
+    pub fn file_paths(&self) -> Vec<String> {
+        let mut seen = HashSet::new();
+        std::iter::once(&self.endpoint.file_path)
+            .chain(self.steps.iter().map(|s| &s.file_path))
+            .filter(|f| seen.insert(f.as_str()))
+            .cloned()
+            .collect()
+    }
+
+    fn context(&self) -> String {
+        let mut context = format!(
+            "Route: {} {} ({} handler `{}`) registered at {}:{}\n\nCall path:\n{}",
+            self.endpoint.method,
+            self.endpoint.path,
+            self.endpoint.framework.as_str(),
+            self.endpoint.handler.as_deref().unwrap_or("inline"),
+            self.endpoint.file_path,
+            self.endpoint.line,
+            self.to_outline()
+        );
+
+        for step in &self.steps {
+            let block = format!(
+                "\n--- {} ({}:{}-{}) ---\n{}\n",
+                step.symbol, step.file_path, step.line, step.end_line, step.content
+            );
+            if context.len() + block.len() > MAX_CONTEXT_LENGTH {
+                context.push_str("\n[further functions omitted for length]\n");
+                break;
+            }
+            context.push_str(&block);
+        }
+
+        context
+    }
+}
+
+impl FlowDocument {
+    /// Slug the document is persisted under, e.g. `flow-post-api-tasks-id-execute`
+    pub fn slug(&self) -> String {
+        flow_slug(&self.method, &self.path)
+    }
+
+    /// Render the document as a wiki page
+    pub fn to_wiki_page(&self, branch: &str, commit_sha: &str, order: u32) -> WikiPage {
+        let citations = std::iter::once(SourceCitation::new(
+            self.trace.endpoint.file_path.clone(),
+            Some(self.trace.endpoint.line),
+            Some(self.trace.endpoint.line),
+        ))
+        .chain(
+            self.trace
+                .steps
+                .iter()
+                .map(|s| SourceCitation::new(s.file_path.clone(), Some(s.line), Some(s.end_line))),
+        )
+        .collect();
+
+        WikiPage::new_advanced(
+            branch.to_string(),
+            self.slug(),
+            format!("Flow: {} {}", self.method, self.path),
+            self.content.clone(),
+            PageType::Architecture,
+            None,
+            order,
+            self.trace.file_paths(),
+            commit_sha.to_string(),
+            Importance::Medium,
+            Vec::new(),
+            None,
+            citations,
+        )
+    }
+}
+
+/// Traces request flows and narrates them with the chat model
+pub struct FlowTracer<'a> {
+    openrouter: &'a OpenRouterClient,
+    chat_model: String,
+    max_depth: u32,
+    max_steps: usize,
+}
+
+impl<'a> FlowTracer<'a> {
+    /// Create a new flow tracer
+    pub fn new(openrouter: &'a OpenRouterClient, chat_model: impl Into<String>) -> Self {
+        Self {
+            openrouter,
+            chat_model: chat_model.into(),
+            max_depth: DEFAULT_MAX_DEPTH,
+            max_steps: DEFAULT_MAX_STEPS,
+        }
+    }
+
+    /// Set the maximum number of call hops followed from the handler
+    pub fn with_max_depth(mut self, max_depth: u32) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Set the maximum number of functions on the flow
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// Trace `method path` through `chunks` and write the flow document
+    pub async fn trace(
+        &self,
+        chunks: &[CodeChunk],
+        method: &str,
+        path: &str,
+    ) -> WikiResult<FlowDocument> {
+        let method = method.to_uppercase();
+        info!("Tracing flow for {} {}", method, path);
+
+        let trace = FlowTrace::from_chunks(chunks, &method, path, self.max_depth, self.max_steps)
+            .ok_or_else(|| WikiError::RouteNotFound {
+            method: method.clone(),
+            path: path.to_string(),
+        })?;
+        debug!(
+            "Route {} {} resolved to {}:{} with {} steps",
+            method,
+            path,
+            trace.endpoint.file_path,
+            trace.endpoint.line,
+            trace.steps.len()
+        );
+
+        let messages = vec![
+            ChatMessage::system(FLOW_SYSTEM_PROMPT),
+            ChatMessage::user(format!(
+                "Explain what happens when `{} {}` is called.\n\n{}",
+                method,
+                path,
+                trace.context()
+            )),
+        ];
+        let narrative = self
+            .openrouter
+            .chat_completion(messages, &self.chat_model, Some(0.3), Some(2048))
+            .await?;
+
+        let content = format!(
+            "# {} {}\n\n{}\n\n## Call Path\n\n{}",
+            method,
+            path,
+            narrative.trim(),
+            trace.to_outline()
+        );
+
+        Ok(FlowDocument {
+            method,
+            path: path.to_string(),
+            trace,
+            content,
+        })
+    }
+}
+
+/// Persist `document` as a wiki page of `branch` and list it in the branch's
+/// wiki structure. Tracing the same route again replaces the page.
+pub fn save_flow_page(
+    store: &VectorStore,
+    branch: &str,
+    document: &FlowDocument,
+) -> WikiResult<WikiPage> {
+    let slug = document.slug();
+    let commit_sha = store
+        .get_index_status(branch)?
+        .and_then(|s| s.last_commit_sha)
+        .unwrap_or_default();
+    let order = match store.get_wiki_page_in_branch(&slug, Some(branch))? {
+        Some(existing) => existing.order,
+        None => store.get_page_count(branch)?,
+    };
+
+    let page = document.to_wiki_page(branch, &commit_sha, order);
+    store.insert_wiki_page(&page)?;
+
+    if let Some(mut structure) = store.get_wiki_structure(branch)? {
+        if structure.root.find(&slug).is_none() {
+            structure.root.add_child(WikiTree::new(
+                slug.clone(),
+                page.title.clone(),
+                page.page_type,
+                order,
+            ));
+            structure.page_count = structure.root.count() as u32;
+            store.save_wiki_structure(&structure)?;
+        }
+    }
+
+    info!("Saved flow page '{}' for branch '{}'", slug, branch);
+    Ok(page)
+}
+
+/// Slug a flow for `method path` is persisted under
+pub fn flow_slug(method: &str, path: &str) -> String {
+    let route = format!("{} {}", method, path).to_lowercase();
+    let slug = route
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    format!("{}{}", FLOW_PAGE_PREFIX, slug)
+}
+
+/// Best route for `method path`: an exact match, or else a route whose path is
+/// a suffix of the requested one (routers nested under a prefix)
+fn find_route<'e>(endpoints: &'e [Endpoint], method: &str, path: &str) -> Option<&'e Endpoint> {
+    let requested = path_segments(path);
+    let candidates = endpoints
+        .iter()
+        .filter(|e| e.method == "ANY" || e.method.eq_ignore_ascii_case(method));
+
+    let mut nested = None;
+    for endpoint in candidates {
+        let pattern = path_segments(&endpoint.path);
+        if pattern.is_empty() || pattern.len() > requested.len() {
+            continue;
+        }
+        let offset = requested.len() - pattern.len();
+        let matches = pattern
+            .iter()
+            .zip(&requested[offset..])
+            .all(|(p, r)| p == r || is_path_param(p) || is_path_param(r));
+        if !matches {
+            continue;
+        }
+        if offset == 0 {
+            return Some(endpoint);
+        }
+        nested.get_or_insert(endpoint);
+    }
+    nested
+}
+
+fn path_segments(path: &str) -> Vec<&str> {
+    path.split('?')
+        .next()
+        .unwrap_or_default()
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+fn is_path_param(segment: &str) -> bool {
+    segment.starts_with(':')
+        || (segment.starts_with('{') && segment.ends_with('}'))
+        || (segment.starts_with('<') && segment.ends_with('>'))
+}
+
+/// Breadth-first walk of the call graph starting at `handler`
+fn follow_calls(
+    chunks: &[CodeChunk],
+    handler: &str,
+    handler_file: &str,
+    max_depth: u32,
+    max_steps: usize,
+) -> Vec<FlowStep> {
+    let mut definers: HashMap<String, Vec<&CodeChunk>> = HashMap::new();
+    for chunk in chunks.iter().filter(|c| !is_test_path(&c.file_path)) {
+        for symbol in symbols::extract_definitions(&chunk.content) {
+            definers.entry(symbol).or_default().push(chunk);
+        }
+    }
+
+    let mut steps = Vec::new();
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::from([(handler.to_string(), 0, None, handler_file.to_string())]);
+
+    while let Some((symbol, depth, called_from, caller_file)) = queue.pop_front() {
+        if steps.len() >= max_steps {
+            break;
+        }
+        if !visited.insert(symbol.clone()) {
+            continue;
+        }
+        let Some(candidates) = definers.get(&symbol) else {
+            continue;
+        };
+        let Some((chunk, offset)) = pick_definition(candidates, &symbol, &caller_file) else {
+            continue;
+        };
+
+        let body = function_body(&chunk.content, &symbol, offset);
+        let line = chunk.start_line + offset;
+        let step = FlowStep {
+            symbol: symbol.clone(),
+            file_path: chunk.file_path.clone(),
+            line,
+            end_line: line + body.lines().count().saturating_sub(1) as u32,
+            depth,
+            called_from,
+            content: body.to_string(),
+        };
+
+        if depth < max_depth {
+            for call in symbols::extract_calls(body)
+                .into_iter()
+                .filter(|c| !visited.contains(c))
+                .take(MAX_CALLS_PER_STEP)
+            {
+                queue.push_back((
+                    call,
+                    depth + 1,
+                    Some(symbol.clone()),
+                    chunk.file_path.clone(),
+                ));
+            }
+        }
+        steps.push(step);
+    }
+
+    steps
+}
+
+/// Chunk defining `symbol`, preferring the caller's file and, among
+/// overlapping chunks, the one containing most of the body
+fn pick_definition<'c>(
+    candidates: &[&'c CodeChunk],
+    symbol: &str,
+    caller_file: &str,
+) -> Option<(&'c CodeChunk, u32)> {
+    candidates
+        .iter()
+        .filter_map(|c| symbols::definition_line(&c.content, symbol).map(|offset| (*c, offset)))
+        .min_by_key(|(c, offset)| (c.file_path != caller_file, *offset))
+}
+
+/// Lines of `content` from the definition of `symbol` up to the next definition
+fn function_body<'c>(content: &'c str, symbol: &str, offset: u32) -> &'c str {
+    let next_definition = symbols::extract_definitions(content)
+        .iter()
+        .filter(|d| *d != symbol)
+        .filter_map(|d| symbols::definition_line(content, d))
+        .filter(|line| *line > offset)
+        .min();
+
+    let line_start = |line: u32| {
+        content
+            .match_indices('\n')
+            .nth(line as usize - 1)
+            .map_or(content.len(), |(i, _)| i + 1)
+    };
+    let start = if offset == 0 { 0 } else { line_start(offset) };
+    let end = next_definition.map_or(content.len(), line_start);
+    content[start..end].trim_end()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::chunk::ChunkType;
+    use crate::endpoints::HttpFramework;
+
+    fn chunk(file_path: &str, start_line: u32, content: &str) -> CodeChunk {
+        CodeChunk::new(
+            "main".to_string(),
+            file_path.to_string(),
+            start_line,
+            start_line + content.lines().count() as u32 - 1,
+            content.to_string(),
+            ChunkType::Code,
+            Some("rust".to_string()),
+            0,
+            0,
+            "abc".to_string(),
+        )
+    }
+
+    fn endpoint(method: &str, path: &str) -> Endpoint {
+        Endpoint {
+            method: method.to_string(),
+            path: path.to_string(),
+            handler: None,
+            framework: HttpFramework::Axum,
+            file_path: "src/lib.rs".to_string(),
+            line: 1,
+        }
+    }
+
+    #[test]
+    fn test_find_route_matches_params_and_nesting() {
+        let endpoints = vec![
+            endpoint("GET", "/api/tasks/{id}"),
+            endpoint("POST", "/api/tasks/{id}/execute"),
+            endpoint("POST", "/:id/cancel"),
+        ];
+
+        let found = find_route(&endpoints, "post", "/api/tasks/42/execute").unwrap();
+        assert_eq!(found.path, "/api/tasks/{id}/execute");
+
+        let found = find_route(&endpoints, "GET", "/api/tasks/{task_id}").unwrap();
+        assert_eq!(found.path, "/api/tasks/{id}");
+
+        let nested = find_route(&endpoints, "POST", "/api/tasks/7/cancel").unwrap();
+        assert_eq!(nested.path, "/:id/cancel");
+
+        assert!(find_route(&endpoints, "DELETE", "/api/tasks/1").is_none());
+    }
+
+    #[test]
+    fn test_trace_follows_handler_calls() {
+        let chunks = vec![
+            chunk(
+                "crates/server/src/lib.rs",
+                10,
+                "Router::new()\n    .route(\"/api/tasks/{id}/execute\", post(routes::execute_task))",
+            ),
+            chunk(
+                "crates/server/src/routes.rs",
+                1,
+                "use crate::Executor;\n\npub async fn execute_task(id: Path<Uuid>) -> Json<Task> {\n    let task = load_task(id)?;\n    run_phase(&task).await\n}\n\nfn load_task(id: Uuid) -> Task {\n    db_fetch(id)\n}",
+            ),
+            chunk(
+                "crates/orchestrator/src/executor.rs",
+                20,
+                "pub async fn run_phase(task: &Task) {\n    emit_event(task);\n}",
+            ),
+            chunk(
+                "crates/orchestrator/tests/executor.rs",
+                1,
+                "fn emit_event() {}",
+            ),
+        ];
+
+        let trace = FlowTrace::from_chunks(&chunks, "POST", "/api/tasks/1/execute", 4, 10).unwrap();
+        assert_eq!(
+            trace.endpoint.handler.as_deref(),
+            Some("routes::execute_task")
+        );
+
+        let steps: Vec<_> = trace
+            .steps
+            .iter()
+            .map(|s| (s.symbol.as_str(), s.line, s.depth, s.called_from.as_deref()))
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                ("execute_task", 3, 0, None),
+                ("load_task", 8, 1, Some("execute_task")),
+                ("run_phase", 20, 1, Some("execute_task")),
+            ]
+        );
+        assert_eq!(trace.steps[0].end_line, 6);
+        assert!(!trace.steps[0].content.contains("db_fetch"));
+
+        let outline = trace.to_outline();
+        assert!(outline.starts_with(
+            "- Route `POST /api/tasks/{id}/execute` registered at `crates/server/src/lib.rs:11`"
+        ));
+        assert!(outline.contains("    - `load_task` at `crates/server/src/routes.rs:8`"));
+        assert_eq!(
+            trace.file_paths(),
+            vec![
+                "crates/server/src/lib.rs",
+                "crates/server/src/routes.rs",
+                "crates/orchestrator/src/executor.rs"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_save_flow_page_replaces_and_lists_page() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = VectorStore::new(&dir.path().join("wiki.db")).unwrap();
+        let root = WikiTree::new(
+            "overview".to_string(),
+            "Overview".to_string(),
+            PageType::Overview,
+            0,
+        );
+        store
+            .save_wiki_structure(&crate::WikiStructure::new("main".to_string(), root))
+            .unwrap();
+
+        let document = FlowDocument {
+            method: "GET".to_string(),
+            path: "/api/tasks".to_string(),
+            trace: FlowTrace {
+                endpoint: endpoint("GET", "/api/tasks"),
+                steps: Vec::new(),
+            },
+            content: "# GET /api/tasks".to_string(),
+        };
+
+        save_flow_page(&store, "main", &document).unwrap();
+        let page = save_flow_page(&store, "main", &document).unwrap();
+
+        assert_eq!(page.slug, "flow-get-api-tasks");
+        assert_eq!(page.order, 0);
+        assert_eq!(store.get_page_count("main").unwrap(), 1);
+        let structure = store.get_wiki_structure("main").unwrap().unwrap();
+        assert!(structure.root.find("flow-get-api-tasks").is_some());
+        assert_eq!(structure.page_count, 2);
+    }
+
+    #[test]
+    fn test_flow_slug() {
+        assert_eq!(
+            flow_slug("POST", "/api/tasks/{id}/execute"),
+            "flow-post-api-tasks-id-execute"
+        );
+    }
+}
@@ -94,7 +94,7 @@ impl ProjectAnalyzer {
                 percentage: (count as f32 / total_files as f32) * 100.0,
             })
             .collect();
-        languages.sort_by(|a, b| b.file_count.cmp(&a.file_count));
+        languages.sort_by_key(|l| std::cmp::Reverse(l.file_count));
 
         let modules: Vec<ModuleInfo> = module_files
             .iter()
@@ -249,7 +249,7 @@ impl ProjectAnalyzer {
         limit: usize,
     ) -> Vec<&'a ModuleInfo> {
         let mut modules: Vec<_> = structure.modules.iter().collect();
-        modules.sort_by(|a, b| b.file_count.cmp(&a.file_count));
+        modules.sort_by_key(|m| std::cmp::Reverse(m.file_count));
         modules.into_iter().take(limit).collect()
     }
 }
@@ -0,0 +1,131 @@
+//! Wiki pages extracted deterministically from indexed code
+//!
+//! Unlike the rest of the wiki these pages need no LLM: they are rebuilt from
+//! the branch's chunks whenever the branch is indexed or the wiki generated.
+
+use tracing::{debug, info};
+
+use crate::data_model::{DataModel, DATA_MODEL_PAGE_SLUG};
+use crate::domain::wiki_page::WikiPage;
+use crate::endpoints::{EndpointInventory, ENDPOINTS_PAGE_SLUG};
+use crate::env_vars::{EnvVarReference, ENV_VARS_PAGE_SLUG};
+use crate::error::WikiResult;
+use crate::onboarding::{OnboardingPath, ONBOARDING_PAGE_SLUG};
+use crate::test_coverage::{TestCoverageMap, TEST_COVERAGE_PAGE_SLUG};
+use crate::vector_store::VectorStore;
+
+/// Slugs of all extracted pages
+const PAGE_SLUGS: &[&str] = &[
+    ENDPOINTS_PAGE_SLUG,
+    DATA_MODEL_PAGE_SLUG,
+    ENV_VARS_PAGE_SLUG,
+    TEST_COVERAGE_PAGE_SLUG,
+    ONBOARDING_PAGE_SLUG,
+];
+
+/// Whether `slug` names an extracted page, which is rebuilt from the code
+/// and should not be edited by hand or by the LLM
+pub fn is_extracted_page(slug: &str) -> bool {
+    PAGE_SLUGS.contains(&slug)
+}
+
+/// Build and store the extracted pages (API endpoints, data model,
+/// environment variables, test coverage) for `branch`, numbering them from `first_order`.
+/// Pages without any extracted content are skipped.
+pub fn generate_pages(
+    store: &VectorStore,
+    branch: &str,
+    commit_sha: &str,
+    first_order: u32,
+) -> WikiResult<Vec<WikiPage>> {
+    let chunks = store.get_branch_chunks(branch)?;
+    let mut pages = Vec::new();
+
+    let inventory = EndpointInventory::from_chunks(&chunks);
+    if inventory.is_empty() {
+        debug!(branch = %branch, "No HTTP endpoints found, skipping endpoints page");
+    } else {
+        info!(
+            branch = %branch,
+            endpoints = inventory.len(),
+            "Generated API endpoints page"
+        );
+        pages.push(inventory.to_wiki_page(branch, commit_sha, first_order));
+    }
+
+    let data_model = DataModel::from_chunks(&chunks);
+    if data_model.is_empty() {
+        debug!(branch = %branch, "No database schema found, skipping data model page");
+    } else {
+        info!(
+            branch = %branch,
+            tables = data_model.tables.len(),
+            relationships = data_model.relationships.len(),
+            "Generated data model page"
+        );
+        let order = first_order + pages.len() as u32;
+        pages.push(data_model.to_wiki_page(branch, commit_sha, order));
+    }
+
+    let env_vars = EnvVarReference::from_chunks(&chunks);
+    if env_vars.is_empty() {
+        debug!(branch = %branch, "No environment variable reads found, skipping env vars page");
+    } else {
+        info!(
+            branch = %branch,
+            variables = env_vars.len(),
+            "Generated environment variables page"
+        );
+        let order = first_order + pages.len() as u32;
+        pages.push(env_vars.to_wiki_page(branch, commit_sha, order));
+    }
+
+    let coverage = TestCoverageMap::from_chunks(&chunks);
+    if coverage.is_empty() {
+        debug!(branch = %branch, "No source modules found, skipping test coverage page");
+    } else {
+        info!(
+            branch = %branch,
+            modules = coverage.modules.len(),
+            untested = coverage.untested_count(),
+            "Generated test coverage page"
+        );
+        let order = first_order + pages.len() as u32;
+        pages.push(coverage.to_wiki_page(branch, commit_sha, order));
+    }
+
+    for page in &pages {
+        store.insert_wiki_page(page)?;
+    }
+
+    Ok(pages)
+}
+
+/// Build and store the onboarding page for `branch` from the pages already
+/// stored for it. Call this after all other pages have been generated.
+pub fn generate_onboarding_page(
+    store: &VectorStore,
+    branch: &str,
+    commit_sha: &str,
+    order: u32,
+) -> WikiResult<Option<WikiPage>> {
+    let pages = store.get_branch_pages(branch)?;
+    let chunks = store.get_branch_chunks(branch)?;
+
+    let path = OnboardingPath::from_pages(&pages, &chunks);
+    if path.is_empty() {
+        debug!(branch = %branch, "No pages to read, skipping onboarding page");
+        return Ok(None);
+    }
+
+    info!(
+        branch = %branch,
+        steps = path.steps.len(),
+        minutes = path.total_minutes(),
+        "Generated onboarding page"
+    );
+    let page = path.to_wiki_page(branch, commit_sha, order);
+    store.insert_wiki_page(&page)?;
+
+    Ok(Some(page))
+}
@@ -0,0 +1,291 @@
+//! Limits on how much of a wiki plan gets generated
+//!
+//! Plans for large repositories can run to 50+ pages. The limits keep the
+//! most important pages and return the rest as [`SkippedPage`]s, which are
+//! recorded in the wiki structure so they can be generated on demand.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{PagePlan, WikiPlan};
+use crate::domain::wiki_page::{Importance, SkipReason, SkippedPage};
+use crate::error::WikiError;
+
+const DEFAULT_MAX_PAGES: usize = 30;
+const DEFAULT_MAX_PAGES_PER_SECTION: usize = 8;
+
+/// Page limits applied to a wiki plan before its pages are generated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageLimits {
+    /// Maximum number of pages generated in one run
+    pub max_pages: usize,
+
+    /// Maximum number of pages generated for one section
+    pub max_pages_per_section: usize,
+
+    /// Pages planned with a lower importance are skipped
+    pub min_importance: Importance,
+}
+
+impl Default for PageLimits {
+    fn default() -> Self {
+        Self {
+            max_pages: DEFAULT_MAX_PAGES,
+            max_pages_per_section: DEFAULT_MAX_PAGES_PER_SECTION,
+            min_importance: Importance::Low,
+        }
+    }
+}
+
+impl PageLimits {
+    /// Trim `plan` to the limits and return the pages left out
+    ///
+    /// When a limit is reached, more important pages are kept first; pages
+    /// of equal importance keep their plan order. The kept pages stay in
+    /// plan order and sections only list kept pages.
+    pub fn apply(&self, plan: &mut WikiPlan) -> Vec<SkippedPage> {
+        let importances: Vec<Importance> = plan.pages.iter().map(planned_importance).collect();
+
+        let mut ranked: Vec<usize> = (0..plan.pages.len()).collect();
+        ranked.sort_by_key(|&i| std::cmp::Reverse(rank(importances[i])));
+
+        let mut reasons = vec![None; plan.pages.len()];
+        let mut per_section: HashMap<&str, usize> = HashMap::new();
+        let mut kept = 0;
+        for i in ranked {
+            let section_pages = per_section
+                .entry(plan.pages[i].section_id.as_str())
+                .or_default();
+            reasons[i] = if rank(importances[i]) < rank(self.min_importance) {
+                Some(SkipReason::BelowMinImportance)
+            } else if *section_pages >= self.max_pages_per_section {
+                Some(SkipReason::SectionLimit)
+            } else if kept >= self.max_pages {
+                Some(SkipReason::PageLimit)
+            } else {
+                *section_pages += 1;
+                kept += 1;
+                None
+            };
+        }
+
+        let mut skipped = Vec::new();
+        for ((page, importance), reason) in std::mem::take(&mut plan.pages)
+            .into_iter()
+            .zip(importances)
+            .zip(reasons)
+        {
+            match reason {
+                Some(reason) => skipped.push(skipped_page(page, importance, reason)),
+                None => plan.pages.push(page),
+            }
+        }
+
+        for section in &mut plan.sections {
+            section
+                .page_ids
+                .retain(|id| !skipped.iter().any(|page| &page.id == id));
+        }
+
+        skipped
+    }
+}
+
+/// Record a page whose generation failed so it can be retried on demand
+pub fn failed_page(page: &PagePlan, error: &WikiError) -> SkippedPage {
+    SkippedPage {
+        error: Some(error.to_string()),
+        ..skipped_page(
+            page.clone(),
+            planned_importance(page),
+            SkipReason::GenerationFailed,
+        )
+    }
+}
+
+/// The plan of a skipped page, for generating it later
+pub fn page_plan(page: &SkippedPage) -> PagePlan {
+    PagePlan {
+        id: page.id.clone(),
+        title: page.title.clone(),
+        section_id: page.section_id.clone(),
+        importance: page.importance.as_str().to_string(),
+        file_paths: page.file_paths.clone(),
+        related_pages: page.related_pages.clone(),
+        description: page.description.clone(),
+    }
+}
+
+fn skipped_page(page: PagePlan, importance: Importance, reason: SkipReason) -> SkippedPage {
+    SkippedPage {
+        id: page.id,
+        title: page.title,
+        section_id: page.section_id,
+        importance,
+        file_paths: page.file_paths,
+        related_pages: page.related_pages,
+        description: page.description,
+        reason,
+        error: None,
+    }
+}
+
+fn planned_importance(page: &PagePlan) -> Importance {
+    Importance::parse(&page.importance).unwrap_or_default()
+}
+
+fn rank(importance: Importance) -> u8 {
+    match importance {
+        Importance::High => 2,
+        Importance::Medium => 1,
+        Importance::Low => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::SectionPlan;
+
+    fn page(id: &str, section_id: &str, importance: &str) -> PagePlan {
+        PagePlan {
+            id: id.to_string(),
+            title: id.to_string(),
+            section_id: section_id.to_string(),
+            importance: importance.to_string(),
+            file_paths: vec![format!("src/{}.rs", id)],
+            related_pages: vec![],
+            description: String::new(),
+        }
+    }
+
+    fn plan(pages: Vec<PagePlan>) -> WikiPlan {
+        let mut sections: Vec<SectionPlan> = Vec::new();
+        for page in &pages {
+            match sections.iter_mut().find(|s| s.id == page.section_id) {
+                Some(section) => section.page_ids.push(page.id.clone()),
+                None => sections.push(SectionPlan {
+                    id: page.section_id.clone(),
+                    title: page.section_id.clone(),
+                    description: String::new(),
+                    page_ids: vec![page.id.clone()],
+                }),
+            }
+        }
+        WikiPlan {
+            title: "Wiki".to_string(),
+            description: String::new(),
+            sections,
+            pages,
+        }
+    }
+
+    fn ids(pages: &[PagePlan]) -> Vec<&str> {
+        pages.iter().map(|p| p.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_default_limits_keep_small_plan() {
+        let mut wiki = plan(vec![page("a", "core", "high"), page("b", "core", "low")]);
+
+        let skipped = PageLimits::default().apply(&mut wiki);
+
+        assert!(skipped.is_empty());
+        assert_eq!(ids(&wiki.pages), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_min_importance() {
+        let mut wiki = plan(vec![
+            page("a", "core", "low"),
+            page("b", "core", "medium"),
+            page("c", "core", "unknown"),
+        ]);
+        let limits = PageLimits {
+            min_importance: Importance::Medium,
+            ..PageLimits::default()
+        };
+
+        let skipped = limits.apply(&mut wiki);
+
+        assert_eq!(ids(&wiki.pages), vec!["b", "c"]);
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].id, "a");
+        assert_eq!(skipped[0].reason, SkipReason::BelowMinImportance);
+        assert_eq!(wiki.sections[0].page_ids, vec!["b", "c"]);
+    }
+
+    #[test]
+    fn test_section_limit_prefers_important_pages() {
+        let mut wiki = plan(vec![
+            page("a", "core", "low"),
+            page("b", "core", "high"),
+            page("c", "core", "medium"),
+            page("d", "guides", "low"),
+        ]);
+        let limits = PageLimits {
+            max_pages_per_section: 2,
+            ..PageLimits::default()
+        };
+
+        let skipped = limits.apply(&mut wiki);
+
+        assert_eq!(ids(&wiki.pages), vec!["b", "c", "d"]);
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].id, "a");
+        assert_eq!(skipped[0].reason, SkipReason::SectionLimit);
+    }
+
+    #[test]
+    fn test_page_limit_keeps_plan_order() {
+        let mut wiki = plan(vec![
+            page("a", "core", "medium"),
+            page("b", "guides", "high"),
+            page("c", "api", "medium"),
+            page("d", "api", "high"),
+        ]);
+        let limits = PageLimits {
+            max_pages: 3,
+            ..PageLimits::default()
+        };
+
+        let skipped = limits.apply(&mut wiki);
+
+        assert_eq!(ids(&wiki.pages), vec!["a", "b", "d"]);
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].id, "c");
+        assert_eq!(skipped[0].reason, SkipReason::PageLimit);
+        assert_eq!(wiki.sections[2].page_ids, vec!["d"]);
+    }
+
+    #[test]
+    fn test_page_plan_roundtrip() {
+        let mut wiki = plan(vec![page("a", "core", "high")]);
+        let limits = PageLimits {
+            max_pages: 0,
+            ..PageLimits::default()
+        };
+
+        let skipped = limits.apply(&mut wiki);
+        let restored = page_plan(&skipped[0]);
+
+        assert_eq!(restored.id, "a");
+        assert_eq!(restored.section_id, "core");
+        assert_eq!(restored.importance, "high");
+        assert_eq!(restored.file_paths, vec!["src/a.rs"]);
+    }
+
+    #[test]
+    fn test_failed_page_keeps_error() {
+        let plan = page("a", "core", "medium");
+        let error = WikiError::GenerationFailed("empty response".to_string());
+
+        let failed = failed_page(&plan, &error);
+
+        assert_eq!(failed.reason, SkipReason::GenerationFailed);
+        assert_eq!(failed.importance, Importance::Medium);
+        assert_eq!(failed.error.as_deref(), Some(&*error.to_string()));
+        assert_eq!(page_plan(&failed).file_paths, plan.file_paths);
+    }
+}
@@ -64,8 +64,14 @@ impl MermaidValidator {
             ));
         }
 
-        let open_braces = trimmed.matches('{').count();
-        let close_braces = trimmed.matches('}').count();
+        // ER relationship cardinalities (`||--o{`, `}|..|{`) use unpaired braces
+        let braced = if first_line.starts_with("erDiagram") {
+            Self::strip_er_cardinalities(trimmed)
+        } else {
+            trimmed.to_string()
+        };
+        let open_braces = braced.matches('{').count();
+        let close_braces = braced.matches('}').count();
         if open_braces != close_braces {
             errors.push(format!(
                 "Unbalanced braces: {} '{{' vs {} '}}'",
@@ -108,6 +114,12 @@ impl MermaidValidator {
         }
     }
 
+    fn strip_er_cardinalities(diagram: &str) -> String {
+        ["o{", "|{", "}o", "}|"]
+            .iter()
+            .fold(diagram.to_string(), |acc, marker| acc.replace(marker, ""))
+    }
+
     pub fn fix(diagram: &str) -> String {
         let mut fixed = diagram.to_string();
 
@@ -283,6 +295,13 @@ mod tests {
         assert!(result.errors.iter().any(|e| e.contains("Unbalanced")));
     }
 
+    #[test]
+    fn test_validate_er_diagram_cardinalities() {
+        let diagram = "erDiagram\n    users {\n        TEXT id PK\n    }\n    users ||--o{ posts : \"user_id\"\n    posts }|..|{ tags : \"tag_id\"";
+        let result = MermaidValidator::validate(diagram);
+        assert!(result.is_valid, "{:?}", result.errors);
+    }
+
     #[test]
     fn test_fix_graph_lr() {
         let diagram = "graph LR\n    A --> B";
@@ -1,11 +1,18 @@
 //! Wiki page generator using AI
 
 pub mod analyzer;
+pub mod extracted;
+pub mod limits;
 pub mod mermaid;
 pub mod prompts;
+mod streaming;
+pub mod throttle;
 
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures::StreamExt;
 
 use regex::Regex;
 use serde::{Deserialize, Serialize};
@@ -13,6 +20,7 @@ use serde_json::Value;
 use tokio::sync::broadcast;
 use tracing::{debug, error, info, warn};
 
+use crate::chunker::TextSplitter;
 use crate::domain::index_status::IndexProgress;
 use crate::domain::wiki_page::{
     Importance, PageType, SourceCitation, WikiPage, WikiStructure, WikiTree,
@@ -23,9 +31,15 @@ use crate::openrouter::{ChatMessage, OpenRouterClient};
 use crate::vector_store::VectorStore;
 
 use analyzer::{FileImportance, ProjectAnalyzer, ProjectStructure};
+use limits::PageLimits;
+use throttle::{PageThrottle, DEFAULT_PAGE_CONCURRENCY};
 
 const MAX_CONTENT_TOKENS: usize = 4000;
 const MAX_FILE_CONTENT_TOKENS: usize = 3000;
+/// File context for the end-of-run retry of failed pages, smaller in case
+/// the size of the request was what failed
+const RETRY_FILE_CONTENT_TOKENS: usize = MAX_FILE_CONTENT_TOKENS / 2;
+const FAILED_PAGE_RETRY_BACKOFF: Duration = Duration::from_secs(5);
 const MAX_STRUCTURE_RETRIES: u32 = 3;
 const TEMPERATURE_STRUCTURE_LOW: f32 = 0.3;
 const TEMPERATURE_CONTENT_CREATIVE: f32 = 0.7;
@@ -60,12 +74,97 @@ pub struct PagePlan {
     pub description: String,
 }
 
+/// Progress across the pages of one run, which are generated concurrently
+///
+/// Every event carries the number of finished pages and the titles of the
+/// pages in flight, so progress only moves forward however pages interleave.
+struct GenerationTracker<'a> {
+    tx: Option<&'a broadcast::Sender<IndexProgress>>,
+    total: u32,
+    state: Mutex<TrackerState>,
+}
+
+#[derive(Default)]
+struct TrackerState {
+    completed: u32,
+    in_flight: Vec<String>,
+}
+
+impl<'a> GenerationTracker<'a> {
+    fn new(tx: Option<&'a broadcast::Sender<IndexProgress>>, total: u32) -> Self {
+        Self {
+            tx,
+            total,
+            state: Mutex::new(TrackerState::default()),
+        }
+    }
+
+    fn started(&self, page: &str) {
+        let mut state = self.state.lock().unwrap();
+        state.in_flight.push(page.to_string());
+        self.send_generating(&state);
+    }
+
+    /// Failed pages don't count as completed; they may still succeed on
+    /// the retry at the end of the run
+    fn finished(&self, page: &str, generated: bool) {
+        let mut state = self.state.lock().unwrap();
+        if let Some(position) = state.in_flight.iter().position(|p| p == page) {
+            state.in_flight.remove(position);
+        }
+        if generated {
+            state.completed += 1;
+        }
+        self.send_generating(&state);
+    }
+
+    fn send_generating(&self, state: &TrackerState) {
+        if let Some(tx) = self.tx {
+            let _ = tx.send(IndexProgress::GeneratingWiki {
+                current: state.completed,
+                total: self.total,
+                current_page: state.in_flight.join(", "),
+            });
+        }
+    }
+
+    fn streaming(&self, page: &str, characters: u32) {
+        if let Some(tx) = self.tx {
+            let completed = self.state.lock().unwrap().completed;
+            let _ = tx.send(IndexProgress::StreamingPage {
+                current: completed,
+                total: self.total,
+                current_page: page.to_string(),
+                characters,
+            });
+        }
+    }
+}
+
+/// Reports how much of the page being generated has streamed in
+struct PageProgress<'a> {
+    tracker: Option<&'a GenerationTracker<'a>>,
+    page: &'a str,
+}
+
+impl PageProgress<'_> {
+    fn characters(&self, characters: u32) {
+        if let Some(tracker) = self.tracker {
+            tracker.streaming(self.page, characters);
+        }
+    }
+}
+
 pub struct WikiGenerator {
     openrouter: Arc<OpenRouterClient>,
     vector_store: Arc<VectorStore>,
     chat_model: String,
     max_chunk_tokens: usize,
     chunk_overlap: usize,
+    splitter: TextSplitter,
+    page_limits: PageLimits,
+    page_concurrency: usize,
+    throttle: PageThrottle,
 }
 
 impl WikiGenerator {
@@ -82,9 +181,29 @@ impl WikiGenerator {
             chat_model,
             max_chunk_tokens,
             chunk_overlap,
+            splitter: TextSplitter::new(max_chunk_tokens, chunk_overlap),
+            page_limits: PageLimits::default(),
+            page_concurrency: DEFAULT_PAGE_CONCURRENCY,
+            throttle: PageThrottle::new(DEFAULT_PAGE_CONCURRENCY),
         }
     }
 
+    /// Limit how many planned pages advanced generation produces
+    pub fn with_page_limits(mut self, page_limits: PageLimits) -> Self {
+        self.page_limits = page_limits;
+        self
+    }
+
+    /// Set how many pages advanced generation produces at once
+    ///
+    /// Rate-limited requests lower the limit for the rest of the run.
+    pub fn with_page_concurrency(mut self, page_concurrency: usize) -> Self {
+        let page_concurrency = page_concurrency.max(1);
+        self.page_concurrency = page_concurrency;
+        self.throttle = PageThrottle::new(page_concurrency);
+        self
+    }
+
     pub async fn generate_wiki(
         &self,
         root_path: &Path,
@@ -98,16 +217,6 @@ impl WikiGenerator {
             project_name, branch
         );
 
-        let send_progress = |current: u32, total: u32, page: &str| {
-            if let Some(ref tx) = progress_tx {
-                let _ = tx.send(IndexProgress::GeneratingWiki {
-                    current,
-                    total,
-                    current_page: page.to_string(),
-                });
-            }
-        };
-
         let analyzer = ProjectAnalyzer::new(self.max_chunk_tokens, self.chunk_overlap);
         let structure = analyzer.analyze(root_path, project_name).map_err(|e| {
             WikiError::GenerationFailed(format!("Failed to analyze project: {}", e))
@@ -117,22 +226,31 @@ impl WikiGenerator {
         let top_modules = analyzer.get_top_modules(&structure, 10);
 
         let total_pages = 1 + top_modules.len() + critical_files.len().min(10);
-        let mut current_page = 0u32;
+        let tracker = GenerationTracker::new(progress_tx.as_ref(), total_pages as u32);
 
-        send_progress(current_page, total_pages as u32, "overview");
+        tracker.started("overview");
+        let progress = PageProgress {
+            tracker: Some(&tracker),
+            page: "overview",
+        };
         let overview = self
-            .generate_overview(&structure, branch, commit_sha)
+            .generate_overview(&structure, branch, commit_sha, &progress)
             .await?;
         self.vector_store.insert_wiki_page(&overview)?;
-        current_page += 1;
+        tracker.finished("overview", true);
 
         let mut module_pages = Vec::new();
         for module in top_modules {
-            send_progress(current_page, total_pages as u32, &module.name);
-            match self
-                .generate_module_page(root_path, module, branch, commit_sha)
-                .await
-            {
+            tracker.started(&module.name);
+            let progress = PageProgress {
+                tracker: Some(&tracker),
+                page: &module.name,
+            };
+            let result = self
+                .generate_module_page(root_path, module, branch, commit_sha, &progress)
+                .await;
+            tracker.finished(&module.name, result.is_ok());
+            match result {
                 Ok(page) => {
                     self.vector_store.insert_wiki_page(&page)?;
                     module_pages.push(page);
@@ -144,16 +262,20 @@ impl WikiGenerator {
                     );
                 }
             }
-            current_page += 1;
         }
 
         let mut file_pages = Vec::new();
         for key_file in critical_files.iter().take(10) {
-            send_progress(current_page, total_pages as u32, &key_file.name);
-            match self
-                .generate_file_page(root_path, key_file, branch, commit_sha)
-                .await
-            {
+            tracker.started(&key_file.name);
+            let progress = PageProgress {
+                tracker: Some(&tracker),
+                page: &key_file.name,
+            };
+            let result = self
+                .generate_file_page(root_path, key_file, branch, commit_sha, &progress)
+                .await;
+            tracker.finished(&key_file.name, result.is_ok());
+            match result {
                 Ok(page) => {
                     self.vector_store.insert_wiki_page(&page)?;
                     file_pages.push(page);
@@ -165,16 +287,35 @@ impl WikiGenerator {
                     );
                 }
             }
-            current_page += 1;
         }
 
-        let wiki_structure =
-            self.build_wiki_structure(branch, &overview, &module_pages, &file_pages);
+        let mut extracted_pages = extracted::generate_pages(
+            &self.vector_store,
+            branch,
+            commit_sha,
+            (1 + module_pages.len() + file_pages.len()) as u32,
+        )?;
+        let onboarding_order =
+            (1 + module_pages.len() + file_pages.len() + extracted_pages.len()) as u32;
+        extracted_pages.extend(extracted::generate_onboarding_page(
+            &self.vector_store,
+            branch,
+            commit_sha,
+            onboarding_order,
+        )?);
+
+        let wiki_structure = self.build_wiki_structure(
+            branch,
+            &overview,
+            &module_pages,
+            &file_pages,
+            &extracted_pages,
+        );
         self.vector_store.save_wiki_structure(&wiki_structure)?;
 
         info!(
             "Wiki generation complete: {} pages created",
-            1 + module_pages.len() + file_pages.len()
+            1 + module_pages.len() + file_pages.len() + extracted_pages.len()
         );
 
         Ok(wiki_structure)
@@ -185,6 +326,7 @@ impl WikiGenerator {
         structure: &ProjectStructure,
         branch: &str,
         commit_sha: &str,
+        progress: &PageProgress<'_>,
     ) -> WikiResult<WikiPage> {
         debug!("Generating overview for '{}'", structure.name);
 
@@ -220,15 +362,7 @@ impl WikiGenerator {
             ChatMessage::user(prompt),
         ];
 
-        let content = self
-            .openrouter
-            .chat_completion(
-                messages,
-                &self.chat_model,
-                Some(TEMPERATURE_CONTENT_CREATIVE),
-                Some(4000),
-            )
-            .await?;
+        let content = self.stream_page_content(messages, 4000, progress).await?;
 
         let content = self.validate_and_fix_mermaid(&content).await;
 
@@ -251,6 +385,7 @@ impl WikiGenerator {
         module: &analyzer::ModuleInfo,
         branch: &str,
         commit_sha: &str,
+        progress: &PageProgress<'_>,
     ) -> WikiResult<WikiPage> {
         debug!("Generating page for module '{}'", module.name);
 
@@ -266,7 +401,7 @@ impl WikiGenerator {
         for file_path in module.key_files.iter().take(3) {
             let full_path = root_path.join(file_path);
             if let Ok(content) = std::fs::read_to_string(&full_path) {
-                let truncated = Self::truncate_content(&content, MAX_CONTENT_TOKENS / 3);
+                let truncated = self.splitter.truncate(&content, MAX_CONTENT_TOKENS / 3);
                 code_samples.push_str(&format!("### {}\n```\n{}\n```\n\n", file_path, truncated));
             }
         }
@@ -278,15 +413,7 @@ impl WikiGenerator {
             ChatMessage::user(prompt),
         ];
 
-        let content = self
-            .openrouter
-            .chat_completion(
-                messages,
-                &self.chat_model,
-                Some(TEMPERATURE_CONTENT_CREATIVE),
-                Some(3000),
-            )
-            .await?;
+        let content = self.stream_page_content(messages, 3000, progress).await?;
 
         let content = self.validate_and_fix_mermaid(&content).await;
 
@@ -311,6 +438,7 @@ impl WikiGenerator {
         key_file: &analyzer::KeyFile,
         branch: &str,
         commit_sha: &str,
+        progress: &PageProgress<'_>,
     ) -> WikiResult<WikiPage> {
         debug!("Generating page for file '{}'", key_file.name);
 
@@ -319,7 +447,7 @@ impl WikiGenerator {
             WikiError::GenerationFailed(format!("Failed to read file {}: {}", key_file.path, e))
         })?;
 
-        let truncated = Self::truncate_content(&content, MAX_CONTENT_TOKENS);
+        let truncated = self.splitter.truncate(&content, MAX_CONTENT_TOKENS);
         let language = key_file.language.as_deref().unwrap_or("text");
 
         let prompt = prompts::file_prompt(&key_file.name, &key_file.path, &truncated, language);
@@ -329,15 +457,7 @@ impl WikiGenerator {
             ChatMessage::user(prompt),
         ];
 
-        let generated = self
-            .openrouter
-            .chat_completion(
-                messages,
-                &self.chat_model,
-                Some(TEMPERATURE_CONTENT_CREATIVE),
-                Some(3000),
-            )
-            .await?;
+        let generated = self.stream_page_content(messages, 3000, progress).await?;
 
         let generated = self.validate_and_fix_mermaid(&generated).await;
 
@@ -357,6 +477,37 @@ impl WikiGenerator {
         ))
     }
 
+    /// Generate page content over a streamed completion, resuming when the
+    /// stream drops so a slow page doesn't fail on a request timeout
+    async fn stream_page_content(
+        &self,
+        messages: Vec<ChatMessage>,
+        max_tokens: u32,
+        progress: &PageProgress<'_>,
+    ) -> WikiResult<String> {
+        streaming::stream_with_resume(
+            messages,
+            |messages| async move {
+                self.throttle.wait_for_pause().await;
+                let stream = self
+                    .openrouter
+                    .chat_completion_stream(
+                        messages,
+                        &self.chat_model,
+                        Some(TEMPERATURE_CONTENT_CREATIVE),
+                        Some(max_tokens),
+                    )
+                    .await;
+                if let Err(WikiError::RateLimited { retry_after }) = &stream {
+                    self.throttle.rate_limited(*retry_after);
+                }
+                stream
+            },
+            &|characters| progress.characters(characters),
+        )
+        .await
+    }
+
     async fn validate_and_fix_mermaid(&self, content: &str) -> String {
         let fixed = mermaid::MermaidValidator::strip_invalid_diagrams(content);
 
@@ -373,6 +524,7 @@ impl WikiGenerator {
         overview: &WikiPage,
         module_pages: &[WikiPage],
         file_pages: &[WikiPage],
+        extracted_pages: &[WikiPage],
     ) -> WikiStructure {
         let mut root = WikiTree::new(
             overview.slug.clone(),
@@ -401,6 +553,16 @@ impl WikiGenerator {
             root.add_child(node);
         }
 
+        for page in extracted_pages {
+            let node = WikiTree::new(
+                page.slug.clone(),
+                page.title.clone(),
+                page.page_type,
+                page.order,
+            );
+            root.add_child(node);
+        }
+
         WikiStructure::new(branch.to_string(), root)
     }
 
@@ -422,16 +584,6 @@ impl WikiGenerator {
         }
     }
 
-    fn truncate_content(content: &str, max_chars: usize) -> String {
-        let approx_chars = max_chars * 4;
-        if content.len() <= approx_chars {
-            content.to_string()
-        } else {
-            let truncated: String = content.chars().take(approx_chars).collect();
-            format!("{}\n\n... (truncated)", truncated)
-        }
-    }
-
     pub async fn generate_wiki_advanced(
         &self,
         root_path: &Path,
@@ -480,7 +632,7 @@ impl WikiGenerator {
             .generate_wiki_structure(project_name, &file_tree, &readme, mode)
             .await;
 
-        let wiki_plan = match wiki_plan_result {
+        let mut wiki_plan = match wiki_plan_result {
             Ok(plan) => {
                 info!(
                     branch = %branch,
@@ -498,6 +650,16 @@ impl WikiGenerator {
             }
         };
 
+        let skipped_pages = self.page_limits.apply(&mut wiki_plan);
+        if !skipped_pages.is_empty() {
+            info!(
+                branch = %branch,
+                kept = wiki_plan.pages.len(),
+                skipped = skipped_pages.len(),
+                "Wiki plan trimmed to page limits"
+            );
+        }
+
         let total_pages = wiki_plan.pages.len() as u32;
         let mut all_pages = Vec::new();
         let mut sections: Vec<WikiSection> = Vec::new();
@@ -520,21 +682,36 @@ impl WikiGenerator {
             sections.push(section);
         }
 
-        info!(branch = %branch, total = total_pages, "Generating wiki pages...");
-        for (idx, page_plan) in wiki_plan.pages.iter().enumerate() {
-            send_progress(idx as u32, total_pages, &page_plan.title);
-            info!(
-                branch = %branch,
-                page = idx + 1,
-                total = total_pages,
-                title = %page_plan.title,
-                "Generating page"
-            );
+        info!(
+            branch = %branch,
+            total = total_pages,
+            concurrency = self.page_concurrency,
+            "Generating wiki pages..."
+        );
+        let tracker = GenerationTracker::new(progress_tx.as_ref(), total_pages);
+        let tracker = &tracker;
+        // `buffered` yields pages in plan order however they finish, so
+        // pages are saved in the same order on every run
+        let mut generated = futures::stream::iter(wiki_plan.pages.iter().enumerate())
+            .map(|(idx, page_plan)| async move {
+                let result = self
+                    .generate_planned_page(
+                        root_path,
+                        page_plan,
+                        branch,
+                        commit_sha,
+                        idx,
+                        MAX_FILE_CONTENT_TOKENS,
+                        tracker,
+                    )
+                    .await;
+                (idx, page_plan, result)
+            })
+            .buffered(self.page_concurrency);
 
-            match self
-                .generate_page_from_plan(root_path, page_plan, branch, commit_sha, idx as u32)
-                .await
-            {
+        let mut failed = Vec::new();
+        while let Some((idx, page_plan, result)) = generated.next().await {
+            match result {
                 Ok(page) => {
                     self.vector_store.insert_wiki_page(&page)?;
                     all_pages.push(page);
@@ -553,13 +730,79 @@ impl WikiGenerator {
                         total = total_pages,
                         title = %page_plan.title,
                         error = %e,
-                        "Failed to generate page"
+                        "Failed to generate page, retrying at the end of the run"
                     );
+                    failed.push((idx, page_plan));
+                }
+            }
+        }
+        drop(generated);
+
+        let mut failed_pages = Vec::new();
+        if !failed.is_empty() {
+            info!(
+                branch = %branch,
+                count = failed.len(),
+                "Retrying failed pages with a smaller context"
+            );
+            tokio::time::sleep(FAILED_PAGE_RETRY_BACKOFF).await;
+
+            for (idx, page_plan) in failed {
+                match self
+                    .generate_planned_page(
+                        root_path,
+                        page_plan,
+                        branch,
+                        commit_sha,
+                        idx,
+                        RETRY_FILE_CONTENT_TOKENS,
+                        tracker,
+                    )
+                    .await
+                {
+                    Ok(page) => {
+                        self.vector_store.insert_wiki_page(&page)?;
+                        all_pages.push(page);
+                        info!(
+                            branch = %branch,
+                            title = %page_plan.title,
+                            "Page generated on retry"
+                        );
+                    }
+                    Err(e) => {
+                        warn!(
+                            branch = %branch,
+                            title = %page_plan.title,
+                            error = %e,
+                            "Page failed again, recording it for manual retry"
+                        );
+                        failed_pages.push(limits::failed_page(page_plan, &e));
+                    }
                 }
             }
+            // Retried pages go back to their place in the plan
+            all_pages.sort_by_key(|page| page.order);
         }
 
-        let wiki_structure = self.build_wiki_structure_from_pages(branch, &all_pages, sections);
+        let extracted_pages = extracted::generate_pages(
+            &self.vector_store,
+            branch,
+            commit_sha,
+            all_pages.len() as u32,
+        )?;
+        all_pages.retain(|p| !extracted_pages.iter().any(|e| e.slug == p.slug));
+        all_pages.extend(extracted_pages);
+        let onboarding_order = all_pages.len() as u32;
+        all_pages.extend(extracted::generate_onboarding_page(
+            &self.vector_store,
+            branch,
+            commit_sha,
+            onboarding_order,
+        )?);
+
+        let mut wiki_structure = self.build_wiki_structure_from_pages(branch, &all_pages, sections);
+        wiki_structure.skipped_pages = skipped_pages;
+        wiki_structure.skipped_pages.extend(failed_pages);
         self.vector_store.save_wiki_structure(&wiki_structure)?;
 
         info!(
@@ -572,6 +815,177 @@ impl WikiGenerator {
         Ok(wiki_structure)
     }
 
+    /// Generate one page of a run once a generation slot is free
+    #[allow(clippy::too_many_arguments)]
+    async fn generate_planned_page(
+        &self,
+        root_path: &Path,
+        page_plan: &PagePlan,
+        branch: &str,
+        commit_sha: &str,
+        idx: usize,
+        file_content_tokens: usize,
+        tracker: &GenerationTracker<'_>,
+    ) -> WikiResult<WikiPage> {
+        let _slot = self.throttle.acquire().await;
+        tracker.started(&page_plan.title);
+        info!(
+            branch = %branch,
+            page = idx + 1,
+            total = tracker.total,
+            title = %page_plan.title,
+            "Generating page"
+        );
+
+        let progress = PageProgress {
+            tracker: Some(tracker),
+            page: &page_plan.title,
+        };
+        let result = self
+            .generate_page_from_plan(
+                root_path,
+                page_plan,
+                branch,
+                commit_sha,
+                idx as u32,
+                file_content_tokens,
+                &progress,
+            )
+            .await;
+        tracker.finished(&page_plan.title, result.is_ok());
+        result
+    }
+
+    /// Generate a page that the last run left out, either because of the
+    /// page limits or because its generation failed
+    ///
+    /// The page is added to the wiki tree and its section, and removed from
+    /// the structure's skipped pages.
+    pub async fn generate_skipped_page(
+        &self,
+        root_path: &Path,
+        branch: &str,
+        commit_sha: &str,
+        page_id: &str,
+    ) -> WikiResult<WikiPage> {
+        let mut structure = self
+            .vector_store
+            .get_wiki_structure(branch)?
+            .ok_or_else(|| WikiError::IndexNotFound {
+                branch: branch.to_string(),
+            })?;
+        let position = structure
+            .skipped_pages
+            .iter()
+            .position(|p| p.id == page_id)
+            .ok_or_else(|| WikiError::PageNotFound {
+                slug: page_id.to_string(),
+            })?;
+
+        let plan = limits::page_plan(&structure.skipped_pages[position]);
+        info!(branch = %branch, page = %plan.id, "Generating skipped page");
+
+        let order = structure.page_count;
+        let progress = PageProgress {
+            tracker: None,
+            page: &plan.title,
+        };
+        let page = self
+            .generate_page_from_plan(
+                root_path,
+                &plan,
+                branch,
+                commit_sha,
+                order,
+                MAX_FILE_CONTENT_TOKENS,
+                &progress,
+            )
+            .await?;
+        self.vector_store.insert_wiki_page(&page)?;
+
+        let mut sections = self.vector_store.get_wiki_sections(branch)?;
+        if let Some(section) = sections.iter_mut().find(|s| s.id == plan.section_id) {
+            if !section.page_slugs.contains(&page.slug) {
+                section.add_page(page.slug.clone());
+                self.vector_store.insert_wiki_section(section)?;
+            }
+        }
+
+        structure.skipped_pages.remove(position);
+        structure.root.add_child(WikiTree::new(
+            page.slug.clone(),
+            page.title.clone(),
+            page.page_type,
+            page.order,
+        ));
+        structure.page_count = structure.root.count() as u32;
+        structure.updated_at = chrono::Utc::now();
+        self.vector_store.save_wiki_structure(&structure)?;
+
+        Ok(page)
+    }
+
+    /// Whether `regenerate_page` can regenerate `page`, so callers that
+    /// regenerate in the background can refuse the request up front
+    pub fn check_regenerable(page: &WikiPage) -> WikiResult<()> {
+        regeneration_plan(page).map(|_| ())
+    }
+
+    /// Regenerate the page `slug` of `branch` from the current content of
+    /// the files it references, leaving the rest of the wiki as it is
+    ///
+    /// The page keeps its place in the wiki: its id, section, order and
+    /// related pages. Extracted pages are rebuilt by indexing instead.
+    pub async fn regenerate_page(
+        &self,
+        root_path: &Path,
+        branch: &str,
+        commit_sha: &str,
+        slug: &str,
+    ) -> WikiResult<WikiPage> {
+        let existing = self
+            .vector_store
+            .get_wiki_page_in_branch(slug, Some(branch))?
+            .ok_or_else(|| WikiError::PageNotFound {
+                slug: slug.to_string(),
+            })?;
+        let plan = regeneration_plan(&existing)?;
+        info!(
+            branch = %branch,
+            page = %slug,
+            files = plan.file_paths.len(),
+            "Regenerating page"
+        );
+
+        let progress = PageProgress {
+            tracker: None,
+            page: &plan.title,
+        };
+        let generated = self
+            .generate_page_from_plan(
+                root_path,
+                &plan,
+                branch,
+                commit_sha,
+                existing.order,
+                MAX_FILE_CONTENT_TOKENS,
+                &progress,
+            )
+            .await?;
+
+        let page = WikiPage {
+            id: existing.id,
+            page_type: existing.page_type,
+            parent_slug: existing.parent_slug,
+            section_id: existing.section_id,
+            created_at: existing.created_at,
+            ..generated
+        };
+        self.vector_store.insert_wiki_page(&page)?;
+
+        Ok(page)
+    }
+
     async fn generate_wiki_structure(
         &self,
         project_name: &str,
@@ -650,6 +1064,7 @@ impl WikiGenerator {
         )))
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn generate_page_from_plan(
         &self,
         root_path: &Path,
@@ -657,6 +1072,8 @@ impl WikiGenerator {
         branch: &str,
         commit_sha: &str,
         order: u32,
+        file_content_tokens: usize,
+        progress: &PageProgress<'_>,
     ) -> WikiResult<WikiPage> {
         debug!(
             title = %plan.title,
@@ -665,7 +1082,8 @@ impl WikiGenerator {
             "Generating page content"
         );
 
-        let file_contents = self.read_file_contents(root_path, &plan.file_paths);
+        let file_contents =
+            self.read_file_contents(root_path, &plan.file_paths, file_content_tokens);
 
         let prompt = prompts::page_content_prompt(
             &plan.title,
@@ -680,15 +1098,7 @@ impl WikiGenerator {
             ChatMessage::user(prompt),
         ];
 
-        let content = self
-            .openrouter
-            .chat_completion(
-                messages,
-                &self.chat_model,
-                Some(TEMPERATURE_CONTENT_CREATIVE),
-                Some(4000),
-            )
-            .await?;
+        let content = self.stream_page_content(messages, 4000, progress).await?;
 
         let content = self.validate_and_fix_mermaid(&content).await;
         let source_citations = Self::extract_source_citations(&content);
@@ -747,21 +1157,26 @@ impl WikiGenerator {
         for name in readme_names {
             let path = root_path.join(name);
             if let Ok(content) = std::fs::read_to_string(&path) {
-                return Self::truncate_content(&content, 2000);
+                return self.splitter.truncate(&content, 2000);
             }
         }
 
         "No README found.".to_string()
     }
 
-    fn read_file_contents(&self, root_path: &Path, file_paths: &[String]) -> String {
+    fn read_file_contents(
+        &self,
+        root_path: &Path,
+        file_paths: &[String],
+        max_tokens: usize,
+    ) -> String {
         let mut contents = String::new();
-        let per_file_limit = MAX_FILE_CONTENT_TOKENS / file_paths.len().max(1);
+        let per_file_limit = max_tokens / file_paths.len().max(1);
 
         for path in file_paths.iter().take(8) {
             let full_path = root_path.join(path);
             if let Ok(content) = std::fs::read_to_string(&full_path) {
-                let truncated = Self::truncate_content(&content, per_file_limit);
+                let truncated = self.splitter.truncate(&content, per_file_limit);
                 let extension = full_path.extension().and_then(|e| e.to_str()).unwrap_or("");
                 contents.push_str(&format!(
                     "### {}\n```{}\n{}\n```\n\n",
@@ -1091,6 +1506,55 @@ impl WikiGenerator {
     }
 }
 
+/// Plan to generate `page` again from the files it references
+fn regeneration_plan(page: &WikiPage) -> WikiResult<PagePlan> {
+    let not_regenerable = |reason: &str| WikiError::PageNotRegenerable {
+        slug: page.slug.clone(),
+        reason: reason.to_string(),
+    };
+    if extracted::is_extracted_page(&page.slug) {
+        return Err(not_regenerable(
+            "it is extracted from the index; re-index the branch to rebuild it",
+        ));
+    }
+    if page.file_paths.is_empty() {
+        return Err(not_regenerable("it references no source files"));
+    }
+
+    Ok(PagePlan {
+        id: page.slug.clone(),
+        title: page.title.clone(),
+        section_id: page.section_id.clone().unwrap_or_default(),
+        importance: page.importance.as_str().to_string(),
+        file_paths: page.file_paths.clone(),
+        related_pages: page.related_pages.clone(),
+        description: page_summary(&page.content).unwrap_or_else(|| page.title.clone()),
+    })
+}
+
+/// First paragraph of a page's markdown, which the page prompt uses as
+/// what the page should cover
+fn page_summary(content: &str) -> Option<String> {
+    const MAX_SUMMARY_CHARS: usize = 500;
+
+    let paragraph: Vec<&str> = content
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| line.is_empty() || line.starts_with('#'))
+        .take_while(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect();
+    if paragraph.is_empty() {
+        return None;
+    }
+    Some(
+        paragraph
+            .join(" ")
+            .chars()
+            .take(MAX_SUMMARY_CHARS)
+            .collect(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1111,17 +1575,6 @@ mod tests {
         assert_eq!(WikiGenerator::get_parent_slug("lib.rs"), None);
     }
 
-    #[test]
-    fn test_truncate_content() {
-        let short = "Hello";
-        assert_eq!(WikiGenerator::truncate_content(short, 100), "Hello");
-
-        let long = "x".repeat(10000);
-        let truncated = WikiGenerator::truncate_content(&long, 100);
-        assert!(truncated.contains("... (truncated)"));
-        assert!(truncated.len() < long.len());
-    }
-
     #[test]
     fn test_extract_source_citations() {
         let content = r#"
@@ -1151,6 +1604,52 @@ File only: [README.md]()
         assert_eq!(citations[2].end_line, Some(42));
     }
 
+    #[test]
+    fn test_regeneration_plan() {
+        let page = WikiPage::new_advanced(
+            "main".to_string(),
+            "auth".to_string(),
+            "Authentication".to_string(),
+            "# Authentication\n\nSessions are signed\nJWT tokens.\n\n## Flow\nLogin first."
+                .to_string(),
+            PageType::Module,
+            None,
+            3,
+            vec!["src/auth/jwt.rs".to_string()],
+            "abc123".to_string(),
+            Importance::High,
+            vec!["api".to_string()],
+            Some("core".to_string()),
+            Vec::new(),
+        );
+
+        let plan = regeneration_plan(&page).unwrap();
+        assert_eq!(plan.id, "auth");
+        assert_eq!(plan.section_id, "core");
+        assert_eq!(plan.importance, "high");
+        assert_eq!(plan.file_paths, vec!["src/auth/jwt.rs"]);
+        assert_eq!(plan.related_pages, vec!["api"]);
+        assert_eq!(plan.description, "Sessions are signed JWT tokens.");
+
+        let without_files = WikiPage {
+            file_paths: Vec::new(),
+            ..page.clone()
+        };
+        assert!(matches!(
+            regeneration_plan(&without_files),
+            Err(WikiError::PageNotRegenerable { .. })
+        ));
+        let extracted = WikiPage {
+            slug: crate::endpoints::ENDPOINTS_PAGE_SLUG.to_string(),
+            ..page
+        };
+        assert!(matches!(
+            regeneration_plan(&extracted),
+            Err(WikiError::PageNotRegenerable { .. })
+        ));
+        assert_eq!(page_summary("# Title only\n"), None);
+    }
+
     #[test]
     fn test_extract_source_citations_empty() {
         let content = "No citations here, just regular text.";
@@ -1291,4 +1790,37 @@ File only: [README.md]()
         assert_eq!(plan.pages.len(), 1);
         assert_eq!(plan.pages[0].file_paths, vec!["lib.rs"]);
     }
+
+    #[test]
+    fn test_generation_tracker_aggregates_pages_in_flight() {
+        let (tx, mut rx) = broadcast::channel(16);
+        let tracker = GenerationTracker::new(Some(&tx), 3);
+
+        tracker.started("Alpha");
+        tracker.started("Beta");
+        tracker.finished("Alpha", true);
+        tracker.streaming("Beta", 120);
+
+        let mut events = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            events.push(event);
+        }
+
+        assert!(matches!(
+            &events[1],
+            IndexProgress::GeneratingWiki { current: 0, total: 3, current_page } if current_page == "Alpha, Beta"
+        ));
+        assert!(matches!(
+            &events[2],
+            IndexProgress::GeneratingWiki { current: 1, current_page, .. } if current_page == "Beta"
+        ));
+        assert!(matches!(
+            &events[3],
+            IndexProgress::StreamingPage {
+                current: 1,
+                characters: 120,
+                ..
+            }
+        ));
+    }
 }
@@ -426,6 +426,11 @@ Return only the fixed Mermaid code."#
     )
 }
 
+/// Sent after a partial answer when a streamed page was cut off
+pub const CONTINUE_PROMPT: &str = r#"Your previous response was cut off. Continue exactly where it ends.
+Do not repeat any text that was already written, do not restart the page and do not add a preamble.
+Output only the remaining content."#;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,249 @@
+//! Streamed page generation with resume
+//!
+//! Pages are generated over a streamed completion so slow providers don't
+//! hit a request timeout. Received text is checkpointed at line boundaries;
+//! when the stream drops mid-page, generation resumes from the last
+//! checkpoint with a "continue" prompt instead of starting over.
+
+use std::future::Future;
+
+use futures::{Stream, StreamExt};
+use tracing::{debug, warn};
+
+use super::prompts;
+use crate::error::WikiResult;
+use crate::openrouter::ChatMessage;
+
+/// How many times a dropped stream is resumed before the page fails
+pub const MAX_STREAM_RESUMES: u32 = 3;
+
+/// Characters received between checkpoints and progress reports
+pub const CHECKPOINT_INTERVAL: usize = 500;
+
+/// Stream a completion to the end, resuming after disconnects
+///
+/// `open` starts a stream for the given messages; `on_progress` receives the
+/// number of characters written so far at every checkpoint and once at the
+/// end.
+pub async fn stream_with_resume<Open, Fut, S>(
+    messages: Vec<ChatMessage>,
+    mut open: Open,
+    on_progress: &(dyn Fn(u32) + Send + Sync),
+) -> WikiResult<String>
+where
+    Open: FnMut(Vec<ChatMessage>) -> Fut,
+    Fut: Future<Output = WikiResult<S>>,
+    S: Stream<Item = WikiResult<String>>,
+{
+    let mut content = String::new();
+    // Length of `content` up to the last line break seen at a checkpoint
+    let mut checkpoint = 0;
+    let mut next_checkpoint = CHECKPOINT_INTERVAL;
+    let mut resumes = 0;
+
+    loop {
+        let request = if content.is_empty() {
+            messages.clone()
+        } else {
+            continuation_messages(&messages, &content)
+        };
+
+        let error = match open(request).await {
+            Ok(stream) => {
+                let mut stream = std::pin::pin!(stream);
+                let mut failure = None;
+                while let Some(delta) = stream.next().await {
+                    match delta {
+                        Ok(delta) => {
+                            content.push_str(&delta);
+                            if content.len() >= next_checkpoint {
+                                if let Some(end) = content.rfind('\n') {
+                                    checkpoint = end + 1;
+                                }
+                                next_checkpoint = content.len() + CHECKPOINT_INTERVAL;
+                                debug!(
+                                    checkpoint = checkpoint,
+                                    received = content.len(),
+                                    "Streamed content checkpoint"
+                                );
+                                on_progress(char_count(&content));
+                            }
+                        }
+                        Err(e) => {
+                            failure = Some(e);
+                            break;
+                        }
+                    }
+                }
+                match failure {
+                    None => {
+                        on_progress(char_count(&content));
+                        return Ok(content);
+                    }
+                    Some(e) => e,
+                }
+            }
+            Err(e) => e,
+        };
+
+        if resumes >= MAX_STREAM_RESUMES {
+            return Err(error);
+        }
+        resumes += 1;
+
+        content.truncate(checkpoint);
+        next_checkpoint = content.len() + CHECKPOINT_INTERVAL;
+        warn!(
+            error = %error,
+            attempt = resumes,
+            max_attempts = MAX_STREAM_RESUMES,
+            kept_chars = content.len(),
+            "Completion stream interrupted, resuming from last checkpoint"
+        );
+    }
+}
+
+/// The original conversation followed by the partial answer and a request to
+/// carry on from where it ends
+fn continuation_messages(messages: &[ChatMessage], partial: &str) -> Vec<ChatMessage> {
+    let mut continued = messages.to_vec();
+    continued.push(ChatMessage::assistant(partial));
+    continued.push(ChatMessage::user(prompts::CONTINUE_PROMPT));
+    continued
+}
+
+fn char_count(content: &str) -> u32 {
+    content.chars().count() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::sync::Mutex;
+
+    use futures::stream;
+
+    use super::*;
+    use crate::error::WikiError;
+    use crate::openrouter::Role;
+
+    fn disconnect() -> WikiError {
+        WikiError::OpenRouterApi {
+            message: "stream closed".to_string(),
+            status_code: None,
+        }
+    }
+
+    type Attempt = Vec<WikiResult<String>>;
+
+    /// Serves one scripted stream per call and records the requests
+    struct Script {
+        attempts: Mutex<VecDeque<Attempt>>,
+        requests: Mutex<Vec<Vec<ChatMessage>>>,
+    }
+
+    impl Script {
+        fn new(attempts: Vec<Attempt>) -> Self {
+            Self {
+                attempts: Mutex::new(attempts.into()),
+                requests: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn open(
+            &self,
+            messages: Vec<ChatMessage>,
+        ) -> impl Future<Output = WikiResult<impl Stream<Item = WikiResult<String>>>> {
+            self.requests.lock().unwrap().push(messages);
+            let attempt = self
+                .attempts
+                .lock()
+                .unwrap()
+                .pop_front()
+                .unwrap_or_default();
+            async move { Ok(stream::iter(attempt)) }
+        }
+    }
+
+    fn prompt() -> Vec<ChatMessage> {
+        vec![ChatMessage::system("system"), ChatMessage::user("write")]
+    }
+
+    #[tokio::test]
+    async fn test_stream_accumulates_deltas() {
+        let script = Script::new(vec![vec![
+            Ok("# Title\n".to_string()),
+            Ok("Body".to_string()),
+        ]]);
+        let reported = Mutex::new(Vec::new());
+
+        let content = stream_with_resume(prompt(), |m| script.open(m), &|chars| {
+            reported.lock().unwrap().push(chars)
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(content, "# Title\nBody");
+        assert_eq!(script.requests.lock().unwrap().len(), 1);
+        assert_eq!(*reported.lock().unwrap(), vec![12]);
+    }
+
+    #[tokio::test]
+    async fn test_stream_resumes_from_checkpoint() {
+        let first_line = format!("{}\n", "a".repeat(CHECKPOINT_INTERVAL));
+        let script = Script::new(vec![
+            vec![
+                Ok(first_line.clone()),
+                Ok("half a li".to_string()),
+                Err(disconnect()),
+            ],
+            vec![Ok("second line\n".to_string())],
+        ]);
+
+        let content = stream_with_resume(prompt(), |m| script.open(m), &|_| {})
+            .await
+            .unwrap();
+
+        // The partial line after the checkpoint is dropped and regenerated
+        assert_eq!(content, format!("{}second line\n", first_line));
+
+        let requests = script.requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        let resumed = &requests[1];
+        assert_eq!(resumed.len(), 4);
+        assert_eq!(resumed[2].role, Role::Assistant);
+        assert_eq!(resumed[2].content, first_line);
+        assert_eq!(resumed[3].content, prompts::CONTINUE_PROMPT);
+    }
+
+    #[tokio::test]
+    async fn test_stream_restarts_without_checkpoint() {
+        let script = Script::new(vec![
+            vec![Ok("partial".to_string()), Err(disconnect())],
+            vec![Ok("complete".to_string())],
+        ]);
+
+        let content = stream_with_resume(prompt(), |m| script.open(m), &|_| {})
+            .await
+            .unwrap();
+
+        assert_eq!(content, "complete");
+        assert_eq!(script.requests.lock().unwrap()[1].len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_stream_gives_up_after_max_resumes() {
+        let attempts = (0..=MAX_STREAM_RESUMES)
+            .map(|_| vec![Err(disconnect())])
+            .collect();
+        let script = Script::new(attempts);
+
+        let result = stream_with_resume(prompt(), |m| script.open(m), &|_| {}).await;
+
+        assert!(matches!(result, Err(WikiError::OpenRouterApi { .. })));
+        assert_eq!(
+            script.requests.lock().unwrap().len(),
+            MAX_STREAM_RESUMES as usize + 1
+        );
+    }
+}
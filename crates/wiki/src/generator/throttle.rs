@@ -0,0 +1,184 @@
+//! Concurrency limit for page generation
+//!
+//! Bounds how many pages are generated at once. When the provider rate
+//! limits a request, new requests pause for a backoff and the limit drops by
+//! one (never below one) for the rest of the run.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::sync::{Semaphore, SemaphorePermit};
+use tokio::time::Instant;
+use tracing::warn;
+
+/// Pages generated at once unless configured otherwise
+pub const DEFAULT_PAGE_CONCURRENCY: usize = 3;
+
+const INITIAL_BACKOFF: Duration = Duration::from_secs(2);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+pub struct PageThrottle {
+    permits: Semaphore,
+    limit: AtomicUsize,
+    /// Permits to retire as pages finish, one per rate limit
+    pending_reductions: AtomicUsize,
+    paused_until: Mutex<Option<Instant>>,
+    backoff: Mutex<Duration>,
+}
+
+impl PageThrottle {
+    pub fn new(concurrency: usize) -> Self {
+        let concurrency = concurrency.max(1);
+        Self {
+            permits: Semaphore::new(concurrency),
+            limit: AtomicUsize::new(concurrency),
+            pending_reductions: AtomicUsize::new(0),
+            paused_until: Mutex::new(None),
+            backoff: Mutex::new(INITIAL_BACKOFF),
+        }
+    }
+
+    /// Pages that may currently be generated at once
+    pub fn limit(&self) -> usize {
+        self.limit.load(Ordering::SeqCst)
+    }
+
+    /// Wait for a free generation slot
+    pub async fn acquire(&self) -> PageSlot<'_> {
+        let permit = self
+            .permits
+            .acquire()
+            .await
+            .expect("page throttle semaphore is never closed");
+        PageSlot {
+            throttle: self,
+            permit: Some(permit),
+        }
+    }
+
+    /// Wait out a pause after a rate limit, if one is in effect
+    pub async fn wait_for_pause(&self) {
+        loop {
+            let paused_until = *self.paused_until.lock().unwrap();
+            match paused_until {
+                Some(until) if until > Instant::now() => tokio::time::sleep_until(until).await,
+                _ => return,
+            }
+        }
+    }
+
+    /// Record a rate-limited request: pause new requests and lower the limit
+    pub fn rate_limited(&self, retry_after: Option<u64>) {
+        let delay = match retry_after {
+            Some(secs) => Duration::from_secs(secs),
+            None => {
+                let mut backoff = self.backoff.lock().unwrap();
+                let delay = *backoff;
+                *backoff = (*backoff * 2).min(MAX_BACKOFF);
+                delay
+            }
+        }
+        .min(MAX_BACKOFF);
+
+        let until = Instant::now() + delay;
+        {
+            let mut paused_until = self.paused_until.lock().unwrap();
+            if paused_until.map_or(true, |current| current < until) {
+                *paused_until = Some(until);
+            }
+        }
+
+        let lowered = self
+            .limit
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |limit| {
+                (limit > 1).then(|| limit - 1)
+            });
+        if let Ok(previous) = lowered {
+            self.pending_reductions.fetch_add(1, Ordering::SeqCst);
+            warn!(
+                pause_ms = delay.as_millis() as u64,
+                concurrency = previous - 1,
+                "Rate limited, pausing and lowering page concurrency"
+            );
+        } else {
+            warn!(
+                pause_ms = delay.as_millis() as u64,
+                "Rate limited, pausing page generation"
+            );
+        }
+    }
+}
+
+/// A page generation slot, handed back to the throttle on drop
+pub struct PageSlot<'a> {
+    throttle: &'a PageThrottle,
+    permit: Option<SemaphorePermit<'a>>,
+}
+
+impl Drop for PageSlot<'_> {
+    fn drop(&mut self) {
+        if let Some(permit) = self.permit.take() {
+            let retire = self
+                .throttle
+                .pending_reductions
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok();
+            if retire {
+                permit.forget();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_slots_are_bounded() {
+        let throttle = PageThrottle::new(2);
+
+        let first = throttle.acquire().await;
+        let _second = throttle.acquire().await;
+        assert_eq!(throttle.permits.available_permits(), 0);
+
+        drop(first);
+        assert_eq!(throttle.permits.available_permits(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_lowers_concurrency() {
+        let throttle = PageThrottle::new(3);
+        let slot = throttle.acquire().await;
+
+        throttle.rate_limited(Some(0));
+        assert_eq!(throttle.limit(), 2);
+
+        // The slot is retired instead of returned
+        drop(slot);
+        assert_eq!(throttle.permits.available_permits(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_concurrency_never_drops_below_one() {
+        let throttle = PageThrottle::new(1);
+
+        throttle.rate_limited(Some(0));
+        assert_eq!(throttle.limit(), 1);
+
+        drop(throttle.acquire().await);
+        assert_eq!(throttle.permits.available_permits(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_pauses_requests() {
+        let throttle = PageThrottle::new(2);
+
+        throttle.rate_limited(Some(1));
+        let started = Instant::now();
+        throttle.wait_for_pause().await;
+
+        assert!(started.elapsed() >= Duration::from_millis(900));
+    }
+}
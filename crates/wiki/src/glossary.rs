@@ -0,0 +1,261 @@
+//! Project glossary for query expansion
+//!
+//! Teams have their own names for parts of the code ("Flux" for the event
+//! bus). A query using such a name embeds far from the code it means, so
+//! queries are expanded with the synonyms of the glossary terms they mention
+//! before they are embedded. Terms come from `.opencode-studio/glossary.json`,
+//! a map of term to synonyms, and from aliases spelled out in wiki pages,
+//! such as "Flux (also known as the event bus)".
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use regex::Regex;
+use tracing::{debug, warn};
+
+use crate::error::WikiResult;
+use crate::vector_store::VectorStore;
+
+/// Name of the glossary file, next to the wiki database
+pub const GLOSSARY_FILE: &str = "glossary.json";
+
+/// "Term (also known as alias)" and its variants
+fn alias_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"(?:\*\*|`)?([A-Z][\w.-]*(?: [A-Z][\w.-]*){0,3})(?:\*\*|`)? \((?i:also known as|also called|aka|a\.k\.a\.?|formerly)[:,]? ([^)\n]{2,60})\)",
+        )
+        .expect("valid alias regex")
+    })
+}
+
+/// Terms and the other names they go by
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Glossary {
+    /// Synonyms by term, both as written
+    terms: BTreeMap<String, Vec<String>>,
+}
+
+/// A query with the synonyms of the glossary terms it mentions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryExpansion {
+    pub original: String,
+    /// Glossary names found in the query
+    pub matched: Vec<String>,
+    /// Synonyms added to the query
+    pub added: Vec<String>,
+}
+
+impl QueryExpansion {
+    pub fn is_expanded(&self) -> bool {
+        !self.added.is_empty()
+    }
+
+    /// Text to embed for the query
+    pub fn text(&self) -> String {
+        if self.added.is_empty() {
+            self.original.clone()
+        } else {
+            format!("{} ({})", self.original, self.added.join(", "))
+        }
+    }
+}
+
+impl Glossary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a term, merging its synonyms with any already known
+    pub fn with_term<I, S>(mut self, term: impl Into<String>, synonyms: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.add_term(term.into(), synonyms.into_iter().map(Into::into));
+        self
+    }
+
+    fn add_term(&mut self, term: String, synonyms: impl Iterator<Item = String>) {
+        let term = term.trim().to_string();
+        if term.is_empty() {
+            return;
+        }
+        let known = self.terms.entry(term.clone()).or_default();
+        for synonym in synonyms {
+            let synonym = synonym.trim();
+            let is_new = !synonym.is_empty()
+                && !synonym.eq_ignore_ascii_case(&term)
+                && !known.iter().any(|k| k.eq_ignore_ascii_case(synonym));
+            if is_new {
+                known.push(synonym.to_string());
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Read the glossary file in `dir`; a missing file is an empty glossary
+    pub fn read(dir: &Path) -> WikiResult<Self> {
+        let path = dir.join(GLOSSARY_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(&path)?;
+        let terms: BTreeMap<String, Vec<String>> = serde_json::from_str(&content)?;
+        let mut glossary = Self::default();
+        for (term, synonyms) in terms {
+            glossary.add_term(term, synonyms.into_iter());
+        }
+        debug!(path = %path.display(), terms = glossary.len(), "Loaded glossary");
+        Ok(glossary)
+    }
+
+    /// Learn aliases spelled out in page text, like "Flux (aka the event bus)"
+    pub fn learn_from_text(&mut self, text: &str) {
+        for captures in alias_regex().captures_iter(text) {
+            let term = captures[1].to_string();
+            let aliases: Vec<String> = captures[2]
+                .split([',', ';'])
+                .flat_map(|part| part.split(" or "))
+                .map(|alias| {
+                    let alias = alias
+                        .trim()
+                        .trim_matches(|c| c == '*' || c == '`' || c == '"');
+                    alias
+                        .strip_prefix("the ")
+                        .or_else(|| alias.strip_prefix("The "))
+                        .unwrap_or(alias)
+                        .to_string()
+                })
+                .filter(|alias| !alias.is_empty())
+                .collect();
+            self.add_term(term, aliases.into_iter());
+        }
+    }
+
+    /// The glossary file next to the wiki database at `db_path`, plus the
+    /// aliases learned from the pages stored in it
+    pub fn load(db_path: &Path, vector_store: &VectorStore) -> WikiResult<Self> {
+        let mut glossary = match db_path.parent() {
+            Some(dir) => Self::read(dir).unwrap_or_else(|e| {
+                warn!(error = %e, "Ignoring unreadable glossary file");
+                Self::default()
+            }),
+            None => Self::default(),
+        };
+        for content in vector_store.get_all_page_contents()? {
+            glossary.learn_from_text(&content);
+        }
+        Ok(glossary)
+    }
+
+    /// Expand `query` with the synonyms of every term it mentions, by any of
+    /// its names
+    pub fn expand(&self, query: &str) -> QueryExpansion {
+        let lowered = query.to_lowercase();
+        let mut matched = Vec::new();
+        let mut added: Vec<String> = Vec::new();
+
+        for (term, synonyms) in &self.terms {
+            let names = std::iter::once(term).chain(synonyms);
+            let Some(hit) = names
+                .clone()
+                .find(|name| contains_phrase(&lowered, &name.to_lowercase()))
+            else {
+                continue;
+            };
+            matched.push(hit.clone());
+            for name in names {
+                let lowered_name = name.to_lowercase();
+                let is_new = !contains_phrase(&lowered, &lowered_name)
+                    && !added.iter().any(|a| a.to_lowercase() == lowered_name);
+                if is_new {
+                    added.push(name.clone());
+                }
+            }
+        }
+
+        QueryExpansion {
+            original: query.to_string(),
+            matched,
+            added,
+        }
+    }
+}
+
+/// Whether `phrase` occurs in `text` as whole words
+fn contains_phrase(text: &str, phrase: &str) -> bool {
+    if phrase.is_empty() {
+        return false;
+    }
+    let is_word = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+    text.match_indices(phrase).any(|(start, _)| {
+        let before = text[..start].chars().next_back();
+        let after = text[start + phrase.len()..].chars().next();
+        !is_word(before) && !is_word(after)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_expand_query() {
+        let glossary = Glossary::new()
+            .with_term("Flux", ["event bus", "EventBus"])
+            .with_term("Atlas", ["wiki generator"]);
+
+        let expansion = glossary.expand("How does flux deliver events?");
+        assert_eq!(expansion.matched, vec!["Flux"]);
+        assert_eq!(expansion.added, vec!["event bus", "EventBus"]);
+        assert_eq!(
+            expansion.text(),
+            "How does flux deliver events? (event bus, EventBus)"
+        );
+
+        // Synonyms lead back to the term
+        let expansion = glossary.expand("who calls the wiki generator");
+        assert_eq!(expansion.added, vec!["Atlas"]);
+
+        // Only whole words match
+        assert!(!glossary.expand("influx of tasks").is_expanded());
+    }
+
+    #[test]
+    fn test_learn_aliases_from_pages() {
+        let mut glossary = Glossary::new();
+        glossary.learn_from_text(
+            "Events go through **Flux** (also known as the event bus), \
+             which Atlas (aka `wiki generator`) listens to.",
+        );
+        assert_eq!(glossary.expand("flux").added, vec!["event bus"]);
+        assert_eq!(glossary.expand("Atlas").added, vec!["wiki generator"]);
+    }
+
+    #[test]
+    fn test_read_glossary_file() {
+        let dir = tempdir().unwrap();
+        assert!(Glossary::read(dir.path()).unwrap().is_empty());
+
+        std::fs::write(
+            dir.path().join(GLOSSARY_FILE),
+            r#"{"Flux": ["event bus"], "Atlas": []}"#,
+        )
+        .unwrap();
+        let glossary = Glossary::read(dir.path()).unwrap();
+        assert_eq!(glossary.len(), 2);
+        assert_eq!(glossary.expand("Flux").added, vec!["event bus"]);
+    }
+}
@@ -0,0 +1,188 @@
+//! Dry-run estimate of what indexing a repository costs
+//!
+//! Walks the repository and chunks it exactly as indexing would, without
+//! embedding anything, then prices the tokens at the embedding model's rate
+//! from the OpenRouter models endpoint. Chunk summaries are not estimated.
+
+use std::path::Path;
+
+use rayon::prelude::*;
+use serde::Serialize;
+use tracing::warn;
+
+use super::reader::FileReader;
+use super::{normalize_root, root_of, CodeIndexer, EMBEDDING_BATCH_SIZE};
+use crate::chunker::TextSplitter;
+use crate::embeddings::batching::{batch_token_limit, plan_by_token_counts};
+use crate::embeddings::{EmbeddingProviderConfig, EmbeddingProviderKind};
+use crate::error::{WikiError, WikiResult};
+use crate::openrouter::OpenRouterClient;
+
+/// Rough wall time of one embedding request, including the round trip
+const SECONDS_PER_BATCH: f64 = 1.5;
+
+/// Files, chunks and tokens indexing would embed, and what that costs
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexEstimate {
+    pub files: usize,
+    pub chunks: usize,
+    pub tokens: usize,
+    /// Embedding requests the chunks are sent in
+    pub batches: usize,
+    pub embedding_model: String,
+    /// USD per million input tokens; `None` when the model's price is unknown
+    pub price_per_million_tokens: Option<f64>,
+    pub estimated_cost_usd: Option<f64>,
+    pub estimated_seconds: u64,
+}
+
+impl IndexEstimate {
+    /// Price the estimate at `price_per_token` USD
+    pub fn with_price(mut self, price_per_token: f64) -> Self {
+        self.price_per_million_tokens = Some(price_per_token * 1_000_000.0);
+        self.estimated_cost_usd = Some(price_per_token * self.tokens as f64);
+        self
+    }
+
+    /// Price the estimate at the rate of the configured provider. Local
+    /// models are free; hosted ones are looked up on OpenRouter, and an
+    /// unlisted model or a failed lookup leaves the cost unknown.
+    pub async fn priced(self, provider: &EmbeddingProviderConfig) -> Self {
+        let model = match provider.kind {
+            EmbeddingProviderKind::Local => return self.with_price(0.0),
+            EmbeddingProviderKind::OpenAi if !self.embedding_model.contains('/') => {
+                format!("openai/{}", self.embedding_model)
+            }
+            _ => self.embedding_model.clone(),
+        };
+        let client = OpenRouterClient::new(
+            provider.openrouter_api_key.clone().unwrap_or_default(),
+            provider
+                .openrouter_base_url
+                .clone()
+                .unwrap_or_else(|| "https://openrouter.ai/api/v1".to_string()),
+        );
+        match client.model_price(&model).await {
+            Ok(Some(price)) => self.with_price(price),
+            Ok(None) => self,
+            Err(e) => {
+                warn!(model = %model, error = %e, "Failed to look up embedding price");
+                self
+            }
+        }
+    }
+}
+
+/// Counts what indexing a directory would embed
+pub struct IndexEstimator {
+    max_chunk_tokens: usize,
+    chunk_overlap: usize,
+    roots: Vec<String>,
+}
+
+impl IndexEstimator {
+    pub fn new(max_chunk_tokens: usize, chunk_overlap: usize) -> Self {
+        Self {
+            max_chunk_tokens,
+            chunk_overlap,
+            roots: Vec::new(),
+        }
+    }
+
+    /// Only count files under `roots`, as [`CodeIndexer::with_roots`] indexes
+    pub fn with_roots(mut self, roots: &[String]) -> Self {
+        self.roots = roots
+            .iter()
+            .map(|root| normalize_root(root))
+            .filter(|root| !root.is_empty())
+            .collect();
+        self
+    }
+
+    /// Estimate indexing `root_path` with `embedding_model`, unpriced
+    pub fn estimate(&self, root_path: &Path, embedding_model: &str) -> WikiResult<IndexEstimate> {
+        let files = FileReader::new(self.max_chunk_tokens, self.chunk_overlap)
+            .read_directory(root_path)
+            .map_err(|e| WikiError::IndexingFailed(format!("Failed to read directory: {}", e)))?;
+        let files: Vec<_> = files
+            .into_iter()
+            .filter(|file| {
+                self.roots.is_empty() || root_of(&file.relative_path, &self.roots).is_some()
+            })
+            .collect();
+
+        let text_splitter = TextSplitter::new(self.max_chunk_tokens, self.chunk_overlap);
+        let token_counts: Vec<usize> = files
+            .par_iter()
+            .flat_map(|file| {
+                CodeIndexer::create_chunks_from_file_static(file, "", "", &text_splitter)
+                    .into_iter()
+                    .map(|chunk| chunk.token_count as usize)
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        let batches = plan_by_token_counts(
+            &token_counts,
+            EMBEDDING_BATCH_SIZE,
+            batch_token_limit(embedding_model),
+        )
+        .len();
+
+        Ok(IndexEstimate {
+            files: files.len(),
+            chunks: token_counts.len(),
+            tokens: token_counts.iter().sum(),
+            batches,
+            embedding_model: embedding_model.to_string(),
+            price_per_million_tokens: None,
+            estimated_cost_usd: None,
+            estimated_seconds: (batches as f64 * SECONDS_PER_BATCH).ceil() as u64,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_estimate_counts_chunks_and_prices_tokens() {
+        let dir = tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("backend")).unwrap();
+        fs::create_dir_all(dir.path().join("frontend")).unwrap();
+        fs::write(
+            dir.path().join("backend/main.rs"),
+            "fn main() {\n    println!(\"hello\");\n}\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("frontend/app.ts"),
+            "export const app = 1;\n",
+        )
+        .unwrap();
+
+        let estimator = IndexEstimator::new(350, 100);
+        let estimate = estimator
+            .estimate(dir.path(), "openai/text-embedding-3-small")
+            .unwrap();
+        assert_eq!(estimate.files, 2);
+        assert_eq!(estimate.chunks, 2);
+        assert!(estimate.tokens > 0);
+        assert_eq!(estimate.batches, 1);
+        assert!(estimate.estimated_cost_usd.is_none());
+
+        let priced = estimate.clone().with_price(0.000_000_02);
+        let cost = priced.estimated_cost_usd.unwrap();
+        assert!((cost - estimate.tokens as f64 * 0.000_000_02).abs() < 1e-12);
+        assert!((priced.price_per_million_tokens.unwrap() - 0.02).abs() < 1e-9);
+
+        let scoped = IndexEstimator::new(350, 100)
+            .with_roots(&["backend/".to_string()])
+            .estimate(dir.path(), "openai/text-embedding-3-small")
+            .unwrap();
+        assert_eq!(scoped.files, 1);
+    }
+}
@@ -1,52 +1,130 @@
 //! Code indexer for creating embeddings and storing chunks
 
+pub mod estimate;
 pub mod reader;
+pub mod tuning;
 
 use std::path::Path;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
+use futures::stream::{self, StreamExt};
 use rayon::prelude::*;
 use tokio::sync::broadcast;
 use tracing::{debug, error, info, warn};
 
 use crate::chunker::TextSplitter;
 use crate::domain::chunk::{ChunkType, CodeChunk};
+use crate::domain::chunk_generation::DEFAULT_HISTORY_GENERATIONS;
 use crate::domain::index_status::{IndexProgress, IndexState, IndexStatus};
+use crate::embeddings::batching::plan_batches;
+use crate::embeddings::EmbeddingProvider;
 use crate::error::{WikiError, WikiResult};
+use crate::generator::extracted;
 use crate::git;
 use crate::openrouter::OpenRouterClient;
+use crate::public_api;
+use crate::summarizer::ChunkSummarizer;
+use crate::symbols;
 use crate::vector_store::VectorStore;
 
 use reader::{FileInfo, FileReader};
 
 const EMBEDDING_BATCH_SIZE: usize = 100;
 
+/// Embedding batches in flight at once while indexing
+pub const DEFAULT_EMBEDDING_CONCURRENCY: usize = 4;
+
 pub struct CodeIndexer {
-    openrouter: Arc<OpenRouterClient>,
+    embeddings: Arc<dyn EmbeddingProvider>,
     vector_store: Arc<VectorStore>,
     embedding_model: String,
     max_chunk_tokens: usize,
     chunk_overlap: usize,
+    /// Client and chat model that summarize each chunk before it is embedded
+    summaries: Option<(Arc<OpenRouterClient>, String)>,
+    /// Sub-directories indexed as workspace roots; empty indexes the whole
+    /// repository
+    roots: Vec<String>,
+    /// Earlier indexes of a branch kept for searches as of their commit
+    history_generations: usize,
+    /// Embedding batches requested at once
+    embedding_concurrency: usize,
+}
+
+/// `root` as stored on chunks: relative to the repository, without a leading
+/// `./` or trailing slashes
+pub fn normalize_root(root: &str) -> String {
+    let root = root.trim().trim_matches('/');
+    root.strip_prefix("./").unwrap_or(root).to_string()
+}
+
+/// The root of `roots` that `file_path` lies under, the most specific one if
+/// roots are nested
+pub fn root_of<'a>(file_path: &str, roots: &'a [String]) -> Option<&'a str> {
+    roots
+        .iter()
+        .filter(|root| {
+            root.is_empty()
+                || file_path
+                    .strip_prefix(root.as_str())
+                    .is_some_and(|rest| rest.starts_with('/'))
+        })
+        .max_by_key(|root| root.len())
+        .map(String::as_str)
 }
 
 impl CodeIndexer {
     pub fn new(
-        openrouter: Arc<OpenRouterClient>,
+        embeddings: Arc<dyn EmbeddingProvider>,
         vector_store: Arc<VectorStore>,
         embedding_model: String,
         max_chunk_tokens: usize,
         chunk_overlap: usize,
     ) -> Self {
         Self {
-            openrouter,
+            embeddings,
             vector_store,
             embedding_model,
             max_chunk_tokens,
             chunk_overlap,
+            summaries: None,
+            roots: Vec::new(),
+            history_generations: DEFAULT_HISTORY_GENERATIONS,
+            embedding_concurrency: DEFAULT_EMBEDDING_CONCURRENCY,
         }
     }
 
+    /// Summarize each chunk with `model` and embed the summary with its code
+    pub fn with_summaries(mut self, openrouter: Arc<OpenRouterClient>, model: String) -> Self {
+        self.summaries = Some((openrouter, model));
+        self
+    }
+
+    /// Only index files under `roots`, tagging each chunk with its root so
+    /// searches can be scoped to one of them
+    pub fn with_roots(mut self, roots: &[String]) -> Self {
+        self.roots = roots
+            .iter()
+            .map(|root| normalize_root(root))
+            .filter(|root| !root.is_empty())
+            .collect();
+        self
+    }
+
+    /// Keep the `generations` most recent earlier indexes of a branch when
+    /// it is re-indexed; zero keeps none
+    pub fn with_history(mut self, generations: usize) -> Self {
+        self.history_generations = generations;
+        self
+    }
+
+    /// Request up to `concurrency` embedding batches at once; at least one
+    pub fn with_embedding_concurrency(mut self, concurrency: usize) -> Self {
+        self.embedding_concurrency = concurrency.max(1);
+        self
+    }
+
     pub async fn index_branch(
         &self,
         root_path: &Path,
@@ -77,7 +155,15 @@ impl CodeIndexer {
             }
         }
 
+        if let Err(e) = self
+            .vector_store
+            .archive_generation(branch, self.history_generations)
+        {
+            warn!("Failed to keep the previous index of '{}': {}", branch, e);
+        }
         self.vector_store.clear_branch(branch)?;
+        self.vector_store
+            .activate_embedding_model(branch, &self.embedding_model)?;
 
         let mut status = IndexStatus::new(branch.to_string());
         status.state = IndexState::Indexing;
@@ -86,7 +172,11 @@ impl CodeIndexer {
 
         let reader = FileReader::new(self.max_chunk_tokens, self.chunk_overlap);
         let files = match reader.read_directory(root_path) {
-            Ok(f) => f,
+            Ok(f) if self.roots.is_empty() => f,
+            Ok(f) => f
+                .into_iter()
+                .filter(|file| root_of(&file.relative_path, &self.roots).is_some())
+                .collect(),
             Err(e) => {
                 let err_msg = format!("Failed to read directory: {}", e);
                 error!("{}", err_msg);
@@ -118,8 +208,9 @@ impl CodeIndexer {
         let text_splitter = TextSplitter::new(self.max_chunk_tokens, self.chunk_overlap);
         let branch_str = branch.to_string();
         let commit_sha_str = commit_sha.to_string();
+        let roots = &self.roots;
 
-        let all_chunks: Vec<CodeChunk> = files
+        let mut all_chunks: Vec<CodeChunk> = files
             .par_iter()
             .flat_map(|file| {
                 let count = processed_count.fetch_add(1, Ordering::Relaxed);
@@ -131,12 +222,17 @@ impl CodeIndexer {
                         file.relative_path
                     );
                 }
-                Self::create_chunks_from_file_static(
+                let mut chunks = Self::create_chunks_from_file_static(
                     file,
                     &branch_str,
                     &commit_sha_str,
                     &text_splitter,
-                )
+                );
+                let root = root_of(&file.relative_path, roots);
+                for chunk in &mut chunks {
+                    chunk.root = root.map(str::to_string);
+                }
+                chunks
             })
             .collect();
 
@@ -152,72 +248,57 @@ impl CodeIndexer {
             total_chunks, total_files
         );
 
+        if let Some((ref openrouter, ref model)) = self.summaries {
+            status.current_phase = Some("summarizing_chunks".to_string());
+            status.current_item = None;
+            self.vector_store.update_index_status(&status)?;
+
+            let summarized = ChunkSummarizer::new(openrouter, model)
+                .summarize(&mut all_chunks)
+                .await;
+            info!("Summarized {}/{} chunks", summarized, total_chunks);
+        }
+
         self.vector_store.insert_chunks_batch(&all_chunks)?;
 
-        let chunk_contents: Vec<String> = all_chunks.iter().map(|c| c.content.clone()).collect();
+        let chunk_contents: Vec<String> =
+            all_chunks.iter().map(CodeChunk::embedding_text).collect();
         let chunk_ids: Vec<_> = all_chunks.iter().map(|c| c.id).collect();
 
-        let total_batches = chunk_contents.len().div_ceil(EMBEDDING_BATCH_SIZE);
+        let batches = plan_batches(
+            &chunk_contents,
+            EMBEDDING_BATCH_SIZE,
+            self.embeddings.batch_token_limit(&self.embedding_model),
+        );
+        let total_batches = batches.len();
 
         status.current_phase = Some("creating_embeddings".to_string());
         status.chunk_count = total_chunks as u32;
         self.vector_store.update_index_status(&status)?;
 
-        for (batch_idx, batch) in chunk_contents.chunks(EMBEDDING_BATCH_SIZE).enumerate() {
-            let batch_start = batch_idx * EMBEDDING_BATCH_SIZE;
-
-            let progress = IndexProgress::CreatingEmbeddings {
-                current: (batch_idx + 1) as u32,
-                total: total_batches as u32,
-            };
-            send_progress(progress.clone());
-
-            status.progress_percent = progress.percent();
-            status.current_item = Some(format!("batch {}/{}", batch_idx + 1, total_batches));
-            let _ = self.vector_store.update_index_status(&status);
+        // Batches are requested concurrently but come back in order, so each
+        // is stored with its own chunk ids and progress counts up steadily
+        let chunk_contents = &chunk_contents;
+        let mut embedded = stream::iter(batches.into_iter().enumerate())
+            .map(|(batch_idx, range)| async move {
+                let batch = &chunk_contents[range.clone()];
+                debug!(
+                    "Creating embeddings for batch {}/{} ({} chunks)",
+                    batch_idx + 1,
+                    total_batches,
+                    batch.len()
+                );
+                let embeddings = self
+                    .embeddings
+                    .create_embeddings_split(batch, &self.embedding_model)
+                    .await;
+                (batch_idx, range, embeddings)
+            })
+            .buffered(self.embedding_concurrency);
 
-            debug!(
-                "Creating embeddings for batch {}/{} ({} chunks)",
-                batch_idx + 1,
-                total_batches,
-                batch.len()
-            );
-
-            let batch_vec: Vec<String> = batch.to_vec();
-            let batch_chunk_ids: Vec<_> =
-                chunk_ids[batch_start..batch_start + batch.len()].to_vec();
-
-            let embeddings = match self
-                .openrouter
-                .create_embeddings_batch(&batch_vec, &self.embedding_model)
-                .await
-            {
+        while let Some((batch_idx, range, embeddings)) = embedded.next().await {
+            let embeddings = match embeddings {
                 Ok(emb) => emb,
-                Err(WikiError::RateLimited { retry_after }) => {
-                    let wait_secs = retry_after.unwrap_or(60);
-                    warn!("Rate limited, waiting {}s before retry", wait_secs);
-                    tokio::time::sleep(tokio::time::Duration::from_secs(wait_secs)).await;
-
-                    match self
-                        .openrouter
-                        .create_embeddings_batch(&batch_vec, &self.embedding_model)
-                        .await
-                    {
-                        Ok(emb) => emb,
-                        Err(e) => {
-                            let err_msg = format!("Embedding creation failed after retry: {}", e);
-                            error!("{}", err_msg);
-                            status.state = IndexState::Failed;
-                            status.error_message = Some(err_msg.clone());
-                            self.vector_store.update_index_status(&status)?;
-                            send_progress(IndexProgress::Failed {
-                                branch: branch.to_string(),
-                                error: err_msg.clone(),
-                            });
-                            return Err(WikiError::IndexingFailed(err_msg));
-                        }
-                    }
-                }
                 Err(e) => {
                     let err_msg = format!("Embedding creation failed: {}", e);
                     error!("{}", err_msg);
@@ -232,10 +313,11 @@ impl CodeIndexer {
                 }
             };
 
-            if let Err(e) = self
-                .vector_store
-                .insert_embeddings_batch(&batch_chunk_ids, &embeddings)
-            {
+            if let Err(e) = self.vector_store.insert_embeddings_batch(
+                &self.embedding_model,
+                &chunk_ids[range],
+                &embeddings,
+            ) {
                 error!("Failed to store embeddings: {}", e);
                 status.state = IndexState::Failed;
                 status.error_message = Some(e.to_string());
@@ -246,6 +328,22 @@ impl CodeIndexer {
                 });
                 return Err(e);
             }
+
+            let progress = IndexProgress::CreatingEmbeddings {
+                current: (batch_idx + 1) as u32,
+                total: total_batches as u32,
+            };
+            send_progress(progress.clone());
+
+            status.progress_percent = progress.percent();
+            status.current_item = Some(format!("batch {}/{}", batch_idx + 1, total_batches));
+            let _ = self.vector_store.update_index_status(&status);
+        }
+
+        // Extracted reference pages only depend on the chunks, so refresh them
+        // now rather than waiting for the next (LLM-backed) wiki generation.
+        if let Err(e) = extracted::generate_pages(&self.vector_store, branch, commit_sha, 0) {
+            warn!("Failed to refresh extracted pages for '{}': {}", branch, e);
         }
 
         status.state = IndexState::Indexed;
@@ -345,8 +443,14 @@ impl CodeIndexer {
             .map(|(idx, (content, start_line, end_line))| {
                 let token_count = text_splitter.count_tokens(&content);
                 let chunk_type = Self::detect_chunk_type(&file.relative_path, &content);
+                let is_public_api = public_api::is_public_api(
+                    &file.relative_path,
+                    file.language.as_deref(),
+                    chunk_type,
+                    &content,
+                );
 
-                CodeChunk::new(
+                let mut chunk = CodeChunk::new(
                     branch.to_string(),
                     file.relative_path.clone(),
                     start_line,
@@ -357,7 +461,11 @@ impl CodeIndexer {
                     token_count as u32,
                     idx as u32,
                     commit_sha.to_string(),
-                )
+                );
+                chunk.is_public_api = is_public_api;
+                chunk.symbols = symbols::extract_symbols(&chunk.content);
+                chunk.imports = symbols::extract_imports(&chunk.content);
+                chunk
             })
             .collect()
     }
@@ -423,8 +531,106 @@ impl CodeIndexer {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use tempfile::tempdir;
+
     use super::*;
 
+    /// Embeds each text as a vector derived from its hash, slowly enough
+    /// that concurrent batches overlap, and records the most in flight
+    #[derive(Default)]
+    struct SlowHashes {
+        in_flight: AtomicUsize,
+        max_in_flight: AtomicUsize,
+    }
+
+    fn hash_embedding(text: &str) -> Vec<f32> {
+        let mut hasher = DefaultHasher::new();
+        text.hash(&mut hasher);
+        hasher
+            .finish()
+            .to_le_bytes()
+            .iter()
+            .map(|&b| b as f32 / 255.0)
+            .collect()
+    }
+
+    #[async_trait]
+    impl EmbeddingProvider for SlowHashes {
+        fn name(&self) -> &'static str {
+            "slow"
+        }
+
+        async fn create_embeddings_batch(
+            &self,
+            texts: &[String],
+            _model: &str,
+        ) -> WikiResult<Vec<Vec<f32>>> {
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(texts.iter().map(|text| hash_embedding(text)).collect())
+        }
+    }
+
+    #[tokio::test]
+    #[allow(clippy::arc_with_non_send_sync)]
+    async fn test_index_branch_embeds_batches_concurrently() {
+        let repo = tempdir().unwrap();
+        for i in 0..250 {
+            std::fs::write(
+                repo.path().join(format!("file_{}.rs", i)),
+                format!("pub fn function_{}() -> u32 {{ {} }}\n", i, i),
+            )
+            .unwrap();
+        }
+        let db = tempdir().unwrap();
+        let store = Arc::new(
+            VectorStore::with_embedding_dimension(&db.path().join("wiki.db"), "model", 8).unwrap(),
+        );
+        let provider = Arc::new(SlowHashes::default());
+
+        let status = CodeIndexer::new(provider.clone(), store.clone(), "model".into(), 350, 100)
+            .with_embedding_concurrency(2)
+            .index_branch(repo.path(), "main", "abc123", None)
+            .await
+            .unwrap();
+        assert_eq!(status.state, IndexState::Indexed);
+        assert_eq!(status.chunk_count, 250);
+        assert_eq!(provider.max_in_flight.load(Ordering::SeqCst), 2);
+
+        // Every chunk is stored with the embedding of its own text
+        for chunk in store.get_branch_chunks("main").unwrap().iter().step_by(25) {
+            let results = store
+                .search_similar(&hash_embedding(&chunk.embedding_text()), "model", 1)
+                .unwrap();
+            assert_eq!(results[0].chunk_id, chunk.id);
+        }
+    }
+
+    #[test]
+    fn test_root_of() {
+        let roots: Vec<String> = ["./backend/", "frontend", "frontend/admin"]
+            .iter()
+            .map(|root| normalize_root(root))
+            .collect();
+        assert_eq!(roots[0], "backend");
+
+        assert_eq!(root_of("backend/src/main.rs", &roots), Some("backend"));
+        assert_eq!(root_of("frontend/src/app.ts", &roots), Some("frontend"));
+        assert_eq!(
+            root_of("frontend/admin/index.ts", &roots),
+            Some("frontend/admin")
+        );
+        assert_eq!(root_of("backend-legacy/main.rs", &roots), None);
+        assert_eq!(root_of("README.md", &roots), None);
+    }
+
     #[test]
     fn test_detect_chunk_type_test_file() {
         assert_eq!(
@@ -191,7 +191,7 @@ mod tests {
         fs::write(src_dir.join("lib.rs"), "fn main() {}").unwrap();
         fs::write(src_dir.join("test.py"), "def main(): pass").unwrap();
         fs::write(dir.path().join("README.md"), "# README").unwrap();
-        fs::write(dir.path().join("image.png"), &[0u8; 100]).unwrap();
+        fs::write(dir.path().join("image.png"), [0u8; 100]).unwrap();
 
         let reader = FileReader::new(350, 100);
         let files = reader.read_directory(dir.path()).unwrap();
@@ -0,0 +1,451 @@
+//! Chunk size tuning
+//!
+//! Which chunk size retrieves best depends on the language and on how a
+//! repository is written. Tuning indexes a sample of the repository's files
+//! in memory at several chunk size and overlap settings and measures how
+//! well each finds code: every function defined in the sample becomes a
+//! query made of its name's words, answered right when a top-ranked chunk
+//! holds the definition. The setting with the best mean reciprocal rank is
+//! recommended.
+
+use std::path::Path;
+
+use serde::Serialize;
+use tracing::{debug, info};
+
+use super::reader::{FileInfo, FileReader};
+use super::{normalize_root, root_of, CodeIndexer};
+use crate::chunker::TextSplitter;
+use crate::embeddings::EmbeddingProvider;
+use crate::error::{WikiError, WikiResult};
+use crate::symbols::{definition_line, extract_definitions};
+
+/// Files of the repository tuned on by default
+pub const DEFAULT_TUNING_FILES: usize = 40;
+
+/// Definitions of a file turned into queries
+const QUERIES_PER_FILE: usize = 2;
+
+/// Chunks of each query's ranking that count as found
+const TOP_K: usize = 5;
+
+/// Queries below which the measurements say too little to tune on
+const MIN_QUERIES: usize = 5;
+
+/// A chunk size and overlap, in tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ChunkSetting {
+    pub max_chunk_tokens: usize,
+    pub chunk_overlap: usize,
+}
+
+impl ChunkSetting {
+    pub const fn new(max_chunk_tokens: usize, chunk_overlap: usize) -> Self {
+        Self {
+            max_chunk_tokens,
+            chunk_overlap,
+        }
+    }
+}
+
+/// Settings tried when none are given; the default indexing setting is
+/// among them
+pub const DEFAULT_TUNING_SETTINGS: &[ChunkSetting] = &[
+    ChunkSetting::new(200, 50),
+    ChunkSetting::new(350, 100),
+    ChunkSetting::new(500, 100),
+    ChunkSetting::new(800, 150),
+];
+
+/// A query whose answer is a function's definition
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TuningQuery {
+    pub text: String,
+    pub file_path: String,
+    /// 1-indexed line of the definition
+    pub line: u32,
+}
+
+/// Files and queries a tuning pass measures with
+pub struct TuningSample {
+    files: Vec<FileInfo>,
+    pub queries: Vec<TuningQuery>,
+}
+
+impl TuningSample {
+    pub fn files(&self) -> usize {
+        self.files.len()
+    }
+}
+
+/// Retrieval quality at one setting
+#[derive(Debug, Clone, Serialize)]
+pub struct ChunkSettingScore {
+    pub setting: ChunkSetting,
+    /// Chunks the sample was split into
+    pub chunks: usize,
+    /// Share of queries with their definition among the top chunks
+    pub hit_rate: f64,
+    /// Mean reciprocal rank of the chunk holding the definition, counting
+    /// only the top chunks
+    pub mean_reciprocal_rank: f64,
+}
+
+/// What a tuning pass measured and the setting it recommends
+#[derive(Debug, Clone, Serialize)]
+pub struct ChunkTuningReport {
+    pub files: usize,
+    pub queries: usize,
+    pub embedding_model: String,
+    pub scores: Vec<ChunkSettingScore>,
+    pub recommended: ChunkSetting,
+}
+
+/// Measures retrieval quality at several chunk settings
+pub struct ChunkTuner<'a> {
+    embeddings: &'a dyn EmbeddingProvider,
+    embedding_model: String,
+    settings: Vec<ChunkSetting>,
+    max_files: usize,
+    roots: Vec<String>,
+}
+
+impl<'a> ChunkTuner<'a> {
+    pub fn new(embeddings: &'a dyn EmbeddingProvider, embedding_model: impl Into<String>) -> Self {
+        Self {
+            embeddings,
+            embedding_model: embedding_model.into(),
+            settings: DEFAULT_TUNING_SETTINGS.to_vec(),
+            max_files: DEFAULT_TUNING_FILES,
+            roots: Vec::new(),
+        }
+    }
+
+    pub fn with_settings(mut self, settings: Vec<ChunkSetting>) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    pub fn with_max_files(mut self, max_files: usize) -> Self {
+        self.max_files = max_files.max(1);
+        self
+    }
+
+    /// Only sample files under `roots`, as [`CodeIndexer::with_roots`] indexes
+    pub fn with_roots(mut self, roots: &[String]) -> Self {
+        self.roots = roots
+            .iter()
+            .map(|root| normalize_root(root))
+            .filter(|root| !root.is_empty())
+            .collect();
+        self
+    }
+
+    /// Pick the files to tune on from `root_path`: files defining functions,
+    /// spread evenly over the repository's paths. Reads the filesystem, so
+    /// callers on an async runtime should run it on a blocking thread.
+    pub fn sample(&self, root_path: &Path) -> WikiResult<TuningSample> {
+        let mut files: Vec<FileInfo> = FileReader::new(350, 100)
+            .read_directory(root_path)
+            .map_err(|e| WikiError::IndexingFailed(format!("Failed to read directory: {}", e)))?
+            .into_iter()
+            .filter(|file| {
+                self.roots.is_empty() || root_of(&file.relative_path, &self.roots).is_some()
+            })
+            .filter(|file| !extract_definitions(&file.content).is_empty())
+            .collect();
+        files.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+
+        let files = spread(files, self.max_files);
+        let queries = files.iter().flat_map(file_queries).collect();
+        Ok(TuningSample { files, queries })
+    }
+
+    /// Score every setting on `sample` and recommend the best
+    pub async fn tune(&self, sample: &TuningSample) -> WikiResult<ChunkTuningReport> {
+        if self.settings.is_empty() {
+            return Err(WikiError::InvalidConfig(
+                "No chunk settings to tune".to_string(),
+            ));
+        }
+        if sample.queries.len() < MIN_QUERIES {
+            return Err(WikiError::IndexingFailed(format!(
+                "Found {} functions to search for; tuning needs at least {}",
+                sample.queries.len(),
+                MIN_QUERIES
+            )));
+        }
+
+        let query_texts: Vec<String> = sample.queries.iter().map(|q| q.text.clone()).collect();
+        let query_embeddings = self
+            .embeddings
+            .create_embeddings_split(&query_texts, &self.embedding_model)
+            .await?;
+
+        let mut scores = Vec::with_capacity(self.settings.len());
+        for setting in &self.settings {
+            let splitter = TextSplitter::new(setting.max_chunk_tokens, setting.chunk_overlap);
+            let chunks: Vec<_> = sample
+                .files
+                .iter()
+                .flat_map(|file| {
+                    CodeIndexer::create_chunks_from_file_static(file, "", "", &splitter)
+                })
+                .collect();
+            let texts: Vec<String> = chunks.iter().map(|c| c.embedding_text()).collect();
+            let chunk_embeddings = self
+                .embeddings
+                .create_embeddings_split(&texts, &self.embedding_model)
+                .await?;
+
+            let ranks: Vec<Option<usize>> = sample
+                .queries
+                .iter()
+                .zip(&query_embeddings)
+                .map(|(query, embedding)| {
+                    let mut ranked: Vec<(f32, usize)> = chunk_embeddings
+                        .iter()
+                        .enumerate()
+                        .map(|(i, chunk)| (cosine_similarity(embedding, chunk), i))
+                        .collect();
+                    ranked.sort_by(|a, b| b.0.total_cmp(&a.0));
+                    ranked.iter().take(TOP_K).position(|(_, i)| {
+                        let chunk = &chunks[*i];
+                        chunk.file_path == query.file_path
+                            && (chunk.start_line..=chunk.end_line).contains(&query.line)
+                    })
+                })
+                .collect();
+
+            let score = score(*setting, chunks.len(), &ranks);
+            debug!(
+                max_chunk_tokens = setting.max_chunk_tokens,
+                chunk_overlap = setting.chunk_overlap,
+                hit_rate = score.hit_rate,
+                mrr = score.mean_reciprocal_rank,
+                "Scored chunk setting"
+            );
+            scores.push(score);
+        }
+
+        let recommended = recommend(&scores);
+        info!(
+            files = sample.files(),
+            queries = sample.queries.len(),
+            max_chunk_tokens = recommended.max_chunk_tokens,
+            chunk_overlap = recommended.chunk_overlap,
+            "Chunk tuning finished"
+        );
+
+        Ok(ChunkTuningReport {
+            files: sample.files(),
+            queries: sample.queries.len(),
+            embedding_model: self.embedding_model.clone(),
+            scores,
+            recommended,
+        })
+    }
+}
+
+/// Up to `max` items taken at even steps through `items`
+fn spread<T>(items: Vec<T>, max: usize) -> Vec<T> {
+    if items.len() <= max {
+        return items;
+    }
+    let step = items.len() as f64 / max as f64;
+    let picks: Vec<usize> = (0..max).map(|i| (i as f64 * step) as usize).collect();
+    items
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| picks.contains(i))
+        .map(|(_, item)| item)
+        .collect()
+}
+
+/// Queries for the first definitions of a file
+fn file_queries(file: &FileInfo) -> Vec<TuningQuery> {
+    extract_definitions(&file.content)
+        .into_iter()
+        .filter_map(|symbol| {
+            let line = definition_line(&file.content, &symbol)? + 1;
+            Some(TuningQuery {
+                text: symbol_words(&symbol),
+                file_path: file.relative_path.clone(),
+                line,
+            })
+        })
+        .take(QUERIES_PER_FILE)
+        .collect()
+}
+
+/// The words of an identifier, e.g. "load config" for `loadConfig` and
+/// `load_config`
+fn symbol_words(symbol: &str) -> String {
+    let mut words = String::new();
+    let mut previous_lower = false;
+    for c in symbol.chars() {
+        if c == '_' || c == '$' {
+            previous_lower = false;
+            if !words.ends_with(' ') && !words.is_empty() {
+                words.push(' ');
+            }
+            continue;
+        }
+        if c.is_uppercase() && previous_lower {
+            words.push(' ');
+        }
+        previous_lower = c.is_lowercase() || c.is_ascii_digit();
+        words.extend(c.to_lowercase());
+    }
+    words.trim().to_string()
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a * norm_b)
+    }
+}
+
+/// Score a setting from the 0-indexed rank each query's definition had
+/// among the top chunks
+fn score(setting: ChunkSetting, chunks: usize, ranks: &[Option<usize>]) -> ChunkSettingScore {
+    let queries = ranks.len().max(1) as f64;
+    ChunkSettingScore {
+        setting,
+        chunks,
+        hit_rate: ranks.iter().filter(|r| r.is_some()).count() as f64 / queries,
+        mean_reciprocal_rank: ranks
+            .iter()
+            .flatten()
+            .map(|rank| 1.0 / (rank + 1) as f64)
+            .sum::<f64>()
+            / queries,
+    }
+}
+
+/// The setting with the best mean reciprocal rank, then hit rate; a tie
+/// goes to the setting with fewer chunks, which is cheaper to embed
+fn recommend(scores: &[ChunkSettingScore]) -> ChunkSetting {
+    scores
+        .iter()
+        .min_by(|a, b| {
+            b.mean_reciprocal_rank
+                .total_cmp(&a.mean_reciprocal_rank)
+                .then(b.hit_rate.total_cmp(&a.hit_rate))
+                .then(a.chunks.cmp(&b.chunks))
+        })
+        .map(|s| s.setting)
+        .unwrap_or(DEFAULT_TUNING_SETTINGS[1])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use async_trait::async_trait;
+    use tempfile::tempdir;
+
+    use super::*;
+
+    /// Embeds text as counts of a few words, so a query matches the chunks
+    /// that use its words
+    struct WordCounts;
+
+    #[async_trait]
+    impl EmbeddingProvider for WordCounts {
+        fn name(&self) -> &'static str {
+            "words"
+        }
+
+        async fn create_embeddings_batch(
+            &self,
+            texts: &[String],
+            _model: &str,
+        ) -> WikiResult<Vec<Vec<f32>>> {
+            Ok(texts
+                .iter()
+                .map(|text| {
+                    let text = text.to_lowercase().replace('_', " ");
+                    ["load", "config", "parse", "token", "render", "page"]
+                        .iter()
+                        .map(|word| text.matches(word).count() as f32)
+                        .collect()
+                })
+                .collect())
+        }
+    }
+
+    #[test]
+    fn test_symbol_words() {
+        assert_eq!(symbol_words("load_config"), "load config");
+        assert_eq!(symbol_words("loadConfig"), "load config");
+        assert_eq!(symbol_words("_private"), "private");
+    }
+
+    #[test]
+    fn test_spread_takes_even_steps() {
+        assert_eq!(spread((0..10).collect(), 5), vec![0, 2, 4, 6, 8]);
+        assert_eq!(spread(vec![1, 2], 5), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_recommend_prefers_rank_then_fewer_chunks() {
+        let scores = vec![
+            score(ChunkSetting::new(200, 50), 40, &[Some(0), Some(1)]),
+            score(ChunkSetting::new(350, 100), 20, &[Some(0), Some(1)]),
+            score(ChunkSetting::new(800, 150), 10, &[Some(2), None]),
+        ];
+        assert!((scores[0].mean_reciprocal_rank - 0.75).abs() < 1e-9);
+        assert!((scores[2].hit_rate - 0.5).abs() < 1e-9);
+        assert_eq!(recommend(&scores), ChunkSetting::new(350, 100));
+    }
+
+    #[tokio::test]
+    async fn test_tune_scores_every_setting() {
+        let dir = tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("src")).unwrap();
+        fs::write(
+            dir.path().join("src/config.rs"),
+            "pub fn load_config() {}\n\nfn parse_token() {}\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("src/page.ts"),
+            "function renderPage() {}\nfunction loadPage() {}\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("src/more.py"),
+            "def parse_config():\n    pass\n",
+        )
+        .unwrap();
+        fs::write(dir.path().join("README.md"), "# Nothing defined here\n").unwrap();
+
+        let tuner = ChunkTuner::new(&WordCounts, "model")
+            .with_settings(vec![ChunkSetting::new(10, 0), ChunkSetting::new(350, 100)]);
+        let sample = tuner.sample(dir.path()).unwrap();
+        assert_eq!(sample.files(), 3);
+        assert_eq!(sample.queries.len(), 5);
+        assert_eq!(sample.queries[0].text, "load config");
+        assert_eq!(sample.queries[0].line, 1);
+
+        let report = tuner.tune(&sample).await.unwrap();
+        assert_eq!(report.queries, 5);
+        assert_eq!(report.scores.len(), 2);
+        assert!(report.scores.iter().all(|s| s.hit_rate > 0.0));
+
+        let scoped = ChunkTuner::new(&WordCounts, "model")
+            .with_roots(&["docs/".to_string()])
+            .sample(dir.path())
+            .unwrap();
+        assert!(matches!(
+            tuner.tune(&scoped).await,
+            Err(WikiError::IndexingFailed(_))
+        ));
+    }
+}
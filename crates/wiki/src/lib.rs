@@ -6,42 +6,123 @@
 //! # Architecture
 //!
 //! - **OpenRouter Client**: Embeddings and chat completions via OpenRouter API
-//! - **Vector Store**: SQLite + sqlite-vec for vector similarity search
+//! - **Embedding Providers**: OpenRouter, OpenAI or a local model for embeddings
+//! - **Vector Store**: SQLite + sqlite-vec for vector similarity search, also over
+//!   earlier indexes kept by commit
 //! - **Chunker**: Intelligent code splitting with overlap
-//! - **Indexer**: File traversal, chunking, and embedding creation
+//! - **Indexer**: File traversal, chunking, and embedding creation, with a
+//!   dry-run cost estimate and chunk size tuning
 //! - **Generator**: Wiki page generation with Mermaid diagrams
 //! - **RAG Engine**: Question answering over codebase
-
+//! - **Endpoints**: HTTP route inventory for API reference pages
+//! - **Public API**: Tagging of chunks that define public interfaces
+//! - **Data Model**: Database schema extraction from migrations
+//! - **Env Vars**: Environment variable reference extraction
+//! - **Test Coverage**: Mapping of source modules to their tests
+//! - **Onboarding**: Suggested reading order for new contributors
+//! - **Flow Tracing**: Step-by-step documentation of a request path
+//! - **Page Updates**: Diff-aware suggestions and rewrites for stale pages
+//! - **Review Context**: Wiki pages and related code for reviewing a diff
+//! - **Planning Context**: Code and pages related to a task description
+//! - **Re-embedding**: Incremental migration of a branch to a new embedding model
+//! - **Recall**: Semantic search over past tasks, findings and sessions
+
+pub mod change_summary;
 pub mod chunker;
+pub mod compare;
+pub mod data_model;
 pub mod domain;
+pub mod embeddings;
+pub mod endpoints;
+pub mod env_vars;
 pub mod error;
+pub mod flow;
 pub mod generator;
 pub mod git;
+pub mod glossary;
 pub mod indexer;
+pub mod onboarding;
 pub mod openrouter;
+pub mod page_updates;
+pub mod planning_context;
+pub mod public_api;
 pub mod rag;
+pub mod recall;
+pub mod reembed;
+pub mod review_context;
+pub mod summarizer;
+pub mod symbols;
 pub mod sync;
+pub mod test_coverage;
+pub mod usage;
 pub mod vector_store;
 
+pub use change_summary::ChangeSummarizer;
 pub use chunker::TextSplitter;
+pub use compare::{BranchComparison, ComparedPage, PageChange};
+pub use data_model::DataModel;
 pub use domain::{
-    chunk::{ChunkType, CodeChunk},
+    ask_feedback::{AskFeedback, AskFeedbackStats, AskRating, FeedbackSource, SourceVotes},
+    branch_lock::{BranchLock, BranchLockAttempt},
+    change_summary::{ChangeRisk, ChangeSummary, FileChangeSummary},
+    chunk::{ChunkType, CodeChunk, IndexedFile},
+    chunk_generation::{ChunkGeneration, DEFAULT_HISTORY_GENERATIONS},
+    embedding_index::{EmbeddingIndex, EmbeddingIndexState, LEGACY_EMBEDDING_MODEL},
     index_status::{IndexProgress, IndexState, IndexStatus},
-    search_result::SearchResult,
-    wiki_page::{Importance, PageType, SourceCitation, WikiPage, WikiStructure, WikiTree},
+    recall_item::{RecallItem, RecallMatch, RecallScope},
+    search_result::{
+        boost_symbol_matches, ContextExpansion, ExpansionRelation, SearchFilter, SearchMode,
+        SearchResult, SearchScope,
+    },
+    wiki_page::{
+        Importance, PageType, SkipReason, SkippedPage, SourceCitation, WikiPage, WikiStructure,
+        WikiTree,
+    },
     wiki_section::{GenerationMode, WikiSection},
 };
+#[cfg(feature = "local-embeddings")]
+pub use embeddings::LocalEmbeddings;
+pub use embeddings::{
+    EmbeddingProvider, EmbeddingProviderConfig, EmbeddingProviderKind, OpenAiEmbeddings,
+};
+pub use endpoints::{Endpoint, EndpointInventory, HttpFramework};
+pub use env_vars::{EnvVar, EnvVarReference, EnvVarUsage};
 pub use error::{WikiError, WikiResult};
-pub use generator::{analyzer::ProjectAnalyzer, WikiGenerator};
-pub use indexer::{reader::FileReader, CodeIndexer};
+pub use flow::{FlowDocument, FlowStep, FlowTrace, FlowTracer};
+pub use generator::{analyzer::ProjectAnalyzer, limits::PageLimits, WikiGenerator};
+pub use glossary::{Glossary, QueryExpansion};
+pub use indexer::{
+    estimate::{IndexEstimate, IndexEstimator},
+    normalize_root,
+    reader::FileReader,
+    root_of,
+    tuning::{
+        ChunkSetting, ChunkSettingScore, ChunkTuner, ChunkTuningReport, DEFAULT_TUNING_FILES,
+    },
+    CodeIndexer, DEFAULT_EMBEDDING_CONCURRENCY,
+};
+pub use onboarding::{OnboardingPath, OnboardingStage, OnboardingStep};
 pub use openrouter::client::OpenRouterClient;
+pub use openrouter::scheduler::{ModelUsage, RateLimitConfig, RequestScheduler};
 pub use openrouter::types::ChatMessage;
-pub use rag::{Conversation, Message, MessageRole, RagEngine, RagResponse, RagSource};
+pub use page_updates::{PageUpdate, PageUpdateAction, PageUpdater};
+pub use planning_context::{PlanningContext, PlanningContextBuilder};
+pub use rag::{
+    AnswerMode, CallGraphExpansion, Conversation, Message, MessageRole, QueryDecomposition,
+    RagEngine, RagResponse, RagSource,
+};
+pub use recall::RecallIndex;
+pub use reembed::{ReembedProgress, Reembedder};
+pub use review_context::{ReviewContext, ReviewContextBuilder};
+pub use summarizer::ChunkSummarizer;
 pub use sync::WikiSyncService;
-pub use vector_store::VectorStore;
+pub use test_coverage::{ModuleCoverage, TestCoverageMap};
+pub use usage::{ModelPrices, UsageKind, UsageRecord, UsageScope, UsageSink, UsageTracker};
+pub use vector_store::{BranchLockGuard, VectorStore};
 
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::sync::Arc;
 
 /// Configuration for the Wiki engine
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -81,6 +162,49 @@ pub struct WikiConfig {
     /// Access token for private repositories (GitHub PAT, GitLab token, etc.)
     #[serde(default)]
     pub access_token: Option<String>,
+
+    /// Summarize each chunk with the chat model while indexing
+    #[serde(default)]
+    pub chunk_summaries: bool,
+
+    /// Sub-directories of a monorepo to index, e.g. `["backend/", "frontend/"]`.
+    /// Chunks are tagged with their root so searches can be scoped to one;
+    /// empty indexes the whole repository.
+    #[serde(default)]
+    pub roots: Vec<String>,
+
+    /// Where embeddings come from; chat always goes through OpenRouter
+    #[serde(default)]
+    pub embedding_provider: EmbeddingProviderKind,
+
+    /// OpenAI API key, for the `openai` embedding provider
+    #[serde(default)]
+    pub openai_api_key: Option<String>,
+
+    /// Model directory, for the `local` embedding provider
+    #[serde(default)]
+    pub local_model_path: Option<PathBuf>,
+
+    /// Vector dimension of `embedding_model`, e.g. 3072 for
+    /// text-embedding-3-large; taken from the first embedding when unset
+    #[serde(default)]
+    pub embedding_dimension: Option<usize>,
+
+    /// Earlier indexes of each branch kept for searches as of their commit
+    #[serde(default = "default_history_generations")]
+    pub history_generations: usize,
+
+    /// Embedding batches requested at once while indexing
+    #[serde(default = "default_embedding_concurrency")]
+    pub embedding_concurrency: usize,
+}
+
+fn default_history_generations() -> usize {
+    DEFAULT_HISTORY_GENERATIONS
+}
+
+fn default_embedding_concurrency() -> usize {
+    DEFAULT_EMBEDDING_CONCURRENCY
 }
 
 impl Default for WikiConfig {
@@ -97,6 +221,40 @@ impl Default for WikiConfig {
             api_base_url: "https://openrouter.ai/api/v1".to_string(),
             repo_url: None,
             access_token: None,
+            chunk_summaries: false,
+            roots: Vec::new(),
+            embedding_provider: EmbeddingProviderKind::default(),
+            openai_api_key: None,
+            local_model_path: None,
+            embedding_dimension: None,
+            history_generations: DEFAULT_HISTORY_GENERATIONS,
+            embedding_concurrency: DEFAULT_EMBEDDING_CONCURRENCY,
+        }
+    }
+}
+
+impl WikiConfig {
+    /// Settings for the configured embedding provider
+    pub fn embedding_provider_config(&self) -> EmbeddingProviderConfig {
+        EmbeddingProviderConfig {
+            kind: self.embedding_provider,
+            openrouter_api_key: Some(self.openrouter_api_key.clone()),
+            openrouter_base_url: Some(self.api_base_url.clone()),
+            openai_api_key: self.openai_api_key.clone(),
+            local_model_path: self.local_model_path.clone(),
+        }
+    }
+
+    /// Open the vector store, sizing the embedding model's vector table to
+    /// `embedding_dimension` when it is set
+    pub fn open_vector_store(&self) -> WikiResult<VectorStore> {
+        match self.embedding_dimension {
+            Some(dimension) => VectorStore::with_embedding_dimension(
+                &self.db_path,
+                &self.embedding_model,
+                dimension,
+            ),
+            None => VectorStore::new(&self.db_path),
         }
     }
 }
@@ -105,6 +263,7 @@ impl Default for WikiConfig {
 pub struct WikiEngine {
     config: WikiConfig,
     openrouter: OpenRouterClient,
+    embeddings: Arc<dyn EmbeddingProvider>,
     vector_store: VectorStore,
     text_splitter: TextSplitter,
 }
@@ -117,12 +276,14 @@ impl WikiEngine {
             config.api_base_url.clone(),
         );
 
-        let vector_store = VectorStore::new(&config.db_path)?;
+        let embeddings = config.embedding_provider_config().build()?;
+        let vector_store = config.open_vector_store()?;
         let text_splitter = TextSplitter::new(config.max_chunk_tokens, config.chunk_overlap);
 
         Ok(Self {
             config,
             openrouter,
+            embeddings,
             vector_store,
             text_splitter,
         })
@@ -138,6 +299,11 @@ impl WikiEngine {
         &self.openrouter
     }
 
+    /// Get the embedding provider
+    pub fn embeddings(&self) -> &dyn EmbeddingProvider {
+        self.embeddings.as_ref()
+    }
+
     /// Get a reference to the vector store
     pub fn vector_store(&self) -> &VectorStore {
         &self.vector_store
@@ -153,16 +319,34 @@ impl WikiEngine {
         self.vector_store.get_index_status(branch)
     }
 
-    /// Search for similar code chunks
-    pub async fn search(&self, query: &str, limit: usize) -> WikiResult<Vec<SearchResult>> {
+    /// Search for code chunks matching `query`, in the workspace `root` if
+    /// it is set
+    pub async fn search(
+        &self,
+        query: &str,
+        mode: SearchMode,
+        limit: usize,
+        root: Option<&str>,
+    ) -> WikiResult<Vec<SearchResult>> {
+        if !mode.needs_embedding() {
+            return self.vector_store.search_keyword(query, limit, None, root);
+        }
+
         // Create embedding for query
         let embedding = self
-            .openrouter
+            .embeddings
             .create_embedding(query, &self.config.embedding_model)
             .await?;
 
-        // Search vector store
-        self.vector_store.search_similar(&embedding, limit)
+        let model = &self.config.embedding_model;
+        match mode {
+            SearchMode::Hybrid => self
+                .vector_store
+                .search_hybrid(query, &embedding, model, limit, None, root),
+            _ => self
+                .vector_store
+                .search_similar_in_branch(&embedding, model, limit, None, root),
+        }
     }
 
     /// Get wiki page by slug
@@ -170,6 +354,38 @@ impl WikiEngine {
         self.vector_store.get_wiki_page(slug)
     }
 
+    /// Get the HTTP endpoint inventory extracted from a branch's indexed code
+    pub fn get_endpoints(&self, branch: &str) -> WikiResult<EndpointInventory> {
+        let chunks = self.vector_store.get_branch_chunks(branch)?;
+        Ok(EndpointInventory::from_chunks(&chunks))
+    }
+
+    /// Get the environment variable reference extracted from a branch's indexed code
+    pub fn get_env_vars(&self, branch: &str) -> WikiResult<EnvVarReference> {
+        let chunks = self.vector_store.get_branch_chunks(branch)?;
+        Ok(EnvVarReference::from_chunks(&chunks))
+    }
+
+    /// Get the map of source modules to their tests for a branch's indexed code
+    pub fn get_test_coverage(&self, branch: &str) -> WikiResult<TestCoverageMap> {
+        let chunks = self.vector_store.get_branch_chunks(branch)?;
+        Ok(TestCoverageMap::from_chunks(&chunks))
+    }
+
+    /// Get the suggested onboarding reading order for a branch's wiki
+    pub fn get_onboarding(&self, branch: &str) -> WikiResult<OnboardingPath> {
+        let pages = self.vector_store.get_branch_pages(branch)?;
+        let chunks = self.vector_store.get_branch_chunks(branch)?;
+        Ok(OnboardingPath::from_pages(&pages, &chunks))
+    }
+
+    /// Compare the wiki pages of two branches
+    pub fn compare_branches(&self, base: &str, head: &str) -> WikiResult<BranchComparison> {
+        let base_pages = self.vector_store.get_branch_pages(base)?;
+        let head_pages = self.vector_store.get_branch_pages(head)?;
+        Ok(BranchComparison::from_pages(&base_pages, &head_pages))
+    }
+
     /// Get wiki structure (tree of pages)
     pub fn get_structure(&self, branch: &str) -> WikiResult<Option<WikiStructure>> {
         self.vector_store.get_wiki_structure(branch)
@@ -0,0 +1,489 @@
+//! Onboarding path: a suggested reading order through the wiki
+//!
+//! Pages are grouped into three stages, read in order:
+//!
+//! 1. **Entry points**: the overview and pages documenting entry files
+//!    (`main.rs`, `lib.rs`, `index.ts`, ...)
+//! 2. **Core modules**: module and file pages, most depended-on first, so that
+//!    foundations are read before the code built on them
+//! 3. **Key flows**: API and architecture pages that tie the modules together
+//!
+//! Dependencies between pages come from a file-level call graph: a file
+//! depends on another when it calls a function that only the other defines.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::chunk::CodeChunk;
+use crate::domain::wiki_page::{Importance, PageType, WikiPage};
+use crate::symbols;
+use crate::test_coverage::is_test_path;
+
+/// Slug of the generated onboarding page
+pub const ONBOARDING_PAGE_SLUG: &str = "onboarding";
+
+/// Reading speed used for time estimates
+const WORDS_PER_MINUTE: usize = 200;
+
+/// Maximum number of core module pages on the path
+const MAX_CORE_MODULES: usize = 8;
+
+/// Maximum number of key flow pages on the path
+const MAX_KEY_FLOWS: usize = 5;
+
+/// File names that mark a program or library entry point
+const ENTRY_POINT_FILES: &[&str] = &[
+    "main.rs",
+    "lib.rs",
+    "main.py",
+    "app.py",
+    "__main__.py",
+    "main.go",
+    "index.ts",
+    "index.js",
+    "main.ts",
+    "main.tsx",
+    "app.ts",
+    "app.tsx",
+];
+
+/// Stage of the onboarding path a page belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OnboardingStage {
+    EntryPoint,
+    CoreModule,
+    KeyFlow,
+}
+
+impl OnboardingStage {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OnboardingStage::EntryPoint => "entry_point",
+            OnboardingStage::CoreModule => "core_module",
+            OnboardingStage::KeyFlow => "key_flow",
+        }
+    }
+
+    fn heading(&self) -> &'static str {
+        match self {
+            OnboardingStage::EntryPoint => "Start Here",
+            OnboardingStage::CoreModule => "Core Modules",
+            OnboardingStage::KeyFlow => "Key Flows",
+        }
+    }
+}
+
+/// A single page on the onboarding path
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OnboardingStep {
+    pub slug: String,
+    pub title: String,
+    pub stage: OnboardingStage,
+    /// Why the page is on the path
+    pub reason: String,
+    pub reading_minutes: u32,
+}
+
+/// Ordered reading list for new contributors
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OnboardingPath {
+    pub steps: Vec<OnboardingStep>,
+}
+
+impl OnboardingPath {
+    /// Compute the path from a branch's wiki pages and indexed chunks.
+    /// Generated reference pages (and a previous onboarding page) are left out.
+    pub fn from_pages(pages: &[WikiPage], chunks: &[CodeChunk]) -> Self {
+        let pages: Vec<&WikiPage> = pages
+            .iter()
+            .filter(|p| p.slug != ONBOARDING_PAGE_SLUG && p.page_type != PageType::Custom)
+            .collect();
+        let dependents = page_dependents(&pages, &file_dependencies(chunks));
+
+        let mut steps = Vec::new();
+        let mut placed = HashSet::new();
+
+        let mut entry_points: Vec<(&WikiPage, String)> = pages
+            .iter()
+            .filter_map(|p| {
+                if p.page_type == PageType::Overview {
+                    return Some((*p, "Project overview".to_string()));
+                }
+                p.file_paths
+                    .iter()
+                    .find(|f| is_entry_point(f))
+                    .map(|f| (*p, format!("Documents entry point `{}`", f)))
+            })
+            .collect();
+        entry_points.sort_by_key(|(p, _)| (p.page_type != PageType::Overview, p.order));
+        for (page, reason) in entry_points {
+            placed.insert(page.slug.as_str());
+            steps.push(OnboardingStep::new(
+                page,
+                OnboardingStage::EntryPoint,
+                reason,
+            ));
+        }
+
+        let mut core: Vec<&WikiPage> = pages
+            .iter()
+            .copied()
+            .filter(|p| matches!(p.page_type, PageType::Module | PageType::File))
+            .filter(|p| !placed.contains(p.slug.as_str()) && p.importance != Importance::Low)
+            .collect();
+        core.sort_by_key(|p| {
+            (
+                std::cmp::Reverse(dependents.get(p.slug.as_str()).copied().unwrap_or(0)),
+                importance_rank(p.importance),
+                p.order,
+            )
+        });
+        for page in core.into_iter().take(MAX_CORE_MODULES) {
+            let reason = match dependents.get(page.slug.as_str()).copied().unwrap_or(0) {
+                0 => format!("{} importance module", capitalize(page.importance.as_str())),
+                1 => "Used by 1 other page".to_string(),
+                n => format!("Used by {} other pages", n),
+            };
+            placed.insert(page.slug.as_str());
+            steps.push(OnboardingStep::new(
+                page,
+                OnboardingStage::CoreModule,
+                reason,
+            ));
+        }
+
+        let mut flows: Vec<&WikiPage> = pages
+            .iter()
+            .copied()
+            .filter(|p| matches!(p.page_type, PageType::Api | PageType::Architecture))
+            .filter(|p| !placed.contains(p.slug.as_str()))
+            .collect();
+        flows.sort_by_key(|p| (importance_rank(p.importance), p.order));
+        for page in flows.into_iter().take(MAX_KEY_FLOWS) {
+            let reason = match page.page_type {
+                PageType::Api => "How requests enter the system",
+                _ => "How the pieces fit together",
+            };
+            steps.push(OnboardingStep::new(
+                page,
+                OnboardingStage::KeyFlow,
+                reason.to_string(),
+            ));
+        }
+
+        Self { steps }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    pub fn total_minutes(&self) -> u32 {
+        self.steps.iter().map(|s| s.reading_minutes).sum()
+    }
+
+    /// Render the path as a numbered markdown reading list
+    pub fn to_markdown(&self) -> String {
+        let mut md = String::from("# Onboarding\n\n");
+        md.push_str(&format!(
+            "A suggested reading order for new contributors: {} page{}, about {} minute{} \
+             of reading. Start with the entry points, then work through the core modules \
+             (most depended-on first) and finish with the flows that connect them.\n",
+            self.steps.len(),
+            if self.steps.len() == 1 { "" } else { "s" },
+            self.total_minutes(),
+            if self.total_minutes() == 1 { "" } else { "s" },
+        ));
+
+        let mut current_stage = None;
+        for (i, step) in self.steps.iter().enumerate() {
+            if current_stage != Some(step.stage) {
+                current_stage = Some(step.stage);
+                md.push_str(&format!("\n## {}\n\n", step.stage.heading()));
+            }
+            md.push_str(&format!(
+                "{}. [{}](#{}) - {} ({} min)\n",
+                i + 1,
+                step.title,
+                step.slug,
+                step.reason,
+                step.reading_minutes
+            ));
+        }
+
+        md
+    }
+
+    /// Render the path as a wiki page
+    pub fn to_wiki_page(&self, branch: &str, commit_sha: &str, order: u32) -> WikiPage {
+        WikiPage::new_advanced(
+            branch.to_string(),
+            ONBOARDING_PAGE_SLUG.to_string(),
+            "Onboarding".to_string(),
+            self.to_markdown(),
+            PageType::Custom,
+            None,
+            order,
+            Vec::new(),
+            commit_sha.to_string(),
+            Importance::High,
+            self.steps.iter().map(|s| s.slug.clone()).collect(),
+            None,
+            Vec::new(),
+        )
+    }
+}
+
+impl OnboardingStep {
+    fn new(page: &WikiPage, stage: OnboardingStage, reason: String) -> Self {
+        Self {
+            slug: page.slug.clone(),
+            title: page.title.clone(),
+            stage,
+            reason,
+            reading_minutes: reading_minutes(&page.content),
+        }
+    }
+}
+
+/// Estimated minutes to read `content`, at least one
+pub fn reading_minutes(content: &str) -> u32 {
+    let words = content.split_whitespace().count();
+    words.div_ceil(WORDS_PER_MINUTE).max(1) as u32
+}
+
+fn is_entry_point(file_path: &str) -> bool {
+    let name = file_path.rsplit('/').next().unwrap_or(file_path);
+    ENTRY_POINT_FILES.contains(&name) && !is_test_path(file_path)
+}
+
+fn importance_rank(importance: Importance) -> u8 {
+    match importance {
+        Importance::High => 0,
+        Importance::Medium => 1,
+        Importance::Low => 2,
+    }
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .map(|c| c.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+/// File-level dependencies: file -> files defining the functions it calls.
+/// Only functions defined in exactly one file create an edge.
+fn file_dependencies(chunks: &[CodeChunk]) -> BTreeMap<&str, BTreeSet<&str>> {
+    let mut definitions: BTreeMap<&str, BTreeSet<String>> = BTreeMap::new();
+    let mut calls: BTreeMap<&str, BTreeSet<String>> = BTreeMap::new();
+    for chunk in chunks.iter().filter(|c| !is_test_path(&c.file_path)) {
+        definitions
+            .entry(&chunk.file_path)
+            .or_default()
+            .extend(symbols::extract_definitions(&chunk.content));
+        calls
+            .entry(&chunk.file_path)
+            .or_default()
+            .extend(symbols::extract_calls(&chunk.content));
+    }
+
+    let mut definers: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (path, defs) in &definitions {
+        for def in defs {
+            definers.entry(def.as_str()).or_default().push(*path);
+        }
+    }
+
+    let mut dependencies: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    for (path, file_calls) in &calls {
+        for call in file_calls {
+            if let Some([definer]) = definers.get(call.as_str()).map(Vec::as_slice) {
+                if definer != path {
+                    dependencies.entry(*path).or_default().insert(*definer);
+                }
+            }
+        }
+    }
+    dependencies
+}
+
+/// Number of other pages whose files depend on each page's files
+fn page_dependents<'a>(
+    pages: &[&'a WikiPage],
+    dependencies: &BTreeMap<&str, BTreeSet<&str>>,
+) -> HashMap<&'a str, usize> {
+    let mut pages_by_file: HashMap<&str, Vec<&str>> = HashMap::new();
+    for page in pages {
+        for file in &page.file_paths {
+            pages_by_file
+                .entry(file.as_str())
+                .or_default()
+                .push(&page.slug);
+        }
+    }
+
+    let mut dependents: HashMap<&'a str, BTreeSet<&str>> = HashMap::new();
+    for page in pages {
+        let depended_on: BTreeSet<&str> = page
+            .file_paths
+            .iter()
+            .filter_map(|f| dependencies.get(f.as_str()))
+            .flatten()
+            .filter_map(|f| pages_by_file.get(f))
+            .flatten()
+            .copied()
+            .filter(|slug| *slug != page.slug)
+            .collect();
+        for slug in depended_on {
+            if let Some(target) = pages.iter().find(|p| p.slug == slug) {
+                dependents
+                    .entry(target.slug.as_str())
+                    .or_default()
+                    .insert(&page.slug);
+            }
+        }
+    }
+
+    dependents
+        .into_iter()
+        .map(|(slug, pages)| (slug, pages.len()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::chunk::ChunkType;
+
+    fn page(slug: &str, page_type: PageType, files: &[&str], importance: Importance) -> WikiPage {
+        WikiPage::new_advanced(
+            "main".to_string(),
+            slug.to_string(),
+            slug.to_uppercase(),
+            "word ".repeat(450),
+            page_type,
+            None,
+            0,
+            files.iter().map(|f| f.to_string()).collect(),
+            "abc".to_string(),
+            importance,
+            Vec::new(),
+            None,
+            Vec::new(),
+        )
+    }
+
+    fn chunk(file_path: &str, content: &str) -> CodeChunk {
+        CodeChunk::new(
+            "main".to_string(),
+            file_path.to_string(),
+            1,
+            1,
+            content.to_string(),
+            ChunkType::Code,
+            None,
+            0,
+            0,
+            "abc".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_reading_minutes() {
+        assert_eq!(reading_minutes(""), 1);
+        assert_eq!(reading_minutes(&"word ".repeat(200)), 1);
+        assert_eq!(reading_minutes(&"word ".repeat(201)), 2);
+    }
+
+    #[test]
+    fn test_path_orders_stages_and_dependencies() {
+        let pages = vec![
+            page("api", PageType::Api, &[], Importance::High),
+            page("cli", PageType::File, &["src/cli.rs"], Importance::Medium),
+            page(
+                "store",
+                PageType::Module,
+                &["src/store.rs"],
+                Importance::Medium,
+            ),
+            page("overview", PageType::Overview, &[], Importance::High),
+            page("core", PageType::Module, &["src/lib.rs"], Importance::High),
+            page(
+                "helpers",
+                PageType::File,
+                &["src/helpers.rs"],
+                Importance::Low,
+            ),
+            page(
+                "env-vars",
+                PageType::Custom,
+                &["src/cli.rs"],
+                Importance::Medium,
+            ),
+        ];
+        let chunks = vec![
+            chunk("src/lib.rs", "pub fn run() { open_store(); }"),
+            chunk("src/cli.rs", "fn parse_args() { open_store(); }"),
+            chunk("src/store.rs", "pub fn open_store() {}"),
+        ];
+
+        let path = OnboardingPath::from_pages(&pages, &chunks);
+
+        let order: Vec<_> = path
+            .steps
+            .iter()
+            .map(|s| (s.slug.as_str(), s.stage))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                ("overview", OnboardingStage::EntryPoint),
+                ("core", OnboardingStage::EntryPoint),
+                ("store", OnboardingStage::CoreModule),
+                ("cli", OnboardingStage::CoreModule),
+                ("api", OnboardingStage::KeyFlow),
+            ]
+        );
+        assert_eq!(path.steps[1].reason, "Documents entry point `src/lib.rs`");
+        assert_eq!(path.steps[2].reason, "Used by 2 other pages");
+        assert_eq!(path.steps[3].reason, "Medium importance module");
+        assert_eq!(path.total_minutes(), 15);
+    }
+
+    #[test]
+    fn test_markdown_links_pages_by_stage() {
+        let path = OnboardingPath {
+            steps: vec![
+                OnboardingStep {
+                    slug: "overview".to_string(),
+                    title: "Overview".to_string(),
+                    stage: OnboardingStage::EntryPoint,
+                    reason: "Project overview".to_string(),
+                    reading_minutes: 2,
+                },
+                OnboardingStep {
+                    slug: "auth".to_string(),
+                    title: "Auth".to_string(),
+                    stage: OnboardingStage::CoreModule,
+                    reason: "Used by 3 other pages".to_string(),
+                    reading_minutes: 4,
+                },
+            ],
+        };
+
+        let page = path.to_wiki_page("main", "abc", 9);
+        assert_eq!(page.slug, ONBOARDING_PAGE_SLUG);
+        assert_eq!(page.related_pages, vec!["overview", "auth"]);
+        assert!(page.content.contains("2 pages, about 6 minutes"));
+        assert!(page
+            .content
+            .contains("## Start Here\n\n1. [Overview](#overview) - Project overview (2 min)"));
+        assert!(page
+            .content
+            .contains("## Core Modules\n\n2. [Auth](#auth) - Used by 3 other pages (4 min)"));
+    }
+}
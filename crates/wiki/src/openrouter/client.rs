@@ -1,21 +1,32 @@
-use std::time::Duration;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
 
 use reqwest::Client;
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, warn};
 
+use super::scheduler::RequestScheduler;
 use super::types::*;
 use crate::error::{WikiError, WikiResult};
+use crate::usage::{ModelPrices, UsageKind, UsageTracker};
 
-const DEFAULT_MAX_RETRIES: u32 = 3;
-const INITIAL_BACKOFF_MS: u64 = 1000;
-const MAX_BACKOFF_MS: u64 = 60000;
+/// Prices of models by base URL and model; `None` for unpriced models
+type PriceCache = Mutex<HashMap<(String, String), Option<ModelPrices>>>;
+
+/// Model prices, looked up once per process
+static PRICES: OnceLock<PriceCache> = OnceLock::new();
 
 /// Client for OpenRouter API
+///
+/// Requests go through a [`RequestScheduler`]; clients created with
+/// [`OpenRouterClient::new`] share the process-wide one, so all of them
+/// spend the same rate-limit budget.
 #[derive(Clone)]
 pub struct OpenRouterClient {
     client: Client,
     api_key: String,
     base_url: String,
+    scheduler: Arc<RequestScheduler>,
+    usage: Option<UsageTracker>,
 }
 
 impl OpenRouterClient {
@@ -24,90 +35,33 @@ impl OpenRouterClient {
             client: Client::new(),
             api_key,
             base_url,
+            scheduler: RequestScheduler::shared(),
+            usage: None,
         }
     }
 
-    async fn with_retry<T, F, Fut>(&self, operation: F, operation_name: &str) -> WikiResult<T>
-    where
-        F: Fn() -> Fut,
-        Fut: std::future::Future<Output = WikiResult<T>>,
-    {
-        let mut retries = 0;
-        let mut backoff_ms = INITIAL_BACKOFF_MS;
-
-        loop {
-            match operation().await {
-                Ok(result) => return Ok(result),
-                Err(WikiError::RateLimited { retry_after }) => {
-                    if retries >= DEFAULT_MAX_RETRIES {
-                        error!(
-                            "{} failed after {} retries due to rate limiting",
-                            operation_name, retries
-                        );
-                        return Err(WikiError::RateLimited { retry_after });
-                    }
+    /// Report the tokens and cost of every successful call to `tracker`
+    pub fn with_usage_tracker(mut self, tracker: UsageTracker) -> Self {
+        self.usage = Some(tracker);
+        self
+    }
 
-                    let wait_ms = retry_after
-                        .map(|s| s * 1000)
-                        .unwrap_or(backoff_ms)
-                        .min(MAX_BACKOFF_MS);
-
-                    warn!(
-                        "{} rate limited, retrying in {}ms (attempt {}/{})",
-                        operation_name,
-                        wait_ms,
-                        retries + 1,
-                        DEFAULT_MAX_RETRIES
-                    );
-
-                    tokio::time::sleep(Duration::from_millis(wait_ms)).await;
-                    retries += 1;
-                    backoff_ms = (backoff_ms * 2).min(MAX_BACKOFF_MS);
-                }
-                Err(WikiError::OpenRouterApi {
-                    ref message,
-                    status_code: Some(code),
-                }) if code >= 500 => {
-                    if retries >= DEFAULT_MAX_RETRIES {
-                        error!(
-                            "{} failed after {} retries due to server error: {}",
-                            operation_name, retries, message
-                        );
-                        return Err(WikiError::OpenRouterApi {
-                            message: message.clone(),
-                            status_code: Some(code),
-                        });
-                    }
+    /// Schedule requests with `scheduler` instead of the shared one
+    pub fn with_scheduler(mut self, scheduler: Arc<RequestScheduler>) -> Self {
+        self.scheduler = scheduler;
+        self
+    }
 
-                    warn!(
-                        "{} server error ({}), retrying in {}ms (attempt {}/{})",
-                        operation_name,
-                        code,
-                        backoff_ms,
-                        retries + 1,
-                        DEFAULT_MAX_RETRIES
-                    );
-
-                    tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
-                    retries += 1;
-                    backoff_ms = (backoff_ms * 2).min(MAX_BACKOFF_MS);
-                }
-                Err(e) => {
-                    if retries > 0 {
-                        info!("{} failed after {} retries: {}", operation_name, retries, e);
-                    }
-                    return Err(e);
-                }
-            }
-        }
+    pub fn scheduler(&self) -> &Arc<RequestScheduler> {
+        &self.scheduler
     }
 
     pub async fn create_embedding(&self, text: &str, model: &str) -> WikiResult<Vec<f32>> {
         let text = text.to_string();
         let model = model.to_string();
 
-        self.with_retry(
-            || async {
+        self.scheduler
+            .run(&model, "create_embedding", || async {
                 let texts = vec![text.clone()];
                 let embeddings = self.create_embeddings_batch_inner(&texts, &model).await?;
                 embeddings
@@ -117,10 +71,8 @@ impl OpenRouterClient {
                         message: "No embedding returned".to_string(),
                         status_code: None,
                     })
-            },
-            "create_embedding",
-        )
-        .await
+            })
+            .await
     }
 
     pub async fn create_embeddings_batch(
@@ -135,11 +87,11 @@ impl OpenRouterClient {
         let texts = texts.to_vec();
         let model = model.to_string();
 
-        self.with_retry(
-            || async { self.create_embeddings_batch_inner(&texts, &model).await },
-            "create_embeddings_batch",
-        )
-        .await
+        self.scheduler
+            .run(&model, "create_embeddings_batch", || async {
+                self.create_embeddings_batch_inner(&texts, &model).await
+            })
+            .await
     }
 
     async fn create_embeddings_batch_inner(
@@ -177,12 +129,13 @@ impl OpenRouterClient {
 
         let status = response.status();
         if !status.is_success() {
+            let retry_after = retry_after(&response);
             let error_text = response.text().await.unwrap_or_default();
 
             // Check for rate limiting
             if status.as_u16() == 429 {
                 warn!("Rate limited by OpenRouter");
-                return Err(WikiError::RateLimited { retry_after: None });
+                return Err(WikiError::RateLimited { retry_after });
             }
 
             // Try to parse error response
@@ -204,6 +157,9 @@ impl OpenRouterClient {
         }
 
         let embedding_response: EmbeddingResponse = response.json().await?;
+        let tokens = u64::from(embedding_response.usage.total_tokens);
+        self.scheduler.record_tokens(model, tokens);
+        self.track(model, UsageKind::Embedding, tokens, 0).await;
 
         // Sort by index and extract embeddings
         let mut data = embedding_response.data;
@@ -221,14 +177,12 @@ impl OpenRouterClient {
     ) -> WikiResult<String> {
         let model = model.to_string();
 
-        self.with_retry(
-            || async {
+        self.scheduler
+            .run(&model, "chat_completion", || async {
                 self.chat_completion_inner(messages.clone(), &model, temperature, max_tokens)
                     .await
-            },
-            "chat_completion",
-        )
-        .await
+            })
+            .await
     }
 
     async fn chat_completion_inner(
@@ -263,11 +217,12 @@ impl OpenRouterClient {
 
         let status = response.status();
         if !status.is_success() {
+            let retry_after = retry_after(&response);
             let error_text = response.text().await.unwrap_or_default();
 
             if status.as_u16() == 429 {
                 warn!("Rate limited by OpenRouter");
-                return Err(WikiError::RateLimited { retry_after: None });
+                return Err(WikiError::RateLimited { retry_after });
             }
 
             if let Ok(error_resp) = serde_json::from_str::<OpenRouterError>(&error_text) {
@@ -288,6 +243,17 @@ impl OpenRouterClient {
         }
 
         let chat_response: ChatCompletionResponse = response.json().await?;
+        if let Some(usage) = &chat_response.usage {
+            self.scheduler
+                .record_tokens(model, u64::from(usage.total_tokens));
+            self.track(
+                model,
+                UsageKind::Chat,
+                u64::from(usage.prompt_tokens),
+                u64::from(usage.completion_tokens),
+            )
+            .await;
+        }
 
         chat_response
             .choices
@@ -300,6 +266,79 @@ impl OpenRouterClient {
             })
     }
 
+    /// Price of one input token of `model` in USD, from the models
+    /// endpoint; `None` when the model is not listed or has no price
+    pub async fn model_price(&self, model: &str) -> WikiResult<Option<f64>> {
+        Ok(self.model_prices(model).await?.map(|prices| prices.prompt))
+    }
+
+    /// Prices of `model` from the models endpoint; `None` when the model is
+    /// not listed or has no price
+    pub async fn model_prices(&self, model: &str) -> WikiResult<Option<ModelPrices>> {
+        let response = self
+            .client
+            .get(format!("{}/models", self.base_url))
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .send()
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(WikiError::OpenRouterApi {
+                message: response.text().await.unwrap_or_default(),
+                status_code: Some(status.as_u16()),
+            });
+        }
+
+        let models: ModelsResponse = response.json().await?;
+        Ok(models
+            .data
+            .into_iter()
+            .find(|m| m.id == model)
+            .and_then(|m| m.pricing)
+            .and_then(|p| {
+                Some(ModelPrices {
+                    prompt: p.prompt_per_token()?,
+                    completion: p.completion_per_token()?,
+                })
+            }))
+    }
+
+    /// Report a call to the usage tracker, if there is one. Prices are
+    /// looked up once per model; a failed lookup leaves the cost unknown.
+    async fn track(
+        &self,
+        model: &str,
+        kind: UsageKind,
+        prompt_tokens: u64,
+        completion_tokens: u64,
+    ) {
+        let Some(tracker) = &self.usage else {
+            return;
+        };
+
+        let prices = PRICES.get_or_init(Default::default);
+        let key = (self.base_url.clone(), model.to_string());
+        let cached = prices.lock().unwrap().get(&key).copied();
+        let model_prices = match cached {
+            Some(cached) => cached,
+            None => match self.model_prices(model).await {
+                Ok(found) => {
+                    prices.lock().unwrap().insert(key, found);
+                    found
+                }
+                Err(e) => {
+                    debug!(model, error = %e, "Failed to look up model prices");
+                    None
+                }
+            },
+        };
+
+        tracker
+            .record(model, kind, prompt_tokens, completion_tokens, model_prices)
+            .await;
+    }
+
     /// Create a streaming chat completion
     ///
     /// Returns a stream of content chunks. Use `eventsource-stream` to parse SSE.
@@ -327,69 +366,98 @@ impl OpenRouterClient {
             stream: Some(true),
         };
 
+        // Only opening the stream is scheduled and retried; dropped streams
+        // are resumed by the caller
         let response = self
-            .client
-            .post(format!("{}/chat/completions", self.base_url))
-            .header("Authorization", format!("Bearer {}", self.api_key))
-            .header("Content-Type", "application/json")
-            .json(&request)
-            .send()
-            .await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response.text().await.unwrap_or_default();
+            .scheduler
+            .run(model, "chat_completion_stream", || async {
+                let response = self
+                    .client
+                    .post(format!("{}/chat/completions", self.base_url))
+                    .header("Authorization", format!("Bearer {}", self.api_key))
+                    .header("Content-Type", "application/json")
+                    .json(&request)
+                    .send()
+                    .await?;
+
+                let status = response.status();
+                if !status.is_success() {
+                    let retry_after = retry_after(&response);
+                    let error_text = response.text().await.unwrap_or_default();
+
+                    if status.as_u16() == 429 {
+                        return Err(WikiError::RateLimited { retry_after });
+                    }
 
-            if status.as_u16() == 429 {
-                return Err(WikiError::RateLimited { retry_after: None });
-            }
+                    if let Ok(error_resp) = serde_json::from_str::<OpenRouterError>(&error_text) {
+                        return Err(WikiError::OpenRouterApi {
+                            message: error_resp.error.message,
+                            status_code: Some(status.as_u16()),
+                        });
+                    }
 
-            if let Ok(error_resp) = serde_json::from_str::<OpenRouterError>(&error_text) {
-                return Err(WikiError::OpenRouterApi {
-                    message: error_resp.error.message,
-                    status_code: Some(status.as_u16()),
-                });
-            }
+                    return Err(WikiError::OpenRouterApi {
+                        message: error_text,
+                        status_code: Some(status.as_u16()),
+                    });
+                }
 
-            return Err(WikiError::OpenRouterApi {
-                message: error_text,
-                status_code: Some(status.as_u16()),
-            });
-        }
+                Ok(response)
+            })
+            .await?;
 
         let byte_stream = response
             .bytes_stream()
             .map(|r| r.map_err(std::io::Error::other));
 
         let event_stream = byte_stream.eventsource();
+        let tracked = self
+            .usage
+            .is_some()
+            .then(|| (self.clone(), model.to_string()));
 
         // Map SSE events to content strings
-        let content_stream = event_stream.filter_map(|event_result| async move {
-            match event_result {
-                Ok(event) => {
-                    if event.data == "[DONE]" {
-                        return None;
-                    }
+        let content_stream = event_stream.filter_map(move |event_result| {
+            let tracked = tracked.clone();
+            async move {
+                match event_result {
+                    Ok(event) => {
+                        if event.data == "[DONE]" {
+                            return None;
+                        }
 
-                    match serde_json::from_str::<ChatCompletionChunk>(&event.data) {
-                        Ok(chunk) => {
-                            if let Some(choice) = chunk.choices.first() {
-                                if let Some(content) = &choice.delta.content {
-                                    return Some(Ok(content.clone()));
+                        match serde_json::from_str::<ChatCompletionChunk>(&event.data) {
+                            Ok(chunk) => {
+                                if let (Some(usage), Some((client, model))) =
+                                    (&chunk.usage, &tracked)
+                                {
+                                    client
+                                        .track(
+                                            model,
+                                            UsageKind::Chat,
+                                            u64::from(usage.prompt_tokens),
+                                            u64::from(usage.completion_tokens),
+                                        )
+                                        .await;
+                                }
+                                if let Some(choice) = chunk.choices.first() {
+                                    if let Some(content) = &choice.delta.content {
+                                        return Some(Ok(content.clone()));
+                                    }
                                 }
+                                None
+                            }
+                            Err(e) => {
+                                warn!("Failed to parse SSE chunk: {}", e);
+                                None
                             }
-                            None
-                        }
-                        Err(e) => {
-                            warn!("Failed to parse SSE chunk: {}", e);
-                            None
                         }
                     }
+                    Err(e) => Some(Err(WikiError::OpenRouterApi {
+                        message: format!("SSE error: {}", e),
+                        status_code: None,
+                    })),
                 }
-                Err(e) => Some(Err(WikiError::OpenRouterApi {
-                    message: format!("SSE error: {}", e),
-                    status_code: None,
-                })),
             }
         });
 
@@ -397,6 +465,15 @@ impl OpenRouterClient {
     }
 }
 
+/// Seconds the provider asks to wait before retrying, from `Retry-After`
+fn retry_after(response: &reqwest::Response) -> Option<u64> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse().ok())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,7 +1,9 @@
 //! OpenRouter API client for embeddings and chat completions
 
 pub mod client;
+pub mod scheduler;
 pub mod types;
 
 pub use client::OpenRouterClient;
+pub use scheduler::{ModelUsage, RateLimitConfig, RequestScheduler};
 pub use types::*;
@@ -0,0 +1,394 @@
+//! Rate-limit budget shared by every OpenRouter request
+//!
+//! Requests draw from a token bucket per model and from one concurrency
+//! limit, so indexing, page generation and RAG queries running at the same
+//! time spend a single budget instead of tripping the provider's limits
+//! independently. A rate-limited request pauses its model for everyone and
+//! is retried with exponential backoff and jitter.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+use tokio::sync::{Semaphore, SemaphorePermit};
+use tokio::time::Instant;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::error::{WikiError, WikiResult};
+
+/// Limits applied to OpenRouter requests
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimitConfig {
+    /// Requests in flight at once, across all models
+    pub max_concurrency: usize,
+    /// Requests per second each model's bucket refills with
+    pub requests_per_second: f64,
+    /// Requests a model may make in a burst after being idle
+    pub burst: u32,
+    /// Retries of a rate-limited or failed request before giving up
+    pub max_retries: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrency: 8,
+            requests_per_second: 5.0,
+            burst: 10,
+            max_retries: 3,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+        }
+    }
+}
+
+/// What one model has spent of the budget
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ModelUsage {
+    /// Requests sent, retries included
+    pub requests: u64,
+    /// Requests the provider rate limited
+    pub rate_limited: u64,
+    /// Tokens the provider reported using
+    pub tokens: u64,
+}
+
+struct ModelBudget {
+    available: f64,
+    refilled_at: Instant,
+    paused_until: Option<Instant>,
+    usage: ModelUsage,
+}
+
+/// Schedules OpenRouter requests within the rate-limit budget
+pub struct RequestScheduler {
+    config: RateLimitConfig,
+    permits: Semaphore,
+    models: Mutex<HashMap<String, ModelBudget>>,
+}
+
+static SHARED: OnceLock<Arc<RequestScheduler>> = OnceLock::new();
+
+impl RequestScheduler {
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self {
+            permits: Semaphore::new(config.max_concurrency.max(1)),
+            config,
+            models: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The scheduler clients use unless given their own, created with the
+    /// default limits on first use
+    pub fn shared() -> Arc<Self> {
+        Arc::clone(SHARED.get_or_init(|| Arc::new(Self::new(RateLimitConfig::default()))))
+    }
+
+    /// Set the limits of the shared scheduler. Only takes effect before the
+    /// first client is created; returns whether it did.
+    pub fn configure_shared(config: RateLimitConfig) -> bool {
+        SHARED.set(Arc::new(Self::new(config))).is_ok()
+    }
+
+    pub fn config(&self) -> &RateLimitConfig {
+        &self.config
+    }
+
+    /// Budget spent by `model` so far
+    pub fn usage(&self, model: &str) -> ModelUsage {
+        self.models
+            .lock()
+            .unwrap()
+            .get(model)
+            .map(|budget| budget.usage)
+            .unwrap_or_default()
+    }
+
+    /// Record tokens the provider reported for a request to `model`
+    pub fn record_tokens(&self, model: &str, tokens: u64) {
+        self.with_budget(model, |budget| budget.usage.tokens += tokens);
+    }
+
+    /// Run `operation` within the budget of `model`, retrying rate limits
+    /// and server errors with backoff
+    pub async fn run<T, F, Fut>(
+        &self,
+        model: &str,
+        operation_name: &str,
+        operation: F,
+    ) -> WikiResult<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = WikiResult<T>>,
+    {
+        let mut retries = 0;
+
+        loop {
+            let result = {
+                let _permit = self.acquire(model).await;
+                operation().await
+            };
+
+            let delay = match result {
+                Ok(value) => return Ok(value),
+                Err(WikiError::RateLimited { retry_after }) => {
+                    let delay = self.backoff(retries, retry_after, jitter());
+                    self.rate_limited(model, delay);
+                    if retries >= self.config.max_retries {
+                        error!(
+                            "{} failed after {} retries due to rate limiting",
+                            operation_name, retries
+                        );
+                        return Err(WikiError::RateLimited { retry_after });
+                    }
+                    delay
+                }
+                Err(WikiError::OpenRouterApi {
+                    message,
+                    status_code: Some(code),
+                }) if code >= 500 => {
+                    if retries >= self.config.max_retries {
+                        error!(
+                            "{} failed after {} retries due to server error: {}",
+                            operation_name, retries, message
+                        );
+                        return Err(WikiError::OpenRouterApi {
+                            message,
+                            status_code: Some(code),
+                        });
+                    }
+                    self.backoff(retries, None, jitter())
+                }
+                Err(e) => {
+                    if retries > 0 {
+                        info!("{} failed after {} retries: {}", operation_name, retries, e);
+                    }
+                    return Err(e);
+                }
+            };
+
+            warn!(
+                "{} failed, retrying in {}ms (attempt {}/{})",
+                operation_name,
+                delay.as_millis(),
+                retries + 1,
+                self.config.max_retries
+            );
+            tokio::time::sleep(delay).await;
+            retries += 1;
+        }
+    }
+
+    /// Wait until `model` may send a request and a concurrency slot is free
+    pub async fn acquire(&self, model: &str) -> SemaphorePermit<'_> {
+        loop {
+            let wait = self.reserve(model, Instant::now());
+            if wait.is_zero() {
+                break;
+            }
+            tokio::time::sleep(wait).await;
+        }
+        self.permits
+            .acquire()
+            .await
+            .expect("request scheduler semaphore is never closed")
+    }
+
+    /// Take a request from the bucket of `model`, or tell how long to wait
+    /// before one is available
+    fn reserve(&self, model: &str, now: Instant) -> Duration {
+        let rate = self.config.requests_per_second.max(f64::EPSILON);
+        let burst = f64::from(self.config.burst.max(1));
+        self.with_budget(model, |budget| {
+            if let Some(until) = budget.paused_until {
+                if until > now {
+                    return until - now;
+                }
+                budget.paused_until = None;
+            }
+
+            let elapsed = now.saturating_duration_since(budget.refilled_at);
+            budget.available = (budget.available + elapsed.as_secs_f64() * rate).min(burst);
+            budget.refilled_at = now;
+
+            if budget.available >= 1.0 {
+                budget.available -= 1.0;
+                budget.usage.requests += 1;
+                Duration::ZERO
+            } else {
+                Duration::from_secs_f64((1.0 - budget.available) / rate)
+            }
+        })
+    }
+
+    /// Pause `model` for `delay` and empty its bucket, so requests already
+    /// queued don't run into the same limit
+    fn rate_limited(&self, model: &str, delay: Duration) {
+        let until = Instant::now() + delay;
+        self.with_budget(model, |budget| {
+            budget.usage.rate_limited += 1;
+            budget.available = 0.0;
+            if budget.paused_until.map_or(true, |current| current < until) {
+                budget.paused_until = Some(until);
+            }
+        });
+    }
+
+    /// Delay before retry `attempt` (counting from zero). The provider's
+    /// `Retry-After` wins when given; otherwise the backoff doubles each
+    /// attempt, and `jitter` in `[0, 1)` spreads the upper half of it so
+    /// callers limited together don't retry together.
+    fn backoff(&self, attempt: u32, retry_after: Option<u64>, jitter: f64) -> Duration {
+        if let Some(secs) = retry_after {
+            return Duration::from_secs(secs).min(self.config.max_backoff);
+        }
+        let exponential = self
+            .config
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.config.max_backoff);
+        exponential / 2 + exponential.mul_f64(jitter.clamp(0.0, 1.0) / 2.0)
+    }
+
+    fn with_budget<R>(&self, model: &str, f: impl FnOnce(&mut ModelBudget) -> R) -> R {
+        let mut models = self.models.lock().unwrap();
+        let budget = models
+            .entry(model.to_string())
+            .or_insert_with(|| ModelBudget {
+                available: f64::from(self.config.burst.max(1)),
+                refilled_at: Instant::now(),
+                paused_until: None,
+                usage: ModelUsage::default(),
+            });
+        f(budget)
+    }
+}
+
+/// A value in `[0, 1)` to spread retries with
+fn jitter() -> f64 {
+    (Uuid::new_v4().as_u128() as u64 >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn scheduler(requests_per_second: f64, burst: u32) -> RequestScheduler {
+        RequestScheduler::new(RateLimitConfig {
+            max_concurrency: 2,
+            requests_per_second,
+            burst,
+            max_retries: 2,
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_millis(100),
+        })
+    }
+
+    #[test]
+    fn test_bucket_allows_burst_then_refills() {
+        let scheduler = scheduler(10.0, 2);
+        let start = Instant::now();
+
+        assert_eq!(scheduler.reserve("m", start), Duration::ZERO);
+        assert_eq!(scheduler.reserve("m", start), Duration::ZERO);
+        let wait = scheduler.reserve("m", start);
+        assert!(wait > Duration::from_millis(90) && wait <= Duration::from_millis(100));
+
+        // Another model has its own bucket
+        assert_eq!(scheduler.reserve("other", start), Duration::ZERO);
+
+        let later = start + Duration::from_millis(100);
+        assert_eq!(scheduler.reserve("m", later), Duration::ZERO);
+        assert_eq!(scheduler.usage("m").requests, 3);
+    }
+
+    #[test]
+    fn test_rate_limit_pauses_model() {
+        let scheduler = scheduler(10.0, 5);
+        scheduler.rate_limited("m", Duration::from_secs(1));
+
+        assert!(scheduler.reserve("m", Instant::now()) > Duration::from_millis(900));
+        assert_eq!(scheduler.reserve("other", Instant::now()), Duration::ZERO);
+        assert_eq!(scheduler.usage("m").rate_limited, 1);
+    }
+
+    #[test]
+    fn test_backoff_grows_with_jitter() {
+        let scheduler = scheduler(10.0, 5);
+
+        assert_eq!(scheduler.backoff(0, None, 0.0), Duration::from_millis(5));
+        assert_eq!(scheduler.backoff(0, None, 1.0), Duration::from_millis(10));
+        assert_eq!(scheduler.backoff(2, None, 1.0), Duration::from_millis(40));
+        assert_eq!(scheduler.backoff(10, None, 1.0), Duration::from_millis(100));
+        // Retry-After is honoured, up to the maximum backoff
+        assert_eq!(scheduler.backoff(0, Some(0), 0.5), Duration::ZERO);
+        assert_eq!(
+            scheduler.backoff(0, Some(5), 0.5),
+            Duration::from_millis(100)
+        );
+
+        let spread = jitter();
+        assert!((0.0..1.0).contains(&spread));
+    }
+
+    #[tokio::test]
+    async fn test_run_retries_rate_limits() {
+        let scheduler = scheduler(1000.0, 10);
+        let attempts = AtomicU32::new(0);
+
+        let result = scheduler
+            .run("m", "test", || async {
+                if attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                    Err(WikiError::RateLimited { retry_after: None })
+                } else {
+                    Ok(7)
+                }
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), 7);
+        assert_eq!(scheduler.usage("m").requests, 3);
+        assert_eq!(scheduler.usage("m").rate_limited, 2);
+    }
+
+    #[tokio::test]
+    async fn test_run_gives_up_after_max_retries() {
+        let scheduler = scheduler(1000.0, 10);
+        let attempts = AtomicU32::new(0);
+
+        let result: WikiResult<()> = scheduler
+            .run("m", "test", || async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(WikiError::OpenRouterApi {
+                    message: "unavailable".to_string(),
+                    status_code: Some(503),
+                })
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(WikiError::OpenRouterApi {
+                status_code: Some(503),
+                ..
+            })
+        ));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+        let client_error: WikiResult<()> = scheduler
+            .run("m", "test", || async {
+                Err(WikiError::OpenRouterApi {
+                    message: "bad request".to_string(),
+                    status_code: Some(400),
+                })
+            })
+            .await;
+        assert!(client_error.is_err());
+        assert_eq!(scheduler.usage("m").requests, 4);
+    }
+}
@@ -123,6 +123,9 @@ pub struct ChatCompletionChunk {
     pub id: String,
     pub choices: Vec<ChatChunkChoice>,
     pub model: String,
+    /// Sent with the last chunk only
+    #[serde(default)]
+    pub usage: Option<ChatUsage>,
 }
 
 /// A choice in streaming chunk
@@ -157,6 +160,44 @@ pub struct OpenRouterErrorDetail {
     pub code: Option<String>,
 }
 
+/// Response from the models endpoint
+#[derive(Debug, Deserialize)]
+pub struct ModelsResponse {
+    pub data: Vec<ModelInfo>,
+}
+
+/// A model listed by the models endpoint
+#[derive(Debug, Deserialize)]
+pub struct ModelInfo {
+    pub id: String,
+    #[serde(default)]
+    pub pricing: Option<ModelPricing>,
+}
+
+/// Prices of a model, in USD per token as decimal strings
+#[derive(Debug, Deserialize)]
+pub struct ModelPricing {
+    pub prompt: String,
+    #[serde(default)]
+    pub completion: Option<String>,
+}
+
+impl ModelPricing {
+    /// Price of one input token in USD
+    pub fn prompt_per_token(&self) -> Option<f64> {
+        self.prompt.parse().ok().filter(|price: &f64| *price >= 0.0)
+    }
+
+    /// Price of one output token in USD; zero when not listed, as for
+    /// embedding models
+    pub fn completion_per_token(&self) -> Option<f64> {
+        match &self.completion {
+            Some(price) => price.parse().ok().filter(|price: &f64| *price >= 0.0),
+            None => Some(0.0),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -197,4 +238,26 @@ mod tests {
         assert_eq!(resp.data.len(), 1);
         assert_eq!(resp.data[0].embedding.len(), 3);
     }
+
+    #[test]
+    fn test_models_response_deserialization() {
+        let json = r#"{
+            "data": [
+                {"id": "openai/text-embedding-3-small", "pricing": {"prompt": "0.00000002", "completion": "0"}},
+                {"id": "some/model"}
+            ]
+        }"#;
+
+        let resp: ModelsResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(resp.data.len(), 2);
+        let price = resp.data[0].pricing.as_ref().unwrap().prompt_per_token();
+        assert_eq!(price, Some(0.00000002));
+        let price = resp.data[0]
+            .pricing
+            .as_ref()
+            .unwrap()
+            .completion_per_token();
+        assert_eq!(price, Some(0.0));
+        assert!(resp.data[1].pricing.is_none());
+    }
 }
@@ -0,0 +1,434 @@
+//! Diff-aware wiki page updates
+//!
+//! After a change is merged, the pages documenting the touched files go stale.
+//! This module maps a unified diff onto those pages (through their file paths
+//! and source citations) and either drafts edit suggestions for them or
+//! rewrites them in place.
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+use crate::domain::wiki_page::{Importance, WikiPage};
+use crate::error::WikiResult;
+use crate::generator::{extracted, WikiGenerator};
+use crate::openrouter::client::OpenRouterClient;
+use crate::openrouter::types::ChatMessage;
+use crate::vector_store::VectorStore;
+
+/// Default maximum number of pages updated for a single diff
+const DEFAULT_MAX_PAGES: usize = 8;
+
+/// Maximum length of the diff excerpt sent with a page, in characters
+const MAX_DIFF_LENGTH: usize = 16000;
+
+/// Reply the LLM gives when a page needs no edits
+const NO_CHANGES: &str = "NO_CHANGES";
+
+/// System prompt for update suggestions
+const SUGGEST_SYSTEM_PROMPT: &str = r#"You maintain the documentation wiki of a codebase.
+
+You are given a wiki page and a diff that was just merged into the code the page documents.
+List the concrete edits the page needs to stay accurate:
+- One bullet per edit, naming the section it applies to
+- Cite the changed code in the format `file_path:line` where possible
+- Only mention statements the diff actually invalidates or new behaviour the page should cover
+If the page is still accurate, reply with exactly NO_CHANGES."#;
+
+/// System prompt for page regeneration
+const REGENERATE_SYSTEM_PROMPT: &str = r#"You maintain the documentation wiki of a codebase.
+
+You are given a wiki page and a diff that was just merged into the code the page documents.
+Rewrite the page so it is accurate after the change:
+- Keep the structure, headings, tone and Mermaid diagrams of the original page
+- Only change what the diff invalidates, and document new behaviour it introduces
+- Keep existing source citations unless the cited code moved, and cite new code as [file_path:line]()
+- Reply with the complete updated page in markdown and nothing else
+If the page is still accurate, reply with exactly NO_CHANGES."#;
+
+/// How a stale page is handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PageUpdateAction {
+    /// Edit suggestions were drafted, the page itself is untouched
+    Suggested,
+    /// The page was rewritten in place
+    Regenerated,
+}
+
+impl PageUpdateAction {
+    /// Get string representation for database storage
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PageUpdateAction::Suggested => "suggested",
+            PageUpdateAction::Regenerated => "regenerated",
+        }
+    }
+
+    /// Parse from database string
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "suggested" => Some(PageUpdateAction::Suggested),
+            "regenerated" => Some(PageUpdateAction::Regenerated),
+            _ => None,
+        }
+    }
+}
+
+/// The part of a unified diff touching a single file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileDiff {
+    pub file_path: String,
+    pub patch: String,
+}
+
+/// A page documenting files changed by a diff
+#[derive(Debug, Clone)]
+pub struct AffectedPage<'a> {
+    pub page: &'a WikiPage,
+    /// Changed files the page documents or cites
+    pub changed_files: Vec<String>,
+}
+
+/// Outcome of updating one stale page
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageUpdate {
+    pub slug: String,
+    pub title: String,
+    pub action: PageUpdateAction,
+    pub changed_files: Vec<String>,
+    /// Edit suggestions, or the rewritten page for regenerated pages
+    pub content: String,
+}
+
+/// Drafts suggestions for, or rewrites, the pages affected by a diff
+pub struct PageUpdater<'a> {
+    openrouter: &'a OpenRouterClient,
+    chat_model: String,
+    max_pages: usize,
+}
+
+impl<'a> PageUpdater<'a> {
+    /// Create a new page updater
+    pub fn new(openrouter: &'a OpenRouterClient, chat_model: impl Into<String>) -> Self {
+        Self {
+            openrouter,
+            chat_model: chat_model.into(),
+            max_pages: DEFAULT_MAX_PAGES,
+        }
+    }
+
+    /// Set the maximum number of pages updated for a single diff
+    pub fn with_max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = max_pages;
+        self
+    }
+
+    /// Update the pages among `pages` that document files changed by `diff`.
+    ///
+    /// Pages the LLM considers still accurate are left out of the result, and
+    /// a page that fails to update is logged and skipped so one bad response
+    /// does not lose the others.
+    pub async fn update(
+        &self,
+        pages: &[WikiPage],
+        diff: &str,
+        action: PageUpdateAction,
+    ) -> WikiResult<Vec<PageUpdate>> {
+        let file_diffs = split_diff(diff);
+        let changed_files: Vec<String> = file_diffs.iter().map(|d| d.file_path.clone()).collect();
+        let mut affected = affected_pages(pages, &changed_files);
+        affected.truncate(self.max_pages);
+
+        info!(
+            "Updating {} wiki pages affected by {} changed files ({})",
+            affected.len(),
+            changed_files.len(),
+            action.as_str()
+        );
+
+        let patches: HashMap<&str, &str> = file_diffs
+            .iter()
+            .map(|d| (d.file_path.as_str(), d.patch.as_str()))
+            .collect();
+
+        let mut updates = Vec::new();
+        for affected_page in affected {
+            let page = affected_page.page;
+            let excerpt = diff_excerpt(&affected_page.changed_files, &patches);
+
+            match self.update_page(page, &excerpt, action).await {
+                Ok(Some(content)) => updates.push(PageUpdate {
+                    slug: page.slug.clone(),
+                    title: page.title.clone(),
+                    action,
+                    changed_files: affected_page.changed_files,
+                    content,
+                }),
+                Ok(None) => debug!("Wiki page '{}' is still accurate", page.slug),
+                Err(e) => warn!("Failed to update wiki page '{}': {}", page.slug, e),
+            }
+        }
+
+        Ok(updates)
+    }
+
+    async fn update_page(
+        &self,
+        page: &WikiPage,
+        diff_excerpt: &str,
+        action: PageUpdateAction,
+    ) -> WikiResult<Option<String>> {
+        let system_prompt = match action {
+            PageUpdateAction::Suggested => SUGGEST_SYSTEM_PROMPT,
+            PageUpdateAction::Regenerated => REGENERATE_SYSTEM_PROMPT,
+        };
+        let messages = vec![
+            ChatMessage::system(system_prompt),
+            ChatMessage::user(format!(
+                "## Wiki page: {}\n\n{}\n\n## Merged diff\n\n```diff\n{}\n```",
+                page.title, page.content, diff_excerpt
+            )),
+        ];
+
+        let max_tokens = match action {
+            PageUpdateAction::Suggested => 1024,
+            PageUpdateAction::Regenerated => 4096,
+        };
+        let response = self
+            .openrouter
+            .chat_completion(messages, &self.chat_model, Some(0.2), Some(max_tokens))
+            .await?;
+
+        let response = response.trim();
+        if response.is_empty() || response == NO_CHANGES {
+            return Ok(None);
+        }
+        Ok(Some(response.to_string()))
+    }
+}
+
+/// Write the regenerated pages among `updates` back to `branch`, returning
+/// the stored pages. Suggestions are ignored.
+pub fn apply_page_updates(
+    store: &VectorStore,
+    branch: &str,
+    updates: &[PageUpdate],
+) -> WikiResult<Vec<WikiPage>> {
+    let mut applied = Vec::new();
+
+    for update in updates
+        .iter()
+        .filter(|u| u.action == PageUpdateAction::Regenerated)
+    {
+        let Some(mut page) = store.get_wiki_page_in_branch(&update.slug, Some(branch))? else {
+            warn!(
+                "Wiki page '{}' disappeared before it could be updated",
+                update.slug
+            );
+            continue;
+        };
+
+        page.content = update.content.clone();
+        page.has_diagrams = page.content.contains("```mermaid");
+        let citations = WikiGenerator::extract_source_citations(&page.content);
+        if !citations.is_empty() {
+            page.source_citations = citations;
+        }
+        page.updated_at = Utc::now();
+
+        store.insert_wiki_page(&page)?;
+        applied.push(page);
+    }
+
+    Ok(applied)
+}
+
+/// Split a unified (git-style) diff into per-file patches. Sections for a
+/// file that appears more than once (e.g. committed and uncommitted changes
+/// concatenated) are merged into one patch.
+pub fn split_diff(diff: &str) -> Vec<FileDiff> {
+    let mut file_diffs: Vec<FileDiff> = Vec::new();
+    let mut current = None;
+
+    for line in diff.lines() {
+        if let Some(header) = line.strip_prefix("diff --git ") {
+            let file_path = header
+                .rsplit_once(" b/")
+                .map(|(_, path)| path)
+                .unwrap_or(header);
+            current = match file_diffs.iter().position(|d| d.file_path == file_path) {
+                Some(index) => Some(index),
+                None => {
+                    file_diffs.push(FileDiff {
+                        file_path: file_path.to_string(),
+                        patch: String::new(),
+                    });
+                    Some(file_diffs.len() - 1)
+                }
+            };
+        }
+
+        if let Some(index) = current {
+            let patch = &mut file_diffs[index].patch;
+            patch.push_str(line);
+            patch.push('\n');
+        }
+    }
+
+    file_diffs
+}
+
+/// Find the pages documenting or citing any of `changed_files`, most
+/// affected first. Extracted pages are skipped: they are rebuilt from the
+/// code on the next index.
+pub fn affected_pages<'a>(
+    pages: &'a [WikiPage],
+    changed_files: &[String],
+) -> Vec<AffectedPage<'a>> {
+    let mut affected: Vec<AffectedPage<'a>> = pages
+        .iter()
+        .filter(|page| !extracted::is_extracted_page(&page.slug))
+        .filter_map(|page| {
+            let matched: Vec<String> = changed_files
+                .iter()
+                .filter(|file| documents_file(page, file))
+                .cloned()
+                .collect();
+            (!matched.is_empty()).then_some(AffectedPage {
+                page,
+                changed_files: matched,
+            })
+        })
+        .collect();
+
+    affected.sort_by(|a, b| {
+        b.changed_files
+            .len()
+            .cmp(&a.changed_files.len())
+            .then_with(|| {
+                importance_rank(a.page.importance).cmp(&importance_rank(b.page.importance))
+            })
+            .then_with(|| a.page.order.cmp(&b.page.order))
+    });
+    affected
+}
+
+/// Whether `page` documents `file`, directly, through a citation, or through
+/// one of its parent directories
+fn documents_file(page: &WikiPage, file: &str) -> bool {
+    let covers = |path: &str| {
+        let path = path.trim_end_matches('/');
+        file == path
+            || file
+                .strip_prefix(path)
+                .is_some_and(|rest| rest.starts_with('/'))
+    };
+
+    page.file_paths.iter().any(|p| covers(p))
+        || page.source_citations.iter().any(|c| c.file_path == file)
+}
+
+fn importance_rank(importance: Importance) -> u8 {
+    match importance {
+        Importance::High => 0,
+        Importance::Medium => 1,
+        Importance::Low => 2,
+    }
+}
+
+fn diff_excerpt(changed_files: &[String], patches: &HashMap<&str, &str>) -> String {
+    let mut excerpt = String::new();
+    for file in changed_files {
+        let Some(patch) = patches.get(file.as_str()) else {
+            continue;
+        };
+        if excerpt.len() + patch.len() > MAX_DIFF_LENGTH {
+            excerpt.push_str("[further changes omitted for length]\n");
+            break;
+        }
+        excerpt.push_str(patch);
+    }
+    excerpt
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::wiki_page::{PageType, SourceCitation};
+
+    const DIFF: &str = "diff --git a/src/auth/login.rs b/src/auth/login.rs
+index 1111111..2222222 100644
+--- a/src/auth/login.rs
++++ b/src/auth/login.rs
+@@ -1,3 +1,3 @@
+-fn login() {}
++fn login(remember: bool) {}
+diff --git a/README.md b/README.md
+index 3333333..4444444 100644
+--- a/README.md
++++ b/README.md
+@@ -1 +1 @@
+-Old
++New
+";
+
+    fn page(slug: &str, file_paths: &[&str]) -> WikiPage {
+        WikiPage::new(
+            "main".to_string(),
+            slug.to_string(),
+            slug.to_string(),
+            String::new(),
+            PageType::Module,
+            None,
+            0,
+            file_paths.iter().map(|p| p.to_string()).collect(),
+            "abc".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_split_diff() {
+        let diffs = split_diff(DIFF);
+        assert_eq!(diffs.len(), 2);
+        assert_eq!(diffs[0].file_path, "src/auth/login.rs");
+        assert!(diffs[0].patch.contains("+fn login(remember: bool) {}"));
+        assert!(!diffs[0].patch.contains("README"));
+        assert_eq!(diffs[1].file_path, "README.md");
+
+        let repeated = split_diff(&format!("{}{}", DIFF, DIFF));
+        assert_eq!(repeated.len(), 2);
+        assert_eq!(repeated[0].patch.matches("diff --git").count(), 2);
+    }
+
+    #[test]
+    fn test_affected_pages() {
+        let mut cited = page("sessions", &[]);
+        cited.source_citations = vec![SourceCitation::lines("src/auth/login.rs".to_string(), 1, 3)];
+        let pages = vec![
+            page("overview", &["Cargo.toml"]),
+            page("auth", &["src/auth"]),
+            cited,
+            page("docs", &["README.md", "src/auth/login.rs"]),
+            page(
+                crate::test_coverage::TEST_COVERAGE_PAGE_SLUG,
+                &["src/auth/login.rs"],
+            ),
+        ];
+        let changed: Vec<String> = split_diff(DIFF).into_iter().map(|d| d.file_path).collect();
+
+        let affected = affected_pages(&pages, &changed);
+        let slugs: Vec<&str> = affected.iter().map(|a| a.page.slug.as_str()).collect();
+        assert_eq!(slugs, vec!["docs", "auth", "sessions"]);
+        assert_eq!(affected[0].changed_files.len(), 2);
+    }
+
+    #[test]
+    fn test_documents_file_requires_directory_boundary() {
+        let page = page("auth", &["src/auth"]);
+        assert!(documents_file(&page, "src/auth/login.rs"));
+        assert!(!documents_file(&page, "src/authz.rs"));
+    }
+}
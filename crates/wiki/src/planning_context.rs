@@ -0,0 +1,261 @@
+//! Wiki context for planning
+//!
+//! Before a task is planned, the indexed code most similar to the task
+//! description and the wiki pages documenting it are retrieved, so the plan
+//! is grounded in the code it will touch. The consulted sources are listed
+//! alongside the plan for human verification.
+
+use std::path::Path;
+
+use tracing::debug;
+
+use crate::chunker::TextSplitter;
+use crate::domain::search_result::SearchResult;
+use crate::embeddings::EmbeddingProvider;
+use crate::error::WikiResult;
+use crate::page_updates::affected_pages;
+use crate::review_context::{excerpt, PageExcerpt};
+use crate::vector_store::VectorStore;
+
+/// Default number of chunks retrieved for a task
+const DEFAULT_MAX_CHUNKS: usize = 8;
+
+/// Default number of pages attached to a task
+const DEFAULT_MAX_PAGES: usize = 3;
+
+/// Characters of a page kept as its excerpt
+const PAGE_EXCERPT_LENGTH: usize = 2000;
+
+/// Characters of the task description embedded as the similarity query
+const QUERY_LENGTH: usize = 8000;
+
+/// Wiki context gathered for a task description
+#[derive(Debug, Clone, Default)]
+pub struct PlanningContext {
+    pub pages: Vec<PageExcerpt>,
+    /// Indexed chunks most similar to the task description
+    pub chunks: Vec<SearchResult>,
+}
+
+impl PlanningContext {
+    pub fn is_empty(&self) -> bool {
+        self.pages.is_empty() && self.chunks.is_empty()
+    }
+
+    /// Render the context as markdown, keeping it within `max_tokens`.
+    /// Pages are added before chunks.
+    pub fn to_markdown(&self, max_tokens: usize) -> String {
+        let mut markdown = String::new();
+        for (_, _, section) in self.fitted_sections(max_tokens) {
+            markdown.push_str(&section);
+            markdown.push('\n');
+        }
+        markdown.trim_end().to_string()
+    }
+
+    /// The sources included by [`Self::to_markdown`] with the same budget,
+    /// one line each
+    pub fn sources(&self, max_tokens: usize) -> Vec<String> {
+        self.fitted_sections(max_tokens)
+            .into_iter()
+            .map(|(source, _, _)| source)
+            .collect()
+    }
+
+    /// The chunks included by [`Self::to_markdown`] with the same budget
+    pub fn shown_chunks(&self, max_tokens: usize) -> Vec<&SearchResult> {
+        self.fitted_sections(max_tokens)
+            .into_iter()
+            .filter_map(|(_, chunk, _)| chunk)
+            .collect()
+    }
+
+    /// Sections that fit the budget: their source line, the chunk they
+    /// render, if any, and their markdown
+    fn fitted_sections(&self, max_tokens: usize) -> Vec<(String, Option<&SearchResult>, String)> {
+        let splitter = TextSplitter::new(max_tokens, 0);
+        let mut used = 0;
+
+        let pages = self.pages.iter().map(|page| {
+            (
+                format!("Wiki: {} (`{}`)", page.title, page.slug),
+                None,
+                format!(
+                    "**Wiki: {}** (`{}`)\n\n{}\n",
+                    page.title, page.slug, page.excerpt
+                ),
+            )
+        });
+        let chunks = self.chunks.iter().map(|chunk| {
+            (
+                format!("`{}`", chunk.location()),
+                Some(chunk),
+                format!(
+                    "**Related code:** `{}`\n\n```{}\n{}\n```\n",
+                    chunk.location(),
+                    chunk.language.as_deref().unwrap_or(""),
+                    chunk.content.trim_end()
+                ),
+            )
+        });
+
+        pages
+            .chain(chunks)
+            .filter(|(_, _, section)| {
+                let tokens = splitter.count_tokens(section);
+                if used + tokens > max_tokens {
+                    return false;
+                }
+                used += tokens;
+                true
+            })
+            .collect()
+    }
+}
+
+/// Retrieves the code and pages related to a task description
+pub struct PlanningContextBuilder<'a> {
+    embeddings: &'a dyn EmbeddingProvider,
+    embedding_model: String,
+    max_chunks: usize,
+    max_pages: usize,
+}
+
+impl<'a> PlanningContextBuilder<'a> {
+    /// Create a new planning context builder
+    pub fn new(embeddings: &'a dyn EmbeddingProvider, embedding_model: impl Into<String>) -> Self {
+        Self {
+            embeddings,
+            embedding_model: embedding_model.into(),
+            max_chunks: DEFAULT_MAX_CHUNKS,
+            max_pages: DEFAULT_MAX_PAGES,
+        }
+    }
+
+    /// Set the number of chunks retrieved for a task
+    pub fn with_max_chunks(mut self, max_chunks: usize) -> Self {
+        self.max_chunks = max_chunks;
+        self
+    }
+
+    /// Set the number of pages attached to a task
+    pub fn with_max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = max_pages;
+        self
+    }
+
+    /// Gather context from the wiki database at `db_path` for `query`, the
+    /// task title and description, on `branch`.
+    ///
+    /// Pages are those documenting the files of the retrieved chunks, so
+    /// they follow the code the task is most likely to touch.
+    pub async fn build(
+        &self,
+        db_path: &Path,
+        branch: &str,
+        query: &str,
+    ) -> WikiResult<PlanningContext> {
+        if query.trim().is_empty() {
+            return Ok(PlanningContext::default());
+        }
+
+        let embedding = self
+            .embeddings
+            .create_embedding(&excerpt(query, QUERY_LENGTH), &self.embedding_model)
+            .await?;
+
+        let store = VectorStore::new(db_path)?;
+        let chunks = store.search_similar_in_branch(
+            &embedding,
+            &self.embedding_model,
+            self.max_chunks,
+            Some(branch),
+            None,
+        )?;
+
+        let mut files: Vec<String> = Vec::new();
+        for chunk in &chunks {
+            if !files.contains(&chunk.file_path) {
+                files.push(chunk.file_path.clone());
+            }
+        }
+        let branch_pages = store.get_branch_pages(branch)?;
+        let pages: Vec<PageExcerpt> = affected_pages(&branch_pages, &files)
+            .into_iter()
+            .take(self.max_pages)
+            .map(|affected| PageExcerpt {
+                slug: affected.page.slug.clone(),
+                title: affected.page.title.clone(),
+                excerpt: excerpt(&affected.page.content, PAGE_EXCERPT_LENGTH),
+            })
+            .collect();
+
+        debug!(
+            "Gathered planning context ({} pages, {} chunks)",
+            pages.len(),
+            chunks.len()
+        );
+
+        Ok(PlanningContext { pages, chunks })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::chunk::ChunkType;
+    use uuid::Uuid;
+
+    fn context() -> PlanningContext {
+        PlanningContext {
+            pages: vec![PageExcerpt {
+                slug: "auth".to_string(),
+                title: "Authentication".to_string(),
+                excerpt: "Sessions are created on login.".to_string(),
+            }],
+            chunks: vec![
+                SearchResult::new(
+                    Uuid::new_v4(),
+                    "src/auth/login.rs".to_string(),
+                    3,
+                    12,
+                    "fn login() {}".to_string(),
+                    ChunkType::Function,
+                    Some("rust".to_string()),
+                    0.8,
+                ),
+                SearchResult::new(
+                    Uuid::new_v4(),
+                    "src/auth/session.rs".to_string(),
+                    1,
+                    400,
+                    "fn session() {}\n".repeat(300),
+                    ChunkType::File,
+                    Some("rust".to_string()),
+                    0.7,
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_to_markdown_and_sources_share_budget() {
+        let context = context();
+        let markdown = context.to_markdown(200);
+        let sources = context.sources(200);
+
+        assert!(markdown.contains("**Wiki: Authentication** (`auth`)"));
+        assert!(markdown.contains("fn login() {}"));
+        assert!(!markdown.contains("fn session()"));
+        assert_eq!(
+            sources,
+            vec![
+                "Wiki: Authentication (`auth`)".to_string(),
+                "`src/auth/login.rs:3-12`".to_string(),
+            ]
+        );
+
+        assert_eq!(context.sources(10_000).len(), 3);
+        assert!(context.to_markdown(0).is_empty());
+    }
+}
@@ -0,0 +1,149 @@
+//! Detection of public API boundary code
+//!
+//! Chunks that define an interface other code or users depend on are tagged
+//! while indexing, so searches can be narrowed to them and review findings
+//! touching them can be rated more strictly. Detection is line-pattern based
+//! and errs towards tagging:
+//!
+//! - **HTTP handlers**: route registrations recognised by [`crate::endpoints`]
+//! - **Exported items**: `pub` items in Rust, `export` in JavaScript and
+//!   TypeScript, capitalised Go declarations, `public` types and methods in
+//!   Java, Kotlin and C#, `__all__` in Python
+//! - **CLI commands**: clap, click/typer, argparse, commander and cobra
+//!
+//! Test chunks are never public API.
+
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use crate::domain::chunk::ChunkType;
+use crate::endpoints::extract_endpoints;
+
+fn rust_export_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"(?m)^\s*pub\s+(?:(?:async|const|unsafe|extern\s+\S+)\s+)*(?:fn|struct|enum|trait|type|union|mod|static|const)\s",
+        )
+        .expect("valid rust export regex")
+    })
+}
+
+fn js_export_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?m)^\s*(?:export\s|module\.exports\b|exports\.\w+\s*=)")
+            .expect("valid js export regex")
+    })
+}
+
+fn go_export_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?m)^(?:func\s+(?:\([^)]*\)\s*)?|type\s+|var\s+|const\s+)[A-Z]")
+            .expect("valid go export regex")
+    })
+}
+
+fn jvm_export_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?m)^\s*public\s|^\s*\[(?:ApiController|Http(?:Get|Post|Put|Delete|Patch))\b|@(?:RestController|(?:Get|Post|Put|Delete|Patch|Request)Mapping)\b")
+            .expect("valid jvm export regex")
+    })
+}
+
+fn python_export_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?m)^__all__\s*=").expect("valid python export regex"))
+}
+
+fn cli_command_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"#\[derive\([^)]*\b(?:Parser|Subcommand)\b|#\[command\(|@\w+\.command\b|@click\.(?:command|group)\b|\bArgumentParser\(|\.add_parser\(|\bprogram\s*\.\s*command\(|&cobra\.Command\s*\{",
+        )
+        .expect("valid cli command regex")
+    })
+}
+
+/// Whether a chunk of `file_path` defines public API: an HTTP handler, an
+/// exported item or a CLI command
+pub fn is_public_api(
+    file_path: &str,
+    language: Option<&str>,
+    chunk_type: ChunkType,
+    content: &str,
+) -> bool {
+    if chunk_type == ChunkType::Test {
+        return false;
+    }
+
+    let exported = match language {
+        Some("rust") => rust_export_regex().is_match(content),
+        Some("javascript" | "typescript") => js_export_regex().is_match(content),
+        Some("go") => go_export_regex().is_match(content),
+        Some("java" | "kotlin" | "csharp") => jvm_export_regex().is_match(content),
+        Some("python") => python_export_regex().is_match(content),
+        _ => false,
+    };
+
+    exported
+        || cli_command_regex().is_match(content)
+        || !extract_endpoints(file_path, content, 1).is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tagged(file_path: &str, content: &str) -> bool {
+        let language = crate::chunker::TextSplitter::detect_language(file_path);
+        is_public_api(file_path, language.as_deref(), ChunkType::Code, content)
+    }
+
+    #[test]
+    fn test_rust_visibility() {
+        assert!(tagged("src/lib.rs", "pub async fn handler() {}"));
+        assert!(tagged(
+            "src/lib.rs",
+            "#[derive(Debug)]\npub struct Config {}"
+        ));
+        assert!(!tagged("src/lib.rs", "pub(crate) fn helper() {}"));
+        assert!(!tagged("src/lib.rs", "fn private() {}\nlet x = 1;"));
+    }
+
+    #[test]
+    fn test_exports_by_language() {
+        assert!(tagged("src/api.ts", "export function load() {}"));
+        assert!(!tagged("src/api.ts", "function load() {}"));
+        assert!(tagged("pkg/server.go", "func (s *Server) Serve() error {"));
+        assert!(!tagged("pkg/server.go", "func (s *Server) serve() error {"));
+        assert!(tagged("App.java", "public class App {"));
+        assert!(tagged("pkg/__init__.py", "__all__ = [\"load\"]"));
+        assert!(!tagged("pkg/util.py", "def _helper():\n    pass"));
+    }
+
+    #[test]
+    fn test_http_handlers_and_cli_commands() {
+        assert!(tagged(
+            "app/main.py",
+            "@app.get(\"/users\")\ndef list_users():\n    pass"
+        ));
+        assert!(tagged("src/routes.js", "router.post('/login', login);"));
+        assert!(tagged("src/cli.rs", "#[derive(Parser)]\nstruct Cli {}"));
+        assert!(tagged("tool.py", "@click.command()\ndef main():\n    pass"));
+    }
+
+    #[test]
+    fn test_tests_are_never_public_api() {
+        assert!(!is_public_api(
+            "tests/api.rs",
+            Some("rust"),
+            ChunkType::Test,
+            "pub fn helper() {}"
+        ));
+    }
+}
@@ -0,0 +1,213 @@
+//! Query decomposition for compound questions
+//!
+//! A question like "how does auth work and where are tokens stored" embeds
+//! somewhere between its two topics and retrieves poorly for both. The chat
+//! model splits such questions into sub-queries; each is retrieved on its own
+//! and the hits are merged into one context, remembering which sub-queries
+//! found each chunk.
+
+use std::collections::HashMap;
+
+use tracing::debug;
+use uuid::Uuid;
+
+use crate::domain::search_result::SearchResult;
+use crate::error::WikiResult;
+use crate::openrouter::client::OpenRouterClient;
+use crate::openrouter::types::ChatMessage;
+
+const DECOMPOSITION_PROMPT: &str = r#"You split developer questions about a codebase into search queries.
+
+Write one short, standalone query per distinct thing the question asks about, phrased for semantic code search.
+If the question asks about only one thing, reply with the question itself as the only line.
+Reply with at most {max} queries, one per line, with no numbering and no other text."#;
+
+/// Bounds for query decomposition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryDecomposition {
+    /// Maximum number of sub-queries retrieved for one question
+    pub max_sub_queries: usize,
+}
+
+impl Default for QueryDecomposition {
+    fn default() -> Self {
+        Self { max_sub_queries: 4 }
+    }
+}
+
+/// A merged search hit with the sub-queries that retrieved it
+#[derive(Debug, Clone)]
+pub struct AttributedResult {
+    pub result: SearchResult,
+    /// Sub-queries that retrieved the chunk, in the order they found it
+    pub sub_queries: Vec<String>,
+}
+
+impl QueryDecomposition {
+    /// Ask the chat model to split `question` into sub-queries. A single
+    /// sub-query means the question did not need splitting.
+    pub async fn decompose(
+        &self,
+        openrouter: &OpenRouterClient,
+        chat_model: &str,
+        question: &str,
+    ) -> WikiResult<Vec<String>> {
+        let messages = vec![
+            ChatMessage::system(
+                DECOMPOSITION_PROMPT.replace("{max}", &self.max_sub_queries.to_string()),
+            ),
+            ChatMessage::user(question),
+        ];
+        let reply = openrouter
+            .chat_completion(messages, chat_model, Some(0.0), Some(256))
+            .await?;
+
+        let sub_queries = parse_sub_queries(&reply, self.max_sub_queries);
+        debug!(?sub_queries, "Decomposed question");
+        Ok(sub_queries)
+    }
+}
+
+/// Sub-queries from a model reply: one per line, list markers stripped,
+/// duplicates dropped
+pub(crate) fn parse_sub_queries(reply: &str, max: usize) -> Vec<String> {
+    let mut sub_queries: Vec<String> = Vec::new();
+
+    for line in reply.lines() {
+        let line = line
+            .trim()
+            .trim_start_matches(|c: char| c.is_ascii_digit())
+            .trim_start_matches(['-', '*', '•', '.', ')'])
+            .trim()
+            .trim_matches('"');
+        if line.is_empty() {
+            continue;
+        }
+        if !sub_queries.iter().any(|q| q.eq_ignore_ascii_case(line)) {
+            sub_queries.push(line.to_string());
+        }
+        if sub_queries.len() >= max {
+            break;
+        }
+    }
+
+    sub_queries
+}
+
+/// Merge the hits of each sub-query into one list.
+///
+/// Hits are taken round-robin by rank, so every sub-query is represented
+/// before any gets its lower-ranked hits in. A chunk found by several
+/// sub-queries appears once, with its best score and all of them attributed.
+/// Merging stops once `fits` rejects a chunk.
+pub(crate) fn merge_sub_query_results(
+    per_query: Vec<(String, Vec<SearchResult>)>,
+    mut fits: impl FnMut(&SearchResult) -> bool,
+) -> Vec<AttributedResult> {
+    let mut merged: Vec<AttributedResult> = Vec::new();
+    let mut index: HashMap<Uuid, usize> = HashMap::new();
+    let rounds = per_query
+        .iter()
+        .map(|(_, results)| results.len())
+        .max()
+        .unwrap_or(0);
+
+    for rank in 0..rounds {
+        for (sub_query, results) in &per_query {
+            let Some(result) = results.get(rank) else {
+                continue;
+            };
+            if let Some(&i) = index.get(&result.chunk_id) {
+                let entry = &mut merged[i];
+                entry.result.score = entry.result.score.max(result.score);
+                if !entry.sub_queries.contains(sub_query) {
+                    entry.sub_queries.push(sub_query.clone());
+                }
+                continue;
+            }
+            if !fits(result) {
+                debug!(
+                    chunks = merged.len(),
+                    "Stopped merging sub-query results at the context budget"
+                );
+                return merged;
+            }
+            index.insert(result.chunk_id, merged.len());
+            merged.push(AttributedResult {
+                result: result.clone(),
+                sub_queries: vec![sub_query.clone()],
+            });
+        }
+    }
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::chunk::ChunkType;
+
+    fn hit(file_path: &str, score: f32) -> SearchResult {
+        SearchResult::new(
+            Uuid::new_v4(),
+            file_path.to_string(),
+            1,
+            10,
+            format!("// {}", file_path),
+            ChunkType::Function,
+            Some("rust".to_string()),
+            score,
+        )
+    }
+
+    #[test]
+    fn test_parse_sub_queries() {
+        let reply = "1. How does authentication work?\n\n- where are tokens stored\n* \"Where are tokens stored\"\n3) session expiry";
+        assert_eq!(
+            parse_sub_queries(reply, 4),
+            vec![
+                "How does authentication work?",
+                "where are tokens stored",
+                "session expiry"
+            ]
+        );
+        assert_eq!(parse_sub_queries(reply, 1).len(), 1);
+    }
+
+    #[test]
+    fn test_merge_interleaves_and_attributes() {
+        let shared = hit("src/auth/token.rs", 0.7);
+        let auth = vec![hit("src/auth/login.rs", 0.9), shared.clone()];
+        let mut tokens = vec![shared.clone(), hit("src/db/tokens.rs", 0.8)];
+        tokens[0].score = 0.95;
+
+        let merged = merge_sub_query_results(
+            vec![("auth".to_string(), auth), ("tokens".to_string(), tokens)],
+            |_| true,
+        );
+
+        let files: Vec<&str> = merged.iter().map(|m| m.result.file_path.as_str()).collect();
+        assert_eq!(
+            files,
+            vec!["src/auth/login.rs", "src/auth/token.rs", "src/db/tokens.rs"]
+        );
+        assert_eq!(merged[1].sub_queries, vec!["tokens", "auth"]);
+        assert_eq!(merged[1].result.score, 0.95);
+
+        // The budget cuts off the remaining hits
+        let mut budget = 2;
+        let merged = merge_sub_query_results(
+            vec![
+                ("a".to_string(), vec![hit("a.rs", 0.9), hit("b.rs", 0.8)]),
+                ("c".to_string(), vec![hit("c.rs", 0.9)]),
+            ],
+            |_| {
+                budget -= 1;
+                budget >= 0
+            },
+        );
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[1].result.file_path, "c.rs");
+    }
+}
@@ -0,0 +1,340 @@
+//! Call-graph aware context expansion for RAG retrieval
+//!
+//! When a retrieved chunk is a function, its callers and callees are often the
+//! missing context. This module follows call edges from the top search hits
+//! and appends the connected chunks, tagged with why they were added. The top
+//! hits also get their neighbouring chunks of the same file attached as
+//! surrounding context.
+
+use std::collections::HashSet;
+
+use tracing::debug;
+
+use crate::domain::chunk::CodeChunk;
+use crate::domain::search_result::{ContextExpansion, ExpansionRelation, SearchResult};
+use crate::error::WikiResult;
+use crate::symbols;
+use crate::vector_store::VectorStore;
+
+/// Maximum number of symbols followed from a single chunk
+const MAX_SYMBOLS_PER_CHUNK: usize = 8;
+
+/// Maximum number of candidate chunks inspected per symbol
+const MAX_CANDIDATES_PER_SYMBOL: usize = 20;
+
+/// Bounds for call-graph expansion
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CallGraphExpansion {
+    /// Maximum number of call-graph hops from a search hit
+    pub max_depth: u32,
+    /// Number of top search hits to expand from
+    pub max_seed_hits: usize,
+    /// Maximum number of chunks added by expansion
+    pub max_expansions: usize,
+    /// Token budget shared by all expanded chunks
+    pub max_tokens: u32,
+}
+
+impl Default for CallGraphExpansion {
+    fn default() -> Self {
+        Self {
+            max_depth: 1,
+            max_seed_hits: 3,
+            max_expansions: 6,
+            max_tokens: 2000,
+        }
+    }
+}
+
+impl CallGraphExpansion {
+    /// Append callers/callees of the top `results` to the result list.
+    ///
+    /// Original results keep their order; expanded results follow them and
+    /// carry a [`ContextExpansion`] describing the edge that pulled them in.
+    /// Edges are only followed within the branch of the chunk they start from.
+    pub fn expand(
+        &self,
+        store: &VectorStore,
+        mut results: Vec<SearchResult>,
+    ) -> WikiResult<Vec<SearchResult>> {
+        let mut tokens_used = self.attach_neighbours(store, &mut results)?;
+
+        let mut seen: HashSet<(String, u32)> = results
+            .iter()
+            .map(|r| (r.file_path.clone(), r.start_line))
+            .collect();
+        let mut frontier: Vec<SearchResult> =
+            results.iter().take(self.max_seed_hits).cloned().collect();
+        let mut expanded: Vec<SearchResult> = Vec::new();
+
+        'depth: for depth in 1..=self.max_depth {
+            let mut next_frontier = Vec::new();
+
+            for origin in &frontier {
+                let branch = store.get_chunk_branch(origin.chunk_id)?;
+
+                for (relation, symbol) in Self::edges(&origin.content) {
+                    if expanded.len() >= self.max_expansions {
+                        break 'depth;
+                    }
+
+                    let candidates = store.find_chunks_mentioning(
+                        &symbol,
+                        branch.as_deref(),
+                        MAX_CANDIDATES_PER_SYMBOL,
+                    )?;
+
+                    let linked = candidates.into_iter().find(|chunk| {
+                        let connected = match relation {
+                            ExpansionRelation::Callee => symbols::defines(&chunk.content, &symbol),
+                            ExpansionRelation::Caller => symbols::calls(&chunk.content, &symbol),
+                        };
+                        connected
+                            && tokens_used + chunk.token_count <= self.max_tokens
+                            && !seen.contains(&(chunk.file_path.clone(), chunk.start_line))
+                    });
+
+                    let Some(chunk) = linked else {
+                        continue;
+                    };
+
+                    seen.insert((chunk.file_path.clone(), chunk.start_line));
+                    tokens_used += chunk.token_count;
+
+                    let result = SearchResult::new(
+                        chunk.id,
+                        chunk.file_path,
+                        chunk.start_line,
+                        chunk.end_line,
+                        chunk.content,
+                        chunk.chunk_type,
+                        chunk.language,
+                        origin.score,
+                    )
+                    .with_public_api(chunk.is_public_api)
+                    .with_symbols(chunk.symbols)
+                    .with_expansion(ContextExpansion {
+                        relation,
+                        symbol,
+                        origin: origin.location(),
+                        depth,
+                    });
+
+                    next_frontier.push(result.clone());
+                    expanded.push(result);
+                }
+            }
+
+            if next_frontier.is_empty() {
+                break;
+            }
+            frontier = next_frontier;
+        }
+
+        debug!(
+            "Call-graph expansion added {} chunks ({} tokens)",
+            expanded.len(),
+            tokens_used
+        );
+
+        let mut combined = results;
+        combined.extend(expanded);
+        Ok(combined)
+    }
+
+    /// Fill `context_before`/`context_after` of the top hits with the
+    /// previous/next chunk of the same file, within the token budget.
+    /// Returns the tokens spent.
+    fn attach_neighbours(
+        &self,
+        store: &VectorStore,
+        results: &mut [SearchResult],
+    ) -> WikiResult<u32> {
+        let mut tokens_used = 0u32;
+
+        for result in results.iter_mut().take(self.max_seed_hits) {
+            if result.context_before.is_some() || result.context_after.is_some() {
+                continue;
+            }
+            let Some(chunk) = store.get_chunk(result.chunk_id)? else {
+                continue;
+            };
+
+            let file_chunks = store.get_chunks_for_file(&chunk.branch, &chunk.file_path)?;
+            let Some(position) = file_chunks.iter().position(|c| c.id == chunk.id) else {
+                continue;
+            };
+
+            let mut take = |neighbour: Option<&CodeChunk>| {
+                let neighbour = neighbour?;
+                if tokens_used + neighbour.token_count > self.max_tokens {
+                    return None;
+                }
+                tokens_used += neighbour.token_count;
+                Some(neighbour.content.clone())
+            };
+            let before = take(position.checked_sub(1).and_then(|i| file_chunks.get(i)));
+            let after = take(file_chunks.get(position + 1));
+
+            *result = result.clone().with_context(before, after);
+        }
+
+        Ok(tokens_used)
+    }
+
+    /// Outgoing (callee) edges first, then incoming (caller) edges
+    fn edges(content: &str) -> Vec<(ExpansionRelation, String)> {
+        let callees = symbols::extract_calls(content)
+            .into_iter()
+            .take(MAX_SYMBOLS_PER_CHUNK)
+            .map(|s| (ExpansionRelation::Callee, s));
+        let callers = symbols::extract_definitions(content)
+            .into_iter()
+            .take(MAX_SYMBOLS_PER_CHUNK)
+            .map(|s| (ExpansionRelation::Caller, s));
+
+        callees.chain(callers).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::chunk::ChunkType;
+    use tempfile::tempdir;
+
+    fn chunk(path: &str, content: &str, tokens: u32) -> CodeChunk {
+        CodeChunk::new(
+            "main".to_string(),
+            path.to_string(),
+            1,
+            5,
+            content.to_string(),
+            ChunkType::Function,
+            Some("rust".to_string()),
+            tokens,
+            0,
+            "abc123".to_string(),
+        )
+    }
+
+    fn hit(c: &CodeChunk, score: f32) -> SearchResult {
+        SearchResult::new(
+            c.id,
+            c.file_path.clone(),
+            c.start_line,
+            c.end_line,
+            c.content.clone(),
+            c.chunk_type,
+            c.language.clone(),
+            score,
+        )
+    }
+
+    #[test]
+    fn test_expand_adds_callers_and_callees() {
+        let dir = tempdir().unwrap();
+        let store = VectorStore::new(&dir.path().join("test.db")).unwrap();
+
+        let origin = chunk("src/handler.rs", "fn handle() { validate_input(x); }", 10);
+        let callee = chunk("src/validate.rs", "fn validate_input(x: u32) {}", 10);
+        let caller = chunk("src/router.rs", "fn route() { handle(); }", 10);
+        let unrelated = chunk("src/other.rs", "fn other() {}", 10);
+        store
+            .insert_chunks_batch(&[origin.clone(), callee, caller, unrelated])
+            .unwrap();
+
+        let expanded = CallGraphExpansion::default()
+            .expand(&store, vec![hit(&origin, 0.9)])
+            .unwrap();
+
+        assert_eq!(expanded.len(), 3);
+        assert!(expanded[0].expansion.is_none());
+
+        let callee_hit = &expanded[1];
+        assert_eq!(callee_hit.file_path, "src/validate.rs");
+        let expansion = callee_hit.expansion.as_ref().unwrap();
+        assert_eq!(expansion.relation, ExpansionRelation::Callee);
+        assert_eq!(expansion.symbol, "validate_input");
+        assert_eq!(expansion.origin, "src/handler.rs:1-5");
+
+        let caller_hit = &expanded[2];
+        assert_eq!(caller_hit.file_path, "src/router.rs");
+        assert_eq!(
+            caller_hit.expansion.as_ref().unwrap().relation,
+            ExpansionRelation::Caller
+        );
+    }
+
+    #[test]
+    fn test_expand_stays_in_branch_of_hit() {
+        let dir = tempdir().unwrap();
+        let store = VectorStore::new(&dir.path().join("test.db")).unwrap();
+
+        let origin = chunk("src/handler.rs", "fn handle() { validate_input(x); }", 10);
+        let mut other_branch = chunk("src/validate.rs", "fn validate_input(x: u32) {}", 10);
+        other_branch.branch = "feature".to_string();
+        store
+            .insert_chunks_batch(&[origin.clone(), other_branch])
+            .unwrap();
+
+        let expanded = CallGraphExpansion::default()
+            .expand(&store, vec![hit(&origin, 0.9)])
+            .unwrap();
+
+        assert_eq!(expanded.len(), 1);
+    }
+
+    #[test]
+    fn test_expand_respects_token_budget() {
+        let dir = tempdir().unwrap();
+        let store = VectorStore::new(&dir.path().join("test.db")).unwrap();
+
+        let origin = chunk("src/a.rs", "fn run() { big_helper(); }", 10);
+        let callee = chunk("src/b.rs", "fn big_helper() {}", 5000);
+        store
+            .insert_chunks_batch(&[origin.clone(), callee])
+            .unwrap();
+
+        let expanded = CallGraphExpansion::default()
+            .expand(&store, vec![hit(&origin, 0.9)])
+            .unwrap();
+
+        assert_eq!(expanded.len(), 1);
+    }
+
+    #[test]
+    fn test_expand_attaches_neighbouring_chunks() {
+        let dir = tempdir().unwrap();
+        let store = VectorStore::new(&dir.path().join("test.db")).unwrap();
+
+        let mut chunks: Vec<CodeChunk> = ["// head", "fn middle() {}", "// tail"]
+            .iter()
+            .map(|content| chunk("src/lib.rs", content, 10))
+            .collect();
+        for (index, c) in chunks.iter_mut().enumerate() {
+            c.chunk_index = index as u32;
+        }
+        store.insert_chunks_batch(&chunks).unwrap();
+
+        let expanded = CallGraphExpansion::default()
+            .expand(&store, vec![hit(&chunks[1], 0.9), hit(&chunks[0], 0.8)])
+            .unwrap();
+
+        assert_eq!(expanded[0].context_before.as_deref(), Some("// head"));
+        assert_eq!(expanded[0].context_after.as_deref(), Some("// tail"));
+        assert_eq!(expanded[1].context_before, None);
+        assert_eq!(expanded[1].context_after.as_deref(), Some("fn middle() {}"));
+    }
+
+    #[test]
+    fn test_expansion_describe() {
+        let expansion = ContextExpansion {
+            relation: ExpansionRelation::Callee,
+            symbol: "parse".to_string(),
+            origin: "src/lib.rs:10-20".to_string(),
+            depth: 1,
+        };
+        assert_eq!(expansion.describe(), "callee `parse` of src/lib.rs:10-20");
+    }
+}
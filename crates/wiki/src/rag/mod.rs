@@ -1,15 +1,25 @@
 //! RAG (Retrieval-Augmented Generation) engine for Q&A over codebase
 
+pub mod decomposition;
+pub mod expansion;
+pub mod mode;
+
 use futures::StreamExt;
 use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 use crate::domain::search_result::SearchResult;
+use crate::embeddings::EmbeddingProvider;
 use crate::error::WikiResult;
 use crate::openrouter::client::OpenRouterClient;
 use crate::openrouter::types::ChatMessage;
 use crate::vector_store::VectorStore;
+use decomposition::merge_sub_query_results;
+
+pub use decomposition::QueryDecomposition;
+pub use expansion::CallGraphExpansion;
+pub use mode::AnswerMode;
 
 /// Default number of chunks to retrieve for context
 const DEFAULT_TOP_K: usize = 10;
@@ -17,19 +27,6 @@ const DEFAULT_TOP_K: usize = 10;
 /// Maximum context length in characters
 const MAX_CONTEXT_LENGTH: usize = 32000;
 
-/// System prompt for code Q&A
-const RAG_SYSTEM_PROMPT: &str = r#"You are a knowledgeable code assistant helping developers understand a codebase.
-
-You have access to relevant code snippets from the codebase to answer questions.
-When answering:
-- Reference specific files and line numbers when relevant (format: `file_path:line_number`)
-- Provide concise but complete explanations
-- Include code examples when helpful
-- If the context doesn't contain enough information, say so clearly
-- Don't make up information that's not in the provided context
-
-Always cite the relevant code locations to support your answers."#;
-
 /// A message in the conversation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Message {
@@ -135,6 +132,9 @@ pub struct RagResponse {
     pub sources: Vec<RagSource>,
     /// The query that was asked
     pub query: String,
+    /// Sub-queries retrieved separately when the question was decomposed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sub_queries: Vec<String>,
 }
 
 /// A source reference in a RAG response
@@ -150,6 +150,16 @@ pub struct RagSource {
     pub score: f32,
     /// Content snippet
     pub snippet: String,
+    /// One-line summary of the source's code, if one was generated at index time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// Why this source was included when it was not a direct search hit
+    /// (e.g. "callee `parse` of src/lib.rs:10-20")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expansion: Option<String>,
+    /// Sub-queries that retrieved this source when the question was decomposed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sub_queries: Vec<String>,
 }
 
 impl From<&SearchResult> for RagSource {
@@ -160,17 +170,34 @@ impl From<&SearchResult> for RagSource {
             end_line: result.end_line,
             score: result.score,
             snippet: truncate_snippet(&result.content, 200),
+            summary: result.summary.clone(),
+            expansion: result.expansion.as_ref().map(|e| e.describe()),
+            sub_queries: Vec::new(),
         }
     }
 }
 
+/// Chunks retrieved for a question, with their sources
+struct Retrieval {
+    results: Vec<SearchResult>,
+    sources: Vec<RagSource>,
+    sub_queries: Vec<String>,
+}
+
 /// RAG engine for question answering over codebase
 pub struct RagEngine<'a> {
     openrouter: &'a OpenRouterClient,
+    /// Embeds queries; the OpenRouter client unless set
+    embeddings: &'a dyn EmbeddingProvider,
     vector_store: &'a VectorStore,
     embedding_model: String,
     chat_model: String,
     top_k: usize,
+    expansion: Option<CallGraphExpansion>,
+    decomposition: Option<QueryDecomposition>,
+    answer_mode: AnswerMode,
+    /// Workspace root retrieval is limited to
+    root: Option<String>,
 }
 
 impl<'a> RagEngine<'a> {
@@ -183,64 +210,178 @@ impl<'a> RagEngine<'a> {
     ) -> Self {
         Self {
             openrouter,
+            embeddings: openrouter,
             vector_store,
             embedding_model: embedding_model.into(),
             chat_model: chat_model.into(),
             top_k: DEFAULT_TOP_K,
+            expansion: Some(CallGraphExpansion::default()),
+            decomposition: None,
+            answer_mode: AnswerMode::default(),
+            root: None,
         }
     }
 
+    /// Embed queries with `embeddings` instead of the OpenRouter client
+    pub fn with_embeddings(mut self, embeddings: &'a dyn EmbeddingProvider) -> Self {
+        self.embeddings = embeddings;
+        self
+    }
+
     /// Set the number of chunks to retrieve
     pub fn with_top_k(mut self, top_k: usize) -> Self {
         self.top_k = top_k;
         self
     }
 
-    /// Ask a question about the codebase (non-streaming)
-    pub async fn ask(&self, query: &str) -> WikiResult<RagResponse> {
-        info!("RAG query: {}", query);
+    /// Configure call-graph context expansion (`None` disables it)
+    pub fn with_call_graph_expansion(mut self, expansion: Option<CallGraphExpansion>) -> Self {
+        self.expansion = expansion;
+        self
+    }
+
+    /// Configure splitting compound questions into sub-queries that are
+    /// retrieved separately (`None`, the default, disables it)
+    pub fn with_query_decomposition(mut self, decomposition: Option<QueryDecomposition>) -> Self {
+        self.decomposition = decomposition;
+        self
+    }
+
+    /// Set the shape of the answers
+    pub fn with_answer_mode(mut self, answer_mode: AnswerMode) -> Self {
+        self.answer_mode = answer_mode;
+        self
+    }
+
+    /// Only retrieve code from the workspace `root` (`None`, the default,
+    /// searches every root)
+    pub fn with_root(mut self, root: Option<String>) -> Self {
+        self.root = root;
+        self
+    }
+
+    /// Retrieve context for the query, decomposing it into sub-queries first
+    /// when enabled and the question asks about several things
+    async fn retrieve(&self, query: &str) -> WikiResult<Retrieval> {
+        let sub_queries = match &self.decomposition {
+            Some(decomposition) => decomposition
+                .decompose(self.openrouter, &self.chat_model, query)
+                .await
+                .unwrap_or_else(|e| {
+                    warn!(error = %e, "Query decomposition failed, retrieving the question as is");
+                    Vec::new()
+                }),
+            None => Vec::new(),
+        };
+
+        if sub_queries.len() < 2 {
+            let results = self.search(query).await?;
+            let sources = results.iter().map(RagSource::from).collect();
+            return Ok(Retrieval {
+                results,
+                sources,
+                sub_queries: Vec::new(),
+            });
+        }
+
+        let mut per_query = Vec::with_capacity(sub_queries.len());
+        for sub_query in &sub_queries {
+            let results = self.search(sub_query).await?;
+            debug!(sub_query = %sub_query, hits = results.len(), "Retrieved sub-query");
+            per_query.push((sub_query.clone(), results));
+        }
+
+        let mut context_length = 0;
+        let merged = merge_sub_query_results(per_query, |result| {
+            context_length += format_chunk(0, result).len();
+            context_length <= MAX_CONTEXT_LENGTH
+        });
+
+        let mut results = Vec::with_capacity(merged.len());
+        let mut sources = Vec::with_capacity(merged.len());
+        for attributed in merged {
+            let mut source = RagSource::from(&attributed.result);
+            source.sub_queries = attributed.sub_queries;
+            sources.push(source);
+            results.push(attributed.result);
+        }
 
-        // 1. Create embedding for the query
+        Ok(Retrieval {
+            results,
+            sources,
+            sub_queries,
+        })
+    }
+
+    /// Embed the query, search for similar chunks and expand the top hits
+    async fn search(&self, query: &str) -> WikiResult<Vec<SearchResult>> {
         let query_embedding = self
-            .openrouter
+            .embeddings
             .create_embedding(query, &self.embedding_model)
             .await?;
 
-        // 2. Search for similar chunks
-        let search_results = self
-            .vector_store
-            .search_similar(&query_embedding, self.top_k)?;
+        let search_results = self.vector_store.search_similar_in_branch(
+            &query_embedding,
+            &self.embedding_model,
+            self.top_k,
+            None,
+            self.root.as_deref(),
+        )?;
+
+        match &self.expansion {
+            Some(expansion) if !search_results.is_empty() => {
+                expansion.expand(self.vector_store, search_results)
+            }
+            _ => Ok(search_results),
+        }
+    }
+
+    /// Ask a question about the codebase (non-streaming)
+    pub async fn ask(&self, query: &str) -> WikiResult<RagResponse> {
+        info!("RAG query: {}", query);
+
+        // 1-2. Embed the query (or its sub-queries), search for similar chunks and expand via the call graph
+        let retrieval = self.retrieve(query).await?;
 
-        if search_results.is_empty() {
+        if retrieval.results.is_empty() {
             return Ok(RagResponse {
                 answer: "I couldn't find any relevant code in the indexed codebase to answer your question.".to_string(),
                 sources: Vec::new(),
                 query: query.to_string(),
+                sub_queries: retrieval.sub_queries,
             });
         }
 
-        debug!("Found {} relevant chunks for query", search_results.len());
+        debug!(
+            "Found {} relevant chunks for query",
+            retrieval.results.len()
+        );
 
         // 3. Build context from search results
-        let context = build_context(&search_results);
-        let sources: Vec<RagSource> = search_results.iter().map(RagSource::from).collect();
+        let context = build_context(&retrieval.results);
 
         // 4. Create chat messages
         let messages = vec![
-            ChatMessage::system(RAG_SYSTEM_PROMPT),
+            ChatMessage::system(self.answer_mode.system_prompt()),
             ChatMessage::user(format_user_prompt(query, &context)),
         ];
 
         // 5. Get completion
         let answer = self
             .openrouter
-            .chat_completion(messages, &self.chat_model, Some(0.3), Some(2048))
+            .chat_completion(
+                messages,
+                &self.chat_model,
+                Some(0.3),
+                Some(self.answer_mode.max_tokens()),
+            )
             .await?;
 
         Ok(RagResponse {
             answer,
-            sources,
+            sources: retrieval.sources,
             query: query.to_string(),
+            sub_queries: retrieval.sub_queries,
         })
     }
 
@@ -258,33 +399,25 @@ impl<'a> RagEngine<'a> {
         // Add user message to history
         conversation.add_user_message(query);
 
-        // 1. Create embedding for the query
-        let query_embedding = self
-            .openrouter
-            .create_embedding(query, &self.embedding_model)
-            .await?;
-
-        // 2. Search for similar chunks
-        let search_results = self
-            .vector_store
-            .search_similar(&query_embedding, self.top_k)?;
+        // 1-2. Embed the query (or its sub-queries), search for similar chunks and expand via the call graph
+        let retrieval = self.retrieve(query).await?;
 
-        if search_results.is_empty() {
+        if retrieval.results.is_empty() {
             let answer = "I couldn't find any relevant code in the indexed codebase to answer your question.".to_string();
             conversation.add_assistant_message(&answer);
             return Ok(RagResponse {
                 answer,
                 sources: Vec::new(),
                 query: query.to_string(),
+                sub_queries: retrieval.sub_queries,
             });
         }
 
         // 3. Build context from search results
-        let context = build_context(&search_results);
-        let sources: Vec<RagSource> = search_results.iter().map(RagSource::from).collect();
+        let context = build_context(&retrieval.results);
 
         // 4. Create chat messages with history
-        let mut messages = vec![ChatMessage::system(RAG_SYSTEM_PROMPT)];
+        let mut messages = vec![ChatMessage::system(self.answer_mode.system_prompt())];
 
         // Add conversation history (skip the last user message, we'll add it with context)
         for msg in conversation
@@ -304,7 +437,12 @@ impl<'a> RagEngine<'a> {
         // 5. Get completion
         let answer = self
             .openrouter
-            .chat_completion(messages, &self.chat_model, Some(0.3), Some(2048))
+            .chat_completion(
+                messages,
+                &self.chat_model,
+                Some(0.3),
+                Some(self.answer_mode.max_tokens()),
+            )
             .await?;
 
         // Add assistant response to history
@@ -312,8 +450,9 @@ impl<'a> RagEngine<'a> {
 
         Ok(RagResponse {
             answer,
-            sources,
+            sources: retrieval.sources,
             query: query.to_string(),
+            sub_queries: retrieval.sub_queries,
         })
     }
 
@@ -324,18 +463,12 @@ impl<'a> RagEngine<'a> {
     ) -> WikiResult<(mpsc::Receiver<WikiResult<String>>, Vec<RagSource>)> {
         info!("RAG streaming query: {}", query);
 
-        // 1. Create embedding for the query
-        let query_embedding = self
-            .openrouter
-            .create_embedding(query, &self.embedding_model)
-            .await?;
-
-        // 2. Search for similar chunks
-        let search_results = self
-            .vector_store
-            .search_similar(&query_embedding, self.top_k)?;
-
-        let sources: Vec<RagSource> = search_results.iter().map(RagSource::from).collect();
+        // 1-2. Embed the query (or its sub-queries), search for similar chunks and expand via the call graph
+        let Retrieval {
+            results: search_results,
+            sources,
+            ..
+        } = self.retrieve(query).await?;
 
         if search_results.is_empty() {
             let (tx, rx) = mpsc::channel(1);
@@ -355,14 +488,19 @@ impl<'a> RagEngine<'a> {
 
         // 4. Create chat messages
         let messages = vec![
-            ChatMessage::system(RAG_SYSTEM_PROMPT),
+            ChatMessage::system(self.answer_mode.system_prompt()),
             ChatMessage::user(format_user_prompt(query, &context)),
         ];
 
         // 5. Get streaming completion
         let stream = self
             .openrouter
-            .chat_completion_stream(messages, &self.chat_model, Some(0.3), Some(2048))
+            .chat_completion_stream(
+                messages,
+                &self.chat_model,
+                Some(0.3),
+                Some(self.answer_mode.max_tokens()),
+            )
             .await?;
 
         // Create channel for forwarding chunks
@@ -392,18 +530,12 @@ impl<'a> RagEngine<'a> {
             conversation.id, query
         );
 
-        // 1. Create embedding for the query
-        let query_embedding = self
-            .openrouter
-            .create_embedding(query, &self.embedding_model)
-            .await?;
-
-        // 2. Search for similar chunks
-        let search_results = self
-            .vector_store
-            .search_similar(&query_embedding, self.top_k)?;
-
-        let sources: Vec<RagSource> = search_results.iter().map(RagSource::from).collect();
+        // 1-2. Embed the query (or its sub-queries), search for similar chunks and expand via the call graph
+        let Retrieval {
+            results: search_results,
+            sources,
+            ..
+        } = self.retrieve(query).await?;
 
         if search_results.is_empty() {
             let (tx, rx) = mpsc::channel(1);
@@ -417,7 +549,7 @@ impl<'a> RagEngine<'a> {
         let context = build_context(&search_results);
 
         // 4. Create chat messages with history
-        let mut messages = vec![ChatMessage::system(RAG_SYSTEM_PROMPT)];
+        let mut messages = vec![ChatMessage::system(self.answer_mode.system_prompt())];
 
         // Add conversation history
         for msg in &conversation.messages {
@@ -433,7 +565,12 @@ impl<'a> RagEngine<'a> {
         // 5. Get streaming completion
         let stream = self
             .openrouter
-            .chat_completion_stream(messages, &self.chat_model, Some(0.3), Some(2048))
+            .chat_completion_stream(
+                messages,
+                &self.chat_model,
+                Some(0.3),
+                Some(self.answer_mode.max_tokens()),
+            )
             .await?;
 
         // Create channel for forwarding chunks
@@ -459,36 +596,63 @@ fn build_context(results: &[SearchResult]) -> String {
     let mut total_length = 0;
 
     for (i, result) in results.iter().enumerate() {
-        let chunk_header = format!(
-            "\n--- Source {}: {} (lines {}-{}) ---\n",
-            i + 1,
-            result.file_path,
-            result.start_line,
-            result.end_line
-        );
-
-        let chunk_content = if let Some(lang) = &result.language {
-            format!("```{}\n{}\n```\n", lang, result.content)
-        } else {
-            format!("```\n{}\n```\n", result.content)
-        };
-
-        let chunk_total = chunk_header.len() + chunk_content.len();
+        let chunk = format_chunk(i, result);
 
         // Check if adding this chunk would exceed max length
-        if total_length + chunk_total > MAX_CONTEXT_LENGTH {
+        if total_length + chunk.len() > MAX_CONTEXT_LENGTH {
             debug!("Context truncated at {} chunks due to length limit", i);
             break;
         }
 
-        context.push_str(&chunk_header);
-        context.push_str(&chunk_content);
-        total_length += chunk_total;
+        total_length += chunk.len();
+        context.push_str(&chunk);
     }
 
     context
 }
 
+/// Format one search result as a context entry with a source header
+fn format_chunk(index: usize, result: &SearchResult) -> String {
+    let chunk_header = format!(
+        "\n--- Source {}: {} (lines {}-{}){} ---\n",
+        index + 1,
+        result.file_path,
+        result.start_line,
+        result.end_line,
+        expansion_note(result)
+    );
+
+    let fence = |content: &str| match &result.language {
+        Some(lang) => format!("```{}\n{}\n```\n", lang, content),
+        None => format!("```\n{}\n```\n", content),
+    };
+
+    let mut chunk_content = String::new();
+    if let Some(summary) = &result.summary {
+        chunk_content.push_str(&format!("Summary: {}\n", summary));
+    }
+    if let Some(before) = &result.context_before {
+        chunk_content.push_str("Preceding code:\n");
+        chunk_content.push_str(&fence(before));
+    }
+    chunk_content.push_str(&fence(&result.content));
+    if let Some(after) = &result.context_after {
+        chunk_content.push_str("Following code:\n");
+        chunk_content.push_str(&fence(after));
+    }
+
+    chunk_header + &chunk_content
+}
+
+/// Annotation for context headers of chunks added by expansion
+fn expansion_note(result: &SearchResult) -> String {
+    result
+        .expansion
+        .as_ref()
+        .map(|e| format!(" [{}]", e.describe()))
+        .unwrap_or_default()
+}
+
 /// Format the user prompt with query and context
 fn format_user_prompt(query: &str, context: &str) -> String {
     format!(
@@ -662,8 +826,12 @@ mod tests {
                 end_line: 10,
                 score: 0.9,
                 snippet: "fn test()".to_string(),
+                summary: None,
+                expansion: None,
+                sub_queries: Vec::new(),
             }],
             query: "What does test do?".to_string(),
+            sub_queries: Vec::new(),
         };
 
         let json = serde_json::to_string(&response).unwrap();
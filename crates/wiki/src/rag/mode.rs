@@ -0,0 +1,128 @@
+//! Answer shapes for codebase Q&A
+//!
+//! Each mode pairs a system prompt with a completion budget, so callers can
+//! ask for a quick summary, a full walkthrough or just the relevant code.
+
+use serde::{Deserialize, Serialize};
+
+const STANDARD_PROMPT: &str = r#"You are a knowledgeable code assistant helping developers understand a codebase.
+
+You have access to relevant code snippets from the codebase to answer questions.
+When answering:
+- Reference specific files and line numbers when relevant (format: `file_path:line_number`)
+- Provide concise but complete explanations
+- Include code examples when helpful
+- If the context doesn't contain enough information, say so clearly
+- Don't make up information that's not in the provided context
+
+Always cite the relevant code locations to support your answers."#;
+
+const CONCISE_PROMPT: &str = r#"You are a code assistant giving quick answers about a codebase.
+
+You have access to relevant code snippets from the codebase to answer questions.
+When answering:
+- Answer in at most three sentences or a short bullet list
+- Cite the one or two most relevant locations (format: `file_path:line_number`)
+- Leave out code blocks unless a single line says it best
+- If the context doesn't contain enough information, say so in one sentence
+- Don't make up information that's not in the provided context"#;
+
+const DETAILED_PROMPT: &str = r#"You are a senior engineer walking a colleague through a codebase.
+
+You have access to relevant code snippets from the codebase to answer questions.
+When answering:
+- Start with a one-paragraph summary, then walk through the flow step by step
+- Explain the key types and functions involved and how they connect
+- Call out edge cases, error handling and anything surprising
+- Reference specific files and line numbers for every step (format: `file_path:line_number`)
+- Include code excerpts where they help follow the walkthrough
+- If the context doesn't contain enough information, say what is missing
+- Don't make up information that's not in the provided context"#;
+
+const CODE_ONLY_PROMPT: &str = r#"You are a code search assistant. Reply with code, not explanations.
+
+You have access to relevant code snippets from the codebase to answer questions.
+When answering:
+- Reply only with fenced code blocks taken from the provided context
+- Put a `// file_path:start_line-end_line` comment on the first line of each block
+- Order blocks from most to least relevant and trim them to the lines that answer the question
+- Write no prose; if no snippet answers the question, reply with a single line saying so
+- Don't write code that's not in the provided context"#;
+
+/// Shape of an answer to a codebase question
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnswerMode {
+    /// Balanced explanation with citations
+    #[default]
+    Standard,
+    /// A few sentences
+    Concise,
+    /// Step-by-step walkthrough
+    Detailed,
+    /// Relevant code snippets without prose
+    CodeOnly,
+}
+
+impl AnswerMode {
+    pub const ALL: [AnswerMode; 4] = [
+        AnswerMode::Standard,
+        AnswerMode::Concise,
+        AnswerMode::Detailed,
+        AnswerMode::CodeOnly,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AnswerMode::Standard => "standard",
+            AnswerMode::Concise => "concise",
+            AnswerMode::Detailed => "detailed",
+            AnswerMode::CodeOnly => "code_only",
+        }
+    }
+
+    /// Parse a mode, accepting `code-only` as well as `code_only`
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim() {
+            "standard" => Some(AnswerMode::Standard),
+            "concise" => Some(AnswerMode::Concise),
+            "detailed" => Some(AnswerMode::Detailed),
+            "code_only" | "code-only" => Some(AnswerMode::CodeOnly),
+            _ => None,
+        }
+    }
+
+    pub fn system_prompt(&self) -> &'static str {
+        match self {
+            AnswerMode::Standard => STANDARD_PROMPT,
+            AnswerMode::Concise => CONCISE_PROMPT,
+            AnswerMode::Detailed => DETAILED_PROMPT,
+            AnswerMode::CodeOnly => CODE_ONLY_PROMPT,
+        }
+    }
+
+    /// Completion budget for answers in this mode
+    pub fn max_tokens(&self) -> u32 {
+        match self {
+            AnswerMode::Standard => 2048,
+            AnswerMode::Concise => 512,
+            AnswerMode::Detailed => 4096,
+            AnswerMode::CodeOnly => 1536,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_mode_parse() {
+        for mode in AnswerMode::ALL {
+            assert_eq!(AnswerMode::parse(mode.as_str()), Some(mode));
+        }
+        assert_eq!(AnswerMode::parse("code-only"), Some(AnswerMode::CodeOnly));
+        assert_eq!(AnswerMode::parse("verbose"), None);
+        assert!(AnswerMode::Concise.max_tokens() < AnswerMode::Detailed.max_tokens());
+    }
+}
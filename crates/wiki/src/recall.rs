@@ -0,0 +1,94 @@
+//! Cross-task recall
+//!
+//! Past tasks, review findings and session summaries are embedded into their
+//! own table of the wiki database, so the planner and reviewer can find
+//! related earlier work ("we fixed a similar bug last month").
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use tracing::debug;
+
+use crate::domain::recall_item::{RecallItem, RecallMatch, RecallScope};
+use crate::embeddings::EmbeddingProvider;
+use crate::error::WikiResult;
+use crate::review_context::excerpt;
+use crate::vector_store::VectorStore;
+
+/// Items embedded per API call
+const EMBEDDING_BATCH_SIZE: usize = 100;
+
+/// Characters of an item embedded
+const EMBEDDED_TEXT_LENGTH: usize = 8000;
+
+/// Embeds and searches recall items
+pub struct RecallIndex<'a> {
+    embeddings: &'a dyn EmbeddingProvider,
+    embedding_model: String,
+}
+
+impl<'a> RecallIndex<'a> {
+    /// Create a new recall index
+    pub fn new(embeddings: &'a dyn EmbeddingProvider, embedding_model: impl Into<String>) -> Self {
+        Self {
+            embeddings,
+            embedding_model: embedding_model.into(),
+        }
+    }
+
+    /// Bring the recall table of the wiki database at `db_path` in line with
+    /// `items`, the complete set of current items: new or changed items are
+    /// embedded and items no longer present are removed.
+    ///
+    /// Returns the number of items embedded.
+    pub async fn sync(&self, db_path: &Path, items: &[RecallItem]) -> WikiResult<usize> {
+        let stale: Vec<RecallItem> = {
+            let store = VectorStore::new(db_path)?;
+            let ids: HashSet<String> = items.iter().map(|item| item.id.clone()).collect();
+            let removed = store.retain_recall_items(&ids)?;
+            if removed > 0 {
+                debug!("Removed {} recall items", removed);
+            }
+            store
+                .stale_recall_items(items, &self.embedding_model)?
+                .into_iter()
+                .cloned()
+                .collect()
+        };
+
+        for batch in stale.chunks(EMBEDDING_BATCH_SIZE) {
+            let texts: Vec<String> = batch
+                .iter()
+                .map(|item| excerpt(&item.embedding_text(), EMBEDDED_TEXT_LENGTH))
+                .collect();
+            let embeddings = self
+                .embeddings
+                .create_embeddings_batch(&texts, &self.embedding_model)
+                .await?;
+
+            let store = VectorStore::new(db_path)?;
+            let batch: Vec<&RecallItem> = batch.iter().collect();
+            store.upsert_recall_items(&self.embedding_model, &batch, &embeddings)?;
+        }
+
+        debug!("Embedded {} recall items", stale.len());
+        Ok(stale.len())
+    }
+
+    /// The items most similar to `query`, optionally limited to one scope
+    pub async fn search(
+        &self,
+        db_path: &Path,
+        query: &str,
+        scope: Option<RecallScope>,
+        limit: usize,
+    ) -> WikiResult<Vec<RecallMatch>> {
+        let embedding = self
+            .embeddings
+            .create_embedding(&excerpt(query, EMBEDDED_TEXT_LENGTH), &self.embedding_model)
+            .await?;
+
+        let store = VectorStore::open_read_only(db_path)?;
+        store.search_recall(&embedding, &self.embedding_model, scope, limit)
+    }
+}
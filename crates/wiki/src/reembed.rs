@@ -0,0 +1,120 @@
+//! Incremental migration of a branch to a new embedding model
+//!
+//! The branch's chunks are embedded with the new model into a side-by-side
+//! index while the current index keeps serving searches. Each run embeds at
+//! most a configurable number of chunks and can be resumed; the branch
+//! switches over once every chunk has a vector from the new model.
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+
+use crate::domain::chunk::CodeChunk;
+use crate::embeddings::EmbeddingProvider;
+use crate::error::WikiResult;
+use crate::vector_store::VectorStore;
+
+/// Chunks embedded per API call
+const DEFAULT_BATCH_SIZE: usize = 100;
+
+/// Outcome of one re-embed run
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReembedProgress {
+    pub branch: String,
+    pub model: String,
+    /// Version the branch's index gets once the migration completes
+    pub version: u32,
+    /// Chunks embedded by this run
+    pub embedded: u32,
+    /// Chunks still without a vector from `model`
+    pub remaining: u32,
+    /// Whether the branch now searches with `model`
+    pub completed: bool,
+}
+
+/// Migrates a branch's vectors to a new embedding model
+pub struct Reembedder<'a> {
+    embeddings: &'a dyn EmbeddingProvider,
+    vector_store: &'a VectorStore,
+    batch_size: usize,
+    max_chunks: Option<usize>,
+}
+
+impl<'a> Reembedder<'a> {
+    /// Create a new re-embedder
+    pub fn new(embeddings: &'a dyn EmbeddingProvider, vector_store: &'a VectorStore) -> Self {
+        Self {
+            embeddings,
+            vector_store,
+            batch_size: DEFAULT_BATCH_SIZE,
+            max_chunks: None,
+        }
+    }
+
+    /// Set the number of chunks embedded per API call
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Stop after embedding `max_chunks` chunks; the next run resumes
+    pub fn with_max_chunks(mut self, max_chunks: usize) -> Self {
+        self.max_chunks = Some(max_chunks);
+        self
+    }
+
+    /// Embed the chunks of `branch` that lack a `model` vector, switching the
+    /// branch to `model` once none are left
+    pub async fn reembed(&self, branch: &str, model: &str) -> WikiResult<ReembedProgress> {
+        let index = self.vector_store.begin_embedding_migration(branch, model)?;
+        info!(
+            "Re-embedding '{}' with {} (version {}, {} chunks already embedded)",
+            branch, model, index.version, index.embedded_chunks
+        );
+
+        let mut embedded = 0;
+        loop {
+            let budget = self.max_chunks.map(|max| max.saturating_sub(embedded));
+            if budget == Some(0) {
+                break;
+            }
+            let limit = budget.map_or(self.batch_size, |b| b.min(self.batch_size));
+
+            let chunks = self
+                .vector_store
+                .get_chunks_missing_embeddings(branch, model, limit)?;
+            if chunks.is_empty() {
+                break;
+            }
+
+            let texts: Vec<String> = chunks.iter().map(CodeChunk::embedding_text).collect();
+            let ids: Vec<_> = chunks.iter().map(|c| c.id).collect();
+            let embeddings = self
+                .embeddings
+                .create_embeddings_split(&texts, model)
+                .await?;
+            self.vector_store
+                .insert_embeddings_batch(model, &ids, &embeddings)?;
+
+            embedded += chunks.len();
+            debug!("Re-embedded {} chunks of '{}'", embedded, branch);
+        }
+
+        let remaining = self
+            .vector_store
+            .count_chunks_missing_embeddings(branch, model)?;
+        let completed = remaining == 0;
+        if completed {
+            self.vector_store
+                .complete_embedding_migration(branch, model)?;
+        }
+
+        Ok(ReembedProgress {
+            branch: branch.to_string(),
+            model: model.to_string(),
+            version: index.version,
+            embedded: embedded as u32,
+            remaining,
+            completed,
+        })
+    }
+}
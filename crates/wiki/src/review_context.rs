@@ -0,0 +1,349 @@
+//! Wiki context for code review
+//!
+//! Before an AI review runs, the pages documenting each changed file and the
+//! indexed chunks most similar to its patch are gathered, so the reviewer
+//! sees how unfamiliar code is meant to work without having to search for it.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use tracing::debug;
+use uuid::Uuid;
+
+use crate::chunker::TextSplitter;
+use crate::domain::search_result::SearchResult;
+use crate::embeddings::EmbeddingProvider;
+use crate::error::WikiResult;
+use crate::page_updates::{affected_pages, split_diff};
+use crate::vector_store::VectorStore;
+
+/// Default number of chunks retrieved per changed file
+const DEFAULT_CHUNKS_PER_FILE: usize = 3;
+
+/// Default number of changed files context is gathered for
+const DEFAULT_MAX_FILES: usize = 10;
+
+/// Pages attached per changed file
+const PAGES_PER_FILE: usize = 2;
+
+/// Characters of a page kept as its excerpt
+const PAGE_EXCERPT_LENGTH: usize = 1500;
+
+/// Characters of a patch embedded as the similarity query
+const QUERY_PATCH_LENGTH: usize = 4000;
+
+/// An excerpt of a wiki page documenting a changed file
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageExcerpt {
+    pub slug: String,
+    pub title: String,
+    pub excerpt: String,
+}
+
+/// Wiki context gathered for one changed file
+#[derive(Debug, Clone)]
+pub struct FileReviewContext {
+    pub file_path: String,
+    pub pages: Vec<PageExcerpt>,
+    /// Indexed chunks most similar to the file's patch
+    pub chunks: Vec<SearchResult>,
+}
+
+/// Wiki context gathered for a whole diff
+#[derive(Debug, Clone, Default)]
+pub struct ReviewContext {
+    pub files: Vec<FileReviewContext>,
+}
+
+impl ReviewContext {
+    pub fn is_empty(&self) -> bool {
+        self.files
+            .iter()
+            .all(|f| f.pages.is_empty() && f.chunks.is_empty())
+    }
+
+    /// Render the context as markdown, keeping it within `max_tokens`.
+    ///
+    /// Sections are added breadth-first - every file's pages before any
+    /// file's chunks - so a tight budget still covers each changed file.
+    pub fn to_markdown(&self, max_tokens: usize) -> String {
+        let mut sections: Vec<Vec<String>> = vec![Vec::new(); self.files.len()];
+        for (i, _, section) in self.fitted_sections(max_tokens) {
+            sections[i].push(section);
+        }
+
+        let mut markdown = String::new();
+        for (file, sections) in self.files.iter().zip(sections) {
+            if sections.is_empty() {
+                continue;
+            }
+            markdown.push_str(&format!("### `{}`\n\n", file.file_path));
+            for section in sections {
+                markdown.push_str(&section);
+                markdown.push('\n');
+            }
+        }
+        markdown.trim_end().to_string()
+    }
+
+    /// The chunks included by [`Self::to_markdown`] with the same budget
+    pub fn shown_chunks(&self, max_tokens: usize) -> Vec<&SearchResult> {
+        self.fitted_sections(max_tokens)
+            .into_iter()
+            .filter_map(|(_, chunk, _)| chunk)
+            .collect()
+    }
+
+    /// Sections that fit the budget, with the index of their file and the
+    /// chunk they render, if any
+    fn fitted_sections(&self, max_tokens: usize) -> Vec<(usize, Option<&SearchResult>, String)> {
+        let splitter = TextSplitter::new(max_tokens, 0);
+        let mut used = 0;
+
+        let page_sections = self.files.iter().enumerate().flat_map(|(i, file)| {
+            file.pages.iter().map(move |page| {
+                (
+                    i,
+                    None,
+                    format!(
+                        "**Wiki: {}** (`{}`)\n\n{}\n",
+                        page.title, page.slug, page.excerpt
+                    ),
+                )
+            })
+        });
+        let chunk_sections = self.files.iter().enumerate().flat_map(|(i, file)| {
+            file.chunks.iter().map(move |chunk| {
+                (
+                    i,
+                    Some(chunk),
+                    format!(
+                        "**Related code:** `{}` (lines {}-{})\n\n```{}\n{}\n```\n",
+                        chunk.file_path,
+                        chunk.start_line,
+                        chunk.end_line,
+                        chunk.language.as_deref().unwrap_or(""),
+                        chunk.content.trim_end()
+                    ),
+                )
+            })
+        });
+
+        page_sections
+            .chain(chunk_sections)
+            .filter(|(_, _, section)| {
+                let tokens = splitter.count_tokens(section);
+                if used + tokens > max_tokens {
+                    return false;
+                }
+                used += tokens;
+                true
+            })
+            .collect()
+    }
+}
+
+/// Gathers wiki pages and similar chunks for the files changed by a diff
+pub struct ReviewContextBuilder<'a> {
+    embeddings: &'a dyn EmbeddingProvider,
+    embedding_model: String,
+    chunks_per_file: usize,
+    max_files: usize,
+}
+
+impl<'a> ReviewContextBuilder<'a> {
+    /// Create a new review context builder
+    pub fn new(embeddings: &'a dyn EmbeddingProvider, embedding_model: impl Into<String>) -> Self {
+        Self {
+            embeddings,
+            embedding_model: embedding_model.into(),
+            chunks_per_file: DEFAULT_CHUNKS_PER_FILE,
+            max_files: DEFAULT_MAX_FILES,
+        }
+    }
+
+    /// Set the number of chunks retrieved per changed file
+    pub fn with_chunks_per_file(mut self, chunks_per_file: usize) -> Self {
+        self.chunks_per_file = chunks_per_file;
+        self
+    }
+
+    /// Set the maximum number of changed files context is gathered for
+    pub fn with_max_files(mut self, max_files: usize) -> Self {
+        self.max_files = max_files;
+        self
+    }
+
+    /// Gather context from the wiki database at `db_path` for the files
+    /// changed by `diff` on `branch`.
+    ///
+    /// The store is opened only around the synchronous lookups so the
+    /// returned future stays `Send`.
+    pub async fn build(
+        &self,
+        db_path: &Path,
+        branch: &str,
+        diff: &str,
+    ) -> WikiResult<ReviewContext> {
+        let mut file_diffs = split_diff(diff);
+        file_diffs.truncate(self.max_files);
+        if file_diffs.is_empty() {
+            return Ok(ReviewContext::default());
+        }
+
+        let mut files: Vec<FileReviewContext> = {
+            let store = VectorStore::new(db_path)?;
+            let pages = store.get_branch_pages(branch)?;
+            file_diffs
+                .iter()
+                .map(|file_diff| {
+                    let changed = [file_diff.file_path.clone()];
+                    let pages = affected_pages(&pages, &changed)
+                        .into_iter()
+                        .take(PAGES_PER_FILE)
+                        .map(|affected| PageExcerpt {
+                            slug: affected.page.slug.clone(),
+                            title: affected.page.title.clone(),
+                            excerpt: excerpt(&affected.page.content, PAGE_EXCERPT_LENGTH),
+                        })
+                        .collect();
+                    FileReviewContext {
+                        file_path: file_diff.file_path.clone(),
+                        pages,
+                        chunks: Vec::new(),
+                    }
+                })
+                .collect()
+        };
+
+        if self.chunks_per_file > 0 {
+            let queries: Vec<String> = file_diffs
+                .iter()
+                .map(|d| excerpt(&d.patch, QUERY_PATCH_LENGTH))
+                .collect();
+            let embeddings = self
+                .embeddings
+                .create_embeddings_batch(&queries, &self.embedding_model)
+                .await?;
+
+            let store = VectorStore::new(db_path)?;
+            let mut seen: HashSet<Uuid> = HashSet::new();
+            for (file, embedding) in files.iter_mut().zip(embeddings) {
+                // Over-fetch: the file's own chunks are skipped, the diff
+                // already shows them
+                let results = store.search_similar_in_branch(
+                    &embedding,
+                    &self.embedding_model,
+                    self.chunks_per_file * 2,
+                    Some(branch),
+                    None,
+                )?;
+                file.chunks = results
+                    .into_iter()
+                    .filter(|r| r.file_path != file.file_path && seen.insert(r.chunk_id))
+                    .take(self.chunks_per_file)
+                    .collect();
+            }
+        }
+
+        debug!(
+            "Gathered review context for {} files ({} pages, {} chunks)",
+            files.len(),
+            files.iter().map(|f| f.pages.len()).sum::<usize>(),
+            files.iter().map(|f| f.chunks.len()).sum::<usize>()
+        );
+
+        Ok(ReviewContext { files })
+    }
+}
+
+/// The first `max_chars` characters of `text`, cut on a line boundary
+pub(crate) fn excerpt(text: &str, max_chars: usize) -> String {
+    if text.len() <= max_chars {
+        return text.trim_end().to_string();
+    }
+    let mut end = max_chars;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    let cut = &text[..end];
+    let cut = cut.rsplit_once('\n').map(|(head, _)| head).unwrap_or(cut);
+    format!("{}\n…", cut.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::chunk::ChunkType;
+
+    fn chunk(file_path: &str, content: &str) -> SearchResult {
+        SearchResult::new(
+            Uuid::new_v4(),
+            file_path.to_string(),
+            1,
+            10,
+            content.to_string(),
+            ChunkType::Function,
+            Some("rust".to_string()),
+            0.9,
+        )
+    }
+
+    fn context() -> ReviewContext {
+        ReviewContext {
+            files: vec![
+                FileReviewContext {
+                    file_path: "src/auth/login.rs".to_string(),
+                    pages: vec![PageExcerpt {
+                        slug: "auth".to_string(),
+                        title: "Authentication".to_string(),
+                        excerpt: "Sessions are created on login.".to_string(),
+                    }],
+                    chunks: vec![chunk(
+                        "src/auth/session.rs",
+                        &"fn session() {}\n".repeat(200),
+                    )],
+                },
+                FileReviewContext {
+                    file_path: "README.md".to_string(),
+                    pages: vec![PageExcerpt {
+                        slug: "overview".to_string(),
+                        title: "Overview".to_string(),
+                        excerpt: "The project overview.".to_string(),
+                    }],
+                    chunks: Vec::new(),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_to_markdown_includes_pages_and_chunks() {
+        let markdown = context().to_markdown(10_000);
+        assert!(markdown.contains("### `src/auth/login.rs`"));
+        assert!(markdown.contains("**Wiki: Authentication** (`auth`)"));
+        assert!(markdown.contains("`src/auth/session.rs` (lines 1-10)"));
+        assert!(markdown.contains("### `README.md`"));
+    }
+
+    #[test]
+    fn test_to_markdown_respects_token_cap() {
+        let markdown = context().to_markdown(100);
+        // Pages for every file fit; the oversized chunk is dropped
+        assert!(markdown.contains("Authentication"));
+        assert!(markdown.contains("Overview"));
+        assert!(!markdown.contains("fn session()"));
+        assert!(TextSplitter::new(100, 0).count_tokens(&markdown) <= 120);
+        assert!(context().shown_chunks(100).is_empty());
+        assert_eq!(context().shown_chunks(10_000).len(), 1);
+
+        assert!(context().to_markdown(0).is_empty());
+    }
+
+    #[test]
+    fn test_excerpt_cuts_on_line_boundary() {
+        assert_eq!(excerpt("short\n", 100), "short");
+        let text = "first line\nsecond line\nthird line";
+        assert_eq!(excerpt(text, 15), "first line\n…");
+    }
+}
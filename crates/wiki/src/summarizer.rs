@@ -0,0 +1,194 @@
+//! One-line summaries of indexed chunks
+//!
+//! Search results and RAG sources otherwise show raw code with no
+//! explanation. When enabled, the indexer asks a cheap chat model for a
+//! sentence per chunk, a batch of chunks per request. The summary is stored
+//! with the chunk, shown next to its code and embedded in front of it, so
+//! conceptual queries ("where do we retry uploads") can match code that
+//! never uses those words.
+
+use tracing::{debug, warn};
+
+use crate::domain::chunk::CodeChunk;
+use crate::error::WikiResult;
+use crate::openrouter::client::OpenRouterClient;
+use crate::openrouter::types::ChatMessage;
+
+/// Chunks summarized per chat request
+pub const SUMMARY_BATCH_SIZE: usize = 20;
+
+/// Characters of a chunk's code included in the summary prompt
+const MAX_CHUNK_CHARS: usize = 1500;
+
+/// Longest summary kept; longer replies are cut at a word boundary
+const MAX_SUMMARY_CHARS: usize = 200;
+
+const SUMMARY_PROMPT: &str = r#"You summarize code for a search index.
+
+For each numbered code chunk, write one sentence in plain English saying what the code does and what it is for.
+Name the domain concepts involved rather than restating identifiers.
+Reply with exactly one line per chunk in the form `<number>: <summary>` and no other text."#;
+
+/// Writes one-line summaries of code chunks with a chat model
+pub struct ChunkSummarizer<'a> {
+    openrouter: &'a OpenRouterClient,
+    model: &'a str,
+}
+
+impl<'a> ChunkSummarizer<'a> {
+    pub fn new(openrouter: &'a OpenRouterClient, model: &'a str) -> Self {
+        Self { openrouter, model }
+    }
+
+    /// Summarize `chunks` in batches and store each summary on its chunk.
+    /// Batches the model fails on are skipped, leaving their chunks without
+    /// a summary. Returns the number of chunks summarized.
+    pub async fn summarize(&self, chunks: &mut [CodeChunk]) -> usize {
+        let mut summarized = 0;
+        for batch in chunks.chunks_mut(SUMMARY_BATCH_SIZE) {
+            match self.summarize_batch(batch).await {
+                Ok(summaries) => {
+                    for (chunk, summary) in batch.iter_mut().zip(summaries) {
+                        if summary.is_some() {
+                            summarized += 1;
+                        }
+                        chunk.summary = summary;
+                    }
+                }
+                Err(e) => warn!("Failed to summarize {} chunks: {}", batch.len(), e),
+            }
+        }
+        debug!("Summarized {}/{} chunks", summarized, chunks.len());
+        summarized
+    }
+
+    /// Summaries of one batch, in chunk order
+    pub async fn summarize_batch(&self, chunks: &[CodeChunk]) -> WikiResult<Vec<Option<String>>> {
+        let messages = vec![
+            ChatMessage::system(SUMMARY_PROMPT),
+            ChatMessage::user(format_batch(chunks)),
+        ];
+        let reply = self
+            .openrouter
+            .chat_completion(
+                messages,
+                self.model,
+                Some(0.0),
+                Some((chunks.len() * 60) as u32),
+            )
+            .await?;
+
+        Ok(parse_summaries(&reply, chunks.len()))
+    }
+}
+
+/// The numbered chunks of a summary request
+fn format_batch(chunks: &[CodeChunk]) -> String {
+    let mut prompt = String::new();
+    for (i, chunk) in chunks.iter().enumerate() {
+        let code = match chunk.content.char_indices().nth(MAX_CHUNK_CHARS) {
+            Some((end, _)) => &chunk.content[..end],
+            None => &chunk.content,
+        };
+        prompt.push_str(&format!(
+            "{}: {} (lines {}-{})\n```{}\n{}\n```\n\n",
+            i + 1,
+            chunk.file_path,
+            chunk.start_line,
+            chunk.end_line,
+            chunk.language.as_deref().unwrap_or(""),
+            code
+        ));
+    }
+    prompt
+}
+
+/// Summaries from a model reply, indexed by the chunk number each line
+/// starts with. Chunks the reply skips get `None`.
+pub(crate) fn parse_summaries(reply: &str, count: usize) -> Vec<Option<String>> {
+    let mut summaries = vec![None; count];
+
+    for line in reply.lines() {
+        let line = line.trim().trim_start_matches(['-', '*']).trim();
+        let Some((number, summary)) = line.split_once([':', '.', ')']) else {
+            continue;
+        };
+        let Ok(number) = number.trim().parse::<usize>() else {
+            continue;
+        };
+        let summary = summary.trim().trim_matches('"');
+        if summary.is_empty() || number == 0 || number > count {
+            continue;
+        }
+        summaries[number - 1] = Some(truncate_summary(summary));
+    }
+
+    summaries
+}
+
+fn truncate_summary(summary: &str) -> String {
+    if summary.chars().count() <= MAX_SUMMARY_CHARS {
+        return summary.to_string();
+    }
+    let cut: String = summary.chars().take(MAX_SUMMARY_CHARS).collect();
+    match cut.rfind(' ') {
+        Some(end) => format!("{}...", &cut[..end]),
+        None => format!("{}...", cut),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::chunk::ChunkType;
+
+    #[test]
+    fn test_parse_summaries() {
+        let reply = "1: Parses the project config file.\n\
+                     - 3) \"Retries failed uploads with backoff.\"\n\
+                     7: Out of range.\n\
+                     not a summary line";
+        let summaries = parse_summaries(reply, 3);
+
+        assert_eq!(
+            summaries,
+            vec![
+                Some("Parses the project config file.".to_string()),
+                None,
+                Some("Retries failed uploads with backoff.".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_long_summaries_are_truncated() {
+        let reply = format!("1: {}", "word ".repeat(100));
+        let summary = parse_summaries(&reply, 1).remove(0).unwrap();
+
+        assert!(summary.chars().count() <= MAX_SUMMARY_CHARS + 3);
+        assert!(summary.ends_with("word..."));
+    }
+
+    #[test]
+    fn test_embedding_text_leads_with_summary() {
+        let mut chunk = CodeChunk::new(
+            "main".to_string(),
+            "src/upload.rs".to_string(),
+            1,
+            3,
+            "fn upload() {}".to_string(),
+            ChunkType::Function,
+            Some("rust".to_string()),
+            4,
+            0,
+            "abc".to_string(),
+        );
+        assert_eq!(chunk.embedding_text(), "fn upload() {}");
+
+        chunk.summary = Some("Uploads a file.".to_string());
+        assert_eq!(chunk.embedding_text(), "Uploads a file.\n\nfn upload() {}");
+
+        let prompt = format_batch(std::slice::from_ref(&chunk));
+        assert!(prompt.starts_with("1: src/upload.rs (lines 1-3)\n```rust\n"));
+    }
+}
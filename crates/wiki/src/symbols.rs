@@ -0,0 +1,300 @@
+//! Lightweight, pattern-based symbol extraction for code chunks
+//!
+//! This is not a parser: it recognises function definitions and call sites
+//! across the languages the indexer supports well enough to link chunks
+//! together (e.g. for call-graph aware RAG context expansion).
+
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+/// Identifiers that look like calls but are language keywords or ubiquitous
+/// built-ins, and would only add noise to the graph.
+const IGNORED_CALLS: &[&str] = &[
+    "if",
+    "for",
+    "while",
+    "match",
+    "return",
+    "loop",
+    "switch",
+    "catch",
+    "fn",
+    "def",
+    "func",
+    "function",
+    "new",
+    "Some",
+    "None",
+    "Ok",
+    "Err",
+    "Box",
+    "Vec",
+    "String",
+    "Arc",
+    "Rc",
+    "println",
+    "print",
+    "format",
+    "vec",
+    "write",
+    "writeln",
+    "assert",
+    "assert_eq",
+    "assert_ne",
+    "panic",
+    "debug",
+    "info",
+    "warn",
+    "error",
+    "trace",
+    "len",
+    "map",
+    "unwrap",
+    "expect",
+    "clone",
+    "to_string",
+    "into",
+    "from",
+    "iter",
+    "collect",
+    "push",
+    "get",
+    "set",
+    "self",
+    "super",
+    "typeof",
+    "require",
+    "import",
+    "await",
+    "async",
+    "sizeof",
+    "str",
+    "int",
+    "list",
+    "dict",
+    "range",
+    "isinstance",
+    "console",
+    "log",
+    "then",
+];
+
+/// Minimum identifier length considered meaningful for graph edges
+const MIN_SYMBOL_LEN: usize = 3;
+
+fn definition_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"(?m)(?:\bfn\s+([A-Za-z_]\w*)|\bdef\s+([A-Za-z_]\w*)|\bfunction\s*\*?\s*([A-Za-z_$][\w$]*)|\bfunc\s+(?:\([^)]*\)\s*)?([A-Za-z_]\w*)|\b(?:const|let|var)\s+([A-Za-z_$][\w$]*)\s*=\s*(?:async\s*)?(?:\([^)]*\)|[A-Za-z_$][\w$]*)\s*=>)",
+        )
+        .expect("valid definition regex")
+    })
+}
+
+fn type_definition_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"(?m)(?:\b(?:struct|enum|trait|union|class|interface|mod)\s+([A-Za-z_$][\w$]*)|\btype\s+([A-Za-z_$][\w$]*)\s*(?:<[^>]*>)?\s*=)",
+        )
+        .expect("valid type definition regex")
+    })
+}
+
+fn import_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r#"(?m)^\s*(?:pub(?:\([^)]*\))?\s+)?(?:use\s+([\w:]+)|import\s+(?:[^'"\n]*?\s+from\s+)?['"]([^'"]+)['"]|from\s+([\w.]+)\s+import\b|import\s+([\w.]+)|#include\s+[<"]([^>"]+)[>"])"#,
+        )
+        .expect("valid import regex")
+    })
+}
+
+fn call_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"\b([A-Za-z_][\w]*)\s*(?:::<[^>]*>)?\(").expect("valid call regex")
+    })
+}
+
+/// Extract the names of functions defined in `content`, in order of appearance
+pub fn extract_definitions(content: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    definition_regex()
+        .captures_iter(content)
+        .filter_map(|cap| cap.iter().skip(1).flatten().next())
+        .map(|m| m.as_str().to_string())
+        .filter(|name| name.len() >= MIN_SYMBOL_LEN && seen.insert(name.clone()))
+        .collect()
+}
+
+/// Extract the names of functions and types (structs, enums, traits,
+/// classes, interfaces, type aliases and modules) defined in `content`, in
+/// order of appearance
+pub fn extract_symbols(content: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let types = type_definition_regex()
+        .captures_iter(content)
+        .filter_map(|cap| {
+            cap.iter()
+                .skip(1)
+                .flatten()
+                .next()
+                .map(|m| (m.start(), m.as_str().to_string()))
+        });
+    let functions = definition_regex().captures_iter(content).filter_map(|cap| {
+        cap.iter()
+            .skip(1)
+            .flatten()
+            .next()
+            .map(|m| (m.start(), m.as_str().to_string()))
+    });
+
+    let mut symbols: Vec<(usize, String)> = types.chain(functions).collect();
+    symbols.sort_by_key(|(start, _)| *start);
+    symbols
+        .into_iter()
+        .map(|(_, name)| name)
+        .filter(|name| name.len() >= MIN_SYMBOL_LEN && seen.insert(name.clone()))
+        .collect()
+}
+
+/// Extract the modules imported by `content`: Rust `use` paths without
+/// their item lists, JS/TS module specifiers, Python and Go packages and C
+/// headers
+pub fn extract_imports(content: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    import_regex()
+        .captures_iter(content)
+        .filter_map(|cap| cap.iter().skip(1).flatten().next())
+        .map(|m| m.as_str().trim_end_matches("::").to_string())
+        .filter(|path| !path.is_empty() && seen.insert(path.clone()))
+        .collect()
+}
+
+/// Extract the names of functions called from `content`, excluding functions
+/// defined in the same content and common keywords/built-ins
+pub fn extract_calls(content: &str) -> Vec<String> {
+    let defined: HashSet<String> = extract_definitions(content).into_iter().collect();
+    let mut seen = HashSet::new();
+
+    call_regex()
+        .captures_iter(content)
+        .filter_map(|cap| cap.get(1))
+        .map(|m| m.as_str().to_string())
+        .filter(|name| {
+            name.len() >= MIN_SYMBOL_LEN
+                && !IGNORED_CALLS.contains(&name.as_str())
+                && !defined.contains(name)
+                && seen.insert(name.clone())
+        })
+        .collect()
+}
+
+/// Whether `content` defines a function named `symbol`
+pub fn defines(content: &str, symbol: &str) -> bool {
+    extract_definitions(content).iter().any(|d| d == symbol)
+}
+
+/// Zero-based line within `content` on which `symbol` is defined
+pub fn definition_line(content: &str, symbol: &str) -> Option<u32> {
+    definition_regex()
+        .captures_iter(content)
+        .find(|cap| cap.iter().skip(1).flatten().any(|m| m.as_str() == symbol))
+        .and_then(|cap| cap.get(0))
+        .map(|m| content[..m.start()].matches('\n').count() as u32)
+}
+
+/// Whether `content` calls a function named `symbol` (without defining it)
+pub fn calls(content: &str, symbol: &str) -> bool {
+    extract_calls(content).iter().any(|c| c == symbol)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_definitions_across_languages() {
+        let rust = "pub async fn load_config(path: &Path) -> Config {\n    parse(path)\n}";
+        assert_eq!(extract_definitions(rust), vec!["load_config"]);
+
+        let python = "def handle_request(req):\n    return validate(req)";
+        assert_eq!(extract_definitions(python), vec!["handle_request"]);
+
+        let go = "func (s *Server) ServeHTTP(w http.ResponseWriter) {}";
+        assert_eq!(extract_definitions(go), vec!["ServeHTTP"]);
+
+        let ts = "export function render() {}\nconst onClick = (e) => submit(e);";
+        assert_eq!(extract_definitions(ts), vec!["render", "onClick"]);
+    }
+
+    #[test]
+    fn test_extract_symbols_includes_types() {
+        let rust = "pub struct Config {}\n\nimpl Config {\n    pub fn load_config() {}\n}\npub enum Mode { A }";
+        assert_eq!(extract_symbols(rust), vec!["Config", "load_config", "Mode"]);
+
+        let ts = "export interface Props {}\nexport class Widget {}\nfunction render() {}";
+        assert_eq!(extract_symbols(ts), vec!["Props", "Widget", "render"]);
+    }
+
+    #[test]
+    fn test_extract_imports_across_languages() {
+        let rust = "use std::collections::{HashMap, HashSet};\npub use crate::domain::CodeChunk;";
+        assert_eq!(
+            extract_imports(rust),
+            vec!["std::collections", "crate::domain::CodeChunk"]
+        );
+
+        let ts = "import { useState } from 'react';\nimport './styles.css';";
+        assert_eq!(extract_imports(ts), vec!["react", "./styles.css"]);
+
+        let python = "import os.path\nfrom typing import List";
+        assert_eq!(extract_imports(python), vec!["os.path", "typing"]);
+
+        let go = "import \"net/http\"";
+        assert_eq!(extract_imports(go), vec!["net/http"]);
+    }
+
+    #[test]
+    fn test_extract_calls_skips_keywords_and_local_definitions() {
+        let content = r#"
+fn process(items: Vec<Item>) -> Result<()> {
+    if items.is_empty() {
+        return Ok(());
+    }
+    let parsed = parse_items(&items)?;
+    store.save_all(parsed);
+    process(rest)
+}
+"#;
+        let calls = extract_calls(content);
+        assert!(calls.contains(&"parse_items".to_string()));
+        assert!(calls.contains(&"save_all".to_string()));
+        assert!(calls.contains(&"is_empty".to_string()));
+        assert!(!calls.contains(&"process".to_string()));
+        assert!(!calls.contains(&"Ok".to_string()));
+        assert!(!calls.contains(&"if".to_string()));
+    }
+
+    #[test]
+    fn test_defines_and_calls() {
+        let content = "fn run() { execute_task(id) }";
+        assert!(defines(content, "run"));
+        assert!(!defines(content, "execute_task"));
+        assert!(calls(content, "execute_task"));
+        assert!(!calls(content, "run"));
+    }
+
+    #[test]
+    fn test_definition_line() {
+        let content = "use std::io;\n\n/// Entry\npub fn run() {\n    helper()\n}\nfn helper() {}";
+        assert_eq!(definition_line(content, "run"), Some(3));
+        assert_eq!(definition_line(content, "helper"), Some(6));
+        assert_eq!(definition_line(content, "missing"), None);
+    }
+}
@@ -9,6 +9,7 @@ use tokio::sync::broadcast;
 use tracing::{debug, error, info, warn};
 
 use crate::domain::index_status::{IndexProgress, IndexState, IndexStatus};
+use crate::embeddings::EmbeddingProvider;
 use crate::error::WikiResult;
 use crate::generator::WikiGenerator;
 use crate::indexer::CodeIndexer;
@@ -19,6 +20,7 @@ use crate::WikiConfig;
 pub struct WikiSyncService {
     config: WikiConfig,
     openrouter: Arc<OpenRouterClient>,
+    embeddings: Arc<dyn EmbeddingProvider>,
     #[allow(clippy::arc_with_non_send_sync)]
     vector_store: Arc<VectorStore>,
 }
@@ -30,12 +32,14 @@ impl WikiSyncService {
             config.openrouter_api_key.clone(),
             config.api_base_url.clone(),
         ));
+        let embeddings = config.embedding_provider_config().build()?;
 
-        let vector_store = Arc::new(VectorStore::new(&config.db_path)?);
+        let vector_store = Arc::new(config.open_vector_store()?);
 
         Ok(Self {
             config,
             openrouter,
+            embeddings,
             vector_store,
         })
     }
@@ -43,11 +47,13 @@ impl WikiSyncService {
     pub fn from_parts(
         config: WikiConfig,
         openrouter: Arc<OpenRouterClient>,
+        embeddings: Arc<dyn EmbeddingProvider>,
         #[allow(clippy::arc_with_non_send_sync)] vector_store: Arc<VectorStore>,
     ) -> Self {
         Self {
             config,
             openrouter,
+            embeddings,
             vector_store,
         }
     }
@@ -110,12 +116,20 @@ impl WikiSyncService {
         let start_time = std::time::Instant::now();
 
         let indexer = CodeIndexer::new(
-            self.openrouter.clone(),
+            self.embeddings.clone(),
             self.vector_store.clone(),
             self.config.embedding_model.clone(),
             self.config.max_chunk_tokens,
             self.config.chunk_overlap,
-        );
+        )
+        .with_roots(&self.config.roots)
+        .with_history(self.config.history_generations)
+        .with_embedding_concurrency(self.config.embedding_concurrency);
+        let indexer = if self.config.chunk_summaries {
+            indexer.with_summaries(self.openrouter.clone(), self.config.chat_model.clone())
+        } else {
+            indexer
+        };
 
         let index_status = indexer
             .index_branch(root_path, branch, current_commit, progress_tx.clone())
@@ -0,0 +1,484 @@
+//! Test-coverage map: which source modules have associated tests
+//!
+//! This does not measure line coverage. A source module counts as tested when
+//! a test can be associated with it by one of:
+//!
+//! - **Inline tests**: the module contains its own tests (`#[cfg(test)]`, `#[test]`)
+//! - **Path convention**: a test file named after the module, e.g.
+//!   `foo.test.ts`, `foo.spec.js`, `test_foo.py`, `foo_test.go`, `FooTest.java`
+//!   or `tests/foo.rs`
+//! - **Symbol references**: a test file calls a function that is defined in
+//!   exactly one source module
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::chunker::TextSplitter;
+use crate::domain::chunk::CodeChunk;
+use crate::domain::wiki_page::{Importance, PageType, SourceCitation, WikiPage};
+use crate::symbols;
+
+/// Slug of the generated test-coverage page
+pub const TEST_COVERAGE_PAGE_SLUG: &str = "test-coverage";
+
+/// Languages whose files are considered source modules
+const SOURCE_LANGUAGES: &[&str] = &[
+    "rust",
+    "python",
+    "javascript",
+    "typescript",
+    "go",
+    "java",
+    "c",
+    "cpp",
+    "csharp",
+    "ruby",
+    "php",
+    "swift",
+    "kotlin",
+    "scala",
+];
+
+/// Directory names whose contents are treated as tests
+const TEST_DIRS: &[&str] = &["test", "tests", "__tests__", "spec", "specs"];
+
+/// File stems that name their directory rather than themselves
+const INDEX_STEMS: &[&str] = &["mod", "lib", "main", "index", "__init__"];
+
+/// Maximum number of test files listed per module on the wiki page
+const MAX_LISTED_TESTS: usize = 3;
+
+/// Tests associated with a single source module
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModuleCoverage {
+    pub file_path: String,
+    pub language: String,
+    /// Test files associated by path convention or symbol references, sorted
+    pub test_files: Vec<String>,
+    /// Whether the module contains its own tests
+    pub has_inline_tests: bool,
+}
+
+impl ModuleCoverage {
+    pub fn is_tested(&self) -> bool {
+        self.has_inline_tests || !self.test_files.is_empty()
+    }
+}
+
+/// Test-coverage map of a branch, with modules sorted by path
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestCoverageMap {
+    pub modules: Vec<ModuleCoverage>,
+    /// All test files found, sorted
+    pub test_files: Vec<String>,
+}
+
+#[derive(Default)]
+struct FileSymbols {
+    language: String,
+    definitions: BTreeSet<String>,
+    calls: BTreeSet<String>,
+    has_inline_tests: bool,
+}
+
+impl TestCoverageMap {
+    /// Build the map from a branch's indexed chunks.
+    /// Source files that define no functions (re-export modules, type-only
+    /// files) have nothing to test and are left out.
+    pub fn from_chunks(chunks: &[CodeChunk]) -> Self {
+        let mut sources: BTreeMap<&str, FileSymbols> = BTreeMap::new();
+        let mut tests: BTreeMap<&str, FileSymbols> = BTreeMap::new();
+
+        for chunk in chunks {
+            let Some(language) = TextSplitter::detect_language(&chunk.file_path) else {
+                continue;
+            };
+            if !SOURCE_LANGUAGES.contains(&language.as_str()) {
+                continue;
+            }
+
+            let files = if is_test_path(&chunk.file_path) {
+                &mut tests
+            } else {
+                &mut sources
+            };
+            let entry = files.entry(&chunk.file_path).or_default();
+            entry.language = language;
+            entry
+                .definitions
+                .extend(symbols::extract_definitions(&chunk.content));
+            entry.calls.extend(symbols::extract_calls(&chunk.content));
+            entry.has_inline_tests |= has_inline_tests(&chunk.content);
+        }
+
+        sources.retain(|_, file| !file.definitions.is_empty());
+
+        let mut associations: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+
+        let mut by_stem: HashMap<String, Vec<&str>> = HashMap::new();
+        for path in sources.keys() {
+            by_stem.entry(module_stem(path)).or_default().push(*path);
+        }
+        for test_path in tests.keys() {
+            let Some(candidates) = by_stem.get(&test_subject_stem(test_path)) else {
+                continue;
+            };
+            for source in closest_paths(test_path, candidates) {
+                associations.entry(source).or_default().insert(*test_path);
+            }
+        }
+
+        let mut definers: HashMap<&str, Vec<&str>> = HashMap::new();
+        for (path, file) in &sources {
+            for definition in &file.definitions {
+                definers.entry(definition.as_str()).or_default().push(*path);
+            }
+        }
+        for (test_path, test) in &tests {
+            for call in &test.calls {
+                if let Some([source]) = definers.get(call.as_str()).map(Vec::as_slice) {
+                    associations.entry(*source).or_default().insert(*test_path);
+                }
+            }
+        }
+
+        let modules = sources
+            .iter()
+            .map(|(path, file)| ModuleCoverage {
+                file_path: path.to_string(),
+                language: file.language.clone(),
+                test_files: associations
+                    .get(path)
+                    .map(|t| t.iter().map(|p| p.to_string()).collect())
+                    .unwrap_or_default(),
+                has_inline_tests: file.has_inline_tests,
+            })
+            .collect();
+
+        Self {
+            modules,
+            test_files: tests.keys().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty()
+    }
+
+    /// Modules without any associated tests
+    pub fn untested(&self) -> impl Iterator<Item = &ModuleCoverage> {
+        self.modules.iter().filter(|m| !m.is_tested())
+    }
+
+    pub fn tested_count(&self) -> usize {
+        self.modules.iter().filter(|m| m.is_tested()).count()
+    }
+
+    pub fn untested_count(&self) -> usize {
+        self.modules.len() - self.tested_count()
+    }
+
+    /// Source module paths, sorted
+    pub fn file_paths(&self) -> Vec<String> {
+        self.modules.iter().map(|m| m.file_path.clone()).collect()
+    }
+
+    /// Render the map as markdown, untested modules first
+    pub fn to_markdown(&self) -> String {
+        let mut md = String::from("# Test Coverage\n\n");
+        md.push_str(&format!(
+            "This page is generated from the indexed code: {} of {} source module{} \
+             {} associated tests, found in {} test file{}. Modules count as tested when \
+             they contain inline tests, have a test file named after them, or are the \
+             only module defining a function called from a test.\n\n",
+            self.tested_count(),
+            self.modules.len(),
+            if self.modules.len() == 1 { "" } else { "s" },
+            if self.tested_count() == 1 {
+                "has"
+            } else {
+                "have"
+            },
+            self.test_files.len(),
+            if self.test_files.len() == 1 { "" } else { "s" },
+        ));
+
+        md.push_str("## Untested Modules\n\n");
+        if self.untested_count() == 0 {
+            md.push_str("Every source module has associated tests.\n\n");
+        } else {
+            for module in self.untested() {
+                md.push_str(&format!("- `{}`\n", module.file_path));
+            }
+            md.push('\n');
+        }
+
+        md.push_str("## Tested Modules\n\n");
+        if self.tested_count() == 0 {
+            md.push_str("No source module has associated tests.\n");
+            return md;
+        }
+        md.push_str("| Module | Tests |\n");
+        md.push_str("|--------|-------|\n");
+        for module in self.modules.iter().filter(|m| m.is_tested()) {
+            let mut tests: Vec<String> = Vec::new();
+            if module.has_inline_tests {
+                tests.push("inline".to_string());
+            }
+            tests.extend(
+                module
+                    .test_files
+                    .iter()
+                    .take(MAX_LISTED_TESTS)
+                    .map(|t| format!("`{}`", t)),
+            );
+            let mut tests = tests.join(", ");
+            if module.test_files.len() > MAX_LISTED_TESTS {
+                tests.push_str(&format!(
+                    " (+{} more)",
+                    module.test_files.len() - MAX_LISTED_TESTS
+                ));
+            }
+            md.push_str(&format!("| `{}` | {} |\n", module.file_path, tests));
+        }
+
+        md
+    }
+
+    /// Render the map as a wiki page
+    pub fn to_wiki_page(&self, branch: &str, commit_sha: &str, order: u32) -> WikiPage {
+        let citations = self
+            .untested()
+            .map(|m| SourceCitation::new(m.file_path.clone(), None, None))
+            .collect();
+
+        WikiPage::new_advanced(
+            branch.to_string(),
+            TEST_COVERAGE_PAGE_SLUG.to_string(),
+            "Test Coverage".to_string(),
+            self.to_markdown(),
+            PageType::Custom,
+            None,
+            order,
+            self.file_paths(),
+            commit_sha.to_string(),
+            Importance::Medium,
+            Vec::new(),
+            None,
+            citations,
+        )
+    }
+}
+
+/// Whether `file_path` is a test file by directory or naming convention
+pub fn is_test_path(file_path: &str) -> bool {
+    let mut segments = file_path.split('/').collect::<Vec<_>>();
+    let file_name = segments.pop().unwrap_or_default();
+    if segments.iter().any(|s| TEST_DIRS.contains(s)) {
+        return true;
+    }
+
+    let stem = file_stem(file_name);
+    file_name == "tests.rs"
+        || stem.ends_with(".test")
+        || stem.ends_with(".spec")
+        || stem.ends_with("_test")
+        || stem.starts_with("test_")
+        || (stem.len() > 4 && (stem.ends_with("Test") || stem.ends_with("Tests")))
+}
+
+fn has_inline_tests(content: &str) -> bool {
+    content.contains("#[cfg(test)]") || content.contains("#[test]")
+}
+
+/// File name without its final extension
+fn file_stem(file_name: &str) -> &str {
+    file_name
+        .rsplit_once('.')
+        .map_or(file_name, |(stem, _)| stem)
+}
+
+/// Name a source module is known by: its file stem, or the directory name
+/// for index files like `mod.rs` and `index.ts`
+fn module_stem(file_path: &str) -> String {
+    raw_module_stem(file_path, INDEX_STEMS).to_lowercase()
+}
+
+fn raw_module_stem<'a>(file_path: &'a str, index_stems: &[&str]) -> &'a str {
+    let mut segments = file_path.rsplit('/');
+    let stem = file_stem(segments.next().unwrap_or_default());
+    if index_stems.contains(&stem) {
+        segments.next().unwrap_or(stem)
+    } else {
+        stem
+    }
+}
+
+/// Name of the module a test file is about, with test markers removed.
+/// A Rust `tests.rs` is about the module directory it sits in.
+fn test_subject_stem(file_path: &str) -> String {
+    let stem = raw_module_stem(file_path, &["tests"]);
+    let stem = [".test", ".spec", "_test", "Tests", "Test"]
+        .iter()
+        .find_map(|marker| stem.strip_suffix(marker).filter(|s| !s.is_empty()))
+        .unwrap_or(stem);
+    let stem = stem.strip_prefix("test_").unwrap_or(stem);
+    stem.to_lowercase()
+}
+
+/// The candidates sharing the longest directory prefix with `test_path`
+fn closest_paths<'a>(test_path: &str, candidates: &[&'a str]) -> Vec<&'a str> {
+    let shared = |candidate: &str| {
+        candidate
+            .split('/')
+            .zip(test_path.split('/'))
+            .take_while(|(a, b)| a == b)
+            .count()
+    };
+    let best = candidates.iter().map(|c| shared(c)).max().unwrap_or(0);
+    candidates
+        .iter()
+        .copied()
+        .filter(|c| shared(c) == best)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::chunk::ChunkType;
+
+    fn chunk(file_path: &str, content: &str) -> CodeChunk {
+        CodeChunk::new(
+            "main".to_string(),
+            file_path.to_string(),
+            1,
+            content.lines().count() as u32,
+            content.to_string(),
+            ChunkType::Code,
+            None,
+            0,
+            0,
+            "abc".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_is_test_path() {
+        assert!(is_test_path("crates/wiki/tests/search.rs"));
+        assert!(is_test_path("src/components/__tests__/Button.tsx"));
+        assert!(is_test_path("src/utils/format.test.ts"));
+        assert!(is_test_path("web/api.spec.js"));
+        assert!(is_test_path("app/test_models.py"));
+        assert!(is_test_path("pkg/server/handler_test.go"));
+        assert!(is_test_path("src/main/java/UserServiceTest.java"));
+        assert!(!is_test_path("src/testing_utils.rs"));
+        assert!(!is_test_path("src/contest.ts"));
+        assert!(!is_test_path("src/Test.java"));
+    }
+
+    #[test]
+    fn test_test_subject_stem() {
+        assert_eq!(test_subject_stem("src/utils/format.test.ts"), "format");
+        assert_eq!(test_subject_stem("app/test_models.py"), "models");
+        assert_eq!(test_subject_stem("pkg/server/handler_test.go"), "handler");
+        assert_eq!(test_subject_stem("src/UserServiceTest.java"), "userservice");
+        assert_eq!(test_subject_stem("tests/search.rs"), "search");
+        assert_eq!(test_subject_stem("src/store/tests.rs"), "store");
+    }
+
+    #[test]
+    fn test_associates_tests_by_path_and_inline() {
+        let chunks = vec![
+            chunk("src/utils/format.ts", "export function formatDate(d) {}"),
+            chunk(
+                "src/utils/format.test.ts",
+                "it('works', () => expect(formatDate(x)))",
+            ),
+            chunk(
+                "src/store.rs",
+                "fn save() {}\n#[cfg(test)]\nmod tests { #[test] fn t() {} }",
+            ),
+            chunk("pkg/handler.go", "func Handle() {}"),
+            chunk("pkg/handler_test.go", "func TestHandle(t *testing.T) {}"),
+            chunk("src/orphan.py", "def lonely():\n    pass"),
+            chunk("src/index.ts", "export * from './utils/format';"),
+        ];
+
+        let map = TestCoverageMap::from_chunks(&chunks);
+
+        let paths: Vec<_> = map.modules.iter().map(|m| m.file_path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "pkg/handler.go",
+                "src/orphan.py",
+                "src/store.rs",
+                "src/utils/format.ts"
+            ]
+        );
+        assert_eq!(map.modules[0].test_files, vec!["pkg/handler_test.go"]);
+        assert!(map.modules[2].has_inline_tests);
+        assert!(map.modules[2].test_files.is_empty());
+        assert_eq!(map.modules[3].test_files, vec!["src/utils/format.test.ts"]);
+
+        let untested: Vec<_> = map.untested().map(|m| m.file_path.as_str()).collect();
+        assert_eq!(untested, vec!["src/orphan.py"]);
+        assert_eq!(map.tested_count(), 3);
+    }
+
+    #[test]
+    fn test_associates_tests_by_unique_symbol() {
+        let chunks = vec![
+            chunk(
+                "crates/core/src/parser.rs",
+                "pub fn parse_config(s: &str) {}\npub fn validate() {}",
+            ),
+            chunk("crates/core/src/checker.rs", "pub fn validate() {}"),
+            chunk(
+                "crates/core/tests/integration.rs",
+                "#[test]\nfn loads() { parse_config(\"\"); validate(); }",
+            ),
+        ];
+
+        let map = TestCoverageMap::from_chunks(&chunks);
+
+        let checker = &map.modules[0];
+        let parser = &map.modules[1];
+        assert_eq!(checker.file_path, "crates/core/src/checker.rs");
+        assert!(!checker.is_tested());
+        assert_eq!(parser.test_files, vec!["crates/core/tests/integration.rs"]);
+        assert!(!parser.has_inline_tests);
+    }
+
+    #[test]
+    fn test_markdown_lists_untested_modules_first() {
+        let map = TestCoverageMap {
+            modules: vec![
+                ModuleCoverage {
+                    file_path: "src/a.rs".to_string(),
+                    language: "rust".to_string(),
+                    test_files: vec![],
+                    has_inline_tests: true,
+                },
+                ModuleCoverage {
+                    file_path: "src/b.rs".to_string(),
+                    language: "rust".to_string(),
+                    test_files: vec![],
+                    has_inline_tests: false,
+                },
+            ],
+            test_files: vec![],
+        };
+
+        let page = map.to_wiki_page("main", "abc", 3);
+        assert_eq!(page.slug, TEST_COVERAGE_PAGE_SLUG);
+        assert!(page
+            .content
+            .contains("1 of 2 source modules has associated tests"));
+        assert!(page.content.contains("## Untested Modules\n\n- `src/b.rs`"));
+        assert!(page.content.contains("| `src/a.rs` | inline |"));
+        assert_eq!(page.source_citations.len(), 1);
+    }
+}
@@ -0,0 +1,199 @@
+//! Token and cost accounting of OpenRouter calls
+//!
+//! A client given a [`UsageTracker`] reports the tokens of every successful
+//! chat and embedding call, with its estimated cost, to the tracker's sink
+//! under the tracker's scope - the task and session, or the wiki branch, the
+//! call was made for. Where the records go is up to the sink; the server
+//! stores them in its database.
+
+use std::fmt;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+/// What a call was made for
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UsageScope {
+    pub task_id: Option<String>,
+    pub session_id: Option<String>,
+    pub wiki_branch: Option<String>,
+}
+
+impl UsageScope {
+    /// Calls made for a task, outside any of its sessions
+    pub fn task(task_id: impl Into<String>) -> Self {
+        Self {
+            task_id: Some(task_id.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Calls made for a session of a task
+    pub fn session(task_id: impl Into<String>, session_id: impl Into<String>) -> Self {
+        Self {
+            session_id: Some(session_id.into()),
+            ..Self::task(task_id)
+        }
+    }
+
+    /// Calls made indexing, generating or querying the wiki of a branch
+    pub fn wiki(branch: impl Into<String>) -> Self {
+        Self {
+            wiki_branch: Some(branch.into()),
+            ..Default::default()
+        }
+    }
+
+    /// The same scope, also naming the wiki branch
+    pub fn with_wiki_branch(mut self, branch: impl Into<String>) -> Self {
+        self.wiki_branch = Some(branch.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsageKind {
+    Chat,
+    Embedding,
+}
+
+impl UsageKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Chat => "chat",
+            Self::Embedding => "embedding",
+        }
+    }
+}
+
+/// Tokens and estimated cost of one call
+#[derive(Debug, Clone, PartialEq)]
+pub struct UsageRecord {
+    pub scope: UsageScope,
+    pub model: String,
+    pub kind: UsageKind,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    /// `None` when the model's price is unknown
+    pub cost_usd: Option<f64>,
+}
+
+/// Where usage records are kept
+#[async_trait]
+pub trait UsageSink: Send + Sync {
+    /// Keep `record`. Failing to keep it must not fail the call it
+    /// describes, so errors are the sink's to log.
+    async fn record(&self, record: UsageRecord);
+}
+
+/// Prices of a model in USD per token
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ModelPrices {
+    pub prompt: f64,
+    pub completion: f64,
+}
+
+impl ModelPrices {
+    /// Cost of a call using the given tokens
+    pub fn cost(&self, prompt_tokens: u64, completion_tokens: u64) -> f64 {
+        self.prompt * prompt_tokens as f64 + self.completion * completion_tokens as f64
+    }
+}
+
+/// Reports the usage of a client's calls to a sink under one scope
+#[derive(Clone)]
+pub struct UsageTracker {
+    sink: Arc<dyn UsageSink>,
+    scope: UsageScope,
+}
+
+impl UsageTracker {
+    pub fn new(sink: Arc<dyn UsageSink>, scope: UsageScope) -> Self {
+        Self { sink, scope }
+    }
+
+    pub fn scope(&self) -> &UsageScope {
+        &self.scope
+    }
+
+    /// Report a call to `model`, priced with `prices` when known
+    pub async fn record(
+        &self,
+        model: &str,
+        kind: UsageKind,
+        prompt_tokens: u64,
+        completion_tokens: u64,
+        prices: Option<ModelPrices>,
+    ) {
+        self.sink
+            .record(UsageRecord {
+                scope: self.scope.clone(),
+                model: model.to_string(),
+                kind,
+                prompt_tokens,
+                completion_tokens,
+                cost_usd: prices.map(|p| p.cost(prompt_tokens, completion_tokens)),
+            })
+            .await;
+    }
+}
+
+impl fmt::Debug for UsageTracker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UsageTracker")
+            .field("scope", &self.scope)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MemorySink(Mutex<Vec<UsageRecord>>);
+
+    #[async_trait]
+    impl UsageSink for MemorySink {
+        async fn record(&self, record: UsageRecord) {
+            self.0.lock().unwrap().push(record);
+        }
+    }
+
+    #[test]
+    fn test_scopes() {
+        let scope = UsageScope::session("task-1", "session-1");
+        assert_eq!(scope.task_id.as_deref(), Some("task-1"));
+        assert_eq!(scope.session_id.as_deref(), Some("session-1"));
+        assert!(scope.wiki_branch.is_none());
+
+        let scope = UsageScope::task("task-1").with_wiki_branch("main");
+        assert_eq!(scope.wiki_branch.as_deref(), Some("main"));
+        assert!(UsageScope::wiki("main").task_id.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_tracker_prices_records() {
+        let sink = Arc::new(MemorySink::default());
+        let tracker = UsageTracker::new(sink.clone(), UsageScope::wiki("main"));
+
+        let prices = ModelPrices {
+            prompt: 0.000_001,
+            completion: 0.000_002,
+        };
+        tracker
+            .record("openai/gpt-4o", UsageKind::Chat, 1000, 500, Some(prices))
+            .await;
+        tracker
+            .record("local", UsageKind::Embedding, 10, 0, None)
+            .await;
+
+        let records = sink.0.lock().unwrap();
+        assert_eq!(records.len(), 2);
+        assert!((records[0].cost_usd.unwrap() - 0.002).abs() < 1e-12);
+        assert_eq!(records[0].scope, UsageScope::wiki("main"));
+        assert_eq!(records[1].cost_usd, None);
+        assert_eq!(records[1].kind.as_str(), "embedding");
+    }
+}
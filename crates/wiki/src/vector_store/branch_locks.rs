@@ -0,0 +1,290 @@
+//! Per-branch locks of indexing and generation jobs
+//!
+//! Jobs run in background threads, sometimes of other processes such as the
+//! MCP server, so the lock lives in the database. A job refreshes its lock
+//! while it runs; a lock whose heartbeat stopped is taken over by the next
+//! job.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use rusqlite::{params, OptionalExtension, Row};
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use super::VectorStore;
+use crate::domain::branch_lock::{
+    BranchLock, BranchLockAttempt, BRANCH_LOCK_HEARTBEAT_SECS, BRANCH_LOCK_STALE_SECS,
+};
+use crate::error::WikiResult;
+
+fn parse_time(value: String) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(&value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|_| Utc::now())
+}
+
+fn lock_from_row(row: &Row<'_>) -> rusqlite::Result<BranchLock> {
+    Ok(BranchLock {
+        branch: row.get(0)?,
+        job_id: row.get(1)?,
+        operation: row.get(2)?,
+        acquired_at: parse_time(row.get(3)?),
+        heartbeat_at: parse_time(row.get(4)?),
+    })
+}
+
+impl VectorStore {
+    /// Create the branch lock table
+    pub(super) fn init_branch_lock_schema(&self) -> WikiResult<()> {
+        self.conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS branch_locks (
+                branch TEXT PRIMARY KEY,
+                job_id TEXT NOT NULL,
+                operation TEXT NOT NULL,
+                acquired_at TEXT NOT NULL,
+                heartbeat_at TEXT NOT NULL
+            );
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// Lock `branch` for a new `operation` job, unless a job whose lock is
+    /// not stale holds it already
+    pub fn acquire_branch_lock(
+        &self,
+        branch: &str,
+        operation: &str,
+    ) -> WikiResult<BranchLockAttempt> {
+        let now = Utc::now();
+        let stale_before = now - chrono::Duration::seconds(BRANCH_LOCK_STALE_SECS);
+        let job_id = Uuid::new_v4().to_string();
+
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
+            r#"
+            INSERT INTO branch_locks (branch, job_id, operation, acquired_at, heartbeat_at)
+            VALUES (?1, ?2, ?3, ?4, ?4)
+            ON CONFLICT(branch) DO UPDATE SET
+                job_id = excluded.job_id,
+                operation = excluded.operation,
+                acquired_at = excluded.acquired_at,
+                heartbeat_at = excluded.heartbeat_at
+            WHERE branch_locks.heartbeat_at < ?5
+            "#,
+            params![
+                branch,
+                job_id,
+                operation,
+                now.to_rfc3339(),
+                stale_before.to_rfc3339()
+            ],
+        )?;
+        let lock = tx.query_row(
+            r#"
+            SELECT branch, job_id, operation, acquired_at, heartbeat_at
+            FROM branch_locks WHERE branch = ?1
+            "#,
+            params![branch],
+            lock_from_row,
+        )?;
+        tx.commit()?;
+
+        if lock.job_id == job_id {
+            debug!(branch = %branch, job_id = %job_id, operation = %operation, "Locked branch");
+            Ok(BranchLockAttempt::Acquired(lock))
+        } else {
+            Ok(BranchLockAttempt::Held(lock))
+        }
+    }
+
+    /// The job holding `branch`, if its lock is not stale
+    pub fn get_branch_lock(&self, branch: &str) -> WikiResult<Option<BranchLock>> {
+        let lock = self
+            .conn
+            .query_row(
+                r#"
+                SELECT branch, job_id, operation, acquired_at, heartbeat_at
+                FROM branch_locks WHERE branch = ?1
+                "#,
+                params![branch],
+                lock_from_row,
+            )
+            .optional()?;
+
+        Ok(lock.filter(|lock| !lock.is_stale(Utc::now())))
+    }
+
+    /// Record that `job_id` still runs. Returns false if it no longer holds
+    /// `branch`, e.g. because its lock went stale and another job took over.
+    pub fn refresh_branch_lock(&self, branch: &str, job_id: &str) -> WikiResult<bool> {
+        let updated = self.conn.execute(
+            "UPDATE branch_locks SET heartbeat_at = ?1 WHERE branch = ?2 AND job_id = ?3",
+            params![Utc::now().to_rfc3339(), branch, job_id],
+        )?;
+        Ok(updated > 0)
+    }
+
+    /// Unlock `branch` if `job_id` holds it
+    pub fn release_branch_lock(&self, branch: &str, job_id: &str) -> WikiResult<()> {
+        self.conn.execute(
+            "DELETE FROM branch_locks WHERE branch = ?1 AND job_id = ?2",
+            params![branch, job_id],
+        )?;
+        debug!(branch = %branch, job_id = %job_id, "Unlocked branch");
+        Ok(())
+    }
+}
+
+/// Holds a branch lock for a running job: refreshes it in the background and
+/// releases it when dropped. Must be created inside a Tokio runtime.
+pub struct BranchLockGuard {
+    db_path: PathBuf,
+    lock: BranchLock,
+    heartbeat: tokio::task::JoinHandle<()>,
+}
+
+impl BranchLockGuard {
+    pub fn new(db_path: &Path, lock: BranchLock) -> Self {
+        let heartbeat = tokio::spawn(heartbeat(
+            db_path.to_path_buf(),
+            lock.branch.clone(),
+            lock.job_id.clone(),
+        ));
+        Self {
+            db_path: db_path.to_path_buf(),
+            lock,
+            heartbeat,
+        }
+    }
+
+    pub fn job_id(&self) -> &str {
+        &self.lock.job_id
+    }
+}
+
+async fn heartbeat(db_path: PathBuf, branch: String, job_id: String) {
+    let store = match VectorStore::new(&db_path) {
+        Ok(store) => store,
+        Err(e) => {
+            warn!(error = %e, branch = %branch, "Failed to open store for branch lock heartbeat");
+            return;
+        }
+    };
+    let mut interval = tokio::time::interval(Duration::from_secs(BRANCH_LOCK_HEARTBEAT_SECS));
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        match store.refresh_branch_lock(&branch, &job_id) {
+            Ok(true) => {}
+            Ok(false) => {
+                warn!(branch = %branch, job_id = %job_id, "Lost branch lock to another job");
+                return;
+            }
+            Err(e) => warn!(error = %e, branch = %branch, "Failed to refresh branch lock"),
+        }
+    }
+}
+
+impl Drop for BranchLockGuard {
+    fn drop(&mut self) {
+        self.heartbeat.abort();
+        let released = VectorStore::new(&self.db_path)
+            .and_then(|store| store.release_branch_lock(&self.lock.branch, &self.lock.job_id));
+        if let Err(e) = released {
+            warn!(error = %e, branch = %self.lock.branch, "Failed to release branch lock");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_branch_lock_excludes_concurrent_jobs() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = VectorStore::new(&temp_dir.path().join("wiki.db")).unwrap();
+
+        let BranchLockAttempt::Acquired(first) =
+            store.acquire_branch_lock("main", "generation").unwrap()
+        else {
+            panic!("an unlocked branch should be acquired");
+        };
+        let BranchLockAttempt::Held(held) = store.acquire_branch_lock("main", "indexing").unwrap()
+        else {
+            panic!("a locked branch should not be acquired twice");
+        };
+        assert_eq!(held.job_id, first.job_id);
+        assert_eq!(held.operation, "generation");
+        assert!(matches!(
+            store.acquire_branch_lock("develop", "indexing").unwrap(),
+            BranchLockAttempt::Acquired(_)
+        ));
+
+        assert!(store.refresh_branch_lock("main", &first.job_id).unwrap());
+        assert!(!store.refresh_branch_lock("main", "other-job").unwrap());
+        store.release_branch_lock("main", "other-job").unwrap();
+        let current = store.get_branch_lock("main").unwrap().unwrap();
+        assert_eq!(current.job_id, first.job_id);
+
+        store.release_branch_lock("main", &first.job_id).unwrap();
+        assert_eq!(store.get_branch_lock("main").unwrap(), None);
+        assert!(matches!(
+            store.acquire_branch_lock("main", "indexing").unwrap(),
+            BranchLockAttempt::Acquired(_)
+        ));
+    }
+
+    #[test]
+    fn test_stale_branch_lock_is_taken_over() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = VectorStore::new(&temp_dir.path().join("wiki.db")).unwrap();
+
+        let BranchLockAttempt::Acquired(crashed) =
+            store.acquire_branch_lock("main", "indexing").unwrap()
+        else {
+            panic!("an unlocked branch should be acquired");
+        };
+        let long_ago = Utc::now() - chrono::Duration::seconds(BRANCH_LOCK_STALE_SECS + 60);
+        store
+            .conn
+            .execute(
+                "UPDATE branch_locks SET heartbeat_at = ?1",
+                params![long_ago.to_rfc3339()],
+            )
+            .unwrap();
+        assert_eq!(store.get_branch_lock("main").unwrap(), None);
+
+        let BranchLockAttempt::Acquired(next) =
+            store.acquire_branch_lock("main", "generation").unwrap()
+        else {
+            panic!("a stale lock should be taken over");
+        };
+        assert_ne!(next.job_id, crashed.job_id);
+        assert!(!store.refresh_branch_lock("main", &crashed.job_id).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_guard_releases_lock_on_drop() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("wiki.db");
+        let store = VectorStore::new(&db_path).unwrap();
+
+        let BranchLockAttempt::Acquired(lock) =
+            store.acquire_branch_lock("main", "indexing").unwrap()
+        else {
+            panic!("an unlocked branch should be acquired");
+        };
+        let guard = BranchLockGuard::new(&db_path, lock);
+        assert!(store.get_branch_lock("main").unwrap().is_some());
+
+        drop(guard);
+        assert_eq!(store.get_branch_lock("main").unwrap(), None);
+    }
+}
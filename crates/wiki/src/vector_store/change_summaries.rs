@@ -0,0 +1,106 @@
+//! Cached summaries of workspace diffs
+//!
+//! Summarizing a diff takes several chat requests, so the latest summary of
+//! each task is kept together with the head commit it describes.
+
+use rusqlite::{params, OptionalExtension};
+
+use super::VectorStore;
+use crate::domain::change_summary::ChangeSummary;
+use crate::error::WikiResult;
+
+impl VectorStore {
+    /// Create the change summary table
+    pub(super) fn init_change_summary_schema(&self) -> WikiResult<()> {
+        self.conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS change_summaries (
+                task_id TEXT PRIMARY KEY,
+                head_commit TEXT NOT NULL,
+                summary TEXT NOT NULL,
+                created_at TEXT NOT NULL
+            );
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// The cached summary of a task's diff, if it was taken at `head_commit`
+    pub fn get_change_summary(
+        &self,
+        task_id: &str,
+        head_commit: &str,
+    ) -> WikiResult<Option<ChangeSummary>> {
+        let json: Option<String> = self
+            .conn
+            .query_row(
+                "SELECT summary FROM change_summaries WHERE task_id = ?1 AND head_commit = ?2",
+                params![task_id, head_commit],
+                |row| row.get(0),
+            )
+            .optional()?;
+
+        Ok(json.map(|json| serde_json::from_str(&json)).transpose()?)
+    }
+
+    /// Store the summary of a task's diff, replacing the previous one
+    pub fn upsert_change_summary(&self, task_id: &str, summary: &ChangeSummary) -> WikiResult<()> {
+        let json = serde_json::to_string(summary)?;
+
+        self.conn.execute(
+            r#"
+            INSERT OR REPLACE INTO change_summaries (task_id, head_commit, summary, created_at)
+            VALUES (?1, ?2, ?3, ?4)
+            "#,
+            params![
+                task_id,
+                summary.head_commit,
+                json,
+                summary.created_at.to_rfc3339()
+            ],
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::domain::change_summary::{ChangeRisk, FileChangeSummary};
+
+    #[test]
+    fn test_change_summary_cache() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = VectorStore::new(&temp_dir.path().join("wiki.db")).unwrap();
+
+        let summary = ChangeSummary {
+            head_commit: "abc123".to_string(),
+            files: vec![FileChangeSummary {
+                file_path: "src/lib.rs".to_string(),
+                bullets: vec!["Adds retries to uploads".to_string()],
+            }],
+            risks: vec![ChangeRisk {
+                file_path: "src/lib.rs".to_string(),
+                description: "Retries are unbounded".to_string(),
+            }],
+            created_at: Utc::now(),
+        };
+        store.upsert_change_summary("task-1", &summary).unwrap();
+
+        assert_eq!(
+            store.get_change_summary("task-1", "abc123").unwrap(),
+            Some(summary.clone())
+        );
+        assert_eq!(store.get_change_summary("task-1", "def456").unwrap(), None);
+
+        let newer = ChangeSummary {
+            head_commit: "def456".to_string(),
+            ..summary
+        };
+        store.upsert_change_summary("task-1", &newer).unwrap();
+        assert_eq!(store.get_change_summary("task-1", "abc123").unwrap(), None);
+    }
+}
@@ -0,0 +1,492 @@
+//! Per-model embedding tables and the embedding index of each branch
+//!
+//! sqlite-vec tables have a fixed dimension, so every embedding model gets
+//! its own vector table. `embedding_indexes` records which model (and which
+//! version of the branch's index) serves each branch, so vectors from
+//! different models are never compared. A table takes the dimension
+//! configured for its model, or that of the model's first embedding.
+
+use rusqlite::params;
+use tracing::{debug, info};
+
+use super::{VectorStore, EMBEDDING_DIMENSION};
+use crate::domain::chunk::CodeChunk;
+use crate::domain::embedding_index::{EmbeddingIndex, EmbeddingIndexState, LEGACY_EMBEDDING_MODEL};
+use crate::error::{WikiError, WikiResult};
+
+/// Vector table created before embedding models were tracked
+const LEGACY_EMBEDDING_TABLE: &str = "chunk_embeddings";
+
+impl VectorStore {
+    /// Attribute vectors stored before models were tracked to the legacy model
+    pub(super) fn migrate_embedding_indexes(&self) -> WikiResult<()> {
+        let now = chrono::Utc::now().to_rfc3339();
+
+        self.conn.execute(
+            r#"
+            INSERT OR IGNORE INTO embedding_models (model, table_name, dimension, created_at)
+            VALUES (?1, ?2, ?3, ?4)
+            "#,
+            params![
+                LEGACY_EMBEDDING_MODEL,
+                LEGACY_EMBEDDING_TABLE,
+                EMBEDDING_DIMENSION,
+                now
+            ],
+        )?;
+
+        let registered = self.conn.execute(
+            r#"
+            INSERT INTO embedding_indexes (branch, model, version, state, updated_at)
+            SELECT DISTINCT branch, ?1, 1, 'active', ?2
+            FROM chunks
+            WHERE branch NOT IN (SELECT branch FROM embedding_indexes)
+            "#,
+            params![LEGACY_EMBEDDING_MODEL, now],
+        )?;
+        if registered > 0 {
+            info!(
+                "Attributed {} existing branch indexes to embedding model {}",
+                registered, LEGACY_EMBEDDING_MODEL
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Name and dimension of the vector table holding `model`'s embeddings
+    pub(super) fn embedding_table(&self, model: &str) -> WikiResult<Option<(String, usize)>> {
+        let result = self.conn.query_row(
+            "SELECT table_name, dimension FROM embedding_models WHERE model = ?1",
+            params![model],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        );
+
+        match result {
+            Ok(table) => Ok(Some(table)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// The vector table for `model`, created with `dimension` on first use
+    pub(super) fn ensure_embedding_table(
+        &self,
+        model: &str,
+        dimension: usize,
+    ) -> WikiResult<String> {
+        if let Some((table_name, expected)) = self.embedding_table(model)? {
+            if expected != dimension {
+                return Err(WikiError::DimensionMismatch {
+                    expected,
+                    actual: dimension,
+                });
+            }
+            return Ok(table_name);
+        }
+
+        let next_id: i64 = self.conn.query_row(
+            "SELECT COALESCE(MAX(rowid), 0) + 1 FROM embedding_models",
+            [],
+            |row| row.get(0),
+        )?;
+        let table_name = format!("chunk_embeddings_{}", next_id);
+
+        self.conn.execute_batch(&format!(
+            "CREATE VIRTUAL TABLE IF NOT EXISTS {} USING vec0(
+                chunk_id TEXT PRIMARY KEY,
+                embedding FLOAT[{}]
+            );",
+            table_name, dimension
+        ))?;
+        self.conn.execute(
+            r#"
+            INSERT INTO embedding_models (model, table_name, dimension, created_at)
+            VALUES (?1, ?2, ?3, ?4)
+            "#,
+            params![
+                model,
+                table_name,
+                dimension,
+                chrono::Utc::now().to_rfc3339()
+            ],
+        )?;
+
+        info!(
+            "Created vector table {} for embedding model {} ({} dimensions)",
+            table_name, model, dimension
+        );
+        Ok(table_name)
+    }
+
+    /// Give `model` a vector table of `dimension`, the dimension configured
+    /// for it, instead of the one of its first embedding
+    ///
+    /// When the model's table was created with another dimension, its
+    /// vectors can no longer be searched: the table is recreated empty and
+    /// the branches searching with the model are marked stale so they are
+    /// re-indexed. Returns whether the table was recreated.
+    pub fn configure_embedding_dimension(&self, model: &str, dimension: usize) -> WikiResult<bool> {
+        if dimension == 0 {
+            return Err(WikiError::InvalidConfig(format!(
+                "Embedding dimension of '{}' must be positive",
+                model
+            )));
+        }
+
+        let Some((table_name, stored)) = self.embedding_table(model)? else {
+            self.ensure_embedding_table(model, dimension)?;
+            return Ok(false);
+        };
+        if stored == dimension {
+            return Ok(false);
+        }
+
+        self.conn.execute_batch(&format!(
+            "DROP TABLE IF EXISTS {table};
+            CREATE VIRTUAL TABLE {table} USING vec0(
+                chunk_id TEXT PRIMARY KEY,
+                embedding FLOAT[{dimension}]
+            );",
+            table = table_name,
+            dimension = dimension
+        ))?;
+        self.conn.execute(
+            "UPDATE embedding_models SET dimension = ?2 WHERE model = ?1",
+            params![model, dimension],
+        )?;
+        let stale = self.conn.execute(
+            r#"
+            UPDATE index_status SET state = 'stale'
+            WHERE branch IN (
+                SELECT branch FROM embedding_indexes WHERE model = ?1 AND state = 'active'
+            )
+            "#,
+            params![model],
+        )?;
+
+        info!(
+            "Embedding dimension of {} changed from {} to {}, recreated {} and marked {} branches stale",
+            model, stored, dimension, table_name, stale
+        );
+        Ok(true)
+    }
+
+    /// Fail unless `model` is the active embedding model of `branch`, or of
+    /// at least one indexed branch when `branch` is `None`. Branches without
+    /// chunks are ignored.
+    pub(super) fn ensure_searchable(&self, model: &str, branch: Option<&str>) -> WikiResult<()> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT i.branch, i.model
+            FROM embedding_indexes i
+            WHERE i.state = 'active'
+              AND (?1 IS NULL OR i.branch = ?1)
+              AND EXISTS (SELECT 1 FROM chunks c WHERE c.branch = i.branch)
+            ORDER BY i.branch
+            "#,
+        )?;
+        let active: Vec<(String, String)> = stmt
+            .query_map(params![branch], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match active.iter().find(|(_, m)| m == model) {
+            Some(_) => Ok(()),
+            None => match active.into_iter().next() {
+                Some((branch, indexed)) => Err(WikiError::EmbeddingModelMismatch {
+                    branch,
+                    indexed,
+                    requested: model.to_string(),
+                }),
+                None => Ok(()),
+            },
+        }
+    }
+
+    /// Embedding indexes of a branch, active first
+    pub fn get_embedding_indexes(&self, branch: &str) -> WikiResult<Vec<EmbeddingIndex>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT i.model, i.version, i.state, i.updated_at, m.dimension
+            FROM embedding_indexes i
+            LEFT JOIN embedding_models m ON m.model = i.model
+            WHERE i.branch = ?1
+            ORDER BY i.state = 'active' DESC, i.version DESC
+            "#,
+        )?;
+        let rows: Vec<(String, u32, String, String, Option<usize>)> = stmt
+            .query_map(params![branch], |row| {
+                Ok((
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        rows.into_iter()
+            .map(|(model, version, state, updated_at, dimension)| {
+                let embedded_chunks = self.count_embedded_chunks(branch, &model)?;
+                Ok(EmbeddingIndex {
+                    branch: branch.to_string(),
+                    model,
+                    version,
+                    state: EmbeddingIndexState::parse(&state)
+                        .unwrap_or(EmbeddingIndexState::Migrating),
+                    dimension,
+                    embedded_chunks,
+                    updated_at: chrono::DateTime::parse_from_rfc3339(&updated_at)
+                        .map(|dt| dt.with_timezone(&chrono::Utc))
+                        .unwrap_or_else(|_| chrono::Utc::now()),
+                })
+            })
+            .collect()
+    }
+
+    /// The embedding index serving searches on a branch
+    pub fn get_active_embedding_index(&self, branch: &str) -> WikiResult<Option<EmbeddingIndex>> {
+        Ok(self
+            .get_embedding_indexes(branch)?
+            .into_iter()
+            .find(|index| index.is_active()))
+    }
+
+    /// Make `model` the active embedding model of a branch that is about to
+    /// be (re)indexed from scratch, dropping any other index of the branch.
+    /// The version is kept when the model is unchanged and bumped otherwise.
+    pub fn activate_embedding_model(
+        &self,
+        branch: &str,
+        model: &str,
+    ) -> WikiResult<EmbeddingIndex> {
+        let version = match self.get_active_embedding_index(branch)? {
+            Some(active) if active.model == model => active.version,
+            Some(active) => active.version + 1,
+            None => 1,
+        };
+
+        for index in self.get_embedding_indexes(branch)? {
+            if index.model != model {
+                self.delete_branch_embeddings(branch, &index.model)?;
+            }
+        }
+        self.conn.execute(
+            "DELETE FROM embedding_indexes WHERE branch = ?1",
+            params![branch],
+        )?;
+        self.insert_embedding_index(branch, model, version, EmbeddingIndexState::Active)?;
+
+        self.get_active_embedding_index(branch)?
+            .ok_or_else(|| WikiError::IndexNotFound {
+                branch: branch.to_string(),
+            })
+    }
+
+    /// Start (or resume) migrating a branch to `model`. The active index
+    /// keeps serving searches until [`Self::complete_embedding_migration`].
+    /// A migration to a different model that is still in progress is
+    /// abandoned.
+    pub fn begin_embedding_migration(
+        &self,
+        branch: &str,
+        model: &str,
+    ) -> WikiResult<EmbeddingIndex> {
+        let indexes = self.get_embedding_indexes(branch)?;
+        let active = indexes
+            .iter()
+            .find(|index| index.is_active())
+            .ok_or_else(|| WikiError::IndexNotFound {
+                branch: branch.to_string(),
+            })?;
+
+        if active.model == model {
+            return Err(WikiError::InvalidConfig(format!(
+                "Branch '{}' already uses embedding model '{}'",
+                branch, model
+            )));
+        }
+
+        if let Some(migrating) = indexes.iter().find(|index| !index.is_active()) {
+            if migrating.model == model {
+                debug!(
+                    "Resuming migration of '{}' to {} ({} chunks embedded)",
+                    branch, model, migrating.embedded_chunks
+                );
+                return Ok(migrating.clone());
+            }
+            info!(
+                "Abandoning migration of '{}' to {} in favour of {}",
+                branch, migrating.model, model
+            );
+            self.delete_branch_embeddings(branch, &migrating.model)?;
+            self.conn.execute(
+                "DELETE FROM embedding_indexes WHERE branch = ?1 AND model = ?2",
+                params![branch, migrating.model],
+            )?;
+        }
+
+        self.insert_embedding_index(
+            branch,
+            model,
+            active.version + 1,
+            EmbeddingIndexState::Migrating,
+        )?;
+
+        self.get_embedding_indexes(branch)?
+            .into_iter()
+            .find(|index| index.model == model)
+            .ok_or_else(|| WikiError::IndexNotFound {
+                branch: branch.to_string(),
+            })
+    }
+
+    /// Switch a branch to the model it is migrating to, once every chunk has
+    /// a vector from it, and drop the previous model's vectors
+    pub fn complete_embedding_migration(
+        &self,
+        branch: &str,
+        model: &str,
+    ) -> WikiResult<EmbeddingIndex> {
+        let missing = self.count_chunks_missing_embeddings(branch, model)?;
+        if missing > 0 {
+            return Err(WikiError::IndexingFailed(format!(
+                "{} chunks of '{}' have no {} embedding yet",
+                missing, branch, model
+            )));
+        }
+
+        let indexes = self.get_embedding_indexes(branch)?;
+        if !indexes
+            .iter()
+            .any(|index| index.model == model && !index.is_active())
+        {
+            return Err(WikiError::InvalidConfig(format!(
+                "Branch '{}' is not migrating to embedding model '{}'",
+                branch, model
+            )));
+        }
+
+        for index in indexes.iter().filter(|index| index.is_active()) {
+            self.delete_branch_embeddings(branch, &index.model)?;
+        }
+        self.conn.execute(
+            "DELETE FROM embedding_indexes WHERE branch = ?1 AND state = 'active'",
+            params![branch],
+        )?;
+        self.conn.execute(
+            r#"
+            UPDATE embedding_indexes SET state = 'active', updated_at = ?3
+            WHERE branch = ?1 AND model = ?2
+            "#,
+            params![branch, model, chrono::Utc::now().to_rfc3339()],
+        )?;
+
+        info!("Branch '{}' now uses embedding model {}", branch, model);
+        self.get_active_embedding_index(branch)?
+            .ok_or_else(|| WikiError::IndexNotFound {
+                branch: branch.to_string(),
+            })
+    }
+
+    /// Chunks of a branch without a vector from `model`, in file order
+    pub fn get_chunks_missing_embeddings(
+        &self,
+        branch: &str,
+        model: &str,
+        limit: usize,
+    ) -> WikiResult<Vec<CodeChunk>> {
+        let filter = match self.embedding_table(model)? {
+            Some((table_name, _)) => format!("AND id NOT IN (SELECT chunk_id FROM {})", table_name),
+            None => String::new(),
+        };
+        let mut stmt = self.conn.prepare(&format!(
+            r#"
+            SELECT id, branch, file_path, start_line, end_line, content, chunk_type,
+                   language, token_count, chunk_index, commit_sha, created_at, summary, root,
+                   is_public_api, symbols, imports
+            FROM chunks
+            WHERE branch = ?1 {}
+            ORDER BY file_path, chunk_index
+            LIMIT ?2
+            "#,
+            filter
+        ))?;
+
+        let chunks = stmt
+            .query_map(params![branch, limit as i64], Self::row_to_chunk)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(chunks)
+    }
+
+    /// Number of chunks of a branch without a vector from `model`
+    pub fn count_chunks_missing_embeddings(&self, branch: &str, model: &str) -> WikiResult<u32> {
+        let total = self.get_chunk_count(branch)?;
+        Ok(total.saturating_sub(self.count_embedded_chunks(branch, model)?))
+    }
+
+    fn count_embedded_chunks(&self, branch: &str, model: &str) -> WikiResult<u32> {
+        let Some((table_name, _)) = self.embedding_table(model)? else {
+            return Ok(0);
+        };
+        let count: u32 = self.conn.query_row(
+            &format!(
+                "SELECT COUNT(*) FROM chunks WHERE branch = ?1 AND id IN (SELECT chunk_id FROM {})",
+                table_name
+            ),
+            params![branch],
+            |row| row.get(0),
+        )?;
+        Ok(count)
+    }
+
+    /// Delete `model`'s vectors for the chunks of a branch
+    pub(super) fn delete_branch_embeddings(&self, branch: &str, model: &str) -> WikiResult<()> {
+        if let Some((table_name, _)) = self.embedding_table(model)? {
+            self.conn.execute(
+                &format!(
+                    "DELETE FROM {} WHERE chunk_id IN (SELECT id FROM chunks WHERE branch = ?1)",
+                    table_name
+                ),
+                params![branch],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Names of all per-model vector tables
+    pub(super) fn embedding_table_names(&self) -> WikiResult<Vec<String>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT table_name FROM embedding_models")?;
+        let names = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(names)
+    }
+
+    fn insert_embedding_index(
+        &self,
+        branch: &str,
+        model: &str,
+        version: u32,
+        state: EmbeddingIndexState,
+    ) -> WikiResult<()> {
+        self.conn.execute(
+            r#"
+            INSERT OR REPLACE INTO embedding_indexes (branch, model, version, state, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            "#,
+            params![
+                branch,
+                model,
+                version,
+                state.as_str(),
+                chrono::Utc::now().to_rfc3339()
+            ],
+        )?;
+        Ok(())
+    }
+}
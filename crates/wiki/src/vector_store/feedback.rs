@@ -0,0 +1,274 @@
+//! Feedback on wiki answers
+//!
+//! Each rating is stored with the question, the answer and the code ranges
+//! retrieved for it, so rated-down answers can be traced back to the chunks
+//! that produced them.
+
+use chrono::{DateTime, Utc};
+use rusqlite::{params, OptionalExtension};
+
+use super::VectorStore;
+use crate::domain::ask_feedback::{
+    AskFeedback, AskFeedbackStats, AskRating, FeedbackSource, SourceVotes, FLAG_MIN_NEGATIVE_VOTES,
+};
+use crate::error::WikiResult;
+
+impl VectorStore {
+    /// Create the feedback tables
+    pub(super) fn init_feedback_schema(&self) -> WikiResult<()> {
+        self.conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS ask_feedback (
+                id TEXT PRIMARY KEY,
+                conversation_id TEXT NOT NULL,
+                rating TEXT NOT NULL,
+                question TEXT NOT NULL,
+                answer TEXT NOT NULL,
+                comment TEXT,
+                created_at TEXT NOT NULL,
+                UNIQUE (conversation_id, question)
+            );
+
+            CREATE TABLE IF NOT EXISTS ask_feedback_sources (
+                feedback_id TEXT NOT NULL,
+                file_path TEXT NOT NULL,
+                start_line INTEGER NOT NULL,
+                end_line INTEGER NOT NULL
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_ask_feedback_sources_feedback
+                ON ask_feedback_sources(feedback_id);
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// Store feedback on an answer. Rating the same question of a
+    /// conversation again replaces the earlier rating.
+    pub fn record_ask_feedback(&self, feedback: &AskFeedback) -> WikiResult<()> {
+        let previous: Option<String> = self
+            .conn
+            .query_row(
+                "SELECT id FROM ask_feedback WHERE conversation_id = ?1 AND question = ?2",
+                params![feedback.conversation_id, feedback.question],
+                |row| row.get(0),
+            )
+            .optional()?;
+        if let Some(previous) = previous {
+            self.delete_ask_feedback(&previous)?;
+        }
+
+        self.conn.execute(
+            r#"
+            INSERT INTO ask_feedback
+                (id, conversation_id, rating, question, answer, comment, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            "#,
+            params![
+                feedback.id,
+                feedback.conversation_id,
+                feedback.rating.as_str(),
+                feedback.question,
+                feedback.answer,
+                feedback.comment,
+                feedback.created_at.to_rfc3339()
+            ],
+        )?;
+
+        let mut stmt = self.conn.prepare_cached(
+            r#"
+            INSERT INTO ask_feedback_sources (feedback_id, file_path, start_line, end_line)
+            VALUES (?1, ?2, ?3, ?4)
+            "#,
+        )?;
+        for source in &feedback.sources {
+            stmt.execute(params![
+                feedback.id,
+                source.file_path,
+                source.start_line,
+                source.end_line
+            ])?;
+        }
+
+        Ok(())
+    }
+
+    fn delete_ask_feedback(&self, id: &str) -> WikiResult<()> {
+        self.conn.execute(
+            "DELETE FROM ask_feedback_sources WHERE feedback_id = ?1",
+            params![id],
+        )?;
+        self.conn
+            .execute("DELETE FROM ask_feedback WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+
+    /// The latest feedback, newest first, optionally only with one rating
+    pub fn list_ask_feedback(
+        &self,
+        rating: Option<AskRating>,
+        limit: usize,
+    ) -> WikiResult<Vec<AskFeedback>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT id, conversation_id, rating, question, answer, comment, created_at
+            FROM ask_feedback
+            WHERE ?1 IS NULL OR rating = ?1
+            ORDER BY created_at DESC
+            LIMIT ?2
+            "#,
+        )?;
+        let mut feedback = stmt
+            .query_map(params![rating.map(|r| r.as_str()), limit as i64], |row| {
+                let rating: String = row.get(2)?;
+                let created_at: String = row.get(6)?;
+                Ok(AskFeedback {
+                    id: row.get(0)?,
+                    conversation_id: row.get(1)?,
+                    rating: AskRating::parse(&rating).unwrap_or(AskRating::Down),
+                    question: row.get(3)?,
+                    answer: row.get(4)?,
+                    sources: Vec::new(),
+                    comment: row.get(5)?,
+                    created_at: DateTime::parse_from_rfc3339(&created_at)
+                        .map(|d| d.with_timezone(&Utc))
+                        .unwrap_or_else(|_| Utc::now()),
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut sources = self.conn.prepare_cached(
+            r#"
+            SELECT file_path, start_line, end_line
+            FROM ask_feedback_sources
+            WHERE feedback_id = ?1
+            "#,
+        )?;
+        for entry in &mut feedback {
+            entry.sources = sources
+                .query_map(params![entry.id], |row| {
+                    Ok(FeedbackSource {
+                        file_path: row.get(0)?,
+                        start_line: row.get(1)?,
+                        end_line: row.get(2)?,
+                    })
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+        }
+
+        Ok(feedback)
+    }
+
+    /// Vote totals, with the sources flagged by negative feedback
+    pub fn ask_feedback_stats(&self) -> WikiResult<AskFeedbackStats> {
+        let (total, positive, negative): (u32, u32, u32) = self.conn.query_row(
+            r#"
+            SELECT COUNT(*),
+                   COALESCE(SUM(rating = 'up'), 0),
+                   COALESCE(SUM(rating = 'down'), 0)
+            FROM ask_feedback
+            "#,
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )?;
+
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT s.file_path, s.start_line, s.end_line,
+                   SUM(f.rating = 'up') AS positive,
+                   SUM(f.rating = 'down') AS negative
+            FROM ask_feedback_sources s
+            JOIN ask_feedback f ON f.id = s.feedback_id
+            GROUP BY s.file_path, s.start_line, s.end_line
+            HAVING negative >= ?1 AND negative > positive
+            ORDER BY negative DESC, s.file_path, s.start_line
+            "#,
+        )?;
+        let flagged_sources = stmt
+            .query_map(params![FLAG_MIN_NEGATIVE_VOTES], |row| {
+                Ok(SourceVotes {
+                    source: FeedbackSource {
+                        file_path: row.get(0)?,
+                        start_line: row.get(1)?,
+                        end_line: row.get(2)?,
+                    },
+                    positive: row.get(3)?,
+                    negative: row.get(4)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(AskFeedbackStats {
+            total,
+            positive,
+            negative,
+            flagged_sources,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn source(file_path: &str) -> FeedbackSource {
+        FeedbackSource {
+            file_path: file_path.to_string(),
+            start_line: 1,
+            end_line: 40,
+        }
+    }
+
+    #[test]
+    fn test_feedback_flags_sources() {
+        let dir = tempdir().unwrap();
+        let store = VectorStore::new(&dir.path().join("test.db")).unwrap();
+
+        let sources = vec![source("src/auth.rs"), source("src/db.rs")];
+        for conversation in ["a", "b"] {
+            let feedback = AskFeedback::new(
+                conversation,
+                AskRating::Down,
+                "How does login work?",
+                "It doesn't",
+                sources.clone(),
+            );
+            store.record_ask_feedback(&feedback).unwrap();
+        }
+        let feedback = AskFeedback::new(
+            "c",
+            AskRating::Up,
+            "Where are sessions stored?",
+            "In SQLite",
+            vec![source("src/db.rs")],
+        )
+        .with_comment("Spot on");
+        store.record_ask_feedback(&feedback).unwrap();
+
+        let stats = store.ask_feedback_stats().unwrap();
+        assert_eq!((stats.total, stats.positive, stats.negative), (3, 1, 2));
+        assert_eq!(stats.flagged_sources.len(), 2);
+        assert!(stats.flagged_sources.iter().all(SourceVotes::is_flagged));
+
+        // Changing a rating replaces it
+        let feedback = AskFeedback::new(
+            "b",
+            AskRating::Up,
+            "How does login work?",
+            "It doesn't",
+            sources,
+        );
+        store.record_ask_feedback(&feedback).unwrap();
+
+        let stats = store.ask_feedback_stats().unwrap();
+        assert_eq!((stats.total, stats.positive, stats.negative), (3, 2, 1));
+        assert!(stats.flagged_sources.is_empty());
+
+        let negative = store.list_ask_feedback(Some(AskRating::Down), 10).unwrap();
+        assert_eq!(negative.len(), 1);
+        assert_eq!(negative[0].conversation_id, "a");
+        assert_eq!(negative[0].sources.len(), 2);
+        assert_eq!(store.list_ask_feedback(None, 10).unwrap().len(), 3);
+    }
+}
@@ -0,0 +1,551 @@
+//! Earlier indexes of each branch, searchable by commit
+//!
+//! Re-indexing a branch replaces its chunks. Before that, the chunks and
+//! their vectors from the active embedding model are copied into
+//! `chunk_history`, keyed by the commit they were indexed at, so questions
+//! like "what did this code look like at commit X" can still be answered.
+//! Only the most recent generations of each branch are kept.
+
+use std::collections::BTreeSet;
+
+use rusqlite::params;
+use tracing::{debug, info};
+
+use super::VectorStore;
+use crate::domain::chunk::CodeChunk;
+use crate::domain::chunk_generation::ChunkGeneration;
+use crate::domain::index_status::IndexState;
+use crate::domain::search_result::SearchResult;
+use crate::error::{WikiError, WikiResult};
+use crate::indexer::normalize_root;
+
+/// Shortest commit prefix accepted for `as_of`
+const MIN_COMMIT_PREFIX: usize = 4;
+
+/// Where the chunks of a branch at a commit are found
+enum Generation {
+    /// The branch's current chunks
+    Current { branch: String },
+    /// Chunks kept in `chunk_history`
+    Archived(ChunkGeneration),
+}
+
+impl VectorStore {
+    /// Create the tables of earlier indexes
+    pub(super) fn init_history_schema(&self) -> WikiResult<()> {
+        self.conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS chunk_generations (
+                branch TEXT NOT NULL,
+                commit_sha TEXT NOT NULL,
+                model TEXT,
+                chunk_count INTEGER NOT NULL,
+                archived_at TEXT NOT NULL,
+                PRIMARY KEY (branch, commit_sha)
+            );
+
+            CREATE TABLE IF NOT EXISTS chunk_history (
+                id TEXT NOT NULL,
+                branch TEXT NOT NULL,
+                file_path TEXT NOT NULL,
+                start_line INTEGER NOT NULL,
+                end_line INTEGER NOT NULL,
+                content TEXT NOT NULL,
+                chunk_type TEXT NOT NULL,
+                language TEXT,
+                token_count INTEGER NOT NULL,
+                chunk_index INTEGER NOT NULL,
+                commit_sha TEXT NOT NULL,
+                created_at TEXT NOT NULL,
+                summary TEXT,
+                root TEXT,
+                is_public_api INTEGER NOT NULL DEFAULT 0,
+                symbols TEXT NOT NULL DEFAULT '[]',
+                imports TEXT NOT NULL DEFAULT '[]',
+                embedding BLOB,
+                PRIMARY KEY (branch, commit_sha, id)
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_chunk_history_file
+                ON chunk_history(branch, commit_sha, file_path);
+            "#,
+        )?;
+        self.migrate_symbol_columns("chunk_history")
+    }
+
+    /// Keep the current chunks of `branch` as a generation before it is
+    /// re-indexed, then drop all but the `keep` most recent generations
+    ///
+    /// Nothing is kept while the branch is being indexed, after a failed
+    /// run or when `keep` is zero. Returns the kept generation.
+    pub fn archive_generation(
+        &self,
+        branch: &str,
+        keep: usize,
+    ) -> WikiResult<Option<ChunkGeneration>> {
+        let commit_sha = match self.get_index_status(branch)? {
+            Some(status)
+                if keep > 0
+                    && matches!(
+                        status.state,
+                        IndexState::Indexed | IndexState::Generating | IndexState::Stale
+                    ) =>
+            {
+                status.last_commit_sha.filter(|sha| sha != "unknown")
+            }
+            _ => None,
+        };
+        let Some(commit_sha) = commit_sha else {
+            self.prune_generations(branch, keep)?;
+            return Ok(None);
+        };
+
+        let vectors = match self.get_active_embedding_index(branch)? {
+            Some(index) => self
+                .embedding_table(&index.model)?
+                .map(|(table_name, _)| (index.model, table_name)),
+            None => None,
+        };
+        let (model, embedding, join) = match vectors {
+            Some((model, table_name)) => (
+                Some(model),
+                "e.embedding",
+                format!("LEFT JOIN {} e ON e.chunk_id = c.id", table_name),
+            ),
+            None => (None, "NULL", String::new()),
+        };
+
+        self.delete_generation(branch, &commit_sha)?;
+        let chunk_count = self.conn.execute(
+            &format!(
+                r#"
+                INSERT INTO chunk_history
+                (id, branch, file_path, start_line, end_line, content, chunk_type,
+                 language, token_count, chunk_index, commit_sha, created_at, summary, root,
+                 is_public_api, symbols, imports, embedding)
+                SELECT c.id, c.branch, c.file_path, c.start_line, c.end_line, c.content,
+                       c.chunk_type, c.language, c.token_count, c.chunk_index, ?2,
+                       c.created_at, c.summary, c.root, c.is_public_api, c.symbols, c.imports,
+                       {}
+                FROM chunks c
+                {}
+                WHERE c.branch = ?1
+                "#,
+                embedding, join
+            ),
+            params![branch, commit_sha],
+        )?;
+        if chunk_count == 0 {
+            self.prune_generations(branch, keep)?;
+            return Ok(None);
+        }
+
+        let generation = ChunkGeneration {
+            branch: branch.to_string(),
+            commit_sha,
+            model,
+            chunk_count: chunk_count as u32,
+            archived_at: chrono::Utc::now(),
+        };
+        self.conn.execute(
+            r#"
+            INSERT INTO chunk_generations (branch, commit_sha, model, chunk_count, archived_at)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            "#,
+            params![
+                generation.branch,
+                generation.commit_sha,
+                generation.model,
+                generation.chunk_count,
+                generation.archived_at.to_rfc3339()
+            ],
+        )?;
+        info!(
+            "Kept {} chunks of '{}' at commit {}",
+            chunk_count, branch, generation.commit_sha
+        );
+
+        self.prune_generations(branch, keep)?;
+        Ok(Some(generation))
+    }
+
+    /// Kept generations of a branch, newest first
+    pub fn get_chunk_generations(&self, branch: &str) -> WikiResult<Vec<ChunkGeneration>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT branch, commit_sha, model, chunk_count, archived_at
+            FROM chunk_generations
+            WHERE branch = ?1
+            ORDER BY archived_at DESC, rowid DESC
+            "#,
+        )?;
+
+        let generations = stmt
+            .query_map(params![branch], Self::row_to_generation)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(generations)
+    }
+
+    /// Search the chunks closest to `query_embedding` as they were indexed at
+    /// the commit `as_of` (a full SHA or a prefix of one), on `branch` or on
+    /// every branch indexed at that commit
+    ///
+    /// Fails with [`WikiError::CommitNotIndexed`] when neither the current
+    /// index nor a kept generation is of that commit.
+    pub fn search_similar_as_of(
+        &self,
+        query_embedding: &[f32],
+        model: &str,
+        limit: usize,
+        branch: Option<&str>,
+        root: Option<&str>,
+        as_of: &str,
+    ) -> WikiResult<Vec<SearchResult>> {
+        let mut results = Vec::new();
+        for generation in self.resolve_as_of(branch, as_of)? {
+            match generation {
+                Generation::Current { branch } => results.extend(self.search_similar_in_branch(
+                    query_embedding,
+                    model,
+                    limit,
+                    Some(&branch),
+                    root,
+                )?),
+                Generation::Archived(generation) => results.extend(self.search_generation(
+                    query_embedding,
+                    model,
+                    limit,
+                    &generation,
+                    root,
+                )?),
+            }
+        }
+
+        results.sort_by(|a, b| b.score.total_cmp(&a.score));
+        results.truncate(limit);
+        Ok(results)
+    }
+
+    /// Chunks of one file of `branch` as indexed at the commit `as_of`,
+    /// ordered by chunk index
+    pub fn get_chunks_for_file_as_of(
+        &self,
+        branch: &str,
+        path: &str,
+        as_of: &str,
+    ) -> WikiResult<Vec<CodeChunk>> {
+        let Some(generation) = self.resolve_as_of(Some(branch), as_of)?.into_iter().next() else {
+            return Ok(Vec::new());
+        };
+        let commit_sha = match generation {
+            Generation::Current { branch } => return self.get_chunks_for_file(&branch, path),
+            Generation::Archived(generation) => generation.commit_sha,
+        };
+
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT id, branch, file_path, start_line, end_line, content, chunk_type,
+                   language, token_count, chunk_index, commit_sha, created_at, summary, root,
+                   is_public_api, symbols, imports
+            FROM chunk_history
+            WHERE branch = ?1 AND commit_sha = ?2 AND file_path = ?3
+            ORDER BY chunk_index
+            "#,
+        )?;
+
+        let chunks = stmt
+            .query_map(params![branch, commit_sha, path], Self::row_to_chunk)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(chunks)
+    }
+
+    /// The current indexes and kept generations of the commit `as_of`. A
+    /// branch whose current chunks are of that commit is searched live.
+    fn resolve_as_of(&self, branch: Option<&str>, as_of: &str) -> WikiResult<Vec<Generation>> {
+        let as_of = as_of.trim().to_ascii_lowercase();
+        if as_of.len() < MIN_COMMIT_PREFIX || !as_of.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(WikiError::InvalidConfig(format!(
+                "'{}' is not a commit SHA of at least {} hex digits",
+                as_of, MIN_COMMIT_PREFIX
+            )));
+        }
+
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT DISTINCT branch, commit_sha
+            FROM chunks
+            WHERE (?1 IS NULL OR branch = ?1) AND substr(commit_sha, 1, length(?2)) = ?2
+            ORDER BY branch
+            "#,
+        )?;
+        let current: Vec<(String, String)> = stmt
+            .query_map(params![branch, as_of], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT branch, commit_sha, model, chunk_count, archived_at
+            FROM chunk_generations
+            WHERE (?1 IS NULL OR branch = ?1) AND substr(commit_sha, 1, length(?2)) = ?2
+            ORDER BY branch
+            "#,
+        )?;
+        let archived: Vec<ChunkGeneration> = stmt
+            .query_map(params![branch, as_of], Self::row_to_generation)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let commits: BTreeSet<&str> = current
+            .iter()
+            .map(|(_, sha)| sha.as_str())
+            .chain(archived.iter().map(|g| g.commit_sha.as_str()))
+            .collect();
+        match commits.len() {
+            0 => {
+                return Err(WikiError::CommitNotIndexed {
+                    commit: as_of.to_string(),
+                })
+            }
+            1 => {}
+            _ => {
+                return Err(WikiError::InvalidConfig(format!(
+                    "Commit prefix '{}' matches several indexed commits",
+                    as_of
+                )))
+            }
+        }
+
+        debug!(
+            "Commit {} is indexed on {} branches and kept on {}",
+            as_of,
+            current.len(),
+            archived.len()
+        );
+        let live: BTreeSet<String> = current.iter().map(|(b, _)| b.clone()).collect();
+        let mut generations: Vec<Generation> = live
+            .iter()
+            .map(|branch| Generation::Current {
+                branch: branch.clone(),
+            })
+            .collect();
+        generations.extend(
+            archived
+                .into_iter()
+                .filter(|g| !live.contains(&g.branch))
+                .map(Generation::Archived),
+        );
+        Ok(generations)
+    }
+
+    /// Semantic search within one kept generation
+    fn search_generation(
+        &self,
+        query_embedding: &[f32],
+        model: &str,
+        limit: usize,
+        generation: &ChunkGeneration,
+        root: Option<&str>,
+    ) -> WikiResult<Vec<SearchResult>> {
+        match generation.model.as_deref() {
+            None => return Ok(Vec::new()),
+            Some(indexed) if indexed != model => {
+                return Err(WikiError::EmbeddingModelMismatch {
+                    branch: generation.branch.clone(),
+                    indexed: indexed.to_string(),
+                    requested: model.to_string(),
+                })
+            }
+            Some(_) => {}
+        }
+        let root = root.map(normalize_root);
+
+        let embedding_bytes: Vec<u8> = query_embedding
+            .iter()
+            .flat_map(|f| f.to_le_bytes())
+            .collect();
+
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT
+                id, file_path, start_line, end_line, content,
+                chunk_type, language, summary, is_public_api, symbols,
+                vec_distance_cosine(embedding, ?1) as distance
+            FROM chunk_history
+            WHERE branch = ?3 AND commit_sha = ?4 AND embedding IS NOT NULL
+              AND (?5 IS NULL OR root = ?5)
+            ORDER BY distance ASC
+            LIMIT ?2
+            "#,
+        )?;
+
+        let results = stmt
+            .query_map(
+                params![
+                    embedding_bytes,
+                    limit as i64,
+                    generation.branch,
+                    generation.commit_sha,
+                    root
+                ],
+                Self::row_to_search_result,
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(results)
+    }
+
+    /// Drop all but the `keep` most recent generations of a branch
+    fn prune_generations(&self, branch: &str, keep: usize) -> WikiResult<()> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT commit_sha FROM chunk_generations
+            WHERE branch = ?1
+            ORDER BY archived_at DESC, rowid DESC
+            LIMIT -1 OFFSET ?2
+            "#,
+        )?;
+        let expired: Vec<String> = stmt
+            .query_map(params![branch, keep as i64], |row| row.get(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for commit_sha in &expired {
+            self.delete_generation(branch, commit_sha)?;
+        }
+        if !expired.is_empty() {
+            debug!("Dropped {} kept generations of '{}'", expired.len(), branch);
+        }
+        Ok(())
+    }
+
+    fn delete_generation(&self, branch: &str, commit_sha: &str) -> WikiResult<()> {
+        self.conn.execute(
+            "DELETE FROM chunk_history WHERE branch = ?1 AND commit_sha = ?2",
+            params![branch, commit_sha],
+        )?;
+        self.conn.execute(
+            "DELETE FROM chunk_generations WHERE branch = ?1 AND commit_sha = ?2",
+            params![branch, commit_sha],
+        )?;
+        Ok(())
+    }
+
+    fn row_to_generation(row: &rusqlite::Row) -> rusqlite::Result<ChunkGeneration> {
+        let archived_str: String = row.get(4)?;
+        Ok(ChunkGeneration {
+            branch: row.get(0)?,
+            commit_sha: row.get(1)?,
+            model: row.get(2)?,
+            chunk_count: row.get(3)?,
+            archived_at: chrono::DateTime::parse_from_rfc3339(&archived_str)
+                .map(|dt| dt.with_timezone(&chrono::Utc))
+                .unwrap_or_else(|_| chrono::Utc::now()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::chunk::ChunkType;
+    use crate::domain::index_status::IndexStatus;
+    use tempfile::tempdir;
+
+    const MODEL: &str = "small";
+
+    fn index_at(store: &VectorStore, commit_sha: &str, content: &str, vector: [f32; 4]) {
+        store.clear_branch("main").unwrap();
+        store.activate_embedding_model("main", MODEL).unwrap();
+        let chunk = CodeChunk::new(
+            "main".to_string(),
+            "src/lib.rs".to_string(),
+            1,
+            3,
+            content.to_string(),
+            ChunkType::Function,
+            Some("rust".to_string()),
+            5,
+            0,
+            commit_sha.to_string(),
+        );
+        store
+            .insert_chunks_batch(std::slice::from_ref(&chunk))
+            .unwrap();
+        store
+            .insert_embeddings_batch(MODEL, &[chunk.id], &[vector.to_vec()])
+            .unwrap();
+        store
+            .update_index_status(&IndexStatus {
+                state: IndexState::Indexed,
+                last_commit_sha: Some(commit_sha.to_string()),
+                ..IndexStatus::new("main".to_string())
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_search_and_file_chunks_as_of_kept_commit() {
+        let dir = tempdir().unwrap();
+        let store = VectorStore::new(&dir.path().join("test.db")).unwrap();
+
+        index_at(&store, "aaaa1111", "fn old() {}", [1.0, 0.0, 0.0, 0.0]);
+        let kept = store.archive_generation("main", 5).unwrap().unwrap();
+        assert_eq!(kept.commit_sha, "aaaa1111");
+        assert_eq!(kept.model.as_deref(), Some(MODEL));
+        assert_eq!(kept.chunk_count, 1);
+        index_at(&store, "bbbb2222", "fn new() {}", [0.0, 1.0, 0.0, 0.0]);
+
+        let query = [1.0, 0.0, 0.0, 0.0];
+        let past = store
+            .search_similar_as_of(&query, MODEL, 5, Some("main"), None, "aaaa")
+            .unwrap();
+        assert_eq!(past.len(), 1);
+        assert_eq!(past[0].content, "fn old() {}");
+        assert!(past[0].score > 0.99);
+
+        let now = store
+            .search_similar_as_of(&query, MODEL, 5, None, None, "bbbb2222")
+            .unwrap();
+        assert_eq!(now[0].content, "fn new() {}");
+
+        let chunks = store
+            .get_chunks_for_file_as_of("main", "src/lib.rs", "aaaa1111")
+            .unwrap();
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].content, "fn old() {}");
+        assert_eq!(chunks[0].commit_sha, "aaaa1111");
+
+        assert!(matches!(
+            store.search_similar_as_of(&query, MODEL, 5, None, None, "cccc"),
+            Err(WikiError::CommitNotIndexed { .. })
+        ));
+        assert!(store
+            .search_similar_as_of(&query, MODEL, 5, None, None, "aa")
+            .is_err());
+        assert!(matches!(
+            store.search_similar_as_of(&query, "large", 5, None, None, "aaaa"),
+            Err(WikiError::EmbeddingModelMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_retention_keeps_most_recent_generations() {
+        let dir = tempdir().unwrap();
+        let store = VectorStore::new(&dir.path().join("test.db")).unwrap();
+
+        for commit_sha in ["aaaa0001", "aaaa0002", "aaaa0003"] {
+            index_at(&store, commit_sha, "fn f() {}", [1.0, 0.0, 0.0, 0.0]);
+            store.archive_generation("main", 2).unwrap();
+        }
+        let kept: Vec<String> = store
+            .get_chunk_generations("main")
+            .unwrap()
+            .into_iter()
+            .map(|g| g.commit_sha)
+            .collect();
+        assert_eq!(kept, vec!["aaaa0003", "aaaa0002"]);
+        assert!(store
+            .get_chunks_for_file_as_of("main", "src/lib.rs", "aaaa0001")
+            .is_err());
+
+        assert!(store.archive_generation("main", 0).unwrap().is_none());
+        assert!(store.get_chunk_generations("main").unwrap().is_empty());
+    }
+}
@@ -0,0 +1,389 @@
+//! Keyword index over chunk content
+//!
+//! Embeddings place `insert_embeddings_batch` near every other batch
+//! insert, so an exact identifier can rank below fuzzy matches. The FTS5
+//! table `chunk_fts` indexes each chunk's content and path under the
+//! chunk's rowid; it is kept in step with `chunks` by the insert and clear
+//! methods rather than by triggers, since `INSERT OR REPLACE` does not
+//! fire delete triggers.
+
+use std::collections::HashMap;
+
+use rusqlite::params;
+use tracing::debug;
+use uuid::Uuid;
+
+use super::VectorStore;
+use crate::domain::chunk::ChunkType;
+use crate::domain::search_result::{SearchResult, SearchScope};
+use crate::error::WikiResult;
+use crate::indexer::normalize_root;
+
+/// Rank constant of reciprocal rank fusion; larger values flatten the
+/// advantage of the top ranks
+const RRF_K: f32 = 60.0;
+
+/// Candidates taken from each ranking per requested hybrid result
+const HYBRID_CANDIDATES_PER_RESULT: usize = 4;
+
+impl VectorStore {
+    /// Create the keyword index, filling it from existing chunks when it is new
+    pub(super) fn init_keyword_schema(&self) -> WikiResult<()> {
+        let exists: bool = self.conn.query_row(
+            "SELECT COUNT(*) > 0 FROM sqlite_master WHERE name = 'chunk_fts'",
+            [],
+            |row| row.get(0),
+        )?;
+        if exists {
+            return Ok(());
+        }
+
+        self.conn.execute_batch(
+            r#"
+            CREATE VIRTUAL TABLE chunk_fts USING fts5(content, file_path);
+
+            INSERT INTO chunk_fts (rowid, content, file_path)
+            SELECT rowid, content, file_path FROM chunks;
+            "#,
+        )?;
+        debug!("Created keyword index");
+        Ok(())
+    }
+
+    /// Drop the keyword entry of the chunk stored as `chunk_id`, before the
+    /// chunk is replaced
+    pub(super) fn remove_keyword_entry(&self, chunk_id: &Uuid) -> WikiResult<()> {
+        self.conn
+            .prepare_cached(
+                "DELETE FROM chunk_fts WHERE rowid = (SELECT rowid FROM chunks WHERE id = ?1)",
+            )?
+            .execute(params![chunk_id.to_string()])?;
+        Ok(())
+    }
+
+    /// Index the stored content of `chunk_id`
+    pub(super) fn add_keyword_entry(&self, chunk_id: &Uuid) -> WikiResult<()> {
+        self.conn
+            .prepare_cached(
+                r#"
+                INSERT INTO chunk_fts (rowid, content, file_path)
+                SELECT rowid, content, file_path FROM chunks WHERE id = ?1
+                "#,
+            )?
+            .execute(params![chunk_id.to_string()])?;
+        Ok(())
+    }
+
+    /// Drop the keyword entries of every chunk of `branch`
+    pub(super) fn clear_keyword_entries(&self, branch: &str) -> WikiResult<()> {
+        self.conn.execute(
+            "DELETE FROM chunk_fts WHERE rowid IN (SELECT rowid FROM chunks WHERE branch = ?1)",
+            params![branch],
+        )?;
+        Ok(())
+    }
+
+    /// The chunks that best match the terms of `query`, ranked by BM25.
+    ///
+    /// Each whitespace-separated term is matched as a phrase, so
+    /// `insert_embeddings_batch` only matches those three words in a row.
+    /// Chunks need not contain every term; those containing more rank
+    /// higher. Scores are BM25 relevance mapped into 0..1. Only chunks of the
+    /// workspace `root` are searched if it is set.
+    pub fn search_keyword(
+        &self,
+        query: &str,
+        limit: usize,
+        branch: Option<&str>,
+        root: Option<&str>,
+    ) -> WikiResult<Vec<SearchResult>> {
+        self.search_keyword_scoped(query, limit, branch, root, &SearchScope::default())
+    }
+
+    /// Like [`Self::search_keyword`], only considering chunks whose path and
+    /// language fall in `scope`
+    pub fn search_keyword_scoped(
+        &self,
+        query: &str,
+        limit: usize,
+        branch: Option<&str>,
+        root: Option<&str>,
+        scope: &SearchScope,
+    ) -> WikiResult<Vec<SearchResult>> {
+        let Some(match_expr) = fts_match_expression(query) else {
+            return Ok(Vec::new());
+        };
+        let root = root.map(normalize_root);
+
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT
+                c.id, c.file_path, c.start_line, c.end_line, c.content,
+                c.chunk_type, c.language, c.summary, c.is_public_api, c.symbols,
+                bm25(chunk_fts) AS rank
+            FROM chunk_fts
+            JOIN chunks c ON c.rowid = chunk_fts.rowid
+            WHERE chunk_fts MATCH ?1 AND (?2 IS NULL OR c.branch = ?2)
+                AND (?4 IS NULL OR c.root = ?4)
+                AND (?5 IS NULL OR c.file_path GLOB ?5)
+                AND (?6 IS NULL OR lower(c.language) = ?6)
+            ORDER BY rank
+            LIMIT ?3
+            "#,
+        )?;
+
+        let results = stmt
+            .query_map(
+                params![
+                    match_expr,
+                    branch,
+                    limit as i64,
+                    root,
+                    scope.sql_glob(),
+                    scope.sql_language()
+                ],
+                |row| {
+                    let id_str: String = row.get(0)?;
+                    let chunk_type_str: String = row.get(5)?;
+                    let rank: f64 = row.get(10)?;
+
+                    let id = Uuid::parse_str(&id_str).map_err(|e| {
+                        rusqlite::Error::FromSqlConversionFailure(
+                            0,
+                            rusqlite::types::Type::Text,
+                            Box::new(e),
+                        )
+                    })?;
+                    // bm25() is negative, lower being more relevant
+                    let relevance = (-rank).max(0.0) as f32;
+
+                    Ok(SearchResult::new(
+                        id,
+                        row.get(1)?,
+                        row.get(2)?,
+                        row.get(3)?,
+                        row.get(4)?,
+                        ChunkType::parse(&chunk_type_str).unwrap_or(ChunkType::Code),
+                        row.get(6)?,
+                        relevance / (1.0 + relevance),
+                    )
+                    .with_summary(row.get(7)?)
+                    .with_public_api(row.get(8)?)
+                    .with_symbols(Self::json_list(row, 9)?))
+                },
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(results)
+    }
+
+    /// Semantic and keyword results for `query` merged by reciprocal rank
+    /// fusion. `query_embedding` must have been created from `query` with
+    /// `model`, as for [`VectorStore::search_similar_in_branch`].
+    pub fn search_hybrid(
+        &self,
+        query: &str,
+        query_embedding: &[f32],
+        model: &str,
+        limit: usize,
+        branch: Option<&str>,
+        root: Option<&str>,
+    ) -> WikiResult<Vec<SearchResult>> {
+        self.search_hybrid_scoped(
+            query,
+            query_embedding,
+            model,
+            limit,
+            branch,
+            root,
+            &SearchScope::default(),
+        )
+    }
+
+    /// Like [`Self::search_hybrid`], only considering chunks whose path and
+    /// language fall in `scope`
+    #[allow(clippy::too_many_arguments)]
+    pub fn search_hybrid_scoped(
+        &self,
+        query: &str,
+        query_embedding: &[f32],
+        model: &str,
+        limit: usize,
+        branch: Option<&str>,
+        root: Option<&str>,
+        scope: &SearchScope,
+    ) -> WikiResult<Vec<SearchResult>> {
+        let candidates = limit * HYBRID_CANDIDATES_PER_RESULT;
+        let semantic =
+            self.search_similar_scoped(query_embedding, model, candidates, branch, root, scope)?;
+        let keyword = self.search_keyword_scoped(query, candidates, branch, root, scope)?;
+        Ok(reciprocal_rank_fusion(vec![semantic, keyword], limit))
+    }
+}
+
+/// FTS5 query matching any of the terms of `query`, each quoted so
+/// operators and punctuation in it are taken literally; `None` when no term
+/// contains a letter or digit
+fn fts_match_expression(query: &str) -> Option<String> {
+    let terms: Vec<String> = query
+        .split_whitespace()
+        .filter(|term| term.chars().any(char::is_alphanumeric))
+        .map(|term| format!("\"{}\"", term.replace('"', "")))
+        .collect();
+
+    (!terms.is_empty()).then(|| terms.join(" OR "))
+}
+
+/// Merge rankings, scoring each result by the sum of `1 / (RRF_K + rank)`
+/// over the rankings it appears in. Scores are scaled so a result ranked
+/// first everywhere scores 1.0.
+fn reciprocal_rank_fusion(rankings: Vec<Vec<SearchResult>>, limit: usize) -> Vec<SearchResult> {
+    let best = rankings.len() as f32 / (RRF_K + 1.0);
+    let mut fused: HashMap<Uuid, SearchResult> = HashMap::new();
+
+    for ranking in rankings {
+        for (index, mut result) in ranking.into_iter().enumerate() {
+            let contribution = 1.0 / (RRF_K + index as f32 + 1.0);
+            fused
+                .entry(result.chunk_id)
+                .and_modify(|r| r.score += contribution)
+                .or_insert_with(|| {
+                    result.score = contribution;
+                    result
+                });
+        }
+    }
+
+    let mut results: Vec<SearchResult> = fused
+        .into_values()
+        .map(|mut result| {
+            result.score /= best;
+            result
+        })
+        .collect();
+    results.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.location().cmp(&b.location()))
+    });
+    results.truncate(limit);
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::chunk::CodeChunk;
+    use tempfile::tempdir;
+
+    fn chunk(path: &str, content: &str) -> CodeChunk {
+        CodeChunk::new(
+            "main".to_string(),
+            path.to_string(),
+            1,
+            10,
+            content.to_string(),
+            ChunkType::Function,
+            Some("rust".to_string()),
+            10,
+            0,
+            "abc".to_string(),
+        )
+    }
+
+    fn result(id: Uuid, path: &str) -> SearchResult {
+        SearchResult::new(
+            id,
+            path.to_string(),
+            1,
+            1,
+            String::new(),
+            ChunkType::Code,
+            None,
+            0.5,
+        )
+    }
+
+    #[test]
+    fn test_fts_match_expression() {
+        assert_eq!(
+            fts_match_expression("insert_embeddings_batch vector-store").as_deref(),
+            Some("\"insert_embeddings_batch\" OR \"vector-store\"")
+        );
+        assert_eq!(
+            fts_match_expression("say \"hi\"").as_deref(),
+            Some("\"say\" OR \"hi\"")
+        );
+        assert_eq!(fts_match_expression(" -- * "), None);
+    }
+
+    #[test]
+    fn test_keyword_search_finds_exact_identifier() {
+        let dir = tempdir().unwrap();
+        let store = VectorStore::new(&dir.path().join("test.db")).unwrap();
+
+        let batch = chunk(
+            "src/vector_store.rs",
+            "pub fn insert_embeddings_batch(&self) {}",
+        );
+        let single = chunk("src/vector_store.rs", "pub fn insert_embedding(&self) {}");
+        store.insert_chunks_batch(&[batch.clone(), single]).unwrap();
+
+        let results = store
+            .search_keyword("insert_embeddings_batch", 10, Some("main"), None)
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].chunk_id, batch.id);
+        assert!(results[0].score > 0.0 && results[0].score < 1.0);
+
+        // Replacing a chunk replaces its keyword entry
+        let mut renamed = batch.clone();
+        renamed.content = "pub fn upsert_vectors(&self) {}".to_string();
+        store.insert_chunk(&renamed).unwrap();
+        assert!(store
+            .search_keyword("insert_embeddings_batch", 10, None, None)
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            store
+                .search_keyword("upsert_vectors", 10, None, None)
+                .unwrap()
+                .len(),
+            1
+        );
+
+        assert!(store
+            .search_keyword("upsert_vectors", 10, Some("develop"), None)
+            .unwrap()
+            .is_empty());
+
+        store.clear_branch("main").unwrap();
+        assert!(store
+            .search_keyword("insert_embedding", 10, None, None)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_reciprocal_rank_fusion() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let semantic = vec![result(a, "a.rs"), result(b, "b.rs")];
+        let keyword = vec![result(b, "b.rs"), result(c, "c.rs")];
+
+        let fused = reciprocal_rank_fusion(vec![semantic, keyword], 10);
+        let order: Vec<Uuid> = fused.iter().map(|r| r.chunk_id).collect();
+        // b appears in both rankings, so it beats a's single first place
+        assert_eq!(order, vec![b, a, c]);
+        assert!(fused[0].score <= 1.0);
+
+        let ranked_first_everywhere =
+            reciprocal_rank_fusion(vec![vec![result(a, "a.rs")], vec![result(a, "a.rs")]], 10);
+        assert!((ranked_first_everywhere[0].score - 1.0).abs() < 1e-6);
+
+        assert_eq!(
+            reciprocal_rank_fusion(vec![vec![result(a, "a.rs"), result(b, "b.rs")]], 1).len(),
+            1
+        );
+    }
+}
@@ -1,24 +1,47 @@
 //! Vector store using SQLite + sqlite-vec for similarity search
 
+mod branch_locks;
+mod change_summaries;
+mod embedding_indexes;
+mod feedback;
+mod history;
+mod keyword;
+mod recall;
+
 use std::path::Path;
 use std::sync::Once;
+use std::time::Duration;
 
-use rusqlite::{ffi::sqlite3_auto_extension, params, Connection};
+use rusqlite::{ffi::sqlite3_auto_extension, params, Connection, OpenFlags, OptionalExtension};
 use tracing::{debug, info};
 use uuid::Uuid;
 
 use crate::domain::{
-    chunk::{ChunkType, CodeChunk},
+    chunk::{ChunkType, CodeChunk, IndexedFile},
     index_status::{IndexState, IndexStatus},
-    search_result::SearchResult,
+    search_result::{SearchResult, SearchScope},
     wiki_page::{Importance, PageType, SourceCitation, WikiPage, WikiStructure, WikiTree},
     wiki_section::WikiSection,
 };
 use crate::error::{WikiError, WikiResult};
+use crate::indexer::normalize_root;
+
+pub use branch_locks::BranchLockGuard;
 
-/// Embedding dimension for text-embedding-3-small
+/// Embedding dimension for text-embedding-3-small, the dimension of the
+/// vector table created before embedding models were tracked. Other models
+/// get the dimension configured with [`VectorStore::with_embedding_dimension`]
+/// or that of their first embedding.
 pub const EMBEDDING_DIMENSION: usize = 1536;
 
+/// Version of the schema [`VectorStore::new`] creates, kept in the
+/// database's `user_version`. Bump it whenever the schema changes so
+/// read-only stores know to migrate the database first.
+const SCHEMA_VERSION: i64 = 2;
+
+/// How long a read-only store waits for the indexer to finish a write
+const READ_ONLY_BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
 static SQLITE_VEC_INIT: Once = Once::new();
 
 fn init_sqlite_vec_extension() {
@@ -62,6 +85,60 @@ impl VectorStore {
         Ok(store)
     }
 
+    /// Open an existing database for reads only, for readers such as the
+    /// MCP wiki server that should not contend with the indexer for locks
+    ///
+    /// The schema is neither created nor migrated, so writes fail. A missing
+    /// database opens as an empty in-memory store, and one whose schema is
+    /// older than this build is migrated by opening it with [`Self::new`]
+    /// once. The database is not opened `immutable` or `nolock`: the
+    /// indexer may write to it while it is open.
+    pub fn open_read_only(db_path: &Path) -> WikiResult<Self> {
+        init_sqlite_vec_extension();
+
+        if !db_path.exists() {
+            debug!("No wiki database at {:?}, reading an empty store", db_path);
+            let store = Self {
+                conn: Connection::open_in_memory()?,
+            };
+            store.init_schema()?;
+            return Ok(store);
+        }
+
+        let conn = Connection::open_with_flags(
+            db_path,
+            OpenFlags::SQLITE_OPEN_READ_ONLY
+                | OpenFlags::SQLITE_OPEN_NO_MUTEX
+                | OpenFlags::SQLITE_OPEN_URI,
+        )?;
+        conn.busy_timeout(READ_ONLY_BUSY_TIMEOUT)?;
+
+        let version: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+        if version < SCHEMA_VERSION {
+            drop(conn);
+            info!(
+                "Migrating wiki database {:?} from schema version {} before reading",
+                db_path, version
+            );
+            return Self::new(db_path);
+        }
+
+        Ok(Self { conn })
+    }
+
+    /// Open the store with the vector table of `model` sized to `dimension`,
+    /// e.g. 3072 for text-embedding-3-large or the output size of a local
+    /// model. See [`Self::configure_embedding_dimension`].
+    pub fn with_embedding_dimension(
+        db_path: &Path,
+        model: &str,
+        dimension: usize,
+    ) -> WikiResult<Self> {
+        let store = Self::new(db_path)?;
+        store.configure_embedding_dimension(model, dimension)?;
+        Ok(store)
+    }
+
     /// Initialize the database schema
     fn init_schema(&self) -> WikiResult<()> {
         self.conn.execute_batch(
@@ -79,7 +156,12 @@ impl VectorStore {
                 token_count INTEGER NOT NULL,
                 chunk_index INTEGER NOT NULL,
                 commit_sha TEXT NOT NULL,
-                created_at TEXT NOT NULL
+                created_at TEXT NOT NULL,
+                summary TEXT,
+                root TEXT,
+                is_public_api INTEGER NOT NULL DEFAULT 0,
+                symbols TEXT NOT NULL DEFAULT '[]',
+                imports TEXT NOT NULL DEFAULT '[]'
             );
 
             CREATE INDEX IF NOT EXISTS idx_chunks_branch ON chunks(branch);
@@ -149,16 +231,114 @@ impl VectorStore {
             );
 
             CREATE INDEX IF NOT EXISTS idx_wiki_sections_branch ON wiki_sections(branch);
+
+            -- Vector table of each embedding model
+            CREATE TABLE IF NOT EXISTS embedding_models (
+                model TEXT PRIMARY KEY,
+                table_name TEXT NOT NULL UNIQUE,
+                dimension INTEGER NOT NULL,
+                created_at TEXT NOT NULL
+            );
+
+            -- Embedding model and index version of each branch
+            CREATE TABLE IF NOT EXISTS embedding_indexes (
+                branch TEXT NOT NULL,
+                model TEXT NOT NULL,
+                version INTEGER NOT NULL,
+                state TEXT NOT NULL,
+                updated_at TEXT NOT NULL,
+                PRIMARY KEY (branch, model)
+            );
             "#,
         )?;
 
+        self.migrate_chunks_columns()?;
         self.migrate_index_status_columns()?;
         self.migrate_wiki_pages_columns()?;
+        self.migrate_wiki_structure_columns()?;
+        self.migrate_embedding_indexes()?;
+        self.init_recall_schema()?;
+        self.init_feedback_schema()?;
+        self.init_change_summary_schema()?;
+        self.init_branch_lock_schema()?;
+        self.init_history_schema()?;
+        self.init_keyword_schema()?;
+
+        let version: i64 = self
+            .conn
+            .query_row("PRAGMA user_version", [], |row| row.get(0))?;
+        if version < SCHEMA_VERSION {
+            self.conn
+                .execute_batch(&format!("PRAGMA user_version = {}", SCHEMA_VERSION))?;
+        }
 
         debug!("Database schema initialized");
         Ok(())
     }
 
+    fn migrate_chunks_columns(&self) -> WikiResult<()> {
+        for column in ["summary", "root"] {
+            let column_exists: bool = self.conn.query_row(
+                "SELECT COUNT(*) > 0 FROM pragma_table_info('chunks') WHERE name = ?1",
+                params![column],
+                |row| row.get(0),
+            )?;
+
+            if !column_exists {
+                self.conn.execute(
+                    &format!("ALTER TABLE chunks ADD COLUMN {} TEXT", column),
+                    [],
+                )?;
+                debug!("Added column {} to chunks table", column);
+            }
+        }
+
+        let public_api_exists: bool = self.conn.query_row(
+            "SELECT COUNT(*) > 0 FROM pragma_table_info('chunks') WHERE name = 'is_public_api'",
+            [],
+            |row| row.get(0),
+        )?;
+        if !public_api_exists {
+            self.conn.execute(
+                "ALTER TABLE chunks ADD COLUMN is_public_api INTEGER NOT NULL DEFAULT 0",
+                [],
+            )?;
+            debug!("Added column is_public_api to chunks table");
+        }
+        self.migrate_symbol_columns("chunks")?;
+
+        self.conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_chunks_root ON chunks(branch, root)",
+            [],
+        )?;
+
+        Ok(())
+    }
+
+    /// Add the JSON lists of defined symbols and imports to a table of
+    /// chunks created before chunks carried them
+    pub(super) fn migrate_symbol_columns(&self, table: &str) -> WikiResult<()> {
+        for column in ["symbols", "imports"] {
+            let column_exists: bool = self.conn.query_row(
+                "SELECT COUNT(*) > 0 FROM pragma_table_info(?1) WHERE name = ?2",
+                params![table, column],
+                |row| row.get(0),
+            )?;
+
+            if !column_exists {
+                self.conn.execute(
+                    &format!(
+                        "ALTER TABLE {} ADD COLUMN {} TEXT NOT NULL DEFAULT '[]'",
+                        table, column
+                    ),
+                    [],
+                )?;
+                debug!("Added column {} to {} table", column, table);
+            }
+        }
+        Ok(())
+    }
+
     fn migrate_index_status_columns(&self) -> WikiResult<()> {
         let columns_to_add = [
             ("page_count", "INTEGER NOT NULL DEFAULT 0"),
@@ -214,14 +394,36 @@ impl VectorStore {
         Ok(())
     }
 
+    fn migrate_wiki_structure_columns(&self) -> WikiResult<()> {
+        let column_exists: bool = self.conn.query_row(
+            "SELECT COUNT(*) > 0 FROM pragma_table_info('wiki_structure') WHERE name = 'skipped_pages'",
+            [],
+            |row| row.get(0),
+        )?;
+
+        if !column_exists {
+            self.conn.execute(
+                "ALTER TABLE wiki_structure ADD COLUMN skipped_pages TEXT NOT NULL DEFAULT '[]'",
+                [],
+            )?;
+            debug!("Added column skipped_pages to wiki_structure table");
+        }
+
+        Ok(())
+    }
+
     /// Insert a code chunk
     pub fn insert_chunk(&self, chunk: &CodeChunk) -> WikiResult<()> {
+        self.remove_keyword_entry(&chunk.id)?;
+        let symbols_json = serde_json::to_string(&chunk.symbols)?;
+        let imports_json = serde_json::to_string(&chunk.imports)?;
         self.conn.execute(
             r#"
             INSERT OR REPLACE INTO chunks 
             (id, branch, file_path, start_line, end_line, content, chunk_type, 
-             language, token_count, chunk_index, commit_sha, created_at)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
+             language, token_count, chunk_index, commit_sha, created_at, summary, root,
+             is_public_api, symbols, imports)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)
             "#,
             params![
                 chunk.id.to_string(),
@@ -236,23 +438,32 @@ impl VectorStore {
                 chunk.chunk_index,
                 chunk.commit_sha,
                 chunk.created_at.to_rfc3339(),
+                chunk.summary,
+                chunk.root,
+                chunk.is_public_api,
+                symbols_json,
+                imports_json,
             ],
         )?;
+        self.add_keyword_entry(&chunk.id)?;
         Ok(())
     }
 
-    pub fn insert_embedding(&self, chunk_id: &Uuid, embedding: &[f32]) -> WikiResult<()> {
-        if embedding.len() != EMBEDDING_DIMENSION {
-            return Err(WikiError::DimensionMismatch {
-                expected: EMBEDDING_DIMENSION,
-                actual: embedding.len(),
-            });
-        }
-
+    /// Store the `model` embedding of a chunk
+    pub fn insert_embedding(
+        &self,
+        model: &str,
+        chunk_id: &Uuid,
+        embedding: &[f32],
+    ) -> WikiResult<()> {
+        let table_name = self.ensure_embedding_table(model, embedding.len())?;
         let embedding_bytes: Vec<u8> = embedding.iter().flat_map(|f| f.to_le_bytes()).collect();
 
         self.conn.execute(
-            "INSERT OR REPLACE INTO chunk_embeddings (chunk_id, embedding) VALUES (?1, ?2)",
+            &format!(
+                "INSERT OR REPLACE INTO {} (chunk_id, embedding) VALUES (?1, ?2)",
+                table_name
+            ),
             params![chunk_id.to_string(), embedding_bytes],
         )?;
         Ok(())
@@ -267,12 +478,16 @@ impl VectorStore {
             r#"
             INSERT OR REPLACE INTO chunks 
             (id, branch, file_path, start_line, end_line, content, chunk_type, 
-             language, token_count, chunk_index, commit_sha, created_at)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
+             language, token_count, chunk_index, commit_sha, created_at, summary, root,
+             is_public_api, symbols, imports)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)
             "#,
         )?;
 
         for chunk in chunks {
+            self.remove_keyword_entry(&chunk.id)?;
+            let symbols_json = serde_json::to_string(&chunk.symbols)?;
+            let imports_json = serde_json::to_string(&chunk.imports)?;
             stmt.execute(params![
                 chunk.id.to_string(),
                 chunk.branch,
@@ -286,14 +501,22 @@ impl VectorStore {
                 chunk.chunk_index,
                 chunk.commit_sha,
                 chunk.created_at.to_rfc3339(),
+                chunk.summary,
+                chunk.root,
+                chunk.is_public_api,
+                symbols_json,
+                imports_json,
             ])?;
+            self.add_keyword_entry(&chunk.id)?;
         }
 
         Ok(())
     }
 
+    /// Store the `model` embeddings of a batch of chunks
     pub fn insert_embeddings_batch(
         &self,
+        model: &str,
         chunk_ids: &[Uuid],
         embeddings: &[Vec<f32>],
     ) -> WikiResult<()> {
@@ -309,14 +532,17 @@ impl VectorStore {
             return Ok(());
         }
 
-        let mut stmt = self.conn.prepare_cached(
-            "INSERT OR REPLACE INTO chunk_embeddings (chunk_id, embedding) VALUES (?1, ?2)",
-        )?;
+        let dimension = embeddings[0].len();
+        let table_name = self.ensure_embedding_table(model, dimension)?;
+        let mut stmt = self.conn.prepare_cached(&format!(
+            "INSERT OR REPLACE INTO {} (chunk_id, embedding) VALUES (?1, ?2)",
+            table_name
+        ))?;
 
         for (chunk_id, embedding) in chunk_ids.iter().zip(embeddings.iter()) {
-            if embedding.len() != EMBEDDING_DIMENSION {
+            if embedding.len() != dimension {
                 return Err(WikiError::DimensionMismatch {
-                    expected: EMBEDDING_DIMENSION,
+                    expected: dimension,
                     actual: embedding.len(),
                 });
             }
@@ -329,23 +555,60 @@ impl VectorStore {
         Ok(())
     }
 
+    /// Search all branches indexed with `model` for the chunks closest to
+    /// `query_embedding`, which must have been created with `model`
     pub fn search_similar(
         &self,
         query_embedding: &[f32],
+        model: &str,
         limit: usize,
     ) -> WikiResult<Vec<SearchResult>> {
-        self.search_similar_in_branch(query_embedding, limit, None)
+        self.search_similar_in_branch(query_embedding, model, limit, None, None)
     }
 
+    /// Search for the chunks closest to `query_embedding`, which must have
+    /// been created with `model`. Fails with
+    /// [`WikiError::EmbeddingModelMismatch`] rather than comparing vectors
+    /// from different models. Only chunks of the workspace `root` are
+    /// searched if it is set.
     pub fn search_similar_in_branch(
         &self,
         query_embedding: &[f32],
+        model: &str,
+        limit: usize,
+        branch: Option<&str>,
+        root: Option<&str>,
+    ) -> WikiResult<Vec<SearchResult>> {
+        self.search_similar_scoped(
+            query_embedding,
+            model,
+            limit,
+            branch,
+            root,
+            &SearchScope::default(),
+        )
+    }
+
+    /// Like [`Self::search_similar_in_branch`], only considering chunks
+    /// whose path and language fall in `scope`
+    pub fn search_similar_scoped(
+        &self,
+        query_embedding: &[f32],
+        model: &str,
         limit: usize,
         branch: Option<&str>,
+        root: Option<&str>,
+        scope: &SearchScope,
     ) -> WikiResult<Vec<SearchResult>> {
-        if query_embedding.len() != EMBEDDING_DIMENSION {
+        self.ensure_searchable(model, branch)?;
+        let root = root.map(normalize_root);
+
+        let Some((table_name, dimension)) = self.embedding_table(model)? else {
+            return Ok(Vec::new());
+        };
+        if query_embedding.len() != dimension {
             return Err(WikiError::DimensionMismatch {
-                expected: EMBEDDING_DIMENSION,
+                expected: dimension,
                 actual: query_embedding.len(),
             });
         }
@@ -355,78 +618,262 @@ impl VectorStore {
             .flat_map(|f| f.to_le_bytes())
             .collect();
 
-        let (sql, use_branch_filter) = if branch.is_some() {
-            (
-                r#"
-                SELECT 
-                    c.id, c.file_path, c.start_line, c.end_line, c.content,
-                    c.chunk_type, c.language,
-                    vec_distance_cosine(e.embedding, ?1) as distance
-                FROM chunk_embeddings e
-                JOIN chunks c ON c.id = e.chunk_id
-                WHERE c.branch = ?3
-                ORDER BY distance ASC
-                LIMIT ?2
-                "#,
-                true,
-            )
-        } else {
-            (
+        let sql = format!(
+            r#"
+            SELECT 
+                c.id, c.file_path, c.start_line, c.end_line, c.content,
+                c.chunk_type, c.language, c.summary, c.is_public_api, c.symbols,
+                vec_distance_cosine(e.embedding, ?1) as distance
+            FROM {} e
+            JOIN chunks c ON c.id = e.chunk_id
+            JOIN embedding_indexes i
+                ON i.branch = c.branch AND i.model = ?3 AND i.state = 'active'
+            WHERE (?4 IS NULL OR c.branch = ?4) AND (?5 IS NULL OR c.root = ?5)
+                AND (?6 IS NULL OR c.file_path GLOB ?6)
+                AND (?7 IS NULL OR lower(c.language) = ?7)
+            ORDER BY distance ASC
+            LIMIT ?2
+            "#,
+            table_name
+        );
+
+        let mut stmt = self.conn.prepare(&sql)?;
+
+        let results = stmt
+            .query_map(
+                params![
+                    embedding_bytes,
+                    limit as i64,
+                    model,
+                    branch,
+                    root,
+                    scope.sql_glob(),
+                    scope.sql_language()
+                ],
+                Self::row_to_search_result,
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(results)
+    }
+
+    /// A semantic search hit from a row of id, file path, lines, content,
+    /// chunk type, language, summary, public API tag, symbols and distance
+    fn row_to_search_result(row: &rusqlite::Row) -> rusqlite::Result<SearchResult> {
+        let id_str: String = row.get(0)?;
+        let file_path: String = row.get(1)?;
+        let start_line: u32 = row.get(2)?;
+        let end_line: u32 = row.get(3)?;
+        let content: String = row.get(4)?;
+        let chunk_type_str: String = row.get(5)?;
+        let language: Option<String> = row.get(6)?;
+        let summary: Option<String> = row.get(7)?;
+        let is_public_api: bool = row.get(8)?;
+        let symbols = Self::json_list(row, 9)?;
+        let distance: f32 = row.get(10)?;
+
+        let score = 1.0 - distance;
+
+        let id = Uuid::parse_str(&id_str).map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(0, rusqlite::types::Type::Text, Box::new(e))
+        })?;
+
+        let chunk_type = ChunkType::parse(&chunk_type_str).unwrap_or(ChunkType::Code);
+
+        Ok(SearchResult::new(
+            id, file_path, start_line, end_line, content, chunk_type, language, score,
+        )
+        .with_summary(summary)
+        .with_public_api(is_public_api)
+        .with_symbols(symbols))
+    }
+
+    /// A JSON list of strings stored in column `idx`
+    pub(super) fn json_list(row: &rusqlite::Row, idx: usize) -> rusqlite::Result<Vec<String>> {
+        let json: String = row.get(idx)?;
+        serde_json::from_str(&json).map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(idx, rusqlite::types::Type::Text, Box::new(e))
+        })
+    }
+
+    /// Find chunks whose content mentions `symbol` (case-sensitive substring match).
+    ///
+    /// Chunks that define `symbol` come first, so a small `limit` still finds
+    /// the definition. Callers are expected to refine the candidates, e.g. with
+    /// `crate::symbols`.
+    pub fn find_chunks_mentioning(
+        &self,
+        symbol: &str,
+        branch: Option<&str>,
+        limit: usize,
+    ) -> WikiResult<Vec<CodeChunk>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT id, branch, file_path, start_line, end_line, content, chunk_type,
+                   language, token_count, chunk_index, commit_sha, created_at, summary, root,
+                   is_public_api, symbols, imports
+            FROM chunks
+            WHERE instr(content, ?1) > 0 AND (?2 IS NULL OR branch = ?2)
+            ORDER BY instr(symbols, '"' || ?1 || '"') > 0 DESC, file_path, chunk_index
+            LIMIT ?3
+            "#,
+        )?;
+
+        let chunks = stmt
+            .query_map(params![symbol, branch, limit as i64], Self::row_to_chunk)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(chunks)
+    }
+
+    /// Get all chunks of a branch, ordered by file and position
+    pub fn get_branch_chunks(&self, branch: &str) -> WikiResult<Vec<CodeChunk>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT id, branch, file_path, start_line, end_line, content, chunk_type,
+                   language, token_count, chunk_index, commit_sha, created_at, summary, root,
+                   is_public_api, symbols, imports
+            FROM chunks
+            WHERE branch = ?1
+            ORDER BY file_path, chunk_index
+            "#,
+        )?;
+
+        let chunks = stmt
+            .query_map(params![branch], Self::row_to_chunk)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(chunks)
+    }
+
+    /// Get a single chunk by id
+    pub fn get_chunk(&self, id: Uuid) -> WikiResult<Option<CodeChunk>> {
+        let chunk = self
+            .conn
+            .query_row(
                 r#"
-                SELECT 
-                    c.id, c.file_path, c.start_line, c.end_line, c.content,
-                    c.chunk_type, c.language,
-                    vec_distance_cosine(e.embedding, ?1) as distance
-                FROM chunk_embeddings e
-                JOIN chunks c ON c.id = e.chunk_id
-                ORDER BY distance ASC
-                LIMIT ?2
+                SELECT id, branch, file_path, start_line, end_line, content, chunk_type,
+                       language, token_count, chunk_index, commit_sha, created_at, summary, root,
+                       is_public_api, symbols, imports
+                FROM chunks
+                WHERE id = ?1
                 "#,
-                false,
+                params![id.to_string()],
+                Self::row_to_chunk,
             )
-        };
-
-        let mut stmt = self.conn.prepare(sql)?;
+            .optional()?;
 
-        let row_mapper = |row: &rusqlite::Row| {
-            let id_str: String = row.get(0)?;
-            let file_path: String = row.get(1)?;
-            let start_line: u32 = row.get(2)?;
-            let end_line: u32 = row.get(3)?;
-            let content: String = row.get(4)?;
-            let chunk_type_str: String = row.get(5)?;
-            let language: Option<String> = row.get(6)?;
-            let distance: f32 = row.get(7)?;
+        Ok(chunk)
+    }
 
-            let score = 1.0 - distance;
+    /// Get all chunks of one file in a branch, ordered by chunk index
+    pub fn get_chunks_for_file(&self, branch: &str, path: &str) -> WikiResult<Vec<CodeChunk>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT id, branch, file_path, start_line, end_line, content, chunk_type,
+                   language, token_count, chunk_index, commit_sha, created_at, summary, root,
+                   is_public_api, symbols, imports
+            FROM chunks
+            WHERE branch = ?1 AND file_path = ?2
+            ORDER BY chunk_index
+            "#,
+        )?;
 
-            let id = Uuid::parse_str(&id_str).map_err(|e| {
-                rusqlite::Error::FromSqlConversionFailure(
-                    0,
-                    rusqlite::types::Type::Text,
-                    Box::new(e),
-                )
-            })?;
+        let chunks = stmt
+            .query_map(params![branch, path], Self::row_to_chunk)?
+            .collect::<Result<Vec<_>, _>>()?;
 
-            let chunk_type = ChunkType::parse(&chunk_type_str).unwrap_or(ChunkType::Code);
+        Ok(chunks)
+    }
 
-            Ok(SearchResult::new(
-                id, file_path, start_line, end_line, content, chunk_type, language, score,
-            ))
-        };
+    /// List the indexed files of a branch with their chunk counts, ordered by
+    /// path. Only files under `path_prefix` are listed if it is set.
+    pub fn list_indexed_files(
+        &self,
+        branch: &str,
+        path_prefix: Option<&str>,
+        limit: usize,
+        offset: usize,
+    ) -> WikiResult<Vec<IndexedFile>> {
+        // SQLite takes the bare columns from the row with MAX(created_at), so
+        // commit_sha is the one of the most recently indexed chunk
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT file_path, COUNT(*), commit_sha, MAX(created_at)
+            FROM chunks
+            WHERE branch = ?1 AND (?2 IS NULL OR substr(file_path, 1, length(?2)) = ?2)
+            GROUP BY file_path
+            ORDER BY file_path
+            LIMIT ?3 OFFSET ?4
+            "#,
+        )?;
 
-        let results = if use_branch_filter {
-            stmt.query_map(
-                params![embedding_bytes, limit as i64, branch.unwrap()],
-                row_mapper,
+        let files = stmt
+            .query_map(
+                params![branch, path_prefix, limit as i64, offset as i64],
+                |row| {
+                    let indexed_str: String = row.get(3)?;
+                    Ok(IndexedFile {
+                        file_path: row.get(0)?,
+                        chunk_count: row.get(1)?,
+                        commit_sha: row.get(2)?,
+                        indexed_at: chrono::DateTime::parse_from_rfc3339(&indexed_str)
+                            .map(|dt| dt.with_timezone(&chrono::Utc))
+                            .unwrap_or_else(|_| chrono::Utc::now()),
+                    })
+                },
             )?
-            .collect::<Result<Vec<_>, _>>()?
-        } else {
-            stmt.query_map(params![embedding_bytes, limit as i64], row_mapper)?
-                .collect::<Result<Vec<_>, _>>()?
-        };
+            .collect::<Result<Vec<_>, _>>()?;
 
-        Ok(results)
+        Ok(files)
+    }
+
+    /// Count the indexed files of a branch, optionally only under `path_prefix`
+    pub fn count_indexed_files(&self, branch: &str, path_prefix: Option<&str>) -> WikiResult<u32> {
+        let count: u32 = self.conn.query_row(
+            r#"
+            SELECT COUNT(DISTINCT file_path)
+            FROM chunks
+            WHERE branch = ?1 AND (?2 IS NULL OR substr(file_path, 1, length(?2)) = ?2)
+            "#,
+            params![branch, path_prefix],
+            |row| row.get(0),
+        )?;
+        Ok(count)
+    }
+
+    fn row_to_chunk(row: &rusqlite::Row) -> rusqlite::Result<CodeChunk> {
+        let id_str: String = row.get(0)?;
+        let chunk_type_str: String = row.get(6)?;
+        let created_str: String = row.get(11)?;
+
+        let id = Uuid::parse_str(&id_str).map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(0, rusqlite::types::Type::Text, Box::new(e))
+        })?;
+
+        let created_at = chrono::DateTime::parse_from_rfc3339(&created_str)
+            .map(|dt| dt.with_timezone(&chrono::Utc))
+            .unwrap_or_else(|_| chrono::Utc::now());
+
+        Ok(CodeChunk {
+            id,
+            branch: row.get(1)?,
+            file_path: row.get(2)?,
+            start_line: row.get(3)?,
+            end_line: row.get(4)?,
+            content: row.get(5)?,
+            chunk_type: ChunkType::parse(&chunk_type_str).unwrap_or(ChunkType::Code),
+            language: row.get(7)?,
+            token_count: row.get(8)?,
+            chunk_index: row.get(9)?,
+            commit_sha: row.get(10)?,
+            created_at,
+            summary: row.get(12)?,
+            root: row.get(13)?,
+            is_public_api: row.get(14)?,
+            symbols: Self::json_list(row, 15)?,
+            imports: Self::json_list(row, 16)?,
+        })
     }
 
     pub fn get_index_status(&self, branch: &str) -> WikiResult<Option<IndexStatus>> {
@@ -439,26 +886,7 @@ impl VectorStore {
             "#,
         )?;
 
-        let result = stmt.query_row(params![branch], |row| {
-            let state_str: String = row.get(1)?;
-            let last_indexed_str: Option<String> = row.get(6)?;
-
-            Ok(IndexStatus {
-                branch: row.get(0)?,
-                state: IndexState::parse(&state_str).unwrap_or(IndexState::NotIndexed),
-                last_commit_sha: row.get(2)?,
-                file_count: row.get(3)?,
-                chunk_count: row.get(4)?,
-                page_count: row.get(5)?,
-                last_indexed_at: last_indexed_str
-                    .and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok())
-                    .map(|dt| dt.with_timezone(&chrono::Utc)),
-                error_message: row.get(7)?,
-                progress_percent: row.get(8)?,
-                current_phase: row.get(9)?,
-                current_item: row.get(10)?,
-            })
-        });
+        let result = stmt.query_row(params![branch], Self::row_to_index_status);
 
         match result {
             Ok(status) => Ok(Some(status)),
@@ -467,6 +895,45 @@ impl VectorStore {
         }
     }
 
+    /// Index status of every branch that was ever indexed, ordered by branch
+    pub fn get_index_statuses(&self) -> WikiResult<Vec<IndexStatus>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT branch, state, last_commit_sha, file_count, chunk_count, page_count,
+                   last_indexed_at, error_message, progress_percent, current_phase, current_item
+            FROM index_status
+            ORDER BY branch
+            "#,
+        )?;
+
+        let statuses = stmt
+            .query_map([], Self::row_to_index_status)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(statuses)
+    }
+
+    fn row_to_index_status(row: &rusqlite::Row) -> rusqlite::Result<IndexStatus> {
+        let state_str: String = row.get(1)?;
+        let last_indexed_str: Option<String> = row.get(6)?;
+
+        Ok(IndexStatus {
+            branch: row.get(0)?,
+            state: IndexState::parse(&state_str).unwrap_or(IndexState::NotIndexed),
+            last_commit_sha: row.get(2)?,
+            file_count: row.get(3)?,
+            chunk_count: row.get(4)?,
+            page_count: row.get(5)?,
+            last_indexed_at: last_indexed_str
+                .and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok())
+                .map(|dt| dt.with_timezone(&chrono::Utc)),
+            error_message: row.get(7)?,
+            progress_percent: row.get(8)?,
+            current_phase: row.get(9)?,
+            current_item: row.get(10)?,
+        })
+    }
+
     pub fn update_index_status(&self, status: &IndexStatus) -> WikiResult<()> {
         self.conn.execute(
             r#"
@@ -565,105 +1032,126 @@ impl VectorStore {
 
         let mut stmt = self.conn.prepare(sql)?;
 
-        let row_mapper = |row: &rusqlite::Row| {
-            let id_str: String = row.get(0)?;
-            let page_type_str: String = row.get(5)?;
-            let file_paths_json: String = row.get(8)?;
-            let created_str: String = row.get(11)?;
-            let updated_str: String = row.get(12)?;
+        let result = if use_branch {
+            stmt.query_row(params![slug, branch.unwrap()], Self::row_to_wiki_page)
+        } else {
+            stmt.query_row(params![slug], Self::row_to_wiki_page)
+        };
+
+        match result {
+            Ok(page) => Ok(Some(page)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Get all wiki pages of a branch, in page order
+    pub fn get_branch_pages(&self, branch: &str) -> WikiResult<Vec<WikiPage>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT id, branch, slug, title, content, page_type, parent_slug,
+                   page_order, file_paths, has_diagrams, commit_sha, created_at, updated_at,
+                   importance, related_pages, section_id, source_citations
+            FROM wiki_pages
+            WHERE branch = ?1
+            ORDER BY page_order, slug
+            "#,
+        )?;
 
-            let importance_str: Option<String> = row.get(13)?;
-            let related_pages_json: Option<String> = row.get(14)?;
-            let section_id: Option<String> = row.get(15)?;
-            let source_citations_json: Option<String> = row.get(16)?;
+        let pages = stmt
+            .query_map(params![branch], Self::row_to_wiki_page)?
+            .collect::<Result<Vec<_>, _>>()?;
 
-            let id = Uuid::parse_str(&id_str).map_err(|e| {
+        Ok(pages)
+    }
+
+    fn row_to_wiki_page(row: &rusqlite::Row) -> rusqlite::Result<WikiPage> {
+        let id_str: String = row.get(0)?;
+        let page_type_str: String = row.get(5)?;
+        let file_paths_json: String = row.get(8)?;
+        let created_str: String = row.get(11)?;
+        let updated_str: String = row.get(12)?;
+
+        let importance_str: Option<String> = row.get(13)?;
+        let related_pages_json: Option<String> = row.get(14)?;
+        let section_id: Option<String> = row.get(15)?;
+        let source_citations_json: Option<String> = row.get(16)?;
+
+        let id = Uuid::parse_str(&id_str).map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(0, rusqlite::types::Type::Text, Box::new(e))
+        })?;
+
+        let file_paths: Vec<String> = serde_json::from_str(&file_paths_json).map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(8, rusqlite::types::Type::Text, Box::new(e))
+        })?;
+
+        let created_at = chrono::DateTime::parse_from_rfc3339(&created_str)
+            .map(|dt| dt.with_timezone(&chrono::Utc))
+            .map_err(|e| {
                 rusqlite::Error::FromSqlConversionFailure(
-                    0,
+                    11,
                     rusqlite::types::Type::Text,
                     Box::new(e),
                 )
             })?;
 
-            let file_paths: Vec<String> = serde_json::from_str(&file_paths_json).map_err(|e| {
+        let updated_at = chrono::DateTime::parse_from_rfc3339(&updated_str)
+            .map(|dt| dt.with_timezone(&chrono::Utc))
+            .map_err(|e| {
                 rusqlite::Error::FromSqlConversionFailure(
-                    8,
+                    12,
                     rusqlite::types::Type::Text,
                     Box::new(e),
                 )
             })?;
 
-            let created_at = chrono::DateTime::parse_from_rfc3339(&created_str)
-                .map(|dt| dt.with_timezone(&chrono::Utc))
-                .map_err(|e| {
-                    rusqlite::Error::FromSqlConversionFailure(
-                        11,
-                        rusqlite::types::Type::Text,
-                        Box::new(e),
-                    )
-                })?;
-
-            let updated_at = chrono::DateTime::parse_from_rfc3339(&updated_str)
-                .map(|dt| dt.with_timezone(&chrono::Utc))
-                .map_err(|e| {
-                    rusqlite::Error::FromSqlConversionFailure(
-                        12,
-                        rusqlite::types::Type::Text,
-                        Box::new(e),
-                    )
-                })?;
-
-            let importance = importance_str
-                .and_then(|s| Importance::parse(&s))
-                .unwrap_or_default();
-
-            let related_pages: Vec<String> = related_pages_json
-                .and_then(|s| serde_json::from_str(&s).ok())
-                .unwrap_or_default();
-
-            let source_citations: Vec<SourceCitation> = source_citations_json
-                .and_then(|s| serde_json::from_str(&s).ok())
-                .unwrap_or_default();
-
-            Ok(WikiPage {
-                id,
-                branch: row.get(1)?,
-                slug: row.get(2)?,
-                title: row.get(3)?,
-                content: row.get(4)?,
-                page_type: PageType::parse(&page_type_str).unwrap_or(PageType::Custom),
-                parent_slug: row.get(6)?,
-                order: row.get(7)?,
-                file_paths,
-                has_diagrams: row.get(9)?,
-                commit_sha: row.get(10)?,
-                created_at,
-                updated_at,
-                importance,
-                related_pages,
-                section_id,
-                source_citations,
-            })
-        };
-
-        let result = if use_branch {
-            stmt.query_row(params![slug, branch.unwrap()], row_mapper)
-        } else {
-            stmt.query_row(params![slug], row_mapper)
-        };
+        let importance = importance_str
+            .and_then(|s| Importance::parse(&s))
+            .unwrap_or_default();
+
+        let related_pages: Vec<String> = related_pages_json
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+
+        let source_citations: Vec<SourceCitation> = source_citations_json
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+
+        Ok(WikiPage {
+            id,
+            branch: row.get(1)?,
+            slug: row.get(2)?,
+            title: row.get(3)?,
+            content: row.get(4)?,
+            page_type: PageType::parse(&page_type_str).unwrap_or(PageType::Custom),
+            parent_slug: row.get(6)?,
+            order: row.get(7)?,
+            file_paths,
+            has_diagrams: row.get(9)?,
+            commit_sha: row.get(10)?,
+            created_at,
+            updated_at,
+            importance,
+            related_pages,
+            section_id,
+            source_citations,
+        })
+    }
 
-        match result {
-            Ok(page) => Ok(Some(page)),
-            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
-            Err(e) => Err(e.into()),
-        }
+    /// Text of every stored page, across branches
+    pub fn get_all_page_contents(&self) -> WikiResult<Vec<String>> {
+        let mut stmt = self.conn.prepare("SELECT content FROM wiki_pages")?;
+        let contents = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(contents)
     }
 
     /// Get wiki structure for a branch
     pub fn get_wiki_structure(&self, branch: &str) -> WikiResult<Option<WikiStructure>> {
         let mut stmt = self.conn.prepare(
             r#"
-            SELECT structure_json, page_count, updated_at
+            SELECT structure_json, page_count, updated_at, skipped_pages
             FROM wiki_structure
             WHERE branch = ?1
             "#,
@@ -692,6 +1180,15 @@ impl VectorStore {
                     )
                 })?;
 
+            let skipped_json: String = row.get(3)?;
+            let skipped_pages = serde_json::from_str(&skipped_json).map_err(|e| {
+                rusqlite::Error::FromSqlConversionFailure(
+                    3,
+                    rusqlite::types::Type::Text,
+                    Box::new(e),
+                )
+            })?;
+
             Ok(WikiStructure {
                 branch: branch.to_string(),
                 root,
@@ -699,6 +1196,7 @@ impl VectorStore {
                 updated_at,
                 sections: Vec::new(),
                 root_section_ids: Vec::new(),
+                skipped_pages,
             })
         });
 
@@ -712,33 +1210,45 @@ impl VectorStore {
     /// Save wiki structure
     pub fn save_wiki_structure(&self, structure: &WikiStructure) -> WikiResult<()> {
         let json = serde_json::to_string(&structure.root)?;
+        let skipped_json = serde_json::to_string(&structure.skipped_pages)?;
 
         self.conn.execute(
             r#"
             INSERT OR REPLACE INTO wiki_structure 
-            (branch, structure_json, page_count, updated_at)
-            VALUES (?1, ?2, ?3, ?4)
+            (branch, structure_json, page_count, updated_at, skipped_pages)
+            VALUES (?1, ?2, ?3, ?4, ?5)
             "#,
             params![
                 structure.branch,
                 json,
                 structure.page_count,
                 structure.updated_at.to_rfc3339(),
+                skipped_json,
             ],
         )?;
         Ok(())
     }
 
     /// Delete all data for a branch (for re-indexing)
+    ///
+    /// The branch's active embedding index is kept so its version carries
+    /// over; an in-progress migration is dropped.
     pub fn clear_branch(&self, branch: &str) -> WikiResult<()> {
+        for table_name in self.embedding_table_names()? {
+            self.conn.execute(
+                &format!(
+                    "DELETE FROM {} WHERE chunk_id IN (SELECT id FROM chunks WHERE branch = ?1)",
+                    table_name
+                ),
+                params![branch],
+            )?;
+        }
         self.conn.execute(
-            r#"
-            DELETE FROM chunk_embeddings 
-            WHERE chunk_id IN (SELECT id FROM chunks WHERE branch = ?1)
-            "#,
+            "DELETE FROM embedding_indexes WHERE branch = ?1 AND state != 'active'",
             params![branch],
         )?;
 
+        self.clear_keyword_entries(branch)?;
         self.conn
             .execute("DELETE FROM chunks WHERE branch = ?1", params![branch])?;
         self.conn
@@ -884,6 +1394,19 @@ impl VectorStore {
         }
     }
 
+    /// Get the branch a chunk was indexed in
+    pub fn get_chunk_branch(&self, id: Uuid) -> WikiResult<Option<String>> {
+        let branch = self
+            .conn
+            .query_row(
+                "SELECT branch FROM chunks WHERE id = ?1",
+                params![id.to_string()],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(branch)
+    }
+
     /// Get chunk count for a branch
     pub fn get_chunk_count(&self, branch: &str) -> WikiResult<u32> {
         let count: u32 = self.conn.query_row(
@@ -908,6 +1431,8 @@ impl VectorStore {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::embedding_index::LEGACY_EMBEDDING_MODEL;
+    use crate::domain::wiki_page::{SkipReason, SkippedPage};
     use tempfile::tempdir;
 
     fn create_test_store() -> (VectorStore, tempfile::TempDir) {
@@ -917,6 +1442,49 @@ mod tests {
         (store, dir)
     }
 
+    #[test]
+    fn test_open_read_only() {
+        let (store, dir) = create_test_store();
+        let db_path = dir.path().join("test.db");
+        let mut status = IndexStatus::new("main".to_string());
+        status.state = IndexState::Indexed;
+        store.update_index_status(&status).unwrap();
+
+        let reader = VectorStore::open_read_only(&db_path).unwrap();
+        let status = reader.get_index_status("main").unwrap().unwrap();
+        assert_eq!(status.state, IndexState::Indexed);
+        assert!(reader
+            .update_index_status(&IndexStatus::new("dev".to_string()))
+            .is_err());
+    }
+
+    #[test]
+    fn test_open_read_only_missing_database() {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("missing.db");
+
+        let reader = VectorStore::open_read_only(&db_path).unwrap();
+        assert!(reader.get_index_statuses().unwrap().is_empty());
+        assert!(!db_path.exists());
+    }
+
+    #[test]
+    fn test_open_read_only_migrates_old_schema() {
+        let (store, dir) = create_test_store();
+        let db_path = dir.path().join("test.db");
+        store.conn.execute_batch("PRAGMA user_version = 0").unwrap();
+        drop(store);
+
+        let reader = VectorStore::open_read_only(&db_path).unwrap();
+        assert!(reader.get_index_statuses().unwrap().is_empty());
+
+        let conn = Connection::open(&db_path).unwrap();
+        let version: i64 = conn
+            .query_row("PRAGMA user_version", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(version, SCHEMA_VERSION);
+    }
+
     #[test]
     fn test_vector_store_creation() {
         let (store, _dir) = create_test_store();
@@ -973,6 +1541,268 @@ mod tests {
         assert_eq!(retrieved.branch, "main");
         assert_eq!(retrieved.state, IndexState::Indexing);
         assert_eq!(retrieved.file_count, 10);
+
+        store
+            .update_index_status(&IndexStatus::new("develop".to_string()))
+            .unwrap();
+        let branches: Vec<String> = store
+            .get_index_statuses()
+            .unwrap()
+            .into_iter()
+            .map(|s| s.branch)
+            .collect();
+        assert_eq!(branches, vec!["develop", "main"]);
+    }
+
+    #[test]
+    fn test_find_chunks_mentioning() {
+        let (store, _dir) = create_test_store();
+
+        let caller = CodeChunk::new(
+            "main".to_string(),
+            "src/main.rs".to_string(),
+            1,
+            5,
+            "fn main() { load_config(); }".to_string(),
+            ChunkType::Function,
+            Some("rust".to_string()),
+            8,
+            0,
+            "abc123".to_string(),
+        );
+        let other = CodeChunk::new(
+            "feature".to_string(),
+            "src/config.rs".to_string(),
+            1,
+            5,
+            "fn load_config() {}".to_string(),
+            ChunkType::Function,
+            Some("rust".to_string()),
+            6,
+            0,
+            "abc123".to_string(),
+        );
+        store.insert_chunks_batch(&[caller, other]).unwrap();
+
+        let all = store
+            .find_chunks_mentioning("load_config", None, 10)
+            .unwrap();
+        assert_eq!(all.len(), 2);
+
+        let main_only = store
+            .find_chunks_mentioning("load_config", Some("main"), 10)
+            .unwrap();
+        assert_eq!(main_only.len(), 1);
+        assert_eq!(main_only[0].file_path, "src/main.rs");
+        assert_eq!(main_only[0].chunk_type, ChunkType::Function);
+
+        let feature = store.get_branch_chunks("feature").unwrap();
+        assert_eq!(feature.len(), 1);
+        assert_eq!(feature[0].file_path, "src/config.rs");
+
+        assert!(store
+            .find_chunks_mentioning("Load_Config", None, 10)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_find_chunks_mentioning_ranks_definitions_first() {
+        let (store, _dir) = create_test_store();
+
+        let chunk = |path: &str, content: &str| {
+            CodeChunk::new(
+                "main".to_string(),
+                path.to_string(),
+                1,
+                5,
+                content.to_string(),
+                ChunkType::Function,
+                Some("rust".to_string()),
+                8,
+                0,
+                "abc123".to_string(),
+            )
+        };
+        let caller = chunk("src/a.rs", "fn a() { load_config(); }");
+        let mut definition = chunk("src/z.rs", "fn load_config() {}");
+        definition.symbols = vec!["load_config".to_string()];
+        store.insert_chunks_batch(&[caller, definition]).unwrap();
+
+        let first = store
+            .find_chunks_mentioning("load_config", Some("main"), 1)
+            .unwrap();
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].file_path, "src/z.rs");
+    }
+
+    #[test]
+    fn test_list_indexed_files() {
+        let (store, _dir) = create_test_store();
+        let chunk = |branch: &str, path: &str, index: u32| {
+            CodeChunk::new(
+                branch.to_string(),
+                path.to_string(),
+                index * 10 + 1,
+                index * 10 + 10,
+                "fn f() {}".to_string(),
+                ChunkType::Function,
+                Some("rust".to_string()),
+                5,
+                index,
+                "abc123".to_string(),
+            )
+        };
+        store
+            .insert_chunks_batch(&[
+                chunk("main", "src/a.rs", 0),
+                chunk("main", "src/a.rs", 1),
+                chunk("main", "src/b.rs", 0),
+                chunk("main", "tests/a.rs", 0),
+                chunk("dev", "src/c.rs", 0),
+            ])
+            .unwrap();
+
+        let files = store.list_indexed_files("main", None, 10, 0).unwrap();
+        let paths: Vec<_> = files.iter().map(|f| f.file_path.as_str()).collect();
+        assert_eq!(paths, vec!["src/a.rs", "src/b.rs", "tests/a.rs"]);
+        assert_eq!(files[0].chunk_count, 2);
+        assert_eq!(files[0].commit_sha, "abc123");
+        assert_eq!(store.count_indexed_files("main", None).unwrap(), 3);
+
+        let src = store
+            .list_indexed_files("main", Some("src/"), 1, 1)
+            .unwrap();
+        assert_eq!(src.len(), 1);
+        assert_eq!(src[0].file_path, "src/b.rs");
+        assert_eq!(store.count_indexed_files("main", Some("src/")).unwrap(), 2);
+        assert_eq!(store.count_indexed_files("main", Some("src_")).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_get_chunks_for_file() {
+        let (store, _dir) = create_test_store();
+        let chunk = |branch: &str, path: &str, index: u32| {
+            CodeChunk::new(
+                branch.to_string(),
+                path.to_string(),
+                index * 10 + 1,
+                index * 10 + 10,
+                format!("// chunk {}", index),
+                ChunkType::Code,
+                Some("rust".to_string()),
+                5,
+                index,
+                "abc123".to_string(),
+            )
+        };
+        let mut second = chunk("main", "src/a.rs", 1);
+        second.summary = Some("Second chunk of a.rs.".to_string());
+        store
+            .insert_chunks_batch(&[
+                second.clone(),
+                chunk("main", "src/a.rs", 0),
+                chunk("main", "src/b.rs", 0),
+                chunk("dev", "src/a.rs", 2),
+            ])
+            .unwrap();
+
+        let chunks = store.get_chunks_for_file("main", "src/a.rs").unwrap();
+        let indexes: Vec<_> = chunks.iter().map(|c| c.chunk_index).collect();
+        assert_eq!(indexes, vec![0, 1]);
+        assert!(store
+            .get_chunks_for_file("main", "src/missing.rs")
+            .unwrap()
+            .is_empty());
+
+        let found = store.get_chunk(second.id).unwrap().unwrap();
+        assert_eq!(found.content, "// chunk 1");
+        assert_eq!(found.summary.as_deref(), Some("Second chunk of a.rs."));
+        assert!(chunks[0].summary.is_none());
+        assert!(store.get_chunk(Uuid::new_v4()).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_get_branch_pages() {
+        let (store, _dir) = create_test_store();
+
+        for (slug, order, branch) in [
+            ("modules", 2, "main"),
+            ("overview", 0, "main"),
+            ("other", 1, "dev"),
+        ] {
+            let page = WikiPage::new(
+                branch.to_string(),
+                slug.to_string(),
+                slug.to_string(),
+                "# Page".to_string(),
+                PageType::Module,
+                None,
+                order,
+                vec!["src/lib.rs".to_string()],
+                "abc123".to_string(),
+            );
+            store.insert_wiki_page(&page).unwrap();
+        }
+
+        let slugs: Vec<_> = store
+            .get_branch_pages("main")
+            .unwrap()
+            .into_iter()
+            .map(|p| p.slug)
+            .collect();
+        assert_eq!(slugs, vec!["overview", "modules"]);
+        assert!(store.get_branch_pages("feature").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_wiki_structure_keeps_skipped_pages() {
+        let (store, _dir) = create_test_store();
+
+        let root = WikiTree::new(
+            "overview".to_string(),
+            "Overview".to_string(),
+            PageType::Overview,
+            0,
+        );
+        let mut structure = WikiStructure::new("main".to_string(), root);
+        structure.skipped_pages.push(SkippedPage {
+            id: "helpers".to_string(),
+            title: "Helpers".to_string(),
+            section_id: "utilities".to_string(),
+            importance: Importance::Low,
+            file_paths: vec!["src/helpers.rs".to_string()],
+            related_pages: vec![],
+            description: "Small helpers".to_string(),
+            reason: SkipReason::BelowMinImportance,
+            error: None,
+        });
+        structure.skipped_pages.push(SkippedPage {
+            id: "storage".to_string(),
+            title: "Storage".to_string(),
+            section_id: "core".to_string(),
+            importance: Importance::High,
+            file_paths: vec!["src/storage.rs".to_string()],
+            related_pages: vec![],
+            description: "Storage layer".to_string(),
+            reason: SkipReason::GenerationFailed,
+            error: Some("stream closed".to_string()),
+        });
+        store.save_wiki_structure(&structure).unwrap();
+
+        let loaded = store.get_wiki_structure("main").unwrap().unwrap();
+        assert_eq!(loaded.skipped_pages.len(), 2);
+        assert_eq!(loaded.skipped_pages[0].id, "helpers");
+        assert_eq!(
+            loaded.skipped_pages[0].reason,
+            SkipReason::BelowMinImportance
+        );
+        assert_eq!(loaded.skipped_pages[0].error, None);
+        assert_eq!(loaded.skipped_pages[1].reason, SkipReason::GenerationFailed);
+        assert_eq!(
+            loaded.skipped_pages[1].error.as_deref(),
+            Some("stream closed")
+        );
     }
 
     #[test]
@@ -1004,4 +1834,397 @@ mod tests {
         assert_eq!(store.get_chunk_count("main").unwrap(), 0);
         assert!(store.get_index_status("main").unwrap().is_none());
     }
+
+    fn insert_test_chunks(store: &VectorStore, branch: &str, count: usize) -> Vec<Uuid> {
+        let chunks: Vec<CodeChunk> = (0..count)
+            .map(|i| {
+                CodeChunk::new(
+                    branch.to_string(),
+                    format!("src/file_{}.rs", i),
+                    1,
+                    10,
+                    format!("fn f{}() {{}}", i),
+                    ChunkType::Function,
+                    Some("rust".to_string()),
+                    5,
+                    0,
+                    "abc123".to_string(),
+                )
+            })
+            .collect();
+        store.insert_chunks_batch(&chunks).unwrap();
+        chunks.into_iter().map(|c| c.id).collect()
+    }
+
+    #[test]
+    fn test_search_scoped_to_root() {
+        let (store, _dir) = create_test_store();
+        let mut chunks: Vec<CodeChunk> = ["backend/src/lib.rs", "frontend/src/app.ts"]
+            .iter()
+            .map(|path| {
+                CodeChunk::new(
+                    "main".to_string(),
+                    path.to_string(),
+                    1,
+                    10,
+                    "fn handler() {}".to_string(),
+                    ChunkType::Function,
+                    None,
+                    5,
+                    0,
+                    "abc123".to_string(),
+                )
+            })
+            .collect();
+        chunks[0].root = Some("backend".to_string());
+        chunks[1].root = Some("frontend".to_string());
+        store.insert_chunks_batch(&chunks).unwrap();
+        let ids: Vec<Uuid> = chunks.iter().map(|c| c.id).collect();
+
+        store.activate_embedding_model("main", "small").unwrap();
+        store
+            .insert_embeddings_batch("small", &ids, &[vec![1.0; 4], vec![1.0; 4]])
+            .unwrap();
+
+        let all = store
+            .search_similar_in_branch(&[1.0; 4], "small", 5, None, None)
+            .unwrap();
+        assert_eq!(all.len(), 2);
+
+        let backend = store
+            .search_similar_in_branch(&[1.0; 4], "small", 5, None, Some("./backend/"))
+            .unwrap();
+        assert_eq!(backend.len(), 1);
+        assert_eq!(backend[0].file_path, "backend/src/lib.rs");
+
+        let frontend = store
+            .search_keyword("handler", 5, None, Some("frontend"))
+            .unwrap();
+        assert_eq!(frontend.len(), 1);
+        assert_eq!(frontend[0].chunk_id, ids[1]);
+        assert_eq!(
+            store.get_chunk(ids[1]).unwrap().unwrap().root.as_deref(),
+            Some("frontend")
+        );
+    }
+
+    #[test]
+    fn test_search_scoped_by_path_and_language() {
+        let (store, _dir) = create_test_store();
+        let chunks: Vec<CodeChunk> = [
+            ("crates/server/src/lib.rs", Some("rust")),
+            ("crates/wiki/src/lib.rs", Some("rust")),
+            ("frontend/src/app.ts", Some("typescript")),
+        ]
+        .iter()
+        .map(|(path, language)| {
+            CodeChunk::new(
+                "main".to_string(),
+                path.to_string(),
+                1,
+                10,
+                "fn handler() {}".to_string(),
+                ChunkType::Function,
+                language.map(String::from),
+                5,
+                0,
+                "abc123".to_string(),
+            )
+        })
+        .collect();
+        store.insert_chunks_batch(&chunks).unwrap();
+        let ids: Vec<Uuid> = chunks.iter().map(|c| c.id).collect();
+        store.activate_embedding_model("main", "small").unwrap();
+        store
+            .insert_embeddings_batch("small", &ids, &[vec![1.0; 4], vec![1.0; 4], vec![1.0; 4]])
+            .unwrap();
+
+        let scope = |glob: Option<&str>, language: Option<&str>| SearchScope {
+            path_glob: glob.map(String::from),
+            language: language.map(String::from),
+        };
+        let paths = |results: Vec<SearchResult>| {
+            let mut paths: Vec<String> = results.into_iter().map(|r| r.file_path).collect();
+            paths.sort();
+            paths
+        };
+
+        let server = store
+            .search_similar_scoped(
+                &[1.0; 4],
+                "small",
+                5,
+                None,
+                None,
+                &scope(Some("crates/server/**"), None),
+            )
+            .unwrap();
+        assert_eq!(paths(server), vec!["crates/server/src/lib.rs"]);
+
+        let rust = store
+            .search_similar_scoped(&[1.0; 4], "small", 5, None, None, &scope(None, Some("rs")))
+            .unwrap();
+        assert_eq!(
+            paths(rust),
+            vec!["crates/server/src/lib.rs", "crates/wiki/src/lib.rs"]
+        );
+
+        let keyword = store
+            .search_keyword_scoped(
+                "handler",
+                5,
+                None,
+                None,
+                &scope(Some("**/*.ts"), Some("TypeScript")),
+            )
+            .unwrap();
+        assert_eq!(paths(keyword), vec!["frontend/src/app.ts"]);
+
+        let none = store
+            .search_hybrid_scoped(
+                "handler",
+                &[1.0; 4],
+                "small",
+                5,
+                None,
+                None,
+                &scope(Some("crates/**"), Some("typescript")),
+            )
+            .unwrap();
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn test_public_api_tag_roundtrip() {
+        let (store, _dir) = create_test_store();
+        let mut chunk = CodeChunk::new(
+            "main".to_string(),
+            "src/api.rs".to_string(),
+            1,
+            10,
+            "pub fn handler() {}".to_string(),
+            ChunkType::Function,
+            Some("rust".to_string()),
+            5,
+            0,
+            "abc123".to_string(),
+        );
+        chunk.is_public_api = true;
+        store.insert_chunk(&chunk).unwrap();
+        store.activate_embedding_model("main", "small").unwrap();
+        store
+            .insert_embedding("small", &chunk.id, &[1.0; 4])
+            .unwrap();
+
+        assert!(store.get_chunk(chunk.id).unwrap().unwrap().is_public_api);
+        let semantic = store
+            .search_similar_in_branch(&[1.0; 4], "small", 5, None, None)
+            .unwrap();
+        assert!(semantic[0].is_public_api);
+        let keyword = store.search_keyword("handler", 5, None, None).unwrap();
+        assert!(keyword[0].is_public_api);
+    }
+
+    #[test]
+    fn test_symbols_and_imports_roundtrip() {
+        let (store, _dir) = create_test_store();
+        let mut chunk = CodeChunk::new(
+            "main".to_string(),
+            "src/config.rs".to_string(),
+            1,
+            10,
+            "use serde::Deserialize;\npub struct Config {}\nfn load_config() {}".to_string(),
+            ChunkType::Code,
+            Some("rust".to_string()),
+            12,
+            0,
+            "abc123".to_string(),
+        );
+        chunk.symbols = vec!["Config".to_string(), "load_config".to_string()];
+        chunk.imports = vec!["serde".to_string()];
+        store
+            .insert_chunks_batch(std::slice::from_ref(&chunk))
+            .unwrap();
+        store.activate_embedding_model("main", "small").unwrap();
+        store
+            .insert_embedding("small", &chunk.id, &[1.0; 4])
+            .unwrap();
+
+        let stored = store.get_chunk(chunk.id).unwrap().unwrap();
+        assert_eq!(stored.symbols, chunk.symbols);
+        assert_eq!(stored.imports, chunk.imports);
+        let semantic = store
+            .search_similar_in_branch(&[1.0; 4], "small", 5, None, None)
+            .unwrap();
+        assert_eq!(semantic[0].symbols, chunk.symbols);
+        let keyword = store.search_keyword("load_config", 5, None, None).unwrap();
+        assert_eq!(keyword[0].symbols, chunk.symbols);
+    }
+
+    #[test]
+    fn test_search_rejects_other_embedding_model() {
+        let (store, _dir) = create_test_store();
+        let ids = insert_test_chunks(&store, "main", 2);
+
+        store.activate_embedding_model("main", "small").unwrap();
+        store
+            .insert_embeddings_batch(
+                "small",
+                &ids,
+                &[vec![1.0, 0.0, 0.0, 0.0], vec![0.0, 1.0, 0.0, 0.0]],
+            )
+            .unwrap();
+
+        let results = store
+            .search_similar_in_branch(&[1.0, 0.0, 0.0, 0.0], "small", 5, Some("main"), None)
+            .unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].chunk_id, ids[0]);
+
+        let err = store
+            .search_similar_in_branch(&[1.0, 0.0], "large", 5, Some("main"), None)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            WikiError::EmbeddingModelMismatch { ref indexed, .. } if indexed == "small"
+        ));
+    }
+
+    #[test]
+    fn test_embedding_migration() {
+        let (store, _dir) = create_test_store();
+        let ids = insert_test_chunks(&store, "main", 3);
+
+        let active = store.activate_embedding_model("main", "small").unwrap();
+        assert_eq!(active.version, 1);
+        store
+            .insert_embeddings_batch("small", &ids, &vec![vec![1.0; 4]; 3])
+            .unwrap();
+
+        let migrating = store.begin_embedding_migration("main", "large").unwrap();
+        assert_eq!(migrating.version, 2);
+        assert!(!migrating.is_active());
+        assert_eq!(
+            store
+                .count_chunks_missing_embeddings("main", "large")
+                .unwrap(),
+            3
+        );
+
+        let batch = store
+            .get_chunks_missing_embeddings("main", "large", 2)
+            .unwrap();
+        let batch_ids: Vec<Uuid> = batch.iter().map(|c| c.id).collect();
+        store
+            .insert_embeddings_batch("large", &batch_ids, &vec![vec![1.0; 8]; 2])
+            .unwrap();
+        assert!(store.complete_embedding_migration("main", "large").is_err());
+
+        // Searches keep using the old model during the migration
+        assert!(store
+            .search_similar_in_branch(&[1.0; 4], "small", 5, Some("main"), None)
+            .is_ok());
+
+        // Resuming keeps the vectors embedded so far
+        let resumed = store.begin_embedding_migration("main", "large").unwrap();
+        assert_eq!(resumed.embedded_chunks, 2);
+
+        let rest = store
+            .get_chunks_missing_embeddings("main", "large", 10)
+            .unwrap();
+        assert_eq!(rest.len(), 1);
+        store
+            .insert_embeddings_batch("large", &[rest[0].id], &[vec![1.0; 8]])
+            .unwrap();
+
+        let completed = store.complete_embedding_migration("main", "large").unwrap();
+        assert_eq!(completed.model, "large");
+        assert_eq!(completed.version, 2);
+        assert_eq!(completed.embedded_chunks, 3);
+        assert_eq!(store.get_embedding_indexes("main").unwrap().len(), 1);
+        assert!(store
+            .search_similar_in_branch(&[1.0; 4], "small", 5, Some("main"), None)
+            .is_err());
+        assert_eq!(
+            store
+                .search_similar_in_branch(&[1.0; 8], "large", 5, Some("main"), None)
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_legacy_branches_use_legacy_model() {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        {
+            let store = VectorStore::new(&db_path).unwrap();
+            insert_test_chunks(&store, "main", 1);
+            store
+                .conn
+                .execute("DELETE FROM embedding_indexes", [])
+                .unwrap();
+        }
+
+        let store = VectorStore::new(&db_path).unwrap();
+        let active = store.get_active_embedding_index("main").unwrap().unwrap();
+        assert_eq!(active.model, LEGACY_EMBEDDING_MODEL);
+        assert_eq!(active.version, 1);
+        assert_eq!(active.dimension, Some(EMBEDDING_DIMENSION));
+    }
+
+    #[test]
+    fn test_configured_embedding_dimension() {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        let model = "openai/text-embedding-3-large";
+        {
+            let store = VectorStore::with_embedding_dimension(&db_path, model, 8).unwrap();
+            let ids = insert_test_chunks(&store, "main", 2);
+            store.activate_embedding_model("main", model).unwrap();
+            store
+                .insert_embeddings_batch(model, &ids, &[vec![1.0; 8], vec![0.5; 8]])
+                .unwrap();
+            store
+                .update_index_status(&IndexStatus {
+                    state: IndexState::Indexed,
+                    ..IndexStatus::new("main".to_string())
+                })
+                .unwrap();
+            assert!(matches!(
+                store.insert_embedding(model, &ids[0], &[1.0; 4]),
+                Err(WikiError::DimensionMismatch {
+                    expected: 8,
+                    actual: 4
+                })
+            ));
+        }
+
+        // Reopening with the same dimension keeps the vectors
+        let store = VectorStore::with_embedding_dimension(&db_path, model, 8).unwrap();
+        assert_eq!(
+            store
+                .count_chunks_missing_embeddings("main", model)
+                .unwrap(),
+            0
+        );
+
+        assert!(store.configure_embedding_dimension(model, 4).unwrap());
+        assert_eq!(
+            store
+                .count_chunks_missing_embeddings("main", model)
+                .unwrap(),
+            2
+        );
+        assert_eq!(
+            store.get_index_status("main").unwrap().unwrap().state,
+            IndexState::Stale
+        );
+        assert!(store
+            .search_similar_in_branch(&[1.0; 4], model, 5, Some("main"), None)
+            .unwrap()
+            .is_empty());
+        assert!(store.configure_embedding_dimension(model, 0).is_err());
+    }
 }
@@ -0,0 +1,246 @@
+//! Embeddings of past tasks, findings and sessions
+//!
+//! Recall items live apart from code chunks: they are not tied to a branch
+//! and are refreshed from the task database rather than from the source
+//! tree. Vectors are stored as plain blobs next to the model that produced
+//! them, so searches only ever compare vectors from the same model.
+
+use std::collections::HashSet;
+
+use rusqlite::params;
+use uuid::Uuid;
+
+use super::VectorStore;
+use crate::domain::recall_item::{RecallItem, RecallMatch, RecallScope};
+use crate::error::{WikiError, WikiResult};
+
+impl VectorStore {
+    /// Create the recall tables
+    pub(super) fn init_recall_schema(&self) -> WikiResult<()> {
+        self.conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS recall_items (
+                id TEXT PRIMARY KEY,
+                scope TEXT NOT NULL,
+                task_id TEXT NOT NULL,
+                title TEXT NOT NULL,
+                content TEXT NOT NULL,
+                model TEXT NOT NULL,
+                embedding BLOB NOT NULL,
+                updated_at TEXT NOT NULL
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_recall_items_scope ON recall_items(scope, model);
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// The items whose stored text or embedding model differs from `items`,
+    /// i.e. those that need to be (re-)embedded with `model`
+    pub fn stale_recall_items<'a>(
+        &self,
+        items: &'a [RecallItem],
+        model: &str,
+    ) -> WikiResult<Vec<&'a RecallItem>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT title, content, model FROM recall_items WHERE id = ?1")?;
+
+        let mut stale = Vec::new();
+        for item in items {
+            let stored = stmt.query_row(params![item.id], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            });
+            match stored {
+                Ok((title, content, stored_model))
+                    if title == item.title && content == item.content && stored_model == model => {}
+                Ok(_) | Err(rusqlite::Error::QueryReturnedNoRows) => stale.push(item),
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(stale)
+    }
+
+    /// Store recall items with their `model` embeddings, replacing earlier
+    /// versions
+    pub fn upsert_recall_items(
+        &self,
+        model: &str,
+        items: &[&RecallItem],
+        embeddings: &[Vec<f32>],
+    ) -> WikiResult<()> {
+        if items.len() != embeddings.len() {
+            return Err(WikiError::IndexingFailed(format!(
+                "Recall items count ({}) doesn't match embeddings count ({})",
+                items.len(),
+                embeddings.len()
+            )));
+        }
+
+        let now = chrono::Utc::now().to_rfc3339();
+        let mut stmt = self.conn.prepare_cached(
+            r#"
+            INSERT OR REPLACE INTO recall_items
+                (id, scope, task_id, title, content, model, embedding, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+            "#,
+        )?;
+
+        for (item, embedding) in items.iter().zip(embeddings) {
+            let embedding_bytes: Vec<u8> = embedding.iter().flat_map(|f| f.to_le_bytes()).collect();
+            stmt.execute(params![
+                item.id,
+                item.scope.as_str(),
+                item.task_id.to_string(),
+                item.title,
+                item.content,
+                model,
+                embedding_bytes,
+                now
+            ])?;
+        }
+
+        Ok(())
+    }
+
+    /// Delete the recall items not in `ids`, e.g. those of deleted tasks.
+    /// Returns the number of items deleted.
+    pub fn retain_recall_items(&self, ids: &HashSet<String>) -> WikiResult<usize> {
+        let mut stmt = self.conn.prepare("SELECT id FROM recall_items")?;
+        let stored: Vec<String> = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut deleted = 0;
+        for id in stored.iter().filter(|id| !ids.contains(*id)) {
+            deleted += self
+                .conn
+                .execute("DELETE FROM recall_items WHERE id = ?1", params![id])?;
+        }
+        Ok(deleted)
+    }
+
+    /// The recall items embedded with `model` closest to `query_embedding`,
+    /// optionally limited to one scope
+    pub fn search_recall(
+        &self,
+        query_embedding: &[f32],
+        model: &str,
+        scope: Option<RecallScope>,
+        limit: usize,
+    ) -> WikiResult<Vec<RecallMatch>> {
+        let embedding_bytes: Vec<u8> = query_embedding
+            .iter()
+            .flat_map(|f| f.to_le_bytes())
+            .collect();
+
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT id, scope, task_id, title, content,
+                   vec_distance_cosine(embedding, ?1) AS distance
+            FROM recall_items
+            WHERE model = ?2
+              AND length(embedding) = ?3
+              AND (?4 IS NULL OR scope = ?4)
+            ORDER BY distance ASC
+            LIMIT ?5
+            "#,
+        )?;
+
+        let matches = stmt
+            .query_map(
+                params![
+                    embedding_bytes,
+                    model,
+                    embedding_bytes.len() as i64,
+                    scope.map(|s| s.as_str()),
+                    limit as i64
+                ],
+                |row| {
+                    let scope: String = row.get(1)?;
+                    let task_id: String = row.get(2)?;
+                    let distance: f32 = row.get(5)?;
+                    Ok(RecallMatch {
+                        item: RecallItem {
+                            id: row.get(0)?,
+                            scope: RecallScope::parse(&scope).unwrap_or(RecallScope::Task),
+                            task_id: Uuid::parse_str(&task_id).unwrap_or_default(),
+                            title: row.get(3)?,
+                            content: row.get(4)?,
+                        },
+                        score: 1.0 - distance,
+                    })
+                },
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(matches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn item(id: &str, scope: RecallScope, title: &str) -> RecallItem {
+        RecallItem::new(id, scope, Uuid::new_v4(), title, "details")
+    }
+
+    #[test]
+    fn test_recall_sync_and_search() {
+        let dir = tempdir().unwrap();
+        let store = VectorStore::new(&dir.path().join("test.db")).unwrap();
+
+        let items = vec![
+            item("task:1", RecallScope::Task, "Fix login race"),
+            item(
+                "finding:1:finding-1",
+                RecallScope::Finding,
+                "Unchecked unwrap",
+            ),
+        ];
+        assert_eq!(store.stale_recall_items(&items, "small").unwrap().len(), 2);
+
+        let stale = store.stale_recall_items(&items, "small").unwrap();
+        store
+            .upsert_recall_items("small", &stale, &[vec![1.0, 0.0], vec![0.0, 1.0]])
+            .unwrap();
+        assert!(store
+            .stale_recall_items(&items, "small")
+            .unwrap()
+            .is_empty());
+        assert_eq!(store.stale_recall_items(&items, "large").unwrap().len(), 2);
+
+        let all = store.search_recall(&[1.0, 0.0], "small", None, 10).unwrap();
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].item.id, "task:1");
+
+        let findings = store
+            .search_recall(&[1.0, 0.0], "small", Some(RecallScope::Finding), 10)
+            .unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].item.title, "Unchecked unwrap");
+
+        // Vectors from another model are never compared
+        assert!(store
+            .search_recall(&[1.0, 0.0, 0.0], "large", None, 10)
+            .unwrap()
+            .is_empty());
+
+        let keep: HashSet<String> = ["task:1".to_string()].into_iter().collect();
+        assert_eq!(store.retain_recall_items(&keep).unwrap(), 1);
+        assert_eq!(
+            store
+                .search_recall(&[1.0, 0.0], "small", None, 10)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+}
@@ -260,7 +260,7 @@ export default function App() {
 								</span>
 								{currentProject && (
 									<span className="text-[11px] text-muted-foreground truncate">
-										{currentProject.vcs}
+										{currentProject.demo ? `demo · ${currentProject.vcs}` : currentProject.vcs}
 									</span>
 								)}
 							</div>
@@ -0,0 +1,574 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import {
+  useMutation,
+  useQuery
+} from '@tanstack/react-query';
+import type {
+  DataTag,
+  DefinedInitialDataOptions,
+  DefinedUseQueryResult,
+  MutationFunction,
+  QueryClient,
+  QueryFunction,
+  QueryKey,
+  UndefinedInitialDataOptions,
+  UseMutationOptions,
+  UseMutationResult,
+  UseQueryOptions,
+  UseQueryResult
+} from '@tanstack/react-query';
+
+import type {
+  ApiTokenResponse,
+  CreateApiTokenRequest,
+  CreateApiTokenResponse,
+  SeedDemoResponse,
+  WikiFeedbackStatsResponse,
+  WipeDemoResponse
+} from '.././model';
+
+import { customFetch } from '../../../lib/api-fetcher';
+
+
+type SecondParameter<T extends (...args: never) => unknown> = Parameters<T>[1];
+
+
+
+export type listApiTokensResponse200 = {
+  data: ApiTokenResponse[]
+  status: 200
+}
+    
+export type listApiTokensResponseSuccess = (listApiTokensResponse200) & {
+  headers: Headers;
+};
+;
+
+export type listApiTokensResponse = (listApiTokensResponseSuccess)
+
+export const getListApiTokensUrl = () => {
+
+
+  
+
+  return `/api/admin/tokens`
+}
+
+export const listApiTokens = async ( options?: RequestInit): Promise<listApiTokensResponse> => {
+  
+  return customFetch<listApiTokensResponse>(getListApiTokensUrl(),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getListApiTokensQueryKey = () => {
+    return [
+    `/api/admin/tokens`
+    ] as const;
+    }
+
+    
+export const getListApiTokensQueryOptions = <TData = Awaited<ReturnType<typeof listApiTokens>>, TError = unknown>( options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listApiTokens>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getListApiTokensQueryKey();
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof listApiTokens>>> = ({ signal }) => listApiTokens({ signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof listApiTokens>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type ListApiTokensQueryResult = NonNullable<Awaited<ReturnType<typeof listApiTokens>>>
+export type ListApiTokensQueryError = unknown
+
+
+export function useListApiTokens<TData = Awaited<ReturnType<typeof listApiTokens>>, TError = unknown>(
+  options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof listApiTokens>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof listApiTokens>>,
+          TError,
+          Awaited<ReturnType<typeof listApiTokens>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useListApiTokens<TData = Awaited<ReturnType<typeof listApiTokens>>, TError = unknown>(
+  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listApiTokens>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof listApiTokens>>,
+          TError,
+          Awaited<ReturnType<typeof listApiTokens>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useListApiTokens<TData = Awaited<ReturnType<typeof listApiTokens>>, TError = unknown>(
+  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listApiTokens>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useListApiTokens<TData = Awaited<ReturnType<typeof listApiTokens>>, TError = unknown>(
+  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listApiTokens>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getListApiTokensQueryOptions(options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type createApiTokenResponse201 = {
+  data: CreateApiTokenResponse
+  status: 201
+}
+
+export type createApiTokenResponse400 = {
+  data: void
+  status: 400
+}
+    
+export type createApiTokenResponseSuccess = (createApiTokenResponse201) & {
+  headers: Headers;
+};
+export type createApiTokenResponseError = (createApiTokenResponse400) & {
+  headers: Headers;
+};
+
+export type createApiTokenResponse = (createApiTokenResponseSuccess | createApiTokenResponseError)
+
+export const getCreateApiTokenUrl = () => {
+
+
+  
+
+  return `/api/admin/tokens`
+}
+
+export const createApiToken = async (createApiTokenRequest: CreateApiTokenRequest, options?: RequestInit): Promise<createApiTokenResponse> => {
+  
+  return customFetch<createApiTokenResponse>(getCreateApiTokenUrl(),
+  {      
+    ...options,
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      createApiTokenRequest,)
+  }
+);}
+
+
+
+
+export const getCreateApiTokenMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof createApiToken>>, TError,{data: CreateApiTokenRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof createApiToken>>, TError,{data: CreateApiTokenRequest}, TContext> => {
+
+const mutationKey = ['createApiToken'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof createApiToken>>, {data: CreateApiTokenRequest}> = (props) => {
+          const {data} = props ?? {};
+
+          return  createApiToken(data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type CreateApiTokenMutationResult = NonNullable<Awaited<ReturnType<typeof createApiToken>>>
+    export type CreateApiTokenMutationBody = CreateApiTokenRequest
+    export type CreateApiTokenMutationError = void
+
+    export const useCreateApiToken = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof createApiToken>>, TError,{data: CreateApiTokenRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof createApiToken>>,
+        TError,
+        {data: CreateApiTokenRequest},
+        TContext
+      > => {
+
+      const mutationOptions = getCreateApiTokenMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type revokeApiTokenResponse204 = {
+  data: void
+  status: 204
+}
+
+export type revokeApiTokenResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type revokeApiTokenResponseSuccess = (revokeApiTokenResponse204) & {
+  headers: Headers;
+};
+export type revokeApiTokenResponseError = (revokeApiTokenResponse404) & {
+  headers: Headers;
+};
+
+export type revokeApiTokenResponse = (revokeApiTokenResponseSuccess | revokeApiTokenResponseError)
+
+export const getRevokeApiTokenUrl = (id: string,) => {
+
+
+  
+
+  return `/api/admin/tokens/${id}`
+}
+
+export const revokeApiToken = async (id: string, options?: RequestInit): Promise<revokeApiTokenResponse> => {
+  
+  return customFetch<revokeApiTokenResponse>(getRevokeApiTokenUrl(id),
+  {      
+    ...options,
+    method: 'DELETE'
+    
+    
+  }
+);}
+
+
+
+
+export const getRevokeApiTokenMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof revokeApiToken>>, TError,{id: string}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof revokeApiToken>>, TError,{id: string}, TContext> => {
+
+const mutationKey = ['revokeApiToken'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof revokeApiToken>>, {id: string}> = (props) => {
+          const {id} = props ?? {};
+
+          return  revokeApiToken(id,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type RevokeApiTokenMutationResult = NonNullable<Awaited<ReturnType<typeof revokeApiToken>>>
+    
+    export type RevokeApiTokenMutationError = void
+
+    export const useRevokeApiToken = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof revokeApiToken>>, TError,{id: string}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof revokeApiToken>>,
+        TError,
+        {id: string},
+        TContext
+      > => {
+
+      const mutationOptions = getRevokeApiTokenMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    
+
+
+
+export type getWikiFeedbackStatsResponse200 = {
+  data: WikiFeedbackStatsResponse
+  status: 200
+}
+    
+export type getWikiFeedbackStatsResponseSuccess = (getWikiFeedbackStatsResponse200) & {
+  headers: Headers;
+};
+;
+
+export type getWikiFeedbackStatsResponse = (getWikiFeedbackStatsResponseSuccess)
+
+export const getGetWikiFeedbackStatsUrl = () => {
+
+
+  
+
+  return `/api/admin/wiki-feedback`
+}
+
+export const getWikiFeedbackStats = async ( options?: RequestInit): Promise<getWikiFeedbackStatsResponse> => {
+  
+  return customFetch<getWikiFeedbackStatsResponse>(getGetWikiFeedbackStatsUrl(),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetWikiFeedbackStatsQueryKey = () => {
+    return [
+    `/api/admin/wiki-feedback`
+    ] as const;
+    }
+
+    
+export const getGetWikiFeedbackStatsQueryOptions = <TData = Awaited<ReturnType<typeof getWikiFeedbackStats>>, TError = unknown>( options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiFeedbackStats>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetWikiFeedbackStatsQueryKey();
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getWikiFeedbackStats>>> = ({ signal }) => getWikiFeedbackStats({ signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getWikiFeedbackStats>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetWikiFeedbackStatsQueryResult = NonNullable<Awaited<ReturnType<typeof getWikiFeedbackStats>>>
+export type GetWikiFeedbackStatsQueryError = unknown
+
+
+export function useGetWikiFeedbackStats<TData = Awaited<ReturnType<typeof getWikiFeedbackStats>>, TError = unknown>(
+  options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiFeedbackStats>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getWikiFeedbackStats>>,
+          TError,
+          Awaited<ReturnType<typeof getWikiFeedbackStats>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetWikiFeedbackStats<TData = Awaited<ReturnType<typeof getWikiFeedbackStats>>, TError = unknown>(
+  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiFeedbackStats>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getWikiFeedbackStats>>,
+          TError,
+          Awaited<ReturnType<typeof getWikiFeedbackStats>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetWikiFeedbackStats<TData = Awaited<ReturnType<typeof getWikiFeedbackStats>>, TError = unknown>(
+  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiFeedbackStats>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetWikiFeedbackStats<TData = Awaited<ReturnType<typeof getWikiFeedbackStats>>, TError = unknown>(
+  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiFeedbackStats>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetWikiFeedbackStatsQueryOptions(options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+export type seedDemoResponse201 = {
+  data: SeedDemoResponse
+  status: 201
+}
+    
+export type seedDemoResponseSuccess = (seedDemoResponse201) & {
+  headers: Headers;
+};
+;
+
+export type seedDemoResponse = (seedDemoResponseSuccess)
+
+export const getSeedDemoUrl = () => {
+
+
+  
+
+  return `/api/admin/seed`
+}
+
+export const seedDemo = async (options?: RequestInit): Promise<seedDemoResponse> => {
+  
+  return customFetch<seedDemoResponse>(getSeedDemoUrl(),
+  {      
+    ...options,
+    method: 'POST'
+    
+    
+  }
+);}
+
+
+
+
+export const getSeedDemoMutationOptions = <TError = unknown,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof seedDemo>>, TError,void, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof seedDemo>>, TError,void, TContext> => {
+
+const mutationKey = ['seedDemo'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof seedDemo>>, void> = () => {
+
+
+          return  seedDemo(requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type SeedDemoMutationResult = NonNullable<Awaited<ReturnType<typeof seedDemo>>>
+    
+    export type SeedDemoMutationError = unknown
+
+    export const useSeedDemo = <TError = unknown,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof seedDemo>>, TError,void, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof seedDemo>>,
+        TError,
+        void,
+        TContext
+      > => {
+
+      const mutationOptions = getSeedDemoMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type wipeDemoResponse200 = {
+  data: WipeDemoResponse
+  status: 200
+}
+    
+export type wipeDemoResponseSuccess = (wipeDemoResponse200) & {
+  headers: Headers;
+};
+;
+
+export type wipeDemoResponse = (wipeDemoResponseSuccess)
+
+export const getWipeDemoUrl = () => {
+
+
+  
+
+  return `/api/admin/seed`
+}
+
+export const wipeDemo = async (options?: RequestInit): Promise<wipeDemoResponse> => {
+  
+  return customFetch<wipeDemoResponse>(getWipeDemoUrl(),
+  {      
+    ...options,
+    method: 'DELETE'
+    
+    
+  }
+);}
+
+
+
+
+export const getWipeDemoMutationOptions = <TError = unknown,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof wipeDemo>>, TError,void, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof wipeDemo>>, TError,void, TContext> => {
+
+const mutationKey = ['wipeDemo'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof wipeDemo>>, void> = () => {
+
+
+          return  wipeDemo(requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type WipeDemoMutationResult = NonNullable<Awaited<ReturnType<typeof wipeDemo>>>
+    
+    export type WipeDemoMutationError = unknown
+
+    export const useWipeDemo = <TError = unknown,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof wipeDemo>>, TError,void, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof wipeDemo>>,
+        TError,
+        void,
+        TContext
+      > => {
+
+      const mutationOptions = getWipeDemoMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    
\ No newline at end of file
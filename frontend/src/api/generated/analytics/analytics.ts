@@ -0,0 +1,154 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import {
+  useQuery
+} from '@tanstack/react-query';
+import type {
+  DataTag,
+  DefinedInitialDataOptions,
+  DefinedUseQueryResult,
+  QueryClient,
+  QueryFunction,
+  QueryKey,
+  UndefinedInitialDataOptions,
+  UseQueryOptions,
+  UseQueryResult
+} from '@tanstack/react-query';
+
+import type {
+  GetMcpAnalyticsParams,
+  McpAnalyticsResponse
+} from '.././model';
+
+import { customFetch } from '../../../lib/api-fetcher';
+
+
+type SecondParameter<T extends (...args: never) => unknown> = Parameters<T>[1];
+
+
+
+export type getMcpAnalyticsResponse200 = {
+  data: McpAnalyticsResponse
+  status: 200
+}
+
+export type getMcpAnalyticsResponse400 = {
+  data: void
+  status: 400
+}
+    
+export type getMcpAnalyticsResponseSuccess = (getMcpAnalyticsResponse200) & {
+  headers: Headers;
+};
+export type getMcpAnalyticsResponseError = (getMcpAnalyticsResponse400) & {
+  headers: Headers;
+};
+
+export type getMcpAnalyticsResponse = (getMcpAnalyticsResponseSuccess | getMcpAnalyticsResponseError)
+
+export const getGetMcpAnalyticsUrl = (params?: GetMcpAnalyticsParams,) => {
+  const normalizedParams = new URLSearchParams();
+
+  Object.entries(params || {}).forEach(([key, value]) => {
+    
+    if (value !== undefined) {
+      normalizedParams.append(key, value === null ? 'null' : value.toString())
+    }
+  });
+
+  const stringifiedParams = normalizedParams.toString();
+
+  return stringifiedParams.length > 0 ? `/api/analytics/mcp?${stringifiedParams}` : `/api/analytics/mcp`
+}
+
+export const getMcpAnalytics = async (params?: GetMcpAnalyticsParams, options?: RequestInit): Promise<getMcpAnalyticsResponse> => {
+  
+  return customFetch<getMcpAnalyticsResponse>(getGetMcpAnalyticsUrl(params),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetMcpAnalyticsQueryKey = (params?: GetMcpAnalyticsParams,) => {
+    return [
+    `/api/analytics/mcp`, ...(params ? [params]: [])
+    ] as const;
+    }
+
+    
+export const getGetMcpAnalyticsQueryOptions = <TData = Awaited<ReturnType<typeof getMcpAnalytics>>, TError = void>(params?: GetMcpAnalyticsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getMcpAnalytics>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetMcpAnalyticsQueryKey(params);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getMcpAnalytics>>> = ({ signal }) => getMcpAnalytics(params, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getMcpAnalytics>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetMcpAnalyticsQueryResult = NonNullable<Awaited<ReturnType<typeof getMcpAnalytics>>>
+export type GetMcpAnalyticsQueryError = void
+
+
+export function useGetMcpAnalytics<TData = Awaited<ReturnType<typeof getMcpAnalytics>>, TError = void>(
+ params: undefined |  GetMcpAnalyticsParams, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getMcpAnalytics>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getMcpAnalytics>>,
+          TError,
+          Awaited<ReturnType<typeof getMcpAnalytics>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetMcpAnalytics<TData = Awaited<ReturnType<typeof getMcpAnalytics>>, TError = void>(
+ params?: GetMcpAnalyticsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getMcpAnalytics>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getMcpAnalytics>>,
+          TError,
+          Awaited<ReturnType<typeof getMcpAnalytics>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetMcpAnalytics<TData = Awaited<ReturnType<typeof getMcpAnalytics>>, TError = void>(
+ params?: GetMcpAnalyticsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getMcpAnalytics>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetMcpAnalytics<TData = Awaited<ReturnType<typeof getMcpAnalytics>>, TError = void>(
+ params?: GetMcpAnalyticsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getMcpAnalytics>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetMcpAnalyticsQueryOptions(params,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
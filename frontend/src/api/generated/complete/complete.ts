@@ -25,6 +25,7 @@ import type {
 } from '@tanstack/react-query';
 
 import type {
+  CiGateRecord,
   CompletePreviewResponse,
   CompleteTaskRequest,
   CompleteTaskResponse
@@ -250,3 +251,210 @@ export function useGetCompletePreview<TData = Awaited<ReturnType<typeof getCompl
 
 
 
+export type getTaskCiGateResponse200 = {
+  data: CiGateRecord
+  status: 200
+}
+
+export type getTaskCiGateResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type getTaskCiGateResponseSuccess = (getTaskCiGateResponse200) & {
+  headers: Headers;
+};
+export type getTaskCiGateResponseError = (getTaskCiGateResponse404) & {
+  headers: Headers;
+};
+
+export type getTaskCiGateResponse = (getTaskCiGateResponseSuccess | getTaskCiGateResponseError)
+
+export const getGetTaskCiGateUrl = (taskId: string,) => {
+
+
+  
+
+  return `/api/tasks/${taskId}/ci-gate`
+}
+
+export const getTaskCiGate = async (taskId: string, options?: RequestInit): Promise<getTaskCiGateResponse> => {
+  
+  return customFetch<getTaskCiGateResponse>(getGetTaskCiGateUrl(taskId),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetTaskCiGateQueryKey = (taskId?: string,) => {
+    return [
+    `/api/tasks/${taskId}/ci-gate`
+    ] as const;
+    }
+
+    
+export const getGetTaskCiGateQueryOptions = <TData = Awaited<ReturnType<typeof getTaskCiGate>>, TError = void>(taskId: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskCiGate>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetTaskCiGateQueryKey(taskId);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getTaskCiGate>>> = ({ signal }) => getTaskCiGate(taskId, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, enabled: !!(taskId), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getTaskCiGate>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetTaskCiGateQueryResult = NonNullable<Awaited<ReturnType<typeof getTaskCiGate>>>
+export type GetTaskCiGateQueryError = void
+
+
+export function useGetTaskCiGate<TData = Awaited<ReturnType<typeof getTaskCiGate>>, TError = void>(
+ taskId: string, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskCiGate>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getTaskCiGate>>,
+          TError,
+          Awaited<ReturnType<typeof getTaskCiGate>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetTaskCiGate<TData = Awaited<ReturnType<typeof getTaskCiGate>>, TError = void>(
+ taskId: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskCiGate>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getTaskCiGate>>,
+          TError,
+          Awaited<ReturnType<typeof getTaskCiGate>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetTaskCiGate<TData = Awaited<ReturnType<typeof getTaskCiGate>>, TError = void>(
+ taskId: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskCiGate>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetTaskCiGate<TData = Awaited<ReturnType<typeof getTaskCiGate>>, TError = void>(
+ taskId: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskCiGate>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetTaskCiGateQueryOptions(taskId,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type restartTaskCiGateResponse202 = {
+  data: CiGateRecord
+  status: 202
+}
+
+export type restartTaskCiGateResponse400 = {
+  data: void
+  status: 400
+}
+
+export type restartTaskCiGateResponse404 = {
+  data: void
+  status: 404
+}
+
+export type restartTaskCiGateResponse409 = {
+  data: void
+  status: 409
+}
+    
+export type restartTaskCiGateResponseSuccess = (restartTaskCiGateResponse202) & {
+  headers: Headers;
+};
+export type restartTaskCiGateResponseError = (restartTaskCiGateResponse400 | restartTaskCiGateResponse404 | restartTaskCiGateResponse409) & {
+  headers: Headers;
+};
+
+export type restartTaskCiGateResponse = (restartTaskCiGateResponseSuccess | restartTaskCiGateResponseError)
+
+export const getRestartTaskCiGateUrl = (taskId: string,) => {
+
+
+  
+
+  return `/api/tasks/${taskId}/ci-gate`
+}
+
+export const restartTaskCiGate = async (taskId: string, options?: RequestInit): Promise<restartTaskCiGateResponse> => {
+  
+  return customFetch<restartTaskCiGateResponse>(getRestartTaskCiGateUrl(taskId),
+  {      
+    ...options,
+    method: 'POST'
+    
+    
+  }
+);}
+
+
+
+
+export const getRestartTaskCiGateMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof restartTaskCiGate>>, TError,{taskId: string}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof restartTaskCiGate>>, TError,{taskId: string}, TContext> => {
+
+const mutationKey = ['restartTaskCiGate'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof restartTaskCiGate>>, {taskId: string}> = (props) => {
+          const {taskId} = props ?? {};
+
+          return  restartTaskCiGate(taskId,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type RestartTaskCiGateMutationResult = NonNullable<Awaited<ReturnType<typeof restartTaskCiGate>>>
+    
+    export type RestartTaskCiGateMutationError = void
+
+    export const useRestartTaskCiGate = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof restartTaskCiGate>>, TError,{taskId: string}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof restartTaskCiGate>>,
+        TError,
+        {taskId: string},
+        TContext
+      > => {
+
+      const mutationOptions = getRestartTaskCiGateMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    
\ No newline at end of file
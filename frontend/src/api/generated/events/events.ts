@@ -21,7 +21,9 @@ import type {
 } from '@tanstack/react-query';
 
 import type {
-  EventsStreamParams
+  EventEnvelope,
+  EventsStreamParams,
+  ListEventHistoryParams
 } from '.././model';
 
 import { customFetch } from '../../../lib/api-fetcher';
@@ -35,13 +37,20 @@ export type eventsStreamResponse200 = {
   data: void
   status: 200
 }
+
+export type eventsStreamResponse400 = {
+  data: void
+  status: 400
+}
     
 export type eventsStreamResponseSuccess = (eventsStreamResponse200) & {
   headers: Headers;
 };
-;
+export type eventsStreamResponseError = (eventsStreamResponse400) & {
+  headers: Headers;
+};
 
-export type eventsStreamResponse = (eventsStreamResponseSuccess)
+export type eventsStreamResponse = (eventsStreamResponseSuccess | eventsStreamResponseError)
 
 export const getEventsStreamUrl = (params?: EventsStreamParams,) => {
   const normalizedParams = new URLSearchParams();
@@ -80,7 +89,7 @@ export const getEventsStreamQueryKey = (params?: EventsStreamParams,) => {
     }
 
     
-export const getEventsStreamQueryOptions = <TData = Awaited<ReturnType<typeof eventsStream>>, TError = unknown>(params?: EventsStreamParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof eventsStream>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+export const getEventsStreamQueryOptions = <TData = Awaited<ReturnType<typeof eventsStream>>, TError = void>(params?: EventsStreamParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof eventsStream>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
 ) => {
 
 const {query: queryOptions, request: requestOptions} = options ?? {};
@@ -99,10 +108,10 @@ const {query: queryOptions, request: requestOptions} = options ?? {};
 }
 
 export type EventsStreamQueryResult = NonNullable<Awaited<ReturnType<typeof eventsStream>>>
-export type EventsStreamQueryError = unknown
+export type EventsStreamQueryError = void
 
 
-export function useEventsStream<TData = Awaited<ReturnType<typeof eventsStream>>, TError = unknown>(
+export function useEventsStream<TData = Awaited<ReturnType<typeof eventsStream>>, TError = void>(
  params: undefined |  EventsStreamParams, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof eventsStream>>, TError, TData>> & Pick<
         DefinedInitialDataOptions<
           Awaited<ReturnType<typeof eventsStream>>,
@@ -112,7 +121,7 @@ export function useEventsStream<TData = Awaited<ReturnType<typeof eventsStream>>
       >, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient
   ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
-export function useEventsStream<TData = Awaited<ReturnType<typeof eventsStream>>, TError = unknown>(
+export function useEventsStream<TData = Awaited<ReturnType<typeof eventsStream>>, TError = void>(
  params?: EventsStreamParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof eventsStream>>, TError, TData>> & Pick<
         UndefinedInitialDataOptions<
           Awaited<ReturnType<typeof eventsStream>>,
@@ -122,12 +131,12 @@ export function useEventsStream<TData = Awaited<ReturnType<typeof eventsStream>>
       >, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient
   ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
-export function useEventsStream<TData = Awaited<ReturnType<typeof eventsStream>>, TError = unknown>(
+export function useEventsStream<TData = Awaited<ReturnType<typeof eventsStream>>, TError = void>(
  params?: EventsStreamParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof eventsStream>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient
   ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
 
-export function useEventsStream<TData = Awaited<ReturnType<typeof eventsStream>>, TError = unknown>(
+export function useEventsStream<TData = Awaited<ReturnType<typeof eventsStream>>, TError = void>(
  params?: EventsStreamParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof eventsStream>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient 
  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
@@ -144,3 +153,123 @@ export function useEventsStream<TData = Awaited<ReturnType<typeof eventsStream>>
 
 
 
+export type listEventHistoryResponse200 = {
+  data: EventEnvelope[]
+  status: 200
+}
+
+export type listEventHistoryResponse400 = {
+  data: void
+  status: 400
+}
+    
+export type listEventHistoryResponseSuccess = (listEventHistoryResponse200) & {
+  headers: Headers;
+};
+export type listEventHistoryResponseError = (listEventHistoryResponse400) & {
+  headers: Headers;
+};
+
+export type listEventHistoryResponse = (listEventHistoryResponseSuccess | listEventHistoryResponseError)
+
+export const getListEventHistoryUrl = (params?: ListEventHistoryParams,) => {
+  const normalizedParams = new URLSearchParams();
+
+  Object.entries(params || {}).forEach(([key, value]) => {
+    
+    if (value !== undefined) {
+      normalizedParams.append(key, value === null ? 'null' : value.toString())
+    }
+  });
+
+  const stringifiedParams = normalizedParams.toString();
+
+  return stringifiedParams.length > 0 ? `/api/events/history?${stringifiedParams}` : `/api/events/history`
+}
+
+export const listEventHistory = async (params?: ListEventHistoryParams, options?: RequestInit): Promise<listEventHistoryResponse> => {
+  
+  return customFetch<listEventHistoryResponse>(getListEventHistoryUrl(params),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getListEventHistoryQueryKey = (params?: ListEventHistoryParams,) => {
+    return [
+    `/api/events/history`, ...(params ? [params]: [])
+    ] as const;
+    }
+
+    
+export const getListEventHistoryQueryOptions = <TData = Awaited<ReturnType<typeof listEventHistory>>, TError = void>(params?: ListEventHistoryParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listEventHistory>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getListEventHistoryQueryKey(params);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof listEventHistory>>> = ({ signal }) => listEventHistory(params, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof listEventHistory>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type ListEventHistoryQueryResult = NonNullable<Awaited<ReturnType<typeof listEventHistory>>>
+export type ListEventHistoryQueryError = void
+
+
+export function useListEventHistory<TData = Awaited<ReturnType<typeof listEventHistory>>, TError = void>(
+ params: undefined |  ListEventHistoryParams, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof listEventHistory>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof listEventHistory>>,
+          TError,
+          Awaited<ReturnType<typeof listEventHistory>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useListEventHistory<TData = Awaited<ReturnType<typeof listEventHistory>>, TError = void>(
+ params?: ListEventHistoryParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listEventHistory>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof listEventHistory>>,
+          TError,
+          Awaited<ReturnType<typeof listEventHistory>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useListEventHistory<TData = Awaited<ReturnType<typeof listEventHistory>>, TError = void>(
+ params?: ListEventHistoryParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listEventHistory>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useListEventHistory<TData = Awaited<ReturnType<typeof listEventHistory>>, TError = void>(
+ params?: ListEventHistoryParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listEventHistory>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getListEventHistoryQueryOptions(params,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
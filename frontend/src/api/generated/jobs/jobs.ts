@@ -0,0 +1,158 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import {
+  useQuery
+} from '@tanstack/react-query';
+import type {
+  DataTag,
+  DefinedInitialDataOptions,
+  DefinedUseQueryResult,
+  QueryClient,
+  QueryFunction,
+  QueryKey,
+  UndefinedInitialDataOptions,
+  UseQueryOptions,
+  UseQueryResult
+} from '@tanstack/react-query';
+
+import type {
+  GetJobLogsParams,
+  JobLogsResponse
+} from '.././model';
+
+import { customFetch } from '../../../lib/api-fetcher';
+
+
+type SecondParameter<T extends (...args: never) => unknown> = Parameters<T>[1];
+
+
+
+export type getJobLogsResponse200 = {
+  data: JobLogsResponse
+  status: 200
+}
+
+export type getJobLogsResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type getJobLogsResponseSuccess = (getJobLogsResponse200) & {
+  headers: Headers;
+};
+export type getJobLogsResponseError = (getJobLogsResponse404) & {
+  headers: Headers;
+};
+
+export type getJobLogsResponse = (getJobLogsResponseSuccess | getJobLogsResponseError)
+
+export const getGetJobLogsUrl = (id: string,
+    params?: GetJobLogsParams,) => {
+  const normalizedParams = new URLSearchParams();
+
+  Object.entries(params || {}).forEach(([key, value]) => {
+    
+    if (value !== undefined) {
+      normalizedParams.append(key, value === null ? 'null' : value.toString())
+    }
+  });
+
+  const stringifiedParams = normalizedParams.toString();
+
+  return stringifiedParams.length > 0 ? `/api/jobs/${id}/logs?${stringifiedParams}` : `/api/jobs/${id}/logs`
+}
+
+export const getJobLogs = async (id: string,
+    params?: GetJobLogsParams, options?: RequestInit): Promise<getJobLogsResponse> => {
+  
+  return customFetch<getJobLogsResponse>(getGetJobLogsUrl(id,params),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetJobLogsQueryKey = (id?: string,
+    params?: GetJobLogsParams,) => {
+    return [
+    `/api/jobs/${id}/logs`, ...(params ? [params]: [])
+    ] as const;
+    }
+
+    
+export const getGetJobLogsQueryOptions = <TData = Awaited<ReturnType<typeof getJobLogs>>, TError = void>(id: string,
+    params?: GetJobLogsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getJobLogs>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetJobLogsQueryKey(id,params);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getJobLogs>>> = ({ signal }) => getJobLogs(id,params, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, enabled: !!(id), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getJobLogs>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetJobLogsQueryResult = NonNullable<Awaited<ReturnType<typeof getJobLogs>>>
+export type GetJobLogsQueryError = void
+
+
+export function useGetJobLogs<TData = Awaited<ReturnType<typeof getJobLogs>>, TError = void>(
+ id: string,
+    params?: GetJobLogsParams, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getJobLogs>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getJobLogs>>,
+          TError,
+          Awaited<ReturnType<typeof getJobLogs>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetJobLogs<TData = Awaited<ReturnType<typeof getJobLogs>>, TError = void>(
+ id: string,
+    params?: GetJobLogsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getJobLogs>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getJobLogs>>,
+          TError,
+          Awaited<ReturnType<typeof getJobLogs>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetJobLogs<TData = Awaited<ReturnType<typeof getJobLogs>>, TError = void>(
+ id: string,
+    params?: GetJobLogsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getJobLogs>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetJobLogs<TData = Awaited<ReturnType<typeof getJobLogs>>, TError = void>(
+ id: string,
+    params?: GetJobLogsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getJobLogs>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetJobLogsQueryOptions(id,params,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
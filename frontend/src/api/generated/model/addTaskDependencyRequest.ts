@@ -0,0 +1,12 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export interface AddTaskDependencyRequest {
+  /** Task that must be done first */
+  depends_on: string;
+}
@@ -0,0 +1,16 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export interface AgentMessageData {
+  /** The message content */
+  content: string;
+  /** Whether this is a partial/streaming message */
+  is_partial: boolean;
+  /** Message role (assistant, user, system) */
+  role: string;
+}
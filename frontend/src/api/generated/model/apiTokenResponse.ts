@@ -0,0 +1,19 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { ApiTokenResponseLastUsedAt } from './apiTokenResponseLastUsedAt';
+import type { ApiTokenResponseRevokedAt } from './apiTokenResponseRevokedAt';
+
+export interface ApiTokenResponse {
+  created_at: number;
+  id: string;
+  last_used_at?: ApiTokenResponseLastUsedAt;
+  name: string;
+  revoked_at?: ApiTokenResponseRevokedAt;
+  /** `read:tasks`, `read:wiki`, `write:tasks` or `admin` */
+  scopes: string[];
+}
@@ -0,0 +1,21 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * Shape of a wiki answer
+ */
+export type AskAnswerMode = typeof AskAnswerMode[keyof typeof AskAnswerMode];
+
+
+// eslint-disable-next-line @typescript-eslint/no-redeclare
+export const AskAnswerMode = {
+  standard: 'standard',
+  concise: 'concise',
+  detailed: 'detailed',
+  code_only: 'code_only',
+} as const;
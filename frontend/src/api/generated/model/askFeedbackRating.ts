@@ -0,0 +1,16 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export type AskFeedbackRating = typeof AskFeedbackRating[keyof typeof AskFeedbackRating];
+
+
+// eslint-disable-next-line @typescript-eslint/no-redeclare
+export const AskFeedbackRating = {
+  up: 'up',
+  down: 'down',
+} as const;
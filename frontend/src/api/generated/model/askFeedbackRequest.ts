@@ -0,0 +1,19 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { AskFeedbackRating } from './askFeedbackRating';
+import type { AskFeedbackRequestComment } from './askFeedbackRequestComment';
+import type { AskFeedbackSource } from './askFeedbackSource';
+
+export interface AskFeedbackRequest {
+  answer: string;
+  /** What was wrong or right about the answer */
+  comment?: AskFeedbackRequestComment;
+  question: string;
+  rating: AskFeedbackRating;
+  sources?: AskFeedbackSource[];
+}
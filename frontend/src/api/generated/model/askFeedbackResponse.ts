@@ -0,0 +1,21 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { AskFeedbackRating } from './askFeedbackRating';
+import type { AskFeedbackResponseComment } from './askFeedbackResponseComment';
+import type { AskFeedbackSource } from './askFeedbackSource';
+
+export interface AskFeedbackResponse {
+  answer: string;
+  comment?: AskFeedbackResponseComment;
+  conversation_id: string;
+  created_at: string;
+  id: string;
+  question: string;
+  rating: AskFeedbackRating;
+  sources: AskFeedbackSource[];
+}
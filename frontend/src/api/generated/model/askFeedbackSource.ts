@@ -0,0 +1,18 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * Code range an answer was built from, as returned in `AskResponse.sources`
+ */
+export interface AskFeedbackSource {
+  /** @minimum 0 */
+  end_line: number;
+  file_path: string;
+  /** @minimum 0 */
+  start_line: number;
+}
@@ -6,8 +6,14 @@
  * OpenAPI spec version: 0.1.0
  */
 import type { AskRequestConversationId } from './askRequestConversationId';
+import type { AskRequestMode } from './askRequestMode';
+import type { AskRequestRoot } from './askRequestRoot';
 
 export interface AskRequest {
   conversation_id?: AskRequestConversationId;
+  /** Shape of the answer; `standard` when left out */
+  mode?: AskRequestMode;
   question: string;
+  /** Only answer from code under this workspace root */
+  root?: AskRequestRoot;
 }
@@ -5,10 +5,13 @@
  * API for OpenCode Studio - AI-powered development platform
  * OpenAPI spec version: 0.1.0
  */
+import type { AskAnswerMode } from './askAnswerMode';
 import type { AskSource } from './askSource';
 
 export interface AskResponse {
   answer: string;
   conversation_id: string;
+  /** Mode the answer was written in */
+  mode: AskAnswerMode;
   sources: AskSource[];
 }
@@ -5,13 +5,19 @@
  * API for OpenCode Studio - AI-powered development platform
  * OpenAPI spec version: 0.1.0
  */
+import type { AskSourceExpansion } from './askSourceExpansion';
+import type { AskSourceSummary } from './askSourceSummary';
 
 export interface AskSource {
   /** @minimum 0 */
   end_line: number;
+  /** Set when the source was added by call-graph expansion rather than search */
+  expansion?: AskSourceExpansion;
   file_path: string;
   score: number;
   snippet: string;
   /** @minimum 0 */
   start_line: number;
+  /** One-line summary of the source's code, written at index time */
+  summary?: AskSourceSummary;
 }
@@ -0,0 +1,19 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { AskAnswerMode } from './askAnswerMode';
+import type { AskSource } from './askSource';
+
+/**
+ * Server-sent event of `/api/wiki/ask/stream`; the SSE event name is the
+ * `type` tag
+ */
+export type AskStreamEvent =
+  | { type: 'sources'; conversation_id: string; mode: AskAnswerMode; sources: AskSource[] }
+  | { type: 'token'; content: string }
+  | { type: 'done'; answer: string }
+  | { type: 'error'; message: string };
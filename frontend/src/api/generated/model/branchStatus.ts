@@ -5,21 +5,39 @@
  * API for OpenCode Studio - AI-powered development platform
  * OpenAPI spec version: 0.1.0
  */
+import type { BranchStatusActiveJobId } from './branchStatusActiveJobId';
 import type { BranchStatusCurrentItem } from './branchStatusCurrentItem';
 import type { BranchStatusCurrentPhase } from './branchStatusCurrentPhase';
+import type { BranchStatusEmbeddingModel } from './branchStatusEmbeddingModel';
+import type { BranchStatusEmbeddingVersion } from './branchStatusEmbeddingVersion';
 import type { BranchStatusErrorMessage } from './branchStatusErrorMessage';
 import type { BranchStatusLastIndexedAt } from './branchStatusLastIndexedAt';
+import type { BranchStatusMigratingToModel } from './branchStatusMigratingToModel';
 
 export interface BranchStatus {
+  /**
+   * Indexing or generation job running on the branch
+   */
+  active_job_id?: BranchStatusActiveJobId;
   branch: string;
   /** @minimum 0 */
   chunk_count: number;
   current_item?: BranchStatusCurrentItem;
   current_phase?: BranchStatusCurrentPhase;
+  /** Embedding model serving searches on the branch */
+  embedding_model?: BranchStatusEmbeddingModel;
+  embedding_version?: BranchStatusEmbeddingVersion;
   error_message?: BranchStatusErrorMessage;
   /** @minimum 0 */
   file_count: number;
+  /**
+   * Commits of earlier indexes that searches can target with `as_of`,
+   * newest first
+   */
+  kept_commits: string[];
   last_indexed_at?: BranchStatusLastIndexedAt;
+  /** Model a re-embed is migrating the branch to */
+  migrating_to_model?: BranchStatusMigratingToModel;
   /** @minimum 0 */
   page_count: number;
   /** @minimum 0 */
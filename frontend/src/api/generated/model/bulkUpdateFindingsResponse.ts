@@ -0,0 +1,19 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { BulkFindingResult } from './bulkFindingResult';
+import type { FindingStatus } from './findingStatus';
+
+export interface BulkUpdateFindingsResponse {
+  results: BulkFindingResult[];
+  status: FindingStatus;
+  /**
+   * Number of findings whose status changed
+   * @minimum 0
+   */
+  updated: number;
+}
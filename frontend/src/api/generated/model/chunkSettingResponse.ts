@@ -0,0 +1,14 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export interface ChunkSettingResponse {
+  /** @minimum 0 */
+  chunk_overlap: number;
+  /** @minimum 0 */
+  max_chunk_tokens: number;
+}
@@ -0,0 +1,20 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { ChunkSettingResponse } from './chunkSettingResponse';
+
+export interface ChunkSettingScoreResponse {
+  /**
+   * Chunks the sample was split into
+   * @minimum 0
+   */
+  chunks: number;
+  /** Share of queries that found their definition among the top 5 chunks */
+  hit_rate: number;
+  mean_reciprocal_rank: number;
+  setting: ChunkSettingResponse;
+}
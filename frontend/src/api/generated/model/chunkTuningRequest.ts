@@ -0,0 +1,15 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { ChunkTuningRequestMaxFiles } from './chunkTuningRequestMaxFiles';
+
+export interface ChunkTuningRequest {
+  /** Store the recommended setting for the next indexing run */
+  apply?: boolean;
+  /** Files to sample, at most 200 (default: 40) */
+  max_files?: ChunkTuningRequestMaxFiles;
+}
@@ -0,0 +1,27 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { ChunkSettingResponse } from './chunkSettingResponse';
+import type { ChunkSettingScoreResponse } from './chunkSettingScoreResponse';
+
+export interface ChunkTuningResponse {
+  /** Whether the recommended setting was stored for the next indexing run */
+  applied: boolean;
+  embedding_model: string;
+  /**
+   * Files sampled
+   * @minimum 0
+   */
+  files: number;
+  /**
+   * Function definitions searched for
+   * @minimum 0
+   */
+  queries: number;
+  recommended: ChunkSettingResponse;
+  scores: ChunkSettingScoreResponse[];
+}
@@ -0,0 +1,31 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { CiGateState } from './ciGateState';
+
+/**
+ * GitHub checks a task waits on after its workspace was merged or a pull
+ * request was opened from it
+ */
+export interface CiGateRecord {
+  /** Move the task to done once the checks pass */
+  complete_on_pass?: boolean;
+  /** @minimum 0 */
+  failed: number;
+  /** Names of the checks that failed */
+  failed_checks?: string[];
+  /** @minimum 0 */
+  passed: number;
+  /** @minimum 0 */
+  pending: number;
+  /** Branch or commit the checks run on */
+  ref_name: string;
+  started_at: string;
+  state: CiGateState;
+  task_id: string;
+  updated_at: string;
+}
@@ -0,0 +1,21 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * Where the GitHub checks gating a task's completion stand
+ */
+export type CiGateState = typeof CiGateState[keyof typeof CiGateState];
+
+
+// eslint-disable-next-line @typescript-eslint/no-redeclare
+export const CiGateState = {
+  pending: 'pending',
+  passed: 'passed',
+  failed: 'failed',
+  timed_out: 'timed_out',
+} as const;
@@ -0,0 +1,18 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export interface CommitResolutionResponse {
+  /** Identifier of the resolution commit (a change id for jujutsu) */
+  commit: string;
+  /**
+   * Whether the commit completed a merge into main that had recorded
+   * conflicts
+   */
+  merged: boolean;
+  task_id: string;
+}
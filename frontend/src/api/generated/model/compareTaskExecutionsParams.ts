@@ -0,0 +1,20 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export type CompareTaskExecutionsParams = {
+/**
+ * Run number to compare from
+ * @minimum 0
+ */
+from: number;
+/**
+ * Run number to compare to
+ * @minimum 0
+ */
+to: number;
+};
@@ -9,6 +9,10 @@ import type { CompleteTaskResponseMergeResult } from './completeTaskResponseMerg
 import type { CompleteTaskResponsePr } from './completeTaskResponsePr';
 
 export interface CompleteTaskResponse {
+  /**
+   * The task stays in review until the GitHub checks of its changes pass
+   */
+  ci_pending: boolean;
   merge_result?: CompleteTaskResponseMergeResult;
   pr?: CompleteTaskResponsePr;
   success: boolean;
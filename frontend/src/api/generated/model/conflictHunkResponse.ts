@@ -0,0 +1,25 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { ConflictHunkResponseBase } from './conflictHunkResponseBase';
+
+export interface ConflictHunkResponse {
+  /** Common ancestor's lines, when the conflict markers include them */
+  base?: ConflictHunkResponseBase;
+  /**
+   * 1-based line of the closing conflict marker
+   * @minimum 0
+   */
+  end_line: number;
+  ours: string;
+  /**
+   * 1-based line of the opening conflict marker
+   * @minimum 0
+   */
+  start_line: number;
+  theirs: string;
+}
@@ -0,0 +1,20 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * Side of a conflict to keep. For a rebase onto main, as jujutsu merges,
+ * ours is main's and theirs the workspace's.
+ */
+export type ConflictSide = typeof ConflictSide[keyof typeof ConflictSide];
+
+
+// eslint-disable-next-line @typescript-eslint/no-redeclare
+export const ConflictSide = {
+  ours: 'ours',
+  theirs: 'theirs',
+} as const;
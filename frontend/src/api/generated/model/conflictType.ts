@@ -0,0 +1,19 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export type ConflictType = typeof ConflictType[keyof typeof ConflictType];
+
+
+// eslint-disable-next-line @typescript-eslint/no-redeclare
+export const ConflictType = {
+  content: 'content',
+  add_add: 'add_add',
+  modify_delete: 'modify_delete',
+  delete_modify: 'delete_modify',
+  rename: 'rename',
+} as const;
@@ -0,0 +1,19 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { ConflictHunkResponse } from './conflictHunkResponse';
+import type { ConflictType } from './conflictType';
+
+export interface ConflictedFileResponse {
+  conflict_type: ConflictType;
+  /**
+   * Conflicted regions, in file order; empty for files without conflict
+   * markers, such as deleted or binary ones
+   */
+  hunks: ConflictHunkResponse[];
+  path: string;
+}
@@ -0,0 +1,14 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { ConflictedFileResponse } from './conflictedFileResponse';
+
+export interface ConflictsResponse {
+  /** Conflicted files; empty once every conflict is resolved */
+  files: ConflictedFileResponse[];
+  task_id: string;
+}
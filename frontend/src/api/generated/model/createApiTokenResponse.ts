@@ -0,0 +1,14 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { ApiTokenResponse } from './apiTokenResponse';
+
+export interface CreateApiTokenResponse {
+  api_token: ApiTokenResponse;
+  /** The token itself; it is only shown once */
+  token: string;
+}
@@ -0,0 +1,13 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * How long the link stays open, at most 720 hours (default: 72)
+ * @minimum 0
+ */
+export type CreateSessionShareRequestExpiresInHours = number | null;
@@ -0,0 +1,16 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { SessionShareResponse } from './sessionShareResponse';
+
+export interface CreateSessionShareResponse {
+  share: SessionShareResponse;
+  /** The link's token; it is only shown once */
+  token: string;
+  /** Path of the read-only view, relative to the server */
+  url: string;
+}
@@ -0,0 +1,17 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { CreateWorkspacePrRequestBaseBranch } from './createWorkspacePrRequestBaseBranch';
+import type { CreateWorkspacePrRequestTitle } from './createWorkspacePrRequestTitle';
+
+export interface CreateWorkspacePrRequest {
+  /** Branch to merge into; the main branch by default */
+  base_branch?: CreateWorkspacePrRequestBaseBranch;
+  draft?: boolean;
+  /** Pull request title; the task title by default */
+  title?: CreateWorkspacePrRequestTitle;
+}
@@ -0,0 +1,30 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { ColumnCount } from './columnCount';
+import type { EventEnvelope } from './eventEnvelope';
+import type { MonthlySpend } from './monthlySpend';
+import type { PendingApproval } from './pendingApproval';
+import type { RunningExecution } from './runningExecution';
+import type { WikiFreshness } from './wikiFreshness';
+
+export interface DashboardResponse {
+  generated_at: string;
+  /** Oldest first */
+  pending_approvals: PendingApproval[];
+  /** Latest events, newest first; empty when event history is off */
+  recent_events: EventEnvelope[];
+  running_executions: RunningExecution[];
+  spend: MonthlySpend;
+  /** Tasks per board column, in board order */
+  task_counts: ColumnCount[];
+  /**
+   * Index state of the configured wiki branches; empty when the wiki is
+   * disabled
+   */
+  wiki: WikiFreshness[];
+}
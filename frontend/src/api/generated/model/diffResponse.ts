@@ -5,8 +5,14 @@
  * API for OpenCode Studio - AI-powered development platform
  * OpenAPI spec version: 0.1.0
  */
+import type { ConflictedFileResponse } from './conflictedFileResponse';
 
 export interface DiffResponse {
+  /**
+   * Files a merge left conflicted, with their conflicted regions; empty
+   * unless the workspace holds an unresolved merge
+   */
+  conflicts: ConflictedFileResponse[];
   diff: string;
   task_id: string;
 }
@@ -0,0 +1,25 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { DiffFileSummary } from './diffFileSummary';
+import type { DiffRisk } from './diffRisk';
+
+export interface DiffSummaryResponse {
+  /** Whether the summary was cached from an earlier request */
+  cached: boolean;
+  created_at: string;
+  /** Per-file summaries, in diff order */
+  files: DiffFileSummary[];
+  /**
+   * Head commit the summary describes, with a digest of the diff appended
+   * when the workspace has uncommitted changes
+   */
+  head_commit: string;
+  /** Changes a reviewer should look at closely */
+  risks: DiffRisk[];
+  task_id: string;
+}
@@ -0,0 +1,32 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { DigestConfigBudgetUsd } from './digestConfigBudgetUsd';
+import type { DigestConfigFrom } from './digestConfigFrom';
+import type { DigestConfigSmtpHost } from './digestConfigSmtpHost';
+import type { DigestConfigSmtpPassword } from './digestConfigSmtpPassword';
+import type { DigestConfigSmtpUsername } from './digestConfigSmtpUsername';
+
+/**
+ * Email digests of board activity, sent over SMTP
+ */
+export interface DigestConfig {
+  /** Model spend in USD per digest period the usage section is compared to */
+  budget_usd?: DigestConfigBudgetUsd;
+  /** Send digests to users who scheduled them */
+  enabled?: boolean;
+  /** Sender address, e.g. "OpenCode Studio <studio@example.com>" */
+  from?: DigestConfigFrom;
+  /** SMTP server, e.g. "smtp.example.com" */
+  smtp_host?: DigestConfigSmtpHost;
+  smtp_password?: DigestConfigSmtpPassword;
+  /** @minimum 0 */
+  smtp_port?: number;
+  /** Upgrade the connection with STARTTLS; off only for local relays */
+  smtp_starttls?: boolean;
+  smtp_username?: DigestConfigSmtpUsername;
+}
@@ -0,0 +1,19 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * Where a resolved variable was defined
+ */
+export type EnvVarSource = typeof EnvVarSource[keyof typeof EnvVarSource];
+
+
+// eslint-disable-next-line @typescript-eslint/no-redeclare
+export const EnvVarSource = {
+  project: 'project',
+  task: 'task',
+} as const;
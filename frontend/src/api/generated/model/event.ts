@@ -0,0 +1,41 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { AgentMessageData } from './agentMessageData';
+import type { ToolExecutionData } from './toolExecutionData';
+import type { WikiGenerationPhase } from './wikiGenerationPhase';
+
+export type Event =
+  | { type: 'task.created'; task_id: string; title: string }
+  | { type: 'task.updated'; task_id: string }
+  | { type: 'task.status_changed'; task_id: string; from_status: string; to_status: string }
+  | { type: 'findings.updated'; task_id: string; finding_ids: string[]; status: string }
+  | { type: 'task.transition_rule_evaluated'; task_id: string; outcome: string; matched: boolean; from_status: string; to_status: string; queued_phase?: string | null; reason?: string | null }
+  | { type: 'session.started'; session_id: string; task_id: string; phase: string; status: string; opencode_session_id?: string | null; created_at: string }
+  | { type: 'session.ended'; session_id: string; task_id: string; success: boolean }
+  | { type: 'phase.completed'; task_id: string; session_id: string; phase_number: number; total_phases: number; phase_title: string }
+  | { type: 'phase.continuing'; task_id: string; next_phase_number: number; total_phases: number }
+  | { type: 'phase.progress'; task_id: string; session_id?: string | null; phase: string; step: string; current: number; total: number; findings_count?: number | null; message?: string | null }
+  | { type: 'agent.message'; session_id: string; task_id: string; message: AgentMessageData }
+  | { type: 'tool.execution'; session_id: string; task_id: string; tool: ToolExecutionData }
+  | { type: 'workspace.created'; task_id: string; path: string }
+  | { type: 'workspace.merged'; task_id: string; success: boolean }
+  | { type: 'workspace.deleted'; task_id: string }
+  | { type: 'workspace.path_violation'; task_id: string; session_id: string; tool: string; path: string; blocked: boolean }
+  | { type: 'ci.status_changed'; task_id: string; ref_name: string; state: string; passed: number; failed: number; pending: number }
+  | { type: 'project.opened'; path: string; name: string; was_initialized: boolean }
+  | { type: 'project.closed'; path: string }
+  | { type: 'wiki.generation_progress'; branch: string; phase: WikiGenerationPhase; current: number; total: number; current_item?: string | null; message?: string | null }
+  | { type: 'wiki.index_refreshed'; branch: string; commit_sha?: string | null; chunk_count: number }
+  | { type: 'wiki.docs_refreshed'; task_id: string; branch: string; pages: number; action: string }
+  | { type: 'roadmap.generation_started' }
+  | { type: 'roadmap.generation_progress'; phase: string; progress: number; message: string }
+  | { type: 'roadmap.generation_completed'; feature_count: number; phase_count: number }
+  | { type: 'roadmap.generation_failed'; error: string }
+  | { type: 'roadmap.feature_updated'; feature_id: string; status?: string | null }
+  | { type: 'roadmap.feature_converted'; feature_id: string; task_id: string }
+  | { type: 'error'; message: string; context?: string | null };
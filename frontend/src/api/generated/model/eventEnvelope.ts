@@ -0,0 +1,22 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { Event } from './event';
+
+export interface EventEnvelope {
+  /** The actual event */
+  event: Event;
+  /** Unique event ID */
+  id: string;
+  /**
+   * Version of the event schema the envelope follows
+   * @minimum 0
+   */
+  schema_version?: number;
+  /** When the event occurred */
+  timestamp: string;
+}
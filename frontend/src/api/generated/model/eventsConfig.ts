@@ -0,0 +1,20 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * Event history configuration
+ */
+export interface EventsConfig {
+  /** Store published events so they can be queried and replayed later */
+  persist?: boolean;
+  /**
+   * Days stored events are kept for
+   * @minimum 0
+   */
+  retention_days?: number;
+}
@@ -11,4 +11,17 @@ export type EventsStreamParams = {
  * Comma-separated task IDs to filter events
  */
 task_ids?: string;
+/**
+ * Comma-separated event types to send, e.g. task.created, or classes like session.* (default: all)
+ */
+types?: string;
+/**
+ * Replay stored events after this sequence number first, e.g. the X-Event-Seq of an earlier response; a Last-Event-ID header takes precedence. At most 500 events are replayed; X-Event-Replay-Next-Seq then gives the since_seq to continue from
+ */
+since_seq?: number;
+/**
+ * Highest event schema version the client understands (default: current)
+ * @minimum 0
+ */
+version?: number;
 };
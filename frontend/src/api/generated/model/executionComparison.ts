@@ -0,0 +1,27 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { ReviewFinding } from './reviewFinding';
+import type { TaskExecution } from './taskExecution';
+
+/**
+ * Differences between two runs of a task
+ */
+export interface ExecutionComparison {
+  /** Files only `to` changed */
+  added_files: string[];
+  from: TaskExecution;
+  /** Findings `to` reports that `from` did not */
+  new_findings: ReviewFinding[];
+  /** Findings both runs report, as `to` reported them */
+  persisting_findings: ReviewFinding[];
+  /** Files only `from` changed */
+  removed_files: string[];
+  /** Findings of `from` that `to` no longer reports */
+  resolved_findings: ReviewFinding[];
+  to: TaskExecution;
+}
@@ -0,0 +1,60 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { ExecutionConfigReviewLanguage } from './executionConfigReviewLanguage';
+import type { ReviewPolicy } from './reviewPolicy';
+
+/**
+ * Session execution configuration
+ */
+export interface ExecutionConfig {
+  /** Re-rate review finding severities against a rubric and the project guidelines */
+  calibrate_review_severity?: boolean;
+  /**
+   * Keep tasks from done until the GitHub checks of their merged or
+   * pushed changes pass; needs a GitHub token
+   */
+  ci_gate?: boolean;
+  /**
+   * Token budget for the files a plan cites, attached to implementation
+   * prompts so the model doesn't have to look for them; 0 attaches none
+   * @minimum 0
+   */
+  context_file_tokens?: number;
+  /**
+   * Raise the severity of review findings on code the wiki index tagged
+   * as public API: HTTP handlers, exported items and CLI commands
+   */
+  escalate_public_api_findings?: boolean;
+  /**
+   * How many times a failed phase session can be retried
+   * @minimum 0
+   */
+  max_session_retries?: number;
+  review_language?: ExecutionConfigReviewLanguage;
+  /**
+   * Review samples that must report a finding for it to be kept
+   * @minimum 0
+   */
+  review_min_agreement?: number;
+  /** Give each extra review sample a different focus, e.g. security */
+  review_personas?: boolean;
+  /** Which review findings send a task to the fix phase */
+  review_policy?: ReviewPolicy;
+  /**
+   * Reviews run per review round; findings need agreement across them.
+   * Each extra sample costs about one more review prompt of tokens
+   * @minimum 0
+   */
+  review_samples?: number;
+  /**
+   * Paths outside the workspace that implementation and fix sessions may
+   * write, absolute or relative to the workspace; any other write outside
+   * it is denied and reported as a finding
+   */
+  shared_paths?: string[];
+}
@@ -0,0 +1,13 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * Language review summaries and findings are written in, e.g. `de` or
+ * `Japanese`; English when unset
+ */
+export type ExecutionConfigReviewLanguage = string | null;
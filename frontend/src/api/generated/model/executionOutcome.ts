@@ -0,0 +1,19 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export type ExecutionOutcome = typeof ExecutionOutcome[keyof typeof ExecutionOutcome];
+
+
+// eslint-disable-next-line @typescript-eslint/no-redeclare
+export const ExecutionOutcome = {
+  running: 'running',
+  failed: 'failed',
+  approved: 'approved',
+  findings_reported: 'findings_reported',
+  completed: 'completed',
+} as const;
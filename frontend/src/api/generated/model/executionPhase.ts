@@ -0,0 +1,25 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { SessionPhase } from './sessionPhase';
+import type { SessionStatus } from './sessionStatus';
+
+/**
+ * Sessions of one phase within a run
+ */
+export interface ExecutionPhase {
+  /** Time spent in the phase's finished sessions */
+  duration_secs: number;
+  phase: SessionPhase;
+  /**
+   * Sessions that ran the phase, including retries and review rounds
+   * @minimum 0
+   */
+  sessions: number;
+  /** Status of the phase's latest session */
+  status: SessionStatus;
+}
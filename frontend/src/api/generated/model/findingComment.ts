@@ -0,0 +1,18 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { FindingCommentAuthor } from './findingCommentAuthor';
+
+/**
+ * A comment in the discussion of a finding
+ */
+export interface FindingComment {
+  author: FindingCommentAuthor;
+  body: string;
+  created_at: string;
+  id: string;
+}
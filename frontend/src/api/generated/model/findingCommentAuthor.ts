@@ -0,0 +1,19 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * Who wrote a comment on a finding
+ */
+export type FindingCommentAuthor = typeof FindingCommentAuthor[keyof typeof FindingCommentAuthor];
+
+
+// eslint-disable-next-line @typescript-eslint/no-redeclare
+export const FindingCommentAuthor = {
+  human: 'human',
+  agent: 'agent',
+} as const;
@@ -17,4 +17,5 @@ export const FindingStatus = {
   pending: 'pending',
   fixed: 'fixed',
   skipped: 'skipped',
+  dismissed: 'dismissed',
 } as const;
@@ -0,0 +1,17 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * A wiki page documenting flagged sources; a candidate for regeneration
+ */
+export interface FlaggedPageResponse {
+  /** Flagged files the page documents or cites */
+  files: string[];
+  slug: string;
+  title: string;
+}
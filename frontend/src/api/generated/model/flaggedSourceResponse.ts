@@ -0,0 +1,22 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * A code range mostly seen in rated-down answers; a candidate for re-chunking
+ */
+export interface FlaggedSourceResponse {
+  /** @minimum 0 */
+  end_line: number;
+  file_path: string;
+  /** @minimum 0 */
+  negative: number;
+  /** @minimum 0 */
+  positive: number;
+  /** @minimum 0 */
+  start_line: number;
+}
@@ -5,9 +5,15 @@
  * API for OpenCode Studio - AI-powered development platform
  * OpenAPI spec version: 0.1.0
  */
+import type { GenerateWikiResponseJobId } from './generateWikiResponseJobId';
 
 export interface GenerateWikiResponse {
   branch: string;
+  /**
+   * Job generating the branch: the one started, or when `started` is false
+   * the one already running, whose progress events to follow instead
+   */
+  job_id?: GenerateWikiResponseJobId;
   message: string;
   started: boolean;
 }
@@ -0,0 +1,13 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * Job generating the branch: the one started, or when `started` is false
+ * the one already running, whose progress events to follow instead
+ */
+export type GenerateWikiResponseJobId = string | null;
@@ -0,0 +1,14 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export type GetJobLogsParams = {
+/**
+ * Stream the log as server-sent events (`log` events, then `end` once the job finishes) instead of returning it
+ */
+follow?: boolean;
+};
@@ -0,0 +1,26 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export type GetMcpAnalyticsParams = {
+/**
+ * Only tools whose name or server contains this text
+ */
+q?: string;
+/**
+ * Only tools of this MCP server, e.g. opencode-wiki
+ */
+server?: string;
+/**
+ * Only calls made by this session
+ */
+session_id?: string;
+/**
+ * Only calls at or after this RFC 3339 time
+ */
+since?: string;
+};
@@ -0,0 +1,22 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export type GetUsageParams = {
+/**
+ * Only calls made for this task
+ */
+task_id?: string;
+/**
+ * Only calls made for the wiki of this branch
+ */
+wiki_branch?: string;
+/**
+ * Only calls at or after this RFC 3339 time
+ */
+since?: string;
+};
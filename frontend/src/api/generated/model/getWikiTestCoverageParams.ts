@@ -0,0 +1,18 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export type GetWikiTestCoverageParams = {
+/**
+ * Branch name (default: first configured branch)
+ */
+branch?: string;
+/**
+ * Only return modules without associated tests
+ */
+untested_only?: boolean;
+};
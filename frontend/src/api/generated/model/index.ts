@@ -6,27 +6,64 @@
  * OpenAPI spec version: 0.1.0
  */
 
+export * from './addFindingCommentRequest';
+export * from './addTaskDependencyRequest';
+export * from './agentMessageData';
+export * from './apiTokenResponse';
+export * from './apiTokenResponseLastUsedAt';
+export * from './apiTokenResponseRevokedAt';
+export * from './askAnswerMode';
+export * from './askFeedbackRating';
+export * from './askFeedbackRequest';
+export * from './askFeedbackRequestComment';
+export * from './askFeedbackResponse';
+export * from './askFeedbackResponseComment';
+export * from './askFeedbackSource';
 export * from './askRequest';
 export * from './askRequestConversationId';
+export * from './askRequestMode';
+export * from './askRequestRoot';
 export * from './askResponse';
 export * from './askSource';
+export * from './askSourceExpansion';
+export * from './askSourceSummary';
+export * from './askStreamEvent';
 export * from './branchStatus';
+export * from './branchStatusActiveJobId';
 export * from './branchStatusCurrentItem';
 export * from './branchStatusCurrentPhase';
+export * from './branchStatusEmbeddingModel';
+export * from './branchStatusEmbeddingVersion';
 export * from './branchStatusErrorMessage';
 export * from './branchStatusLastIndexedAt';
+export * from './branchStatusMigratingToModel';
+export * from './branchUsageTotals';
 export * from './browseDirectoryParams';
 export * from './browseQuery';
 export * from './browseResponse';
 export * from './browseResponseParentPath';
 export * from './browseResponseVcs';
+export * from './bulkFindingResult';
+export * from './bulkUpdateFindingsRequest';
+export * from './bulkUpdateFindingsResponse';
 export * from './checkRun';
 export * from './checkRunConclusion';
 export * from './checkRunHtmlUrl';
+export * from './chunkSettingResponse';
+export * from './chunkSettingScoreResponse';
+export * from './chunkTuningRequest';
+export * from './chunkTuningRequestMaxFiles';
+export * from './chunkTuningResponse';
+export * from './ciGateRecord';
+export * from './ciGateState';
 export * from './ciState';
 export * from './ciStatus';
 export * from './clearRecentResponse';
+export * from './columnCount';
 export * from './commentsListResponse';
+export * from './commitResolutionRequest';
+export * from './commitResolutionResponse';
+export * from './compareTaskExecutionsParams';
 export * from './completeAction';
 export * from './completePreviewResponse';
 export * from './completeTaskRequest';
@@ -35,23 +72,62 @@ export * from './completeTaskRequestPrOptions';
 export * from './completeTaskResponse';
 export * from './completeTaskResponseMergeResult';
 export * from './completeTaskResponsePr';
+export * from './conflictHunkResponse';
+export * from './conflictHunkResponseBase';
+export * from './conflictSide';
+export * from './conflictType';
+export * from './conflictedFileResponse';
+export * from './conflictsResponse';
 export * from './convertToTaskResponse';
+export * from './createApiTokenRequest';
+export * from './createApiTokenResponse';
 export * from './createCommentRequest';
 export * from './createPrCommentRequest';
 export * from './createPrCommentRequestInReplyTo';
+export * from './createSessionShareRequest';
+export * from './createSessionShareRequestExpiresInHours';
+export * from './createSessionShareResponse';
+export * from './createTaskFromTemplateRequest';
+export * from './createTaskFromTemplateRequestValues';
 export * from './createTaskRequest';
 export * from './createTaskRequestRoadmapItemId';
+export * from './createWorkspacePrRequest';
+export * from './createWorkspacePrRequestBaseBranch';
+export * from './createWorkspacePrRequestTitle';
 export * from './currentProjectResponse';
 export * from './currentProjectResponseProject';
+export * from './dashboardResponse';
+export * from './diffFileSummary';
 export * from './diffResponse';
+export * from './diffRisk';
 export * from './diffSide';
 export * from './diffSummary';
+export * from './diffSummaryResponse';
+export * from './digestConfig';
+export * from './digestConfigBudgetUsd';
+export * from './digestConfigFrom';
+export * from './digestConfigSmtpHost';
+export * from './digestConfigSmtpPassword';
+export * from './digestConfigSmtpUsername';
 export * from './directoryEntry';
 export * from './directoryEntryVcs';
+export * from './effectiveEnvVar';
+export * from './envVar';
+export * from './envVarSource';
+export * from './event';
+export * from './eventEnvelope';
+export * from './eventsConfig';
 export * from './eventsStreamParams';
 export * from './executeAsyncResponse';
 export * from './executeResponse';
+export * from './executionComparison';
+export * from './executionConfig';
+export * from './executionConfigReviewLanguage';
+export * from './executionOutcome';
+export * from './executionPhase';
 export * from './fileStatus';
+export * from './findingComment';
+export * from './findingCommentAuthor';
 export * from './findingSeverity';
 export * from './findingStatus';
 export * from './findingsResponse';
@@ -60,29 +136,63 @@ export * from './fixFindingsRequestFindingIds';
 export * from './fixFindingsRequestFixAll';
 export * from './fixFromCommentsRequest';
 export * from './fixFromCommentsResponse';
+export * from './flaggedPageResponse';
+export * from './flaggedSourceResponse';
 export * from './generateRoadmapRequest';
+export * from './generateWikiPageRequest';
+export * from './generateWikiPageRequestBranch';
 export * from './generateWikiRequest';
 export * from './generateWikiRequestBranch';
 export * from './generateWikiRequestMode';
 export * from './generateWikiResponse';
+export * from './generateWikiResponseJobId';
+export * from './getJobLogsParams';
+export * from './getMcpAnalyticsParams';
+export * from './getTaskFindingsSarif200';
+export * from './getTaskReviewReportParams';
+export * from './getUsageParams';
+export * from './getWikiCompareParams';
+export * from './getWikiEndpointsParams';
+export * from './getWikiEnvVarsParams';
+export * from './getWikiFileChunksParams';
+export * from './getWikiOnboardingParams';
 export * from './getWikiStructureParams';
+export * from './getWikiTestCoverageParams';
 export * from './gitHubSettingsResponse';
 export * from './gitHubSettingsResponseMaskedToken';
 export * from './gitHubUser';
 export * from './healthResponse';
+export * from './indexEstimateRequest';
+export * from './indexEstimateRequestRoots';
+export * from './indexEstimateResponse';
+export * from './indexEstimateResponseEstimatedCostUsd';
+export * from './indexEstimateResponsePricePerMillionTokens';
 export * from './indexRequest';
 export * from './indexRequestBranch';
 export * from './indexRequestForce';
 export * from './indexRequestIndexOnly';
 export * from './indexRequestMode';
 export * from './indexResponse';
+export * from './indexResponseJobId';
 export * from './initProjectRequest';
 export * from './initProjectResponse';
 export * from './initProjectResponseError';
 export * from './initProjectResponseProject';
+export * from './jobLogLine';
+export * from './jobLogsResponse';
 export * from './label';
 export * from './labelDescription';
+export * from './listEventHistoryParams';
 export * from './listPullRequestsParams';
+export * from './listSessionsParams';
+export * from './listTasksParams';
+export * from './listWorkspacesParams';
+export * from './mcpAnalyticsResponse';
+export * from './mcpToolCallResponse';
+export * from './mcpToolCallResponseError';
+export * from './mcpToolCallResponseSessionId';
+export * from './mcpToolStats';
+export * from './mcpToolStatsLastError';
 export * from './mergeOptions';
 export * from './mergeRequest';
 export * from './mergeResponse';
@@ -97,15 +207,24 @@ export * from './mergeResultInfoOneOfFour';
 export * from './mergeResultInfoOneOfFourStatus';
 export * from './mergeResultInfoOneOfStatus';
 export * from './modelSelection';
+export * from './modelUsageTotals';
+export * from './monthlySpend';
+export * from './monthlySpendBudgetUsd';
 export * from './openCodeModel';
 export * from './openCodeProvider';
 export * from './openProjectRequest';
 export * from './openProjectResponse';
 export * from './openProjectResponseError';
 export * from './openProjectResponseProject';
+export * from './pendingApproval';
 export * from './phaseInfo';
 export * from './phaseInfoSessionId';
 export * from './phaseInfoSummary';
+export * from './phaseMcpTools';
+export * from './phaseMcpToolsFix';
+export * from './phaseMcpToolsImplementation';
+export * from './phaseMcpToolsPlanning';
+export * from './phaseMcpToolsReview';
 export * from './phaseModels';
 export * from './phaseModelsFix';
 export * from './phaseModelsImplementation';
@@ -132,7 +251,17 @@ export * from './phaseSummary';
 export * from './phaseSummaryNotes';
 export * from './phasesResponse';
 export * from './phasesResponseCurrentPhase';
+export * from './pipelineResponse';
+export * from './pipelineStage';
+export * from './pipelineStageCompletedAt';
+export * from './pipelineStageEstimatedConsensusTokens';
+export * from './pipelineStageMaxIterations';
+export * from './pipelineStageReviewSamples';
+export * from './pipelineStageStartedAt';
+export * from './pipelineStageStatus';
+export * from './pipelineStageTokens';
 export * from './planResponse';
+export * from './postMergeWikiUpdate';
 export * from './prCommentsResponse';
 export * from './prDiffResponse';
 export * from './prFile';
@@ -166,17 +295,41 @@ export * from './pullRequestsListResponse';
 export * from './reactions';
 export * from './recentProject';
 export * from './recentProjectsResponse';
+export * from './reembedRequest';
+export * from './reembedRequestBranch';
+export * from './reembedRequestMaxChunks';
+export * from './reembedRequestModel';
+export * from './reembedResponse';
+export * from './releaseFinding';
+export * from './releaseReviewReport';
+export * from './releaseReviewReportCompletedAt';
+export * from './releaseReviewReportError';
+export * from './releaseReviewReportSinceTag';
+export * from './releaseReviewStatus';
+export * from './releaseReviewTask';
 export * from './remoteBranchesResponse';
 export * from './remoteBranchesResponseCurrentBranch';
 export * from './remoteBranchesResponseRemoteUrl';
 export * from './removeRecentRequest';
 export * from './removeRecentResponse';
 export * from './replyToCommentRequest';
+export * from './resolveConflictRequest';
+export * from './retrySessionResponse';
 export * from './reviewCommentResponse';
 export * from './reviewFinding';
+export * from './reviewFindingAcceptanceCriteria';
+export * from './reviewFindingConsensusScore';
 export * from './reviewFindingFilePath';
 export * from './reviewFindingLineEnd';
 export * from './reviewFindingLineStart';
+export * from './reviewFindingOriginalSeverity';
+export * from './reviewFindingReproduction';
+export * from './reviewFindingSeverityRationale';
+export * from './reviewFindingSnippet';
+export * from './reviewFindingSuggestedFix';
+export * from './reviewPolicy';
+export * from './reviewPolicyMaxWarnings';
+export * from './reviewPolicyResponse';
 export * from './reviewState';
 export * from './roadmap';
 export * from './roadmapComplexity';
@@ -202,30 +355,92 @@ export * from './roadmapStatsByComplexity';
 export * from './roadmapStatsByPriority';
 export * from './roadmapStatsByStatus';
 export * from './roadmapStatus';
+export * from './runningExecution';
+export * from './runningExecutionStartedAt';
 export * from './searchRequest';
+export * from './searchRequestAsOf';
+export * from './searchRequestDedupOverlap';
 export * from './searchRequestLimit';
+export * from './searchRequestMaxPerFile';
+export * from './searchRequestNormalizeScores';
+export * from './searchRequestPublicApiOnly';
+export * from './searchRequestRoot';
+export * from './seedDemoResponse';
+export * from './semanticSearchParams';
+export * from './semanticSearchResponse';
+export * from './semanticSearchResult';
+export * from './sendTestDigestRequest';
+export * from './sendTestDigestRequestTo';
+export * from './sendTestDigestResponse';
 export * from './sendToFixRequest';
 export * from './sendToFixResponse';
 export * from './session';
+export * from './sessionArtifactResponse';
 export * from './sessionCompletedAt';
 export * from './sessionImplementationPhaseNumber';
 export * from './sessionImplementationPhaseTitle';
 export * from './sessionOpencodeSessionId';
 export * from './sessionPhase';
+export * from './sessionRetryOf';
+export * from './sessionShareResponse';
+export * from './sessionShareResponseRevokedAt';
 export * from './sessionStartedAt';
 export * from './sessionStatus';
+export * from './sessionUsageTotals';
+export * from './sessionUsageTotalsSessionId';
 export * from './setViewedRequest';
+export * from './sharedSessionResponse';
+export * from './sharedSessionResponseDiff';
+export * from './sharedSessionResponseTranscriptItem';
+export * from './skippedWikiPageResponse';
+export * from './skippedWikiPageResponseError';
 export * from './sourceCitationResponse';
 export * from './sourceCitationResponseEndLine';
 export * from './sourceCitationResponseStartLine';
+export * from './startReleaseReviewRequest';
+export * from './startReleaseReviewRequestSinceTag';
+export * from './startReleaseReviewRequestTaskIds';
+export * from './stateMachineGuard';
+export * from './stateMachineResponse';
+export * from './stateMachineStatus';
+export * from './stateMachineStatusNextStatus';
+export * from './stateMachineStatusPreviousStatus';
+export * from './stateMachineTransition';
 export * from './targetAudience';
 export * from './targetAudienceUsageContext';
 export * from './task';
+export * from './taskDependenciesResponse';
+export * from './taskEnvResponse';
+export * from './taskExecution';
+export * from './taskExecutionCompletedAt';
+export * from './taskExecutionCost';
+export * from './taskExecutionDurationSecs';
+export * from './taskExecutionFindingsCount';
+export * from './taskExecutionTokens';
+export * from './taskExecutionsResponse';
+export * from './taskPrNumber';
 export * from './taskRoadmapItemId';
 export * from './taskStatus';
+export * from './taskTemplateRequest';
+export * from './taskTemplateResponse';
+export * from './taskTransition';
+export * from './taskTransitionBlockedReason';
+export * from './taskTransitionsResponse';
+export * from './taskTransitionsResponseNextStatus';
+export * from './taskUsageResponse';
+export * from './taskUsageTotals';
+export * from './taskWikiUpdateResponse';
+export * from './taskWikiUpdatesResponse';
 export * from './taskWorkspacePath';
+export * from './tokenUsage';
+export * from './toolExecutionData';
+export * from './traceFlowRequest';
+export * from './traceFlowRequestBranch';
+export * from './traceFlowResponse';
+export * from './traceFlowResponsePageSlug';
 export * from './transitionRequest';
 export * from './transitionResponse';
+export * from './translateFindingsParams';
 export * from './updateFeatureRequest';
 export * from './updateFeatureRequestLinkedTaskId';
 export * from './updateFeatureRequestPhaseId';
@@ -239,51 +454,129 @@ export * from './updatePhaseModelsRequestImplementation';
 export * from './updatePhaseModelsRequestPlanning';
 export * from './updatePhaseModelsRequestReview';
 export * from './updateRoadmapSettingsRequest';
+export * from './updateTaskEnvRequest';
 export * from './updateTaskRequest';
 export * from './updateTaskRequestDescription';
 export * from './updateTaskRequestStatus';
 export * from './updateTaskRequestTitle';
 export * from './updateTaskRequestWorkspacePath';
 export * from './updateUserModeRequest';
+export * from './updateUserPreferencesRequest';
+export * from './updateUserPreferencesRequestAskModel';
+export * from './updateUserPreferencesRequestBoardFilters';
+export * from './updateUserPreferencesRequestDigestEmail';
+export * from './updateUserPreferencesRequestDigestSchedule';
+export * from './updateUserPreferencesRequestEventSubscriptions';
+export * from './updateUserPreferencesRequestNotificationChannels';
 export * from './updateWikiSettingsRequest';
 export * from './updateWikiSettingsRequestAccessToken';
 export * from './updateWikiSettingsRequestAutoSync';
 export * from './updateWikiSettingsRequestBranches';
 export * from './updateWikiSettingsRequestChatModel';
+export * from './updateWikiSettingsRequestChunkSummaries';
+export * from './updateWikiSettingsRequestEmbeddingConcurrency';
+export * from './updateWikiSettingsRequestEmbeddingDimension';
 export * from './updateWikiSettingsRequestEmbeddingModel';
+export * from './updateWikiSettingsRequestEmbeddingProvider';
 export * from './updateWikiSettingsRequestEnabled';
+export * from './updateWikiSettingsRequestHistoryGenerations';
+export * from './updateWikiSettingsRequestLocalEmbeddingModelPath';
+export * from './updateWikiSettingsRequestMaxPages';
+export * from './updateWikiSettingsRequestMaxPagesPerSection';
+export * from './updateWikiSettingsRequestMinPageImportance';
+export * from './updateWikiSettingsRequestOpenaiApiKey';
 export * from './updateWikiSettingsRequestOpenrouterApiKey';
+export * from './updateWikiSettingsRequestPageConcurrency';
+export * from './updateWikiSettingsRequestPlanningContext';
+export * from './updateWikiSettingsRequestPlanningContextMaxTokens';
+export * from './updateWikiSettingsRequestPostMergeUpdate';
 export * from './updateWikiSettingsRequestRepoUrl';
+export * from './updateWikiSettingsRequestReviewContext';
+export * from './updateWikiSettingsRequestReviewContextMaxTokens';
+export * from './updateWikiSettingsRequestRoots';
+export * from './usageReportResponse';
+export * from './usageTotals';
+export * from './usageTotalsCostUsd';
 export * from './userMode';
 export * from './userModeResponse';
+export * from './userPreferencesResponse';
+export * from './userPreferencesResponseAskModel';
+export * from './userPreferencesResponseBoardFilters';
+export * from './userPreferencesResponseDigestEmail';
+export * from './userPreferencesResponseDigestLastSentAt';
 export * from './validatePathRequest';
 export * from './validatePathResponse';
 export * from './validatePathResponseError';
 export * from './validatePathResponseName';
 export * from './validatePathResponseVcs';
+export * from './validatePathResponseWarning';
+export * from './vcsCapabilities';
 export * from './viewedFilesResponse';
+export * from './webhookPullRequest';
+export * from './webhookPullRequestBase';
+export * from './webhookPullRequestEvent';
 export * from './webhookPushRequest';
 export * from './webhookResponse';
+export * from './wikiChunkTuning';
+export * from './wikiCompareResponse';
+export * from './wikiComparedPage';
 export * from './wikiConfig';
 export * from './wikiConfigAccessToken';
 export * from './wikiConfigChatModel';
+export * from './wikiConfigChunkTuning';
+export * from './wikiConfigEmbeddingDimension';
 export * from './wikiConfigEmbeddingModel';
+export * from './wikiConfigLocalEmbeddingModelPath';
+export * from './wikiConfigOpenaiApiKey';
 export * from './wikiConfigOpenrouterApiKey';
 export * from './wikiConfigRepoUrl';
+export * from './wikiEmbeddingProvider';
+export * from './wikiEndpoint';
+export * from './wikiEndpointHandler';
+export * from './wikiEndpointsResponse';
+export * from './wikiEnvVar';
+export * from './wikiEnvVarDefaultValue';
+export * from './wikiEnvVarDescription';
+export * from './wikiEnvVarUsage';
+export * from './wikiEnvVarsResponse';
+export * from './wikiFeedbackStatsResponse';
+export * from './wikiFileChunk';
+export * from './wikiFileChunkLanguage';
+export * from './wikiFileChunkSummary';
+export * from './wikiFileChunksResponse';
+export * from './wikiFlowStep';
+export * from './wikiFlowStepCalledFrom';
+export * from './wikiFreshness';
+export * from './wikiFreshnessIndexedCommit';
+export * from './wikiFreshnessLastIndexedAt';
+export * from './wikiGenerationPhase';
+export * from './wikiModuleCoverage';
+export * from './wikiOnboardingResponse';
+export * from './wikiOnboardingStep';
+export * from './wikiPageChange';
+export * from './wikiPageChangePreviousTitle';
+export * from './wikiPageImportance';
 export * from './wikiPageResponse';
 export * from './wikiPageResponseParentSlug';
 export * from './wikiPageResponseSectionId';
 export * from './wikiSearchResponse';
 export * from './wikiSearchResult';
 export * from './wikiSearchResultLanguage';
+export * from './wikiSearchResultSummary';
 export * from './wikiSectionResponse';
 export * from './wikiSectionResponseDescription';
 export * from './wikiSettingsResponse';
 export * from './wikiSettingsResponseChatModel';
+export * from './wikiSettingsResponseChunkTuning';
+export * from './wikiSettingsResponseEmbeddingDimension';
 export * from './wikiSettingsResponseEmbeddingModel';
+export * from './wikiSettingsResponseLocalEmbeddingModelPath';
 export * from './wikiSettingsResponseRepoUrl';
 export * from './wikiStatusResponse';
 export * from './wikiStructureResponse';
+export * from './wikiTestCoverageResponse';
 export * from './wikiTreeNode';
+export * from './wipeDemoResponse';
+export * from './workspacePrResponse';
 export * from './workspaceResponse';
-export * from './workspaceStatusResponse';
\ No newline at end of file
+export * from './workspaceStatusResponse';
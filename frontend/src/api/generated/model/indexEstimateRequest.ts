@@ -0,0 +1,13 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { IndexEstimateRequestRoots } from './indexEstimateRequestRoots';
+
+export interface IndexEstimateRequest {
+  /** Sub-directories to estimate, defaults to the configured roots */
+  roots?: IndexEstimateRequestRoots;
+}
@@ -0,0 +1,29 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { IndexEstimateResponseEstimatedCostUsd } from './indexEstimateResponseEstimatedCostUsd';
+import type { IndexEstimateResponsePricePerMillionTokens } from './indexEstimateResponsePricePerMillionTokens';
+
+export interface IndexEstimateResponse {
+  /**
+   * Embedding requests the chunks are sent in
+   * @minimum 0
+   */
+  batches: number;
+  /** @minimum 0 */
+  chunks: number;
+  embedding_model: string;
+  estimated_cost_usd?: IndexEstimateResponseEstimatedCostUsd;
+  /** @minimum 0 */
+  estimated_seconds: number;
+  /** @minimum 0 */
+  files: number;
+  /** USD per million input tokens, unset when the model's price is unknown */
+  price_per_million_tokens?: IndexEstimateResponsePricePerMillionTokens;
+  /** @minimum 0 */
+  tokens: number;
+}
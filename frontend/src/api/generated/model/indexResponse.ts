@@ -5,9 +5,15 @@
  * API for OpenCode Studio - AI-powered development platform
  * OpenAPI spec version: 0.1.0
  */
+import type { IndexResponseJobId } from './indexResponseJobId';
 
 export interface IndexResponse {
   branch: string;
+  /**
+   * Job indexing the branch: the one started, or when `started` is false
+   * the one already running, whose progress events to follow instead
+   */
+  job_id?: IndexResponseJobId;
   message: string;
   started: boolean;
 }
@@ -0,0 +1,20 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export interface JobLogLine {
+  level: string;
+  message: string;
+  /**
+   * Position of the line in the job's log, from 0
+   * @minimum 0
+   */
+  seq: number;
+  /** Module the line was logged from */
+  target: string;
+  timestamp: string;
+}
@@ -0,0 +1,15 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { JobLogLine } from './jobLogLine';
+
+export interface JobLogsResponse {
+  /** Whether the job has finished; its log is complete if so */
+  finished: boolean;
+  job_id: string;
+  lines: JobLogLine[];
+}
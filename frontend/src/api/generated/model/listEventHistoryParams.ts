@@ -0,0 +1,40 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export type ListEventHistoryParams = {
+/**
+ * Only events at or after this RFC 3339 time
+ */
+since?: string;
+/**
+ * Only events stored after this sequence number, e.g. the X-Event-Seq of an earlier response
+ */
+since_seq?: number;
+/**
+ * Only events of this type, e.g. task.status_changed
+ */
+type?: string;
+/**
+ * Only events of this task
+ */
+task_id?: string;
+/**
+ * Maximum number of items (max: 500; default: all)
+ * @minimum 0
+ */
+limit?: number;
+/**
+ * Number of items to skip
+ * @minimum 0
+ */
+offset?: number;
+/**
+ * Field to sort by, prefixed with '-' for descending order (timestamp or type; default: oldest first)
+ */
+sort?: string;
+};
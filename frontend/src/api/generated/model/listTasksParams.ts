@@ -0,0 +1,28 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export type ListTasksParams = {
+/**
+ * Maximum number of items (max: 500; default: all)
+ * @minimum 0
+ */
+limit?: number;
+/**
+ * Number of items to skip
+ * @minimum 0
+ */
+offset?: number;
+/**
+ * Field to sort by, prefixed with '-' for descending order (title, status, created_at or updated_at; default: -created_at)
+ */
+sort?: string;
+/**
+ * Comma-separated fields to return (default: all)
+ */
+fields?: string;
+};
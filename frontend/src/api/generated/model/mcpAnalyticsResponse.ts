@@ -0,0 +1,22 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { McpToolCallResponse } from './mcpToolCallResponse';
+import type { McpToolStats } from './mcpToolStats';
+
+export interface McpAnalyticsResponse {
+  /** Error rate at which tools are flagged */
+  error_rate_threshold: number;
+  /** Latest failed calls, newest first */
+  recent_errors: McpToolCallResponse[];
+  /** Flagged tools first, then most called */
+  tools: McpToolStats[];
+  /** @minimum 0 */
+  total_calls: number;
+  /** @minimum 0 */
+  total_errors: number;
+}
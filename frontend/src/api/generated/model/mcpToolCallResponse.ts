@@ -0,0 +1,19 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { McpToolCallResponseError } from './mcpToolCallResponseError';
+import type { McpToolCallResponseSessionId } from './mcpToolCallResponseSessionId';
+
+export interface McpToolCallResponse {
+  called_at: string;
+  /** @minimum 0 */
+  duration_ms: number;
+  error?: McpToolCallResponseError;
+  server: string;
+  session_id?: McpToolCallResponseSessionId;
+  tool: string;
+}
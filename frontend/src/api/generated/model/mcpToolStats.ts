@@ -0,0 +1,35 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { McpToolStatsLastError } from './mcpToolStatsLastError';
+
+/**
+ * Calls of one tool of one MCP server
+ */
+export interface McpToolStats {
+  /** @minimum 0 */
+  avg_duration_ms: number;
+  /** @minimum 0 */
+  calls: number;
+  /** Share of failed calls, from 0 to 1 */
+  error_rate: number;
+  /** @minimum 0 */
+  errors: number;
+  /** Whether the tool fails often enough to look into */
+  flagged: boolean;
+  last_called_at: string;
+  last_error?: McpToolStatsLastError;
+  /** @minimum 0 */
+  p95_duration_ms: number;
+  server: string;
+  /**
+   * Sessions that called the tool
+   * @minimum 0
+   */
+  sessions: number;
+  tool: string;
+}
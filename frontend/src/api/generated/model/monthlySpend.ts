@@ -0,0 +1,26 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { MonthlySpendBudgetUsd } from './monthlySpendBudgetUsd';
+
+/**
+ * Model spend since the start of the month
+ */
+export interface MonthlySpend {
+  /** Monthly budget from the digest settings */
+  budget_usd?: MonthlySpendBudgetUsd;
+  /** Cost in USD as reported by OpenCode */
+  cost: number;
+  period_start: string;
+  /** @minimum 0 */
+  tokens: number;
+  /**
+   * Sessions OpenCode could not report usage for
+   * @minimum 0
+   */
+  unreported_sessions: number;
+}
@@ -0,0 +1,19 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { TaskStatus } from './taskStatus';
+
+/**
+ * A task waiting for someone to approve its plan or implementation
+ */
+export interface PendingApproval {
+  /** When the task started waiting */
+  since: string;
+  status: TaskStatus;
+  task_id: string;
+  title: string;
+}
@@ -0,0 +1,21 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { PhaseMcpToolsFix } from './phaseMcpToolsFix';
+import type { PhaseMcpToolsImplementation } from './phaseMcpToolsImplementation';
+import type { PhaseMcpToolsPlanning } from './phaseMcpToolsPlanning';
+import type { PhaseMcpToolsReview } from './phaseMcpToolsReview';
+
+/**
+ * MCP tools each phase may call; phases left out use the built-in profile
+ */
+export interface PhaseMcpTools {
+  fix?: PhaseMcpToolsFix;
+  implementation?: PhaseMcpToolsImplementation;
+  planning?: PhaseMcpToolsPlanning;
+  review?: PhaseMcpToolsReview;
+}
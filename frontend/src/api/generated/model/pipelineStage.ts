@@ -0,0 +1,46 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { PipelineStageCompletedAt } from './pipelineStageCompletedAt';
+import type { PipelineStageEstimatedConsensusTokens } from './pipelineStageEstimatedConsensusTokens';
+import type { PipelineStageMaxIterations } from './pipelineStageMaxIterations';
+import type { PipelineStageReviewSamples } from './pipelineStageReviewSamples';
+import type { PipelineStageStartedAt } from './pipelineStageStartedAt';
+import type { PipelineStageStatus } from './pipelineStageStatus';
+import type { PipelineStageTokens } from './pipelineStageTokens';
+import type { SessionPhase } from './sessionPhase';
+
+export interface PipelineStage {
+  /** When the latest session of the phase finished */
+  completed_at?: PipelineStageCompletedAt;
+  /**
+   * Extra tokens the consensus samples of the next review round are
+   * expected to cost, going by the latest review session
+   * @minimum 0
+   */
+  estimated_consensus_tokens?: PipelineStageEstimatedConsensusTokens;
+  /**
+   * Most review/fix rounds before the task goes to human review
+   * @minimum 0
+   */
+  max_iterations?: PipelineStageMaxIterations;
+  phase: SessionPhase;
+  /** Whether a human has to approve the phase before the task moves on */
+  requires_approval: boolean;
+  /**
+   * Reviews run per review round, when review consensus is on
+   * @minimum 0
+   */
+  review_samples?: PipelineStageReviewSamples;
+  /** Sessions that ran the phase, oldest first */
+  session_ids: string[];
+  /** When the first session of the phase started */
+  started_at?: PipelineStageStartedAt;
+  status: PipelineStageStatus;
+  /** `None` when OpenCode could not report usage for any session */
+  tokens?: PipelineStageTokens;
+}
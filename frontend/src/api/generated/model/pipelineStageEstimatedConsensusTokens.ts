@@ -0,0 +1,13 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * Extra tokens the consensus samples of the next review round are
+ * expected to cost, going by the latest review session
+ */
+export type PipelineStageEstimatedConsensusTokens = number | null;
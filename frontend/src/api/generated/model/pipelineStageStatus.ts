@@ -0,0 +1,20 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export type PipelineStageStatus = typeof PipelineStageStatus[keyof typeof PipelineStageStatus];
+
+
+// eslint-disable-next-line @typescript-eslint/no-redeclare
+export const PipelineStageStatus = {
+  pending: 'pending',
+  running: 'running',
+  awaiting_approval: 'awaiting_approval',
+  completed: 'completed',
+  failed: 'failed',
+  skipped: 'skipped',
+} as const;
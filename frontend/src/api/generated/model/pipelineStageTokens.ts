@@ -0,0 +1,13 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { TokenUsage } from './tokenUsage';
+
+/**
+ * `None` when OpenCode could not report usage for any session
+ */
+export type PipelineStageTokens = null | TokenUsage;
@@ -0,0 +1,20 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * What happens to stale wiki pages after a task is merged
+ */
+export type PostMergeWikiUpdate = typeof PostMergeWikiUpdate[keyof typeof PostMergeWikiUpdate];
+
+
+// eslint-disable-next-line @typescript-eslint/no-redeclare
+export const PostMergeWikiUpdate = {
+  off: 'off',
+  suggest: 'suggest',
+  regenerate: 'regenerate',
+} as const;
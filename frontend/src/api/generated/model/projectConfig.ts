@@ -5,6 +5,11 @@
  * API for OpenCode Studio - AI-powered development platform
  * OpenAPI spec version: 0.1.0
  */
+import type { DigestConfig } from './digestConfig';
+import type { EnvVar } from './envVar';
+import type { EventsConfig } from './eventsConfig';
+import type { ExecutionConfig } from './executionConfig';
+import type { PhaseMcpTools } from './phaseMcpTools';
 import type { PhaseModels } from './phaseModels';
 import type { RoadmapConfig } from './roadmapConfig';
 import type { UserMode } from './userMode';
@@ -14,6 +19,12 @@ import type { WikiConfig } from './wikiConfig';
  * Project-level configuration stored in .opencode-studio/config.json
  */
 export interface ProjectConfig {
+  digest?: DigestConfig;
+  /** Environment variables for every task's sessions */
+  environment?: EnvVar[];
+  events?: EventsConfig;
+  execution?: ExecutionConfig;
+  mcp_tools?: PhaseMcpTools;
   phase_models?: PhaseModels;
   roadmap?: RoadmapConfig;
   user_mode?: UserMode;
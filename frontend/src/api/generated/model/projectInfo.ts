@@ -5,11 +5,17 @@
  * API for OpenCode Studio - AI-powered development platform
  * OpenAPI spec version: 0.1.0
  */
+import type { VcsCapabilities } from './vcsCapabilities';
 
 export interface ProjectInfo {
+  /** Seeded by demo mode; wipe it with `DELETE /api/admin/seed` */
+  demo: boolean;
   initialized: boolean;
   name: string;
   path: string;
   tasks_count: number;
+  /** `git`, `jujutsu`, or `none` for directories without version control */
   vcs: string;
+  /** What the project's VCS supports; without version control, workspaces are plain copies and commit, push and pull requests are unavailable */
+  vcs_capabilities: VcsCapabilities;
 }
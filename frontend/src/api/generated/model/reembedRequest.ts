@@ -0,0 +1,18 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { ReembedRequestBranch } from './reembedRequestBranch';
+import type { ReembedRequestMaxChunks } from './reembedRequestMaxChunks';
+import type { ReembedRequestModel } from './reembedRequestModel';
+
+export interface ReembedRequest {
+  branch?: ReembedRequestBranch;
+  /** Stop after embedding this many chunks; a later request resumes */
+  max_chunks?: ReembedRequestMaxChunks;
+  /** Target embedding model, defaults to the configured one */
+  model?: ReembedRequestModel;
+}
@@ -0,0 +1,13 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * Stop after embedding this many chunks; a later request resumes
+ * @minimum 0
+ */
+export type ReembedRequestMaxChunks = number | null;
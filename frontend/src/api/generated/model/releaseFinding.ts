@@ -0,0 +1,17 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { ReviewFinding } from './reviewFinding';
+
+/**
+ * A release review finding and the tasks that changed its file
+ */
+export interface ReleaseFinding {
+  finding: ReviewFinding;
+  /** Originating tasks; empty when the finding names no file of theirs */
+  task_ids: string[];
+}
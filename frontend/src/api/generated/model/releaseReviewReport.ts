@@ -0,0 +1,29 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { ReleaseReviewReportCompletedAt } from './releaseReviewReportCompletedAt';
+import type { ReleaseReviewReportError } from './releaseReviewReportError';
+import type { ReleaseReviewReportSinceTag } from './releaseReviewReportSinceTag';
+import type { ReleaseFinding } from './releaseFinding';
+import type { ReleaseReviewStatus } from './releaseReviewStatus';
+import type { ReleaseReviewTask } from './releaseReviewTask';
+
+/**
+ * One review over the changes of several merged tasks
+ */
+export interface ReleaseReviewReport {
+  approved: boolean;
+  completed_at?: ReleaseReviewReportCompletedAt;
+  created_at: string;
+  error?: ReleaseReviewReportError;
+  findings: ReleaseFinding[];
+  id: string;
+  since_tag?: ReleaseReviewReportSinceTag;
+  status: ReleaseReviewStatus;
+  summary: string;
+  tasks: ReleaseReviewTask[];
+}
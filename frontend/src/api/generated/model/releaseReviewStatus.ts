@@ -0,0 +1,20 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * State of a release review
+ */
+export type ReleaseReviewStatus = typeof ReleaseReviewStatus[keyof typeof ReleaseReviewStatus];
+
+
+// eslint-disable-next-line @typescript-eslint/no-redeclare
+export const ReleaseReviewStatus = {
+  running: 'running',
+  completed: 'completed',
+  failed: 'failed',
+} as const;
@@ -0,0 +1,17 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * A merged task a release review covers
+ */
+export interface ReleaseReviewTask {
+  /** Files the task's merged changes touched */
+  files: string[];
+  task_id: string;
+  title: string;
+}
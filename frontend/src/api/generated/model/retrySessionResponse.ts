@@ -0,0 +1,22 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { Session } from './session';
+import type { Task } from './task';
+
+export interface RetrySessionResponse {
+  /**
+   * Retry number of the new session, starting at 1
+   * @minimum 0
+   */
+  attempt: number;
+  /** @minimum 0 */
+  max_retries: number;
+  /** The new session, linked to the failed one through `retry_of` */
+  session: Session;
+  task: Task;
+}
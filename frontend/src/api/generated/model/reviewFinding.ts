@@ -5,9 +5,17 @@
  * API for OpenCode Studio - AI-powered development platform
  * OpenAPI spec version: 0.1.0
  */
+import type { ReviewFindingAcceptanceCriteria } from './reviewFindingAcceptanceCriteria';
+import type { ReviewFindingConsensusScore } from './reviewFindingConsensusScore';
 import type { ReviewFindingFilePath } from './reviewFindingFilePath';
 import type { ReviewFindingLineEnd } from './reviewFindingLineEnd';
 import type { ReviewFindingLineStart } from './reviewFindingLineStart';
+import type { ReviewFindingOriginalSeverity } from './reviewFindingOriginalSeverity';
+import type { ReviewFindingReproduction } from './reviewFindingReproduction';
+import type { ReviewFindingSeverityRationale } from './reviewFindingSeverityRationale';
+import type { ReviewFindingSnippet } from './reviewFindingSnippet';
+import type { ReviewFindingSuggestedFix } from './reviewFindingSuggestedFix';
+import type { FindingComment } from './findingComment';
 import type { FindingSeverity } from './findingSeverity';
 import type { FindingStatus } from './findingStatus';
 
@@ -15,12 +23,22 @@ import type { FindingStatus } from './findingStatus';
  * A single review finding
  */
 export interface ReviewFinding {
+  acceptance_criteria?: ReviewFindingAcceptanceCriteria;
+  /** Discussion between the human reviewer and the agents, oldest first */
+  comments?: FindingComment[];
+  consensus_score?: ReviewFindingConsensusScore;
   description: string;
   file_path?: ReviewFindingFilePath;
   id: string;
   line_end?: ReviewFindingLineEnd;
   line_start?: ReviewFindingLineStart;
+  original_severity?: ReviewFindingOriginalSeverity;
+  reproduction?: ReviewFindingReproduction;
   severity: FindingSeverity;
+  severity_rationale?: ReviewFindingSeverityRationale;
+  /** The lines the finding points at, as they read when it was created */
+  snippet?: ReviewFindingSnippet;
   status: FindingStatus;
+  suggested_fix?: ReviewFindingSuggestedFix;
   title: string;
 }
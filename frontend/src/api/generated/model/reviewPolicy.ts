@@ -0,0 +1,18 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { FindingSeverity } from './findingSeverity';
+import type { ReviewPolicyMaxWarnings } from './reviewPolicyMaxWarnings';
+
+/**
+ * Review gating policy
+ */
+export interface ReviewPolicy {
+  /** Severities of which a single pending finding blocks the review */
+  block_on?: FindingSeverity[];
+  max_warnings?: ReviewPolicyMaxWarnings;
+}
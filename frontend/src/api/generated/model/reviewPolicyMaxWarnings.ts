@@ -0,0 +1,14 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * Pending warnings tolerated when warnings don't block on their own;
+ * one more blocks the review
+ * @minimum 0
+ */
+export type ReviewPolicyMaxWarnings = number | null;
@@ -0,0 +1,22 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { RunningExecutionStartedAt } from './runningExecutionStartedAt';
+import type { SessionPhase } from './sessionPhase';
+import type { SessionStatus } from './sessionStatus';
+
+/**
+ * A session that is running or about to
+ */
+export interface RunningExecution {
+  phase: SessionPhase;
+  session_id: string;
+  started_at?: RunningExecutionStartedAt;
+  status: SessionStatus;
+  task_id: string;
+  task_title: string;
+}
@@ -5,10 +5,31 @@
  * API for OpenCode Studio - AI-powered development platform
  * OpenAPI spec version: 0.1.0
  */
+import type { SearchRequestAsOf } from './searchRequestAsOf';
+import type { SearchRequestDedupOverlap } from './searchRequestDedupOverlap';
 import type { SearchRequestLimit } from './searchRequestLimit';
+import type { SearchRequestMaxPerFile } from './searchRequestMaxPerFile';
+import type { SearchRequestNormalizeScores } from './searchRequestNormalizeScores';
+import type { SearchRequestPublicApiOnly } from './searchRequestPublicApiOnly';
+import type { SearchRequestRoot } from './searchRequestRoot';
 
 export interface SearchRequest {
+  /**
+   * Search the index as of this commit (a SHA or a prefix of at least
+   * four hex digits) instead of the current one
+   */
+  as_of?: SearchRequestAsOf;
+  dedup_overlap?: SearchRequestDedupOverlap;
   /** @minimum 0 */
   limit?: SearchRequestLimit;
+  max_per_file?: SearchRequestMaxPerFile;
+  normalize_scores?: SearchRequestNormalizeScores;
+  /**
+   * Keep only code tagged as public API: HTTP handlers, exported items
+   * and CLI commands
+   */
+  public_api_only?: SearchRequestPublicApiOnly;
   query: string;
+  /** Only search code under this workspace root */
+  root?: SearchRequestRoot;
 }
@@ -0,0 +1,13 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * Search the index as of this commit (a SHA or a prefix of at least
+ * four hex digits) instead of the current one
+ */
+export type SearchRequestAsOf = string | null;
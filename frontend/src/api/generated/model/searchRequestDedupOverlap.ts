@@ -0,0 +1,13 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * Drop results whose lines overlap a better result of the same file by
+ * at least this share (0.0-1.0) of the shorter range
+ */
+export type SearchRequestDedupOverlap = number | null;
@@ -0,0 +1,13 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * Keep only code tagged as public API: HTTP handlers, exported items
+ * and CLI commands
+ */
+export type SearchRequestPublicApiOnly = boolean | null;
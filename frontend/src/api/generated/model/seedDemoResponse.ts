@@ -0,0 +1,20 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export interface SeedDemoResponse {
+  /** @minimum 0 */
+  findings: number;
+  /** Path of the demo project, which is now the open project */
+  project_path: string;
+  /** @minimum 0 */
+  sessions: number;
+  /** @minimum 0 */
+  tasks: number;
+  /** @minimum 0 */
+  wiki_pages: number;
+}
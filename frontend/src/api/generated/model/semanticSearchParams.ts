@@ -0,0 +1,23 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export type SemanticSearchParams = {
+/**
+ * Natural language query
+ */
+q: string;
+/**
+ * tasks, findings or sessions (default: all)
+ */
+scope?: string;
+/**
+ * Maximum number of results (default: 10, max: 50)
+ * @minimum 0
+ */
+limit?: number;
+};
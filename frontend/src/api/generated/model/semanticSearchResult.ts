@@ -0,0 +1,17 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export interface SemanticSearchResult {
+  id: string;
+  /** `task`, `finding` or `session` */
+  scope: string;
+  score: number;
+  snippet: string;
+  task_id: string;
+  title: string;
+}
@@ -10,6 +10,7 @@ import type { SessionImplementationPhaseNumber } from './sessionImplementationPh
 import type { SessionImplementationPhaseTitle } from './sessionImplementationPhaseTitle';
 import type { SessionOpencodeSessionId } from './sessionOpencodeSessionId';
 import type { SessionPhase } from './sessionPhase';
+import type { SessionRetryOf } from './sessionRetryOf';
 import type { SessionStartedAt } from './sessionStartedAt';
 import type { SessionStatus } from './sessionStatus';
 
@@ -26,6 +27,8 @@ export interface Session {
   implementation_phase_title?: SessionImplementationPhaseTitle;
   opencode_session_id?: SessionOpencodeSessionId;
   phase: SessionPhase;
+  /** Failed session this one retries */
+  retry_of?: SessionRetryOf;
   started_at?: SessionStartedAt;
   status: SessionStatus;
   task_id: string;
@@ -0,0 +1,21 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export interface SessionArtifactResponse {
+  /** SHA-256 of the content */
+  content_hash: string;
+  created_at: number;
+  id: string;
+  /** `plan`, `review`, `findings`, `phase_summary` or `diff` */
+  kind: string;
+  media_type: string;
+  name: string;
+  session_id: string;
+  size_bytes: number;
+  task_id: string;
+}
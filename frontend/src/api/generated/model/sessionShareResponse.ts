@@ -0,0 +1,18 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { SessionShareResponseRevokedAt } from './sessionShareResponseRevokedAt';
+
+export interface SessionShareResponse {
+  /** Whether the link still opens the session */
+  active: boolean;
+  created_at: number;
+  expires_at: number;
+  id: string;
+  revoked_at?: SessionShareResponseRevokedAt;
+  session_id: string;
+}
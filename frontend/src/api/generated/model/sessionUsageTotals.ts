@@ -0,0 +1,15 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { SessionUsageTotalsSessionId } from './sessionUsageTotalsSessionId';
+import type { UsageTotals } from './usageTotals';
+
+export interface SessionUsageTotals {
+  /** `None` for calls made for the task outside its sessions */
+  session_id?: SessionUsageTotalsSessionId;
+  usage: UsageTotals;
+}
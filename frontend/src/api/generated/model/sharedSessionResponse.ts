@@ -0,0 +1,22 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { Session } from './session';
+import type { SharedSessionResponseDiff } from './sharedSessionResponseDiff';
+import type { SharedSessionResponseTranscriptItem } from './sharedSessionResponseTranscriptItem';
+
+/**
+ * What a share link shows
+ */
+export interface SharedSessionResponse {
+  diff?: SharedSessionResponseDiff;
+  expires_at: number;
+  session: Session;
+  task_title: string;
+  /** Messages, reasoning and tool calls of the session, oldest first */
+  transcript: SharedSessionResponseTranscriptItem[];
+}
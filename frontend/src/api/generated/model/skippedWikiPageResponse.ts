@@ -0,0 +1,22 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { SkippedWikiPageResponseError } from './skippedWikiPageResponseError';
+
+/**
+ * A planned page left out by the generation limits or that failed to generate
+ */
+export interface SkippedWikiPageResponse {
+  description: string;
+  error?: SkippedWikiPageResponseError;
+  file_paths: string[];
+  id: string;
+  importance: string;
+  reason: string;
+  section_id: string;
+  title: string;
+}
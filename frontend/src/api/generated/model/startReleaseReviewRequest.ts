@@ -0,0 +1,14 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { StartReleaseReviewRequestSinceTag } from './startReleaseReviewRequestSinceTag';
+import type { StartReleaseReviewRequestTaskIds } from './startReleaseReviewRequestTaskIds';
+
+export interface StartReleaseReviewRequest {
+  since_tag?: StartReleaseReviewRequestSinceTag;
+  task_ids?: StartReleaseReviewRequestTaskIds;
+}
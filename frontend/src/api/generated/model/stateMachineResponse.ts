@@ -0,0 +1,19 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { StateMachineStatus } from './stateMachineStatus';
+import type { StateMachineTransition } from './stateMachineTransition';
+import type { TaskStatus } from './taskStatus';
+
+export interface StateMachineResponse {
+  initial_status: TaskStatus;
+  /** Statuses in workflow order */
+  statuses: StateMachineStatus[];
+  transitions: StateMachineTransition[];
+  /** Name of the project's workflow, `default` for the built-in one */
+  workflow: string;
+}
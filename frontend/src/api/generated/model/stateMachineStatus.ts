@@ -0,0 +1,18 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { StateMachineStatusNextStatus } from './stateMachineStatusNextStatus';
+import type { StateMachineStatusPreviousStatus } from './stateMachineStatusPreviousStatus';
+import type { TaskStatus } from './taskStatus';
+
+export interface StateMachineStatus {
+  next_status?: StateMachineStatusNextStatus;
+  previous_status?: StateMachineStatusPreviousStatus;
+  status: TaskStatus;
+  /** No transitions leave the status */
+  terminal: boolean;
+}
@@ -5,14 +5,22 @@
  * API for OpenCode Studio - AI-powered development platform
  * OpenAPI spec version: 0.1.0
  */
+import type { TaskPrNumber } from './taskPrNumber';
 import type { TaskRoadmapItemId } from './taskRoadmapItemId';
 import type { TaskStatus } from './taskStatus';
 import type { TaskWorkspacePath } from './taskWorkspacePath';
 
 export interface Task {
   created_at: string;
+  /** Tasks that must be done before this one can run */
+  depends_on?: string[];
   description: string;
   id: string;
+  /**
+   * Number of the GitHub pull request opened from the task's workspace
+   * @minimum 0
+   */
+  pr_number?: TaskPrNumber;
   roadmap_item_id?: TaskRoadmapItemId;
   status: TaskStatus;
   title: string;
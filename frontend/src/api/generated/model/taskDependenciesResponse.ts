@@ -0,0 +1,19 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { Task } from './task';
+
+export interface TaskDependenciesResponse {
+  /** Tasks that depend on the task */
+  dependents: Task[];
+  /** Tasks the task depends on */
+  depends_on: Task[];
+  /** The task, with its status after blocking or unblocking */
+  task: Task;
+  /** Dependencies that are not done yet */
+  unfinished: string[];
+}
@@ -0,0 +1,18 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { EffectiveEnvVar } from './effectiveEnvVar';
+import type { EnvVar } from './envVar';
+
+export interface TaskEnvResponse {
+  /** Project and task variables combined, as sessions get them */
+  effective: EffectiveEnvVar[];
+  /** Variables left out because their secret is not set on the server */
+  missing_secrets: string[];
+  /** The task's own variables, as defined */
+  vars: EnvVar[];
+}
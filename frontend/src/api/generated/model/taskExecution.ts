@@ -0,0 +1,46 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { ExecutionOutcome } from './executionOutcome';
+import type { ExecutionPhase } from './executionPhase';
+import type { TaskExecutionCompletedAt } from './taskExecutionCompletedAt';
+import type { TaskExecutionCost } from './taskExecutionCost';
+import type { TaskExecutionDurationSecs } from './taskExecutionDurationSecs';
+import type { TaskExecutionFindingsCount } from './taskExecutionFindingsCount';
+import type { TaskExecutionTokens } from './taskExecutionTokens';
+
+/**
+ * One run of a task
+ */
+export interface TaskExecution {
+  /** Files changed when the run's last review started */
+  changed_files: string[];
+  /** `None` while the run is still going */
+  completed_at?: TaskExecutionCompletedAt;
+  /** Cost in USD reported by OpenCode */
+  cost?: TaskExecutionCost;
+  duration_secs?: TaskExecutionDurationSecs;
+  /**
+   * Findings of the run's last review, `None` without a review
+   * @minimum 0
+   */
+  findings_count?: TaskExecutionFindingsCount;
+  /** `provider/model` of every model the run used */
+  models: string[];
+  /**
+   * Run number, starting at 1 for the oldest run
+   * @minimum 0
+   */
+  number: number;
+  outcome: ExecutionOutcome;
+  /** Phases in the order the run first reached them */
+  phases: ExecutionPhase[];
+  session_ids: string[];
+  started_at: string;
+  /** `None` when OpenCode could not report usage for any session */
+  tokens?: TaskExecutionTokens;
+}
@@ -12,6 +12,7 @@ export type TaskStatus = typeof TaskStatus[keyof typeof TaskStatus];
 // eslint-disable-next-line @typescript-eslint/no-redeclare
 export const TaskStatus = {
   todo: 'todo',
+  blocked: 'blocked',
   planning: 'planning',
   planning_review: 'planning_review',
   in_progress: 'in_progress',
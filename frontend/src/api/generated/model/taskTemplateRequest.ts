@@ -0,0 +1,18 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { PhaseModels } from './phaseModels';
+
+export interface TaskTemplateRequest {
+  /** Appended to the description of created tasks */
+  acceptance_criteria?: string;
+  description?: string;
+  name: string;
+  phase_models?: PhaseModels;
+  /** Title of created tasks; may hold `{{name}}` placeholders */
+  title: string;
+}
@@ -0,0 +1,21 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { PhaseModels } from './phaseModels';
+
+export interface TaskTemplateResponse {
+  acceptance_criteria: string;
+  created_at: number;
+  description: string;
+  id: string;
+  name: string;
+  phase_models: PhaseModels;
+  /** Placeholders a task created from the template needs values for */
+  placeholders: string[];
+  title: string;
+  updated_at: number;
+}
@@ -0,0 +1,20 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { TaskStatus } from './taskStatus';
+import type { TaskTransitionBlockedReason } from './taskTransitionBlockedReason';
+
+export interface TaskTransition {
+  allowed: boolean;
+  /** Why the transition is blocked right now */
+  blocked_reason?: TaskTransitionBlockedReason;
+  /** Phases stop before this transition and wait for a person to make it */
+  requires_approval: boolean;
+  /** The workflow starts the target status' phase after the transition */
+  run_phase: boolean;
+  to: TaskStatus;
+}
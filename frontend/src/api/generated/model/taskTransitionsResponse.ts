@@ -0,0 +1,17 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { TaskStatus } from './taskStatus';
+import type { TaskTransition } from './taskTransition';
+import type { TaskTransitionsResponseNextStatus } from './taskTransitionsResponseNextStatus';
+
+export interface TaskTransitionsResponse {
+  /** Status the workflow moves to next by default, if any */
+  next_status?: TaskTransitionsResponseNextStatus;
+  status: TaskStatus;
+  transitions: TaskTransition[];
+}
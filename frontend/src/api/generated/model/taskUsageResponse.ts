@@ -0,0 +1,17 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { ModelUsageTotals } from './modelUsageTotals';
+import type { SessionUsageTotals } from './sessionUsageTotals';
+import type { UsageTotals } from './usageTotals';
+
+export interface TaskUsageResponse {
+  by_model: ModelUsageTotals[];
+  by_session: SessionUsageTotals[];
+  task_id: string;
+  total: UsageTotals;
+}
@@ -0,0 +1,21 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export interface TaskWikiUpdateResponse {
+  /** `suggested` or `regenerated` */
+  action: string;
+  branch: string;
+  changed_files: string[];
+  /** Edit suggestions, or the rewritten page content */
+  content: string;
+  created_at: number;
+  id: string;
+  page_slug: string;
+  page_title: string;
+  task_id: string;
+}
@@ -0,0 +1,23 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * Tokens used by the OpenCode sessions of a phase
+ */
+export interface TokenUsage {
+  /** @minimum 0 */
+  cache_read: number;
+  /** @minimum 0 */
+  cache_write: number;
+  /** @minimum 0 */
+  input: number;
+  /** @minimum 0 */
+  output: number;
+  /** @minimum 0 */
+  reasoning: number;
+}
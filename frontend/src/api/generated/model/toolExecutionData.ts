@@ -0,0 +1,18 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export interface ToolExecutionData {
+  /** Tool input (JSON string or summary) */
+  input?: string | null;
+  /** Tool name */
+  name: string;
+  /** Tool output (truncated if large) */
+  output?: string | null;
+  /** Whether the tool succeeded */
+  success: boolean;
+}
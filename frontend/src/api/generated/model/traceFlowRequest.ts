@@ -0,0 +1,18 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { TraceFlowRequestBranch } from './traceFlowRequestBranch';
+
+export interface TraceFlowRequest {
+  branch?: TraceFlowRequestBranch;
+  /** HTTP method of the request to trace, e.g. `POST` */
+  method: string;
+  /** Request path, e.g. `/api/tasks/{id}/execute` */
+  path: string;
+  /** Save the flow document as a wiki page */
+  persist?: boolean;
+}
@@ -0,0 +1,19 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { TraceFlowResponsePageSlug } from './traceFlowResponsePageSlug';
+import type { WikiEndpoint } from './wikiEndpoint';
+import type { WikiFlowStep } from './wikiFlowStep';
+
+export interface TraceFlowResponse {
+  branch: string;
+  /** Markdown flow document */
+  content: string;
+  page_slug?: TraceFlowResponsePageSlug;
+  route: WikiEndpoint;
+  steps: WikiFlowStep[];
+}
@@ -10,5 +10,7 @@ import type { Task } from './task';
 
 export interface TransitionResponse {
   previous_status: TaskStatus;
+  /** The workflow starts the new status' phase after this transition */
+  run_phase: boolean;
   task: Task;
 }
@@ -0,0 +1,25 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { UpdateUserPreferencesRequestAskModel } from './updateUserPreferencesRequestAskModel';
+import type { UpdateUserPreferencesRequestBoardFilters } from './updateUserPreferencesRequestBoardFilters';
+import type { UpdateUserPreferencesRequestDigestEmail } from './updateUserPreferencesRequestDigestEmail';
+import type { UpdateUserPreferencesRequestDigestSchedule } from './updateUserPreferencesRequestDigestSchedule';
+import type { UpdateUserPreferencesRequestEventSubscriptions } from './updateUserPreferencesRequestEventSubscriptions';
+import type { UpdateUserPreferencesRequestNotificationChannels } from './updateUserPreferencesRequestNotificationChannels';
+
+export interface UpdateUserPreferencesRequest {
+  /** Empty to fall back to the project's chat model */
+  ask_model?: UpdateUserPreferencesRequestAskModel;
+  board_filters?: UpdateUserPreferencesRequestBoardFilters;
+  /** Empty to stop sending digests anywhere */
+  digest_email?: UpdateUserPreferencesRequestDigestEmail;
+  /** `off`, `daily` or `weekly` */
+  digest_schedule?: UpdateUserPreferencesRequestDigestSchedule;
+  event_subscriptions?: UpdateUserPreferencesRequestEventSubscriptions;
+  notification_channels?: UpdateUserPreferencesRequestNotificationChannels;
+}
@@ -9,18 +9,56 @@ import type { UpdateWikiSettingsRequestAccessToken } from './updateWikiSettingsR
 import type { UpdateWikiSettingsRequestAutoSync } from './updateWikiSettingsRequestAutoSync';
 import type { UpdateWikiSettingsRequestBranches } from './updateWikiSettingsRequestBranches';
 import type { UpdateWikiSettingsRequestChatModel } from './updateWikiSettingsRequestChatModel';
+import type { UpdateWikiSettingsRequestChunkSummaries } from './updateWikiSettingsRequestChunkSummaries';
+import type { UpdateWikiSettingsRequestEmbeddingConcurrency } from './updateWikiSettingsRequestEmbeddingConcurrency';
+import type { UpdateWikiSettingsRequestEmbeddingDimension } from './updateWikiSettingsRequestEmbeddingDimension';
 import type { UpdateWikiSettingsRequestEmbeddingModel } from './updateWikiSettingsRequestEmbeddingModel';
+import type { UpdateWikiSettingsRequestEmbeddingProvider } from './updateWikiSettingsRequestEmbeddingProvider';
 import type { UpdateWikiSettingsRequestEnabled } from './updateWikiSettingsRequestEnabled';
+import type { UpdateWikiSettingsRequestHistoryGenerations } from './updateWikiSettingsRequestHistoryGenerations';
+import type { UpdateWikiSettingsRequestLocalEmbeddingModelPath } from './updateWikiSettingsRequestLocalEmbeddingModelPath';
+import type { UpdateWikiSettingsRequestMaxPages } from './updateWikiSettingsRequestMaxPages';
+import type { UpdateWikiSettingsRequestMaxPagesPerSection } from './updateWikiSettingsRequestMaxPagesPerSection';
+import type { UpdateWikiSettingsRequestMinPageImportance } from './updateWikiSettingsRequestMinPageImportance';
+import type { UpdateWikiSettingsRequestOpenaiApiKey } from './updateWikiSettingsRequestOpenaiApiKey';
 import type { UpdateWikiSettingsRequestOpenrouterApiKey } from './updateWikiSettingsRequestOpenrouterApiKey';
+import type { UpdateWikiSettingsRequestPageConcurrency } from './updateWikiSettingsRequestPageConcurrency';
+import type { UpdateWikiSettingsRequestPlanningContext } from './updateWikiSettingsRequestPlanningContext';
+import type { UpdateWikiSettingsRequestPlanningContextMaxTokens } from './updateWikiSettingsRequestPlanningContextMaxTokens';
+import type { UpdateWikiSettingsRequestPostMergeUpdate } from './updateWikiSettingsRequestPostMergeUpdate';
 import type { UpdateWikiSettingsRequestRepoUrl } from './updateWikiSettingsRequestRepoUrl';
+import type { UpdateWikiSettingsRequestReviewContext } from './updateWikiSettingsRequestReviewContext';
+import type { UpdateWikiSettingsRequestReviewContextMaxTokens } from './updateWikiSettingsRequestReviewContextMaxTokens';
+import type { UpdateWikiSettingsRequestRoots } from './updateWikiSettingsRequestRoots';
 
 export interface UpdateWikiSettingsRequest {
   access_token?: UpdateWikiSettingsRequestAccessToken;
   auto_sync?: UpdateWikiSettingsRequestAutoSync;
   branches?: UpdateWikiSettingsRequestBranches;
   chat_model?: UpdateWikiSettingsRequestChatModel;
+  chunk_summaries?: UpdateWikiSettingsRequestChunkSummaries;
+  embedding_concurrency?: UpdateWikiSettingsRequestEmbeddingConcurrency;
+  /** Zero clears the dimension */
+  embedding_dimension?: UpdateWikiSettingsRequestEmbeddingDimension;
   embedding_model?: UpdateWikiSettingsRequestEmbeddingModel;
+  embedding_provider?: UpdateWikiSettingsRequestEmbeddingProvider;
   enabled?: UpdateWikiSettingsRequestEnabled;
+  /** Earlier indexes kept per branch; zero keeps none */
+  history_generations?: UpdateWikiSettingsRequestHistoryGenerations;
+  /** Empty clears the path */
+  local_embedding_model_path?: UpdateWikiSettingsRequestLocalEmbeddingModelPath;
+  max_pages?: UpdateWikiSettingsRequestMaxPages;
+  max_pages_per_section?: UpdateWikiSettingsRequestMaxPagesPerSection;
+  min_page_importance?: UpdateWikiSettingsRequestMinPageImportance;
+  /** Empty clears the key */
+  openai_api_key?: UpdateWikiSettingsRequestOpenaiApiKey;
   openrouter_api_key?: UpdateWikiSettingsRequestOpenrouterApiKey;
+  page_concurrency?: UpdateWikiSettingsRequestPageConcurrency;
+  planning_context?: UpdateWikiSettingsRequestPlanningContext;
+  planning_context_max_tokens?: UpdateWikiSettingsRequestPlanningContextMaxTokens;
+  post_merge_update?: UpdateWikiSettingsRequestPostMergeUpdate;
   repo_url?: UpdateWikiSettingsRequestRepoUrl;
+  review_context?: UpdateWikiSettingsRequestReviewContext;
+  review_context_max_tokens?: UpdateWikiSettingsRequestReviewContextMaxTokens;
+  roots?: UpdateWikiSettingsRequestRoots;
 }
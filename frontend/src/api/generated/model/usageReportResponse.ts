@@ -0,0 +1,23 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { BranchUsageTotals } from './branchUsageTotals';
+import type { ModelUsageTotals } from './modelUsageTotals';
+import type { TaskUsageTotals } from './taskUsageTotals';
+import type { UsageTotals } from './usageTotals';
+
+export interface UsageReportResponse {
+  by_model: ModelUsageTotals[];
+  /** Calls made for tasks, by task */
+  by_task: TaskUsageTotals[];
+  /**
+   * Calls made for the wiki, by branch; task calls that read the wiki
+   * count here too
+   */
+  by_wiki_branch: BranchUsageTotals[];
+  total: UsageTotals;
+}
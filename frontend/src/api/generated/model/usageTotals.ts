@@ -0,0 +1,25 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { UsageTotalsCostUsd } from './usageTotalsCostUsd';
+
+/**
+ * Usage summed over a set of calls
+ */
+export interface UsageTotals {
+  /** @minimum 0 */
+  completion_tokens: number;
+  /**
+   * Estimated cost in USD of the calls whose model price is known;
+   * `None` when no price was known
+   */
+  cost_usd?: UsageTotalsCostUsd;
+  /** @minimum 0 */
+  prompt_tokens: number;
+  /** @minimum 0 */
+  requests: number;
+}
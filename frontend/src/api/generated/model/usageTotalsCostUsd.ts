@@ -0,0 +1,13 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * Estimated cost in USD of the calls whose model price is known;
+ * `None` when no price was known
+ */
+export type UsageTotalsCostUsd = number | null;
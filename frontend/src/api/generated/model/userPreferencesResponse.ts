@@ -0,0 +1,33 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { UserPreferencesResponseAskModel } from './userPreferencesResponseAskModel';
+import type { UserPreferencesResponseBoardFilters } from './userPreferencesResponseBoardFilters';
+import type { UserPreferencesResponseDigestEmail } from './userPreferencesResponseDigestEmail';
+import type { UserPreferencesResponseDigestLastSentAt } from './userPreferencesResponseDigestLastSentAt';
+
+export interface UserPreferencesResponse {
+  /** Chat model for wiki questions, overriding the project's */
+  ask_model?: UserPreferencesResponseAskModel;
+  /** Board filters restored when the board is opened */
+  board_filters: UserPreferencesResponseBoardFilters;
+  /** Address email digests are sent to */
+  digest_email?: UserPreferencesResponseDigestEmail;
+  /** Unix timestamp of the last digest sent */
+  digest_last_sent_at?: UserPreferencesResponseDigestLastSentAt;
+  /** How often digests are sent: `off`, `daily` or `weekly` */
+  digest_schedule: string;
+  /**
+   * Event types the UI shows notifications for, e.g. `session.ended` or
+   * `task.*`; empty means every event. Events are streamed regardless.
+   */
+  event_subscriptions: string[];
+  /** Channels notifications are delivered to (`in_app`) */
+  notification_channels: string[];
+  updated_at: number;
+  user_id: string;
+}
@@ -8,6 +8,7 @@
 import type { ValidatePathResponseError } from './validatePathResponseError';
 import type { ValidatePathResponseName } from './validatePathResponseName';
 import type { ValidatePathResponseVcs } from './validatePathResponseVcs';
+import type { ValidatePathResponseWarning } from './validatePathResponseWarning';
 
 export interface ValidatePathResponse {
   error?: ValidatePathResponseError;
@@ -16,4 +17,5 @@ export interface ValidatePathResponse {
   name?: ValidatePathResponseName;
   valid: boolean;
   vcs?: ValidatePathResponseVcs;
+  warning?: ValidatePathResponseWarning;
 }
@@ -0,0 +1,23 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export interface VcsCapabilities {
+  /** Workspaces are worktrees on their own branch rather than plain copies */
+  branch_workspaces: boolean;
+  /** Changes can be committed to history */
+  commit: boolean;
+  /**
+   * Merges record conflicts in the merge commit, to be resolved in the
+   * workspace, rather than failing
+   */
+  conflict_commits: boolean;
+  /** Workspace changes can be merged back into the project */
+  merge: boolean;
+  /** Branches can be pushed to a remote, which pull requests need */
+  push: boolean;
+}
@@ -0,0 +1,15 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { WebhookPullRequestBase } from './webhookPullRequestBase';
+
+export interface WebhookPullRequest {
+  base: WebhookPullRequestBase;
+  merged?: boolean;
+  /** @minimum 0 */
+  number: number;
+}
@@ -0,0 +1,16 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { WebhookPullRequest } from './webhookPullRequest';
+
+/**
+ * The parts of a GitHub `pull_request` webhook payload the wiki reads
+ */
+export interface WebhookPullRequestEvent {
+  action: string;
+  pull_request: WebhookPullRequest;
+}
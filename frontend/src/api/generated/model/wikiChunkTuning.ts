@@ -0,0 +1,22 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * Chunk settings a tuning pass measured as retrieving best
+ */
+export interface WikiChunkTuning {
+  /** @minimum 0 */
+  chunk_overlap: number;
+  /** Embedding model the setting was tuned with */
+  embedding_model: string;
+  /** @minimum 0 */
+  max_chunk_tokens: number;
+  /** Mean reciprocal rank the setting scored */
+  mean_reciprocal_rank: number;
+  tuned_at: number;
+}
@@ -0,0 +1,22 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { WikiComparedPage } from './wikiComparedPage';
+import type { WikiPageChange } from './wikiPageChange';
+
+export interface WikiCompareResponse {
+  /** Pages only in `head` */
+  added: WikiComparedPage[];
+  base: string;
+  /** Pages in both branches whose title or content differs */
+  changed: WikiPageChange[];
+  head: string;
+  /** Pages only in `base` */
+  removed: WikiComparedPage[];
+  /** @minimum 0 */
+  unchanged_count: number;
+}
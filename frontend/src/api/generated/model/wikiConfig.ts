@@ -5,11 +5,18 @@
  * API for OpenCode Studio - AI-powered development platform
  * OpenAPI spec version: 0.1.0
  */
+import type { PostMergeWikiUpdate } from './postMergeWikiUpdate';
 import type { WikiConfigAccessToken } from './wikiConfigAccessToken';
 import type { WikiConfigChatModel } from './wikiConfigChatModel';
+import type { WikiConfigChunkTuning } from './wikiConfigChunkTuning';
+import type { WikiConfigEmbeddingDimension } from './wikiConfigEmbeddingDimension';
 import type { WikiConfigEmbeddingModel } from './wikiConfigEmbeddingModel';
+import type { WikiConfigLocalEmbeddingModelPath } from './wikiConfigLocalEmbeddingModelPath';
+import type { WikiConfigOpenaiApiKey } from './wikiConfigOpenaiApiKey';
 import type { WikiConfigOpenrouterApiKey } from './wikiConfigOpenrouterApiKey';
 import type { WikiConfigRepoUrl } from './wikiConfigRepoUrl';
+import type { WikiEmbeddingProvider } from './wikiEmbeddingProvider';
+import type { WikiPageImportance } from './wikiPageImportance';
 
 /**
  * Wiki feature configuration
@@ -23,12 +30,87 @@ export interface WikiConfig {
   branches?: string[];
   /** Chat model for RAG (default: "anthropic/claude-3.5-sonnet") */
   chat_model?: WikiConfigChatModel;
+  /**
+   * Summarize each chunk with the chat model while indexing; summaries
+   * are shown with search results and embedded with the code
+   */
+  chunk_summaries?: boolean;
+  /**
+   * Chunk size and overlap a tuning pass picked for the repository;
+   * indexing uses 350 and 100 tokens when unset
+   */
+  chunk_tuning?: WikiConfigChunkTuning;
+  /**
+   * Embedding batches requested at once while indexing
+   * @minimum 0
+   */
+  embedding_concurrency?: number;
+  /**
+   * Vector dimension of the embedding model, e.g. 3072 for
+   * `text-embedding-3-large`; taken from its first embedding when unset.
+   * Changing it empties the model's vectors and marks its branches stale.
+   */
+  embedding_dimension?: WikiConfigEmbeddingDimension;
   /** Embedding model (default: "openai/text-embedding-3-small") */
   embedding_model?: WikiConfigEmbeddingModel;
+  /** Where embeddings come from; chat features always use OpenRouter */
+  embedding_provider?: WikiEmbeddingProvider;
   /** Whether wiki feature is enabled */
   enabled?: boolean;
+  /**
+   * Earlier indexes of each branch kept for searches as of their commit;
+   * zero keeps none
+   * @minimum 0
+   */
+  history_generations?: number;
+  /**
+   * Directory with `config.json`, `tokenizer.json` and `model.safetensors`
+   * of the model for the `local` embedding provider
+   */
+  local_embedding_model_path?: WikiConfigLocalEmbeddingModelPath;
+  /**
+   * Maximum pages generated in one wiki generation run
+   * @minimum 0
+   */
+  max_pages?: number;
+  /**
+   * Maximum pages generated for one wiki section
+   * @minimum 0
+   */
+  max_pages_per_section?: number;
+  /** Planned pages below this importance are skipped */
+  min_page_importance?: WikiPageImportance;
+  /** OpenAI API key for the `openai` embedding provider */
+  openai_api_key?: WikiConfigOpenaiApiKey;
   /** OpenRouter API key for embeddings and chat */
   openrouter_api_key?: WikiConfigOpenrouterApiKey;
+  /**
+   * Wiki pages generated at once; lowered automatically when rate limited
+   * @minimum 0
+   */
+  page_concurrency?: number;
+  /** Ground planning prompts in code and wiki pages related to the task */
+  planning_context?: boolean;
+  /**
+   * Token cap for the wiki context attached to planning prompts
+   * @minimum 0
+   */
+  planning_context_max_tokens?: number;
+  /** Handling of wiki pages affected by a merged task */
+  post_merge_update?: PostMergeWikiUpdate;
   /** Remote repository URL for indexing external repos */
   repo_url?: WikiConfigRepoUrl;
+  /** Attach wiki pages and related code for changed files to AI review prompts */
+  review_context?: boolean;
+  /**
+   * Token cap for the wiki context attached to AI review prompts
+   * @minimum 0
+   */
+  review_context_max_tokens?: number;
+  /**
+   * Sub-directories of a monorepo to index, e.g. `["backend/", "frontend/"]`;
+   * searches and questions can be scoped to one of them. Empty indexes
+   * the whole repository.
+   */
+  roots?: string[];
 }
@@ -0,0 +1,15 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * Vector dimension of the embedding model, e.g. 3072 for
+ * `text-embedding-3-large`; taken from its first embedding when unset.
+ * Changing it empties the model's vectors and marks its branches stale.
+ * @minimum 0
+ */
+export type WikiConfigEmbeddingDimension = number | null;
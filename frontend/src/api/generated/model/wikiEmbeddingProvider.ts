@@ -0,0 +1,20 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * Where wiki embeddings come from
+ */
+export type WikiEmbeddingProvider = typeof WikiEmbeddingProvider[keyof typeof WikiEmbeddingProvider];
+
+
+// eslint-disable-next-line @typescript-eslint/no-redeclare
+export const WikiEmbeddingProvider = {
+  openrouter: 'openrouter',
+  openai: 'openai',
+  local: 'local',
+} as const;
@@ -0,0 +1,18 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { WikiEndpointHandler } from './wikiEndpointHandler';
+
+export interface WikiEndpoint {
+  file_path: string;
+  framework: string;
+  handler?: WikiEndpointHandler;
+  /** @minimum 0 */
+  line: number;
+  method: string;
+  path: string;
+}
@@ -0,0 +1,17 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { WikiEnvVarDefaultValue } from './wikiEnvVarDefaultValue';
+import type { WikiEnvVarDescription } from './wikiEnvVarDescription';
+import type { WikiEnvVarUsage } from './wikiEnvVarUsage';
+
+export interface WikiEnvVar {
+  default_value?: WikiEnvVarDefaultValue;
+  description?: WikiEnvVarDescription;
+  name: string;
+  usages: WikiEnvVarUsage[];
+}
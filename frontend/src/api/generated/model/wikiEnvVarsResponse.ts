@@ -0,0 +1,15 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { WikiEnvVar } from './wikiEnvVar';
+
+export interface WikiEnvVarsResponse {
+  branch: string;
+  /** @minimum 0 */
+  total_count: number;
+  variables: WikiEnvVar[];
+}
@@ -0,0 +1,26 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { AskFeedbackResponse } from './askFeedbackResponse';
+import type { FlaggedPageResponse } from './flaggedPageResponse';
+import type { FlaggedSourceResponse } from './flaggedSourceResponse';
+
+export interface WikiFeedbackStatsResponse {
+  branch: string;
+  /** Pages of `branch` citing flagged sources, most affected first */
+  flagged_pages: FlaggedPageResponse[];
+  /** Most negative votes first */
+  flagged_sources: FlaggedSourceResponse[];
+  /** @minimum 0 */
+  negative: number;
+  /** @minimum 0 */
+  positive: number;
+  /** Latest rated-down answers, newest first */
+  recent_negative: AskFeedbackResponse[];
+  /** @minimum 0 */
+  total: number;
+}
@@ -0,0 +1,28 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { WikiFileChunkLanguage } from './wikiFileChunkLanguage';
+import type { WikiFileChunkSummary } from './wikiFileChunkSummary';
+
+export interface WikiFileChunk {
+  /** @minimum 0 */
+  chunk_index: number;
+  chunk_type: string;
+  commit_sha: string;
+  content: string;
+  /** @minimum 0 */
+  end_line: number;
+  id: string;
+  indexed_at: string;
+  language?: WikiFileChunkLanguage;
+  /** @minimum 0 */
+  start_line: number;
+  /** One-line summary written at index time */
+  summary?: WikiFileChunkSummary;
+  /** @minimum 0 */
+  token_count: number;
+}
@@ -0,0 +1,15 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { WikiFileChunk } from './wikiFileChunk';
+
+export interface WikiFileChunksResponse {
+  branch: string;
+  /** Chunks of the file, ordered by chunk index */
+  chunks: WikiFileChunk[];
+  file_path: string;
+}
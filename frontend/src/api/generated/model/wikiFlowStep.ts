@@ -0,0 +1,20 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { WikiFlowStepCalledFrom } from './wikiFlowStepCalledFrom';
+
+export interface WikiFlowStep {
+  called_from?: WikiFlowStepCalledFrom;
+  /** @minimum 0 */
+  depth: number;
+  /** @minimum 0 */
+  end_line: number;
+  file_path: string;
+  /** @minimum 0 */
+  line: number;
+  symbol: string;
+}
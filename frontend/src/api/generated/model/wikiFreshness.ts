@@ -0,0 +1,20 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { WikiFreshnessIndexedCommit } from './wikiFreshnessIndexedCommit';
+import type { WikiFreshnessLastIndexedAt } from './wikiFreshnessLastIndexedAt';
+
+/**
+ * How current the wiki index of a branch is
+ */
+export interface WikiFreshness {
+  branch: string;
+  /** Commit the index was built from */
+  indexed_commit?: WikiFreshnessIndexedCommit;
+  last_indexed_at?: WikiFreshnessLastIndexedAt;
+  state: string;
+}
@@ -0,0 +1,19 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export type WikiGenerationPhase = typeof WikiGenerationPhase[keyof typeof WikiGenerationPhase];
+
+
+// eslint-disable-next-line @typescript-eslint/no-redeclare
+export const WikiGenerationPhase = {
+  analyzing: 'analyzing',
+  planning: 'planning',
+  generating_pages: 'generating_pages',
+  completed: 'completed',
+  failed: 'failed',
+} as const;
@@ -0,0 +1,15 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { WikiOnboardingStep } from './wikiOnboardingStep';
+
+export interface WikiOnboardingResponse {
+  branch: string;
+  steps: WikiOnboardingStep[];
+  /** @minimum 0 */
+  total_minutes: number;
+}
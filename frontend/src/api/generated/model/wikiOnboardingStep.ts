@@ -0,0 +1,16 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+export interface WikiOnboardingStep {
+  /** @minimum 0 */
+  reading_minutes: number;
+  reason: string;
+  slug: string;
+  stage: string;
+  title: string;
+}
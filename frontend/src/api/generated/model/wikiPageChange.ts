@@ -0,0 +1,22 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { WikiPageChangePreviousTitle } from './wikiPageChangePreviousTitle';
+
+export interface WikiPageChange {
+  /** Unified diff of the page content, empty when only the title changed */
+  diff: string;
+  /** @minimum 0 */
+  lines_added: number;
+  /** @minimum 0 */
+  lines_removed: number;
+  /** Title in `base`, when it differs */
+  previous_title?: WikiPageChangePreviousTitle;
+  slug: string;
+  /** Title in `head` */
+  title: string;
+}
@@ -0,0 +1,20 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+
+/**
+ * Lowest importance of planned wiki pages that get generated
+ */
+export type WikiPageImportance = typeof WikiPageImportance[keyof typeof WikiPageImportance];
+
+
+// eslint-disable-next-line @typescript-eslint/no-redeclare
+export const WikiPageImportance = {
+  low: 'low',
+  medium: 'medium',
+  high: 'high',
+} as const;
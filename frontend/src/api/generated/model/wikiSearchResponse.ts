@@ -10,6 +10,8 @@ import type { WikiSearchResult } from './wikiSearchResult';
 export interface WikiSearchResponse {
   /** @minimum 0 */
   duration_ms: number;
+  /** Synonyms from the project glossary added to the query before embedding */
+  expanded_terms: string[];
   query: string;
   results: WikiSearchResult[];
   /** @minimum 0 */
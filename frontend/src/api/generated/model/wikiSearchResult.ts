@@ -6,14 +6,19 @@
  * OpenAPI spec version: 0.1.0
  */
 import type { WikiSearchResultLanguage } from './wikiSearchResultLanguage';
+import type { WikiSearchResultSummary } from './wikiSearchResultSummary';
 
 export interface WikiSearchResult {
   content: string;
   /** @minimum 0 */
   end_line: number;
   file_path: string;
+  /** Whether the code defines public API */
+  is_public_api: boolean;
   language?: WikiSearchResultLanguage;
   score: number;
   /** @minimum 0 */
   start_line: number;
+  /** One-line summary of the code, written at index time */
+  summary?: WikiSearchResultSummary;
 }
@@ -5,17 +5,50 @@
  * API for OpenCode Studio - AI-powered development platform
  * OpenAPI spec version: 0.1.0
  */
+import type { PostMergeWikiUpdate } from './postMergeWikiUpdate';
+import type { WikiEmbeddingProvider } from './wikiEmbeddingProvider';
+import type { WikiPageImportance } from './wikiPageImportance';
 import type { WikiSettingsResponseChatModel } from './wikiSettingsResponseChatModel';
+import type { WikiSettingsResponseChunkTuning } from './wikiSettingsResponseChunkTuning';
+import type { WikiSettingsResponseEmbeddingDimension } from './wikiSettingsResponseEmbeddingDimension';
 import type { WikiSettingsResponseEmbeddingModel } from './wikiSettingsResponseEmbeddingModel';
+import type { WikiSettingsResponseLocalEmbeddingModelPath } from './wikiSettingsResponseLocalEmbeddingModelPath';
 import type { WikiSettingsResponseRepoUrl } from './wikiSettingsResponseRepoUrl';
 
 export interface WikiSettingsResponse {
   auto_sync: boolean;
   branches: string[];
   chat_model?: WikiSettingsResponseChatModel;
+  chunk_summaries: boolean;
+  /** Chunk settings picked by the last applied tuning pass */
+  chunk_tuning?: WikiSettingsResponseChunkTuning;
+  /** @minimum 0 */
+  embedding_concurrency: number;
+  embedding_dimension?: WikiSettingsResponseEmbeddingDimension;
   embedding_model?: WikiSettingsResponseEmbeddingModel;
+  embedding_provider: WikiEmbeddingProvider;
   enabled: boolean;
   has_access_token: boolean;
   has_api_key: boolean;
+  has_openai_api_key: boolean;
+  /** @minimum 0 */
+  history_generations: number;
+  local_embedding_model_path?: WikiSettingsResponseLocalEmbeddingModelPath;
+  /** @minimum 0 */
+  max_pages: number;
+  /** @minimum 0 */
+  max_pages_per_section: number;
+  min_page_importance: WikiPageImportance;
+  /** @minimum 0 */
+  page_concurrency: number;
+  planning_context: boolean;
+  /** @minimum 0 */
+  planning_context_max_tokens: number;
+  post_merge_update: PostMergeWikiUpdate;
   repo_url?: WikiSettingsResponseRepoUrl;
+  review_context: boolean;
+  /** @minimum 0 */
+  review_context_max_tokens: number;
+  /** Workspace roots indexed; empty when the whole repository is */
+  roots: string[];
 }
@@ -7,6 +7,7 @@
  */
 import type { WikiTreeNode } from './wikiTreeNode';
 import type { WikiSectionResponse } from './wikiSectionResponse';
+import type { SkippedWikiPageResponse } from './skippedWikiPageResponse';
 
 export interface WikiStructureResponse {
   branch: string;
@@ -14,4 +15,5 @@ export interface WikiStructureResponse {
   page_count: number;
   root: WikiTreeNode;
   sections: WikiSectionResponse[];
+  skipped_pages: SkippedWikiPageResponse[];
 }
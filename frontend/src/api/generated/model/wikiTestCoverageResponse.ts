@@ -0,0 +1,19 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import type { WikiModuleCoverage } from './wikiModuleCoverage';
+
+export interface WikiTestCoverageResponse {
+  branch: string;
+  modules: WikiModuleCoverage[];
+  /** @minimum 0 */
+  test_file_count: number;
+  /** @minimum 0 */
+  tested_count: number;
+  /** @minimum 0 */
+  untested_count: number;
+}
@@ -0,0 +1,333 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import {
+  useMutation,
+  useQuery
+} from '@tanstack/react-query';
+import type {
+  DataTag,
+  DefinedInitialDataOptions,
+  DefinedUseQueryResult,
+  MutationFunction,
+  QueryClient,
+  QueryFunction,
+  QueryKey,
+  UndefinedInitialDataOptions,
+  UseMutationOptions,
+  UseMutationResult,
+  UseQueryOptions,
+  UseQueryResult
+} from '@tanstack/react-query';
+
+import type {
+  SendTestDigestRequest,
+  SendTestDigestResponse,
+  UpdateUserPreferencesRequest,
+  UserPreferencesResponse
+} from '.././model';
+
+import { customFetch } from '../../../lib/api-fetcher';
+
+
+type SecondParameter<T extends (...args: never) => unknown> = Parameters<T>[1];
+
+
+
+export type getUserPreferencesResponse200 = {
+  data: UserPreferencesResponse
+  status: 200
+}
+
+export type getUserPreferencesResponse500 = {
+  data: void
+  status: 500
+}
+    
+export type getUserPreferencesResponseSuccess = (getUserPreferencesResponse200) & {
+  headers: Headers;
+};
+export type getUserPreferencesResponseError = (getUserPreferencesResponse500) & {
+  headers: Headers;
+};
+
+export type getUserPreferencesResponse = (getUserPreferencesResponseSuccess | getUserPreferencesResponseError)
+
+export const getGetUserPreferencesUrl = () => {
+
+
+  
+
+  return `/api/me/preferences`
+}
+
+export const getUserPreferences = async ( options?: RequestInit): Promise<getUserPreferencesResponse> => {
+  
+  return customFetch<getUserPreferencesResponse>(getGetUserPreferencesUrl(),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetUserPreferencesQueryKey = () => {
+    return [
+    `/api/me/preferences`
+    ] as const;
+    }
+
+    
+export const getGetUserPreferencesQueryOptions = <TData = Awaited<ReturnType<typeof getUserPreferences>>, TError = void>( options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getUserPreferences>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetUserPreferencesQueryKey();
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getUserPreferences>>> = ({ signal }) => getUserPreferences({ signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getUserPreferences>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetUserPreferencesQueryResult = NonNullable<Awaited<ReturnType<typeof getUserPreferences>>>
+export type GetUserPreferencesQueryError = void
+
+
+export function useGetUserPreferences<TData = Awaited<ReturnType<typeof getUserPreferences>>, TError = void>(
+  options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getUserPreferences>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getUserPreferences>>,
+          TError,
+          Awaited<ReturnType<typeof getUserPreferences>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetUserPreferences<TData = Awaited<ReturnType<typeof getUserPreferences>>, TError = void>(
+  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getUserPreferences>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getUserPreferences>>,
+          TError,
+          Awaited<ReturnType<typeof getUserPreferences>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetUserPreferences<TData = Awaited<ReturnType<typeof getUserPreferences>>, TError = void>(
+  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getUserPreferences>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetUserPreferences<TData = Awaited<ReturnType<typeof getUserPreferences>>, TError = void>(
+  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getUserPreferences>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetUserPreferencesQueryOptions(options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type updateUserPreferencesResponse200 = {
+  data: UserPreferencesResponse
+  status: 200
+}
+
+export type updateUserPreferencesResponse400 = {
+  data: void
+  status: 400
+}
+
+export type updateUserPreferencesResponse500 = {
+  data: void
+  status: 500
+}
+    
+export type updateUserPreferencesResponseSuccess = (updateUserPreferencesResponse200) & {
+  headers: Headers;
+};
+export type updateUserPreferencesResponseError = (updateUserPreferencesResponse400 | updateUserPreferencesResponse500) & {
+  headers: Headers;
+};
+
+export type updateUserPreferencesResponse = (updateUserPreferencesResponseSuccess | updateUserPreferencesResponseError)
+
+export const getUpdateUserPreferencesUrl = () => {
+
+
+  
+
+  return `/api/me/preferences`
+}
+
+export const updateUserPreferences = async (updateUserPreferencesRequest: UpdateUserPreferencesRequest, options?: RequestInit): Promise<updateUserPreferencesResponse> => {
+  
+  return customFetch<updateUserPreferencesResponse>(getUpdateUserPreferencesUrl(),
+  {      
+    ...options,
+    method: 'PUT',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      updateUserPreferencesRequest,)
+  }
+);}
+
+
+
+
+export const getUpdateUserPreferencesMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof updateUserPreferences>>, TError,{data: UpdateUserPreferencesRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof updateUserPreferences>>, TError,{data: UpdateUserPreferencesRequest}, TContext> => {
+
+const mutationKey = ['updateUserPreferences'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof updateUserPreferences>>, {data: UpdateUserPreferencesRequest}> = (props) => {
+          const {data} = props ?? {};
+
+          return  updateUserPreferences(data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type UpdateUserPreferencesMutationResult = NonNullable<Awaited<ReturnType<typeof updateUserPreferences>>>
+    export type UpdateUserPreferencesMutationBody = UpdateUserPreferencesRequest
+    export type UpdateUserPreferencesMutationError = void
+
+    export const useUpdateUserPreferences = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof updateUserPreferences>>, TError,{data: UpdateUserPreferencesRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof updateUserPreferences>>,
+        TError,
+        {data: UpdateUserPreferencesRequest},
+        TContext
+      > => {
+
+      const mutationOptions = getUpdateUserPreferencesMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    
+export type sendTestDigestResponse200 = {
+  data: SendTestDigestResponse
+  status: 200
+}
+
+export type sendTestDigestResponse400 = {
+  data: void
+  status: 400
+}
+
+export type sendTestDigestResponse500 = {
+  data: void
+  status: 500
+}
+    
+export type sendTestDigestResponseSuccess = (sendTestDigestResponse200) & {
+  headers: Headers;
+};
+export type sendTestDigestResponseError = (sendTestDigestResponse400 | sendTestDigestResponse500) & {
+  headers: Headers;
+};
+
+export type sendTestDigestResponse = (sendTestDigestResponseSuccess | sendTestDigestResponseError)
+
+export const getSendTestDigestUrl = () => {
+
+
+  
+
+  return `/api/me/digest/test`
+}
+
+export const sendTestDigest = async (sendTestDigestRequest: SendTestDigestRequest, options?: RequestInit): Promise<sendTestDigestResponse> => {
+  
+  return customFetch<sendTestDigestResponse>(getSendTestDigestUrl(),
+  {      
+    ...options,
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      sendTestDigestRequest,)
+  }
+);}
+
+
+
+
+export const getSendTestDigestMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof sendTestDigest>>, TError,{data: SendTestDigestRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof sendTestDigest>>, TError,{data: SendTestDigestRequest}, TContext> => {
+
+const mutationKey = ['sendTestDigest'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof sendTestDigest>>, {data: SendTestDigestRequest}> = (props) => {
+          const {data} = props ?? {};
+
+          return  sendTestDigest(data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type SendTestDigestMutationResult = NonNullable<Awaited<ReturnType<typeof sendTestDigest>>>
+    export type SendTestDigestMutationBody = SendTestDigestRequest
+    export type SendTestDigestMutationError = void
+
+    export const useSendTestDigest = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof sendTestDigest>>, TError,{data: SendTestDigestRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof sendTestDigest>>,
+        TError,
+        {data: SendTestDigestRequest},
+        TContext
+      > => {
+
+      const mutationOptions = getSendTestDigestMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    
\ No newline at end of file
@@ -0,0 +1,341 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import {
+  useMutation,
+  useQuery
+} from '@tanstack/react-query';
+import type {
+  DataTag,
+  DefinedInitialDataOptions,
+  DefinedUseQueryResult,
+  MutationFunction,
+  QueryClient,
+  QueryFunction,
+  QueryKey,
+  UndefinedInitialDataOptions,
+  UseMutationOptions,
+  UseMutationResult,
+  UseQueryOptions,
+  UseQueryResult
+} from '@tanstack/react-query';
+
+import type {
+  ReleaseReviewReport,
+  StartReleaseReviewRequest
+} from '.././model';
+
+import { customFetch } from '../../../lib/api-fetcher';
+
+
+type SecondParameter<T extends (...args: never) => unknown> = Parameters<T>[1];
+
+
+
+export type listReleaseReviewsResponse200 = {
+  data: ReleaseReviewReport[]
+  status: 200
+}
+    
+export type listReleaseReviewsResponseSuccess = (listReleaseReviewsResponse200) & {
+  headers: Headers;
+};
+;
+
+export type listReleaseReviewsResponse = (listReleaseReviewsResponseSuccess)
+
+export const getListReleaseReviewsUrl = () => {
+
+
+  
+
+  return `/api/releases/reviews`
+}
+
+export const listReleaseReviews = async (options?: RequestInit): Promise<listReleaseReviewsResponse> => {
+  
+  return customFetch<listReleaseReviewsResponse>(getListReleaseReviewsUrl(),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getListReleaseReviewsQueryKey = () => {
+    return [
+    `/api/releases/reviews`
+    ] as const;
+    }
+
+    
+export const getListReleaseReviewsQueryOptions = <TData = Awaited<ReturnType<typeof listReleaseReviews>>, TError = unknown>(options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listReleaseReviews>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getListReleaseReviewsQueryKey();
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof listReleaseReviews>>> = ({ signal }) => listReleaseReviews({ signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof listReleaseReviews>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type ListReleaseReviewsQueryResult = NonNullable<Awaited<ReturnType<typeof listReleaseReviews>>>
+export type ListReleaseReviewsQueryError = unknown
+
+
+export function useListReleaseReviews<TData = Awaited<ReturnType<typeof listReleaseReviews>>, TError = unknown>(
+ options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof listReleaseReviews>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof listReleaseReviews>>,
+          TError,
+          Awaited<ReturnType<typeof listReleaseReviews>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useListReleaseReviews<TData = Awaited<ReturnType<typeof listReleaseReviews>>, TError = unknown>(
+ options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listReleaseReviews>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof listReleaseReviews>>,
+          TError,
+          Awaited<ReturnType<typeof listReleaseReviews>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useListReleaseReviews<TData = Awaited<ReturnType<typeof listReleaseReviews>>, TError = unknown>(
+ options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listReleaseReviews>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useListReleaseReviews<TData = Awaited<ReturnType<typeof listReleaseReviews>>, TError = unknown>(
+ options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listReleaseReviews>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getListReleaseReviewsQueryOptions(options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type startReleaseReviewResponse202 = {
+  data: ReleaseReviewReport
+  status: 202
+}
+
+export type startReleaseReviewResponse400 = {
+  data: void
+  status: 400
+}
+    
+export type startReleaseReviewResponseSuccess = (startReleaseReviewResponse202) & {
+  headers: Headers;
+};
+export type startReleaseReviewResponseError = (startReleaseReviewResponse400) & {
+  headers: Headers;
+};
+
+export type startReleaseReviewResponse = (startReleaseReviewResponseSuccess | startReleaseReviewResponseError)
+
+export const getStartReleaseReviewUrl = () => {
+
+
+  
+
+  return `/api/releases/reviews`
+}
+
+export const startReleaseReview = async (startReleaseReviewRequest: StartReleaseReviewRequest, options?: RequestInit): Promise<startReleaseReviewResponse> => {
+  
+  return customFetch<startReleaseReviewResponse>(getStartReleaseReviewUrl(),
+  {      
+    ...options,
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      startReleaseReviewRequest,)
+  }
+);}
+
+
+
+
+export const getStartReleaseReviewMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof startReleaseReview>>, TError,{data: StartReleaseReviewRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof startReleaseReview>>, TError,{data: StartReleaseReviewRequest}, TContext> => {
+
+const mutationKey = ['startReleaseReview'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof startReleaseReview>>, {data: StartReleaseReviewRequest}> = (props) => {
+          const {data} = props ?? {};
+
+          return  startReleaseReview(data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type StartReleaseReviewMutationResult = NonNullable<Awaited<ReturnType<typeof startReleaseReview>>>
+    export type StartReleaseReviewMutationBody = StartReleaseReviewRequest
+    export type StartReleaseReviewMutationError = void
+
+    export const useStartReleaseReview = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof startReleaseReview>>, TError,{data: StartReleaseReviewRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof startReleaseReview>>,
+        TError,
+        {data: StartReleaseReviewRequest},
+        TContext
+      > => {
+
+      const mutationOptions = getStartReleaseReviewMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type getReleaseReviewResponse200 = {
+  data: ReleaseReviewReport
+  status: 200
+}
+
+export type getReleaseReviewResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type getReleaseReviewResponseSuccess = (getReleaseReviewResponse200) & {
+  headers: Headers;
+};
+export type getReleaseReviewResponseError = (getReleaseReviewResponse404) & {
+  headers: Headers;
+};
+
+export type getReleaseReviewResponse = (getReleaseReviewResponseSuccess | getReleaseReviewResponseError)
+
+export const getGetReleaseReviewUrl = (id: string,) => {
+
+
+  
+
+  return `/api/releases/reviews/${id}`
+}
+
+export const getReleaseReview = async (id: string, options?: RequestInit): Promise<getReleaseReviewResponse> => {
+  
+  return customFetch<getReleaseReviewResponse>(getGetReleaseReviewUrl(id),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetReleaseReviewQueryKey = (id?: string,) => {
+    return [
+    `/api/releases/reviews/${id}`
+    ] as const;
+    }
+
+    
+export const getGetReleaseReviewQueryOptions = <TData = Awaited<ReturnType<typeof getReleaseReview>>, TError = void>(id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getReleaseReview>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetReleaseReviewQueryKey(id);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getReleaseReview>>> = ({ signal }) => getReleaseReview(id, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, enabled: !!(id), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getReleaseReview>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetReleaseReviewQueryResult = NonNullable<Awaited<ReturnType<typeof getReleaseReview>>>
+export type GetReleaseReviewQueryError = void
+
+
+export function useGetReleaseReview<TData = Awaited<ReturnType<typeof getReleaseReview>>, TError = void>(
+ id: string, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getReleaseReview>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getReleaseReview>>,
+          TError,
+          Awaited<ReturnType<typeof getReleaseReview>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetReleaseReview<TData = Awaited<ReturnType<typeof getReleaseReview>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getReleaseReview>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getReleaseReview>>,
+          TError,
+          Awaited<ReturnType<typeof getReleaseReview>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetReleaseReview<TData = Awaited<ReturnType<typeof getReleaseReview>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getReleaseReview>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetReleaseReview<TData = Awaited<ReturnType<typeof getReleaseReview>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getReleaseReview>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetReleaseReviewQueryOptions(id,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
@@ -0,0 +1,159 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import {
+  useQuery
+} from '@tanstack/react-query';
+import type {
+  DataTag,
+  DefinedInitialDataOptions,
+  DefinedUseQueryResult,
+  QueryClient,
+  QueryFunction,
+  QueryKey,
+  UndefinedInitialDataOptions,
+  UseQueryOptions,
+  UseQueryResult
+} from '@tanstack/react-query';
+
+import type {
+  SemanticSearchParams,
+  SemanticSearchResponse
+} from '.././model';
+
+import { customFetch } from '../../../lib/api-fetcher';
+
+
+type SecondParameter<T extends (...args: never) => unknown> = Parameters<T>[1];
+
+
+
+export type semanticSearchResponse200 = {
+  data: SemanticSearchResponse
+  status: 200
+}
+
+export type semanticSearchResponse400 = {
+  data: void
+  status: 400
+}
+
+export type semanticSearchResponse500 = {
+  data: void
+  status: 500
+}
+    
+export type semanticSearchResponseSuccess = (semanticSearchResponse200) & {
+  headers: Headers;
+};
+export type semanticSearchResponseError = (semanticSearchResponse400 | semanticSearchResponse500) & {
+  headers: Headers;
+};
+
+export type semanticSearchResponse = (semanticSearchResponseSuccess | semanticSearchResponseError)
+
+export const getSemanticSearchUrl = (params: SemanticSearchParams,) => {
+  const normalizedParams = new URLSearchParams();
+
+  Object.entries(params || {}).forEach(([key, value]) => {
+    
+    if (value !== undefined) {
+      normalizedParams.append(key, value === null ? 'null' : value.toString())
+    }
+  });
+
+  const stringifiedParams = normalizedParams.toString();
+
+  return stringifiedParams.length > 0 ? `/api/search/semantic?${stringifiedParams}` : `/api/search/semantic`
+}
+
+export const semanticSearch = async (params: SemanticSearchParams, options?: RequestInit): Promise<semanticSearchResponse> => {
+  
+  return customFetch<semanticSearchResponse>(getSemanticSearchUrl(params),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getSemanticSearchQueryKey = (params: SemanticSearchParams,) => {
+    return [
+    `/api/search/semantic`, ...(params ? [params]: [])
+    ] as const;
+    }
+
+    
+export const getSemanticSearchQueryOptions = <TData = Awaited<ReturnType<typeof semanticSearch>>, TError = void>(params: SemanticSearchParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof semanticSearch>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getSemanticSearchQueryKey(params);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof semanticSearch>>> = ({ signal }) => semanticSearch(params, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof semanticSearch>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type SemanticSearchQueryResult = NonNullable<Awaited<ReturnType<typeof semanticSearch>>>
+export type SemanticSearchQueryError = void
+
+
+export function useSemanticSearch<TData = Awaited<ReturnType<typeof semanticSearch>>, TError = void>(
+ params: SemanticSearchParams, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof semanticSearch>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof semanticSearch>>,
+          TError,
+          Awaited<ReturnType<typeof semanticSearch>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useSemanticSearch<TData = Awaited<ReturnType<typeof semanticSearch>>, TError = void>(
+ params: SemanticSearchParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof semanticSearch>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof semanticSearch>>,
+          TError,
+          Awaited<ReturnType<typeof semanticSearch>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useSemanticSearch<TData = Awaited<ReturnType<typeof semanticSearch>>, TError = void>(
+ params: SemanticSearchParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof semanticSearch>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useSemanticSearch<TData = Awaited<ReturnType<typeof semanticSearch>>, TError = void>(
+ params: SemanticSearchParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof semanticSearch>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getSemanticSearchQueryOptions(params,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
@@ -25,7 +25,14 @@ import type {
 } from '@tanstack/react-query';
 
 import type {
-  Session
+  CreateSessionShareRequest,
+  CreateSessionShareResponse,
+  ListSessionsParams,
+  RetrySessionResponse,
+  Session,
+  SessionArtifactResponse,
+  SessionShareResponse,
+  SharedSessionResponse
 } from '.././model';
 
 import { customFetch } from '../../../lib/api-fetcher';
@@ -47,17 +54,24 @@ export type listSessionsResponseSuccess = (listSessionsResponse200) & {
 
 export type listSessionsResponse = (listSessionsResponseSuccess)
 
-export const getListSessionsUrl = () => {
+export const getListSessionsUrl = (params?: ListSessionsParams,) => {
+  const normalizedParams = new URLSearchParams();
 
+  Object.entries(params || {}).forEach(([key, value]) => {
+    
+    if (value !== undefined) {
+      normalizedParams.append(key, value === null ? 'null' : value.toString())
+    }
+  });
 
-  
+  const stringifiedParams = normalizedParams.toString();
 
-  return `/api/sessions`
+  return stringifiedParams.length > 0 ? `/api/sessions?${stringifiedParams}` : `/api/sessions`
 }
 
-export const listSessions = async ( options?: RequestInit): Promise<listSessionsResponse> => {
+export const listSessions = async (params?: ListSessionsParams, options?: RequestInit): Promise<listSessionsResponse> => {
   
-  return customFetch<listSessionsResponse>(getListSessionsUrl(),
+  return customFetch<listSessionsResponse>(getListSessionsUrl(params),
   {      
     ...options,
     method: 'GET'
@@ -70,23 +84,23 @@ export const listSessions = async ( options?: RequestInit): Promise<listSessions
 
 
 
-export const getListSessionsQueryKey = () => {
+export const getListSessionsQueryKey = (params?: ListSessionsParams,) => {
     return [
-    `/api/sessions`
+    `/api/sessions`, ...(params ? [params]: [])
     ] as const;
     }
 
     
-export const getListSessionsQueryOptions = <TData = Awaited<ReturnType<typeof listSessions>>, TError = unknown>( options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listSessions>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+export const getListSessionsQueryOptions = <TData = Awaited<ReturnType<typeof listSessions>>, TError = unknown>(params?: ListSessionsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listSessions>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
 ) => {
 
 const {query: queryOptions, request: requestOptions} = options ?? {};
 
-  const queryKey =  queryOptions?.queryKey ?? getListSessionsQueryKey();
+  const queryKey =  queryOptions?.queryKey ?? getListSessionsQueryKey(params);
 
   
 
-    const queryFn: QueryFunction<Awaited<ReturnType<typeof listSessions>>> = ({ signal }) => listSessions({ signal, ...requestOptions });
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof listSessions>>> = ({ signal }) => listSessions(params, { signal, ...requestOptions });
 
       
 
@@ -100,7 +114,7 @@ export type ListSessionsQueryError = unknown
 
 
 export function useListSessions<TData = Awaited<ReturnType<typeof listSessions>>, TError = unknown>(
-  options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof listSessions>>, TError, TData>> & Pick<
+ params: undefined |  ListSessionsParams, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof listSessions>>, TError, TData>> & Pick<
         DefinedInitialDataOptions<
           Awaited<ReturnType<typeof listSessions>>,
           TError,
@@ -110,7 +124,7 @@ export function useListSessions<TData = Awaited<ReturnType<typeof listSessions>>
  , queryClient?: QueryClient
   ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
 export function useListSessions<TData = Awaited<ReturnType<typeof listSessions>>, TError = unknown>(
-  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listSessions>>, TError, TData>> & Pick<
+ params?: ListSessionsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listSessions>>, TError, TData>> & Pick<
         UndefinedInitialDataOptions<
           Awaited<ReturnType<typeof listSessions>>,
           TError,
@@ -120,16 +134,16 @@ export function useListSessions<TData = Awaited<ReturnType<typeof listSessions>>
  , queryClient?: QueryClient
   ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
 export function useListSessions<TData = Awaited<ReturnType<typeof listSessions>>, TError = unknown>(
-  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listSessions>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ params?: ListSessionsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listSessions>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient
   ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
 
 export function useListSessions<TData = Awaited<ReturnType<typeof listSessions>>, TError = unknown>(
-  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listSessions>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ params?: ListSessionsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listSessions>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient 
  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
 
-  const queryOptions = getListSessionsQueryOptions(options)
+  const queryOptions = getListSessionsQueryOptions(params,options)
 
   const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
 
@@ -450,6 +464,333 @@ export function useSessionActivityStream<TData = Awaited<ReturnType<typeof sessi
 
 
 
+export type retrySessionResponse202 = {
+  data: RetrySessionResponse
+  status: 202
+}
+
+export type retrySessionResponse404 = {
+  data: void
+  status: 404
+}
+
+export type retrySessionResponse409 = {
+  data: void
+  status: 409
+}
+
+export type retrySessionResponse500 = {
+  data: void
+  status: 500
+}
+    
+export type retrySessionResponseSuccess = (retrySessionResponse202) & {
+  headers: Headers;
+};
+export type retrySessionResponseError = (retrySessionResponse404 | retrySessionResponse409 | retrySessionResponse500) & {
+  headers: Headers;
+};
+
+export type retrySessionResponse = (retrySessionResponseSuccess | retrySessionResponseError)
+
+export const getRetrySessionUrl = (id: string,) => {
+
+
+  
+
+  return `/api/sessions/${id}/retry`
+}
+
+export const retrySession = async (id: string, options?: RequestInit): Promise<retrySessionResponse> => {
+  
+  return customFetch<retrySessionResponse>(getRetrySessionUrl(id),
+  {      
+    ...options,
+    method: 'POST'
+    
+    
+  }
+);}
+
+
+
+
+export const getRetrySessionMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof retrySession>>, TError,{id: string}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof retrySession>>, TError,{id: string}, TContext> => {
+
+const mutationKey = ['retrySession'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof retrySession>>, {id: string}> = (props) => {
+          const {id} = props ?? {};
+
+          return  retrySession(id,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type RetrySessionMutationResult = NonNullable<Awaited<ReturnType<typeof retrySession>>>
+    
+    export type RetrySessionMutationError = void
+
+    export const useRetrySession = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof retrySession>>, TError,{id: string}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof retrySession>>,
+        TError,
+        {id: string},
+        TContext
+      > => {
+
+      const mutationOptions = getRetrySessionMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type listSessionArtifactsResponse200 = {
+  data: SessionArtifactResponse[]
+  status: 200
+}
+
+export type listSessionArtifactsResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type listSessionArtifactsResponseSuccess = (listSessionArtifactsResponse200) & {
+  headers: Headers;
+};
+export type listSessionArtifactsResponseError = (listSessionArtifactsResponse404) & {
+  headers: Headers;
+};
+
+export type listSessionArtifactsResponse = (listSessionArtifactsResponseSuccess | listSessionArtifactsResponseError)
+
+export const getListSessionArtifactsUrl = (id: string,) => {
+
+
+  
+
+  return `/api/sessions/${id}/artifacts`
+}
+
+export const listSessionArtifacts = async (id: string, options?: RequestInit): Promise<listSessionArtifactsResponse> => {
+  
+  return customFetch<listSessionArtifactsResponse>(getListSessionArtifactsUrl(id),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getListSessionArtifactsQueryKey = (id?: string,) => {
+    return [
+    `/api/sessions/${id}/artifacts`
+    ] as const;
+    }
+
+    
+export const getListSessionArtifactsQueryOptions = <TData = Awaited<ReturnType<typeof listSessionArtifacts>>, TError = void>(id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listSessionArtifacts>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getListSessionArtifactsQueryKey(id);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof listSessionArtifacts>>> = ({ signal }) => listSessionArtifacts(id, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, enabled: !!(id), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof listSessionArtifacts>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type ListSessionArtifactsQueryResult = NonNullable<Awaited<ReturnType<typeof listSessionArtifacts>>>
+export type ListSessionArtifactsQueryError = void
+
+
+export function useListSessionArtifacts<TData = Awaited<ReturnType<typeof listSessionArtifacts>>, TError = void>(
+ id: string, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof listSessionArtifacts>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof listSessionArtifacts>>,
+          TError,
+          Awaited<ReturnType<typeof listSessionArtifacts>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useListSessionArtifacts<TData = Awaited<ReturnType<typeof listSessionArtifacts>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listSessionArtifacts>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof listSessionArtifacts>>,
+          TError,
+          Awaited<ReturnType<typeof listSessionArtifacts>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useListSessionArtifacts<TData = Awaited<ReturnType<typeof listSessionArtifacts>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listSessionArtifacts>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useListSessionArtifacts<TData = Awaited<ReturnType<typeof listSessionArtifacts>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listSessionArtifacts>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getListSessionArtifactsQueryOptions(id,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type downloadSessionArtifactResponse200 = {
+  data: Blob
+  status: 200
+}
+
+export type downloadSessionArtifactResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type downloadSessionArtifactResponseSuccess = (downloadSessionArtifactResponse200) & {
+  headers: Headers;
+};
+export type downloadSessionArtifactResponseError = (downloadSessionArtifactResponse404) & {
+  headers: Headers;
+};
+
+export type downloadSessionArtifactResponse = (downloadSessionArtifactResponseSuccess | downloadSessionArtifactResponseError)
+
+export const getDownloadSessionArtifactUrl = (id: string,
+    artifactId: string,) => {
+
+
+  
+
+  return `/api/sessions/${id}/artifacts/${artifactId}`
+}
+
+export const downloadSessionArtifact = async (id: string,
+    artifactId: string, options?: RequestInit): Promise<downloadSessionArtifactResponse> => {
+  
+  return customFetch<downloadSessionArtifactResponse>(getDownloadSessionArtifactUrl(id,artifactId),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getDownloadSessionArtifactQueryKey = (id?: string,
+    artifactId?: string,) => {
+    return [
+    `/api/sessions/${id}/artifacts/${artifactId}`
+    ] as const;
+    }
+
+    
+export const getDownloadSessionArtifactQueryOptions = <TData = Awaited<ReturnType<typeof downloadSessionArtifact>>, TError = void>(id: string,
+    artifactId: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof downloadSessionArtifact>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getDownloadSessionArtifactQueryKey(id,artifactId);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof downloadSessionArtifact>>> = ({ signal }) => downloadSessionArtifact(id,artifactId, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, enabled: !!(id && artifactId), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof downloadSessionArtifact>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type DownloadSessionArtifactQueryResult = NonNullable<Awaited<ReturnType<typeof downloadSessionArtifact>>>
+export type DownloadSessionArtifactQueryError = void
+
+
+export function useDownloadSessionArtifact<TData = Awaited<ReturnType<typeof downloadSessionArtifact>>, TError = void>(
+ id: string,
+    artifactId: string, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof downloadSessionArtifact>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof downloadSessionArtifact>>,
+          TError,
+          Awaited<ReturnType<typeof downloadSessionArtifact>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useDownloadSessionArtifact<TData = Awaited<ReturnType<typeof downloadSessionArtifact>>, TError = void>(
+ id: string,
+    artifactId: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof downloadSessionArtifact>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof downloadSessionArtifact>>,
+          TError,
+          Awaited<ReturnType<typeof downloadSessionArtifact>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useDownloadSessionArtifact<TData = Awaited<ReturnType<typeof downloadSessionArtifact>>, TError = void>(
+ id: string,
+    artifactId: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof downloadSessionArtifact>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useDownloadSessionArtifact<TData = Awaited<ReturnType<typeof downloadSessionArtifact>>, TError = void>(
+ id: string,
+    artifactId: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof downloadSessionArtifact>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getDownloadSessionArtifactQueryOptions(id,artifactId,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
 export type listSessionsForTaskResponse200 = {
   data: Session[]
   status: 200
@@ -556,3 +897,403 @@ export function useListSessionsForTask<TData = Awaited<ReturnType<typeof listSes
 
 
 
+export type createSessionShareResponse201 = {
+  data: CreateSessionShareResponse
+  status: 201
+}
+
+export type createSessionShareResponse400 = {
+  data: void
+  status: 400
+}
+
+export type createSessionShareResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type createSessionShareResponseSuccess = (createSessionShareResponse201) & {
+  headers: Headers;
+};
+export type createSessionShareResponseError = (createSessionShareResponse400 | createSessionShareResponse404) & {
+  headers: Headers;
+};
+
+export type createSessionShareResponse = (createSessionShareResponseSuccess | createSessionShareResponseError)
+
+export const getCreateSessionShareUrl = (id: string,) => {
+
+
+  
+
+  return `/api/sessions/${id}/share`
+}
+
+export const createSessionShare = async (id: string,
+    createSessionShareRequest: CreateSessionShareRequest, options?: RequestInit): Promise<createSessionShareResponse> => {
+  
+  return customFetch<createSessionShareResponse>(getCreateSessionShareUrl(id),
+  {      
+    ...options,
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      createSessionShareRequest,)
+  }
+);}
+
+
+
+
+export const getCreateSessionShareMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof createSessionShare>>, TError,{id: string;data: CreateSessionShareRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof createSessionShare>>, TError,{id: string;data: CreateSessionShareRequest}, TContext> => {
+
+const mutationKey = ['createSessionShare'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof createSessionShare>>, {id: string;data: CreateSessionShareRequest}> = (props) => {
+          const {id,data} = props ?? {};
+
+          return  createSessionShare(id,data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type CreateSessionShareMutationResult = NonNullable<Awaited<ReturnType<typeof createSessionShare>>>
+    export type CreateSessionShareMutationBody = CreateSessionShareRequest
+    export type CreateSessionShareMutationError = void
+
+    export const useCreateSessionShare = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof createSessionShare>>, TError,{id: string;data: CreateSessionShareRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof createSessionShare>>,
+        TError,
+        {id: string;data: CreateSessionShareRequest},
+        TContext
+      > => {
+
+      const mutationOptions = getCreateSessionShareMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type listSessionSharesResponse200 = {
+  data: SessionShareResponse[]
+  status: 200
+}
+
+export type listSessionSharesResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type listSessionSharesResponseSuccess = (listSessionSharesResponse200) & {
+  headers: Headers;
+};
+export type listSessionSharesResponseError = (listSessionSharesResponse404) & {
+  headers: Headers;
+};
+
+export type listSessionSharesResponse = (listSessionSharesResponseSuccess | listSessionSharesResponseError)
+
+export const getListSessionSharesUrl = (id: string,) => {
+
+
+  
+
+  return `/api/sessions/${id}/shares`
+}
+
+export const listSessionShares = async (id: string, options?: RequestInit): Promise<listSessionSharesResponse> => {
+  
+  return customFetch<listSessionSharesResponse>(getListSessionSharesUrl(id),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getListSessionSharesQueryKey = (id?: string,) => {
+    return [
+    `/api/sessions/${id}/shares`
+    ] as const;
+    }
+
+    
+export const getListSessionSharesQueryOptions = <TData = Awaited<ReturnType<typeof listSessionShares>>, TError = void>(id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listSessionShares>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getListSessionSharesQueryKey(id);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof listSessionShares>>> = ({ signal }) => listSessionShares(id, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, enabled: !!(id), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof listSessionShares>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type ListSessionSharesQueryResult = NonNullable<Awaited<ReturnType<typeof listSessionShares>>>
+export type ListSessionSharesQueryError = void
+
+
+export function useListSessionShares<TData = Awaited<ReturnType<typeof listSessionShares>>, TError = void>(
+ id: string, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof listSessionShares>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof listSessionShares>>,
+          TError,
+          Awaited<ReturnType<typeof listSessionShares>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useListSessionShares<TData = Awaited<ReturnType<typeof listSessionShares>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listSessionShares>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof listSessionShares>>,
+          TError,
+          Awaited<ReturnType<typeof listSessionShares>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useListSessionShares<TData = Awaited<ReturnType<typeof listSessionShares>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listSessionShares>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useListSessionShares<TData = Awaited<ReturnType<typeof listSessionShares>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listSessionShares>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getListSessionSharesQueryOptions(id,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type revokeSessionShareResponse204 = {
+  data: void
+  status: 204
+}
+
+export type revokeSessionShareResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type revokeSessionShareResponseSuccess = (revokeSessionShareResponse204) & {
+  headers: Headers;
+};
+export type revokeSessionShareResponseError = (revokeSessionShareResponse404) & {
+  headers: Headers;
+};
+
+export type revokeSessionShareResponse = (revokeSessionShareResponseSuccess | revokeSessionShareResponseError)
+
+export const getRevokeSessionShareUrl = (id: string,shareId: string,) => {
+
+
+  
+
+  return `/api/sessions/${id}/shares/${shareId}`
+}
+
+export const revokeSessionShare = async (id: string,
+    shareId: string, options?: RequestInit): Promise<revokeSessionShareResponse> => {
+  
+  return customFetch<revokeSessionShareResponse>(getRevokeSessionShareUrl(id,shareId),
+  {      
+    ...options,
+    method: 'DELETE'
+    
+    
+  }
+);}
+
+
+
+
+export const getRevokeSessionShareMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof revokeSessionShare>>, TError,{id: string;shareId: string}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof revokeSessionShare>>, TError,{id: string;shareId: string}, TContext> => {
+
+const mutationKey = ['revokeSessionShare'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof revokeSessionShare>>, {id: string;shareId: string}> = (props) => {
+          const {id,shareId} = props ?? {};
+
+          return  revokeSessionShare(id,shareId,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type RevokeSessionShareMutationResult = NonNullable<Awaited<ReturnType<typeof revokeSessionShare>>>
+    
+    export type RevokeSessionShareMutationError = void
+
+    export const useRevokeSessionShare = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof revokeSessionShare>>, TError,{id: string;shareId: string}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof revokeSessionShare>>,
+        TError,
+        {id: string;shareId: string},
+        TContext
+      > => {
+
+      const mutationOptions = getRevokeSessionShareMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type getSharedSessionResponse200 = {
+  data: SharedSessionResponse
+  status: 200
+}
+
+export type getSharedSessionResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type getSharedSessionResponseSuccess = (getSharedSessionResponse200) & {
+  headers: Headers;
+};
+export type getSharedSessionResponseError = (getSharedSessionResponse404) & {
+  headers: Headers;
+};
+
+export type getSharedSessionResponse = (getSharedSessionResponseSuccess | getSharedSessionResponseError)
+
+export const getGetSharedSessionUrl = (token: string,) => {
+
+
+  
+
+  return `/api/shared/${token}`
+}
+
+export const getSharedSession = async (token: string, options?: RequestInit): Promise<getSharedSessionResponse> => {
+  
+  return customFetch<getSharedSessionResponse>(getGetSharedSessionUrl(token),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetSharedSessionQueryKey = (token?: string,) => {
+    return [
+    `/api/shared/${token}`
+    ] as const;
+    }
+
+    
+export const getGetSharedSessionQueryOptions = <TData = Awaited<ReturnType<typeof getSharedSession>>, TError = void>(token: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getSharedSession>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetSharedSessionQueryKey(token);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getSharedSession>>> = ({ signal }) => getSharedSession(token, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, enabled: !!(token), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getSharedSession>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetSharedSessionQueryResult = NonNullable<Awaited<ReturnType<typeof getSharedSession>>>
+export type GetSharedSessionQueryError = void
+
+
+export function useGetSharedSession<TData = Awaited<ReturnType<typeof getSharedSession>>, TError = void>(
+ token: string, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getSharedSession>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getSharedSession>>,
+          TError,
+          Awaited<ReturnType<typeof getSharedSession>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetSharedSession<TData = Awaited<ReturnType<typeof getSharedSession>>, TError = void>(
+ token: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getSharedSession>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getSharedSession>>,
+          TError,
+          Awaited<ReturnType<typeof getSharedSession>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetSharedSession<TData = Awaited<ReturnType<typeof getSharedSession>>, TError = void>(
+ token: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getSharedSession>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetSharedSession<TData = Awaited<ReturnType<typeof getSharedSession>>, TError = void>(
+ token: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getSharedSession>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetSharedSessionQueryOptions(token,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
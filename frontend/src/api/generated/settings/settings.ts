@@ -27,6 +27,8 @@ import type {
 import type {
   GitHubSettingsResponse,
   PhaseModelsResponse,
+  ReviewPolicy,
+  ReviewPolicyResponse,
   RoadmapSettingsResponse,
   UpdateGitHubTokenRequest,
   UpdatePhaseModelsRequest,
@@ -1070,4 +1072,201 @@ const {mutation: mutationOptions, request: requestOptions} = options ?
 
       return useMutation(mutationOptions, queryClient);
     }
+    export type getReviewPolicyResponse200 = {
+  data: ReviewPolicyResponse
+  status: 200
+}
+
+export type getReviewPolicyResponse500 = {
+  data: void
+  status: 500
+}
+    
+export type getReviewPolicyResponseSuccess = (getReviewPolicyResponse200) & {
+  headers: Headers;
+};
+export type getReviewPolicyResponseError = (getReviewPolicyResponse500) & {
+  headers: Headers;
+};
+
+export type getReviewPolicyResponse = (getReviewPolicyResponseSuccess | getReviewPolicyResponseError)
+
+export const getGetReviewPolicyUrl = () => {
+
+
+  
+
+  return `/api/settings/review-policy`
+}
+
+export const getReviewPolicy = async (options?: RequestInit): Promise<getReviewPolicyResponse> => {
+  
+  return customFetch<getReviewPolicyResponse>(getGetReviewPolicyUrl(),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetReviewPolicyQueryKey = () => {
+    return [
+    `/api/settings/review-policy`
+    ] as const;
+    }
+
+    
+export const getGetReviewPolicyQueryOptions = <TData = Awaited<ReturnType<typeof getReviewPolicy>>, TError = void>(options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getReviewPolicy>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetReviewPolicyQueryKey();
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getReviewPolicy>>> = ({ signal }) => getReviewPolicy({ signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getReviewPolicy>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetReviewPolicyQueryResult = NonNullable<Awaited<ReturnType<typeof getReviewPolicy>>>
+export type GetReviewPolicyQueryError = void
+
+
+export function useGetReviewPolicy<TData = Awaited<ReturnType<typeof getReviewPolicy>>, TError = void>(
+ options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getReviewPolicy>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getReviewPolicy>>,
+          TError,
+          Awaited<ReturnType<typeof getReviewPolicy>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetReviewPolicy<TData = Awaited<ReturnType<typeof getReviewPolicy>>, TError = void>(
+ options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getReviewPolicy>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getReviewPolicy>>,
+          TError,
+          Awaited<ReturnType<typeof getReviewPolicy>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetReviewPolicy<TData = Awaited<ReturnType<typeof getReviewPolicy>>, TError = void>(
+ options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getReviewPolicy>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetReviewPolicy<TData = Awaited<ReturnType<typeof getReviewPolicy>>, TError = void>(
+ options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getReviewPolicy>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetReviewPolicyQueryOptions(options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type updateReviewPolicyResponse200 = {
+  data: ReviewPolicyResponse
+  status: 200
+}
+
+export type updateReviewPolicyResponse500 = {
+  data: void
+  status: 500
+}
+    
+export type updateReviewPolicyResponseSuccess = (updateReviewPolicyResponse200) & {
+  headers: Headers;
+};
+export type updateReviewPolicyResponseError = (updateReviewPolicyResponse500) & {
+  headers: Headers;
+};
+
+export type updateReviewPolicyResponse = (updateReviewPolicyResponseSuccess | updateReviewPolicyResponseError)
+
+export const getUpdateReviewPolicyUrl = () => {
+
+
+  
+
+  return `/api/settings/review-policy`
+}
+
+export const updateReviewPolicy = async (reviewPolicy: ReviewPolicy, options?: RequestInit): Promise<updateReviewPolicyResponse> => {
+  
+  return customFetch<updateReviewPolicyResponse>(getUpdateReviewPolicyUrl(),
+  {      
+    ...options,
+    method: 'put',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      reviewPolicy,)
+  }
+);}
+
+
+
+
+export const getUpdateReviewPolicyMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof updateReviewPolicy>>, TError,{data: ReviewPolicy}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof updateReviewPolicy>>, TError,{data: ReviewPolicy}, TContext> => {
+
+const mutationKey = ['updateReviewPolicy'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof updateReviewPolicy>>, {data: ReviewPolicy}> = (props) => {
+          const {data} = props ?? {};
+
+          return  updateReviewPolicy(data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type UpdateReviewPolicyMutationResult = NonNullable<Awaited<ReturnType<typeof updateReviewPolicy>>>
+    export type UpdateReviewPolicyMutationBody = ReviewPolicy
+    export type UpdateReviewPolicyMutationError = void
+
+    export const useUpdateReviewPolicy = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof updateReviewPolicy>>, TError,{data: ReviewPolicy}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof updateReviewPolicy>>,
+        TError,
+        {data: ReviewPolicy},
+        TContext
+      > => {
+
+      const mutationOptions = getUpdateReviewPolicyMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
     
\ No newline at end of file
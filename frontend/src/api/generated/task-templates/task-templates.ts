@@ -0,0 +1,607 @@
+/**
+ * Generated by orval v7.17.2 🍺
+ * Do not edit manually.
+ * OpenCode Studio API
+ * API for OpenCode Studio - AI-powered development platform
+ * OpenAPI spec version: 0.1.0
+ */
+import {
+  useMutation,
+  useQuery
+} from '@tanstack/react-query';
+import type {
+  DataTag,
+  DefinedInitialDataOptions,
+  DefinedUseQueryResult,
+  MutationFunction,
+  QueryClient,
+  QueryFunction,
+  QueryKey,
+  UndefinedInitialDataOptions,
+  UseMutationOptions,
+  UseMutationResult,
+  UseQueryOptions,
+  UseQueryResult
+} from '@tanstack/react-query';
+
+import type {
+  CreateTaskFromTemplateRequest,
+  Task,
+  TaskTemplateRequest,
+  TaskTemplateResponse
+} from '.././model';
+
+import { customFetch } from '../../../lib/api-fetcher';
+
+
+type SecondParameter<T extends (...args: never) => unknown> = Parameters<T>[1];
+
+
+
+export type listTaskTemplatesResponse200 = {
+  data: TaskTemplateResponse[]
+  status: 200
+}
+    
+export type listTaskTemplatesResponseSuccess = (listTaskTemplatesResponse200) & {
+  headers: Headers;
+};
+;
+
+export type listTaskTemplatesResponse = (listTaskTemplatesResponseSuccess)
+
+export const getListTaskTemplatesUrl = () => {
+
+
+  
+
+  return `/api/task-templates`
+}
+
+export const listTaskTemplates = async (options?: RequestInit): Promise<listTaskTemplatesResponse> => {
+  
+  return customFetch<listTaskTemplatesResponse>(getListTaskTemplatesUrl(),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getListTaskTemplatesQueryKey = () => {
+    return [
+    `/api/task-templates`
+    ] as const;
+    }
+
+    
+export const getListTaskTemplatesQueryOptions = <TData = Awaited<ReturnType<typeof listTaskTemplates>>, TError = unknown>(options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTaskTemplates>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getListTaskTemplatesQueryKey();
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof listTaskTemplates>>> = ({ signal }) => listTaskTemplates({ signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof listTaskTemplates>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type ListTaskTemplatesQueryResult = NonNullable<Awaited<ReturnType<typeof listTaskTemplates>>>
+export type ListTaskTemplatesQueryError = unknown
+
+
+export function useListTaskTemplates<TData = Awaited<ReturnType<typeof listTaskTemplates>>, TError = unknown>(
+ options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTaskTemplates>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof listTaskTemplates>>,
+          TError,
+          Awaited<ReturnType<typeof listTaskTemplates>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useListTaskTemplates<TData = Awaited<ReturnType<typeof listTaskTemplates>>, TError = unknown>(
+ options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTaskTemplates>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof listTaskTemplates>>,
+          TError,
+          Awaited<ReturnType<typeof listTaskTemplates>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useListTaskTemplates<TData = Awaited<ReturnType<typeof listTaskTemplates>>, TError = unknown>(
+ options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTaskTemplates>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useListTaskTemplates<TData = Awaited<ReturnType<typeof listTaskTemplates>>, TError = unknown>(
+ options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTaskTemplates>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getListTaskTemplatesQueryOptions(options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type createTaskTemplateResponse201 = {
+  data: TaskTemplateResponse
+  status: 201
+}
+
+export type createTaskTemplateResponse400 = {
+  data: void
+  status: 400
+}
+    
+export type createTaskTemplateResponseSuccess = (createTaskTemplateResponse201) & {
+  headers: Headers;
+};
+export type createTaskTemplateResponseError = (createTaskTemplateResponse400) & {
+  headers: Headers;
+};
+
+export type createTaskTemplateResponse = (createTaskTemplateResponseSuccess | createTaskTemplateResponseError)
+
+export const getCreateTaskTemplateUrl = () => {
+
+
+  
+
+  return `/api/task-templates`
+}
+
+export const createTaskTemplate = async (taskTemplateRequest: TaskTemplateRequest, options?: RequestInit): Promise<createTaskTemplateResponse> => {
+  
+  return customFetch<createTaskTemplateResponse>(getCreateTaskTemplateUrl(),
+  {      
+    ...options,
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      taskTemplateRequest,)
+  }
+);}
+
+
+
+
+export const getCreateTaskTemplateMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof createTaskTemplate>>, TError,{data: TaskTemplateRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof createTaskTemplate>>, TError,{data: TaskTemplateRequest}, TContext> => {
+
+const mutationKey = ['createTaskTemplate'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof createTaskTemplate>>, {data: TaskTemplateRequest}> = (props) => {
+          const {data} = props ?? {};
+
+          return  createTaskTemplate(data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type CreateTaskTemplateMutationResult = NonNullable<Awaited<ReturnType<typeof createTaskTemplate>>>
+    export type CreateTaskTemplateMutationBody = TaskTemplateRequest
+    export type CreateTaskTemplateMutationError = void
+
+    export const useCreateTaskTemplate = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof createTaskTemplate>>, TError,{data: TaskTemplateRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof createTaskTemplate>>,
+        TError,
+        {data: TaskTemplateRequest},
+        TContext
+      > => {
+
+      const mutationOptions = getCreateTaskTemplateMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type getTaskTemplateResponse200 = {
+  data: TaskTemplateResponse
+  status: 200
+}
+
+export type getTaskTemplateResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type getTaskTemplateResponseSuccess = (getTaskTemplateResponse200) & {
+  headers: Headers;
+};
+export type getTaskTemplateResponseError = (getTaskTemplateResponse404) & {
+  headers: Headers;
+};
+
+export type getTaskTemplateResponse = (getTaskTemplateResponseSuccess | getTaskTemplateResponseError)
+
+export const getGetTaskTemplateUrl = (id: string,) => {
+
+
+  
+
+  return `/api/task-templates/${id}`
+}
+
+export const getTaskTemplate = async (id: string, options?: RequestInit): Promise<getTaskTemplateResponse> => {
+  
+  return customFetch<getTaskTemplateResponse>(getGetTaskTemplateUrl(id),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetTaskTemplateQueryKey = (id?: string,) => {
+    return [
+    `/api/task-templates/${id}`
+    ] as const;
+    }
+
+    
+export const getGetTaskTemplateQueryOptions = <TData = Awaited<ReturnType<typeof getTaskTemplate>>, TError = void>(id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskTemplate>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetTaskTemplateQueryKey(id);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getTaskTemplate>>> = ({ signal }) => getTaskTemplate(id, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, enabled: !!(id), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getTaskTemplate>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetTaskTemplateQueryResult = NonNullable<Awaited<ReturnType<typeof getTaskTemplate>>>
+export type GetTaskTemplateQueryError = void
+
+
+export function useGetTaskTemplate<TData = Awaited<ReturnType<typeof getTaskTemplate>>, TError = void>(
+ id: string, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskTemplate>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getTaskTemplate>>,
+          TError,
+          Awaited<ReturnType<typeof getTaskTemplate>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetTaskTemplate<TData = Awaited<ReturnType<typeof getTaskTemplate>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskTemplate>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getTaskTemplate>>,
+          TError,
+          Awaited<ReturnType<typeof getTaskTemplate>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetTaskTemplate<TData = Awaited<ReturnType<typeof getTaskTemplate>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskTemplate>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetTaskTemplate<TData = Awaited<ReturnType<typeof getTaskTemplate>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskTemplate>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetTaskTemplateQueryOptions(id,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type updateTaskTemplateResponse200 = {
+  data: TaskTemplateResponse
+  status: 200
+}
+
+export type updateTaskTemplateResponse400 = {
+  data: void
+  status: 400
+}
+
+export type updateTaskTemplateResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type updateTaskTemplateResponseSuccess = (updateTaskTemplateResponse200) & {
+  headers: Headers;
+};
+export type updateTaskTemplateResponseError = (updateTaskTemplateResponse400 | updateTaskTemplateResponse404) & {
+  headers: Headers;
+};
+
+export type updateTaskTemplateResponse = (updateTaskTemplateResponseSuccess | updateTaskTemplateResponseError)
+
+export const getUpdateTaskTemplateUrl = (id: string,) => {
+
+
+  
+
+  return `/api/task-templates/${id}`
+}
+
+export const updateTaskTemplate = async (id: string,
+    taskTemplateRequest: TaskTemplateRequest, options?: RequestInit): Promise<updateTaskTemplateResponse> => {
+  
+  return customFetch<updateTaskTemplateResponse>(getUpdateTaskTemplateUrl(id),
+  {      
+    ...options,
+    method: 'PUT',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      taskTemplateRequest,)
+  }
+);}
+
+
+
+
+export const getUpdateTaskTemplateMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof updateTaskTemplate>>, TError,{id: string;data: TaskTemplateRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof updateTaskTemplate>>, TError,{id: string;data: TaskTemplateRequest}, TContext> => {
+
+const mutationKey = ['updateTaskTemplate'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof updateTaskTemplate>>, {id: string;data: TaskTemplateRequest}> = (props) => {
+          const {id,data} = props ?? {};
+
+          return  updateTaskTemplate(id,data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type UpdateTaskTemplateMutationResult = NonNullable<Awaited<ReturnType<typeof updateTaskTemplate>>>
+    export type UpdateTaskTemplateMutationBody = TaskTemplateRequest
+    export type UpdateTaskTemplateMutationError = void
+
+    export const useUpdateTaskTemplate = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof updateTaskTemplate>>, TError,{id: string;data: TaskTemplateRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof updateTaskTemplate>>,
+        TError,
+        {id: string;data: TaskTemplateRequest},
+        TContext
+      > => {
+
+      const mutationOptions = getUpdateTaskTemplateMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type deleteTaskTemplateResponse204 = {
+  data: void
+  status: 204
+}
+
+export type deleteTaskTemplateResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type deleteTaskTemplateResponseSuccess = (deleteTaskTemplateResponse204) & {
+  headers: Headers;
+};
+export type deleteTaskTemplateResponseError = (deleteTaskTemplateResponse404) & {
+  headers: Headers;
+};
+
+export type deleteTaskTemplateResponse = (deleteTaskTemplateResponseSuccess | deleteTaskTemplateResponseError)
+
+export const getDeleteTaskTemplateUrl = (id: string,) => {
+
+
+  
+
+  return `/api/task-templates/${id}`
+}
+
+export const deleteTaskTemplate = async (id: string, options?: RequestInit): Promise<deleteTaskTemplateResponse> => {
+  
+  return customFetch<deleteTaskTemplateResponse>(getDeleteTaskTemplateUrl(id),
+  {      
+    ...options,
+    method: 'DELETE'
+    
+    
+  }
+);}
+
+
+
+
+export const getDeleteTaskTemplateMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof deleteTaskTemplate>>, TError,{id: string}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof deleteTaskTemplate>>, TError,{id: string}, TContext> => {
+
+const mutationKey = ['deleteTaskTemplate'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof deleteTaskTemplate>>, {id: string}> = (props) => {
+          const {id} = props ?? {};
+
+          return  deleteTaskTemplate(id,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type DeleteTaskTemplateMutationResult = NonNullable<Awaited<ReturnType<typeof deleteTaskTemplate>>>
+    
+    export type DeleteTaskTemplateMutationError = void
+
+    export const useDeleteTaskTemplate = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof deleteTaskTemplate>>, TError,{id: string}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof deleteTaskTemplate>>,
+        TError,
+        {id: string},
+        TContext
+      > => {
+
+      const mutationOptions = getDeleteTaskTemplateMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type createTaskFromTemplateResponse201 = {
+  data: Task
+  status: 201
+}
+
+export type createTaskFromTemplateResponse400 = {
+  data: void
+  status: 400
+}
+
+export type createTaskFromTemplateResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type createTaskFromTemplateResponseSuccess = (createTaskFromTemplateResponse201) & {
+  headers: Headers;
+};
+export type createTaskFromTemplateResponseError = (createTaskFromTemplateResponse400 | createTaskFromTemplateResponse404) & {
+  headers: Headers;
+};
+
+export type createTaskFromTemplateResponse = (createTaskFromTemplateResponseSuccess | createTaskFromTemplateResponseError)
+
+export const getCreateTaskFromTemplateUrl = (id: string,) => {
+
+
+  
+
+  return `/api/task-templates/${id}/tasks`
+}
+
+export const createTaskFromTemplate = async (id: string,
+    createTaskFromTemplateRequest: CreateTaskFromTemplateRequest, options?: RequestInit): Promise<createTaskFromTemplateResponse> => {
+  
+  return customFetch<createTaskFromTemplateResponse>(getCreateTaskFromTemplateUrl(id),
+  {      
+    ...options,
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      createTaskFromTemplateRequest,)
+  }
+);}
+
+
+
+
+export const getCreateTaskFromTemplateMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof createTaskFromTemplate>>, TError,{id: string;data: CreateTaskFromTemplateRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof createTaskFromTemplate>>, TError,{id: string;data: CreateTaskFromTemplateRequest}, TContext> => {
+
+const mutationKey = ['createTaskFromTemplate'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof createTaskFromTemplate>>, {id: string;data: CreateTaskFromTemplateRequest}> = (props) => {
+          const {id,data} = props ?? {};
+
+          return  createTaskFromTemplate(id,data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type CreateTaskFromTemplateMutationResult = NonNullable<Awaited<ReturnType<typeof createTaskFromTemplate>>>
+    export type CreateTaskFromTemplateMutationBody = CreateTaskFromTemplateRequest
+    export type CreateTaskFromTemplateMutationError = void
+
+    export const useCreateTaskFromTemplate = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof createTaskFromTemplate>>, TError,{id: string;data: CreateTaskFromTemplateRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof createTaskFromTemplate>>,
+        TError,
+        {id: string;data: CreateTaskFromTemplateRequest},
+        TContext
+      > => {
+
+      const mutationOptions = getCreateTaskFromTemplateMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    
\ No newline at end of file
@@ -25,15 +25,33 @@ import type {
 } from '@tanstack/react-query';
 
 import type {
+  AddFindingCommentRequest,
+  AddTaskDependencyRequest,
+  BulkUpdateFindingsRequest,
+  BulkUpdateFindingsResponse,
+  CompareTaskExecutionsParams,
   CreateTaskRequest,
   ExecuteResponse,
+  ExecutionComparison,
+  FindingComment,
   FindingsResponse,
   FixFindingsRequest,
+  GetTaskFindingsSarif200,
+  GetTaskReviewReportParams,
+  ListTasksParams,
   PhasesResponse,
+  PipelineResponse,
   PlanResponse,
+  StateMachineResponse,
   Task,
+  TaskDependenciesResponse,
+  TaskEnvResponse,
+  TaskExecutionsResponse,
+  TaskTransitionsResponse,
   TransitionRequest,
   TransitionResponse,
+  TranslateFindingsParams,
+  UpdateTaskEnvRequest,
   UpdateTaskRequest
 } from '.././model';
 
@@ -56,17 +74,24 @@ export type listTasksResponseSuccess = (listTasksResponse200) & {
 
 export type listTasksResponse = (listTasksResponseSuccess)
 
-export const getListTasksUrl = () => {
+export const getListTasksUrl = (params?: ListTasksParams,) => {
+  const normalizedParams = new URLSearchParams();
 
+  Object.entries(params || {}).forEach(([key, value]) => {
+    
+    if (value !== undefined) {
+      normalizedParams.append(key, value === null ? 'null' : value.toString())
+    }
+  });
 
-  
+  const stringifiedParams = normalizedParams.toString();
 
-  return `/api/tasks`
+  return stringifiedParams.length > 0 ? `/api/tasks?${stringifiedParams}` : `/api/tasks`
 }
 
-export const listTasks = async ( options?: RequestInit): Promise<listTasksResponse> => {
+export const listTasks = async (params?: ListTasksParams, options?: RequestInit): Promise<listTasksResponse> => {
   
-  return customFetch<listTasksResponse>(getListTasksUrl(),
+  return customFetch<listTasksResponse>(getListTasksUrl(params),
   {      
     ...options,
     method: 'GET'
@@ -79,23 +104,23 @@ export const listTasks = async ( options?: RequestInit): Promise<listTasksRespon
 
 
 
-export const getListTasksQueryKey = () => {
+export const getListTasksQueryKey = (params?: ListTasksParams,) => {
     return [
-    `/api/tasks`
+    `/api/tasks`, ...(params ? [params]: [])
     ] as const;
     }
 
     
-export const getListTasksQueryOptions = <TData = Awaited<ReturnType<typeof listTasks>>, TError = unknown>( options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTasks>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+export const getListTasksQueryOptions = <TData = Awaited<ReturnType<typeof listTasks>>, TError = unknown>(params?: ListTasksParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTasks>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
 ) => {
 
 const {query: queryOptions, request: requestOptions} = options ?? {};
 
-  const queryKey =  queryOptions?.queryKey ?? getListTasksQueryKey();
+  const queryKey =  queryOptions?.queryKey ?? getListTasksQueryKey(params);
 
   
 
-    const queryFn: QueryFunction<Awaited<ReturnType<typeof listTasks>>> = ({ signal }) => listTasks({ signal, ...requestOptions });
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof listTasks>>> = ({ signal }) => listTasks(params, { signal, ...requestOptions });
 
       
 
@@ -109,7 +134,7 @@ export type ListTasksQueryError = unknown
 
 
 export function useListTasks<TData = Awaited<ReturnType<typeof listTasks>>, TError = unknown>(
-  options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTasks>>, TError, TData>> & Pick<
+ params: undefined |  ListTasksParams, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTasks>>, TError, TData>> & Pick<
         DefinedInitialDataOptions<
           Awaited<ReturnType<typeof listTasks>>,
           TError,
@@ -119,7 +144,7 @@ export function useListTasks<TData = Awaited<ReturnType<typeof listTasks>>, TErr
  , queryClient?: QueryClient
   ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
 export function useListTasks<TData = Awaited<ReturnType<typeof listTasks>>, TError = unknown>(
-  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTasks>>, TError, TData>> & Pick<
+ params?: ListTasksParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTasks>>, TError, TData>> & Pick<
         UndefinedInitialDataOptions<
           Awaited<ReturnType<typeof listTasks>>,
           TError,
@@ -129,16 +154,16 @@ export function useListTasks<TData = Awaited<ReturnType<typeof listTasks>>, TErr
  , queryClient?: QueryClient
   ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
 export function useListTasks<TData = Awaited<ReturnType<typeof listTasks>>, TError = unknown>(
-  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTasks>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ params?: ListTasksParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTasks>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient
   ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
 
 export function useListTasks<TData = Awaited<ReturnType<typeof listTasks>>, TError = unknown>(
-  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTasks>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ params?: ListTasksParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTasks>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient 
  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
 
-  const queryOptions = getListTasksQueryOptions(options)
+  const queryOptions = getListTasksQueryOptions(params,options)
 
   const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
 
@@ -515,6 +540,209 @@ const {mutation: mutationOptions, request: requestOptions} = options ?
 
       return useMutation(mutationOptions, queryClient);
     }
+    export type getTaskEnvResponse200 = {
+  data: TaskEnvResponse
+  status: 200
+}
+
+export type getTaskEnvResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type getTaskEnvResponseSuccess = (getTaskEnvResponse200) & {
+  headers: Headers;
+};
+export type getTaskEnvResponseError = (getTaskEnvResponse404) & {
+  headers: Headers;
+};
+
+export type getTaskEnvResponse = (getTaskEnvResponseSuccess | getTaskEnvResponseError)
+
+export const getGetTaskEnvUrl = (id: string,) => {
+
+
+  
+
+  return `/api/tasks/${id}/env`
+}
+
+export const getTaskEnv = async (id: string, options?: RequestInit): Promise<getTaskEnvResponse> => {
+  
+  return customFetch<getTaskEnvResponse>(getGetTaskEnvUrl(id),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetTaskEnvQueryKey = (id?: string,) => {
+    return [
+    `/api/tasks/${id}/env`
+    ] as const;
+    }
+
+    
+export const getGetTaskEnvQueryOptions = <TData = Awaited<ReturnType<typeof getTaskEnv>>, TError = void>(id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskEnv>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetTaskEnvQueryKey(id);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getTaskEnv>>> = ({ signal }) => getTaskEnv(id, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, enabled: !!(id), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getTaskEnv>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetTaskEnvQueryResult = NonNullable<Awaited<ReturnType<typeof getTaskEnv>>>
+export type GetTaskEnvQueryError = void
+
+
+export function useGetTaskEnv<TData = Awaited<ReturnType<typeof getTaskEnv>>, TError = void>(
+ id: string, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskEnv>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getTaskEnv>>,
+          TError,
+          Awaited<ReturnType<typeof getTaskEnv>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetTaskEnv<TData = Awaited<ReturnType<typeof getTaskEnv>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskEnv>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getTaskEnv>>,
+          TError,
+          Awaited<ReturnType<typeof getTaskEnv>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetTaskEnv<TData = Awaited<ReturnType<typeof getTaskEnv>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskEnv>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetTaskEnv<TData = Awaited<ReturnType<typeof getTaskEnv>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskEnv>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetTaskEnvQueryOptions(id,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type updateTaskEnvResponse200 = {
+  data: TaskEnvResponse
+  status: 200
+}
+
+export type updateTaskEnvResponse400 = {
+  data: void
+  status: 400
+}
+
+export type updateTaskEnvResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type updateTaskEnvResponseSuccess = (updateTaskEnvResponse200) & {
+  headers: Headers;
+};
+export type updateTaskEnvResponseError = (updateTaskEnvResponse400 | updateTaskEnvResponse404) & {
+  headers: Headers;
+};
+
+export type updateTaskEnvResponse = (updateTaskEnvResponseSuccess | updateTaskEnvResponseError)
+
+export const getUpdateTaskEnvUrl = (id: string,) => {
+
+
+  
+
+  return `/api/tasks/${id}/env`
+}
+
+export const updateTaskEnv = async (id: string,
+    updateTaskEnvRequest: UpdateTaskEnvRequest, options?: RequestInit): Promise<updateTaskEnvResponse> => {
+  
+  return customFetch<updateTaskEnvResponse>(getUpdateTaskEnvUrl(id),
+  {      
+    ...options,
+    method: 'PUT',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      updateTaskEnvRequest,)
+  }
+);}
+
+
+
+
+export const getUpdateTaskEnvMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof updateTaskEnv>>, TError,{id: string;data: UpdateTaskEnvRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof updateTaskEnv>>, TError,{id: string;data: UpdateTaskEnvRequest}, TContext> => {
+
+const mutationKey = ['updateTaskEnv'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof updateTaskEnv>>, {id: string;data: UpdateTaskEnvRequest}> = (props) => {
+          const {id,data} = props ?? {};
+
+          return  updateTaskEnv(id,data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type UpdateTaskEnvMutationResult = NonNullable<Awaited<ReturnType<typeof updateTaskEnv>>>
+    export type UpdateTaskEnvMutationBody = UpdateTaskEnvRequest
+    export type UpdateTaskEnvMutationError = void
+
+    export const useUpdateTaskEnv = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof updateTaskEnv>>, TError,{id: string;data: UpdateTaskEnvRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof updateTaskEnv>>,
+        TError,
+        {id: string;data: UpdateTaskEnvRequest},
+        TContext
+      > => {
+
+      const mutationOptions = getUpdateTaskEnvMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
     export type executeTaskResponse202 = {
   data: ExecuteResponse
   status: 202
@@ -716,59 +944,59 @@ export function useGetTaskFindings<TData = Awaited<ReturnType<typeof getTaskFind
 
 
 
-export type fixFindingsResponse202 = {
-  data: ExecuteResponse
-  status: 202
+export type bulkUpdateFindingsResponse200 = {
+  data: BulkUpdateFindingsResponse
+  status: 200
 }
 
-export type fixFindingsResponse400 = {
+export type bulkUpdateFindingsResponse400 = {
   data: void
   status: 400
 }
 
-export type fixFindingsResponse404 = {
+export type bulkUpdateFindingsResponse404 = {
   data: void
   status: 404
 }
     
-export type fixFindingsResponseSuccess = (fixFindingsResponse202) & {
+export type bulkUpdateFindingsResponseSuccess = (bulkUpdateFindingsResponse200) & {
   headers: Headers;
 };
-export type fixFindingsResponseError = (fixFindingsResponse400 | fixFindingsResponse404) & {
+export type bulkUpdateFindingsResponseError = (bulkUpdateFindingsResponse400 | bulkUpdateFindingsResponse404) & {
   headers: Headers;
 };
 
-export type fixFindingsResponse = (fixFindingsResponseSuccess | fixFindingsResponseError)
+export type bulkUpdateFindingsResponse = (bulkUpdateFindingsResponseSuccess | bulkUpdateFindingsResponseError)
 
-export const getFixFindingsUrl = (id: string,) => {
+export const getBulkUpdateFindingsUrl = (id: string,) => {
 
 
   
 
-  return `/api/tasks/${id}/findings/fix`
+  return `/api/tasks/${id}/findings/bulk`
 }
 
-export const fixFindings = async (id: string,
-    fixFindingsRequest: FixFindingsRequest, options?: RequestInit): Promise<fixFindingsResponse> => {
+export const bulkUpdateFindings = async (id: string,
+    bulkUpdateFindingsRequest: BulkUpdateFindingsRequest, options?: RequestInit): Promise<bulkUpdateFindingsResponse> => {
   
-  return customFetch<fixFindingsResponse>(getFixFindingsUrl(id),
+  return customFetch<bulkUpdateFindingsResponse>(getBulkUpdateFindingsUrl(id),
   {      
     ...options,
-    method: 'POST',
+    method: 'PATCH',
     headers: { 'Content-Type': 'application/json', ...options?.headers },
     body: JSON.stringify(
-      fixFindingsRequest,)
+      bulkUpdateFindingsRequest,)
   }
 );}
 
 
 
 
-export const getFixFindingsMutationOptions = <TError = void,
-    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof fixFindings>>, TError,{id: string;data: FixFindingsRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
-): UseMutationOptions<Awaited<ReturnType<typeof fixFindings>>, TError,{id: string;data: FixFindingsRequest}, TContext> => {
+export const getBulkUpdateFindingsMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof bulkUpdateFindings>>, TError,{id: string;data: BulkUpdateFindingsRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof bulkUpdateFindings>>, TError,{id: string;data: BulkUpdateFindingsRequest}, TContext> => {
 
-const mutationKey = ['fixFindings'];
+const mutationKey = ['bulkUpdateFindings'];
 const {mutation: mutationOptions, request: requestOptions} = options ?
       options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
       options
@@ -778,10 +1006,10 @@ const {mutation: mutationOptions, request: requestOptions} = options ?
       
 
 
-      const mutationFn: MutationFunction<Awaited<ReturnType<typeof fixFindings>>, {id: string;data: FixFindingsRequest}> = (props) => {
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof bulkUpdateFindings>>, {id: string;data: BulkUpdateFindingsRequest}> = (props) => {
           const {id,data} = props ?? {};
 
-          return  fixFindings(id,data,requestOptions)
+          return  bulkUpdateFindings(id,data,requestOptions)
         }
 
         
@@ -789,61 +1017,56 @@ const {mutation: mutationOptions, request: requestOptions} = options ?
 
   return  { mutationFn, ...mutationOptions }}
 
-    export type FixFindingsMutationResult = NonNullable<Awaited<ReturnType<typeof fixFindings>>>
-    export type FixFindingsMutationBody = FixFindingsRequest
-    export type FixFindingsMutationError = void
+    export type BulkUpdateFindingsMutationResult = NonNullable<Awaited<ReturnType<typeof bulkUpdateFindings>>>
+    export type BulkUpdateFindingsMutationBody = BulkUpdateFindingsRequest
+    export type BulkUpdateFindingsMutationError = void
 
-    export const useFixFindings = <TError = void,
-    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof fixFindings>>, TError,{id: string;data: FixFindingsRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+    export const useBulkUpdateFindings = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof bulkUpdateFindings>>, TError,{id: string;data: BulkUpdateFindingsRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient): UseMutationResult<
-        Awaited<ReturnType<typeof fixFindings>>,
+        Awaited<ReturnType<typeof bulkUpdateFindings>>,
         TError,
-        {id: string;data: FixFindingsRequest},
+        {id: string;data: BulkUpdateFindingsRequest},
         TContext
       > => {
 
-      const mutationOptions = getFixFindingsMutationOptions(options);
+      const mutationOptions = getBulkUpdateFindingsMutationOptions(options);
 
       return useMutation(mutationOptions, queryClient);
     }
-    export type skipFindingsResponse200 = {
-  data: Task
+    export type getTaskFindingsSarifResponse200 = {
+  data: GetTaskFindingsSarif200
   status: 200
 }
 
-export type skipFindingsResponse400 = {
-  data: void
-  status: 400
-}
-
-export type skipFindingsResponse404 = {
+export type getTaskFindingsSarifResponse404 = {
   data: void
   status: 404
 }
     
-export type skipFindingsResponseSuccess = (skipFindingsResponse200) & {
+export type getTaskFindingsSarifResponseSuccess = (getTaskFindingsSarifResponse200) & {
   headers: Headers;
 };
-export type skipFindingsResponseError = (skipFindingsResponse400 | skipFindingsResponse404) & {
+export type getTaskFindingsSarifResponseError = (getTaskFindingsSarifResponse404) & {
   headers: Headers;
 };
 
-export type skipFindingsResponse = (skipFindingsResponseSuccess | skipFindingsResponseError)
+export type getTaskFindingsSarifResponse = (getTaskFindingsSarifResponseSuccess | getTaskFindingsSarifResponseError)
 
-export const getSkipFindingsUrl = (id: string,) => {
+export const getGetTaskFindingsSarifUrl = (id: string,) => {
 
 
   
 
-  return `/api/tasks/${id}/findings/skip`
+  return `/api/tasks/${id}/findings/sarif`
 }
 
-export const skipFindings = async (id: string, options?: RequestInit): Promise<skipFindingsResponse> => {
+export const getTaskFindingsSarif = async (id: string, options?: RequestInit): Promise<getTaskFindingsSarifResponse> => {
   
-  return customFetch<skipFindingsResponse>(getSkipFindingsUrl(id),
+  return customFetch<getTaskFindingsSarifResponse>(getGetTaskFindingsSarifUrl(id),
   {      
     ...options,
-    method: 'POST'
+    method: 'GET'
     
     
   }
@@ -852,78 +1075,1406 @@ export const skipFindings = async (id: string, options?: RequestInit): Promise<s
 
 
 
-export const getSkipFindingsMutationOptions = <TError = void,
-    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof skipFindings>>, TError,{id: string}, TContext>, request?: SecondParameter<typeof customFetch>}
-): UseMutationOptions<Awaited<ReturnType<typeof skipFindings>>, TError,{id: string}, TContext> => {
-
-const mutationKey = ['skipFindings'];
-const {mutation: mutationOptions, request: requestOptions} = options ?
-      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
-      options
-      : {...options, mutation: {...options.mutation, mutationKey}}
-      : {mutation: { mutationKey, }, request: undefined};
-
-      
-
-
-      const mutationFn: MutationFunction<Awaited<ReturnType<typeof skipFindings>>, {id: string}> = (props) => {
-          const {id} = props ?? {};
 
-          return  skipFindings(id,requestOptions)
-        }
+export const getGetTaskFindingsSarifQueryKey = (id?: string,) => {
+    return [
+    `/api/tasks/${id}/findings/sarif`
+    ] as const;
+    }
 
-        
+    
+export const getGetTaskFindingsSarifQueryOptions = <TData = Awaited<ReturnType<typeof getTaskFindingsSarif>>, TError = void>(id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskFindingsSarif>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
 
+const {query: queryOptions, request: requestOptions} = options ?? {};
 
-  return  { mutationFn, ...mutationOptions }}
+  const queryKey =  queryOptions?.queryKey ?? getGetTaskFindingsSarifQueryKey(id);
 
-    export type SkipFindingsMutationResult = NonNullable<Awaited<ReturnType<typeof skipFindings>>>
-    
-    export type SkipFindingsMutationError = void
+  
 
-    export const useSkipFindings = <TError = void,
-    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof skipFindings>>, TError,{id: string}, TContext>, request?: SecondParameter<typeof customFetch>}
- , queryClient?: QueryClient): UseMutationResult<
-        Awaited<ReturnType<typeof skipFindings>>,
-        TError,
-        {id: string},
-        TContext
-      > => {
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getTaskFindingsSarif>>> = ({ signal }) => getTaskFindingsSarif(id, { signal, ...requestOptions });
 
-      const mutationOptions = getSkipFindingsMutationOptions(options);
+      
 
-      return useMutation(mutationOptions, queryClient);
-    }
-    export type getTaskPhasesResponse200 = {
-  data: PhasesResponse
-  status: 200
-}
+      
 
-export type getTaskPhasesResponse404 = {
-  data: void
-  status: 404
+   return  { queryKey, queryFn, enabled: !!(id), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getTaskFindingsSarif>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
 }
-    
-export type getTaskPhasesResponseSuccess = (getTaskPhasesResponse200) & {
-  headers: Headers;
-};
-export type getTaskPhasesResponseError = (getTaskPhasesResponse404) & {
-  headers: Headers;
-};
-
-export type getTaskPhasesResponse = (getTaskPhasesResponseSuccess | getTaskPhasesResponseError)
 
-export const getGetTaskPhasesUrl = (id: string,) => {
+export type GetTaskFindingsSarifQueryResult = NonNullable<Awaited<ReturnType<typeof getTaskFindingsSarif>>>
+export type GetTaskFindingsSarifQueryError = void
+
+
+export function useGetTaskFindingsSarif<TData = Awaited<ReturnType<typeof getTaskFindingsSarif>>, TError = void>(
+ id: string, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskFindingsSarif>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getTaskFindingsSarif>>,
+          TError,
+          Awaited<ReturnType<typeof getTaskFindingsSarif>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetTaskFindingsSarif<TData = Awaited<ReturnType<typeof getTaskFindingsSarif>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskFindingsSarif>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getTaskFindingsSarif>>,
+          TError,
+          Awaited<ReturnType<typeof getTaskFindingsSarif>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetTaskFindingsSarif<TData = Awaited<ReturnType<typeof getTaskFindingsSarif>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskFindingsSarif>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetTaskFindingsSarif<TData = Awaited<ReturnType<typeof getTaskFindingsSarif>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskFindingsSarif>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetTaskFindingsSarifQueryOptions(id,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+    export type getTaskReviewReportResponse200 = {
+  data: string
+  status: 200
+}
+
+export type getTaskReviewReportResponse400 = {
+  data: void
+  status: 400
+}
+
+export type getTaskReviewReportResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type getTaskReviewReportResponseSuccess = (getTaskReviewReportResponse200) & {
+  headers: Headers;
+};
+export type getTaskReviewReportResponseError = (getTaskReviewReportResponse400 | getTaskReviewReportResponse404) & {
+  headers: Headers;
+};
+
+export type getTaskReviewReportResponse = (getTaskReviewReportResponseSuccess | getTaskReviewReportResponseError)
+
+export const getGetTaskReviewReportUrl = (id: string,
+    params?: GetTaskReviewReportParams,) => {
+  const normalizedParams = new URLSearchParams();
+
+  Object.entries(params || {}).forEach(([key, value]) => {
+    
+    if (value !== undefined) {
+      normalizedParams.append(key, value === null ? 'null' : value.toString())
+    }
+  });
+
+  const stringifiedParams = normalizedParams.toString();
+
+  return stringifiedParams.length > 0 ? `/api/tasks/${id}/review-report?${stringifiedParams}` : `/api/tasks/${id}/review-report`
+}
+
+export const getTaskReviewReport = async (id: string,
+    params?: GetTaskReviewReportParams, options?: RequestInit): Promise<getTaskReviewReportResponse> => {
+  
+  return customFetch<getTaskReviewReportResponse>(getGetTaskReviewReportUrl(id,params),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetTaskReviewReportQueryKey = (id?: string,
+    params?: GetTaskReviewReportParams,) => {
+    return [
+    `/api/tasks/${id}/review-report`, ...(params ? [params]: [])
+    ] as const;
+    }
+
+    
+export const getGetTaskReviewReportQueryOptions = <TData = Awaited<ReturnType<typeof getTaskReviewReport>>, TError = void>(id: string,
+    params?: GetTaskReviewReportParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskReviewReport>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetTaskReviewReportQueryKey(id,params);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getTaskReviewReport>>> = ({ signal }) => getTaskReviewReport(id,params, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, enabled: !!(id), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getTaskReviewReport>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetTaskReviewReportQueryResult = NonNullable<Awaited<ReturnType<typeof getTaskReviewReport>>>
+export type GetTaskReviewReportQueryError = void
+
+
+export function useGetTaskReviewReport<TData = Awaited<ReturnType<typeof getTaskReviewReport>>, TError = void>(
+ id: string,
+    params?: GetTaskReviewReportParams, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskReviewReport>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getTaskReviewReport>>,
+          TError,
+          Awaited<ReturnType<typeof getTaskReviewReport>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetTaskReviewReport<TData = Awaited<ReturnType<typeof getTaskReviewReport>>, TError = void>(
+ id: string,
+    params?: GetTaskReviewReportParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskReviewReport>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getTaskReviewReport>>,
+          TError,
+          Awaited<ReturnType<typeof getTaskReviewReport>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetTaskReviewReport<TData = Awaited<ReturnType<typeof getTaskReviewReport>>, TError = void>(
+ id: string,
+    params?: GetTaskReviewReportParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskReviewReport>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetTaskReviewReport<TData = Awaited<ReturnType<typeof getTaskReviewReport>>, TError = void>(
+ id: string,
+    params?: GetTaskReviewReportParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskReviewReport>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetTaskReviewReportQueryOptions(id,params,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+    export type translateFindingsResponse200 = {
+  data: FindingsResponse
+  status: 200
+}
+
+export type translateFindingsResponse400 = {
+  data: void
+  status: 400
+}
+
+export type translateFindingsResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type translateFindingsResponseSuccess = (translateFindingsResponse200) & {
+  headers: Headers;
+};
+export type translateFindingsResponseError = (translateFindingsResponse400 | translateFindingsResponse404) & {
+  headers: Headers;
+};
+
+export type translateFindingsResponse = (translateFindingsResponseSuccess | translateFindingsResponseError)
+
+export const getTranslateFindingsUrl = (id: string,
+    params?: TranslateFindingsParams,) => {
+  const normalizedParams = new URLSearchParams();
+
+  Object.entries(params || {}).forEach(([key, value]) => {
+    
+    if (value !== undefined) {
+      normalizedParams.append(key, value === null ? 'null' : value.toString())
+    }
+  });
+
+  const stringifiedParams = normalizedParams.toString();
+
+  return stringifiedParams.length > 0 ? `/api/tasks/${id}/findings/translate?${stringifiedParams}` : `/api/tasks/${id}/findings/translate`
+}
+
+export const translateFindings = async (id: string,
+    params?: TranslateFindingsParams, options?: RequestInit): Promise<translateFindingsResponse> => {
+  
+  return customFetch<translateFindingsResponse>(getTranslateFindingsUrl(id,params),
+  {      
+    ...options,
+    method: 'POST'
+    
+    
+  }
+);}
+
+
+
+
+export const getTranslateFindingsMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof translateFindings>>, TError,{id: string;params?: TranslateFindingsParams}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof translateFindings>>, TError,{id: string;params?: TranslateFindingsParams}, TContext> => {
+
+const mutationKey = ['translateFindings'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof translateFindings>>, {id: string;params?: TranslateFindingsParams}> = (props) => {
+          const {id,params} = props ?? {};
+
+          return  translateFindings(id,params,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type TranslateFindingsMutationResult = NonNullable<Awaited<ReturnType<typeof translateFindings>>>
+    
+    export type TranslateFindingsMutationError = void
+
+    export const useTranslateFindings = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof translateFindings>>, TError,{id: string;params?: TranslateFindingsParams}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof translateFindings>>,
+        TError,
+        {id: string;params?: TranslateFindingsParams},
+        TContext
+      > => {
+
+      const mutationOptions = getTranslateFindingsMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    
+export type fixFindingsResponse202 = {
+  data: ExecuteResponse
+  status: 202
+}
+
+export type fixFindingsResponse400 = {
+  data: void
+  status: 400
+}
+
+export type fixFindingsResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type fixFindingsResponseSuccess = (fixFindingsResponse202) & {
+  headers: Headers;
+};
+export type fixFindingsResponseError = (fixFindingsResponse400 | fixFindingsResponse404) & {
+  headers: Headers;
+};
+
+export type fixFindingsResponse = (fixFindingsResponseSuccess | fixFindingsResponseError)
+
+export const getFixFindingsUrl = (id: string,) => {
+
+
+  
+
+  return `/api/tasks/${id}/findings/fix`
+}
+
+export const fixFindings = async (id: string,
+    fixFindingsRequest: FixFindingsRequest, options?: RequestInit): Promise<fixFindingsResponse> => {
+  
+  return customFetch<fixFindingsResponse>(getFixFindingsUrl(id),
+  {      
+    ...options,
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      fixFindingsRequest,)
+  }
+);}
+
+
+
+
+export const getFixFindingsMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof fixFindings>>, TError,{id: string;data: FixFindingsRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof fixFindings>>, TError,{id: string;data: FixFindingsRequest}, TContext> => {
+
+const mutationKey = ['fixFindings'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof fixFindings>>, {id: string;data: FixFindingsRequest}> = (props) => {
+          const {id,data} = props ?? {};
+
+          return  fixFindings(id,data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type FixFindingsMutationResult = NonNullable<Awaited<ReturnType<typeof fixFindings>>>
+    export type FixFindingsMutationBody = FixFindingsRequest
+    export type FixFindingsMutationError = void
+
+    export const useFixFindings = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof fixFindings>>, TError,{id: string;data: FixFindingsRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof fixFindings>>,
+        TError,
+        {id: string;data: FixFindingsRequest},
+        TContext
+      > => {
+
+      const mutationOptions = getFixFindingsMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type skipFindingsResponse200 = {
+  data: Task
+  status: 200
+}
+
+export type skipFindingsResponse400 = {
+  data: void
+  status: 400
+}
+
+export type skipFindingsResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type skipFindingsResponseSuccess = (skipFindingsResponse200) & {
+  headers: Headers;
+};
+export type skipFindingsResponseError = (skipFindingsResponse400 | skipFindingsResponse404) & {
+  headers: Headers;
+};
+
+export type skipFindingsResponse = (skipFindingsResponseSuccess | skipFindingsResponseError)
+
+export const getSkipFindingsUrl = (id: string,) => {
+
+
+  
+
+  return `/api/tasks/${id}/findings/skip`
+}
+
+export const skipFindings = async (id: string, options?: RequestInit): Promise<skipFindingsResponse> => {
+  
+  return customFetch<skipFindingsResponse>(getSkipFindingsUrl(id),
+  {      
+    ...options,
+    method: 'POST'
+    
+    
+  }
+);}
+
+
+
+
+export const getSkipFindingsMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof skipFindings>>, TError,{id: string}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof skipFindings>>, TError,{id: string}, TContext> => {
+
+const mutationKey = ['skipFindings'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof skipFindings>>, {id: string}> = (props) => {
+          const {id} = props ?? {};
+
+          return  skipFindings(id,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type SkipFindingsMutationResult = NonNullable<Awaited<ReturnType<typeof skipFindings>>>
+    
+    export type SkipFindingsMutationError = void
+
+    export const useSkipFindings = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof skipFindings>>, TError,{id: string}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof skipFindings>>,
+        TError,
+        {id: string},
+        TContext
+      > => {
+
+      const mutationOptions = getSkipFindingsMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type getTaskPhasesResponse200 = {
+  data: PhasesResponse
+  status: 200
+}
+
+export type getTaskPhasesResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type getTaskPhasesResponseSuccess = (getTaskPhasesResponse200) & {
+  headers: Headers;
+};
+export type getTaskPhasesResponseError = (getTaskPhasesResponse404) & {
+  headers: Headers;
+};
+
+export type getTaskPhasesResponse = (getTaskPhasesResponseSuccess | getTaskPhasesResponseError)
+
+export const getGetTaskPhasesUrl = (id: string,) => {
+
+
+  
+
+  return `/api/tasks/${id}/phases`
+}
+
+export const getTaskPhases = async (id: string, options?: RequestInit): Promise<getTaskPhasesResponse> => {
+  
+  return customFetch<getTaskPhasesResponse>(getGetTaskPhasesUrl(id),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetTaskPhasesQueryKey = (id?: string,) => {
+    return [
+    `/api/tasks/${id}/phases`
+    ] as const;
+    }
+
+    
+export const getGetTaskPhasesQueryOptions = <TData = Awaited<ReturnType<typeof getTaskPhases>>, TError = void>(id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPhases>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetTaskPhasesQueryKey(id);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getTaskPhases>>> = ({ signal }) => getTaskPhases(id, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, enabled: !!(id), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getTaskPhases>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetTaskPhasesQueryResult = NonNullable<Awaited<ReturnType<typeof getTaskPhases>>>
+export type GetTaskPhasesQueryError = void
+
+
+export function useGetTaskPhases<TData = Awaited<ReturnType<typeof getTaskPhases>>, TError = void>(
+ id: string, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPhases>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getTaskPhases>>,
+          TError,
+          Awaited<ReturnType<typeof getTaskPhases>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetTaskPhases<TData = Awaited<ReturnType<typeof getTaskPhases>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPhases>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getTaskPhases>>,
+          TError,
+          Awaited<ReturnType<typeof getTaskPhases>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetTaskPhases<TData = Awaited<ReturnType<typeof getTaskPhases>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPhases>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetTaskPhases<TData = Awaited<ReturnType<typeof getTaskPhases>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPhases>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetTaskPhasesQueryOptions(id,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type getTaskPipelineResponse200 = {
+  data: PipelineResponse
+  status: 200
+}
+
+export type getTaskPipelineResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type getTaskPipelineResponseSuccess = (getTaskPipelineResponse200) & {
+  headers: Headers;
+};
+export type getTaskPipelineResponseError = (getTaskPipelineResponse404) & {
+  headers: Headers;
+};
+
+export type getTaskPipelineResponse = (getTaskPipelineResponseSuccess | getTaskPipelineResponseError)
+
+export const getGetTaskPipelineUrl = (id: string,) => {
+
+
+  
+
+  return `/api/tasks/${id}/pipeline`
+}
+
+export const getTaskPipeline = async (id: string, options?: RequestInit): Promise<getTaskPipelineResponse> => {
+  
+  return customFetch<getTaskPipelineResponse>(getGetTaskPipelineUrl(id),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetTaskPipelineQueryKey = (id?: string,) => {
+    return [
+    `/api/tasks/${id}/pipeline`
+    ] as const;
+    }
+
+    
+export const getGetTaskPipelineQueryOptions = <TData = Awaited<ReturnType<typeof getTaskPipeline>>, TError = void>(id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPipeline>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetTaskPipelineQueryKey(id);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getTaskPipeline>>> = ({ signal }) => getTaskPipeline(id, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, enabled: !!(id), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getTaskPipeline>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetTaskPipelineQueryResult = NonNullable<Awaited<ReturnType<typeof getTaskPipeline>>>
+export type GetTaskPipelineQueryError = void
+
+
+export function useGetTaskPipeline<TData = Awaited<ReturnType<typeof getTaskPipeline>>, TError = void>(
+ id: string, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPipeline>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getTaskPipeline>>,
+          TError,
+          Awaited<ReturnType<typeof getTaskPipeline>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetTaskPipeline<TData = Awaited<ReturnType<typeof getTaskPipeline>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPipeline>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getTaskPipeline>>,
+          TError,
+          Awaited<ReturnType<typeof getTaskPipeline>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetTaskPipeline<TData = Awaited<ReturnType<typeof getTaskPipeline>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPipeline>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetTaskPipeline<TData = Awaited<ReturnType<typeof getTaskPipeline>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPipeline>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetTaskPipelineQueryOptions(id,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type listTaskExecutionsResponse200 = {
+  data: TaskExecutionsResponse
+  status: 200
+}
+
+export type listTaskExecutionsResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type listTaskExecutionsResponseSuccess = (listTaskExecutionsResponse200) & {
+  headers: Headers;
+};
+export type listTaskExecutionsResponseError = (listTaskExecutionsResponse404) & {
+  headers: Headers;
+};
+
+export type listTaskExecutionsResponse = (listTaskExecutionsResponseSuccess | listTaskExecutionsResponseError)
+
+export const getListTaskExecutionsUrl = (id: string,) => {
+
+
+  
+
+  return `/api/tasks/${id}/executions`
+}
+
+export const listTaskExecutions = async (id: string, options?: RequestInit): Promise<listTaskExecutionsResponse> => {
+  
+  return customFetch<listTaskExecutionsResponse>(getListTaskExecutionsUrl(id),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getListTaskExecutionsQueryKey = (id?: string,) => {
+    return [
+    `/api/tasks/${id}/executions`
+    ] as const;
+    }
+
+    
+export const getListTaskExecutionsQueryOptions = <TData = Awaited<ReturnType<typeof listTaskExecutions>>, TError = void>(id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTaskExecutions>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getListTaskExecutionsQueryKey(id);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof listTaskExecutions>>> = ({ signal }) => listTaskExecutions(id, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, enabled: !!(id), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof listTaskExecutions>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type ListTaskExecutionsQueryResult = NonNullable<Awaited<ReturnType<typeof listTaskExecutions>>>
+export type ListTaskExecutionsQueryError = void
+
+
+export function useListTaskExecutions<TData = Awaited<ReturnType<typeof listTaskExecutions>>, TError = void>(
+ id: string, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTaskExecutions>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof listTaskExecutions>>,
+          TError,
+          Awaited<ReturnType<typeof listTaskExecutions>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useListTaskExecutions<TData = Awaited<ReturnType<typeof listTaskExecutions>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTaskExecutions>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof listTaskExecutions>>,
+          TError,
+          Awaited<ReturnType<typeof listTaskExecutions>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useListTaskExecutions<TData = Awaited<ReturnType<typeof listTaskExecutions>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTaskExecutions>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useListTaskExecutions<TData = Awaited<ReturnType<typeof listTaskExecutions>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTaskExecutions>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getListTaskExecutionsQueryOptions(id,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type compareTaskExecutionsResponse200 = {
+  data: ExecutionComparison
+  status: 200
+}
+
+export type compareTaskExecutionsResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type compareTaskExecutionsResponseSuccess = (compareTaskExecutionsResponse200) & {
+  headers: Headers;
+};
+export type compareTaskExecutionsResponseError = (compareTaskExecutionsResponse404) & {
+  headers: Headers;
+};
+
+export type compareTaskExecutionsResponse = (compareTaskExecutionsResponseSuccess | compareTaskExecutionsResponseError)
+
+export const getCompareTaskExecutionsUrl = (id: string,
+    params: CompareTaskExecutionsParams,) => {
+  const normalizedParams = new URLSearchParams();
+
+  Object.entries(params || {}).forEach(([key, value]) => {
+    
+    if (value !== undefined) {
+      normalizedParams.append(key, value === null ? 'null' : value.toString())
+    }
+  });
+
+  const stringifiedParams = normalizedParams.toString();
+
+  return stringifiedParams.length > 0 ? `/api/tasks/${id}/executions/compare?${stringifiedParams}` : `/api/tasks/${id}/executions/compare`
+}
+
+export const compareTaskExecutions = async (id: string,
+    params: CompareTaskExecutionsParams, options?: RequestInit): Promise<compareTaskExecutionsResponse> => {
+  
+  return customFetch<compareTaskExecutionsResponse>(getCompareTaskExecutionsUrl(id,params),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getCompareTaskExecutionsQueryKey = (id?: string,
+    params?: CompareTaskExecutionsParams,) => {
+    return [
+    `/api/tasks/${id}/executions/compare`, ...(params ? [params]: [])
+    ] as const;
+    }
+
+    
+export const getCompareTaskExecutionsQueryOptions = <TData = Awaited<ReturnType<typeof compareTaskExecutions>>, TError = void>(id: string,
+    params: CompareTaskExecutionsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof compareTaskExecutions>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getCompareTaskExecutionsQueryKey(id,params);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof compareTaskExecutions>>> = ({ signal }) => compareTaskExecutions(id,params, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, enabled: !!(id), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof compareTaskExecutions>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type CompareTaskExecutionsQueryResult = NonNullable<Awaited<ReturnType<typeof compareTaskExecutions>>>
+export type CompareTaskExecutionsQueryError = void
+
+
+export function useCompareTaskExecutions<TData = Awaited<ReturnType<typeof compareTaskExecutions>>, TError = void>(
+ id: string,
+    params: CompareTaskExecutionsParams, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof compareTaskExecutions>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof compareTaskExecutions>>,
+          TError,
+          Awaited<ReturnType<typeof compareTaskExecutions>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useCompareTaskExecutions<TData = Awaited<ReturnType<typeof compareTaskExecutions>>, TError = void>(
+ id: string,
+    params: CompareTaskExecutionsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof compareTaskExecutions>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof compareTaskExecutions>>,
+          TError,
+          Awaited<ReturnType<typeof compareTaskExecutions>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useCompareTaskExecutions<TData = Awaited<ReturnType<typeof compareTaskExecutions>>, TError = void>(
+ id: string,
+    params: CompareTaskExecutionsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof compareTaskExecutions>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useCompareTaskExecutions<TData = Awaited<ReturnType<typeof compareTaskExecutions>>, TError = void>(
+ id: string,
+    params: CompareTaskExecutionsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof compareTaskExecutions>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getCompareTaskExecutionsQueryOptions(id,params,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type getTaskPlanResponse200 = {
+  data: PlanResponse
+  status: 200
+}
+
+export type getTaskPlanResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type getTaskPlanResponseSuccess = (getTaskPlanResponse200) & {
+  headers: Headers;
+};
+export type getTaskPlanResponseError = (getTaskPlanResponse404) & {
+  headers: Headers;
+};
+
+export type getTaskPlanResponse = (getTaskPlanResponseSuccess | getTaskPlanResponseError)
+
+export const getGetTaskPlanUrl = (id: string,) => {
+
+
+  
+
+  return `/api/tasks/${id}/plan`
+}
+
+export const getTaskPlan = async (id: string, options?: RequestInit): Promise<getTaskPlanResponse> => {
+  
+  return customFetch<getTaskPlanResponse>(getGetTaskPlanUrl(id),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetTaskPlanQueryKey = (id?: string,) => {
+    return [
+    `/api/tasks/${id}/plan`
+    ] as const;
+    }
+
+    
+export const getGetTaskPlanQueryOptions = <TData = Awaited<ReturnType<typeof getTaskPlan>>, TError = void>(id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPlan>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetTaskPlanQueryKey(id);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getTaskPlan>>> = ({ signal }) => getTaskPlan(id, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, enabled: !!(id), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getTaskPlan>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetTaskPlanQueryResult = NonNullable<Awaited<ReturnType<typeof getTaskPlan>>>
+export type GetTaskPlanQueryError = void
+
+
+export function useGetTaskPlan<TData = Awaited<ReturnType<typeof getTaskPlan>>, TError = void>(
+ id: string, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPlan>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getTaskPlan>>,
+          TError,
+          Awaited<ReturnType<typeof getTaskPlan>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetTaskPlan<TData = Awaited<ReturnType<typeof getTaskPlan>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPlan>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getTaskPlan>>,
+          TError,
+          Awaited<ReturnType<typeof getTaskPlan>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetTaskPlan<TData = Awaited<ReturnType<typeof getTaskPlan>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPlan>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetTaskPlan<TData = Awaited<ReturnType<typeof getTaskPlan>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPlan>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetTaskPlanQueryOptions(id,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type transitionTaskResponse200 = {
+  data: TransitionResponse
+  status: 200
+}
+
+export type transitionTaskResponse400 = {
+  data: void
+  status: 400
+}
+
+export type transitionTaskResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type transitionTaskResponseSuccess = (transitionTaskResponse200) & {
+  headers: Headers;
+};
+export type transitionTaskResponseError = (transitionTaskResponse400 | transitionTaskResponse404) & {
+  headers: Headers;
+};
+
+export type transitionTaskResponse = (transitionTaskResponseSuccess | transitionTaskResponseError)
+
+export const getTransitionTaskUrl = (id: string,) => {
+
+
+  
+
+  return `/api/tasks/${id}/transition`
+}
+
+export const transitionTask = async (id: string,
+    transitionRequest: TransitionRequest, options?: RequestInit): Promise<transitionTaskResponse> => {
+  
+  return customFetch<transitionTaskResponse>(getTransitionTaskUrl(id),
+  {      
+    ...options,
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      transitionRequest,)
+  }
+);}
+
+
+
+
+export const getTransitionTaskMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof transitionTask>>, TError,{id: string;data: TransitionRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof transitionTask>>, TError,{id: string;data: TransitionRequest}, TContext> => {
+
+const mutationKey = ['transitionTask'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof transitionTask>>, {id: string;data: TransitionRequest}> = (props) => {
+          const {id,data} = props ?? {};
+
+          return  transitionTask(id,data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type TransitionTaskMutationResult = NonNullable<Awaited<ReturnType<typeof transitionTask>>>
+    export type TransitionTaskMutationBody = TransitionRequest
+    export type TransitionTaskMutationError = void
+
+    export const useTransitionTask = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof transitionTask>>, TError,{id: string;data: TransitionRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof transitionTask>>,
+        TError,
+        {id: string;data: TransitionRequest},
+        TContext
+      > => {
+
+      const mutationOptions = getTransitionTaskMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type getTaskTransitionsResponse200 = {
+  data: TaskTransitionsResponse
+  status: 200
+}
+
+export type getTaskTransitionsResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type getTaskTransitionsResponseSuccess = (getTaskTransitionsResponse200) & {
+  headers: Headers;
+};
+export type getTaskTransitionsResponseError = (getTaskTransitionsResponse404) & {
+  headers: Headers;
+};
+
+export type getTaskTransitionsResponse = (getTaskTransitionsResponseSuccess | getTaskTransitionsResponseError)
+
+export const getGetTaskTransitionsUrl = (id: string,) => {
+
+
+  
+
+  return `/api/tasks/${id}/transitions`
+}
+
+export const getTaskTransitions = async (id: string, options?: RequestInit): Promise<getTaskTransitionsResponse> => {
+  
+  return customFetch<getTaskTransitionsResponse>(getGetTaskTransitionsUrl(id),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetTaskTransitionsQueryKey = (id?: string,) => {
+    return [
+    `/api/tasks/${id}/transitions`
+    ] as const;
+    }
+
+    
+export const getGetTaskTransitionsQueryOptions = <TData = Awaited<ReturnType<typeof getTaskTransitions>>, TError = void>(id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskTransitions>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetTaskTransitionsQueryKey(id);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getTaskTransitions>>> = ({ signal }) => getTaskTransitions(id, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, enabled: !!(id), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getTaskTransitions>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetTaskTransitionsQueryResult = NonNullable<Awaited<ReturnType<typeof getTaskTransitions>>>
+export type GetTaskTransitionsQueryError = void
+
+
+export function useGetTaskTransitions<TData = Awaited<ReturnType<typeof getTaskTransitions>>, TError = void>(
+ id: string, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskTransitions>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getTaskTransitions>>,
+          TError,
+          Awaited<ReturnType<typeof getTaskTransitions>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetTaskTransitions<TData = Awaited<ReturnType<typeof getTaskTransitions>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskTransitions>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getTaskTransitions>>,
+          TError,
+          Awaited<ReturnType<typeof getTaskTransitions>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetTaskTransitions<TData = Awaited<ReturnType<typeof getTaskTransitions>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskTransitions>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetTaskTransitions<TData = Awaited<ReturnType<typeof getTaskTransitions>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskTransitions>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetTaskTransitionsQueryOptions(id,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type getStateMachineResponse200 = {
+  data: StateMachineResponse
+  status: 200
+}
+    
+export type getStateMachineResponseSuccess = (getStateMachineResponse200) & {
+  headers: Headers;
+};
+;
+
+export type getStateMachineResponse = (getStateMachineResponseSuccess)
+
+export const getGetStateMachineUrl = () => {
+
+
+  
+
+  return `/api/state-machine`
+}
+
+export const getStateMachine = async (options?: RequestInit): Promise<getStateMachineResponse> => {
+  
+  return customFetch<getStateMachineResponse>(getGetStateMachineUrl(),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetStateMachineQueryKey = () => {
+    return [
+    `/api/state-machine`
+    ] as const;
+    }
+
+    
+export const getGetStateMachineQueryOptions = <TData = Awaited<ReturnType<typeof getStateMachine>>, TError = unknown>(options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getStateMachine>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetStateMachineQueryKey();
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getStateMachine>>> = ({ signal }) => getStateMachine({ signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getStateMachine>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetStateMachineQueryResult = NonNullable<Awaited<ReturnType<typeof getStateMachine>>>
+export type GetStateMachineQueryError = unknown
+
+
+export function useGetStateMachine<TData = Awaited<ReturnType<typeof getStateMachine>>, TError = unknown>(
+ options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getStateMachine>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getStateMachine>>,
+          TError,
+          Awaited<ReturnType<typeof getStateMachine>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetStateMachine<TData = Awaited<ReturnType<typeof getStateMachine>>, TError = unknown>(
+ options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getStateMachine>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getStateMachine>>,
+          TError,
+          Awaited<ReturnType<typeof getStateMachine>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetStateMachine<TData = Awaited<ReturnType<typeof getStateMachine>>, TError = unknown>(
+ options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getStateMachine>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetStateMachine<TData = Awaited<ReturnType<typeof getStateMachine>>, TError = unknown>(
+ options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getStateMachine>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetStateMachineQueryOptions(options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type getTaskDependenciesResponse200 = {
+  data: TaskDependenciesResponse
+  status: 200
+}
+
+export type getTaskDependenciesResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type getTaskDependenciesResponseSuccess = (getTaskDependenciesResponse200) & {
+  headers: Headers;
+};
+export type getTaskDependenciesResponseError = (getTaskDependenciesResponse404) & {
+  headers: Headers;
+};
+
+export type getTaskDependenciesResponse = (getTaskDependenciesResponseSuccess | getTaskDependenciesResponseError)
+
+export const getGetTaskDependenciesUrl = (id: string,) => {
 
 
   
 
-  return `/api/tasks/${id}/phases`
+  return `/api/tasks/${id}/dependencies`
 }
 
-export const getTaskPhases = async (id: string, options?: RequestInit): Promise<getTaskPhasesResponse> => {
+export const getTaskDependencies = async (id: string, options?: RequestInit): Promise<getTaskDependenciesResponse> => {
   
-  return customFetch<getTaskPhasesResponse>(getGetTaskPhasesUrl(id),
+  return customFetch<getTaskDependenciesResponse>(getGetTaskDependenciesUrl(id),
   {      
     ...options,
     method: 'GET'
@@ -936,66 +2487,66 @@ export const getTaskPhases = async (id: string, options?: RequestInit): Promise<
 
 
 
-export const getGetTaskPhasesQueryKey = (id?: string,) => {
+export const getGetTaskDependenciesQueryKey = (id?: string,) => {
     return [
-    `/api/tasks/${id}/phases`
+    `/api/tasks/${id}/dependencies`
     ] as const;
     }
 
     
-export const getGetTaskPhasesQueryOptions = <TData = Awaited<ReturnType<typeof getTaskPhases>>, TError = void>(id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPhases>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+export const getGetTaskDependenciesQueryOptions = <TData = Awaited<ReturnType<typeof getTaskDependencies>>, TError = void>(id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskDependencies>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
 ) => {
 
 const {query: queryOptions, request: requestOptions} = options ?? {};
 
-  const queryKey =  queryOptions?.queryKey ?? getGetTaskPhasesQueryKey(id);
+  const queryKey =  queryOptions?.queryKey ?? getGetTaskDependenciesQueryKey(id);
 
   
 
-    const queryFn: QueryFunction<Awaited<ReturnType<typeof getTaskPhases>>> = ({ signal }) => getTaskPhases(id, { signal, ...requestOptions });
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getTaskDependencies>>> = ({ signal }) => getTaskDependencies(id, { signal, ...requestOptions });
 
       
 
       
 
-   return  { queryKey, queryFn, enabled: !!(id), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getTaskPhases>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+   return  { queryKey, queryFn, enabled: !!(id), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getTaskDependencies>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
 }
 
-export type GetTaskPhasesQueryResult = NonNullable<Awaited<ReturnType<typeof getTaskPhases>>>
-export type GetTaskPhasesQueryError = void
+export type GetTaskDependenciesQueryResult = NonNullable<Awaited<ReturnType<typeof getTaskDependencies>>>
+export type GetTaskDependenciesQueryError = void
 
 
-export function useGetTaskPhases<TData = Awaited<ReturnType<typeof getTaskPhases>>, TError = void>(
- id: string, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPhases>>, TError, TData>> & Pick<
+export function useGetTaskDependencies<TData = Awaited<ReturnType<typeof getTaskDependencies>>, TError = void>(
+ id: string, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskDependencies>>, TError, TData>> & Pick<
         DefinedInitialDataOptions<
-          Awaited<ReturnType<typeof getTaskPhases>>,
+          Awaited<ReturnType<typeof getTaskDependencies>>,
           TError,
-          Awaited<ReturnType<typeof getTaskPhases>>
+          Awaited<ReturnType<typeof getTaskDependencies>>
         > , 'initialData'
       >, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient
   ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
-export function useGetTaskPhases<TData = Awaited<ReturnType<typeof getTaskPhases>>, TError = void>(
- id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPhases>>, TError, TData>> & Pick<
+export function useGetTaskDependencies<TData = Awaited<ReturnType<typeof getTaskDependencies>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskDependencies>>, TError, TData>> & Pick<
         UndefinedInitialDataOptions<
-          Awaited<ReturnType<typeof getTaskPhases>>,
+          Awaited<ReturnType<typeof getTaskDependencies>>,
           TError,
-          Awaited<ReturnType<typeof getTaskPhases>>
+          Awaited<ReturnType<typeof getTaskDependencies>>
         > , 'initialData'
       >, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient
   ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
-export function useGetTaskPhases<TData = Awaited<ReturnType<typeof getTaskPhases>>, TError = void>(
- id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPhases>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+export function useGetTaskDependencies<TData = Awaited<ReturnType<typeof getTaskDependencies>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskDependencies>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient
   ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
 
-export function useGetTaskPhases<TData = Awaited<ReturnType<typeof getTaskPhases>>, TError = void>(
- id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPhases>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+export function useGetTaskDependencies<TData = Awaited<ReturnType<typeof getTaskDependencies>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskDependencies>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient 
  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
 
-  const queryOptions = getGetTaskPhasesQueryOptions(id,options)
+  const queryOptions = getGetTaskDependenciesQueryOptions(id,options)
 
   const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
 
@@ -1007,36 +2558,217 @@ export function useGetTaskPhases<TData = Awaited<ReturnType<typeof getTaskPhases
 
 
 
-export type getTaskPlanResponse200 = {
-  data: PlanResponse
+export type addTaskDependencyResponse200 = {
+  data: TaskDependenciesResponse
   status: 200
 }
 
-export type getTaskPlanResponse404 = {
+export type addTaskDependencyResponse400 = {
+  data: void
+  status: 400
+}
+
+export type addTaskDependencyResponse404 = {
   data: void
   status: 404
 }
+
+export type addTaskDependencyResponse409 = {
+  data: void
+  status: 409
+}
     
-export type getTaskPlanResponseSuccess = (getTaskPlanResponse200) & {
+export type addTaskDependencyResponseSuccess = (addTaskDependencyResponse200) & {
   headers: Headers;
 };
-export type getTaskPlanResponseError = (getTaskPlanResponse404) & {
+export type addTaskDependencyResponseError = (addTaskDependencyResponse400 | addTaskDependencyResponse404 | addTaskDependencyResponse409) & {
   headers: Headers;
 };
 
-export type getTaskPlanResponse = (getTaskPlanResponseSuccess | getTaskPlanResponseError)
+export type addTaskDependencyResponse = (addTaskDependencyResponseSuccess | addTaskDependencyResponseError)
 
-export const getGetTaskPlanUrl = (id: string,) => {
+export const getAddTaskDependencyUrl = (id: string,) => {
 
 
   
 
-  return `/api/tasks/${id}/plan`
+  return `/api/tasks/${id}/dependencies`
 }
 
-export const getTaskPlan = async (id: string, options?: RequestInit): Promise<getTaskPlanResponse> => {
+export const addTaskDependency = async (id: string,
+    addTaskDependencyRequest: AddTaskDependencyRequest, options?: RequestInit): Promise<addTaskDependencyResponse> => {
   
-  return customFetch<getTaskPlanResponse>(getGetTaskPlanUrl(id),
+  return customFetch<addTaskDependencyResponse>(getAddTaskDependencyUrl(id),
+  {      
+    ...options,
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      addTaskDependencyRequest,)
+  }
+);}
+
+
+
+
+export const getAddTaskDependencyMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof addTaskDependency>>, TError,{id: string;data: AddTaskDependencyRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof addTaskDependency>>, TError,{id: string;data: AddTaskDependencyRequest}, TContext> => {
+
+const mutationKey = ['addTaskDependency'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof addTaskDependency>>, {id: string;data: AddTaskDependencyRequest}> = (props) => {
+          const {id,data} = props ?? {};
+
+          return  addTaskDependency(id,data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type AddTaskDependencyMutationResult = NonNullable<Awaited<ReturnType<typeof addTaskDependency>>>
+    export type AddTaskDependencyMutationBody = AddTaskDependencyRequest
+    export type AddTaskDependencyMutationError = void
+
+    export const useAddTaskDependency = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof addTaskDependency>>, TError,{id: string;data: AddTaskDependencyRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof addTaskDependency>>,
+        TError,
+        {id: string;data: AddTaskDependencyRequest},
+        TContext
+      > => {
+
+      const mutationOptions = getAddTaskDependencyMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type removeTaskDependencyResponse200 = {
+  data: TaskDependenciesResponse
+  status: 200
+}
+
+export type removeTaskDependencyResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type removeTaskDependencyResponseSuccess = (removeTaskDependencyResponse200) & {
+  headers: Headers;
+};
+export type removeTaskDependencyResponseError = (removeTaskDependencyResponse404) & {
+  headers: Headers;
+};
+
+export type removeTaskDependencyResponse = (removeTaskDependencyResponseSuccess | removeTaskDependencyResponseError)
+
+export const getRemoveTaskDependencyUrl = (id: string,
+    dependsOn: string,) => {
+
+
+  
+
+  return `/api/tasks/${id}/dependencies/${dependsOn}`
+}
+
+export const removeTaskDependency = async (id: string,
+    dependsOn: string, options?: RequestInit): Promise<removeTaskDependencyResponse> => {
+  
+  return customFetch<removeTaskDependencyResponse>(getRemoveTaskDependencyUrl(id,dependsOn),
+  {      
+    ...options,
+    method: 'DELETE'
+    
+    
+  }
+);}
+
+
+
+
+export const getRemoveTaskDependencyMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof removeTaskDependency>>, TError,{id: string;dependsOn: string}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof removeTaskDependency>>, TError,{id: string;dependsOn: string}, TContext> => {
+
+const mutationKey = ['removeTaskDependency'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof removeTaskDependency>>, {id: string;dependsOn: string}> = (props) => {
+          const {id,dependsOn} = props ?? {};
+
+          return  removeTaskDependency(id,dependsOn,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type RemoveTaskDependencyMutationResult = NonNullable<Awaited<ReturnType<typeof removeTaskDependency>>>
+    
+    export type RemoveTaskDependencyMutationError = void
+
+    export const useRemoveTaskDependency = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof removeTaskDependency>>, TError,{id: string;dependsOn: string}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof removeTaskDependency>>,
+        TError,
+        {id: string;dependsOn: string},
+        TContext
+      > => {
+
+      const mutationOptions = getRemoveTaskDependencyMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type listFindingCommentsResponse200 = {
+  data: FindingComment[]
+  status: 200
+}
+
+export type listFindingCommentsResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type listFindingCommentsResponseSuccess = (listFindingCommentsResponse200) & {
+  headers: Headers;
+};
+export type listFindingCommentsResponseError = (listFindingCommentsResponse404) & {
+  headers: Headers;
+};
+
+export type listFindingCommentsResponse = (listFindingCommentsResponseSuccess | listFindingCommentsResponseError)
+
+export const getListFindingCommentsUrl = (id: string,findingId: string,) => {
+
+
+  
+
+  return `/api/tasks/${id}/findings/${findingId}/comments`
+}
+
+export const listFindingComments = async (id: string,
+    findingId: string, options?: RequestInit): Promise<listFindingCommentsResponse> => {
+  
+  return customFetch<listFindingCommentsResponse>(getListFindingCommentsUrl(id,findingId),
   {      
     ...options,
     method: 'GET'
@@ -1049,66 +2781,72 @@ export const getTaskPlan = async (id: string, options?: RequestInit): Promise<ge
 
 
 
-export const getGetTaskPlanQueryKey = (id?: string,) => {
+export const getListFindingCommentsQueryKey = (id?: string,
+    findingId?: string,) => {
     return [
-    `/api/tasks/${id}/plan`
+    `/api/tasks/${id}/findings/${findingId}/comments`
     ] as const;
     }
 
     
-export const getGetTaskPlanQueryOptions = <TData = Awaited<ReturnType<typeof getTaskPlan>>, TError = void>(id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPlan>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+export const getListFindingCommentsQueryOptions = <TData = Awaited<ReturnType<typeof listFindingComments>>, TError = void>(id: string,
+    findingId: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listFindingComments>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
 ) => {
 
 const {query: queryOptions, request: requestOptions} = options ?? {};
 
-  const queryKey =  queryOptions?.queryKey ?? getGetTaskPlanQueryKey(id);
+  const queryKey =  queryOptions?.queryKey ?? getListFindingCommentsQueryKey(id,findingId);
 
   
 
-    const queryFn: QueryFunction<Awaited<ReturnType<typeof getTaskPlan>>> = ({ signal }) => getTaskPlan(id, { signal, ...requestOptions });
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof listFindingComments>>> = ({ signal }) => listFindingComments(id,findingId, { signal, ...requestOptions });
 
       
 
       
 
-   return  { queryKey, queryFn, enabled: !!(id), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getTaskPlan>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+   return  { queryKey, queryFn, enabled: !!(id && findingId), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof listFindingComments>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
 }
 
-export type GetTaskPlanQueryResult = NonNullable<Awaited<ReturnType<typeof getTaskPlan>>>
-export type GetTaskPlanQueryError = void
+export type ListFindingCommentsQueryResult = NonNullable<Awaited<ReturnType<typeof listFindingComments>>>
+export type ListFindingCommentsQueryError = void
 
 
-export function useGetTaskPlan<TData = Awaited<ReturnType<typeof getTaskPlan>>, TError = void>(
- id: string, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPlan>>, TError, TData>> & Pick<
+export function useListFindingComments<TData = Awaited<ReturnType<typeof listFindingComments>>, TError = void>(
+ id: string,
+    findingId: string, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof listFindingComments>>, TError, TData>> & Pick<
         DefinedInitialDataOptions<
-          Awaited<ReturnType<typeof getTaskPlan>>,
+          Awaited<ReturnType<typeof listFindingComments>>,
           TError,
-          Awaited<ReturnType<typeof getTaskPlan>>
+          Awaited<ReturnType<typeof listFindingComments>>
         > , 'initialData'
       >, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient
   ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
-export function useGetTaskPlan<TData = Awaited<ReturnType<typeof getTaskPlan>>, TError = void>(
- id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPlan>>, TError, TData>> & Pick<
+export function useListFindingComments<TData = Awaited<ReturnType<typeof listFindingComments>>, TError = void>(
+ id: string,
+    findingId: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listFindingComments>>, TError, TData>> & Pick<
         UndefinedInitialDataOptions<
-          Awaited<ReturnType<typeof getTaskPlan>>,
+          Awaited<ReturnType<typeof listFindingComments>>,
           TError,
-          Awaited<ReturnType<typeof getTaskPlan>>
+          Awaited<ReturnType<typeof listFindingComments>>
         > , 'initialData'
       >, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient
   ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
-export function useGetTaskPlan<TData = Awaited<ReturnType<typeof getTaskPlan>>, TError = void>(
- id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPlan>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+export function useListFindingComments<TData = Awaited<ReturnType<typeof listFindingComments>>, TError = void>(
+ id: string,
+    findingId: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listFindingComments>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient
   ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
 
-export function useGetTaskPlan<TData = Awaited<ReturnType<typeof getTaskPlan>>, TError = void>(
- id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getTaskPlan>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+export function useListFindingComments<TData = Awaited<ReturnType<typeof listFindingComments>>, TError = void>(
+ id: string,
+    findingId: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listFindingComments>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient 
  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
 
-  const queryOptions = getGetTaskPlanQueryOptions(id,options)
+  const queryOptions = getListFindingCommentsQueryOptions(id,findingId,options)
 
   const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
 
@@ -1120,59 +2858,60 @@ export function useGetTaskPlan<TData = Awaited<ReturnType<typeof getTaskPlan>>,
 
 
 
-export type transitionTaskResponse200 = {
-  data: TransitionResponse
-  status: 200
+export type addFindingCommentResponse201 = {
+  data: FindingComment
+  status: 201
 }
 
-export type transitionTaskResponse400 = {
+export type addFindingCommentResponse400 = {
   data: void
   status: 400
 }
 
-export type transitionTaskResponse404 = {
+export type addFindingCommentResponse404 = {
   data: void
   status: 404
 }
     
-export type transitionTaskResponseSuccess = (transitionTaskResponse200) & {
+export type addFindingCommentResponseSuccess = (addFindingCommentResponse201) & {
   headers: Headers;
 };
-export type transitionTaskResponseError = (transitionTaskResponse400 | transitionTaskResponse404) & {
+export type addFindingCommentResponseError = (addFindingCommentResponse400 | addFindingCommentResponse404) & {
   headers: Headers;
 };
 
-export type transitionTaskResponse = (transitionTaskResponseSuccess | transitionTaskResponseError)
+export type addFindingCommentResponse = (addFindingCommentResponseSuccess | addFindingCommentResponseError)
 
-export const getTransitionTaskUrl = (id: string,) => {
+export const getAddFindingCommentUrl = (id: string,findingId: string,) => {
 
 
   
 
-  return `/api/tasks/${id}/transition`
+  return `/api/tasks/${id}/findings/${findingId}/comments`
 }
 
-export const transitionTask = async (id: string,
-    transitionRequest: TransitionRequest, options?: RequestInit): Promise<transitionTaskResponse> => {
+export const addFindingComment = async (id: string,
+    findingId: string,
+    addFindingCommentRequest: AddFindingCommentRequest, options?: RequestInit): Promise<addFindingCommentResponse> => {
   
-  return customFetch<transitionTaskResponse>(getTransitionTaskUrl(id),
+  return customFetch<addFindingCommentResponse>(getAddFindingCommentUrl(id,findingId),
   {      
     ...options,
     method: 'POST',
     headers: { 'Content-Type': 'application/json', ...options?.headers },
     body: JSON.stringify(
-      transitionRequest,)
+      addFindingCommentRequest,)
   }
 );}
 
 
 
 
-export const getTransitionTaskMutationOptions = <TError = void,
-    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof transitionTask>>, TError,{id: string;data: TransitionRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
-): UseMutationOptions<Awaited<ReturnType<typeof transitionTask>>, TError,{id: string;data: TransitionRequest}, TContext> => {
+export const getAddFindingCommentMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof addFindingComment>>, TError,{id: string;findingId: string;data: AddFindingCommentRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof addFindingComment>>, TError,{id: string;findingId: string;data: AddFindingCommentRequest}, TContext> => {
 
-const mutationKey = ['transitionTask'];
+const mutationKey = ['addFindingComment'];
 const {mutation: mutationOptions, request: requestOptions} = options ?
       options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
       options
@@ -1182,10 +2921,10 @@ const {mutation: mutationOptions, request: requestOptions} = options ?
       
 
 
-      const mutationFn: MutationFunction<Awaited<ReturnType<typeof transitionTask>>, {id: string;data: TransitionRequest}> = (props) => {
-          const {id,data} = props ?? {};
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof addFindingComment>>, {id: string;findingId: string;data: AddFindingCommentRequest}> = (props) => {
+          const {id,findingId,data} = props ?? {};
 
-          return  transitionTask(id,data,requestOptions)
+          return  addFindingComment(id,findingId,data,requestOptions)
         }
 
         
@@ -1193,20 +2932,20 @@ const {mutation: mutationOptions, request: requestOptions} = options ?
 
   return  { mutationFn, ...mutationOptions }}
 
-    export type TransitionTaskMutationResult = NonNullable<Awaited<ReturnType<typeof transitionTask>>>
-    export type TransitionTaskMutationBody = TransitionRequest
-    export type TransitionTaskMutationError = void
+    export type AddFindingCommentMutationResult = NonNullable<Awaited<ReturnType<typeof addFindingComment>>>
+    export type AddFindingCommentMutationBody = AddFindingCommentRequest
+    export type AddFindingCommentMutationError = void
 
-    export const useTransitionTask = <TError = void,
-    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof transitionTask>>, TError,{id: string;data: TransitionRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+    export const useAddFindingComment = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof addFindingComment>>, TError,{id: string;findingId: string;data: AddFindingCommentRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient): UseMutationResult<
-        Awaited<ReturnType<typeof transitionTask>>,
+        Awaited<ReturnType<typeof addFindingComment>>,
         TError,
-        {id: string;data: TransitionRequest},
+        {id: string;findingId: string;data: AddFindingCommentRequest},
         TContext
       > => {
 
-      const mutationOptions = getTransitionTaskMutationOptions(options);
+      const mutationOptions = getAddFindingCommentMutationOptions(options);
 
       return useMutation(mutationOptions, queryClient);
     }
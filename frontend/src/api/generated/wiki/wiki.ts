@@ -25,21 +25,46 @@ import type {
 } from '@tanstack/react-query';
 
 import type {
+  AskFeedbackRequest,
+  AskFeedbackResponse,
   AskRequest,
   AskResponse,
+  ChunkTuningRequest,
+  ChunkTuningResponse,
+  GenerateWikiPageRequest,
   GenerateWikiRequest,
   GenerateWikiResponse,
+  GetWikiCompareParams,
+  GetWikiEndpointsParams,
+  GetWikiEnvVarsParams,
+  GetWikiFileChunksParams,
+  GetWikiOnboardingParams,
   GetWikiStructureParams,
+  GetWikiTestCoverageParams,
+  IndexEstimateRequest,
+  IndexEstimateResponse,
   IndexRequest,
   IndexResponse,
+  ReembedRequest,
+  ReembedResponse,
   RemoteBranchesResponse,
   SearchRequest,
+  TaskWikiUpdatesResponse,
+  TraceFlowRequest,
+  TraceFlowResponse,
+  WebhookPullRequestEvent,
   WebhookPushRequest,
   WebhookResponse,
+  WikiCompareResponse,
+  WikiEndpointsResponse,
+  WikiEnvVarsResponse,
+  WikiFileChunksResponse,
+  WikiOnboardingResponse,
   WikiPageResponse,
   WikiSearchResponse,
   WikiStatusResponse,
-  WikiStructureResponse
+  WikiStructureResponse,
+  WikiTestCoverageResponse
 } from '.././model';
 
 import { customFetch } from '../../../lib/api-fetcher';
@@ -49,147 +74,164 @@ type SecondParameter<T extends (...args: never) => unknown> = Parameters<T>[1];
 
 
 
-export type askWikiResponse200 = {
-  data: AskResponse
+export type listTaskWikiUpdatesResponse200 = {
+  data: TaskWikiUpdatesResponse
   status: 200
 }
-
-export type askWikiResponse400 = {
-  data: void
-  status: 400
-}
-
-export type askWikiResponse500 = {
-  data: void
-  status: 500
-}
     
-export type askWikiResponseSuccess = (askWikiResponse200) & {
-  headers: Headers;
-};
-export type askWikiResponseError = (askWikiResponse400 | askWikiResponse500) & {
+export type listTaskWikiUpdatesResponseSuccess = (listTaskWikiUpdatesResponse200) & {
   headers: Headers;
 };
+;
 
-export type askWikiResponse = (askWikiResponseSuccess | askWikiResponseError)
+export type listTaskWikiUpdatesResponse = (listTaskWikiUpdatesResponseSuccess)
 
-export const getAskWikiUrl = () => {
+export const getListTaskWikiUpdatesUrl = (id: string,) => {
 
 
   
 
-  return `/api/wiki/ask`
+  return `/api/tasks/${id}/wiki-updates`
 }
 
-export const askWiki = async (askRequest: AskRequest, options?: RequestInit): Promise<askWikiResponse> => {
+export const listTaskWikiUpdates = async (id: string, options?: RequestInit): Promise<listTaskWikiUpdatesResponse> => {
   
-  return customFetch<askWikiResponse>(getAskWikiUrl(),
+  return customFetch<listTaskWikiUpdatesResponse>(getListTaskWikiUpdatesUrl(id),
   {      
     ...options,
-    method: 'POST',
-    headers: { 'Content-Type': 'application/json', ...options?.headers },
-    body: JSON.stringify(
-      askRequest,)
+    method: 'GET'
+    
+    
   }
 );}
 
 
 
 
-export const getAskWikiMutationOptions = <TError = void,
-    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof askWiki>>, TError,{data: AskRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
-): UseMutationOptions<Awaited<ReturnType<typeof askWiki>>, TError,{data: AskRequest}, TContext> => {
 
-const mutationKey = ['askWiki'];
-const {mutation: mutationOptions, request: requestOptions} = options ?
-      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
-      options
-      : {...options, mutation: {...options.mutation, mutationKey}}
-      : {mutation: { mutationKey, }, request: undefined};
+export const getListTaskWikiUpdatesQueryKey = (id?: string,) => {
+    return [
+    `/api/tasks/${id}/wiki-updates`
+    ] as const;
+    }
+
+    
+export const getListTaskWikiUpdatesQueryOptions = <TData = Awaited<ReturnType<typeof listTaskWikiUpdates>>, TError = void>(id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTaskWikiUpdates>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getListTaskWikiUpdatesQueryKey(id);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof listTaskWikiUpdates>>> = ({ signal }) => listTaskWikiUpdates(id, { signal, ...requestOptions });
 
       
 
+      
 
-      const mutationFn: MutationFunction<Awaited<ReturnType<typeof askWiki>>, {data: AskRequest}> = (props) => {
-          const {data} = props ?? {};
+   return  { queryKey, queryFn, enabled: !!(id), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof listTaskWikiUpdates>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
 
-          return  askWiki(data,requestOptions)
-        }
+export type ListTaskWikiUpdatesQueryResult = NonNullable<Awaited<ReturnType<typeof listTaskWikiUpdates>>>
+export type ListTaskWikiUpdatesQueryError = void
 
-        
 
+export function useListTaskWikiUpdates<TData = Awaited<ReturnType<typeof listTaskWikiUpdates>>, TError = void>(
+ id: string, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTaskWikiUpdates>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof listTaskWikiUpdates>>,
+          TError,
+          Awaited<ReturnType<typeof listTaskWikiUpdates>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useListTaskWikiUpdates<TData = Awaited<ReturnType<typeof listTaskWikiUpdates>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTaskWikiUpdates>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof listTaskWikiUpdates>>,
+          TError,
+          Awaited<ReturnType<typeof listTaskWikiUpdates>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useListTaskWikiUpdates<TData = Awaited<ReturnType<typeof listTaskWikiUpdates>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTaskWikiUpdates>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useListTaskWikiUpdates<TData = Awaited<ReturnType<typeof listTaskWikiUpdates>>, TError = void>(
+ id: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listTaskWikiUpdates>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
 
-  return  { mutationFn, ...mutationOptions }}
+  const queryOptions = getListTaskWikiUpdatesQueryOptions(id,options)
 
-    export type AskWikiMutationResult = NonNullable<Awaited<ReturnType<typeof askWiki>>>
-    export type AskWikiMutationBody = AskRequest
-    export type AskWikiMutationError = void
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
 
-    export const useAskWiki = <TError = void,
-    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof askWiki>>, TError,{data: AskRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
- , queryClient?: QueryClient): UseMutationResult<
-        Awaited<ReturnType<typeof askWiki>>,
-        TError,
-        {data: AskRequest},
-        TContext
-      > => {
+  query.queryKey = queryOptions.queryKey ;
 
-      const mutationOptions = getAskWikiMutationOptions(options);
+  return query;
+}
 
-      return useMutation(mutationOptions, queryClient);
-    }
-    export type generateWikiResponse200 = {
-  data: GenerateWikiResponse
+
+
+
+export type askWikiResponse200 = {
+  data: AskResponse
   status: 200
 }
 
-export type generateWikiResponse400 = {
+export type askWikiResponse400 = {
   data: void
   status: 400
 }
 
-export type generateWikiResponse500 = {
+export type askWikiResponse500 = {
   data: void
   status: 500
 }
     
-export type generateWikiResponseSuccess = (generateWikiResponse200) & {
+export type askWikiResponseSuccess = (askWikiResponse200) & {
   headers: Headers;
 };
-export type generateWikiResponseError = (generateWikiResponse400 | generateWikiResponse500) & {
+export type askWikiResponseError = (askWikiResponse400 | askWikiResponse500) & {
   headers: Headers;
 };
 
-export type generateWikiResponse = (generateWikiResponseSuccess | generateWikiResponseError)
+export type askWikiResponse = (askWikiResponseSuccess | askWikiResponseError)
 
-export const getGenerateWikiUrl = () => {
+export const getAskWikiUrl = () => {
 
 
   
 
-  return `/api/wiki/generate`
+  return `/api/wiki/ask`
 }
 
-export const generateWiki = async (generateWikiRequest: GenerateWikiRequest, options?: RequestInit): Promise<generateWikiResponse> => {
+export const askWiki = async (askRequest: AskRequest, options?: RequestInit): Promise<askWikiResponse> => {
   
-  return customFetch<generateWikiResponse>(getGenerateWikiUrl(),
+  return customFetch<askWikiResponse>(getAskWikiUrl(),
   {      
     ...options,
     method: 'POST',
     headers: { 'Content-Type': 'application/json', ...options?.headers },
     body: JSON.stringify(
-      generateWikiRequest,)
+      askRequest,)
   }
 );}
 
 
 
 
-export const getGenerateWikiMutationOptions = <TError = void,
-    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof generateWiki>>, TError,{data: GenerateWikiRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
-): UseMutationOptions<Awaited<ReturnType<typeof generateWiki>>, TError,{data: GenerateWikiRequest}, TContext> => {
+export const getAskWikiMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof askWiki>>, TError,{data: AskRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof askWiki>>, TError,{data: AskRequest}, TContext> => {
 
-const mutationKey = ['generateWiki'];
+const mutationKey = ['askWiki'];
 const {mutation: mutationOptions, request: requestOptions} = options ?
       options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
       options
@@ -199,10 +241,10 @@ const {mutation: mutationOptions, request: requestOptions} = options ?
       
 
 
-      const mutationFn: MutationFunction<Awaited<ReturnType<typeof generateWiki>>, {data: GenerateWikiRequest}> = (props) => {
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof askWiki>>, {data: AskRequest}> = (props) => {
           const {data} = props ?? {};
 
-          return  generateWiki(data,requestOptions)
+          return  askWiki(data,requestOptions)
         }
 
         
@@ -210,75 +252,71 @@ const {mutation: mutationOptions, request: requestOptions} = options ?
 
   return  { mutationFn, ...mutationOptions }}
 
-    export type GenerateWikiMutationResult = NonNullable<Awaited<ReturnType<typeof generateWiki>>>
-    export type GenerateWikiMutationBody = GenerateWikiRequest
-    export type GenerateWikiMutationError = void
+    export type AskWikiMutationResult = NonNullable<Awaited<ReturnType<typeof askWiki>>>
+    export type AskWikiMutationBody = AskRequest
+    export type AskWikiMutationError = void
 
-    export const useGenerateWiki = <TError = void,
-    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof generateWiki>>, TError,{data: GenerateWikiRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+    export const useAskWiki = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof askWiki>>, TError,{data: AskRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient): UseMutationResult<
-        Awaited<ReturnType<typeof generateWiki>>,
+        Awaited<ReturnType<typeof askWiki>>,
         TError,
-        {data: GenerateWikiRequest},
+        {data: AskRequest},
         TContext
       > => {
 
-      const mutationOptions = getGenerateWikiMutationOptions(options);
+      const mutationOptions = getAskWikiMutationOptions(options);
 
       return useMutation(mutationOptions, queryClient);
     }
-    export type startIndexingResponse200 = {
-  data: IndexResponse
-  status: 200
+    export type submitAskFeedbackResponse201 = {
+  data: AskFeedbackResponse
+  status: 201
 }
 
-export type startIndexingResponse400 = {
+export type submitAskFeedbackResponse400 = {
   data: void
   status: 400
 }
-
-export type startIndexingResponse500 = {
-  data: void
-  status: 500
-}
     
-export type startIndexingResponseSuccess = (startIndexingResponse200) & {
+export type submitAskFeedbackResponseSuccess = (submitAskFeedbackResponse201) & {
   headers: Headers;
 };
-export type startIndexingResponseError = (startIndexingResponse400 | startIndexingResponse500) & {
+export type submitAskFeedbackResponseError = (submitAskFeedbackResponse400) & {
   headers: Headers;
 };
 
-export type startIndexingResponse = (startIndexingResponseSuccess | startIndexingResponseError)
+export type submitAskFeedbackResponse = (submitAskFeedbackResponseSuccess | submitAskFeedbackResponseError)
 
-export const getStartIndexingUrl = () => {
+export const getSubmitAskFeedbackUrl = (conversationId: string,) => {
 
 
   
 
-  return `/api/wiki/index`
+  return `/api/wiki/ask/${conversationId}/feedback`
 }
 
-export const startIndexing = async (indexRequest: IndexRequest, options?: RequestInit): Promise<startIndexingResponse> => {
+export const submitAskFeedback = async (conversationId: string,
+    askFeedbackRequest: AskFeedbackRequest, options?: RequestInit): Promise<submitAskFeedbackResponse> => {
   
-  return customFetch<startIndexingResponse>(getStartIndexingUrl(),
+  return customFetch<submitAskFeedbackResponse>(getSubmitAskFeedbackUrl(conversationId),
   {      
     ...options,
     method: 'POST',
     headers: { 'Content-Type': 'application/json', ...options?.headers },
     body: JSON.stringify(
-      indexRequest,)
+      askFeedbackRequest,)
   }
 );}
 
 
 
 
-export const getStartIndexingMutationOptions = <TError = void,
-    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof startIndexing>>, TError,{data: IndexRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
-): UseMutationOptions<Awaited<ReturnType<typeof startIndexing>>, TError,{data: IndexRequest}, TContext> => {
+export const getSubmitAskFeedbackMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof submitAskFeedback>>, TError,{conversationId: string;data: AskFeedbackRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof submitAskFeedback>>, TError,{conversationId: string;data: AskFeedbackRequest}, TContext> => {
 
-const mutationKey = ['startIndexing'];
+const mutationKey = ['submitAskFeedback'];
 const {mutation: mutationOptions, request: requestOptions} = options ?
       options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
       options
@@ -288,10 +326,10 @@ const {mutation: mutationOptions, request: requestOptions} = options ?
       
 
 
-      const mutationFn: MutationFunction<Awaited<ReturnType<typeof startIndexing>>, {data: IndexRequest}> = (props) => {
-          const {data} = props ?? {};
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof submitAskFeedback>>, {conversationId: string;data: AskFeedbackRequest}> = (props) => {
+          const {conversationId,data} = props ?? {};
 
-          return  startIndexing(data,requestOptions)
+          return  submitAskFeedback(conversationId,data,requestOptions)
         }
 
         
@@ -299,58 +337,65 @@ const {mutation: mutationOptions, request: requestOptions} = options ?
 
   return  { mutationFn, ...mutationOptions }}
 
-    export type StartIndexingMutationResult = NonNullable<Awaited<ReturnType<typeof startIndexing>>>
-    export type StartIndexingMutationBody = IndexRequest
-    export type StartIndexingMutationError = void
+    export type SubmitAskFeedbackMutationResult = NonNullable<Awaited<ReturnType<typeof submitAskFeedback>>>
+    export type SubmitAskFeedbackMutationBody = AskFeedbackRequest
+    export type SubmitAskFeedbackMutationError = void
 
-    export const useStartIndexing = <TError = void,
-    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof startIndexing>>, TError,{data: IndexRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+    export const useSubmitAskFeedback = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof submitAskFeedback>>, TError,{conversationId: string;data: AskFeedbackRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient): UseMutationResult<
-        Awaited<ReturnType<typeof startIndexing>>,
+        Awaited<ReturnType<typeof submitAskFeedback>>,
         TError,
-        {data: IndexRequest},
+        {conversationId: string;data: AskFeedbackRequest},
         TContext
       > => {
 
-      const mutationOptions = getStartIndexingMutationOptions(options);
+      const mutationOptions = getSubmitAskFeedbackMutationOptions(options);
 
       return useMutation(mutationOptions, queryClient);
     }
-    export type getWikiPageResponse200 = {
-  data: WikiPageResponse
+    export type getWikiEndpointsResponse200 = {
+  data: WikiEndpointsResponse
   status: 200
 }
 
-export type getWikiPageResponse404 = {
+export type getWikiEndpointsResponse400 = {
   data: void
-  status: 404
+  status: 400
 }
 
-export type getWikiPageResponse500 = {
+export type getWikiEndpointsResponse500 = {
   data: void
   status: 500
 }
     
-export type getWikiPageResponseSuccess = (getWikiPageResponse200) & {
+export type getWikiEndpointsResponseSuccess = (getWikiEndpointsResponse200) & {
   headers: Headers;
 };
-export type getWikiPageResponseError = (getWikiPageResponse404 | getWikiPageResponse500) & {
+export type getWikiEndpointsResponseError = (getWikiEndpointsResponse400 | getWikiEndpointsResponse500) & {
   headers: Headers;
 };
 
-export type getWikiPageResponse = (getWikiPageResponseSuccess | getWikiPageResponseError)
+export type getWikiEndpointsResponse = (getWikiEndpointsResponseSuccess | getWikiEndpointsResponseError)
 
-export const getGetWikiPageUrl = (slug: string,) => {
+export const getGetWikiEndpointsUrl = (params?: GetWikiEndpointsParams,) => {
+  const normalizedParams = new URLSearchParams();
 
+  Object.entries(params || {}).forEach(([key, value]) => {
+    
+    if (value !== undefined) {
+      normalizedParams.append(key, value === null ? 'null' : value.toString())
+    }
+  });
 
-  
+  const stringifiedParams = normalizedParams.toString();
 
-  return `/api/wiki/pages/${slug}`
+  return stringifiedParams.length > 0 ? `/api/wiki/endpoints?${stringifiedParams}` : `/api/wiki/endpoints`
 }
 
-export const getWikiPage = async (slug: string, options?: RequestInit): Promise<getWikiPageResponse> => {
+export const getWikiEndpoints = async (params?: GetWikiEndpointsParams, options?: RequestInit): Promise<getWikiEndpointsResponse> => {
   
-  return customFetch<getWikiPageResponse>(getGetWikiPageUrl(slug),
+  return customFetch<getWikiEndpointsResponse>(getGetWikiEndpointsUrl(params),
   {      
     ...options,
     method: 'GET'
@@ -363,66 +408,66 @@ export const getWikiPage = async (slug: string, options?: RequestInit): Promise<
 
 
 
-export const getGetWikiPageQueryKey = (slug?: string,) => {
+export const getGetWikiEndpointsQueryKey = (params?: GetWikiEndpointsParams,) => {
     return [
-    `/api/wiki/pages/${slug}`
+    `/api/wiki/endpoints`, ...(params ? [params]: [])
     ] as const;
     }
 
     
-export const getGetWikiPageQueryOptions = <TData = Awaited<ReturnType<typeof getWikiPage>>, TError = void>(slug: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiPage>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+export const getGetWikiEndpointsQueryOptions = <TData = Awaited<ReturnType<typeof getWikiEndpoints>>, TError = void>(params?: GetWikiEndpointsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiEndpoints>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
 ) => {
 
 const {query: queryOptions, request: requestOptions} = options ?? {};
 
-  const queryKey =  queryOptions?.queryKey ?? getGetWikiPageQueryKey(slug);
+  const queryKey =  queryOptions?.queryKey ?? getGetWikiEndpointsQueryKey(params);
 
   
 
-    const queryFn: QueryFunction<Awaited<ReturnType<typeof getWikiPage>>> = ({ signal }) => getWikiPage(slug, { signal, ...requestOptions });
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getWikiEndpoints>>> = ({ signal }) => getWikiEndpoints(params, { signal, ...requestOptions });
 
       
 
       
 
-   return  { queryKey, queryFn, enabled: !!(slug), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getWikiPage>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+   return  { queryKey, queryFn, ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getWikiEndpoints>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
 }
 
-export type GetWikiPageQueryResult = NonNullable<Awaited<ReturnType<typeof getWikiPage>>>
-export type GetWikiPageQueryError = void
+export type GetWikiEndpointsQueryResult = NonNullable<Awaited<ReturnType<typeof getWikiEndpoints>>>
+export type GetWikiEndpointsQueryError = void
 
 
-export function useGetWikiPage<TData = Awaited<ReturnType<typeof getWikiPage>>, TError = void>(
- slug: string, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiPage>>, TError, TData>> & Pick<
+export function useGetWikiEndpoints<TData = Awaited<ReturnType<typeof getWikiEndpoints>>, TError = void>(
+ params: undefined |  GetWikiEndpointsParams, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiEndpoints>>, TError, TData>> & Pick<
         DefinedInitialDataOptions<
-          Awaited<ReturnType<typeof getWikiPage>>,
+          Awaited<ReturnType<typeof getWikiEndpoints>>,
           TError,
-          Awaited<ReturnType<typeof getWikiPage>>
+          Awaited<ReturnType<typeof getWikiEndpoints>>
         > , 'initialData'
       >, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient
   ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
-export function useGetWikiPage<TData = Awaited<ReturnType<typeof getWikiPage>>, TError = void>(
- slug: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiPage>>, TError, TData>> & Pick<
+export function useGetWikiEndpoints<TData = Awaited<ReturnType<typeof getWikiEndpoints>>, TError = void>(
+ params?: GetWikiEndpointsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiEndpoints>>, TError, TData>> & Pick<
         UndefinedInitialDataOptions<
-          Awaited<ReturnType<typeof getWikiPage>>,
+          Awaited<ReturnType<typeof getWikiEndpoints>>,
           TError,
-          Awaited<ReturnType<typeof getWikiPage>>
+          Awaited<ReturnType<typeof getWikiEndpoints>>
         > , 'initialData'
       >, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient
   ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
-export function useGetWikiPage<TData = Awaited<ReturnType<typeof getWikiPage>>, TError = void>(
- slug: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiPage>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+export function useGetWikiEndpoints<TData = Awaited<ReturnType<typeof getWikiEndpoints>>, TError = void>(
+ params?: GetWikiEndpointsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiEndpoints>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient
   ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
 
-export function useGetWikiPage<TData = Awaited<ReturnType<typeof getWikiPage>>, TError = void>(
- slug: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiPage>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+export function useGetWikiEndpoints<TData = Awaited<ReturnType<typeof getWikiEndpoints>>, TError = void>(
+ params?: GetWikiEndpointsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiEndpoints>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient 
  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
 
-  const queryOptions = getGetWikiPageQueryOptions(slug,options)
+  const queryOptions = getGetWikiEndpointsQueryOptions(params,options)
 
   const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
 
@@ -434,36 +479,48 @@ export function useGetWikiPage<TData = Awaited<ReturnType<typeof getWikiPage>>,
 
 
 
-export type getRemoteBranchesResponse200 = {
-  data: RemoteBranchesResponse
+export type getWikiEnvVarsResponse200 = {
+  data: WikiEnvVarsResponse
   status: 200
 }
 
-export type getRemoteBranchesResponse500 = {
+export type getWikiEnvVarsResponse400 = {
+  data: void
+  status: 400
+}
+
+export type getWikiEnvVarsResponse500 = {
   data: void
   status: 500
 }
     
-export type getRemoteBranchesResponseSuccess = (getRemoteBranchesResponse200) & {
+export type getWikiEnvVarsResponseSuccess = (getWikiEnvVarsResponse200) & {
   headers: Headers;
 };
-export type getRemoteBranchesResponseError = (getRemoteBranchesResponse500) & {
+export type getWikiEnvVarsResponseError = (getWikiEnvVarsResponse400 | getWikiEnvVarsResponse500) & {
   headers: Headers;
 };
 
-export type getRemoteBranchesResponse = (getRemoteBranchesResponseSuccess | getRemoteBranchesResponseError)
+export type getWikiEnvVarsResponse = (getWikiEnvVarsResponseSuccess | getWikiEnvVarsResponseError)
 
-export const getGetRemoteBranchesUrl = () => {
+export const getGetWikiEnvVarsUrl = (params?: GetWikiEnvVarsParams,) => {
+  const normalizedParams = new URLSearchParams();
 
+  Object.entries(params || {}).forEach(([key, value]) => {
+    
+    if (value !== undefined) {
+      normalizedParams.append(key, value === null ? 'null' : value.toString())
+    }
+  });
 
-  
+  const stringifiedParams = normalizedParams.toString();
 
-  return `/api/wiki/remote-branches`
+  return stringifiedParams.length > 0 ? `/api/wiki/env-vars?${stringifiedParams}` : `/api/wiki/env-vars`
 }
 
-export const getRemoteBranches = async ( options?: RequestInit): Promise<getRemoteBranchesResponse> => {
+export const getWikiEnvVars = async (params?: GetWikiEnvVarsParams, options?: RequestInit): Promise<getWikiEnvVarsResponse> => {
   
-  return customFetch<getRemoteBranchesResponse>(getGetRemoteBranchesUrl(),
+  return customFetch<getWikiEnvVarsResponse>(getGetWikiEnvVarsUrl(params),
   {      
     ...options,
     method: 'GET'
@@ -476,66 +533,66 @@ export const getRemoteBranches = async ( options?: RequestInit): Promise<getRemo
 
 
 
-export const getGetRemoteBranchesQueryKey = () => {
+export const getGetWikiEnvVarsQueryKey = (params?: GetWikiEnvVarsParams,) => {
     return [
-    `/api/wiki/remote-branches`
+    `/api/wiki/env-vars`, ...(params ? [params]: [])
     ] as const;
     }
 
     
-export const getGetRemoteBranchesQueryOptions = <TData = Awaited<ReturnType<typeof getRemoteBranches>>, TError = void>( options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getRemoteBranches>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+export const getGetWikiEnvVarsQueryOptions = <TData = Awaited<ReturnType<typeof getWikiEnvVars>>, TError = void>(params?: GetWikiEnvVarsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiEnvVars>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
 ) => {
 
 const {query: queryOptions, request: requestOptions} = options ?? {};
 
-  const queryKey =  queryOptions?.queryKey ?? getGetRemoteBranchesQueryKey();
+  const queryKey =  queryOptions?.queryKey ?? getGetWikiEnvVarsQueryKey(params);
 
   
 
-    const queryFn: QueryFunction<Awaited<ReturnType<typeof getRemoteBranches>>> = ({ signal }) => getRemoteBranches({ signal, ...requestOptions });
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getWikiEnvVars>>> = ({ signal }) => getWikiEnvVars(params, { signal, ...requestOptions });
 
       
 
       
 
-   return  { queryKey, queryFn, ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getRemoteBranches>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+   return  { queryKey, queryFn, ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getWikiEnvVars>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
 }
 
-export type GetRemoteBranchesQueryResult = NonNullable<Awaited<ReturnType<typeof getRemoteBranches>>>
-export type GetRemoteBranchesQueryError = void
+export type GetWikiEnvVarsQueryResult = NonNullable<Awaited<ReturnType<typeof getWikiEnvVars>>>
+export type GetWikiEnvVarsQueryError = void
 
 
-export function useGetRemoteBranches<TData = Awaited<ReturnType<typeof getRemoteBranches>>, TError = void>(
-  options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getRemoteBranches>>, TError, TData>> & Pick<
+export function useGetWikiEnvVars<TData = Awaited<ReturnType<typeof getWikiEnvVars>>, TError = void>(
+ params: undefined |  GetWikiEnvVarsParams, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiEnvVars>>, TError, TData>> & Pick<
         DefinedInitialDataOptions<
-          Awaited<ReturnType<typeof getRemoteBranches>>,
+          Awaited<ReturnType<typeof getWikiEnvVars>>,
           TError,
-          Awaited<ReturnType<typeof getRemoteBranches>>
+          Awaited<ReturnType<typeof getWikiEnvVars>>
         > , 'initialData'
       >, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient
   ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
-export function useGetRemoteBranches<TData = Awaited<ReturnType<typeof getRemoteBranches>>, TError = void>(
-  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getRemoteBranches>>, TError, TData>> & Pick<
+export function useGetWikiEnvVars<TData = Awaited<ReturnType<typeof getWikiEnvVars>>, TError = void>(
+ params?: GetWikiEnvVarsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiEnvVars>>, TError, TData>> & Pick<
         UndefinedInitialDataOptions<
-          Awaited<ReturnType<typeof getRemoteBranches>>,
+          Awaited<ReturnType<typeof getWikiEnvVars>>,
           TError,
-          Awaited<ReturnType<typeof getRemoteBranches>>
+          Awaited<ReturnType<typeof getWikiEnvVars>>
         > , 'initialData'
       >, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient
   ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
-export function useGetRemoteBranches<TData = Awaited<ReturnType<typeof getRemoteBranches>>, TError = void>(
-  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getRemoteBranches>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+export function useGetWikiEnvVars<TData = Awaited<ReturnType<typeof getWikiEnvVars>>, TError = void>(
+ params?: GetWikiEnvVarsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiEnvVars>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient
   ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
 
-export function useGetRemoteBranches<TData = Awaited<ReturnType<typeof getRemoteBranches>>, TError = void>(
-  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getRemoteBranches>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+export function useGetWikiEnvVars<TData = Awaited<ReturnType<typeof getWikiEnvVars>>, TError = void>(
+ params?: GetWikiEnvVarsParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiEnvVars>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient 
  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
 
-  const queryOptions = getGetRemoteBranchesQueryOptions(options)
+  const queryOptions = getGetWikiEnvVarsQueryOptions(params,options)
 
   const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
 
@@ -547,58 +604,58 @@ export function useGetRemoteBranches<TData = Awaited<ReturnType<typeof getRemote
 
 
 
-export type searchWikiResponse200 = {
-  data: WikiSearchResponse
+export type reembedWikiResponse200 = {
+  data: ReembedResponse
   status: 200
 }
 
-export type searchWikiResponse400 = {
+export type reembedWikiResponse400 = {
   data: void
   status: 400
 }
 
-export type searchWikiResponse500 = {
+export type reembedWikiResponse500 = {
   data: void
   status: 500
 }
     
-export type searchWikiResponseSuccess = (searchWikiResponse200) & {
+export type reembedWikiResponseSuccess = (reembedWikiResponse200) & {
   headers: Headers;
 };
-export type searchWikiResponseError = (searchWikiResponse400 | searchWikiResponse500) & {
+export type reembedWikiResponseError = (reembedWikiResponse400 | reembedWikiResponse500) & {
   headers: Headers;
 };
 
-export type searchWikiResponse = (searchWikiResponseSuccess | searchWikiResponseError)
+export type reembedWikiResponse = (reembedWikiResponseSuccess | reembedWikiResponseError)
 
-export const getSearchWikiUrl = () => {
+export const getReembedWikiUrl = () => {
 
 
   
 
-  return `/api/wiki/search`
+  return `/api/wiki/reembed`
 }
 
-export const searchWiki = async (searchRequest: SearchRequest, options?: RequestInit): Promise<searchWikiResponse> => {
+export const reembedWiki = async (reembedRequest: ReembedRequest, options?: RequestInit): Promise<reembedWikiResponse> => {
   
-  return customFetch<searchWikiResponse>(getSearchWikiUrl(),
+  return customFetch<reembedWikiResponse>(getReembedWikiUrl(),
   {      
     ...options,
     method: 'POST',
     headers: { 'Content-Type': 'application/json', ...options?.headers },
     body: JSON.stringify(
-      searchRequest,)
+      reembedRequest,)
   }
 );}
 
 
 
 
-export const getSearchWikiMutationOptions = <TError = void,
-    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof searchWiki>>, TError,{data: SearchRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
-): UseMutationOptions<Awaited<ReturnType<typeof searchWiki>>, TError,{data: SearchRequest}, TContext> => {
+export const getReembedWikiMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof reembedWiki>>, TError,{data: ReembedRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof reembedWiki>>, TError,{data: ReembedRequest}, TContext> => {
 
-const mutationKey = ['searchWiki'];
+const mutationKey = ['reembedWiki'];
 const {mutation: mutationOptions, request: requestOptions} = options ?
       options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
       options
@@ -608,10 +665,10 @@ const {mutation: mutationOptions, request: requestOptions} = options ?
       
 
 
-      const mutationFn: MutationFunction<Awaited<ReturnType<typeof searchWiki>>, {data: SearchRequest}> = (props) => {
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof reembedWiki>>, {data: ReembedRequest}> = (props) => {
           const {data} = props ?? {};
 
-          return  searchWiki(data,requestOptions)
+          return  reembedWiki(data,requestOptions)
         }
 
         
@@ -619,161 +676,226 @@ const {mutation: mutationOptions, request: requestOptions} = options ?
 
   return  { mutationFn, ...mutationOptions }}
 
-    export type SearchWikiMutationResult = NonNullable<Awaited<ReturnType<typeof searchWiki>>>
-    export type SearchWikiMutationBody = SearchRequest
-    export type SearchWikiMutationError = void
+    export type ReembedWikiMutationResult = NonNullable<Awaited<ReturnType<typeof reembedWiki>>>
+    export type ReembedWikiMutationBody = ReembedRequest
+    export type ReembedWikiMutationError = void
 
-    export const useSearchWiki = <TError = void,
-    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof searchWiki>>, TError,{data: SearchRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+    export const useReembedWiki = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof reembedWiki>>, TError,{data: ReembedRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient): UseMutationResult<
-        Awaited<ReturnType<typeof searchWiki>>,
+        Awaited<ReturnType<typeof reembedWiki>>,
         TError,
-        {data: SearchRequest},
+        {data: ReembedRequest},
         TContext
       > => {
 
-      const mutationOptions = getSearchWikiMutationOptions(options);
+      const mutationOptions = getReembedWikiMutationOptions(options);
 
       return useMutation(mutationOptions, queryClient);
     }
-    export type getWikiStatusResponse200 = {
-  data: WikiStatusResponse
+    export type generateWikiResponse200 = {
+  data: GenerateWikiResponse
   status: 200
 }
 
-export type getWikiStatusResponse500 = {
+export type generateWikiResponse400 = {
+  data: void
+  status: 400
+}
+
+export type generateWikiResponse500 = {
   data: void
   status: 500
 }
     
-export type getWikiStatusResponseSuccess = (getWikiStatusResponse200) & {
+export type generateWikiResponseSuccess = (generateWikiResponse200) & {
   headers: Headers;
 };
-export type getWikiStatusResponseError = (getWikiStatusResponse500) & {
+export type generateWikiResponseError = (generateWikiResponse400 | generateWikiResponse500) & {
   headers: Headers;
 };
 
-export type getWikiStatusResponse = (getWikiStatusResponseSuccess | getWikiStatusResponseError)
+export type generateWikiResponse = (generateWikiResponseSuccess | generateWikiResponseError)
 
-export const getGetWikiStatusUrl = () => {
+export const getGenerateWikiUrl = () => {
 
 
   
 
-  return `/api/wiki/status`
+  return `/api/wiki/generate`
 }
 
-export const getWikiStatus = async ( options?: RequestInit): Promise<getWikiStatusResponse> => {
+export const generateWiki = async (generateWikiRequest: GenerateWikiRequest, options?: RequestInit): Promise<generateWikiResponse> => {
   
-  return customFetch<getWikiStatusResponse>(getGetWikiStatusUrl(),
+  return customFetch<generateWikiResponse>(getGenerateWikiUrl(),
   {      
     ...options,
-    method: 'GET'
-    
-    
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      generateWikiRequest,)
   }
 );}
 
 
 
 
+export const getGenerateWikiMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof generateWiki>>, TError,{data: GenerateWikiRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof generateWiki>>, TError,{data: GenerateWikiRequest}, TContext> => {
 
-export const getGetWikiStatusQueryKey = () => {
-    return [
-    `/api/wiki/status`
-    ] as const;
+const mutationKey = ['generateWiki'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof generateWiki>>, {data: GenerateWikiRequest}> = (props) => {
+          const {data} = props ?? {};
+
+          return  generateWiki(data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type GenerateWikiMutationResult = NonNullable<Awaited<ReturnType<typeof generateWiki>>>
+    export type GenerateWikiMutationBody = GenerateWikiRequest
+    export type GenerateWikiMutationError = void
+
+    export const useGenerateWiki = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof generateWiki>>, TError,{data: GenerateWikiRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof generateWiki>>,
+        TError,
+        {data: GenerateWikiRequest},
+        TContext
+      > => {
+
+      const mutationOptions = getGenerateWikiMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
     }
+    export type startIndexingResponse200 = {
+  data: IndexResponse
+  status: 200
+}
+
+export type startIndexingResponse400 = {
+  data: void
+  status: 400
+}
 
+export type startIndexingResponse500 = {
+  data: void
+  status: 500
+}
     
-export const getGetWikiStatusQueryOptions = <TData = Awaited<ReturnType<typeof getWikiStatus>>, TError = void>( options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiStatus>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
-) => {
+export type startIndexingResponseSuccess = (startIndexingResponse200) & {
+  headers: Headers;
+};
+export type startIndexingResponseError = (startIndexingResponse400 | startIndexingResponse500) & {
+  headers: Headers;
+};
 
-const {query: queryOptions, request: requestOptions} = options ?? {};
+export type startIndexingResponse = (startIndexingResponseSuccess | startIndexingResponseError)
+
+export const getStartIndexingUrl = () => {
 
-  const queryKey =  queryOptions?.queryKey ?? getGetWikiStatusQueryKey();
 
   
 
-    const queryFn: QueryFunction<Awaited<ReturnType<typeof getWikiStatus>>> = ({ signal }) => getWikiStatus({ signal, ...requestOptions });
+  return `/api/wiki/index`
+}
 
-      
+export const startIndexing = async (indexRequest: IndexRequest, options?: RequestInit): Promise<startIndexingResponse> => {
+  
+  return customFetch<startIndexingResponse>(getStartIndexingUrl(),
+  {      
+    ...options,
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      indexRequest,)
+  }
+);}
 
-      
 
-   return  { queryKey, queryFn, ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getWikiStatus>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
-}
 
-export type GetWikiStatusQueryResult = NonNullable<Awaited<ReturnType<typeof getWikiStatus>>>
-export type GetWikiStatusQueryError = void
 
+export const getStartIndexingMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof startIndexing>>, TError,{data: IndexRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof startIndexing>>, TError,{data: IndexRequest}, TContext> => {
 
-export function useGetWikiStatus<TData = Awaited<ReturnType<typeof getWikiStatus>>, TError = void>(
-  options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiStatus>>, TError, TData>> & Pick<
-        DefinedInitialDataOptions<
-          Awaited<ReturnType<typeof getWikiStatus>>,
-          TError,
-          Awaited<ReturnType<typeof getWikiStatus>>
-        > , 'initialData'
-      >, request?: SecondParameter<typeof customFetch>}
- , queryClient?: QueryClient
-  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
-export function useGetWikiStatus<TData = Awaited<ReturnType<typeof getWikiStatus>>, TError = void>(
-  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiStatus>>, TError, TData>> & Pick<
-        UndefinedInitialDataOptions<
-          Awaited<ReturnType<typeof getWikiStatus>>,
-          TError,
-          Awaited<ReturnType<typeof getWikiStatus>>
-        > , 'initialData'
-      >, request?: SecondParameter<typeof customFetch>}
- , queryClient?: QueryClient
-  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
-export function useGetWikiStatus<TData = Awaited<ReturnType<typeof getWikiStatus>>, TError = void>(
-  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiStatus>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
- , queryClient?: QueryClient
-  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+const mutationKey = ['startIndexing'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
 
-export function useGetWikiStatus<TData = Awaited<ReturnType<typeof getWikiStatus>>, TError = void>(
-  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiStatus>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
- , queryClient?: QueryClient 
- ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+      
 
-  const queryOptions = getGetWikiStatusQueryOptions(options)
 
-  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof startIndexing>>, {data: IndexRequest}> = (props) => {
+          const {data} = props ?? {};
 
-  query.queryKey = queryOptions.queryKey ;
+          return  startIndexing(data,requestOptions)
+        }
 
-  return query;
-}
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
 
+    export type StartIndexingMutationResult = NonNullable<Awaited<ReturnType<typeof startIndexing>>>
+    export type StartIndexingMutationBody = IndexRequest
+    export type StartIndexingMutationError = void
 
+    export const useStartIndexing = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof startIndexing>>, TError,{data: IndexRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof startIndexing>>,
+        TError,
+        {data: IndexRequest},
+        TContext
+      > => {
 
+      const mutationOptions = getStartIndexingMutationOptions(options);
 
-export type getWikiStructureResponse200 = {
-  data: WikiStructureResponse
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type getWikiOnboardingResponse200 = {
+  data: WikiOnboardingResponse
   status: 200
 }
 
-export type getWikiStructureResponse404 = {
+export type getWikiOnboardingResponse400 = {
   data: void
-  status: 404
+  status: 400
 }
 
-export type getWikiStructureResponse500 = {
+export type getWikiOnboardingResponse500 = {
   data: void
   status: 500
 }
     
-export type getWikiStructureResponseSuccess = (getWikiStructureResponse200) & {
+export type getWikiOnboardingResponseSuccess = (getWikiOnboardingResponse200) & {
   headers: Headers;
 };
-export type getWikiStructureResponseError = (getWikiStructureResponse404 | getWikiStructureResponse500) & {
+export type getWikiOnboardingResponseError = (getWikiOnboardingResponse400 | getWikiOnboardingResponse500) & {
   headers: Headers;
 };
 
-export type getWikiStructureResponse = (getWikiStructureResponseSuccess | getWikiStructureResponseError)
+export type getWikiOnboardingResponse = (getWikiOnboardingResponseSuccess | getWikiOnboardingResponseError)
 
-export const getGetWikiStructureUrl = (params?: GetWikiStructureParams,) => {
+export const getGetWikiOnboardingUrl = (params?: GetWikiOnboardingParams,) => {
   const normalizedParams = new URLSearchParams();
 
   Object.entries(params || {}).forEach(([key, value]) => {
@@ -785,12 +907,12 @@ export const getGetWikiStructureUrl = (params?: GetWikiStructureParams,) => {
 
   const stringifiedParams = normalizedParams.toString();
 
-  return stringifiedParams.length > 0 ? `/api/wiki/structure?${stringifiedParams}` : `/api/wiki/structure`
+  return stringifiedParams.length > 0 ? `/api/wiki/onboarding?${stringifiedParams}` : `/api/wiki/onboarding`
 }
 
-export const getWikiStructure = async (params?: GetWikiStructureParams, options?: RequestInit): Promise<getWikiStructureResponse> => {
+export const getWikiOnboarding = async (params?: GetWikiOnboardingParams, options?: RequestInit): Promise<getWikiOnboardingResponse> => {
   
-  return customFetch<getWikiStructureResponse>(getGetWikiStructureUrl(params),
+  return customFetch<getWikiOnboardingResponse>(getGetWikiOnboardingUrl(params),
   {      
     ...options,
     method: 'GET'
@@ -803,66 +925,66 @@ export const getWikiStructure = async (params?: GetWikiStructureParams, options?
 
 
 
-export const getGetWikiStructureQueryKey = (params?: GetWikiStructureParams,) => {
+export const getGetWikiOnboardingQueryKey = (params?: GetWikiOnboardingParams,) => {
     return [
-    `/api/wiki/structure`, ...(params ? [params]: [])
+    `/api/wiki/onboarding`, ...(params ? [params]: [])
     ] as const;
     }
 
     
-export const getGetWikiStructureQueryOptions = <TData = Awaited<ReturnType<typeof getWikiStructure>>, TError = void>(params?: GetWikiStructureParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiStructure>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+export const getGetWikiOnboardingQueryOptions = <TData = Awaited<ReturnType<typeof getWikiOnboarding>>, TError = void>(params?: GetWikiOnboardingParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiOnboarding>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
 ) => {
 
 const {query: queryOptions, request: requestOptions} = options ?? {};
 
-  const queryKey =  queryOptions?.queryKey ?? getGetWikiStructureQueryKey(params);
+  const queryKey =  queryOptions?.queryKey ?? getGetWikiOnboardingQueryKey(params);
 
   
 
-    const queryFn: QueryFunction<Awaited<ReturnType<typeof getWikiStructure>>> = ({ signal }) => getWikiStructure(params, { signal, ...requestOptions });
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getWikiOnboarding>>> = ({ signal }) => getWikiOnboarding(params, { signal, ...requestOptions });
 
       
 
       
 
-   return  { queryKey, queryFn, ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getWikiStructure>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+   return  { queryKey, queryFn, ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getWikiOnboarding>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
 }
 
-export type GetWikiStructureQueryResult = NonNullable<Awaited<ReturnType<typeof getWikiStructure>>>
-export type GetWikiStructureQueryError = void
+export type GetWikiOnboardingQueryResult = NonNullable<Awaited<ReturnType<typeof getWikiOnboarding>>>
+export type GetWikiOnboardingQueryError = void
 
 
-export function useGetWikiStructure<TData = Awaited<ReturnType<typeof getWikiStructure>>, TError = void>(
- params: undefined |  GetWikiStructureParams, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiStructure>>, TError, TData>> & Pick<
+export function useGetWikiOnboarding<TData = Awaited<ReturnType<typeof getWikiOnboarding>>, TError = void>(
+ params: undefined |  GetWikiOnboardingParams, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiOnboarding>>, TError, TData>> & Pick<
         DefinedInitialDataOptions<
-          Awaited<ReturnType<typeof getWikiStructure>>,
+          Awaited<ReturnType<typeof getWikiOnboarding>>,
           TError,
-          Awaited<ReturnType<typeof getWikiStructure>>
+          Awaited<ReturnType<typeof getWikiOnboarding>>
         > , 'initialData'
       >, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient
   ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
-export function useGetWikiStructure<TData = Awaited<ReturnType<typeof getWikiStructure>>, TError = void>(
- params?: GetWikiStructureParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiStructure>>, TError, TData>> & Pick<
+export function useGetWikiOnboarding<TData = Awaited<ReturnType<typeof getWikiOnboarding>>, TError = void>(
+ params?: GetWikiOnboardingParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiOnboarding>>, TError, TData>> & Pick<
         UndefinedInitialDataOptions<
-          Awaited<ReturnType<typeof getWikiStructure>>,
+          Awaited<ReturnType<typeof getWikiOnboarding>>,
           TError,
-          Awaited<ReturnType<typeof getWikiStructure>>
+          Awaited<ReturnType<typeof getWikiOnboarding>>
         > , 'initialData'
       >, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient
   ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
-export function useGetWikiStructure<TData = Awaited<ReturnType<typeof getWikiStructure>>, TError = void>(
- params?: GetWikiStructureParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiStructure>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+export function useGetWikiOnboarding<TData = Awaited<ReturnType<typeof getWikiOnboarding>>, TError = void>(
+ params?: GetWikiOnboardingParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiOnboarding>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient
   ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
 
-export function useGetWikiStructure<TData = Awaited<ReturnType<typeof getWikiStructure>>, TError = void>(
- params?: GetWikiStructureParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiStructure>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+export function useGetWikiOnboarding<TData = Awaited<ReturnType<typeof getWikiOnboarding>>, TError = void>(
+ params?: GetWikiOnboardingParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiOnboarding>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient 
  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
 
-  const queryOptions = getGetWikiStructureQueryOptions(params,options)
+  const queryOptions = getGetWikiOnboardingQueryOptions(params,options)
 
   const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
 
@@ -874,53 +996,1366 @@ export function useGetWikiStructure<TData = Awaited<ReturnType<typeof getWikiStr
 
 
 
-export type handlePushWebhookResponse200 = {
+export type getWikiPageResponse200 = {
+  data: WikiPageResponse
+  status: 200
+}
+
+export type getWikiPageResponse404 = {
+  data: void
+  status: 404
+}
+
+export type getWikiPageResponse500 = {
+  data: void
+  status: 500
+}
+    
+export type getWikiPageResponseSuccess = (getWikiPageResponse200) & {
+  headers: Headers;
+};
+export type getWikiPageResponseError = (getWikiPageResponse404 | getWikiPageResponse500) & {
+  headers: Headers;
+};
+
+export type getWikiPageResponse = (getWikiPageResponseSuccess | getWikiPageResponseError)
+
+export const getGetWikiPageUrl = (slug: string,) => {
+
+
+  
+
+  return `/api/wiki/pages/${slug}`
+}
+
+export const getWikiPage = async (slug: string, options?: RequestInit): Promise<getWikiPageResponse> => {
+  
+  return customFetch<getWikiPageResponse>(getGetWikiPageUrl(slug),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetWikiPageQueryKey = (slug?: string,) => {
+    return [
+    `/api/wiki/pages/${slug}`
+    ] as const;
+    }
+
+    
+export const getGetWikiPageQueryOptions = <TData = Awaited<ReturnType<typeof getWikiPage>>, TError = void>(slug: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiPage>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetWikiPageQueryKey(slug);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getWikiPage>>> = ({ signal }) => getWikiPage(slug, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, enabled: !!(slug), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getWikiPage>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetWikiPageQueryResult = NonNullable<Awaited<ReturnType<typeof getWikiPage>>>
+export type GetWikiPageQueryError = void
+
+
+export function useGetWikiPage<TData = Awaited<ReturnType<typeof getWikiPage>>, TError = void>(
+ slug: string, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiPage>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getWikiPage>>,
+          TError,
+          Awaited<ReturnType<typeof getWikiPage>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetWikiPage<TData = Awaited<ReturnType<typeof getWikiPage>>, TError = void>(
+ slug: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiPage>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getWikiPage>>,
+          TError,
+          Awaited<ReturnType<typeof getWikiPage>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetWikiPage<TData = Awaited<ReturnType<typeof getWikiPage>>, TError = void>(
+ slug: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiPage>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetWikiPage<TData = Awaited<ReturnType<typeof getWikiPage>>, TError = void>(
+ slug: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiPage>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetWikiPageQueryOptions(slug,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+export type generateSkippedWikiPageResponse200 = {
+  data: WikiPageResponse
+  status: 200
+}
+
+export type generateSkippedWikiPageResponse400 = {
+  data: void
+  status: 400
+}
+
+export type generateSkippedWikiPageResponse404 = {
+  data: void
+  status: 404
+}
+
+export type generateSkippedWikiPageResponse500 = {
+  data: void
+  status: 500
+}
+    
+export type generateSkippedWikiPageResponseSuccess = (generateSkippedWikiPageResponse200) & {
+  headers: Headers;
+};
+export type generateSkippedWikiPageResponseError = (generateSkippedWikiPageResponse400 | generateSkippedWikiPageResponse404 | generateSkippedWikiPageResponse500) & {
+  headers: Headers;
+};
+
+export type generateSkippedWikiPageResponse = (generateSkippedWikiPageResponseSuccess | generateSkippedWikiPageResponseError)
+
+export const getGenerateSkippedWikiPageUrl = (slug: string,) => {
+
+
+  
+
+  return `/api/wiki/pages/${slug}/generate`
+}
+
+export const generateSkippedWikiPage = async (slug: string,
+    generateWikiPageRequest: GenerateWikiPageRequest, options?: RequestInit): Promise<generateSkippedWikiPageResponse> => {
+  
+  return customFetch<generateSkippedWikiPageResponse>(getGenerateSkippedWikiPageUrl(slug),
+  {      
+    ...options,
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      generateWikiPageRequest,)
+  }
+);}
+
+
+
+
+export const getGenerateSkippedWikiPageMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof generateSkippedWikiPage>>, TError,{slug: string;data: GenerateWikiPageRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof generateSkippedWikiPage>>, TError,{slug: string;data: GenerateWikiPageRequest}, TContext> => {
+
+const mutationKey = ['generateSkippedWikiPage'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof generateSkippedWikiPage>>, {slug: string;data: GenerateWikiPageRequest}> = (props) => {
+          const {slug,data} = props ?? {};
+
+          return  generateSkippedWikiPage(slug,data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type GenerateSkippedWikiPageMutationResult = NonNullable<Awaited<ReturnType<typeof generateSkippedWikiPage>>>
+    export type GenerateSkippedWikiPageMutationBody = GenerateWikiPageRequest
+    export type GenerateSkippedWikiPageMutationError = void
+
+    export const useGenerateSkippedWikiPage = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof generateSkippedWikiPage>>, TError,{slug: string;data: GenerateWikiPageRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof generateSkippedWikiPage>>,
+        TError,
+        {slug: string;data: GenerateWikiPageRequest},
+        TContext
+      > => {
+
+      const mutationOptions = getGenerateSkippedWikiPageMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    
+export type getRemoteBranchesResponse200 = {
+  data: RemoteBranchesResponse
+  status: 200
+}
+
+export type getRemoteBranchesResponse500 = {
+  data: void
+  status: 500
+}
+    
+export type getRemoteBranchesResponseSuccess = (getRemoteBranchesResponse200) & {
+  headers: Headers;
+};
+export type getRemoteBranchesResponseError = (getRemoteBranchesResponse500) & {
+  headers: Headers;
+};
+
+export type getRemoteBranchesResponse = (getRemoteBranchesResponseSuccess | getRemoteBranchesResponseError)
+
+export const getGetRemoteBranchesUrl = () => {
+
+
+  
+
+  return `/api/wiki/remote-branches`
+}
+
+export const getRemoteBranches = async ( options?: RequestInit): Promise<getRemoteBranchesResponse> => {
+  
+  return customFetch<getRemoteBranchesResponse>(getGetRemoteBranchesUrl(),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetRemoteBranchesQueryKey = () => {
+    return [
+    `/api/wiki/remote-branches`
+    ] as const;
+    }
+
+    
+export const getGetRemoteBranchesQueryOptions = <TData = Awaited<ReturnType<typeof getRemoteBranches>>, TError = void>( options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getRemoteBranches>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetRemoteBranchesQueryKey();
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getRemoteBranches>>> = ({ signal }) => getRemoteBranches({ signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getRemoteBranches>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetRemoteBranchesQueryResult = NonNullable<Awaited<ReturnType<typeof getRemoteBranches>>>
+export type GetRemoteBranchesQueryError = void
+
+
+export function useGetRemoteBranches<TData = Awaited<ReturnType<typeof getRemoteBranches>>, TError = void>(
+  options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getRemoteBranches>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getRemoteBranches>>,
+          TError,
+          Awaited<ReturnType<typeof getRemoteBranches>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetRemoteBranches<TData = Awaited<ReturnType<typeof getRemoteBranches>>, TError = void>(
+  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getRemoteBranches>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getRemoteBranches>>,
+          TError,
+          Awaited<ReturnType<typeof getRemoteBranches>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetRemoteBranches<TData = Awaited<ReturnType<typeof getRemoteBranches>>, TError = void>(
+  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getRemoteBranches>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetRemoteBranches<TData = Awaited<ReturnType<typeof getRemoteBranches>>, TError = void>(
+  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getRemoteBranches>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetRemoteBranchesQueryOptions(options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type searchWikiResponse200 = {
+  data: WikiSearchResponse
+  status: 200
+}
+
+export type searchWikiResponse400 = {
+  data: void
+  status: 400
+}
+
+export type searchWikiResponse500 = {
+  data: void
+  status: 500
+}
+    
+export type searchWikiResponseSuccess = (searchWikiResponse200) & {
+  headers: Headers;
+};
+export type searchWikiResponseError = (searchWikiResponse400 | searchWikiResponse500) & {
+  headers: Headers;
+};
+
+export type searchWikiResponse = (searchWikiResponseSuccess | searchWikiResponseError)
+
+export const getSearchWikiUrl = () => {
+
+
+  
+
+  return `/api/wiki/search`
+}
+
+export const searchWiki = async (searchRequest: SearchRequest, options?: RequestInit): Promise<searchWikiResponse> => {
+  
+  return customFetch<searchWikiResponse>(getSearchWikiUrl(),
+  {      
+    ...options,
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      searchRequest,)
+  }
+);}
+
+
+
+
+export const getSearchWikiMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof searchWiki>>, TError,{data: SearchRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof searchWiki>>, TError,{data: SearchRequest}, TContext> => {
+
+const mutationKey = ['searchWiki'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof searchWiki>>, {data: SearchRequest}> = (props) => {
+          const {data} = props ?? {};
+
+          return  searchWiki(data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type SearchWikiMutationResult = NonNullable<Awaited<ReturnType<typeof searchWiki>>>
+    export type SearchWikiMutationBody = SearchRequest
+    export type SearchWikiMutationError = void
+
+    export const useSearchWiki = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof searchWiki>>, TError,{data: SearchRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof searchWiki>>,
+        TError,
+        {data: SearchRequest},
+        TContext
+      > => {
+
+      const mutationOptions = getSearchWikiMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type getWikiStatusResponse200 = {
+  data: WikiStatusResponse
+  status: 200
+}
+
+export type getWikiStatusResponse500 = {
+  data: void
+  status: 500
+}
+    
+export type getWikiStatusResponseSuccess = (getWikiStatusResponse200) & {
+  headers: Headers;
+};
+export type getWikiStatusResponseError = (getWikiStatusResponse500) & {
+  headers: Headers;
+};
+
+export type getWikiStatusResponse = (getWikiStatusResponseSuccess | getWikiStatusResponseError)
+
+export const getGetWikiStatusUrl = () => {
+
+
+  
+
+  return `/api/wiki/status`
+}
+
+export const getWikiStatus = async ( options?: RequestInit): Promise<getWikiStatusResponse> => {
+  
+  return customFetch<getWikiStatusResponse>(getGetWikiStatusUrl(),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetWikiStatusQueryKey = () => {
+    return [
+    `/api/wiki/status`
+    ] as const;
+    }
+
+    
+export const getGetWikiStatusQueryOptions = <TData = Awaited<ReturnType<typeof getWikiStatus>>, TError = void>( options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiStatus>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetWikiStatusQueryKey();
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getWikiStatus>>> = ({ signal }) => getWikiStatus({ signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getWikiStatus>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetWikiStatusQueryResult = NonNullable<Awaited<ReturnType<typeof getWikiStatus>>>
+export type GetWikiStatusQueryError = void
+
+
+export function useGetWikiStatus<TData = Awaited<ReturnType<typeof getWikiStatus>>, TError = void>(
+  options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiStatus>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getWikiStatus>>,
+          TError,
+          Awaited<ReturnType<typeof getWikiStatus>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetWikiStatus<TData = Awaited<ReturnType<typeof getWikiStatus>>, TError = void>(
+  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiStatus>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getWikiStatus>>,
+          TError,
+          Awaited<ReturnType<typeof getWikiStatus>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetWikiStatus<TData = Awaited<ReturnType<typeof getWikiStatus>>, TError = void>(
+  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiStatus>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetWikiStatus<TData = Awaited<ReturnType<typeof getWikiStatus>>, TError = void>(
+  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiStatus>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetWikiStatusQueryOptions(options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type getWikiStructureResponse200 = {
+  data: WikiStructureResponse
+  status: 200
+}
+
+export type getWikiStructureResponse404 = {
+  data: void
+  status: 404
+}
+
+export type getWikiStructureResponse500 = {
+  data: void
+  status: 500
+}
+    
+export type getWikiStructureResponseSuccess = (getWikiStructureResponse200) & {
+  headers: Headers;
+};
+export type getWikiStructureResponseError = (getWikiStructureResponse404 | getWikiStructureResponse500) & {
+  headers: Headers;
+};
+
+export type getWikiStructureResponse = (getWikiStructureResponseSuccess | getWikiStructureResponseError)
+
+export const getGetWikiStructureUrl = (params?: GetWikiStructureParams,) => {
+  const normalizedParams = new URLSearchParams();
+
+  Object.entries(params || {}).forEach(([key, value]) => {
+    
+    if (value !== undefined) {
+      normalizedParams.append(key, value === null ? 'null' : value.toString())
+    }
+  });
+
+  const stringifiedParams = normalizedParams.toString();
+
+  return stringifiedParams.length > 0 ? `/api/wiki/structure?${stringifiedParams}` : `/api/wiki/structure`
+}
+
+export const getWikiStructure = async (params?: GetWikiStructureParams, options?: RequestInit): Promise<getWikiStructureResponse> => {
+  
+  return customFetch<getWikiStructureResponse>(getGetWikiStructureUrl(params),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetWikiStructureQueryKey = (params?: GetWikiStructureParams,) => {
+    return [
+    `/api/wiki/structure`, ...(params ? [params]: [])
+    ] as const;
+    }
+
+    
+export const getGetWikiStructureQueryOptions = <TData = Awaited<ReturnType<typeof getWikiStructure>>, TError = void>(params?: GetWikiStructureParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiStructure>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetWikiStructureQueryKey(params);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getWikiStructure>>> = ({ signal }) => getWikiStructure(params, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getWikiStructure>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetWikiStructureQueryResult = NonNullable<Awaited<ReturnType<typeof getWikiStructure>>>
+export type GetWikiStructureQueryError = void
+
+
+export function useGetWikiStructure<TData = Awaited<ReturnType<typeof getWikiStructure>>, TError = void>(
+ params: undefined |  GetWikiStructureParams, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiStructure>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getWikiStructure>>,
+          TError,
+          Awaited<ReturnType<typeof getWikiStructure>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetWikiStructure<TData = Awaited<ReturnType<typeof getWikiStructure>>, TError = void>(
+ params?: GetWikiStructureParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiStructure>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getWikiStructure>>,
+          TError,
+          Awaited<ReturnType<typeof getWikiStructure>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetWikiStructure<TData = Awaited<ReturnType<typeof getWikiStructure>>, TError = void>(
+ params?: GetWikiStructureParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiStructure>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetWikiStructure<TData = Awaited<ReturnType<typeof getWikiStructure>>, TError = void>(
+ params?: GetWikiStructureParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiStructure>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetWikiStructureQueryOptions(params,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type getWikiTestCoverageResponse200 = {
+  data: WikiTestCoverageResponse
+  status: 200
+}
+
+export type getWikiTestCoverageResponse400 = {
+  data: void
+  status: 400
+}
+
+export type getWikiTestCoverageResponse500 = {
+  data: void
+  status: 500
+}
+    
+export type getWikiTestCoverageResponseSuccess = (getWikiTestCoverageResponse200) & {
+  headers: Headers;
+};
+export type getWikiTestCoverageResponseError = (getWikiTestCoverageResponse400 | getWikiTestCoverageResponse500) & {
+  headers: Headers;
+};
+
+export type getWikiTestCoverageResponse = (getWikiTestCoverageResponseSuccess | getWikiTestCoverageResponseError)
+
+export const getGetWikiTestCoverageUrl = (params?: GetWikiTestCoverageParams,) => {
+  const normalizedParams = new URLSearchParams();
+
+  Object.entries(params || {}).forEach(([key, value]) => {
+    
+    if (value !== undefined) {
+      normalizedParams.append(key, value === null ? 'null' : value.toString())
+    }
+  });
+
+  const stringifiedParams = normalizedParams.toString();
+
+  return stringifiedParams.length > 0 ? `/api/wiki/test-coverage?${stringifiedParams}` : `/api/wiki/test-coverage`
+}
+
+export const getWikiTestCoverage = async (params?: GetWikiTestCoverageParams, options?: RequestInit): Promise<getWikiTestCoverageResponse> => {
+  
+  return customFetch<getWikiTestCoverageResponse>(getGetWikiTestCoverageUrl(params),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetWikiTestCoverageQueryKey = (params?: GetWikiTestCoverageParams,) => {
+    return [
+    `/api/wiki/test-coverage`, ...(params ? [params]: [])
+    ] as const;
+    }
+
+    
+export const getGetWikiTestCoverageQueryOptions = <TData = Awaited<ReturnType<typeof getWikiTestCoverage>>, TError = void>(params?: GetWikiTestCoverageParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiTestCoverage>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetWikiTestCoverageQueryKey(params);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getWikiTestCoverage>>> = ({ signal }) => getWikiTestCoverage(params, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getWikiTestCoverage>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetWikiTestCoverageQueryResult = NonNullable<Awaited<ReturnType<typeof getWikiTestCoverage>>>
+export type GetWikiTestCoverageQueryError = void
+
+
+export function useGetWikiTestCoverage<TData = Awaited<ReturnType<typeof getWikiTestCoverage>>, TError = void>(
+ params: undefined |  GetWikiTestCoverageParams, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiTestCoverage>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getWikiTestCoverage>>,
+          TError,
+          Awaited<ReturnType<typeof getWikiTestCoverage>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetWikiTestCoverage<TData = Awaited<ReturnType<typeof getWikiTestCoverage>>, TError = void>(
+ params?: GetWikiTestCoverageParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiTestCoverage>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getWikiTestCoverage>>,
+          TError,
+          Awaited<ReturnType<typeof getWikiTestCoverage>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetWikiTestCoverage<TData = Awaited<ReturnType<typeof getWikiTestCoverage>>, TError = void>(
+ params?: GetWikiTestCoverageParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiTestCoverage>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetWikiTestCoverage<TData = Awaited<ReturnType<typeof getWikiTestCoverage>>, TError = void>(
+ params?: GetWikiTestCoverageParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiTestCoverage>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetWikiTestCoverageQueryOptions(params,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type traceFlowResponse200 = {
+  data: TraceFlowResponse
+  status: 200
+}
+
+export type traceFlowResponse400 = {
+  data: void
+  status: 400
+}
+
+export type traceFlowResponse404 = {
+  data: void
+  status: 404
+}
+
+export type traceFlowResponse500 = {
+  data: void
+  status: 500
+}
+    
+export type traceFlowResponseSuccess = (traceFlowResponse200) & {
+  headers: Headers;
+};
+export type traceFlowResponseError = (traceFlowResponse400 | traceFlowResponse404 | traceFlowResponse500) & {
+  headers: Headers;
+};
+
+export type traceFlowResponse = (traceFlowResponseSuccess | traceFlowResponseError)
+
+export const getTraceFlowUrl = () => {
+
+
+  
+
+  return `/api/wiki/trace-flow`
+}
+
+export const traceFlow = async (traceFlowRequest: TraceFlowRequest, options?: RequestInit): Promise<traceFlowResponse> => {
+  
+  return customFetch<traceFlowResponse>(getTraceFlowUrl(),
+  {      
+    ...options,
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      traceFlowRequest,)
+  }
+);}
+
+
+
+
+export const getTraceFlowMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof traceFlow>>, TError,{data: TraceFlowRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof traceFlow>>, TError,{data: TraceFlowRequest}, TContext> => {
+
+const mutationKey = ['traceFlow'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof traceFlow>>, {data: TraceFlowRequest}> = (props) => {
+          const {data} = props ?? {};
+
+          return  traceFlow(data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type TraceFlowMutationResult = NonNullable<Awaited<ReturnType<typeof traceFlow>>>
+    export type TraceFlowMutationBody = TraceFlowRequest
+    export type TraceFlowMutationError = void
+
+    export const useTraceFlow = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof traceFlow>>, TError,{data: TraceFlowRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof traceFlow>>,
+        TError,
+        {data: TraceFlowRequest},
+        TContext
+      > => {
+
+      const mutationOptions = getTraceFlowMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type handlePushWebhookResponse200 = {
+  data: WebhookResponse
+  status: 200
+}
+
+export type handlePushWebhookResponse400 = {
+  data: void
+  status: 400
+}
+    
+export type handlePushWebhookResponseSuccess = (handlePushWebhookResponse200) & {
+  headers: Headers;
+};
+export type handlePushWebhookResponseError = (handlePushWebhookResponse400) & {
+  headers: Headers;
+};
+
+export type handlePushWebhookResponse = (handlePushWebhookResponseSuccess | handlePushWebhookResponseError)
+
+export const getHandlePushWebhookUrl = () => {
+
+
+  
+
+  return `/api/wiki/webhook/push`
+}
+
+export const handlePushWebhook = async (webhookPushRequest: WebhookPushRequest, options?: RequestInit): Promise<handlePushWebhookResponse> => {
+  
+  return customFetch<handlePushWebhookResponse>(getHandlePushWebhookUrl(),
+  {      
+    ...options,
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      webhookPushRequest,)
+  }
+);}
+
+
+
+
+export const getHandlePushWebhookMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof handlePushWebhook>>, TError,{data: WebhookPushRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof handlePushWebhook>>, TError,{data: WebhookPushRequest}, TContext> => {
+
+const mutationKey = ['handlePushWebhook'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof handlePushWebhook>>, {data: WebhookPushRequest}> = (props) => {
+          const {data} = props ?? {};
+
+          return  handlePushWebhook(data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type HandlePushWebhookMutationResult = NonNullable<Awaited<ReturnType<typeof handlePushWebhook>>>
+    export type HandlePushWebhookMutationBody = WebhookPushRequest
+    export type HandlePushWebhookMutationError = void
+
+    export const useHandlePushWebhook = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof handlePushWebhook>>, TError,{data: WebhookPushRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof handlePushWebhook>>,
+        TError,
+        {data: WebhookPushRequest},
+        TContext
+      > => {
+
+      const mutationOptions = getHandlePushWebhookMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type handlePullRequestWebhookResponse200 = {
   data: WebhookResponse
   status: 200
 }
 
-export type handlePushWebhookResponse400 = {
+export type handlePullRequestWebhookResponse400 = {
+  data: void
+  status: 400
+}
+    
+export type handlePullRequestWebhookResponseSuccess = (handlePullRequestWebhookResponse200) & {
+  headers: Headers;
+};
+export type handlePullRequestWebhookResponseError = (handlePullRequestWebhookResponse400) & {
+  headers: Headers;
+};
+
+export type handlePullRequestWebhookResponse = (handlePullRequestWebhookResponseSuccess | handlePullRequestWebhookResponseError)
+
+export const getHandlePullRequestWebhookUrl = () => {
+
+
+  
+
+  return `/api/wiki/webhook/pull-request`
+}
+
+export const handlePullRequestWebhook = async (webhookPullRequestEvent: WebhookPullRequestEvent, options?: RequestInit): Promise<handlePullRequestWebhookResponse> => {
+  
+  return customFetch<handlePullRequestWebhookResponse>(getHandlePullRequestWebhookUrl(),
+  {      
+    ...options,
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      webhookPullRequestEvent,)
+  }
+);}
+
+
+
+
+export const getHandlePullRequestWebhookMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof handlePullRequestWebhook>>, TError,{data: WebhookPullRequestEvent}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof handlePullRequestWebhook>>, TError,{data: WebhookPullRequestEvent}, TContext> => {
+
+const mutationKey = ['handlePullRequestWebhook'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof handlePullRequestWebhook>>, {data: WebhookPullRequestEvent}> = (props) => {
+          const {data} = props ?? {};
+
+          return  handlePullRequestWebhook(data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type HandlePullRequestWebhookMutationResult = NonNullable<Awaited<ReturnType<typeof handlePullRequestWebhook>>>
+    export type HandlePullRequestWebhookMutationBody = WebhookPullRequestEvent
+    export type HandlePullRequestWebhookMutationError = void
+
+    export const useHandlePullRequestWebhook = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof handlePullRequestWebhook>>, TError,{data: WebhookPullRequestEvent}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof handlePullRequestWebhook>>,
+        TError,
+        {data: WebhookPullRequestEvent},
+        TContext
+      > => {
+
+      const mutationOptions = getHandlePullRequestWebhookMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type getWikiFileChunksResponse200 = {
+  data: WikiFileChunksResponse
+  status: 200
+}
+
+export type getWikiFileChunksResponse400 = {
+  data: void
+  status: 400
+}
+
+export type getWikiFileChunksResponse404 = {
+  data: void
+  status: 404
+}
+
+export type getWikiFileChunksResponse500 = {
+  data: void
+  status: 500
+}
+    
+export type getWikiFileChunksResponseSuccess = (getWikiFileChunksResponse200) & {
+  headers: Headers;
+};
+export type getWikiFileChunksResponseError = (getWikiFileChunksResponse400 | getWikiFileChunksResponse404 | getWikiFileChunksResponse500) & {
+  headers: Headers;
+};
+
+export type getWikiFileChunksResponse = (getWikiFileChunksResponseSuccess | getWikiFileChunksResponseError)
+
+export const getGetWikiFileChunksUrl = (path: string,
+    params?: GetWikiFileChunksParams,) => {
+  const normalizedParams = new URLSearchParams();
+
+  Object.entries(params || {}).forEach(([key, value]) => {
+    
+    if (value !== undefined) {
+      normalizedParams.append(key, value === null ? 'null' : value.toString())
+    }
+  });
+
+  const stringifiedParams = normalizedParams.toString();
+
+  return stringifiedParams.length > 0 ? `/api/wiki/files/${path}/chunks?${stringifiedParams}` : `/api/wiki/files/${path}/chunks`
+}
+
+export const getWikiFileChunks = async (path: string,
+    params?: GetWikiFileChunksParams, options?: RequestInit): Promise<getWikiFileChunksResponse> => {
+  
+  return customFetch<getWikiFileChunksResponse>(getGetWikiFileChunksUrl(path,params),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetWikiFileChunksQueryKey = (path?: string,
+    params?: GetWikiFileChunksParams,) => {
+    return [
+    `/api/wiki/files/${path}/chunks`, ...(params ? [params]: [])
+    ] as const;
+    }
+
+    
+export const getGetWikiFileChunksQueryOptions = <TData = Awaited<ReturnType<typeof getWikiFileChunks>>, TError = void>(path: string,
+    params?: GetWikiFileChunksParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiFileChunks>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetWikiFileChunksQueryKey(path,params);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getWikiFileChunks>>> = ({ signal }) => getWikiFileChunks(path,params, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, enabled: !!(path), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getWikiFileChunks>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetWikiFileChunksQueryResult = NonNullable<Awaited<ReturnType<typeof getWikiFileChunks>>>
+export type GetWikiFileChunksQueryError = void
+
+
+export function useGetWikiFileChunks<TData = Awaited<ReturnType<typeof getWikiFileChunks>>, TError = void>(
+ path: string,
+    params: undefined |  GetWikiFileChunksParams, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiFileChunks>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getWikiFileChunks>>,
+          TError,
+          Awaited<ReturnType<typeof getWikiFileChunks>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetWikiFileChunks<TData = Awaited<ReturnType<typeof getWikiFileChunks>>, TError = void>(
+ path: string,
+    params?: GetWikiFileChunksParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiFileChunks>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getWikiFileChunks>>,
+          TError,
+          Awaited<ReturnType<typeof getWikiFileChunks>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetWikiFileChunks<TData = Awaited<ReturnType<typeof getWikiFileChunks>>, TError = void>(
+ path: string,
+    params?: GetWikiFileChunksParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiFileChunks>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetWikiFileChunks<TData = Awaited<ReturnType<typeof getWikiFileChunks>>, TError = void>(
+ path: string,
+    params?: GetWikiFileChunksParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiFileChunks>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetWikiFileChunksQueryOptions(path,params,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type getWikiCompareResponse200 = {
+  data: WikiCompareResponse
+  status: 200
+}
+
+export type getWikiCompareResponse400 = {
+  data: void
+  status: 400
+}
+
+export type getWikiCompareResponse404 = {
+  data: void
+  status: 404
+}
+
+export type getWikiCompareResponse500 = {
+  data: void
+  status: 500
+}
+    
+export type getWikiCompareResponseSuccess = (getWikiCompareResponse200) & {
+  headers: Headers;
+};
+export type getWikiCompareResponseError = (getWikiCompareResponse400 | getWikiCompareResponse404 | getWikiCompareResponse500) & {
+  headers: Headers;
+};
+
+export type getWikiCompareResponse = (getWikiCompareResponseSuccess | getWikiCompareResponseError)
+
+export const getGetWikiCompareUrl = (params: GetWikiCompareParams,) => {
+  const normalizedParams = new URLSearchParams();
+
+  Object.entries(params || {}).forEach(([key, value]) => {
+    
+    if (value !== undefined) {
+      normalizedParams.append(key, value === null ? 'null' : value.toString())
+    }
+  });
+
+  const stringifiedParams = normalizedParams.toString();
+
+  return stringifiedParams.length > 0 ? `/api/wiki/compare?${stringifiedParams}` : `/api/wiki/compare`
+}
+
+export const getWikiCompare = async (params: GetWikiCompareParams, options?: RequestInit): Promise<getWikiCompareResponse> => {
+  
+  return customFetch<getWikiCompareResponse>(getGetWikiCompareUrl(params),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetWikiCompareQueryKey = (params?: GetWikiCompareParams,) => {
+    return [
+    `/api/wiki/compare`, ...(params ? [params]: [])
+    ] as const;
+    }
+
+    
+export const getGetWikiCompareQueryOptions = <TData = Awaited<ReturnType<typeof getWikiCompare>>, TError = void>(params: GetWikiCompareParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiCompare>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetWikiCompareQueryKey(params);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getWikiCompare>>> = ({ signal }) => getWikiCompare(params, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getWikiCompare>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetWikiCompareQueryResult = NonNullable<Awaited<ReturnType<typeof getWikiCompare>>>
+export type GetWikiCompareQueryError = void
+
+
+export function useGetWikiCompare<TData = Awaited<ReturnType<typeof getWikiCompare>>, TError = void>(
+ params: GetWikiCompareParams, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiCompare>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getWikiCompare>>,
+          TError,
+          Awaited<ReturnType<typeof getWikiCompare>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetWikiCompare<TData = Awaited<ReturnType<typeof getWikiCompare>>, TError = void>(
+ params: GetWikiCompareParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiCompare>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getWikiCompare>>,
+          TError,
+          Awaited<ReturnType<typeof getWikiCompare>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetWikiCompare<TData = Awaited<ReturnType<typeof getWikiCompare>>, TError = void>(
+ params: GetWikiCompareParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiCompare>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetWikiCompare<TData = Awaited<ReturnType<typeof getWikiCompare>>, TError = void>(
+ params: GetWikiCompareParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWikiCompare>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetWikiCompareQueryOptions(params,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type askWikiStreamResponse200 = {
+  data: void
+  status: 200
+}
+
+export type askWikiStreamResponse400 = {
   data: void
   status: 400
 }
+
+export type askWikiStreamResponse500 = {
+  data: void
+  status: 500
+}
     
-export type handlePushWebhookResponseSuccess = (handlePushWebhookResponse200) & {
+export type askWikiStreamResponseSuccess = (askWikiStreamResponse200) & {
   headers: Headers;
 };
-export type handlePushWebhookResponseError = (handlePushWebhookResponse400) & {
+export type askWikiStreamResponseError = (askWikiStreamResponse400 | askWikiStreamResponse500) & {
   headers: Headers;
 };
 
-export type handlePushWebhookResponse = (handlePushWebhookResponseSuccess | handlePushWebhookResponseError)
+export type askWikiStreamResponse = (askWikiStreamResponseSuccess | askWikiStreamResponseError)
 
-export const getHandlePushWebhookUrl = () => {
+export const getAskWikiStreamUrl = () => {
 
 
   
 
-  return `/api/wiki/webhook/push`
+  return `/api/wiki/ask/stream`
 }
 
-export const handlePushWebhook = async (webhookPushRequest: WebhookPushRequest, options?: RequestInit): Promise<handlePushWebhookResponse> => {
+export const askWikiStream = async (askRequest: AskRequest, options?: RequestInit): Promise<askWikiStreamResponse> => {
   
-  return customFetch<handlePushWebhookResponse>(getHandlePushWebhookUrl(),
+  return customFetch<askWikiStreamResponse>(getAskWikiStreamUrl(),
   {      
     ...options,
     method: 'POST',
     headers: { 'Content-Type': 'application/json', ...options?.headers },
     body: JSON.stringify(
-      webhookPushRequest,)
+      askRequest,)
   }
 );}
 
 
 
 
-export const getHandlePushWebhookMutationOptions = <TError = void,
-    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof handlePushWebhook>>, TError,{data: WebhookPushRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
-): UseMutationOptions<Awaited<ReturnType<typeof handlePushWebhook>>, TError,{data: WebhookPushRequest}, TContext> => {
+export const getAskWikiStreamMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof askWikiStream>>, TError,{data: AskRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof askWikiStream>>, TError,{data: AskRequest}, TContext> => {
 
-const mutationKey = ['handlePushWebhook'];
+const mutationKey = ['askWikiStream'];
 const {mutation: mutationOptions, request: requestOptions} = options ?
       options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
       options
@@ -930,10 +2365,10 @@ const {mutation: mutationOptions, request: requestOptions} = options ?
       
 
 
-      const mutationFn: MutationFunction<Awaited<ReturnType<typeof handlePushWebhook>>, {data: WebhookPushRequest}> = (props) => {
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof askWikiStream>>, {data: AskRequest}> = (props) => {
           const {data} = props ?? {};
 
-          return  handlePushWebhook(data,requestOptions)
+          return  askWikiStream(data,requestOptions)
         }
 
         
@@ -941,20 +2376,293 @@ const {mutation: mutationOptions, request: requestOptions} = options ?
 
   return  { mutationFn, ...mutationOptions }}
 
-    export type HandlePushWebhookMutationResult = NonNullable<Awaited<ReturnType<typeof handlePushWebhook>>>
-    export type HandlePushWebhookMutationBody = WebhookPushRequest
-    export type HandlePushWebhookMutationError = void
+    export type AskWikiStreamMutationResult = NonNullable<Awaited<ReturnType<typeof askWikiStream>>>
+    export type AskWikiStreamMutationBody = AskRequest
+    export type AskWikiStreamMutationError = void
 
-    export const useHandlePushWebhook = <TError = void,
-    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof handlePushWebhook>>, TError,{data: WebhookPushRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+    export const useAskWikiStream = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof askWikiStream>>, TError,{data: AskRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient): UseMutationResult<
-        Awaited<ReturnType<typeof handlePushWebhook>>,
+        Awaited<ReturnType<typeof askWikiStream>>,
         TError,
-        {data: WebhookPushRequest},
+        {data: AskRequest},
         TContext
       > => {
 
-      const mutationOptions = getHandlePushWebhookMutationOptions(options);
+      const mutationOptions = getAskWikiStreamMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type estimateIndexingResponse200 = {
+  data: IndexEstimateResponse
+  status: 200
+}
+
+export type estimateIndexingResponse500 = {
+  data: void
+  status: 500
+}
+    
+export type estimateIndexingResponseSuccess = (estimateIndexingResponse200) & {
+  headers: Headers;
+};
+export type estimateIndexingResponseError = (estimateIndexingResponse500) & {
+  headers: Headers;
+};
+
+export type estimateIndexingResponse = (estimateIndexingResponseSuccess | estimateIndexingResponseError)
+
+export const getEstimateIndexingUrl = () => {
+
+
+  
+
+  return `/api/wiki/index/estimate`
+}
+
+export const estimateIndexing = async (indexEstimateRequest: IndexEstimateRequest, options?: RequestInit): Promise<estimateIndexingResponse> => {
+  
+  return customFetch<estimateIndexingResponse>(getEstimateIndexingUrl(),
+  {      
+    ...options,
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      indexEstimateRequest,)
+  }
+);}
+
+
+
+
+export const getEstimateIndexingMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof estimateIndexing>>, TError,{data: IndexEstimateRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof estimateIndexing>>, TError,{data: IndexEstimateRequest}, TContext> => {
+
+const mutationKey = ['estimateIndexing'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof estimateIndexing>>, {data: IndexEstimateRequest}> = (props) => {
+          const {data} = props ?? {};
+
+          return  estimateIndexing(data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type EstimateIndexingMutationResult = NonNullable<Awaited<ReturnType<typeof estimateIndexing>>>
+    export type EstimateIndexingMutationBody = IndexEstimateRequest
+    export type EstimateIndexingMutationError = void
+
+    export const useEstimateIndexing = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof estimateIndexing>>, TError,{data: IndexEstimateRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof estimateIndexing>>,
+        TError,
+        {data: IndexEstimateRequest},
+        TContext
+      > => {
+
+      const mutationOptions = getEstimateIndexingMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type regenerateWikiPageResponse200 = {
+  data: WikiPageResponse
+  status: 200
+}
+
+export type regenerateWikiPageResponse400 = {
+  data: void
+  status: 400
+}
+
+export type regenerateWikiPageResponse404 = {
+  data: void
+  status: 404
+}
+
+export type regenerateWikiPageResponse409 = {
+  data: void
+  status: 409
+}
+
+export type regenerateWikiPageResponse500 = {
+  data: void
+  status: 500
+}
+    
+export type regenerateWikiPageResponseSuccess = (regenerateWikiPageResponse200) & {
+  headers: Headers;
+};
+export type regenerateWikiPageResponseError = (regenerateWikiPageResponse400 | regenerateWikiPageResponse404 | regenerateWikiPageResponse409 | regenerateWikiPageResponse500) & {
+  headers: Headers;
+};
+
+export type regenerateWikiPageResponse = (regenerateWikiPageResponseSuccess | regenerateWikiPageResponseError)
+
+export const getRegenerateWikiPageUrl = (slug: string,) => {
+
+
+  
+
+  return `/api/wiki/pages/${slug}/regenerate`
+}
+
+export const regenerateWikiPage = async (slug: string,
+    generateWikiPageRequest: GenerateWikiPageRequest, options?: RequestInit): Promise<regenerateWikiPageResponse> => {
+  
+  return customFetch<regenerateWikiPageResponse>(getRegenerateWikiPageUrl(slug),
+  {      
+    ...options,
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      generateWikiPageRequest,)
+  }
+);}
+
+
+
+
+export const getRegenerateWikiPageMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof regenerateWikiPage>>, TError,{slug: string;data: GenerateWikiPageRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof regenerateWikiPage>>, TError,{slug: string;data: GenerateWikiPageRequest}, TContext> => {
+
+const mutationKey = ['regenerateWikiPage'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof regenerateWikiPage>>, {slug: string;data: GenerateWikiPageRequest}> = (props) => {
+          const {slug,data} = props ?? {};
+
+          return  regenerateWikiPage(slug,data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type RegenerateWikiPageMutationResult = NonNullable<Awaited<ReturnType<typeof regenerateWikiPage>>>
+    export type RegenerateWikiPageMutationBody = GenerateWikiPageRequest
+    export type RegenerateWikiPageMutationError = void
+
+    export const useRegenerateWikiPage = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof regenerateWikiPage>>, TError,{slug: string;data: GenerateWikiPageRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof regenerateWikiPage>>,
+        TError,
+        {slug: string;data: GenerateWikiPageRequest},
+        TContext
+      > => {
+
+      const mutationOptions = getRegenerateWikiPageMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type tuneWikiChunksResponse200 = {
+  data: ChunkTuningResponse
+  status: 200
+}
+
+export type tuneWikiChunksResponse400 = {
+  data: void
+  status: 400
+}
+
+export type tuneWikiChunksResponse500 = {
+  data: void
+  status: 500
+}
+    
+export type tuneWikiChunksResponseSuccess = (tuneWikiChunksResponse200) & {
+  headers: Headers;
+};
+export type tuneWikiChunksResponseError = (tuneWikiChunksResponse400 | tuneWikiChunksResponse500) & {
+  headers: Headers;
+};
+
+export type tuneWikiChunksResponse = (tuneWikiChunksResponseSuccess | tuneWikiChunksResponseError)
+
+export const getTuneWikiChunksUrl = () => {
+
+
+  
+
+  return `/api/wiki/chunk-tuning`
+}
+
+export const tuneWikiChunks = async (chunkTuningRequest: ChunkTuningRequest, options?: RequestInit): Promise<tuneWikiChunksResponse> => {
+  
+  return customFetch<tuneWikiChunksResponse>(getTuneWikiChunksUrl(),
+  {      
+    ...options,
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      chunkTuningRequest,)
+  }
+);}
+
+
+
+
+export const getTuneWikiChunksMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof tuneWikiChunks>>, TError,{data: ChunkTuningRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof tuneWikiChunks>>, TError,{data: ChunkTuningRequest}, TContext> => {
+
+const mutationKey = ['tuneWikiChunks'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof tuneWikiChunks>>, {data: ChunkTuningRequest}> = (props) => {
+          const {data} = props ?? {};
+
+          return  tuneWikiChunks(data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type TuneWikiChunksMutationResult = NonNullable<Awaited<ReturnType<typeof tuneWikiChunks>>>
+    export type TuneWikiChunksMutationBody = ChunkTuningRequest
+    export type TuneWikiChunksMutationError = void
+
+    export const useTuneWikiChunks = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof tuneWikiChunks>>, TError,{data: ChunkTuningRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof tuneWikiChunks>>,
+        TError,
+        {data: ChunkTuningRequest},
+        TContext
+      > => {
+
+      const mutationOptions = getTuneWikiChunksMutationOptions(options);
 
       return useMutation(mutationOptions, queryClient);
     }
@@ -25,11 +25,19 @@ import type {
 } from '@tanstack/react-query';
 
 import type {
+  CommitResolutionRequest,
+  CommitResolutionResponse,
+  ConflictsResponse,
+  CreateWorkspacePrRequest,
   DiffResponse,
+  DiffSummaryResponse,
+  ListWorkspacesParams,
   MergeRequest,
   MergeResponse,
+  ResolveConflictRequest,
   SetViewedRequest,
   ViewedFilesResponse,
+  WorkspacePrResponse,
   WorkspaceResponse,
   WorkspaceStatusResponse
 } from '.././model';
@@ -320,17 +328,24 @@ export type listWorkspacesResponseSuccess = (listWorkspacesResponse200) & {
 
 export type listWorkspacesResponse = (listWorkspacesResponseSuccess)
 
-export const getListWorkspacesUrl = () => {
+export const getListWorkspacesUrl = (params?: ListWorkspacesParams,) => {
+  const normalizedParams = new URLSearchParams();
 
+  Object.entries(params || {}).forEach(([key, value]) => {
+    
+    if (value !== undefined) {
+      normalizedParams.append(key, value === null ? 'null' : value.toString())
+    }
+  });
 
-  
+  const stringifiedParams = normalizedParams.toString();
 
-  return `/api/workspaces`
+  return stringifiedParams.length > 0 ? `/api/workspaces?${stringifiedParams}` : `/api/workspaces`
 }
 
-export const listWorkspaces = async ( options?: RequestInit): Promise<listWorkspacesResponse> => {
+export const listWorkspaces = async (params?: ListWorkspacesParams, options?: RequestInit): Promise<listWorkspacesResponse> => {
   
-  return customFetch<listWorkspacesResponse>(getListWorkspacesUrl(),
+  return customFetch<listWorkspacesResponse>(getListWorkspacesUrl(params),
   {      
     ...options,
     method: 'GET'
@@ -343,23 +358,23 @@ export const listWorkspaces = async ( options?: RequestInit): Promise<listWorksp
 
 
 
-export const getListWorkspacesQueryKey = () => {
+export const getListWorkspacesQueryKey = (params?: ListWorkspacesParams,) => {
     return [
-    `/api/workspaces`
+    `/api/workspaces`, ...(params ? [params]: [])
     ] as const;
     }
 
     
-export const getListWorkspacesQueryOptions = <TData = Awaited<ReturnType<typeof listWorkspaces>>, TError = unknown>( options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listWorkspaces>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+export const getListWorkspacesQueryOptions = <TData = Awaited<ReturnType<typeof listWorkspaces>>, TError = unknown>(params?: ListWorkspacesParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listWorkspaces>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
 ) => {
 
 const {query: queryOptions, request: requestOptions} = options ?? {};
 
-  const queryKey =  queryOptions?.queryKey ?? getListWorkspacesQueryKey();
+  const queryKey =  queryOptions?.queryKey ?? getListWorkspacesQueryKey(params);
 
   
 
-    const queryFn: QueryFunction<Awaited<ReturnType<typeof listWorkspaces>>> = ({ signal }) => listWorkspaces({ signal, ...requestOptions });
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof listWorkspaces>>> = ({ signal }) => listWorkspaces(params, { signal, ...requestOptions });
 
       
 
@@ -373,7 +388,7 @@ export type ListWorkspacesQueryError = unknown
 
 
 export function useListWorkspaces<TData = Awaited<ReturnType<typeof listWorkspaces>>, TError = unknown>(
-  options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof listWorkspaces>>, TError, TData>> & Pick<
+ params: undefined |  ListWorkspacesParams, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof listWorkspaces>>, TError, TData>> & Pick<
         DefinedInitialDataOptions<
           Awaited<ReturnType<typeof listWorkspaces>>,
           TError,
@@ -383,7 +398,7 @@ export function useListWorkspaces<TData = Awaited<ReturnType<typeof listWorkspac
  , queryClient?: QueryClient
   ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
 export function useListWorkspaces<TData = Awaited<ReturnType<typeof listWorkspaces>>, TError = unknown>(
-  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listWorkspaces>>, TError, TData>> & Pick<
+ params?: ListWorkspacesParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listWorkspaces>>, TError, TData>> & Pick<
         UndefinedInitialDataOptions<
           Awaited<ReturnType<typeof listWorkspaces>>,
           TError,
@@ -393,16 +408,16 @@ export function useListWorkspaces<TData = Awaited<ReturnType<typeof listWorkspac
  , queryClient?: QueryClient
   ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
 export function useListWorkspaces<TData = Awaited<ReturnType<typeof listWorkspaces>>, TError = unknown>(
-  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listWorkspaces>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ params?: ListWorkspacesParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listWorkspaces>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient
   ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
 
 export function useListWorkspaces<TData = Awaited<ReturnType<typeof listWorkspaces>>, TError = unknown>(
-  options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listWorkspaces>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ params?: ListWorkspacesParams, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof listWorkspaces>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
  , queryClient?: QueryClient 
  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
 
-  const queryOptions = getListWorkspacesQueryOptions(options)
+  const queryOptions = getListWorkspacesQueryOptions(params,options)
 
   const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
 
@@ -808,4 +823,520 @@ const {mutation: mutationOptions, request: requestOptions} = options ?
 
       return useMutation(mutationOptions, queryClient);
     }
+    export type getWorkspaceDiffSummaryResponse200 = {
+  data: DiffSummaryResponse
+  status: 200
+}
+
+export type getWorkspaceDiffSummaryResponse400 = {
+  data: void
+  status: 400
+}
+
+export type getWorkspaceDiffSummaryResponse404 = {
+  data: void
+  status: 404
+}
+
+export type getWorkspaceDiffSummaryResponse500 = {
+  data: void
+  status: 500
+}
+    
+export type getWorkspaceDiffSummaryResponseSuccess = (getWorkspaceDiffSummaryResponse200) & {
+  headers: Headers;
+};
+export type getWorkspaceDiffSummaryResponseError = (getWorkspaceDiffSummaryResponse400 | getWorkspaceDiffSummaryResponse404 | getWorkspaceDiffSummaryResponse500) & {
+  headers: Headers;
+};
+
+export type getWorkspaceDiffSummaryResponse = (getWorkspaceDiffSummaryResponseSuccess | getWorkspaceDiffSummaryResponseError)
+
+export const getGetWorkspaceDiffSummaryUrl = (taskId: string,) => {
+
+
+  
+
+  return `/api/workspaces/${taskId}/diff/summary`
+}
+
+export const getWorkspaceDiffSummary = async (taskId: string, options?: RequestInit): Promise<getWorkspaceDiffSummaryResponse> => {
+  
+  return customFetch<getWorkspaceDiffSummaryResponse>(getGetWorkspaceDiffSummaryUrl(taskId),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetWorkspaceDiffSummaryQueryKey = (taskId?: string,) => {
+    return [
+    `/api/workspaces/${taskId}/diff/summary`
+    ] as const;
+    }
+
+    
+export const getGetWorkspaceDiffSummaryQueryOptions = <TData = Awaited<ReturnType<typeof getWorkspaceDiffSummary>>, TError = void>(taskId: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWorkspaceDiffSummary>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetWorkspaceDiffSummaryQueryKey(taskId);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getWorkspaceDiffSummary>>> = ({ signal }) => getWorkspaceDiffSummary(taskId, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, enabled: !!(taskId), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getWorkspaceDiffSummary>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetWorkspaceDiffSummaryQueryResult = NonNullable<Awaited<ReturnType<typeof getWorkspaceDiffSummary>>>
+export type GetWorkspaceDiffSummaryQueryError = void
+
+
+export function useGetWorkspaceDiffSummary<TData = Awaited<ReturnType<typeof getWorkspaceDiffSummary>>, TError = void>(
+ taskId: string, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWorkspaceDiffSummary>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getWorkspaceDiffSummary>>,
+          TError,
+          Awaited<ReturnType<typeof getWorkspaceDiffSummary>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetWorkspaceDiffSummary<TData = Awaited<ReturnType<typeof getWorkspaceDiffSummary>>, TError = void>(
+ taskId: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWorkspaceDiffSummary>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getWorkspaceDiffSummary>>,
+          TError,
+          Awaited<ReturnType<typeof getWorkspaceDiffSummary>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetWorkspaceDiffSummary<TData = Awaited<ReturnType<typeof getWorkspaceDiffSummary>>, TError = void>(
+ taskId: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWorkspaceDiffSummary>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetWorkspaceDiffSummary<TData = Awaited<ReturnType<typeof getWorkspaceDiffSummary>>, TError = void>(
+ taskId: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWorkspaceDiffSummary>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetWorkspaceDiffSummaryQueryOptions(taskId,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type getWorkspaceConflictsResponse200 = {
+  data: ConflictsResponse
+  status: 200
+}
+
+export type getWorkspaceConflictsResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type getWorkspaceConflictsResponseSuccess = (getWorkspaceConflictsResponse200) & {
+  headers: Headers;
+};
+export type getWorkspaceConflictsResponseError = (getWorkspaceConflictsResponse404) & {
+  headers: Headers;
+};
+
+export type getWorkspaceConflictsResponse = (getWorkspaceConflictsResponseSuccess | getWorkspaceConflictsResponseError)
+
+export const getGetWorkspaceConflictsUrl = (taskId: string,) => {
+
+
+  
+
+  return `/api/workspaces/${taskId}/conflicts`
+}
+
+export const getWorkspaceConflicts = async (taskId: string, options?: RequestInit): Promise<getWorkspaceConflictsResponse> => {
+  
+  return customFetch<getWorkspaceConflictsResponse>(getGetWorkspaceConflictsUrl(taskId),
+  {      
+    ...options,
+    method: 'GET'
+    
+    
+  }
+);}
+
+
+
+
+
+export const getGetWorkspaceConflictsQueryKey = (taskId?: string,) => {
+    return [
+    `/api/workspaces/${taskId}/conflicts`
+    ] as const;
+    }
+
+    
+export const getGetWorkspaceConflictsQueryOptions = <TData = Awaited<ReturnType<typeof getWorkspaceConflicts>>, TError = void>(taskId: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWorkspaceConflicts>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+) => {
+
+const {query: queryOptions, request: requestOptions} = options ?? {};
+
+  const queryKey =  queryOptions?.queryKey ?? getGetWorkspaceConflictsQueryKey(taskId);
+
+  
+
+    const queryFn: QueryFunction<Awaited<ReturnType<typeof getWorkspaceConflicts>>> = ({ signal }) => getWorkspaceConflicts(taskId, { signal, ...requestOptions });
+
+      
+
+      
+
+   return  { queryKey, queryFn, enabled: !!(taskId), ...queryOptions} as UseQueryOptions<Awaited<ReturnType<typeof getWorkspaceConflicts>>, TError, TData> & { queryKey: DataTag<QueryKey, TData, TError> }
+}
+
+export type GetWorkspaceConflictsQueryResult = NonNullable<Awaited<ReturnType<typeof getWorkspaceConflicts>>>
+export type GetWorkspaceConflictsQueryError = void
+
+
+export function useGetWorkspaceConflicts<TData = Awaited<ReturnType<typeof getWorkspaceConflicts>>, TError = void>(
+ taskId: string, options: { query:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWorkspaceConflicts>>, TError, TData>> & Pick<
+        DefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getWorkspaceConflicts>>,
+          TError,
+          Awaited<ReturnType<typeof getWorkspaceConflicts>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  DefinedUseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetWorkspaceConflicts<TData = Awaited<ReturnType<typeof getWorkspaceConflicts>>, TError = void>(
+ taskId: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWorkspaceConflicts>>, TError, TData>> & Pick<
+        UndefinedInitialDataOptions<
+          Awaited<ReturnType<typeof getWorkspaceConflicts>>,
+          TError,
+          Awaited<ReturnType<typeof getWorkspaceConflicts>>
+        > , 'initialData'
+      >, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+export function useGetWorkspaceConflicts<TData = Awaited<ReturnType<typeof getWorkspaceConflicts>>, TError = void>(
+ taskId: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWorkspaceConflicts>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient
+  ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> }
+
+export function useGetWorkspaceConflicts<TData = Awaited<ReturnType<typeof getWorkspaceConflicts>>, TError = void>(
+ taskId: string, options?: { query?:Partial<UseQueryOptions<Awaited<ReturnType<typeof getWorkspaceConflicts>>, TError, TData>>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient 
+ ):  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> } {
+
+  const queryOptions = getGetWorkspaceConflictsQueryOptions(taskId,options)
+
+  const query = useQuery(queryOptions, queryClient) as  UseQueryResult<TData, TError> & { queryKey: DataTag<QueryKey, TData, TError> };
+
+  query.queryKey = queryOptions.queryKey ;
+
+  return query;
+}
+
+
+
+
+export type resolveWorkspaceConflictResponse200 = {
+  data: ConflictsResponse
+  status: 200
+}
+
+export type resolveWorkspaceConflictResponse400 = {
+  data: void
+  status: 400
+}
+
+export type resolveWorkspaceConflictResponse404 = {
+  data: void
+  status: 404
+}
+    
+export type resolveWorkspaceConflictResponseSuccess = (resolveWorkspaceConflictResponse200) & {
+  headers: Headers;
+};
+export type resolveWorkspaceConflictResponseError = (resolveWorkspaceConflictResponse400 | resolveWorkspaceConflictResponse404) & {
+  headers: Headers;
+};
+
+export type resolveWorkspaceConflictResponse = (resolveWorkspaceConflictResponseSuccess | resolveWorkspaceConflictResponseError)
+
+export const getResolveWorkspaceConflictUrl = (taskId: string,) => {
+
+
+  
+
+  return `/api/workspaces/${taskId}/conflicts/resolve`
+}
+
+export const resolveWorkspaceConflict = async (taskId: string,
+    resolveConflictRequest: ResolveConflictRequest, options?: RequestInit): Promise<resolveWorkspaceConflictResponse> => {
+  
+  return customFetch<resolveWorkspaceConflictResponse>(getResolveWorkspaceConflictUrl(taskId),
+  {      
+    ...options,
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      resolveConflictRequest,)
+  }
+);}
+
+
+
+
+export const getResolveWorkspaceConflictMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof resolveWorkspaceConflict>>, TError,{taskId: string;data: ResolveConflictRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof resolveWorkspaceConflict>>, TError,{taskId: string;data: ResolveConflictRequest}, TContext> => {
+
+const mutationKey = ['resolveWorkspaceConflict'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof resolveWorkspaceConflict>>, {taskId: string;data: ResolveConflictRequest}> = (props) => {
+          const {taskId,data} = props ?? {};
+
+          return  resolveWorkspaceConflict(taskId,data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type ResolveWorkspaceConflictMutationResult = NonNullable<Awaited<ReturnType<typeof resolveWorkspaceConflict>>>
+    export type ResolveWorkspaceConflictMutationBody = ResolveConflictRequest
+    export type ResolveWorkspaceConflictMutationError = void
+
+    export const useResolveWorkspaceConflict = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof resolveWorkspaceConflict>>, TError,{taskId: string;data: ResolveConflictRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof resolveWorkspaceConflict>>,
+        TError,
+        {taskId: string;data: ResolveConflictRequest},
+        TContext
+      > => {
+
+      const mutationOptions = getResolveWorkspaceConflictMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type commitWorkspaceResolutionResponse201 = {
+  data: CommitResolutionResponse
+  status: 201
+}
+
+export type commitWorkspaceResolutionResponse400 = {
+  data: void
+  status: 400
+}
+
+export type commitWorkspaceResolutionResponse404 = {
+  data: void
+  status: 404
+}
+
+export type commitWorkspaceResolutionResponse409 = {
+  data: void
+  status: 409
+}
+    
+export type commitWorkspaceResolutionResponseSuccess = (commitWorkspaceResolutionResponse201) & {
+  headers: Headers;
+};
+export type commitWorkspaceResolutionResponseError = (commitWorkspaceResolutionResponse400 | commitWorkspaceResolutionResponse404 | commitWorkspaceResolutionResponse409) & {
+  headers: Headers;
+};
+
+export type commitWorkspaceResolutionResponse = (commitWorkspaceResolutionResponseSuccess | commitWorkspaceResolutionResponseError)
+
+export const getCommitWorkspaceResolutionUrl = (taskId: string,) => {
+
+
+  
+
+  return `/api/workspaces/${taskId}/conflicts/commit`
+}
+
+export const commitWorkspaceResolution = async (taskId: string,
+    commitResolutionRequest: CommitResolutionRequest, options?: RequestInit): Promise<commitWorkspaceResolutionResponse> => {
+  
+  return customFetch<commitWorkspaceResolutionResponse>(getCommitWorkspaceResolutionUrl(taskId),
+  {      
+    ...options,
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      commitResolutionRequest,)
+  }
+);}
+
+
+
+
+export const getCommitWorkspaceResolutionMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof commitWorkspaceResolution>>, TError,{taskId: string;data: CommitResolutionRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof commitWorkspaceResolution>>, TError,{taskId: string;data: CommitResolutionRequest}, TContext> => {
+
+const mutationKey = ['commitWorkspaceResolution'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof commitWorkspaceResolution>>, {taskId: string;data: CommitResolutionRequest}> = (props) => {
+          const {taskId,data} = props ?? {};
+
+          return  commitWorkspaceResolution(taskId,data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type CommitWorkspaceResolutionMutationResult = NonNullable<Awaited<ReturnType<typeof commitWorkspaceResolution>>>
+    export type CommitWorkspaceResolutionMutationBody = CommitResolutionRequest
+    export type CommitWorkspaceResolutionMutationError = void
+
+    export const useCommitWorkspaceResolution = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof commitWorkspaceResolution>>, TError,{taskId: string;data: CommitResolutionRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof commitWorkspaceResolution>>,
+        TError,
+        {taskId: string;data: CommitResolutionRequest},
+        TContext
+      > => {
+
+      const mutationOptions = getCommitWorkspaceResolutionMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
+    export type createWorkspacePullRequestResponse201 = {
+  data: WorkspacePrResponse
+  status: 201
+}
+
+export type createWorkspacePullRequestResponse400 = {
+  data: void
+  status: 400
+}
+
+export type createWorkspacePullRequestResponse404 = {
+  data: void
+  status: 404
+}
+
+export type createWorkspacePullRequestResponse409 = {
+  data: void
+  status: 409
+}
+    
+export type createWorkspacePullRequestResponseSuccess = (createWorkspacePullRequestResponse201) & {
+  headers: Headers;
+};
+export type createWorkspacePullRequestResponseError = (createWorkspacePullRequestResponse400 | createWorkspacePullRequestResponse404 | createWorkspacePullRequestResponse409) & {
+  headers: Headers;
+};
+
+export type createWorkspacePullRequestResponse = (createWorkspacePullRequestResponseSuccess | createWorkspacePullRequestResponseError)
+
+export const getCreateWorkspacePullRequestUrl = (taskId: string,) => {
+
+
+  
+
+  return `/api/workspaces/${taskId}/pull-request`
+}
+
+export const createWorkspacePullRequest = async (taskId: string,
+    createWorkspacePrRequest: CreateWorkspacePrRequest, options?: RequestInit): Promise<createWorkspacePullRequestResponse> => {
+  
+  return customFetch<createWorkspacePullRequestResponse>(getCreateWorkspacePullRequestUrl(taskId),
+  {      
+    ...options,
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json', ...options?.headers },
+    body: JSON.stringify(
+      createWorkspacePrRequest,)
+  }
+);}
+
+
+
+
+export const getCreateWorkspacePullRequestMutationOptions = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof createWorkspacePullRequest>>, TError,{taskId: string;data: CreateWorkspacePrRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+): UseMutationOptions<Awaited<ReturnType<typeof createWorkspacePullRequest>>, TError,{taskId: string;data: CreateWorkspacePrRequest}, TContext> => {
+
+const mutationKey = ['createWorkspacePullRequest'];
+const {mutation: mutationOptions, request: requestOptions} = options ?
+      options.mutation && 'mutationKey' in options.mutation && options.mutation.mutationKey ?
+      options
+      : {...options, mutation: {...options.mutation, mutationKey}}
+      : {mutation: { mutationKey, }, request: undefined};
+
+      
+
+
+      const mutationFn: MutationFunction<Awaited<ReturnType<typeof createWorkspacePullRequest>>, {taskId: string;data: CreateWorkspacePrRequest}> = (props) => {
+          const {taskId,data} = props ?? {};
+
+          return  createWorkspacePullRequest(taskId,data,requestOptions)
+        }
+
+        
+
+
+  return  { mutationFn, ...mutationOptions }}
+
+    export type CreateWorkspacePullRequestMutationResult = NonNullable<Awaited<ReturnType<typeof createWorkspacePullRequest>>>
+    export type CreateWorkspacePullRequestMutationBody = CreateWorkspacePrRequest
+    export type CreateWorkspacePullRequestMutationError = void
+
+    export const useCreateWorkspacePullRequest = <TError = void,
+    TContext = unknown>(options?: { mutation?:UseMutationOptions<Awaited<ReturnType<typeof createWorkspacePullRequest>>, TError,{taskId: string;data: CreateWorkspacePrRequest}, TContext>, request?: SecondParameter<typeof customFetch>}
+ , queryClient?: QueryClient): UseMutationResult<
+        Awaited<ReturnType<typeof createWorkspacePullRequest>>,
+        TError,
+        {taskId: string;data: CreateWorkspacePrRequest},
+        TContext
+      > => {
+
+      const mutationOptions = getCreateWorkspacePullRequestMutationOptions(options);
+
+      return useMutation(mutationOptions, queryClient);
+    }
     
\ No newline at end of file
@@ -20,6 +20,12 @@ const COLUMN_CONFIG: Record<
     dotColor: "bg-muted-foreground",
     borderColor: "border-muted-foreground/20",
   },
+  blocked: {
+    label: "Blocked",
+    headerBg: "bg-muted/50",
+    dotColor: "bg-slate-400",
+    borderColor: "border-slate-500/30",
+  },
   planning: {
     label: "Planning",
     headerBg: "bg-blue-500/10",
@@ -10,6 +10,7 @@ import { TaskCard } from "./TaskCard";
 // Map original statuses to consolidated columns
 const STATUS_TO_COLUMN: Record<TaskStatus, KanbanColumnType> = {
   todo: "backlog",
+  blocked: "backlog",
   planning: "planning",
   planning_review: "planning",
   in_progress: "in_progress",
@@ -27,7 +28,10 @@ type SubGroup = {
 };
 
 const COLUMN_SUBGROUPS: Record<KanbanColumnType, SubGroup[]> = {
-  backlog: [{ key: "all", label: "", statuses: ["todo"] }],
+  backlog: [
+    { key: "todo", label: "", statuses: ["todo"] },
+    { key: "blocked", label: "Blocked", statuses: ["blocked"] },
+  ],
   planning: [
     { key: "ai", label: "AI Planning", statuses: ["planning"] },
     { key: "review", label: "Awaiting Review", statuses: ["planning_review"] },
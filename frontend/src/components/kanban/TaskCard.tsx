@@ -1,7 +1,7 @@
 import type { Task, TaskStatus } from "@/api/generated/model";
 import { Badge } from "@/components/ui/badge";
 import { cn } from "@/lib/utils";
-import { useIsTaskExecuting } from "@/stores/useExecutingTasksStore";
+import { useIsTaskExecuting, useTaskPhaseProgress } from "@/stores/useExecutingTasksStore";
 
 // Status groupings for consolidated kanban
 const AI_STATUSES: TaskStatus[] = ["planning", "in_progress", "ai_review"];
@@ -17,6 +17,7 @@ function TaskCard({ task, isSelected, onClick }: TaskCardProps) {
   const isAiPhase = AI_STATUSES.includes(task.status);
   const isHumanPhase = HUMAN_STATUSES.includes(task.status);
   const isExecuting = useIsTaskExecuting(task.id);
+  const progress = useTaskPhaseProgress(task.id);
 
   return (
     <div
@@ -91,6 +92,23 @@ function TaskCard({ task, isSelected, onClick }: TaskCardProps) {
             </span>
           </div>
         )}
+
+        {isExecuting && progress && progress.total > 0 && (
+          <div className="mt-1.5" title={progress.message ?? progress.step}>
+            <div className="h-1 rounded-full bg-emerald-500/15 overflow-hidden">
+              <div
+                className="h-full bg-emerald-500 transition-all duration-300"
+                style={{ width: `${Math.min(100, (progress.current / progress.total) * 100)}%` }}
+              />
+            </div>
+            <div className="mt-0.5 flex justify-between text-[9px] text-muted-foreground">
+              <span className="truncate">{progress.message ?? progress.step}</span>
+              {progress.findings_count != null && progress.findings_count > 0 && (
+                <span className="shrink-0">{progress.findings_count} findings</span>
+              )}
+            </div>
+          </div>
+        )}
       </div>
     </div>
   );
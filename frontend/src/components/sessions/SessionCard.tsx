@@ -1,13 +1,21 @@
 import { useState } from "react";
+import { useQueryClient } from "@tanstack/react-query";
 import type {
   Session,
   SessionPhase,
   SessionStatus,
 } from "@/api/generated/model";
+import {
+  getListSessionsQueryKey,
+  useRetrySession,
+} from "@/api/generated/sessions/sessions";
+import { getListTasksQueryKey } from "@/api/generated/tasks/tasks";
 import { ActivityFeed } from "@/components/activity/ActivityFeed";
 import { Badge } from "@/components/ui/badge";
+import { Button } from "@/components/ui/button";
 import { useSessionActivitySSE } from "@/hooks/useSessionActivitySSE";
 import { cn } from "@/lib/utils";
+import { toast } from "@/stores/useToastStore";
 
 const PHASE_COLORS: Record<SessionPhase, string> = {
   planning: "bg-blue-500/10 text-blue-500 border-blue-500/20",
@@ -30,6 +38,7 @@ interface SessionCardProps {
 
 export function SessionCard({ session }: SessionCardProps) {
   const [expanded, setExpanded] = useState(false);
+  const queryClient = useQueryClient();
 
   const { activities, isConnected } = useSessionActivitySSE(session.id, {
     enabled: expanded,
@@ -48,6 +57,24 @@ export function SessionCard({ session }: SessionCardProps) {
   };
 
   const isRunning = session.status === "running";
+  const canRetry = session.status === "failed" || session.status === "aborted";
+
+  const retrySession = useRetrySession({
+    mutation: {
+      onSuccess: () => {
+        void queryClient.invalidateQueries({
+          queryKey: getListSessionsQueryKey(),
+        });
+        void queryClient.invalidateQueries({
+          queryKey: getListTasksQueryKey(),
+        });
+        toast.success(`Retrying ${session.phase} phase`);
+      },
+      onError: () => {
+        toast.error("Failed to retry session");
+      },
+    },
+  });
 
   return (
     <div className="rounded-lg border bg-card">
@@ -92,9 +119,27 @@ export function SessionCard({ session }: SessionCardProps) {
               OpenCode: {session.opencode_session_id}
             </span>
           )}
+          {session.retry_of && (
+            <span className="font-mono">
+              Retry of: {session.retry_of.slice(0, 8)}
+            </span>
+          )}
         </div>
       </button>
 
+      {canRetry && (
+        <div className="flex justify-end border-t px-4 py-2">
+          <Button
+            size="sm"
+            variant="outline"
+            onClick={() => retrySession.mutate({ id: session.id })}
+            disabled={retrySession.isPending}
+          >
+            {retrySession.isPending ? "Retrying..." : "Retry phase"}
+          </Button>
+        </div>
+      )}
+
       {expanded && (
         <div className="border-t h-80">
           <ActivityFeed activities={activities} isConnected={isConnected} />
@@ -34,7 +34,7 @@ export function SessionsList() {
 		data: sessionsResponse,
 		isLoading,
 		error,
-	} = useListSessions({
+	} = useListSessions(undefined, {
 		query: { staleTime: 30000 },
 	});
 
@@ -3,6 +3,7 @@ import { useQueryClient } from "@tanstack/react-query";
 import type { ReviewFinding } from "@/api/generated/model/reviewFinding";
 import { FindingStatus } from "@/api/generated/model/findingStatus";
 import {
+  useBulkUpdateFindings,
   useFixFindings,
   useSkipFindings,
   getGetTaskFindingsQueryKey,
@@ -59,7 +60,7 @@ export function ProblemsTab({ taskId, findings, summary }: ProblemsTabProps) {
     for (const finding of findings) {
       if (finding.status === FindingStatus.fixed) {
         fixed.push(finding);
-      } else if (finding.status !== FindingStatus.skipped) {
+      } else if (finding.status === FindingStatus.pending) {
         pending.push(finding);
       }
     }
@@ -113,6 +114,24 @@ export function ProblemsTab({ taskId, findings, summary }: ProblemsTabProps) {
     },
   });
 
+  const dismissFindings = useBulkUpdateFindings({
+    mutation: {
+      onSuccess: (response) => {
+        void queryClient.invalidateQueries({
+          queryKey: getGetTaskFindingsQueryKey(taskId),
+        });
+        setSelectedIds(new Set());
+        if (response.status === 200) {
+          const count = response.data.updated;
+          toast.success(`Dismissed ${count} finding${count !== 1 ? "s" : ""}`);
+        }
+      },
+      onError: () => {
+        toast.error("Failed to dismiss findings");
+      },
+    },
+  });
+
   const toggleFinding = (id: string) => {
     setSelectedIds((prev) => {
       const next = new Set(prev);
@@ -168,11 +187,23 @@ export function ProblemsTab({ taskId, findings, summary }: ProblemsTabProps) {
     });
   };
 
+  const handleDismissSelected = () => {
+    if (selectedIds.size === 0) return;
+    dismissFindings.mutate({
+      id: taskId,
+      data: {
+        finding_ids: Array.from(selectedIds),
+        status: FindingStatus.dismissed,
+      },
+    });
+  };
+
   const handleSkip = () => {
     skipFindings.mutate({ id: taskId });
   };
 
-  const isLoading = fixFindings.isPending || skipFindings.isPending;
+  const isLoading =
+    fixFindings.isPending || skipFindings.isPending || dismissFindings.isPending;
 
   // All findings are fixed
   if (pendingFindings.length === 0 && fixedFindings.length > 0) {
@@ -247,6 +278,14 @@ export function ProblemsTab({ taskId, findings, summary }: ProblemsTabProps) {
             >
               Fix all ({pendingFindings.length})
             </Button>
+            <Button
+              size="sm"
+              variant="ghost"
+              onClick={handleDismissSelected}
+              disabled={selectedIds.size === 0 || isLoading}
+            >
+              Dismiss selected
+            </Button>
             <Button
               size="sm"
               variant="outline"
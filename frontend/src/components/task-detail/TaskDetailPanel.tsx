@@ -4,9 +4,11 @@ import type { Session, Task, TaskStatus } from "@/api/generated/model";
 import { FindingStatus } from "@/api/generated/model/findingStatus";
 import { useListSessionsForTask } from "@/api/generated/sessions/sessions";
 import {
+  getGetTaskTransitionsQueryKey,
   getListTasksQueryKey,
   useExecuteTask,
   useGetTaskFindings,
+  useGetTaskTransitions,
   useTransitionTask,
 } from "@/api/generated/tasks/tasks";
 import { getListSessionsForTaskQueryKey } from "@/api/generated/sessions/sessions";
@@ -15,6 +17,7 @@ import { ActivityFeed } from "@/components/activity/ActivityFeed";
 import { DiffOverlay } from "@/components/diff/DiffOverlay";
 import { PhasesList } from "@/components/task-detail/PhasesList";
 import { ProblemsTab } from "@/components/task-detail/ProblemsTab";
+import { WikiUpdatesList } from "@/components/task-detail/WikiUpdatesList";
 import { STATUS_CONFIG } from "@/components/kanban/KanbanColumn";
 import { Badge } from "@/components/ui/badge";
 import { Button } from "@/components/ui/button";
@@ -27,22 +30,6 @@ import { cn } from "@/lib/utils";
 import { useIsTaskExecuting } from "@/stores/useExecutingTasksStore";
 import { CompleteTaskDialog } from "@/components/dialogs/CompleteTaskDialog";
 
-const NEXT_STATUS: Partial<Record<TaskStatus, TaskStatus>> = {
-  todo: "planning",
-  planning: "in_progress",
-  planning_review: "in_progress",
-  in_progress: "ai_review",
-  ai_review: "review",
-  review: "done",
-};
-
-const EXECUTABLE_STATUSES: TaskStatus[] = [
-  "todo",
-  "planning",
-  "in_progress",
-  "ai_review",
-];
-
 interface TaskDetailPanelProps {
   task: Task;
   onClose: () => void;
@@ -102,8 +89,7 @@ function TaskDetailPanel({ task, onClose }: TaskDetailPanelProps) {
   const pendingFindingsCount = useMemo(() => {
     if (!hasFindings || findingsData?.status !== 200) return 0;
     return findingsData.data.findings.filter(
-      (f) =>
-        f.status !== FindingStatus.fixed && f.status !== FindingStatus.skipped,
+      (f) => f.status === FindingStatus.pending,
     ).length;
   }, [hasFindings, findingsData]);
 
@@ -158,20 +144,31 @@ function TaskDetailPanel({ task, onClose }: TaskDetailPanelProps) {
 
   const transitionTask = useTransitionTask({
     mutation: {
-      onSuccess: (_data, variables) => {
+      onSuccess: (response) => {
         void queryClient.invalidateQueries({
           queryKey: getListTasksQueryKey(),
         });
-        // Auto-execute when transitioning to an executable phase
-        const targetStatus = variables.data.status;
-        if (EXECUTABLE_STATUSES.includes(targetStatus)) {
+        void queryClient.invalidateQueries({
+          queryKey: getGetTaskTransitionsQueryKey(task.id),
+        });
+        // Auto-execute when the project's workflow runs the new status' phase
+        if (response.status === 200 && response.data.run_phase) {
           executeTask.mutate({ id: task.id });
         }
       },
     },
   });
 
-  const nextStatus = NEXT_STATUS[task.status];
+  // Which transitions are legal comes from the server's state machine
+  const { data: transitionsData } = useGetTaskTransitions(task.id, {
+    query: { queryKey: [...getGetTaskTransitionsQueryKey(task.id), task.status] },
+  });
+  const transitions =
+    transitionsData?.status === 200 ? transitionsData.data : undefined;
+  const nextTransition = transitions?.transitions.find(
+    (t) => t.to === transitions.next_status,
+  );
+  const nextStatus = nextTransition?.to;
   const statusConfig = STATUS_CONFIG[task.status];
 
   const handleExecute = () => {
@@ -198,12 +195,14 @@ function TaskDetailPanel({ task, onClose }: TaskDetailPanelProps) {
 
     switch (task.status) {
       case "todo":
-        // Only show "Start Planning" which transitions and auto-executes
+        // Only show "Start" which transitions to the first status and auto-executes
+        if (!nextStatus) return null;
         return (
           <Button
             size="sm"
-            onClick={() => handleTransition("planning")}
-            disabled={isPending}
+            onClick={() => handleTransition(nextStatus)}
+            disabled={isPending || !nextTransition?.allowed}
+            title={nextTransition?.blocked_reason ?? undefined}
           >
             {isPending ? (
               <>
@@ -211,7 +210,7 @@ function TaskDetailPanel({ task, onClose }: TaskDetailPanelProps) {
                 Starting...
               </>
             ) : (
-              "Start Planning"
+              `Start ${STATUS_CONFIG[nextStatus].label}`
             )}
           </Button>
         );
@@ -237,9 +236,10 @@ function TaskDetailPanel({ task, onClose }: TaskDetailPanelProps) {
                 size="sm"
                 variant="outline"
                 onClick={() => handleTransition(nextStatus)}
-                disabled={isPending}
+                disabled={isPending || !nextTransition?.allowed}
+                title={nextTransition?.blocked_reason ?? undefined}
               >
-                {EXECUTABLE_STATUSES.includes(nextStatus)
+                {nextTransition?.run_phase
                   ? `Start ${STATUS_CONFIG[nextStatus].label}`
                   : `Move to ${STATUS_CONFIG[nextStatus].label}`}
               </Button>
@@ -698,6 +698,9 @@ function TaskDetailPanel({ task, onClose }: TaskDetailPanelProps) {
                 task.status,
               ) && <PhasesList taskId={task.id} />}
 
+              {/* Wiki pages affected by the merge */}
+              {task.status === "done" && <WikiUpdatesList taskId={task.id} />}
+
               {/* Technical details - collapsible */}
               <details className="group">
                 <summary className="flex items-center gap-2 text-xs text-muted-foreground/50 cursor-pointer hover:text-muted-foreground/70 transition-colors">
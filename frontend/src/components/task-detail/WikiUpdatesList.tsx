@@ -0,0 +1,79 @@
+import { useState } from "react";
+import type { TaskWikiUpdateResponse } from "@/api/generated/model";
+import { useListTaskWikiUpdates } from "@/api/generated/wiki/wiki";
+import { Markdown } from "@/components/ui/markdown";
+import { cn } from "@/lib/utils";
+import { ChevronDown, ChevronRight } from "lucide-react";
+
+interface WikiUpdatesListProps {
+  taskId: string;
+  className?: string;
+}
+
+function WikiUpdateItem({ update }: { update: TaskWikiUpdateResponse }) {
+  const [isExpanded, setIsExpanded] = useState(false);
+  const Chevron = isExpanded ? ChevronDown : ChevronRight;
+
+  return (
+    <div className="py-1.5">
+      <button
+        type="button"
+        onClick={() => setIsExpanded(!isExpanded)}
+        className="flex w-full items-center gap-2 text-left text-sm hover:text-foreground transition-colors"
+      >
+        <Chevron className="w-3.5 h-3.5 shrink-0 text-muted-foreground/50" />
+        <span className="flex-1 truncate">{update.page_title}</span>
+        <span
+          className={cn(
+            "text-[10px] uppercase tracking-wider",
+            update.action === "regenerated"
+              ? "text-emerald-500/70"
+              : "text-muted-foreground/50",
+          )}
+        >
+          {update.action}
+        </span>
+      </button>
+      {isExpanded && (
+        <div className="mt-2 ml-5 space-y-2">
+          <p className="text-xs text-muted-foreground/60 font-mono">
+            {update.changed_files.join(", ")}
+          </p>
+          {update.action === "suggested" && (
+            <Markdown text={update.content} className="text-sm" />
+          )}
+        </div>
+      )}
+    </div>
+  );
+}
+
+/**
+ * Wiki pages suggested for update or regenerated after the task was merged
+ */
+export function WikiUpdatesList({ taskId, className }: WikiUpdatesListProps) {
+  const { data } = useListTaskWikiUpdates(taskId);
+  const updates = data?.status === 200 ? data.data.updates : [];
+
+  if (updates.length === 0) {
+    return null;
+  }
+
+  return (
+    <div className={cn(className)}>
+      <div className="flex items-center justify-between mb-2">
+        <span className="text-xs font-medium text-muted-foreground/60 uppercase tracking-wider">
+          Wiki Updates
+        </span>
+        <span className="text-[10px] text-muted-foreground/40 tabular-nums">
+          {updates.length}
+        </span>
+      </div>
+      <div>
+        {updates.map((update) => (
+          <WikiUpdateItem key={update.id} update={update} />
+        ))}
+      </div>
+    </div>
+  );
+}
@@ -1,12 +1,22 @@
-import { useEffect, useRef } from "react";
+import { ThumbsDown, ThumbsUp } from "lucide-react";
+import { useEffect, useRef, useState } from "react";
 import Markdown from "react-markdown";
 import remarkGfm from "remark-gfm";
 import rehypeRaw from "rehype-raw";
 import { Prism as SyntaxHighlighter } from "react-syntax-highlighter";
 import { vscDarkPlus } from "react-syntax-highlighter/dist/esm/styles/prism";
-import { useAskWiki } from "@/api/generated/wiki/wiki";
+import { AskAnswerMode } from "@/api/generated/model";
+import { useAskWiki, useSubmitAskFeedback } from "@/api/generated/wiki/wiki";
+import { toast } from "@/stores/useToastStore";
 import { type ChatMessage, useWikiStore } from "@/stores/useWikiStore";
 
+const ANSWER_MODES: Array<{ value: AskAnswerMode; label: string }> = [
+	{ value: AskAnswerMode.standard, label: "Standard" },
+	{ value: AskAnswerMode.concise, label: "Concise" },
+	{ value: AskAnswerMode.detailed, label: "Detailed" },
+	{ value: AskAnswerMode.code_only, label: "Code only" },
+];
+
 export function WikiChat() {
 	const {
 		chatMessages,
@@ -14,6 +24,7 @@ export function WikiChat() {
 		isChatLoading,
 		conversationId,
 		addChatMessage,
+		setChatMessageRating,
 		setChatInput,
 		setIsChatLoading,
 		setConversationId,
@@ -22,7 +33,9 @@ export function WikiChat() {
 
 	const messagesEndRef = useRef<HTMLDivElement>(null);
 	const inputRef = useRef<HTMLTextAreaElement>(null);
+	const [mode, setMode] = useState<AskAnswerMode>(AskAnswerMode.standard);
 	const askMutation = useAskWiki();
+	const feedbackMutation = useSubmitAskFeedback();
 
 	// Auto-scroll to bottom when new messages arrive
 	useEffect(() => {
@@ -50,6 +63,7 @@ export function WikiChat() {
 				data: {
 					question,
 					conversation_id: conversationId ?? undefined,
+					mode,
 				},
 			});
 
@@ -63,6 +77,7 @@ export function WikiChat() {
 				const assistantMessage: ChatMessage = {
 					role: "assistant",
 					content: answer,
+					question,
 					sources: sources?.map((s) => ({
 						file_path: s.file_path,
 						start_line: s.start_line,
@@ -89,6 +104,30 @@ export function WikiChat() {
 		}
 	};
 
+	const handleRate = async (index: number, rating: "up" | "down") => {
+		const message = chatMessages[index];
+		if (!conversationId || !message?.question) return;
+
+		setChatMessageRating(index, rating);
+		try {
+			await feedbackMutation.mutateAsync({
+				conversationId,
+				data: {
+					rating,
+					question: message.question,
+					answer: message.content,
+					sources: message.sources?.map((s) => ({
+						file_path: s.file_path,
+						start_line: s.start_line,
+						end_line: s.end_line,
+					})),
+				},
+			});
+		} catch {
+			toast.error("Failed to send feedback");
+		}
+	};
+
 	const handleKeyDown = (e: React.KeyboardEvent<HTMLTextAreaElement>) => {
 		if (e.key === "Enter" && !e.shiftKey) {
 			e.preventDefault();
@@ -156,7 +195,15 @@ export function WikiChat() {
 				) : (
 					<>
 						{chatMessages.map((message, index) => (
-							<ChatMessageItem key={`msg-${index}`} message={message} />
+							<ChatMessageItem
+								key={`msg-${index}`}
+								message={message}
+								onRate={
+									conversationId && message.question
+										? (rating) => void handleRate(index, rating)
+										: undefined
+								}
+							/>
 						))}
 						{isChatLoading && (
 							<div className="flex items-center gap-2 text-muted-foreground">
@@ -182,6 +229,18 @@ export function WikiChat() {
 			{/* Input */}
 			<div className="border-t border-border p-4">
 				<form onSubmit={handleSubmit} className="flex gap-2">
+					<select
+						value={mode}
+						onChange={(e) => setMode(e.target.value as AskAnswerMode)}
+						title="Answer mode"
+						className="px-2 py-2 text-sm bg-accent border border-border rounded-md focus:outline-none focus:ring-2 focus:ring-primary/50"
+					>
+						{ANSWER_MODES.map(({ value, label }) => (
+							<option key={value} value={value}>
+								{label}
+							</option>
+						))}
+					</select>
 					<textarea
 						ref={inputRef}
 						value={chatInput}
@@ -213,7 +272,13 @@ export function WikiChat() {
 	);
 }
 
-function ChatMessageItem({ message }: { message: ChatMessage }) {
+function ChatMessageItem({
+	message,
+	onRate,
+}: {
+	message: ChatMessage;
+	onRate?: (rating: "up" | "down") => void;
+}) {
 	const isUser = message.role === "user";
 
 	return (
@@ -351,6 +416,33 @@ function ChatMessageItem({ message }: { message: ChatMessage }) {
 						))}
 					</div>
 				)}
+
+				{/* Feedback (for answered questions) */}
+				{onRate && (
+					<div className="mt-2 flex items-center gap-1 text-muted-foreground">
+						<span className="text-xs mr-1">Was this helpful?</span>
+						<button
+							type="button"
+							onClick={() => onRate("up")}
+							title="Helpful"
+							className={`p-1 rounded hover:bg-accent transition-colors ${
+								message.rating === "up" ? "text-green-500" : ""
+							}`}
+						>
+							<ThumbsUp className="h-3.5 w-3.5" />
+						</button>
+						<button
+							type="button"
+							onClick={() => onRate("down")}
+							title="Not helpful"
+							className={`p-1 rounded hover:bg-accent transition-colors ${
+								message.rating === "down" ? "text-red-500" : ""
+							}`}
+						>
+							<ThumbsDown className="h-3.5 w-3.5" />
+						</button>
+					</div>
+				)}
 			</div>
 		</div>
 	);
@@ -1,6 +1,7 @@
 import { useQueryClient } from "@tanstack/react-query";
 import { useStartIndexing, getGetWikiStatusQueryKey } from "@/api/generated/wiki/wiki";
 import { cn } from "@/lib/utils";
+import { toast } from "@/stores/useToastStore";
 import { useWikiStore, type WikiGenerationProgress } from "@/stores/useWikiStore";
 
 interface WikiIndexProgressProps {
@@ -21,16 +22,13 @@ export function WikiIndexProgress({ compact = false, indexOnly = false }: WikiIn
 		setIsIndexing(true);
 
 		try {
-			if (branchToIndex) {
-				await startIndexingMutation.mutateAsync({
-					data: { branch: branchToIndex.branch, index_only: indexOnly },
+			const branch = branchToIndex ?? branchStatuses[0];
+			if (branch) {
+				const response = await startIndexingMutation.mutateAsync({
+					data: { branch: branch.branch, force: !branchToIndex, index_only: indexOnly },
 				});
-			} else {
-				const firstBranch = branchStatuses[0];
-				if (firstBranch) {
-					await startIndexingMutation.mutateAsync({
-						data: { branch: firstBranch.branch, force: true, index_only: indexOnly },
-					});
+				if (response.status === 200 && !response.data.started) {
+					toast.info(response.data.message);
 				}
 			}
 			setTimeout(() => {
@@ -0,0 +1,48 @@
+import { useGetWikiFileChunks } from "@/api/generated/wiki/wiki";
+
+/** Debugging view of how a file was split into chunks by the indexer */
+export function WikiIndexedFile({ filePath }: { filePath: string }) {
+	const { data, isLoading, error } = useGetWikiFileChunks(encodeURIComponent(filePath));
+
+	if (isLoading) {
+		return <div className="p-4 text-sm text-muted-foreground">Loading indexed content...</div>;
+	}
+
+	if (error || data?.status !== 200) {
+		return (
+			<div className="p-4 text-sm text-destructive">
+				Failed to load indexed content for {filePath}
+			</div>
+		);
+	}
+
+	const { branch, chunks } = data.data;
+
+	return (
+		<div className="divide-y divide-border">
+			<div className="px-4 py-2 text-xs text-muted-foreground">
+				{chunks.length} chunks indexed on {branch} at {chunks[0]?.commit_sha.slice(0, 7)}
+			</div>
+			{chunks.map((chunk) => (
+				<div key={chunk.id}>
+					<div className="flex items-center gap-2 px-4 py-1 text-xs text-muted-foreground bg-accent/30">
+						<span className="font-mono">#{chunk.chunk_index}</span>
+						<span>
+							lines {chunk.start_line}-{chunk.end_line}
+						</span>
+						<span className="px-1.5 py-0.5 rounded bg-primary/10 text-primary">
+							{chunk.chunk_type}
+						</span>
+						<span className="ml-auto">{chunk.token_count} tokens</span>
+					</div>
+					{chunk.summary && (
+						<div className="px-4 pt-2 text-sm text-muted-foreground">{chunk.summary}</div>
+					)}
+					<pre className="p-4 overflow-x-auto text-sm">
+						<code className="font-mono">{chunk.content}</code>
+					</pre>
+				</div>
+			))}
+		</div>
+	);
+}
@@ -1,6 +1,7 @@
 import { useState } from "react";
 import { useSearchWiki } from "@/api/generated/wiki/wiki";
 import { useWikiStore } from "@/stores/useWikiStore";
+import { WikiIndexedFile } from "./WikiIndexedFile";
 
 export function WikiSearch() {
 	const { searchQuery, searchResults, setSearchQuery, setSearchResults, setIsSearching } =
@@ -30,6 +31,7 @@ export function WikiSearch() {
 						start_line: r.start_line,
 						end_line: r.end_line,
 						content: r.content,
+						summary: r.summary ?? null,
 						language: r.language ?? null,
 						score: r.score,
 					})),
@@ -161,11 +163,13 @@ function SearchResultItem({
 		start_line: number;
 		end_line: number;
 		content: string;
+		summary: string | null;
 		language: string | null;
 		score: number;
 	};
 }) {
 	const scorePercent = Math.round(result.score * 100);
+	const [showIndexed, setShowIndexed] = useState(false);
 
 	return (
 		<div className="border border-border rounded-lg overflow-hidden bg-card">
@@ -194,13 +198,30 @@ function SearchResultItem({
 						</span>
 					)}
 					<span className="text-xs text-muted-foreground">{scorePercent}% match</span>
+					<button
+						type="button"
+						onClick={() => setShowIndexed((show) => !show)}
+						className="text-xs text-muted-foreground hover:text-foreground transition-colors"
+					>
+						{showIndexed ? "Hide indexed content" : "View indexed content"}
+					</button>
 				</div>
 			</div>
 
+			{result.summary && !showIndexed && (
+				<div className="px-4 py-2 text-sm text-muted-foreground border-b border-border">
+					{result.summary}
+				</div>
+			)}
+
 			{/* Code content */}
-			<pre className="p-4 overflow-x-auto text-sm">
-				<code className="font-mono">{result.content}</code>
-			</pre>
+			{showIndexed ? (
+				<WikiIndexedFile filePath={result.file_path} />
+			) : (
+				<pre className="p-4 overflow-x-auto text-sm">
+					<code className="font-mono">{result.content}</code>
+				</pre>
+			)}
 		</div>
 	);
 }
@@ -1,5 +1,6 @@
 import { useQueryClient } from "@tanstack/react-query";
 import { useCallback, useEffect, useState } from "react";
+import { PostMergeWikiUpdate } from "@/api/generated/model";
 import { useGetWikiSettings, useUpdateWikiSettings } from "@/api/generated/settings/settings";
 import { useGetWikiStatus } from "@/api/generated/wiki/wiki";
 import { useEventStream } from "@/hooks/useEventStream";
@@ -107,6 +108,15 @@ export function WikiSettings() {
 	const [branches, setBranches] = useState<string[]>([]);
 	const [openrouterApiKey, setOpenrouterApiKey] = useState("");
 	const [autoSync, setAutoSync] = useState(false);
+	const [chunkSummaries, setChunkSummaries] = useState(false);
+	const [roots, setRoots] = useState("");
+	const [postMergeUpdate, setPostMergeUpdate] = useState<PostMergeWikiUpdate>(
+		PostMergeWikiUpdate.suggest,
+	);
+	const [reviewContext, setReviewContext] = useState(true);
+	const [reviewContextMaxTokens, setReviewContextMaxTokens] = useState(4000);
+	const [planningContext, setPlanningContext] = useState(true);
+	const [planningContextMaxTokens, setPlanningContextMaxTokens] = useState(6000);
 	const [isDirty, setIsDirty] = useState(false);
 
 	const fetchRemoteBranches = useCallback(async () => {
@@ -134,6 +144,13 @@ export function WikiSettings() {
 			setEnabled(settings.enabled);
 			setBranches(settings.branches);
 			setAutoSync(settings.auto_sync);
+			setChunkSummaries(settings.chunk_summaries);
+			setRoots(settings.roots.join(", "));
+			setPostMergeUpdate(settings.post_merge_update);
+			setReviewContext(settings.review_context);
+			setReviewContextMaxTokens(settings.review_context_max_tokens);
+			setPlanningContext(settings.planning_context);
+			setPlanningContextMaxTokens(settings.planning_context_max_tokens);
 		}
 	}, [settingsData]);
 
@@ -144,6 +161,16 @@ export function WikiSettings() {
 				branches,
 				openrouter_api_key: openrouterApiKey || null,
 				auto_sync: autoSync,
+				chunk_summaries: chunkSummaries,
+				roots: roots
+					.split(",")
+					.map((root) => root.trim())
+					.filter((root) => root.length > 0),
+				post_merge_update: postMergeUpdate,
+				review_context: reviewContext,
+				review_context_max_tokens: reviewContextMaxTokens,
+				planning_context: planningContext,
+				planning_context_max_tokens: planningContextMaxTokens,
 			},
 		});
 
@@ -322,11 +349,64 @@ export function WikiSettings() {
 				)}
 			</div>
 
+			{/* Workspace roots */}
+			<div className="space-y-2">
+				<label htmlFor="wiki-roots" className="text-sm font-medium">
+					Workspace Roots
+				</label>
+				<input
+					id="wiki-roots"
+					type="text"
+					value={roots}
+					onChange={(e) => {
+						setRoots(e.target.value);
+						setIsDirty(true);
+					}}
+					placeholder="backend/, frontend/ (leave empty to index everything)"
+					className="w-full px-3 py-2 bg-accent border border-border rounded-md focus:outline-none focus:ring-2 focus:ring-primary/50 text-sm font-mono"
+				/>
+				<p className="text-xs text-muted-foreground">
+					Comma-separated sub-directories of a monorepo to index. Search and questions can be
+					scoped to one of them.
+				</p>
+			</div>
+
 			{/* Indexing Status & Controls */}
 			<div className="pt-2 border-t border-border">
 				<WikiIndexProgress indexOnly />
 			</div>
 
+			{/* Chunk summaries */}
+			<div className="flex items-center justify-between">
+				<div>
+					<label htmlFor="wiki-chunk-summaries" className="text-sm font-medium">
+						Summarize Code Chunks
+					</label>
+					<p className="text-xs text-muted-foreground">
+						Write a one-line summary of each chunk while indexing to improve search
+					</p>
+				</div>
+				<button
+					id="wiki-chunk-summaries"
+					type="button"
+					role="switch"
+					aria-checked={chunkSummaries}
+					onClick={() => {
+						setChunkSummaries(!chunkSummaries);
+						setIsDirty(true);
+					}}
+					className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+						chunkSummaries ? "bg-primary" : "bg-accent"
+					}`}
+				>
+					<span
+						className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+							chunkSummaries ? "translate-x-6" : "translate-x-1"
+						}`}
+					/>
+				</button>
+			</div>
+
 			{/* Auto-sync */}
 			<div className="flex items-center justify-between">
 				<div>
@@ -358,6 +438,137 @@ export function WikiSettings() {
 				</button>
 			</div>
 
+			{/* Post-merge updates */}
+			<div className="space-y-2">
+				<label htmlFor="wiki-post-merge" className="text-sm font-medium">
+					After a Task Merges
+				</label>
+				<select
+					id="wiki-post-merge"
+					value={postMergeUpdate}
+					onChange={(e) => {
+						setPostMergeUpdate(e.target.value as PostMergeWikiUpdate);
+						setIsDirty(true);
+					}}
+					className="w-full px-3 py-2 bg-accent border border-border rounded-md focus:outline-none focus:ring-2 focus:ring-primary/50 text-sm"
+				>
+					<option value={PostMergeWikiUpdate.off}>Do nothing</option>
+					<option value={PostMergeWikiUpdate.suggest}>Suggest updates to affected pages</option>
+					<option value={PostMergeWikiUpdate.regenerate}>Regenerate affected pages</option>
+				</select>
+				<p className="text-xs text-muted-foreground">
+					Pages documenting files changed by the merge are listed on the task
+				</p>
+			</div>
+
+			{/* Planning context */}
+			<div className="flex items-center justify-between">
+				<div>
+					<label htmlFor="wiki-planning-context" className="text-sm font-medium">
+						Wiki Context in Planning
+					</label>
+					<p className="text-xs text-muted-foreground">
+						Ground plans in related code and pages, listed as sources in the plan
+					</p>
+				</div>
+				<button
+					id="wiki-planning-context"
+					type="button"
+					role="switch"
+					aria-checked={planningContext}
+					onClick={() => {
+						setPlanningContext(!planningContext);
+						setIsDirty(true);
+					}}
+					className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+						planningContext ? "bg-primary" : "bg-accent"
+					}`}
+				>
+					<span
+						className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+							planningContext ? "translate-x-6" : "translate-x-1"
+						}`}
+					/>
+				</button>
+			</div>
+
+			{planningContext && (
+				<div className="space-y-2">
+					<label htmlFor="wiki-planning-context-tokens" className="text-sm font-medium">
+						Planning Context Token Cap
+					</label>
+					<input
+						id="wiki-planning-context-tokens"
+						type="number"
+						min={0}
+						step={500}
+						value={planningContextMaxTokens}
+						onChange={(e) => {
+							setPlanningContextMaxTokens(Math.max(0, Number(e.target.value) || 0));
+							setIsDirty(true);
+						}}
+						className="w-full px-3 py-2 bg-accent border border-border rounded-md focus:outline-none focus:ring-2 focus:ring-primary/50 text-sm"
+					/>
+					<p className="text-xs text-muted-foreground">
+						Takes effect the next time the project is opened
+					</p>
+				</div>
+			)}
+
+			{/* Review context */}
+			<div className="flex items-center justify-between">
+				<div>
+					<label htmlFor="wiki-review-context" className="text-sm font-medium">
+						Wiki Context in AI Review
+					</label>
+					<p className="text-xs text-muted-foreground">
+						Attach wiki pages and related code for changed files to the review prompt
+					</p>
+				</div>
+				<button
+					id="wiki-review-context"
+					type="button"
+					role="switch"
+					aria-checked={reviewContext}
+					onClick={() => {
+						setReviewContext(!reviewContext);
+						setIsDirty(true);
+					}}
+					className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+						reviewContext ? "bg-primary" : "bg-accent"
+					}`}
+				>
+					<span
+						className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+							reviewContext ? "translate-x-6" : "translate-x-1"
+						}`}
+					/>
+				</button>
+			</div>
+
+			{reviewContext && (
+				<div className="space-y-2">
+					<label htmlFor="wiki-review-context-tokens" className="text-sm font-medium">
+						Review Context Token Cap
+					</label>
+					<input
+						id="wiki-review-context-tokens"
+						type="number"
+						min={0}
+						step={500}
+						value={reviewContextMaxTokens}
+						onChange={(e) => {
+							setReviewContextMaxTokens(Math.max(0, Number(e.target.value) || 0));
+							setIsDirty(true);
+						}}
+						className="w-full px-3 py-2 bg-accent border border-border rounded-md focus:outline-none focus:ring-2 focus:ring-primary/50 text-sm"
+					/>
+					<p className="text-xs text-muted-foreground">
+						Takes effect the next time the project is opened
+					</p>
+				</div>
+			)}
+
 			{/* Save button */}
 			<div className="flex justify-end pt-2">
 				<button
@@ -9,6 +9,7 @@ import {
 } from "@/api/generated/wiki/wiki";
 import { Loader } from "@/components/ui/loader";
 import { useEventStream } from "@/hooks/useEventStream";
+import { toast } from "@/stores/useToastStore";
 import {
 	useWikiStore,
 	type WikiGenerationPhase,
@@ -160,9 +161,12 @@ export function WikiView() {
 		if (branchToGenerate) {
 			setIsIndexing(true);
 			try {
-				await generateWikiMutation.mutateAsync({
+				const response = await generateWikiMutation.mutateAsync({
 					data: { branch: branchToGenerate.branch },
 				});
+				if (response.status === 200 && !response.data.started) {
+					toast.info(response.data.message);
+				}
 				setTimeout(() => {
 					void queryClient.invalidateQueries({ queryKey: getGetWikiStatusQueryKey() });
 				}, 500);
@@ -1,5 +1,6 @@
 export { MermaidDiagram } from "./MermaidDiagram";
 export { WikiChat } from "./WikiChat";
+export { WikiIndexedFile } from "./WikiIndexedFile";
 export { WikiIndexProgress } from "./WikiIndexProgress";
 export { WikiPage } from "./WikiPage";
 export { WikiSearch } from "./WikiSearch";
@@ -1,6 +1,15 @@
 import { useQueryClient } from "@tanstack/react-query";
 import { useCallback, useEffect, useRef, useState } from "react";
-import type { Session, SessionPhase, SessionStatus, Task, TaskStatus } from "@/api/generated/model";
+import type {
+	Session,
+	SessionPhase,
+	SessionStatus,
+	Task,
+	TaskStatus,
+	UserPreferencesResponse,
+} from "@/api/generated/model";
+import { getGetTaskCiGateQueryKey } from "@/api/generated/complete/complete";
+import { useGetUserPreferences } from "@/api/generated/preferences/preferences";
 import {
 	getListSessionsQueryKey,
 	getListSessionsForTaskQueryKey,
@@ -10,9 +19,15 @@ import {
 	getListTasksQueryKey,
 	getGetTaskPlanQueryKey,
 	getGetTaskFindingsQueryKey,
+	getGetTaskPipelineQueryKey,
 	type listTasksResponse,
 } from "@/api/generated/tasks/tasks";
 import { getGetTaskPhasesQueryKey } from "@/api/generated/phases/phases";
+import {
+	getGetWikiStatusQueryKey,
+	getGetWikiStructureQueryKey,
+	getListTaskWikiUpdatesQueryKey,
+} from "@/api/generated/wiki/wiki";
 import { useExecutingTasksStore } from "@/stores/useExecutingTasksStore";
 import { toast } from "@/stores/useToastStore";
 import type { Event } from "@/types/generated/Event";
@@ -20,16 +35,36 @@ import type { EventEnvelope } from "@/types/generated/EventEnvelope";
 
 const INITIAL_RECONNECT_DELAY = 1000;
 const MAX_RECONNECT_DELAY = 30000;
+// Highest event schema version this client understands
+const EVENT_SCHEMA_VERSION = 6;
 
 function getEventsUrl(taskIds?: string[]): string {
 	const base = import.meta.env.VITE_API_URL || "";
 	const url = new URL(`${base}/api/events`, window.location.origin);
+	url.searchParams.set("version", String(EVENT_SCHEMA_VERSION));
 	if (taskIds && taskIds.length > 0) {
 		url.searchParams.set("task_ids", taskIds.join(","));
 	}
 	return url.toString();
 }
 
+// Whether the user wants a toast for an event. No subscriptions means every
+// event; a trailing `*` subscribes to every event with that prefix.
+function wantsInAppNotification(
+	preferences: UserPreferencesResponse | undefined,
+	eventType: string,
+): boolean {
+	if (!preferences) return true;
+	if (!preferences.notification_channels.includes("in_app")) return false;
+	const subscriptions = preferences.event_subscriptions;
+	return (
+		subscriptions.length === 0 ||
+		subscriptions.some((s) =>
+			s.endsWith("*") ? eventType.startsWith(s.slice(0, -1)) : s === eventType,
+		)
+	);
+}
+
 interface UseEventStreamOptions {
 	taskId?: string;
 	onEvent?: (event: Event) => void;
@@ -41,6 +76,7 @@ export function useEventStream(options: UseEventStreamOptions = {}) {
 	const queryClient = useQueryClient();
 	const startExecuting = useExecutingTasksStore((s) => s.startExecuting);
 	const stopExecuting = useExecutingTasksStore((s) => s.stopExecuting);
+	const setProgress = useExecutingTasksStore((s) => s.setProgress);
 	const eventSourceRef = useRef<EventSource | null>(null);
 	const reconnectTimeoutRef = useRef<ReturnType<typeof setTimeout> | null>(null);
 	const reconnectDelayRef = useRef(INITIAL_RECONNECT_DELAY);
@@ -48,6 +84,13 @@ export function useEventStream(options: UseEventStreamOptions = {}) {
 	const [isConnected, setIsConnected] = useState(false);
 	const [error, setError] = useState<string | null>(null);
 
+	const { data: preferencesResponse } = useGetUserPreferences();
+	const preferencesRef = useRef<UserPreferencesResponse | undefined>(undefined);
+	useEffect(() => {
+		preferencesRef.current =
+			preferencesResponse?.status === 200 ? preferencesResponse.data : undefined;
+	}, [preferencesResponse]);
+
 	const onEventRef = useRef(onEvent);
 	const onAutoExecuteRef = useRef(onAutoExecute);
 	useEffect(() => {
@@ -120,6 +163,7 @@ export function useEventStream(options: UseEventStreamOptions = {}) {
 
 	const invalidateQueries = useCallback(
 		(event: Event) => {
+			const notify = wantsInAppNotification(preferencesRef.current, event.type);
 			switch (event.type) {
 				case "task.created":
 					// New task - need full refetch to get all data
@@ -152,19 +196,33 @@ export function useEventStream(options: UseEventStreamOptions = {}) {
 					void queryClient.invalidateQueries({
 						queryKey: getGetTaskFindingsQueryKey(event.task_id),
 					});
+					void queryClient.invalidateQueries({
+						queryKey: getGetTaskPipelineQueryKey(event.task_id),
+					});
 					// Auto-execute AI Review when transitioning from in_progress
 					if (event.from_status === "in_progress" && event.to_status === "ai_review") {
 						onAutoExecuteRef.current?.(event.task_id);
 					}
 					// Notify user when AI Review completes and found issues
-					if (event.from_status === "ai_review" && event.to_status === "fix") {
+					if (notify && event.from_status === "ai_review" && event.to_status === "fix") {
 						toast.info("AI Review complete - issues found. Check the Problems tab.");
 					}
 					// Notify when AI Review passes with no issues
-					if (event.from_status === "ai_review" && event.to_status === "review") {
+					if (notify && event.from_status === "ai_review" && event.to_status === "review") {
 						toast.success("AI Review complete - no issues found!");
 					}
 					break;
+				case "findings.updated":
+					void queryClient.invalidateQueries({
+						queryKey: getGetTaskFindingsQueryKey(event.task_id),
+					});
+					break;
+				case "task.transition_rule_evaluated":
+					// A matched rule that left the task in place is waiting on someone
+					if (notify && event.matched && event.reason) {
+						toast.info(`Task stays in ${event.from_status}: ${event.reason}`);
+					}
+					break;
 				case "session.started": {
 					// Mark task as executing
 					startExecuting(event.task_id);
@@ -183,8 +241,12 @@ export function useEventStream(options: UseEventStreamOptions = {}) {
 					void queryClient.invalidateQueries({
 						queryKey: getGetTaskPhasesQueryKey(event.task_id),
 					});
-					// Show notification
-					toast.info(`${event.phase} session started`);
+					void queryClient.invalidateQueries({
+						queryKey: getGetTaskPipelineQueryKey(event.task_id),
+					});
+					if (notify) {
+						toast.info(`${event.phase} session started`);
+					}
 					break;
 				}
 				case "session.ended": {
@@ -208,11 +270,15 @@ export function useEventStream(options: UseEventStreamOptions = {}) {
 					void queryClient.invalidateQueries({
 						queryKey: getGetTaskFindingsQueryKey(event.task_id),
 					});
-					// Show notification
-					if (event.success) {
-						toast.success("Session completed successfully");
-					} else {
-						toast.error("Session failed");
+					void queryClient.invalidateQueries({
+						queryKey: getGetTaskPipelineQueryKey(event.task_id),
+					});
+					if (notify) {
+						if (event.success) {
+							toast.success("Session completed successfully");
+						} else {
+							toast.error("Session failed");
+						}
 					}
 					break;
 				}
@@ -223,6 +289,40 @@ export function useEventStream(options: UseEventStreamOptions = {}) {
 						queryKey: getListTasksQueryKey(),
 					});
 					break;
+				case "workspace.path_violation":
+					// The violation is recorded as a finding of the task
+					void queryClient.invalidateQueries({
+						queryKey: getGetTaskFindingsQueryKey(event.task_id),
+					});
+					if (notify) {
+						toast.error(
+							event.blocked
+								? `Blocked a write outside the workspace: ${event.path}`
+								: `Session wrote outside its workspace: ${event.path}`,
+						);
+					}
+					break;
+				case "ci.status_changed":
+					void queryClient.invalidateQueries({
+						queryKey: getGetTaskCiGateQueryKey(event.task_id),
+					});
+					if (notify && event.state === "failed") {
+						toast.error(`CI failed on ${event.ref_name}: ${event.failed} failing checks`);
+					}
+					break;
+				case "wiki.index_refreshed":
+					void queryClient.invalidateQueries({
+						queryKey: getGetWikiStatusQueryKey(),
+					});
+					break;
+				case "wiki.docs_refreshed":
+					void queryClient.invalidateQueries({
+						queryKey: getGetWikiStructureQueryKey(),
+					});
+					void queryClient.invalidateQueries({
+						queryKey: getListTaskWikiUpdatesQueryKey(event.task_id),
+					});
+					break;
 				case "phase.completed": {
 					// Invalidate phases query to update the UI
 					void queryClient.invalidateQueries({
@@ -240,9 +340,11 @@ export function useEventStream(options: UseEventStreamOptions = {}) {
 					void queryClient.invalidateQueries({
 						queryKey: getListSessionsForTaskQueryKey(event.task_id),
 					});
-					toast.success(
-						`Phase ${event.phase_number}/${event.total_phases} completed: ${event.phase_title}`,
-					);
+					if (notify) {
+						toast.success(
+							`Phase ${event.phase_number}/${event.total_phases} completed: ${event.phase_title}`,
+						);
+					}
 					break;
 				}
 				case "phase.continuing": {
@@ -254,9 +356,15 @@ export function useEventStream(options: UseEventStreamOptions = {}) {
 					void queryClient.invalidateQueries({
 						queryKey: getListTasksQueryKey(),
 					});
-					toast.info(`Starting phase ${event.next_phase_number}/${event.total_phases}`);
+					if (notify) {
+						toast.info(`Starting phase ${event.next_phase_number}/${event.total_phases}`);
+					}
 					break;
 				}
+				case "phase.progress":
+					// Drives the progress bar of running planning and review sessions
+					setProgress(event);
+					break;
 				case "project.opened":
 				case "project.closed":
 					// Project changed - invalidate all task and session data
@@ -276,6 +384,7 @@ export function useEventStream(options: UseEventStreamOptions = {}) {
 			updateSessionInCache,
 			startExecuting,
 			stopExecuting,
+			setProgress,
 		],
 	);
 
@@ -336,10 +445,12 @@ export function useEventStream(options: UseEventStreamOptions = {}) {
 				"task.created",
 				"task.updated",
 				"task.status_changed",
+				"task.transition_rule_evaluated",
 				"session.started",
 				"session.ended",
 				"phase.completed",
 				"phase.continuing",
+				"phase.progress",
 				"agent.message",
 				"tool.execution",
 				"workspace.created",
@@ -1,4 +1,8 @@
 import { create } from "zustand";
+import type { Event } from "@/types/generated/Event";
+
+/** Latest step reported by a running planning or review session */
+export type PhaseProgress = Omit<Extract<Event, { type: "phase.progress" }>, "type">;
 
 type ExecutingTasksState = {
 	/** Set of task IDs that are currently executing (have running sessions) */
@@ -9,6 +13,10 @@ type ExecutingTasksState = {
 	stopExecuting: (taskId: string) => void;
 	/** Check if a task is executing */
 	isExecuting: (taskId: string) => boolean;
+	/** Latest phase progress per executing task */
+	progress: Map<string, PhaseProgress>;
+	/** Record the latest phase progress of a task */
+	setProgress: (progress: PhaseProgress) => void;
 };
 
 export const useExecutingTasksStore = create<ExecutingTasksState>()((set, get) => ({
@@ -21,10 +29,20 @@ export const useExecutingTasksStore = create<ExecutingTasksState>()((set, get) =
 		set((state) => {
 			const newSet = new Set(state.executingTaskIds);
 			newSet.delete(taskId);
-			return { executingTaskIds: newSet };
+			const progress = new Map(state.progress);
+			progress.delete(taskId);
+			return { executingTaskIds: newSet, progress };
 		}),
 	isExecuting: (taskId) => get().executingTaskIds.has(taskId),
+	progress: new Map<string, PhaseProgress>(),
+	setProgress: (progress) =>
+		set((state) => ({
+			progress: new Map(state.progress).set(progress.task_id, progress),
+		})),
 }));
 
 export const useIsTaskExecuting = (taskId: string) =>
 	useExecutingTasksStore((s) => s.executingTaskIds.has(taskId));
+
+export const useTaskPhaseProgress = (taskId: string) =>
+	useExecutingTasksStore((s) => s.progress.get(taskId));
@@ -84,6 +84,9 @@ export type ChatMessage = {
 		score: number;
 		snippet: string;
 	}>;
+	/** Question an assistant message answers, sent back with feedback */
+	question?: string;
+	rating?: "up" | "down";
 };
 
 type State = {
@@ -110,6 +113,7 @@ type State = {
 	setSearchResults: (results: WikiSearchResult[]) => void;
 	setIsSearching: (isSearching: boolean) => void;
 	addChatMessage: (message: ChatMessage) => void;
+	setChatMessageRating: (index: number, rating: "up" | "down") => void;
 	setChatInput: (input: string) => void;
 	setIsChatLoading: (isLoading: boolean) => void;
 	setConversationId: (id: string | null) => void;
@@ -148,6 +152,12 @@ export const useWikiStore = create<State>((set) => ({
 	setSearchResults: (searchResults) => set({ searchResults }),
 	setIsSearching: (isSearching) => set({ isSearching }),
 	addChatMessage: (message) => set((state) => ({ chatMessages: [...state.chatMessages, message] })),
+	setChatMessageRating: (index, rating) =>
+		set((state) => ({
+			chatMessages: state.chatMessages.map((message, i) =>
+				i === index ? { ...message, rating } : message,
+			),
+		})),
 	setChatInput: (chatInput) => set({ chatInput }),
 	setIsChatLoading: (isChatLoading) => set({ isChatLoading }),
 	setConversationId: (conversationId) => set({ conversationId }),
@@ -6,6 +6,10 @@ export type EventEnvelope = {
  * Unique event ID
  */
 id: string, 
+/**
+ * Version of the event schema the envelope follows
+ */
+schema_version: number, 
 /**
  * When the event occurred
  */
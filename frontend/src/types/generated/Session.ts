@@ -10,4 +10,8 @@ implementation_phase_number: number | null,
 /**
  * For multi-phase implementation: current phase title
  */
-implementation_phase_title: string | null, };
+implementation_phase_title: string | null, 
+/**
+ * Failed session this one retries
+ */
+retry_of: string | null, };